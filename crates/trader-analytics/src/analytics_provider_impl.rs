@@ -12,11 +12,13 @@
 use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use tracing::{debug, warn};
 use trader_core::{
     domain::{
-        AnalyticsError, AnalyticsProvider, GlobalScoreResult, MacroEnvironment, MarketBreadth,
-        MarketRegime, RouteState, ScreeningPreset, ScreeningResult, StructuralFeatures,
+        AnalyticsError, AnalyticsProvider, GlobalScoreBreakdown, GlobalScoreResult,
+        MacroEnvironment, MarketBreadth, MarketRegime, RouteState, ScreeningPreset,
+        ScreeningResult, StructuralFeatures,
     },
     types::MarketType,
     Timeframe,
@@ -24,8 +26,8 @@ use trader_core::{
 use trader_data::cache::CachedHistoricalDataProvider;
 
 use crate::{
-    GlobalScorer, IndicatorEngine, MarketRegimeCalculator, RouteStateCalculator,
-    StructuralFeaturesCalculator,
+    GlobalScorer, GlobalScorerParams, IndicatorEngine, MarketRegimeCalculator,
+    RouteStateCalculator, StructuralFeaturesCalculator,
 };
 
 /// AnalyticsProvider 구현체.
@@ -228,6 +230,46 @@ impl AnalyticsProvider for AnalyticsProviderImpl {
         Ok(Vec::new())
     }
 
+    async fn fetch_global_score_breakdowns(
+        &self,
+        tickers: &[&str],
+    ) -> Result<HashMap<String, GlobalScoreBreakdown>, AnalyticsError> {
+        let mut results = HashMap::new();
+        let indicator_engine = IndicatorEngine::new();
+
+        for ticker in tickers {
+            let candles = match self.get_candles(ticker, 80).await {
+                Ok(candles) => candles,
+                Err(e) => {
+                    warn!(ticker = ticker, error = %e, "Failed to fetch candles for GlobalScore breakdown");
+                    continue;
+                }
+            };
+
+            // StructuralFeatures는 선택 입력 (실패 시 ERS 결측으로 표시됨)
+            let structural_features =
+                StructuralFeaturesCalculator::from_candles(ticker, &candles, &indicator_engine)
+                    .ok();
+
+            let params = GlobalScorerParams {
+                symbol: Some(ticker.to_string()),
+                structural_features,
+                ..Default::default()
+            };
+
+            match self.global_scorer.calculate_breakdown(&candles, &params) {
+                Ok(breakdown) => {
+                    results.insert(ticker.to_string(), breakdown);
+                }
+                Err(e) => {
+                    warn!(ticker = ticker, error = %e, "GlobalScore breakdown calculation failed");
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
     async fn fetch_route_states(
         &self,
         tickers: &[&str],
@@ -334,6 +376,32 @@ impl AnalyticsProvider for AnalyticsProviderImpl {
         debug!("fetch_market_breadth called (not yet implemented)");
         Ok(MarketBreadth::default())
     }
+
+    async fn fetch_updated_tickers(
+        &self,
+        tickers: &[&str],
+        since: DateTime<Utc>,
+    ) -> Result<Vec<String>, AnalyticsError> {
+        let mut updated = Vec::new();
+
+        for ticker in tickers {
+            // 최신 캔들의 종료 시각으로 판단 (진행 중인 캔들은 항상 갱신 대상)
+            match self.get_candles(ticker, 1).await {
+                Ok(candles) => {
+                    if candles.last().is_some_and(|kline| kline.close_time > since) {
+                        updated.push(ticker.to_string());
+                    }
+                }
+                Err(e) => {
+                    // 확인할 수 없으면 재조회 대상에 포함
+                    warn!(ticker = ticker, error = %e, "Failed to check candle update time");
+                    updated.push(ticker.to_string());
+                }
+            }
+        }
+
+        Ok(updated)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,385 @@
+//! 벤치마크(Buy & Hold) 대비 상대 성과 분석.
+//!
+//! 전략 자산 곡선과 같은 기간 동안 벤치마크 심볼을 단순 보유했을 때의 자산 곡선을
+//! 계산하고, 두 곡선의 기간 수익률로 알파/베타/정보 비율을 구합니다.
+//!
+//! # 계산 방식
+//!
+//! - 벤치마크 곡선은 비교 구간 시작 시점의 전략 자산으로 첫 캔들 시가에 전량 매수한 것으로 가정합니다.
+//! - 전략 자산은 각 벤치마크 캔들 종료 시각 기준 직전 자산 곡선 값(as-of)으로 맞춥니다.
+//! - 베타 = Cov(전략, 벤치마크) / Var(벤치마크)
+//! - 알파 = 젠센 알파, 연율화 % (`(R̄s - rf) - β(R̄b - rf)` × 252)
+//! - 정보 비율 = 초과 수익률 평균 / 추적 오차 × √252
+//!
+//! 벤치마크 데이터가 전략 기간보다 짧으면 공통 구간으로 맞추고 경고를 남깁니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use serde::{Deserialize, Serialize};
+use trader_core::Kline;
+
+use crate::performance::{EquityPoint, TRADING_DAYS_PER_YEAR};
+
+/// 기간 불일치 경고 허용 오차 (휴장일 차이 흡수)
+const ALIGNMENT_TOLERANCE_DAYS: i64 = 3;
+
+/// 벤치마크 대비 성과 비교 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkComparison {
+    /// 벤치마크 심볼
+    pub symbol: String,
+
+    /// 비교 구간 시작
+    pub start_time: DateTime<Utc>,
+
+    /// 비교 구간 종료
+    pub end_time: DateTime<Utc>,
+
+    /// 비교 구간 전략 수익률 (%)
+    pub strategy_return_pct: Decimal,
+
+    /// 벤치마크 보유 수익률 (%)
+    pub benchmark_return_pct: Decimal,
+
+    /// 젠센 알파 (연율화, %)
+    pub alpha: Decimal,
+
+    /// 베타 (벤치마크 민감도)
+    pub beta: Decimal,
+
+    /// 정보 비율 (연율화)
+    pub information_ratio: Decimal,
+
+    /// 벤치마크 보유 자산 곡선 (차트 표시용)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub equity_curve: Vec<EquityPoint>,
+}
+
+impl BenchmarkComparison {
+    /// 전략 자산 곡선과 벤치마크 캔들로 비교 결과를 계산합니다.
+    ///
+    /// 공통 구간에 벤치마크 캔들이 없거나 가격이 0이면 None을 반환합니다.
+    pub fn calculate(
+        symbol: &str,
+        benchmark_klines: &[Kline],
+        strategy_curve: &[EquityPoint],
+        risk_free_rate: f64,
+    ) -> Option<Self> {
+        let strategy_start = strategy_curve.first()?.timestamp;
+        let strategy_end = strategy_curve.last()?.timestamp;
+
+        let in_period: Vec<&Kline> = benchmark_klines
+            .iter()
+            .filter(|k| k.open_time >= strategy_start && k.close_time <= strategy_end)
+            .collect();
+        let first = *in_period.first()?;
+        let last = *in_period.last()?;
+
+        // 벤치마크 데이터가 전략 기간보다 짧으면 공통 구간으로 정렬
+        let start_time = strategy_start.max(first.open_time);
+        let end_time = strategy_end.min(last.close_time);
+        let tolerance = chrono::Duration::days(ALIGNMENT_TOLERANCE_DAYS);
+        if first.open_time - strategy_start > tolerance
+            || strategy_end - last.close_time > tolerance
+        {
+            tracing::warn!(
+                benchmark = symbol,
+                strategy_start = %strategy_start.format("%Y-%m-%d"),
+                strategy_end = %strategy_end.format("%Y-%m-%d"),
+                common_start = %start_time.format("%Y-%m-%d"),
+                common_end = %end_time.format("%Y-%m-%d"),
+                "벤치마크 데이터가 전략 기간보다 짧아 공통 구간으로 비교합니다"
+            );
+        }
+
+        let base_price = if first.open.is_zero() {
+            first.close
+        } else {
+            first.open
+        };
+        let base_equity = equity_as_of(strategy_curve, start_time)?;
+        if base_price.is_zero() || base_equity.is_zero() {
+            return None;
+        }
+
+        // (전략 자산, 벤치마크 가격) 쌍: 시작점 + 각 캔들 종료 시점
+        let mut levels = Vec::with_capacity(in_period.len() + 1);
+        levels.push((base_equity, base_price));
+        let mut equity_curve = Vec::with_capacity(in_period.len() + 1);
+        equity_curve.push(EquityPoint {
+            timestamp: start_time,
+            equity: base_equity,
+            drawdown_pct: Decimal::ZERO,
+        });
+
+        let mut peak = base_equity;
+        for kline in &in_period {
+            let Some(strategy_equity) = equity_as_of(strategy_curve, kline.close_time) else {
+                continue;
+            };
+            levels.push((strategy_equity, kline.close));
+
+            let equity = base_equity * kline.close / base_price;
+            peak = peak.max(equity);
+            let drawdown_pct = if peak.is_zero() {
+                Decimal::ZERO
+            } else {
+                (peak - equity) / peak * Decimal::ONE_HUNDRED
+            };
+            equity_curve.push(EquityPoint {
+                timestamp: kline.close_time,
+                equity,
+                drawdown_pct,
+            });
+        }
+
+        let (strategy_returns, benchmark_returns): (Vec<Decimal>, Vec<Decimal>) = levels
+            .windows(2)
+            .filter(|w| !w[0].0.is_zero() && !w[0].1.is_zero())
+            .map(|w| {
+                (
+                    w[1].0 / w[0].0 - Decimal::ONE,
+                    w[1].1 / w[0].1 - Decimal::ONE,
+                )
+            })
+            .unzip();
+
+        let (last_equity, last_price) = *levels.last()?;
+        let strategy_return_pct = (last_equity / base_equity - Decimal::ONE) * Decimal::ONE_HUNDRED;
+        let benchmark_return_pct = (last_price / base_price - Decimal::ONE) * Decimal::ONE_HUNDRED;
+
+        let periods_per_year = Decimal::from(TRADING_DAYS_PER_YEAR);
+        let period_rf =
+            Decimal::from_f64(risk_free_rate).unwrap_or(Decimal::ZERO) / periods_per_year;
+
+        let beta = beta(&strategy_returns, &benchmark_returns);
+        let alpha = match (mean(&strategy_returns), mean(&benchmark_returns)) {
+            (Some(rs), Some(rb)) => {
+                ((rs - period_rf) - beta * (rb - period_rf))
+                    * periods_per_year
+                    * Decimal::ONE_HUNDRED
+            }
+            _ => Decimal::ZERO,
+        };
+
+        let active_returns: Vec<Decimal> = strategy_returns
+            .iter()
+            .zip(&benchmark_returns)
+            .map(|(s, b)| s - b)
+            .collect();
+        let information_ratio = match (mean(&active_returns), std_dev(&active_returns)) {
+            (Some(avg), Some(tracking_error)) if !tracking_error.is_zero() => {
+                avg / tracking_error * sqrt(periods_per_year)
+            }
+            _ => Decimal::ZERO,
+        };
+
+        Some(Self {
+            symbol: symbol.to_string(),
+            start_time,
+            end_time,
+            strategy_return_pct: strategy_return_pct.round_dp(4),
+            benchmark_return_pct: benchmark_return_pct.round_dp(4),
+            alpha: alpha.round_dp(4),
+            beta: beta.round_dp(4),
+            information_ratio: information_ratio.round_dp(4),
+            equity_curve,
+        })
+    }
+
+    /// 벤치마크 대비 초과 수익률 (%p)
+    pub fn excess_return_pct(&self) -> Decimal {
+        self.strategy_return_pct - self.benchmark_return_pct
+    }
+
+    /// 요약 문자열 반환
+    pub fn summary(&self) -> String {
+        format!(
+            "벤치마크 비교 ({}, {} → {})\n\
+             ───────────────────────────────────────\n\
+             전략 수익률: {:.2}%\n\
+             벤치마크 수익률: {:.2}%\n\
+             초과 수익률: {:+.2}%p\n\
+             알파 (연율화): {:.2}%\n\
+             베타: {:.2}\n\
+             정보 비율: {:.2}",
+            self.symbol,
+            self.start_time.format("%Y-%m-%d"),
+            self.end_time.format("%Y-%m-%d"),
+            self.strategy_return_pct,
+            self.benchmark_return_pct,
+            self.excess_return_pct(),
+            self.alpha,
+            self.beta,
+            self.information_ratio,
+        )
+    }
+}
+
+/// 주어진 시각 이전의 마지막 자산 값 (as-of 조회)
+fn equity_as_of(curve: &[EquityPoint], time: DateTime<Utc>) -> Option<Decimal> {
+    let idx = curve.partition_point(|p| p.timestamp <= time);
+    idx.checked_sub(1).map(|i| curve[i].equity)
+}
+
+fn mean(values: &[Decimal]) -> Option<Decimal> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().copied().sum::<Decimal>() / Decimal::from(values.len()))
+}
+
+/// 표본 표준편차 (n-1)
+fn std_dev(values: &[Decimal]) -> Option<Decimal> {
+    if values.len() < 2 {
+        return None;
+    }
+    let avg = mean(values)?;
+    let variance = values
+        .iter()
+        .map(|v| (*v - avg) * (*v - avg))
+        .sum::<Decimal>()
+        / Decimal::from(values.len() - 1);
+    Some(sqrt(variance))
+}
+
+/// 베타 = Cov(s, b) / Var(b). 데이터가 부족하거나 벤치마크 분산이 0이면 0.
+fn beta(strategy_returns: &[Decimal], benchmark_returns: &[Decimal]) -> Decimal {
+    let n = strategy_returns.len().min(benchmark_returns.len());
+    if n < 2 {
+        return Decimal::ZERO;
+    }
+    let (Some(mean_s), Some(mean_b)) = (mean(strategy_returns), mean(benchmark_returns)) else {
+        return Decimal::ZERO;
+    };
+
+    let mut covariance = Decimal::ZERO;
+    let mut variance = Decimal::ZERO;
+    for (s, b) in strategy_returns.iter().zip(benchmark_returns) {
+        covariance += (*s - mean_s) * (*b - mean_b);
+        variance += (*b - mean_b) * (*b - mean_b);
+    }
+
+    if variance.is_zero() {
+        Decimal::ZERO
+    } else {
+        covariance / variance
+    }
+}
+
+fn sqrt(value: Decimal) -> Decimal {
+    value
+        .to_f64()
+        .filter(|v| *v > 0.0)
+        .and_then(|v| Decimal::from_f64(v.sqrt()))
+        .unwrap_or(Decimal::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+    use rust_decimal_macros::dec;
+    use trader_core::Timeframe;
+
+    use super::*;
+
+    fn day(n: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::days(n)
+    }
+
+    fn kline(n: i64, open: Decimal, close: Decimal) -> Kline {
+        Kline::new(
+            "SPY".to_string(),
+            Timeframe::D1,
+            day(n),
+            open,
+            open.max(close),
+            open.min(close),
+            close,
+            dec!(1000),
+            day(n + 1),
+        )
+    }
+
+    fn point(n: i64, equity: Decimal) -> EquityPoint {
+        EquityPoint {
+            timestamp: day(n),
+            equity,
+            drawdown_pct: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_strategy_equal_to_benchmark() {
+        // 전략 자산이 벤치마크와 동일하게 움직이면 베타 1, 알파/정보 비율 0에 가까움
+        let prices = [dec!(100), dec!(102), dec!(101), dec!(105), dec!(104)];
+        let klines: Vec<Kline> = prices
+            .windows(2)
+            .enumerate()
+            .map(|(i, w)| kline(i as i64, w[0], w[1]))
+            .collect();
+        let curve: Vec<EquityPoint> = prices
+            .iter()
+            .enumerate()
+            .map(|(i, p)| point(i as i64, *p * dec!(100)))
+            .collect();
+
+        let result = BenchmarkComparison::calculate("SPY", &klines, &curve, 0.0).unwrap();
+
+        assert_eq!(result.benchmark_return_pct, dec!(4));
+        assert_eq!(result.strategy_return_pct, dec!(4));
+        assert_eq!(result.beta, dec!(1));
+        assert_eq!(result.alpha, Decimal::ZERO);
+        assert_eq!(result.information_ratio, Decimal::ZERO);
+        assert_eq!(result.equity_curve.len(), 5);
+        assert_eq!(result.equity_curve.last().unwrap().equity, dec!(10400));
+    }
+
+    #[test]
+    fn test_flat_strategy_has_zero_beta() {
+        let klines = vec![
+            kline(0, dec!(100), dec!(110)),
+            kline(1, dec!(110), dec!(99)),
+            kline(2, dec!(99), dec!(120)),
+        ];
+        let curve: Vec<EquityPoint> = (0..4).map(|i| point(i, dec!(10000))).collect();
+
+        let result = BenchmarkComparison::calculate("SPY", &klines, &curve, 0.0).unwrap();
+
+        assert_eq!(result.benchmark_return_pct, dec!(20));
+        assert_eq!(result.strategy_return_pct, Decimal::ZERO);
+        assert_eq!(result.beta, Decimal::ZERO);
+        assert!(result.information_ratio < Decimal::ZERO);
+        assert_eq!(result.excess_return_pct(), dec!(-20));
+    }
+
+    #[test]
+    fn test_shorter_benchmark_uses_common_period() {
+        // 벤치마크는 3일차부터 존재 → 비교 구간은 3일차 시작
+        let klines = vec![kline(3, dec!(50), dec!(55)), kline(4, dec!(55), dec!(60))];
+        let curve: Vec<EquityPoint> = (0..6)
+            .map(|i| point(i, dec!(1000) + Decimal::from(i * 100)))
+            .collect();
+
+        let result = BenchmarkComparison::calculate("SPY", &klines, &curve, 0.0).unwrap();
+
+        assert_eq!(result.start_time, day(3));
+        assert_eq!(result.end_time, day(5));
+        // 전략: 1300 → 1500
+        assert_eq!(result.strategy_return_pct.round_dp(2), dec!(15.38));
+        assert_eq!(result.benchmark_return_pct, dec!(20));
+        assert_eq!(result.equity_curve.first().unwrap().equity, dec!(1300));
+    }
+
+    #[test]
+    fn test_no_overlap_returns_none() {
+        let klines = vec![kline(10, dec!(100), dec!(101))];
+        let curve: Vec<EquityPoint> = (0..3).map(|i| point(i, dec!(1000))).collect();
+
+        assert!(BenchmarkComparison::calculate("SPY", &klines, &curve, 0.0).is_none());
+        assert!(BenchmarkComparison::calculate("SPY", &[], &curve, 0.0).is_none());
+    }
+}
@@ -0,0 +1,590 @@
+//! 거래 비용 민감도 분석.
+//!
+//! 편도 거래 비용(수수료 + 슬리피지)을 0부터 상한까지 단계적으로 높여가며
+//! 같은 전략을 반복 백테스트하고, 수익률이 0이 되는 손익분기 비용을 찾습니다.
+//!
+//! # 해석
+//!
+//! - 비용 한 단위가 오를 때마다 수익률은 대략 `회전율 × 비용`만큼 줄어듭니다.
+//!   회전율이 높은 전략일수록 낮은 비용에서 수익이 사라집니다.
+//! - 안전마진은 손익분기 비용이 현재 설정 비용보다 얼마나 높은지를 나타냅니다.
+//!
+//! # 병렬 실행
+//!
+//! 각 비용 수준의 백테스트는 CPU-intensive 작업이므로 `spawn_blocking`으로
+//! blocking thread pool에서 실행하며, 동시 실행 수는 `max_parallel`로 제한합니다.
+
+use std::sync::Arc;
+
+use futures::{stream, StreamExt};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use trader_core::{Kline, StrategyContext};
+use trader_strategy::Strategy;
+
+use super::engine::{BacktestConfig, BacktestEngine, BacktestError, BacktestResult};
+
+/// 전략 인스턴스 생성 함수 (비용 수준마다 새 인스턴스 필요).
+pub type StrategyFactory = Arc<dyn Fn() -> Box<dyn Strategy> + Send + Sync>;
+
+/// 비용 민감도 분석 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostSensitivityConfig {
+    /// 편도 총 비용 상한 (수수료 + 슬리피지, 예: 0.01 = 1%)
+    pub max_cost_rate: Decimal,
+    /// 0부터 상한까지 나눌 구간 수
+    pub steps: usize,
+    /// 동시에 실행할 백테스트 수
+    pub max_parallel: usize,
+}
+
+impl Default for CostSensitivityConfig {
+    fn default() -> Self {
+        Self {
+            max_cost_rate: Decimal::new(1, 2), // 1%
+            steps: 20,
+            max_parallel: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(4),
+        }
+    }
+}
+
+impl CostSensitivityConfig {
+    /// 설정 검증
+    pub fn validate(&self) -> BacktestResult<()> {
+        if self.max_cost_rate <= Decimal::ZERO {
+            return Err(BacktestError::ConfigError(
+                "비용 상한은 0보다 커야 합니다".to_string(),
+            ));
+        }
+        if self.steps == 0 {
+            return Err(BacktestError::ConfigError(
+                "구간 수는 1 이상이어야 합니다".to_string(),
+            ));
+        }
+        if self.max_parallel == 0 {
+            return Err(BacktestError::ConfigError(
+                "동시 실행 수는 1 이상이어야 합니다".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 비용 수준별 백테스트 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostLevelResult {
+    /// 편도 총 비용 (수수료 + 슬리피지)
+    pub cost_rate: Decimal,
+    /// 적용된 수수료율
+    pub commission_rate: Decimal,
+    /// 적용된 슬리피지율
+    pub slippage_rate: Decimal,
+    /// 총 수익률 (%)
+    pub total_return_pct: Decimal,
+    /// 순손익
+    pub net_profit: Decimal,
+    /// 완료된 거래 수 (라운드트립)
+    pub total_trades: usize,
+    /// 총 거래대금 (매수 + 매도)
+    pub traded_value: Decimal,
+    /// 현재 설정 비용 수준 여부
+    pub is_base: bool,
+}
+
+/// 손익분기 비용 탐색 결과 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakEvenStatus {
+    /// 분석 범위 안에서 손익분기 비용을 찾음
+    Found,
+    /// 비용 상한에서도 수익 - 손익분기 비용은 상한보다 높음
+    AboveRange,
+    /// 비용이 0이어도 수익이 없음 - 전략 자체에 우위가 없음
+    NoEdge,
+}
+
+/// 비용 민감도 분석 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostSensitivityReport {
+    /// 현재 설정된 편도 총 비용
+    pub base_cost_rate: Decimal,
+    /// 현재 설정 비용에서의 총 수익률 (%)
+    pub base_return_pct: Decimal,
+    /// 비용 수준별 결과 (비용 오름차순, 수익률 곡선)
+    pub levels: Vec<CostLevelResult>,
+    /// 손익분기 탐색 상태
+    pub break_even_status: BreakEvenStatus,
+    /// 수익률이 0이 되는 편도 비용 (인접 구간 선형 보간)
+    pub break_even_cost_rate: Option<Decimal>,
+    /// 안전마진 (손익분기 비용 - 현재 비용, 음수면 이미 손실 구간)
+    pub safety_margin: Option<Decimal>,
+    /// 안전배수 (손익분기 비용 / 현재 비용)
+    pub safety_multiple: Option<Decimal>,
+    /// 회전율 (비용 0 기준 총 거래대금 / 초기 자본)
+    pub turnover: Decimal,
+}
+
+/// 거래 비용 민감도 분석기.
+pub struct CostSensitivityAnalyzer {
+    /// 기준 백테스트 설정 (현재 비용 포함)
+    base_config: BacktestConfig,
+    /// 분석 설정
+    config: CostSensitivityConfig,
+}
+
+impl CostSensitivityAnalyzer {
+    /// 새 분석기 생성
+    pub fn new(base_config: BacktestConfig, config: CostSensitivityConfig) -> Self {
+        Self {
+            base_config,
+            config,
+        }
+    }
+
+    /// 현재 설정된 편도 총 비용 (수수료 + 슬리피지).
+    pub fn base_cost_rate(&self) -> Decimal {
+        self.base_config.commission_rate + self.base_config.slippage_rate
+    }
+
+    /// 분석할 편도 총 비용 목록 (오름차순, 현재 비용 포함).
+    pub fn cost_levels(&self) -> Vec<Decimal> {
+        let steps = Decimal::from(self.config.steps);
+        let mut levels: Vec<Decimal> = (0..=self.config.steps)
+            .map(|i| self.config.max_cost_rate * Decimal::from(i) / steps)
+            .collect();
+
+        let base = self.base_cost_rate();
+        if !levels.contains(&base) {
+            levels.push(base);
+            levels.sort();
+        }
+        levels
+    }
+
+    /// 총 비용을 현재 설정의 수수료:슬리피지 비율대로 나눔.
+    ///
+    /// 현재 비용이 0이면 절반씩 배분합니다.
+    fn split_cost(&self, cost_rate: Decimal) -> (Decimal, Decimal) {
+        let base = self.base_cost_rate();
+        let commission_share = if base > Decimal::ZERO {
+            self.base_config.commission_rate / base
+        } else {
+            Decimal::new(5, 1)
+        };
+        let commission_rate = cost_rate * commission_share;
+        (commission_rate, cost_rate - commission_rate)
+    }
+
+    /// 비용 민감도 분석 실행.
+    ///
+    /// 비용 수준마다 `strategy_factory`로 새 전략을 만들어 `strategy_params`로 초기화한 뒤
+    /// 백테스트합니다. 비용을 정확히 통제하기 위해 동적 슬리피지 모델은 사용하지 않고
+    /// 고정 슬리피지율을 적용합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `strategy_factory` - 전략 인스턴스 생성 함수
+    /// * `strategy_params` - 전략 초기화 파라미터
+    /// * `klines` - 메인 티커의 과거 캔들 데이터
+    /// * `context` - StrategyContext 원본 (실행마다 복제, 다중 심볼 klines 포함)
+    /// * `ticker` - 메인 종목 티커
+    pub async fn run(
+        &self,
+        strategy_factory: StrategyFactory,
+        strategy_params: serde_json::Value,
+        klines: Vec<Kline>,
+        context: StrategyContext,
+        ticker: &str,
+    ) -> BacktestResult<CostSensitivityReport> {
+        self.config.validate()?;
+        self.base_config.validate()?;
+
+        let klines = Arc::new(klines);
+        let base_cost = self.base_cost_rate();
+
+        let jobs: Vec<BacktestConfig> = self
+            .cost_levels()
+            .into_iter()
+            .map(|cost_rate| {
+                let (commission_rate, slippage_rate) = self.split_cost(cost_rate);
+                let mut config = self.base_config.clone();
+                config.commission_rate = commission_rate;
+                config.slippage_rate = slippage_rate;
+                config.slippage_model = None;
+                config
+            })
+            .collect();
+
+        let results: Vec<BacktestResult<CostLevelResult>> = stream::iter(jobs)
+            .map(|config| {
+                let factory = Arc::clone(&strategy_factory);
+                let params = strategy_params.clone();
+                let klines = Arc::clone(&klines);
+                let context = context.clone();
+                let ticker = ticker.to_string();
+                async move {
+                    tokio::task::spawn_blocking(move || {
+                        // blocking 컨텍스트에서 async 백테스트를 실행하기 위해 새 runtime 생성
+                        let rt = tokio::runtime::Builder::new_current_thread()
+                            .enable_all()
+                            .build()
+                            .map_err(|e| {
+                                BacktestError::ExecutionError(format!("Runtime 생성 실패: {}", e))
+                            })?;
+                        rt.block_on(run_cost_level(
+                            config, factory, params, &klines, context, &ticker,
+                        ))
+                    })
+                    .await
+                    .map_err(|e| {
+                        BacktestError::ExecutionError(format!("백테스트 태스크 실행 실패: {}", e))
+                    })?
+                }
+            })
+            .buffer_unordered(self.config.max_parallel)
+            .collect()
+            .await;
+
+        let mut levels = results.into_iter().collect::<BacktestResult<Vec<_>>>()?;
+        levels.sort_by_key(|l| l.cost_rate);
+        for level in &mut levels {
+            level.is_base = level.cost_rate == base_cost;
+        }
+
+        Ok(build_report(
+            levels,
+            base_cost,
+            self.base_config.initial_capital,
+        ))
+    }
+}
+
+/// 단일 비용 수준 백테스트 실행.
+async fn run_cost_level(
+    config: BacktestConfig,
+    factory: StrategyFactory,
+    params: serde_json::Value,
+    klines: &[Kline],
+    context: StrategyContext,
+    ticker: &str,
+) -> BacktestResult<CostLevelResult> {
+    let commission_rate = config.commission_rate;
+    let slippage_rate = config.slippage_rate;
+
+    let mut strategy = factory();
+    strategy
+        .initialize(params)
+        .await
+        .map_err(|e| BacktestError::StrategyError(format!("전략 초기화 실패: {}", e)))?;
+
+    let context = Arc::new(RwLock::new(context));
+    strategy.set_context(Arc::clone(&context));
+
+    let mut engine = BacktestEngine::new(config);
+    let report = engine
+        .run(&mut *strategy, klines, context, ticker, None)
+        .await?;
+
+    let traded_value = report.all_trades.iter().map(|t| t.price * t.quantity).sum();
+
+    Ok(CostLevelResult {
+        cost_rate: commission_rate + slippage_rate,
+        commission_rate,
+        slippage_rate,
+        total_return_pct: report.metrics.total_return_pct,
+        net_profit: report.metrics.net_profit,
+        total_trades: report.metrics.total_trades,
+        traded_value,
+        is_base: false,
+    })
+}
+
+/// 비용 수준별 결과로 손익분기 비용과 안전마진 계산.
+fn build_report(
+    levels: Vec<CostLevelResult>,
+    base_cost_rate: Decimal,
+    initial_capital: Decimal,
+) -> CostSensitivityReport {
+    let base_return_pct = levels
+        .iter()
+        .find(|l| l.is_base)
+        .map(|l| l.total_return_pct)
+        .unwrap_or(Decimal::ZERO);
+
+    let turnover = match levels.first() {
+        Some(zero_cost) if initial_capital > Decimal::ZERO => {
+            zero_cost.traded_value / initial_capital
+        }
+        _ => Decimal::ZERO,
+    };
+
+    let (break_even_status, break_even_cost_rate) = find_break_even(&levels);
+
+    let safety_margin = break_even_cost_rate.map(|be| be - base_cost_rate);
+    let safety_multiple = break_even_cost_rate
+        .filter(|_| base_cost_rate > Decimal::ZERO)
+        .map(|be| be / base_cost_rate);
+
+    CostSensitivityReport {
+        base_cost_rate,
+        base_return_pct,
+        levels,
+        break_even_status,
+        break_even_cost_rate,
+        safety_margin,
+        safety_multiple,
+        turnover,
+    }
+}
+
+/// 수익률이 처음으로 0 이하가 되는 구간을 찾아 선형 보간.
+fn find_break_even(levels: &[CostLevelResult]) -> (BreakEvenStatus, Option<Decimal>) {
+    match levels.first() {
+        Some(first) if first.total_return_pct > Decimal::ZERO => {}
+        _ => return (BreakEvenStatus::NoEdge, None),
+    }
+
+    for pair in levels.windows(2) {
+        let (prev, next) = (&pair[0], &pair[1]);
+        if next.total_return_pct <= Decimal::ZERO {
+            let drop = prev.total_return_pct - next.total_return_pct;
+            let ratio = if drop > Decimal::ZERO {
+                prev.total_return_pct / drop
+            } else {
+                Decimal::ONE
+            };
+            let cost = prev.cost_rate + (next.cost_rate - prev.cost_rate) * ratio;
+            return (BreakEvenStatus::Found, Some(cost));
+        }
+    }
+
+    (BreakEvenStatus::AboveRange, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::{Duration, TimeZone, Utc};
+    use rust_decimal_macros::dec;
+    use serde_json::Value;
+    use trader_core::{MarketData, MarketDataType, Order, Position, Side, Signal, Timeframe};
+
+    use super::*;
+
+    /// `hold`개 캔들마다 진입/청산을 반복하는 전략 (테스트용)
+    struct PeriodicStrategy {
+        hold: usize,
+        count: usize,
+        in_position: bool,
+    }
+
+    #[async_trait]
+    impl Strategy for PeriodicStrategy {
+        fn name(&self) -> &str {
+            "Periodic"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "주기적 매매 테스트 전략"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.count = 0;
+            self.in_position = false;
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            if !matches!(data.data, MarketDataType::Kline(_)) {
+                return Ok(vec![]);
+            }
+            self.count += 1;
+            if self.count % self.hold != 0 {
+                return Ok(vec![]);
+            }
+
+            self.in_position = !self.in_position;
+            let signal = if self.in_position {
+                Signal::entry("Periodic", data.ticker.clone(), Side::Buy)
+            } else {
+                Signal::exit("Periodic", data.ticker.clone(), Side::Sell)
+            };
+            Ok(vec![signal])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({ "count": self.count })
+        }
+    }
+
+    fn factory(hold: usize) -> StrategyFactory {
+        Arc::new(move || {
+            Box::new(PeriodicStrategy {
+                hold,
+                count: 0,
+                in_position: false,
+            })
+        })
+    }
+
+    /// 완만하게 상승하는 캔들 데이터
+    fn rising_klines(count: usize) -> Vec<Kline> {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        (0..count)
+            .map(|i| {
+                let price = dec!(10000) + Decimal::from(i) * dec!(10);
+                let open_time = start + Duration::days(i as i64);
+                Kline::new(
+                    "TEST".to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    price,
+                    price + dec!(20),
+                    price - dec!(20),
+                    price,
+                    dec!(1000),
+                    open_time + Duration::days(1),
+                )
+            })
+            .collect()
+    }
+
+    async fn analyze(hold: usize) -> CostSensitivityReport {
+        let base = BacktestConfig::new(dec!(10_000_000))
+            .with_commission_rate(dec!(0.0003))
+            .with_slippage_rate(dec!(0.0002));
+        let analyzer = CostSensitivityAnalyzer::new(
+            base,
+            CostSensitivityConfig {
+                max_cost_rate: dec!(0.02),
+                steps: 20,
+                max_parallel: 4,
+            },
+        );
+        analyzer
+            .run(
+                factory(hold),
+                Value::Null,
+                rising_klines(120),
+                StrategyContext::default(),
+                "TEST",
+            )
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_cost_levels_include_base() {
+        let base = BacktestConfig::new(dec!(1_000_000))
+            .with_commission_rate(dec!(0.0003))
+            .with_slippage_rate(dec!(0.0004));
+        let analyzer = CostSensitivityAnalyzer::new(
+            base,
+            CostSensitivityConfig {
+                max_cost_rate: dec!(0.01),
+                steps: 10,
+                max_parallel: 1,
+            },
+        );
+
+        let levels = analyzer.cost_levels();
+        assert_eq!(levels.len(), 12);
+        assert_eq!(levels[0], Decimal::ZERO);
+        assert!(levels.contains(&dec!(0.0007)));
+        assert!(levels.windows(2).all(|w| w[0] < w[1]));
+
+        // 현재 비율(3:4)대로 분배
+        let (commission, slippage) = analyzer.split_cost(dec!(0.007));
+        assert_eq!(commission, dec!(0.003));
+        assert_eq!(slippage, dec!(0.004));
+    }
+
+    #[test]
+    fn test_find_break_even_interpolates() {
+        let level = |cost: Decimal, ret: Decimal| CostLevelResult {
+            cost_rate: cost,
+            commission_rate: cost,
+            slippage_rate: Decimal::ZERO,
+            total_return_pct: ret,
+            net_profit: Decimal::ZERO,
+            total_trades: 1,
+            traded_value: Decimal::ZERO,
+            is_base: false,
+        };
+
+        let levels = vec![
+            level(dec!(0), dec!(10)),
+            level(dec!(0.01), dec!(4)),
+            level(dec!(0.02), dec!(-4)),
+        ];
+        assert_eq!(
+            find_break_even(&levels),
+            (BreakEvenStatus::Found, Some(dec!(0.015)))
+        );
+
+        assert_eq!(
+            find_break_even(&levels[..2]),
+            (BreakEvenStatus::AboveRange, None)
+        );
+        assert_eq!(
+            find_break_even(&[level(dec!(0), dec!(-1))]),
+            (BreakEvenStatus::NoEdge, None)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_high_turnover_breaks_even_at_lower_cost() {
+        let active = analyze(2).await;
+        let passive = analyze(30).await;
+
+        assert_eq!(active.levels.len(), 22);
+        assert!(active.turnover > passive.turnover);
+        assert!(active.levels.iter().any(|l| l.is_base));
+
+        // 수익률은 비용이 오를수록 감소
+        assert!(active
+            .levels
+            .windows(2)
+            .all(|w| w[0].total_return_pct >= w[1].total_return_pct));
+
+        assert_eq!(active.break_even_status, BreakEvenStatus::Found);
+        let active_be = active.break_even_cost_rate.unwrap();
+        let passive_be = passive.break_even_cost_rate.unwrap_or(dec!(0.02));
+        assert!(active_be < passive_be);
+        assert_eq!(
+            active.safety_margin,
+            Some(active_be - active.base_cost_rate)
+        );
+    }
+}
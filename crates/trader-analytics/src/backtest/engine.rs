@@ -7,6 +7,7 @@
 //! - **전략 시뮬레이션**: 과거 시장 데이터로 전략의 신호 생성 및 실행
 //! - **주문 체결 시뮬레이션**: 슬리피지, 수수료 등 현실적인 체결 모델
 //! - **성과 분석**: PerformanceTracker와 통합된 상세한 성과 지표
+//! - **증거금 시뮬레이션**: 레버리지 진입, 유지 증거금 미달 시 마진콜/강제청산
 //! - **자산 곡선**: 시간에 따른 자산 가치 변화 추적
 //!
 //! # 사용 예시
@@ -42,14 +43,25 @@ use thiserror::Error;
 use tokio::sync::RwLock;
 use trader_core::{
     unrealized_pnl, Kline, MarketData, ScreeningCalculator, Side, Signal, SignalMarker, SignalType,
-    StrategyContext, Trade,
+    StrategyContext, Timeframe, Trade,
 };
-use trader_execution::{ProcessorConfig, SignalProcessor, SimulatedExecutor, TradeResult};
+use trader_execution::{
+    PositionSizingMethod, ProcessorConfig, ProcessorPosition, SignalProcessor, SimulatedExecutor,
+    SlippageModel, TradeResult,
+};
+use trader_strategy::strategies::common::{PerformanceTargetConfig, TargetEvaluation};
 use uuid::Uuid;
 
 use crate::{
-    backtest::{candle_processor::CandleProcessor, slippage::SlippageModel},
-    performance::{EquityPoint, PerformanceMetrics, PerformanceTracker, RoundTrip},
+    backtest::{
+        benchmark::BenchmarkComparison,
+        candle_processor::CandleProcessor,
+        margin::{is_margin_call, LiquidationRecord, MarginCallEvent, MarginReport},
+        progress::{BacktestProgress, BacktestProgressHandle},
+    },
+    performance::{
+        EquityPoint, ExcursionAnalysis, PerformanceMetrics, PerformanceTracker, RoundTrip,
+    },
 };
 
 /// 백테스트 오류
@@ -77,11 +89,38 @@ pub enum BacktestError {
         required: Decimal,
         available: Decimal,
     },
+
+    /// 실행 중 취소됨
+    #[error("백테스트가 취소되었습니다")]
+    Cancelled,
 }
 
 /// 백테스트 결과 타입
 pub type BacktestResult<T> = Result<T, BacktestError>;
 
+/// 신호 체결 시점
+///
+/// 신호는 캔들 종가 시점에 생성되므로, 같은 캔들 종가로 체결하면
+/// 실거래에서는 알 수 없는 가격으로 체결하는 미래 정보 누수가 생길 수 있습니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FillTiming {
+    /// 신호 발생 캔들의 종가로 즉시 체결 (기존 동작)
+    #[default]
+    CurrentClose,
+    /// 다음 캔들의 시가로 체결
+    NextOpen,
+    /// 다음 캔들의 종가로 체결
+    NextClose,
+}
+
+impl FillTiming {
+    /// 신호를 다음 캔들로 지연 체결하는지 여부
+    pub fn is_delayed(&self) -> bool {
+        !matches!(self, Self::CurrentClose)
+    }
+}
+
 /// 백테스트 설정
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BacktestConfig {
@@ -95,7 +134,8 @@ pub struct BacktestConfig {
 
     /// 슬리피지율 (예: 0.0005 = 0.05%)
     ///
-    /// 참고: slippage_model이 설정되면 무시됩니다.
+    /// 참고: slippage_model이 설정되면 모델이 우선하며, 모델에 필요한
+    /// 거래량 데이터가 없을 때의 폴백 비율로만 사용됩니다.
     #[serde(default = "default_slippage_rate")]
     pub slippage_rate: Decimal,
 
@@ -114,6 +154,12 @@ pub struct BacktestConfig {
     #[serde(default = "default_max_position_size_pct")]
     pub max_position_size_pct: Decimal,
 
+    /// 포지션 사이징 방식 (기본값: 고정 비율)
+    ///
+    /// 변동성 타깃/Kelly도 max_position_size_pct를 상한으로 사용합니다.
+    #[serde(default)]
+    pub sizing_method: PositionSizingMethod,
+
     /// 무위험 이자율 (연율화 계산용)
     #[serde(default = "default_risk_free_rate")]
     pub risk_free_rate: f64,
@@ -127,9 +173,20 @@ pub struct BacktestConfig {
     pub use_tick_simulation: bool,
 
     /// 마진 거래 허용 여부
+    ///
+    /// 활성화하면 초기 증거금률만큼의 자기자본으로 레버리지 진입이 가능하고,
+    /// 유지 증거금 미달 시 마진콜로 강제청산됩니다.
     #[serde(default)]
     pub allow_margin: bool,
 
+    /// 초기 증거금률 (예: 0.5 = 최대 2배 레버리지)
+    #[serde(default = "default_initial_margin_rate")]
+    pub initial_margin_rate: Decimal,
+
+    /// 유지 증거금률 (평가 자산 / 총 노출이 이 값 미만이면 마진콜)
+    #[serde(default = "default_maintenance_margin_rate")]
+    pub maintenance_margin_rate: Decimal,
+
     /// 숏 포지션 허용 여부
     #[serde(default)]
     pub allow_short: bool,
@@ -154,6 +211,18 @@ pub struct BacktestConfig {
     /// 최소 신호 강도 (기본값: 0.0 = 모든 신호 허용)
     #[serde(default)]
     pub min_strength: f64,
+
+    /// 벤치마크 심볼 (설정 시 Buy & Hold 대비 성과를 리포트에 포함)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmark_symbol: Option<String>,
+
+    /// 신호 체결 시점 (기본값: 신호 캔들 종가)
+    #[serde(default)]
+    pub fill_timing: FillTiming,
+
+    /// 성과 목표 (설정 시 리포트에 목표 달성 평가를 포함)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performance_target: Option<PerformanceTargetConfig>,
 }
 
 // 설정 기본값 함수들 (serde default용)
@@ -178,6 +247,12 @@ fn default_risk_free_rate() -> f64 {
 fn default_exchange_name() -> String {
     "backtest".to_string()
 }
+fn default_initial_margin_rate() -> Decimal {
+    Decimal::new(5, 1)
+} // 50%
+fn default_maintenance_margin_rate() -> Decimal {
+    Decimal::new(25, 2)
+} // 25%
 fn default_stop_loss_pct() -> Decimal {
     Decimal::new(5, 2)
 } // 5%
@@ -194,16 +269,22 @@ impl Default for BacktestConfig {
             slippage_model: None,
             max_positions: default_max_positions(),
             max_position_size_pct: default_max_position_size_pct(),
+            sizing_method: PositionSizingMethod::default(),
             risk_free_rate: default_risk_free_rate(),
             exchange_name: default_exchange_name(),
             use_tick_simulation: false,
             allow_margin: false,
+            initial_margin_rate: default_initial_margin_rate(),
+            maintenance_margin_rate: default_maintenance_margin_rate(),
             allow_short: false,
             auto_stop_loss: false,
             auto_take_profit: false,
             stop_loss_pct: default_stop_loss_pct(),
             take_profit_pct: default_take_profit_pct(),
             min_strength: 0.0,
+            benchmark_symbol: None,
+            fill_timing: FillTiming::default(),
+            performance_target: None,
         }
     }
 }
@@ -225,7 +306,7 @@ impl BacktestConfig {
 
     /// 슬리피지율 설정 (고정 비율)
     ///
-    /// 참고: with_slippage_model()로 동적 모델을 설정하면 무시됩니다.
+    /// 참고: with_slippage_model()로 동적 모델을 설정하면 폴백 비율로만 사용됩니다.
     pub fn with_slippage_rate(mut self, rate: Decimal) -> Self {
         self.slippage_rate = rate;
         self
@@ -256,6 +337,12 @@ impl BacktestConfig {
         self
     }
 
+    /// 포지션 사이징 방식 설정
+    pub fn with_sizing_method(mut self, method: PositionSizingMethod) -> Self {
+        self.sizing_method = method;
+        self
+    }
+
     /// 최대 포지션 수 설정
     pub fn with_max_positions(mut self, max: usize) -> Self {
         self.max_positions = max;
@@ -280,6 +367,17 @@ impl BacktestConfig {
         self
     }
 
+    /// 마진 거래 설정 (초기/유지 증거금률)
+    ///
+    /// 예: `with_margin(dec!(0.5), dec!(0.25))` = 최대 2배 레버리지,
+    /// 평가 자산이 총 노출의 25% 미만이 되면 마진콜.
+    pub fn with_margin(mut self, initial_rate: Decimal, maintenance_rate: Decimal) -> Self {
+        self.allow_margin = true;
+        self.initial_margin_rate = initial_rate;
+        self.maintenance_margin_rate = maintenance_rate;
+        self
+    }
+
     /// 자동 손절 설정
     pub fn with_stop_loss(mut self, enabled: bool, pct: Decimal) -> Self {
         self.auto_stop_loss = enabled;
@@ -300,6 +398,33 @@ impl BacktestConfig {
         self
     }
 
+    /// 벤치마크 심볼 설정
+    ///
+    /// 벤치마크 캔들은 주 티커이거나 StrategyContext에 일봉으로 등록되어 있어야 합니다.
+    /// 그 외에는 [`BacktestReport::compare_with_benchmark`]로 직접 계산합니다.
+    pub fn with_benchmark_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.benchmark_symbol = Some(symbol.into());
+        self
+    }
+
+    /// 신호 체결 시점 설정
+    ///
+    /// [`FillTiming::NextOpen`]/[`FillTiming::NextClose`]는 신호를 다음 캔들에서 체결하며,
+    /// 데이터 마지막 캔들에서 발생한 신호는 미체결로 리포트에 남습니다.
+    pub fn with_fill_timing(mut self, timing: FillTiming) -> Self {
+        self.fill_timing = timing;
+        self
+    }
+
+    /// 성과 목표 설정
+    ///
+    /// 벤치마크 캔들 확보 규칙은 [`Self::with_benchmark_symbol`]과 같으며,
+    /// 확보하지 못하면 절대수익 기준으로 평가합니다.
+    pub fn with_performance_target(mut self, target: PerformanceTargetConfig) -> Self {
+        self.performance_target = Some(target);
+        self
+    }
+
     /// 설정 검증
     pub fn validate(&self) -> BacktestResult<()> {
         if self.initial_capital <= Decimal::ZERO {
@@ -317,6 +442,21 @@ impl BacktestConfig {
                 "슬리피지율은 0 이상이어야 합니다".to_string(),
             ));
         }
+        if self.allow_margin {
+            if self.initial_margin_rate <= Decimal::ZERO || self.initial_margin_rate > Decimal::ONE
+            {
+                return Err(BacktestError::ConfigError(
+                    "초기 증거금률은 0보다 크고 1 이하여야 합니다".to_string(),
+                ));
+            }
+            if self.maintenance_margin_rate <= Decimal::ZERO
+                || self.maintenance_margin_rate > self.initial_margin_rate
+            {
+                return Err(BacktestError::ConfigError(
+                    "유지 증거금률은 0보다 크고 초기 증거금률 이하여야 합니다".to_string(),
+                ));
+            }
+        }
         Ok(())
     }
 }
@@ -371,14 +511,101 @@ pub struct BacktestReport {
     /// 모든 거래 기록 (매수/매도 포함) - 매매일지용
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub all_trades: Vec<TradeResult>,
+
+    /// 성과 목표 평가 결과 (전략에 `performance_target`이 설정된 경우)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_evaluation: Option<TargetEvaluation>,
+
+    /// 벤치마크(Buy & Hold) 대비 성과 (`benchmark_symbol`이 설정된 경우)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmark: Option<BenchmarkComparison>,
+
+    /// 미체결 신호 (지연 체결 시 데이터 끝에 걸려 체결할 캔들이 없었던 신호)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unfilled_signals: Vec<SignalMarker>,
+
+    /// 증거금 시뮬레이션 결과 (`allow_margin`이 설정된 경우)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin: Option<MarginReport>,
+
+    /// 거래별 MAE/MFE 및 보유 기간 분포 (완료된 거래가 있는 경우)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excursion: Option<ExcursionAnalysis>,
 }
 
 impl BacktestReport {
+    /// 성과 목표 달성 여부를 평가하여 리포트에 기록합니다.
+    ///
+    /// 벤치마크 수익률은 백테스트 기간 내 벤치마크 캔들의 보유(Buy & Hold) 수익률로 계산합니다.
+    /// 벤치마크 캔들이 부족하면 절대수익 기준으로 폴백합니다.
+    pub fn evaluate_target(
+        &mut self,
+        target: &PerformanceTargetConfig,
+        benchmark_klines: &[Kline],
+    ) -> &TargetEvaluation {
+        let benchmark_return_pct = self.benchmark_return_pct(benchmark_klines);
+        self.target_evaluation
+            .insert(target.evaluate(self.metrics.total_return_pct, benchmark_return_pct))
+    }
+
+    /// 벤치마크 캔들로 Buy & Hold 대비 성과를 계산하여 리포트에 기록합니다.
+    ///
+    /// 벤치마크 데이터가 백테스트 기간보다 짧으면 공통 구간으로 비교합니다.
+    /// 공통 구간이 없으면 기존 비교 결과를 지우고 None을 반환합니다.
+    pub fn compare_with_benchmark(
+        &mut self,
+        symbol: &str,
+        benchmark_klines: &[Kline],
+    ) -> Option<&BenchmarkComparison> {
+        self.benchmark = BenchmarkComparison::calculate(
+            symbol,
+            benchmark_klines,
+            &self.equity_curve,
+            self.config.risk_free_rate,
+        );
+        if self.benchmark.is_none() {
+            tracing::warn!(
+                benchmark = symbol,
+                "백테스트 기간과 겹치는 벤치마크 데이터가 없습니다"
+            );
+        }
+        self.benchmark.as_ref()
+    }
+
+    /// 총 거래대금 (매수 + 매도 체결 금액 합계).
+    pub fn traded_value(&self) -> Decimal {
+        self.all_trades.iter().map(|t| t.price * t.quantity).sum()
+    }
+
+    /// 회전율 (총 거래대금 / 초기 자본).
+    ///
+    /// 같은 전략의 설정별 매매 빈도를 비교할 때 사용합니다.
+    pub fn turnover(&self) -> Decimal {
+        if self.config.initial_capital <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        self.traded_value() / self.config.initial_capital
+    }
+
+    /// 백테스트 기간 내 벤치마크 보유 수익률 (%).
+    fn benchmark_return_pct(&self, benchmark_klines: &[Kline]) -> Option<Decimal> {
+        let mut in_period = benchmark_klines
+            .iter()
+            .filter(|k| k.open_time >= self.start_time && k.open_time <= self.end_time);
+        let first = in_period.next()?;
+        let last = in_period.next_back()?;
+
+        if first.close.is_zero() {
+            return None;
+        }
+        Some((last.close - first.close) / first.close * Decimal::from(100))
+    }
+
     /// 요약 문자열 반환
     pub fn summary(&self) -> String {
         let duration_days = (self.end_time - self.start_time).num_days();
 
-        format!(
+        let summary = format!(
             "백테스트 결과 요약\n\
              ═══════════════════════════════════════\n\
              기간: {} → {} ({} 일)\n\
@@ -398,6 +625,7 @@ impl BacktestReport {
              소르티노 비율: {:.2}\n\
              최대 낙폭: {:.2}%\n\
              칼마 비율: {:.2}\n\
+             얼서 지수: {:.2}\n\
              ───────────────────────────────────────\n\
              총 수수료: {:.2}\n\
              총 슬리피지: {:.2}\n\
@@ -418,9 +646,41 @@ impl BacktestReport {
             self.metrics.sortino_ratio,
             self.metrics.max_drawdown_pct,
             self.metrics.calmar_ratio,
+            self.metrics.ulcer_index,
             self.total_commission,
             self.total_slippage,
-        )
+        );
+
+        let summary = if self.unfilled_signals.is_empty() {
+            summary
+        } else {
+            format!(
+                "{}\n미체결 신호: {} 건 (체결 시점: {:?})",
+                summary,
+                self.unfilled_signals.len(),
+                self.config.fill_timing
+            )
+        };
+
+        let summary = match &self.excursion {
+            Some(excursion) => format!("{}\n{}", summary, excursion.summary()),
+            None => summary,
+        };
+
+        let summary = match &self.margin {
+            Some(margin) => format!("{}\n{}", summary, margin.summary()),
+            None => summary,
+        };
+
+        let summary = match &self.benchmark {
+            Some(benchmark) => format!("{}\n{}", summary, benchmark.summary()),
+            None => summary,
+        };
+
+        match &self.target_evaluation {
+            Some(evaluation) => format!("{}\n성과 목표: {}", summary, evaluation.summary()),
+            None => summary,
+        }
     }
 }
 
@@ -460,6 +720,15 @@ pub struct BacktestEngine {
 
     /// 총 슬리피지 (executor와 별도 추적 - 기존 호환성)
     total_slippage: Decimal,
+
+    /// 지연 체결 대기 신호 (신호, 발생 캔들 시각, 발생 시점 가격)
+    pending_signals: Vec<(Signal, DateTime<Utc>, Decimal)>,
+
+    /// 진행률 공유/취소 핸들 (비동기 작업 큐용)
+    progress: Option<BacktestProgressHandle>,
+
+    /// 증거금 시뮬레이션 상태 (마진 거래 허용 시)
+    margin: Option<MarginReport>,
 }
 
 impl BacktestEngine {
@@ -482,8 +751,20 @@ impl BacktestEngine {
             auto_take_profit: config.auto_take_profit,
             stop_loss_pct: config.stop_loss_pct,
             take_profit_pct: config.take_profit_pct,
+            trailing_stop_pct: None,
+            sizing_method: config.sizing_method.clone(),
+            conflict_policy: None,
         };
-        let executor = SimulatedExecutor::new(executor_config, config.initial_capital);
+        let mut executor = SimulatedExecutor::new(executor_config, config.initial_capital);
+        if let Some(model) = config.slippage_model.clone() {
+            executor = executor.with_slippage_model(model);
+        }
+        if config.allow_margin {
+            executor = executor.with_margin(config.initial_margin_rate);
+        }
+        let margin = config
+            .allow_margin
+            .then(|| MarginReport::new(config.initial_margin_rate, config.maintenance_margin_rate));
 
         Self {
             config,
@@ -493,9 +774,21 @@ impl BacktestEngine {
             current_prices: HashMap::new(),
             signal_markers: Vec::new(),
             total_slippage: Decimal::ZERO,
+            pending_signals: Vec::new(),
+            progress: None,
+            margin,
         }
     }
 
+    /// 진행률 핸들 연결.
+    ///
+    /// 캔들마다 진행 상황을 기록하고, 핸들이 취소되면 다음 캔들 처리 전에
+    /// [`BacktestError::Cancelled`]로 중단합니다.
+    pub fn with_progress(mut self, progress: BacktestProgressHandle) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
     // === 위임 메서드 (기존 API 호환성 유지) ===
 
     /// 현재 잔고 조회 (executor에서 위임)
@@ -570,6 +863,33 @@ impl BacktestEngine {
         let end_time = klines.last().unwrap().close_time;
         let data_points = klines.len();
 
+        // 벤치마크 캔들 확보 (루프 중 컨텍스트 klines가 현재 시점까지로 잘리므로 미리 복사)
+        let benchmark_klines = match self.config.benchmark_symbol.as_deref() {
+            Some(symbol) if symbol == ticker => Some(klines.to_vec()),
+            Some(symbol) => Some(
+                context
+                    .read()
+                    .await
+                    .get_klines(symbol, Timeframe::D1)
+                    .to_vec(),
+            ),
+            None => None,
+        };
+        let target_klines = match self
+            .config
+            .performance_target
+            .as_ref()
+            .and_then(|t| t.benchmark.as_deref())
+        {
+            Some(symbol) if symbol == ticker => klines.to_vec(),
+            Some(symbol) => context
+                .read()
+                .await
+                .get_klines(symbol, Timeframe::D1)
+                .to_vec(),
+            None => Vec::new(),
+        };
+
         // 백테스트 시작 시간으로 equity curve 초기 timestamp 설정
         self.tracker.set_initial_timestamp(start_time);
 
@@ -579,6 +899,8 @@ impl BacktestEngine {
 
         // 각 캔들에 대해 시뮬레이션
         for (idx, kline) in klines.iter().enumerate() {
+            self.check_cancelled()?;
+
             // 1. StrategyContext 업데이트 (공통: 지표, klines, 스크리닝)
             let historical_klines = &klines[..=idx];
             candle_processor
@@ -596,6 +918,14 @@ impl BacktestEngine {
             self.current_time = candle_processor.current_time();
             self.current_prices
                 .clone_from(candle_processor.current_prices());
+            // 슬리피지 모델의 평균 거래량(ADV) 추정용 캔들 이력 갱신
+            self.executor.update_kline(kline.clone());
+
+            // 직전 캔들에서 대기 중인 신호 체결 (지연 체결 모드)
+            self.fill_pending_signals(kline).await?;
+
+            // 보유 포지션 가격 경로 갱신 (MAE/MFE, 이번 캔들 신호 진입 전)
+            self.observe_excursions(kline);
 
             // 2. 시그널 생성 (공통: 멀티 심볼/멀티 TF + Entry/Exit 파티셔닝)
             let signals = candle_processor
@@ -603,13 +933,13 @@ impl BacktestEngine {
                 .await?;
 
             // 3. 시그널 처리 (BacktestEngine 고유: PerformanceTracker/SignalMarker 기록)
-            for signal in &signals.entry_signals {
-                self.process_signal(signal, kline).await?;
-            }
-            for signal in &signals.exit_signals {
-                self.process_signal(signal, kline).await?;
+            for signal in signals.entry_signals.iter().chain(&signals.exit_signals) {
+                self.submit_signal(signal, kline).await?;
             }
 
+            // 유지 증거금 검사 (마진 거래 시 미달이면 강제청산)
+            self.check_margin(kline).await?;
+
             // 4. 포지션 동기화 (공통: 전략에 현재 포지션 상태 알림)
             candle_processor
                 .sync_positions(
@@ -624,8 +954,13 @@ impl BacktestEngine {
             // 5. 미실현 손익 반영하여 자산 업데이트 (BacktestEngine 고유)
             let equity = self.calculate_equity(kline);
             self.tracker.update_equity(kline.close_time, equity);
+            self.observe_leverage(kline, equity);
+            self.report_progress(idx + 1, data_points, kline, equity);
         }
 
+        // 체결할 다음 캔들이 없는 신호는 미체결로 남김
+        let unfilled_signals = self.take_unfilled_signals();
+
         // 미청산 포지션 강제 청산
         let last_kline = klines.last().unwrap();
         self.close_all_positions(last_kline).await?;
@@ -639,12 +974,9 @@ impl BacktestEngine {
         let performance_by_symbol = self.calculate_performance_by_symbol();
 
         // 결과 생성
-        // PerformanceMetrics는 완료된 거래(RoundTrip) 기준으로 계산
-        // MDD는 equity curve 기반으로 교체
-        let mut metrics = self.tracker.get_metrics();
-        metrics.max_drawdown_pct = self.tracker.max_drawdown_pct();
+        let metrics = self.calculate_metrics();
 
-        Ok(BacktestReport {
+        let mut report = BacktestReport {
             config: self.config.clone(),
             metrics,
             trades: self.tracker.get_round_trips().to_vec(),
@@ -660,7 +992,115 @@ impl BacktestEngine {
             klines: klines.to_vec(),
             symbol: ticker.to_string(),
             all_trades: self.executor.trades().to_vec(),
-        })
+            target_evaluation: None,
+            benchmark: None,
+            unfilled_signals,
+            margin: self.margin.clone(),
+            excursion: None,
+        };
+        report.excursion = (!report.trades.is_empty())
+            .then(|| ExcursionAnalysis::from_round_trips(&report.trades));
+
+        // 벤치마크 대비 성과
+        if let (Some(symbol), Some(benchmark_klines)) =
+            (self.config.benchmark_symbol.as_deref(), benchmark_klines)
+        {
+            report.compare_with_benchmark(symbol, &benchmark_klines);
+        }
+
+        // 성과 목표 평가
+        if let Some(target) = &self.config.performance_target {
+            report.evaluate_target(target, &target_klines);
+        }
+
+        Ok(report)
+    }
+
+    /// 취소 요청이 있으면 [`BacktestError::Cancelled`]를 반환합니다.
+    fn check_cancelled(&self) -> BacktestResult<()> {
+        match &self.progress {
+            Some(progress) if progress.is_cancelled() => Err(BacktestError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+
+    /// 캔들 처리 후 진행 상황을 핸들에 기록합니다.
+    fn report_progress(&self, processed: usize, total: usize, kline: &Kline, equity: Decimal) {
+        if let Some(progress) = &self.progress {
+            progress.update(BacktestProgress {
+                processed_candles: processed,
+                total_candles: total,
+                current_time: Some(kline.close_time),
+                equity,
+                total_trades: self.tracker.get_round_trips().len(),
+            });
+        }
+    }
+
+    /// 전략이 생성한 신호를 체결 시점 설정에 따라 처리합니다.
+    ///
+    /// 지연 체결 모드에서는 다음 캔들까지 대기열에 보관합니다.
+    /// Alert는 체결 대상이 아니므로 항상 즉시 기록합니다.
+    async fn submit_signal(&mut self, signal: &Signal, kline: &Kline) -> BacktestResult<()> {
+        if self.config.fill_timing.is_delayed() && signal.signal_type != SignalType::Alert {
+            let signal_price = self.get_price_for_signal(signal, kline);
+            self.pending_signals
+                .push((signal.clone(), kline.open_time, signal_price));
+            return Ok(());
+        }
+        self.process_signal(signal, kline).await
+    }
+
+    /// 대기 중인 신호를 현재 캔들의 시가/종가로 체결합니다.
+    async fn fill_pending_signals(&mut self, kline: &Kline) -> BacktestResult<()> {
+        let pending = std::mem::take(&mut self.pending_signals);
+        for (signal, _, _) in pending {
+            let price = self.get_delayed_fill_price(&signal, kline);
+            self.process_signal_at(&signal, kline, price).await?;
+        }
+        Ok(())
+    }
+
+    /// 체결할 캔들이 남지 않은 대기 신호를 미체결 마커로 변환합니다.
+    fn take_unfilled_signals(&mut self) -> Vec<SignalMarker> {
+        let pending = std::mem::take(&mut self.pending_signals);
+        if !pending.is_empty() {
+            tracing::info!(
+                "백테스트 종료: {} 개 신호 미체결 (체결 시점: {:?})",
+                pending.len(),
+                self.config.fill_timing
+            );
+        }
+        pending
+            .into_iter()
+            .map(|(signal, signal_time, signal_price)| {
+                SignalMarker::from_signal(&signal, signal_price, signal_time, &signal.strategy_id)
+                    .with_executed(false)
+            })
+            .collect()
+    }
+
+    /// 지연 체결 가격 조회
+    ///
+    /// 신호 심볼이 현재 캔들 심볼이면 설정에 따라 시가/종가를 사용합니다.
+    /// 다중 자산 전략의 다른 심볼은 시가 정보가 없으므로 현재가(종가)로 체결합니다.
+    /// 신호 캔들 기준의 suggested_price는 사용하지 않습니다.
+    fn get_delayed_fill_price(&self, signal: &Signal, kline: &Kline) -> Decimal {
+        let base_ticker = signal.ticker.split('/').next().unwrap_or(&signal.ticker);
+        let kline_base = kline.ticker.split('/').next().unwrap_or(&kline.ticker);
+
+        if base_ticker == kline_base {
+            return match self.config.fill_timing {
+                FillTiming::NextOpen => kline.open,
+                FillTiming::CurrentClose | FillTiming::NextClose => kline.close,
+            };
+        }
+
+        self.current_prices
+            .get(&signal.ticker)
+            .or_else(|| self.current_prices.get(base_ticker))
+            .copied()
+            .unwrap_or(kline.close)
     }
 
     /// 신호를 처리합니다.
@@ -669,7 +1109,16 @@ impl BacktestEngine {
     async fn process_signal(&mut self, signal: &Signal, kline: &Kline) -> BacktestResult<()> {
         // 실행 가격 결정
         let current_price = self.get_price_for_signal(signal, kline);
+        self.process_signal_at(signal, kline, current_price).await
+    }
 
+    /// 지정한 가격으로 신호를 처리합니다.
+    async fn process_signal_at(
+        &mut self,
+        signal: &Signal,
+        kline: &Kline,
+        current_price: Decimal,
+    ) -> BacktestResult<()> {
         // Alert는 실행하지 않음 - marker만 저장
         if signal.signal_type == SignalType::Alert {
             let marker = SignalMarker::from_signal(
@@ -682,7 +1131,8 @@ impl BacktestEngine {
             return Ok(());
         }
 
-        // SimulatedExecutor에 Signal 처리 위임
+        // SimulatedExecutor에 Signal 처리 위임 (슬리피지 모델용 캔들 전달)
+        self.executor.update_kline(kline.clone());
         let result = self
             .executor
             .process_signal(signal, current_price, kline.close_time)
@@ -837,37 +1287,170 @@ impl BacktestEngine {
     ///
     /// executor에서 잔고와 포지션 정보를 가져와 총 자산을 계산합니다.
     fn calculate_equity(&self, kline: &Kline) -> Decimal {
-        let mut equity = self.executor.balance();
+        self.executor.balance()
+            + self
+                .executor
+                .positions()
+                .values()
+                .map(|position| position_value(position, self.mark_price(&position.symbol, kline)))
+                .sum::<Decimal>()
+    }
 
-        for (symbol, position) in self.executor.positions().iter() {
-            // 티커 형식 정규화: "TLT/USD" → "TLT"
-            let base_ticker = symbol.split('/').next().unwrap_or(symbol);
-            let current_price = self
-                .current_prices
-                .get(symbol)
-                .or_else(|| self.current_prices.get(base_ticker))
-                .copied()
-                .unwrap_or(kline.close);
+    /// 포지션 평가 가격 (현재가, 없으면 kline 종가).
+    fn mark_price(&self, symbol: &str, kline: &Kline) -> Decimal {
+        // 티커 형식 정규화: "TLT/USD" → "TLT"
+        let base_ticker = symbol.split('/').next().unwrap_or(symbol);
+        self.current_prices
+            .get(symbol)
+            .or_else(|| self.current_prices.get(base_ticker))
+            .copied()
+            .unwrap_or(kline.close)
+    }
 
-            let position_value = match position.side {
-                Side::Buy => current_price * position.quantity,
-                Side::Sell => {
-                    // 숏 포지션: 원금 + 미실현 손익
-                    let entry_value = position.entry_price * position.quantity;
-                    let pnl = unrealized_pnl(
-                        position.entry_price,
-                        current_price,
-                        position.quantity,
-                        position.side,
-                    );
-                    entry_value + pnl
-                }
+    /// 캔들 내 불리한 가격 (롱은 저가, 숏은 고가).
+    ///
+    /// 현재 캔들 심볼이 아닌 포지션은 장중 가격을 알 수 없으므로 현재가를 사용합니다.
+    fn adverse_price(&self, symbol: &str, side: Side, kline: &Kline) -> Decimal {
+        let base_ticker = symbol.split('/').next().unwrap_or(symbol);
+        let kline_base = kline.ticker.split('/').next().unwrap_or(&kline.ticker);
+        if base_ticker != kline_base {
+            return self.mark_price(symbol, kline);
+        }
+        match side {
+            Side::Buy => kline.low,
+            Side::Sell => kline.high,
+        }
+    }
+
+    /// 유지 증거금을 검사하고 미달이면 강제청산합니다.
+    ///
+    /// 캔들 내 불리한 가격으로 평가하여 평가 자산이 유지 증거금에 못 미치면
+    /// 손실이 큰 포지션부터 불리한 가격으로 청산하고, 평가 자산이
+    /// 초기 증거금 이상으로 회복되면 멈춥니다.
+    async fn check_margin(&mut self, kline: &Kline) -> BacktestResult<()> {
+        let Some((initial_rate, maintenance_rate)) = self
+            .margin
+            .as_ref()
+            .map(|m| (m.initial_margin_rate, m.maintenance_margin_rate))
+        else {
+            return Ok(());
+        };
+
+        let mut event: Option<MarginCallEvent> = None;
+        loop {
+            let positions: Vec<_> = self
+                .executor
+                .positions()
+                .values()
+                .map(|pos| {
+                    let price = self.adverse_price(&pos.symbol, pos.side, kline);
+                    (pos.clone(), price)
+                })
+                .collect();
+
+            let equity = self.executor.balance()
+                + positions
+                    .iter()
+                    .map(|(pos, price)| position_value(pos, *price))
+                    .sum::<Decimal>();
+            let gross_exposure: Decimal = positions
+                .iter()
+                .map(|(pos, price)| *price * pos.quantity)
+                .sum();
+
+            // 최초 판정은 유지 증거금, 청산 진행 중에는 초기 증거금까지 회복
+            let threshold = if event.is_some() {
+                initial_rate
+            } else {
+                maintenance_rate
+            };
+            if !is_margin_call(equity, gross_exposure, threshold) {
+                break;
+            }
+
+            // 손실이 가장 큰 포지션부터 청산
+            let Some((position, price)) = positions.into_iter().min_by_key(|(pos, price)| {
+                unrealized_pnl(pos.entry_price, *price, pos.quantity, pos.side)
+            }) else {
+                break;
             };
+            let event = event.get_or_insert_with(|| {
+                tracing::warn!(
+                    time = %kline.close_time,
+                    equity = %equity,
+                    gross_exposure = %gross_exposure,
+                    "마진콜 발생: 유지 증거금 미달"
+                );
+                MarginCallEvent::new(kline.close_time, equity, gross_exposure)
+            });
 
-            equity += position_value;
+            let exit_side = match position.side {
+                Side::Buy => Side::Sell,
+                Side::Sell => Side::Buy,
+            };
+            let mut signal = Signal::exit("margin_call", position.symbol.clone(), exit_side);
+            if let Some(pid) = position.position_id.clone() {
+                signal = signal.with_position_id(pid);
+            }
+            signal.metadata.insert(
+                "reason".to_string(),
+                serde_json::Value::String("마진콜 강제청산".to_string()),
+            );
+
+            let orders_before = self.executor.trades().len();
+            self.process_signal_at(&signal, kline, price).await?;
+            let Some(trade) = self.executor.trades()[orders_before..].last() else {
+                tracing::warn!(symbol = %position.symbol, "강제청산 실패: 체결되지 않음");
+                break;
+            };
+            event.liquidations.push(LiquidationRecord {
+                symbol: position.symbol,
+                side: position.side,
+                quantity: trade.quantity,
+                price: trade.price,
+                realized_pnl: trade.realized_pnl.unwrap_or(Decimal::ZERO),
+            });
+        }
+
+        if let (Some(event), Some(margin)) = (event, self.margin.as_mut()) {
+            margin.record_margin_call(event);
         }
+        Ok(())
+    }
+
+    /// 보유 포지션의 캔들 고가/저가를 성과 추적기에 반영합니다 (MAE/MFE 계산용).
+    ///
+    /// 현재 캔들 심볼은 고가/저가를, 다중 자산 전략의 다른 심볼은 장중 가격을
+    /// 알 수 없으므로 현재가(종가)를 사용합니다.
+    fn observe_excursions(&mut self, kline: &Kline) {
+        let kline_base = kline.ticker.split('/').next().unwrap_or(&kline.ticker);
+        let current_prices = &self.current_prices;
+        self.tracker.observe_bar(|symbol| {
+            let base_ticker = symbol.split('/').next().unwrap_or(symbol);
+            if base_ticker == kline_base {
+                return Some((kline.high, kline.low));
+            }
+            current_prices
+                .get(symbol)
+                .or_else(|| current_prices.get(base_ticker))
+                .map(|price| (*price, *price))
+        });
+    }
 
-        equity
+    /// 캔들 종가 기준 레버리지를 관측합니다 (마진 거래 시).
+    fn observe_leverage(&mut self, kline: &Kline, equity: Decimal) {
+        if self.margin.is_none() {
+            return;
+        }
+        let gross_exposure: Decimal = self
+            .executor
+            .positions()
+            .values()
+            .map(|pos| self.mark_price(&pos.symbol, kline) * pos.quantity)
+            .sum();
+        if let Some(margin) = self.margin.as_mut() {
+            margin.observe_leverage(kline.close_time, equity, gross_exposure);
+        }
     }
 
     /// Trade 객체를 생성합니다.
@@ -909,6 +1492,23 @@ impl BacktestEngine {
         .with_metadata(metadata)
     }
 
+    /// 전체 성과 지표를 계산합니다.
+    ///
+    /// PerformanceMetrics는 완료된 거래(RoundTrip) 기준으로 계산하고,
+    /// 낙폭 기반 지표(MDD/칼마/얼서)는 캔들별 equity curve 기준으로 교체합니다.
+    fn calculate_metrics(&self) -> PerformanceMetrics {
+        let equity_values: Vec<Decimal> = self
+            .tracker
+            .get_equity_curve()
+            .iter()
+            .map(|p| p.equity)
+            .collect();
+
+        let mut metrics = self.tracker.get_metrics();
+        metrics.apply_equity_curve(&equity_values);
+        metrics
+    }
+
     /// 심볼별 성과를 계산합니다.
     fn calculate_performance_by_symbol(&self) -> HashMap<String, PerformanceMetrics> {
         let mut by_symbol: HashMap<String, Vec<RoundTrip>> = HashMap::new();
@@ -1006,11 +1606,20 @@ impl BacktestEngine {
         let is_multi_tf_strategy = strategy.multi_timeframe_config().is_some();
 
         // 각 Primary 캔들에 대해 시뮬레이션
-        for kline in primary_klines {
+        for (idx, kline) in primary_klines.iter().enumerate() {
+            self.check_cancelled()?;
+
             // 캔들 완성 시점으로 현재 시간 설정 (데이터 누수 방지)
             self.current_time = kline.close_time;
             self.current_prices
                 .insert(kline.ticker.to_string(), kline.close);
+            self.executor.update_kline(kline.clone());
+
+            // 직전 캔들에서 대기 중인 신호 체결 (지연 체결 모드)
+            self.fill_pending_signals(kline).await?;
+
+            // 보유 포지션 가격 경로 갱신 (MAE/MFE, 이번 캔들 신호 진입 전)
+            self.observe_excursions(kline);
 
             // 시장 데이터 생성
             let market_data = MarketData::from_kline(&self.config.exchange_name, kline.clone());
@@ -1034,15 +1643,23 @@ impl BacktestEngine {
             };
 
             // 신호 처리
-            for signal in signals {
-                self.process_signal(&signal, kline).await?;
+            for signal in &signals {
+                self.submit_signal(signal, kline).await?;
             }
 
+            // 유지 증거금 검사 (마진 거래 시 미달이면 강제청산)
+            self.check_margin(kline).await?;
+
             // 미실현 손익 반영하여 자산 업데이트
             let equity = self.calculate_equity(kline);
             self.tracker.update_equity(kline.close_time, equity);
+            self.observe_leverage(kline, equity);
+            self.report_progress(idx + 1, data_points, kline, equity);
         }
 
+        // 체결할 다음 캔들이 없는 신호는 미체결로 남김
+        let unfilled_signals = self.take_unfilled_signals();
+
         // 미청산 포지션 강제 청산
         let last_kline = primary_klines.last().unwrap();
         self.close_all_positions(last_kline).await?;
@@ -1056,12 +1673,9 @@ impl BacktestEngine {
         let performance_by_symbol = self.calculate_performance_by_symbol();
 
         // 결과 생성
-        // PerformanceMetrics는 완료된 거래(RoundTrip) 기준으로 계산
-        // MDD는 equity curve 기반으로 교체
-        let mut metrics = self.tracker.get_metrics();
-        metrics.max_drawdown_pct = self.tracker.max_drawdown_pct();
+        let metrics = self.calculate_metrics();
 
-        Ok(BacktestReport {
+        let mut report = BacktestReport {
             config: self.config.clone(),
             metrics,
             trades: self.tracker.get_round_trips().to_vec(),
@@ -1080,7 +1694,50 @@ impl BacktestEngine {
                 .map(|k| k.ticker.to_string())
                 .unwrap_or_default(),
             all_trades: self.executor.trades().to_vec(),
-        })
+            target_evaluation: None,
+            benchmark: None,
+            unfilled_signals,
+            margin: self.margin.clone(),
+            excursion: None,
+        };
+        report.excursion = (!report.trades.is_empty())
+            .then(|| ExcursionAnalysis::from_round_trips(&report.trades));
+
+        // 벤치마크 대비 성과 (주 티커가 벤치마크인 경우만, 그 외는 compare_with_benchmark 사용)
+        if let Some(symbol) = self.config.benchmark_symbol.clone() {
+            if symbol == report.symbol {
+                report.compare_with_benchmark(&symbol, primary_klines);
+            }
+        }
+
+        // 성과 목표 평가 (벤치마크가 주 티커가 아니면 절대수익 기준)
+        if let Some(target) = self.config.performance_target.clone() {
+            let target_klines = if target.benchmark.as_deref() == Some(report.symbol.as_str()) {
+                primary_klines
+            } else {
+                &[]
+            };
+            report.evaluate_target(&target, target_klines);
+        }
+
+        Ok(report)
+    }
+}
+
+/// 포지션 평가액 (롱: 가격 × 수량, 숏: 진입 원금 + 미실현 손익).
+fn position_value(position: &ProcessorPosition, price: Decimal) -> Decimal {
+    match position.side {
+        Side::Buy => price * position.quantity,
+        Side::Sell => {
+            let entry_value = position.entry_price * position.quantity;
+            entry_value
+                + unrealized_pnl(
+                    position.entry_price,
+                    price,
+                    position.quantity,
+                    position.side,
+                )
+        }
     }
 }
 
@@ -1284,6 +1941,92 @@ pub mod test_strategies {
             serde_json::json!({ "bought": self.bought })
         }
     }
+
+    /// 지정한 캔들 순번에서 진입/청산 신호를 내는 테스트 전략
+    pub struct ScheduledStrategy {
+        bar: usize,
+        entry_bar: usize,
+        exit_bar: Option<usize>,
+    }
+
+    impl ScheduledStrategy {
+        pub fn new(entry_bar: usize, exit_bar: Option<usize>) -> Self {
+            Self {
+                bar: 0,
+                entry_bar,
+                exit_bar,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl trader_strategy::Strategy for ScheduledStrategy {
+        fn name(&self) -> &str {
+            "Scheduled"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "지정한 캔들에서 진입/청산하는 테스트 전략"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.bar = 0;
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            let bar = self.bar;
+            self.bar += 1;
+
+            if bar == self.entry_bar {
+                Ok(vec![Signal::entry(
+                    "Scheduled",
+                    data.ticker.clone(),
+                    Side::Buy,
+                )])
+            } else if Some(bar) == self.exit_bar {
+                Ok(vec![Signal::exit(
+                    "Scheduled",
+                    data.ticker.clone(),
+                    Side::Sell,
+                )])
+            } else {
+                Ok(vec![])
+            }
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({ "bar": self.bar })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1383,6 +2126,55 @@ mod tests {
         assert!(report.total_commission > Decimal::ZERO);
     }
 
+    #[tokio::test]
+    async fn test_backtest_reports_progress() {
+        let progress = BacktestProgressHandle::new();
+        let mut engine =
+            BacktestEngine::new(BacktestConfig::new(dec!(100000))).with_progress(progress.clone());
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let klines = create_test_klines(10, dec!(50000), dec!(100));
+
+        engine
+            .run(
+                &mut strategy,
+                &klines,
+                create_test_context(),
+                "BTC/USDT",
+                None,
+            )
+            .await
+            .unwrap();
+
+        let snapshot = progress.snapshot();
+        assert_eq!(snapshot.processed_candles, 10);
+        assert_eq!(snapshot.total_candles, 10);
+        assert_eq!(snapshot.percent(), 100.0);
+        assert_eq!(snapshot.current_time, Some(klines[9].close_time));
+        assert!(snapshot.equity > Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_backtest_cancelled() {
+        let progress = BacktestProgressHandle::new();
+        progress.cancel();
+        let mut engine =
+            BacktestEngine::new(BacktestConfig::new(dec!(100000))).with_progress(progress.clone());
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let klines = create_test_klines(10, dec!(50000), dec!(100));
+
+        let result = engine
+            .run(
+                &mut strategy,
+                &klines,
+                create_test_context(),
+                "BTC/USDT",
+                None,
+            )
+            .await;
+        assert!(matches!(result, Err(BacktestError::Cancelled)));
+        assert_eq!(progress.snapshot().processed_candles, 0);
+    }
+
     #[tokio::test]
     async fn test_backtest_sma_strategy() {
         let config = BacktestConfig::new(dec!(1000000))
@@ -1431,6 +2223,104 @@ mod tests {
         // 리포트 확인
         assert!(!result.equity_curve.is_empty());
         assert!(!result.summary().is_empty());
+
+        // 낙폭 기반 지표는 equity curve 기준 MDD와 일관되어야 함
+        let metrics = &result.metrics;
+        assert_eq!(metrics.max_drawdown_pct, engine.tracker.max_drawdown_pct());
+        assert_eq!(
+            metrics.calmar_ratio,
+            PerformanceMetrics::calculate_calmar_ratio(
+                metrics.annualized_return_pct,
+                metrics.max_drawdown_pct
+            )
+        );
+        assert!(metrics.ulcer_index <= metrics.max_drawdown_pct);
+    }
+
+    #[tokio::test]
+    async fn test_backtest_report_target_evaluation() {
+        use trader_strategy::strategies::common::{EvaluationBasis, PerformanceGoal};
+
+        let config = BacktestConfig::new(dec!(100000));
+        let mut engine = BacktestEngine::new(config);
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let context = create_test_context();
+
+        let klines = create_test_klines(20, dec!(50000), dec!(50));
+
+        let mut report = engine
+            .run(&mut strategy, &klines, context, "BTC/USDT", None)
+            .await
+            .unwrap();
+
+        let target = PerformanceTargetConfig {
+            benchmark: Some("BTC/USDT".to_string()),
+            goal: PerformanceGoal::BenchmarkRelative {
+                excess_return_pct: dec!(1),
+            },
+            underperformance_alert_periods: 3,
+        };
+
+        // 벤치마크 데이터가 있으면 벤치마크 대비 평가
+        let evaluation = report.evaluate_target(&target, &klines);
+        assert_eq!(evaluation.basis, EvaluationBasis::Benchmark);
+        assert!(evaluation.benchmark_return_pct.unwrap() > Decimal::ZERO);
+        assert!(report.summary().contains("성과 목표"));
+
+        // 벤치마크 데이터 결측 시 절대수익 기준 폴백
+        let evaluation = report.evaluate_target(&target, &[]);
+        assert_eq!(evaluation.basis, EvaluationBasis::AbsoluteFallback);
+    }
+
+    #[tokio::test]
+    async fn test_backtest_report_benchmark_comparison() {
+        let config = BacktestConfig::new(dec!(100000)).with_benchmark_symbol("BTC/USDT");
+        let mut engine = BacktestEngine::new(config);
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let context = create_test_context();
+
+        let klines = create_test_klines(20, dec!(50000), dec!(50));
+
+        let report = engine
+            .run(&mut strategy, &klines, context, "BTC/USDT", None)
+            .await
+            .unwrap();
+
+        // 주 티커를 벤치마크로 지정하면 엔진이 Buy & Hold 비교를 계산
+        let benchmark = report.benchmark.as_ref().unwrap();
+        assert_eq!(benchmark.symbol, "BTC/USDT");
+        assert!(benchmark.benchmark_return_pct > Decimal::ZERO);
+        assert!(!benchmark.equity_curve.is_empty());
+        assert!(report.summary().contains("벤치마크 비교"));
+    }
+
+    #[tokio::test]
+    async fn test_backtest_config_performance_target() {
+        use trader_strategy::strategies::common::{EvaluationBasis, PerformanceGoal};
+
+        let target = PerformanceTargetConfig {
+            benchmark: Some("BTC/USDT".to_string()),
+            goal: PerformanceGoal::BenchmarkRelative {
+                excess_return_pct: dec!(1),
+            },
+            underperformance_alert_periods: 3,
+        };
+        let config = BacktestConfig::new(dec!(100000)).with_performance_target(target);
+        let mut engine = BacktestEngine::new(config);
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let context = create_test_context();
+
+        let klines = create_test_klines(20, dec!(50000), dec!(50));
+
+        let report = engine
+            .run(&mut strategy, &klines, context, "BTC/USDT", None)
+            .await
+            .unwrap();
+
+        // 설정에 목표가 있으면 엔진이 주 티커를 벤치마크로 평가
+        let evaluation = report.target_evaluation.as_ref().unwrap();
+        assert_eq!(evaluation.basis, EvaluationBasis::Benchmark);
+        assert!(evaluation.benchmark_return_pct.unwrap() > Decimal::ZERO);
     }
 
     #[test]
@@ -1439,4 +2329,243 @@ mod tests {
         assert_eq!(config.initial_capital, dec!(10000000));
         assert!(config.validate().is_ok());
     }
+
+    /// 시가/종가가 다른 캔들 생성 (체결 시점 비교용)
+    fn create_gap_klines(prices: &[(Decimal, Decimal)]) -> Vec<Kline> {
+        let base_time = Utc::now() - Duration::days(prices.len() as i64);
+
+        prices
+            .iter()
+            .enumerate()
+            .map(|(i, &(open, close))| {
+                let open_time = base_time + Duration::days(i as i64);
+                Kline::new(
+                    "BTC/USDT".to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    open,
+                    open.max(close),
+                    open.min(close),
+                    close,
+                    dec!(100),
+                    open_time + Duration::days(1),
+                )
+            })
+            .collect()
+    }
+
+    async fn run_scheduled(
+        timing: FillTiming,
+        klines: &[Kline],
+        entry_bar: usize,
+        exit_bar: Option<usize>,
+    ) -> BacktestReport {
+        let config = BacktestConfig::new(dec!(100000))
+            .with_commission_rate(dec!(0))
+            .with_slippage_rate(dec!(0))
+            .with_fill_timing(timing);
+        run_scheduled_with(config, klines, entry_bar, exit_bar).await
+    }
+
+    async fn run_scheduled_with(
+        config: BacktestConfig,
+        klines: &[Kline],
+        entry_bar: usize,
+        exit_bar: Option<usize>,
+    ) -> BacktestReport {
+        let mut engine = BacktestEngine::new(config);
+        let mut strategy = test_strategies::ScheduledStrategy::new(entry_bar, exit_bar);
+
+        engine
+            .run(
+                &mut strategy,
+                klines,
+                create_test_context(),
+                "BTC/USDT",
+                None,
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fill_timing_next_open_removes_look_ahead() {
+        // 1번 캔들 종가 급락 후 다음 캔들 갭 상승: 신호 캔들 종가 체결은 실거래에서 불가능한 가격
+        let klines = create_gap_klines(&[
+            (dec!(100), dec!(100)),
+            (dec!(100), dec!(90)),  // 진입 신호
+            (dec!(110), dec!(110)), // NextOpen 진입 체결
+            (dec!(110), dec!(120)), // 청산 신호
+            (dec!(115), dec!(115)), // NextOpen 청산 체결
+            (dec!(115), dec!(115)),
+        ]);
+
+        let current = run_scheduled(FillTiming::CurrentClose, &klines, 1, Some(3)).await;
+        let next_open = run_scheduled(FillTiming::NextOpen, &klines, 1, Some(3)).await;
+        let next_close = run_scheduled(FillTiming::NextClose, &klines, 1, Some(3)).await;
+
+        // 기존 동작: 신호 캔들 종가로 체결
+        assert_eq!(current.trades.len(), 1);
+        assert_eq!(current.trades[0].entry_price, dec!(90));
+        assert_eq!(current.trades[0].exit_price, dec!(120));
+
+        // NextOpen: 다음 캔들 시가로 체결
+        assert_eq!(next_open.trades.len(), 1);
+        assert_eq!(next_open.trades[0].entry_price, dec!(110));
+        assert_eq!(next_open.trades[0].exit_price, dec!(115));
+
+        // NextClose: 다음 캔들 종가로 체결
+        assert_eq!(next_close.trades[0].entry_price, dec!(110));
+        assert_eq!(next_close.trades[0].exit_price, dec!(115));
+
+        // 누수 제거로 수익률이 과대평가되지 않음
+        assert!(current.metrics.total_return_pct > next_open.metrics.total_return_pct);
+        assert!(current.unfilled_signals.is_empty());
+        assert!(next_open.unfilled_signals.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fill_timing_next_open_leaves_last_signal_unfilled() {
+        let klines = create_gap_klines(&[
+            (dec!(100), dec!(100)),
+            (dec!(100), dec!(101)),
+            (dec!(101), dec!(102)), // 마지막 캔들에서 진입 신호
+        ]);
+
+        let current = run_scheduled(FillTiming::CurrentClose, &klines, 2, None).await;
+        let next_open = run_scheduled(FillTiming::NextOpen, &klines, 2, None).await;
+
+        // 기존 동작은 마지막 캔들 종가로 진입 후 강제 청산
+        assert_eq!(current.trades.len(), 1);
+        assert!(current.unfilled_signals.is_empty());
+
+        // NextOpen은 체결할 캔들이 없어 미체결로 남김
+        assert!(next_open.trades.is_empty());
+        assert_eq!(next_open.unfilled_signals.len(), 1);
+        let unfilled = &next_open.unfilled_signals[0];
+        assert!(!unfilled.executed);
+        assert_eq!(unfilled.signal_type, SignalType::Entry);
+        assert_eq!(unfilled.timestamp, klines[2].open_time);
+        assert!(next_open.summary().contains("미체결 신호: 1 건"));
+    }
+
+    #[test]
+    fn test_fill_timing_serde_default() {
+        let config: BacktestConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.fill_timing, FillTiming::CurrentClose);
+
+        let config: BacktestConfig =
+            serde_json::from_str(r#"{"fill_timing":"next_open"}"#).unwrap();
+        assert_eq!(config.fill_timing, FillTiming::NextOpen);
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_excursion() {
+        let mut klines = create_gap_klines(&[
+            (dec!(100), dec!(100)), // 진입 (종가)
+            (dec!(100), dec!(95)),
+            (dec!(95), dec!(110)),
+            (dec!(110), dec!(108)), // 청산 (종가)
+            (dec!(108), dec!(108)),
+        ]);
+        klines[0].low = dec!(50); // 진입 전 가격은 MAE에 포함되지 않음
+        klines[1].low = dec!(90);
+        klines[2].high = dec!(115);
+        klines[4].high = dec!(200); // 청산 후 가격도 제외
+
+        let report = run_scheduled(FillTiming::CurrentClose, &klines, 0, Some(3)).await;
+
+        // 자본 100,000 × 20% = 20,000 → 200주
+        let trade = &report.trades[0];
+        assert_eq!(trade.quantity, dec!(200));
+        assert_eq!(trade.mae, dec!(-2000)); // (90 - 100) × 200
+        assert_eq!(trade.mfe, dec!(3000)); // (115 - 100) × 200
+        assert_eq!(trade.holding_bars, 3);
+
+        let excursion = report.excursion.as_ref().unwrap();
+        assert_eq!(excursion.trade_count, 1);
+        assert_eq!(excursion.mae_pct.median, dec!(-10));
+        assert_eq!(excursion.mfe_pct.median, dec!(15));
+        assert!(report.summary().contains("MAE/MFE 분포"));
+    }
+
+    fn margin_config() -> BacktestConfig {
+        BacktestConfig::new(dec!(100000))
+            .with_commission_rate(dec!(0))
+            .with_slippage_rate(dec!(0))
+            .with_max_position_size_pct(dec!(1))
+            .with_margin(dec!(0.5), dec!(0.3))
+    }
+
+    #[tokio::test]
+    async fn test_margin_leverage_without_margin_call() {
+        let klines = create_gap_klines(&[
+            (dec!(100), dec!(100)), // 진입 (2배 레버리지)
+            (dec!(100), dec!(105)),
+            (dec!(105), dec!(110)),
+        ]);
+
+        let report = run_scheduled_with(margin_config(), &klines, 0, None).await;
+
+        // 초기 증거금 50% → 자본 2배 규모로 진입
+        assert_eq!(report.all_trades[0].quantity, dec!(2000));
+        // 상승분이 2배로 반영: (110 - 100) × 2000 = 20,000
+        assert_eq!(report.metrics.net_profit, dec!(20000));
+
+        let margin = report.margin.as_ref().unwrap();
+        assert_eq!(margin.margin_calls, 0);
+        assert_eq!(margin.max_leverage, dec!(2));
+        assert_eq!(margin.max_leverage_at, Some(klines[0].close_time));
+    }
+
+    #[tokio::test]
+    async fn test_margin_call_liquidates_at_adverse_price() {
+        let mut klines = create_gap_klines(&[
+            (dec!(100), dec!(100)), // 진입 (2배 레버리지)
+            (dec!(100), dec!(105)),
+            (dec!(95), dec!(90)), // 장중 저가 60까지 급락
+            (dec!(90), dec!(95)),
+        ]);
+        klines[2].low = dec!(60);
+
+        let report = run_scheduled_with(margin_config(), &klines, 0, None).await;
+
+        // 저가 기준 자산 20,000 / 노출 120,000 = 16.7% < 유지 30% → 마진콜
+        let margin = report.margin.as_ref().unwrap();
+        assert_eq!(margin.margin_calls, 1);
+        let event = &margin.events[0];
+        assert_eq!(event.timestamp, klines[2].close_time);
+        assert_eq!(event.equity, dec!(20000));
+
+        // 저가로 전량 강제청산: (60 - 100) × 2000 = -80,000
+        assert_eq!(event.liquidations.len(), 1);
+        let liquidation = &event.liquidations[0];
+        assert_eq!(liquidation.price, dec!(60));
+        assert_eq!(liquidation.quantity, dec!(2000));
+        assert_eq!(liquidation.realized_pnl, dec!(-80000));
+
+        // 이후 반등과 무관하게 손실 확정
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.metrics.net_profit, dec!(-80000));
+        assert!(report.summary().contains("마진콜: 1 회"));
+    }
+
+    #[test]
+    fn test_margin_config_validation() {
+        assert!(margin_config().validate().is_ok());
+        assert!(BacktestConfig::default()
+            .with_margin(dec!(0.5), dec!(0.6))
+            .validate()
+            .is_err());
+        assert!(BacktestConfig::default()
+            .with_margin(dec!(0), dec!(0))
+            .validate()
+            .is_err());
+
+        // serde 기본값: 마진 비활성, 초기 50% / 유지 25%
+        let config: BacktestConfig = serde_json::from_str("{}").unwrap();
+        assert!(!config.allow_margin);
+        assert_eq!(config.initial_margin_rate, dec!(0.5));
+        assert_eq!(config.maintenance_margin_rate, dec!(0.25));
+    }
 }
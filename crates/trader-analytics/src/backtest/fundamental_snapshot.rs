@@ -0,0 +1,217 @@
+//! 백테스트용 시점별 펀더멘털 스냅샷
+//!
+//! 날짜별로 저장된 PER/PBR/ROE를 보관하고, 백테스트 시점 기준으로
+//! 사용 가능한 가장 최근 값을 조회합니다.
+//!
+//! # Look-Ahead Bias 방지
+//!
+//! 스냅샷 날짜는 해당 값이 시장에 공개된 날짜로 간주합니다.
+//! 조회 시점보다 미래 날짜의 스냅샷은 절대 반환하지 않으며,
+//! 해당 날짜에 스냅샷이 없으면 가장 가까운 과거 값을 캐리포워드합니다.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+};
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::engine::{BacktestError, BacktestResult};
+use crate::seven_factor::{SevenFactorCalculator, SevenFactorInput};
+
+/// 특정 날짜의 종목 펀더멘털 스냅샷.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FundamentalSnapshot {
+    /// 종목 코드
+    pub ticker: String,
+    /// 공개 기준 날짜
+    pub date: NaiveDate,
+    /// PER (주가수익비율)
+    #[serde(default)]
+    pub per: Option<Decimal>,
+    /// PBR (주가순자산비율)
+    #[serde(default)]
+    pub pbr: Option<Decimal>,
+    /// ROE (자기자본이익률, %)
+    #[serde(default)]
+    pub roe: Option<Decimal>,
+}
+
+impl FundamentalSnapshot {
+    /// 펀더멘털 점수 (0-100).
+    ///
+    /// 7Factor의 가치(PER/PBR)와 품질(ROE) 점수 평균입니다.
+    pub fn score(&self) -> Decimal {
+        let input = SevenFactorInput {
+            per: self.per,
+            pbr: self.pbr,
+            roe: self.roe,
+            ..Default::default()
+        };
+        let scores = SevenFactorCalculator::calculate(&input);
+        (scores.norm_value + scores.norm_quality) / Decimal::TWO
+    }
+}
+
+/// 종목별·날짜별 펀더멘털 스냅샷 저장소.
+#[derive(Debug, Clone, Default)]
+pub struct FundamentalSnapshotStore {
+    snapshots: HashMap<String, BTreeMap<NaiveDate, FundamentalSnapshot>>,
+}
+
+impl FundamentalSnapshotStore {
+    /// 빈 저장소 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 스냅샷 목록으로 저장소 생성
+    pub fn from_snapshots(snapshots: impl IntoIterator<Item = FundamentalSnapshot>) -> Self {
+        let mut store = Self::new();
+        for snapshot in snapshots {
+            store.insert(snapshot);
+        }
+        store
+    }
+
+    /// JSON 파일에서 스냅샷 로드.
+    ///
+    /// 파일은 [`FundamentalSnapshot`] 배열이어야 합니다.
+    pub fn load_json(path: impl AsRef<Path>) -> BacktestResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            BacktestError::DataError(format!(
+                "펀더멘털 스냅샷 파일 읽기 실패 ({}): {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let snapshots: Vec<FundamentalSnapshot> = serde_json::from_str(&content)
+            .map_err(|e| BacktestError::DataError(format!("펀더멘털 스냅샷 파싱 실패: {}", e)))?;
+        Ok(Self::from_snapshots(snapshots))
+    }
+
+    /// 스냅샷 추가 (같은 종목·날짜는 덮어씀)
+    pub fn insert(&mut self, snapshot: FundamentalSnapshot) {
+        self.snapshots
+            .entry(snapshot.ticker.clone())
+            .or_default()
+            .insert(snapshot.date, snapshot);
+    }
+
+    /// 해당 날짜 기준 사용 가능한 가장 최근 스냅샷.
+    ///
+    /// `date` 이후의 스냅샷은 반환하지 않습니다.
+    pub fn as_of(&self, ticker: &str, date: NaiveDate) -> Option<&FundamentalSnapshot> {
+        self.snapshots
+            .get(ticker)?
+            .range(..=date)
+            .next_back()
+            .map(|(_, snapshot)| snapshot)
+    }
+
+    /// 스냅샷이 있는 종목 수
+    pub fn ticker_count(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// 저장소가 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn snapshot(ticker: &str, date: &str, per: Decimal) -> FundamentalSnapshot {
+        FundamentalSnapshot {
+            ticker: ticker.to_string(),
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            per: Some(per),
+            pbr: Some(dec!(1.0)),
+            roe: Some(dec!(10)),
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_as_of_carries_forward_past_value() {
+        let store = FundamentalSnapshotStore::from_snapshots(vec![
+            snapshot("005930", "2024-03-31", dec!(10)),
+            snapshot("005930", "2024-06-30", dec!(15)),
+        ]);
+
+        // 정확한 날짜
+        assert_eq!(
+            store.as_of("005930", date("2024-03-31")).unwrap().per,
+            Some(dec!(10))
+        );
+        // 스냅샷 사이 날짜 → 직전 값 캐리포워드
+        assert_eq!(
+            store.as_of("005930", date("2024-06-29")).unwrap().per,
+            Some(dec!(10))
+        );
+        assert_eq!(
+            store.as_of("005930", date("2024-12-31")).unwrap().per,
+            Some(dec!(15))
+        );
+    }
+
+    #[test]
+    fn test_as_of_never_uses_future_value() {
+        let store = FundamentalSnapshotStore::from_snapshots(vec![snapshot(
+            "005930",
+            "2024-03-31",
+            dec!(10),
+        )]);
+
+        assert!(store.as_of("005930", date("2024-03-30")).is_none());
+        assert!(store.as_of("000660", date("2024-12-31")).is_none());
+    }
+
+    #[test]
+    fn test_score_prefers_cheap_and_profitable() {
+        let cheap = FundamentalSnapshot {
+            ticker: "A".to_string(),
+            date: date("2024-01-01"),
+            per: Some(dec!(5)),
+            pbr: Some(dec!(0.5)),
+            roe: Some(dec!(25)),
+        };
+        let expensive = FundamentalSnapshot {
+            ticker: "B".to_string(),
+            date: date("2024-01-01"),
+            per: Some(dec!(40)),
+            pbr: Some(dec!(5)),
+            roe: Some(dec!(1)),
+        };
+
+        assert!(cheap.score() > expensive.score());
+        assert!(cheap.score() <= dec!(100));
+        assert!(expensive.score() >= Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_deserialize_snapshot_list() {
+        let json = r#"[
+            {"ticker": "005930", "date": "2024-03-31", "per": "12.5", "pbr": "1.2", "roe": "9.8"},
+            {"ticker": "005930", "date": "2024-06-30", "per": "11.0"}
+        ]"#;
+        let snapshots: Vec<FundamentalSnapshot> = serde_json::from_str(json).unwrap();
+        let store = FundamentalSnapshotStore::from_snapshots(snapshots);
+
+        let latest = store.as_of("005930", date("2024-07-01")).unwrap();
+        assert_eq!(latest.per, Some(dec!(11.0)));
+        assert_eq!(latest.roe, None);
+        assert_eq!(store.ticker_count(), 1);
+    }
+}
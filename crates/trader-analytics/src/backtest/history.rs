@@ -0,0 +1,518 @@
+//! 백테스트 실행 이력 비교.
+//!
+//! 저장된 백테스트 결과를 설정 해시로 식별하고, 두 실행의 차이(diff)와
+//! 같은 전략의 설정 변경에 따른 성과 추이를 계산합니다.
+//! 영속화는 호출자(trader-api `BacktestResultsRepository`)가 담당하며,
+//! 이 모듈은 DB에 의존하지 않는 순수 계산만 제공합니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! let hash = BacktestRunKey { /* ... */ }.config_hash();
+//! let diff = BacktestRunDiff::between(&older_run, &newer_run);
+//! let trend = build_performance_trend(&runs);
+//! ```
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+// ============================================================================
+// 설정 해시
+// ============================================================================
+
+/// 동일 실행 판별에 쓰는 백테스트 입력.
+///
+/// 같은 키로 실행한 백테스트는 같은 결과를 내므로 하나만 보관합니다.
+#[derive(Debug, Clone)]
+pub struct BacktestRunKey {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 심볼 (다중 자산은 콤마 구분)
+    pub symbol: String,
+    /// 시작 날짜
+    pub start_date: NaiveDate,
+    /// 종료 날짜
+    pub end_date: NaiveDate,
+    /// 초기 자본
+    pub initial_capital: Decimal,
+    /// 수수료율
+    pub commission_rate: Option<Decimal>,
+    /// 슬리피지율
+    pub slippage_rate: Option<Decimal>,
+    /// 전략 파라미터
+    pub parameters: Value,
+}
+
+impl BacktestRunKey {
+    /// SHA-256 설정 해시 (16진수 64자).
+    ///
+    /// JSON 키 순서와 Decimal 스케일(`10000` vs `10000.00`)에 영향받지 않습니다.
+    pub fn config_hash(&self) -> String {
+        let decimal = |d: Option<Decimal>| d.map(|d| d.normalize().to_string());
+        let canonical = serde_json::json!({
+            "strategy_id": self.strategy_id.trim(),
+            "symbol": self.symbol.trim().to_uppercase(),
+            "start_date": self.start_date.to_string(),
+            "end_date": self.end_date.to_string(),
+            "initial_capital": self.initial_capital.normalize().to_string(),
+            "commission_rate": decimal(self.commission_rate),
+            "slippage_rate": decimal(self.slippage_rate),
+            "parameters": self.parameters,
+        });
+
+        let mut buf = String::new();
+        write_canonical_json(&canonical, &mut buf);
+
+        Sha256::digest(buf.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+}
+
+/// 객체 키를 정렬하여 JSON 직렬화 (serde_json `preserve_order` 여부와 무관).
+fn write_canonical_json(value: &Value, buf: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let sorted: BTreeMap<_, _> = map.iter().collect();
+            buf.push('{');
+            for (i, (key, val)) in sorted.into_iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                buf.push_str(&Value::String(key.clone()).to_string());
+                buf.push(':');
+                write_canonical_json(val, buf);
+            }
+            buf.push('}');
+        }
+        Value::Array(items) => {
+            buf.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                write_canonical_json(item, buf);
+            }
+            buf.push(']');
+        }
+        other => buf.push_str(&other.to_string()),
+    }
+}
+
+// ============================================================================
+// 실행 이력
+// ============================================================================
+
+/// 비교에 사용하는 핵심 성과 지표.
+///
+/// 저장된 `metrics` JSON에서 추출하며, 누락된 필드는 0으로 둡니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(default)]
+pub struct RunMetrics {
+    /// 총 수익률 (%)
+    pub total_return_pct: Decimal,
+    /// 연율화 수익률 (%)
+    pub annualized_return_pct: Decimal,
+    /// 순수익
+    pub net_profit: Decimal,
+    /// 총 거래 수
+    pub total_trades: usize,
+    /// 승률 (%)
+    pub win_rate_pct: Decimal,
+    /// 프로핏 팩터
+    pub profit_factor: Decimal,
+    /// 샤프 비율
+    pub sharpe_ratio: Decimal,
+    /// 최대 낙폭 (%)
+    pub max_drawdown_pct: Decimal,
+}
+
+impl RunMetrics {
+    /// 저장된 성과 지표 JSON에서 추출.
+    pub fn from_json(metrics: &Value) -> Self {
+        serde_json::from_value(metrics.clone()).unwrap_or_default()
+    }
+
+    /// 비교 대상 지표 (이름, 값) 목록.
+    fn entries(&self) -> [(&'static str, Decimal); 8] {
+        [
+            ("total_return_pct", self.total_return_pct),
+            ("annualized_return_pct", self.annualized_return_pct),
+            ("net_profit", self.net_profit),
+            ("total_trades", Decimal::from(self.total_trades)),
+            ("win_rate_pct", self.win_rate_pct),
+            ("profit_factor", self.profit_factor),
+            ("sharpe_ratio", self.sharpe_ratio),
+            ("max_drawdown_pct", self.max_drawdown_pct),
+        ]
+    }
+}
+
+/// 저장된 백테스트 실행 1건.
+#[derive(Debug, Clone)]
+pub struct BacktestRun {
+    /// 결과 ID
+    pub id: Uuid,
+    /// 설정 해시 (해시 도입 이전 결과는 None)
+    pub config_hash: Option<String>,
+    /// 전략 파라미터
+    pub parameters: Value,
+    /// 실행(저장) 시각
+    pub created_at: DateTime<Utc>,
+    /// 성과 지표
+    pub metrics: RunMetrics,
+}
+
+// ============================================================================
+// 실행 비교 (diff)
+// ============================================================================
+
+/// 지표 변화량.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct MetricDelta {
+    /// 지표 이름
+    pub metric: String,
+    /// 기준 실행 값
+    pub base: Decimal,
+    /// 비교 실행 값
+    pub target: Decimal,
+    /// 변화량 (target - base)
+    pub delta: Decimal,
+}
+
+/// 설정 변경 항목.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct ConfigChange {
+    /// 파라미터 경로 (중첩 객체는 `.`으로 연결)
+    pub path: String,
+    /// 기준 실행 값 (None이면 추가된 항목)
+    pub base: Option<Value>,
+    /// 비교 실행 값 (None이면 삭제된 항목)
+    pub target: Option<Value>,
+}
+
+/// 두 백테스트 실행의 차이.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct BacktestRunDiff {
+    /// 기준 실행 ID
+    pub base_id: Uuid,
+    /// 비교 실행 ID
+    pub target_id: Uuid,
+    /// 설정 해시 동일 여부
+    pub same_config: bool,
+    /// 변경된 파라미터
+    pub config_changes: Vec<ConfigChange>,
+    /// 지표 변화량 (모든 비교 지표)
+    pub metric_deltas: Vec<MetricDelta>,
+}
+
+impl BacktestRunDiff {
+    /// 기준 실행 대비 비교 실행의 차이 계산.
+    pub fn between(base: &BacktestRun, target: &BacktestRun) -> Self {
+        let base_params = flatten_parameters(&base.parameters);
+        let target_params = flatten_parameters(&target.parameters);
+
+        let mut paths: Vec<&String> = base_params.keys().chain(target_params.keys()).collect();
+        paths.sort();
+        paths.dedup();
+
+        let config_changes = paths
+            .into_iter()
+            .filter_map(|path| {
+                let b = base_params.get(path);
+                let t = target_params.get(path);
+                (b != t).then(|| ConfigChange {
+                    path: path.clone(),
+                    base: b.cloned(),
+                    target: t.cloned(),
+                })
+            })
+            .collect();
+
+        let metric_deltas = base
+            .metrics
+            .entries()
+            .into_iter()
+            .zip(target.metrics.entries())
+            .map(|((metric, b), (_, t))| MetricDelta {
+                metric: metric.to_string(),
+                base: b,
+                target: t,
+                delta: t - b,
+            })
+            .collect();
+
+        Self {
+            base_id: base.id,
+            target_id: target.id,
+            same_config: base.config_hash.is_some() && base.config_hash == target.config_hash,
+            config_changes,
+            metric_deltas,
+        }
+    }
+}
+
+/// 중첩 객체를 `a.b.c` 경로의 leaf 값으로 평탄화.
+fn flatten_parameters(value: &Value) -> BTreeMap<String, Value> {
+    fn walk(prefix: &str, value: &Value, out: &mut BTreeMap<String, Value>) {
+        match value {
+            Value::Object(map) if !map.is_empty() => {
+                for (key, val) in map {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    walk(&path, val, out);
+                }
+            }
+            Value::Null if prefix.is_empty() => {}
+            other => {
+                out.insert(prefix.to_string(), other.clone());
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    walk("", value, &mut out);
+    out
+}
+
+// ============================================================================
+// 성과 추이
+// ============================================================================
+
+/// 성과 추이의 한 시점.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct TrendPoint {
+    /// 결과 ID
+    pub run_id: Uuid,
+    /// 실행 시각
+    pub created_at: DateTime<Utc>,
+    /// 설정 해시
+    pub config_hash: Option<String>,
+    /// 직전 실행 대비 설정 변경 여부 (첫 실행은 false)
+    pub config_changed: bool,
+    /// 총 수익률 (%)
+    pub total_return_pct: Decimal,
+    /// 샤프 비율
+    pub sharpe_ratio: Decimal,
+    /// 최대 낙폭 (%)
+    pub max_drawdown_pct: Decimal,
+    /// 직전 실행 대비 수익률 변화 (%p)
+    pub return_change_pct: Option<Decimal>,
+}
+
+/// 실행 이력을 시간순으로 정렬하여 성과 추이 계산.
+pub fn build_performance_trend(runs: &[BacktestRun]) -> Vec<TrendPoint> {
+    let mut sorted: Vec<&BacktestRun> = runs.iter().collect();
+    sorted.sort_by_key(|run| run.created_at);
+
+    let mut points: Vec<TrendPoint> = Vec::with_capacity(sorted.len());
+    let mut prev: Option<&BacktestRun> = None;
+
+    for run in sorted {
+        let (config_changed, return_change_pct) = match prev {
+            Some(p) => (
+                p.config_hash != run.config_hash || p.parameters != run.parameters,
+                Some(run.metrics.total_return_pct - p.metrics.total_return_pct),
+            ),
+            None => (false, None),
+        };
+
+        points.push(TrendPoint {
+            run_id: run.id,
+            created_at: run.created_at,
+            config_hash: run.config_hash.clone(),
+            config_changed,
+            total_return_pct: run.metrics.total_return_pct,
+            sharpe_ratio: run.metrics.sharpe_ratio,
+            max_drawdown_pct: run.metrics.max_drawdown_pct,
+            return_change_pct,
+        });
+        prev = Some(run);
+    }
+
+    points
+}
+
+// ============================================================================
+// 보존 정책
+// ============================================================================
+
+/// 백테스트 결과 보존 정책.
+///
+/// 전략별 최신 `max_runs_per_strategy`건을 넘거나 `max_age_days`보다 오래된 결과를 정리합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// 전략별 최대 보관 건수
+    pub max_runs_per_strategy: usize,
+    /// 최대 보관 기간 (일, None이면 무제한)
+    pub max_age_days: Option<u32>,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            max_runs_per_strategy: 50,
+            max_age_days: Some(180),
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// 이 시각 이전에 저장된 결과는 정리 대상.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.max_age_days
+            .map(|days| now - Duration::days(i64::from(days)))
+    }
+
+    /// 정리 대상 실행 ID (보관 건수 초과 또는 보관 기간 경과).
+    pub fn expired_runs(&self, runs: &[BacktestRun], now: DateTime<Utc>) -> Vec<Uuid> {
+        let mut sorted: Vec<&BacktestRun> = runs.iter().collect();
+        sorted.sort_by_key(|run| std::cmp::Reverse(run.created_at));
+        let cutoff = self.cutoff(now);
+
+        sorted
+            .into_iter()
+            .enumerate()
+            .filter(|(rank, run)| {
+                *rank >= self.max_runs_per_strategy || cutoff.is_some_and(|c| run.created_at < c)
+            })
+            .map(|(_, run)| run.id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::*;
+
+    fn key(parameters: Value) -> BacktestRunKey {
+        BacktestRunKey {
+            strategy_id: "rsi".to_string(),
+            symbol: "005930".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            initial_capital: dec!(10000000),
+            commission_rate: Some(dec!(0.001)),
+            slippage_rate: None,
+            parameters,
+        }
+    }
+
+    fn run(hours_ago: i64, params: Value, return_pct: Decimal) -> BacktestRun {
+        let parameters = params.clone();
+        BacktestRun {
+            id: Uuid::new_v4(),
+            config_hash: Some(key(params).config_hash()),
+            parameters,
+            created_at: Utc::now() - Duration::hours(hours_ago),
+            metrics: RunMetrics {
+                total_return_pct: return_pct,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_config_hash_is_canonical() {
+        let a = key(json!({"period": 14, "thresholds": {"low": 30, "high": 70}}));
+        let mut b = key(json!({"thresholds": {"high": 70, "low": 30}, "period": 14}));
+        b.initial_capital = dec!(10000000.00);
+
+        assert_eq!(a.config_hash(), b.config_hash());
+        assert_eq!(a.config_hash().len(), 64);
+
+        let c = key(json!({"period": 21, "thresholds": {"low": 30, "high": 70}}));
+        assert_ne!(a.config_hash(), c.config_hash());
+    }
+
+    #[test]
+    fn test_diff_reports_config_and_metric_changes() {
+        let base = run(2, json!({"period": 14, "filter": {"adx": 20}}), dec!(5));
+        let target = run(
+            1,
+            json!({"period": 21, "filter": {"adx": 20}, "stop": 3}),
+            dec!(8),
+        );
+
+        let diff = BacktestRunDiff::between(&base, &target);
+
+        assert!(!diff.same_config);
+        assert_eq!(diff.config_changes.len(), 2);
+        assert_eq!(diff.config_changes[0].path, "period");
+        assert_eq!(diff.config_changes[1].path, "stop");
+        assert_eq!(diff.config_changes[1].base, None);
+
+        let ret = diff
+            .metric_deltas
+            .iter()
+            .find(|d| d.metric == "total_return_pct")
+            .unwrap();
+        assert_eq!(ret.delta, dec!(3));
+
+        let same = BacktestRunDiff::between(&base, &base);
+        assert!(same.same_config);
+        assert!(same.config_changes.is_empty());
+    }
+
+    #[test]
+    fn test_performance_trend_marks_config_changes() {
+        let runs = vec![
+            run(1, json!({"period": 21}), dec!(7)),
+            run(3, json!({"period": 14}), dec!(5)),
+            run(2, json!({"period": 14}), dec!(4)),
+        ];
+
+        let trend = build_performance_trend(&runs);
+
+        assert_eq!(trend.len(), 3);
+        assert!(!trend[0].config_changed);
+        assert_eq!(trend[0].return_change_pct, None);
+        assert!(!trend[1].config_changed);
+        assert_eq!(trend[1].return_change_pct, Some(dec!(-1)));
+        assert!(trend[2].config_changed);
+        assert_eq!(trend[2].return_change_pct, Some(dec!(3)));
+    }
+
+    #[test]
+    fn test_retention_policy_expires_old_and_excess_runs() {
+        let now = Utc::now();
+        let runs = vec![
+            run(1, json!({}), dec!(1)),
+            run(2, json!({}), dec!(1)),
+            run(3, json!({}), dec!(1)),
+            run(24 * 200, json!({}), dec!(1)),
+        ];
+
+        let policy = RetentionPolicy {
+            max_runs_per_strategy: 2,
+            max_age_days: Some(180),
+        };
+        let expired = policy.expired_runs(&runs, now);
+        assert_eq!(expired, vec![runs[2].id, runs[3].id]);
+
+        let unlimited = RetentionPolicy {
+            max_runs_per_strategy: 10,
+            max_age_days: None,
+        };
+        assert!(unlimited.expired_runs(&runs, now).is_empty());
+        assert!(unlimited.cutoff(now).is_none());
+    }
+}
@@ -0,0 +1,238 @@
+//! 증거금(마진) 계좌 시뮬레이션 결과.
+//!
+//! [`BacktestConfig::with_margin`](super::BacktestConfig::with_margin)으로 마진 거래를
+//! 허용하면 엔진이 캔들마다 유지 증거금을 검사하고, 부족하면 마진콜로 포지션을
+//! 강제청산합니다. 이 모듈은 그 판정 규칙과 리포트 타입을 정의합니다.
+//!
+//! # 판정 규칙
+//!
+//! - 총 노출 = Σ |가격 × 수량|
+//! - 증거금 비율 = 평가 자산 / 총 노출
+//! - 마진콜: 평가 자산 < 총 노출 × 유지 증거금률
+//! - 강제청산은 손실이 큰 포지션부터 진행하며, 평가 자산이 총 노출 × 초기 증거금률
+//!   이상으로 회복되면 멈춥니다.
+//!
+//! 평가는 캔들 내 불리한 가격(롱은 저가, 숏은 고가)으로 하므로 종가 기준으로는
+//! 회복된 캔들에서도 장중 마진콜이 발생할 수 있습니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::Side;
+
+/// 평가 자산이 유지 기준에 못 미치는지 판정합니다.
+///
+/// 노출이 없으면 마진콜이 아닙니다.
+pub fn is_margin_call(equity: Decimal, gross_exposure: Decimal, margin_rate: Decimal) -> bool {
+    gross_exposure > Decimal::ZERO && equity < gross_exposure * margin_rate
+}
+
+/// 증거금 비율 (평가 자산 / 총 노출). 노출이 없으면 None.
+pub fn margin_ratio(equity: Decimal, gross_exposure: Decimal) -> Option<Decimal> {
+    (gross_exposure > Decimal::ZERO).then(|| equity / gross_exposure)
+}
+
+/// 강제청산 체결 기록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidationRecord {
+    /// 심볼
+    pub symbol: String,
+
+    /// 청산된 포지션 방향
+    pub side: Side,
+
+    /// 청산 수량
+    pub quantity: Decimal,
+
+    /// 체결 가격 (불리한 캔들 가격 + 슬리피지)
+    pub price: Decimal,
+
+    /// 실현 손익
+    pub realized_pnl: Decimal,
+}
+
+/// 마진콜 발생 기록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarginCallEvent {
+    /// 발생 시각 (캔들 종료 시각)
+    pub timestamp: DateTime<Utc>,
+
+    /// 마진콜 판정 시 평가 자산
+    pub equity: Decimal,
+
+    /// 마진콜 판정 시 총 노출
+    pub gross_exposure: Decimal,
+
+    /// 마진콜 판정 시 증거금 비율
+    pub margin_ratio: Decimal,
+
+    /// 강제청산 내역
+    pub liquidations: Vec<LiquidationRecord>,
+}
+
+impl MarginCallEvent {
+    /// 판정 시점의 자산/노출로 이벤트를 생성합니다.
+    pub fn new(timestamp: DateTime<Utc>, equity: Decimal, gross_exposure: Decimal) -> Self {
+        Self {
+            timestamp,
+            equity,
+            gross_exposure,
+            margin_ratio: margin_ratio(equity, gross_exposure).unwrap_or(Decimal::ZERO),
+            liquidations: Vec::new(),
+        }
+    }
+
+    /// 강제청산으로 실현된 손익 합계
+    pub fn realized_pnl(&self) -> Decimal {
+        self.liquidations.iter().map(|l| l.realized_pnl).sum()
+    }
+}
+
+/// 증거금 시뮬레이션 리포트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarginReport {
+    /// 초기 증거금률
+    pub initial_margin_rate: Decimal,
+
+    /// 유지 증거금률
+    pub maintenance_margin_rate: Decimal,
+
+    /// 마진콜 횟수
+    pub margin_calls: usize,
+
+    /// 마진콜 이벤트 (강제청산 내역 포함)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<MarginCallEvent>,
+
+    /// 최대 레버리지 (총 노출 / 평가 자산, 캔들 종가 기준)
+    pub max_leverage: Decimal,
+
+    /// 최대 레버리지 도달 시각
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_leverage_at: Option<DateTime<Utc>>,
+}
+
+impl MarginReport {
+    /// 증거금률로 빈 리포트를 생성합니다.
+    pub fn new(initial_margin_rate: Decimal, maintenance_margin_rate: Decimal) -> Self {
+        Self {
+            initial_margin_rate,
+            maintenance_margin_rate,
+            margin_calls: 0,
+            events: Vec::new(),
+            max_leverage: Decimal::ZERO,
+            max_leverage_at: None,
+        }
+    }
+
+    /// 마진콜 이벤트 기록
+    pub fn record_margin_call(&mut self, event: MarginCallEvent) {
+        self.margin_calls += 1;
+        self.events.push(event);
+    }
+
+    /// 레버리지 관측 (최대값 갱신).
+    ///
+    /// 평가 자산이 0 이하이면 레버리지를 정의할 수 없으므로 무시합니다.
+    pub fn observe_leverage(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        equity: Decimal,
+        gross_exposure: Decimal,
+    ) {
+        if equity <= Decimal::ZERO {
+            return;
+        }
+        let leverage = gross_exposure / equity;
+        if leverage > self.max_leverage {
+            self.max_leverage = leverage;
+            self.max_leverage_at = Some(timestamp);
+        }
+    }
+
+    /// 모든 강제청산 기록
+    pub fn liquidations(&self) -> impl Iterator<Item = &LiquidationRecord> {
+        self.events.iter().flat_map(|e| e.liquidations.iter())
+    }
+
+    /// 강제청산 실현 손익 합계
+    pub fn liquidation_pnl(&self) -> Decimal {
+        self.events.iter().map(MarginCallEvent::realized_pnl).sum()
+    }
+
+    /// 요약 문자열
+    pub fn summary(&self) -> String {
+        let max_leverage_at = self
+            .max_leverage_at
+            .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "-".to_string());
+        format!(
+            "증거금 (초기 {:.1}% / 유지 {:.1}%)\n\
+             ───────────────────────────────────────\n\
+             마진콜: {} 회\n\
+             강제청산: {} 건 (실현 손익 {:.2})\n\
+             최대 레버리지: {:.2}배 ({})",
+            self.initial_margin_rate * Decimal::from(100),
+            self.maintenance_margin_rate * Decimal::from(100),
+            self.margin_calls,
+            self.liquidations().count(),
+            self.liquidation_pnl(),
+            self.max_leverage,
+            max_leverage_at,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_is_margin_call() {
+        // 노출 200, 유지 25% → 자산 50 미만이면 마진콜
+        assert!(!is_margin_call(dec!(50), dec!(200), dec!(0.25)));
+        assert!(is_margin_call(dec!(49), dec!(200), dec!(0.25)));
+        // 노출이 없으면 마진콜 아님
+        assert!(!is_margin_call(dec!(-10), Decimal::ZERO, dec!(0.25)));
+    }
+
+    #[test]
+    fn test_observe_leverage_tracks_maximum() {
+        let t1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let t3 = Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap();
+
+        let mut report = MarginReport::new(dec!(0.5), dec!(0.25));
+        report.observe_leverage(t1, dec!(100), dec!(150));
+        report.observe_leverage(t2, dec!(100), dec!(200));
+        report.observe_leverage(t3, dec!(100), dec!(120));
+        report.observe_leverage(t3, Decimal::ZERO, dec!(120));
+
+        assert_eq!(report.max_leverage, dec!(2));
+        assert_eq!(report.max_leverage_at, Some(t2));
+    }
+
+    #[test]
+    fn test_liquidation_pnl() {
+        let t = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut event = MarginCallEvent::new(t, dec!(40), dec!(200));
+        assert_eq!(event.margin_ratio, dec!(0.2));
+        event.liquidations.push(LiquidationRecord {
+            symbol: "AAPL".to_string(),
+            side: Side::Buy,
+            quantity: dec!(2),
+            price: dec!(60),
+            realized_pnl: dec!(-80),
+        });
+
+        let mut report = MarginReport::new(dec!(0.5), dec!(0.25));
+        report.record_margin_call(event);
+
+        assert_eq!(report.margin_calls, 1);
+        assert_eq!(report.liquidations().count(), 1);
+        assert_eq!(report.liquidation_pnl(), dec!(-80));
+    }
+}
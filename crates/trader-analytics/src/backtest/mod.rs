@@ -7,22 +7,61 @@
 //! - [`BacktestConfig`]: 백테스트 설정 (초기 자본, 수수료, 슬리피지 등)
 //! - [`BacktestEngine`]: 백테스트 실행 엔진
 //! - [`BacktestReport`]: 백테스트 결과 리포트
+//! - [`FillTiming`]: 신호 체결 시점 (신호 캔들 종가 / 다음 캔들 시가·종가)
 //! - [`SlippageModel`]: 동적 슬리피지 모델 (Fixed/Linear/VolatilityBased/Tiered)
 //! - [`BacktestScreeningProvider`]: 백테스트용 스크리닝 결과 제공자
+//! - [`FundamentalSnapshotStore`]: 시점별 펀더멘털 스냅샷 (룩어헤드 없는 캐리포워드)
 //! - [`CandleProcessor`]: 캔들 처리 공통 프로세서 (BacktestEngine/SimulationEngine 공유)
+//! - [`CostSensitivityAnalyzer`]: 거래 비용 민감도 분석 (손익분기 비용, 안전마진)
+//! - [`RebalanceFilterComparison`]: 비용 인지 리밸런싱 필터 on/off 비교 (회전율, 순수익)
+//! - [`BacktestRunDiff`]: 저장된 백테스트 실행 비교 (설정 해시, 성과 추이, 보존 정책)
+//! - [`BenchmarkComparison`]: 벤치마크(Buy & Hold) 대비 성과 (알파/베타/정보 비율)
+//! - [`MarginReport`]: 증거금 시뮬레이션 결과 (마진콜/강제청산, 최대 레버리지)
+//! - [`MonteCarloReport`]: 몬테카를로 수익률 부트스트랩 (수익률/낙폭 분포, 파산 확률)
+//! - [`BacktestProgressHandle`]: 실행 중 진행률/부분 결과 조회 및 취소
 
+pub mod benchmark;
 pub mod candle_processor;
+pub mod cost_sensitivity;
 pub mod engine;
+pub mod fundamental_snapshot;
+pub mod history;
+pub mod margin;
+pub mod monte_carlo;
+pub mod progress;
+pub mod rebalance_filter;
 pub mod screening_provider;
-pub mod slippage;
 
+pub use benchmark::BenchmarkComparison;
 pub use candle_processor::{
     CandleProcessor, PartitionedSignals, ProcessCandleContext, MIN_CANDLES_FOR_INDICATORS,
 };
-pub use engine::{BacktestConfig, BacktestEngine, BacktestError, BacktestReport, BacktestResult};
+pub use cost_sensitivity::{
+    BreakEvenStatus, CostLevelResult, CostSensitivityAnalyzer, CostSensitivityConfig,
+    CostSensitivityReport, StrategyFactory,
+};
+pub use engine::{
+    BacktestConfig, BacktestEngine, BacktestError, BacktestReport, BacktestResult, FillTiming,
+};
+pub use fundamental_snapshot::{FundamentalSnapshot, FundamentalSnapshotStore};
+pub use history::{
+    build_performance_trend, BacktestRun, BacktestRunDiff, BacktestRunKey, ConfigChange,
+    MetricDelta, RetentionPolicy, RunMetrics, TrendPoint,
+};
+pub use margin::{LiquidationRecord, MarginCallEvent, MarginReport};
+pub use monte_carlo::{
+    monte_carlo, monte_carlo_with, MonteCarloConfig, MonteCarloReport, PercentileSummary,
+    ResampleMethod,
+};
+pub use progress::{BacktestProgress, BacktestProgressHandle};
+pub use rebalance_filter::{
+    RebalanceFilterComparison, RebalanceFilterReport, RebalanceFilterRun, COST_AWARE_REBALANCE_KEY,
+};
 pub use screening_provider::{
-    BacktestScreeningConfig, BacktestScreeningProvider, MIN_CANDLES_FOR_SCREENING,
+    BacktestScreeningConfig, BacktestScreeningProvider, DEFAULT_FUNDAMENTAL_WEIGHT,
+    MIN_CANDLES_FOR_SCREENING,
 };
-pub use slippage::{SlippageModel, SlippageResult, SlippageTier};
+// 슬리피지 모델은 SimulatedExecutor와 공유하기 위해 trader-execution에 정의
+pub use trader_execution::{SlippageModel, SlippageResult, SlippageTier};
 // Re-export core types for convenience
 pub use trader_core::{ScreeningCalculator, ScreeningCalculatorConfig, ScreeningUpdateFrequency};
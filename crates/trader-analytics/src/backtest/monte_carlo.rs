@@ -0,0 +1,536 @@
+//! 몬테카를로 수익률 부트스트랩.
+//!
+//! 단일 백테스트 경로는 거래가 "그 순서로" 일어난 한 번의 결과일 뿐이므로,
+//! 거래별 수익률을 리샘플링해 여러 시나리오의 자산 곡선을 만들고 최종 수익률과
+//! 최대 낙폭의 분포, 신뢰구간, 파산 확률을 계산합니다.
+//!
+//! # 리샘플링 방식
+//!
+//! - [`ResampleMethod::Shuffle`]: 거래 순서만 무작위로 섞습니다 (비복원). 복리 특성상
+//!   최종 수익률은 원래 경로와 같고, 낙폭 분포가 순서 운에 얼마나 좌우되는지 보여줍니다.
+//! - [`ResampleMethod::Block`]: 연속 거래 블록 단위 복원 추출(원형 블록 부트스트랩).
+//!   연승/연패 같은 자기상관을 보존하면서 최종 수익률 분포도 함께 추정합니다.
+//!
+//! # 수익률 정의
+//!
+//! 거래별 수익률은 `손익 / 거래 직전 자산`이며, 자산은 초기 자본에 실현 손익을 누적해
+//! 계산합니다. 낙폭은 거래 단위 자산 경로 기준이므로 캔들 단위 자산 곡선의 낙폭보다
+//! 작을 수 있습니다.
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::backtest::monte_carlo;
+//!
+//! let mc = monte_carlo(&report, 1000, 42)?;
+//! println!("{}", mc.summary());
+//! ```
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use serde::{Deserialize, Serialize};
+
+use super::engine::{BacktestError, BacktestReport, BacktestResult};
+
+/// 차트용으로 보관하는 최대 시나리오 경로 수
+pub const MAX_SAMPLE_PATHS: usize = 100;
+
+/// 리샘플링 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResampleMethod {
+    /// 거래 순서 섞기 (비복원)
+    Shuffle,
+    /// 원형 블록 부트스트랩 (복원, 연속성 보존)
+    Block {
+        /// 블록 길이 (거래 수)
+        block_size: usize,
+    },
+}
+
+impl Default for ResampleMethod {
+    fn default() -> Self {
+        Self::Block { block_size: 5 }
+    }
+}
+
+/// 몬테카를로 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloConfig {
+    /// 리샘플링 방식
+    pub method: ResampleMethod,
+    /// 신뢰수준 (예: 0.95 = 95% 신뢰구간)
+    pub confidence: f64,
+    /// 파산으로 간주하는 최대 낙폭 (%, 예: 50 = 50% 이상 하락)
+    pub ruin_drawdown_pct: Decimal,
+}
+
+impl Default for MonteCarloConfig {
+    fn default() -> Self {
+        Self {
+            method: ResampleMethod::default(),
+            confidence: 0.95,
+            ruin_drawdown_pct: Decimal::from(50),
+        }
+    }
+}
+
+impl MonteCarloConfig {
+    /// 리샘플링 방식 설정
+    pub fn with_method(mut self, method: ResampleMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// 신뢰수준 설정
+    pub fn with_confidence(mut self, confidence: f64) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// 파산 기준 낙폭 설정
+    pub fn with_ruin_drawdown_pct(mut self, pct: Decimal) -> Self {
+        self.ruin_drawdown_pct = pct;
+        self
+    }
+}
+
+/// 분포 요약 (백분위수)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PercentileSummary {
+    /// 평균
+    pub mean: Decimal,
+    /// 5 백분위
+    pub p5: Decimal,
+    /// 25 백분위
+    pub p25: Decimal,
+    /// 중앙값
+    pub median: Decimal,
+    /// 75 백분위
+    pub p75: Decimal,
+    /// 95 백분위
+    pub p95: Decimal,
+    /// 신뢰구간 하한
+    pub ci_lower: Decimal,
+    /// 신뢰구간 상한
+    pub ci_upper: Decimal,
+}
+
+impl PercentileSummary {
+    fn from_values(values: &mut [f64], confidence: f64) -> Self {
+        values.sort_by(f64::total_cmp);
+        let tail = (1.0 - confidence) / 2.0;
+        let mean = values.iter().sum::<f64>() / values.len().max(1) as f64;
+        Self {
+            mean: to_decimal(mean),
+            p5: to_decimal(percentile(values, 0.05)),
+            p25: to_decimal(percentile(values, 0.25)),
+            median: to_decimal(percentile(values, 0.5)),
+            p75: to_decimal(percentile(values, 0.75)),
+            p95: to_decimal(percentile(values, 0.95)),
+            ci_lower: to_decimal(percentile(values, tail)),
+            ci_upper: to_decimal(percentile(values, 1.0 - tail)),
+        }
+    }
+}
+
+/// 몬테카를로 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloReport {
+    /// 리샘플링 방식
+    pub method: ResampleMethod,
+    /// 시나리오 수
+    pub n_runs: usize,
+    /// 난수 시드 (재현용)
+    pub seed: u64,
+    /// 리샘플링한 거래 수
+    pub trade_count: usize,
+    /// 신뢰수준
+    pub confidence: f64,
+    /// 원래 경로의 최종 수익률 (%)
+    pub original_return_pct: Decimal,
+    /// 원래 경로의 최대 낙폭 (%, 거래 단위)
+    pub original_max_drawdown_pct: Decimal,
+    /// 최종 수익률 분포 (%)
+    pub final_return_pct: PercentileSummary,
+    /// 최대 낙폭 분포 (%)
+    pub max_drawdown_pct: PercentileSummary,
+    /// 손실로 끝난 시나리오 비율 (%)
+    pub loss_probability_pct: Decimal,
+    /// 파산 기준 낙폭 (%)
+    pub ruin_drawdown_pct: Decimal,
+    /// 파산 확률 (최대 낙폭이 기준 이상인 시나리오 비율, %)
+    pub ruin_probability_pct: Decimal,
+    /// 차트용 시나리오 자산 경로 (초기 자본 기준, 최대 [`MAX_SAMPLE_PATHS`]개)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sample_paths: Vec<Vec<f64>>,
+}
+
+impl MonteCarloReport {
+    /// 요약 표
+    pub fn summary(&self) -> String {
+        let method = match self.method {
+            ResampleMethod::Shuffle => "거래 순서 섞기".to_string(),
+            ResampleMethod::Block { block_size } => {
+                format!("블록 부트스트랩 (블록 {})", block_size)
+            }
+        };
+        let ci = format!("{:.0}% CI", self.confidence * 100.0);
+        let row = |name: &str, original: Decimal, s: &PercentileSummary| {
+            format!(
+                "{:<10} {:>9.2} {:>9.2} {:>9.2} {:>9.2} {:>9.2}   [{:.2}, {:.2}]",
+                name, original, s.mean, s.p5, s.median, s.p95, s.ci_lower, s.ci_upper
+            )
+        };
+
+        format!(
+            "몬테카를로 시뮬레이션 ({}, {} 회, 거래 {}건, seed {})\n\
+             ───────────────────────────────────────────────────────────────────────────\n\
+             {:<10} {:>9} {:>9} {:>9} {:>9} {:>9}   {}\n\
+             {}\n\
+             {}\n\
+             ───────────────────────────────────────────────────────────────────────────\n\
+             손실 확률: {:.1}%\n\
+             파산 확률 (낙폭 {}% 이상): {:.1}%",
+            method,
+            self.n_runs,
+            self.trade_count,
+            self.seed,
+            "지표(%)",
+            "원래",
+            "평균",
+            "P5",
+            "중앙값",
+            "P95",
+            ci,
+            row("수익률", self.original_return_pct, &self.final_return_pct),
+            row(
+                "최대낙폭",
+                self.original_max_drawdown_pct,
+                &self.max_drawdown_pct
+            ),
+            self.loss_probability_pct,
+            self.ruin_drawdown_pct,
+            self.ruin_probability_pct,
+        )
+    }
+}
+
+/// 기본 설정(블록 부트스트랩, 95% 신뢰구간, 낙폭 50% 파산)으로 몬테카를로 시뮬레이션.
+pub fn monte_carlo(
+    report: &BacktestReport,
+    n_runs: usize,
+    seed: u64,
+) -> BacktestResult<MonteCarloReport> {
+    monte_carlo_with(report, n_runs, seed, &MonteCarloConfig::default())
+}
+
+/// 설정을 지정하여 몬테카를로 시뮬레이션.
+///
+/// 완료된 거래가 없으면 [`BacktestError::DataError`], 설정이 잘못되면
+/// [`BacktestError::ConfigError`]를 반환합니다.
+pub fn monte_carlo_with(
+    report: &BacktestReport,
+    n_runs: usize,
+    seed: u64,
+    config: &MonteCarloConfig,
+) -> BacktestResult<MonteCarloReport> {
+    if n_runs == 0 {
+        return Err(BacktestError::ConfigError(
+            "시나리오 수는 1 이상이어야 합니다".to_string(),
+        ));
+    }
+    if !(config.confidence > 0.0 && config.confidence < 1.0) {
+        return Err(BacktestError::ConfigError(
+            "신뢰수준은 0과 1 사이여야 합니다".to_string(),
+        ));
+    }
+    if let ResampleMethod::Block { block_size: 0 } = config.method {
+        return Err(BacktestError::ConfigError(
+            "블록 길이는 1 이상이어야 합니다".to_string(),
+        ));
+    }
+
+    let initial_capital = report.config.initial_capital.to_f64().unwrap_or(0.0);
+    if initial_capital <= 0.0 {
+        return Err(BacktestError::ConfigError(
+            "초기 자본은 0보다 커야 합니다".to_string(),
+        ));
+    }
+    let returns = trade_returns(report, initial_capital);
+    if returns.is_empty() {
+        return Err(BacktestError::DataError(
+            "몬테카를로 분석에 필요한 완료 거래가 없습니다".to_string(),
+        ));
+    }
+
+    let ruin_drawdown = config.ruin_drawdown_pct.to_f64().unwrap_or(100.0);
+    let (original_return, original_drawdown, _) = simulate_path(&returns, initial_capital);
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut final_returns = Vec::with_capacity(n_runs);
+    let mut drawdowns = Vec::with_capacity(n_runs);
+    let mut sample_paths = Vec::new();
+    let mut losses = 0usize;
+    let mut ruins = 0usize;
+
+    for _ in 0..n_runs {
+        let resampled = resample(&returns, config.method, &mut rng);
+        let (final_return, max_drawdown, path) = simulate_path(&resampled, initial_capital);
+
+        if final_return < 0.0 {
+            losses += 1;
+        }
+        if max_drawdown >= ruin_drawdown {
+            ruins += 1;
+        }
+        if sample_paths.len() < MAX_SAMPLE_PATHS {
+            sample_paths.push(path);
+        }
+        final_returns.push(final_return);
+        drawdowns.push(max_drawdown);
+    }
+
+    let pct = |count: usize| to_decimal(count as f64 / n_runs as f64 * 100.0);
+
+    Ok(MonteCarloReport {
+        method: config.method,
+        n_runs,
+        seed,
+        trade_count: returns.len(),
+        confidence: config.confidence,
+        original_return_pct: to_decimal(original_return),
+        original_max_drawdown_pct: to_decimal(original_drawdown),
+        final_return_pct: PercentileSummary::from_values(&mut final_returns, config.confidence),
+        max_drawdown_pct: PercentileSummary::from_values(&mut drawdowns, config.confidence),
+        loss_probability_pct: pct(losses),
+        ruin_drawdown_pct: config.ruin_drawdown_pct,
+        ruin_probability_pct: pct(ruins),
+        sample_paths,
+    })
+}
+
+/// 거래별 수익률 (손익 / 거래 직전 자산, 청산 시각 순).
+fn trade_returns(report: &BacktestReport, initial_capital: f64) -> Vec<f64> {
+    let mut trades: Vec<_> = report.trades.iter().collect();
+    trades.sort_by_key(|t| t.exit_time);
+
+    let mut equity = initial_capital;
+    trades
+        .into_iter()
+        .map(|trade| {
+            let pnl = trade.pnl.to_f64().unwrap_or(0.0);
+            let r = if equity > 0.0 {
+                (pnl / equity).max(-1.0)
+            } else {
+                -1.0
+            };
+            equity += pnl;
+            r
+        })
+        .collect()
+}
+
+/// 수익률 시퀀스 리샘플링.
+fn resample(returns: &[f64], method: ResampleMethod, rng: &mut StdRng) -> Vec<f64> {
+    let n = returns.len();
+    match method {
+        ResampleMethod::Shuffle => {
+            // Fisher-Yates
+            let mut shuffled = returns.to_vec();
+            for i in (1..n).rev() {
+                let j = rng.gen_range(0..=i);
+                shuffled.swap(i, j);
+            }
+            shuffled
+        }
+        ResampleMethod::Block { block_size } => {
+            let block_size = block_size.min(n);
+            let mut sampled = Vec::with_capacity(n);
+            while sampled.len() < n {
+                let start = rng.gen_range(0..n);
+                sampled.extend(
+                    (0..block_size)
+                        .map(|k| returns[(start + k) % n])
+                        .take(n - sampled.len()),
+                );
+            }
+            sampled
+        }
+    }
+}
+
+/// 수익률 시퀀스로 자산 경로 시뮬레이션.
+///
+/// (최종 수익률 %, 최대 낙폭 %, 자산 경로)를 반환합니다. 자산이 0 이하가 되면 파산으로 멈춥니다.
+fn simulate_path(returns: &[f64], initial_capital: f64) -> (f64, f64, Vec<f64>) {
+    let mut equity = initial_capital;
+    let mut peak = initial_capital;
+    let mut max_drawdown: f64 = 0.0;
+    let mut path = Vec::with_capacity(returns.len() + 1);
+    path.push(equity);
+
+    for r in returns {
+        equity = (equity * (1.0 + r)).max(0.0);
+        peak = peak.max(equity);
+        max_drawdown = max_drawdown.max((peak - equity) / peak * 100.0);
+        path.push(equity);
+        if equity <= 0.0 {
+            break;
+        }
+    }
+
+    let final_return = (equity - initial_capital) / initial_capital * 100.0;
+    (final_return, max_drawdown, path)
+}
+
+/// 정렬된 값의 백분위수 (선형 보간).
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        1 => sorted[0],
+        n => {
+            let pos = q.clamp(0.0, 1.0) * (n - 1) as f64;
+            let lower = pos.floor() as usize;
+            let upper = pos.ceil() as usize;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f64)
+        }
+    }
+}
+
+fn to_decimal(value: f64) -> Decimal {
+    Decimal::from_f64(value)
+        .unwrap_or(Decimal::ZERO)
+        .round_dp(4)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+
+    use super::*;
+    use crate::{
+        backtest::BacktestConfig,
+        performance::{PerformanceMetrics, RoundTrip},
+    };
+
+    fn report_with_pnls(pnls: &[i64]) -> BacktestReport {
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let trades = pnls
+            .iter()
+            .enumerate()
+            .map(|(i, &pnl)| {
+                let entry = base + Duration::days(i as i64);
+                // 수량 1, 진입가 1000 → 손익 = 청산가 - 1000
+                RoundTrip::new(
+                    "BTC/USDT",
+                    Side::Buy,
+                    dec!(1000),
+                    Decimal::from(1000 + pnl),
+                    Decimal::ONE,
+                    Decimal::ZERO,
+                    entry,
+                    entry + Duration::hours(12),
+                )
+            })
+            .collect();
+
+        BacktestReport {
+            config: BacktestConfig::new(dec!(10000)),
+            metrics: PerformanceMetrics::default(),
+            trades,
+            equity_curve: Vec::new(),
+            total_orders: 0,
+            total_commission: Decimal::ZERO,
+            total_slippage: Decimal::ZERO,
+            start_time: base,
+            end_time: base + Duration::days(pnls.len() as i64),
+            data_points: 0,
+            performance_by_symbol: Default::default(),
+            signal_markers: Vec::new(),
+            klines: Vec::new(),
+            symbol: "BTC/USDT".to_string(),
+            all_trades: Vec::new(),
+            target_evaluation: None,
+            benchmark: None,
+            unfilled_signals: Vec::new(),
+            margin: None,
+            excursion: None,
+        }
+    }
+
+    #[test]
+    fn test_shuffle_preserves_final_return() {
+        let report = report_with_pnls(&[500, -300, 200, -600, 400, 100, -200, 300]);
+        let config = MonteCarloConfig::default().with_method(ResampleMethod::Shuffle);
+
+        let mc = monte_carlo_with(&report, 200, 7, &config).unwrap();
+
+        // 순서만 바뀌므로 모든 시나리오의 최종 수익률이 원래 경로와 같음
+        assert_eq!(mc.final_return_pct.p5, mc.original_return_pct);
+        assert_eq!(mc.final_return_pct.p95, mc.original_return_pct);
+        // 낙폭은 순서에 따라 달라짐
+        assert!(mc.max_drawdown_pct.p5 < mc.max_drawdown_pct.p95);
+        assert_eq!(mc.trade_count, 8);
+        assert_eq!(mc.sample_paths.len(), MAX_SAMPLE_PATHS);
+        assert_eq!(mc.sample_paths[0].len(), 9);
+    }
+
+    #[test]
+    fn test_block_bootstrap_is_reproducible() {
+        let report = report_with_pnls(&[500, -300, 200, -600, 400, 100, -200, 300, 250, -150]);
+
+        let a = monte_carlo(&report, 300, 42).unwrap();
+        let b = monte_carlo(&report, 300, 42).unwrap();
+        let c = monte_carlo(&report, 300, 43).unwrap();
+
+        assert_eq!(a.final_return_pct, b.final_return_pct);
+        assert_eq!(a.max_drawdown_pct, b.max_drawdown_pct);
+        assert_ne!(a.final_return_pct, c.final_return_pct);
+        // 복원 추출이므로 최종 수익률이 분포를 가짐
+        assert!(a.final_return_pct.ci_lower < a.final_return_pct.ci_upper);
+        assert!(a.final_return_pct.p5 <= a.final_return_pct.median);
+        assert!(a.summary().contains("블록 부트스트랩"));
+    }
+
+    #[test]
+    fn test_ruin_probability() {
+        // 매 거래 자산의 약 20%를 잃는 전략 → 대부분 시나리오가 50% 낙폭 초과
+        let report = report_with_pnls(&[-2000, -1600, -1280, 500, -1000, -800]);
+        let config = MonteCarloConfig::default().with_ruin_drawdown_pct(dec!(50));
+
+        let mc = monte_carlo_with(&report, 500, 1, &config).unwrap();
+        assert!(mc.ruin_probability_pct > dec!(50));
+        assert!(mc.loss_probability_pct > dec!(90));
+
+        // 기준을 높이면 파산 확률 감소
+        let lenient = config.with_ruin_drawdown_pct(dec!(99));
+        let mc_lenient = monte_carlo_with(&report, 500, 1, &lenient).unwrap();
+        assert!(mc_lenient.ruin_probability_pct < mc.ruin_probability_pct);
+    }
+
+    #[test]
+    fn test_monte_carlo_validation() {
+        let report = report_with_pnls(&[100, -50]);
+        assert!(monte_carlo(&report, 0, 1).is_err());
+        assert!(monte_carlo(&report_with_pnls(&[]), 100, 1).is_err());
+
+        let config =
+            MonteCarloConfig::default().with_method(ResampleMethod::Block { block_size: 0 });
+        assert!(monte_carlo_with(&report, 10, 1, &config).is_err());
+    }
+
+    #[test]
+    fn test_percentile_interpolation() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(percentile(&values, 0.5), 3.0);
+        assert_eq!(percentile(&values, 0.25), 2.0);
+        assert_eq!(percentile(&[0.0, 10.0], 0.25), 2.5);
+        assert_eq!(percentile(&[], 0.5), 0.0);
+    }
+}
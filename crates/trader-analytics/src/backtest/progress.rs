@@ -0,0 +1,118 @@
+//! 백테스트 진행률 추적 및 취소.
+//!
+//! [`BacktestEngine::with_progress`](super::BacktestEngine::with_progress)로 핸들을 연결하면
+//! 엔진이 캔들마다 처리 개수와 현재 자산을 기록합니다. 다른 태스크는 같은 핸들의
+//! [`BacktestProgressHandle::snapshot`]으로 진행 중인 부분 결과를 조회하고,
+//! [`BacktestProgressHandle::cancel`]로 다음 캔들 처리 전에 실행을 중단시킬 수 있습니다.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// 백테스트 진행 상황 (실행 중 부분 결과).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BacktestProgress {
+    /// 처리한 캔들 수
+    pub processed_candles: usize,
+    /// 전체 캔들 수
+    pub total_candles: usize,
+    /// 마지막으로 처리한 캔들 시각
+    pub current_time: Option<DateTime<Utc>>,
+    /// 현재 자산 (미실현 손익 포함)
+    pub equity: Decimal,
+    /// 지금까지 완료된 거래(라운드트립) 수
+    pub total_trades: usize,
+}
+
+impl BacktestProgress {
+    /// 진행률 (0.0 ~ 100.0).
+    pub fn percent(&self) -> f64 {
+        if self.total_candles == 0 {
+            return 0.0;
+        }
+        (self.processed_candles as f64 / self.total_candles as f64 * 100.0).min(100.0)
+    }
+}
+
+/// 진행률 공유 및 취소 핸들.
+///
+/// 복제본은 모두 같은 상태를 공유합니다.
+#[derive(Debug, Clone, Default)]
+pub struct BacktestProgressHandle {
+    progress: Arc<Mutex<BacktestProgress>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl BacktestProgressHandle {
+    /// 새 핸들 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 현재 진행 상황 복사본.
+    pub fn snapshot(&self) -> BacktestProgress {
+        self.progress
+            .lock()
+            .map(|progress| progress.clone())
+            .unwrap_or_default()
+    }
+
+    /// 실행 취소 요청.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// 취소 요청 여부.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// 진행 상황 갱신 (엔진 내부용).
+    pub(crate) fn update(&self, progress: BacktestProgress) {
+        if let Ok(mut current) = self.progress.lock() {
+            *current = progress;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_progress_percent() {
+        let progress = BacktestProgress {
+            processed_candles: 25,
+            total_candles: 200,
+            ..Default::default()
+        };
+        assert_eq!(progress.percent(), 12.5);
+        assert_eq!(BacktestProgress::default().percent(), 0.0);
+    }
+
+    #[test]
+    fn test_handle_shares_state() {
+        let handle = BacktestProgressHandle::new();
+        let clone = handle.clone();
+
+        clone.update(BacktestProgress {
+            processed_candles: 10,
+            total_candles: 10,
+            equity: dec!(1000),
+            ..Default::default()
+        });
+        assert_eq!(handle.snapshot().processed_candles, 10);
+        assert_eq!(handle.snapshot().equity, dec!(1000));
+
+        assert!(!handle.is_cancelled());
+        clone.cancel();
+        assert!(handle.is_cancelled());
+    }
+}
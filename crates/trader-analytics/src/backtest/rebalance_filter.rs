@@ -0,0 +1,339 @@
+//! 비용 인지 리밸런싱 필터 on/off 비교.
+//!
+//! 자산 배분 전략의 `cost_aware_rebalance` 옵션만 바꿔 같은 데이터로 두 번
+//! 백테스트하고, 회전율·거래비용·순수익 차이를 리포트로 정리합니다.
+//!
+//! # 해석
+//!
+//! - 회전율 감소분이 클수록 필터가 잦은 소액 거래를 많이 걸러낸 것입니다.
+//! - 순수익 차이가 양수면 절감한 비용이 추적 오차로 잃은 수익보다 큽니다.
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::RwLock;
+use trader_core::{Kline, StrategyContext};
+
+use super::cost_sensitivity::StrategyFactory;
+use super::engine::{BacktestConfig, BacktestEngine, BacktestError, BacktestResult};
+
+/// 필터 on/off를 전환하는 전략 파라미터 키.
+pub const COST_AWARE_REBALANCE_KEY: &str = "cost_aware_rebalance";
+
+/// 필터 설정별 백테스트 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RebalanceFilterRun {
+    /// 비용 인지 필터 사용 여부
+    pub cost_aware: bool,
+    /// 총 수익률 (%)
+    pub total_return_pct: Decimal,
+    /// 순손익 (비용 차감 후)
+    pub net_profit: Decimal,
+    /// 총 주문 수 (진입 + 청산)
+    pub total_orders: usize,
+    /// 총 거래대금 (매수 + 매도)
+    pub traded_value: Decimal,
+    /// 회전율 (총 거래대금 / 초기 자본)
+    pub turnover: Decimal,
+    /// 총 거래비용 (수수료 + 슬리피지)
+    pub total_costs: Decimal,
+}
+
+/// 비용 인지 필터 on/off 비교 리포트.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RebalanceFilterReport {
+    /// 필터 미사용 결과
+    pub filter_off: RebalanceFilterRun,
+    /// 필터 사용 결과
+    pub filter_on: RebalanceFilterRun,
+    /// 회전율 감소분 (off - on)
+    pub turnover_reduction: Decimal,
+    /// 거래비용 절감액 (off - on)
+    pub cost_savings: Decimal,
+    /// 순손익 차이 (on - off, 양수면 필터가 유리)
+    pub net_profit_diff: Decimal,
+    /// 수익률 차이 (on - off, %p)
+    pub return_diff_pct: Decimal,
+}
+
+impl RebalanceFilterReport {
+    /// 필터 적용이 순수익을 개선했는지 여부.
+    pub fn filter_improves(&self) -> bool {
+        self.net_profit_diff > Decimal::ZERO
+    }
+
+    fn from_runs(filter_off: RebalanceFilterRun, filter_on: RebalanceFilterRun) -> Self {
+        Self {
+            turnover_reduction: filter_off.turnover - filter_on.turnover,
+            cost_savings: filter_off.total_costs - filter_on.total_costs,
+            net_profit_diff: filter_on.net_profit - filter_off.net_profit,
+            return_diff_pct: filter_on.total_return_pct - filter_off.total_return_pct,
+            filter_off,
+            filter_on,
+        }
+    }
+}
+
+/// 비용 인지 리밸런싱 필터 비교기.
+pub struct RebalanceFilterComparison {
+    /// 백테스트 설정 (두 실행에 동일하게 적용)
+    config: BacktestConfig,
+}
+
+impl RebalanceFilterComparison {
+    /// 새 비교기 생성
+    pub fn new(config: BacktestConfig) -> Self {
+        Self { config }
+    }
+
+    /// 필터 off/on 백테스트를 순서대로 실행하고 비교 리포트 생성.
+    ///
+    /// `strategy_params`는 JSON 객체여야 하며, 실행마다
+    /// [`COST_AWARE_REBALANCE_KEY`] 값만 덮어씁니다.
+    pub async fn run(
+        &self,
+        strategy_factory: StrategyFactory,
+        strategy_params: Value,
+        klines: &[Kline],
+        context: StrategyContext,
+        ticker: &str,
+    ) -> BacktestResult<RebalanceFilterReport> {
+        self.config.validate()?;
+        if !strategy_params.is_object() {
+            return Err(BacktestError::ConfigError(
+                "전략 파라미터는 JSON 객체여야 합니다".to_string(),
+            ));
+        }
+
+        let filter_off = self
+            .run_variant(
+                false,
+                &strategy_factory,
+                &strategy_params,
+                klines,
+                context.clone(),
+                ticker,
+            )
+            .await?;
+        let filter_on = self
+            .run_variant(
+                true,
+                &strategy_factory,
+                &strategy_params,
+                klines,
+                context,
+                ticker,
+            )
+            .await?;
+
+        Ok(RebalanceFilterReport::from_runs(filter_off, filter_on))
+    }
+
+    /// 필터 설정 하나로 백테스트 실행.
+    async fn run_variant(
+        &self,
+        cost_aware: bool,
+        factory: &StrategyFactory,
+        params: &Value,
+        klines: &[Kline],
+        context: StrategyContext,
+        ticker: &str,
+    ) -> BacktestResult<RebalanceFilterRun> {
+        let mut params = params.clone();
+        if let Some(obj) = params.as_object_mut() {
+            obj.insert(
+                COST_AWARE_REBALANCE_KEY.to_string(),
+                Value::Bool(cost_aware),
+            );
+        }
+
+        let mut strategy = factory();
+        strategy
+            .initialize(params)
+            .await
+            .map_err(|e| BacktestError::StrategyError(format!("전략 초기화 실패: {}", e)))?;
+
+        let context = Arc::new(RwLock::new(context));
+        strategy.set_context(Arc::clone(&context));
+
+        let mut engine = BacktestEngine::new(self.config.clone());
+        let report = engine
+            .run(&mut *strategy, klines, context, ticker, None)
+            .await?;
+
+        Ok(RebalanceFilterRun {
+            cost_aware,
+            total_return_pct: report.metrics.total_return_pct,
+            net_profit: report.metrics.net_profit,
+            total_orders: report.total_orders,
+            traded_value: report.traded_value(),
+            turnover: report.turnover(),
+            total_costs: report.total_commission + report.total_slippage,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::{Duration, TimeZone, Utc};
+    use rust_decimal_macros::dec;
+    use trader_core::{MarketData, MarketDataType, Order, Position, Side, Signal, Timeframe};
+    use trader_strategy::Strategy;
+
+    use super::*;
+
+    /// 필터 사용 시 드물게, 미사용 시 자주 매매하는 전략 (테스트용)
+    struct FilterAwareStrategy {
+        hold: usize,
+        count: usize,
+        in_position: bool,
+    }
+
+    #[async_trait]
+    impl Strategy for FilterAwareStrategy {
+        fn name(&self) -> &str {
+            "FilterAware"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "리밸런싱 필터 비교 테스트 전략"
+        }
+
+        async fn initialize(
+            &mut self,
+            config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            let cost_aware = config
+                .get(COST_AWARE_REBALANCE_KEY)
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            self.hold = if cost_aware { 30 } else { 2 };
+            self.count = 0;
+            self.in_position = false;
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            if !matches!(data.data, MarketDataType::Kline(_)) {
+                return Ok(vec![]);
+            }
+            self.count += 1;
+            if self.count % self.hold != 0 {
+                return Ok(vec![]);
+            }
+
+            self.in_position = !self.in_position;
+            let signal = if self.in_position {
+                Signal::entry("FilterAware", data.ticker.clone(), Side::Buy)
+            } else {
+                Signal::exit("FilterAware", data.ticker.clone(), Side::Sell)
+            };
+            Ok(vec![signal])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({ "hold": self.hold })
+        }
+    }
+
+    fn factory() -> StrategyFactory {
+        Arc::new(|| {
+            Box::new(FilterAwareStrategy {
+                hold: 2,
+                count: 0,
+                in_position: false,
+            })
+        })
+    }
+
+    fn flat_klines(count: usize) -> Vec<Kline> {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        (0..count)
+            .map(|i| {
+                let price = dec!(10000);
+                let open_time = start + Duration::days(i as i64);
+                Kline::new(
+                    "TEST".to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    price,
+                    price + dec!(20),
+                    price - dec!(20),
+                    price,
+                    dec!(1000),
+                    open_time + Duration::days(1),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_filter_reduces_turnover_and_costs() {
+        let config = BacktestConfig::new(dec!(10_000_000))
+            .with_commission_rate(dec!(0.001))
+            .with_slippage_rate(dec!(0.001));
+        let comparison = RebalanceFilterComparison::new(config);
+
+        let report = comparison
+            .run(
+                factory(),
+                serde_json::json!({}),
+                &flat_klines(120),
+                StrategyContext::default(),
+                "TEST",
+            )
+            .await
+            .unwrap();
+
+        assert!(!report.filter_off.cost_aware);
+        assert!(report.filter_on.cost_aware);
+        assert!(report.turnover_reduction > Decimal::ZERO);
+        assert!(report.cost_savings > Decimal::ZERO);
+        // 가격 변동이 없으므로 비용을 아낀 쪽이 순수익에서 유리
+        assert!(report.filter_improves());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_non_object_params() {
+        let comparison = RebalanceFilterComparison::new(BacktestConfig::new(dec!(1_000_000)));
+        let result = comparison
+            .run(
+                factory(),
+                Value::Null,
+                &flat_klines(10),
+                StrategyContext::default(),
+                "TEST",
+            )
+            .await;
+
+        assert!(matches!(result, Err(BacktestError::ConfigError(_))));
+    }
+}
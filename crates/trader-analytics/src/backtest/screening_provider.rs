@@ -2,15 +2,20 @@
 //!
 //! 백테스트 환경에서 캔들 데이터만으로 스크리닝 결과를 생성합니다.
 //! 실거래의 AnalyticsProvider 역할을 대신합니다.
+//!
+//! 시점별 펀더멘털 스냅샷([`FundamentalSnapshotStore`])을 주입하면
+//! 해당 시점에 공개된 PER/PBR/ROE를 점수에 반영합니다.
 
 use std::collections::HashMap;
 
 use chrono::{DateTime, Datelike, Utc};
+use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use trader_core::domain::{Kline, RouteState, ScreeningResult};
 // trader-core에서 정의된 trait과 타입 사용
 use trader_core::{ScreeningCalculator, ScreeningCalculatorConfig, ScreeningUpdateFrequency};
 
+use super::fundamental_snapshot::FundamentalSnapshotStore;
 use crate::{
     global_scorer::{GlobalScorer, GlobalScorerParams},
     route_state_calculator::RouteStateCalculator,
@@ -19,6 +24,9 @@ use crate::{
 /// 백테스트용 스크리닝 결과를 계산하는 최소 캔들 수
 pub const MIN_CANDLES_FOR_SCREENING: usize = 50;
 
+/// 펀더멘털 스냅샷이 있을 때 종합 점수에서 펀더멘털 점수 비중 기본값
+pub const DEFAULT_FUNDAMENTAL_WEIGHT: Decimal = dec!(0.5);
+
 // ================================================================================================
 // 하위 호환성을 위한 타입 별칭
 // ================================================================================================
@@ -43,13 +51,18 @@ pub type BacktestScreeningConfig = ScreeningCalculatorConfig;
 /// BacktestEngine에서 의존성 주입을 통해 사용됩니다.
 ///
 /// ```ignore
-/// let provider = BacktestScreeningProvider::with_config(config);
+/// let provider = BacktestScreeningProvider::with_config(config)
+///     .with_fundamentals(FundamentalSnapshotStore::load_json("fundamentals.json")?);
 /// let results = provider.calculate_from_klines(&klines, timestamp);
 /// ```
 pub struct BacktestScreeningProvider {
     global_scorer: GlobalScorer,
     route_calculator: RouteStateCalculator,
     config: ScreeningCalculatorConfig,
+    /// 시점별 펀더멘털 스냅샷 (None이면 기술적 지표만 반영)
+    fundamentals: Option<FundamentalSnapshotStore>,
+    /// 종합 점수에서 펀더멘털 점수 비중 (0~1)
+    fundamental_weight: Decimal,
 }
 
 impl Default for BacktestScreeningProvider {
@@ -65,6 +78,8 @@ impl BacktestScreeningProvider {
             global_scorer: GlobalScorer::new(),
             route_calculator: RouteStateCalculator::new(),
             config: ScreeningCalculatorConfig::default(),
+            fundamentals: None,
+            fundamental_weight: DEFAULT_FUNDAMENTAL_WEIGHT,
         }
     }
 
@@ -74,9 +89,28 @@ impl BacktestScreeningProvider {
             global_scorer: GlobalScorer::new(),
             route_calculator: RouteStateCalculator::new(),
             config,
+            fundamentals: None,
+            fundamental_weight: DEFAULT_FUNDAMENTAL_WEIGHT,
         }
     }
 
+    /// 시점별 펀더멘털 스냅샷 설정
+    pub fn with_fundamentals(mut self, fundamentals: FundamentalSnapshotStore) -> Self {
+        self.fundamentals = Some(fundamentals);
+        self
+    }
+
+    /// 펀더멘털 점수 비중 설정 (0~1로 제한)
+    pub fn with_fundamental_weight(mut self, weight: Decimal) -> Self {
+        self.fundamental_weight = weight.clamp(Decimal::ZERO, Decimal::ONE);
+        self
+    }
+
+    /// 펀더멘털 스냅샷 사용 여부
+    pub fn has_fundamentals(&self) -> bool {
+        self.fundamentals.is_some()
+    }
+
     /// 캔들 데이터 기반 스크리닝 결과 생성 (기존 API 하위 호환용)
     ///
     /// 새 코드에서는 `ScreeningCalculator::calculate_from_klines()`를 사용하세요.
@@ -142,7 +176,20 @@ impl BacktestScreeningProvider {
             Err(_) => return None,
         };
 
-        let overall_score = score_result.overall_score;
+        // 현재 시점까지 공개된 펀더멘털만 반영 (미래 스냅샷 사용 금지)
+        let snapshot = self
+            .fundamentals
+            .as_ref()
+            .and_then(|store| store.as_of(ticker, current_time.date_naive()));
+        let fundamental_score = snapshot.map(|s| s.score());
+
+        let overall_score = match fundamental_score {
+            Some(fundamental) => {
+                score_result.overall_score * (Decimal::ONE - self.fundamental_weight)
+                    + fundamental * self.fundamental_weight
+            }
+            None => score_result.overall_score,
+        };
 
         // 2. RouteState 계산
         let route_state = self
@@ -165,6 +212,9 @@ impl BacktestScreeningProvider {
         for (key, value) in &score_result.component_scores {
             criteria_results.insert(format!("score_{}", key), *value >= dec!(50));
         }
+        if let Some(fundamental) = fundamental_score {
+            criteria_results.insert("score_fundamental".to_string(), fundamental >= dec!(50));
+        }
 
         // 4. ScreeningResult 생성
         Some(ScreeningResult {
@@ -263,11 +313,11 @@ impl ScreeningCalculator for BacktestScreeningProvider {
 
 #[cfg(test)]
 mod tests {
-    use chrono::TimeZone;
-    use rust_decimal::Decimal;
+    use chrono::{NaiveDate, TimeZone};
     use trader_core::Timeframe;
 
     use super::*;
+    use crate::backtest::fundamental_snapshot::FundamentalSnapshot;
 
     fn create_test_klines(count: usize, base_price: f64) -> Vec<Kline> {
         (0..count)
@@ -289,6 +339,7 @@ mod tests {
                     volume: Decimal::from(1000),
                     quote_volume: Some(price * Decimal::from(1000)),
                     num_trades: Some(100),
+                    synthetic: false,
                 }
             })
             .collect()
@@ -369,6 +420,66 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    fn fundamental_store(
+        date: &str,
+        per: Decimal,
+        pbr: Decimal,
+        roe: Decimal,
+    ) -> FundamentalSnapshotStore {
+        FundamentalSnapshotStore::from_snapshots(vec![FundamentalSnapshot {
+            ticker: "TEST".to_string(),
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            per: Some(per),
+            pbr: Some(pbr),
+            roe: Some(roe),
+        }])
+    }
+
+    fn score_of(provider: &BacktestScreeningProvider, current_time: DateTime<Utc>) -> Decimal {
+        let mut all_klines = HashMap::new();
+        all_klines.insert("TEST".to_string(), create_test_klines(60, 100.0));
+        provider.calculate_from_klines(&all_klines, current_time)[0].overall_score
+    }
+
+    #[test]
+    fn test_fundamental_snapshot_affects_score() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+        let cheap = BacktestScreeningProvider::with_config(ScreeningCalculatorConfig::default())
+            .with_fundamentals(fundamental_store(
+                "2024-01-01",
+                dec!(5),
+                dec!(0.5),
+                dec!(25),
+            ));
+        let expensive =
+            BacktestScreeningProvider::with_config(ScreeningCalculatorConfig::default())
+                .with_fundamentals(fundamental_store("2024-01-01", dec!(40), dec!(5), dec!(1)));
+
+        assert!(score_of(&cheap, now) > score_of(&expensive, now));
+    }
+
+    #[test]
+    fn test_future_fundamental_snapshot_ignored() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+        let technical_only = BacktestScreeningProvider::new();
+        // 백테스트 시점 이후 공개된 스냅샷은 룩어헤드이므로 반영되면 안 됨
+        let future = BacktestScreeningProvider::new().with_fundamentals(fundamental_store(
+            "2024-02-01",
+            dec!(5),
+            dec!(0.5),
+            dec!(25),
+        ));
+
+        assert_eq!(score_of(&future, now), score_of(&technical_only, now));
+
+        let mut all_klines = HashMap::new();
+        all_klines.insert("TEST".to_string(), create_test_klines(60, 100.0));
+        let results = future.calculate_from_klines(&all_klines, now);
+        assert!(!results[0]
+            .criteria_results
+            .contains_key("score_fundamental"));
+    }
+
     #[test]
     fn test_top_n_filter() {
         let results = vec![
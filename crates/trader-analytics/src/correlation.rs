@@ -7,6 +7,7 @@
 //!
 //! - **Pearson 상관계수**: 두 종목 간 선형 상관관계 측정
 //! - **상관행렬**: 여러 종목 간 상관관계를 N×N 행렬로 표현
+//! - **리스크 연동**: 최근 일봉 수익률 상관계수를 `RiskManager` 상관 노출 한도용 테이블로 변환
 //!
 //! # 예시
 //!
@@ -22,8 +23,11 @@
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
+use trader_core::Kline;
+use trader_risk::CorrelationTable;
 
 /// 상관행렬 결과.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -207,6 +211,49 @@ pub fn calculate_correlation_matrix_decimal(
     calculate_correlation_matrix(&prices_f64, symbols)
 }
 
+impl CorrelationMatrix {
+    /// `RiskManager` 상관 노출 한도용 테이블로 변환.
+    pub fn to_risk_table(&self, computed_at: DateTime<Utc>) -> CorrelationTable {
+        CorrelationTable::from_matrix(&self.symbols, &self.matrix, computed_at)
+    }
+}
+
+/// 캔들 데이터의 최근 수익률로 리스크 상관계수 테이블 계산.
+///
+/// 종목별 최근 `lookback`개 종가를 사용합니다. 데이터가 부족한 종목은 제외되며,
+/// 계산 가능한 종목이 2개 미만이면 `None`을 반환합니다 (섹터 폴백 사용).
+pub fn risk_correlations_from_klines(
+    klines: &HashMap<String, Vec<Kline>>,
+    lookback: usize,
+    computed_at: DateTime<Utc>,
+) -> Option<CorrelationTable> {
+    let prices: HashMap<String, Vec<Decimal>> = klines
+        .iter()
+        .filter_map(|(symbol, candles)| {
+            let skip = candles.len().saturating_sub(lookback);
+            let closes: Vec<Decimal> = candles.iter().skip(skip).map(|k| k.close).collect();
+            // 상관행렬 최소 요구: 수익률 5개 이상
+            (closes.len() > 5).then(|| (symbol.clone(), closes))
+        })
+        .collect();
+    if prices.len() < 2 {
+        return None;
+    }
+
+    // 종목마다 길이가 다르면 가장 짧은 길이에 맞춰 최근 구간만 비교
+    let min_len = prices.values().map(Vec::len).min().unwrap_or(0);
+    let aligned: HashMap<String, Vec<Decimal>> = prices
+        .into_iter()
+        .map(|(symbol, closes)| {
+            let skip = closes.len() - min_len;
+            (symbol, closes.into_iter().skip(skip).collect())
+        })
+        .collect();
+
+    calculate_correlation_matrix_decimal(&aligned, None)
+        .map(|matrix| matrix.to_risk_table(computed_at))
+}
+
 #[cfg(test)]
 mod tests {
     use rust_decimal_macros::dec;
@@ -339,4 +386,52 @@ mod tests {
         assert!(corr.is_some());
         assert!((corr.unwrap() - 1.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_risk_correlations_from_klines() {
+        use trader_core::Timeframe;
+
+        let now = Utc::now();
+        let candles = |closes: &[f64]| -> Vec<Kline> {
+            closes
+                .iter()
+                .map(|c| {
+                    let price = Decimal::from_f64_retain(*c).unwrap();
+                    Kline::new(
+                        "X".to_string(),
+                        Timeframe::D1,
+                        now,
+                        price,
+                        price,
+                        price,
+                        price,
+                        dec!(1),
+                        now,
+                    )
+                })
+                .collect()
+        };
+
+        let mut klines = HashMap::new();
+        klines.insert(
+            "TQQQ".to_string(),
+            candles(&[100.0, 105.0, 102.0, 110.0, 108.0, 115.0, 120.0]),
+        );
+        klines.insert(
+            "SOXL".to_string(),
+            candles(&[50.0, 52.5, 51.0, 55.0, 54.0, 57.5, 60.0]),
+        );
+        // 데이터 부족 종목은 제외
+        klines.insert("NEW".to_string(), candles(&[10.0, 11.0]));
+
+        let table = risk_correlations_from_klines(&klines, 60, now).unwrap();
+        let corr = table
+            .correlation("TQQQ", "SOXL", chrono::Duration::days(1), now)
+            .unwrap();
+        assert!(corr > 0.8);
+        assert_eq!(
+            table.correlation("TQQQ", "NEW", chrono::Duration::days(1), now),
+            None
+        );
+    }
 }
@@ -27,7 +27,14 @@ use std::collections::HashMap;
 use chrono::Utc;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
-use trader_core::{domain::StructuralFeatures, types::MarketType, GlobalScoreResult, Kline};
+use trader_core::{
+    domain::{
+        GlobalScoreBreakdown, ScoreComponent, ScoreComponentGroup, ScoreNormalization,
+        StructuralFeatures,
+    },
+    types::MarketType,
+    GlobalScoreResult, Kline,
+};
 
 use crate::indicators::{
     BollingerBandsParams, IndicatorEngine, IndicatorError, MacdParams, RsiParams, SmaParams,
@@ -153,54 +160,20 @@ impl GlobalScorer {
         candles: &[Kline],
         params: GlobalScorerParams,
     ) -> GlobalScorerResult<GlobalScoreResult> {
-        const MIN_CANDLES: usize = 50;
-
-        if candles.len() < MIN_CANDLES {
-            return Err(GlobalScorerError::InsufficientData {
-                required: MIN_CANDLES,
-                provided: candles.len(),
-            });
-        }
-
-        let current_price = candles.last().unwrap().close;
-
-        // 7개 팩터 계산
-        let rr_score = self.calculate_risk_reward(current_price, &params)?;
-        let t1_score = self.calculate_target_room(current_price, &params)?;
-        let sl_score = self.calculate_stop_room(current_price, &params)?;
-        let near_score = self.calculate_entry_proximity(current_price, &params)?;
-        let mom_score = self.calculate_momentum(candles, &params)?;
-        let liq_score = self.calculate_liquidity(&params)?;
-        let tec_score = self.calculate_technical_balance(candles)?;
-
-        // 가중 합산 (0~100)
-        let mut overall_score = rr_score * self.weights.risk_reward
-            + t1_score * self.weights.target_room
-            + sl_score * self.weights.stop_room
-            + near_score * self.weights.entry_proximity
-            + mom_score * self.weights.momentum
-            + liq_score * self.weights.liquidity
-            + tec_score * self.weights.technical_balance;
-
-        // 7개 페널티 차감
-        let penalties = self.calculate_penalties(candles, current_price, &params)?;
-        overall_score = (overall_score - penalties).max(0.0);
+        let breakdown = self.calculate_breakdown(candles, &params)?;
 
         // 컴포넌트별 점수
-        let mut component_scores = HashMap::new();
-        component_scores.insert("risk_reward".to_string(), rr_score);
-        component_scores.insert("target_room".to_string(), t1_score);
-        component_scores.insert("stop_room".to_string(), sl_score);
-        component_scores.insert("entry_proximity".to_string(), near_score);
-        component_scores.insert("momentum".to_string(), mom_score);
-        component_scores.insert("liquidity".to_string(), liq_score);
-        component_scores.insert("technical_balance".to_string(), tec_score);
-        component_scores.insert("penalties".to_string(), -penalties);
+        let mut component_scores: HashMap<String, Decimal> = breakdown
+            .components
+            .iter()
+            .map(|c| (c.name.clone(), c.score))
+            .collect();
+        component_scores.insert("penalties".to_string(), -breakdown.total_penalty());
 
         // 추천 방향 결정
-        let recommendation = if overall_score >= 70.0 {
+        let recommendation = if breakdown.overall_score >= dec!(70) {
             "BUY".to_string()
-        } else if overall_score >= 50.0 {
+        } else if breakdown.overall_score >= dec!(50) {
             "WATCH".to_string()
         } else {
             "HOLD".to_string()
@@ -208,23 +181,154 @@ impl GlobalScorer {
 
         // 신뢰도 계산 (데이터 완전성 기준)
         let confidence = self.calculate_confidence(&params);
-
-        // f32 → Decimal 변환하여 결과 생성
-        let overall_score_dec = Decimal::from_f32_retain(overall_score).unwrap_or(Decimal::ZERO);
         let confidence_dec = Decimal::from_f32_retain(confidence).unwrap_or(Decimal::ZERO);
-        let component_scores_dec: HashMap<String, Decimal> = component_scores
-            .into_iter()
-            .map(|(k, v)| (k, Decimal::from_f32_retain(v).unwrap_or(Decimal::ZERO)))
-            .collect();
 
         Ok(GlobalScoreResult {
             // Symbol을 ticker 문자열로 변환
             ticker: params.symbol.clone(),
             market_type: params.market_type,
-            overall_score: overall_score_dec,
-            component_scores: component_scores_dec,
+            overall_score: breakdown.overall_score,
+            component_scores,
             recommendation,
             confidence: confidence_dec,
+            timestamp: breakdown.timestamp,
+        })
+    }
+
+    /// 캔들 데이터로부터 Global Score 구성요소 분해 계산.
+    ///
+    /// 7개 팩터의 점수·가중치·기여도와 페널티 항목을 반환합니다.
+    /// 입력이 없는 팩터는 0점(추천가 없으면 NEAR 100점)으로 채우고 결측으로 표시하며,
+    /// 정규화 방식은 [`ScoreNormalization::ZeroFill`]입니다.
+    ///
+    /// # 에러
+    ///
+    /// - 캔들 개수 부족 (최소 50개)
+    /// - 지표 계산 실패
+    pub fn calculate_breakdown(
+        &self,
+        candles: &[Kline],
+        params: &GlobalScorerParams,
+    ) -> GlobalScorerResult<GlobalScoreBreakdown> {
+        const MIN_CANDLES: usize = 50;
+
+        if candles.len() < MIN_CANDLES {
+            return Err(GlobalScorerError::InsufficientData {
+                required: MIN_CANDLES,
+                provided: candles.len(),
+            });
+        }
+
+        let current_price = candles.last().unwrap().close;
+
+        let missing = |inputs: &[(&str, bool)]| -> Vec<String> {
+            inputs
+                .iter()
+                .filter(|(_, present)| !present)
+                .map(|(name, _)| name.to_string())
+                .collect()
+        };
+        let has_target = params.target_price.is_some();
+        let has_stop = params.stop_price.is_some();
+
+        // 7개 팩터 계산: (이름, 분류, 점수, 가중치, 결측 입력, 구성요소 전체 결측 여부)
+        let factors = [
+            (
+                "risk_reward",
+                ScoreComponentGroup::Fundamental,
+                self.calculate_risk_reward(current_price, params)?,
+                self.weights.risk_reward,
+                missing(&[("target_price", has_target), ("stop_price", has_stop)]),
+                !(has_target && has_stop),
+            ),
+            (
+                "target_room",
+                ScoreComponentGroup::Fundamental,
+                self.calculate_target_room(current_price, params)?,
+                self.weights.target_room,
+                missing(&[("target_price", has_target)]),
+                !has_target,
+            ),
+            (
+                "stop_room",
+                ScoreComponentGroup::Fundamental,
+                self.calculate_stop_room(current_price, params)?,
+                self.weights.stop_room,
+                missing(&[("stop_price", has_stop)]),
+                !has_stop,
+            ),
+            (
+                "entry_proximity",
+                ScoreComponentGroup::Fundamental,
+                self.calculate_entry_proximity(current_price, params)?,
+                self.weights.entry_proximity,
+                missing(&[("entry_price", params.entry_price.is_some())]),
+                params.entry_price.is_none(),
+            ),
+            (
+                "momentum",
+                ScoreComponentGroup::Momentum,
+                self.calculate_momentum(candles, params)?,
+                self.weights.momentum,
+                // StructuralFeatures 없으면 ERS(30점)만 빠지는 부분 결측
+                missing(&[("structural_features", params.structural_features.is_some())]),
+                false,
+            ),
+            (
+                "liquidity",
+                ScoreComponentGroup::Liquidity,
+                self.calculate_liquidity(params)?,
+                self.weights.liquidity,
+                missing(&[("volume_percentile", params.volume_percentile.is_some())]),
+                params.volume_percentile.is_none(),
+            ),
+            (
+                "technical_balance",
+                ScoreComponentGroup::Technical,
+                self.calculate_technical_balance(candles)?,
+                self.weights.technical_balance,
+                Vec::new(),
+                false,
+            ),
+        ];
+
+        // 가중 합산 (0~100)
+        let weighted_sum: f32 = factors
+            .iter()
+            .map(|(_, _, score, weight, _, _)| score * weight)
+            .sum();
+
+        // 7개 페널티 차감
+        let penalties = self.calculate_penalties(candles, current_price, params);
+        let total_penalty: f32 = penalties.iter().map(|(_, p)| p).sum();
+        let overall_score = (weighted_sum - total_penalty).max(0.0);
+
+        // f32 → Decimal 변환
+        let to_dec = |v: f32| Decimal::from_f32_retain(v).unwrap_or(Decimal::ZERO);
+        let components = factors
+            .into_iter()
+            .map(
+                |(name, group, score, weight, missing_inputs, missing)| ScoreComponent {
+                    name: name.to_string(),
+                    group,
+                    score: to_dec(score),
+                    weight: to_dec(weight).round_dp(4),
+                    contribution: to_dec(score * weight),
+                    missing_inputs,
+                    missing,
+                },
+            )
+            .collect();
+
+        Ok(GlobalScoreBreakdown {
+            ticker: params.symbol.clone(),
+            overall_score: to_dec(overall_score),
+            components,
+            penalties: penalties
+                .into_iter()
+                .map(|(name, p)| (name.to_string(), to_dec(p)))
+                .collect(),
+            normalization: ScoreNormalization::ZeroFill,
             timestamp: Utc::now(),
         })
     }
@@ -583,30 +687,30 @@ impl GlobalScorer {
     // 7개 페널티 계산
     // ================================================================================================
 
-    /// 7개 페널티 합계 계산.
+    /// 7개 페널티 계산.
     ///
     /// # 반환
     ///
-    /// 총 페널티 점수 (양수, 최종 점수에서 차감됨)
+    /// 적용된 페널티 항목 (이름, 차감 점수), 점수는 양수이며 최종 점수에서 차감됨
     fn calculate_penalties(
         &self,
         candles: &[Kline],
         current_price: Decimal,
         params: &GlobalScorerParams,
-    ) -> GlobalScorerResult<f32> {
-        let mut total_penalty = 0.0;
+    ) -> Vec<(&'static str, f32)> {
+        let mut penalties = Vec::new();
 
         // 1. 5일 과열 (-6점)
         if let Ok(return_5d) = self.calculate_return_period(candles, 5) {
             if return_5d > 10.0 {
-                total_penalty += 6.0;
+                penalties.push(("overheat_5d", 6.0));
             }
         }
 
         // 2. 10일 과열 (-6점)
         if let Ok(return_10d) = self.calculate_return_period(candles, 10) {
             if return_10d > 20.0 {
-                total_penalty += 6.0;
+                penalties.push(("overheat_10d", 6.0));
             }
         }
 
@@ -616,7 +720,7 @@ impl GlobalScorer {
             if let Some(Some(rsi)) = rsi_values.last() {
                 let rsi_f32 = rsi.to_string().parse::<f32>().unwrap_or(50.0);
                 if !(45.0..=65.0).contains(&rsi_f32) {
-                    total_penalty += 4.0;
+                    penalties.push(("rsi_out_of_band", 4.0));
                 }
             }
         }
@@ -628,7 +732,7 @@ impl GlobalScorer {
                 let prev_macd = macd_result[macd_result.len() - 2].macd.unwrap_or(dec!(0));
 
                 if last_macd < prev_macd {
-                    total_penalty += 4.0;
+                    penalties.push(("macd_falling", 4.0));
                 }
             }
         }
@@ -642,7 +746,7 @@ impl GlobalScorer {
                     .unwrap_or(0.0);
 
                 if deviation_pct > 5.0 {
-                    total_penalty += 4.0;
+                    penalties.push(("entry_deviation", 4.0));
                 }
             }
         }
@@ -651,7 +755,7 @@ impl GlobalScorer {
         if let Some(percentile) = params.volume_percentile {
             if percentile < 0.2 {
                 // 하위 20%
-                total_penalty += 4.0;
+                penalties.push(("low_liquidity", 4.0));
             }
         }
 
@@ -672,14 +776,14 @@ impl GlobalScorer {
                             .unwrap_or(0.0);
 
                         if position > 3.0 {
-                            total_penalty += 2.0;
+                            penalties.push(("volatility_spike", 2.0));
                         }
                     }
                 }
             }
         }
 
-        Ok(total_penalty)
+        penalties
     }
 
     // ================================================================================================
@@ -821,6 +925,7 @@ mod tests {
                     close_time: timestamp + chrono::Duration::days(1),
                     quote_volume: Some(price * dec!(1000000)),
                     num_trades: Some(1000),
+                    synthetic: false,
                 }
             })
             .collect()
@@ -1008,4 +1113,85 @@ mod tests {
         // StructuralFeatures가 반영되었는지 확인 (confidence 증가)
         assert!(result.confidence > Decimal::ZERO);
     }
+
+    #[test]
+    fn test_breakdown_matches_calculate() {
+        let scorer = GlobalScorer::new();
+        let candles = create_test_candles(60);
+
+        let params = GlobalScorerParams {
+            symbol: Some("TEST/KRW".to_string()),
+            entry_price: Some(dec!(150)),
+            target_price: Some(dec!(180)),
+            stop_price: Some(dec!(140)),
+            volume_percentile: Some(0.75),
+            ..Default::default()
+        };
+
+        let breakdown = scorer.calculate_breakdown(&candles, &params).unwrap();
+        let result = scorer.calculate(&candles, params).unwrap();
+
+        assert_eq!(breakdown.overall_score, result.overall_score);
+        assert_eq!(breakdown.components.len(), 7);
+        assert_eq!(breakdown.normalization, ScoreNormalization::ZeroFill);
+        for component in &breakdown.components {
+            assert_eq!(
+                result.component_scores.get(&component.name),
+                Some(&component.score)
+            );
+        }
+        assert_eq!(
+            result.component_scores.get("penalties"),
+            Some(&-breakdown.total_penalty())
+        );
+        // 추천가가 모두 주어졌으므로 전체 결측 구성요소 없음
+        assert!(breakdown.missing_components().is_empty());
+    }
+
+    #[test]
+    fn test_breakdown_marks_missing_fundamentals() {
+        let scorer = GlobalScorer::new();
+        let candles = create_test_candles(60);
+
+        // 백테스트와 동일하게 추천가·거래량 퍼센타일 없이 계산
+        let params = GlobalScorerParams {
+            symbol: Some("TEST/KRW".to_string()),
+            ..Default::default()
+        };
+
+        let breakdown = scorer.calculate_breakdown(&candles, &params).unwrap();
+
+        assert_eq!(
+            breakdown.missing_components(),
+            vec![
+                "risk_reward",
+                "target_room",
+                "stop_room",
+                "entry_proximity",
+                "liquidity"
+            ]
+        );
+
+        // 모멘텀은 ERS만 빠지는 부분 결측
+        let momentum = breakdown
+            .components
+            .iter()
+            .find(|c| c.name == "momentum")
+            .unwrap();
+        assert!(!momentum.missing);
+        assert_eq!(momentum.missing_inputs, vec!["structural_features"]);
+
+        // 재정규화 시 남은 가중치 합이 1이 되고 점수는 0~100 범위
+        let reweighted = breakdown.reweighted();
+        assert_eq!(reweighted.normalization, ScoreNormalization::Reweighted);
+        let weight_sum: Decimal = reweighted
+            .components
+            .iter()
+            .filter(|c| !c.missing)
+            .map(|c| c.weight)
+            .sum();
+        assert!((weight_sum - Decimal::ONE).abs() < dec!(0.001));
+        assert!(reweighted.overall_score >= Decimal::ZERO);
+        assert!(reweighted.overall_score <= dec!(100));
+    }
 }
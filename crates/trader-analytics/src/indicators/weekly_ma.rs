@@ -131,6 +131,7 @@ fn create_weekly_candle(daily_klines: &[&Kline]) -> Option<Kline> {
         close_time: last.close_time,
         quote_volume: None,
         num_trades: None,
+        synthetic: false,
     })
 }
 
@@ -327,6 +328,7 @@ mod tests {
                 close_time: date + chrono::Duration::hours(9),
                 quote_volume: None,
                 num_trades: None,
+                synthetic: false,
             });
         }
 
@@ -363,6 +365,7 @@ mod tests {
                 close_time: current_date + chrono::Duration::hours(9),
                 quote_volume: None,
                 num_trades: None,
+                synthetic: false,
             });
 
             current_date += chrono::Duration::days(1);
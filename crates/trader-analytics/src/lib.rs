@@ -43,13 +43,14 @@ pub use analytics_provider_impl::AnalyticsProviderImpl;
 // Backtest 모듈 re-exports (backtest feature 필요)
 #[cfg(feature = "backtest")]
 pub use backtest::{
-    BacktestConfig, BacktestEngine, BacktestError, BacktestReport, BacktestResult, CandleProcessor,
-    PartitionedSignals, ProcessCandleContext, MIN_CANDLES_FOR_INDICATORS,
+    BacktestConfig, BacktestEngine, BacktestError, BacktestProgress, BacktestProgressHandle,
+    BacktestReport, BacktestResult, CandleProcessor, FillTiming, PartitionedSignals,
+    ProcessCandleContext, MIN_CANDLES_FOR_INDICATORS,
 };
 // Correlation re-export
 pub use correlation::{
     calculate_correlation, calculate_correlation_matrix, calculate_correlation_matrix_decimal,
-    CorrelationMatrix,
+    risk_correlations_from_klines, CorrelationMatrix,
 };
 // Global Scorer re-export
 pub use global_scorer::{GlobalScorer, GlobalScorerError, GlobalScorerParams, GlobalScorerResult};
@@ -141,6 +142,7 @@ pub use multi_timeframe_helpers::{
     DivergenceType, SignalDirection, TrendAnalysis, TrendDirection,
 };
 pub use performance::{
+    entry_pattern::{EntryPatternAnalyzer, EntryPatternConfig, EntryPatternReport},
     metrics::{
         PerformanceMetrics, RollingMetrics, RoundTrip, DEFAULT_RISK_FREE_RATE,
         TRADING_DAYS_PER_YEAR,
@@ -263,6 +263,7 @@ mod tests {
                     close_time: Utc::now(),
                     quote_volume: Some(Decimal::ZERO),
                     num_trades: Some((100 + i) as u32),
+                    synthetic: false,
                 }
             })
             .collect()
@@ -97,6 +97,45 @@ impl FeatureConfig {
             + log_return_features
             + candle_features
     }
+
+    /// 추출 순서대로 정렬된 feature 이름 반환.
+    ///
+    /// ONNX 모델의 입력 순서 검증에 사용하며, [`FeatureExtractor::extract`]가
+    /// 생성하는 이름과 동일해야 합니다.
+    pub fn feature_names(&self) -> Vec<String> {
+        let mut names = Vec::with_capacity(self.feature_count());
+        names.extend(self.sma_periods.iter().map(|p| format!("sma_{}_ratio", p)));
+        names.extend(self.ema_periods.iter().map(|p| format!("ema_{}_ratio", p)));
+        names.extend(
+            [
+                "rsi",
+                "macd_histogram",
+                "macd_signal_ratio",
+                "bb_percent_b",
+                "bb_bandwidth",
+                "atr_ratio",
+            ]
+            .iter()
+            .map(|n| n.to_string()),
+        );
+        names.extend(self.return_periods.iter().map(|p| format!("return_{}", p)));
+        names.extend(
+            self.return_periods
+                .iter()
+                .map(|p| format!("log_return_{}", p)),
+        );
+        names.extend(
+            [
+                "body_ratio",
+                "upper_shadow_ratio",
+                "lower_shadow_ratio",
+                "volume_change",
+            ]
+            .iter()
+            .map(|n| n.to_string()),
+        );
+        names
+    }
 }
 
 /// Kline 데이터를 ML feature vector로 변환하는 feature 추출기.
@@ -554,6 +593,17 @@ mod tests {
         assert!(names.contains(&"macd_histogram".to_string()));
     }
 
+    #[test]
+    fn test_feature_names_match_extraction_order() {
+        let config = FeatureConfig::default();
+        let extractor = FeatureExtractor::new(config.clone());
+        let features = extractor.extract(&create_test_klines(100)).unwrap();
+
+        let expected = config.feature_names();
+        assert_eq!(expected.len(), config.feature_count());
+        assert_eq!(features.names().unwrap(), expected.as_slice());
+    }
+
     #[test]
     fn test_insufficient_data() {
         let extractor = FeatureExtractor::with_defaults();
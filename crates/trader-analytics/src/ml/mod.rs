@@ -6,6 +6,7 @@
 //! - **패턴 인식**: 캔들스틱 및 차트 패턴 감지
 //! - **Feature Engineering**: ML 입력을 위한 기술 지표 추출
 //! - **통합 서비스**: MlService로 모든 기능 통합
+//! - **모델 검증**: 학습된 ONNX 모델의 로드/입력 shape/특성 순서 검증
 //!
 //! # 아키텍처
 //!
@@ -58,6 +59,7 @@ pub mod pattern;
 pub mod predictor;
 pub mod service;
 pub mod types;
+pub mod validation;
 
 // 자주 사용되는 타입 재내보내기
 pub use error::{MlError, MlResult};
@@ -76,3 +78,6 @@ pub use service::{
     MlServiceConfig, PatternDetectionResult,
 };
 pub use types::{ConfidenceLevel, FeatureVector, Prediction, PredictionDirection};
+pub use validation::{
+    check_feature_layout, validate_onnx_model, ModelMetadata, ModelValidationReport,
+};
@@ -1738,6 +1738,7 @@ mod tests {
             close_time: time + chrono::Duration::hours(1),
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         }
     }
 
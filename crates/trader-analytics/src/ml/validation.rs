@@ -0,0 +1,270 @@
+//! 학습된 ONNX 모델 배포 전 검증.
+//!
+//! Python 학습 스크립트(`tools/ml/train_model.py`)가 생성한 모델을 Rust 추론
+//! 런타임([`OnnxPredictor`])으로 실제 로드하여 다음을 확인합니다:
+//!
+//! 1. 메타데이터(`{model}_metadata.json`)의 특성 개수와 순서가
+//!    [`FeatureConfig::feature_names`]와 일치하는지
+//! 2. 모델이 로드되는지
+//! 3. `[1, feature_count]` 더미 입력으로 추론이 성공하고 확률이 유한한지
+//!
+//! 하나라도 실패하면 [`ModelValidationReport::mismatches`]에 사유가 기록되며,
+//! 배포 도구는 [`ModelValidationReport::is_valid`]가 `true`일 때만 배포해야 합니다.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ml::{
+    FeatureConfig, FeatureVector, MlError, MlResult, OnnxPredictor, PredictionResult,
+    PredictorConfig,
+};
+
+/// 불일치 목록에 개별 출력할 최대 특성 수.
+const MAX_REPORTED_FEATURE_MISMATCHES: usize = 10;
+
+/// 학습 스크립트가 모델과 함께 저장하는 메타데이터.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelMetadata {
+    /// 모델 이름
+    pub model_name: String,
+    /// 모델 유형 (xgboost, lightgbm 등)
+    #[serde(default)]
+    pub model_type: String,
+    /// 학습 시 사용한 특성 이름 (입력 순서)
+    pub feature_names: Vec<String>,
+    /// 입력 특성 수
+    pub n_features: usize,
+}
+
+impl ModelMetadata {
+    /// 모델 경로에 대응하는 메타데이터 경로 (`{stem}_metadata.json`).
+    pub fn path_for(model_path: &Path) -> PathBuf {
+        let stem = model_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        model_path.with_file_name(format!("{}_metadata.json", stem))
+    }
+
+    /// JSON 파일에서 메타데이터 로드.
+    pub fn from_file(path: &Path) -> MlResult<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            MlError::ModelLoad(format!("Failed to read metadata {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str(&content)
+            .map_err(|e| MlError::ModelLoad(format!("Invalid metadata {}: {}", path.display(), e)))
+    }
+}
+
+/// 모델 검증 결과.
+#[derive(Debug, Clone)]
+pub struct ModelValidationReport {
+    /// 검증한 모델 경로
+    pub model_path: PathBuf,
+    /// Rust feature 추출기가 생성하는 특성 이름 (입력 순서)
+    pub expected_features: Vec<String>,
+    /// 로드된 메타데이터 (없으면 None)
+    pub metadata: Option<ModelMetadata>,
+    /// 더미 입력 추론 결과 (성공 시)
+    pub sample_prediction: Option<PredictionResult>,
+    /// 발견된 불일치/실패 사유
+    pub mismatches: Vec<String>,
+}
+
+impl ModelValidationReport {
+    /// 모든 검증을 통과했는지 여부.
+    pub fn is_valid(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// 기대 입력 텐서 shape (`[1, feature_count]`).
+    pub fn input_shape(&self) -> [usize; 2] {
+        [1, self.expected_features.len()]
+    }
+}
+
+/// 메타데이터의 특성 개수와 순서를 기대값과 비교.
+///
+/// 불일치 사유 목록을 반환하며, 일치하면 빈 벡터입니다.
+pub fn check_feature_layout(metadata: &ModelMetadata, expected: &[String]) -> Vec<String> {
+    let mut mismatches = Vec::new();
+
+    if metadata.n_features != expected.len() {
+        mismatches.push(format!(
+            "입력 크기 불일치: 모델 [1, {}] / 기대 [1, {}]",
+            metadata.n_features,
+            expected.len()
+        ));
+    }
+    if metadata.feature_names.len() != metadata.n_features {
+        mismatches.push(format!(
+            "메타데이터 불일치: n_features={} 이지만 feature_names는 {}개",
+            metadata.n_features,
+            metadata.feature_names.len()
+        ));
+    }
+
+    let len = metadata.feature_names.len().max(expected.len());
+    let differing: Vec<String> = (0..len)
+        .filter_map(|i| {
+            let actual = metadata.feature_names.get(i).map(String::as_str);
+            let wanted = expected.get(i).map(String::as_str);
+            (actual != wanted).then(|| {
+                format!(
+                    "특성 #{} 불일치: 모델 '{}' / 기대 '{}'",
+                    i,
+                    actual.unwrap_or("-"),
+                    wanted.unwrap_or("-")
+                )
+            })
+        })
+        .collect();
+
+    if !differing.is_empty() {
+        let mut actual_sorted = metadata.feature_names.clone();
+        let mut expected_sorted = expected.to_vec();
+        actual_sorted.sort();
+        expected_sorted.sort();
+        if actual_sorted == expected_sorted {
+            mismatches.push("특성 집합은 같지만 순서가 다릅니다".to_string());
+        }
+
+        let total = differing.len();
+        mismatches.extend(differing.into_iter().take(MAX_REPORTED_FEATURE_MISMATCHES));
+        if total > MAX_REPORTED_FEATURE_MISMATCHES {
+            mismatches.push(format!(
+                "... 외 {}개 특성 불일치",
+                total - MAX_REPORTED_FEATURE_MISMATCHES
+            ));
+        }
+    }
+
+    mismatches
+}
+
+/// ONNX 모델을 추론 런타임으로 로드하여 검증.
+///
+/// 검증 실패는 에러가 아니라 [`ModelValidationReport::mismatches`]로 보고됩니다.
+pub fn validate_onnx_model(model_path: &Path, config: &FeatureConfig) -> ModelValidationReport {
+    let expected_features = config.feature_names();
+    let mut report = ModelValidationReport {
+        model_path: model_path.to_path_buf(),
+        expected_features,
+        metadata: None,
+        sample_prediction: None,
+        mismatches: Vec::new(),
+    };
+
+    // 1. 특성 개수/순서 (메타데이터 기준)
+    let metadata_path = ModelMetadata::path_for(model_path);
+    match ModelMetadata::from_file(&metadata_path) {
+        Ok(metadata) => {
+            report
+                .mismatches
+                .extend(check_feature_layout(&metadata, &report.expected_features));
+            report.metadata = Some(metadata);
+        }
+        Err(e) => report.mismatches.push(format!(
+            "메타데이터를 읽을 수 없어 특성 순서를 확인할 수 없음: {}",
+            e
+        )),
+    }
+
+    // 2. 모델 로드
+    let input_size = report.expected_features.len();
+    let model_name = model_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let predictor_config = PredictorConfig::new(model_path)
+        .with_input_size(input_size)
+        .with_model_name(model_name);
+    let mut predictor = match OnnxPredictor::load(predictor_config) {
+        Ok(predictor) => predictor,
+        Err(e) => {
+            report.mismatches.push(format!("모델 로드 실패: {}", e));
+            return report;
+        }
+    };
+
+    // 3. 더미 입력 추론
+    let dummy = FeatureVector::new(vec![0.0; input_size]);
+    match predictor.predict(&dummy) {
+        Ok(result) => {
+            if result.probabilities.iter().any(|p| !p.is_finite()) {
+                report.mismatches.push(format!(
+                    "더미 입력 추론 결과가 유한하지 않음: {:?}",
+                    result.probabilities
+                ));
+            }
+            report.sample_prediction = Some(result);
+        }
+        Err(e) => report
+            .mismatches
+            .push(format!("더미 입력 [1, {}] 추론 실패: {}", input_size, e)),
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(names: Vec<String>) -> ModelMetadata {
+        ModelMetadata {
+            model_name: "test".to_string(),
+            model_type: "xgboost".to_string(),
+            n_features: names.len(),
+            feature_names: names,
+        }
+    }
+
+    #[test]
+    fn test_metadata_path_for() {
+        let path = ModelMetadata::path_for(Path::new("models/xgb_SPY_5y.onnx"));
+        assert_eq!(path, PathBuf::from("models/xgb_SPY_5y_metadata.json"));
+    }
+
+    #[test]
+    fn test_feature_layout_matches() {
+        let expected = FeatureConfig::default().feature_names();
+        assert!(check_feature_layout(&metadata(expected.clone()), &expected).is_empty());
+    }
+
+    #[test]
+    fn test_feature_layout_order_mismatch() {
+        let expected = FeatureConfig::default().feature_names();
+        let mut swapped = expected.clone();
+        swapped.swap(0, 1);
+
+        let mismatches = check_feature_layout(&metadata(swapped), &expected);
+        assert!(mismatches.iter().any(|m| m.contains("순서가 다릅니다")));
+        assert!(mismatches.iter().any(|m| m.starts_with("특성 #0")));
+        assert!(mismatches.iter().any(|m| m.starts_with("특성 #1")));
+    }
+
+    #[test]
+    fn test_feature_layout_size_mismatch() {
+        let expected = FeatureConfig::default().feature_names();
+        let truncated = expected[..expected.len() - 1].to_vec();
+
+        let mismatches = check_feature_layout(&metadata(truncated), &expected);
+        assert!(mismatches[0].starts_with("입력 크기 불일치"));
+    }
+
+    #[test]
+    fn test_validate_missing_model() {
+        let report = validate_onnx_model(
+            Path::new("models/does_not_exist.onnx"),
+            &FeatureConfig::default(),
+        );
+        assert!(!report.is_valid());
+        assert!(report.metadata.is_none());
+        assert!(report
+            .mismatches
+            .iter()
+            .any(|m| m.starts_with("모델 로드 실패")));
+    }
+}
@@ -0,0 +1,501 @@
+//! 재진입/추격매수 패턴 분석
+//!
+//! 완료된 거래(RoundTrip) 시퀀스에서 진입 타이밍의 습관적 문제를 정량화합니다.
+//!
+//! # 분석 항목
+//!
+//! - **빠른 재진입**: 청산 후 짧은 간격 내 재진입 (과잉 거래 신호)
+//! - **추격매수**: 진입 직전 가격이 급등(숏은 급락)한 상태에서의 진입
+//! - **같은 방향 연속 거래**: 동일 방향 거래 연속 및 손실 직후 같은 방향 재진입
+//!
+//! 각 패턴의 성과를 일반 거래와 비교하여 쿨다운·확인 지연 설정의 필요성을 판단합니다.
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::performance::{EntryPatternAnalyzer, EntryPatternConfig};
+//!
+//! let analyzer = EntryPatternAnalyzer::new(EntryPatternConfig::default());
+//! let report = analyzer.analyze(&report.trades, &klines_by_symbol);
+//! println!("{}", report.summary());
+//! ```
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::{Kline, Side};
+use uuid::Uuid;
+
+use super::metrics::RoundTrip;
+
+/// 패턴 비교 시 통계적으로 의미 있다고 보는 최소 표본 수
+const MIN_SAMPLE_SIZE: usize = 3;
+
+/// 재진입/추격매수 분석 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryPatternConfig {
+    /// 빠른 재진입으로 간주하는 청산→재진입 최대 간격 (초)
+    pub quick_reentry_secs: i64,
+
+    /// 추격매수 판단 시 진입 직전 참조 캔들 수
+    pub chase_lookback_bars: usize,
+
+    /// 추격매수로 간주하는 진입 직전 가격 변화율 (%)
+    pub chase_threshold_pct: Decimal,
+
+    /// 패턴별 예시 거래 최대 개수
+    pub max_examples: usize,
+}
+
+impl Default for EntryPatternConfig {
+    fn default() -> Self {
+        Self {
+            quick_reentry_secs: 3600,
+            chase_lookback_bars: 5,
+            chase_threshold_pct: Decimal::from(3),
+            max_examples: 5,
+        }
+    }
+}
+
+/// 거래 그룹별 성과 통계
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PatternGroupStats {
+    /// 거래 수
+    pub count: usize,
+    /// 승률 (%)
+    pub win_rate_pct: Decimal,
+    /// 평균 수익률 (%)
+    pub avg_return_pct: Decimal,
+    /// 총 손익
+    pub total_pnl: Decimal,
+}
+
+impl PatternGroupStats {
+    fn from_trades(trades: &[&RoundTrip]) -> Self {
+        if trades.is_empty() {
+            return Self::default();
+        }
+
+        let count = Decimal::from(trades.len());
+        let wins = trades.iter().filter(|t| t.is_winner()).count();
+
+        Self {
+            count: trades.len(),
+            win_rate_pct: Decimal::from(wins) / count * Decimal::from(100),
+            avg_return_pct: trades.iter().map(|t| t.return_pct).sum::<Decimal>() / count,
+            total_pnl: trades.iter().map(|t| t.pnl).sum(),
+        }
+    }
+}
+
+/// 청산→재진입 간격 통계
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReentryStats {
+    /// 재진입 횟수 (같은 심볼의 이전 거래가 있는 진입)
+    pub reentry_count: usize,
+    /// 평균 청산→재진입 간격 (초)
+    pub avg_gap_secs: i64,
+    /// 최소 청산→재진입 간격 (초)
+    pub min_gap_secs: Option<i64>,
+    /// 빠른 재진입 성과
+    pub quick: PatternGroupStats,
+    /// 일반 재진입 성과
+    pub normal: PatternGroupStats,
+}
+
+/// 추격매수 통계
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChaseStats {
+    /// 가격 데이터로 판단 가능했던 진입 수
+    pub evaluated_count: usize,
+    /// 추격매수 비율 (%)
+    pub chase_ratio_pct: Decimal,
+    /// 추격매수 진입 성과
+    pub chase: PatternGroupStats,
+    /// 일반 진입 성과
+    pub normal: PatternGroupStats,
+}
+
+/// 같은 방향 연속 거래 통계
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StreakStats {
+    /// 같은 방향 최대 연속 거래 수
+    pub max_same_direction_streak: usize,
+    /// 손실 직후 같은 방향 빠른 재진입 횟수
+    pub reentry_after_loss_count: usize,
+    /// 손실 직후 같은 방향 빠른 재진입 성과
+    pub reentry_after_loss: PatternGroupStats,
+}
+
+/// 패턴 유형
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryPatternKind {
+    /// 빠른 재진입
+    QuickReentry,
+    /// 추격매수
+    Chase,
+    /// 손실 직후 같은 방향 재진입
+    ReentryAfterLoss,
+}
+
+/// 패턴 예시 거래
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternExample {
+    /// 패턴 유형
+    pub kind: EntryPatternKind,
+    /// 거래 ID
+    pub trade_id: Uuid,
+    /// 심볼
+    pub symbol: String,
+    /// 진입 시각
+    pub entry_time: DateTime<Utc>,
+    /// 수익률 (%)
+    pub return_pct: Decimal,
+    /// 손익
+    pub pnl: Decimal,
+    /// 설명 (간격 또는 진입 직전 변화율)
+    pub detail: String,
+}
+
+/// 재진입/추격매수 분석 결과
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntryPatternReport {
+    /// 분석한 거래 수
+    pub total_trades: usize,
+    /// 재진입 통계
+    pub reentry: ReentryStats,
+    /// 추격매수 통계
+    pub chase: ChaseStats,
+    /// 연속 거래 통계
+    pub streak: StreakStats,
+    /// 쿨다운 설정 권장 여부 (빠른 재진입 성과가 일반 재진입보다 나쁨)
+    pub cooldown_recommended: bool,
+    /// 진입 확인 지연 권장 여부 (추격매수 성과가 일반 진입보다 나쁨)
+    pub confirmation_delay_recommended: bool,
+    /// 예시 거래 (패턴별 손익이 나쁜 순)
+    pub examples: Vec<PatternExample>,
+}
+
+impl EntryPatternReport {
+    /// 요약 문자열 반환
+    pub fn summary(&self) -> String {
+        format!(
+            "진입 패턴 분석 ({} 거래)\n\
+             재진입 {}건 (평균 간격 {}초) - 빠른 재진입 {}건 평균 {:.2}% / 일반 {}건 평균 {:.2}%\n\
+             추격매수 비율 {:.1}% - 추격 {}건 평균 {:.2}% / 일반 {}건 평균 {:.2}%\n\
+             같은 방향 최대 연속 {}건, 손실 직후 재진입 {}건 평균 {:.2}%\n\
+             쿨다운 권장: {}, 확인 지연 권장: {}",
+            self.total_trades,
+            self.reentry.reentry_count,
+            self.reentry.avg_gap_secs,
+            self.reentry.quick.count,
+            self.reentry.quick.avg_return_pct,
+            self.reentry.normal.count,
+            self.reentry.normal.avg_return_pct,
+            self.chase.chase_ratio_pct,
+            self.chase.chase.count,
+            self.chase.chase.avg_return_pct,
+            self.chase.normal.count,
+            self.chase.normal.avg_return_pct,
+            self.streak.max_same_direction_streak,
+            self.streak.reentry_after_loss_count,
+            self.streak.reentry_after_loss.avg_return_pct,
+            if self.cooldown_recommended {
+                "예"
+            } else {
+                "아니오"
+            },
+            if self.confirmation_delay_recommended {
+                "예"
+            } else {
+                "아니오"
+            },
+        )
+    }
+}
+
+/// 재진입/추격매수 패턴 분석기
+#[derive(Debug, Clone, Default)]
+pub struct EntryPatternAnalyzer {
+    config: EntryPatternConfig,
+}
+
+impl EntryPatternAnalyzer {
+    /// 새 분석기 생성
+    pub fn new(config: EntryPatternConfig) -> Self {
+        Self { config }
+    }
+
+    /// 거래 시퀀스를 분석합니다.
+    ///
+    /// # 매개변수
+    ///
+    /// * `trades` - 완료된 거래 목록 (순서 무관)
+    /// * `klines_by_symbol` - 심볼별 캔들 (추격매수 판단용, 없는 심볼은 추격 분석에서 제외)
+    pub fn analyze(
+        &self,
+        trades: &[RoundTrip],
+        klines_by_symbol: &HashMap<String, Vec<Kline>>,
+    ) -> EntryPatternReport {
+        let mut by_symbol: HashMap<&str, Vec<&RoundTrip>> = HashMap::new();
+        for trade in trades {
+            by_symbol.entry(&trade.symbol).or_default().push(trade);
+        }
+
+        let mut quick = Vec::new();
+        let mut normal_reentry = Vec::new();
+        let mut after_loss = Vec::new();
+        let mut gaps = Vec::new();
+        let mut max_streak = 0usize;
+        let mut examples = Vec::new();
+
+        for symbol_trades in by_symbol.values_mut() {
+            symbol_trades.sort_by_key(|t| t.entry_time);
+
+            let mut streak = 0usize;
+            let mut prev: Option<&RoundTrip> = None;
+            for &trade in symbol_trades.iter() {
+                streak = match prev {
+                    Some(p) if p.side == trade.side => streak + 1,
+                    _ => 1,
+                };
+                max_streak = max_streak.max(streak);
+
+                if let Some(p) = prev {
+                    let gap_secs = (trade.entry_time - p.exit_time).num_seconds();
+                    if gap_secs >= 0 {
+                        gaps.push(gap_secs);
+                        let detail = format!("청산 후 {}초 만에 재진입", gap_secs);
+                        if gap_secs <= self.config.quick_reentry_secs {
+                            quick.push(trade);
+                            examples.push(example(EntryPatternKind::QuickReentry, trade, &detail));
+
+                            if p.side == trade.side && !p.is_winner() {
+                                after_loss.push(trade);
+                                examples.push(example(
+                                    EntryPatternKind::ReentryAfterLoss,
+                                    trade,
+                                    &detail,
+                                ));
+                            }
+                        } else {
+                            normal_reentry.push(trade);
+                        }
+                    }
+                }
+                prev = Some(trade);
+            }
+        }
+
+        let mut chase = Vec::new();
+        let mut normal_entry = Vec::new();
+        for trade in trades {
+            let Some(run_up) = klines_by_symbol
+                .get(&trade.symbol)
+                .and_then(|klines| self.pre_entry_change_pct(trade, klines))
+            else {
+                continue;
+            };
+
+            // 롱은 급등 후 진입, 숏은 급락 후 진입을 추격으로 판단
+            let directional = match trade.side {
+                Side::Buy => run_up,
+                Side::Sell => -run_up,
+            };
+            if directional >= self.config.chase_threshold_pct {
+                chase.push(trade);
+                examples.push(example(
+                    EntryPatternKind::Chase,
+                    trade,
+                    &format!("진입 직전 {:.2}% 변동", run_up),
+                ));
+            } else {
+                normal_entry.push(trade);
+            }
+        }
+
+        let reentry = ReentryStats {
+            reentry_count: gaps.len(),
+            avg_gap_secs: if gaps.is_empty() {
+                0
+            } else {
+                gaps.iter().sum::<i64>() / gaps.len() as i64
+            },
+            min_gap_secs: gaps.iter().copied().min(),
+            quick: PatternGroupStats::from_trades(&quick),
+            normal: PatternGroupStats::from_trades(&normal_reentry),
+        };
+
+        let evaluated = chase.len() + normal_entry.len();
+        let chase_stats = ChaseStats {
+            evaluated_count: evaluated,
+            chase_ratio_pct: if evaluated == 0 {
+                Decimal::ZERO
+            } else {
+                Decimal::from(chase.len()) / Decimal::from(evaluated) * Decimal::from(100)
+            },
+            chase: PatternGroupStats::from_trades(&chase),
+            normal: PatternGroupStats::from_trades(&normal_entry),
+        };
+
+        let streak = StreakStats {
+            max_same_direction_streak: max_streak,
+            reentry_after_loss_count: after_loss.len(),
+            reentry_after_loss: PatternGroupStats::from_trades(&after_loss),
+        };
+
+        // 패턴별로 손익이 나쁜 거래부터 예시로 선택
+        examples.sort_by_key(|e| e.pnl);
+        let mut per_kind: HashMap<EntryPatternKind, usize> = HashMap::new();
+        examples.retain(|e| {
+            let count = per_kind.entry(e.kind).or_default();
+            *count += 1;
+            *count <= self.config.max_examples
+        });
+
+        EntryPatternReport {
+            total_trades: trades.len(),
+            cooldown_recommended: is_worse(&reentry.quick, &reentry.normal),
+            confirmation_delay_recommended: is_worse(&chase_stats.chase, &chase_stats.normal),
+            reentry,
+            chase: chase_stats,
+            streak,
+            examples,
+        }
+    }
+
+    /// 진입 직전 `chase_lookback_bars` 캔들 동안의 가격 변화율 (%).
+    fn pre_entry_change_pct(&self, trade: &RoundTrip, klines: &[Kline]) -> Option<Decimal> {
+        let before: Vec<&Kline> = klines
+            .iter()
+            .filter(|k| k.close_time <= trade.entry_time)
+            .collect();
+        if before.len() <= self.config.chase_lookback_bars {
+            return None;
+        }
+
+        let base = before[before.len() - 1 - self.config.chase_lookback_bars].close;
+        if base.is_zero() {
+            return None;
+        }
+        Some((trade.entry_price - base) / base * Decimal::from(100))
+    }
+}
+
+/// 패턴 그룹이 비교 그룹보다 평균 수익률이 낮은지 (양쪽 최소 표본 충족 시).
+fn is_worse(pattern: &PatternGroupStats, baseline: &PatternGroupStats) -> bool {
+    pattern.count >= MIN_SAMPLE_SIZE
+        && baseline.count >= MIN_SAMPLE_SIZE
+        && pattern.avg_return_pct < baseline.avg_return_pct
+}
+
+fn example(kind: EntryPatternKind, trade: &RoundTrip, detail: &str) -> PatternExample {
+    PatternExample {
+        kind,
+        trade_id: trade.id,
+        symbol: trade.symbol.clone(),
+        entry_time: trade.entry_time,
+        return_pct: trade.return_pct,
+        pnl: trade.pnl,
+        detail: detail.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+    use trader_core::Timeframe;
+
+    use super::*;
+
+    fn trade(entry_min: i64, exit_min: i64, entry: Decimal, exit: Decimal) -> RoundTrip {
+        let base = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        RoundTrip::new(
+            "BTC/USDT",
+            Side::Buy,
+            entry,
+            exit,
+            dec!(1),
+            Decimal::ZERO,
+            base + Duration::minutes(entry_min),
+            base + Duration::minutes(exit_min),
+        )
+    }
+
+    #[test]
+    fn test_quick_reentry_worse_than_normal_recommends_cooldown() {
+        let trades = vec![
+            trade(0, 10, dec!(100), dec!(105)),
+            // 빠른 재진입 3건 (모두 손실)
+            trade(15, 20, dec!(100), dec!(98)),
+            trade(25, 30, dec!(100), dec!(97)),
+            trade(35, 40, dec!(100), dec!(99)),
+            // 일반 재진입 3건 (수익)
+            trade(200, 210, dec!(100), dec!(104)),
+            trade(400, 410, dec!(100), dec!(103)),
+            trade(600, 610, dec!(100), dec!(102)),
+        ];
+
+        let report = EntryPatternAnalyzer::default().analyze(&trades, &HashMap::new());
+
+        assert_eq!(report.reentry.reentry_count, 6);
+        assert_eq!(report.reentry.quick.count, 3);
+        assert_eq!(report.reentry.normal.count, 3);
+        assert_eq!(report.reentry.min_gap_secs, Some(300));
+        assert!(report.cooldown_recommended);
+        // 손실 직후 같은 방향 빠른 재진입: 2번째→3번째, 3번째→4번째
+        assert_eq!(report.streak.reentry_after_loss_count, 2);
+        assert_eq!(report.streak.max_same_direction_streak, 7);
+        assert!(report
+            .examples
+            .iter()
+            .any(|e| e.kind == EntryPatternKind::QuickReentry));
+    }
+
+    #[test]
+    fn test_chase_detection() {
+        let base = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        // 1분봉 10개, 100 → 109 상승
+        let klines: Vec<Kline> = (0..10)
+            .map(|i| {
+                let price = dec!(100) + Decimal::from(i);
+                let open_time = base + Duration::minutes(i);
+                Kline::new(
+                    "BTC/USDT".to_string(),
+                    Timeframe::M1,
+                    open_time,
+                    price,
+                    price,
+                    price,
+                    price,
+                    dec!(1),
+                    open_time + Duration::minutes(1),
+                )
+            })
+            .collect();
+        let klines_by_symbol = HashMap::from([("BTC/USDT".to_string(), klines)]);
+
+        // 10분 시점 109에 진입: 5봉 전 종가 104 대비 +4.8% → 추격
+        let chase_trade = trade(10, 20, dec!(109), dec!(105));
+        // 3분 시점 진입: 참조 캔들 부족 → 평가 제외
+        let early_trade = trade(3, 5, dec!(102), dec!(103));
+
+        let report =
+            EntryPatternAnalyzer::default().analyze(&[chase_trade, early_trade], &klines_by_symbol);
+
+        assert_eq!(report.chase.evaluated_count, 1);
+        assert_eq!(report.chase.chase.count, 1);
+        assert_eq!(report.chase.chase_ratio_pct, dec!(100));
+        assert!(!report.confirmation_delay_recommended);
+        assert!(report.summary().contains("추격매수 비율"));
+    }
+}
@@ -0,0 +1,245 @@
+//! 거래별 MAE/MFE 및 보유 기간 분포
+//!
+//! 완료된 거래(RoundTrip)의 최대역행(MAE), 최대순행(MFE), 보유 캔들 수를 모아
+//! 평균·중앙값·히스토그램으로 요약합니다.
+//!
+//! # 활용
+//!
+//! - **손절 위치**: 수익 거래의 MAE 분포가 대부분 -2% 이내라면, 그보다 깊은 손절은
+//!   회복하지 못할 거래를 오래 들고 있는 셈입니다.
+//! - **익절 위치**: 손실 거래의 MFE가 크다면 익절/추적 손절로 수익을 지킬 여지가 있습니다.
+//!
+//! MAE/MFE는 거래 규모와 무관하게 비교할 수 있도록 진입 명목 가치 대비 %로 집계합니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::metrics::RoundTrip;
+
+/// 히스토그램 기본 구간 수
+pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 10;
+
+/// 히스토그램 구간
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistogramBucket {
+    /// 구간 하한 (포함)
+    pub lower: Decimal,
+    /// 구간 상한 (마지막 구간만 포함)
+    pub upper: Decimal,
+    /// 구간에 속한 거래 수
+    pub count: usize,
+}
+
+/// 값 분포 요약
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DistributionSummary {
+    /// 평균
+    pub mean: Decimal,
+    /// 중앙값
+    pub median: Decimal,
+    /// 최소값
+    pub min: Decimal,
+    /// 최대값
+    pub max: Decimal,
+    /// 등간격 히스토그램 (최소~최대)
+    pub histogram: Vec<HistogramBucket>,
+}
+
+impl DistributionSummary {
+    /// 값 목록으로 분포를 계산합니다. 값이 없으면 기본값(모두 0)입니다.
+    pub fn from_values(values: &[Decimal], buckets: usize) -> Self {
+        if values.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = values.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let min = sorted[0];
+        let max = sorted[n - 1];
+        let median = if n % 2 == 0 {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / Decimal::TWO
+        } else {
+            sorted[n / 2]
+        };
+
+        Self {
+            mean: sorted.iter().sum::<Decimal>() / Decimal::from(n),
+            median,
+            min,
+            max,
+            histogram: histogram(&sorted, min, max, buckets.max(1)),
+        }
+    }
+}
+
+/// 최소~최대를 등간격으로 나눈 히스토그램 (모든 값이 같으면 단일 구간).
+fn histogram(
+    values: &[Decimal],
+    min: Decimal,
+    max: Decimal,
+    buckets: usize,
+) -> Vec<HistogramBucket> {
+    if min == max {
+        return vec![HistogramBucket {
+            lower: min,
+            upper: max,
+            count: values.len(),
+        }];
+    }
+
+    let width = (max - min) / Decimal::from(buckets);
+    let mut result: Vec<HistogramBucket> = (0..buckets)
+        .map(|i| HistogramBucket {
+            lower: min + width * Decimal::from(i),
+            upper: if i + 1 == buckets {
+                max
+            } else {
+                min + width * Decimal::from(i + 1)
+            },
+            count: 0,
+        })
+        .collect();
+
+    for value in values {
+        let idx = result
+            .iter()
+            .position(|b| *value < b.upper)
+            .unwrap_or(buckets - 1);
+        result[idx].count += 1;
+    }
+    result
+}
+
+/// 거래별 MAE/MFE 및 보유 기간 분포
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExcursionAnalysis {
+    /// 분석한 거래 수
+    pub trade_count: usize,
+    /// MAE 분포 (진입 명목 가치 대비 %, 0 이하)
+    pub mae_pct: DistributionSummary,
+    /// MFE 분포 (진입 명목 가치 대비 %, 0 이상)
+    pub mfe_pct: DistributionSummary,
+    /// 보유 캔들 수 분포
+    pub holding_bars: DistributionSummary,
+    /// 수익 거래의 MAE 분포 (손절 위치 판단용)
+    pub winners_mae_pct: DistributionSummary,
+    /// 손실 거래의 MFE 분포 (익절 위치 판단용)
+    pub losers_mfe_pct: DistributionSummary,
+}
+
+impl ExcursionAnalysis {
+    /// 완료된 거래로 분포를 계산합니다 (기본 히스토그램 구간 수).
+    pub fn from_round_trips(trades: &[RoundTrip]) -> Self {
+        Self::with_buckets(trades, DEFAULT_HISTOGRAM_BUCKETS)
+    }
+
+    /// 히스토그램 구간 수를 지정하여 분포를 계산합니다.
+    pub fn with_buckets(trades: &[RoundTrip], buckets: usize) -> Self {
+        let collect = |filter: fn(&RoundTrip) -> bool, value: fn(&RoundTrip) -> Decimal| {
+            let values: Vec<Decimal> = trades.iter().filter(|t| filter(t)).map(value).collect();
+            DistributionSummary::from_values(&values, buckets)
+        };
+
+        Self {
+            trade_count: trades.len(),
+            mae_pct: collect(|_| true, RoundTrip::mae_pct),
+            mfe_pct: collect(|_| true, RoundTrip::mfe_pct),
+            holding_bars: collect(|_| true, |t| Decimal::from(t.holding_bars)),
+            winners_mae_pct: collect(RoundTrip::is_winner, RoundTrip::mae_pct),
+            losers_mfe_pct: collect(|t| !t.is_winner(), RoundTrip::mfe_pct),
+        }
+    }
+
+    /// 요약 문자열
+    pub fn summary(&self) -> String {
+        format!(
+            "MAE/MFE 분포 ({} 거래)\n\
+             ───────────────────────────────────────\n\
+             MAE: 평균 {:.2}% / 중앙값 {:.2}% / 최저 {:.2}%\n\
+             MFE: 평균 {:.2}% / 중앙값 {:.2}% / 최고 {:.2}%\n\
+             수익 거래 MAE 중앙값: {:.2}%\n\
+             손실 거래 MFE 중앙값: {:.2}%\n\
+             보유 기간: 평균 {:.1} / 중앙값 {:.1} / 최대 {} 캔들",
+            self.trade_count,
+            self.mae_pct.mean,
+            self.mae_pct.median,
+            self.mae_pct.min,
+            self.mfe_pct.mean,
+            self.mfe_pct.median,
+            self.mfe_pct.max,
+            self.winners_mae_pct.median,
+            self.losers_mfe_pct.median,
+            self.holding_bars.mean,
+            self.holding_bars.median,
+            self.holding_bars.max,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+
+    use super::*;
+
+    fn trade(exit: Decimal, low: Decimal, high: Decimal, bars: usize) -> RoundTrip {
+        let now = Utc::now();
+        RoundTrip::new(
+            "BTC/USDT",
+            Side::Buy,
+            dec!(100),
+            exit,
+            dec!(1),
+            dec!(0),
+            now,
+            now,
+        )
+        .with_excursion(low, high, bars)
+    }
+
+    #[test]
+    fn test_distribution_summary() {
+        let summary = DistributionSummary::from_values(&[dec!(4), dec!(1), dec!(3), dec!(2)], 3);
+        assert_eq!(summary.mean, dec!(2.5));
+        assert_eq!(summary.median, dec!(2.5));
+        assert_eq!(summary.min, dec!(1));
+        assert_eq!(summary.max, dec!(4));
+        let counts: Vec<usize> = summary.histogram.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![1, 1, 2]);
+        assert_eq!(summary.histogram.last().unwrap().upper, dec!(4));
+    }
+
+    #[test]
+    fn test_distribution_single_value() {
+        let summary = DistributionSummary::from_values(&[dec!(5), dec!(5)], 10);
+        assert_eq!(summary.histogram.len(), 1);
+        assert_eq!(summary.histogram[0].count, 2);
+        assert_eq!(
+            DistributionSummary::from_values(&[], 10),
+            DistributionSummary::default()
+        );
+    }
+
+    #[test]
+    fn test_excursion_analysis() {
+        let trades = vec![
+            trade(dec!(110), dec!(98), dec!(112), 3), // 수익: MAE -2%, MFE 12%
+            trade(dec!(105), dec!(96), dec!(106), 5), // 수익: MAE -4%, MFE 6%
+            trade(dec!(90), dec!(88), dec!(104), 2),  // 손실: MAE -12%, MFE 4%
+        ];
+
+        let analysis = ExcursionAnalysis::from_round_trips(&trades);
+
+        assert_eq!(analysis.trade_count, 3);
+        assert_eq!(analysis.mae_pct.min, dec!(-12));
+        assert_eq!(analysis.mae_pct.median, dec!(-4));
+        assert_eq!(analysis.mfe_pct.max, dec!(12));
+        assert_eq!(analysis.winners_mae_pct.median, dec!(-3));
+        assert_eq!(analysis.losers_mfe_pct.median, dec!(4));
+        assert_eq!(analysis.holding_bars.median, dec!(3));
+        assert!(analysis.summary().contains("3 거래"));
+    }
+}
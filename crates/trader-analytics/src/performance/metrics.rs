@@ -4,6 +4,8 @@
 //! - 샤프 비율 (Sharpe Ratio): 위험 대비 수익률 측정
 //! - 소르티노 비율 (Sortino Ratio): 하방 위험 대비 수익률 측정
 //! - 최대 낙폭 (Maximum Drawdown): 고점 대비 최대 하락폭
+//! - 칼마 비율 (Calmar Ratio): 최대 낙폭 대비 연율화 수익률
+//! - 얼서 지수 (Ulcer Index): 낙폭의 깊이와 지속 기간을 함께 반영한 하방 위험
 //! - 승률 (Win Rate): 수익 거래 비율
 //! - 프로핏 팩터 (Profit Factor): 총 수익 / 총 손실 비율
 //! - 기대값 (Expectancy): 거래당 기대 수익
@@ -27,6 +29,12 @@
 //! println!("승률: {}%", metrics.win_rate_pct);
 //! println!("샤프 비율: {}", metrics.sharpe_ratio);
 //! ```
+//!
+//! # 표본 부족 처리 규칙
+//!
+//! 비율 지표(샤프/소르티노/칼마)와 얼서 지수는 표본이 [`MIN_SAMPLES_FOR_RISK_METRICS`]개
+//! 미만이면 통계적으로 의미가 없으므로 `0`으로 보고합니다. `None` 대신 `0`을 쓰는 이유는
+//! 리포트/DB 스키마의 지표 필드가 모두 `Decimal`이기 때문이며, `0`은 "계산 불가"를 의미합니다.
 
 use std::collections::VecDeque;
 
@@ -48,6 +56,12 @@ pub const TRADING_DAYS_PER_YEAR: u32 = 252;
 /// 미국 국채 수익률이나 한국 국채 수익률 등을 참고하여 설정합니다.
 pub const DEFAULT_RISK_FREE_RATE: f64 = 0.05;
 
+/// 위험 지표 계산에 필요한 최소 표본 수
+///
+/// 샤프/소르티노는 수익률 개수, 얼서 지수는 자산 곡선 포인트 수 기준입니다.
+/// 이보다 적으면 해당 지표는 `0`(계산 불가)으로 처리합니다.
+pub const MIN_SAMPLES_FOR_RISK_METRICS: usize = 2;
+
 /// 라운드트립 거래 (진입부터 청산까지)
 ///
 /// 하나의 완전한 거래 사이클을 나타냅니다.
@@ -108,6 +122,20 @@ pub struct RoundTrip {
     /// 예: "익절 청산 (4%)", "손절 청산 (2%)"
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exit_reason: Option<String>,
+
+    /// 최대역행 (MAE, Maximum Adverse Excursion)
+    /// 보유 기간 중 가장 낮았던 평가손익 (0 이하)
+    #[serde(default)]
+    pub mae: Decimal,
+
+    /// 최대순행 (MFE, Maximum Favorable Excursion)
+    /// 보유 기간 중 가장 높았던 평가손익 (0 이상)
+    #[serde(default)]
+    pub mfe: Decimal,
+
+    /// 보유 캔들 수 (진입 이후 관측된 캔들 수)
+    #[serde(default)]
+    pub holding_bars: usize,
 }
 
 impl RoundTrip {
@@ -171,6 +199,9 @@ impl RoundTrip {
             strategy_id: None,
             entry_reason: None,
             exit_reason: None,
+            mae: Decimal::ZERO,
+            mfe: Decimal::ZERO,
+            holding_bars: 0,
         }
     }
 
@@ -202,6 +233,45 @@ impl RoundTrip {
         self
     }
 
+    /// 보유 기간 중 최저/최고 가격으로 MAE/MFE와 보유 캔들 수를 설정합니다.
+    ///
+    /// 진입가와 청산가도 경로에 포함되므로 MAE는 0 이하, MFE는 0 이상입니다.
+    pub fn with_excursion(
+        mut self,
+        lowest_price: Decimal,
+        highest_price: Decimal,
+        holding_bars: usize,
+    ) -> Self {
+        let lowest = lowest_price.min(self.entry_price).min(self.exit_price);
+        let highest = highest_price.max(self.entry_price).max(self.exit_price);
+        let (worst, best) = match self.side {
+            Side::Buy => (lowest, highest),
+            Side::Sell => (highest, lowest),
+        };
+        self.mae = realized_pnl(self.entry_price, worst, self.quantity, self.side);
+        self.mfe = realized_pnl(self.entry_price, best, self.quantity, self.side);
+        self.holding_bars = holding_bars;
+        self
+    }
+
+    /// 진입 명목 가치 대비 MAE (%)
+    pub fn mae_pct(&self) -> Decimal {
+        self.excursion_pct(self.mae)
+    }
+
+    /// 진입 명목 가치 대비 MFE (%)
+    pub fn mfe_pct(&self) -> Decimal {
+        self.excursion_pct(self.mfe)
+    }
+
+    fn excursion_pct(&self, excursion: Decimal) -> Decimal {
+        let notional = self.entry_notional();
+        if notional.is_zero() {
+            return Decimal::ZERO;
+        }
+        excursion / notional * Decimal::from(100)
+    }
+
     /// 수익률을 백분율로 계산합니다.
     ///
     /// ## 계산 공식
@@ -300,6 +370,8 @@ impl TradeInfo for RoundTrip {
 /// - `max_drawdown_pct`: 최대 낙폭 (고점 대비 최대 하락률)
 /// - `sharpe_ratio`: 샤프 비율 (위험 대비 수익)
 /// - `sortino_ratio`: 소르티노 비율 (하방 위험 대비 수익)
+/// - `calmar_ratio`: 칼마 비율 (최대 낙폭 대비 연율화 수익)
+/// - `ulcer_index`: 얼서 지수 (낙폭의 깊이 × 지속 기간)
 ///
 /// ## 거래 효율성 지표
 /// - `win_rate_pct`: 승률
@@ -424,8 +496,18 @@ pub struct PerformanceMetrics {
     ///
     /// 낙폭 대비 수익률을 측정합니다.
     /// 높을수록 효율적인 위험-수익 관계입니다.
+    /// 최대 낙폭이 0이면 0으로 보고합니다.
     pub calmar_ratio: Decimal,
 
+    /// 얼서 지수 (Ulcer Index) (%)
+    ///
+    /// 공식: √(Σ 낙폭%² / N)
+    ///
+    /// 최대 낙폭과 달리 낙폭이 얼마나 깊고 오래 지속되었는지를 함께 반영합니다.
+    /// 낮을수록 보유 중 심리적 고통이 적은 전략입니다.
+    #[serde(default)]
+    pub ulcer_index: Decimal,
+
     /// 회복 계수 (Recovery Factor)
     ///
     /// 순수익 / 최대 낙폭 금액
@@ -537,11 +619,10 @@ impl PerformanceMetrics {
         let sortino_ratio = Self::calculate_sortino_ratio(&returns, rf_rate, trading_days);
 
         // === 칼마 비율 ===
-        let calmar_ratio = if max_drawdown_pct > Decimal::ZERO {
-            annualized_return_pct / max_drawdown_pct
-        } else {
-            Decimal::ZERO
-        };
+        let calmar_ratio = Self::calculate_calmar_ratio(annualized_return_pct, max_drawdown_pct);
+
+        // === 얼서 지수 ===
+        let ulcer_index = Self::calculate_ulcer_index(&equity_curve);
 
         // === 회복 계수 ===
         let max_drawdown_value =
@@ -585,12 +666,25 @@ impl PerformanceMetrics {
             total_fees: stats.total_fees,
             net_profit,
             calmar_ratio,
+            ulcer_index,
             recovery_factor,
             avg_return_per_trade,
             expectancy: stats.expectancy,
         }
     }
 
+    /// 낙폭 기반 지표를 주어진 자산 곡선으로 다시 계산합니다.
+    ///
+    /// `from_round_trips`는 청산 시점 자산만으로 낙폭을 추정하므로, 백테스트처럼
+    /// 캔들마다 미실현 손익을 반영한 자산 곡선이 있으면 이 메서드로 최대 낙폭,
+    /// 칼마 비율, 얼서 지수를 교체합니다.
+    pub fn apply_equity_curve(&mut self, equity_curve: &[Decimal]) {
+        self.max_drawdown_pct = Self::calculate_max_drawdown(equity_curve);
+        self.calmar_ratio =
+            Self::calculate_calmar_ratio(self.annualized_return_pct, self.max_drawdown_pct);
+        self.ulcer_index = Self::calculate_ulcer_index(equity_curve);
+    }
+
     /// 라운드트립 거래의 총 거래일 수를 계산합니다.
     ///
     /// 첫 번째 진입부터 마지막 청산까지의 일수를 반환합니다.
@@ -683,6 +777,51 @@ impl PerformanceMetrics {
         max_drawdown
     }
 
+    /// 칼마 비율을 계산합니다.
+    ///
+    /// Calmar = 연율화 수익률(%) / 최대 낙폭(%). 최대 낙폭이 0이면 0을 반환합니다.
+    pub fn calculate_calmar_ratio(
+        annualized_return_pct: Decimal,
+        max_drawdown_pct: Decimal,
+    ) -> Decimal {
+        if max_drawdown_pct > Decimal::ZERO {
+            annualized_return_pct / max_drawdown_pct
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    /// 자산 곡선에서 얼서 지수(Ulcer Index)를 계산합니다.
+    ///
+    /// # 계산 공식
+    ///
+    /// UI = √(Σ Dᵢ² / N), Dᵢ = (누적 고점 - 자산ᵢ) / 누적 고점 × 100
+    ///
+    /// # 예시
+    ///
+    /// 자산이 100 → 80 → 100 → 100이면 낙폭은 0, 20, 0, 0%
+    /// UI = √(400 / 4) = 10
+    pub fn calculate_ulcer_index(equity_curve: &[Decimal]) -> Decimal {
+        if equity_curve.len() < MIN_SAMPLES_FOR_RISK_METRICS {
+            return Decimal::ZERO;
+        }
+
+        let mut peak = equity_curve[0];
+        let mut squared_sum = Decimal::ZERO;
+
+        for &equity in equity_curve {
+            if equity > peak {
+                peak = equity;
+            }
+            if peak > Decimal::ZERO {
+                let drawdown = (peak - equity) / peak * Decimal::from(100);
+                squared_sum += drawdown * drawdown;
+            }
+        }
+
+        Self::decimal_sqrt(squared_sum / Decimal::from(equity_curve.len()))
+    }
+
     /// 샤프 비율을 계산합니다.
     ///
     /// 샤프 비율은 위험(표준편차) 한 단위당 초과 수익을 측정합니다.
@@ -708,7 +847,7 @@ impl PerformanceMetrics {
         trading_days: u32,
     ) -> Decimal {
         // 최소 2개의 수익률 데이터 필요 (표준편차 계산용)
-        if returns.len() < 2 {
+        if returns.len() < MIN_SAMPLES_FOR_RISK_METRICS {
             return Decimal::ZERO;
         }
 
@@ -768,7 +907,7 @@ impl PerformanceMetrics {
         risk_free_rate: f64,
         trading_days: u32,
     ) -> Decimal {
-        if returns.len() < 2 {
+        if returns.len() < MIN_SAMPLES_FOR_RISK_METRICS {
             return Decimal::ZERO;
         }
 
@@ -1267,6 +1406,87 @@ mod tests {
         assert!(sortino != Decimal::ZERO);
     }
 
+    #[test]
+    fn test_sortino_uses_same_sampling_as_sharpe() {
+        let round_trips = create_test_round_trips();
+        let metrics = PerformanceMetrics::from_round_trips(&round_trips, dec!(10000), Some(0.03));
+
+        // 두 지표 모두 라운드트립 수익률 계열과 같은 거래 기간으로 계산
+        let returns: Vec<Decimal> = round_trips.iter().map(|rt| rt.return_pct).collect();
+        let trading_days = PerformanceMetrics::calculate_trading_days(&round_trips);
+        assert_eq!(
+            metrics.sharpe_ratio,
+            PerformanceMetrics::calculate_sharpe_ratio(&returns, 0.03, trading_days)
+        );
+        assert_eq!(
+            metrics.sortino_ratio,
+            PerformanceMetrics::calculate_sortino_ratio(&returns, 0.03, trading_days)
+        );
+
+        // 연율화 계수(√거래일)도 동일: 기간이 1/4이면 두 지표 모두 0.5배
+        let returns = vec![dec!(2.0), dec!(-1.0), dec!(1.5), dec!(0.5), dec!(-0.5)];
+        let sharpe_ratio = PerformanceMetrics::calculate_sharpe_ratio(&returns, 0.0, 63)
+            / PerformanceMetrics::calculate_sharpe_ratio(&returns, 0.0, 252);
+        let sortino_ratio = PerformanceMetrics::calculate_sortino_ratio(&returns, 0.0, 63)
+            / PerformanceMetrics::calculate_sortino_ratio(&returns, 0.0, 252);
+        assert!((sharpe_ratio - dec!(0.5)).abs() < dec!(0.0001));
+        assert!((sortino_ratio - dec!(0.5)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_risk_metrics_insufficient_samples() {
+        // 표본이 부족하면 0 (계산 불가)
+        let single = vec![dec!(5.0)];
+        assert_eq!(
+            PerformanceMetrics::calculate_sharpe_ratio(&single, 0.05, 252),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            PerformanceMetrics::calculate_sortino_ratio(&single, 0.05, 252),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            PerformanceMetrics::calculate_ulcer_index(&[dec!(10000)]),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            PerformanceMetrics::calculate_calmar_ratio(dec!(12), Decimal::ZERO),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn test_ulcer_index() {
+        // 낙폭: 0, 20, 0, 0% → √(400 / 4) = 10
+        let curve = vec![dec!(100), dec!(80), dec!(100), dec!(100)];
+        let ulcer = PerformanceMetrics::calculate_ulcer_index(&curve);
+        assert!((ulcer - dec!(10)).abs() < dec!(0.0001));
+
+        // 낙폭이 오래 지속될수록 MDD가 같아도 얼서 지수는 커짐
+        let prolonged = vec![dec!(100), dec!(80), dec!(80), dec!(80)];
+        assert!(PerformanceMetrics::calculate_ulcer_index(&prolonged) > ulcer);
+        assert_eq!(
+            PerformanceMetrics::calculate_max_drawdown(&prolonged),
+            PerformanceMetrics::calculate_max_drawdown(&curve)
+        );
+    }
+
+    #[test]
+    fn test_apply_equity_curve() {
+        let round_trips = create_test_round_trips();
+        let mut metrics = PerformanceMetrics::from_round_trips(&round_trips, dec!(10000), None);
+
+        let curve = vec![dec!(10000), dec!(8000), dec!(10000), dec!(10500)];
+        metrics.apply_equity_curve(&curve);
+
+        assert_eq!(metrics.max_drawdown_pct, dec!(20));
+        assert_eq!(
+            metrics.calmar_ratio,
+            metrics.annualized_return_pct / dec!(20)
+        );
+        assert!(metrics.ulcer_index > Decimal::ZERO);
+    }
+
     #[test]
     fn test_rolling_metrics() {
         let mut rolling = RollingMetrics::new(5, dec!(10000));
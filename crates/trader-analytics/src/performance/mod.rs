@@ -6,9 +6,15 @@
 //!
 //! - [`metrics`]: 성과 지표 계산 (샤프비율, 최대낙폭, 승률 등)
 //! - [`tracker`]: 실시간 성과 추적 및 이벤트 발생
+//! - [`entry_pattern`]: 재진입/추격매수 패턴 분석
+//! - [`excursion`]: 거래별 MAE/MFE 및 보유 기간 분포
 
+pub mod entry_pattern;
+pub mod excursion;
 pub mod metrics;
 pub mod tracker;
 
+pub use entry_pattern::*;
+pub use excursion::*;
 pub use metrics::*;
 pub use tracker::*;
@@ -141,6 +141,12 @@ struct OpenPosition {
     strategy_id: Option<String>,
     /// 진입 이유 (전략의 논리적 목적)
     entry_reason: Option<String>,
+    /// 보유 중 최저 가격 (MAE/MFE 계산용)
+    lowest_price: Decimal,
+    /// 보유 중 최고 가격 (MAE/MFE 계산용)
+    highest_price: Decimal,
+    /// 진입 이후 관측된 캔들 수
+    bars_held: usize,
 }
 
 /// 자산 곡선의 한 지점
@@ -439,6 +445,24 @@ impl PerformanceTracker {
         self.cleanup_old_data();
     }
 
+    /// 보유 중인 포지션의 가격 경로를 캔들 단위로 갱신합니다 (백테스팅용).
+    ///
+    /// `price_range`는 심볼의 현재 캔들 (고가, 저가)를 반환하며, None이면 해당
+    /// 심볼은 이번 캔들에서 관측하지 않습니다. 캔들마다 한 번, 신호 처리 전에
+    /// 호출해야 진입 캔들의 진입 전 가격이 MAE/MFE에 섞이지 않습니다.
+    pub fn observe_bar<F>(&mut self, price_range: F)
+    where
+        F: Fn(&str) -> Option<(Decimal, Decimal)>,
+    {
+        for position in self.open_positions.values_mut().flatten() {
+            if let Some((high, low)) = price_range(&position.symbol) {
+                position.highest_price = position.highest_price.max(high);
+                position.lowest_price = position.lowest_price.min(low);
+                position.bars_held += 1;
+            }
+        }
+    }
+
     /// 초기 시간을 설정합니다 (백테스팅용)
     ///
     /// 첫 번째 equity point의 timestamp를 백테스트 시작 시간으로 설정합니다.
@@ -639,6 +663,9 @@ impl PerformanceTracker {
             entry_time: trade.executed_at,
             strategy_id,
             entry_reason,
+            lowest_price: trade.price,
+            highest_price: trade.price,
+            bars_held: 0,
         };
 
         self.open_positions
@@ -702,9 +729,14 @@ impl PerformanceTracker {
             round_trip
         };
 
-        // 진입/청산 이유 추가
-        let round_trip =
-            round_trip.with_reasons(open_position.entry_reason.clone(), exit_reason.clone());
+        // 진입/청산 이유 및 보유 중 가격 경로(MAE/MFE) 추가
+        let round_trip = round_trip
+            .with_reasons(open_position.entry_reason.clone(), exit_reason.clone())
+            .with_excursion(
+                open_position.lowest_price,
+                open_position.highest_price,
+                open_position.bars_held,
+            );
 
         // 디버그: RoundTrip reason 확인
         tracing::debug!(
@@ -949,6 +981,55 @@ mod tests {
         assert_eq!(round_trip.pnl, dec!(190));
     }
 
+    #[test]
+    fn test_excursion_tracked_per_position() {
+        let mut tracker = PerformanceTracker::new(dec!(100000));
+
+        // BTC 롱, ETH 숏 동시 보유
+        let btc_entry = create_test_trade(Side::Buy, dec!(100), dec!(10), dec!(0));
+        let mut eth_entry = create_test_trade(Side::Sell, dec!(50), dec!(20), dec!(0));
+        eth_entry.ticker = "ETH/USDT".to_string();
+        tracker.record_trade(&btc_entry, true, None).unwrap();
+        tracker.record_trade(&eth_entry, true, None).unwrap();
+
+        let bars = [
+            // (BTC 고가, 저가), (ETH 고가, 저가)
+            ((dec!(105), dec!(92)), (dec!(53), dec!(48))),
+            ((dec!(112), dec!(101)), (dec!(51), dec!(45))),
+        ];
+        for (btc, eth) in bars {
+            tracker.observe_bar(|symbol| match symbol {
+                "BTC/USDT" => Some(btc),
+                "ETH/USDT" => Some(eth),
+                _ => None,
+            });
+        }
+
+        let btc_exit = create_test_trade(Side::Sell, dec!(110), dec!(10), dec!(0));
+        let btc_trip = tracker
+            .record_trade(&btc_exit, false, None)
+            .unwrap()
+            .unwrap();
+        // 롱: 저가 92 → MAE (92-100)×10, 고가 112 → MFE (112-100)×10
+        assert_eq!(btc_trip.mae, dec!(-80));
+        assert_eq!(btc_trip.mfe, dec!(120));
+        assert_eq!(btc_trip.holding_bars, 2);
+        assert_eq!(btc_trip.mae_pct(), dec!(-8));
+
+        // ETH는 한 캔들 더 보유 (BTC 가격 경로와 섞이지 않음)
+        tracker.observe_bar(|symbol| (symbol == "ETH/USDT").then_some((dec!(55), dec!(49))));
+        let mut eth_exit = create_test_trade(Side::Buy, dec!(49), dec!(20), dec!(0));
+        eth_exit.ticker = "ETH/USDT".to_string();
+        let eth_trip = tracker
+            .record_trade(&eth_exit, false, None)
+            .unwrap()
+            .unwrap();
+        // 숏: 고가 55 → MAE (50-55)×20, 저가 45 → MFE (50-45)×20
+        assert_eq!(eth_trip.mae, dec!(-100));
+        assert_eq!(eth_trip.mfe, dec!(100));
+        assert_eq!(eth_trip.holding_bars, 3);
+    }
+
     #[test]
     fn test_no_matching_entry() {
         let mut tracker = PerformanceTracker::new(dec!(10000));
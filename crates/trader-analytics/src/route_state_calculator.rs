@@ -530,6 +530,7 @@ mod tests {
                 close_time: now + chrono::Duration::days(1),
                 quote_volume: None,
                 num_trades: None,
+                synthetic: false,
             });
         }
 
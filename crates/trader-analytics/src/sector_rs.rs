@@ -329,6 +329,7 @@ mod tests {
                 close_time: Utc::now(),
                 quote_volume: Some(dec!(0)),
                 num_trades: Some(100),
+                synthetic: false,
             })
             .collect()
     }
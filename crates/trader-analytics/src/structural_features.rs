@@ -255,6 +255,7 @@ mod tests {
                     volume: dec!(1000),
                     quote_volume: Some(dec!(0)),
                     num_trades: Some(0),
+                    synthetic: false,
                 }
             })
             .collect()
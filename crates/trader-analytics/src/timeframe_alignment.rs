@@ -225,6 +225,7 @@ mod tests {
             close_time,
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         }
     }
 
@@ -315,6 +315,7 @@ mod tests {
             close_time: time + chrono::Duration::days(1),
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         }
     }
 
@@ -336,6 +336,7 @@ mod tests {
                 close_time: base_time + chrono::Duration::days(1),
                 quote_volume: Some(dec!(0)),
                 num_trades: Some(100),
+                synthetic: false,
             },
             Kline {
                 ticker: "TEST".to_string(),
@@ -349,6 +350,7 @@ mod tests {
                 close_time: base_time + chrono::Duration::days(2),
                 quote_volume: Some(dec!(0)),
                 num_trades: Some(150),
+                synthetic: false,
             },
             Kline {
                 ticker: "TEST".to_string(),
@@ -362,6 +364,7 @@ mod tests {
                 close_time: base_time + chrono::Duration::days(3),
                 quote_volume: Some(dec!(0)),
                 num_trades: Some(200),
+                synthetic: false,
             },
             Kline {
                 ticker: "TEST".to_string(),
@@ -375,6 +378,7 @@ mod tests {
                 close_time: base_time + chrono::Duration::days(4),
                 quote_volume: Some(dec!(0)),
                 num_trades: Some(180),
+                synthetic: false,
             },
         ]
     }
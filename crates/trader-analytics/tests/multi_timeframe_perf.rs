@@ -90,6 +90,7 @@ fn test_grouping_performance() {
                 .unwrap(),
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         };
         flat_data.push((tf.to_string(), kline));
     }
@@ -199,6 +200,7 @@ fn create_mock_klines(timeframe: Timeframe, count: usize) -> Vec<Kline> {
                 close_time,
                 quote_volume: None,
                 num_trades: None,
+                synthetic: false,
             }
         })
         .collect()
@@ -23,4 +23,16 @@ spreadMultiplier?: number,
 /**
  * 호가창 기본 잔량 (기본 100)
  */
-orderbookBaseVolume?: number, };
+orderbookBaseVolume?: number, 
+/**
+ * 변동성 레짐 프리셋 (RandomWalk 전용, "stress_cycle")
+ */
+regimePreset?: string, 
+/**
+ * 난수 시드 (RandomWalk 전용, 같은 시드면 같은 가격 경로)
+ */
+seed?: number, 
+/**
+ * 시장가 잔량 최대 대기 틱 수 (기본 60, 0이면 무제한)
+ */
+maxWaitTicks?: number, };
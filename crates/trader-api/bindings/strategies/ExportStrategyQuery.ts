@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 전략 내보내기 쿼리.
+ */
+export type ExportStrategyQuery = { 
+/**
+ * 최근 백테스트 성과 요약 포함 여부
+ */
+includeBacktest: boolean, };
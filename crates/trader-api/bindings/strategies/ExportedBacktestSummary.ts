@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 내보내기 문서에 포함되는 백테스트 성과 요약.
+ */
+export type ExportedBacktestSummary = { 
+/**
+ * 백테스트 결과 ID
+ */
+backtest_id: string, 
+/**
+ * 백테스트 심볼
+ */
+symbol: string, 
+/**
+ * 시작 날짜
+ */
+start_date: string, 
+/**
+ * 종료 날짜
+ */
+end_date: string, 
+/**
+ * 초기 자본 (Decimal 문자열)
+ */
+initial_capital: string, 
+/**
+ * 성과 지표
+ */
+metrics: Record<string, unknown>, 
+/**
+ * 백테스트 실행 시각 (RFC3339)
+ */
+created_at: string, };
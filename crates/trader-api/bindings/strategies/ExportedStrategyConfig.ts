@@ -0,0 +1,50 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 내보낸 전략 설정 본문.
+ */
+export type ExportedStrategyConfig = { 
+/**
+ * 전략 이름
+ */
+name: string, 
+/**
+ * 전략 설명
+ */
+description?: string, 
+/**
+ * 전략 타입 (예: "grid_trading", "rsi")
+ */
+strategy_type: string, 
+/**
+ * 거래 심볼 목록
+ */
+symbols: Array<string>, 
+/**
+ * 대상 시장 (KR/US/CRYPTO)
+ */
+market: string, 
+/**
+ * 타임프레임
+ */
+timeframe: string, 
+/**
+ * 전략 파라미터 (민감 정보 제외)
+ */
+config: Record<string, unknown>, 
+/**
+ * 리스크 설정
+ */
+risk_config: Record<string, unknown> | null, 
+/**
+ * 할당 자본 (Decimal 문자열)
+ */
+allocated_capital?: string, 
+/**
+ * 리스크 프로필
+ */
+risk_profile?: string, 
+/**
+ * 다중 타임프레임 설정
+ */
+multi_timeframe_config: Record<string, unknown> | null, };
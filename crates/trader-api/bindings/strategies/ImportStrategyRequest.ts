@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NameConflictPolicy } from "./NameConflictPolicy";
+import type { StrategyExportDocument } from "./StrategyExportDocument";
+
+/**
+ * 전략 가져오기 요청.
+ */
+export type ImportStrategyRequest = { 
+/**
+ * 내보내기 문서
+ */
+document: StrategyExportDocument, 
+/**
+ * 새 전략 이름 (옵션, 없으면 문서의 이름 사용)
+ */
+name?: string, 
+/**
+ * 이름 충돌 처리 방식
+ */
+on_name_conflict: NameConflictPolicy, 
+/**
+ * 연결할 거래소 계정 ID (내보내기에서 제외되므로 필요 시 직접 지정)
+ */
+credentialId?: string, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 전략 가져오기 응답.
+ */
+export type ImportStrategyResponse = { 
+/**
+ * 성공 여부
+ */
+success: boolean, 
+/**
+ * 생성된 전략 ID
+ */
+strategy_id: string, 
+/**
+ * 최종 전략 이름
+ */
+name: string, 
+/**
+ * 이름 충돌로 이름이 변경되었는지 여부
+ */
+renamed: boolean, 
+/**
+ * 가져오기 경고 (민감 정보 제거, 이름 변경 등)
+ */
+warnings: Array<string>, 
+/**
+ * 메시지
+ */
+message: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 가져오기 시 이름 충돌 처리 방식.
+ */
+export type NameConflictPolicy = "rename" | "reject";
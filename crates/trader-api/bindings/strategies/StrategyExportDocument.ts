@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExportedBacktestSummary } from "./ExportedBacktestSummary";
+import type { ExportedStrategyConfig } from "./ExportedStrategyConfig";
+import type { StrategyExportSource } from "./StrategyExportSource";
+
+/**
+ * 전략 내보내기 문서.
+ *
+ * `GET /{id}/export` 응답이자 `POST /import` 요청 본문으로 그대로 사용됩니다.
+ */
+export type StrategyExportDocument = { 
+/**
+ * 문서 포맷 버전
+ */
+format_version: number, 
+/**
+ * 내보내기 시각 (RFC3339)
+ */
+exported_at: string, 
+/**
+ * 원본 전략 정보
+ */
+source: StrategyExportSource, 
+/**
+ * 전략 설정
+ */
+strategy: ExportedStrategyConfig, 
+/**
+ * 최근 백테스트 성과 요약 (옵션)
+ */
+backtest_summary?: ExportedBacktestSummary, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 내보내기 원본 정보 (가져오기 시 출처 추적용).
+ */
+export type StrategyExportSource = { 
+/**
+ * 원본 전략 ID
+ */
+strategy_id: string, 
+/**
+ * 원본 전략 이름
+ */
+name: string, 
+/**
+ * 원본 전략 버전
+ */
+strategy_version?: string, };
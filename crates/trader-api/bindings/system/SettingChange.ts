@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 설정 변경 1건.
+ */
+export type SettingChange = { 
+/**
+ * 설정 키
+ */
+key: string, 
+/**
+ * 변경 전 값
+ */
+old_value: unknown, 
+/**
+ * 변경 후 값
+ */
+new_value: unknown, };
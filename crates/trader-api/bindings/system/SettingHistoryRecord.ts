@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 설정 변경 이력 레코드.
+ */
+export type SettingHistoryRecord = { 
+/**
+ * 설정 키
+ */
+key: string, 
+/**
+ * 변경 전 값
+ */
+old_value: unknown, 
+/**
+ * 변경 후 값
+ */
+new_value: unknown, 
+/**
+ * 변경한 사용자 ID
+ */
+changed_by: string | null, 
+/**
+ * 변경 사유
+ */
+reason: string | null, 
+/**
+ * 변경 시각
+ */
+changed_at: string, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SettingHistoryRecord } from "./SettingHistoryRecord";
+
+/**
+ * 설정 변경 이력 응답.
+ */
+export type SettingHistoryResponse = { 
+/**
+ * 변경 이력 (최신순)
+ */
+history: Array<SettingHistoryRecord>, 
+/**
+ * 조회된 건수
+ */
+total: number, };
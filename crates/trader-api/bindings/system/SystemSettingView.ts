@@ -0,0 +1,42 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 설정 조회 결과 (API 응답용).
+ */
+export type SystemSettingView = { 
+/**
+ * 설정 키
+ */
+key: string, 
+/**
+ * 현재 값 (민감 정보는 마스킹, 미설정 읽기 전용 값은 null)
+ */
+value: unknown, 
+/**
+ * 기본값 (읽기 전용 설정은 null)
+ */
+default_value: unknown, 
+/**
+ * 값 타입 (integer, number, string)
+ */
+value_type: string, 
+/**
+ * 최소값
+ */
+min: number | null, 
+/**
+ * 최대값
+ */
+max: number | null, 
+/**
+ * 런타임 변경 가능 여부
+ */
+mutable: boolean, 
+/**
+ * 민감 정보 여부 (마스킹됨)
+ */
+sensitive: boolean, 
+/**
+ * 설명
+ */
+description: string, };
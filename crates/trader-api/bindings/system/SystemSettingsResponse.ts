@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SystemSettingView } from "./SystemSettingView";
+
+/**
+ * 시스템 설정 목록 응답.
+ */
+export type SystemSettingsResponse = { 
+/**
+ * 설정 목록
+ */
+settings: Array<SystemSettingView>, };
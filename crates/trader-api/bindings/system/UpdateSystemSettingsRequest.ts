@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 시스템 설정 변경 요청.
+ */
+export type UpdateSystemSettingsRequest = { 
+/**
+ * 변경할 설정 (키 → 값)
+ */
+settings: Record<string, unknown>, 
+/**
+ * 변경 사유 (감사 로그에 기록)
+ */
+reason: string | null, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SettingChange } from "./SettingChange";
+import type { SystemSettingView } from "./SystemSettingView";
+
+/**
+ * 시스템 설정 변경 응답.
+ */
+export type UpdateSystemSettingsResponse = { 
+/**
+ * 실제로 값이 바뀐 설정
+ */
+changes: Array<SettingChange>, 
+/**
+ * 변경 후 전체 설정
+ */
+settings: Array<SystemSettingView>, };
@@ -70,6 +70,10 @@ impl Claims {
     pub fn has_role(&self, required_role: Role) -> bool {
         self.role.level() >= required_role.level()
     }
+    /// 사용자 ID(`sub`)를 UUID로 파싱 (리소스 소유자 기록용).
+    pub fn user_uuid(&self) -> Option<uuid::Uuid> {
+        uuid::Uuid::parse_str(&self.sub).ok()
+    }
 }
 
 /// Refresh Token 페이로드.
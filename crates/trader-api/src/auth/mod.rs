@@ -7,6 +7,7 @@
 //! - [`Claims`]: JWT 페이로드 구조체
 //! - [`Role`]: 사용자 역할 (Admin, Trader, Viewer)
 //! - [`JwtAuth`]: Axum 미들웨어용 JWT 검증 추출기
+//! - [`AdminAuth`]: Admin 권한을 요구하는 추출기
 //! - 토큰 생성/검증 함수
 //!
 //! # 사용 예시
@@ -26,6 +27,6 @@ mod password;
 mod roles;
 
 pub use jwt::{create_token, decode_token, Claims, RefreshClaims, TokenPair};
-pub use middleware::{require_role, JwtAuth, JwtAuthError};
+pub use middleware::{require_role, AdminAuth, JwtAuth, JwtAuthError, OptionalJwtAuth};
 pub use password::{hash_password, verify_password, PasswordError};
 pub use roles::{Permission, Role};
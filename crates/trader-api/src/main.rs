@@ -17,9 +17,12 @@ use tower_http::{
 use tracing::{error, info, warn};
 use trader_api::{
     metrics::setup_metrics_recorder,
-    middleware::{metrics_layer, rate_limit_middleware, RateLimitConfig, RateLimitState},
+    middleware::{
+        metrics_layer, parse_overrides, rate_limit_middleware, RateLimitState, RateLimiter,
+        RATE_LIMIT_OVERRIDES_ENV,
+    },
     openapi::swagger_ui_router,
-    repository::StrategyRepository,
+    repository::{PgOrderStore, StrategyRepository},
     routes::create_api_router,
     services::ApiBotHandler,
     state::AppState,
@@ -27,12 +30,15 @@ use trader_api::{
         create_subscription_manager, standalone_websocket_router, start_simulator, WsState,
     },
 };
-use trader_core::crypto::CredentialEncryptor;
+use trader_core::{crypto::CredentialEncryptor, ExecutionHistoryRequest};
 use trader_data::{cache::CachedHistoricalDataProvider, Database, DatabaseConfig, RedisCache};
 use trader_execution::{ConversionConfig, OrderExecutor};
 use trader_notification::{NotificationManager, TelegramConfig, TelegramSender};
-use trader_risk::{RiskConfig, RiskManager};
-use trader_strategy::{EngineConfig, StrategyEngine};
+use trader_risk::{
+    CircuitBreakerHook, CorrelatedExposureConfig, DailyLossBreach, RiskConfig, RiskManager,
+    TradingTimezone,
+};
+use trader_strategy::{strategies::common::ConcentrationLimits, EngineConfig, StrategyEngine};
 
 /// Telegram 설정 DB 조회 결과 타입
 type TelegramSettingsRow = (Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>, bool);
@@ -45,6 +51,12 @@ struct ServerConfig {
     port: u16,
     /// 초기 잔고 (리스크 매니저용)
     initial_balance: rust_decimal::Decimal,
+    /// 일일 손실 서킷브레이커 한도 (%, None이면 비활성화)
+    daily_loss_limit_pct: Option<f64>,
+    /// 서킷브레이커 초기화 기준 시장 시간대
+    daily_loss_timezone: TradingTimezone,
+    /// 상관 그룹 합산 노출 한도 (%, None이면 비활성화)
+    correlated_exposure_limit_pct: Option<f64>,
 }
 
 impl Default for ServerConfig {
@@ -53,6 +65,9 @@ impl Default for ServerConfig {
             host: "127.0.0.1".to_string(),
             port: 3000,
             initial_balance: rust_decimal_macros::dec!(10000),
+            daily_loss_limit_pct: None,
+            daily_loss_timezone: TradingTimezone::default(),
+            correlated_exposure_limit_pct: None,
         }
     }
 }
@@ -69,11 +84,29 @@ impl ServerConfig {
             .ok()
             .and_then(|b| b.parse().ok())
             .unwrap_or(rust_decimal_macros::dec!(10000));
+        let daily_loss_limit_pct = std::env::var("DAILY_LOSS_LIMIT_PCT")
+            .ok()
+            .and_then(|v| v.parse().ok());
+        let daily_loss_timezone = match std::env::var("DAILY_LOSS_TIMEZONE")
+            .unwrap_or_default()
+            .to_uppercase()
+            .as_str()
+        {
+            "KST" => TradingTimezone::Kst,
+            "EST" => TradingTimezone::Est,
+            _ => TradingTimezone::Utc,
+        };
+        let correlated_exposure_limit_pct = std::env::var("CORRELATED_EXPOSURE_LIMIT_PCT")
+            .ok()
+            .and_then(|v| v.parse().ok());
 
         Self {
             host,
             port,
             initial_balance,
+            daily_loss_limit_pct,
+            daily_loss_timezone,
+            correlated_exposure_limit_pct,
         }
     }
 
@@ -123,17 +156,64 @@ fn start_mock_simulator_if_needed(
     false
 }
 
+/// 서킷브레이커 발동 시 리스크 경고 알림을 전송하는 훅.
+///
+/// 리스크 검증 경로를 막지 않도록 알림 전송은 별도 태스크에서 수행합니다.
+fn circuit_breaker_alert_hook(manager: Arc<NotificationManager>) -> Arc<dyn CircuitBreakerHook> {
+    Arc::new(move |breach: &DailyLossBreach| {
+        let manager = manager.clone();
+        let message = format!(
+            "{} 일일 손실 한도 초과로 신규 진입이 차단되었습니다 (실현 {}, 미실현 {})",
+            breach.trading_date, breach.realized_pnl, breach.unrealized_pnl
+        );
+        let current = rust_decimal::Decimal::from_f64_retain(breach.loss_pct)
+            .unwrap_or_default()
+            .round_dp(2);
+        let threshold = rust_decimal::Decimal::from_f64_retain(breach.limit_pct)
+            .unwrap_or_default()
+            .round_dp(2);
+
+        tokio::spawn(async move {
+            if let Err(e) = manager
+                .notify_risk_alert("daily_loss_circuit_breaker", &message, current, threshold)
+                .await
+            {
+                warn!("서킷브레이커 알림 전송 실패: {}", e);
+            }
+        });
+    })
+}
+
 /// AppState 초기화.
 async fn create_app_state(config: &ServerConfig) -> AppState {
+    // 리스크 설정 (전략 분산 한도와 RiskManager 집중도 한도를 같은 값으로 검증)
+    let risk_config = RiskConfig {
+        daily_loss_limit_pct: config.daily_loss_limit_pct,
+        circuit_breaker_timezone: config.daily_loss_timezone,
+        correlated_exposure: config.correlated_exposure_limit_pct.map(|limit_pct| {
+            CorrelatedExposureConfig {
+                max_correlated_exposure_pct: limit_pct,
+                ..CorrelatedExposureConfig::default()
+            }
+        }),
+        ..RiskConfig::default()
+    };
+
     // 전략 엔진 생성
-    let strategy_engine = StrategyEngine::new(EngineConfig::default());
+    let strategy_engine = StrategyEngine::new(EngineConfig {
+        risk_limits: Some(ConcentrationLimits::new(
+            risk_config.max_position_pct,
+            risk_config.max_concurrent_positions,
+        )),
+        ..EngineConfig::default()
+    });
 
     // 리스크 매니저 생성
-    let risk_manager = RiskManager::new(RiskConfig::default(), config.initial_balance);
+    let risk_manager = RiskManager::new(risk_config.clone(), config.initial_balance);
 
     // 주문 실행기 생성
     let executor = OrderExecutor::new_complete(
-        RiskManager::new(RiskConfig::default(), config.initial_balance),
+        RiskManager::new(risk_config, config.initial_balance),
         "default_exchange",
         ConversionConfig::default(),
     );
@@ -187,6 +267,9 @@ async fn create_app_state(config: &ServerConfig) -> AppState {
         warn!("DATABASE_URL not set, database features will be disabled");
     }
 
+    // 런타임 시스템 설정 로드 (DB 저장값이 환경변수 초기값보다 우선)
+    state.load_runtime_settings().await;
+
     // 암호화 관리자 설정 (ENCRYPTION_MASTER_KEY 환경변수에서)
     if let Ok(master_key) = std::env::var("ENCRYPTION_MASTER_KEY") {
         match CredentialEncryptor::new(&master_key) {
@@ -251,6 +334,22 @@ async fn create_app_state(config: &ServerConfig) -> AppState {
         notification_manager.add_sender(telegram_sender);
         state = state.with_notification_manager(notification_manager);
         info!("NotificationManager 초기화 완료 (텔레그램 알림 활성화)");
+
+        // 일일 손실 서킷브레이커 발동 시 리스크 경고 전송
+        if let Some(manager) = state.notification_manager.clone() {
+            let hook = circuit_breaker_alert_hook(manager);
+            state
+                .risk_manager
+                .write()
+                .await
+                .set_circuit_breaker_hook(hook.clone());
+            state
+                .executor
+                .read()
+                .await
+                .set_circuit_breaker_hook(hook)
+                .await;
+        }
     } else {
         info!("텔레그램 설정 없음, 알림 기능 비활성화");
     }
@@ -288,9 +387,63 @@ async fn create_app_state(config: &ServerConfig) -> AppState {
         }
     }
 
+    // 주문 상태 영속화 및 재시작 복구 (ExchangeProvider 설정 이후에 수행)
+    if let Some(pool) = state.db_pool.clone() {
+        restore_order_manager(&state, pool).await;
+    }
+
     state
 }
 
+/// OrderManager에 DB 저장소를 연결하고 미완료 주문 추적을 재개.
+///
+/// ExchangeProvider가 있으면 재시작 동안 발생한 체결을 체결 내역과 대조하여 반영합니다.
+async fn restore_order_manager(state: &AppState, pool: sqlx::PgPool) {
+    let order_manager = Arc::clone(state.executor.read().await.order_manager());
+    let mut manager = order_manager.write().await;
+    manager.set_store(Arc::new(PgOrderStore::new(pool)));
+
+    let restored = match manager.restore_open_orders().await {
+        Ok(count) => count,
+        Err(e) => {
+            error!("미완료 주문 복구 실패: {}", e);
+            return;
+        }
+    };
+    if restored == 0 {
+        return;
+    }
+    info!("미완료 주문 {}건 복구", restored);
+
+    let Some(provider) = &state.exchange_provider else {
+        warn!(
+            "ExchangeProvider 없음, 복구된 주문 {}건의 체결 동기화를 건너뜁니다",
+            restored
+        );
+        return;
+    };
+
+    let now = chrono::Utc::now();
+    let since = manager
+        .get_active_orders()
+        .iter()
+        .map(|order| order.created_at)
+        .min()
+        .unwrap_or(now);
+    let request = ExecutionHistoryRequest::new(
+        since.format("%Y%m%d").to_string(),
+        now.format("%Y%m%d").to_string(),
+    );
+
+    match manager.sync_executions(provider.as_ref(), request).await {
+        Ok(report) => info!(
+            "체결 내역 동기화 완료: 반영 {}건, 중복 {}건, 미매칭 {}건, 실패 {}건",
+            report.applied, report.duplicates, report.unmatched, report.failed
+        ),
+        Err(e) => warn!("체결 내역 동기화 실패: {}", e),
+    }
+}
+
 /// CORS 미들웨어 구성.
 ///
 /// CORS_ORIGINS 환경변수가 설정되어 있으면 해당 origin만 허용합니다.
@@ -360,19 +513,36 @@ fn is_rate_limit_disabled() -> bool {
         .unwrap_or(false)
 }
 
-/// Rate Limit 설정 로드.
-fn rate_limit_config() -> RateLimitConfig {
-    let requests_per_minute = std::env::var("RATE_LIMIT_RPM")
-        .ok()
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(1200); // 기본: 분당 1200회
+/// 클라이언트별 개별 Rate Limit 로드.
+///
+/// 환경변수(`RATE_LIMIT_OVERRIDES`)를 먼저 적용하고, DB(`rate_limit_overrides`) 값은
+/// 백그라운드에서 로드하여 같은 대상의 한도를 덮어씁니다.
+fn load_rate_limit_overrides(limiter: &RateLimiter, db_pool: Option<sqlx::PgPool>) {
+    let env_overrides = match std::env::var(RATE_LIMIT_OVERRIDES_ENV) {
+        Ok(spec) => parse_overrides(&spec).unwrap_or_else(|e| {
+            warn!("{} 설정 무시: {}", RATE_LIMIT_OVERRIDES_ENV, e);
+            Default::default()
+        }),
+        Err(_) => Default::default(),
+    };
 
-    info!(
-        requests_per_minute = requests_per_minute,
-        "Rate limiting configured"
-    );
+    let limiter = limiter.clone();
+    tokio::spawn(async move {
+        if !env_overrides.is_empty() {
+            info!(
+                count = env_overrides.len(),
+                "Rate limit overrides loaded from env"
+            );
+            limiter.merge_overrides(env_overrides).await;
+        }
 
-    RateLimitConfig::new(requests_per_minute)
+        if let Some(pool) = db_pool {
+            match limiter.load_overrides_from_db(&pool).await {
+                Ok(count) => info!(count, "Rate limit overrides loaded from database"),
+                Err(e) => warn!("DB Rate limit override 로드 실패: {}", e),
+            }
+        }
+    });
 }
 
 /// 전체 라우터 생성.
@@ -391,7 +561,20 @@ fn create_router(
         info!("Rate limiting DISABLED (RATE_LIMIT_DISABLED=true)");
         create_api_router().with_state(state)
     } else {
-        let rate_limit_state = RateLimitState::new(rate_limit_config());
+        // 런타임 설정(RATE_LIMIT_RPM 또는 DB 저장값)으로 생성하고,
+        // 설정 변경 API가 같은 Limiter를 갱신하도록 연결
+        let rate_limit_config = state.runtime_settings.rate_limit_config();
+        info!(
+            requests_per_minute = rate_limit_config.requests_per_minute,
+            burst_size = rate_limit_config.burst_size,
+            "Rate limiting configured"
+        );
+        let limiter = RateLimiter::new(rate_limit_config);
+        state.runtime_settings.attach_rate_limiter(limiter.clone());
+        load_rate_limit_overrides(&limiter, state.db_pool.clone());
+        limiter.spawn_cleanup_task();
+        let rate_limit_state =
+            RateLimitState::from_limiter(limiter).with_jwt_secret(ws_state.jwt_secret.as_str());
         create_api_router()
             .with_state(state)
             .layer(middleware::from_fn_with_state(
@@ -551,7 +734,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // WebSocket 상태 생성 (AppState의 market_streams를 공유)
     let ws_state = WsState::new(subscriptions_for_ws, jwt_secret)
-        .with_market_streams(state.market_streams.clone());
+        .with_market_streams(state.market_streams.clone())
+        .with_audit_pool(state.db_pool.clone())
+        .with_resource_pool(state.db_pool.clone())
+        .with_strategy_context(state.strategy_context.clone());
 
     info!(version = %state.version, "Application state initialized");
     info!(
@@ -570,7 +756,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // ContextSyncService 시작 (ExchangeProvider + AnalyticsProvider가 모두 설정된 경우)
     if let Some(_sync_handle) = state.start_context_sync(shutdown_token.clone()) {
-        info!("ContextSyncService 시작됨 (거래소: 5초, 분석: 1분 주기)");
+        info!("ContextSyncService 시작됨 (거래소: 5초, 분석: 1분 주기, 증분 동기화)");
     } else {
         warn!("ContextSyncService 시작 실패: ExchangeProvider 또는 AnalyticsProvider 미설정");
     }
@@ -582,6 +768,46 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         warn!("SignalProcessingService 시작 실패: DB 미설정 또는 signal_rx 이미 사용됨");
     }
 
+    // PerformanceAlertService 시작 (전략 성과 알림 규칙 평가)
+    if let Some(_alert_handle) = state.start_performance_alerts(shutdown_token.clone()) {
+        info!(
+            "PerformanceAlertService 시작됨 (평가 주기: {}초)",
+            state.runtime_settings.performance_alert_interval_secs()
+        );
+    } else {
+        warn!("PerformanceAlertService 시작 실패: DB 미설정");
+    }
+
+    // CorrelationRefreshService 시작 (상관 노출 한도용 상관계수 갱신)
+    if let Some(_correlation_handle) = state
+        .start_correlation_refresh(shutdown_token.clone())
+        .await
+    {
+        info!("CorrelationRefreshService 시작됨 (갱신 주기: 1시간)");
+    } else {
+        warn!("CorrelationRefreshService 시작 실패: data_provider 미설정");
+    }
+
+    // PositionReconcileService 시작 (거래소 포지션과 내부 상태 비교)
+    if let Some(_reconcile_handle) = state.start_position_reconciliation(shutdown_token.clone()) {
+        let config = state.runtime_settings.reconciliation_config();
+        info!(
+            "PositionReconcileService 시작됨 (주기: {}초, 모드: {:?})",
+            config.interval_secs, config.mode
+        );
+    } else {
+        warn!("PositionReconcileService 시작 실패: exchange_provider 미설정");
+    }
+
+    // 백테스트 작업 결과 정리 서비스 시작 (만료된 비동기 백테스트 결과 삭제)
+    let _backtest_cleanup_handle = state.start_backtest_job_cleanup(shutdown_token.clone());
+    let backtest_job_config = state.backtest_jobs.config();
+    info!(
+        "BacktestJobCleanupService 시작됨 (동시 실행: {}개, 결과 보관: {}초)",
+        backtest_job_config.max_concurrent,
+        backtest_job_config.result_ttl.as_secs()
+    );
+
     // ConflictBroadcastService 시작 (Signal 충돌 WebSocket 알림)
     if let Some(_conflict_handle) = state.start_conflict_broadcast(shutdown_token.clone()).await {
         info!("ConflictBroadcastService 시작됨 (Signal 충돌 WebSocket 알림)");
@@ -1,9 +1,12 @@
 //! Prometheus 메트릭 설정 및 유틸리티.
 //!
 //! HTTP 요청 메트릭, 비즈니스 메트릭을 수집하고 `/metrics` 엔드포인트로 노출합니다.
+//! 주문·포지션·거래소 API 메트릭은 [`trader_core::telemetry`]에서 정의하며,
+//! 이 모듈은 레코더 설치와 히스토그램 버킷 설정만 담당합니다.
 
 use metrics::{counter, gauge, histogram};
 use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use trader_core::telemetry;
 
 /// Prometheus 메트릭 레코더를 설정하고 핸들을 반환합니다.
 ///
@@ -22,6 +25,16 @@ pub fn setup_metrics_recorder() -> PrometheusHandle {
             &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0],
         )
         .expect("히스토그램 버킷 설정 실패")
+        .set_buckets_for_metric(
+            Matcher::Full(telemetry::ORDER_FILL_LATENCY_SECONDS.to_string()),
+            telemetry::ORDER_FILL_LATENCY_BUCKETS,
+        )
+        .expect("히스토그램 버킷 설정 실패")
+        .set_buckets_for_metric(
+            Matcher::Full(telemetry::EXCHANGE_API_REQUEST_DURATION_SECONDS.to_string()),
+            telemetry::EXCHANGE_API_DURATION_BUCKETS,
+        )
+        .expect("히스토그램 버킷 설정 실패")
         .install_recorder()
         .expect("Prometheus 레코더 설치 실패")
 }
@@ -61,38 +74,6 @@ pub fn record_http_duration(method: &str, path: &str, duration_secs: f64) {
 // 비즈니스 메트릭 헬퍼 함수
 // ============================================================================
 
-/// 거래 주문 카운터 증가.
-pub fn record_order(side: &str, status: &str, exchange: &str) {
-    counter!(
-        "trading_orders_total",
-        "side" => side.to_string(),
-        "status" => status.to_string(),
-        "exchange" => exchange.to_string()
-    )
-    .increment(1);
-}
-
-/// 열린 포지션 수 설정.
-pub fn set_open_positions(exchange: &str, symbol: &str, count: f64) {
-    gauge!(
-        "trading_positions_open",
-        "exchange" => exchange.to_string(),
-        "symbol" => symbol.to_string()
-    )
-    .set(count);
-}
-
-/// 실현 손익 기록.
-pub fn record_realized_pnl(strategy: &str, pnl: f64) {
-    if pnl >= 0.0 {
-        counter!("trading_pnl_realized_profit_total", "strategy" => strategy.to_string())
-            .increment(pnl as u64);
-    } else {
-        counter!("trading_pnl_realized_loss_total", "strategy" => strategy.to_string())
-            .increment((-pnl) as u64);
-    }
-}
-
 /// WebSocket 연결 수 설정.
 pub fn set_websocket_connections(count: f64) {
     gauge!("websocket_connections_active").set(count);
@@ -108,6 +89,25 @@ pub fn decrement_websocket_connections() {
     gauge!("websocket_connections_active").decrement(1.0);
 }
 
+/// 컨텍스트 분석 동기화 주기별 변경 건수 기록.
+///
+/// 마지막 주기의 값은 gauge로, 누적 값은 counter로 기록합니다.
+pub fn record_context_sync_cycle(fetched: usize, changed: usize, removed: usize) {
+    gauge!("context_sync_last_fetched_tickers").set(fetched as f64);
+    gauge!("context_sync_last_changed_tickers").set(changed as f64);
+    gauge!("context_sync_last_removed_tickers").set(removed as f64);
+    counter!("context_sync_cycles_total").increment(1);
+    counter!("context_sync_changed_tickers_total").increment(changed as u64);
+    counter!("context_sync_removed_tickers_total").increment(removed as u64);
+}
+
+/// 전략 Signal 수신 결과 기록.
+///
+/// `status`: `processed`(정상 처리), `duplicate`(중복 수신으로 무시), `failed`(처리 실패)
+pub fn record_signal_received(status: &'static str) {
+    counter!("strategy_signals_received_total", "status" => status).increment(1);
+}
+
 // ============================================================================
 // 경로 정규화 유틸리티
 // ============================================================================
@@ -7,5 +7,6 @@ mod rate_limit;
 
 pub use metrics::metrics_layer;
 pub use rate_limit::{
-    rate_limit_middleware, RateLimitConfig, RateLimitResult, RateLimitState, RateLimiter,
+    parse_overrides, rate_limit_middleware, RateLimitConfig, RateLimitKey, RateLimitResult,
+    RateLimitState, RateLimiter, RATE_LIMIT_OVERRIDES_ENV,
 };
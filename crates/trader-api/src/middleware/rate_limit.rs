@@ -1,11 +1,29 @@
 //! Rate limiting middleware.
 //!
 //! Token Bucket 알고리즘 기반 rate limiting을 제공합니다.
+//!
+//! # 버킷 단위
+//!
+//! 요청마다 [`RateLimitKey`]를 결정하여 클라이언트별 독립 버킷을 유지합니다.
+//!
+//! 1. `X-API-Key` 헤더 - 해시가 등록된 API 키로 인증된 경우 키 단위
+//! 2. `Authorization: Bearer` JWT - 서명 검증에 성공한 경우 사용자 ID(`sub`) 단위
+//! 3. 그 외 - 클라이언트 IP 단위
+//!
+//! 검증되지 않은 식별자로 버킷을 만들면 키를 바꿔가며 한도를 우회할 수 있으므로,
+//! 인증을 통과한 식별자만 버킷 키로 사용하고 나머지는 IP 버킷으로 처리합니다.
+//!
+//! # 개별 한도
+//!
+//! `user:{id}` / `api_key:{SHA-256 해시}` 단위 한도를 환경변수(`RATE_LIMIT_OVERRIDES`)와
+//! DB(`rate_limit_overrides` 테이블)에서 로드합니다. 설정되지 않은 클라이언트는 전역 한도를 따릅니다.
+//! API 키 원문은 저장하지 않고, 요청의 키를 해시하여 조회합니다.
 
 #![allow(dead_code)] // Rate limiting 레이어는 향후 프로덕션 배포 시 활성화 예정
 
 use std::{
     collections::HashMap,
+    fmt,
     net::IpAddr,
     sync::Arc,
     time::{Duration, Instant},
@@ -18,7 +36,22 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use metrics::counter;
-use tokio::sync::RwLock;
+use sha2::{Digest, Sha256};
+use tokio::{sync::RwLock, task::JoinHandle};
+
+use crate::auth::decode_token;
+
+/// 개별 한도 환경변수 이름.
+///
+/// 형식: `user:42=600/60,api_key:abc123=120` (`분당 요청 수[/버스트]`, 버스트 생략 시 10%).
+/// API 키는 원문으로 지정하며 로드 시 해시로 변환됩니다.
+pub const RATE_LIMIT_OVERRIDES_ENV: &str = "RATE_LIMIT_OVERRIDES";
+
+/// API 키 헤더 이름.
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// 남은 요청 수 헤더 이름.
+const REMAINING_HEADER: &str = "x-ratelimit-remaining";
 
 /// Rate Limiter 설정.
 #[derive(Debug, Clone)]
@@ -118,22 +151,137 @@ impl TokenBucket {
             (1.0 - self.tokens) / self.refill_rate
         }
     }
+
+    /// 즉시 허용 가능한 남은 요청 수.
+    fn remaining(&self) -> u32 {
+        self.tokens.max(0.0).floor() as u32
+    }
+}
+
+/// Rate Limit 적용 단위 (버킷 키).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    /// 인증된 사용자 ID (JWT `sub`)
+    User(String),
+    /// 등록된 API 키 (SHA-256 해시, hex)
+    ApiKey(String),
+    /// 클라이언트 IP
+    Ip(IpAddr),
+}
+
+impl RateLimitKey {
+    /// API 키 원문으로 키 생성 (원문 대신 해시를 보관).
+    pub fn api_key(raw: &str) -> Self {
+        RateLimitKey::ApiKey(hash_api_key(raw))
+    }
+
+    /// 개별 한도 조회용 식별자 (`user:{id}`, `api_key:{hash}`, `ip:{addr}`).
+    pub fn subject(&self) -> String {
+        match self {
+            RateLimitKey::User(id) => format!("user:{}", id),
+            RateLimitKey::ApiKey(key) => format!("api_key:{}", key),
+            RateLimitKey::Ip(ip) => format!("ip:{}", ip),
+        }
+    }
+}
+
+impl From<IpAddr> for RateLimitKey {
+    fn from(ip: IpAddr) -> Self {
+        RateLimitKey::Ip(ip)
+    }
+}
+
+impl fmt::Display for RateLimitKey {
+    /// 로그 출력용 (API 키 해시는 앞 8자리만 노출).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateLimitKey::ApiKey(hash) => {
+                let prefix: String = hash.chars().take(8).collect();
+                write!(f, "api_key:{}***", prefix)
+            }
+            other => f.write_str(&other.subject()),
+        }
+    }
+}
+
+/// API 키 해시 (SHA-256, 소문자 hex).
+///
+/// `rate_limit_overrides.subject_id`에는 이 값이 저장됩니다.
+pub fn hash_api_key(raw: &str) -> String {
+    hex::encode(Sha256::digest(raw.as_bytes()))
+}
+
+/// 개별 한도 설정 파싱.
+///
+/// 형식: `user:42=600/60,api_key:abc123=120`. 대상은 `user:` 또는 `api_key:`로 시작해야 합니다.
+/// `api_key:` 대상은 원문 대신 해시(`api_key:{hash}`)로 저장합니다.
+pub fn parse_overrides(spec: &str) -> Result<HashMap<String, RateLimitConfig>, String> {
+    let mut overrides = HashMap::new();
+
+    for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (subject, limit) = entry
+            .split_once('=')
+            .ok_or_else(|| format!("'{}': '대상=분당요청수[/버스트]' 형식이어야 합니다", entry))?;
+        let subject = subject.trim();
+        if !(subject.starts_with("user:") || subject.starts_with("api_key:"))
+            || subject.ends_with(':')
+        {
+            return Err(format!(
+                "'{}': 대상은 user:<ID> 또는 api_key:<키> 형식이어야 합니다",
+                subject
+            ));
+        }
+
+        let (rpm, burst) = match limit.split_once('/') {
+            Some((rpm, burst)) => (rpm, Some(burst)),
+            None => (limit, None),
+        };
+        let rpm: u32 = rpm
+            .trim()
+            .parse()
+            .ok()
+            .filter(|v| *v > 0)
+            .ok_or_else(|| format!("'{}': 분당 요청 수는 양의 정수여야 합니다", entry))?;
+        let config = match burst {
+            Some(burst) => RateLimitConfig {
+                burst_size: burst
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("'{}': 버스트는 0 이상의 정수여야 합니다", entry))?,
+                ..RateLimitConfig::new(rpm)
+            },
+            None => RateLimitConfig::new(rpm),
+        };
+
+        let subject = match subject.strip_prefix("api_key:") {
+            Some(raw) => RateLimitKey::api_key(raw).subject(),
+            None => subject.to_string(),
+        };
+        overrides.insert(subject, config);
+    }
+
+    Ok(overrides)
 }
 
 /// Rate Limiter.
 ///
-/// IP 주소별로 Rate Limiting을 적용합니다.
+/// [`RateLimitKey`]별로 독립된 버킷을 유지합니다.
+/// 복제본은 설정과 버킷을 공유하므로 [`RateLimiter::update_config`]로
+/// 런타임에 한도를 변경하면 미들웨어에 즉시 반영됩니다.
 #[derive(Clone)]
 pub struct RateLimiter {
-    config: RateLimitConfig,
-    buckets: Arc<RwLock<HashMap<IpAddr, TokenBucket>>>,
+    config: Arc<RwLock<RateLimitConfig>>,
+    /// 대상(`user:{id}`, `api_key:{hash}`)별 개별 한도
+    overrides: Arc<RwLock<HashMap<String, RateLimitConfig>>>,
+    buckets: Arc<RwLock<HashMap<RateLimitKey, TokenBucket>>>,
 }
 
 impl RateLimiter {
     /// 새 Rate Limiter 생성.
     pub fn new(config: RateLimitConfig) -> Self {
         Self {
-            config,
+            config: Arc::new(RwLock::new(config)),
+            overrides: Arc::new(RwLock::new(HashMap::new())),
             buckets: Arc::new(RwLock::new(HashMap::new())),
         }
     }
@@ -144,31 +292,121 @@ impl RateLimiter {
     }
 
     /// 요청 허용 여부 확인.
-    pub async fn check(&self, ip: IpAddr) -> RateLimitResult {
+    ///
+    /// 개별 한도가 설정된 대상은 해당 한도로, 나머지는 전역 한도로 버킷을 생성합니다.
+    pub async fn check(&self, key: impl Into<RateLimitKey>) -> RateLimitResult {
+        let key = key.into();
+        let config = match self.overrides.read().await.get(&key.subject()) {
+            Some(config) => config.clone(),
+            None => self.config.read().await.clone(),
+        };
         let mut buckets = self.buckets.write().await;
 
         let bucket = buckets
-            .entry(ip)
-            .or_insert_with(|| TokenBucket::new(&self.config));
+            .entry(key)
+            .or_insert_with(|| TokenBucket::new(&config));
 
         if bucket.try_acquire() {
-            RateLimitResult::Allowed
+            RateLimitResult::Allowed {
+                remaining: bucket.remaining(),
+            }
         } else {
-            let retry_after = bucket.time_until_next_token().ceil() as u64;
+            let retry_after = (bucket.time_until_next_token().ceil() as u64).max(1);
             RateLimitResult::Limited { retry_after }
         }
     }
 
+    /// 개별 한도가 설정된 대상인지 확인.
+    pub async fn has_override(&self, subject: &str) -> bool {
+        self.overrides.read().await.contains_key(subject)
+    }
+
+    /// 개별 한도 추가/갱신.
+    ///
+    /// 같은 대상의 기존 한도는 덮어쓰며, 해당 대상의 버킷은 새 용량으로 다시 생성되도록 제거합니다.
+    pub async fn merge_overrides(&self, overrides: HashMap<String, RateLimitConfig>) {
+        let mut buckets = self.buckets.write().await;
+        buckets.retain(|key, _| !overrides.contains_key(&key.subject()));
+        self.overrides.write().await.extend(overrides);
+    }
+
+    /// 설정된 개별 한도 수.
+    pub async fn override_count(&self) -> usize {
+        self.overrides.read().await.len()
+    }
+
+    /// DB(`rate_limit_overrides`)에서 개별 한도 로드.
+    ///
+    /// 로드한 한도 수를 반환합니다. 환경변수로 설정된 같은 대상의 한도는 DB 값으로 덮어씁니다.
+    /// `api_key` 대상의 `subject_id`는 [`hash_api_key`] 해시입니다.
+    pub async fn load_overrides_from_db(&self, pool: &sqlx::PgPool) -> Result<usize, sqlx::Error> {
+        let rows: Vec<(String, String, i32, i32)> = sqlx::query_as(
+            r#"
+            SELECT subject_type, subject_id, requests_per_minute, burst_size
+            FROM rate_limit_overrides
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let overrides: HashMap<String, RateLimitConfig> = rows
+            .into_iter()
+            .filter_map(|(subject_type, subject_id, rpm, burst)| {
+                Some((
+                    format!("{}:{}", subject_type, subject_id),
+                    RateLimitConfig {
+                        requests_per_minute: u32::try_from(rpm).ok().filter(|v| *v > 0)?,
+                        burst_size: u32::try_from(burst).unwrap_or(0),
+                        ..RateLimitConfig::default()
+                    },
+                ))
+            })
+            .collect();
+
+        let count = overrides.len();
+        self.merge_overrides(overrides).await;
+        Ok(count)
+    }
+
     /// 오래된 버킷 정리.
     pub async fn cleanup(&self) {
+        let cleanup_interval = self.config.read().await.cleanup_interval;
         let mut buckets = self.buckets.write().await;
-        let threshold = Instant::now() - self.config.cleanup_interval;
+        let threshold = Instant::now() - cleanup_interval;
 
         buckets.retain(|_, bucket| bucket.last_refill > threshold);
     }
 
-    /// 현재 추적 중인 IP 수 반환.
-    pub async fn tracked_ips(&self) -> usize {
+    /// 비활성 버킷을 주기적으로 정리하는 백그라운드 태스크 시작.
+    ///
+    /// `cleanup_interval`마다 [`cleanup`](Self::cleanup)을 실행하여 버킷 맵이 무한히
+    /// 커지지 않도록 합니다. 간격은 매 주기 현재 설정에서 다시 읽습니다.
+    pub fn spawn_cleanup_task(&self) -> JoinHandle<()> {
+        let limiter = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let interval = limiter.config.read().await.cleanup_interval;
+                tokio::time::sleep(interval).await;
+                limiter.cleanup().await;
+            }
+        })
+    }
+
+    /// 현재 설정 조회.
+    pub async fn config(&self) -> RateLimitConfig {
+        self.config.read().await.clone()
+    }
+
+    /// 설정 변경 (즉시 적용).
+    ///
+    /// 기존 버킷은 이전 용량으로 계산되어 있으므로 모두 초기화합니다.
+    pub async fn update_config(&self, config: RateLimitConfig) {
+        *self.config.write().await = config;
+        self.buckets.write().await.clear();
+    }
+
+    /// 현재 추적 중인 클라이언트(버킷) 수 반환.
+    pub async fn tracked_clients(&self) -> usize {
         self.buckets.read().await.len()
     }
 }
@@ -177,7 +415,10 @@ impl RateLimiter {
 #[derive(Debug, Clone)]
 pub enum RateLimitResult {
     /// 요청 허용됨
-    Allowed,
+    Allowed {
+        /// 남은 요청 수
+        remaining: u32,
+    },
     /// Rate limit 초과
     Limited {
         /// 재시도까지 대기 시간 (초)
@@ -189,46 +430,69 @@ pub enum RateLimitResult {
 #[derive(Clone)]
 pub struct RateLimitState {
     limiter: RateLimiter,
+    /// 사용자 단위 버킷을 위한 JWT 검증 키 (None이면 IP/API 키 단위만 사용)
+    jwt_secret: Option<Arc<str>>,
 }
 
 impl RateLimitState {
     pub fn new(config: RateLimitConfig) -> Self {
-        Self {
-            limiter: RateLimiter::new(config),
-        }
+        Self::from_limiter(RateLimiter::new(config))
     }
 
     pub fn with_defaults() -> Self {
+        Self::from_limiter(RateLimiter::with_defaults())
+    }
+
+    /// 기존 Rate Limiter를 공유하는 상태 생성.
+    pub fn from_limiter(limiter: RateLimiter) -> Self {
         Self {
-            limiter: RateLimiter::with_defaults(),
+            limiter,
+            jwt_secret: None,
         }
     }
+
+    /// JWT 검증 키 설정 (인증된 요청을 사용자 ID 단위로 제한).
+    pub fn with_jwt_secret(mut self, secret: impl Into<Arc<str>>) -> Self {
+        self.jwt_secret = Some(secret.into());
+        self
+    }
+
+    /// 내부 Rate Limiter 참조.
+    pub fn limiter(&self) -> &RateLimiter {
+        &self.limiter
+    }
 }
 
 /// Rate Limiting 미들웨어 함수.
 ///
-/// 클라이언트 IP별로 Rate Limiting을 적용합니다.
+/// 사용자 ID / API 키 / 클라이언트 IP 단위로 Rate Limiting을 적용하고,
+/// 응답에 `X-RateLimit-Remaining` 헤더를 추가합니다.
 pub async fn rate_limit_middleware(
     axum::extract::State(state): axum::extract::State<RateLimitState>,
     request: Request,
     next: Next,
 ) -> Response {
-    // 클라이언트 IP 추출
-    let ip = extract_client_ip(&request);
+    // 버킷 단위 결정: 인증된 API 키 → 검증된 JWT 사용자 → 클라이언트 IP
+    let credentials = ClientCredentials::from_request(&state, &request);
+    let key = credentials.authenticated_key(&state.limiter).await;
 
     // Rate limit 확인
-    match state.limiter.check(ip).await {
-        RateLimitResult::Allowed => {
+    match state.limiter.check(key.clone()).await {
+        RateLimitResult::Allowed { remaining } => {
             // 요청 허용 - 다음 핸들러로 진행
             counter!("rate_limit_requests_total", "status" => "allowed").increment(1);
-            next.run(request).await
+            let mut response = next.run(request).await;
+            response
+                .headers_mut()
+                .insert(REMAINING_HEADER, HeaderValue::from(remaining));
+            response
         }
         RateLimitResult::Limited { retry_after } => {
             // Rate limit 초과 - 429 응답
             counter!("rate_limit_requests_total", "status" => "limited").increment(1);
 
             tracing::warn!(
-                client_ip = %ip,
+                client = %key,
                 retry_after = retry_after,
                 "Rate limit exceeded"
             );
@@ -244,17 +508,70 @@ pub async fn rate_limit_middleware(
             )
                 .into_response();
 
-            // Retry-After 헤더 추가
-            response.headers_mut().insert(
+            // Retry-After / X-RateLimit-Remaining 헤더 추가
+            let headers = response.headers_mut();
+            headers.insert(
                 axum::http::header::RETRY_AFTER,
-                HeaderValue::from_str(&retry_after.to_string()).unwrap(),
+                HeaderValue::from(retry_after),
             );
+            headers.insert(REMAINING_HEADER, HeaderValue::from(0u32));
 
             response
         }
     }
 }
 
+/// 요청에서 추출한 클라이언트 식별 정보.
+///
+/// (`Request`는 `Sync`가 아니므로 await 전에 동기적으로 추출)
+struct ClientCredentials {
+    /// `X-API-Key` 헤더 값의 해시 (등록 여부는 아직 확인 전)
+    api_key: Option<RateLimitKey>,
+    /// 서명 검증에 성공한 JWT 사용자
+    user: Option<RateLimitKey>,
+    /// 클라이언트 IP
+    ip: IpAddr,
+}
+
+impl ClientCredentials {
+    fn from_request(state: &RateLimitState, request: &Request) -> Self {
+        let headers = request.headers();
+        let api_key = headers
+            .get(API_KEY_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(RateLimitKey::api_key);
+        let user = state.jwt_secret.as_deref().and_then(|secret| {
+            headers
+                .get(axum::http::header::AUTHORIZATION)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.strip_prefix("Bearer "))
+                .and_then(|token| decode_token(token, secret).ok())
+                .map(|data| RateLimitKey::User(data.claims.sub))
+        });
+
+        Self {
+            api_key,
+            user,
+            ip: extract_client_ip(request),
+        }
+    }
+
+    /// 인증을 통과한 식별자로 버킷 키 결정.
+    ///
+    /// API 키는 해시가 등록된 경우에만 인증된 것으로 보고, 그 외에는 검증된
+    /// JWT 사용자, 둘 다 없으면 클라이언트 IP를 사용합니다.
+    async fn authenticated_key(self, limiter: &RateLimiter) -> RateLimitKey {
+        if let Some(api_key) = self.api_key {
+            if limiter.has_override(&api_key.subject()).await {
+                return api_key;
+            }
+        }
+        self.user.unwrap_or(RateLimitKey::Ip(self.ip))
+    }
+}
+
 /// 요청에서 클라이언트 IP 추출.
 ///
 /// X-Forwarded-For, X-Real-IP 헤더를 우선 확인합니다 (프록시/로드밸런서 뒤에 있을 경우).
@@ -317,7 +634,10 @@ mod tests {
         let ip: IpAddr = "192.168.1.1".parse().unwrap();
 
         // 첫 요청은 허용되어야 함
-        assert!(matches!(limiter.check(ip).await, RateLimitResult::Allowed));
+        assert!(matches!(
+            limiter.check(ip).await,
+            RateLimitResult::Allowed { .. }
+        ));
     }
 
     #[tokio::test]
@@ -336,7 +656,7 @@ mod tests {
         for i in 0..max_allowed {
             let result = limiter.check(ip).await;
             assert!(
-                matches!(result, RateLimitResult::Allowed),
+                matches!(result, RateLimitResult::Allowed { .. }),
                 "Request {} should be allowed",
                 i
             );
@@ -361,14 +681,20 @@ mod tests {
         let ip2: IpAddr = "192.168.1.2".parse().unwrap();
 
         // IP1 토큰 소진
-        assert!(matches!(limiter.check(ip1).await, RateLimitResult::Allowed));
+        assert!(matches!(
+            limiter.check(ip1).await,
+            RateLimitResult::Allowed { .. }
+        ));
         assert!(matches!(
             limiter.check(ip1).await,
             RateLimitResult::Limited { .. }
         ));
 
         // IP2는 별도 버킷이므로 허용
-        assert!(matches!(limiter.check(ip2).await, RateLimitResult::Allowed));
+        assert!(matches!(
+            limiter.check(ip2).await,
+            RateLimitResult::Allowed { .. }
+        ));
     }
 
     #[tokio::test]
@@ -383,14 +709,14 @@ mod tests {
 
         // 요청으로 버킷 생성
         let _ = limiter.check(ip).await;
-        assert_eq!(limiter.tracked_ips().await, 1);
+        assert_eq!(limiter.tracked_clients().await, 1);
 
         // 정리 간격 대기
         tokio::time::sleep(Duration::from_millis(20)).await;
 
         // 정리 실행
         limiter.cleanup().await;
-        assert_eq!(limiter.tracked_ips().await, 0);
+        assert_eq!(limiter.tracked_clients().await, 0);
     }
 
     #[tokio::test]
@@ -418,7 +744,47 @@ mod tests {
         tokio::time::sleep(Duration::from_millis(20)).await;
 
         // 일부 토큰이 리필되어 허용
-        assert!(matches!(limiter.check(ip).await, RateLimitResult::Allowed));
+        assert!(matches!(
+            limiter.check(ip).await,
+            RateLimitResult::Allowed { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_update_config_applies_immediately() {
+        let limiter = RateLimiter::new(RateLimitConfig::strict(60));
+        let shared = limiter.clone();
+        let ip: IpAddr = "192.168.1.1".parse().unwrap();
+
+        assert!(matches!(
+            limiter.check(ip).await,
+            RateLimitResult::Allowed { .. }
+        ));
+        assert!(matches!(
+            limiter.check(ip).await,
+            RateLimitResult::Limited { .. }
+        ));
+
+        // 복제본을 통한 변경도 같은 설정/버킷에 반영
+        shared
+            .update_config(RateLimitConfig {
+                requests_per_minute: 60,
+                burst_size: 2,
+                cleanup_interval: Duration::from_secs(60),
+            })
+            .await;
+
+        assert_eq!(limiter.config().await.burst_size, 2);
+        for _ in 0..3 {
+            assert!(matches!(
+                limiter.check(ip).await,
+                RateLimitResult::Allowed { .. }
+            ));
+        }
+        assert!(matches!(
+            limiter.check(ip).await,
+            RateLimitResult::Limited { .. }
+        ));
     }
 
     #[test]
@@ -441,4 +807,111 @@ mod tests {
         assert_eq!(config.requests_per_minute, 100);
         assert_eq!(config.burst_size, 0);
     }
+
+    #[tokio::test]
+    async fn test_rate_limiter_separate_buckets_per_key() {
+        let limiter = RateLimiter::new(RateLimitConfig::strict(60));
+        let user = RateLimitKey::User("user-1".to_string());
+        let ip: IpAddr = "192.168.1.1".parse().unwrap();
+
+        // 사용자 버킷 소진
+        assert!(matches!(
+            limiter.check(user.clone()).await,
+            RateLimitResult::Allowed { .. }
+        ));
+        assert!(matches!(
+            limiter.check(user).await,
+            RateLimitResult::Limited { .. }
+        ));
+
+        // 다른 사용자/IP는 영향 없음
+        assert!(matches!(
+            limiter
+                .check(RateLimitKey::User("user-2".to_string()))
+                .await,
+            RateLimitResult::Allowed { .. }
+        ));
+        assert!(matches!(
+            limiter.check(ip).await,
+            RateLimitResult::Allowed { .. }
+        ));
+        assert_eq!(limiter.tracked_clients().await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_applies_override() {
+        let limiter = RateLimiter::new(RateLimitConfig::strict(60));
+        let key = RateLimitKey::api_key("partner-key");
+
+        limiter
+            .merge_overrides(parse_overrides("api_key:partner-key=60/2").unwrap())
+            .await;
+        assert!(limiter.has_override(&key.subject()).await);
+        assert!(!limiter.has_override("api_key:partner-key").await);
+
+        // 1 (초당) + 2 (버스트) = 3회 허용, 남은 요청 수 감소
+        for expected_remaining in [2, 1, 0] {
+            match limiter.check(key.clone()).await {
+                RateLimitResult::Allowed { remaining } => {
+                    assert_eq!(remaining, expected_remaining)
+                }
+                other => panic!("허용되어야 함: {:?}", other),
+            }
+        }
+        match limiter.check(key).await {
+            RateLimitResult::Limited { retry_after } => assert!(retry_after >= 1),
+            other => panic!("제한되어야 함: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_overrides() {
+        let overrides = parse_overrides("user:42=600/60, api_key:abc=120").unwrap();
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides["user:42"].requests_per_minute, 600);
+        assert_eq!(overrides["user:42"].burst_size, 60);
+        assert_eq!(
+            overrides[&format!("api_key:{}", hash_api_key("abc"))].burst_size,
+            12
+        ); // 10%
+        assert!(parse_overrides("").unwrap().is_empty());
+
+        assert!(parse_overrides("ip:1.2.3.4=10").is_err());
+        assert!(parse_overrides("user:=10").is_err());
+        assert!(parse_overrides("user:42=0").is_err());
+        assert!(parse_overrides("user:42").is_err());
+    }
+
+    #[test]
+    fn test_rate_limit_key_stores_api_key_hash() {
+        let key = RateLimitKey::api_key("abcdef123456");
+        let hash = hash_api_key("abcdef123456");
+        assert_eq!(hash.len(), 64);
+        assert_eq!(key.subject(), format!("api_key:{}", hash));
+        assert!(!key.subject().contains("abcdef123456"));
+        assert_eq!(key.to_string(), format!("api_key:{}***", &hash[..8]));
+    }
+
+    #[tokio::test]
+    async fn test_unregistered_api_key_falls_back_to_ip() {
+        let limiter = RateLimiter::new(RateLimitConfig::strict(60));
+        limiter
+            .merge_overrides(parse_overrides("api_key:partner-key=600").unwrap())
+            .await;
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+        let credentials = |raw: &str| ClientCredentials {
+            api_key: Some(RateLimitKey::api_key(raw)),
+            user: None,
+            ip,
+        };
+
+        assert_eq!(
+            credentials("partner-key").authenticated_key(&limiter).await,
+            RateLimitKey::api_key("partner-key")
+        );
+        assert_eq!(
+            credentials("forged-key").authenticated_key(&limiter).await,
+            RateLimitKey::Ip(ip)
+        );
+    }
 }
@@ -31,7 +31,7 @@ use utoipa_swagger_ui::SwaggerUi;
 use crate::error::ApiErrorResponse;
 use crate::repository::{
     signal_performance::{SignalPerformanceResponse, SignalReturnPoint, SignalSymbolStats},
-    RankedSymbol, SevenFactorData, SevenFactorResponse,
+    RankedSymbol, SettingChange, SettingHistoryRecord, SevenFactorData, SevenFactorResponse,
 };
 use crate::routes::{
     // Alert History 모듈
@@ -47,7 +47,8 @@ use crate::routes::{
     credentials::{
         DiscordSettingsResponse, EmailSettingsResponse, NotificationSettingsConfig,
         SaveDiscordSettingsRequest, SaveEmailSettingsRequest, SaveSlackSettingsRequest,
-        SaveSmsSettingsRequest, SlackSettingsResponse, SmsSettingsResponse,
+        SaveSmsSettingsRequest, SaveWebhookSettingsRequest, SlackSettingsResponse,
+        SmsSettingsResponse, WebhookSettingsResponse,
     },
     // Dataset 추가 타입
     dataset::{
@@ -65,8 +66,8 @@ use crate::routes::{
     },
     // Ranking 모듈
     ranking::{
-        CalculateResponse, FilterInfo, RankingQuery, RankingResponse, SevenFactorBatchRequest,
-        SevenFactorBatchResponse, SevenFactorQuery,
+        CalculateResponse, FilterInfo, RankingQuery, RankingResponse, ScoreBreakdownResponse,
+        SevenFactorBatchRequest, SevenFactorBatchResponse, SevenFactorQuery,
     },
     // Reality Check 모듈
     reality_check::{
@@ -82,6 +83,11 @@ use crate::routes::{
     },
     // Strategies 모듈
     strategies::{ApiError, StrategyListItem},
+    // System 모듈
+    system::{
+        SettingHistoryResponse, SystemSettingsResponse, UpdateSystemSettingsRequest,
+        UpdateSystemSettingsResponse,
+    },
     // Health 모듈
     ComponentHealth,
     ComponentStatus,
@@ -96,6 +102,7 @@ use crate::routes::{
     StatsResponse,
     StrategiesListResponse,
 };
+use crate::services::SystemSettingView;
 
 // ==================== OpenAPI 문서 정의 ====================
 
@@ -151,7 +158,7 @@ use crate::routes::{
         (name = "analytics", description = "분석 - 성과 지표 및 차트"),
         (name = "patterns", description = "패턴 - 캔들/차트 패턴 인식"),
         (name = "market", description = "시장 - 시장 상태 및 시세"),
-        (name = "credentials", description = "자격증명 - API 키 및 알림 설정 관리 (Telegram, Email, Discord, Slack, SMS)"),
+        (name = "credentials", description = "자격증명 - API 키 및 알림 설정 관리 (Telegram, Email, Discord, Slack, SMS, Webhook)"),
         (name = "notifications", description = "알림 - 텔레그램 등 알림 설정"),
         (name = "ml", description = "ML - 머신러닝 모델 훈련"),
         (name = "dataset", description = "데이터셋 - 심볼 동기화 및 데이터 관리"),
@@ -163,9 +170,11 @@ use crate::routes::{
         (name = "ranking", description = "랭킹 - GlobalScore 기반 종목 랭킹 및 7Factor 분석"),
         (name = "reality_check", description = "실제 검증 - 백테스트와 실거래 비교"),
         (name = "signal-alerts", description = "신호 알림 - 신호 기반 알림 규칙 관리"),
+        (name = "performance-alerts", description = "성과 알림 - 전략 성과 임계치 알림 규칙 및 발동 이력"),
         (name = "alerts", description = "알림 히스토리 - 발생한 알림 이력 조회"),
         (name = "schema", description = "스키마 - 전략 스키마 및 프래그먼트 조회"),
-        (name = "watchlist", description = "관심종목 - 관심종목 리스트 관리")
+        (name = "watchlist", description = "관심종목 - 관심종목 리스트 관리"),
+        (name = "system", description = "시스템 설정 - 런타임 파라미터 조회/변경 (Admin)")
     ),
     // ==================== 스키마 등록 ====================
     components(
@@ -219,6 +228,7 @@ use crate::routes::{
             SevenFactorBatchRequest,
             SevenFactorBatchResponse,
             SevenFactorResponse,
+            ScoreBreakdownResponse,
             SevenFactorData,
 
             // ===== Patterns =====
@@ -258,10 +268,21 @@ use crate::routes::{
             SlackSettingsResponse,
             SaveSmsSettingsRequest,
             SmsSettingsResponse,
+            SaveWebhookSettingsRequest,
+            WebhookSettingsResponse,
 
             // ===== Alert History =====
             FrontendAlertHistoryResponse,
 
+            // ===== System Settings =====
+            SystemSettingView,
+            SystemSettingsResponse,
+            UpdateSystemSettingsRequest,
+            UpdateSystemSettingsResponse,
+            SettingChange,
+            SettingHistoryRecord,
+            SettingHistoryResponse,
+
             // ===== Reality Check =====
             RcStatsResponse,
             RcResultsResponse,
@@ -308,6 +329,8 @@ use crate::routes::{
         crate::routes::strategies::update_risk_settings,
         crate::routes::strategies::update_symbols,
         crate::routes::strategies::clone_strategy,
+        crate::routes::strategies::export_strategy,
+        crate::routes::strategies::import_strategy,
         crate::routes::strategies::get_engine_stats,
         crate::routes::strategies::get_strategy_timeframes,
         crate::routes::strategies::update_strategy_timeframes,
@@ -321,6 +344,11 @@ use crate::routes::{
         crate::routes::monitoring::clear_errors,
         crate::routes::monitoring::get_summary,
 
+        // ===== System Settings =====
+        crate::routes::system::get_settings,
+        crate::routes::system::update_settings,
+        crate::routes::system::get_settings_history,
+
         // ===== Screening =====
         crate::routes::screening::run_screening,
         crate::routes::screening::list_presets,
@@ -343,6 +371,7 @@ use crate::routes::{
         crate::routes::ranking::get_top_ranked,
         crate::routes::ranking::get_seven_factor,
         crate::routes::ranking::get_seven_factor_batch,
+        crate::routes::ranking::get_score_breakdown,
         crate::routes::ranking::get_score_history,
 
         // ===== Patterns =====
@@ -386,6 +415,10 @@ use crate::routes::{
         crate::routes::backtest::get_backtest_result,
         crate::routes::backtest::run_multi_backtest,
         crate::routes::backtest::run_batch_backtest,
+        crate::routes::backtest::jobs::submit_backtest_job,
+        crate::routes::backtest::jobs::list_backtest_jobs,
+        crate::routes::backtest::jobs::get_backtest_job,
+        crate::routes::backtest::jobs::cancel_backtest_job,
 
         // ===== Orders =====
         crate::routes::orders::create_order,
@@ -397,6 +430,7 @@ use crate::routes::{
         // ===== Positions =====
         crate::routes::positions::list_positions,
         crate::routes::positions::get_positions_summary,
+        crate::routes::positions::get_reconciliation_status,
         crate::routes::positions::get_position,
 
         // ===== Portfolio =====
@@ -454,6 +488,11 @@ use crate::routes::{
         crate::routes::credentials::email::test_new_email_settings,
         crate::routes::credentials::slack::test_new_slack_settings,
         crate::routes::credentials::sms::test_new_sms_settings,
+        crate::routes::credentials::webhook::get_webhook_settings,
+        crate::routes::credentials::webhook::save_webhook_settings,
+        crate::routes::credentials::webhook::delete_webhook_settings,
+        crate::routes::credentials::webhook::test_webhook_settings,
+        crate::routes::credentials::webhook::test_new_webhook_settings,
 
         // ===== Alert History =====
         crate::routes::alert_history::list_alert_history,
@@ -477,11 +516,24 @@ use crate::routes::{
         crate::routes::signal_alerts::update_alert_rule,
         crate::routes::signal_alerts::delete_alert_rule,
 
+        // ===== Performance Alerts =====
+        crate::routes::performance_alerts::create_performance_alert,
+        crate::routes::performance_alerts::list_performance_alerts,
+        crate::routes::performance_alerts::get_performance_alert,
+        crate::routes::performance_alerts::update_performance_alert,
+        crate::routes::performance_alerts::delete_performance_alert,
+        crate::routes::performance_alerts::reset_performance_alert,
+        crate::routes::performance_alerts::list_performance_alert_history,
+
         // ===== Backtest Results =====
         crate::routes::backtest_results::list_backtest_results,
         crate::routes::backtest_results::save_backtest_result,
         crate::routes::backtest_results::get_backtest_result,
         crate::routes::backtest_results::delete_backtest_result,
+        crate::routes::backtest_results::get_backtest_result_diagnosis,
+        crate::routes::backtest_results::get_backtest_history,
+        crate::routes::backtest_results::diff_backtest_results,
+        crate::routes::backtest_results::prune_backtest_results,
 
         // ===== Schema =====
         crate::routes::schema::list_strategy_meta,
@@ -532,6 +584,8 @@ use crate::routes::{
         crate::routes::credentials::exchange::delete_exchange_credential,
         crate::routes::credentials::exchange::test_exchange_credential,
         crate::routes::credentials::exchange::test_new_exchange_credential,
+        crate::routes::credentials::exchange_bulk::bulk_export_exchange_credentials,
+        crate::routes::credentials::exchange_bulk::bulk_import_exchange_credentials,
 
         // ===== Credentials (Telegram) =====
         crate::routes::credentials::telegram::get_telegram_settings,
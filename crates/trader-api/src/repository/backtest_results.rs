@@ -2,12 +2,16 @@
 //!
 //! 백테스트 결과를 PostgreSQL에 영구 저장하고 조회하는 기능을 제공합니다.
 //! Soft delete 패턴을 사용하여 데이터 무결성을 보장합니다.
+//!
+//! 저장 시 설정 해시(`config_hash`)가 같은 활성 결과가 있으면 새로 추가하지 않고
+//! 기존 결과를 최신 실행으로 갱신합니다.
 
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::Serialize;
 use sqlx::{FromRow, PgPool};
 use tracing::{debug, info, warn};
+use trader_analytics::backtest::{BacktestRun, BacktestRunKey, RetentionPolicy, RunMetrics};
 use uuid::Uuid;
 
 // ==================== DB 레코드 ====================
@@ -33,6 +37,32 @@ pub struct BacktestResultRecord {
     pub deleted_at: Option<DateTime<Utc>>,
     /// 백테스트에 사용된 타임프레임 설정 (다중 TF 백테스트 시)
     pub timeframes_used: Option<serde_json::Value>,
+    /// 설정 해시 (해시 도입 이전 결과는 None)
+    pub config_hash: Option<String>,
+    /// 전략 파라미터
+    pub parameters: serde_json::Value,
+}
+
+/// 이력 비교용 경량 레코드 (자산 곡선/거래 내역 제외).
+#[derive(Debug, Clone, FromRow)]
+struct BacktestRunRow {
+    id: Uuid,
+    config_hash: Option<String>,
+    parameters: serde_json::Value,
+    metrics: serde_json::Value,
+    created_at: DateTime<Utc>,
+}
+
+impl BacktestRunRow {
+    fn into_run(self) -> BacktestRun {
+        BacktestRun {
+            id: self.id,
+            config_hash: self.config_hash,
+            parameters: self.parameters,
+            created_at: self.created_at,
+            metrics: RunMetrics::from_json(&self.metrics),
+        }
+    }
 }
 
 // ==================== 요청/응답 타입 ====================
@@ -66,6 +96,41 @@ pub struct BacktestResultInput {
     pub success: bool,
     /// 백테스트에 사용된 타임프레임 설정 (다중 TF 백테스트 시)
     pub timeframes_used: Option<serde_json::Value>,
+    /// 전략 파라미터 (설정 해시 및 실행 간 비교에 사용)
+    pub parameters: serde_json::Value,
+}
+
+impl BacktestResultInput {
+    /// 동일 실행 판별용 설정 해시.
+    ///
+    /// 수수료율은 별도 컬럼이 없으므로 `config_summary.commission_rate`에서 읽습니다.
+    pub fn config_hash(&self) -> String {
+        let commission_rate = self
+            .config_summary
+            .get("commission_rate")
+            .and_then(|v| serde_json::from_value::<Decimal>(v.clone()).ok());
+
+        BacktestRunKey {
+            strategy_id: self.strategy_id.clone(),
+            symbol: self.symbol.clone(),
+            start_date: self.start_date,
+            end_date: self.end_date,
+            initial_capital: self.initial_capital,
+            commission_rate,
+            slippage_rate: self.slippage_rate,
+            parameters: self.parameters.clone(),
+        }
+        .config_hash()
+    }
+}
+
+/// 결과 저장 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SaveOutcome {
+    /// 결과 ID
+    pub id: Uuid,
+    /// 동일 설정의 기존 결과를 갱신했는지 여부
+    pub replaced: bool,
 }
 
 /// 저장된 결과 응답용 DTO.
@@ -88,6 +153,11 @@ pub struct BacktestResultDto {
     /// 백테스트에 사용된 타임프레임 설정
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeframes_used: Option<serde_json::Value>,
+    /// 설정 해시
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config_hash: Option<String>,
+    /// 전략 파라미터
+    pub parameters: serde_json::Value,
 }
 
 impl From<BacktestResultRecord> for BacktestResultDto {
@@ -108,6 +178,8 @@ impl From<BacktestResultRecord> for BacktestResultDto {
             success: record.success,
             created_at: record.created_at.to_rfc3339(),
             timeframes_used: record.timeframes_used,
+            config_hash: record.config_hash,
+            parameters: record.parameters,
         }
     }
 }
@@ -173,18 +245,39 @@ pub struct BacktestResultsRepository;
 
 impl BacktestResultsRepository {
     /// 백테스트 결과 저장.
-    pub async fn save(pool: &PgPool, input: BacktestResultInput) -> Result<Uuid, sqlx::Error> {
-        debug!("백테스트 결과 저장: strategy_id={}", input.strategy_id);
-
-        let row: (Uuid,) = sqlx::query_as(
+    ///
+    /// 같은 전략에 설정 해시가 같은 활성 결과가 있으면 그 결과를 최신 실행으로 갱신합니다.
+    pub async fn save(
+        pool: &PgPool,
+        input: BacktestResultInput,
+    ) -> Result<SaveOutcome, sqlx::Error> {
+        let config_hash = input.config_hash();
+        debug!(
+            "백테스트 결과 저장: strategy_id={}, config_hash={}",
+            input.strategy_id, config_hash
+        );
+
+        // xmax = 0 이면 신규 INSERT, 아니면 ON CONFLICT UPDATE
+        let row: (Uuid, bool) = sqlx::query_as(
             r#"
             INSERT INTO backtest_results (
                 strategy_id, strategy_type, symbol, start_date, end_date,
                 initial_capital, slippage_rate, metrics, config_summary,
-                equity_curve, trades, success, timeframes_used
+                equity_curve, trades, success, timeframes_used, parameters, config_hash
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
-            RETURNING id
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            ON CONFLICT (strategy_id, config_hash)
+                WHERE deleted_at IS NULL AND config_hash IS NOT NULL
+            DO UPDATE SET
+                strategy_type = EXCLUDED.strategy_type,
+                metrics = EXCLUDED.metrics,
+                config_summary = EXCLUDED.config_summary,
+                equity_curve = EXCLUDED.equity_curve,
+                trades = EXCLUDED.trades,
+                success = EXCLUDED.success,
+                timeframes_used = EXCLUDED.timeframes_used,
+                created_at = NOW()
+            RETURNING id, (xmax = 0) AS inserted
             "#,
         )
         .bind(&input.strategy_id)
@@ -200,11 +293,22 @@ impl BacktestResultsRepository {
         .bind(&input.trades)
         .bind(input.success)
         .bind(&input.timeframes_used)
+        .bind(&input.parameters)
+        .bind(&config_hash)
         .fetch_one(pool)
         .await?;
 
-        info!("백테스트 결과 저장 완료: id={}", row.0);
-        Ok(row.0)
+        let (id, inserted) = row;
+        if inserted {
+            info!("백테스트 결과 저장 완료: id={}", id);
+        } else {
+            info!("동일 설정의 백테스트 결과 갱신: id={}", id);
+        }
+
+        Ok(SaveOutcome {
+            id,
+            replaced: !inserted,
+        })
     }
 
     /// 백테스트 결과 조회 (단일).
@@ -218,7 +322,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   config_hash, parameters
             FROM backtest_results
             WHERE id = $1 AND deleted_at IS NULL
             "#,
@@ -259,7 +364,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   config_hash, parameters
             FROM backtest_results
             WHERE deleted_at IS NULL
               AND ($1::text IS NULL OR strategy_id = $1)
@@ -317,7 +423,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   config_hash, parameters
             FROM backtest_results
             WHERE strategy_id = $1 AND deleted_at IS NULL
             ORDER BY created_at DESC
@@ -341,7 +448,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   config_hash, parameters
             FROM backtest_results
             WHERE deleted_at IS NULL
             ORDER BY created_at DESC
@@ -355,6 +463,95 @@ impl BacktestResultsRepository {
         Ok(records)
     }
 
+    /// 전략의 실행 이력 조회 (최신순).
+    ///
+    /// 비교용 지표와 파라미터만 읽고 자산 곡선/거래 내역은 제외합니다.
+    pub async fn list_runs(
+        pool: &PgPool,
+        strategy_id: &str,
+        limit: i64,
+    ) -> Result<Vec<BacktestRun>, sqlx::Error> {
+        debug!(
+            "백테스트 실행 이력 조회: strategy_id={}, limit={}",
+            strategy_id, limit
+        );
+
+        let rows = sqlx::query_as::<_, BacktestRunRow>(
+            r#"
+            SELECT id, config_hash, parameters, metrics, created_at
+            FROM backtest_results
+            WHERE strategy_id = $1 AND deleted_at IS NULL AND success = TRUE
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(BacktestRunRow::into_run).collect())
+    }
+
+    /// 단일 실행 조회 (비교용).
+    pub async fn get_run(pool: &PgPool, id: Uuid) -> Result<Option<BacktestRun>, sqlx::Error> {
+        let row = sqlx::query_as::<_, BacktestRunRow>(
+            r#"
+            SELECT id, config_hash, parameters, metrics, created_at
+            FROM backtest_results
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(BacktestRunRow::into_run))
+    }
+
+    /// 보존 정책에 따라 오래된 결과 정리 (soft delete).
+    ///
+    /// `strategy_id`가 None이면 모든 전략에 적용합니다. 정리된 건수를 반환합니다.
+    pub async fn prune(
+        pool: &PgPool,
+        strategy_id: Option<&str>,
+        policy: RetentionPolicy,
+    ) -> Result<u64, sqlx::Error> {
+        let cutoff = policy.cutoff(Utc::now());
+
+        let result = sqlx::query(
+            r#"
+            UPDATE backtest_results
+            SET deleted_at = NOW()
+            WHERE id IN (
+                SELECT id FROM (
+                    SELECT id, created_at,
+                           ROW_NUMBER() OVER (PARTITION BY strategy_id ORDER BY created_at DESC) AS rn
+                    FROM backtest_results
+                    WHERE deleted_at IS NULL
+                      AND ($1::text IS NULL OR strategy_id = $1)
+                ) ranked
+                WHERE rn > $2 OR ($3::timestamptz IS NOT NULL AND created_at < $3)
+            )
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(policy.max_runs_per_strategy as i64)
+        .bind(cutoff)
+        .execute(pool)
+        .await?;
+
+        let pruned = result.rows_affected();
+        if pruned > 0 {
+            info!(
+                "백테스트 결과 정리: strategy_id={:?}, {}건",
+                strategy_id, pruned
+            );
+        }
+
+        Ok(pruned)
+    }
+
     /// 결과 개수 조회.
     pub async fn count(pool: &PgPool, strategy_id: Option<&str>) -> Result<i64, sqlx::Error> {
         let count: Option<i64> = if let Some(sid) = strategy_id {
@@ -423,6 +620,8 @@ mod tests {
             created_at: Utc::now(),
             deleted_at: None,
             timeframes_used: None,
+            config_hash: Some("abc".to_string()),
+            parameters: serde_json::json!({"fast_period": 10}),
         };
 
         let dto: BacktestResultDto = record.into();
@@ -430,5 +629,39 @@ mod tests {
         assert_eq!(dto.strategy_id, "test-strategy");
         assert_eq!(dto.strategy_type, "sma_crossover");
         assert!(dto.success);
+        assert_eq!(dto.config_hash.as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn test_input_config_hash_uses_parameters_and_commission() {
+        let input = BacktestResultInput {
+            strategy_id: "rsi".to_string(),
+            strategy_type: "rsi".to_string(),
+            symbol: "005930".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            initial_capital: dec!(10000000),
+            slippage_rate: Some(dec!(0.0005)),
+            metrics: serde_json::json!({}),
+            config_summary: serde_json::json!({"commission_rate": 0.001, "data_points": 240}),
+            equity_curve: serde_json::json!([]),
+            trades: serde_json::json!([]),
+            success: true,
+            timeframes_used: None,
+            parameters: serde_json::json!({"period": 14}),
+        };
+
+        // 결과 값(data_points 등)은 해시에 영향 없음
+        let mut rerun = input.clone();
+        rerun.config_summary = serde_json::json!({"commission_rate": 0.001, "data_points": 241});
+        assert_eq!(input.config_hash(), rerun.config_hash());
+
+        let mut changed = input.clone();
+        changed.parameters = serde_json::json!({"period": 21});
+        assert_ne!(input.config_hash(), changed.config_hash());
+
+        let mut costlier = input.clone();
+        costlier.config_summary = serde_json::json!({"commission_rate": 0.002});
+        assert_ne!(input.config_hash(), costlier.config_hash());
     }
 }
@@ -16,11 +16,12 @@ use trader_core::{CredentialEncryptor, ExchangeProvider, MarketDataProvider};
 use trader_exchange::{
     connector::kis::{KisAccountType, KisClient, KisConfig, KisOAuth},
     provider::{
-        BithumbProvider, DbInvestmentProvider, KisProvider, LsSecProvider, MockConfig,
-        MockExchangeProvider, UpbitProvider,
+        BithumbProvider, BybitProvider, DbInvestmentProvider, InstrumentedProvider, KisProvider,
+        LsSecProvider, MockConfig, MockExchangeProvider, UpbitProvider,
     },
-    BithumbClient, BithumbConfig, DbInvestmentClient, DbInvestmentConfig, LsSecClient, LsSecConfig,
-    UpbitClient, UpbitConfig,
+    BithumbClient, BithumbConfig, BybitCategory, BybitClient, BybitConfig, DbInvestmentClient,
+    DbInvestmentConfig, LsSecClient, LsSecConfig, RequestLogConfig, RequestLogger, UpbitClient,
+    UpbitConfig,
 };
 
 use super::kis_token::KisTokenRepository;
@@ -37,6 +38,16 @@ pub struct ProviderBundle {
     /// 시세 데이터 조회용
     pub market_data: Arc<dyn MarketDataProvider>,
 }
+
+impl ProviderBundle {
+    /// 두 provider를 API 호출 메트릭 계측 래퍼로 감쌉니다.
+    fn instrumented(self, exchange_id: &str) -> Self {
+        Self {
+            exchange: Arc::new(InstrumentedProvider::new(self.exchange, exchange_id)),
+            market_data: Arc::new(InstrumentedProvider::new(self.market_data, exchange_id)),
+        }
+    }
+}
 use uuid::Uuid;
 
 /// 암호화된 credential 구조
@@ -298,8 +309,11 @@ pub async fn create_exchange_providers_from_credential(
     };
 
     // 8. 통합 KisClient 생성 (내부적으로 KR/US 클라이언트 자동 생성)
-    let client =
-        Arc::new(KisClient::new(oauth).map_err(|e| format!("KIS 클라이언트 생성 실패: {}", e))?);
+    let client = Arc::new(
+        KisClient::new(oauth)
+            .map_err(|e| format!("KIS 클라이언트 생성 실패: {}", e))?
+            .with_request_logger(request_logger()),
+    );
 
     // 9. ExchangeProvider로 래핑 (KisExchangeProvider가 KR+US 모두 처리)
     let provider: Arc<dyn ExchangeProvider> = Arc::new(KisProvider::new(client));
@@ -397,7 +411,9 @@ pub async fn create_kis_client_from_credential(
 
     // 통합 KisClient 생성
     Ok(Arc::new(
-        KisClient::new(oauth).map_err(|e| format!("KIS 클라이언트 생성 실패: {}", e))?,
+        KisClient::new(oauth)
+            .map_err(|e| format!("KIS 클라이언트 생성 실패: {}", e))?
+            .with_request_logger(request_logger()),
     ))
 }
 
@@ -430,12 +446,38 @@ async fn load_and_decrypt_credential(
     Ok((credentials, row))
 }
 
+/// 거래소 요청/응답 로거 생성.
+///
+/// 상세도는 `EXCHANGE_REQUEST_LOG`, `EXCHANGE_REQUEST_LOG_MAX_BODY` 환경변수로 조절합니다.
+fn request_logger() -> Arc<RequestLogger> {
+    Arc::new(RequestLogger::new(RequestLogConfig::from_env()))
+}
+
+/// Bybit 클라이언트 설정 생성.
+///
+/// settings의 `category`가 `"linear"`면 USDT 무기한, 그 외는 현물로 연결합니다.
+fn bybit_config(creds: EncryptedCredentials, row: &CredentialRow) -> BybitConfig {
+    let category = match row
+        .settings
+        .as_ref()
+        .and_then(|s| s.get("category"))
+        .and_then(|v| v.as_str())
+    {
+        Some("linear") => BybitCategory::Linear,
+        _ => BybitCategory::Spot,
+    };
+
+    BybitConfig::new(creds.api_key, creds.api_secret)
+        .with_testnet(row.is_testnet)
+        .with_category(category)
+}
+
 /// 거래소 중립적 Provider 생성
 ///
 /// exchange_id에 따라 적절한 ExchangeProvider를 생성합니다.
 /// - mock: MockExchangeProvider (API 키 불필요, DB에서 상태 관리)
 /// - kis: KIS Provider (KR 마켓용)
-/// - upbit, bithumb: 암호화폐 거래소
+/// - upbit, bithumb, bybit: 암호화폐 거래소
 /// - db_investment, ls_sec: 국내 증권사
 ///
 /// # Arguments
@@ -466,7 +508,7 @@ pub async fn create_provider_for_credential(
         exchange_id, credential_id
     );
 
-    match exchange_id.as_str() {
+    let provider: Result<Arc<dyn ExchangeProvider>, String> = match exchange_id.as_str() {
         "mock" => create_mock_provider(pool, credential_id).await,
         "kis" => {
             // KIS는 기존 로직 재사용 (KR Provider 반환)
@@ -481,7 +523,7 @@ pub async fn create_provider_for_credential(
                 access_key: creds.api_key,
                 secret_key: creds.api_secret,
             };
-            let client = Arc::new(UpbitClient::new(config));
+            let client = Arc::new(UpbitClient::new(config).with_request_logger(request_logger()));
             info!("Upbit Provider 생성 완료: credential_id={}", credential_id);
             Ok(Arc::new(UpbitProvider::new(client)))
         }
@@ -498,6 +540,14 @@ pub async fn create_provider_for_credential(
             );
             Ok(Arc::new(BithumbProvider::new(client)))
         }
+        "bybit" => {
+            let (creds, row) = load_and_decrypt_credential(pool, encryptor, credential_id).await?;
+            let client = Arc::new(
+                BybitClient::new(bybit_config(creds, &row)).with_request_logger(request_logger()),
+            );
+            info!("Bybit Provider 생성 완료: credential_id={}", credential_id);
+            Ok(Arc::new(BybitProvider::new(client)))
+        }
         "db_investment" => {
             let (creds, row) = load_and_decrypt_credential(pool, encryptor, credential_id).await?;
             let config = DbInvestmentConfig {
@@ -514,11 +564,12 @@ pub async fn create_provider_for_credential(
             Ok(Arc::new(DbInvestmentProvider::new(client)))
         }
         "ls_sec" => {
-            let (creds, _row) = load_and_decrypt_credential(pool, encryptor, credential_id).await?;
+            let (creds, row) = load_and_decrypt_credential(pool, encryptor, credential_id).await?;
             let config = LsSecConfig {
                 app_key: creds.api_key,
                 app_secret: creds.api_secret,
                 base_url: "https://openapi.ls-sec.co.kr:8080".to_string(),
+                is_virtual: row.is_testnet,
             };
             let client = Arc::new(LsSecClient::new(config));
             info!(
@@ -528,7 +579,9 @@ pub async fn create_provider_for_credential(
             Ok(Arc::new(LsSecProvider::new(client)))
         }
         _ => Err(format!("지원하지 않는 거래소입니다: {}", exchange_id)),
-    }
+    };
+
+    Ok(Arc::new(InstrumentedProvider::new(provider?, exchange_id)))
 }
 
 /// Mock 거래소용 Provider 생성 (encryptor 불필요)
@@ -611,12 +664,36 @@ pub async fn create_mock_provider(
         .unwrap_or("KRW")
         .to_string();
 
+    let base = MockConfig::for_market(&market_type);
+    let lot_size = settings
+        .as_ref()
+        .and_then(|s| s.get("lot_size"))
+        .and_then(|v| v.as_str())
+        .and_then(|s| s.parse::<Decimal>().ok())
+        .unwrap_or(base.lot_size); // 기본: 시장별 주문 단위
+                                   // 실패 주입 (에러 처리 검증용, 기본: 없음)
+    let failure_injection = settings
+        .as_ref()
+        .and_then(|s| s.get("failure_injection"))
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+    // 수량 누락 신호 처리 ("default_one" | "reject", 기본: 1단위로 간주)
+    let missing_quantity = settings
+        .as_ref()
+        .and_then(|s| s.get("missing_quantity"))
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+
     let config = MockConfig {
         initial_balance,
         commission_rate,
         slippage_rate,
         market_type,
         currency,
+        lot_size,
+        failure_injection,
+        missing_quantity,
+        ..base
     };
 
     info!(
@@ -683,12 +760,36 @@ pub async fn create_mock_provider_concrete(
         .unwrap_or("KRW")
         .to_string();
 
+    let base = MockConfig::for_market(&market_type);
+    let lot_size = settings
+        .as_ref()
+        .and_then(|s| s.get("lot_size"))
+        .and_then(|v| v.as_str())
+        .and_then(|s| s.parse::<Decimal>().ok())
+        .unwrap_or(base.lot_size); // 기본: 시장별 주문 단위
+                                   // 실패 주입 (에러 처리 검증용, 기본: 없음)
+    let failure_injection = settings
+        .as_ref()
+        .and_then(|s| s.get("failure_injection"))
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+    // 수량 누락 신호 처리 ("default_one" | "reject", 기본: 1단위로 간주)
+    let missing_quantity = settings
+        .as_ref()
+        .and_then(|s| s.get("missing_quantity"))
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+
     let config = MockConfig {
         initial_balance,
         commission_rate,
         slippage_rate,
         market_type,
         currency,
+        lot_size,
+        failure_injection,
+        missing_quantity,
+        ..base
     };
 
     info!(
@@ -720,7 +821,7 @@ pub async fn create_provider_bundle(
             .map_err(|e| format!("exchange_id 조회 실패: {}", e))?
             .ok_or_else(|| "해당 credential을 찾을 수 없습니다.".to_string())?;
 
-    match exchange_id.as_str() {
+    let bundle = match exchange_id.as_str() {
         "mock" => {
             // Mock: 동일 인스턴스를 두 trait에 사용
             let provider = create_mock_provider_concrete(pool, credential_id).await?;
@@ -752,7 +853,7 @@ pub async fn create_provider_bundle(
                 access_key: creds.api_key,
                 secret_key: creds.api_secret,
             };
-            let client = Arc::new(UpbitClient::new(config));
+            let client = Arc::new(UpbitClient::new(config).with_request_logger(request_logger()));
             let provider = Arc::new(UpbitProvider::new(client));
             Ok(ProviderBundle {
                 exchange: provider.clone(),
@@ -773,6 +874,18 @@ pub async fn create_provider_bundle(
                 market_data: provider,
             })
         }
+        "bybit" => {
+            let encryptor = encryptor.ok_or("Bybit은 encryptor가 필요합니다.")?;
+            let (creds, row) = load_and_decrypt_credential(pool, encryptor, credential_id).await?;
+            let client = Arc::new(
+                BybitClient::new(bybit_config(creds, &row)).with_request_logger(request_logger()),
+            );
+            let provider = Arc::new(BybitProvider::new(client));
+            Ok(ProviderBundle {
+                exchange: provider.clone(),
+                market_data: provider,
+            })
+        }
         "db_investment" => {
             let encryptor = encryptor.ok_or("DB Investment는 encryptor가 필요합니다.")?;
             let (creds, row) = load_and_decrypt_credential(pool, encryptor, credential_id).await?;
@@ -791,11 +904,12 @@ pub async fn create_provider_bundle(
         }
         "ls_sec" => {
             let encryptor = encryptor.ok_or("LS Securities는 encryptor가 필요합니다.")?;
-            let (creds, _row) = load_and_decrypt_credential(pool, encryptor, credential_id).await?;
+            let (creds, row) = load_and_decrypt_credential(pool, encryptor, credential_id).await?;
             let config = LsSecConfig {
                 app_key: creds.api_key,
                 app_secret: creds.api_secret,
                 base_url: "https://openapi.ls-sec.co.kr:8080".to_string(),
+                is_virtual: row.is_testnet,
             };
             let client = Arc::new(LsSecClient::new(config));
             let provider = Arc::new(LsSecProvider::new(client));
@@ -805,7 +919,9 @@ pub async fn create_provider_bundle(
             })
         }
         _ => Err(format!("지원하지 않는 거래소: {}", exchange_id)),
-    }
+    }?;
+
+    Ok(bundle.instrumented(&exchange_id))
 }
 
 /// KIS 통합 클라이언트 생성 (공유 OAuth 사용)
@@ -912,8 +1028,9 @@ async fn create_kis_client(
     }
 
     // 통합 KisClient 생성 (내부적으로 KR/US 클라이언트 자동 생성)
-    let client =
-        KisClient::new(oauth_arc).map_err(|e| format!("KIS 클라이언트 생성 실패: {}", e))?;
+    let client = KisClient::new(oauth_arc)
+        .map_err(|e| format!("KIS 클라이언트 생성 실패: {}", e))?
+        .with_request_logger(request_logger());
 
     Ok(Arc::new(client))
 }
@@ -13,7 +13,9 @@ pub mod global_score;
 pub mod journal;
 pub mod kis_token;
 pub mod klines;
+pub mod order_store;
 pub mod orders;
+pub mod performance_alert;
 pub mod portfolio;
 pub mod positions;
 pub mod reality_check;
@@ -26,6 +28,7 @@ pub mod strategies;
 pub mod strategy_watched_tickers;
 pub mod symbol_fundamental;
 pub mod symbol_info;
+pub mod system_settings;
 pub mod watchlist;
 
 pub use alerts::{
@@ -34,7 +37,7 @@ pub use alerts::{
 };
 pub use backtest_results::{
     BacktestResultDto, BacktestResultInput, BacktestResultRecord, BacktestResultsRepository,
-    ListResultsFilter, ListResultsResponse as BacktestListResponse,
+    ListResultsFilter, ListResultsResponse as BacktestListResponse, SaveOutcome,
 };
 pub use cost_basis::{
     build_tracker_from_executions, CostBasisSummary, CostBasisTracker, FifoSaleResult, Lot,
@@ -84,7 +87,12 @@ pub use journal::{
 };
 pub use kis_token::KisTokenRepository;
 pub use klines::{CacheMetadata, KlineRecord, KlinesRepository, NewKline};
+pub use order_store::PgOrderStore;
 pub use orders::{Order, OrderInput, OrderRepository, OrderStatus};
+pub use performance_alert::{
+    CreatePerformanceAlertRequest, PerformanceAlertHistory, PerformanceAlertRepository,
+    PerformanceAlertRule, UpdatePerformanceAlertRequest,
+};
 pub use portfolio::{PortfolioRepository, Position, PositionUpdate};
 pub use positions::{
     HoldingPosition, PositionInput, PositionRecord, PositionRepository,
@@ -120,6 +128,9 @@ pub use symbol_info::{
     FetchFailureResult, NewSymbolInfo, SymbolInfo, SymbolInfoRepository, SymbolSearchResult,
     MAX_FETCH_FAILURES,
 };
+pub use system_settings::{
+    SettingChange, SettingHistoryRecord, SystemSettingsRepository, RUNTIME_SETTING_PREFIX,
+};
 pub use watchlist::{
     NewWatchlist, NewWatchlistItem, UpdateWatchlistItem, WatchlistItemRecord, WatchlistRecord,
     WatchlistRepository, WatchlistWithCount,
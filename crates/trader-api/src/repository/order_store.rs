@@ -0,0 +1,154 @@
+//! OrderManager 주문 상태 저장소.
+//!
+//! trader-execution의 [`OrderStore`]를 PostgreSQL로 구현하여
+//! API 서버 재시작 후에도 미완료 주문 추적과 처리된 체결 기록을 유지합니다.
+//!
+//! - `order_manager_orders`: 주문 스냅샷 (JSONB)
+//! - `order_manager_fills`: 체결 기록
+//! - `order_processed_executions`: 반영 완료된 거래소 체결 ID
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use sqlx::PgPool;
+use trader_core::{Order, OrderStatusType};
+use trader_execution::{OrderFill, OrderStore, OrderStoreError};
+use uuid::Uuid;
+
+/// PostgreSQL 기반 주문 상태 저장소.
+#[derive(Clone)]
+pub struct PgOrderStore {
+    pool: PgPool,
+}
+
+impl PgOrderStore {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 주문 상태의 직렬화 문자열 (예: `partially_filled`).
+    fn status_str(status: OrderStatusType) -> Result<String, OrderStoreError> {
+        match serde_json::to_value(status) {
+            Ok(Value::String(s)) => Ok(s),
+            Ok(other) => Err(OrderStoreError::Serialization(format!(
+                "unexpected status value: {}",
+                other
+            ))),
+            Err(e) => Err(OrderStoreError::Serialization(e.to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl OrderStore for PgOrderStore {
+    async fn upsert_order(&self, order: &Order) -> Result<(), OrderStoreError> {
+        let snapshot = serde_json::to_value(order)
+            .map_err(|e| OrderStoreError::Serialization(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO order_manager_orders (
+                order_id, exchange, exchange_order_id, ticker, status,
+                snapshot, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (order_id) DO UPDATE
+                SET exchange_order_id = EXCLUDED.exchange_order_id,
+                    status = EXCLUDED.status,
+                    snapshot = EXCLUDED.snapshot,
+                    updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(order.id)
+        .bind(&order.exchange)
+        .bind(&order.exchange_order_id)
+        .bind(&order.ticker)
+        .bind(Self::status_str(order.status)?)
+        .bind(snapshot)
+        .bind(order.created_at)
+        .bind(order.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| OrderStoreError::Store(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn insert_fill(&self, fill: &OrderFill) -> Result<(), OrderStoreError> {
+        sqlx::query(
+            r#"
+            INSERT INTO order_manager_fills (
+                order_id, quantity, price, commission, commission_asset, filled_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(fill.order_id)
+        .bind(fill.quantity)
+        .bind(fill.price)
+        .bind(fill.commission)
+        .bind(&fill.commission_asset)
+        .bind(fill.timestamp)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| OrderStoreError::Store(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn mark_execution_processed(
+        &self,
+        execution_id: &str,
+        order_id: Uuid,
+    ) -> Result<(), OrderStoreError> {
+        sqlx::query(
+            r#"
+            INSERT INTO order_processed_executions (execution_id, order_id)
+            VALUES ($1, $2)
+            ON CONFLICT (execution_id) DO NOTHING
+            "#,
+        )
+        .bind(execution_id)
+        .bind(order_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| OrderStoreError::Store(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn load_open_orders(&self) -> Result<Vec<Order>, OrderStoreError> {
+        let rows: Vec<(Value,)> = sqlx::query_as(
+            r#"
+            SELECT snapshot
+            FROM order_manager_orders
+            WHERE status IN ('pending', 'open', 'partially_filled')
+            ORDER BY created_at
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| OrderStoreError::Store(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|(snapshot,)| {
+                serde_json::from_value(snapshot)
+                    .map_err(|e| OrderStoreError::Serialization(e.to_string()))
+            })
+            .collect()
+    }
+
+    async fn load_processed_execution_ids(
+        &self,
+        order_ids: &[Uuid],
+    ) -> Result<HashSet<String>, OrderStoreError> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT execution_id FROM order_processed_executions WHERE order_id = ANY($1)",
+        )
+        .bind(order_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| OrderStoreError::Store(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|(id,)| id).collect())
+    }
+}
@@ -0,0 +1,386 @@
+//! 전략 성과 알림 규칙 리포지토리.
+//!
+//! 전략별 성과 알림 규칙 CRUD, 발동/리셋 상태 관리, 발동 이력 조회를 제공합니다.
+
+use axum::{http::StatusCode, Json};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    error::{internal_error, not_found, ApiErrorResponse, ApiResult, BoxedApiError},
+    services::{AlertComparison, PerformanceAlertCondition, PerformanceMetric},
+};
+
+const RULE_COLUMNS: &str = "id, strategy_id, rule_name, metric, comparison, threshold, enabled, \
+                            triggered_at, created_at, updated_at";
+
+/// 전략 성과 알림 규칙 엔티티.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct PerformanceAlertRule {
+    /// 규칙 ID
+    pub id: Uuid,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 규칙 이름
+    pub rule_name: String,
+    /// 평가 지표 (return_pct, total_pnl, drawdown_pct, trade_count)
+    pub metric: String,
+    /// 비교 방향 (above, below)
+    pub comparison: String,
+    /// 임계치
+    pub threshold: Decimal,
+    /// 활성화 여부
+    pub enabled: bool,
+    /// 마지막 발동 시각 (None이면 대기 상태)
+    pub triggered_at: Option<DateTime<Utc>>,
+    /// 생성 시각
+    pub created_at: DateTime<Utc>,
+    /// 수정 시각
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PerformanceAlertRule {
+    /// 저장된 지표/비교 방향을 조건으로 변환.
+    pub fn condition(&self) -> Result<PerformanceAlertCondition, String> {
+        Ok(PerformanceAlertCondition {
+            metric: self.metric.parse()?,
+            comparison: self.comparison.parse()?,
+            threshold: self.threshold,
+        })
+    }
+}
+
+/// 전략 성과 알림 규칙 생성 요청.
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct CreatePerformanceAlertRequest {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 규칙 이름
+    pub rule_name: String,
+    /// 평가 지표
+    pub metric: PerformanceMetric,
+    /// 비교 방향
+    pub comparison: AlertComparison,
+    /// 임계치
+    pub threshold: Decimal,
+    /// 활성화 여부 (기본 true)
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// 전략 성과 알림 규칙 수정 요청.
+///
+/// 지표, 비교 방향, 임계치 중 하나라도 바뀌면 발동 상태가 리셋됩니다.
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct UpdatePerformanceAlertRequest {
+    /// 규칙 이름 (선택)
+    pub rule_name: Option<String>,
+    /// 평가 지표 (선택)
+    pub metric: Option<PerformanceMetric>,
+    /// 비교 방향 (선택)
+    pub comparison: Option<AlertComparison>,
+    /// 임계치 (선택)
+    pub threshold: Option<Decimal>,
+    /// 활성화 여부 (선택)
+    pub enabled: Option<bool>,
+}
+
+/// 전략 성과 알림 발동 이력.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
+pub struct PerformanceAlertHistory {
+    /// 이력 ID
+    pub id: Uuid,
+    /// 규칙 ID
+    pub rule_id: Uuid,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 평가 지표
+    pub metric: String,
+    /// 비교 방향
+    pub comparison: String,
+    /// 발동 당시 임계치
+    pub threshold: Decimal,
+    /// 발동 당시 관측값
+    pub observed_value: Decimal,
+    /// 알림 메시지
+    pub message: String,
+    /// 발동 시각
+    pub triggered_at: DateTime<Utc>,
+}
+
+/// 전략 성과 알림 규칙 리포지토리.
+pub struct PerformanceAlertRepository {
+    pool: PgPool,
+}
+
+impl PerformanceAlertRepository {
+    /// 새 리포지토리 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 규칙 생성.
+    pub async fn create(
+        &self,
+        req: CreatePerformanceAlertRequest,
+    ) -> ApiResult<PerformanceAlertRule> {
+        let query = format!(
+            r#"
+            INSERT INTO strategy_performance_alert_rule
+                (strategy_id, rule_name, metric, comparison, threshold, enabled)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING {RULE_COLUMNS}
+            "#
+        );
+
+        sqlx::query_as::<_, PerformanceAlertRule>(&query)
+            .bind(&req.strategy_id)
+            .bind(&req.rule_name)
+            .bind(req.metric.as_str())
+            .bind(req.comparison.as_str())
+            .bind(req.threshold)
+            .bind(req.enabled)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| map_write_error(e, &req.rule_name))
+    }
+
+    /// 규칙 목록 조회.
+    pub async fn list(
+        &self,
+        strategy_id: Option<&str>,
+        enabled_only: bool,
+    ) -> ApiResult<Vec<PerformanceAlertRule>> {
+        let query = format!(
+            r#"
+            SELECT {RULE_COLUMNS}
+            FROM strategy_performance_alert_rule
+            WHERE ($1::VARCHAR IS NULL OR strategy_id = $1)
+              AND ($2 = false OR enabled = true)
+            ORDER BY created_at DESC
+            "#
+        );
+
+        sqlx::query_as::<_, PerformanceAlertRule>(&query)
+            .bind(strategy_id)
+            .bind(enabled_only)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| internal_error(e.to_string()))
+    }
+
+    /// 평가 대기 중인 규칙 조회 (활성화 + 미발동).
+    pub async fn list_pending(&self) -> ApiResult<Vec<PerformanceAlertRule>> {
+        let query = format!(
+            r#"
+            SELECT {RULE_COLUMNS}
+            FROM strategy_performance_alert_rule
+            WHERE enabled = true AND triggered_at IS NULL
+            "#
+        );
+
+        sqlx::query_as::<_, PerformanceAlertRule>(&query)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| internal_error(e.to_string()))
+    }
+
+    /// ID로 규칙 조회.
+    pub async fn find_by_id(&self, id: Uuid) -> ApiResult<PerformanceAlertRule> {
+        let query =
+            format!("SELECT {RULE_COLUMNS} FROM strategy_performance_alert_rule WHERE id = $1");
+
+        sqlx::query_as::<_, PerformanceAlertRule>(&query)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| internal_error(e.to_string()))?
+            .ok_or_else(|| not_found(format!("Performance alert rule {} not found", id)))
+    }
+
+    /// 규칙 수정.
+    pub async fn update(
+        &self,
+        id: Uuid,
+        req: UpdatePerformanceAlertRequest,
+    ) -> ApiResult<PerformanceAlertRule> {
+        let existing = self.find_by_id(id).await?;
+
+        let rule_name = req.rule_name.unwrap_or(existing.rule_name);
+        let metric = req
+            .metric
+            .map(|m| m.as_str().to_string())
+            .unwrap_or(existing.metric.clone());
+        let comparison = req
+            .comparison
+            .map(|c| c.as_str().to_string())
+            .unwrap_or(existing.comparison.clone());
+        let threshold = req.threshold.unwrap_or(existing.threshold);
+        let enabled = req.enabled.unwrap_or(existing.enabled);
+
+        // 조건이 바뀌면 새 규칙으로 간주하여 발동 상태 리셋
+        let condition_changed = metric != existing.metric
+            || comparison != existing.comparison
+            || threshold != existing.threshold;
+        let triggered_at = if condition_changed {
+            None
+        } else {
+            existing.triggered_at
+        };
+
+        let query = format!(
+            r#"
+            UPDATE strategy_performance_alert_rule
+            SET rule_name = $2, metric = $3, comparison = $4, threshold = $5,
+                enabled = $6, triggered_at = $7, updated_at = NOW()
+            WHERE id = $1
+            RETURNING {RULE_COLUMNS}
+            "#
+        );
+
+        sqlx::query_as::<_, PerformanceAlertRule>(&query)
+            .bind(id)
+            .bind(&rule_name)
+            .bind(&metric)
+            .bind(&comparison)
+            .bind(threshold)
+            .bind(enabled)
+            .bind(triggered_at)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| map_write_error(e, &rule_name))
+    }
+
+    /// 규칙 삭제 (발동 이력도 함께 삭제).
+    pub async fn delete(&self, id: Uuid) -> ApiResult<()> {
+        let result = sqlx::query("DELETE FROM strategy_performance_alert_rule WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| internal_error(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(not_found(format!(
+                "Performance alert rule {} not found",
+                id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// 발동 상태 리셋 (다시 평가 대상이 됨).
+    pub async fn reset(&self, id: Uuid) -> ApiResult<PerformanceAlertRule> {
+        let query = format!(
+            r#"
+            UPDATE strategy_performance_alert_rule
+            SET triggered_at = NULL, updated_at = NOW()
+            WHERE id = $1
+            RETURNING {RULE_COLUMNS}
+            "#
+        );
+
+        sqlx::query_as::<_, PerformanceAlertRule>(&query)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| internal_error(e.to_string()))?
+            .ok_or_else(|| not_found(format!("Performance alert rule {} not found", id)))
+    }
+
+    /// 규칙을 발동 상태로 기록.
+    ///
+    /// 아직 발동하지 않은 규칙일 때만 기록하며, 기록에 성공하면 `true`를 반환합니다.
+    pub async fn mark_triggered(&self, id: Uuid) -> ApiResult<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE strategy_performance_alert_rule
+            SET triggered_at = NOW(), updated_at = NOW()
+            WHERE id = $1 AND triggered_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| internal_error(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 발동 이력 저장.
+    pub async fn insert_history(
+        &self,
+        rule: &PerformanceAlertRule,
+        observed_value: Decimal,
+        message: &str,
+    ) -> ApiResult<PerformanceAlertHistory> {
+        sqlx::query_as::<_, PerformanceAlertHistory>(
+            r#"
+            INSERT INTO strategy_performance_alert_history
+                (rule_id, strategy_id, metric, comparison, threshold, observed_value, message)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, rule_id, strategy_id, metric, comparison, threshold,
+                      observed_value, message, triggered_at
+            "#,
+        )
+        .bind(rule.id)
+        .bind(&rule.strategy_id)
+        .bind(&rule.metric)
+        .bind(&rule.comparison)
+        .bind(rule.threshold)
+        .bind(observed_value)
+        .bind(message)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| internal_error(e.to_string()))
+    }
+
+    /// 발동 이력 조회 (최신순).
+    pub async fn list_history(
+        &self,
+        strategy_id: Option<&str>,
+        rule_id: Option<Uuid>,
+        limit: i64,
+    ) -> ApiResult<Vec<PerformanceAlertHistory>> {
+        sqlx::query_as::<_, PerformanceAlertHistory>(
+            r#"
+            SELECT id, rule_id, strategy_id, metric, comparison, threshold,
+                   observed_value, message, triggered_at
+            FROM strategy_performance_alert_history
+            WHERE ($1::VARCHAR IS NULL OR strategy_id = $1)
+              AND ($2::UUID IS NULL OR rule_id = $2)
+            ORDER BY triggered_at DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(rule_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| internal_error(e.to_string()))
+    }
+}
+
+/// 쓰기 에러 변환 (이름 중복은 409).
+fn map_write_error(e: sqlx::Error, rule_name: &str) -> BoxedApiError {
+    if let Some(db_err) = e.as_database_error() {
+        if db_err.is_unique_violation() {
+            return BoxedApiError::from((
+                StatusCode::CONFLICT,
+                Json(ApiErrorResponse::new(
+                    "DUPLICATE_RULE",
+                    format!("Rule '{}' already exists for this strategy", rule_name),
+                )),
+            ));
+        }
+    }
+    internal_error(e.to_string())
+}
@@ -7,11 +7,12 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use sqlx::PgPool;
+use tracing::debug;
 use uuid::Uuid;
 
 use crate::{
     error::{internal_error, not_found, ApiErrorResponse, ApiResult, BoxedApiError},
-    services::SignalAlertFilter,
+    services::{NamedAlertFilter, SignalAlertFilter},
 };
 
 /// 신호 알림 규칙 엔티티.
@@ -285,4 +286,24 @@ impl SignalAlertRuleRepository {
 
         filters.map_err(|e| internal_error(format!("Failed to deserialize filter: {}", e)))
     }
+
+    /// 활성화된 신호 알림 규칙을 이름과 함께 조회.
+    ///
+    /// 신호 필터가 아닌 규칙(RouteState 알림 등)과 해석할 수 없는 규칙은 건너뜁니다.
+    pub async fn get_enabled_rules(&self) -> ApiResult<Vec<NamedAlertFilter>> {
+        let rules = self.list(true).await?;
+
+        Ok(rules
+            .into_iter()
+            .filter_map(
+                |r| match SignalAlertFilter::from_rule_json(&r.filter_conditions) {
+                    Ok(filter) => Some(NamedAlertFilter::new(r.rule_name, filter)),
+                    Err(e) => {
+                        debug!(rule = %r.rule_name, error = %e, "신호 알림 규칙 아님 - 건너뜀");
+                        None
+                    }
+                },
+            )
+            .collect())
+    }
 }
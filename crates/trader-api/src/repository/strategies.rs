@@ -8,7 +8,7 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use tokio::sync::RwLock;
 use trader_core::StrategyContext;
 use uuid::Uuid;
@@ -64,6 +64,29 @@ pub struct CreateStrategyInput {
     pub multi_timeframe_config: Option<Value>,
     /// 전략이 연결된 거래소 계정 ID (NULL이면 기본 활성 계정 사용)
     pub credential_id: Option<Uuid>,
+    /// 전략을 생성한 사용자 ID (NULL이면 관리자 전용)
+    pub owner_id: Option<Uuid>,
+}
+
+/// 가져온(import) 전략 설정의 출처 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct StrategyImportProvenance {
+    /// 가져오기로 생성된 전략 ID
+    pub strategy_id: String,
+    /// 내보내기 원본 전략 ID
+    pub source_strategy_id: Option<String>,
+    /// 내보내기 원본 전략 이름
+    pub source_name: Option<String>,
+    /// 내보내기 원본 전략 버전
+    pub source_strategy_version: Option<String>,
+    /// 내보내기 문서 포맷 버전
+    pub format_version: i32,
+    /// 내보내기 시각
+    pub exported_at: Option<DateTime<Utc>>,
+    /// 가져오기 시 발생한 경고 목록
+    pub warnings: Value,
+    /// 가져오기 시각
+    pub imported_at: DateTime<Utc>,
 }
 
 /// Strategy repository for database operations.
@@ -76,17 +99,43 @@ impl StrategyRepository {
     pub async fn create(
         pool: &PgPool,
         input: CreateStrategyInput,
+    ) -> Result<StrategyRecord, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let record = Self::insert_strategy(&mut tx, input).await?;
+        tx.commit().await?;
+
+        Ok(record)
+    }
+
+    /// 가져온 전략과 출처 정보를 하나의 트랜잭션으로 저장합니다.
+    ///
+    /// 출처 저장이 실패하면 전략 생성도 롤백되어 출처 없는 가져오기 전략이 남지 않습니다.
+    pub async fn create_imported(
+        pool: &PgPool,
+        input: CreateStrategyInput,
+        provenance: &StrategyImportProvenance,
+    ) -> Result<StrategyRecord, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let record = Self::insert_strategy(&mut tx, input).await?;
+        Self::insert_import_provenance(&mut tx, provenance).await?;
+        tx.commit().await?;
+
+        Ok(record)
+    }
+
+    /// 전략 INSERT (트랜잭션 내부에서 호출).
+    async fn insert_strategy(
+        conn: &mut PgConnection,
+        input: CreateStrategyInput,
     ) -> Result<StrategyRecord, sqlx::Error> {
         let symbols_json = serde_json::to_value(&input.symbols).unwrap_or(Value::Array(vec![]));
         let risk_limits = input.risk_config.unwrap_or_else(|| serde_json::json!({}));
         let risk_profile = input.risk_profile.unwrap_or_else(|| "default".to_string());
 
-        let mut tx = pool.begin().await?;
-
-        let record = sqlx::query_as::<_, StrategyRecord>(
+        sqlx::query_as::<_, StrategyRecord>(
             r#"
-            INSERT INTO strategies (id, name, description, strategy_type, symbols, market, timeframe, config, risk_limits, allocated_capital, risk_profile, multi_timeframe_config, credential_id, is_active)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, false)
+            INSERT INTO strategies (id, name, description, strategy_type, symbols, market, timeframe, config, risk_limits, allocated_capital, risk_profile, multi_timeframe_config, credential_id, owner_id, is_active)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, false)
             RETURNING *
             "#
         )
@@ -103,12 +152,9 @@ impl StrategyRepository {
         .bind(&risk_profile)
         .bind(&input.multi_timeframe_config)
         .bind(input.credential_id)
-        .fetch_one(&mut *tx)
-        .await?;
-
-        tx.commit().await?;
-
-        Ok(record)
+        .bind(input.owner_id)
+        .fetch_one(conn)
+        .await
     }
 
     /// Get a strategy by ID.
@@ -380,6 +426,70 @@ impl StrategyRepository {
         Ok(record)
     }
 
+    /// 전략 이름이 이미 사용 중인지 확인합니다.
+    pub async fn exists_by_name(pool: &PgPool, name: &str) -> Result<bool, sqlx::Error> {
+        let result: (bool,) =
+            sqlx::query_as("SELECT EXISTS(SELECT 1 FROM strategies WHERE name = $1)")
+                .bind(name)
+                .fetch_one(pool)
+                .await?;
+
+        Ok(result.0)
+    }
+
+    /// 가져온 전략의 출처 정보 INSERT (트랜잭션 내부에서 호출).
+    async fn insert_import_provenance(
+        conn: &mut PgConnection,
+        provenance: &StrategyImportProvenance,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO strategy_import_provenance
+                (strategy_id, source_strategy_id, source_name, source_strategy_version,
+                 format_version, exported_at, warnings, imported_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (strategy_id) DO UPDATE SET
+                source_strategy_id = EXCLUDED.source_strategy_id,
+                source_name = EXCLUDED.source_name,
+                source_strategy_version = EXCLUDED.source_strategy_version,
+                format_version = EXCLUDED.format_version,
+                exported_at = EXCLUDED.exported_at,
+                warnings = EXCLUDED.warnings,
+                imported_at = EXCLUDED.imported_at
+            "#,
+        )
+        .bind(&provenance.strategy_id)
+        .bind(&provenance.source_strategy_id)
+        .bind(&provenance.source_name)
+        .bind(&provenance.source_strategy_version)
+        .bind(provenance.format_version)
+        .bind(provenance.exported_at)
+        .bind(&provenance.warnings)
+        .bind(provenance.imported_at)
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 전략의 가져오기 출처 정보를 조회합니다 (직접 생성된 전략은 None).
+    pub async fn get_import_provenance(
+        pool: &PgPool,
+        strategy_id: &str,
+    ) -> Result<Option<StrategyImportProvenance>, sqlx::Error> {
+        sqlx::query_as::<_, StrategyImportProvenance>(
+            r#"
+            SELECT strategy_id, source_strategy_id, source_name, source_strategy_version,
+                   format_version, exported_at, warnings, imported_at
+            FROM strategy_import_provenance
+            WHERE strategy_id = $1
+            "#,
+        )
+        .bind(strategy_id)
+        .fetch_optional(pool)
+        .await
+    }
+
     /// Load all strategies from the database and register them with the engine.
     ///
     /// This is called during server startup to restore previously saved strategies.
@@ -1038,6 +1038,26 @@ impl SymbolInfoRepository {
         Ok(result.rows_affected())
     }
 
+    /// 특정 시장의 전체 종목 목록 조회 (비활성 포함).
+    ///
+    /// 온라인 소스와의 증분 비교 시 사용됩니다.
+    pub async fn get_by_market(
+        pool: &PgPool,
+        market: &str,
+    ) -> Result<Vec<SymbolInfo>, sqlx::Error> {
+        sqlx::query_as::<_, SymbolInfo>(
+            r#"
+            SELECT id, ticker, name, name_en, market, exchange, sector, yahoo_symbol,
+                   is_active, created_at, updated_at
+            FROM symbol_info
+            WHERE market = $1
+            "#,
+        )
+        .bind(market)
+        .fetch_all(pool)
+        .await
+    }
+
     /// 특정 시장의 활성 종목 목록 조회.
     ///
     /// 권위 있는 소스 동기화 시 비교 대상으로 사용됩니다.
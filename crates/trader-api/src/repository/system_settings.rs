@@ -0,0 +1,159 @@
+//! 런타임 시스템 설정 Repository.
+//!
+//! 변경 가능한 런타임 파라미터를 `app_settings` 테이블에 `runtime.` 접두사로 저장하고,
+//! 변경 내역은 `audit_logs` 테이블에 `system_setting` 엔티티로 기록합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use sqlx::{FromRow, PgPool};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// app_settings 키 접두사 (런타임 설정 구분용).
+pub const RUNTIME_SETTING_PREFIX: &str = "runtime.";
+
+/// 감사 로그 엔티티 타입.
+const AUDIT_ENTITY_TYPE: &str = "system_setting";
+
+/// 감사 로그 이벤트 타입.
+const AUDIT_EVENT_TYPE: &str = "system_setting_updated";
+
+/// 설정 변경 1건.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export, export_to = "system/")]
+pub struct SettingChange {
+    /// 설정 키
+    pub key: String,
+    /// 변경 전 값
+    #[ts(type = "unknown")]
+    pub old_value: JsonValue,
+    /// 변경 후 값
+    #[ts(type = "unknown")]
+    pub new_value: JsonValue,
+}
+
+/// 설정 변경 이력 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema, TS)]
+#[ts(export, export_to = "system/")]
+pub struct SettingHistoryRecord {
+    /// 설정 키
+    pub key: String,
+    /// 변경 전 값
+    #[ts(type = "unknown")]
+    pub old_value: JsonValue,
+    /// 변경 후 값
+    #[ts(type = "unknown")]
+    pub new_value: JsonValue,
+    /// 변경한 사용자 ID
+    pub changed_by: Option<String>,
+    /// 변경 사유
+    pub reason: Option<String>,
+    /// 변경 시각
+    pub changed_at: DateTime<Utc>,
+}
+
+/// 런타임 시스템 설정 Repository.
+pub struct SystemSettingsRepository;
+
+impl SystemSettingsRepository {
+    /// 저장된 런타임 설정 전체 조회.
+    ///
+    /// 반환되는 키에서는 `runtime.` 접두사가 제거됩니다.
+    pub async fn load_all(pool: &PgPool) -> Result<Vec<(String, String)>, sqlx::Error> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            r#"
+            SELECT setting_key, setting_value
+            FROM app_settings
+            WHERE setting_key LIKE $1
+            "#,
+        )
+        .bind(format!("{}%", RUNTIME_SETTING_PREFIX))
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(key, value)| {
+                key.strip_prefix(RUNTIME_SETTING_PREFIX)
+                    .map(|k| (k.to_string(), value))
+            })
+            .collect())
+    }
+
+    /// 설정 변경 저장 및 감사 로그 기록.
+    ///
+    /// 모든 변경은 하나의 트랜잭션으로 처리되어 일부만 저장되는 일이 없습니다.
+    pub async fn save_changes(
+        pool: &PgPool,
+        changes: &[SettingChange],
+        changed_by: &str,
+        reason: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        for change in changes {
+            sqlx::query(
+                r#"
+                INSERT INTO app_settings (setting_key, setting_value, description, updated_at)
+                VALUES ($1, $2, '런타임 시스템 설정', NOW())
+                ON CONFLICT (setting_key)
+                DO UPDATE SET setting_value = EXCLUDED.setting_value, updated_at = NOW()
+                "#,
+            )
+            .bind(format!("{}{}", RUNTIME_SETTING_PREFIX, change.key))
+            .bind(change.new_value.to_string())
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO audit_logs (event_type, entity_type, user_id, details)
+                VALUES ($1, $2, $3, $4)
+                "#,
+            )
+            .bind(AUDIT_EVENT_TYPE)
+            .bind(AUDIT_ENTITY_TYPE)
+            .bind(changed_by)
+            .bind(serde_json::json!({
+                "key": change.key,
+                "old_value": change.old_value,
+                "new_value": change.new_value,
+                "reason": reason,
+            }))
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+
+    /// 설정 변경 이력 조회 (최신순).
+    pub async fn list_history(
+        pool: &PgPool,
+        key: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<SettingHistoryRecord>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT
+                COALESCE(details->>'key', '') AS key,
+                COALESCE(details->'old_value', 'null'::jsonb) AS old_value,
+                COALESCE(details->'new_value', 'null'::jsonb) AS new_value,
+                user_id AS changed_by,
+                details->>'reason' AS reason,
+                COALESCE(created_at, NOW()) AS changed_at
+            FROM audit_logs
+            WHERE entity_type = $1
+              AND ($2::text IS NULL OR details->>'key' = $2)
+            ORDER BY created_at DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(AUDIT_ENTITY_TYPE)
+        .bind(key)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -1135,6 +1135,7 @@ pub async fn get_volume_profile(
                 close_time: Utc.timestamp_millis_opt(*ts + 86400000).unwrap(),
                 quote_volume: None,
                 num_trades: None,
+                synthetic: false,
             }
         })
         .collect();
@@ -644,30 +644,34 @@ async fn sync_equity_curve_mock(
         }
     };
 
-    // 2. 체결 내역 조회 (ExchangeProvider 인터페이스)
-    let history_request = ExecutionHistoryRequest {
-        start_date: request.start_date.clone(),
-        end_date: request.end_date.clone(),
-        cursor: None,
-        side: None,
-    };
+    // 2. 체결 내역 조회 (ExchangeProvider 인터페이스, 모든 페이지)
+    let mut history_request = ExecutionHistoryRequest::new(&request.start_date, &request.end_date);
+    let mut executions = Vec::new();
 
-    let executions = match provider.fetch_execution_history(&history_request).await {
-        Ok(response) => response.trades,
-        Err(e) => {
-            return (
-                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-                Json(SyncEquityCurveResponse {
-                    success: false,
-                    synced_count: 0,
-                    execution_count: 0,
-                    start_date: request.start_date.clone(),
-                    end_date: request.end_date.clone(),
-                    message: format!("체결 내역 조회 실패: {:?}", e),
-                }),
-            );
+    loop {
+        let response = match provider.fetch_execution_history(&history_request).await {
+            Ok(response) => response,
+            Err(e) => {
+                return (
+                    axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(SyncEquityCurveResponse {
+                        success: false,
+                        synced_count: 0,
+                        execution_count: 0,
+                        start_date: request.start_date.clone(),
+                        end_date: request.end_date.clone(),
+                        message: format!("체결 내역 조회 실패: {:?}", e),
+                    }),
+                );
+            }
+        };
+
+        executions.extend(response.trades);
+        match response.next_cursor {
+            Some(cursor) => history_request.cursor = Some(cursor),
+            None => break,
         }
-    };
+    }
 
     debug!("Mock 체결 내역 조회 완료: {} 건", executions.len());
 
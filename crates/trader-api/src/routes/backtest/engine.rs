@@ -23,9 +23,11 @@ use chrono::{NaiveDate, TimeZone, Utc};
 use rust_decimal::Decimal;
 use tokio::sync::RwLock;
 use tracing::debug;
-use trader_analytics::backtest::{BacktestConfig, BacktestEngine, BacktestReport};
+use trader_analytics::backtest::{
+    BacktestConfig, BacktestEngine, BacktestProgressHandle, BacktestReport,
+};
 use trader_core::{Kline, MarketType, StrategyContext, Symbol, Timeframe};
-use trader_strategy::StrategyRegistry;
+use trader_strategy::{strategies::common::PerformanceTargetConfig, StrategyRegistry};
 
 use super::{
     loader::parse_symbol,
@@ -39,11 +41,13 @@ use super::{
 ///
 /// CPU-intensive 백테스트 계산을 `spawn_blocking`으로 별도 thread pool에서 실행하여
 /// Tokio async runtime의 worker thread를 블로킹하지 않습니다.
+/// `progress`가 주어지면 진행률을 기록하고 취소 요청 시 중단합니다.
 pub async fn run_strategy_backtest(
     strategy_id: &str,
     config: BacktestConfig,
     klines: &[Kline],
     params: &Option<serde_json::Value>,
+    progress: Option<BacktestProgressHandle>,
 ) -> Result<BacktestReport, String> {
     // 데이터를 owned 타입으로 변환하여 spawn_blocking으로 이동
     let strategy_id = strategy_id.to_string();
@@ -63,6 +67,7 @@ pub async fn run_strategy_backtest(
             config,
             &klines,
             &params,
+            progress,
         ))
     })
     .await
@@ -71,6 +76,20 @@ pub async fn run_strategy_backtest(
     Ok(report)
 }
 
+/// 전략 params에 성과 목표가 선언되어 있으면 백테스트 설정에 반영합니다.
+fn apply_performance_target(
+    config: BacktestConfig,
+    params: &Option<serde_json::Value>,
+) -> BacktestConfig {
+    match params
+        .as_ref()
+        .and_then(PerformanceTargetConfig::from_strategy_config)
+    {
+        Some(target) => config.with_performance_target(target),
+        None => config,
+    }
+}
+
 /// SDUI params에 ticker 주입
 ///
 /// SDUI에서 ticker가 제공되지 않은 경우, klines에서 추출한 ticker를 주입합니다.
@@ -103,8 +122,12 @@ async fn run_strategy_backtest_inner(
     config: BacktestConfig,
     klines: &[Kline],
     params: &Option<serde_json::Value>,
+    progress: Option<BacktestProgressHandle>,
 ) -> Result<BacktestReport, String> {
-    let mut engine = BacktestEngine::new(config);
+    let mut engine = BacktestEngine::new(apply_performance_target(config, params));
+    if let Some(progress) = progress {
+        engine = engine.with_progress(progress);
+    }
 
     // 심볼 추출 (klines에서)
     let symbol_str = if let Some(first_kline) = klines.first() {
@@ -151,12 +174,14 @@ async fn run_strategy_backtest_inner(
 ///
 /// CPU-intensive 백테스트 계산을 `spawn_blocking`으로 별도 thread pool에서 실행하여
 /// Tokio async runtime의 worker thread를 블로킹하지 않습니다.
+/// `progress`가 주어지면 진행률을 기록하고 취소 요청 시 중단합니다.
 pub async fn run_multi_strategy_backtest(
     strategy_id: &str,
     config: BacktestConfig,
     merged_klines: &[Kline],
     multi_klines: &HashMap<String, Vec<Kline>>,
     params: &Option<serde_json::Value>,
+    progress: Option<BacktestProgressHandle>,
 ) -> Result<BacktestReport, String> {
     // 데이터를 owned 타입으로 변환하여 spawn_blocking으로 이동
     let strategy_id = strategy_id.to_string();
@@ -178,6 +203,7 @@ pub async fn run_multi_strategy_backtest(
             &merged_klines,
             &multi_klines,
             &params,
+            progress,
         ))
     })
     .await
@@ -238,9 +264,13 @@ async fn run_multi_strategy_backtest_inner(
     merged_klines: &[Kline],
     multi_klines: &HashMap<String, Vec<Kline>>,
     params: &Option<serde_json::Value>,
+    progress: Option<BacktestProgressHandle>,
 ) -> Result<BacktestReport, String> {
     let initial_capital = config.initial_capital;
-    let mut engine = BacktestEngine::new(config);
+    let mut engine = BacktestEngine::new(apply_performance_target(config, params));
+    if let Some(progress) = progress {
+        engine = engine.with_progress(progress);
+    }
 
     // 심볼 목록 추출
     let symbols: Vec<String> = multi_klines.keys().cloned().collect();
@@ -384,6 +414,10 @@ pub fn convert_report_to_response(
         trades,
         config_summary,
         all_trades,
+        target_evaluation: report
+            .target_evaluation
+            .as_ref()
+            .and_then(|e| serde_json::to_value(e).ok()),
     }
 }
 
@@ -471,6 +505,10 @@ pub fn convert_multi_report_to_response(
         trades,
         config_summary,
         data_points_by_symbol,
+        target_evaluation: report
+            .target_evaluation
+            .as_ref()
+            .and_then(|e| serde_json::to_value(e).ok()),
     }
 }
 
@@ -557,6 +595,7 @@ pub fn generate_multi_sample_klines(
                     volume: Decimal::from_f64(volume).unwrap_or(Decimal::from(1000000)),
                     quote_volume: None,
                     num_trades: None,
+                    synthetic: false,
                 }
             })
             .collect();
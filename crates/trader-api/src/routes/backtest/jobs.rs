@@ -0,0 +1,304 @@
+//! 백테스트 비동기 작업 API
+//!
+//! 장기간/다중 심볼 백테스트를 백그라운드 작업으로 제출하고 진행률을 폴링합니다.
+//! 작업 큐 자체는 [`BacktestJobQueue`](crate::services::BacktestJobQueue)에 있습니다.
+//!
+//! # 엔드포인트
+//!
+//! - `POST /api/v1/backtests` - 백테스트 작업 제출 (job_id 반환)
+//! - `GET /api/v1/backtests` - 작업 목록 및 대기열 현황
+//! - `GET /api/v1/backtests/{job_id}` - 진행률/부분 결과/완료 결과 조회
+//! - `DELETE /api/v1/backtests/{job_id}` - 작업 취소
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use serde::Serialize;
+use tracing::debug;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{execute_backtest, validate_run_request, BacktestApiError, BacktestRunRequest};
+use crate::{
+    services::{BacktestJobError, BacktestJobView, BacktestQueueStatus},
+    state::AppState,
+};
+
+/// 작업 목록 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BacktestJobListResponse {
+    /// 대기열 현황
+    pub queue: BacktestQueueStatus,
+    /// 작업 목록 (제출 순)
+    pub jobs: Vec<BacktestJobView>,
+}
+
+/// 작업 큐 오류를 API 오류로 변환.
+fn job_error(error: BacktestJobError) -> (StatusCode, Json<BacktestApiError>) {
+    let (status, code) = match &error {
+        BacktestJobError::QueueFull(_) => (StatusCode::TOO_MANY_REQUESTS, "QUEUE_FULL"),
+        BacktestJobError::NotFound(_) => (StatusCode::NOT_FOUND, "JOB_NOT_FOUND"),
+        BacktestJobError::AlreadyFinished(_) => (StatusCode::CONFLICT, "JOB_ALREADY_FINISHED"),
+    };
+    (status, Json(BacktestApiError::new(code, error.to_string())))
+}
+
+/// 백테스트 작업 제출.
+///
+/// 요청을 검증한 뒤 작업 큐에 등록하고 즉시 반환합니다.
+/// 동시 실행 한도를 넘으면 `queued` 상태로 대기합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/backtests",
+    tag = "backtest",
+    request_body = BacktestRunRequest,
+    responses(
+        (status = 202, description = "작업 제출 성공", body = BacktestJobView),
+        (status = 400, description = "잘못된 요청", body = BacktestApiError),
+        (status = 404, description = "전략 없음", body = BacktestApiError),
+        (status = 429, description = "대기열 초과", body = BacktestApiError)
+    )
+)]
+pub async fn submit_backtest_job(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<BacktestRunRequest>,
+) -> Result<(StatusCode, Json<BacktestJobView>), (StatusCode, Json<BacktestApiError>)> {
+    validate_run_request(&request)?;
+
+    let label = format!("{} {}", request.strategy_id, request.symbol);
+    let job_state = Arc::clone(&state);
+    let job_id = state
+        .backtest_jobs
+        .submit(label, move |progress| async move {
+            let response = execute_backtest(&job_state, &request, Some(progress))
+                .await
+                .map_err(|(_, Json(e))| e.message)?;
+            serde_json::to_value(response).map_err(|e| e.to_string())
+        })
+        .await
+        .map_err(job_error)?;
+
+    debug!(job_id = %job_id, "백테스트 작업 제출");
+
+    let view = state
+        .backtest_jobs
+        .get(job_id)
+        .await
+        .ok_or_else(|| job_error(BacktestJobError::NotFound(job_id)))?;
+    Ok((StatusCode::ACCEPTED, Json(view)))
+}
+
+/// 백테스트 작업 목록 및 대기열 현황 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/backtests",
+    tag = "backtest",
+    responses(
+        (status = 200, description = "작업 목록 조회 성공", body = BacktestJobListResponse)
+    )
+)]
+pub async fn list_backtest_jobs(
+    State(state): State<Arc<AppState>>,
+) -> Json<BacktestJobListResponse> {
+    Json(BacktestJobListResponse {
+        queue: state.backtest_jobs.status().await,
+        jobs: state.backtest_jobs.list().await,
+    })
+}
+
+/// 백테스트 작업 조회.
+///
+/// 대기 중이면 대기열 순번, 실행 중이면 진행률과 부분 결과(현재 자산, 거래 수),
+/// 완료되면 백테스트 결과를 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/backtests/{job_id}",
+    tag = "backtest",
+    params(
+        ("job_id" = Uuid, Path, description = "백테스트 작업 ID")
+    ),
+    responses(
+        (status = 200, description = "작업 조회 성공", body = BacktestJobView),
+        (status = 404, description = "작업 없음 (만료 포함)", body = BacktestApiError)
+    )
+)]
+pub async fn get_backtest_job(
+    State(state): State<Arc<AppState>>,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<BacktestJobView>, (StatusCode, Json<BacktestApiError>)> {
+    state
+        .backtest_jobs
+        .get(job_id)
+        .await
+        .map(Json)
+        .ok_or_else(|| job_error(BacktestJobError::NotFound(job_id)))
+}
+
+/// 백테스트 작업 취소.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/backtests/{job_id}",
+    tag = "backtest",
+    params(
+        ("job_id" = Uuid, Path, description = "백테스트 작업 ID")
+    ),
+    responses(
+        (status = 200, description = "작업 취소 성공", body = BacktestJobView),
+        (status = 404, description = "작업 없음", body = BacktestApiError),
+        (status = 409, description = "이미 종료된 작업", body = BacktestApiError)
+    )
+)]
+pub async fn cancel_backtest_job(
+    State(state): State<Arc<AppState>>,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<BacktestJobView>, (StatusCode, Json<BacktestApiError>)> {
+    let view = state
+        .backtest_jobs
+        .cancel(job_id)
+        .await
+        .map_err(job_error)?;
+    debug!(job_id = %job_id, "백테스트 작업 취소");
+    Ok(Json(view))
+}
+
+/// 백테스트 작업 라우터 생성
+pub fn backtest_jobs_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(list_backtest_jobs).post(submit_backtest_job))
+        .route(
+            "/{job_id}",
+            get(get_backtest_job).delete(cancel_backtest_job),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{services::BacktestJobStatus, state::create_test_state};
+
+    fn submit_request(body: serde_json::Value) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri("/")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap()
+    }
+
+    async fn read_json<T: serde::de::DeserializeOwned>(response: axum::response::Response) -> T {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_submit_and_poll_backtest_job() {
+        let state = Arc::new(create_test_state());
+        let app = backtest_jobs_router().with_state(Arc::clone(&state));
+
+        let response = app
+            .clone()
+            .oneshot(submit_request(serde_json::json!({
+                "strategy_id": "sma_crossover",
+                "symbol": "BTC/USDT",
+                "start_date": "2024-01-01",
+                "end_date": "2024-06-30",
+                "initial_capital": 10000000
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        let submitted: serde_json::Value = read_json(response).await;
+        let job_id: Uuid = serde_json::from_value(submitted["job_id"].clone()).unwrap();
+
+        let mut view = None;
+        for _ in 0..600 {
+            let current = state.backtest_jobs.get(job_id).await.unwrap();
+            if current.status.is_finished() {
+                view = Some(current);
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        let view = view.expect("백테스트 작업이 종료되지 않음");
+        assert_eq!(view.status, BacktestJobStatus::Completed);
+        assert_eq!(view.progress.as_ref().unwrap().percent, 100.0);
+        assert_eq!(
+            view.result.as_ref().unwrap()["strategy_id"],
+            "sma_crossover"
+        );
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/{}", job_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // 종료된 작업은 취소할 수 없음
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/{}", job_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_submit_invalid_request_is_rejected() {
+        let state = Arc::new(create_test_state());
+        let app = backtest_jobs_router().with_state(Arc::clone(&state));
+
+        let response = app
+            .oneshot(submit_request(serde_json::json!({
+                "strategy_id": "sma_crossover",
+                "symbol": "BTC/USDT",
+                "start_date": "2024-06-30",
+                "end_date": "2024-01-01",
+                "initial_capital": 10000000
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(state.backtest_jobs.list().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_unknown_job() {
+        let app = backtest_jobs_router().with_state(Arc::new(create_test_state()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/{}", Uuid::new_v4()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let error: BacktestApiError = read_json(response).await;
+        assert_eq!(error.code, "JOB_NOT_FOUND");
+    }
+}
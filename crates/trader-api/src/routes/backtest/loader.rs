@@ -7,7 +7,7 @@ use std::collections::{HashMap, HashSet};
 use chrono::{NaiveDate, TimeZone, Utc};
 use rust_decimal::Decimal;
 use tracing::{debug, warn};
-use trader_core::{Kline, MarketType, Symbol, Timeframe};
+use trader_core::{trading_days_between, Kline, MarketType, Symbol, Timeframe};
 use trader_data::cache::CachedHistoricalDataProvider;
 
 /// 전략의 기본 타임프레임을 존중하는 Kline 데이터 로드
@@ -118,6 +118,8 @@ pub async fn load_klines_with_multi_tf_fallback(
 }
 
 /// 샘플 Kline 데이터 생성 (DB 데이터가 없을 경우 사용)
+///
+/// 주말과 휴장일은 건너뛰어 거래일에만 캔들을 생성합니다.
 pub fn generate_sample_klines(
     symbol_str: &str,
     start_date: NaiveDate,
@@ -130,12 +132,15 @@ pub fn generate_sample_klines(
     // Symbol 생성자를 통해 country 필드 자동 추론
     let symbol = Symbol::new(base, quote, MarketType::Stock);
 
-    let days = (end_date - start_date).num_days() as usize;
     let base_price = 50000.0_f64; // 기본 가격
 
-    (0..=days)
-        .map(|i| {
-            let date = start_date + chrono::Duration::days(i as i64);
+    // 실제 데이터와 동일하게 해당 시장의 거래일에만 캔들 생성
+    let market = calendar_market_for_symbol(symbol_str);
+
+    trading_days_between(market, start_date, end_date)
+        .into_iter()
+        .enumerate()
+        .map(|(i, date)| {
             let open_time = Utc.from_utc_datetime(&date.and_hms_opt(9, 0, 0).unwrap());
             let close_time = Utc.from_utc_datetime(&date.and_hms_opt(15, 30, 0).unwrap());
 
@@ -162,6 +167,7 @@ pub fn generate_sample_klines(
                 volume: Decimal::from_f64(volume).unwrap_or(Decimal::from(1000000)),
                 quote_volume: None,
                 num_trades: None,
+                synthetic: false,
             }
         })
         .collect()
@@ -337,6 +343,19 @@ pub fn merge_multi_klines(multi_klines: &HashMap<String, Vec<Kline>>) -> Vec<Kli
     all_klines
 }
 
+/// 심볼 문자열에서 거래일 캘린더 시장 코드 추론
+///
+/// `BTC/USDT` 형식은 암호화폐(연중무휴), 숫자 티커는 한국, 그 외는 미국 시장으로 봅니다.
+pub fn calendar_market_for_symbol(symbol_str: &str) -> &'static str {
+    if symbol_str.contains('/') {
+        "CRYPTO"
+    } else if symbol_str.chars().all(|c| c.is_ascii_digit()) {
+        "KR"
+    } else {
+        "US"
+    }
+}
+
 /// 심볼 문자열을 base/quote로 파싱
 pub fn parse_symbol(symbol_str: &str) -> (String, String) {
     if symbol_str.contains('/') {
@@ -7,8 +7,10 @@
 //! - `GET /api/v1/backtest/strategies` - 백테스트 가능한 전략 목록
 //! - `POST /api/v1/backtest/run` - 백테스트 실행
 //! - `GET /api/v1/backtest/results/{id}` - 백테스트 결과 조회
+//! - `/api/v1/backtests` - 비동기 백테스트 작업 ([`jobs`] 참조)
 
 mod engine;
+pub mod jobs;
 mod loader;
 mod types;
 mod ui_schema;
@@ -28,14 +30,15 @@ use engine::{
     convert_multi_report_to_response, convert_report_to_response, generate_multi_sample_klines,
     run_multi_strategy_backtest, run_strategy_backtest,
 };
+pub use jobs::{backtest_jobs_router, BacktestJobListResponse};
 use loader::{
     expand_strategy_symbols, generate_sample_klines, load_klines_with_multi_tf_fallback,
     load_multi_klines_from_db, merge_multi_klines,
 };
 use rust_decimal::Decimal;
 use tracing::{debug, warn};
-use trader_analytics::backtest::BacktestConfig;
-use trader_strategy::StrategyRegistry;
+use trader_analytics::backtest::{BacktestConfig, BacktestProgressHandle};
+use trader_strategy::{StrategyMeta, StrategyRegistry};
 pub use types::{
     BacktestApiError,
     BacktestConfigSummary,
@@ -163,6 +166,22 @@ pub async fn run_backtest(
         request.strategy_id, request.symbol
     );
 
+    execute_backtest(&state, &request, None).await.map(Json)
+}
+
+/// 검증된 단일 백테스트 요청 정보.
+struct ValidatedRunRequest {
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    strategy_meta: &'static StrategyMeta,
+}
+
+/// 단일 백테스트 요청 검증 (날짜, 초기 자본금, 전략 존재 여부).
+///
+/// 비동기 작업 큐는 제출 시점에 이 검증을 수행하여 잘못된 요청을 즉시 거절합니다.
+fn validate_run_request(
+    request: &BacktestRunRequest,
+) -> Result<ValidatedRunRequest, (StatusCode, Json<BacktestApiError>)> {
     // 날짜 파싱 검증
     let start_date = NaiveDate::parse_from_str(&request.start_date, "%Y-%m-%d").map_err(|_| {
         (
@@ -217,6 +236,27 @@ pub async fn run_backtest(
         )
     })?;
 
+    Ok(ValidatedRunRequest {
+        start_date,
+        end_date,
+        strategy_meta,
+    })
+}
+
+/// 단일 백테스트 실행 (동기 API와 비동기 작업 큐 공용).
+///
+/// `progress`가 주어지면 엔진이 진행률을 기록하고, 취소 요청 시 중단합니다.
+async fn execute_backtest(
+    state: &AppState,
+    request: &BacktestRunRequest,
+    progress: Option<BacktestProgressHandle>,
+) -> Result<BacktestRunResponse, (StatusCode, Json<BacktestApiError>)> {
+    let ValidatedRunRequest {
+        start_date,
+        end_date,
+        strategy_meta,
+    } = validate_run_request(request)?;
+
     // 전략 기본 타임프레임 (시뮬레이션과 동일한 fallback 로직 적용)
     // primary가 없으면 다음 secondary가 primary가 됨
     let default_timeframe = strategy_meta.default_timeframe;
@@ -306,6 +346,7 @@ pub async fn run_backtest(
             config,
             &merged_klines,
             &request.parameters,
+            progress,
         )
         .await
         .map_err(|e| {
@@ -330,7 +371,7 @@ pub async fn run_backtest(
             report.metrics.total_return_pct
         );
 
-        return Ok(Json(response));
+        return Ok(response);
     }
 
     // 단일 심볼 전략 (공유 data_provider 사용 - Redis 3계층 캐시)
@@ -391,14 +432,20 @@ pub async fn run_backtest(
         .with_slippage_rate(slippage_rate);
 
     // 전략별 백테스트 실행
-    let report = run_strategy_backtest(&request.strategy_id, config, &klines, &request.parameters)
-        .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(BacktestApiError::new("BACKTEST_ERROR", e.to_string())),
-            )
-        })?;
+    let report = run_strategy_backtest(
+        &request.strategy_id,
+        config,
+        &klines,
+        &request.parameters,
+        progress,
+    )
+    .await
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(BacktestApiError::new("BACKTEST_ERROR", e.to_string())),
+        )
+    })?;
 
     // BacktestReport를 API 응답으로 변환
     let response = convert_report_to_response(
@@ -414,7 +461,7 @@ pub async fn run_backtest(
         report.metrics.total_return_pct
     );
 
-    Ok(Json(response))
+    Ok(response)
 }
 
 /// 백테스트 결과 조회.
@@ -638,6 +685,7 @@ pub async fn run_multi_backtest(
         &merged_klines,
         &multi_klines,
         &request.parameters,
+        None,
     )
     .await
     .map_err(|e| {
@@ -904,7 +952,7 @@ async fn run_single_strategy_internal(
         .with_slippage_rate(slippage_rate);
 
     // 백테스트 실행
-    let report = run_strategy_backtest(strategy_id, config, &klines, params)
+    let report = run_strategy_backtest(strategy_id, config, &klines, params, None)
         .await
         .map_err(|e| e.to_string())?;
 
@@ -974,10 +1022,16 @@ async fn run_multi_strategy_internal(
         .with_slippage_rate(slippage_rate);
 
     // 백테스트 실행
-    let report =
-        run_multi_strategy_backtest(strategy_id, config, &merged_klines, &multi_klines, params)
-            .await
-            .map_err(|e| e.to_string())?;
+    let report = run_multi_strategy_backtest(
+        strategy_id,
+        config,
+        &merged_klines,
+        &multi_klines,
+        params,
+        None,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
 
     // 메트릭만 반환
     Ok(convert_report_to_metrics(&report))
@@ -503,6 +503,9 @@ pub struct BacktestMultiRunResponse {
     pub config_summary: BacktestConfigSummary,
     /// 심볼별 데이터 포인트 수
     pub data_points_by_symbol: HashMap<String, usize>,
+    /// 성과 목표 평가 (전략 params에 목표가 선언된 경우)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_evaluation: Option<serde_json::Value>,
 }
 
 /// 백테스트 성과 지표 응답
@@ -673,6 +676,9 @@ pub struct BacktestRunResponse {
     /// 모든 거래 기록 (매수/매도 개별 거래 포함) - 매매일지용
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub all_trades: Vec<TradeResultItem>,
+    /// 성과 목표 평가 (전략 params에 목표가 선언된 경우)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_evaluation: Option<serde_json::Value>,
 }
 
 /// 백테스트 설정 요약
@@ -12,6 +12,10 @@
 //! - `POST /api/v1/backtest/results` - 결과 저장
 //! - `GET /api/v1/backtest/results/{id}` - 단일 결과 조회
 //! - `DELETE /api/v1/backtest/results/{id}` - 결과 삭제
+//! - `GET /api/v1/backtest/results/{id}/diagnosis` - 성과 자가 진단 리포트
+//! - `GET /api/v1/backtest/results/history` - 전략별 실행 이력 및 성과 추이
+//! - `GET /api/v1/backtest/results/diff` - 두 실행의 설정/지표 비교
+//! - `POST /api/v1/backtest/results/prune` - 보존 정책에 따른 오래된 결과 정리
 
 use std::sync::Arc;
 
@@ -19,19 +23,24 @@ use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    routing::get,
+    routing::{get, post},
     Json, Router,
 };
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, warn};
+use trader_analytics::backtest::{
+    build_performance_trend, BacktestRunDiff, RetentionPolicy, TrendPoint,
+};
+use trader_strategy::strategies::common::{DiagnosisFinding, DiagnosisInput, StrategyDiagnoser};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::{
     repository::{
-        BacktestResultDto, BacktestResultInput, BacktestResultsRepository, ListResultsFilter,
+        BacktestResultDto, BacktestResultInput, BacktestResultRecord, BacktestResultsRepository,
+        ListResultsFilter,
     },
     state::AppState,
 };
@@ -69,6 +78,9 @@ pub struct SaveBacktestResultRequest {
     /// 백테스트에 사용된 타임프레임 설정 (다중 TF 백테스트 시)
     #[serde(default)]
     pub timeframes_used: Option<serde_json::Value>,
+    /// 전략 파라미터 (설정 해시 및 실행 간 비교에 사용)
+    #[serde(default)]
+    pub parameters: Option<serde_json::Value>,
 }
 
 /// 저장된 결과 응답 (Repository DTO 재사용).
@@ -107,6 +119,122 @@ pub struct ListResultsResponse {
 pub struct SaveResultResponse {
     pub id: String,
     pub message: String,
+    /// 동일 설정의 기존 결과를 갱신했는지 여부
+    pub replaced: bool,
+}
+
+/// 실행 이력 조회 쿼리 파라미터.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct BacktestHistoryQuery {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 최근 N개 실행 (기본 50, 최대 500)
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+/// 실행 이력 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BacktestHistoryResponse {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 실행 수
+    pub total: usize,
+    /// 서로 다른 설정 수
+    pub distinct_configs: usize,
+    /// 성과 추이 (오래된 순)
+    pub trend: Vec<TrendPoint>,
+}
+
+/// 실행 비교 쿼리 파라미터.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct BacktestDiffQuery {
+    /// 기준 실행 ID
+    pub base: Uuid,
+    /// 비교 실행 ID
+    pub target: Uuid,
+}
+
+/// 결과 정리 요청.
+///
+/// 생략한 항목은 기본 보존 정책(전략별 50건, 180일)을 따릅니다.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct PruneBacktestResultsRequest {
+    /// 대상 전략 ID (생략 시 전체)
+    #[serde(default)]
+    pub strategy_id: Option<String>,
+    /// 전략별 최대 보관 건수
+    #[serde(default)]
+    pub max_runs_per_strategy: Option<usize>,
+    /// 최대 보관 기간 (일)
+    #[serde(default)]
+    pub max_age_days: Option<u32>,
+}
+
+/// 결과 정리 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PruneBacktestResultsResponse {
+    /// 정리(soft delete)된 결과 수
+    pub pruned: u64,
+}
+
+/// 성과 진단 리포트 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BacktestDiagnosisResponse {
+    /// 백테스트 결과 ID
+    pub result_id: String,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 탐지된 문제가 없는지 여부
+    pub healthy: bool,
+    /// 탐지된 문제 (영향도 내림차순, 근거 지표 및 개선 제안 포함)
+    #[schema(value_type = Vec<Object>)]
+    pub findings: Vec<DiagnosisFinding>,
+}
+
+/// 저장된 성과 지표 중 진단에 필요한 항목.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct StoredMetrics {
+    total_trades: usize,
+    win_rate_pct: Decimal,
+    avg_win: Decimal,
+    avg_loss: Decimal,
+    total_return_pct: Decimal,
+    max_drawdown_pct: Decimal,
+    net_profit: Decimal,
+}
+
+/// 저장된 설정 요약 중 진단에 필요한 항목.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct StoredConfigSummary {
+    total_commission: Decimal,
+    total_slippage: Decimal,
+    data_points: usize,
+}
+
+/// 저장된 결과 레코드에서 진단 입력 추출.
+///
+/// 필드가 없거나 형식이 다르면 기본값으로 두어 해당 규칙을 건너뜁니다.
+fn diagnosis_input_from_record(record: &BacktestResultRecord) -> DiagnosisInput {
+    let metrics: StoredMetrics = serde_json::from_value(record.metrics.clone()).unwrap_or_default();
+    let summary: StoredConfigSummary =
+        serde_json::from_value(record.config_summary.clone()).unwrap_or_default();
+
+    DiagnosisInput {
+        data_points: summary.data_points,
+        total_trades: metrics.total_trades,
+        win_rate_pct: metrics.win_rate_pct,
+        avg_win: metrics.avg_win,
+        avg_loss: metrics.avg_loss,
+        total_return_pct: metrics.total_return_pct,
+        max_drawdown_pct: metrics.max_drawdown_pct,
+        net_profit: metrics.net_profit,
+        total_commission: summary.total_commission,
+        total_slippage: summary.total_slippage,
+        ..Default::default()
+    }
 }
 
 // ==================== 핸들러 ====================
@@ -253,16 +381,39 @@ pub async fn save_backtest_result(
         trades: request.trades,
         success: request.success,
         timeframes_used: request.timeframes_used,
+        parameters: request.parameters.unwrap_or_else(|| serde_json::json!({})),
     };
+    let strategy_id = input.strategy_id.clone();
 
     match BacktestResultsRepository::save(pool, input).await {
-        Ok(id) => {
-            debug!("백테스트 결과 저장 완료: id={}", id);
+        Ok(outcome) => {
+            debug!("백테스트 결과 저장 완료: id={}", outcome.id);
+
+            // 저장 실패로 이어지지 않도록 정리 실패는 경고만 남김
+            if let Err(e) = BacktestResultsRepository::prune(
+                pool,
+                Some(&strategy_id),
+                RetentionPolicy::default(),
+            )
+            .await
+            {
+                warn!(
+                    "백테스트 결과 정리 실패: strategy_id={}, {}",
+                    strategy_id, e
+                );
+            }
+
+            let message = if outcome.replaced {
+                "동일 설정의 기존 백테스트 결과가 갱신되었습니다"
+            } else {
+                "백테스트 결과가 저장되었습니다"
+            };
             (
                 StatusCode::CREATED,
                 Json(SaveResultResponse {
-                    id: id.to_string(),
-                    message: "백테스트 결과가 저장되었습니다".to_string(),
+                    id: outcome.id.to_string(),
+                    message: message.to_string(),
+                    replaced: outcome.replaced,
                 }),
             )
                 .into_response()
@@ -429,6 +580,274 @@ pub async fn delete_backtest_result(
     }
 }
 
+/// 백테스트 결과 성과 진단.
+///
+/// `GET /api/v1/backtest/results/{id}/diagnosis`
+///
+/// 저장된 성과 지표를 규칙 기반으로 진단하여 문제 패턴과 개선 제안을 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/backtest/results/{id}/diagnosis",
+    tag = "backtest",
+    params(("id" = String, Path, description = "백테스트 결과 ID")),
+    responses(
+        (status = 200, description = "진단 리포트", body = BacktestDiagnosisResponse),
+        (status = 404, description = "결과 없음"),
+        (status = 503, description = "DB 미연결")
+    )
+)]
+pub async fn get_backtest_result_diagnosis(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    debug!("백테스트 결과 진단: id={}", id);
+
+    let pool = match &state.db_pool {
+        Some(p) => p,
+        None => {
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(serde_json::json!({
+                    "error": "데이터베이스가 연결되지 않았습니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let uuid = match Uuid::parse_str(&id) {
+        Ok(u) => u,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": "유효하지 않은 ID 형식입니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    match BacktestResultsRepository::get_by_id(pool, uuid).await {
+        Ok(Some(record)) => {
+            // 저장된 결과에는 전략 파라미터가 없으므로 설정 기반 필터 점검은 생략
+            let report = StrategyDiagnoser::default().diagnose(
+                &diagnosis_input_from_record(&record),
+                &serde_json::Value::Null,
+            );
+            Json(BacktestDiagnosisResponse {
+                result_id: record.id.to_string(),
+                strategy_id: record.strategy_id,
+                healthy: report.is_healthy(),
+                findings: report.findings,
+            })
+            .into_response()
+        }
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "결과를 찾을 수 없습니다"
+            })),
+        )
+            .into_response(),
+        Err(e) => {
+            warn!("결과 진단 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "결과 조회 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// 전략별 백테스트 실행 이력 및 성과 추이.
+///
+/// `GET /api/v1/backtest/results/history`
+#[utoipa::path(
+    get,
+    path = "/api/v1/backtest/results/history",
+    tag = "backtest",
+    params(BacktestHistoryQuery),
+    responses(
+        (status = 200, description = "실행 이력", body = BacktestHistoryResponse),
+        (status = 503, description = "DB 미연결")
+    )
+)]
+pub async fn get_backtest_history(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<BacktestHistoryQuery>,
+) -> impl IntoResponse {
+    debug!("백테스트 실행 이력 조회: {:?}", query);
+
+    let pool = match &state.db_pool {
+        Some(p) => p,
+        None => {
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(serde_json::json!({
+                    "error": "데이터베이스가 연결되지 않았습니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    match BacktestResultsRepository::list_runs(pool, &query.strategy_id, query.limit.clamp(1, 500))
+        .await
+    {
+        Ok(runs) => {
+            let trend = build_performance_trend(&runs);
+            let mut hashes: Vec<_> = runs.iter().map(|r| &r.config_hash).collect();
+            hashes.sort();
+            hashes.dedup();
+
+            Json(BacktestHistoryResponse {
+                strategy_id: query.strategy_id,
+                total: trend.len(),
+                distinct_configs: hashes.len(),
+                trend,
+            })
+            .into_response()
+        }
+        Err(e) => {
+            warn!("실행 이력 조회 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "실행 이력 조회 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// 두 백테스트 실행 비교.
+///
+/// `GET /api/v1/backtest/results/diff`
+///
+/// 기준 실행 대비 비교 실행의 파라미터 변경과 지표 변화량을 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/backtest/results/diff",
+    tag = "backtest",
+    params(BacktestDiffQuery),
+    responses(
+        (status = 200, description = "실행 비교 결과", body = BacktestRunDiff),
+        (status = 404, description = "결과 없음"),
+        (status = 503, description = "DB 미연결")
+    )
+)]
+pub async fn diff_backtest_results(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<BacktestDiffQuery>,
+) -> impl IntoResponse {
+    debug!("백테스트 결과 비교: {} → {}", query.base, query.target);
+
+    let pool = match &state.db_pool {
+        Some(p) => p,
+        None => {
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(serde_json::json!({
+                    "error": "데이터베이스가 연결되지 않았습니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let runs = tokio::try_join!(
+        BacktestResultsRepository::get_run(pool, query.base),
+        BacktestResultsRepository::get_run(pool, query.target),
+    );
+
+    match runs {
+        Ok((Some(base), Some(target))) => {
+            Json(BacktestRunDiff::between(&base, &target)).into_response()
+        }
+        Ok(_) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "결과를 찾을 수 없습니다"
+            })),
+        )
+            .into_response(),
+        Err(e) => {
+            warn!("결과 비교 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "결과 조회 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// 보존 정책에 따라 오래된 백테스트 결과 정리.
+///
+/// `POST /api/v1/backtest/results/prune`
+#[utoipa::path(
+    post,
+    path = "/api/v1/backtest/results/prune",
+    tag = "backtest",
+    request_body = PruneBacktestResultsRequest,
+    responses(
+        (status = 200, description = "정리 완료", body = PruneBacktestResultsResponse),
+        (status = 503, description = "DB 미연결")
+    )
+)]
+pub async fn prune_backtest_results(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<PruneBacktestResultsRequest>,
+) -> impl IntoResponse {
+    debug!("백테스트 결과 정리: {:?}", request);
+
+    let pool = match &state.db_pool {
+        Some(p) => p,
+        None => {
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(serde_json::json!({
+                    "error": "데이터베이스가 연결되지 않았습니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let defaults = RetentionPolicy::default();
+    let policy = RetentionPolicy {
+        max_runs_per_strategy: request
+            .max_runs_per_strategy
+            .unwrap_or(defaults.max_runs_per_strategy),
+        max_age_days: request.max_age_days.or(defaults.max_age_days),
+    };
+
+    match BacktestResultsRepository::prune(pool, request.strategy_id.as_deref(), policy).await {
+        Ok(pruned) => Json(PruneBacktestResultsResponse { pruned }).into_response(),
+        Err(e) => {
+            warn!("결과 정리 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "결과 정리 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
 // ==================== 라우터 ====================
 
 /// 백테스트 결과 라우터 생성.
@@ -438,6 +857,12 @@ pub fn backtest_results_router() -> Router<Arc<AppState>> {
     Router::new()
         // 결과 목록 조회 + 저장 (같은 경로에 GET/POST)
         .route("/", get(list_backtest_results).post(save_backtest_result))
+        // 실행 이력/비교/정리 (`/{id}`보다 정적 경로 우선)
+        .route("/history", get(get_backtest_history))
+        .route("/diff", get(diff_backtest_results))
+        .route("/prune", post(prune_backtest_results))
         // 단일 결과 조회 + 삭제 (같은 경로에 GET/DELETE)
         .route("/{id}", get(get_backtest_result).delete(delete_backtest_result))
+        // 성과 진단 리포트
+        .route("/{id}/diagnosis", get(get_backtest_result_diagnosis))
 }
@@ -22,7 +22,11 @@ use super::types::{
     ExchangeCredentialsListResponse, ExchangeTestResponse, SupportedExchange,
     SupportedExchangesResponse, TestNewCredentialRequest, UpdateExchangeCredentialRequest,
 };
-use crate::{routes::strategies::ApiError, state::AppState};
+use crate::{
+    auth::{Claims, OptionalJwtAuth},
+    routes::strategies::ApiError,
+    state::AppState,
+};
 
 // =============================================================================
 // Exchange Credential Handlers
@@ -205,6 +209,38 @@ pub async fn get_supported_exchanges() -> impl IntoResponse {
             docs_url: Some("https://apidocs.bithumb.com/".to_string()),
             is_data_provider: false,
         },
+        SupportedExchange {
+            exchange_id: "bybit".to_string(),
+            display_name: "Bybit".to_string(),
+            market_type: "crypto".to_string(),
+            supports_testnet: true,
+            required_fields: vec![
+                CredentialField {
+                    name: "api_key".to_string(),
+                    label: "API Key".to_string(),
+                    field_type: "password".to_string(),
+                    placeholder: Some("Bybit API Key".to_string()),
+                    help_text: Some("Bybit API 관리에서 발급받은 V5 API Key".to_string()),
+                },
+                CredentialField {
+                    name: "api_secret".to_string(),
+                    label: "API Secret".to_string(),
+                    field_type: "password".to_string(),
+                    placeholder: Some("Bybit API Secret".to_string()),
+                    help_text: Some("HMAC 방식 API Secret".to_string()),
+                },
+            ],
+            optional_fields: vec![CredentialField {
+                name: "category".to_string(),
+                label: "거래 유형".to_string(),
+                field_type: "select".to_string(),
+                placeholder: Some("spot".to_string()),
+                help_text: Some("spot(현물) 또는 linear(USDT 무기한)".to_string()),
+            }],
+            description: "글로벌 암호화폐 거래소 (통합 계좌 V5 API)".to_string(),
+            docs_url: Some("https://bybit-exchange.github.io/docs/v5/intro".to_string()),
+            is_data_provider: false,
+        },
         SupportedExchange {
             exchange_id: "db_investment".to_string(),
             display_name: "DB금융투자".to_string(),
@@ -434,6 +470,7 @@ pub async fn list_exchange_credentials(
 )]
 pub async fn create_exchange_credential(
     State(state): State<Arc<AppState>>,
+    OptionalJwtAuth(claims): OptionalJwtAuth,
     Json(request): Json<CreateExchangeCredentialRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
     debug!("거래소 자격증명 등록 요청: {}", request.exchange_id);
@@ -504,13 +541,14 @@ pub async fn create_exchange_credential(
             INSERT INTO exchange_credentials
                 (id, exchange_id, exchange_name, market_type,
                  encrypted_credentials, encryption_nonce, encryption_version,
-                 is_active, is_testnet, settings)
-            VALUES ($1, $2, $3, $4, $5, $6, 1, true, false, $7)
+                 is_active, is_testnet, settings, owner_id)
+            VALUES ($1, $2, $3, $4, $5, $6, 1, true, false, $7, $8)
             ON CONFLICT (exchange_id, market_type, is_testnet, exchange_name)
             DO UPDATE SET
                 encrypted_credentials = EXCLUDED.encrypted_credentials,
                 encryption_nonce = EXCLUDED.encryption_nonce,
                 settings = EXCLUDED.settings,
+                owner_id = COALESCE(exchange_credentials.owner_id, EXCLUDED.owner_id),
                 updated_at = NOW()
             RETURNING id
             "#,
@@ -522,6 +560,7 @@ pub async fn create_exchange_credential(
         .bind(&encrypted_data)
         .bind(nonce.to_vec())
         .bind(serde_json::to_value(&settings).ok())
+        .bind(claims.as_ref().and_then(Claims::user_uuid))
         .fetch_one(pool)
         .await
         .map_err(|e| {
@@ -625,13 +664,14 @@ pub async fn create_exchange_credential(
         INSERT INTO exchange_credentials
             (id, exchange_id, exchange_name, market_type,
              encrypted_credentials, encryption_nonce, encryption_version,
-             is_active, is_testnet, settings)
-        VALUES ($1, $2, $3, $4, $5, $6, 1, true, $7, $8)
+             is_active, is_testnet, settings, owner_id)
+        VALUES ($1, $2, $3, $4, $5, $6, 1, true, $7, $8, $9)
         ON CONFLICT (exchange_id, market_type, is_testnet, exchange_name)
         DO UPDATE SET
             encrypted_credentials = EXCLUDED.encrypted_credentials,
             encryption_nonce = EXCLUDED.encryption_nonce,
             settings = EXCLUDED.settings,
+            owner_id = COALESCE(exchange_credentials.owner_id, EXCLUDED.owner_id),
             updated_at = NOW()
         RETURNING id
         "#,
@@ -644,6 +684,7 @@ pub async fn create_exchange_credential(
     .bind(nonce.to_vec())
     .bind(request.is_testnet)
     .bind(&request.settings)
+    .bind(claims.as_ref().and_then(Claims::user_uuid))
     .fetch_one(pool)
     .await
     .map_err(|e| {
@@ -1035,7 +1076,7 @@ pub async fn test_exchange_credential(
                 )
             }
         }
-        "upbit" | "bithumb" => {
+        "upbit" | "bithumb" | "bybit" => {
             if credentials.api_key.len() >= 10 && credentials.api_secret.len() >= 10 {
                 (
                     true,
@@ -1171,7 +1212,7 @@ pub async fn test_new_exchange_credential(
 
     // 거래소별 API 키 형식 검증
     let (success, message, permissions) = match request.exchange_id.as_str() {
-        "binance" | "upbit" | "bithumb" => {
+        "binance" | "upbit" | "bithumb" | "bybit" => {
             if api_key.len() >= 10 && api_secret.len() >= 10 {
                 (
                     true,
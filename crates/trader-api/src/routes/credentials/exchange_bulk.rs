@@ -0,0 +1,491 @@
+//! 거래소 자격증명 대량 가져오기/내보내기.
+//!
+//! 운영자가 여러 계정을 한 번에 다른 서버로 마이그레이션할 수 있도록
+//! 암호화된 상태 그대로 자격증명을 JSON 배열로 주고받습니다.
+//!
+//! # 보안
+//! - 내보내기/가져오기 모두 Admin 권한이 필요합니다.
+//! - 내보내기는 API 키 평문을 절대 포함하지 않고 암호화된 blob과 nonce만 담습니다.
+//! - 가져오기는 `key_check`로 마스터 키를 먼저 검증한 뒤, 원본 키로 복호화하고
+//!   현재 서버의 마스터 키로 재암호화하여 저장합니다.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use tracing::{debug, error, info, warn};
+use trader_core::crypto::{CredentialEncryptor, NONCE_SIZE};
+use uuid::Uuid;
+
+use super::types::{
+    infer_market_type, log_credential_access, BulkConflictPolicy, BulkCredentialRecord,
+    BulkExportResponse, BulkImportItemResult, BulkImportQuery, BulkImportRequest,
+    BulkImportResponse, BulkImportStatus, BulkKeyCheck, EncryptedCredentials,
+    ExchangeCredentialRow, BULK_EXPORT_FORMAT_VERSION,
+};
+use crate::{auth::AdminAuth, routes::strategies::ApiError, state::AppState};
+
+/// 마스터 키 검증용 고정 마커
+const KEY_CHECK_MARKER: &str = "zeroquant-exchange-credentials-export";
+
+/// 암호문을 Base64 레코드로 변환.
+fn encode_blob(ciphertext: &[u8], nonce: &[u8]) -> (String, String) {
+    (STANDARD.encode(ciphertext), STANDARD.encode(nonce))
+}
+
+/// Base64 암호문/nonce 디코드.
+fn decode_blob(ciphertext: &str, nonce: &str) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let ciphertext = STANDARD
+        .decode(ciphertext)
+        .map_err(|e| format!("암호문 디코드 실패: {}", e))?;
+    let nonce = STANDARD
+        .decode(nonce)
+        .map_err(|e| format!("nonce 디코드 실패: {}", e))?;
+    if nonce.len() != NONCE_SIZE {
+        return Err(format!(
+            "nonce 길이 오류: {}바이트 (기대값 {})",
+            nonce.len(),
+            NONCE_SIZE
+        ));
+    }
+    Ok((ciphertext, nonce))
+}
+
+/// 마스터 키 검증용 암호문 생성.
+fn create_key_check(encryptor: &CredentialEncryptor) -> Result<BulkKeyCheck, String> {
+    let (ciphertext, nonce) = encryptor
+        .encrypt(KEY_CHECK_MARKER)
+        .map_err(|e| e.to_string())?;
+    let (ciphertext, nonce) = encode_blob(&ciphertext, &nonce);
+    Ok(BulkKeyCheck { ciphertext, nonce })
+}
+
+/// 마스터 키 검증 (복호화 결과가 마커와 일치해야 함).
+fn verify_key_check(encryptor: &CredentialEncryptor, key_check: &BulkKeyCheck) -> bool {
+    decode_blob(&key_check.ciphertext, &key_check.nonce)
+        .ok()
+        .and_then(|(ciphertext, nonce)| encryptor.decrypt(&ciphertext, &nonce).ok())
+        .is_some_and(|marker| marker == KEY_CHECK_MARKER)
+}
+
+/// 원본 키로 복호화한 뒤 대상 키로 재암호화.
+fn reencrypt_record(
+    source: &CredentialEncryptor,
+    target: &CredentialEncryptor,
+    record: &BulkCredentialRecord,
+) -> Result<(Vec<u8>, [u8; NONCE_SIZE]), String> {
+    let (ciphertext, nonce) = decode_blob(&record.encrypted_credentials, &record.encryption_nonce)?;
+    let credentials: EncryptedCredentials = source
+        .decrypt_json(&ciphertext, &nonce)
+        .map_err(|e| format!("복호화 실패: {}", e))?;
+    target
+        .encrypt_json(&credentials)
+        .map_err(|e| format!("재암호화 실패: {}", e))
+}
+
+/// Export exchange credentials in encrypted form.
+///
+/// `GET /api/v1/credentials/exchanges/bulk-export`
+#[utoipa::path(
+    get,
+    path = "/api/v1/credentials/exchanges/bulk-export",
+    tag = "credentials",
+    responses(
+        (status = 200, description = "자격증명 대량 내보내기 성공", body = BulkExportResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요"),
+        (status = 500, description = "서버 내부 오류", body = ApiError)
+    )
+)]
+pub async fn bulk_export_exchange_credentials(
+    AdminAuth(claims): AdminAuth,
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        error!("DB 연결이 설정되지 않았습니다.");
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스 연결이 설정되지 않았습니다.",
+            )),
+        )
+    })?;
+
+    let encryptor = state.encryptor.as_ref().ok_or_else(|| {
+        error!("암호화 관리자가 설정되지 않았습니다.");
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "ENCRYPTOR_NOT_CONFIGURED",
+                "암호화 설정이 없습니다. ENCRYPTION_MASTER_KEY를 설정하세요.",
+            )),
+        )
+    })?;
+
+    let rows: Vec<ExchangeCredentialRow> = sqlx::query_as(
+        r#"
+        SELECT
+            id, exchange_id, exchange_name, market_type,
+            encrypted_credentials, encryption_nonce,
+            is_active, is_testnet, permissions, settings,
+            last_used_at, last_verified_at, created_at, updated_at
+        FROM exchange_credentials
+        ORDER BY created_at
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("자격증명 목록 조회 실패: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DB_ERROR", format!("조회 실패: {}", e))),
+        )
+    })?;
+
+    let key_check = create_key_check(encryptor).map_err(|e| {
+        error!("키 검증 마커 암호화 실패: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("ENCRYPTION_FAILED", "암호화 실패")),
+        )
+    })?;
+
+    let mut credentials = Vec::with_capacity(rows.len());
+    for row in rows {
+        // 감사 로그: 암호문이라도 외부로 반출되므로 자격증명별로 기록
+        log_credential_access(pool, "exchange", row.id, "export", true, None).await;
+
+        let (encrypted_credentials, encryption_nonce) =
+            encode_blob(&row.encrypted_credentials, &row.encryption_nonce);
+        credentials.push(BulkCredentialRecord {
+            exchange_id: row.exchange_id,
+            exchange_name: row.exchange_name,
+            market_type: Some(row.market_type),
+            is_active: row.is_active,
+            is_testnet: row.is_testnet,
+            permissions: row.permissions,
+            settings: row.settings,
+            encrypted_credentials,
+            encryption_nonce,
+        });
+    }
+
+    let total = credentials.len();
+    info!(exported_by = %claims.sub, "자격증명 대량 내보내기: {}개", total);
+
+    Ok(Json(BulkExportResponse {
+        format_version: BULK_EXPORT_FORMAT_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        key_check,
+        credentials,
+        total,
+    }))
+}
+
+/// Import exchange credentials exported by `bulk-export`.
+///
+/// `POST /api/v1/credentials/exchanges/bulk-import?on_conflict=skip|overwrite`
+#[utoipa::path(
+    post,
+    path = "/api/v1/credentials/exchanges/bulk-import",
+    tag = "credentials",
+    params(
+        ("on_conflict" = Option<String>, Query, description = "중복(같은 거래소+라벨) 처리 정책: skip(기본) | overwrite")
+    ),
+    request_body = BulkImportRequest,
+    responses(
+        (status = 200, description = "자격증명 대량 가져오기 결과", body = BulkImportResponse),
+        (status = 400, description = "잘못된 입력 또는 마스터 키 불일치", body = ApiError),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요"),
+        (status = 500, description = "서버 내부 오류", body = ApiError)
+    )
+)]
+pub async fn bulk_import_exchange_credentials(
+    AdminAuth(claims): AdminAuth,
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<BulkImportQuery>,
+    Json(request): Json<BulkImportRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
+    debug!(
+        "자격증명 대량 가져오기 요청: {}개 (on_conflict: {:?})",
+        request.credentials.len(),
+        query.on_conflict
+    );
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        error!("DB 연결이 설정되지 않았습니다.");
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스 연결이 설정되지 않았습니다.",
+            )),
+        )
+    })?;
+
+    let encryptor = state.encryptor.as_ref().ok_or_else(|| {
+        error!("암호화 관리자가 설정되지 않았습니다.");
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "ENCRYPTOR_NOT_CONFIGURED",
+                "암호화 설정이 없습니다. ENCRYPTION_MASTER_KEY를 설정하세요.",
+            )),
+        )
+    })?;
+
+    // 원본 마스터 키 (생략 시 현재 서버 키)
+    let source_encryptor = match request.source_master_key.as_deref() {
+        Some(key) => Some(CredentialEncryptor::new(key).map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new(
+                    "INVALID_MASTER_KEY",
+                    format!("원본 마스터 키가 올바르지 않습니다: {}", e),
+                )),
+            )
+        })?),
+        None => None,
+    };
+    let source = source_encryptor.as_ref().unwrap_or(encryptor.as_ref());
+
+    // 자격증명을 건드리기 전에 마스터 키 검증
+    if !verify_key_check(source, &request.key_check) {
+        warn!("자격증명 가져오기 거부: 마스터 키 불일치");
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new(
+                "MASTER_KEY_MISMATCH",
+                "마스터 키가 내보내기 파일과 일치하지 않습니다. source_master_key를 확인하세요.",
+            )),
+        ));
+    }
+
+    let mut results = Vec::with_capacity(request.credentials.len());
+
+    for record in &request.credentials {
+        let mut result = BulkImportItemResult {
+            exchange_id: record.exchange_id.clone(),
+            exchange_name: record.exchange_name.clone(),
+            status: BulkImportStatus::Failed,
+            id: None,
+            error: None,
+        };
+
+        let (encrypted_data, nonce) = match reencrypt_record(source, encryptor, record) {
+            Ok(blob) => blob,
+            Err(e) => {
+                warn!(
+                    "자격증명 가져오기 실패 ({} / {}): {}",
+                    record.exchange_id, record.exchange_name, e
+                );
+                result.error = Some(e);
+                results.push(result);
+                continue;
+            }
+        };
+
+        let market_type = record
+            .market_type
+            .clone()
+            .unwrap_or_else(|| infer_market_type(&record.exchange_id).to_string());
+
+        match import_record(
+            pool,
+            record,
+            &market_type,
+            &encrypted_data,
+            &nonce,
+            query.on_conflict,
+        )
+        .await
+        {
+            Ok((id, status)) => {
+                if status != BulkImportStatus::Skipped {
+                    log_credential_access(pool, "exchange", id, "import", true, None).await;
+                }
+                result.id = Some(id);
+                result.status = status;
+            }
+            Err(e) => {
+                error!(
+                    "자격증명 저장 실패 ({} / {}): {}",
+                    record.exchange_id, record.exchange_name, e
+                );
+                result.error = Some(format!("저장 실패: {}", e));
+            }
+        }
+
+        results.push(result);
+    }
+
+    let count = |status: BulkImportStatus| results.iter().filter(|r| r.status == status).count();
+    let response = BulkImportResponse {
+        created: count(BulkImportStatus::Created),
+        overwritten: count(BulkImportStatus::Overwritten),
+        skipped: count(BulkImportStatus::Skipped),
+        failed: count(BulkImportStatus::Failed),
+        results,
+    };
+
+    info!(
+        imported_by = %claims.sub,
+        "자격증명 대량 가져오기 완료: 생성 {}, 덮어쓰기 {}, 건너뜀 {}, 실패 {}",
+        response.created, response.overwritten, response.skipped, response.failed
+    );
+
+    Ok(Json(response))
+}
+
+/// 재암호화된 자격증명 1건 저장 (중복 정책 적용).
+///
+/// 중복 판정은 `unique_exchange_account` (exchange_id, market_type, is_testnet, exchange_name)
+/// 제약에 맡겨, 동시에 같은 계정을 가져와도 한 건만 생성된다.
+async fn import_record(
+    pool: &sqlx::PgPool,
+    record: &BulkCredentialRecord,
+    market_type: &str,
+    encrypted_data: &[u8],
+    nonce: &[u8; NONCE_SIZE],
+    on_conflict: BulkConflictPolicy,
+) -> Result<(Uuid, BulkImportStatus), sqlx::Error> {
+    let conflict_action = match on_conflict {
+        BulkConflictPolicy::Skip => "DO NOTHING",
+        BulkConflictPolicy::Overwrite => {
+            r#"DO UPDATE SET
+                encrypted_credentials = EXCLUDED.encrypted_credentials,
+                encryption_nonce = EXCLUDED.encryption_nonce,
+                is_active = EXCLUDED.is_active,
+                permissions = EXCLUDED.permissions,
+                settings = EXCLUDED.settings,
+                updated_at = NOW()"#
+        }
+    };
+
+    // xmax = 0 이면 신규 INSERT, 아니면 ON CONFLICT UPDATE
+    let upserted: Option<(Uuid, bool)> = sqlx::query_as(&format!(
+        r#"
+        INSERT INTO exchange_credentials
+            (id, exchange_id, exchange_name, market_type,
+             encrypted_credentials, encryption_nonce, encryption_version,
+             is_active, is_testnet, permissions, settings)
+        VALUES ($1, $2, $3, $4, $5, $6, 1, $7, $8, $9, $10)
+        ON CONFLICT ON CONSTRAINT unique_exchange_account {}
+        RETURNING id, (xmax = 0) AS inserted
+        "#,
+        conflict_action
+    ))
+    .bind(Uuid::new_v4())
+    .bind(&record.exchange_id)
+    .bind(&record.exchange_name)
+    .bind(market_type)
+    .bind(encrypted_data)
+    .bind(nonce.to_vec())
+    .bind(record.is_active)
+    .bind(record.is_testnet)
+    .bind(&record.permissions)
+    .bind(&record.settings)
+    .fetch_optional(pool)
+    .await?;
+
+    match upserted {
+        Some((id, true)) => Ok((id, BulkImportStatus::Created)),
+        Some((id, false)) => Ok((id, BulkImportStatus::Overwritten)),
+        None => {
+            // DO NOTHING: 기존 자격증명 ID만 조회
+            let id: Uuid = sqlx::query_scalar(
+                r#"
+                SELECT id FROM exchange_credentials
+                WHERE exchange_id = $1 AND market_type = $2 AND is_testnet = $3 AND exchange_name = $4
+                "#,
+            )
+            .bind(&record.exchange_id)
+            .bind(market_type)
+            .bind(record.is_testnet)
+            .bind(&record.exchange_name)
+            .fetch_one(pool)
+            .await?;
+            Ok((id, BulkImportStatus::Skipped))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use trader_core::crypto::generate_master_key;
+
+    use super::*;
+
+    fn encryptor() -> CredentialEncryptor {
+        CredentialEncryptor::new(&generate_master_key()).unwrap()
+    }
+
+    fn record(encryptor: &CredentialEncryptor) -> BulkCredentialRecord {
+        let credentials = EncryptedCredentials {
+            api_key: "test_api_key_1234".to_string(),
+            api_secret: "test_api_secret".to_string(),
+            passphrase: None,
+            additional: None,
+        };
+        let (ciphertext, nonce) = encryptor.encrypt_json(&credentials).unwrap();
+        let (encrypted_credentials, encryption_nonce) = encode_blob(&ciphertext, &nonce);
+        BulkCredentialRecord {
+            exchange_id: "binance".to_string(),
+            exchange_name: "Binance 메인".to_string(),
+            market_type: Some("crypto".to_string()),
+            is_active: true,
+            is_testnet: false,
+            permissions: None,
+            settings: None,
+            encrypted_credentials,
+            encryption_nonce,
+        }
+    }
+
+    #[test]
+    fn test_key_check_verifies_master_key() {
+        let source = encryptor();
+        let key_check = create_key_check(&source).unwrap();
+
+        assert!(verify_key_check(&source, &key_check));
+        assert!(!verify_key_check(&encryptor(), &key_check));
+    }
+
+    #[test]
+    fn test_reencrypt_record_with_new_master_key() {
+        let source = encryptor();
+        let target = encryptor();
+        let record = record(&source);
+
+        let (ciphertext, nonce) = reencrypt_record(&source, &target, &record).unwrap();
+        let decrypted: EncryptedCredentials = target.decrypt_json(&ciphertext, &nonce).unwrap();
+        assert_eq!(decrypted.api_key, "test_api_key_1234");
+
+        // 잘못된 원본 키로는 복호화 실패
+        assert!(reencrypt_record(&target, &target, &record).is_err());
+    }
+
+    #[test]
+    fn test_export_record_has_no_plaintext() {
+        let source = encryptor();
+        let json = serde_json::to_string(&record(&source)).unwrap();
+
+        assert!(!json.contains("test_api_key_1234"));
+        assert!(!json.contains("test_api_secret"));
+    }
+
+    #[test]
+    fn test_decode_blob_rejects_invalid_nonce() {
+        let nonce = STANDARD.encode([0u8; 4]);
+        assert!(decode_blob("AAAA", &nonce).is_err());
+        assert!(decode_blob("not base64!", &nonce).is_err());
+    }
+}
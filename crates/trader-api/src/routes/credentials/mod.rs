@@ -22,6 +22,8 @@
 //! - `DELETE /api/v1/credentials/exchanges/:id` - 자격증명 삭제
 //! - `POST /api/v1/credentials/exchanges/:id/test` - 연결 테스트
 //! - `POST /api/v1/credentials/exchanges/test` - 새 자격증명 테스트
+//! - `GET /api/v1/credentials/exchanges/bulk-export` - 암호화된 자격증명 대량 내보내기
+//! - `POST /api/v1/credentials/exchanges/bulk-import` - 자격증명 대량 가져오기 (재암호화)
 //!
 //! ## 텔레그램 설정
 //! - `GET /api/v1/credentials/telegram` - 텔레그램 설정 조회
@@ -56,15 +58,24 @@
 //! - `DELETE /api/v1/credentials/sms` - SMS 설정 삭제
 //! - `POST /api/v1/credentials/sms/test` - 연결 테스트 (저장된 설정)
 //! - `POST /api/v1/credentials/sms/test/new` - 연결 테스트 (저장 전)
+//!
+//! ## Webhook 설정 (범용 HTTP POST)
+//! - `GET /api/v1/credentials/webhook` - Webhook 설정 조회
+//! - `POST /api/v1/credentials/webhook` - Webhook 설정 저장
+//! - `DELETE /api/v1/credentials/webhook` - Webhook 설정 삭제
+//! - `POST /api/v1/credentials/webhook/test` - 연결 테스트 (저장된 설정)
+//! - `POST /api/v1/credentials/webhook/test/new` - 연결 테스트 (저장 전)
 
 pub mod active_account;
 pub mod discord;
 pub mod email;
 pub mod exchange;
+pub mod exchange_bulk;
 pub mod slack;
 pub mod sms;
 pub mod telegram;
 pub mod types;
+pub mod webhook;
 
 // Re-export types for external use
 use std::sync::Arc;
@@ -87,6 +98,7 @@ use exchange::{
     list_exchange_credentials, test_exchange_credential, test_new_exchange_credential,
     update_exchange_credential,
 };
+use exchange_bulk::{bulk_export_exchange_credentials, bulk_import_exchange_credentials};
 use slack::{
     delete_slack_settings, get_slack_settings, save_slack_settings, test_new_slack_settings,
     test_slack_settings,
@@ -99,14 +111,21 @@ use telegram::{
     delete_telegram_settings, get_telegram_settings, save_telegram_settings, test_telegram_settings,
 };
 pub use types::{
-    ActiveAccountResponse, CreateExchangeCredentialRequest, CredentialField,
-    DiscordSettingsResponse, EmailSettingsResponse, EncryptedCredentials,
-    ExchangeCredentialResponse, ExchangeCredentialsListResponse, ExchangeTestResponse,
-    NotificationSettingsConfig, SaveDiscordSettingsRequest, SaveEmailSettingsRequest,
-    SaveSlackSettingsRequest, SaveSmsSettingsRequest, SaveTelegramSettingsRequest,
+    ActiveAccountResponse, BulkConflictPolicy, BulkCredentialRecord, BulkExportResponse,
+    BulkImportItemResult, BulkImportRequest, BulkImportResponse, BulkImportStatus, BulkKeyCheck,
+    CreateExchangeCredentialRequest, CredentialField, DiscordSettingsResponse,
+    EmailSettingsResponse, EncryptedCredentials, ExchangeCredentialResponse,
+    ExchangeCredentialsListResponse, ExchangeTestResponse, NotificationSettingsConfig,
+    SaveDiscordSettingsRequest, SaveEmailSettingsRequest, SaveSlackSettingsRequest,
+    SaveSmsSettingsRequest, SaveTelegramSettingsRequest, SaveWebhookSettingsRequest,
     SetActiveAccountRequest, SlackSettingsResponse, SmsSettingsResponse, SupportedExchange,
     SupportedExchangesResponse, TelegramNotificationSettings, TelegramSettingsResponse,
-    TestNewCredentialRequest, UpdateExchangeCredentialRequest,
+    TestNewCredentialRequest, UpdateExchangeCredentialRequest, WebhookSettingsResponse,
+};
+
+use webhook::{
+    delete_webhook_settings, get_webhook_settings, save_webhook_settings,
+    test_new_webhook_settings, test_webhook_settings,
 };
 
 use crate::state::AppState;
@@ -123,6 +142,15 @@ pub fn credentials_router() -> Router<Arc<AppState>> {
         .route("/exchanges/list", get(list_exchange_credentials))
         .route("/exchanges", post(create_exchange_credential))
         .route("/exchanges/test", post(test_new_exchange_credential))
+        // 거래소 자격증명 대량 가져오기/내보내기
+        .route(
+            "/exchanges/bulk-export",
+            get(bulk_export_exchange_credentials),
+        )
+        .route(
+            "/exchanges/bulk-import",
+            post(bulk_import_exchange_credentials),
+        )
         .route("/exchanges/{id}", put(update_exchange_credential))
         .route("/exchanges/{id}", delete(delete_exchange_credential))
         .route("/exchanges/{id}/test", post(test_exchange_credential))
@@ -155,4 +183,10 @@ pub fn credentials_router() -> Router<Arc<AppState>> {
         .route("/sms", delete(delete_sms_settings))
         .route("/sms/test", post(test_sms_settings))
         .route("/sms/test/new", post(test_new_sms_settings))
+        // Webhook 설정
+        .route("/webhook", get(get_webhook_settings))
+        .route("/webhook", post(save_webhook_settings))
+        .route("/webhook", delete(delete_webhook_settings))
+        .route("/webhook/test", post(test_webhook_settings))
+        .route("/webhook/test/new", post(test_new_webhook_settings))
 }
@@ -8,7 +8,10 @@
 //! - DB 레코드 타입 (내부용)
 //! - 헬퍼 함수
 
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
 
 use serde::{Deserialize, Serialize};
 use tracing::warn;
@@ -184,6 +187,138 @@ pub struct SupportedExchangesResponse {
     pub exchanges: Vec<SupportedExchange>,
 }
 
+// =============================================================================
+// 거래소 자격증명 대량 가져오기/내보내기 타입
+// =============================================================================
+
+/// 대량 내보내기 형식 버전.
+pub const BULK_EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// 암호화된 자격증명 레코드 (대량 내보내기/가져오기 단위).
+///
+/// API 키 평문은 포함하지 않으며, 암호화된 blob과 nonce만 Base64로 담습니다.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkCredentialRecord {
+    /// 거래소 ID (binance, kis 등)
+    pub exchange_id: String,
+    /// 계좌 구분용 이름 (라벨)
+    pub exchange_name: String,
+    /// 시장 유형 (없으면 거래소 ID로 추론)
+    #[serde(default)]
+    pub market_type: Option<String>,
+    #[serde(default = "default_true")]
+    pub is_active: bool,
+    #[serde(default)]
+    pub is_testnet: bool,
+    #[serde(default)]
+    pub permissions: Option<serde_json::Value>,
+    #[serde(default)]
+    pub settings: Option<serde_json::Value>,
+    /// AES-256-GCM 암호문 (Base64)
+    pub encrypted_credentials: String,
+    /// AES-GCM nonce (Base64, 12바이트)
+    pub encryption_nonce: String,
+}
+
+/// 마스터 키 검증용 암호문.
+///
+/// 내보내기 시 고정 마커를 같은 마스터 키로 암호화해 담아두고, 가져오기 시
+/// 이를 복호화하여 자격증명을 건드리기 전에 키 일치 여부를 확인합니다.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkKeyCheck {
+    /// 암호문 (Base64)
+    pub ciphertext: String,
+    /// nonce (Base64)
+    pub nonce: String,
+}
+
+/// 대량 내보내기 응답 (가져오기 요청 본문으로 그대로 사용 가능).
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkExportResponse {
+    pub format_version: u32,
+    pub exported_at: String,
+    pub key_check: BulkKeyCheck,
+    pub credentials: Vec<BulkCredentialRecord>,
+    pub total: usize,
+}
+
+/// 중복 자격증명 (같은 거래소 + 라벨) 처리 정책.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkConflictPolicy {
+    /// 기존 자격증명 유지 (기본값)
+    #[default]
+    Skip,
+    /// 가져온 자격증명으로 덮어쓰기
+    Overwrite,
+}
+
+/// 대량 가져오기 쿼리 파라미터.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkImportQuery {
+    #[serde(default)]
+    pub on_conflict: BulkConflictPolicy,
+}
+
+/// 대량 가져오기 요청.
+///
+/// # 보안
+/// - `source_master_key`는 내보낸 서버의 마스터 키(Base64)입니다. 생략하면 현재 서버의
+///   마스터 키로 복호화합니다.
+/// - `Debug` 구현은 마스터 키를 마스킹합니다.
+#[derive(Deserialize, ToSchema)]
+pub struct BulkImportRequest {
+    #[serde(default)]
+    pub source_master_key: Option<String>,
+    pub key_check: BulkKeyCheck,
+    pub credentials: Vec<BulkCredentialRecord>,
+}
+
+impl fmt::Debug for BulkImportRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BulkImportRequest")
+            .field(
+                "source_master_key",
+                &self.source_master_key.as_ref().map(|_| "***REDACTED***"),
+            )
+            .field("key_check", &self.key_check)
+            .field("credentials", &self.credentials.len())
+            .finish()
+    }
+}
+
+/// 가져오기 결과 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkImportStatus {
+    Created,
+    Overwritten,
+    Skipped,
+    Failed,
+}
+
+/// 자격증명별 가져오기 결과.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkImportItemResult {
+    pub exchange_id: String,
+    pub exchange_name: String,
+    pub status: BulkImportStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 대량 가져오기 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkImportResponse {
+    pub created: usize,
+    pub overwritten: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    pub results: Vec<BulkImportItemResult>,
+}
+
 // =============================================================================
 // 공통 알림 설정 타입
 // =============================================================================
@@ -477,6 +612,76 @@ pub struct SlackSettingsResponse {
     pub updated_at: String,
 }
 
+// =============================================================================
+// Webhook 설정 타입
+// =============================================================================
+
+/// 범용 Webhook 설정 등록/수정 요청.
+///
+/// # 보안
+/// - `Debug` 구현은 민감 필드(URL, 헤더 값, HMAC 시크릿)를 마스킹합니다.
+#[derive(Deserialize, utoipa::ToSchema)]
+pub struct SaveWebhookSettingsRequest {
+    /// POST 대상 URL
+    #[schema(example = "https://example.com/hooks/zeroquant")]
+    pub url: String,
+    /// 커스텀 헤더 (예: Authorization)
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// payload 템플릿 (JSON, 선택)
+    #[schema(example = r#"{"text": "{{title}}: {{message}}"}"#)]
+    pub payload_template: Option<String>,
+    /// HMAC-SHA256 서명 시크릿 (선택)
+    pub hmac_secret: Option<String>,
+    /// 요청 타임아웃 (초, 1~60, 기본 10)
+    pub timeout_secs: Option<u32>,
+    /// 최대 재시도 횟수 (0~10, 기본 3)
+    pub max_retries: Option<u32>,
+    /// 표시 이름 (메타데이터, 선택)
+    #[schema(example = "ZeroQuant Alerts")]
+    pub display_name: Option<String>,
+    /// 알림 설정 (선택)
+    pub notification_settings: Option<NotificationSettingsConfig>,
+}
+
+impl fmt::Debug for SaveWebhookSettingsRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SaveWebhookSettingsRequest")
+            .field("url", &mask_api_key(&self.url))
+            .field("headers", &self.headers.keys().collect::<Vec<_>>())
+            .field("payload_template", &self.payload_template)
+            .field("hmac_secret", &self.hmac_secret.as_ref().map(|_| "***"))
+            .field("timeout_secs", &self.timeout_secs)
+            .field("max_retries", &self.max_retries)
+            .field("display_name", &self.display_name)
+            .field("notification_settings", &self.notification_settings)
+            .finish()
+    }
+}
+
+/// Webhook 설정 응답 (마스킹됨).
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct WebhookSettingsResponse {
+    pub id: Uuid,
+    /// 마스킹된 URL
+    #[schema(example = "http...ntquant")]
+    pub url_masked: String,
+    /// 설정된 커스텀 헤더 이름 (값은 반환하지 않음)
+    pub header_names: Vec<String>,
+    pub payload_template: Option<String>,
+    /// HMAC 서명 사용 여부
+    pub has_hmac_secret: bool,
+    pub timeout_secs: i32,
+    pub max_retries: i32,
+    pub display_name: Option<String>,
+    pub is_enabled: bool,
+    pub notification_settings: Option<NotificationSettingsConfig>,
+    pub last_message_at: Option<String>,
+    pub last_verified_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
 // =============================================================================
 // SMS 설정 타입 (Twilio)
 // =============================================================================
@@ -638,6 +843,29 @@ pub(crate) struct SlackSettingsRow {
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// DB에서 조회한 Webhook 설정 레코드.
+#[derive(Debug, sqlx::FromRow)]
+pub(crate) struct WebhookSettingsRow {
+    pub id: Uuid,
+    pub encrypted_url: Vec<u8>,
+    pub encryption_nonce_url: Vec<u8>,
+    pub encrypted_headers: Option<Vec<u8>>,
+    pub encryption_nonce_headers: Option<Vec<u8>>,
+    pub encrypted_hmac_secret: Option<Vec<u8>>,
+    pub encryption_nonce_hmac: Option<Vec<u8>>,
+    pub header_names: Vec<String>,
+    pub payload_template: Option<String>,
+    pub timeout_secs: i32,
+    pub max_retries: i32,
+    pub display_name: Option<String>,
+    pub is_enabled: bool,
+    pub notification_settings: Option<serde_json::Value>,
+    pub last_message_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_verified_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
 /// DB에서 조회한 SMS 설정 레코드.
 #[derive(Debug, sqlx::FromRow)]
 pub(crate) struct SmsSettingsRow {
@@ -773,7 +1001,7 @@ pub(crate) fn mask_api_key(key: &str) -> String {
 /// - `"unknown"`: 알 수 없는 거래소
 pub(crate) fn infer_market_type(exchange_id: &str) -> &'static str {
     match exchange_id {
-        "binance" | "coinbase" | "kraken" | "upbit" | "bithumb" => "crypto",
+        "binance" | "coinbase" | "kraken" | "upbit" | "bithumb" | "bybit" => "crypto",
         "kis" | "db_investment" | "ls_sec" => "stock_kr",
         "interactive_brokers" | "ib" => "stock_us",
         "oanda" => "forex",
@@ -0,0 +1,631 @@
+//! Webhook settings handlers.
+//!
+//! This module provides handlers for managing generic webhook notification settings
+//! with AES-256-GCM encryption for sensitive data (url, custom headers, HMAC secret).
+
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use tracing::{debug, error};
+use trader_core::crypto::CredentialEncryptor;
+use trader_notification::{WebhookConfig, WebhookSender};
+use uuid::Uuid;
+
+use super::types::{
+    log_credential_access, mask_api_key, NotificationSettingsConfig, SaveWebhookSettingsRequest,
+    WebhookSettingsRow,
+};
+use crate::{routes::strategies::ApiError, state::AppState};
+
+/// 허용 타임아웃 범위 (초)
+const TIMEOUT_SECS_RANGE: std::ops::RangeInclusive<u32> = 1..=60;
+/// 허용 재시도 횟수 최댓값
+const MAX_RETRIES_LIMIT: u32 = 10;
+/// 기본 타임아웃 (초)
+const DEFAULT_TIMEOUT_SECS: u32 = 10;
+/// 기본 재시도 횟수
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+const SELECT_WEBHOOK_SETTINGS: &str = r#"
+    SELECT
+        id, encrypted_url, encryption_nonce_url,
+        encrypted_headers, encryption_nonce_headers,
+        encrypted_hmac_secret, encryption_nonce_hmac,
+        header_names, payload_template, timeout_secs, max_retries,
+        display_name, is_enabled, notification_settings,
+        last_message_at, last_verified_at, created_at, updated_at
+    FROM webhook_settings
+    LIMIT 1
+"#;
+
+type HandlerError = (StatusCode, Json<ApiError>);
+
+/// 암호화된 선택 필드 (암호문, nonce)
+type EncryptedField = (Option<Vec<u8>>, Option<Vec<u8>>);
+
+fn invalid_input(message: impl Into<String>) -> HandlerError {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ApiError::new("INVALID_INPUT", message)),
+    )
+}
+
+/// 요청을 검증하고 전송 설정으로 변환.
+fn build_config(request: &SaveWebhookSettingsRequest) -> Result<WebhookConfig, HandlerError> {
+    if request.url.is_empty() {
+        return Err(invalid_input("Webhook URL은 필수입니다."));
+    }
+
+    let timeout_secs = request.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS);
+    if !TIMEOUT_SECS_RANGE.contains(&timeout_secs) {
+        return Err(invalid_input(format!(
+            "timeout_secs는 {}~{} 사이여야 합니다.",
+            TIMEOUT_SECS_RANGE.start(),
+            TIMEOUT_SECS_RANGE.end()
+        )));
+    }
+
+    let max_retries = request.max_retries.unwrap_or(DEFAULT_MAX_RETRIES);
+    if max_retries > MAX_RETRIES_LIMIT {
+        return Err(invalid_input(format!(
+            "max_retries는 {} 이하여야 합니다.",
+            MAX_RETRIES_LIMIT
+        )));
+    }
+
+    let mut config = WebhookConfig::new(request.url.clone());
+    config.headers = request.headers.clone();
+    config.payload_template = request.payload_template.clone().filter(|t| !t.is_empty());
+    config.hmac_secret = request.hmac_secret.clone().filter(|s| !s.is_empty());
+    config.timeout = Duration::from_secs(u64::from(timeout_secs));
+    config.max_retries = max_retries;
+    config.display_name = request.display_name.clone();
+
+    config
+        .validate()
+        .map_err(|e| invalid_input(e.to_string()))?;
+    Ok(config)
+}
+
+/// 선택 필드 암호화 (값이 없으면 `(None, None)`).
+fn encrypt_optional(
+    encryptor: &CredentialEncryptor,
+    value: Option<&str>,
+) -> Result<EncryptedField, HandlerError> {
+    let Some(value) = value else {
+        return Ok((None, None));
+    };
+    let (encrypted, nonce) = encryptor.encrypt(value).map_err(|e| {
+        error!("Webhook 설정 암호화 실패: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("ENCRYPTION_FAILED", "암호화 실패")),
+        )
+    })?;
+    Ok((Some(encrypted), Some(nonce.to_vec())))
+}
+
+/// 저장된 레코드를 복호화하여 전송 설정으로 변환.
+fn decrypt_config(
+    encryptor: &CredentialEncryptor,
+    settings: &WebhookSettingsRow,
+) -> Result<WebhookConfig, HandlerError> {
+    let decryption_failed = |e: trader_core::crypto::CryptoError| {
+        error!("Webhook 설정 복호화 실패: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DECRYPTION_FAILED", "복호화 실패")),
+        )
+    };
+
+    let url = encryptor
+        .decrypt(&settings.encrypted_url, &settings.encryption_nonce_url)
+        .map_err(decryption_failed)?;
+
+    let mut config = WebhookConfig::new(url);
+
+    if let (Some(encrypted), Some(nonce)) = (
+        &settings.encrypted_headers,
+        &settings.encryption_nonce_headers,
+    ) {
+        let json = encryptor
+            .decrypt(encrypted, nonce)
+            .map_err(decryption_failed)?;
+        config.headers = serde_json::from_str::<BTreeMap<String, String>>(&json).map_err(|e| {
+            error!("Webhook 헤더 파싱 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new("DECRYPTION_FAILED", "헤더 파싱 실패")),
+            )
+        })?;
+    }
+
+    if let (Some(encrypted), Some(nonce)) = (
+        &settings.encrypted_hmac_secret,
+        &settings.encryption_nonce_hmac,
+    ) {
+        config.hmac_secret = Some(
+            encryptor
+                .decrypt(encrypted, nonce)
+                .map_err(decryption_failed)?,
+        );
+    }
+
+    config.payload_template = settings.payload_template.clone();
+    config.timeout = Duration::from_secs(settings.timeout_secs.max(1) as u64);
+    config.max_retries = settings.max_retries.max(0) as u32;
+    config.display_name = settings.display_name.clone();
+    config.enabled = settings.is_enabled;
+
+    Ok(config)
+}
+
+// =============================================================================
+// Webhook Settings Handlers
+// =============================================================================
+
+/// Webhook 설정 조회.
+///
+/// `GET /api/v1/credentials/webhook`
+#[utoipa::path(
+    get,
+    path = "/api/v1/credentials/webhook",
+    tag = "credentials",
+    responses(
+        (status = 200, description = "Webhook 설정 조회 성공"),
+        (status = 500, description = "서버 오류")
+    )
+)]
+pub async fn get_webhook_settings(
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스 연결이 설정되지 않았습니다.",
+            )),
+        )
+    })?;
+
+    let encryptor = state.encryptor.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "ENCRYPTOR_NOT_CONFIGURED",
+                "암호화 설정이 없습니다.",
+            )),
+        )
+    })?;
+
+    let row: Option<WebhookSettingsRow> = sqlx::query_as(SELECT_WEBHOOK_SETTINGS)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            error!("Webhook 설정 조회 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new("DB_ERROR", format!("조회 실패: {}", e))),
+            )
+        })?;
+
+    match row {
+        Some(settings) => {
+            let url_masked =
+                match encryptor.decrypt(&settings.encrypted_url, &settings.encryption_nonce_url) {
+                    Ok(url) => mask_api_key(&url),
+                    Err(_) => "***복호화 실패***".to_string(),
+                };
+
+            let notification_settings: Option<NotificationSettingsConfig> = settings
+                .notification_settings
+                .and_then(|v| serde_json::from_value(v).ok());
+
+            Ok(Json(serde_json::json!({
+                "configured": true,
+                "id": settings.id,
+                "url_masked": url_masked,
+                "header_names": settings.header_names,
+                "payload_template": settings.payload_template,
+                "has_hmac_secret": settings.encrypted_hmac_secret.is_some(),
+                "timeout_secs": settings.timeout_secs,
+                "max_retries": settings.max_retries,
+                "display_name": settings.display_name,
+                "is_enabled": settings.is_enabled,
+                "notification_settings": notification_settings,
+                "last_message_at": settings.last_message_at.map(|t| t.to_rfc3339()),
+                "last_verified_at": settings.last_verified_at.map(|t| t.to_rfc3339()),
+                "created_at": settings.created_at.to_rfc3339(),
+                "updated_at": settings.updated_at.to_rfc3339()
+            })))
+        }
+        None => Ok(Json(serde_json::json!({
+            "configured": false,
+            "message": "Webhook 설정이 없습니다. 설정해주세요."
+        }))),
+    }
+}
+
+/// Webhook 설정 저장.
+///
+/// `POST /api/v1/credentials/webhook`
+#[utoipa::path(
+    post,
+    path = "/api/v1/credentials/webhook",
+    tag = "credentials",
+    request_body = SaveWebhookSettingsRequest,
+    responses(
+        (status = 201, description = "Webhook 설정 저장 성공"),
+        (status = 400, description = "잘못된 설정 (URL, 헤더, 템플릿, 타임아웃/재시도 범위)"),
+        (status = 500, description = "서버 오류")
+    )
+)]
+pub async fn save_webhook_settings(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<SaveWebhookSettingsRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
+    debug!("Webhook 설정 저장 요청");
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스 연결이 설정되지 않았습니다.",
+            )),
+        )
+    })?;
+
+    let encryptor = state.encryptor.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "ENCRYPTOR_NOT_CONFIGURED",
+                "암호화 설정이 없습니다.",
+            )),
+        )
+    })?;
+
+    // 입력 검증
+    let config = build_config(&request)?;
+
+    // 민감 정보 암호화
+    let (encrypted_url, nonce_url) = encrypt_optional(encryptor, Some(&config.url))?;
+    let headers_json = (!config.headers.is_empty())
+        .then(|| serde_json::to_string(&config.headers).unwrap_or_default());
+    let (encrypted_headers, nonce_headers) = encrypt_optional(encryptor, headers_json.as_deref())?;
+    let (encrypted_hmac, nonce_hmac) = encrypt_optional(encryptor, config.hmac_secret.as_deref())?;
+    let header_names: Vec<String> = config.headers.keys().cloned().collect();
+
+    let notification_settings = request
+        .notification_settings
+        .as_ref()
+        .and_then(|s| serde_json::to_value(s).ok());
+
+    let settings_id = Uuid::new_v4();
+
+    sqlx::query(
+        r#"
+        INSERT INTO webhook_settings
+            (id, encrypted_url, encryption_nonce_url,
+             encrypted_headers, encryption_nonce_headers,
+             encrypted_hmac_secret, encryption_nonce_hmac,
+             header_names, payload_template, timeout_secs, max_retries,
+             display_name, is_enabled, notification_settings)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, true, $13)
+        ON CONFLICT ((1))
+        DO UPDATE SET
+            encrypted_url = EXCLUDED.encrypted_url,
+            encryption_nonce_url = EXCLUDED.encryption_nonce_url,
+            encrypted_headers = EXCLUDED.encrypted_headers,
+            encryption_nonce_headers = EXCLUDED.encryption_nonce_headers,
+            encrypted_hmac_secret = EXCLUDED.encrypted_hmac_secret,
+            encryption_nonce_hmac = EXCLUDED.encryption_nonce_hmac,
+            header_names = EXCLUDED.header_names,
+            payload_template = EXCLUDED.payload_template,
+            timeout_secs = EXCLUDED.timeout_secs,
+            max_retries = EXCLUDED.max_retries,
+            display_name = EXCLUDED.display_name,
+            notification_settings = EXCLUDED.notification_settings,
+            updated_at = NOW()
+        "#,
+    )
+    .bind(settings_id)
+    .bind(&encrypted_url)
+    .bind(&nonce_url)
+    .bind(&encrypted_headers)
+    .bind(&nonce_headers)
+    .bind(&encrypted_hmac)
+    .bind(&nonce_hmac)
+    .bind(&header_names)
+    .bind(&config.payload_template)
+    .bind(config.timeout.as_secs() as i32)
+    .bind(config.max_retries as i32)
+    .bind(&config.display_name)
+    .bind(&notification_settings)
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        error!("Webhook 설정 저장 실패: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DB_ERROR", format!("저장 실패: {}", e))),
+        )
+    })?;
+
+    log_credential_access(pool, "webhook", settings_id, "create", true, None).await;
+
+    debug!("Webhook 설정 저장 완료");
+
+    Ok((
+        StatusCode::CREATED,
+        Json(serde_json::json!({
+            "success": true,
+            "message": "Webhook 설정이 저장되었습니다.",
+            "url_masked": mask_api_key(&config.url)
+        })),
+    ))
+}
+
+/// Webhook 설정 삭제.
+///
+/// `DELETE /api/v1/credentials/webhook`
+#[utoipa::path(
+    delete,
+    path = "/api/v1/credentials/webhook",
+    tag = "credentials",
+    responses(
+        (status = 200, description = "Webhook 설정 삭제 성공"),
+        (status = 404, description = "설정 없음"),
+        (status = 500, description = "서버 오류")
+    )
+)]
+pub async fn delete_webhook_settings(
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
+    debug!("Webhook 설정 삭제 요청");
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스 연결이 설정되지 않았습니다.",
+            )),
+        )
+    })?;
+
+    let row: Option<(Uuid,)> = sqlx::query_as("SELECT id FROM webhook_settings LIMIT 1")
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            error!("Webhook 설정 조회 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new("DB_ERROR", format!("조회 실패: {}", e))),
+            )
+        })?;
+
+    let result = sqlx::query("DELETE FROM webhook_settings")
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Webhook 설정 삭제 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new("DB_ERROR", format!("삭제 실패: {}", e))),
+            )
+        })?;
+
+    if result.rows_affected() == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new(
+                "NOT_FOUND",
+                "삭제할 Webhook 설정이 없습니다.",
+            )),
+        ));
+    }
+
+    if let Some((id,)) = row {
+        log_credential_access(pool, "webhook", id, "delete", true, None).await;
+    }
+
+    debug!("Webhook 설정 삭제 완료");
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": "Webhook 설정이 삭제되었습니다."
+    })))
+}
+
+/// Webhook 새 설정 테스트 (저장 전).
+///
+/// `POST /api/v1/credentials/webhook/test/new`
+///
+/// 저장하지 않고 입력된 설정으로 테스트 메시지를 전송합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/credentials/webhook/test/new",
+    tag = "credentials",
+    request_body = SaveWebhookSettingsRequest,
+    responses(
+        (status = 200, description = "테스트 메시지 전송 성공"),
+        (status = 400, description = "잘못된 설정"),
+        (status = 500, description = "전송 실패")
+    )
+)]
+pub async fn test_new_webhook_settings(
+    Json(request): Json<SaveWebhookSettingsRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
+    debug!("Webhook 새 설정 테스트 (저장 전)");
+
+    let config = build_config(&request)?;
+    let sender = WebhookSender::new(config);
+
+    match sender.send_test().await {
+        Ok(()) => {
+            debug!("Webhook 새 설정 테스트 성공");
+            Ok(Json(serde_json::json!({
+                "success": true,
+                "message": "테스트 메시지가 Webhook으로 전송되었습니다."
+            })))
+        }
+        Err(e) => {
+            let error_msg = format!("Webhook 전송 실패: {}", e);
+            error!("{}", error_msg);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new("SEND_FAILED", error_msg)),
+            ))
+        }
+    }
+}
+
+/// Webhook 설정 테스트.
+///
+/// `POST /api/v1/credentials/webhook/test`
+#[utoipa::path(
+    post,
+    path = "/api/v1/credentials/webhook/test",
+    tag = "credentials",
+    responses(
+        (status = 200, description = "테스트 메시지 전송 성공"),
+        (status = 404, description = "설정 없음"),
+        (status = 500, description = "전송 실패")
+    )
+)]
+pub async fn test_webhook_settings(
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
+    debug!("Webhook 설정 테스트");
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스 연결이 설정되지 않았습니다.",
+            )),
+        )
+    })?;
+
+    let encryptor = state.encryptor.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "ENCRYPTOR_NOT_CONFIGURED",
+                "암호화 설정이 없습니다.",
+            )),
+        )
+    })?;
+
+    let row: Option<WebhookSettingsRow> = sqlx::query_as(SELECT_WEBHOOK_SETTINGS)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            error!("Webhook 설정 조회 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new("DB_ERROR", format!("조회 실패: {}", e))),
+            )
+        })?;
+
+    let settings = row.ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NOT_FOUND", "Webhook 설정이 없습니다.")),
+        )
+    })?;
+
+    // 비활성화 상태여도 테스트는 전송
+    let mut config = decrypt_config(encryptor, &settings)?;
+    config.enabled = true;
+    let sender = WebhookSender::new(config);
+
+    match sender.send_test().await {
+        Ok(()) => {
+            let _ =
+                sqlx::query("UPDATE webhook_settings SET last_verified_at = NOW() WHERE id = $1")
+                    .bind(settings.id)
+                    .execute(pool)
+                    .await;
+
+            log_credential_access(pool, "webhook", settings.id, "verify", true, None).await;
+
+            Ok(Json(serde_json::json!({
+                "success": true,
+                "message": "테스트 메시지가 Webhook으로 전송되었습니다."
+            })))
+        }
+        Err(e) => {
+            let error_msg = format!("Webhook 전송 실패: {}", e);
+            log_credential_access(
+                pool,
+                "webhook",
+                settings.id,
+                "verify",
+                false,
+                Some(&error_msg),
+            )
+            .await;
+
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new("SEND_FAILED", error_msg)),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(url: &str) -> SaveWebhookSettingsRequest {
+        SaveWebhookSettingsRequest {
+            url: url.to_string(),
+            headers: BTreeMap::new(),
+            payload_template: None,
+            hmac_secret: None,
+            timeout_secs: None,
+            max_retries: None,
+            display_name: None,
+            notification_settings: None,
+        }
+    }
+
+    #[test]
+    fn test_build_config_applies_defaults() {
+        let mut req = request("https://example.com/hook");
+        req.hmac_secret = Some(String::new());
+        req.headers
+            .insert("Authorization".to_string(), "Bearer x".to_string());
+
+        let config = build_config(&req).unwrap();
+        assert_eq!(config.timeout, Duration::from_secs(10));
+        assert_eq!(config.max_retries, 3);
+        assert!(config.hmac_secret.is_none());
+        assert_eq!(config.headers.len(), 1);
+    }
+
+    #[test]
+    fn test_build_config_rejects_invalid_input() {
+        assert!(build_config(&request("")).is_err());
+        assert!(build_config(&request("example.com/hook")).is_err());
+
+        let mut req = request("https://example.com/hook");
+        req.timeout_secs = Some(0);
+        assert!(build_config(&req).is_err());
+
+        let mut req = request("https://example.com/hook");
+        req.max_retries = Some(11);
+        assert!(build_config(&req).is_err());
+
+        let mut req = request("https://example.com/hook");
+        req.payload_template = Some("{\"text\": {{title}}}".to_string());
+        let (status, _) = build_config(&req).unwrap_err();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -11,6 +11,7 @@
 //! - `/api/v1/positions` - 포지션 관리
 //! - `/api/v1/notifications` - 알림 설정
 //! - `/api/v1/backtest` - 백테스트 실행
+//! - `/api/v1/backtests` - 비동기 백테스트 작업 (제출/진행률/취소)
 //! - `/api/v1/analytics` - 포트폴리오 분석
 //! - `/api/v1/patterns` - 패턴 인식 (캔들스틱/차트)
 //! - `/api/v1/portfolio` - 포트폴리오 요약/잔고/보유종목
@@ -24,6 +25,8 @@
 //! - `/api/v1/ranking` - GlobalScore 기반 종목 랭킹
 //! - `/api/v1/watchlist` - 관심종목 관리
 //! - `/api/v1/alerts` - 알림 히스토리
+//! - `/api/v1/performance-alerts` - 전략 성과 알림 규칙 (목표 도달/손실 경보)
+//! - `/api/v1/system` - 런타임 시스템 설정 (Admin 전용)
 
 pub mod alert_history;
 pub mod analytics;
@@ -42,6 +45,7 @@ pub mod notifications;
 pub mod orders;
 pub mod paper_trading;
 pub mod patterns;
+pub mod performance_alerts;
 pub mod portfolio;
 pub mod positions;
 pub mod ranking;
@@ -52,6 +56,7 @@ pub mod signal_alerts;
 pub mod signals;
 pub mod simulation;
 pub mod strategies;
+pub mod system;
 pub mod watchlist;
 
 use std::sync::Arc;
@@ -63,8 +68,8 @@ pub use analytics::{
 };
 use axum::Router;
 pub use backtest::{
-    backtest_router, BacktestMultiRunRequest, BacktestMultiRunResponse, BacktestRunRequest,
-    BacktestRunResponse, BacktestStrategiesResponse,
+    backtest_jobs_router, backtest_router, BacktestMultiRunRequest, BacktestMultiRunResponse,
+    BacktestRunRequest, BacktestRunResponse, BacktestStrategiesResponse,
 };
 pub use backtest_results::{
     backtest_results_router, BacktestResultResponse, ListResultsResponse, SaveBacktestResultRequest,
@@ -117,6 +122,10 @@ pub use signals::{
 };
 pub use simulation::{simulation_router, SimulationStartRequest, SimulationStatusResponse};
 pub use strategies::{strategies_router, ApiError, StrategiesListResponse, StrategyDetailResponse};
+pub use system::{
+    system_router, SettingHistoryResponse, SystemSettingsResponse, UpdateSystemSettingsRequest,
+    UpdateSystemSettingsResponse,
+};
 pub use watchlist::{
     watchlist_router, AddItemsRequest, AddItemsResponse, WatchlistDetailResponse,
     WatchlistListResponse,
@@ -140,6 +149,7 @@ pub fn create_api_router() -> Router<Arc<AppState>> {
         .nest("/api/v1/positions", positions_router())
         .nest("/api/v1/backtest", backtest_router())
         .nest("/api/v1/backtest/results", backtest_results_router())
+        .nest("/api/v1/backtests", backtest_jobs_router())
         .nest("/api/v1/simulation", simulation_router())
         .nest("/api/v1/analytics", analytics_router())
         .nest("/api/v1/patterns", patterns_router())
@@ -154,11 +164,16 @@ pub fn create_api_router() -> Router<Arc<AppState>> {
         .nest("/api/v1/sectors", sectors_router())
         .nest("/api/v1/signals", signals_router())
         .nest("/api/v1/signal-alerts", signal_alerts::signal_alerts_router())
+        .nest(
+            "/api/v1/performance-alerts",
+            performance_alerts::performance_alerts_router(),
+        )
         .nest("/api/v1/reality-check", reality_check_router())
         .nest("/api/v1/monitoring", monitoring_router())
         .nest("/api/v1/ranking", ranking_router())
         .nest("/api/v1/watchlist", watchlist_router())
         .nest("/api/v1/alerts", alert_history_router())
+        .nest("/api/v1/system", system_router())
         .nest("/api/v1/paper-trading", paper_trading::router());
 
     // Feature: notifications - 텔레그램/이메일 알림
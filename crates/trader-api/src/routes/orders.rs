@@ -25,7 +25,6 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::{
-    metrics::record_order,
     routes::strategies::ApiError,
     state::AppState,
     websocket::{OrderUpdateData, ServerMessage},
@@ -201,7 +200,7 @@ pub async fn create_order(
     State(state): State<Arc<AppState>>,
     Json(request): Json<CreateOrderRequest>,
 ) -> Result<Json<CreateOrderResponse>, (StatusCode, Json<ApiError>)> {
-    use trader_core::{MarketType, OrderRequest, Symbol, TimeInForce};
+    use trader_core::{MarketType, OrderRequest, OrderSession, Symbol, TimeInForce};
 
     // 심볼 파싱 (기본적으로 Crypto 시장으로 가정)
     // 심볼 형식: "BTC/USDT" 또는 "AAPL/USD"
@@ -240,6 +239,8 @@ pub async fn create_order(
         time_in_force: TimeInForce::GTC,
         client_order_id: None,
         strategy_id: None,
+        reduce_only: false,
+        session: OrderSession::Regular,
     };
 
     // Order 생성 (Order::from_request 사용)
@@ -263,12 +264,10 @@ pub async fn create_order(
         }
     }
 
-    // 메트릭 기록
     let side_str = match request.side {
         Side::Buy => "buy",
         Side::Sell => "sell",
     };
-    record_order(&request.symbol, side_str, "manual");
 
     // WebSocket 브로드캐스트: 주문 생성 알림
     state.broadcast(ServerMessage::OrderUpdate(OrderUpdateData {
@@ -450,12 +449,10 @@ pub async fn cancel_order(
 
     match cancel_result {
         Ok(()) => {
-            // 주문 취소 메트릭 기록
             let side_str = match order_info.side {
                 Side::Buy => "buy",
                 Side::Sell => "sell",
             };
-            record_order(&order_info.ticker, "cancelled", "default");
 
             // WebSocket 브로드캐스트: 주문 취소 알림
             state.broadcast(ServerMessage::OrderUpdate(OrderUpdateData {
@@ -902,6 +902,17 @@ pub struct MockStreamingConfigDto {
     #[serde(rename = "orderbookBaseVolume")]
     #[ts(optional, type = "number")]
     pub orderbook_base_volume: Option<f64>,
+    /// 변동성 레짐 프리셋 (RandomWalk 전용, "stress_cycle")
+    #[serde(rename = "regimePreset")]
+    #[ts(optional)]
+    pub regime_preset: Option<String>,
+    /// 난수 시드 (RandomWalk 전용, 같은 시드면 같은 가격 경로)
+    #[ts(optional, type = "number")]
+    pub seed: Option<u64>,
+    /// 시장가 잔량 최대 대기 틱 수 (기본 60, 0이면 무제한)
+    #[serde(rename = "maxWaitTicks")]
+    #[ts(optional, type = "number")]
+    pub max_wait_ticks: Option<u64>,
 }
 
 /// Paper Trading 시작 요청.
@@ -1298,7 +1309,9 @@ pub async fn start_paper_trading(
     if !symbols.is_empty() && !mock_provider.is_streaming() {
         if let Some(ref config_dto) = request.streaming_config {
             // 확장 스트리밍 모드
-            use trader_exchange::provider::{MockPriceMode, MockStreamingConfig};
+            use trader_exchange::provider::{
+                MockPriceMode, MockStreamingConfig, RegimeSchedule, DEFAULT_MAX_WAIT_TICKS,
+            };
 
             let mode = match config_dto.mode.as_deref() {
                 Some("random_walk") => MockPriceMode::RandomWalk,
@@ -1318,6 +1331,12 @@ pub async fn start_paper_trading(
                 )
                 .unwrap_or(Decimal::from(100)),
                 replay_speed: config_dto.replay_speed.unwrap_or(1.0),
+                regime_schedule: config_dto
+                    .regime_preset
+                    .as_deref()
+                    .and_then(RegimeSchedule::preset),
+                seed: config_dto.seed,
+                max_wait_ticks: config_dto.max_wait_ticks.unwrap_or(DEFAULT_MAX_WAIT_TICKS),
             };
 
             if let Err(e) = mock_provider
@@ -1343,6 +1362,7 @@ pub async fn start_paper_trading(
                 spread_multiplier: Decimal::ONE,
                 orderbook_base_volume: Decimal::from(100),
                 replay_speed: 1.0,
+                ..Default::default()
             };
 
             if let Err(e) = mock_provider
@@ -1362,20 +1382,19 @@ pub async fn start_paper_trading(
 
     // MarketStream 표준 파이프라인 연결 (Mock → Aggregator → WebSocket)
     {
-        use crate::services::get_or_create_market_stream;
-
-        match get_or_create_market_stream(
-            &state.market_streams,
-            "mock",
-            Some(pool),
-            state.encryptor.as_deref(),
-            &state.kis_oauth_cache,
-            &state.mock_providers,
-            credential_id,
-            state.subscriptions.as_ref(),
-        )
-        .await
-        {
+        use crate::services::{get_or_create_market_stream, MarketStreamContext};
+
+        let ctx = MarketStreamContext {
+            market_streams: &state.market_streams,
+            pool: Some(pool),
+            encryptor: state.encryptor.as_deref(),
+            kis_oauth_cache: &state.kis_oauth_cache,
+            mock_providers: &state.mock_providers,
+            subscriptions: state.subscriptions.as_ref(),
+            // Mock 시뮬레이션 가격이 실거래 시세 캐시를 덮어쓰지 않도록 연결하지 않음
+            quote_cache: None,
+        };
+        match get_or_create_market_stream(&ctx, "mock", credential_id).await {
             Ok(handle) => {
                 // 모든 활성 심볼을 MarketStream에도 구독
                 for symbol in &symbols {
@@ -0,0 +1,261 @@
+//! 전략 성과 알림 API 라우트.
+//!
+//! 전략별 성과 알림 규칙(목표 도달/손실 경보) CRUD, 발동 상태 리셋,
+//! 발동 이력 조회 엔드포인트를 제공합니다.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{delete, get, post, put},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiErrorResponse, ApiResult},
+    repository::{
+        CreatePerformanceAlertRequest, PerformanceAlertHistory, PerformanceAlertRepository,
+        PerformanceAlertRule, UpdatePerformanceAlertRequest,
+    },
+    AppState,
+};
+
+// ==================== Request/Response 타입 ====================
+
+/// 성과 알림 규칙 목록 조회 쿼리.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ListPerformanceAlertsQuery {
+    /// 전략 ID 필터
+    pub strategy_id: Option<String>,
+    /// 활성화된 규칙만 조회 (기본 false)
+    #[serde(default)]
+    pub enabled_only: bool,
+}
+
+/// 성과 알림 규칙 목록 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ListPerformanceAlertsResponse {
+    /// 총 규칙 수
+    pub total: usize,
+    /// 규칙 목록
+    pub rules: Vec<PerformanceAlertRule>,
+}
+
+/// 발동 이력 조회 쿼리.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PerformanceAlertHistoryQuery {
+    /// 전략 ID 필터
+    pub strategy_id: Option<String>,
+    /// 규칙 ID 필터
+    pub rule_id: Option<Uuid>,
+    /// 최대 조회 개수 (기본 100, 최대 1000)
+    #[serde(default = "default_history_limit")]
+    pub limit: i64,
+}
+
+fn default_history_limit() -> i64 {
+    100
+}
+
+/// 발동 이력 응답.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PerformanceAlertHistoryResponse {
+    /// 조회된 이력 수
+    pub total: usize,
+    /// 발동 이력 (최신순)
+    pub history: Vec<PerformanceAlertHistory>,
+}
+
+fn repository(state: &AppState) -> ApiResult<PerformanceAlertRepository> {
+    let db_pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiErrorResponse::new(
+                "DATABASE_ERROR",
+                "Database not available",
+            )),
+        )
+    })?;
+
+    Ok(PerformanceAlertRepository::new(db_pool.clone()))
+}
+
+// ==================== API 핸들러 ====================
+
+/// 성과 알림 규칙 생성.
+#[utoipa::path(
+    post,
+    path = "/api/v1/performance-alerts",
+    tag = "performance-alerts",
+    request_body = CreatePerformanceAlertRequest,
+    responses(
+        (status = 200, description = "성과 알림 규칙 생성 성공", body = PerformanceAlertRule),
+        (status = 409, description = "같은 전략에 동일한 이름의 규칙 존재", body = ApiErrorResponse),
+        (status = 500, description = "서버 에러", body = ApiErrorResponse)
+    )
+)]
+pub async fn create_performance_alert(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<CreatePerformanceAlertRequest>,
+) -> ApiResult<Json<PerformanceAlertRule>> {
+    let rule = repository(&state)?.create(req).await?;
+    Ok(Json(rule))
+}
+
+/// 성과 알림 규칙 목록 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/performance-alerts",
+    tag = "performance-alerts",
+    params(ListPerformanceAlertsQuery),
+    responses(
+        (status = 200, description = "성과 알림 규칙 목록", body = ListPerformanceAlertsResponse),
+        (status = 500, description = "서버 에러", body = ApiErrorResponse)
+    )
+)]
+pub async fn list_performance_alerts(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ListPerformanceAlertsQuery>,
+) -> ApiResult<Json<ListPerformanceAlertsResponse>> {
+    let rules = repository(&state)?
+        .list(query.strategy_id.as_deref(), query.enabled_only)
+        .await?;
+
+    Ok(Json(ListPerformanceAlertsResponse {
+        total: rules.len(),
+        rules,
+    }))
+}
+
+/// ID로 성과 알림 규칙 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/performance-alerts/{id}",
+    tag = "performance-alerts",
+    params(("id" = Uuid, Path, description = "성과 알림 규칙 ID")),
+    responses(
+        (status = 200, description = "성과 알림 규칙 상세", body = PerformanceAlertRule),
+        (status = 404, description = "규칙 없음", body = ApiErrorResponse),
+        (status = 500, description = "서버 에러", body = ApiErrorResponse)
+    )
+)]
+pub async fn get_performance_alert(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<PerformanceAlertRule>> {
+    let rule = repository(&state)?.find_by_id(id).await?;
+    Ok(Json(rule))
+}
+
+/// 성과 알림 규칙 수정.
+///
+/// 지표, 비교 방향, 임계치가 바뀌면 발동 상태가 리셋됩니다.
+#[utoipa::path(
+    put,
+    path = "/api/v1/performance-alerts/{id}",
+    tag = "performance-alerts",
+    params(("id" = Uuid, Path, description = "성과 알림 규칙 ID")),
+    request_body = UpdatePerformanceAlertRequest,
+    responses(
+        (status = 200, description = "성과 알림 규칙 수정 성공", body = PerformanceAlertRule),
+        (status = 404, description = "규칙 없음", body = ApiErrorResponse),
+        (status = 500, description = "서버 에러", body = ApiErrorResponse)
+    )
+)]
+pub async fn update_performance_alert(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdatePerformanceAlertRequest>,
+) -> ApiResult<Json<PerformanceAlertRule>> {
+    let rule = repository(&state)?.update(id, req).await?;
+    Ok(Json(rule))
+}
+
+/// 성과 알림 규칙 삭제.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/performance-alerts/{id}",
+    tag = "performance-alerts",
+    params(("id" = Uuid, Path, description = "성과 알림 규칙 ID")),
+    responses(
+        (status = 204, description = "삭제 성공"),
+        (status = 404, description = "규칙 없음", body = ApiErrorResponse),
+        (status = 500, description = "서버 에러", body = ApiErrorResponse)
+    )
+)]
+pub async fn delete_performance_alert(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    repository(&state)?.delete(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 성과 알림 규칙 발동 상태 리셋.
+///
+/// 발동한 규칙은 리셋 전까지 다시 평가되지 않습니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/performance-alerts/{id}/reset",
+    tag = "performance-alerts",
+    params(("id" = Uuid, Path, description = "성과 알림 규칙 ID")),
+    responses(
+        (status = 200, description = "리셋 성공", body = PerformanceAlertRule),
+        (status = 404, description = "규칙 없음", body = ApiErrorResponse),
+        (status = 500, description = "서버 에러", body = ApiErrorResponse)
+    )
+)]
+pub async fn reset_performance_alert(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<PerformanceAlertRule>> {
+    let rule = repository(&state)?.reset(id).await?;
+    Ok(Json(rule))
+}
+
+/// 성과 알림 발동 이력 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/performance-alerts/history",
+    tag = "performance-alerts",
+    params(PerformanceAlertHistoryQuery),
+    responses(
+        (status = 200, description = "발동 이력", body = PerformanceAlertHistoryResponse),
+        (status = 500, description = "서버 에러", body = ApiErrorResponse)
+    )
+)]
+pub async fn list_performance_alert_history(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<PerformanceAlertHistoryQuery>,
+) -> ApiResult<Json<PerformanceAlertHistoryResponse>> {
+    let history = repository(&state)?
+        .list_history(
+            query.strategy_id.as_deref(),
+            query.rule_id,
+            query.limit.clamp(1, 1000),
+        )
+        .await?;
+
+    Ok(Json(PerformanceAlertHistoryResponse {
+        total: history.len(),
+        history,
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 전략 성과 알림 API 라우터.
+pub fn performance_alerts_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(create_performance_alert))
+        .route("/", get(list_performance_alerts))
+        .route("/history", get(list_performance_alert_history))
+        .route("/{:id}", get(get_performance_alert))
+        .route("/{:id}", put(update_performance_alert))
+        .route("/{:id}", delete(delete_performance_alert))
+        .route("/{:id}/reset", post(reset_performance_alert))
+}
@@ -6,6 +6,7 @@
 //!
 //! - `GET /api/v1/positions` - 열린 포지션 목록 조회
 //! - `GET /api/v1/positions/summary` - 포지션 요약 통계
+//! - `GET /api/v1/positions/reconciliation` - 거래소 리컨실리에이션 설정 및 마지막 결과
 //! - `GET /api/v1/positions/{symbol}` - 특정 심볼 포지션 조회
 
 use std::sync::Arc;
@@ -20,6 +21,7 @@ use axum::{
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use trader_core::{Position, Side};
+use trader_execution::{ReconciliationMode, ReconciliationReport};
 use utoipa::ToSchema;
 
 use crate::{routes::strategies::ApiError, state::AppState};
@@ -143,6 +145,21 @@ impl PositionSummaryResponse {
     }
 }
 
+/// 포지션 리컨실리에이션 상태 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReconciliationStatusResponse {
+    /// 백그라운드 리컨실리에이션 실행 여부 (거래소 연결 시)
+    pub enabled: bool,
+    /// 불일치 처리 방식 (`report_only` / `auto_correct`)
+    #[schema(value_type = String)]
+    pub mode: ReconciliationMode,
+    /// 실행 주기 (초)
+    pub interval_secs: u64,
+    /// 마지막 실행 결과 (아직 실행 전이면 null)
+    #[schema(value_type = Object)]
+    pub last_run: Option<ReconciliationReport>,
+}
+
 // ==================== handler ====================
 
 /// 열린 포지션 목록 조회.
@@ -202,6 +219,30 @@ pub async fn get_positions_summary(State(state): State<Arc<AppState>>) -> impl I
     Json(PositionSummaryResponse::from_positions(&positions))
 }
 
+/// 포지션 리컨실리에이션 상태 조회.
+///
+/// 거래소 포지션과 내부 추적 상태의 마지막 비교 결과를 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/positions/reconciliation",
+    tag = "positions",
+    responses(
+        (status = 200, description = "리컨실리에이션 상태 조회 성공", body = ReconciliationStatusResponse)
+    )
+)]
+pub async fn get_reconciliation_status(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let config = state.runtime_settings.reconciliation_config();
+    let executor = state.executor.read().await;
+    let tracker = executor.position_tracker().read().await;
+
+    Json(ReconciliationStatusResponse {
+        enabled: state.has_exchange_provider(),
+        mode: config.mode,
+        interval_secs: config.interval_secs,
+        last_run: tracker.last_reconciliation().cloned(),
+    })
+}
+
 /// 특정 심볼 포지션 조회.
 #[utoipa::path(
     get,
@@ -245,6 +286,7 @@ pub fn positions_router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/", get(list_positions))
         .route("/summary", get(get_positions_summary))
+        .route("/reconciliation", get(get_reconciliation_status))
         .route("/{symbol}", get(get_position))
 }
 
@@ -351,6 +393,38 @@ mod tests {
         assert_eq!(summary.short_count, 0);
     }
 
+    #[tokio::test]
+    async fn test_get_reconciliation_status_before_first_run() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/positions/reconciliation", get(get_reconciliation_status))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/positions/reconciliation")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let status: ReconciliationStatusResponse = serde_json::from_slice(&body).unwrap();
+
+        assert!(!status.enabled);
+        assert_eq!(status.mode, ReconciliationMode::ReportOnly);
+        assert_eq!(status.interval_secs, 300);
+        assert!(status.last_run.is_none());
+    }
+
     #[test]
     fn test_position_summary_calculation() {
         use rust_decimal_macros::dec;
@@ -8,6 +8,7 @@
 //! - `GET /api/v1/ranking/top` - 상위 랭킹 조회
 //! - `GET /api/v1/ranking/7factor/{ticker}` - 7Factor 데이터 조회
 //! - `POST /api/v1/ranking/7factor/batch` - 7Factor 일괄 조회
+//! - `GET /api/v1/ranking/breakdown/{ticker}` - GlobalScore 구성요소 분해 조회
 
 use std::sync::Arc;
 
@@ -20,6 +21,7 @@ use axum::{
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
+use trader_core::domain::GlobalScoreBreakdown;
 use ts_rs::TS;
 use utoipa::{IntoParams, ToSchema};
 
@@ -138,6 +140,22 @@ pub struct SevenFactorBatchResponse {
     pub total: usize,
 }
 
+/// GlobalScore 구성요소 분해 응답
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "ranking/")]
+pub struct ScoreBreakdownResponse {
+    /// 결측 구성요소를 0점으로 채운 분해 (GlobalScore와 동일한 점수)
+    #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
+    pub breakdown: GlobalScoreBreakdown,
+    /// 결측 구성요소를 제외하고 가중치를 재정규화한 분해
+    #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
+    pub reweighted: GlobalScoreBreakdown,
+    /// 결측 구성요소 이름 목록
+    pub missing_components: Vec<String>,
+}
+
 // ================================================================================================
 // Handlers
 // ================================================================================================
@@ -408,6 +426,64 @@ pub struct ScoreHistoryResponse {
     pub total: usize,
 }
 
+/// GET /api/v1/ranking/breakdown/{ticker} - GlobalScore 구성요소 분해 조회
+#[utoipa::path(
+    get,
+    path = "/api/v1/ranking/breakdown/{ticker}",
+    tag = "ranking",
+    params(
+        ("ticker" = String, Path, description = "종목 티커")
+    ),
+    responses(
+        (status = 200, description = "GlobalScore 구성요소 분해", body = ScoreBreakdownResponse),
+        (status = 404, description = "종목 없음 또는 캔들 부족"),
+        (status = 500, description = "서버 에러")
+    )
+)]
+pub async fn get_score_breakdown(
+    State(state): State<Arc<AppState>>,
+    Path(ticker): Path<String>,
+) -> Result<Json<ScoreBreakdownResponse>, (StatusCode, String)> {
+    debug!("GlobalScore 분해 조회: {}", ticker);
+
+    let analytics_provider = state.analytics_provider.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Analytics provider not available".to_string(),
+        )
+    })?;
+
+    let mut breakdowns = analytics_provider
+        .fetch_global_score_breakdowns(&[ticker.as_str()])
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("GlobalScore 분해 실패: {}", e),
+            )
+        })?;
+
+    let breakdown = breakdowns.remove(&ticker).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            format!("종목을 찾을 수 없습니다: {}", ticker),
+        )
+    })?;
+
+    let missing_components = breakdown
+        .missing_components()
+        .into_iter()
+        .map(String::from)
+        .collect();
+    let reweighted = breakdown.reweighted();
+
+    Ok(Json(ScoreBreakdownResponse {
+        breakdown,
+        reweighted,
+        missing_components,
+    }))
+}
+
 // ================================================================================================
 // Score History Handlers
 // ================================================================================================
@@ -474,5 +550,6 @@ pub fn ranking_router() -> Router<Arc<AppState>> {
         .route("/top", get(get_top_ranked))
         .route("/7factor/{ticker}", get(get_seven_factor))
         .route("/7factor/batch", post(get_seven_factor_batch))
+        .route("/breakdown/{ticker}", get(get_score_breakdown))
         .route("/history/{ticker}", get(get_score_history))
 }
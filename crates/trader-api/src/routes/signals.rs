@@ -470,7 +470,7 @@ pub async fn get_backtest_signals(
 /// POST /api/v1/signals
 ///
 /// 새 시그널을 생성하고 필터 조건을 만족하면 텔레그램 알림을 전송합니다.
-/// 알림 조건: 강도 >= 0.7, Entry/Exit/Alert 유형
+/// 알림 조건: 강도 >= `alert.min_signal_strength` 시스템 설정 (기본 0.7), Entry/Exit/Alert 유형
 #[utoipa::path(
     post,
     path = "/api/v1/signals",
@@ -567,7 +567,10 @@ pub async fn create_signal(
     #[allow(unused_assignments)]
     let mut notification_result: Option<String> = None;
 
-    if req.notify && req.strength >= 0.7 {
+    // 런타임 설정의 알림 최소 강도 (기본 0.7)
+    let min_strength = state.runtime_settings.alert_min_signal_strength();
+
+    if req.notify && req.strength >= min_strength {
         if let Some(notification_manager) = &state.notification_manager {
             // 방향 문자열
             let side_str = side.map(|s| match s {
@@ -614,8 +617,9 @@ pub async fn create_signal(
         notification_result = Some("알림 비활성화 (notify=false)".to_string());
     } else {
         notification_result = Some(format!(
-            "알림 필터 미충족 (강도 {:.1}% < 70%)",
-            req.strength * 100.0
+            "알림 필터 미충족 (강도 {:.1}% < {:.0}%)",
+            req.strength * 100.0,
+            min_strength * 100.0
         ));
     }
 
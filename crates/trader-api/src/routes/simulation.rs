@@ -200,6 +200,9 @@ impl Default for SimulationEngine {
             auto_take_profit: false,
             stop_loss_pct: dec!(0.05),
             take_profit_pct: dec!(0.10),
+            trailing_stop_pct: None,
+            sizing_method: Default::default(),
+            conflict_policy: None,
         };
 
         Self {
@@ -239,6 +242,9 @@ impl SimulationEngine {
             auto_take_profit: false,
             stop_loss_pct: dec!(0.05),
             take_profit_pct: dec!(0.10),
+            trailing_stop_pct: None,
+            sizing_method: Default::default(),
+            conflict_policy: None,
         };
 
         Self {
@@ -606,6 +612,9 @@ impl SimulationEngine {
             auto_take_profit: take_profit_enabled,
             stop_loss_pct,
             take_profit_pct,
+            trailing_stop_pct: None,
+            sizing_method: Default::default(),
+            conflict_policy: None,
         };
         self.executor = SimulatedExecutor::new(config, initial_balance);
 
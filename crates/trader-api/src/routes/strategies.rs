@@ -11,11 +11,13 @@
 //! - `POST /api/v1/strategies/{id}/start` - 전략 시작
 //! - `POST /api/v1/strategies/{id}/stop` - 전략 중지
 //! - `PUT /api/v1/strategies/{id}/config` - 전략 설정 변경
+//! - `GET /api/v1/strategies/{id}/export` - 전략 설정 내보내기 (공유용 JSON)
+//! - `POST /api/v1/strategies/import` - 내보낸 전략 설정 가져오기
 
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     routing::{get, post, put},
@@ -33,7 +35,11 @@ use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
-    repository::{strategies::CreateStrategyInput, StrategyRepository},
+    auth::{Claims, OptionalJwtAuth},
+    repository::{
+        strategies::{CreateStrategyInput, StrategyImportProvenance},
+        BacktestResultsRepository, StrategyRepository,
+    },
     state::AppState,
     websocket::{ServerMessage, StrategyUpdateData},
 };
@@ -228,6 +234,183 @@ pub struct CloneStrategyResponse {
     pub message: String,
 }
 
+/// 현재 전략 내보내기 문서 포맷 버전.
+///
+/// 문서 구조가 호환되지 않게 바뀌면 증가시킵니다.
+/// 가져오기는 이 버전 이하의 문서만 허용합니다.
+pub const STRATEGY_EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// 내보내기 시 제외하는 민감 설정 키 (부분 일치, 소문자 비교).
+const SENSITIVE_CONFIG_KEYS: &[&str] = &[
+    "api_key",
+    "secret",
+    "password",
+    "passphrase",
+    "token",
+    "private_key",
+    "credential",
+    "account_no",
+    "account_number",
+];
+
+/// 전략 내보내기 쿼리.
+#[derive(Debug, Default, Deserialize, ToSchema, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct ExportStrategyQuery {
+    /// 최근 백테스트 성과 요약 포함 여부
+    #[serde(default, rename = "includeBacktest")]
+    pub include_backtest: bool,
+}
+
+/// 내보내기 원본 정보 (가져오기 시 출처 추적용).
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct StrategyExportSource {
+    /// 원본 전략 ID
+    pub strategy_id: String,
+    /// 원본 전략 이름
+    pub name: String,
+    /// 원본 전략 버전
+    #[serde(default)]
+    #[ts(optional)]
+    pub strategy_version: Option<String>,
+}
+
+/// 내보낸 전략 설정 본문.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct ExportedStrategyConfig {
+    /// 전략 이름
+    pub name: String,
+    /// 전략 설명
+    #[serde(default)]
+    #[ts(optional)]
+    pub description: Option<String>,
+    /// 전략 타입 (예: "grid_trading", "rsi")
+    pub strategy_type: String,
+    /// 거래 심볼 목록
+    #[serde(default)]
+    pub symbols: Vec<String>,
+    /// 대상 시장 (KR/US/CRYPTO)
+    pub market: String,
+    /// 타임프레임
+    pub timeframe: String,
+    /// 전략 파라미터 (민감 정보 제외)
+    #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
+    pub config: Value,
+    /// 리스크 설정
+    #[serde(default)]
+    #[ts(type = "Record<string, unknown> | null")]
+    #[schema(value_type = Option<Object>)]
+    pub risk_config: Option<Value>,
+    /// 할당 자본 (Decimal 문자열)
+    #[serde(default)]
+    #[ts(optional)]
+    pub allocated_capital: Option<String>,
+    /// 리스크 프로필
+    #[serde(default)]
+    #[ts(optional)]
+    pub risk_profile: Option<String>,
+    /// 다중 타임프레임 설정
+    #[serde(default)]
+    #[ts(type = "Record<string, unknown> | null")]
+    #[schema(value_type = Option<Object>)]
+    pub multi_timeframe_config: Option<Value>,
+}
+
+/// 내보내기 문서에 포함되는 백테스트 성과 요약.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct ExportedBacktestSummary {
+    /// 백테스트 결과 ID
+    pub backtest_id: String,
+    /// 백테스트 심볼
+    pub symbol: String,
+    /// 시작 날짜
+    pub start_date: String,
+    /// 종료 날짜
+    pub end_date: String,
+    /// 초기 자본 (Decimal 문자열)
+    pub initial_capital: String,
+    /// 성과 지표
+    #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
+    pub metrics: Value,
+    /// 백테스트 실행 시각 (RFC3339)
+    pub created_at: String,
+}
+
+/// 전략 내보내기 문서.
+///
+/// `GET /{id}/export` 응답이자 `POST /import` 요청 본문으로 그대로 사용됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct StrategyExportDocument {
+    /// 문서 포맷 버전
+    pub format_version: u32,
+    /// 내보내기 시각 (RFC3339)
+    pub exported_at: String,
+    /// 원본 전략 정보
+    pub source: StrategyExportSource,
+    /// 전략 설정
+    pub strategy: ExportedStrategyConfig,
+    /// 최근 백테스트 성과 요약 (옵션)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub backtest_summary: Option<ExportedBacktestSummary>,
+}
+
+/// 가져오기 시 이름 충돌 처리 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "strategies/")]
+pub enum NameConflictPolicy {
+    /// 번호를 붙여 자동으로 이름 변경 (기본값)
+    #[default]
+    Rename,
+    /// 충돌 시 가져오기 거부
+    Reject,
+}
+
+/// 전략 가져오기 요청.
+#[derive(Debug, Deserialize, Validate, ToSchema, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct ImportStrategyRequest {
+    /// 내보내기 문서
+    pub document: StrategyExportDocument,
+    /// 새 전략 이름 (옵션, 없으면 문서의 이름 사용)
+    #[serde(default)]
+    #[validate(length(min = 1, max = 100, message = "전략 이름은 1-100자여야 합니다"))]
+    #[ts(optional)]
+    pub name: Option<String>,
+    /// 이름 충돌 처리 방식
+    #[serde(default)]
+    pub on_name_conflict: NameConflictPolicy,
+    /// 연결할 거래소 계정 ID (내보내기에서 제외되므로 필요 시 직접 지정)
+    #[serde(default, rename = "credentialId")]
+    #[ts(optional)]
+    pub credential_id: Option<String>,
+}
+
+/// 전략 가져오기 응답.
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct ImportStrategyResponse {
+    /// 성공 여부
+    pub success: bool,
+    /// 생성된 전략 ID
+    pub strategy_id: String,
+    /// 최종 전략 이름
+    pub name: String,
+    /// 이름 충돌로 이름이 변경되었는지 여부
+    pub renamed: bool,
+    /// 가져오기 경고 (민감 정보 제거, 이름 변경 등)
+    pub warnings: Vec<String>,
+    /// 메시지
+    pub message: String,
+}
+
 /// 전략 생성 요청.
 #[derive(Debug, Deserialize, Validate, ToSchema, TS)]
 #[ts(export, export_to = "strategies/")]
@@ -379,6 +562,36 @@ fn create_strategy_instance(strategy_type: &str) -> Result<Box<dyn Strategy>, St
     trader_strategy::StrategyRegistry::create_instance(strategy_type)
 }
 
+/// 전략 설정 검증 (UI 저장 경로).
+///
+/// 필수값 누락·타입 불일치는 400 에러로 거부하고,
+/// 알 수 없는 필드는 경고 로그만 남기고 허용합니다.
+fn validate_strategy_config(
+    strategy_type: &str,
+    config: &Value,
+) -> Result<(), (StatusCode, Json<ApiError>)> {
+    let report = trader_strategy::StrategyRegistry::validate_config(strategy_type, config)
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new("INVALID_STRATEGY_TYPE", e)),
+            )
+        })?;
+
+    for warning in report.warnings() {
+        tracing::warn!(strategy_type = %strategy_type, "{}", warning);
+    }
+
+    if report.has_errors() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new("INVALID_CONFIG", report.error_summary())),
+        ));
+    }
+
+    Ok(())
+}
+
 /// 전략 타입에서 기본 이름 가져오기.
 ///
 /// StrategyRegistry를 통해 등록된 전략의 이름을 조회합니다.
@@ -406,6 +619,46 @@ fn get_strategy_default_symbols(strategy_type: &str) -> Vec<String> {
         .unwrap_or_default()
 }
 
+// ==================== 전략 내보내기/가져오기 ====================
+
+/// 설정에서 민감 정보 키를 재귀적으로 제거하고, 제거된 경로를 반환.
+fn strip_sensitive_fields(value: &mut Value, path: &str, removed: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            let sensitive: Vec<String> = map
+                .keys()
+                .filter(|key| {
+                    let lower = key.to_lowercase();
+                    SENSITIVE_CONFIG_KEYS.iter().any(|s| lower.contains(s))
+                })
+                .cloned()
+                .collect();
+            for key in sensitive {
+                map.remove(&key);
+                removed.push(format!("{}{}", path, key));
+            }
+            for (key, child) in map.iter_mut() {
+                strip_sensitive_fields(child, &format!("{}{}.", path, key), removed);
+            }
+        }
+        Value::Array(items) => {
+            for (i, child) in items.iter_mut().enumerate() {
+                strip_sensitive_fields(child, &format!("{}{}.", path, i), removed);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 사용 중인 이름과 겹치지 않도록 " (2)", " (3)" 형식의 접미사를 붙인 이름을 생성.
+fn numbered_name(base: &str, attempt: u32) -> String {
+    if attempt <= 1 {
+        base.to_string()
+    } else {
+        format!("{} ({})", base, attempt)
+    }
+}
+
 // ==================== 에러 처리 ====================
 
 /// EngineError를 HTTP 응답으로 변환.
@@ -441,6 +694,7 @@ fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ApiError>) {
 )]
 pub async fn create_strategy(
     State(state): State<Arc<AppState>>,
+    OptionalJwtAuth(claims): OptionalJwtAuth,
     Json(request): Json<CreateStrategyRequest>,
 ) -> Result<Json<CreateStrategyResponse>, (StatusCode, Json<ApiError>)> {
     // 입력 유효성 검사
@@ -459,6 +713,9 @@ pub async fn create_strategy(
         )
     })?;
 
+    // 설정 검증 (필수값 누락·타입 불일치 거부)
+    validate_strategy_config(&request.strategy_type, &request.parameters)?;
+
     // 전략 ID 생성 (UUID)
     let strategy_id = format!(
         "{}_{}",
@@ -545,6 +802,7 @@ pub async fn create_strategy(
             risk_profile: request.risk_profile.clone(),
             multi_timeframe_config: request.multi_timeframe_config.clone(),
             credential_id,
+            owner_id: claims.as_ref().and_then(Claims::user_uuid),
         };
 
         StrategyRepository::create(pool, input).await.map_err(|e| {
@@ -972,7 +1230,7 @@ async fn subscribe_strategy_symbols_to_stream(
     engine: &trader_strategy::StrategyEngine,
     strategy_id: &str,
 ) {
-    use crate::services::get_or_create_market_stream;
+    use crate::services::{get_or_create_market_stream, MarketStreamContext};
 
     // 1. 전략 설정에서 credential_id와 symbols 가져오기
     let config = match engine.get_strategy_config(strategy_id).await {
@@ -1046,18 +1304,16 @@ async fn subscribe_strategy_symbols_to_stream(
     };
 
     // 3. MarketStream 핸들 가져오기 (없으면 생성)
-    let handle = match get_or_create_market_stream(
-        &state.market_streams,
-        &exchange_id,
-        Some(pool),
-        Some(encryptor.as_ref()),
-        &state.kis_oauth_cache,
-        &state.mock_providers,
-        credential_id,
-        state.subscriptions.as_ref(),
-    )
-    .await
-    {
+    let ctx = MarketStreamContext {
+        market_streams: &state.market_streams,
+        pool: Some(pool),
+        encryptor: Some(encryptor.as_ref()),
+        kis_oauth_cache: &state.kis_oauth_cache,
+        mock_providers: &state.mock_providers,
+        subscriptions: state.subscriptions.as_ref(),
+        quote_cache: state.quote_cache.as_ref(),
+    };
+    let handle = match get_or_create_market_stream(&ctx, &exchange_id, credential_id).await {
         Ok(handle) => handle,
         Err(e) => {
             tracing::warn!(
@@ -1304,6 +1560,7 @@ pub async fn stop_strategy(
     request_body = UpdateConfigRequest,
     responses(
         (status = 200, description = "설정 변경 성공", body = StrategyActionResponse),
+        (status = 400, description = "설정 검증 실패", body = ApiError),
         (status = 404, description = "전략을 찾을 수 없음", body = ApiError),
         (status = 500, description = "서버 오류", body = ApiError)
     )
@@ -1315,6 +1572,15 @@ pub async fn update_config(
 ) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
     let engine = state.strategy_engine.read().await;
 
+    // 설정 검증 (name은 엔진이 custom_name으로 분리하므로 제외)
+    if let Ok(strategy_type) = engine.get_strategy_type(&id).await {
+        let mut config = request.config.clone();
+        if let Some(obj) = config.as_object_mut() {
+            obj.remove("name");
+        }
+        validate_strategy_config(&strategy_type, &config)?;
+    }
+
     // 전략 상태 가져오기 (브로드캐스트용)
     let (strategy_name, is_running) = engine
         .get_strategy_status(&id)
@@ -1639,6 +1905,7 @@ pub async fn update_credential(
 )]
 pub async fn clone_strategy(
     State(state): State<Arc<AppState>>,
+    OptionalJwtAuth(claims): OptionalJwtAuth,
     Path(source_id): Path<String>,
     Json(request): Json<CloneStrategyRequest>,
 ) -> Result<Json<CloneStrategyResponse>, (StatusCode, Json<ApiError>)> {
@@ -1734,6 +2001,7 @@ pub async fn clone_strategy(
         risk_profile: source.risk_profile.clone(),
         multi_timeframe_config: source.multi_timeframe_config.clone(),
         credential_id: source.credential_id,
+        owner_id: claims.as_ref().and_then(Claims::user_uuid),
     };
 
     StrategyRepository::create(pool, input).await.map_err(|e| {
@@ -1786,6 +2054,373 @@ pub async fn clone_strategy(
     }))
 }
 
+/// 전략 설정 내보내기.
+///
+/// GET /api/v1/strategies/{id}/export
+///
+/// 거래소 계정 등 민감 정보는 제외되며, `includeBacktest=true`이면
+/// 가장 최근 성공한 백테스트 성과 요약이 함께 포함됩니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/export",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "전략 ID"),
+        ("includeBacktest" = Option<bool>, Query, description = "백테스트 성과 요약 포함 여부")
+    ),
+    responses(
+        (status = 200, description = "전략 내보내기 성공", body = StrategyExportDocument),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError),
+        (status = 500, description = "서버 오류", body = ApiError)
+    )
+)]
+pub async fn export_strategy(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<ExportStrategyQuery>,
+) -> Result<Json<StrategyExportDocument>, (StatusCode, Json<ApiError>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
+        )
+    })?;
+
+    let record = StrategyRepository::get_by_id(pool, &id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new(
+                    "DB_ERROR",
+                    format!("Failed to get strategy: {}", e),
+                )),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiError::new(
+                    "STRATEGY_NOT_FOUND",
+                    format!("Strategy '{}' not found", id),
+                )),
+            )
+        })?;
+
+    // 민감 정보 제거 (credential_id는 문서 구조에서 제외)
+    let mut removed = Vec::new();
+    let mut config = record.config.clone();
+    strip_sensitive_fields(&mut config, "", &mut removed);
+    let mut risk_config = record.risk_limits.clone();
+    strip_sensitive_fields(&mut risk_config, "risk.", &mut removed);
+    if !removed.is_empty() {
+        tracing::info!(
+            strategy_id = %id,
+            removed = ?removed,
+            "Sensitive fields excluded from strategy export"
+        );
+    }
+
+    let symbols: Vec<String> = record
+        .symbols
+        .as_ref()
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let backtest_summary = if query.include_backtest {
+        BacktestResultsRepository::get_by_strategy_id(pool, &id)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new(
+                        "DB_ERROR",
+                        format!("Failed to get backtest results: {}", e),
+                    )),
+                )
+            })?
+            .into_iter()
+            .find(|r| r.success)
+            .map(|r| ExportedBacktestSummary {
+                backtest_id: r.id.to_string(),
+                symbol: r.symbol,
+                start_date: r.start_date.to_string(),
+                end_date: r.end_date.to_string(),
+                initial_capital: r.initial_capital.to_string(),
+                metrics: r.metrics,
+                created_at: r.created_at.to_rfc3339(),
+            })
+    } else {
+        None
+    };
+
+    let strategy_type = record
+        .strategy_type
+        .clone()
+        .unwrap_or_else(|| "unknown".to_string());
+
+    Ok(Json(StrategyExportDocument {
+        format_version: STRATEGY_EXPORT_FORMAT_VERSION,
+        exported_at: Utc::now().to_rfc3339(),
+        source: StrategyExportSource {
+            strategy_id: record.id.clone(),
+            name: record.name.clone(),
+            strategy_version: record.version.clone(),
+        },
+        strategy: ExportedStrategyConfig {
+            name: record.name,
+            description: record.description,
+            strategy_type,
+            symbols,
+            market: record.market.unwrap_or_else(|| "KR".to_string()),
+            timeframe: record.timeframe.unwrap_or_else(|| "1d".to_string()),
+            config,
+            risk_config: Some(risk_config),
+            allocated_capital: record.allocated_capital.map(|c| c.to_string()),
+            risk_profile: record.risk_profile,
+            multi_timeframe_config: record.multi_timeframe_config,
+        },
+        backtest_summary,
+    }))
+}
+
+/// 전략 설정 가져오기.
+///
+/// POST /api/v1/strategies/import
+///
+/// 문서 포맷 버전과 전략 타입을 확인하고 파라미터를 전략 설정 타입 기준으로 검증한 뒤
+/// 새 전략으로 생성합니다. 출처 정보는 `strategy_import_provenance`에 기록됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/import",
+    tag = "strategies",
+    request_body = ImportStrategyRequest,
+    responses(
+        (status = 200, description = "전략 가져오기 성공", body = ImportStrategyResponse),
+        (status = 400, description = "잘못된 문서 또는 파라미터", body = ApiError),
+        (status = 409, description = "이름 충돌", body = ApiError),
+        (status = 500, description = "서버 오류", body = ApiError)
+    )
+)]
+pub async fn import_strategy(
+    State(state): State<Arc<AppState>>,
+    OptionalJwtAuth(claims): OptionalJwtAuth,
+    Json(request): Json<ImportStrategyRequest>,
+) -> Result<Json<ImportStrategyResponse>, (StatusCode, Json<ApiError>)> {
+    request.validate().map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::validation_error(&e)),
+        )
+    })?;
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
+        )
+    })?;
+
+    let document = request.document;
+    let bad_request =
+        |code: &str, message: String| (StatusCode::BAD_REQUEST, Json(ApiError::new(code, message)));
+
+    // 포맷 버전 호환성 확인
+    if document.format_version == 0 || document.format_version > STRATEGY_EXPORT_FORMAT_VERSION {
+        return Err(bad_request(
+            "UNSUPPORTED_EXPORT_VERSION",
+            format!(
+                "Export format version {} is not supported (supported: 1..={})",
+                document.format_version, STRATEGY_EXPORT_FORMAT_VERSION
+            ),
+        ));
+    }
+
+    // 전략 타입 확인 (별칭은 정식 ID로 정규화)
+    let meta = trader_strategy::StrategyRegistry::find(&document.strategy.strategy_type)
+        .ok_or_else(|| {
+            bad_request(
+                "UNKNOWN_STRATEGY_TYPE",
+                format!("Unknown strategy type: {}", document.strategy.strategy_type),
+            )
+        })?;
+    let strategy_type = meta.id.to_string();
+
+    let mut warnings = Vec::new();
+
+    // 문서에 민감 정보가 남아 있으면 제거
+    let mut removed = Vec::new();
+    let mut config = document.strategy.config.clone();
+    strip_sensitive_fields(&mut config, "", &mut removed);
+    let mut risk_config = document.strategy.risk_config.clone();
+    if let Some(risk) = risk_config.as_mut() {
+        strip_sensitive_fields(risk, "risk.", &mut removed);
+    }
+    if !removed.is_empty() {
+        warnings.push(format!(
+            "민감 정보 필드가 제거되었습니다: {}",
+            removed.join(", ")
+        ));
+    }
+
+    // 파라미터 검증 (UI 저장 경로와 같은 레지스트리 설정 검증)
+    validate_strategy_config(&strategy_type, &config)?;
+
+    if let Some(risk_profile) = document.strategy.risk_profile.as_deref() {
+        validate_risk_profile(risk_profile).map_err(|_| {
+            bad_request(
+                "VALIDATION_ERROR",
+                format!("Invalid risk profile: {}", risk_profile),
+            )
+        })?;
+    }
+
+    let allocated_capital = match document.strategy.allocated_capital.as_deref() {
+        Some(raw) => Some(raw.parse::<Decimal>().map_err(|e| {
+            bad_request(
+                "VALIDATION_ERROR",
+                format!("Invalid allocated capital '{}': {}", raw, e),
+            )
+        })?),
+        None => None,
+    };
+
+    let credential_id = match request.credential_id.as_deref() {
+        Some(raw) => Some(Uuid::parse_str(raw).map_err(|e| {
+            bad_request(
+                "VALIDATION_ERROR",
+                format!("Invalid credential ID '{}': {}", raw, e),
+            )
+        })?),
+        None => None,
+    };
+
+    // 이름 충돌 처리
+    let base_name = request
+        .name
+        .unwrap_or_else(|| document.strategy.name.clone());
+    let db_error = |e: sqlx::Error| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "DB_ERROR",
+                format!("Failed to import strategy: {}", e),
+            )),
+        )
+    };
+    let mut name = base_name.clone();
+    let mut attempt = 1;
+    while StrategyRepository::exists_by_name(pool, &name)
+        .await
+        .map_err(db_error)?
+    {
+        if request.on_name_conflict == NameConflictPolicy::Reject {
+            return Err((
+                StatusCode::CONFLICT,
+                Json(ApiError::new(
+                    "NAME_CONFLICT",
+                    format!("Strategy name '{}' already exists", name),
+                )),
+            ));
+        }
+        attempt += 1;
+        name = numbered_name(&base_name, attempt);
+    }
+    let renamed = name != base_name;
+    if renamed {
+        warnings.push(format!(
+            "이름 '{}'이(가) 이미 사용 중이어서 '{}'(으)로 변경되었습니다",
+            base_name, name
+        ));
+    }
+
+    let new_id = format!("{}_{}", strategy_type, &Uuid::new_v4().to_string()[..8]);
+    let input = CreateStrategyInput {
+        id: new_id.clone(),
+        name: name.clone(),
+        description: document.strategy.description.clone(),
+        strategy_type: strategy_type.clone(),
+        symbols: document.strategy.symbols.clone(),
+        market: document.strategy.market.clone(),
+        timeframe: document.strategy.timeframe.clone(),
+        config: config.clone(),
+        risk_config,
+        allocated_capital,
+        risk_profile: document.strategy.risk_profile.clone(),
+        multi_timeframe_config: document.strategy.multi_timeframe_config.clone(),
+        credential_id,
+        owner_id: claims.as_ref().and_then(Claims::user_uuid),
+    };
+
+    // 전략과 출처 정보는 하나의 트랜잭션으로 저장
+    let provenance = StrategyImportProvenance {
+        strategy_id: new_id.clone(),
+        source_strategy_id: Some(document.source.strategy_id.clone()),
+        source_name: Some(document.source.name.clone()),
+        source_strategy_version: document.source.strategy_version.clone(),
+        format_version: document.format_version as i32,
+        exported_at: chrono::DateTime::parse_from_rfc3339(&document.exported_at)
+            .ok()
+            .map(|t| t.with_timezone(&Utc)),
+        warnings: serde_json::json!(warnings),
+        imported_at: Utc::now(),
+    };
+    StrategyRepository::create_imported(pool, input, &provenance)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to create imported strategy: {:?}", e);
+            db_error(e)
+        })?;
+
+    // 전략 인스턴스 생성 및 엔진에 등록 (공유 StrategyContext 전달)
+    if let Ok(strategy) = create_strategy_instance(&strategy_type) {
+        let engine = state.strategy_engine.read().await;
+        let _ = engine
+            .register_strategy(
+                &new_id,
+                strategy,
+                config,
+                Some(name.clone()),
+                state.strategy_context.clone(),
+            )
+            .await;
+    }
+
+    // WebSocket 브로드캐스트: 전략 가져오기 알림
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: new_id.clone(),
+        name: name.clone(),
+        running: false,
+        event: "imported".to_string(),
+        data: Some(serde_json::json!({
+            "source_id": document.source.strategy_id,
+            "strategy_type": strategy_type,
+        })),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    tracing::info!(
+        strategy_id = %new_id,
+        source_id = %provenance.source_strategy_id.as_deref().unwrap_or_default(),
+        warnings = warnings.len(),
+        "Strategy imported"
+    );
+
+    Ok(Json(ImportStrategyResponse {
+        success: true,
+        strategy_id: new_id.clone(),
+        name,
+        renamed,
+        warnings,
+        message: format!("Strategy imported as '{}'", new_id),
+    }))
+}
+
 /// 엔진 통계 조회.
 ///
 /// GET /api/v1/strategies/stats
@@ -2043,6 +2678,8 @@ pub fn strategies_router() -> Router<Arc<AppState>> {
         .route("/stats", get(get_engine_stats))
         // 전략 메타데이터 (SDUI 스키마용)
         .route("/meta", get(list_strategy_meta))
+        // 전략 설정 가져오기
+        .route("/import", post(import_strategy))
         // 개별 전략 조작
         .route("/{id}", get(get_strategy).delete(delete_strategy))
         .route("/{id}/start", post(start_strategy))
@@ -2052,6 +2689,7 @@ pub fn strategies_router() -> Router<Arc<AppState>> {
         .route("/{id}/symbols", put(update_symbols))
         .route("/{id}/credential", put(update_credential))
         .route("/{id}/clone", post(clone_strategy))
+        .route("/{id}/export", get(export_strategy))
         // 전략 스키마 (SDUI)
         .route("/{id}/schema", get(get_strategy_schema))
         // 다중 타임프레임 설정
@@ -2190,6 +2828,64 @@ mod tests {
         assert_eq!(error.message, "Test message");
     }
 
+    #[test]
+    fn test_strip_sensitive_fields() {
+        let mut config = serde_json::json!({
+            "period": 14,
+            "api_key": "abc",
+            "notify": {"telegram_token": "xyz", "enabled": true},
+            "legs": [{"account_no": "123", "weight": 0.5}]
+        });
+        let mut removed = Vec::new();
+        strip_sensitive_fields(&mut config, "", &mut removed);
+
+        assert_eq!(config["period"], 14);
+        assert!(config.get("api_key").is_none());
+        assert!(config["notify"].get("telegram_token").is_none());
+        assert_eq!(config["notify"]["enabled"], true);
+        assert!(config["legs"][0].get("account_no").is_none());
+        assert_eq!(removed.len(), 3);
+        assert!(removed.contains(&"notify.telegram_token".to_string()));
+        assert!(removed.contains(&"legs.0.account_no".to_string()));
+    }
+
+    #[test]
+    fn test_export_document_roundtrip() {
+        let document = StrategyExportDocument {
+            format_version: STRATEGY_EXPORT_FORMAT_VERSION,
+            exported_at: Utc::now().to_rfc3339(),
+            source: StrategyExportSource {
+                strategy_id: "rsi_1234abcd".to_string(),
+                name: "My RSI".to_string(),
+                strategy_version: Some("1.0.0".to_string()),
+            },
+            strategy: ExportedStrategyConfig {
+                name: "My RSI".to_string(),
+                description: None,
+                strategy_type: "rsi".to_string(),
+                symbols: vec!["005930".to_string()],
+                market: "KR".to_string(),
+                timeframe: "1d".to_string(),
+                config: serde_json::json!({"period": 14}),
+                risk_config: None,
+                allocated_capital: Some("1000000".to_string()),
+                risk_profile: Some("default".to_string()),
+                multi_timeframe_config: None,
+            },
+            backtest_summary: None,
+        };
+
+        let json = serde_json::to_value(&document).unwrap();
+        assert!(json.get("backtest_summary").is_none());
+
+        let request: ImportStrategyRequest =
+            serde_json::from_value(serde_json::json!({ "document": json })).unwrap();
+        assert_eq!(request.on_name_conflict, NameConflictPolicy::Rename);
+        assert_eq!(request.document.strategy.symbols, vec!["005930"]);
+        assert_eq!(numbered_name("My RSI", 1), "My RSI");
+        assert_eq!(numbered_name("My RSI", 3), "My RSI (3)");
+    }
+
     #[test]
     fn test_engine_stats_conversion() {
         let stats = EngineStats {
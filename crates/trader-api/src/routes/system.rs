@@ -0,0 +1,376 @@
+//! 시스템 설정 API 엔드포인트.
+//!
+//! 런타임 파라미터(Rate Limit, 알림 임계값, 수집 주기)를 재시작 없이 조회·변경합니다.
+//! 모든 엔드포인트는 Admin 권한이 필요합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/system/settings` - 전체 설정 조회 (민감 정보 마스킹)
+//! - `PUT /api/v1/system/settings` - 설정 변경 (검증 → 즉시 적용 → DB 저장, 저장 실패 시 복원)
+//! - `GET /api/v1/system/settings/history` - 설정 변경 이력 조회
+
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{error, info};
+use ts_rs::TS;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    auth::AdminAuth,
+    error::ApiErrorResponse,
+    repository::{SettingChange, SettingHistoryRecord, SystemSettingsRepository},
+    services::SystemSettingView,
+    state::AppState,
+};
+
+/// 이력 조회 기본 건수.
+const DEFAULT_HISTORY_LIMIT: i64 = 50;
+
+/// 이력 조회 최대 건수.
+const MAX_HISTORY_LIMIT: i64 = 500;
+
+/// 시스템 설정 목록 응답.
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "system/")]
+pub struct SystemSettingsResponse {
+    /// 설정 목록
+    pub settings: Vec<SystemSettingView>,
+}
+
+/// 시스템 설정 변경 요청.
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export, export_to = "system/")]
+pub struct UpdateSystemSettingsRequest {
+    /// 변경할 설정 (키 → 값)
+    #[ts(type = "Record<string, unknown>")]
+    pub settings: HashMap<String, Value>,
+    /// 변경 사유 (감사 로그에 기록)
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// 시스템 설정 변경 응답.
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "system/")]
+pub struct UpdateSystemSettingsResponse {
+    /// 실제로 값이 바뀐 설정
+    pub changes: Vec<SettingChange>,
+    /// 변경 후 전체 설정
+    pub settings: Vec<SystemSettingView>,
+}
+
+/// 설정 변경 이력 조회 쿼리.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SettingHistoryQuery {
+    /// 설정 키 필터
+    pub key: Option<String>,
+    /// 최대 조회 건수 (기본 50, 최대 500)
+    pub limit: Option<i64>,
+}
+
+/// 설정 변경 이력 응답.
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "system/")]
+pub struct SettingHistoryResponse {
+    /// 변경 이력 (최신순)
+    pub history: Vec<SettingHistoryRecord>,
+    /// 조회된 건수
+    pub total: usize,
+}
+
+/// 시스템 설정 조회.
+///
+/// `GET /api/v1/system/settings`
+#[utoipa::path(
+    get,
+    path = "/api/v1/system/settings",
+    tag = "system",
+    responses(
+        (status = 200, description = "설정 조회 성공", body = SystemSettingsResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요")
+    )
+)]
+pub async fn get_settings(
+    AdminAuth(_claims): AdminAuth,
+    State(state): State<Arc<AppState>>,
+) -> Json<SystemSettingsResponse> {
+    Json(SystemSettingsResponse {
+        settings: state.runtime_settings.snapshot(),
+    })
+}
+
+/// 시스템 설정 변경.
+///
+/// 모든 항목을 검증한 뒤 즉시 적용하고 DB에 저장합니다.
+/// 하나라도 유효하지 않으면 아무것도 변경하지 않습니다.
+///
+/// `PUT /api/v1/system/settings`
+#[utoipa::path(
+    put,
+    path = "/api/v1/system/settings",
+    tag = "system",
+    request_body = UpdateSystemSettingsRequest,
+    responses(
+        (status = 200, description = "설정 변경 성공", body = UpdateSystemSettingsResponse),
+        (status = 400, description = "유효하지 않은 설정", body = ApiErrorResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요"),
+        (status = 500, description = "저장 실패", body = ApiErrorResponse)
+    )
+)]
+pub async fn update_settings(
+    AdminAuth(claims): AdminAuth,
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<UpdateSystemSettingsRequest>,
+) -> Result<Json<UpdateSystemSettingsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    if request.settings.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new(
+                "EMPTY_SETTINGS",
+                "변경할 설정이 없습니다.",
+            )),
+        ));
+    }
+
+    let validated = state
+        .runtime_settings
+        .validate_changes(&request.settings)
+        .map_err(|errors| {
+            let details: Vec<Value> = errors
+                .iter()
+                .map(|e| serde_json::json!({ "code": e.code(), "message": e.to_string() }))
+                .collect();
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiErrorResponse::with_details(
+                    "INVALID_SETTINGS",
+                    "유효하지 않은 설정이 포함되어 있습니다.",
+                    Value::from(details),
+                )),
+            )
+        })?;
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiErrorResponse::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스 연결이 설정되지 않았습니다.",
+            )),
+        )
+    })?;
+
+    // 즉시 적용 후 저장, 저장 실패 시 이전 값으로 복원
+    let applied = state.runtime_settings.apply(validated).await;
+    let changes: Vec<SettingChange> = applied
+        .iter()
+        .map(|(key, old_value, new_value)| SettingChange {
+            key: key.to_string(),
+            old_value: old_value.clone(),
+            new_value: new_value.clone(),
+        })
+        .collect();
+
+    if !changes.is_empty() {
+        if let Err(e) = SystemSettingsRepository::save_changes(
+            pool,
+            &changes,
+            &claims.sub,
+            request.reason.as_deref(),
+        )
+        .await
+        {
+            state.runtime_settings.revert(&applied).await;
+            error!(error = %e, "시스템 설정 저장 실패, 변경 취소");
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiErrorResponse::new(
+                    "DB_ERROR",
+                    format!("설정 저장 실패: {}", e),
+                )),
+            ));
+        }
+
+        for change in &changes {
+            info!(
+                key = %change.key,
+                old_value = %change.old_value,
+                new_value = %change.new_value,
+                changed_by = %claims.sub,
+                "시스템 설정 변경"
+            );
+        }
+    }
+
+    Ok(Json(UpdateSystemSettingsResponse {
+        changes,
+        settings: state.runtime_settings.snapshot(),
+    }))
+}
+
+/// 시스템 설정 변경 이력 조회.
+///
+/// `GET /api/v1/system/settings/history`
+#[utoipa::path(
+    get,
+    path = "/api/v1/system/settings/history",
+    tag = "system",
+    params(SettingHistoryQuery),
+    responses(
+        (status = 200, description = "이력 조회 성공", body = SettingHistoryResponse),
+        (status = 401, description = "인증 필요"),
+        (status = 403, description = "Admin 권한 필요"),
+        (status = 500, description = "서버 오류", body = ApiErrorResponse)
+    )
+)]
+pub async fn get_settings_history(
+    AdminAuth(_claims): AdminAuth,
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SettingHistoryQuery>,
+) -> Result<Json<SettingHistoryResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiErrorResponse::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스 연결이 설정되지 않았습니다.",
+            )),
+        )
+    })?;
+
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .clamp(1, MAX_HISTORY_LIMIT);
+
+    let history = SystemSettingsRepository::list_history(pool, query.key.as_deref(), limit)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "설정 변경 이력 조회 실패");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiErrorResponse::new(
+                    "DB_ERROR",
+                    format!("이력 조회 실패: {}", e),
+                )),
+            )
+        })?;
+
+    Ok(Json(SettingHistoryResponse {
+        total: history.len(),
+        history,
+    }))
+}
+
+/// 시스템 설정 라우터 생성.
+pub fn system_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/settings", get(get_settings).put(update_settings))
+        .route("/settings/history", get(get_settings_history))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{
+        auth::{create_token, Claims, Role},
+        state::create_test_state,
+    };
+
+    fn bearer(role: Role) -> String {
+        let secret = std::env::var("JWT_SECRET")
+            .unwrap_or_else(|_| "development-secret-key-change-in-production".to_string());
+        let token = create_token(&Claims::new("user-1", "tester", role, 60), &secret).unwrap();
+        format!("Bearer {}", token)
+    }
+
+    fn app() -> Router {
+        system_router().with_state(Arc::new(create_test_state()))
+    }
+
+    #[tokio::test]
+    async fn test_settings_require_admin() {
+        let response = app()
+            .oneshot(
+                Request::builder()
+                    .uri("/settings")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app()
+            .oneshot(
+                Request::builder()
+                    .uri("/settings")
+                    .header("authorization", bearer(Role::Trader))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = app()
+            .oneshot(
+                Request::builder()
+                    .uri("/settings")
+                    .header("authorization", bearer(Role::Admin))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_read_only_setting() {
+        let state = Arc::new(create_test_state());
+        let app = system_router().with_state(state.clone());
+
+        let body = serde_json::json!({
+            "settings": {
+                "auth.jwt_secret": "changed",
+                "rate_limit.requests_per_minute": 600
+            }
+        });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/settings")
+                    .header("authorization", bearer(Role::Admin))
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        // 유효한 항목도 함께 거부됨
+        assert_eq!(
+            state
+                .runtime_settings
+                .rate_limit_config()
+                .requests_per_minute,
+            1200
+        );
+    }
+}
@@ -0,0 +1,627 @@
+//! 백테스트 비동기 작업 큐.
+//!
+//! 장기간/다중 심볼 백테스트는 HTTP 요청 안에서 동기로 실행하면 타임아웃에 걸리므로,
+//! 작업을 큐에 제출하고 job_id로 진행률과 결과를 조회합니다.
+//!
+//! - 동시 실행 수는 세마포어로 제한하며, 초과분은 제출 순서대로 대기합니다.
+//! - 실행 중인 작업은 [`BacktestProgressHandle`]로 진행률과 부분 결과를 노출합니다.
+//! - 대기/실행 중인 작업은 취소할 수 있습니다.
+//! - 종료된 작업의 결과는 `result_ttl` 동안 보관 후 정리 서비스가 삭제합니다.
+
+use std::{collections::HashMap, future::Future, sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use tokio::sync::{RwLock, Semaphore};
+use tokio_util::sync::CancellationToken;
+use trader_analytics::backtest::BacktestProgressHandle;
+use uuid::Uuid;
+
+/// 작업 큐 설정.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BacktestJobConfig {
+    /// 동시에 실행할 수 있는 최대 작업 수
+    pub max_concurrent: usize,
+    /// 대기열에 쌓을 수 있는 최대 작업 수
+    pub max_queued: usize,
+    /// 종료된 작업 결과 보관 시간
+    pub result_ttl: Duration,
+    /// 만료 결과 정리 주기
+    pub cleanup_interval: Duration,
+}
+
+impl Default for BacktestJobConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: 2,
+            max_queued: 20,
+            result_ttl: Duration::from_secs(3600),
+            cleanup_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+impl BacktestJobConfig {
+    /// 환경변수로 기본값 덮어쓰기.
+    ///
+    /// - `BACKTEST_MAX_CONCURRENT_JOBS`
+    /// - `BACKTEST_MAX_QUEUED_JOBS`
+    /// - `BACKTEST_JOB_RESULT_TTL_SECS`
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let read = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .and_then(|raw| raw.parse::<u64>().ok())
+                .filter(|value| *value > 0)
+        };
+
+        Self {
+            max_concurrent: read("BACKTEST_MAX_CONCURRENT_JOBS")
+                .map(|v| v as usize)
+                .unwrap_or(defaults.max_concurrent),
+            max_queued: read("BACKTEST_MAX_QUEUED_JOBS")
+                .map(|v| v as usize)
+                .unwrap_or(defaults.max_queued),
+            result_ttl: read("BACKTEST_JOB_RESULT_TTL_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.result_ttl),
+            cleanup_interval: defaults.cleanup_interval,
+        }
+    }
+}
+
+/// 작업 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BacktestJobStatus {
+    /// 실행 슬롯 대기 중
+    Queued,
+    /// 실행 중
+    Running,
+    /// 완료
+    Completed,
+    /// 실패
+    Failed,
+    /// 취소됨
+    Cancelled,
+}
+
+impl BacktestJobStatus {
+    /// 종료 상태 여부.
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// 실행 중 진행률 및 부분 결과.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct BacktestJobProgress {
+    /// 진행률 (0.0 ~ 100.0)
+    pub percent: f64,
+    /// 처리한 캔들 수
+    pub processed_candles: usize,
+    /// 전체 캔들 수
+    pub total_candles: usize,
+    /// 마지막으로 처리한 캔들 시각
+    pub current_time: Option<DateTime<Utc>>,
+    /// 현재 자산 (미실현 손익 포함)
+    #[schema(value_type = String)]
+    pub equity: Decimal,
+    /// 지금까지 완료된 거래 수
+    pub total_trades: usize,
+}
+
+/// 작업 조회 결과 (API 응답용).
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct BacktestJobView {
+    /// 작업 ID
+    pub job_id: Uuid,
+    /// 작업 설명 (전략/심볼)
+    pub label: String,
+    /// 상태
+    pub status: BacktestJobStatus,
+    /// 대기열 순번 (1부터, 대기 중일 때만)
+    pub queue_position: Option<usize>,
+    /// 진행률 (실행을 시작한 작업만)
+    pub progress: Option<BacktestJobProgress>,
+    /// 완료 결과
+    pub result: Option<serde_json::Value>,
+    /// 실패 사유
+    pub error: Option<String>,
+    /// 제출 시각
+    pub created_at: DateTime<Utc>,
+    /// 실행 시작 시각
+    pub started_at: Option<DateTime<Utc>>,
+    /// 종료 시각
+    pub finished_at: Option<DateTime<Utc>>,
+    /// 결과 만료 시각 (이후 정리됨)
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// 작업 큐 현황.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct BacktestQueueStatus {
+    /// 최대 동시 실행 수
+    pub max_concurrent: usize,
+    /// 최대 대기 작업 수
+    pub max_queued: usize,
+    /// 실행 중인 작업 수
+    pub running: usize,
+    /// 대기 중인 작업 수
+    pub queued: usize,
+    /// 보관 중인 종료 작업 수
+    pub finished: usize,
+}
+
+/// 작업 큐 오류.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum BacktestJobError {
+    /// 대기열 초과
+    #[error("백테스트 대기열이 가득 찼습니다 (최대 {0}개)")]
+    QueueFull(usize),
+
+    /// 작업 없음 (만료 포함)
+    #[error("백테스트 작업을 찾을 수 없습니다: {0}")]
+    NotFound(Uuid),
+
+    /// 이미 종료된 작업
+    #[error("이미 종료된 작업입니다: {0}")]
+    AlreadyFinished(Uuid),
+}
+
+/// 작업 항목.
+struct JobEntry {
+    seq: u64,
+    label: String,
+    status: BacktestJobStatus,
+    progress: BacktestProgressHandle,
+    cancel: CancellationToken,
+    result: Option<serde_json::Value>,
+    error: Option<String>,
+    created_at: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    jobs: HashMap<Uuid, JobEntry>,
+    next_seq: u64,
+}
+
+/// 백테스트 비동기 작업 큐.
+pub struct BacktestJobQueue {
+    config: BacktestJobConfig,
+    slots: Arc<Semaphore>,
+    state: RwLock<QueueState>,
+}
+
+impl BacktestJobQueue {
+    /// 큐 생성.
+    pub fn new(config: BacktestJobConfig) -> Self {
+        Self {
+            slots: Arc::new(Semaphore::new(config.max_concurrent.max(1))),
+            config,
+            state: RwLock::new(QueueState::default()),
+        }
+    }
+
+    /// 큐 설정.
+    pub fn config(&self) -> BacktestJobConfig {
+        self.config
+    }
+
+    /// 작업 제출.
+    ///
+    /// `job`은 실행 슬롯을 얻은 뒤 진행률 핸들과 함께 호출되며, 결과는 JSON으로 보관됩니다.
+    /// 핸들이 취소되면 작업은 가능한 빨리 중단해야 합니다.
+    pub async fn submit<F, Fut>(
+        self: &Arc<Self>,
+        label: impl Into<String>,
+        job: F,
+    ) -> Result<Uuid, BacktestJobError>
+    where
+        F: FnOnce(BacktestProgressHandle) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<serde_json::Value, String>> + Send + 'static,
+    {
+        let job_id = Uuid::new_v4();
+        let progress = BacktestProgressHandle::new();
+        let cancel = CancellationToken::new();
+
+        {
+            let mut state = self.state.write().await;
+            let queued = state
+                .jobs
+                .values()
+                .filter(|entry| entry.status == BacktestJobStatus::Queued)
+                .count();
+            if queued >= self.config.max_queued {
+                return Err(BacktestJobError::QueueFull(self.config.max_queued));
+            }
+
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.jobs.insert(
+                job_id,
+                JobEntry {
+                    seq,
+                    label: label.into(),
+                    status: BacktestJobStatus::Queued,
+                    progress: progress.clone(),
+                    cancel: cancel.clone(),
+                    result: None,
+                    error: None,
+                    created_at: Utc::now(),
+                    started_at: None,
+                    finished_at: None,
+                },
+            );
+        }
+
+        let queue = Arc::clone(self);
+        tokio::spawn(async move {
+            queue.run_job(job_id, job, progress, cancel).await;
+        });
+
+        Ok(job_id)
+    }
+
+    /// 슬롯 대기 → 실행 → 결과 기록.
+    async fn run_job<F, Fut>(
+        &self,
+        job_id: Uuid,
+        job: F,
+        progress: BacktestProgressHandle,
+        cancel: CancellationToken,
+    ) where
+        F: FnOnce(BacktestProgressHandle) -> Fut,
+        Fut: Future<Output = Result<serde_json::Value, String>>,
+    {
+        // 세마포어는 FIFO이므로 제출 순서대로 슬롯을 얻습니다.
+        let _permit = tokio::select! {
+            biased;
+            _ = cancel.cancelled() => return,
+            permit = Arc::clone(&self.slots).acquire_owned() => match permit {
+                Ok(permit) => permit,
+                Err(_) => return,
+            },
+        };
+
+        if !self.mark_running(job_id).await {
+            return;
+        }
+
+        let outcome = tokio::select! {
+            biased;
+            _ = cancel.cancelled() => None,
+            result = job(progress) => Some(result),
+        };
+
+        let mut state = self.state.write().await;
+        let Some(entry) = state.jobs.get_mut(&job_id) else {
+            return;
+        };
+        if entry.status.is_finished() {
+            return;
+        }
+        match outcome {
+            Some(Ok(result)) => {
+                entry.status = BacktestJobStatus::Completed;
+                entry.result = Some(result);
+            }
+            Some(Err(e)) => {
+                tracing::warn!(job_id = %job_id, error = %e, "백테스트 작업 실패");
+                entry.status = BacktestJobStatus::Failed;
+                entry.error = Some(e);
+            }
+            None => entry.status = BacktestJobStatus::Cancelled,
+        }
+        entry.finished_at = Some(Utc::now());
+    }
+
+    /// 대기 → 실행 전환. 그 사이 취소되었으면 false.
+    async fn mark_running(&self, job_id: Uuid) -> bool {
+        let mut state = self.state.write().await;
+        match state.jobs.get_mut(&job_id) {
+            Some(entry) if entry.status == BacktestJobStatus::Queued => {
+                entry.status = BacktestJobStatus::Running;
+                entry.started_at = Some(Utc::now());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// 작업 조회.
+    pub async fn get(&self, job_id: Uuid) -> Option<BacktestJobView> {
+        let state = self.state.read().await;
+        let entry = state.jobs.get(&job_id)?;
+        Some(self.view(job_id, entry, &state))
+    }
+
+    /// 전체 작업 목록 (제출 순).
+    pub async fn list(&self) -> Vec<BacktestJobView> {
+        let state = self.state.read().await;
+        let mut entries: Vec<_> = state.jobs.iter().collect();
+        entries.sort_by_key(|(_, entry)| entry.seq);
+        entries
+            .into_iter()
+            .map(|(job_id, entry)| self.view(*job_id, entry, &state))
+            .collect()
+    }
+
+    /// 작업 취소.
+    ///
+    /// 대기 중인 작업은 즉시, 실행 중인 작업은 엔진이 다음 캔들을 처리하기 전에 중단됩니다.
+    pub async fn cancel(&self, job_id: Uuid) -> Result<BacktestJobView, BacktestJobError> {
+        let mut state = self.state.write().await;
+        let entry = state
+            .jobs
+            .get_mut(&job_id)
+            .ok_or(BacktestJobError::NotFound(job_id))?;
+        if entry.status.is_finished() {
+            return Err(BacktestJobError::AlreadyFinished(job_id));
+        }
+
+        entry.cancel.cancel();
+        entry.progress.cancel();
+        entry.status = BacktestJobStatus::Cancelled;
+        entry.finished_at = Some(Utc::now());
+
+        let entry = &state.jobs[&job_id];
+        Ok(self.view(job_id, entry, &state))
+    }
+
+    /// 큐 현황.
+    pub async fn status(&self) -> BacktestQueueStatus {
+        let state = self.state.read().await;
+        let count = |status: BacktestJobStatus| {
+            state
+                .jobs
+                .values()
+                .filter(|entry| entry.status == status)
+                .count()
+        };
+        let running = count(BacktestJobStatus::Running);
+        let queued = count(BacktestJobStatus::Queued);
+
+        BacktestQueueStatus {
+            max_concurrent: self.config.max_concurrent,
+            max_queued: self.config.max_queued,
+            running,
+            queued,
+            finished: state.jobs.len() - running - queued,
+        }
+    }
+
+    /// 보관 시간이 지난 종료 작업 삭제. 삭제한 개수를 반환합니다.
+    pub async fn cleanup_expired(&self) -> usize {
+        let now = Utc::now();
+        let mut state = self.state.write().await;
+        let before = state.jobs.len();
+        state.jobs.retain(|_, entry| match self.expires_at(entry) {
+            Some(expires_at) => expires_at > now,
+            None => true,
+        });
+        before - state.jobs.len()
+    }
+
+    fn expires_at(&self, entry: &JobEntry) -> Option<DateTime<Utc>> {
+        let ttl = chrono::Duration::from_std(self.config.result_ttl).ok()?;
+        entry.finished_at.map(|finished_at| finished_at + ttl)
+    }
+
+    fn view(&self, job_id: Uuid, entry: &JobEntry, state: &QueueState) -> BacktestJobView {
+        let queue_position = (entry.status == BacktestJobStatus::Queued).then(|| {
+            state
+                .jobs
+                .values()
+                .filter(|other| other.status == BacktestJobStatus::Queued && other.seq < entry.seq)
+                .count()
+                + 1
+        });
+
+        let progress = entry.started_at.map(|_| {
+            let snapshot = entry.progress.snapshot();
+            BacktestJobProgress {
+                percent: if entry.status == BacktestJobStatus::Completed {
+                    100.0
+                } else {
+                    snapshot.percent()
+                },
+                processed_candles: snapshot.processed_candles,
+                total_candles: snapshot.total_candles,
+                current_time: snapshot.current_time,
+                equity: snapshot.equity,
+                total_trades: snapshot.total_trades,
+            }
+        });
+
+        BacktestJobView {
+            job_id,
+            label: entry.label.clone(),
+            status: entry.status,
+            queue_position,
+            progress,
+            result: entry.result.clone(),
+            error: entry.error.clone(),
+            created_at: entry.created_at,
+            started_at: entry.started_at,
+            finished_at: entry.finished_at,
+            expires_at: self.expires_at(entry),
+        }
+    }
+}
+
+/// 만료된 백테스트 작업 결과 정리 서비스 시작.
+pub fn start_backtest_job_cleanup_service(
+    queue: Arc<BacktestJobQueue>,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let interval = queue.config().cleanup_interval;
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {
+                    let removed = queue.cleanup_expired().await;
+                    if removed > 0 {
+                        tracing::debug!(removed, "만료된 백테스트 작업 정리");
+                    }
+                }
+
+                _ = shutdown.cancelled() => {
+                    tracing::info!("BacktestJobCleanupService 종료");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::oneshot;
+
+    use super::*;
+
+    fn queue(max_concurrent: usize, max_queued: usize) -> Arc<BacktestJobQueue> {
+        Arc::new(BacktestJobQueue::new(BacktestJobConfig {
+            max_concurrent,
+            max_queued,
+            ..Default::default()
+        }))
+    }
+
+    /// 신호를 받을 때까지 실행되는 작업.
+    async fn submit_gated(
+        queue: &Arc<BacktestJobQueue>,
+        label: &str,
+    ) -> (Uuid, oneshot::Sender<()>) {
+        let (tx, rx) = oneshot::channel::<()>();
+        let job_id = queue
+            .submit(label, move |_| async move {
+                let _ = rx.await;
+                Ok(serde_json::json!({ "done": true }))
+            })
+            .await
+            .unwrap();
+        (job_id, tx)
+    }
+
+    async fn wait_for_status(
+        queue: &BacktestJobQueue,
+        job_id: Uuid,
+        status: BacktestJobStatus,
+    ) -> BacktestJobView {
+        for _ in 0..200 {
+            let view = queue.get(job_id).await.unwrap();
+            if view.status == status {
+                return view;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("작업 {} 상태가 {:?}가 되지 않음", job_id, status);
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_limit_and_queue_position() {
+        let queue = queue(1, 10);
+        let (first, release_first) = submit_gated(&queue, "first").await;
+        let (second, release_second) = submit_gated(&queue, "second").await;
+        let (third, _release_third) = submit_gated(&queue, "third").await;
+
+        wait_for_status(&queue, first, BacktestJobStatus::Running).await;
+        let second_view = queue.get(second).await.unwrap();
+        assert_eq!(second_view.status, BacktestJobStatus::Queued);
+        assert_eq!(second_view.queue_position, Some(1));
+        assert_eq!(queue.get(third).await.unwrap().queue_position, Some(2));
+
+        let status = queue.status().await;
+        assert_eq!(status.running, 1);
+        assert_eq!(status.queued, 2);
+
+        release_first.send(()).unwrap();
+        let done = wait_for_status(&queue, first, BacktestJobStatus::Completed).await;
+        assert_eq!(done.result, Some(serde_json::json!({ "done": true })));
+        assert!(done.expires_at.is_some());
+
+        wait_for_status(&queue, second, BacktestJobStatus::Running).await;
+        assert_eq!(queue.get(third).await.unwrap().queue_position, Some(1));
+        release_second.send(()).unwrap();
+        wait_for_status(&queue, second, BacktestJobStatus::Completed).await;
+    }
+
+    #[tokio::test]
+    async fn test_cancel_queued_and_running_jobs() {
+        let queue = queue(1, 10);
+        let (running, _release_running) = submit_gated(&queue, "running").await;
+        let (queued, _release_queued) = submit_gated(&queue, "queued").await;
+        wait_for_status(&queue, running, BacktestJobStatus::Running).await;
+
+        let view = queue.cancel(queued).await.unwrap();
+        assert_eq!(view.status, BacktestJobStatus::Cancelled);
+        assert_eq!(view.queue_position, None);
+
+        queue.cancel(running).await.unwrap();
+        assert_eq!(
+            queue.cancel(running).await.unwrap_err(),
+            BacktestJobError::AlreadyFinished(running)
+        );
+
+        // 취소 후 슬롯이 반환되어 새 작업이 실행됨
+        let (next, release_next) = submit_gated(&queue, "next").await;
+        wait_for_status(&queue, next, BacktestJobStatus::Running).await;
+        release_next.send(()).unwrap();
+        wait_for_status(&queue, next, BacktestJobStatus::Completed).await;
+        assert_eq!(
+            queue.get(queued).await.unwrap().status,
+            BacktestJobStatus::Cancelled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_job_records_error() {
+        let queue = queue(2, 10);
+        let job_id = queue
+            .submit("failing", |_| async { Err("데이터 없음".to_string()) })
+            .await
+            .unwrap();
+
+        let view = wait_for_status(&queue, job_id, BacktestJobStatus::Failed).await;
+        assert_eq!(view.error.as_deref(), Some("데이터 없음"));
+        assert!(view.result.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_queue_full() {
+        let queue = queue(1, 1);
+        let (running, _release_running) = submit_gated(&queue, "running").await;
+        wait_for_status(&queue, running, BacktestJobStatus::Running).await;
+        let (_queued, _release_queued) = submit_gated(&queue, "queued").await;
+
+        let result = queue
+            .submit("overflow", |_| async { Ok(serde_json::Value::Null) })
+            .await;
+        assert_eq!(result.unwrap_err(), BacktestJobError::QueueFull(1));
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_expired_results() {
+        let queue = Arc::new(BacktestJobQueue::new(BacktestJobConfig {
+            result_ttl: Duration::ZERO,
+            ..Default::default()
+        }));
+        let done = queue
+            .submit("done", |_| async { Ok(serde_json::Value::Null) })
+            .await
+            .unwrap();
+        let (pending, _release) = submit_gated(&queue, "pending").await;
+        wait_for_status(&queue, done, BacktestJobStatus::Completed).await;
+
+        assert_eq!(queue.cleanup_expired().await, 1);
+        assert!(queue.get(done).await.is_none());
+        assert!(queue.get(pending).await.is_some());
+    }
+}
@@ -8,15 +8,38 @@
 //! - 모든 종목 식별은 ticker 문자열을 사용합니다 (Symbol 객체가 아님)
 //! - 내부적으로 AnalyticsProvider는 ticker를 받아 CachedHistoricalDataProvider를 통해 데이터 조회
 //! - SymbolResolver가 단일 원천(single source of truth)으로 Symbol 정보 관리
+//!
+//! # 증분 동기화
+//!
+//! 종목별 분석 결과(RouteState, 피처, MarketRegime)는 마지막 동기화 이후
+//! 데이터가 갱신된 종목과 새로 추가된 종목만 다시 조회합니다.
+//! 값이 실제로 바뀐 종목만 WebSocket `context` 채널로 `context_delta` 브로드캐스트합니다.
 
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
+use chrono::{DateTime, Utc};
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
 use trader_core::{
     AnalyticsProvider, ExchangeProvider, MarketType, ScreeningPreset, StrategyContext,
 };
 
+use crate::{
+    metrics::record_context_sync_cycle,
+    websocket::{ContextDeltaData, ContextTickerData, ServerMessage, SharedSubscriptionManager},
+};
+
+/// 분석 동기화 한 주기의 결과.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct AnalyticsSyncStats {
+    /// 다시 조회한 종목 수
+    fetched: usize,
+    /// 값이 바뀐 종목 (정렬됨)
+    changed: Vec<String>,
+    /// 분석 대상에서 제외된 종목 (정렬됨)
+    removed: Vec<String>,
+}
+
 /// 전략 컨텍스트 동기화 서비스.
 ///
 /// 두 가지 독립적인 동기화 주기를 사용합니다:
@@ -28,6 +51,12 @@ pub struct ContextSyncService {
     context: Arc<RwLock<StrategyContext>>,
     exchange_sync_interval: Duration,
     analytics_sync_interval: Duration,
+    /// 변경분 브로드캐스트용 구독 관리자
+    subscriptions: Option<SharedSubscriptionManager>,
+    /// 마지막 분석 동기화 시작 시각 (증분 조회 기준)
+    last_analytics_sync: Option<DateTime<Utc>>,
+    /// 분석 결과가 반영된 종목
+    synced_tickers: HashSet<String>,
 }
 
 impl ContextSyncService {
@@ -53,14 +82,23 @@ impl ContextSyncService {
             context,
             exchange_sync_interval,
             analytics_sync_interval,
+            subscriptions: None,
+            last_analytics_sync: None,
+            synced_tickers: HashSet::new(),
         }
     }
 
+    /// 변경분 브로드캐스트용 구독 관리자 설정.
+    pub fn with_subscriptions(mut self, subscriptions: Option<SharedSubscriptionManager>) -> Self {
+        self.subscriptions = subscriptions;
+        self
+    }
+
     /// 서비스 시작 (메인 루프).
     ///
     /// 두 개의 독립적인 타이머로 거래소 정보와 분석 결과를 주기적으로 동기화합니다.
     /// CancellationToken을 통해 graceful shutdown을 지원합니다.
-    pub async fn run(self, shutdown: CancellationToken) {
+    pub async fn run(mut self, shutdown: CancellationToken) {
         let mut exchange_ticker = tokio::time::interval(self.exchange_sync_interval);
         let mut analytics_ticker = tokio::time::interval(self.analytics_sync_interval);
 
@@ -73,8 +111,9 @@ impl ContextSyncService {
                 }
 
                 _ = analytics_ticker.tick() => {
-                    if let Err(e) = self.sync_analytics().await {
-                        tracing::error!("분석 결과 동기화 실패: {}", e);
+                    match self.sync_analytics().await {
+                        Ok(stats) => self.publish_delta(&stats).await,
+                        Err(e) => tracing::error!("분석 결과 동기화 실패: {}", e),
                     }
                 }
 
@@ -150,15 +189,18 @@ impl ContextSyncService {
 
     /// 분석 결과 동기화.
     ///
-    /// 모든 분석 결과를 조회하여 컨텍스트를 업데이트합니다:
-    /// - Global Score (시장별)
-    /// - RouteState (종목별)
+    /// 분석 결과를 조회하여 컨텍스트를 업데이트합니다:
+    /// - Global Score (시장별, 매 주기 전체 조회)
+    /// - RouteState / 구조적 피처 / MarketRegime (종목별, 증분 조회)
     /// - 스크리닝 결과 (프리셋별)
-    /// - 구조적 피처 (종목별)
-    /// - MarketRegime (종목별)
     /// - MacroEnvironment (글로벌)
     /// - MarketBreadth (글로벌)
-    async fn sync_analytics(&self) -> Result<(), String> {
+    ///
+    /// 종목별 결과는 새로 추가된 종목과 마지막 동기화 이후 데이터가 갱신된
+    /// 종목만 다시 조회하고, 분석 대상에서 빠진 종목은 컨텍스트에서 제거합니다.
+    async fn sync_analytics(&mut self) -> Result<AnalyticsSyncStats, String> {
+        let started_at = Utc::now();
+
         // 1. Global Score 조회 (시장별 - 예: KR Stock)
         let scores = self
             .analytics_provider
@@ -168,20 +210,42 @@ impl ContextSyncService {
 
         // 2. 분석 대상 종목 추출 (보유 포지션 + 전략 관심 종목)
         let ctx_read = self.context.read().await;
-        let tickers: Vec<String> = ctx_read.analytics_target_tickers();
+        let tickers: HashSet<String> = ctx_read.analytics_target_tickers().into_iter().collect();
         drop(ctx_read);
 
+        // 3. 재조회 대상 선정 (신규 종목 + 갱신된 종목)
+        let mut to_fetch: HashSet<String> =
+            tickers.difference(&self.synced_tickers).cloned().collect();
+        let existing: Vec<&str> = tickers
+            .intersection(&self.synced_tickers)
+            .map(|s| s.as_str())
+            .collect();
+        if !existing.is_empty() {
+            match self.last_analytics_sync {
+                Some(since) => {
+                    let updated = self
+                        .analytics_provider
+                        .fetch_updated_tickers(&existing, since)
+                        .await
+                        .map_err(|e| format!("갱신 종목 조회 실패: {}", e))?;
+                    to_fetch.extend(updated);
+                }
+                None => to_fetch.extend(existing.iter().map(|s| s.to_string())),
+            }
+        }
+        let removed: Vec<String> = self.synced_tickers.difference(&tickers).cloned().collect();
+
         // ticker 참조 슬라이스 생성 (API는 &[&str]을 받음)
-        let ticker_refs: Vec<&str> = tickers.iter().map(|s| s.as_str()).collect();
+        let ticker_refs: Vec<&str> = to_fetch.iter().map(|s| s.as_str()).collect();
 
-        // 3. RouteState 조회 (현재 보유 종목)
+        // 4. RouteState 조회
         let states = self
             .analytics_provider
             .fetch_route_states(&ticker_refs)
             .await
             .map_err(|e| format!("RouteState 조회 실패: {}", e))?;
 
-        // 4. 스크리닝 결과 조회 (프리셋 예: "default")
+        // 5. 스크리닝 결과 조회 (프리셋 예: "default")
         let preset = ScreeningPreset::default_preset();
         let preset_name = preset.name.clone();
         let screening = self
@@ -190,47 +254,101 @@ impl ContextSyncService {
             .await
             .map_err(|e| format!("스크리닝 조회 실패: {}", e))?;
 
-        // 5. 구조적 피처 조회
+        // 6. 구조적 피처 조회
         let features = self
             .analytics_provider
             .fetch_features(&ticker_refs)
             .await
             .map_err(|e| format!("Features 조회 실패: {}", e))?;
 
-        // 6. MarketRegime 조회
+        // 7. MarketRegime 조회
         let regimes = self
             .analytics_provider
             .fetch_market_regimes(&ticker_refs)
             .await
             .map_err(|e| format!("MarketRegime 조회 실패: {}", e))?;
 
-        // 7. MacroEnvironment 조회 (글로벌)
+        // 8. MacroEnvironment 조회 (글로벌)
         let macro_env = self
             .analytics_provider
             .fetch_macro_environment()
             .await
             .map_err(|e| format!("MacroEnvironment 조회 실패: {}", e))?;
 
-        // 8. MarketBreadth 조회 (글로벌)
+        // 9. MarketBreadth 조회 (글로벌)
         let breadth = self
             .analytics_provider
             .fetch_market_breadth()
             .await
             .map_err(|e| format!("MarketBreadth 조회 실패: {}", e))?;
 
-        // 9. 컨텍스트 업데이트
+        // 결과가 하나도 없는 종목은 다음 주기에 신규 종목으로 다시 조회
+        let fetched_ok: HashSet<String> = states
+            .keys()
+            .chain(features.keys())
+            .chain(regimes.keys())
+            .cloned()
+            .collect();
+
+        // 10. 컨텍스트 업데이트 (변경분 수집)
         let mut ctx = self.context.write().await;
-        ctx.update_global_scores(scores);
-        ctx.update_route_states(states);
+        let mut changed = ctx.merge_global_scores(scores);
+        changed.extend(ctx.merge_ticker_analytics(states, features, regimes));
+        let mut removed = ctx.remove_ticker_analytics(&removed);
         ctx.update_screening(preset_name, screening);
-        ctx.update_features(features);
-        ctx.update_market_regime(regimes);
         ctx.update_macro_environment(macro_env);
         ctx.update_market_breadth(breadth);
+        drop(ctx);
+
+        self.synced_tickers = tickers
+            .into_iter()
+            .filter(|ticker| !to_fetch.contains(ticker) || fetched_ok.contains(ticker))
+            .collect();
+        self.last_analytics_sync = Some(started_at);
+
+        let mut changed: Vec<String> = changed.into_iter().collect();
+        changed.sort();
+        removed.sort();
+        let stats = AnalyticsSyncStats {
+            fetched: to_fetch.len(),
+            changed,
+            removed,
+        };
+        record_context_sync_cycle(stats.fetched, stats.changed.len(), stats.removed.len());
+
+        tracing::debug!(
+            fetched = stats.fetched,
+            changed = stats.changed.len(),
+            removed = stats.removed.len(),
+            "분석 결과 동기화 완료"
+        );
+
+        Ok(stats)
+    }
 
-        tracing::debug!(ticker_count = tickers.len(), "분석 결과 동기화 완료");
+    /// 변경된 종목만 `context` 구독자에게 브로드캐스트.
+    async fn publish_delta(&self, stats: &AnalyticsSyncStats) {
+        let Some(ref subscriptions) = self.subscriptions else {
+            return;
+        };
+        if stats.changed.is_empty() && stats.removed.is_empty() {
+            return;
+        }
+
+        let ctx = self.context.read().await;
+        let delta = ContextDeltaData {
+            changed: stats
+                .changed
+                .iter()
+                .map(|ticker| ContextTickerData::from_context(&ctx, ticker))
+                .collect(),
+            removed: stats.removed.clone(),
+            synced_at: ctx.last_analytics_sync.timestamp_millis(),
+        };
+        drop(ctx);
 
-        Ok(())
+        // 수신자가 없으면 에러가 반환되지만 정상 상황
+        let _ = subscriptions.broadcast(ServerMessage::ContextDelta(delta));
     }
 }
 
@@ -245,6 +363,7 @@ impl ContextSyncService {
 /// * `exchange_provider` - 거래소 정보 제공자
 /// * `analytics_provider` - 분석 결과 제공자
 /// * `context` - 공유 컨텍스트
+/// * `subscriptions` - 변경분 브로드캐스트용 구독 관리자 (없으면 브로드캐스트 생략)
 /// * `shutdown` - Graceful shutdown을 위한 CancellationToken
 ///
 /// # Returns
@@ -254,6 +373,7 @@ pub fn start_context_sync_service(
     exchange_provider: Arc<dyn ExchangeProvider>,
     analytics_provider: Arc<dyn AnalyticsProvider>,
     context: Arc<RwLock<StrategyContext>>,
+    subscriptions: Option<SharedSubscriptionManager>,
     shutdown: CancellationToken,
 ) -> tokio::task::JoinHandle<()> {
     let service = ContextSyncService::new(
@@ -262,9 +382,238 @@ pub fn start_context_sync_service(
         context,
         Duration::from_secs(5),  // 거래소: 5초
         Duration::from_secs(60), // 분석: 1분
-    );
+    )
+    .with_subscriptions(subscriptions);
 
     tokio::spawn(async move {
         service.run(shutdown).await;
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Mutex};
+
+    use async_trait::async_trait;
+    use trader_core::{
+        AnalyticsError, GlobalScoreResult, MacroEnvironment, MarketBreadth, MarketRegime,
+        PendingOrder, ProviderError, RouteState, ScreeningResult, StrategyAccountInfo,
+        StrategyPositionInfo, StructuralFeatures,
+    };
+
+    use super::*;
+    use crate::websocket::create_subscription_manager;
+
+    struct NoopExchange;
+
+    #[async_trait]
+    impl ExchangeProvider for NoopExchange {
+        async fn fetch_account(&self) -> Result<StrategyAccountInfo, ProviderError> {
+            Ok(StrategyAccountInfo::default())
+        }
+
+        async fn fetch_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
+            Ok(Vec::new())
+        }
+
+        async fn fetch_pending_orders(&self) -> Result<Vec<PendingOrder>, ProviderError> {
+            Ok(Vec::new())
+        }
+
+        fn exchange_name(&self) -> &str {
+            "noop"
+        }
+    }
+
+    /// RouteState만 제공하고, 갱신 종목 목록을 테스트에서 지정하는 provider.
+    #[derive(Default)]
+    struct StubAnalytics {
+        route_states: Mutex<HashMap<String, RouteState>>,
+        updated: Mutex<Vec<String>>,
+        fetched: Mutex<Vec<Vec<String>>>,
+    }
+
+    impl StubAnalytics {
+        fn set_state(&self, ticker: &str, state: RouteState) {
+            self.route_states
+                .lock()
+                .unwrap()
+                .insert(ticker.to_string(), state);
+        }
+
+        fn set_updated(&self, tickers: &[&str]) {
+            *self.updated.lock().unwrap() = tickers.iter().map(|t| t.to_string()).collect();
+        }
+
+        fn last_fetched(&self) -> Vec<String> {
+            let mut tickers = self.fetched.lock().unwrap().last().cloned().unwrap();
+            tickers.sort();
+            tickers
+        }
+    }
+
+    #[async_trait]
+    impl AnalyticsProvider for StubAnalytics {
+        async fn fetch_global_scores(
+            &self,
+            _market_type: MarketType,
+        ) -> Result<Vec<GlobalScoreResult>, AnalyticsError> {
+            Ok(Vec::new())
+        }
+
+        async fn fetch_route_states(
+            &self,
+            tickers: &[&str],
+        ) -> Result<HashMap<String, RouteState>, AnalyticsError> {
+            self.fetched
+                .lock()
+                .unwrap()
+                .push(tickers.iter().map(|t| t.to_string()).collect());
+            let states = self.route_states.lock().unwrap();
+            Ok(tickers
+                .iter()
+                .filter_map(|t| states.get(*t).map(|s| (t.to_string(), *s)))
+                .collect())
+        }
+
+        async fn fetch_screening(
+            &self,
+            _preset: ScreeningPreset,
+        ) -> Result<Vec<ScreeningResult>, AnalyticsError> {
+            Ok(Vec::new())
+        }
+
+        async fn fetch_features(
+            &self,
+            _tickers: &[&str],
+        ) -> Result<HashMap<String, StructuralFeatures>, AnalyticsError> {
+            Ok(HashMap::new())
+        }
+
+        async fn fetch_market_regimes(
+            &self,
+            _tickers: &[&str],
+        ) -> Result<HashMap<String, MarketRegime>, AnalyticsError> {
+            Ok(HashMap::new())
+        }
+
+        async fn fetch_macro_environment(&self) -> Result<MacroEnvironment, AnalyticsError> {
+            Ok(MacroEnvironment::default())
+        }
+
+        async fn fetch_market_breadth(&self) -> Result<MarketBreadth, AnalyticsError> {
+            Ok(MarketBreadth::default())
+        }
+
+        async fn fetch_updated_tickers(
+            &self,
+            tickers: &[&str],
+            _since: DateTime<Utc>,
+        ) -> Result<Vec<String>, AnalyticsError> {
+            let updated = self.updated.lock().unwrap();
+            Ok(tickers
+                .iter()
+                .filter(|t| updated.iter().any(|u| u.as_str() == **t))
+                .map(|t| t.to_string())
+                .collect())
+        }
+    }
+
+    fn service(analytics: Arc<StubAnalytics>, tickers: &[&str]) -> ContextSyncService {
+        let mut context = StrategyContext::new();
+        for ticker in tickers {
+            context.add_watched_ticker(ticker);
+        }
+        ContextSyncService::new(
+            Arc::new(NoopExchange),
+            analytics,
+            Arc::new(RwLock::new(context)),
+            Duration::from_secs(5),
+            Duration::from_secs(60),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_incremental_sync_refetches_only_updated_tickers() {
+        let analytics = Arc::new(StubAnalytics::default());
+        analytics.set_state("005930", RouteState::Wait);
+        analytics.set_state("000660", RouteState::Neutral);
+        let mut service = service(analytics.clone(), &["005930", "000660"]);
+
+        // 첫 주기: 전체 조회
+        let stats = service.sync_analytics().await.unwrap();
+        assert_eq!(stats.fetched, 2);
+        assert_eq!(stats.changed, vec!["000660", "005930"]);
+
+        // 갱신된 종목이 없으면 종목별 조회 없음
+        let stats = service.sync_analytics().await.unwrap();
+        assert_eq!(stats.fetched, 0);
+        assert!(stats.changed.is_empty());
+        assert!(analytics.last_fetched().is_empty());
+
+        // 갱신된 종목만 재조회, 값이 바뀐 종목만 변경분
+        analytics.set_state("005930", RouteState::Attack);
+        analytics.set_updated(&["005930"]);
+        let stats = service.sync_analytics().await.unwrap();
+        assert_eq!(analytics.last_fetched(), vec!["005930"]);
+        assert_eq!(stats.changed, vec!["005930"]);
+        assert_eq!(
+            service.context.read().await.get_route_state("005930"),
+            Some(&RouteState::Attack)
+        );
+
+        // 관심 종목에서 빠진 종목은 제거
+        service
+            .context
+            .write()
+            .await
+            .watched_tickers
+            .remove("000660");
+        analytics.set_updated(&[]);
+        let stats = service.sync_analytics().await.unwrap();
+        assert_eq!(stats.removed, vec!["000660"]);
+        assert!(service
+            .context
+            .read()
+            .await
+            .get_route_state("000660")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_publish_delta_broadcasts_changed_tickers_only() {
+        let analytics = Arc::new(StubAnalytics::default());
+        analytics.set_state("005930", RouteState::Armed);
+        analytics.set_state("000660", RouteState::Neutral);
+        let subscriptions = create_subscription_manager(16);
+        let mut service = service(analytics.clone(), &["005930", "000660"])
+            .with_subscriptions(Some(subscriptions.clone()));
+        let mut rx = subscriptions.register("session").await;
+
+        let stats = service.sync_analytics().await.unwrap();
+        service.publish_delta(&stats).await;
+        assert!(matches!(
+            rx.recv().await,
+            Ok(ServerMessage::ContextDelta(_))
+        ));
+
+        analytics.set_state("000660", RouteState::Overheat);
+        analytics.set_updated(&["005930", "000660"]);
+        let stats = service.sync_analytics().await.unwrap();
+        service.publish_delta(&stats).await;
+        match rx.recv().await {
+            Ok(ServerMessage::ContextDelta(delta)) => {
+                assert_eq!(delta.changed.len(), 1);
+                assert_eq!(delta.changed[0].ticker, "000660");
+                assert_eq!(delta.changed[0].route_state, Some(RouteState::Overheat));
+                assert!(delta.removed.is_empty());
+            }
+            other => panic!("변경분이 브로드캐스트되지 않음: {:?}", other),
+        }
+
+        // 변경이 없으면 브로드캐스트하지 않음
+        let stats = service.sync_analytics().await.unwrap();
+        service.publish_delta(&stats).await;
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,144 @@
+//! 리스크 상관계수 갱신 서비스.
+//!
+//! 보유 포지션과 등록된 전략의 종목에 대해 최근 일봉 수익률 상관계수를
+//! 주기적으로 계산하여 `RiskManager`의 상관 노출 한도 테이블을 갱신합니다.
+//!
+//! - 캔들은 캐시/DB에서만 읽습니다 (외부 API 호출 없음).
+//! - 데이터가 부족하면 기존 테이블을 유지하며, 테이블이 오래되면
+//!   `RiskManager`가 섹터 폴백(같은 섹터 = 상관 1.0)을 사용합니다.
+
+use std::{collections::BTreeSet, sync::Arc, time::Duration};
+
+use chrono::Utc;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use trader_analytics::risk_correlations_from_klines;
+use trader_core::Timeframe;
+use trader_data::cache::CachedHistoricalDataProvider;
+use trader_execution::OrderExecutor;
+use trader_risk::RiskManager;
+use trader_strategy::{extract_tickers_from_config, StrategyEngine};
+
+/// 상관계수 갱신 주기.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// 상관계수 계산에 사용할 일봉 수.
+const LOOKBACK_DAYS: usize = 60;
+
+/// 리스크 상관계수 갱신 서비스.
+pub struct CorrelationRefreshService {
+    data_provider: Arc<CachedHistoricalDataProvider>,
+    strategy_engine: Arc<RwLock<StrategyEngine>>,
+    executor: Arc<RwLock<OrderExecutor>>,
+    /// 갱신 대상 리스크 매니저 (API 조회용 + 실행기 검증용)
+    risk_managers: Vec<Arc<RwLock<RiskManager>>>,
+}
+
+impl CorrelationRefreshService {
+    /// 새 서비스 인스턴스 생성.
+    pub fn new(
+        data_provider: Arc<CachedHistoricalDataProvider>,
+        strategy_engine: Arc<RwLock<StrategyEngine>>,
+        executor: Arc<RwLock<OrderExecutor>>,
+        risk_managers: Vec<Arc<RwLock<RiskManager>>>,
+    ) -> Self {
+        Self {
+            data_provider,
+            strategy_engine,
+            executor,
+            risk_managers,
+        }
+    }
+
+    /// 서비스 시작 (메인 루프).
+    ///
+    /// 시작 직후 한 번 갱신하고, 이후 `REFRESH_INTERVAL`마다 갱신합니다.
+    pub async fn run(self, shutdown: CancellationToken) {
+        let mut ticker = tokio::time::interval(REFRESH_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    match self.refresh_once().await {
+                        Ok(0) => {}
+                        Ok(count) => tracing::debug!("상관계수 갱신 완료 ({}개 종목)", count),
+                        Err(e) => tracing::warn!("상관계수 갱신 실패: {}", e),
+                    }
+                }
+
+                _ = shutdown.cancelled() => {
+                    tracing::info!("CorrelationRefreshService 종료");
+                    break;
+                }
+            }
+        }
+    }
+
+    /// 상관계수를 한 번 갱신하고 대상 종목 수를 반환합니다.
+    pub async fn refresh_once(&self) -> Result<usize, String> {
+        let symbols = self.collect_symbols().await;
+        if symbols.len() < 2 {
+            return Ok(0);
+        }
+
+        let klines = self
+            .data_provider
+            .get_klines_batch_readonly(&symbols, Timeframe::D1, LOOKBACK_DAYS)
+            .await
+            .map_err(|e| format!("캔들 조회: {}", e))?;
+
+        let Some(table) = risk_correlations_from_klines(&klines, LOOKBACK_DAYS, Utc::now()) else {
+            tracing::debug!("상관계수 계산 데이터 부족, 기존 테이블 유지");
+            return Ok(0);
+        };
+
+        for risk_manager in &self.risk_managers {
+            risk_manager
+                .write()
+                .await
+                .update_correlations(table.clone());
+        }
+        Ok(symbols.len())
+    }
+
+    /// 보유 포지션 + 등록된 전략의 종목 목록.
+    async fn collect_symbols(&self) -> Vec<String> {
+        let mut symbols = BTreeSet::new();
+
+        {
+            let executor = self.executor.read().await;
+            let tracker = executor.position_tracker().read().await;
+            symbols.extend(
+                tracker
+                    .get_open_positions()
+                    .into_iter()
+                    .map(|p| p.ticker.clone()),
+            );
+        }
+
+        let engine = self.strategy_engine.read().await;
+        for id in engine.list_strategies().await {
+            if let Ok(config) = engine.get_strategy_config(&id).await {
+                symbols.extend(extract_tickers_from_config(&config));
+            }
+        }
+
+        symbols.into_iter().collect()
+    }
+}
+
+/// 상관계수 갱신 서비스 시작.
+pub fn start_correlation_refresh_service(
+    data_provider: Arc<CachedHistoricalDataProvider>,
+    strategy_engine: Arc<RwLock<StrategyEngine>>,
+    executor: Arc<RwLock<OrderExecutor>>,
+    risk_managers: Vec<Arc<RwLock<RiskManager>>>,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let service =
+        CorrelationRefreshService::new(data_provider, strategy_engine, executor, risk_managers);
+
+    tokio::spawn(async move {
+        service.run(shutdown).await;
+    })
+}
@@ -11,7 +11,7 @@
 //! # 사용 예제
 //!
 //! ```rust,ignore
-//! let handle = get_or_create_market_stream(&state, credential_id).await?;
+//! let handle = get_or_create_market_stream(&ctx, "kis", credential_id).await?;
 //! handle.subscribe("005930").await?;
 //! handle.subscribe("AAPL").await?;
 //! ```
@@ -29,6 +29,7 @@ use trader_exchange::{
         UnifiedMarketStream, UpbitMarketStream,
     },
     traits::MarketStream,
+    CachedMarketDataProvider,
 };
 use uuid::Uuid;
 
@@ -115,6 +116,24 @@ impl MarketStreamHandle {
     }
 }
 
+/// MarketStream 생성/조회에 필요한 공유 자원.
+pub struct MarketStreamContext<'a> {
+    /// 스트림 핸들 캐시
+    pub market_streams: &'a Arc<RwLock<HashMap<Uuid, Arc<MarketStreamHandle>>>>,
+    /// DB 연결 풀 (KIS 등 실거래소에서 credential 조회 필요)
+    pub pool: Option<&'a sqlx::PgPool>,
+    /// 자격증명 복호화기 (KIS 등 실거래소에서 필요)
+    pub encryptor: Option<&'a CredentialEncryptor>,
+    /// OAuth 토큰 캐시 (KIS 전용)
+    pub kis_oauth_cache: &'a Arc<RwLock<HashMap<String, Arc<KisOAuth>>>>,
+    /// Mock 거래소 프로바이더 캐시
+    pub mock_providers: &'a Arc<RwLock<HashMap<Uuid, Arc<MockExchangeProvider>>>>,
+    /// WebSocket 구독 관리자 (이벤트 브로드캐스트용)
+    pub subscriptions: Option<&'a SharedSubscriptionManager>,
+    /// 시세 캐시 (Ticker 이벤트 반영용, 실거래 시세 스트림에만 연결)
+    pub quote_cache: Option<&'a Arc<CachedMarketDataProvider>>,
+}
+
 /// credential_id에 해당하는 MarketStream 핸들 가져오기 (없으면 생성).
 ///
 /// # 싱글턴 보장
@@ -124,27 +143,17 @@ impl MarketStreamHandle {
 ///
 /// # Arguments
 ///
-/// * `market_streams` - 스트림 핸들 캐시
+/// * `ctx` - 스트림 캐시, 자격증명, 이벤트 연결 대상 등 공유 자원
 /// * `exchange_id` - 거래소 ID ("kis", "mock", "upbit", "bithumb", "ls_sec")
-/// * `pool` - DB 연결 풀 (KIS 등 실거래소에서 credential 조회 필요)
-/// * `encryptor` - 자격증명 복호화기 (KIS 등 실거래소에서 필요)
-/// * `kis_oauth_cache` - OAuth 토큰 캐시 (KIS 전용)
-/// * `mock_providers` - Mock 거래소 프로바이더 캐시
 /// * `credential_id` - 거래소 자격증명 ID
-/// * `subscriptions` - WebSocket 구독 관리자 (이벤트 브로드캐스트용)
 pub async fn get_or_create_market_stream(
-    market_streams: &Arc<RwLock<HashMap<Uuid, Arc<MarketStreamHandle>>>>,
+    ctx: &MarketStreamContext<'_>,
     exchange_id: &str,
-    pool: Option<&sqlx::PgPool>,
-    encryptor: Option<&CredentialEncryptor>,
-    kis_oauth_cache: &Arc<RwLock<HashMap<String, Arc<KisOAuth>>>>,
-    mock_providers: &Arc<RwLock<HashMap<Uuid, Arc<MockExchangeProvider>>>>,
     credential_id: Uuid,
-    subscriptions: Option<&SharedSubscriptionManager>,
 ) -> Result<Arc<MarketStreamHandle>, String> {
     // 1. 캐시 확인
     {
-        let streams = market_streams.read().await;
+        let streams = ctx.market_streams.read().await;
         if let Some(handle) = streams.get(&credential_id) {
             return Ok(handle.clone());
         }
@@ -153,13 +162,13 @@ pub async fn get_or_create_market_stream(
     // 2. exchange_id에 따라 UnifiedMarketStream 생성
     let mut stream = match exchange_id {
         "kis" => {
-            let pool = pool.ok_or("KIS 스트림에 DB 풀이 필요합니다")?;
-            let encryptor = encryptor.ok_or("KIS 스트림에 encryptor가 필요합니다")?;
+            let pool = ctx.pool.ok_or("KIS 스트림에 DB 풀이 필요합니다")?;
+            let encryptor = ctx.encryptor.ok_or("KIS 스트림에 encryptor가 필요합니다")?;
 
             let kis_config =
                 load_kis_config_from_credential(pool, encryptor, credential_id).await?;
-            let oauth_kr = create_oauth_instance(kis_oauth_cache, &kis_config, "kr").await?;
-            let oauth_us = create_oauth_instance(kis_oauth_cache, &kis_config, "us").await?;
+            let oauth_kr = create_oauth_instance(ctx.kis_oauth_cache, &kis_config, "kr").await?;
+            let oauth_us = create_oauth_instance(ctx.kis_oauth_cache, &kis_config, "us").await?;
 
             let kr_stream = KisKrMarketStream::new(oauth_kr);
             let us_stream = KisUsMarketStream::new(oauth_us);
@@ -169,7 +178,7 @@ pub async fn get_or_create_market_stream(
                 .with_us_stream(us_stream)
         }
         "mock" => {
-            let providers = mock_providers.read().await;
+            let providers = ctx.mock_providers.read().await;
             let provider = providers
                 .get(&credential_id)
                 .ok_or_else(|| format!("Mock 프로바이더를 찾을 수 없습니다: {}", credential_id))?;
@@ -188,8 +197,10 @@ pub async fn get_or_create_market_stream(
             UnifiedMarketStream::new().with_kr_stream(bithumb_stream)
         }
         "ls_sec" => {
-            let pool = pool.ok_or("LS증권 스트림에 DB 풀이 필요합니다")?;
-            let encryptor = encryptor.ok_or("LS증권 스트림에 encryptor가 필요합니다")?;
+            let pool = ctx.pool.ok_or("LS증권 스트림에 DB 풀이 필요합니다")?;
+            let encryptor = ctx
+                .encryptor
+                .ok_or("LS증권 스트림에 encryptor가 필요합니다")?;
             let token = load_ls_sec_token(pool, encryptor, credential_id).await?;
             let ls_stream = LsSecMarketStream::new(token);
             UnifiedMarketStream::new().with_kr_stream(ls_stream)
@@ -205,17 +216,19 @@ pub async fn get_or_create_market_stream(
         .await
         .map_err(|e| format!("MarketStream 시작 실패: {}", e))?;
 
-    // 4. Aggregator 연결 (stream → WebSocket 브로드캐스트)
+    // 4. Aggregator/시세 캐시 연결 (stream → WebSocket 브로드캐스트, 시세 캐시)
     let stream = Arc::new(RwLock::new(stream));
 
-    if let Some(subs) = subscriptions {
-        let subs = subs.clone();
+    if ctx.subscriptions.is_some() || ctx.quote_cache.is_some() {
+        let aggregator = ctx
+            .subscriptions
+            .map(|subs| MarketDataAggregator::new(subs.clone()));
+        let quote_cache = ctx.quote_cache.cloned();
         let stream_for_aggregator = stream.clone();
         let cred_id = credential_id;
         let ex_id = exchange_id.to_string();
         tokio::spawn(async move {
             info!(credential_id = %cred_id, exchange_id = %ex_id, "MarketStream aggregator bridge 시작");
-            let aggregator = MarketDataAggregator::new(subs);
             loop {
                 let event = {
                     let mut stream = stream_for_aggregator.write().await;
@@ -223,10 +236,20 @@ pub async fn get_or_create_market_stream(
                 };
                 match event {
                     Some(event) => {
-                        aggregator.handle_event(event);
+                        if let Some(cache) = &quote_cache {
+                            cache.apply_event(&event).await;
+                        }
+                        if let Some(aggregator) = &aggregator {
+                            aggregator.handle_event(event);
+                        }
                     }
                     None => {
                         warn!(credential_id = %cred_id, exchange_id = %ex_id, "MarketStream 이벤트 스트림 종료");
+                        if let Some(cache) = &quote_cache {
+                            cache
+                                .apply_event(&trader_exchange::traits::MarketEvent::Disconnected)
+                                .await;
+                        }
                         break;
                     }
                 }
@@ -242,7 +265,7 @@ pub async fn get_or_create_market_stream(
     });
 
     // 6. 캐시에 저장
-    market_streams
+    ctx.market_streams
         .write()
         .await
         .insert(credential_id, handle.clone());
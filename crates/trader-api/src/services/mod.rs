@@ -2,14 +2,33 @@
 //!
 //! 전략 실행, 컨텍스트 동기화 등 백그라운드에서 실행되는 서비스들을 제공합니다.
 
+pub mod backtest_jobs;
 pub mod context_sync;
+pub mod correlation_refresh;
 pub mod market_stream;
+pub mod performance_alert;
+pub mod position_reconcile;
+pub mod runtime_settings;
 pub mod signal_alert;
 pub mod signal_processor;
 pub mod telegram_bot;
 
+pub use backtest_jobs::{
+    start_backtest_job_cleanup_service, BacktestJobConfig, BacktestJobError, BacktestJobProgress,
+    BacktestJobQueue, BacktestJobStatus, BacktestJobView, BacktestQueueStatus,
+};
 pub use context_sync::start_context_sync_service;
-pub use market_stream::{get_or_create_market_stream, MarketStreamHandle};
-pub use signal_alert::{SignalAlertFilter, SignalAlertService};
+pub use correlation_refresh::{start_correlation_refresh_service, CorrelationRefreshService};
+pub use market_stream::{get_or_create_market_stream, MarketStreamContext, MarketStreamHandle};
+pub use performance_alert::{
+    start_performance_alert_service, AlertComparison, PerformanceAlertCondition,
+    PerformanceAlertService, PerformanceMetric, StrategyPerformanceSnapshot,
+};
+pub use position_reconcile::{start_position_reconcile_service, PositionReconcileService};
+pub use runtime_settings::{RuntimeSettings, SettingError, SystemSettingView};
+pub use signal_alert::{
+    AlertThreshold, NamedAlertFilter, SignalAlertFilter, SignalAlertService,
+    DEFAULT_RULE_RELOAD_INTERVAL,
+};
 pub use signal_processor::{start_signal_processing_service, SignalProcessingService};
 pub use telegram_bot::ApiBotHandler;
@@ -0,0 +1,784 @@
+//! 전략 성과 알림 서비스.
+//!
+//! Paper Trading 세션의 실현/미실현 손익을 주기적으로 집계하여
+//! 전략별 성과 알림 규칙(목표 수익률 도달, 손실 경보, 낙폭, 거래 횟수)을 평가하고
+//! 조건을 만족하면 NotificationManager로 알림을 전송합니다.
+//!
+//! # 발동 정책
+//!
+//! - 규칙은 한 번 발동하면 `triggered_at`이 기록되고, 리셋 전까지 다시 발동하지 않습니다.
+//! - 발동 기록은 `UPDATE ... WHERE triggered_at IS NULL`로 원자적으로 처리되어
+//!   여러 인스턴스가 동시에 평가해도 알림은 한 번만 전송됩니다.
+//! - 평가 주기는 런타임 설정 `alert.performance_eval_interval_secs`를 매 주기마다 다시 읽습니다.
+//!
+//! # 성과 목표
+//!
+//! 전략 설정에 `performance_target`이 선언된 경우 하루 한 번(UTC 날짜 기준)
+//! 세션 수익률과 벤치마크 일봉 수익률로 목표를 평가합니다.
+//! 연속 미달 경고는 [`StrategyEngine::evaluate_performance_target`]이 남깁니다.
+
+use std::{collections::HashMap, fmt, str::FromStr, sync::Arc, time::Duration};
+
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use trader_exchange::provider::MockExchangeProvider;
+use trader_notification::{
+    Notification, NotificationEvent, NotificationManager, NotificationPriority,
+};
+use trader_strategy::{strategies::common::PerformanceTargetConfig, StrategyEngine};
+use uuid::Uuid;
+
+use crate::{
+    repository::{PerformanceAlertRepository, PerformanceAlertRule},
+    services::RuntimeSettings,
+};
+
+/// 성과 알림 평가 지표.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PerformanceMetric {
+    /// 누적 수익률 (%, 초기 자산 대비)
+    ReturnPct,
+    /// 누적 손익 금액 (실현 + 미실현)
+    TotalPnl,
+    /// 최고 자산 대비 낙폭 (%, 양수)
+    DrawdownPct,
+    /// 체결 횟수
+    TradeCount,
+}
+
+impl PerformanceMetric {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ReturnPct => "return_pct",
+            Self::TotalPnl => "total_pnl",
+            Self::DrawdownPct => "drawdown_pct",
+            Self::TradeCount => "trade_count",
+        }
+    }
+
+    /// 알림 메시지용 한글 이름.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::ReturnPct => "수익률(%)",
+            Self::TotalPnl => "누적 손익",
+            Self::DrawdownPct => "낙폭(%)",
+            Self::TradeCount => "거래 횟수",
+        }
+    }
+}
+
+impl fmt::Display for PerformanceMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PerformanceMetric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "return_pct" => Ok(Self::ReturnPct),
+            "total_pnl" => Ok(Self::TotalPnl),
+            "drawdown_pct" => Ok(Self::DrawdownPct),
+            "trade_count" => Ok(Self::TradeCount),
+            _ => Err(format!("Invalid performance metric: {}", s)),
+        }
+    }
+}
+
+/// 임계치 비교 방향.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertComparison {
+    /// 관측값 >= 임계치
+    Above,
+    /// 관측값 <= 임계치
+    Below,
+}
+
+impl AlertComparison {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Above => "above",
+            Self::Below => "below",
+        }
+    }
+
+    /// 관측값이 임계치 조건을 만족하는지 확인.
+    pub fn matches(&self, value: Decimal, threshold: Decimal) -> bool {
+        match self {
+            Self::Above => value >= threshold,
+            Self::Below => value <= threshold,
+        }
+    }
+}
+
+impl fmt::Display for AlertComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AlertComparison {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "above" => Ok(Self::Above),
+            "below" => Ok(Self::Below),
+            _ => Err(format!("Invalid alert comparison: {}", s)),
+        }
+    }
+}
+
+/// 성과 알림 조건 (지표 + 비교 방향 + 임계치).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PerformanceAlertCondition {
+    /// 평가 지표
+    pub metric: PerformanceMetric,
+    /// 비교 방향
+    pub comparison: AlertComparison,
+    /// 임계치
+    pub threshold: Decimal,
+}
+
+impl PerformanceAlertCondition {
+    /// 스냅샷이 조건을 만족하면 관측값을 반환합니다.
+    pub fn evaluate(&self, snapshot: &StrategyPerformanceSnapshot) -> Option<Decimal> {
+        let value = snapshot.value(self.metric);
+        self.comparison
+            .matches(value, self.threshold)
+            .then_some(value)
+    }
+
+    /// 손실 경보 성격의 조건인지 여부 (알림 우선순위 결정용).
+    pub fn is_loss_alert(&self) -> bool {
+        match self.metric {
+            PerformanceMetric::ReturnPct | PerformanceMetric::TotalPnl => {
+                self.comparison == AlertComparison::Below
+            }
+            PerformanceMetric::DrawdownPct => self.comparison == AlertComparison::Above,
+            PerformanceMetric::TradeCount => false,
+        }
+    }
+}
+
+/// 전략 성과 스냅샷.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategyPerformanceSnapshot {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 초기 자산
+    pub initial_balance: Decimal,
+    /// 현재 평가 자산 (현금 + 미실현 손익)
+    pub equity: Decimal,
+    /// 관측된 최고 평가 자산
+    pub peak_equity: Decimal,
+    /// 체결 횟수
+    pub trade_count: i64,
+}
+
+impl StrategyPerformanceSnapshot {
+    /// 누적 손익 금액.
+    pub fn total_pnl(&self) -> Decimal {
+        self.equity - self.initial_balance
+    }
+
+    /// 누적 수익률 (%).
+    pub fn return_pct(&self) -> Decimal {
+        if self.initial_balance > Decimal::ZERO {
+            (self.total_pnl() / self.initial_balance * Decimal::from(100)).round_dp(4)
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    /// 최고 자산 대비 낙폭 (%, 양수).
+    pub fn drawdown_pct(&self) -> Decimal {
+        if self.peak_equity > Decimal::ZERO && self.equity < self.peak_equity {
+            ((self.peak_equity - self.equity) / self.peak_equity * Decimal::from(100)).round_dp(4)
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    /// 지표 값 조회.
+    pub fn value(&self, metric: PerformanceMetric) -> Decimal {
+        match metric {
+            PerformanceMetric::ReturnPct => self.return_pct(),
+            PerformanceMetric::TotalPnl => self.total_pnl(),
+            PerformanceMetric::DrawdownPct => self.drawdown_pct(),
+            PerformanceMetric::TradeCount => Decimal::from(self.trade_count),
+        }
+    }
+}
+
+/// 전략별 최고 평가 자산 추적기.
+///
+/// 낙폭 계산을 위해 서비스 수명 동안 관측한 최고 자산을 메모리에 보관합니다.
+/// 서버 재시작 시 초기 자산과 현재 자산 중 큰 값에서 다시 시작합니다.
+#[derive(Debug, Default)]
+pub struct PerformanceTracker {
+    peaks: HashMap<String, Decimal>,
+}
+
+impl PerformanceTracker {
+    /// 새 추적기 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 현재 자산을 반영하고 최고 자산을 반환합니다.
+    pub fn observe(
+        &mut self,
+        strategy_id: &str,
+        initial_balance: Decimal,
+        equity: Decimal,
+    ) -> Decimal {
+        let peak = self
+            .peaks
+            .entry(strategy_id.to_string())
+            .or_insert_with(|| initial_balance.max(equity));
+        if equity > *peak {
+            *peak = equity;
+        }
+        *peak
+    }
+}
+
+/// 발동 대상 규칙과 관측값.
+#[derive(Debug, Clone)]
+pub struct TriggeredRule<'a> {
+    /// 발동한 규칙
+    pub rule: &'a PerformanceAlertRule,
+    /// 규칙 조건
+    pub condition: PerformanceAlertCondition,
+    /// 관측값
+    pub observed_value: Decimal,
+}
+
+/// 스냅샷에 대해 규칙을 평가합니다.
+///
+/// 비활성화되었거나 이미 발동한 규칙, 다른 전략의 규칙, 조건을 해석할 수 없는 규칙은 건너뜁니다.
+pub fn evaluate_rules<'a>(
+    rules: &'a [PerformanceAlertRule],
+    snapshot: &StrategyPerformanceSnapshot,
+) -> Vec<TriggeredRule<'a>> {
+    rules
+        .iter()
+        .filter(|rule| {
+            rule.enabled && rule.triggered_at.is_none() && rule.strategy_id == snapshot.strategy_id
+        })
+        .filter_map(|rule| {
+            let condition = rule.condition().ok()?;
+            let observed_value = condition.evaluate(snapshot)?;
+            Some(TriggeredRule {
+                rule,
+                condition,
+                observed_value,
+            })
+        })
+        .collect()
+}
+
+/// 알림 메시지 생성.
+pub fn format_alert_message(
+    rule: &PerformanceAlertRule,
+    condition: &PerformanceAlertCondition,
+    observed_value: Decimal,
+) -> String {
+    let direction = match condition.comparison {
+        AlertComparison::Above => "이상",
+        AlertComparison::Below => "이하",
+    };
+    format!(
+        "[{}] {}: {} {} {} (현재 {})",
+        rule.strategy_id,
+        rule.rule_name,
+        condition.metric.label(),
+        condition.threshold.normalize(),
+        direction,
+        observed_value.normalize()
+    )
+}
+
+/// 시작 종가 대비 최신 종가 수익률 (%).
+///
+/// 두 종가 중 하나라도 없거나 시작 종가가 0 이하이면 `None`을 반환합니다.
+pub fn benchmark_return_pct(
+    first_close: Option<Decimal>,
+    last_close: Option<Decimal>,
+) -> Option<Decimal> {
+    match (first_close, last_close) {
+        (Some(first), Some(last)) if first > Decimal::ZERO => {
+            Some(((last - first) / first * Decimal::from(100)).round_dp(4))
+        }
+        _ => None,
+    }
+}
+
+/// 전략 성과 알림 서비스.
+pub struct PerformanceAlertService {
+    repo: PerformanceAlertRepository,
+    pool: PgPool,
+    mock_providers: Arc<RwLock<HashMap<Uuid, Arc<MockExchangeProvider>>>>,
+    notification_manager: Option<Arc<NotificationManager>>,
+    runtime_settings: Arc<RuntimeSettings>,
+    strategy_engine: Arc<RwLock<StrategyEngine>>,
+    tracker: PerformanceTracker,
+    last_target_date: Option<NaiveDate>,
+}
+
+impl PerformanceAlertService {
+    /// 새 서비스 인스턴스 생성.
+    pub fn new(
+        pool: PgPool,
+        mock_providers: Arc<RwLock<HashMap<Uuid, Arc<MockExchangeProvider>>>>,
+        notification_manager: Option<Arc<NotificationManager>>,
+        runtime_settings: Arc<RuntimeSettings>,
+        strategy_engine: Arc<RwLock<StrategyEngine>>,
+    ) -> Self {
+        Self {
+            repo: PerformanceAlertRepository::new(pool.clone()),
+            pool,
+            mock_providers,
+            notification_manager,
+            runtime_settings,
+            strategy_engine,
+            tracker: PerformanceTracker::new(),
+            last_target_date: None,
+        }
+    }
+
+    /// 서비스 시작 (메인 루프).
+    pub async fn run(mut self, shutdown: CancellationToken) {
+        loop {
+            let interval =
+                Duration::from_secs(self.runtime_settings.performance_alert_interval_secs());
+
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {
+                    match self.evaluate_once().await {
+                        Ok(0) => {}
+                        Ok(count) => tracing::info!("성과 알림 {}건 발동", count),
+                        Err(e) => tracing::warn!("성과 알림 평가 실패: {}", e),
+                    }
+
+                    let today = Utc::now().date_naive();
+                    if self.last_target_date != Some(today) {
+                        self.last_target_date = Some(today);
+                        self.evaluate_targets().await;
+                    }
+                }
+
+                _ = shutdown.cancelled() => {
+                    tracing::info!("PerformanceAlertService 종료");
+                    break;
+                }
+            }
+        }
+    }
+
+    /// 대기 중인 규칙을 한 번 평가하고 발동한 알림 수를 반환합니다.
+    pub async fn evaluate_once(&mut self) -> Result<usize, String> {
+        let rules = self
+            .repo
+            .list_pending()
+            .await
+            .map_err(|e| format!("규칙 조회: {:?}", e))?;
+        if rules.is_empty() {
+            return Ok(0);
+        }
+
+        let mut strategy_ids: Vec<&str> = rules.iter().map(|r| r.strategy_id.as_str()).collect();
+        strategy_ids.sort_unstable();
+        strategy_ids.dedup();
+
+        let mut fired = 0;
+        for strategy_id in strategy_ids {
+            let snapshot = match self.load_snapshot(strategy_id).await {
+                Ok(Some(snapshot)) => snapshot,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::warn!(strategy_id, "성과 스냅샷 조회 실패: {}", e);
+                    continue;
+                }
+            };
+
+            for triggered in evaluate_rules(&rules, &snapshot) {
+                if self.fire(&triggered).await? {
+                    fired += 1;
+                }
+            }
+        }
+
+        Ok(fired)
+    }
+
+    /// 성과 목표가 선언된 전략을 평가하고 평가한 전략 수를 반환합니다.
+    ///
+    /// Paper Trading 세션이 없는 전략은 건너뛰며,
+    /// 벤치마크 일봉이 없으면 절대수익 기준으로 평가합니다.
+    pub async fn evaluate_targets(&mut self) -> usize {
+        let targets: Vec<(String, PerformanceTargetConfig)> = {
+            let engine = self.strategy_engine.read().await;
+            let mut targets = Vec::new();
+            for id in engine.list_strategies().await {
+                let Ok(config) = engine.get_strategy_config(&id).await else {
+                    continue;
+                };
+                if let Some(target) = PerformanceTargetConfig::from_strategy_config(&config) {
+                    targets.push((id, target));
+                }
+            }
+            targets
+        };
+
+        let mut evaluated = 0;
+        for (strategy_id, target) in targets {
+            let snapshot = match self.load_snapshot(&strategy_id).await {
+                Ok(Some(snapshot)) => snapshot,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::warn!(strategy_id, "성과 스냅샷 조회 실패: {}", e);
+                    continue;
+                }
+            };
+
+            let benchmark_return = match target.benchmark.as_deref() {
+                Some(symbol) => self
+                    .load_benchmark_return(&strategy_id, symbol)
+                    .await
+                    .unwrap_or_else(|e| {
+                        tracing::warn!(strategy_id, symbol, "벤치마크 수익률 조회 실패: {}", e);
+                        None
+                    }),
+                None => None,
+            };
+
+            let result = self
+                .strategy_engine
+                .read()
+                .await
+                .evaluate_performance_target(&strategy_id, snapshot.return_pct(), benchmark_return)
+                .await;
+            match result {
+                Ok(Some(evaluation)) => {
+                    tracing::debug!(strategy_id, summary = %evaluation.summary(), "성과 목표 평가");
+                    evaluated += 1;
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!(strategy_id, "성과 목표 평가 실패: {}", e),
+            }
+        }
+
+        evaluated
+    }
+
+    /// 세션 시작 이후 벤치마크 일봉 수익률 조회.
+    async fn load_benchmark_return(
+        &self,
+        strategy_id: &str,
+        symbol: &str,
+    ) -> Result<Option<Decimal>, sqlx::Error> {
+        let closes: Option<(Option<Decimal>, Option<Decimal>)> = sqlx::query_as(
+            r#"
+            SELECT
+                (SELECT o.close FROM ohlcv o
+                 WHERE o.symbol = $2 AND o.timeframe = '1d'
+                   AND o.open_time >= COALESCE(pts.started_at, pts.created_at)
+                 ORDER BY o.open_time ASC LIMIT 1),
+                (SELECT o.close FROM ohlcv o
+                 WHERE o.symbol = $2 AND o.timeframe = '1d'
+                 ORDER BY o.open_time DESC LIMIT 1)
+            FROM paper_trading_sessions pts
+            WHERE pts.strategy_id = $1
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(symbol)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(closes.and_then(|(first, last)| benchmark_return_pct(first, last)))
+    }
+
+    /// 규칙 발동 처리 (발동 기록 → 이력 저장 → 알림 전송).
+    async fn fire(&self, triggered: &TriggeredRule<'_>) -> Result<bool, String> {
+        let rule = triggered.rule;
+
+        // 다른 인스턴스가 먼저 발동시킨 경우 건너뜀
+        let marked = self
+            .repo
+            .mark_triggered(rule.id)
+            .await
+            .map_err(|e| format!("발동 기록: {:?}", e))?;
+        if !marked {
+            return Ok(false);
+        }
+
+        let message = format_alert_message(rule, &triggered.condition, triggered.observed_value);
+
+        if let Err(e) = self
+            .repo
+            .insert_history(rule, triggered.observed_value, &message)
+            .await
+        {
+            tracing::warn!(rule_id = %rule.id, "성과 알림 이력 저장 실패: {:?}", e);
+        }
+
+        if let Some(manager) = &self.notification_manager {
+            let priority = if triggered.condition.is_loss_alert() {
+                NotificationPriority::High
+            } else {
+                NotificationPriority::Normal
+            };
+            let notification = Notification::new(NotificationEvent::RiskAlert {
+                alert_type: format!("strategy_performance:{}", triggered.condition.metric),
+                message: message.clone(),
+                current_value: triggered.observed_value,
+                threshold: triggered.condition.threshold,
+            })
+            .with_priority(priority);
+
+            if let Err(e) = manager.notify(&notification).await {
+                tracing::warn!(rule_id = %rule.id, "성과 알림 전송 실패: {}", e);
+            }
+        }
+
+        tracing::info!(rule_id = %rule.id, "{}", message);
+        Ok(true)
+    }
+
+    /// 전략의 현재 성과 스냅샷 조회.
+    ///
+    /// Paper Trading 세션이 없는 전략은 `None`을 반환합니다.
+    async fn load_snapshot(
+        &mut self,
+        strategy_id: &str,
+    ) -> Result<Option<StrategyPerformanceSnapshot>, sqlx::Error> {
+        let session: Option<(Uuid, Decimal, Decimal, i64)> = sqlx::query_as(
+            r#"
+            SELECT
+                pts.credential_id,
+                pts.initial_balance,
+                pts.current_balance,
+                (SELECT COUNT(*) FROM mock_executions me WHERE me.strategy_id = pts.strategy_id)
+            FROM paper_trading_sessions pts
+            WHERE pts.strategy_id = $1
+            "#,
+        )
+        .bind(strategy_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some((credential_id, initial_balance, current_balance, trade_count)) = session else {
+            return Ok(None);
+        };
+
+        let positions: Vec<(String, String, Decimal, Decimal)> = sqlx::query_as(
+            "SELECT symbol, side, quantity, entry_price FROM mock_positions WHERE strategy_id = $1",
+        )
+        .bind(strategy_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        // 미실현 손익 (실시간 시세, 없으면 진입가)
+        let mut unrealized_pnl = Decimal::ZERO;
+        {
+            let providers = self.mock_providers.read().await;
+            let provider = providers.get(&credential_id);
+            for (symbol, side, quantity, entry_price) in &positions {
+                let price = match provider {
+                    Some(provider) => provider
+                        .get_latest_ticker(symbol)
+                        .await
+                        .map(|t| t.last)
+                        .unwrap_or(*entry_price),
+                    None => *entry_price,
+                };
+                let diff = if side.eq_ignore_ascii_case("sell") {
+                    *entry_price - price
+                } else {
+                    price - *entry_price
+                };
+                unrealized_pnl += diff * *quantity;
+            }
+        }
+
+        let equity = current_balance + unrealized_pnl;
+        let peak_equity = self.tracker.observe(strategy_id, initial_balance, equity);
+
+        Ok(Some(StrategyPerformanceSnapshot {
+            strategy_id: strategy_id.to_string(),
+            initial_balance,
+            equity,
+            peak_equity,
+            trade_count,
+        }))
+    }
+}
+
+/// 성과 알림 서비스를 백그라운드 태스크로 시작합니다.
+pub fn start_performance_alert_service(
+    pool: PgPool,
+    mock_providers: Arc<RwLock<HashMap<Uuid, Arc<MockExchangeProvider>>>>,
+    notification_manager: Option<Arc<NotificationManager>>,
+    runtime_settings: Arc<RuntimeSettings>,
+    strategy_engine: Arc<RwLock<StrategyEngine>>,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let service = PerformanceAlertService::new(
+        pool,
+        mock_providers,
+        notification_manager,
+        runtime_settings,
+        strategy_engine,
+    );
+
+    tokio::spawn(async move {
+        service.run(shutdown).await;
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn rule(
+        metric: PerformanceMetric,
+        comparison: AlertComparison,
+        threshold: Decimal,
+    ) -> PerformanceAlertRule {
+        PerformanceAlertRule {
+            id: Uuid::new_v4(),
+            strategy_id: "rsi_1".to_string(),
+            rule_name: format!("{}_{}", metric, comparison),
+            metric: metric.as_str().to_string(),
+            comparison: comparison.as_str().to_string(),
+            threshold,
+            enabled: true,
+            triggered_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn snapshot(
+        equity: Decimal,
+        peak_equity: Decimal,
+        trade_count: i64,
+    ) -> StrategyPerformanceSnapshot {
+        StrategyPerformanceSnapshot {
+            strategy_id: "rsi_1".to_string(),
+            initial_balance: dec!(10000000),
+            equity,
+            peak_equity,
+            trade_count,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_metrics() {
+        let snap = snapshot(dec!(9000000), dec!(12000000), 7);
+
+        assert_eq!(snap.total_pnl(), dec!(-1000000));
+        assert_eq!(snap.return_pct(), dec!(-10));
+        assert_eq!(snap.drawdown_pct(), dec!(25));
+        assert_eq!(snap.value(PerformanceMetric::TradeCount), dec!(7));
+    }
+
+    #[test]
+    fn test_evaluate_rules_skips_triggered_and_disabled() {
+        let mut rules = vec![
+            rule(
+                PerformanceMetric::ReturnPct,
+                AlertComparison::Above,
+                dec!(5),
+            ),
+            rule(
+                PerformanceMetric::ReturnPct,
+                AlertComparison::Below,
+                dec!(-5),
+            ),
+            rule(
+                PerformanceMetric::DrawdownPct,
+                AlertComparison::Above,
+                dec!(20),
+            ),
+            rule(
+                PerformanceMetric::TradeCount,
+                AlertComparison::Above,
+                dec!(5),
+            ),
+            rule(
+                PerformanceMetric::TotalPnl,
+                AlertComparison::Below,
+                dec!(-500000),
+            ),
+        ];
+        rules[3].triggered_at = Some(Utc::now());
+        rules[4].enabled = false;
+
+        let snap = snapshot(dec!(9000000), dec!(12000000), 7);
+        let triggered = evaluate_rules(&rules, &snap);
+
+        // 손실 경보와 낙폭만 발동 (목표 수익률 미도달, 거래 횟수는 이미 발동, 손익 규칙은 비활성)
+        let metrics: Vec<_> = triggered.iter().map(|t| t.condition.metric).collect();
+        assert_eq!(
+            metrics,
+            vec![PerformanceMetric::ReturnPct, PerformanceMetric::DrawdownPct]
+        );
+        assert_eq!(triggered[0].observed_value, dec!(-10));
+        assert!(triggered.iter().all(|t| t.condition.is_loss_alert()));
+    }
+
+    #[test]
+    fn test_tracker_keeps_peak_equity() {
+        let mut tracker = PerformanceTracker::new();
+
+        assert_eq!(tracker.observe("a", dec!(1000), dec!(900)), dec!(1000));
+        assert_eq!(tracker.observe("a", dec!(1000), dec!(1200)), dec!(1200));
+        assert_eq!(tracker.observe("a", dec!(1000), dec!(1100)), dec!(1200));
+        assert_eq!(tracker.observe("b", dec!(1000), dec!(1100)), dec!(1100));
+    }
+
+    #[test]
+    fn test_benchmark_return_pct() {
+        assert_eq!(
+            benchmark_return_pct(Some(dec!(400)), Some(dec!(420))),
+            Some(dec!(5))
+        );
+        assert_eq!(
+            benchmark_return_pct(Some(dec!(400)), Some(dec!(380))),
+            Some(dec!(-5))
+        );
+        // 일봉 결측 → 절대수익 폴백
+        assert_eq!(benchmark_return_pct(None, Some(dec!(420))), None);
+        assert_eq!(benchmark_return_pct(Some(dec!(0)), Some(dec!(420))), None);
+    }
+
+    #[test]
+    fn test_metric_round_trip() {
+        for metric in [
+            PerformanceMetric::ReturnPct,
+            PerformanceMetric::TotalPnl,
+            PerformanceMetric::DrawdownPct,
+            PerformanceMetric::TradeCount,
+        ] {
+            assert_eq!(metric.as_str().parse::<PerformanceMetric>(), Ok(metric));
+        }
+        assert!("sharpe".parse::<PerformanceMetric>().is_err());
+    }
+}
@@ -0,0 +1,102 @@
+//! 포지션 리컨실리에이션 서비스.
+//!
+//! 주기적으로 거래소 포지션을 조회하여 실행기 `PositionTracker`의 내부 상태와
+//! 비교합니다. 수동 거래나 누락된 체결 이벤트로 생긴 드리프트를 감지하며,
+//! `reconciliation.auto_correct` 설정이 켜져 있으면 거래소 상태로 보정합니다.
+//!
+//! - 주기와 보정 여부는 [`RuntimeSettings`]에서 매 주기마다 다시 읽습니다.
+//! - 거래소 조회 중에는 트래커 락을 잡지 않습니다.
+
+use std::{sync::Arc, time::Duration};
+
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use trader_core::ExchangeProvider;
+use trader_execution::{OrderExecutor, ReconciliationReport};
+
+use super::RuntimeSettings;
+
+/// 포지션 리컨실리에이션 서비스.
+pub struct PositionReconcileService {
+    exchange_provider: Arc<dyn ExchangeProvider>,
+    executor: Arc<RwLock<OrderExecutor>>,
+    runtime_settings: Arc<RuntimeSettings>,
+}
+
+impl PositionReconcileService {
+    /// 새 서비스 인스턴스 생성.
+    pub fn new(
+        exchange_provider: Arc<dyn ExchangeProvider>,
+        executor: Arc<RwLock<OrderExecutor>>,
+        runtime_settings: Arc<RuntimeSettings>,
+    ) -> Self {
+        Self {
+            exchange_provider,
+            executor,
+            runtime_settings,
+        }
+    }
+
+    /// 서비스 시작 (메인 루프).
+    ///
+    /// 시작 직후 한 번 실행하고, 이후 설정된 주기마다 실행합니다.
+    pub async fn run(self, shutdown: CancellationToken) {
+        loop {
+            match self.reconcile_once().await {
+                Ok(report) if report.discrepancies.is_empty() => {}
+                Ok(report) => tracing::warn!(
+                    "포지션 불일치 {}건 발견 (보정 {}건)",
+                    report.discrepancies.len(),
+                    report.corrected_count()
+                ),
+                Err(e) => tracing::warn!("포지션 리컨실리에이션 실패: {}", e),
+            }
+
+            let interval =
+                Duration::from_secs(self.runtime_settings.reconciliation_config().interval_secs);
+
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+
+                _ = shutdown.cancelled() => {
+                    tracing::info!("PositionReconcileService 종료");
+                    break;
+                }
+            }
+        }
+    }
+
+    /// 리컨실리에이션을 한 번 실행하고 결과를 반환합니다.
+    ///
+    /// 거래소 조회 실패도 트래커의 마지막 결과로 기록됩니다.
+    pub async fn reconcile_once(&self) -> Result<ReconciliationReport, String> {
+        let fetched = self.exchange_provider.fetch_positions().await;
+
+        let executor = self.executor.read().await;
+        let mut tracker = executor.position_tracker().write().await;
+        tracker.set_reconciliation_config(self.runtime_settings.reconciliation_config());
+
+        match fetched {
+            Ok(positions) => Ok(tracker.reconcile_positions(&positions)),
+            Err(e) => {
+                let message = format!("포지션 조회: {}", e);
+                tracker.record_reconciliation_error(message.clone());
+                Err(message)
+            }
+        }
+    }
+}
+
+/// 포지션 리컨실리에이션 서비스 시작.
+pub fn start_position_reconcile_service(
+    exchange_provider: Arc<dyn ExchangeProvider>,
+    executor: Arc<RwLock<OrderExecutor>>,
+    runtime_settings: Arc<RuntimeSettings>,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let service = PositionReconcileService::new(exchange_provider, executor, runtime_settings);
+
+    tokio::spawn(async move {
+        service.run(shutdown).await;
+    })
+}
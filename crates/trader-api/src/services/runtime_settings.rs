@@ -0,0 +1,726 @@
+//! 런타임 시스템 설정 레지스트리.
+//!
+//! 서버 재시작 없이 변경 가능한 파라미터(Rate Limit, 알림 임계값, 수집 주기)와
+//! 변경 불가능한 보안 관련 설정을 구분하여 관리합니다.
+//!
+//! # 설정 구분
+//!
+//! - **변경 가능**: 타입과 범위 검증 후 즉시 적용, DB(`app_settings`)에 저장되어 재시작 후에도 유지
+//! - **읽기 전용**: 환경변수에서만 설정 가능, 민감 정보는 마스킹하여 노출
+//!
+//! # 적용 대상
+//!
+//! - `rate_limit.*`: 연결된 [`RateLimiter`]에 즉시 반영
+//! - `alert.min_signal_strength`: 신호 알림 전송 필터에 반영
+//! - `alert.performance_eval_interval_secs`: 전략 성과 알림 평가 주기, 다음 주기부터 반영
+//! - `collector.interval_minutes`: DB에 저장되며 collector 데몬이 다음 주기에 반영
+//! - `reconciliation.*`: 포지션 리컨실리에이션 주기와 자동 보정 여부, 다음 주기부터 반영
+
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
+
+use serde::Serialize;
+use serde_json::Value;
+use tracing::{error, info, warn};
+use trader_execution::{ReconciliationConfig, ReconciliationMode};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::middleware::{RateLimitConfig, RateLimiter};
+
+/// 분당 최대 요청 수 설정 키.
+pub const RATE_LIMIT_RPM: &str = "rate_limit.requests_per_minute";
+/// Rate Limit 버스트 허용량 설정 키.
+pub const RATE_LIMIT_BURST: &str = "rate_limit.burst_size";
+/// 신호 알림 최소 강도 설정 키.
+pub const ALERT_MIN_SIGNAL_STRENGTH: &str = "alert.min_signal_strength";
+/// 전략 성과 알림 평가 주기(초) 설정 키.
+pub const ALERT_PERFORMANCE_EVAL_INTERVAL_SECS: &str = "alert.performance_eval_interval_secs";
+/// 데이터 수집 주기(분) 설정 키.
+pub const COLLECTOR_INTERVAL_MINUTES: &str = "collector.interval_minutes";
+/// 포지션 리컨실리에이션 주기(초) 설정 키.
+pub const RECONCILIATION_INTERVAL_SECS: &str = "reconciliation.interval_secs";
+/// 포지션 리컨실리에이션 자동 보정 여부(0/1) 설정 키.
+pub const RECONCILIATION_AUTO_CORRECT: &str = "reconciliation.auto_correct";
+
+/// 민감 정보 마스킹 문자열.
+const MASKED_VALUE: &str = "********";
+
+/// 설정 값 종류 및 제약 조건.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingKind {
+    /// 정수 (범위 포함)
+    Integer { min: i64, max: i64, default: i64 },
+    /// 실수 (범위 포함)
+    Float { min: f64, max: f64, default: f64 },
+    /// 읽기 전용 (환경변수에서만 설정)
+    ReadOnly {
+        env_var: &'static str,
+        sensitive: bool,
+    },
+}
+
+/// 시스템 설정 정의.
+#[derive(Debug, Clone, Copy)]
+pub struct SettingDefinition {
+    /// 설정 키
+    pub key: &'static str,
+    /// 설명
+    pub description: &'static str,
+    /// 값 종류 및 제약 조건
+    pub kind: SettingKind,
+}
+
+impl SettingDefinition {
+    /// 런타임 변경 가능 여부.
+    pub fn is_mutable(&self) -> bool {
+        !matches!(self.kind, SettingKind::ReadOnly { .. })
+    }
+
+    /// 기본값 (읽기 전용 설정은 None).
+    pub fn default_value(&self) -> Option<Value> {
+        match self.kind {
+            SettingKind::Integer { default, .. } => Some(Value::from(default)),
+            SettingKind::Float { default, .. } => Some(Value::from(default)),
+            SettingKind::ReadOnly { .. } => None,
+        }
+    }
+
+    /// 값 검증 및 정규화.
+    ///
+    /// 정수 설정에 `1200.0`처럼 소수부가 없는 실수가 들어오면 정수로 변환합니다.
+    pub fn validate(&self, value: &Value) -> Result<Value, SettingError> {
+        match self.kind {
+            SettingKind::Integer { min, max, .. } => {
+                let n = value
+                    .as_i64()
+                    .or_else(|| {
+                        value
+                            .as_f64()
+                            .filter(|f| f.fract() == 0.0 && f.abs() < i64::MAX as f64)
+                            .map(|f| f as i64)
+                    })
+                    .ok_or_else(|| SettingError::InvalidType {
+                        key: self.key.to_string(),
+                        expected: "integer",
+                    })?;
+                if n < min || n > max {
+                    return Err(SettingError::OutOfRange {
+                        key: self.key.to_string(),
+                        min: min as f64,
+                        max: max as f64,
+                    });
+                }
+                Ok(Value::from(n))
+            }
+            SettingKind::Float { min, max, .. } => {
+                let f = value.as_f64().filter(|f| f.is_finite()).ok_or_else(|| {
+                    SettingError::InvalidType {
+                        key: self.key.to_string(),
+                        expected: "number",
+                    }
+                })?;
+                if f < min || f > max {
+                    return Err(SettingError::OutOfRange {
+                        key: self.key.to_string(),
+                        min,
+                        max,
+                    });
+                }
+                Ok(Value::from(f))
+            }
+            SettingKind::ReadOnly { .. } => Err(SettingError::ReadOnly(self.key.to_string())),
+        }
+    }
+}
+
+/// 지원하는 시스템 설정 목록.
+pub const SETTING_DEFINITIONS: &[SettingDefinition] = &[
+    SettingDefinition {
+        key: RATE_LIMIT_RPM,
+        description: "IP별 분당 최대 API 요청 수",
+        kind: SettingKind::Integer {
+            min: 10,
+            max: 100_000,
+            default: 1200,
+        },
+    },
+    SettingDefinition {
+        key: RATE_LIMIT_BURST,
+        description: "Rate Limit 순간 버스트 허용량",
+        kind: SettingKind::Integer {
+            min: 0,
+            max: 10_000,
+            default: 120,
+        },
+    },
+    SettingDefinition {
+        key: ALERT_MIN_SIGNAL_STRENGTH,
+        description: "신호 알림을 전송할 최소 신호 강도 (0.0 ~ 1.0)",
+        kind: SettingKind::Float {
+            min: 0.0,
+            max: 1.0,
+            default: 0.7,
+        },
+    },
+    SettingDefinition {
+        key: ALERT_PERFORMANCE_EVAL_INTERVAL_SECS,
+        description: "전략 성과 알림 규칙 평가 주기 (초)",
+        kind: SettingKind::Integer {
+            min: 5,
+            max: 3600,
+            default: 60,
+        },
+    },
+    SettingDefinition {
+        key: COLLECTOR_INTERVAL_MINUTES,
+        description: "데이터 수집 데몬 실행 주기 (분)",
+        kind: SettingKind::Integer {
+            min: 1,
+            max: 1440,
+            default: 60,
+        },
+    },
+    SettingDefinition {
+        key: RECONCILIATION_INTERVAL_SECS,
+        description: "거래소 포지션 리컨실리에이션 주기 (초)",
+        kind: SettingKind::Integer {
+            min: 30,
+            max: 86_400,
+            default: 300,
+        },
+    },
+    SettingDefinition {
+        key: RECONCILIATION_AUTO_CORRECT,
+        description: "리컨실리에이션 불일치 자동 보정 (0: 보고만, 1: 거래소 기준 보정)",
+        kind: SettingKind::Integer {
+            min: 0,
+            max: 1,
+            default: 0,
+        },
+    },
+    SettingDefinition {
+        key: "auth.jwt_secret",
+        description: "JWT 서명 키",
+        kind: SettingKind::ReadOnly {
+            env_var: "JWT_SECRET",
+            sensitive: true,
+        },
+    },
+    SettingDefinition {
+        key: "security.encryption_master_key",
+        description: "자격증명 암호화 마스터 키",
+        kind: SettingKind::ReadOnly {
+            env_var: "ENCRYPTION_MASTER_KEY",
+            sensitive: true,
+        },
+    },
+    SettingDefinition {
+        key: "database.url",
+        description: "데이터베이스 연결 문자열",
+        kind: SettingKind::ReadOnly {
+            env_var: "DATABASE_URL",
+            sensitive: true,
+        },
+    },
+    SettingDefinition {
+        key: "security.cors_origins",
+        description: "CORS 허용 origin 목록",
+        kind: SettingKind::ReadOnly {
+            env_var: "CORS_ORIGINS",
+            sensitive: false,
+        },
+    },
+    SettingDefinition {
+        key: "rate_limit.disabled",
+        description: "Rate Limit 비활성화 여부",
+        kind: SettingKind::ReadOnly {
+            env_var: "RATE_LIMIT_DISABLED",
+            sensitive: false,
+        },
+    },
+];
+
+/// 키로 설정 정의 조회.
+pub fn find_definition(key: &str) -> Option<&'static SettingDefinition> {
+    SETTING_DEFINITIONS.iter().find(|d| d.key == key)
+}
+
+/// 설정 검증 오류.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SettingError {
+    #[error("알 수 없는 설정입니다: {0}")]
+    Unknown(String),
+    #[error("변경할 수 없는 설정입니다: {0}")]
+    ReadOnly(String),
+    #[error("{key} 값은 {expected} 타입이어야 합니다")]
+    InvalidType { key: String, expected: &'static str },
+    #[error("{key} 값은 {min} ~ {max} 범위여야 합니다")]
+    OutOfRange { key: String, min: f64, max: f64 },
+}
+
+impl SettingError {
+    /// 오류 코드.
+    pub fn code(&self) -> &'static str {
+        match self {
+            SettingError::Unknown(_) => "UNKNOWN_SETTING",
+            SettingError::ReadOnly(_) => "SETTING_READ_ONLY",
+            SettingError::InvalidType { .. } => "INVALID_SETTING_TYPE",
+            SettingError::OutOfRange { .. } => "SETTING_OUT_OF_RANGE",
+        }
+    }
+}
+
+/// 설정 조회 결과 (API 응답용).
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "system/")]
+pub struct SystemSettingView {
+    /// 설정 키
+    pub key: String,
+    /// 현재 값 (민감 정보는 마스킹, 미설정 읽기 전용 값은 null)
+    #[ts(type = "unknown")]
+    pub value: Value,
+    /// 기본값 (읽기 전용 설정은 null)
+    #[ts(type = "unknown")]
+    pub default_value: Option<Value>,
+    /// 값 타입 (integer, number, string)
+    pub value_type: String,
+    /// 최소값
+    pub min: Option<f64>,
+    /// 최대값
+    pub max: Option<f64>,
+    /// 런타임 변경 가능 여부
+    pub mutable: bool,
+    /// 민감 정보 여부 (마스킹됨)
+    pub sensitive: bool,
+    /// 설명
+    pub description: String,
+}
+
+/// 런타임 시스템 설정 저장소.
+///
+/// 변경 가능한 설정의 현재 값을 보관하고, 변경 시 연결된 컴포넌트에 즉시 반영합니다.
+pub struct RuntimeSettings {
+    values: RwLock<HashMap<&'static str, Value>>,
+    rate_limiter: OnceLock<RateLimiter>,
+}
+
+impl Default for RuntimeSettings {
+    fn default() -> Self {
+        let values = SETTING_DEFINITIONS
+            .iter()
+            .filter_map(|d| d.default_value().map(|v| (d.key, v)))
+            .collect();
+
+        Self {
+            values: RwLock::new(values),
+            rate_limiter: OnceLock::new(),
+        }
+    }
+}
+
+impl RuntimeSettings {
+    /// 기본값으로 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 환경변수 초기값을 반영하여 생성.
+    ///
+    /// - `RATE_LIMIT_RPM`: 분당 요청 수 (버스트는 10%)
+    /// - `DAEMON_INTERVAL_MINUTES`: 수집 주기
+    /// - `RECONCILIATION_INTERVAL_SECS`, `RECONCILIATION_AUTO_CORRECT`: 포지션 리컨실리에이션
+    pub fn from_env() -> Self {
+        let settings = Self::new();
+
+        let env_overrides = [
+            ("RATE_LIMIT_RPM", RATE_LIMIT_RPM),
+            ("DAEMON_INTERVAL_MINUTES", COLLECTOR_INTERVAL_MINUTES),
+            ("RECONCILIATION_INTERVAL_SECS", RECONCILIATION_INTERVAL_SECS),
+            ("RECONCILIATION_AUTO_CORRECT", RECONCILIATION_AUTO_CORRECT),
+        ];
+        for (env_var, key) in env_overrides {
+            let Ok(raw) = std::env::var(env_var) else {
+                continue;
+            };
+            if let Err(e) = settings.load_raw(key, &raw) {
+                warn!(env_var, error = %e, "환경변수 설정값이 유효하지 않아 기본값 사용");
+                continue;
+            }
+            if key == RATE_LIMIT_RPM {
+                let burst = settings.get_i64(RATE_LIMIT_RPM) / 10;
+                if let Err(e) = settings.load_raw(RATE_LIMIT_BURST, &burst.to_string()) {
+                    warn!(error = %e, "버스트 허용량 계산값이 유효하지 않아 기본값 사용");
+                }
+            }
+        }
+
+        settings
+    }
+
+    /// 저장된 문자열 값을 검증 후 반영 (DB/환경변수 로드용).
+    pub fn load_raw(&self, key: &str, raw: &str) -> Result<(), SettingError> {
+        let definition = find_definition(key).ok_or_else(|| SettingError::Unknown(key.into()))?;
+        let parsed: Value =
+            serde_json::from_str(raw.trim()).map_err(|_| SettingError::InvalidType {
+                key: key.to_string(),
+                expected: match definition.kind {
+                    SettingKind::Integer { .. } => "integer",
+                    _ => "number",
+                },
+            })?;
+        let value = definition.validate(&parsed)?;
+        self.write_values().insert(definition.key, value);
+        Ok(())
+    }
+
+    /// DB에 저장된 값 일괄 반영.
+    ///
+    /// 유효하지 않은 값은 경고 후 무시하고, 반영된 설정 수를 반환합니다.
+    pub fn load_persisted(&self, rows: &[(String, String)]) -> usize {
+        let mut loaded = 0;
+        for (key, raw) in rows {
+            match self.load_raw(key, raw) {
+                Ok(()) => loaded += 1,
+                Err(e) => warn!(key = %key, error = %e, "저장된 런타임 설정 무시"),
+            }
+        }
+        loaded
+    }
+
+    /// Rate Limiter 연결.
+    ///
+    /// 이후 `rate_limit.*` 설정 변경이 해당 Limiter에 즉시 반영됩니다.
+    pub fn attach_rate_limiter(&self, limiter: RateLimiter) {
+        if self.rate_limiter.set(limiter).is_err() {
+            warn!("Rate Limiter가 이미 연결되어 있습니다");
+        }
+    }
+
+    /// 변경 요청 검증.
+    ///
+    /// 모든 항목을 검사하여 오류를 한 번에 반환하며, 하나라도 실패하면 적용하지 않습니다.
+    pub fn validate_changes(
+        &self,
+        changes: &HashMap<String, Value>,
+    ) -> Result<Vec<(&'static str, Value)>, Vec<SettingError>> {
+        let mut validated = Vec::with_capacity(changes.len());
+        let mut errors = Vec::new();
+
+        for (key, value) in changes {
+            let result = find_definition(key)
+                .ok_or_else(|| SettingError::Unknown(key.clone()))
+                .and_then(|d| d.validate(value).map(|v| (d.key, v)));
+            match result {
+                Ok(entry) => validated.push(entry),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        if errors.is_empty() {
+            validated.sort_by_key(|(key, _)| *key);
+            Ok(validated)
+        } else {
+            errors.sort_by_key(|e| e.to_string());
+            Err(errors)
+        }
+    }
+
+    /// 현재 값 조회 (변경 가능한 설정만).
+    pub fn get(&self, key: &str) -> Option<Value> {
+        self.read_values().get(key).cloned()
+    }
+
+    /// 검증된 값 적용 후 연결된 컴포넌트에 반영.
+    ///
+    /// 반환값은 값이 실제로 바뀐 항목의 (키, 이전 값, 새 값) 목록입니다.
+    pub async fn apply(
+        &self,
+        validated: Vec<(&'static str, Value)>,
+    ) -> Vec<(&'static str, Value, Value)> {
+        let changed: Vec<_> = {
+            let mut values = self.write_values();
+            validated
+                .into_iter()
+                .filter_map(|(key, new_value)| {
+                    let old_value = values.insert(key, new_value.clone()).unwrap_or(Value::Null);
+                    (old_value != new_value).then_some((key, old_value, new_value))
+                })
+                .collect()
+        };
+
+        if changed
+            .iter()
+            .any(|(key, _, _)| key.starts_with("rate_limit."))
+        {
+            if let Some(limiter) = self.rate_limiter.get() {
+                let config = self.rate_limit_config();
+                info!(
+                    requests_per_minute = config.requests_per_minute,
+                    burst_size = config.burst_size,
+                    "Rate Limit 설정 변경 적용"
+                );
+                limiter.update_config(config).await;
+            }
+        }
+
+        changed
+    }
+
+    /// 변경 전 값으로 되돌리기 (저장 실패 시 사용).
+    pub async fn revert(&self, changed: &[(&'static str, Value, Value)]) {
+        let restore = changed
+            .iter()
+            .map(|(key, old_value, _)| (*key, old_value.clone()))
+            .collect();
+        self.apply(restore).await;
+    }
+
+    /// 전체 설정 조회 (민감 정보 마스킹).
+    pub fn snapshot(&self) -> Vec<SystemSettingView> {
+        let values = self.read_values();
+        SETTING_DEFINITIONS
+            .iter()
+            .map(|d| {
+                let (value, value_type, min, max, sensitive) = match d.kind {
+                    SettingKind::Integer { min, max, .. } => (
+                        values.get(d.key).cloned().unwrap_or(Value::Null),
+                        "integer",
+                        Some(min as f64),
+                        Some(max as f64),
+                        false,
+                    ),
+                    SettingKind::Float { min, max, .. } => (
+                        values.get(d.key).cloned().unwrap_or(Value::Null),
+                        "number",
+                        Some(min),
+                        Some(max),
+                        false,
+                    ),
+                    SettingKind::ReadOnly { env_var, sensitive } => {
+                        let value = match std::env::var(env_var) {
+                            Ok(_) if sensitive => Value::from(MASKED_VALUE),
+                            Ok(v) => Value::from(v),
+                            Err(_) => Value::Null,
+                        };
+                        (value, "string", None, None, sensitive)
+                    }
+                };
+
+                SystemSettingView {
+                    key: d.key.to_string(),
+                    value,
+                    default_value: d.default_value(),
+                    value_type: value_type.to_string(),
+                    min,
+                    max,
+                    mutable: d.is_mutable(),
+                    sensitive,
+                    description: d.description.to_string(),
+                }
+            })
+            .collect()
+    }
+
+    /// 현재 Rate Limit 설정.
+    pub fn rate_limit_config(&self) -> RateLimitConfig {
+        RateLimitConfig {
+            requests_per_minute: u32::try_from(self.get_i64(RATE_LIMIT_RPM)).unwrap_or(u32::MAX),
+            burst_size: u32::try_from(self.get_i64(RATE_LIMIT_BURST)).unwrap_or(0),
+            ..RateLimitConfig::default()
+        }
+    }
+
+    /// 신호 알림 최소 강도.
+    pub fn alert_min_signal_strength(&self) -> f64 {
+        self.get(ALERT_MIN_SIGNAL_STRENGTH)
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.7)
+    }
+
+    /// 전략 성과 알림 평가 주기(초).
+    pub fn performance_alert_interval_secs(&self) -> u64 {
+        u64::try_from(self.get_i64(ALERT_PERFORMANCE_EVAL_INTERVAL_SECS))
+            .ok()
+            .filter(|secs| *secs > 0)
+            .unwrap_or(60)
+    }
+
+    /// 포지션 리컨실리에이션 설정.
+    pub fn reconciliation_config(&self) -> ReconciliationConfig {
+        let mode = if self.get_i64(RECONCILIATION_AUTO_CORRECT) == 1 {
+            ReconciliationMode::AutoCorrect
+        } else {
+            ReconciliationMode::ReportOnly
+        };
+        ReconciliationConfig {
+            mode,
+            interval_secs: u64::try_from(self.get_i64(RECONCILIATION_INTERVAL_SECS))
+                .ok()
+                .filter(|secs| *secs > 0)
+                .unwrap_or(300),
+            ..ReconciliationConfig::default()
+        }
+    }
+
+    /// 정수 설정 값 (없으면 0).
+    fn get_i64(&self, key: &str) -> i64 {
+        self.get(key).and_then(|v| v.as_i64()).unwrap_or(0)
+    }
+
+    fn read_values(&self) -> std::sync::RwLockReadGuard<'_, HashMap<&'static str, Value>> {
+        match self.values.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                error!("RuntimeSettings RwLock poisoned (read), recovering");
+                poisoned.into_inner()
+            }
+        }
+    }
+
+    fn write_values(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<&'static str, Value>> {
+        match self.values.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                error!("RuntimeSettings RwLock poisoned (write), recovering");
+                poisoned.into_inner()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use super::*;
+    use crate::middleware::RateLimitResult;
+
+    fn changes(entries: &[(&str, Value)]) -> HashMap<String, Value> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_values() {
+        let settings = RuntimeSettings::new();
+
+        let errors = settings
+            .validate_changes(&changes(&[
+                (RATE_LIMIT_RPM, Value::from(5)),
+                (ALERT_MIN_SIGNAL_STRENGTH, Value::from("high")),
+                ("auth.jwt_secret", Value::from("new-secret")),
+                ("unknown.key", Value::from(1)),
+            ]))
+            .unwrap_err();
+
+        let codes: Vec<_> = errors.iter().map(|e| e.code()).collect();
+        assert_eq!(errors.len(), 4);
+        assert!(codes.contains(&"SETTING_OUT_OF_RANGE"));
+        assert!(codes.contains(&"INVALID_SETTING_TYPE"));
+        assert!(codes.contains(&"SETTING_READ_ONLY"));
+        assert!(codes.contains(&"UNKNOWN_SETTING"));
+
+        // 하나라도 실패하면 아무것도 적용되지 않음
+        assert_eq!(settings.get(RATE_LIMIT_RPM), Some(Value::from(1200)));
+    }
+
+    #[test]
+    fn test_validate_normalizes_integer() {
+        let settings = RuntimeSettings::new();
+
+        let validated = settings
+            .validate_changes(&changes(&[(COLLECTOR_INTERVAL_MINUTES, Value::from(30.0))]))
+            .unwrap();
+        assert_eq!(
+            validated,
+            vec![(COLLECTOR_INTERVAL_MINUTES, Value::from(30))]
+        );
+
+        assert!(settings
+            .validate_changes(&changes(&[(COLLECTOR_INTERVAL_MINUTES, Value::from(30.5))]))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_apply_updates_rate_limiter_immediately() {
+        let settings = RuntimeSettings::new();
+        let limiter = RateLimiter::new(settings.rate_limit_config());
+        settings.attach_rate_limiter(limiter.clone());
+
+        let validated = settings
+            .validate_changes(&changes(&[
+                (RATE_LIMIT_RPM, Value::from(60)),
+                (RATE_LIMIT_BURST, Value::from(0)),
+                (ALERT_MIN_SIGNAL_STRENGTH, Value::from(0.7)),
+            ]))
+            .unwrap();
+        let changed = settings.apply(validated).await;
+
+        // 값이 바뀐 항목만 변경 내역에 포함
+        assert_eq!(changed.len(), 2);
+        assert_eq!(limiter.config().await.requests_per_minute, 60);
+
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+        assert!(matches!(
+            limiter.check(ip).await,
+            RateLimitResult::Allowed { .. }
+        ));
+        assert!(matches!(
+            limiter.check(ip).await,
+            RateLimitResult::Limited { .. }
+        ));
+
+        settings.revert(&changed).await;
+        assert_eq!(limiter.config().await.requests_per_minute, 1200);
+    }
+
+    #[test]
+    fn test_load_persisted_skips_invalid_rows() {
+        let settings = RuntimeSettings::new();
+
+        let loaded = settings.load_persisted(&[
+            (ALERT_MIN_SIGNAL_STRENGTH.to_string(), "0.85".to_string()),
+            (RATE_LIMIT_RPM.to_string(), "abc".to_string()),
+            ("removed.setting".to_string(), "1".to_string()),
+        ]);
+
+        assert_eq!(loaded, 1);
+        assert_eq!(settings.alert_min_signal_strength(), 0.85);
+        assert_eq!(settings.rate_limit_config().requests_per_minute, 1200);
+    }
+
+    #[test]
+    fn test_reconciliation_config_from_settings() {
+        let settings = RuntimeSettings::new();
+        let config = settings.reconciliation_config();
+        assert_eq!(config.mode, ReconciliationMode::ReportOnly);
+        assert_eq!(config.interval_secs, 300);
+
+        settings.load_persisted(&[
+            (RECONCILIATION_AUTO_CORRECT.to_string(), "1".to_string()),
+            (RECONCILIATION_INTERVAL_SECS.to_string(), "60".to_string()),
+        ]);
+        let config = settings.reconciliation_config();
+        assert_eq!(config.mode, ReconciliationMode::AutoCorrect);
+        assert_eq!(config.interval_secs, 60);
+    }
+
+    #[test]
+    fn test_snapshot_masks_sensitive_settings() {
+        let settings = RuntimeSettings::new();
+        let snapshot = settings.snapshot();
+
+        for view in snapshot.iter().filter(|v| v.sensitive) {
+            assert!(!view.mutable);
+            assert!(view.value.is_null() || view.value == MASKED_VALUE);
+        }
+        let rpm = snapshot.iter().find(|v| v.key == RATE_LIMIT_RPM).unwrap();
+        assert!(rpm.mutable);
+        assert_eq!(rpm.value, Value::from(1200));
+    }
+}
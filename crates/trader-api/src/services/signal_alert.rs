@@ -2,13 +2,111 @@
 //!
 //! 백테스트 및 실거래에서 발생한 신호 마커를 필터링하고
 //! 텔레그램 등 알림 채널로 전송합니다.
+//!
+//! # 알림 규칙
+//!
+//! `signal_alert_rule` 테이블의 활성 규칙(이름 + [`SignalAlertFilter`])을 OR로 평가합니다.
+//! 하나의 규칙 안에서는 심볼·전략·신호 유형·수치 조건([`AlertThreshold`])을 모두
+//! 만족해야 하며, 알림에는 매칭된 규칙 이름이 포함됩니다.
+//!
+//! ```json
+//! {
+//!   "signal_types": ["exit"],
+//!   "conditions": [{ "field": "return_pct", "operator": "gte", "value": 3.0 }]
+//! }
+//! ```
+//!
+//! 규칙은 [`SignalAlertService::spawn_rule_reloader`]가 주기적으로 다시 읽어
+//! 재시작 없이 반영됩니다.
+
+use std::{sync::Arc, time::Duration};
 
 use serde::{Deserialize, Serialize};
-use trader_core::SignalMarker;
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use trader_core::{ComparisonOperator, SignalMarker, SignalType};
 use trader_notification::{NotificationManager, NotificationResult};
 
+use crate::repository::SignalAlertRuleRepository;
+
+/// 규칙 다시 읽기 기본 주기.
+pub const DEFAULT_RULE_RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 수치 조건 (마커 필드 값과 비교).
+///
+/// `field` 조회 순서:
+/// - `price`: 신호 가격, `strength`: 신호 강도
+/// - `amount`: 메타데이터 `amount`, 없으면 가격 × 메타데이터 `quantity`
+/// - 그 외: 메타데이터(예: `return_pct`) → 지표(예: `rsi`) 순
+///
+/// 값을 찾을 수 없으면 조건을 만족하지 않은 것으로 봅니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct AlertThreshold {
+    /// 비교 대상 필드
+    pub field: String,
+    /// 비교 연산자 (eq, ne, gt, gte, lt, lte, between)
+    pub operator: ComparisonOperator,
+    /// 기준값
+    pub value: f64,
+    /// 상한값 (between 연산자용)
+    #[serde(default)]
+    pub upper_value: Option<f64>,
+}
+
+impl AlertThreshold {
+    /// 새 수치 조건 생성.
+    pub fn new(field: impl Into<String>, operator: ComparisonOperator, value: f64) -> Self {
+        Self {
+            field: field.into(),
+            operator,
+            value,
+            upper_value: None,
+        }
+    }
+
+    /// 마커가 조건을 만족하는지 확인.
+    pub fn matches(&self, marker: &SignalMarker) -> bool {
+        field_value(marker, &self.field).is_some_and(|current| {
+            self.operator
+                .evaluate(current, self.value, None, self.upper_value)
+        })
+    }
+}
+
+/// 마커에서 수치 필드 값 조회.
+fn field_value(marker: &SignalMarker, field: &str) -> Option<f64> {
+    let decimal = |d: rust_decimal::Decimal| d.to_string().parse::<f64>().ok();
+    let metadata = |key: &str| marker.metadata.get(key).and_then(json_number);
+
+    match field {
+        "price" => decimal(marker.price),
+        "strength" => Some(marker.strength),
+        "amount" => {
+            metadata("amount").or_else(|| Some(decimal(marker.price)? * metadata("quantity")?))
+        }
+        _ => metadata(field).or_else(|| {
+            serde_json::to_value(&marker.indicators)
+                .ok()?
+                .get(field)
+                .and_then(json_number)
+        }),
+    }
+}
+
+/// JSON 숫자 또는 숫자 문자열을 f64로 변환 (Decimal은 문자열로 직렬화됨).
+fn json_number(value: &JsonValue) -> Option<f64> {
+    match value {
+        JsonValue::Number(n) => n.as_f64(),
+        JsonValue::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
 /// 신호 알림 필터 조건.
-#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct SignalAlertFilter {
     /// 최소 신호 강도 (0.0 ~ 1.0)
     pub min_strength: Option<f64>,
@@ -17,7 +115,14 @@ pub struct SignalAlertFilter {
     /// 심볼 필터 (Some이면 특정 심볼만, None이면 모든 심볼)
     pub symbols: Option<Vec<String>>,
     /// 진입 신호만 (true면 Entry만, false면 모든 신호)
+    #[serde(default)]
     pub entry_only: bool,
+    /// 신호 유형 필터 (Some이면 해당 유형만, None이면 모든 유형)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_types: Option<Vec<SignalType>>,
+    /// 수치 조건 (모두 만족해야 함)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<AlertThreshold>,
 }
 
 impl Default for SignalAlertFilter {
@@ -27,6 +132,8 @@ impl Default for SignalAlertFilter {
             strategy_ids: None,
             symbols: None,
             entry_only: false,
+            signal_types: None,
+            conditions: Vec::new(),
         }
     }
 }
@@ -61,6 +168,43 @@ impl SignalAlertFilter {
         self
     }
 
+    /// 신호 유형 필터 설정.
+    pub fn with_signal_types(mut self, signal_types: Vec<SignalType>) -> Self {
+        self.signal_types = Some(signal_types);
+        self
+    }
+
+    /// 수치 조건 추가.
+    pub fn with_condition(mut self, condition: AlertThreshold) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// 규칙 JSON(`filter_conditions`)을 엄격하게 해석.
+    ///
+    /// 모르는 키가 있으면 다른 용도의 규칙(예: RouteState 알림)으로 보고 거부합니다.
+    /// 그렇지 않으면 조건이 비어 모든 신호에 매칭되는 규칙이 되기 때문입니다.
+    /// `description` 키는 설명용으로 허용합니다.
+    pub fn from_rule_json(value: &JsonValue) -> Result<Self, String> {
+        const KNOWN_KEYS: &[&str] = &[
+            "min_strength",
+            "strategy_ids",
+            "symbols",
+            "entry_only",
+            "signal_types",
+            "conditions",
+            "description",
+        ];
+
+        let object = value
+            .as_object()
+            .ok_or_else(|| "필터 조건은 JSON 객체여야 합니다".to_string())?;
+        if let Some(unknown) = object.keys().find(|k| !KNOWN_KEYS.contains(&k.as_str())) {
+            return Err(format!("신호 알림 필터가 아닌 키: {}", unknown));
+        }
+        serde_json::from_value(value.clone()).map_err(|e| e.to_string())
+    }
+
     /// 신호 마커가 필터 조건을 만족하는지 확인.
     pub fn matches(&self, marker: &SignalMarker) -> bool {
         // 최소 강도 확인
@@ -90,16 +234,46 @@ impl SignalAlertFilter {
             return false;
         }
 
-        true
+        // 신호 유형 필터 확인
+        if let Some(ref signal_types) = self.signal_types {
+            if !signal_types.contains(&marker.signal_type) {
+                return false;
+            }
+        }
+
+        // 수치 조건 확인
+        self.conditions.iter().all(|c| c.matches(marker))
+    }
+}
+
+/// 이름이 있는 알림 규칙.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct NamedAlertFilter {
+    /// 규칙 이름 (알림에 표시)
+    pub name: String,
+    /// 필터 조건
+    pub filter: SignalAlertFilter,
+}
+
+impl NamedAlertFilter {
+    /// 새 규칙 생성.
+    pub fn new(name: impl Into<String>, filter: SignalAlertFilter) -> Self {
+        Self {
+            name: name.into(),
+            filter,
+        }
     }
 }
 
 /// 신호 알림 서비스.
 ///
 /// SignalMarker를 받아서 필터링하고 알림을 전송합니다.
+/// 이름 규칙이 있으면 규칙을 OR로 평가하고, 없으면 기본 필터를 사용합니다.
 pub struct SignalAlertService {
     notification_manager: NotificationManager,
     filter: SignalAlertFilter,
+    /// 이름 규칙 (핫 리로드 대상)
+    rules: Arc<RwLock<Vec<NamedAlertFilter>>>,
 }
 
 impl SignalAlertService {
@@ -108,6 +282,7 @@ impl SignalAlertService {
         Self {
             notification_manager,
             filter: SignalAlertFilter::default(),
+            rules: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -117,22 +292,112 @@ impl SignalAlertService {
         self
     }
 
+    /// 이름 규칙 설정.
+    pub fn with_rules(self, rules: Vec<NamedAlertFilter>) -> Self {
+        Self {
+            rules: Arc::new(RwLock::new(rules)),
+            ..self
+        }
+    }
+
+    /// 이름 규칙 교체 (재시작 없이 반영).
+    ///
+    /// 규칙이 바뀌었으면 `true`를 반환합니다.
+    pub async fn reload_rules(&self, rules: Vec<NamedAlertFilter>) -> bool {
+        let mut current = self.rules.write().await;
+        if *current == rules {
+            return false;
+        }
+        *current = rules;
+        true
+    }
+
+    /// 현재 이름 규칙 목록.
+    pub async fn rules(&self) -> Vec<NamedAlertFilter> {
+        self.rules.read().await.clone()
+    }
+
+    /// 마커에 매칭되는 규칙 이름.
+    ///
+    /// - `None`: 알림 대상 아님
+    /// - `Some(빈 목록)`: 이름 규칙 없이 기본 필터로 매칭
+    /// - `Some(이름 목록)`: 매칭된 이름 규칙 (OR 평가)
+    pub async fn matched_rules(&self, marker: &SignalMarker) -> Option<Vec<String>> {
+        let rules = self.rules.read().await;
+        if rules.is_empty() {
+            return self.filter.matches(marker).then(Vec::new);
+        }
+
+        let matched: Vec<String> = rules
+            .iter()
+            .filter(|rule| rule.filter.matches(marker))
+            .map(|rule| rule.name.clone())
+            .collect();
+        (!matched.is_empty()).then_some(matched)
+    }
+
+    /// DB 규칙 주기적 다시 읽기 (핫 리로드).
+    ///
+    /// 시작 시 한 번 읽고, 이후 `interval`마다 활성 규칙을 조회해 바뀌었으면 교체합니다.
+    /// 조회에 실패하면 기존 규칙을 유지합니다.
+    pub fn spawn_rule_reloader(
+        &self,
+        pool: PgPool,
+        interval: Duration,
+        shutdown: CancellationToken,
+    ) -> tokio::task::JoinHandle<()> {
+        let rules = Arc::clone(&self.rules);
+        let repo = SignalAlertRuleRepository::new(pool);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {
+                        match repo.get_enabled_rules().await {
+                            Ok(loaded) => {
+                                let mut current = rules.write().await;
+                                if *current != loaded {
+                                    info!(rules = loaded.len(), "신호 알림 규칙 다시 읽음");
+                                    *current = loaded;
+                                }
+                            }
+                            Err(e) => warn!(error = ?e, "신호 알림 규칙 조회 실패 - 기존 규칙 유지"),
+                        }
+                    }
+                    _ = shutdown.cancelled() => break,
+                }
+            }
+        })
+    }
+
     /// 신호 마커 알림 전송.
     ///
-    /// 필터 조건을 만족하는 경우에만 알림을 전송합니다.
+    /// 필터 조건(또는 이름 규칙 중 하나)을 만족하는 경우에만 알림을 전송합니다.
     ///
     /// # 인자
     /// - `marker`: 신호 마커
     ///
     /// # 반환
     /// - `Ok(true)`: 알림 전송 성공
-    /// - `Ok(false)`: 필터 조건 불만족으로 알림 미전송
+    /// - `Ok(false)`: 필터 조건/규칙 불만족으로 알림 미전송
     /// - `Err`: 알림 전송 실패
     pub async fn notify_signal(&self, marker: &SignalMarker) -> NotificationResult<bool> {
-        // 필터 확인
-        if !self.filter.matches(marker) {
+        // 필터/규칙 확인
+        let Some(matched_rules) = self.matched_rules(marker).await else {
             return Ok(false);
-        }
+        };
+
+        // 어떤 규칙 때문에 받은 알림인지 표시
+        let reason = if matched_rules.is_empty() {
+            marker.reason.clone()
+        } else {
+            format!(
+                "{} [알림 규칙: {}]",
+                marker.reason,
+                matched_rules.join(", ")
+            )
+        };
 
         // 지표 정보를 JSON으로 변환
         let indicators =
@@ -149,7 +414,7 @@ impl SignalAlertService {
                 side_str.as_deref(),
                 marker.price,
                 marker.strength,
-                &marker.reason,
+                &reason,
                 &marker.strategy_name,
                 indicators,
             )
@@ -180,11 +445,9 @@ impl SignalAlertService {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use chrono::Utc;
     use rust_decimal_macros::dec;
-    use trader_core::SignalType;
-
-    use super::*;
 
     #[test]
     fn test_filter_min_strength() {
@@ -269,4 +532,107 @@ mod tests {
         assert!(filter.matches(&matching_marker));
         assert!(!filter.matches(&non_matching_marker));
     }
+
+    fn exit_marker(strategy_id: &str, return_pct: f64) -> SignalMarker {
+        SignalMarker::new(
+            "005930".to_string(),
+            Utc::now(),
+            SignalType::Exit,
+            dec!(70000),
+            strategy_id,
+            "Test Strategy",
+        )
+        .with_metadata("return_pct", serde_json::json!(return_pct))
+        .with_metadata("quantity", serde_json::json!("10"))
+    }
+
+    #[test]
+    fn test_filter_conditions() {
+        let filter = SignalAlertFilter::new()
+            .with_min_strength(0.0)
+            .with_signal_types(vec![SignalType::Exit])
+            .with_condition(AlertThreshold::new(
+                "return_pct",
+                ComparisonOperator::Gte,
+                3.0,
+            ));
+
+        assert!(filter.matches(&exit_marker("rsi", 4.5)));
+        assert!(!filter.matches(&exit_marker("rsi", 1.0)));
+
+        // 금액 = 가격 × 수량 (70000 × 10)
+        let amount = SignalAlertFilter::new()
+            .with_min_strength(0.0)
+            .with_condition(AlertThreshold::new(
+                "amount",
+                ComparisonOperator::Gt,
+                500_000.0,
+            ));
+        assert!(amount.matches(&exit_marker("rsi", 0.0)));
+
+        // 값이 없는 필드는 불만족
+        let missing = SignalAlertFilter::new()
+            .with_min_strength(0.0)
+            .with_condition(AlertThreshold::new("rsi", ComparisonOperator::Lt, 30.0));
+        assert!(!missing.matches(&exit_marker("rsi", 0.0)));
+    }
+
+    #[test]
+    fn test_from_rule_json() {
+        let filter = SignalAlertFilter::from_rule_json(&serde_json::json!({
+            "signal_types": ["exit"],
+            "conditions": [{ "field": "return_pct", "operator": "gte", "value": 3.0 }],
+            "description": "수익률 3% 이상 청산만"
+        }))
+        .unwrap();
+        assert_eq!(filter.signal_types, Some(vec![SignalType::Exit]));
+        assert_eq!(filter.min_strength, None);
+        assert!(!filter.entry_only);
+
+        // RouteState 알림 규칙은 신호 필터가 아님
+        assert!(SignalAlertFilter::from_rule_json(&serde_json::json!({
+            "route_state": "ATTACK",
+            "notify_on_state_change": true
+        }))
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rules_or_evaluation_and_reload() {
+        let profit_exit = NamedAlertFilter::new(
+            "profit_exit",
+            SignalAlertFilter::new()
+                .with_min_strength(0.0)
+                .with_signal_types(vec![SignalType::Exit])
+                .with_condition(AlertThreshold::new(
+                    "return_pct",
+                    ComparisonOperator::Gte,
+                    3.0,
+                )),
+        );
+        let rsi_only = NamedAlertFilter::new(
+            "rsi_only",
+            SignalAlertFilter::new()
+                .with_min_strength(0.0)
+                .with_strategies(vec!["rsi".to_string()]),
+        );
+
+        let service = SignalAlertService::new(NotificationManager::new())
+            .with_rules(vec![profit_exit.clone(), rsi_only]);
+
+        assert_eq!(
+            service.matched_rules(&exit_marker("rsi", 5.0)).await,
+            Some(vec!["profit_exit".to_string(), "rsi_only".to_string()])
+        );
+        assert_eq!(
+            service.matched_rules(&exit_marker("macd", 5.0)).await,
+            Some(vec!["profit_exit".to_string()])
+        );
+        assert_eq!(service.matched_rules(&exit_marker("macd", 1.0)).await, None);
+
+        // 규칙 교체 즉시 반영
+        assert!(service.reload_rules(vec![profit_exit.clone()]).await);
+        assert!(!service.reload_rules(vec![profit_exit]).await);
+        assert_eq!(service.matched_rules(&exit_marker("rsi", 1.0)).await, None);
+    }
 }
@@ -19,25 +19,85 @@
 //!
 //! - Mock 거래소: MockExchangeProvider.process_signal() 호출
 //! - 실제 거래소: (향후) KIS/Binance Provider의 주문 API 호출
+//!
+//! # 중복 제거
+//!
+//! 같은 Signal이 재전송되면 중복 주문이 나므로 [`Signal::dedup_id`]
+//! (전략 + 심볼 + 타임스탬프 해시)로 처리 여부를 판정합니다.
+//!
+//! 1. 최근 처리한 ID의 메모리 TTL 캐시 확인
+//! 2. `idempotency_keys` 테이블(`signal:{id}`)에 락 획득 시도 (다중 인스턴스 대비)
+//! 3. 처리 성공 시 완료 기록, 실패 시 락 해제 (같은 Signal 재시도 허용)
+//!
+//! 서비스 시작 시 DB의 완료 기록으로 캐시를 복원하므로 재시작 직후 재전송된 Signal도
+//! 무시됩니다. 중복은 디버그 로그만 남기고 `strategy_signals_received_total{status="duplicate"}`로
+//! 집계합니다.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use chrono::Utc;
 use sqlx::PgPool;
 use tokio::sync::{mpsc, RwLock};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
-use trader_core::Signal;
+use trader_core::{
+    idempotency::SIGNAL_SCOPE, IdempotencyKey, IdempotencyStatus, IdempotencyStore, Signal,
+};
+use trader_data::PgIdempotencyStore;
 use trader_exchange::provider::MockExchangeProvider;
 use uuid::Uuid;
 
-use crate::repository::create_mock_provider_concrete;
+use crate::{metrics::record_signal_received, repository::create_mock_provider_concrete};
+
+/// 처리한 Signal ID 기본 유지 시간 (이 기간 내 재수신은 중복으로 무시).
+pub const DEFAULT_SIGNAL_DEDUP_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// 처리 중 락 유지 시간 (처리 도중 프로세스가 죽으면 이후 재처리 허용).
+const SIGNAL_LOCK_TTL: Duration = Duration::from_secs(5 * 60);
 
 /// 거래소별 Provider 캐시.
 ///
 /// credential_id를 키로 사용하여 Provider 인스턴스를 캐싱합니다.
 type ProviderCache = HashMap<Uuid, Arc<RwLock<MockExchangeProvider>>>;
 
+/// 최근 처리한 Signal ID의 TTL 캐시.
+///
+/// 만료된 항목은 삽입 시 정리됩니다.
+#[derive(Debug)]
+struct RecentSignals {
+    /// dedup_id → 만료 시각
+    entries: HashMap<String, Instant>,
+}
+
+impl RecentSignals {
+    fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// 만료되지 않은 ID인지 확인.
+    fn contains(&self, id: &str, now: Instant) -> bool {
+        self.entries
+            .get(id)
+            .is_some_and(|expires_at| *expires_at > now)
+    }
+
+    /// ID를 `ttl` 동안 보관.
+    fn insert(&mut self, id: impl Into<String>, ttl: Duration, now: Instant) {
+        self.entries.retain(|_, expires_at| *expires_at > now);
+        self.entries.insert(id.into(), now + ttl);
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
 /// Signal 처리 서비스.
 ///
 /// 전략에서 생성된 Signal을 수신하여 해당 거래소로 라우팅합니다.
@@ -48,6 +108,14 @@ pub struct SignalProcessingService {
     db_pool: PgPool,
     /// Provider 캐시 (credential_id → MockExchangeProvider)
     provider_cache: Arc<RwLock<ProviderCache>>,
+    /// 처리 이력 저장소 (재시작 후 복원용)
+    dedup_store: PgIdempotencyStore,
+    /// 최근 처리한 Signal ID 캐시
+    recent_signals: RecentSignals,
+    /// 처리한 Signal ID 유지 시간
+    dedup_ttl: Duration,
+    /// 중복으로 무시한 Signal 수
+    duplicate_count: u64,
 }
 
 impl SignalProcessingService {
@@ -55,26 +123,35 @@ impl SignalProcessingService {
     pub fn new(signal_rx: mpsc::Receiver<Signal>, db_pool: PgPool) -> Self {
         Self {
             signal_rx,
+            dedup_store: PgIdempotencyStore::new(db_pool.clone()),
             db_pool,
             provider_cache: Arc::new(RwLock::new(HashMap::new())),
+            recent_signals: RecentSignals::new(),
+            dedup_ttl: DEFAULT_SIGNAL_DEDUP_TTL,
+            duplicate_count: 0,
         }
     }
 
+    /// 처리한 Signal ID 유지 시간 설정 (빌더 패턴).
+    pub fn with_dedup_ttl(mut self, ttl: Duration) -> Self {
+        self.dedup_ttl = ttl;
+        self
+    }
+
+    /// 중복으로 무시한 Signal 수.
+    pub fn duplicate_count(&self) -> u64 {
+        self.duplicate_count
+    }
+
     /// 서비스 시작.
     pub async fn run(mut self, shutdown: CancellationToken) {
         info!("SignalProcessingService 시작");
+        self.restore_recent_signals().await;
 
         loop {
             tokio::select! {
                 Some(signal) = self.signal_rx.recv() => {
-                    if let Err(e) = self.process_signal(&signal).await {
-                        error!(
-                            strategy_id = %signal.strategy_id,
-                            ticker = %signal.ticker,
-                            error = %e,
-                            "Signal 처리 실패"
-                        );
-                    }
+                    self.handle_signal(&signal).await;
                 }
 
                 _ = shutdown.cancelled() => {
@@ -85,6 +162,92 @@ impl SignalProcessingService {
         }
     }
 
+    /// DB의 완료 기록으로 최근 처리 Signal 캐시 복원.
+    ///
+    /// 실패해도 서비스는 계속 동작합니다 (DB 락 획득 단계에서 중복을 다시 판정).
+    async fn restore_recent_signals(&mut self) {
+        match self.dedup_store.load_completed(SIGNAL_SCOPE).await {
+            Ok(keys) => {
+                let now = Instant::now();
+                for (key, remaining) in keys {
+                    self.recent_signals.insert(key.id(), remaining, now);
+                }
+                info!(
+                    restored = self.recent_signals.len(),
+                    "최근 처리 Signal 이력 복원"
+                );
+            }
+            Err(e) => warn!(error = %e, "최근 처리 Signal 이력 복원 실패"),
+        }
+    }
+
+    /// 중복 판정 후 Signal 처리.
+    async fn handle_signal(&mut self, signal: &Signal) {
+        let key = IdempotencyKey::signal(signal.dedup_id());
+
+        if self.recent_signals.contains(key.id(), Instant::now()) {
+            self.record_duplicate(signal, &key);
+            return;
+        }
+
+        let acquired = match self.dedup_store.try_acquire(&key, SIGNAL_LOCK_TTL).await {
+            Ok(IdempotencyStatus::Acquired) => true,
+            Ok(IdempotencyStatus::Completed(_)) => {
+                self.recent_signals
+                    .insert(key.id(), self.dedup_ttl, Instant::now());
+                self.record_duplicate(signal, &key);
+                return;
+            }
+            Ok(IdempotencyStatus::InProgress) => {
+                self.record_duplicate(signal, &key);
+                return;
+            }
+            Err(e) => {
+                // 저장소 장애 시 메모리 캐시만으로 판정하고 처리 계속
+                warn!(error = %e, dedup_id = key.id(), "Signal 처리 이력 조회 실패");
+                false
+            }
+        };
+
+        match self.process_signal(signal).await {
+            Ok(()) => {
+                record_signal_received("processed");
+                self.recent_signals
+                    .insert(key.id(), self.dedup_ttl, Instant::now());
+                if let Err(e) = self.dedup_store.complete(&key, None, self.dedup_ttl).await {
+                    warn!(error = %e, dedup_id = key.id(), "Signal 처리 이력 기록 실패");
+                }
+            }
+            Err(e) => {
+                record_signal_received("failed");
+                error!(
+                    strategy_id = %signal.strategy_id,
+                    ticker = %signal.ticker,
+                    error = %e,
+                    "Signal 처리 실패"
+                );
+                if acquired {
+                    if let Err(e) = self.dedup_store.release(&key).await {
+                        warn!(error = %e, dedup_id = key.id(), "Signal 처리 락 해제 실패");
+                    }
+                }
+            }
+        }
+    }
+
+    /// 중복 Signal 기록 (카운터 + 디버그 로그).
+    fn record_duplicate(&mut self, signal: &Signal, key: &IdempotencyKey) {
+        self.duplicate_count += 1;
+        record_signal_received("duplicate");
+        debug!(
+            strategy_id = %signal.strategy_id,
+            ticker = %signal.ticker,
+            dedup_id = key.id(),
+            duplicates = self.duplicate_count,
+            "중복 Signal 무시"
+        );
+    }
+
     /// Signal 처리.
     ///
     /// 1. strategy_id에서 credential_id 조회
@@ -254,3 +417,42 @@ pub fn start_signal_processing_service(
         service.run(shutdown).await;
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_signals_ttl() {
+        let now = Instant::now();
+        let mut recent = RecentSignals::new();
+        recent.insert("a", Duration::from_secs(10), now);
+        recent.insert("b", Duration::from_secs(1), now);
+
+        assert!(recent.contains("a", now));
+        assert!(recent.contains("b", now));
+        assert!(!recent.contains("c", now));
+
+        // 만료 후에는 중복 아님, 만료 항목은 다음 삽입 시 정리
+        let later = now + Duration::from_secs(5);
+        assert!(recent.contains("a", later));
+        assert!(!recent.contains("b", later));
+        recent.insert("c", Duration::from_secs(10), later);
+        assert_eq!(recent.len(), 2);
+    }
+
+    #[test]
+    fn test_resent_signal_maps_to_same_key() {
+        let signal = Signal::entry("rsi_1", "005930".to_string(), trader_core::Side::Buy);
+        let mut resent = signal.clone();
+        resent.id = Uuid::new_v4();
+
+        let key = IdempotencyKey::signal(signal.dedup_id());
+        let mut recent = RecentSignals::new();
+        recent.insert(key.id(), DEFAULT_SIGNAL_DEDUP_TTL, Instant::now());
+
+        let resent_key = IdempotencyKey::signal(resent.dedup_id());
+        assert_eq!(key, resent_key);
+        assert!(recent.contains(resent_key.id(), Instant::now()));
+    }
+}
@@ -13,7 +13,9 @@ use trader_core::{
     StrategyContext,
 };
 use trader_data::{cache::CachedHistoricalDataProvider, RedisCache, RedisConfig, SymbolResolver};
-use trader_exchange::{connector::kis::KisOAuth, provider::MockExchangeProvider};
+use trader_exchange::{
+    connector::kis::KisOAuth, provider::MockExchangeProvider, CachedMarketDataProvider,
+};
 use trader_execution::OrderExecutor;
 use trader_notification::NotificationManager;
 use trader_risk::RiskManager;
@@ -22,7 +24,11 @@ use uuid::Uuid;
 
 use crate::{
     repository::ExchangeProviderArc,
-    services::{context_sync::start_context_sync_service, MarketStreamHandle},
+    repository::SystemSettingsRepository,
+    services::{
+        context_sync::start_context_sync_service, BacktestJobConfig, BacktestJobQueue,
+        MarketStreamHandle, RuntimeSettings,
+    },
     websocket::{ServerMessage, SharedSubscriptionManager},
 };
 
@@ -97,8 +103,14 @@ pub struct AppState {
     /// 시세 데이터 제공자 (MarketDataProvider)
     ///
     /// 현재 선택된 거래소를 통해 실시간 시세를 조회합니다.
+    /// 설정 시 [`CachedMarketDataProvider`]로 감싸져 스트림 시세를 우선 사용합니다.
     pub market_data_provider: Option<Arc<dyn MarketDataProvider>>,
 
+    /// 스트림 통합 시세 캐시 (`market_data_provider`와 같은 인스턴스).
+    ///
+    /// MarketStream bridge가 Ticker 이벤트를 반영합니다.
+    pub quote_cache: Option<Arc<CachedMarketDataProvider>>,
+
     /// 서버 시작 시간 (업타임 계산용)
     pub started_at: chrono::DateTime<chrono::Utc>,
 
@@ -123,6 +135,16 @@ pub struct AppState {
     /// 동일 계좌의 여러 전략이 하나의 WebSocket 스트림을 공유합니다.
     /// `get_or_create_market_stream()`으로 생성/조회합니다.
     pub market_streams: Arc<RwLock<HashMap<Uuid, Arc<MarketStreamHandle>>>>,
+
+    /// 런타임 시스템 설정 (Rate Limit, 알림 임계값, 수집 주기).
+    ///
+    /// `/api/v1/system/settings`로 변경되며 재시작 없이 즉시 반영됩니다.
+    pub runtime_settings: Arc<RuntimeSettings>,
+
+    /// 백테스트 비동기 작업 큐.
+    ///
+    /// `/api/v1/backtests`로 제출된 작업의 진행률과 결과를 보관합니다.
+    pub backtest_jobs: Arc<BacktestJobQueue>,
 }
 
 impl AppState {
@@ -163,11 +185,14 @@ impl AppState {
             strategy_context: None,
             exchange_provider: None,
             market_data_provider: None,
+            quote_cache: None,
             started_at: chrono::Utc::now(),
             version: env!("CARGO_PKG_VERSION").to_string(),
             notification_manager: None,
             mock_providers: Arc::new(RwLock::new(HashMap::new())),
             market_streams: Arc::new(RwLock::new(HashMap::new())),
+            runtime_settings: Arc::new(RuntimeSettings::from_env()),
+            backtest_jobs: Arc::new(BacktestJobQueue::new(BacktestJobConfig::from_env())),
         }
     }
 
@@ -192,6 +217,23 @@ impl AppState {
         self
     }
 
+    /// DB에 저장된 런타임 설정 로드.
+    ///
+    /// 환경변수 초기값보다 DB 값이 우선하며, 유효하지 않은 값은 무시됩니다.
+    pub async fn load_runtime_settings(&self) {
+        let Some(pool) = &self.db_pool else {
+            return;
+        };
+
+        match SystemSettingsRepository::load_all(pool).await {
+            Ok(rows) => {
+                let loaded = self.runtime_settings.load_persisted(&rows);
+                tracing::info!(loaded, "런타임 설정 로드 완료");
+            }
+            Err(e) => tracing::warn!(error = %e, "런타임 설정 로드 실패, 기본값 사용"),
+        }
+    }
+
     /// CachedHistoricalDataProvider 설정.
     ///
     /// 캔들 데이터를 캐싱하여 제공합니다.
@@ -261,8 +303,11 @@ impl AppState {
     /// MarketDataProvider 설정.
     ///
     /// 시세 데이터 제공자를 설정합니다 (예: KisExchangeProvider, MockExchangeProvider).
+    /// 제공자는 스트림 통합 시세 캐시로 감싸집니다.
     pub fn with_market_data_provider(mut self, provider: Arc<dyn MarketDataProvider>) -> Self {
-        self.market_data_provider = Some(provider);
+        let cache = Arc::new(CachedMarketDataProvider::new(provider));
+        self.market_data_provider = Some(cache.clone());
+        self.quote_cache = Some(cache);
         self
     }
 
@@ -295,6 +340,7 @@ impl AppState {
             exchange_provider,
             analytics_provider,
             strategy_context,
+            self.subscriptions.clone(),
             shutdown,
         ))
     }
@@ -329,6 +375,86 @@ impl AppState {
         ))
     }
 
+    /// 리스크 상관계수 갱신 서비스 시작.
+    ///
+    /// 보유 포지션과 전략 종목의 최근 일봉 상관계수를 주기적으로 계산하여
+    /// API용/실행기용 RiskManager의 상관 노출 한도 테이블을 갱신합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 data_provider가 설정되지 않은 것입니다.
+    pub async fn start_correlation_refresh(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let data_provider = self.data_provider.clone()?;
+        let executor_risk_manager = self.executor.read().await.risk_manager().clone();
+
+        Some(crate::services::start_correlation_refresh_service(
+            data_provider,
+            self.strategy_engine.clone(),
+            self.executor.clone(),
+            vec![self.risk_manager.clone(), executor_risk_manager],
+            shutdown,
+        ))
+    }
+
+    /// 포지션 리컨실리에이션 서비스 시작.
+    ///
+    /// 거래소 포지션과 실행기 PositionTracker의 상태를 주기적으로 비교하여
+    /// 불일치를 보고하거나 (설정 시) 거래소 기준으로 보정합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 exchange_provider가 설정되지 않은 것입니다.
+    pub fn start_position_reconciliation(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let exchange_provider = self.exchange_provider.clone()?;
+
+        Some(crate::services::start_position_reconcile_service(
+            exchange_provider,
+            self.executor.clone(),
+            self.runtime_settings.clone(),
+            shutdown,
+        ))
+    }
+
+    /// 백테스트 작업 결과 정리 서비스 시작.
+    ///
+    /// 보관 시간이 지난 종료 작업을 주기적으로 삭제합니다.
+    pub fn start_backtest_job_cleanup(
+        &self,
+        shutdown: CancellationToken,
+    ) -> tokio::task::JoinHandle<()> {
+        crate::services::start_backtest_job_cleanup_service(self.backtest_jobs.clone(), shutdown)
+    }
+
+    /// 전략 성과 알림 서비스 시작.
+    ///
+    /// Paper Trading 세션의 손익을 주기적으로 집계하여 성과 알림 규칙을 평가합니다.
+    /// 알림 전송에는 설정된 NotificationManager를 사용하며, 없으면 발동 이력만 기록합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 DB가 설정되지 않은 것입니다.
+    pub fn start_performance_alerts(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let db_pool = self.db_pool.clone()?;
+
+        Some(crate::services::start_performance_alert_service(
+            db_pool,
+            self.mock_providers.clone(),
+            self.notification_manager.clone(),
+            self.runtime_settings.clone(),
+            self.strategy_engine.clone(),
+            shutdown,
+        ))
+    }
+
     /// Signal 충돌 브로드캐스트 서비스 시작.
     ///
     /// StrategyEngine에서 발생한 SignalConflictEvent를 WebSocket으로 브로드캐스트합니다.
@@ -377,6 +503,7 @@ impl AppState {
                         conflict_type: event.conflict_type,
                         message: event.message,
                         timestamp: event.timestamp.timestamp_millis(),
+                        resolution: event.resolution,
                     };
 
                     // WebSocket 브로드캐스트
@@ -79,6 +79,12 @@ impl MarketDataAggregator {
             MarketEvent::Disconnected => {
                 warn!("거래소 연결 끊김");
             }
+            MarketEvent::ConnectionStatus { connected: true } => {
+                info!("거래소 재연결됨");
+            }
+            MarketEvent::ConnectionStatus { connected: false } => {
+                warn!("거래소 연결 끊김, 재연결 대기 중");
+            }
             MarketEvent::Error(msg) => {
                 error!("거래소 에러: {}", msg);
             }
@@ -0,0 +1,370 @@
+//! WebSocket 구독 권한 검증 및 인증 감사 로그.
+//!
+//! 주문/포지션/계정/전략 채널 구독 시 세션의 JWT 클레임으로 역할 권한을 확인하고,
+//! 특정 리소스(전략/계정) 채널은 소유자인지까지 확인합니다.
+//! 인증 실패/권한 거부/토큰 만료 이벤트는 `audit_logs` 테이블에 기록합니다.
+
+use std::collections::HashSet;
+
+use serde_json::json;
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+
+use super::{messages::ServerMessage, subscriptions::Subscription};
+use crate::auth::{Claims, Role};
+
+/// 감사 로그 엔티티 타입.
+const AUDIT_ENTITY_TYPE: &str = "websocket_session";
+
+/// 구독 거부 사유.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubscriptionDenied {
+    /// 인증되지 않은 세션
+    Unauthenticated,
+    /// 인증 토큰 만료
+    TokenExpired,
+    /// 역할에 필요한 권한 없음 또는 소유하지 않은 리소스
+    Forbidden,
+}
+
+impl SubscriptionDenied {
+    /// 클라이언트에 전달할 에러 코드.
+    pub fn code(&self) -> &'static str {
+        match self {
+            SubscriptionDenied::Unauthenticated => "UNAUTHENTICATED",
+            SubscriptionDenied::TokenExpired => "TOKEN_EXPIRED",
+            SubscriptionDenied::Forbidden => "FORBIDDEN",
+        }
+    }
+
+    /// 사람이 읽을 수 있는 거부 사유.
+    pub fn message(&self) -> &'static str {
+        match self {
+            SubscriptionDenied::Unauthenticated => "이 채널을 구독하려면 인증이 필요합니다",
+            SubscriptionDenied::TokenExpired => "인증 토큰이 만료되었습니다",
+            SubscriptionDenied::Forbidden => "이 채널에 대한 접근 권한이 없습니다",
+        }
+    }
+}
+
+/// 사용자가 소유한 리소스 ID 목록.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OwnedResources {
+    /// 소유한 전략 ID
+    pub strategies: HashSet<String>,
+    /// 소유한 거래소 계정(credential) ID
+    pub credentials: HashSet<String>,
+}
+
+/// 세션이 접근할 수 있는 리소스 범위.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceScope {
+    /// 모든 리소스 (관리자)
+    All,
+    /// 소유한 리소스만
+    Owned(OwnedResources),
+}
+
+impl Default for ResourceScope {
+    fn default() -> Self {
+        ResourceScope::Owned(OwnedResources::default())
+    }
+}
+
+impl ResourceScope {
+    /// 클레임 역할에 맞는 범위 생성.
+    ///
+    /// 관리자는 소유 여부와 관계없이 모든 리소스에 접근합니다.
+    pub fn for_claims(claims: &Claims, owned: OwnedResources) -> Self {
+        if claims.role == Role::Admin {
+            ResourceScope::All
+        } else {
+            ResourceScope::Owned(owned)
+        }
+    }
+
+    /// 전략 접근 가능 여부.
+    pub fn owns_strategy(&self, strategy_id: &str) -> bool {
+        match self {
+            ResourceScope::All => true,
+            ResourceScope::Owned(owned) => owned.strategies.contains(strategy_id),
+        }
+    }
+
+    /// 거래소 계정 접근 가능 여부.
+    pub fn owns_credential(&self, credential_id: &str) -> bool {
+        match self {
+            ResourceScope::All => true,
+            ResourceScope::Owned(owned) => owned.credentials.contains(credential_id),
+        }
+    }
+
+    /// 리소스를 지정한 메시지를 이 범위에서 받을 수 있는지 확인.
+    ///
+    /// 전략/계정 ID를 담은 메시지는 소유한 리소스일 때만 전달하고,
+    /// 리소스를 지정하지 않은 메시지는 채널 권한 검증만으로 충분합니다.
+    pub fn allows(&self, message: &ServerMessage) -> bool {
+        match message {
+            ServerMessage::StrategyUpdate(data) => self.owns_strategy(&data.strategy_id),
+            ServerMessage::SignalConflict(data) => self.owns_strategy(&data.strategy_id),
+            ServerMessage::ActiveAccountChanged(data) => match data.credential_id.as_deref() {
+                Some(id) => self.owns_credential(id),
+                None => true,
+            },
+            _ => true,
+        }
+    }
+}
+
+/// 사용자가 소유한 전략/계정 ID 조회.
+///
+/// `owner_id`가 사용자와 일치하는 리소스만 반환합니다.
+/// 사용자 ID가 UUID가 아니면 소유한 리소스가 없는 것으로 봅니다.
+pub async fn load_owned_resources(
+    pool: &PgPool,
+    user_id: &str,
+) -> Result<OwnedResources, sqlx::Error> {
+    let Ok(owner_id) = Uuid::parse_str(user_id) else {
+        return Ok(OwnedResources::default());
+    };
+
+    let strategies: Vec<String> =
+        sqlx::query_scalar("SELECT id FROM strategies WHERE owner_id = $1")
+            .bind(owner_id)
+            .fetch_all(pool)
+            .await?;
+    let credentials: Vec<Uuid> =
+        sqlx::query_scalar("SELECT id FROM exchange_credentials WHERE owner_id = $1")
+            .bind(owner_id)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(OwnedResources {
+        strategies: strategies.into_iter().collect(),
+        credentials: credentials.iter().map(Uuid::to_string).collect(),
+    })
+}
+
+/// 구독 권한 검증.
+///
+/// 공개 채널은 항상 허용하고, 권한이 필요한 채널은 만료되지 않은 클레임의
+/// 역할이 해당 권한을 가질 때만 허용합니다. 특정 전략/계정 채널은
+/// `scope`에 포함된 리소스일 때만 허용합니다.
+pub fn authorize_subscription(
+    claims: Option<&Claims>,
+    scope: &ResourceScope,
+    subscription: &Subscription,
+    now: i64,
+) -> Result<(), SubscriptionDenied> {
+    let Some(permission) = subscription.required_permission() else {
+        return Ok(());
+    };
+    let claims = claims.ok_or(SubscriptionDenied::Unauthenticated)?;
+    if claims.exp <= now {
+        return Err(SubscriptionDenied::TokenExpired);
+    }
+    if !claims.has_permission(permission) {
+        return Err(SubscriptionDenied::Forbidden);
+    }
+    let owned = match subscription {
+        Subscription::Strategy(id) => scope.owns_strategy(id),
+        Subscription::AccountCredential(id) => scope.owns_credential(id),
+        _ => true,
+    };
+    if !owned {
+        return Err(SubscriptionDenied::Forbidden);
+    }
+    Ok(())
+}
+
+/// WebSocket 인증 감사 이벤트.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsAuditEvent {
+    /// 토큰 검증 실패
+    AuthFailed,
+    /// 구독 권한 거부
+    SubscriptionDenied,
+    /// 연결 중 토큰 만료로 세션 종료
+    TokenExpired,
+}
+
+impl WsAuditEvent {
+    /// `audit_logs.event_type` 값.
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            WsAuditEvent::AuthFailed => "ws_auth_failed",
+            WsAuditEvent::SubscriptionDenied => "ws_subscription_denied",
+            WsAuditEvent::TokenExpired => "ws_token_expired",
+        }
+    }
+}
+
+/// 인증 감사 이벤트 기록.
+///
+/// 항상 `audit` 타겟으로 로그를 남기고, DB 풀이 있으면 `audit_logs`에도
+/// 비동기로 저장합니다. 저장 실패는 연결 처리에 영향을 주지 않습니다.
+pub fn record_audit_event(
+    pool: Option<&PgPool>,
+    event: WsAuditEvent,
+    session_id: &str,
+    user_id: Option<&str>,
+    details: serde_json::Value,
+) {
+    warn!(
+        target: "audit",
+        event = event.event_type(),
+        session_id = %session_id,
+        user_id = user_id.unwrap_or("-"),
+        details = %details,
+        "WebSocket 인증 이벤트"
+    );
+
+    let Some(pool) = pool.cloned() else {
+        return;
+    };
+    let user_id = user_id.map(str::to_string);
+    let details = json!({ "session_id": session_id, "details": details });
+
+    tokio::spawn(async move {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO audit_logs (event_type, entity_type, user_id, details)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(event.event_type())
+        .bind(AUDIT_ENTITY_TYPE)
+        .bind(user_id)
+        .bind(details)
+        .execute(&pool)
+        .await;
+
+        if let Err(e) = result {
+            warn!(error = %e, "WebSocket 감사 로그 저장 실패");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::Role;
+
+    fn claims(role: Role, exp: i64) -> Claims {
+        Claims {
+            sub: "user-1".to_string(),
+            username: "tester".to_string(),
+            role,
+            iat: 0,
+            exp,
+            jti: None,
+        }
+    }
+
+    fn owned_scope(strategies: &[&str], credentials: &[&str]) -> ResourceScope {
+        ResourceScope::Owned(OwnedResources {
+            strategies: strategies.iter().map(|s| s.to_string()).collect(),
+            credentials: credentials.iter().map(|s| s.to_string()).collect(),
+        })
+    }
+
+    #[test]
+    fn test_public_channel_needs_no_auth() {
+        let scope = ResourceScope::default();
+        let market = Subscription::Market("BTC-USDT".to_string());
+        assert_eq!(authorize_subscription(None, &scope, &market, 100), Ok(()));
+        assert_eq!(
+            authorize_subscription(None, &scope, &Subscription::Context, 100),
+            Ok(())
+        );
+
+        // 주문/포지션/계정/전략 채널은 인증 없이 구독할 수 없음
+        for private in [
+            Subscription::Orders,
+            Subscription::Positions,
+            Subscription::Account,
+            Subscription::Strategies,
+        ] {
+            assert_eq!(
+                authorize_subscription(None, &scope, &private, 100),
+                Err(SubscriptionDenied::Unauthenticated),
+                "{:?}",
+                private
+            );
+        }
+    }
+
+    #[test]
+    fn test_resource_channel_requires_valid_claims() {
+        let strategy = Subscription::Strategy("rsi-1".to_string());
+        let scope = owned_scope(&["rsi-1"], &[]);
+
+        assert_eq!(
+            authorize_subscription(None, &scope, &strategy, 100),
+            Err(SubscriptionDenied::Unauthenticated)
+        );
+        assert_eq!(
+            authorize_subscription(Some(&claims(Role::Viewer, 50)), &scope, &strategy, 100),
+            Err(SubscriptionDenied::TokenExpired)
+        );
+        assert_eq!(
+            authorize_subscription(Some(&claims(Role::Viewer, 200)), &scope, &strategy, 100),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_resource_channel_requires_ownership() {
+        let viewer = claims(Role::Viewer, 200);
+        let scope = owned_scope(&["rsi-1"], &["cred-1"]);
+
+        let other_strategy = Subscription::Strategy("grid-1".to_string());
+        let other_account = Subscription::AccountCredential("cred-2".to_string());
+        assert_eq!(
+            authorize_subscription(Some(&viewer), &scope, &other_strategy, 100),
+            Err(SubscriptionDenied::Forbidden)
+        );
+        assert_eq!(
+            authorize_subscription(Some(&viewer), &scope, &other_account, 100),
+            Err(SubscriptionDenied::Forbidden)
+        );
+        assert_eq!(
+            authorize_subscription(
+                Some(&viewer),
+                &scope,
+                &Subscription::AccountCredential("cred-1".to_string()),
+                100
+            ),
+            Ok(())
+        );
+
+        // 관리자는 소유 여부와 관계없이 허용
+        let admin = claims(Role::Admin, 200);
+        let admin_scope = ResourceScope::for_claims(&admin, OwnedResources::default());
+        assert_eq!(
+            authorize_subscription(Some(&admin), &admin_scope, &other_strategy, 100),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_scope_filters_resource_messages() {
+        use super::super::messages::StrategyUpdateData;
+
+        let update = |id: &str| {
+            ServerMessage::StrategyUpdate(StrategyUpdateData {
+                strategy_id: id.to_string(),
+                name: "RSI".to_string(),
+                running: true,
+                event: "started".to_string(),
+                data: None,
+                timestamp: 0,
+            })
+        };
+        let scope = owned_scope(&["rsi-1"], &[]);
+        assert!(scope.allows(&update("rsi-1")));
+        assert!(!scope.allows(&update("grid-1")));
+        assert!(ResourceScope::All.allows(&update("grid-1")));
+    }
+}
@@ -2,11 +2,11 @@
 //!
 //! Axum WebSocket 엔드포인트 및 메시지 처리.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use axum::{
     extract::{
-        ws::{Message, WebSocket, WebSocketUpgrade},
+        ws::{close_code, CloseFrame, Message, WebSocket, WebSocketUpgrade},
         State,
     },
     response::IntoResponse,
@@ -15,13 +15,23 @@ use axum::{
 };
 use chrono::Utc;
 use futures::{SinkExt, StreamExt};
-use tokio::sync::{broadcast, RwLock};
+use serde_json::json;
+use sqlx::PgPool;
+use tokio::{
+    sync::{broadcast, mpsc, RwLock},
+    time::MissedTickBehavior,
+};
 use tracing::{debug, info, warn};
+use trader_core::StrategyContext;
 use uuid::Uuid;
 
 use super::{
-    messages::{ClientMessage, ServerMessage},
-    subscriptions::SharedSubscriptionManager,
+    authorization::{
+        load_owned_resources, record_audit_event, OwnedResources, ResourceScope,
+        SubscriptionDenied, WsAuditEvent,
+    },
+    messages::{ClientMessage, ContextSnapshotData, ServerMessage},
+    subscriptions::{SharedSubscriptionManager, Subscription},
 };
 use crate::{
     auth::{decode_token, Claims},
@@ -33,6 +43,12 @@ use crate::{
 /// MarketStream 핸들 맵 타입 (복잡한 타입 alias)
 type MarketStreamMap = HashMap<Uuid, Arc<MarketStreamHandle>>;
 
+/// 인증 토큰 만료 재검증 주기.
+const TOKEN_REVALIDATION_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 세션별 응답 채널 버퍼 크기.
+const REPLY_CHANNEL_CAPACITY: usize = 64;
+
 /// WebSocket 상태.
 ///
 /// 구독 관리자를 포함한 WebSocket 서버 상태.
@@ -46,6 +62,14 @@ pub struct WsState {
     ///
     /// 프론트엔드에서 `market:{symbol}` 구독 시 거래소 스트림에도 구독을 전달합니다.
     pub market_streams: Option<Arc<RwLock<MarketStreamMap>>>,
+    /// 인증 감사 로그 저장용 DB 풀 (없으면 tracing 로그만 남김)
+    pub audit_pool: Option<PgPool>,
+    /// 리소스 소유자 조회용 DB 풀 (없으면 관리자만 리소스 채널 접근 가능)
+    pub resource_pool: Option<PgPool>,
+    /// 전략 컨텍스트 (`context` 구독 시 전체 스냅샷 전송용).
+    ///
+    /// 이후 변경분은 ContextSyncService가 `context_delta`로 브로드캐스트합니다.
+    pub strategy_context: Option<Arc<RwLock<StrategyContext>>>,
 }
 
 impl WsState {
@@ -55,6 +79,9 @@ impl WsState {
             subscriptions,
             jwt_secret: jwt_secret.into(),
             market_streams: None,
+            audit_pool: None,
+            resource_pool: None,
+            strategy_context: None,
         }
     }
 
@@ -66,6 +93,24 @@ impl WsState {
         self.market_streams = Some(streams);
         self
     }
+
+    /// 인증 감사 로그 DB 풀 설정.
+    pub fn with_audit_pool(mut self, pool: Option<PgPool>) -> Self {
+        self.audit_pool = pool;
+        self
+    }
+
+    /// 리소스 소유자 조회 DB 풀 설정.
+    pub fn with_resource_pool(mut self, pool: Option<PgPool>) -> Self {
+        self.resource_pool = pool;
+        self
+    }
+
+    /// 전략 컨텍스트 설정.
+    pub fn with_strategy_context(mut self, context: Option<Arc<RwLock<StrategyContext>>>) -> Self {
+        self.strategy_context = context;
+        self
+    }
 }
 
 /// WebSocket 업그레이드 핸들러.
@@ -105,6 +150,9 @@ async fn handle_socket(socket: WebSocket, state: WsState) {
         let _ = sender.send(Message::Text(json.into())).await;
     }
 
+    // 이 세션에만 전달할 응답(구독 결과, 에러 등) 채널
+    let (reply_tx, mut reply_rx) = mpsc::channel::<ServerMessage>(REPLY_CHANNEL_CAPACITY);
+
     // 클라이언트 메시지 수신 태스크
     let session_id_clone = session_id.clone();
     let state_clone = state.clone();
@@ -112,7 +160,8 @@ async fn handle_socket(socket: WebSocket, state: WsState) {
         while let Some(result) = receiver.next().await {
             match result {
                 Ok(msg) => {
-                    if !handle_client_message(&session_id_clone, msg, &state_clone).await {
+                    if !handle_client_message(&session_id_clone, msg, &state_clone, &reply_tx).await
+                    {
                         break;
                     }
                 }
@@ -124,30 +173,70 @@ async fn handle_socket(socket: WebSocket, state: WsState) {
         }
     });
 
-    // 브로드캐스트 메시지 전송 태스크
+    // 브로드캐스트/응답 메시지 전송 및 토큰 재검증 태스크
     let session_id_clone = session_id.clone();
     let state_clone = state.clone();
     let send_task = tokio::spawn(async move {
+        let mut revalidation = tokio::time::interval(TOKEN_REVALIDATION_INTERVAL);
+        revalidation.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
         loop {
-            match broadcast_rx.recv().await {
-                Ok(msg) => {
-                    // 이 세션이 메시지를 수신해야 하는지 확인
-                    if state_clone
-                        .subscriptions
-                        .should_session_receive(&session_id_clone, &msg)
-                        .await
-                    {
-                        if let Ok(json) = msg.to_json() {
-                            if sender.send(Message::Text(json.into())).await.is_err() {
-                                break;
-                            }
+            let outgoing = tokio::select! {
+                result = broadcast_rx.recv() => match result {
+                    Ok(msg) => {
+                        // 이 세션이 메시지를 수신해야 하는지 확인
+                        if !state_clone
+                            .subscriptions
+                            .should_session_receive(&session_id_clone, &msg)
+                            .await
+                        {
+                            continue;
                         }
+                        msg
                     }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!("WebSocket lagged by {} messages", n);
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                Some(reply) = reply_rx.recv() => reply,
+                _ = revalidation.tick() => {
+                    let expired_user = state_clone
+                        .subscriptions
+                        .expired_session_user(&session_id_clone, Utc::now().timestamp())
+                        .await;
+                    let Some(user_id) = expired_user else {
+                        continue;
+                    };
+
+                    // 만료된 토큰으로 연결을 유지하지 않도록 세션 종료
+                    record_audit_event(
+                        state_clone.audit_pool.as_ref(),
+                        WsAuditEvent::TokenExpired,
+                        &session_id_clone,
+                        Some(&user_id),
+                        json!({ "reason": "token expired during session" }),
+                    );
+                    let expired = ServerMessage::error(
+                        SubscriptionDenied::TokenExpired.code(),
+                        SubscriptionDenied::TokenExpired.message(),
+                    );
+                    if let Ok(json) = expired.to_json() {
+                        let _ = sender.send(Message::Text(json.into())).await;
+                    }
+                    let _ = sender
+                        .send(Message::Close(Some(CloseFrame {
+                            code: close_code::POLICY,
+                            reason: "token expired".into(),
+                        })))
+                        .await;
+                    break;
                 }
-                Err(broadcast::error::RecvError::Lagged(n)) => {
-                    warn!("WebSocket lagged by {} messages", n);
-                }
-                Err(broadcast::error::RecvError::Closed) => {
+            };
+
+            if let Ok(json) = outgoing.to_json() {
+                if sender.send(Message::Text(json.into())).await.is_err() {
                     break;
                 }
             }
@@ -175,20 +264,28 @@ async fn handle_socket(socket: WebSocket, state: WsState) {
 
 /// 클라이언트 메시지 처리.
 ///
+/// 응답은 `reply`를 통해 이 세션에만 전달됩니다.
+///
 /// # Returns
 ///
 /// `true`면 연결 유지, `false`면 연결 종료
-async fn handle_client_message(session_id: &str, msg: Message, state: &WsState) -> bool {
+async fn handle_client_message(
+    session_id: &str,
+    msg: Message,
+    state: &WsState,
+    reply: &mpsc::Sender<ServerMessage>,
+) -> bool {
     match msg {
         Message::Text(text) => {
             match ClientMessage::from_json(&text) {
-                Ok(client_msg) => process_client_message(session_id, client_msg, state).await,
+                Ok(client_msg) => {
+                    process_client_message(session_id, client_msg, state, reply).await
+                }
                 Err(e) => {
                     warn!("Invalid message from {}: {}", session_id, e);
-                    // 에러 응답 브로드캐스트 (해당 세션에만 전달됨)
-                    let _ = state
-                        .subscriptions
-                        .broadcast(ServerMessage::error("INVALID_MESSAGE", e.to_string()));
+                    let _ = reply
+                        .send(ServerMessage::error("INVALID_MESSAGE", e.to_string()))
+                        .await;
                     true // 연결은 유지
                 }
             }
@@ -207,21 +304,68 @@ async fn handle_client_message(session_id: &str, msg: Message, state: &WsState)
 }
 
 /// 파싱된 클라이언트 메시지 처리.
-async fn process_client_message(session_id: &str, msg: ClientMessage, state: &WsState) -> bool {
+async fn process_client_message(
+    session_id: &str,
+    msg: ClientMessage,
+    state: &WsState,
+    reply: &mpsc::Sender<ServerMessage>,
+) -> bool {
     match msg {
         ClientMessage::Subscribe { channels } => {
-            let subscribed = state.subscriptions.subscribe(session_id, &channels).await;
-            debug!("Session {} subscribed to: {:?}", session_id, subscribed);
+            // 인증 이후 생성된 전략/계정도 반영하도록 구독 시점에 범위 갱신
+            refresh_resource_scope(session_id, state).await;
+            let outcome = state
+                .subscriptions
+                .subscribe_authorized(session_id, &channels, Utc::now().timestamp())
+                .await;
+            debug!(
+                "Session {} subscribed to: {:?}",
+                session_id, outcome.subscribed
+            );
+
+            // 권한 없는 채널은 연결을 유지한 채 에러 프레임으로 거부
+            if !outcome.denied.is_empty() {
+                let user_id = state.subscriptions.session_user(session_id).await;
+                for (channel, denied) in &outcome.denied {
+                    record_audit_event(
+                        state.audit_pool.as_ref(),
+                        WsAuditEvent::SubscriptionDenied,
+                        session_id,
+                        user_id.as_deref(),
+                        json!({ "channel": channel, "reason": denied.code() }),
+                    );
+                    let _ = reply
+                        .send(ServerMessage::error(
+                            denied.code(),
+                            format!("{}: {}", denied.message(), channel),
+                        ))
+                        .await;
+                }
+            }
 
             // 거래소 스트림에 심볼 구독 전달
             if let Some(ref market_streams) = state.market_streams {
-                forward_subscribe_to_exchange_streams(market_streams, &subscribed).await;
+                forward_subscribe_to_exchange_streams(market_streams, &outcome.subscribed).await;
             }
 
+            let context_subscribed = outcome
+                .subscribed
+                .iter()
+                .any(|ch| Subscription::from_channel(ch) == Some(Subscription::Context));
+
             let response = ServerMessage::Subscribed {
-                channels: subscribed,
+                channels: outcome.subscribed,
             };
-            let _ = state.subscriptions.broadcast(response);
+            let _ = reply.send(response).await;
+
+            // 컨텍스트 구독은 전체 스냅샷으로 시작 (이후 변경분만 수신)
+            if context_subscribed {
+                if let Some(ref context) = state.strategy_context {
+                    let context = context.read().await;
+                    let snapshot = ContextSnapshotData::from_context(&context);
+                    let _ = reply.send(ServerMessage::ContextSnapshot(snapshot)).await;
+                }
+            }
             true
         }
 
@@ -235,7 +379,7 @@ async fn process_client_message(session_id: &str, msg: ClientMessage, state: &Ws
             let response = ServerMessage::Unsubscribed {
                 channels: unsubscribed,
             };
-            let _ = state.subscriptions.broadcast(response);
+            let _ = reply.send(response).await;
             true
         }
 
@@ -243,7 +387,7 @@ async fn process_client_message(session_id: &str, msg: ClientMessage, state: &Ws
             let response = ServerMessage::Pong {
                 timestamp: Utc::now().timestamp_millis(),
             };
-            let _ = state.subscriptions.broadcast(response);
+            let _ = reply.send(response).await;
             true
         }
 
@@ -251,32 +395,38 @@ async fn process_client_message(session_id: &str, msg: ClientMessage, state: &Ws
             match decode_token(&token, &state.jwt_secret) {
                 Ok(token_data) => {
                     let claims: Claims = token_data.claims;
+                    let user_id = claims.sub.clone();
                     state
                         .subscriptions
-                        .authenticate(session_id, &claims.sub)
+                        .authenticate_with_claims(session_id, claims)
                         .await;
+                    refresh_resource_scope(session_id, state).await;
 
-                    info!(
-                        "Session {} authenticated as user {}",
-                        session_id, claims.sub
-                    );
+                    info!("Session {} authenticated as user {}", session_id, user_id);
 
                     let response = ServerMessage::AuthResult {
                         success: true,
                         message: "Authenticated successfully".to_string(),
-                        user_id: Some(claims.sub),
+                        user_id: Some(user_id),
                     };
-                    let _ = state.subscriptions.broadcast(response);
+                    let _ = reply.send(response).await;
                 }
                 Err(e) => {
                     warn!("Auth failed for session {}: {}", session_id, e);
+                    record_audit_event(
+                        state.audit_pool.as_ref(),
+                        WsAuditEvent::AuthFailed,
+                        session_id,
+                        None,
+                        json!({ "reason": e.to_string() }),
+                    );
 
                     let response = ServerMessage::AuthResult {
                         success: false,
                         message: format!("Authentication failed: {}", e),
                         user_id: None,
                     };
-                    let _ = state.subscriptions.broadcast(response);
+                    let _ = reply.send(response).await;
                 }
             }
             true
@@ -284,6 +434,35 @@ async fn process_client_message(session_id: &str, msg: ClientMessage, state: &Ws
     }
 }
 
+/// 세션 클레임으로 리소스 접근 범위 갱신.
+///
+/// 관리자는 모든 리소스, 그 외 역할은 소유한 전략/계정만 접근합니다.
+/// 소유자 조회에 실패하면 빈 범위로 두어 리소스 채널을 거부합니다.
+async fn refresh_resource_scope(session_id: &str, state: &WsState) {
+    let Some(claims) = state.subscriptions.session_claims(session_id).await else {
+        return;
+    };
+
+    let owned = match state.resource_pool.as_ref() {
+        Some(pool) => match load_owned_resources(pool, &claims.sub).await {
+            Ok(owned) => owned,
+            Err(e) => {
+                warn!(
+                    "Failed to load owned resources for session {}: {}",
+                    session_id, e
+                );
+                OwnedResources::default()
+            }
+        },
+        None => OwnedResources::default(),
+    };
+
+    state
+        .subscriptions
+        .set_scope(session_id, ResourceScope::for_claims(&claims, owned))
+        .await;
+}
+
 /// WebSocket 라우터 생성.
 ///
 /// AppState와 함께 사용할 수 있는 WebSocket 라우터.
@@ -343,10 +522,7 @@ async fn forward_subscribe_to_exchange_streams(
 
 #[cfg(test)]
 mod tests {
-    use super::{
-        super::subscriptions::{create_subscription_manager, Subscription},
-        *,
-    };
+    use super::{super::subscriptions::create_subscription_manager, *};
 
     #[test]
     fn test_ws_state_creation() {
@@ -375,4 +551,45 @@ mod tests {
             1
         );
     }
+
+    #[tokio::test]
+    async fn test_context_subscribe_sends_snapshot() {
+        let subscriptions = create_subscription_manager(100);
+        let mut context = StrategyContext::new();
+        context.merge_ticker_analytics(
+            [("005930".to_string(), trader_core::RouteState::Armed)].into(),
+            HashMap::new(),
+            HashMap::new(),
+        );
+        let state = WsState::new(subscriptions.clone(), "test-secret")
+            .with_strategy_context(Some(Arc::new(RwLock::new(context))));
+
+        let _rx = subscriptions.register("test-session").await;
+        let (reply_tx, mut reply_rx) = mpsc::channel(8);
+        process_client_message(
+            "test-session",
+            ClientMessage::Subscribe {
+                channels: vec!["context".to_string()],
+            },
+            &state,
+            &reply_tx,
+        )
+        .await;
+
+        assert!(matches!(
+            reply_rx.recv().await,
+            Some(ServerMessage::Subscribed { .. })
+        ));
+        match reply_rx.recv().await {
+            Some(ServerMessage::ContextSnapshot(snapshot)) => {
+                assert_eq!(snapshot.tickers.len(), 1);
+                assert_eq!(snapshot.tickers[0].ticker, "005930");
+            }
+            other => panic!("스냅샷이 전송되지 않음: {:?}", other),
+        }
+        assert_eq!(
+            subscriptions.subscriber_count(&Subscription::Context).await,
+            1
+        );
+    }
 }
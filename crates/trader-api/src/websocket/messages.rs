@@ -5,6 +5,9 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use trader_core::{
+    MarketRegime, RouteState, SignalConflictResolution, StrategyContext, StructuralFeatures,
+};
 
 /// WebSocket 에러.
 #[derive(Debug, thiserror::Error)]
@@ -116,6 +119,87 @@ pub enum ServerMessage {
     ActiveAccountChanged(ActiveAccountChangedData),
     /// Signal 충돌 알림
     SignalConflict(SignalConflictData),
+    /// 전략 컨텍스트 전체 스냅샷 (`context` 구독 직후 1회)
+    ContextSnapshot(ContextSnapshotData),
+    /// 전략 컨텍스트 변경분 (분석 동기화 주기마다, 바뀐 종목만)
+    ContextDelta(ContextDeltaData),
+}
+
+/// 종목별 컨텍스트 분석 데이터.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextTickerData {
+    /// 티커
+    pub ticker: String,
+    /// RouteState
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route_state: Option<RouteState>,
+    /// MarketRegime
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market_regime: Option<MarketRegime>,
+    /// 구조적 피처
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<StructuralFeatures>,
+    /// Global Score 종합 점수
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub global_score: Option<Decimal>,
+}
+
+impl ContextTickerData {
+    /// 컨텍스트에서 특정 종목의 분석 데이터 추출.
+    pub fn from_context(ctx: &StrategyContext, ticker: &str) -> Self {
+        Self {
+            ticker: ticker.to_string(),
+            route_state: ctx.get_route_state(ticker).copied(),
+            market_regime: ctx.get_market_regime(ticker).copied(),
+            features: ctx.get_features(ticker).cloned(),
+            global_score: ctx.get_global_score(ticker).map(|s| s.overall_score),
+        }
+    }
+}
+
+/// 전략 컨텍스트 스냅샷 데이터.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextSnapshotData {
+    /// 분석 데이터가 있는 모든 종목 (티커 순)
+    pub tickers: Vec<ContextTickerData>,
+    /// 마지막 분석 동기화 시각 (밀리초)
+    pub synced_at: i64,
+}
+
+impl ContextSnapshotData {
+    /// 컨텍스트 전체 스냅샷 생성.
+    pub fn from_context(ctx: &StrategyContext) -> Self {
+        let mut tickers: Vec<&String> = ctx
+            .route_states
+            .keys()
+            .chain(ctx.structural_features.keys())
+            .chain(ctx.market_regime.keys())
+            .chain(ctx.global_scores.keys())
+            .collect();
+        tickers.sort();
+        tickers.dedup();
+
+        Self {
+            tickers: tickers
+                .into_iter()
+                .map(|ticker| ContextTickerData::from_context(ctx, ticker))
+                .collect(),
+            synced_at: ctx.last_analytics_sync.timestamp_millis(),
+        }
+    }
+}
+
+/// 전략 컨텍스트 변경분 데이터.
+///
+/// 클라이언트는 스냅샷의 `synced_at`보다 이전 변경분을 무시하면 됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextDeltaData {
+    /// 값이 바뀐 종목 (바뀐 종목의 현재 값 전체)
+    pub changed: Vec<ContextTickerData>,
+    /// 분석 대상에서 제외된 종목
+    pub removed: Vec<String>,
+    /// 동기화 시각 (밀리초)
+    pub synced_at: i64,
 }
 
 /// Signal 충돌 데이터.
@@ -127,12 +211,16 @@ pub struct SignalConflictData {
     pub ticker: String,
     /// 신호 유형 (Entry, Exit 등)
     pub signal_type: String,
-    /// 충돌 유형 (pending_order, duplicate_position, no_position, insufficient_balance)
+    /// 충돌 유형 (pending_order, duplicate_position, no_position, insufficient_balance,
+    /// cross_strategy)
     pub conflict_type: String,
     /// 충돌 메시지 (사람이 읽을 수 있는 형태)
     pub message: String,
     /// 타임스탬프
     pub timestamp: i64,
+    /// 전략 간 충돌 해소 결과 (정책, 신호별 채택/기각)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<SignalConflictResolution>,
 }
 
 /// 활성 계정 변경 데이터.
@@ -406,4 +494,28 @@ mod tests {
         assert!(json.contains("ticker"));
         assert!(json.contains("BTC-USDT"));
     }
+
+    #[test]
+    fn test_context_snapshot_from_context() {
+        let mut ctx = StrategyContext::new();
+        ctx.merge_ticker_analytics(
+            [("005930".to_string(), RouteState::Attack)].into(),
+            Default::default(),
+            [
+                ("005930".to_string(), MarketRegime::StrongUptrend),
+                ("000660".to_string(), MarketRegime::Correction),
+            ]
+            .into(),
+        );
+
+        let snapshot = ContextSnapshotData::from_context(&ctx);
+        let tickers: Vec<&str> = snapshot.tickers.iter().map(|t| t.ticker.as_str()).collect();
+        assert_eq!(tickers, vec!["000660", "005930"]);
+        assert_eq!(snapshot.tickers[1].route_state, Some(RouteState::Attack));
+        assert!(snapshot.tickers[0].route_state.is_none());
+
+        let json = ServerMessage::ContextSnapshot(snapshot).to_json().unwrap();
+        assert!(json.contains(r#""type":"context_snapshot""#));
+        assert!(json.contains(r#""route_state":"ATTACK""#));
+    }
 }
@@ -5,9 +5,20 @@
 //! # 구독 채널
 //!
 //! - `market:{symbol}` - 특정 심볼의 시장 데이터 (ticker, trades)
-//! - `orders` - 주문 상태 업데이트
-//! - `positions` - 포지션 업데이트
-//! - `strategies` - 전략 상태 변경
+//! - `orders` - 주문 상태 업데이트 (인증 필요)
+//! - `positions` - 포지션 업데이트 (인증 필요)
+//! - `account` - 활성 계정 변경 알림 (인증 필요)
+//! - `strategies` - 전략 상태 변경 (인증 필요, 소유 전략만 수신)
+//! - `strategy:{strategy_id}` - 특정 전략 업데이트 (인증 + 소유자)
+//! - `account:{credential_id}` - 특정 계정 변경 알림 (인증 + 소유자)
+//! - `context` - 전략 컨텍스트 분석 결과 (구독 시 `context_snapshot`, 이후 `context_delta`)
+//!
+//! # 인증
+//!
+//! 시장 데이터/시뮬레이션/컨텍스트 외의 채널은 `{"type": "auth", "token": "..."}`로
+//! 인증한 뒤 구독할 수 있으며, 특정 전략/계정 채널은 소유자(관리자는 전체)만 구독합니다.
+//! 권한이 없으면 연결을 유지한 채 `error` 프레임으로 거부됩니다.
+//! 인증 토큰은 주기적으로 재검증되어, 만료되면 연결이 종료됩니다.
 //!
 //! # 메시지 형식
 //!
@@ -30,19 +41,25 @@
 //! ```
 
 pub mod aggregator;
+pub mod authorization;
 pub mod handler;
 pub mod messages;
 pub mod simulator;
 pub mod subscriptions;
 
 pub use aggregator::{start_aggregator, MarketDataAggregator};
+pub use authorization::{
+    authorize_subscription, load_owned_resources, OwnedResources, ResourceScope, SubscriptionDenied,
+};
 pub use handler::{standalone_websocket_router, websocket_handler, websocket_router, WsState};
 pub use messages::{
-    ActiveAccountChangedData, ClientMessage, OrderBookData, OrderBookLevel, OrderUpdateData,
-    PositionUpdateData, ServerMessage, SignalConflictData, SimulationUpdateData,
-    StrategyUpdateData, TickerData, TradeData, WsError,
+    ActiveAccountChangedData, ClientMessage, ContextDeltaData, ContextSnapshotData,
+    ContextTickerData, OrderBookData, OrderBookLevel, OrderUpdateData, PositionUpdateData,
+    ServerMessage, SignalConflictData, SimulationUpdateData, StrategyUpdateData, TickerData,
+    TradeData, WsError,
 };
 pub use simulator::{start_simulator, MockDataSimulator};
 pub use subscriptions::{
-    create_subscription_manager, SharedSubscriptionManager, Subscription, SubscriptionManager,
+    create_subscription_manager, SharedSubscriptionManager, SubscribeOutcome, Subscription,
+    SubscriptionManager,
 };
@@ -9,25 +9,35 @@ use std::{
 
 use tokio::sync::{broadcast, RwLock};
 
-use super::messages::ServerMessage;
+use super::{
+    authorization::{authorize_subscription, ResourceScope, SubscriptionDenied},
+    messages::ServerMessage,
+};
+use crate::auth::{Claims, Permission};
 
 /// 구독 채널 타입.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Subscription {
     /// 특정 심볼의 시장 데이터
     Market(String),
-    /// 주문 업데이트
+    /// 주문 업데이트 (인증 필요)
     Orders,
-    /// 포지션 업데이트
+    /// 포지션 업데이트 (인증 필요)
     Positions,
-    /// 전략 업데이트
+    /// 전략 업데이트 (인증 필요)
     Strategies,
     /// 모든 시장 데이터 (요약)
     AllMarkets,
     /// 시뮬레이션 업데이트
     Simulation,
-    /// 계정 변경 알림 (활성 거래소 변경 등)
+    /// 계정 변경 알림 (활성 거래소 변경 등, 인증 필요)
     Account,
+    /// 전략 컨텍스트 분석 결과 (구독 시 스냅샷, 이후 변경분)
+    Context,
+    /// 특정 전략의 업데이트 (인증 필요)
+    Strategy(String),
+    /// 특정 계정(credential)의 변경 알림 (인증 필요)
+    AccountCredential(String),
 }
 
 impl Subscription {
@@ -40,9 +50,17 @@ impl Subscription {
     /// - `positions` - 포지션 업데이트
     /// - `strategies` - 전략 업데이트
     /// - `all_markets` - 모든 시장 요약
+    /// - `context` - 전략 컨텍스트 분석 결과 (스냅샷 + 변경분)
+    /// - `strategy:{strategy_id}` - 특정 전략 업데이트
+    /// - `account:{credential_id}` - 특정 계정 변경 알림
     pub fn from_channel(channel: &str) -> Option<Self> {
         if let Some(symbol) = channel.strip_prefix("market:") {
             Some(Subscription::Market(symbol.to_uppercase()))
+        } else if let Some(strategy_id) = channel.strip_prefix("strategy:") {
+            (!strategy_id.is_empty()).then(|| Subscription::Strategy(strategy_id.to_string()))
+        } else if let Some(credential_id) = channel.strip_prefix("account:") {
+            (!credential_id.is_empty())
+                .then(|| Subscription::AccountCredential(credential_id.to_string()))
         } else {
             match channel.to_lowercase().as_str() {
                 "orders" => Some(Subscription::Orders),
@@ -51,6 +69,7 @@ impl Subscription {
                 "all_markets" => Some(Subscription::AllMarkets),
                 "simulation" => Some(Subscription::Simulation),
                 "account" => Some(Subscription::Account),
+                "context" => Some(Subscription::Context),
                 _ => None,
             }
         }
@@ -66,6 +85,28 @@ impl Subscription {
             Subscription::AllMarkets => "all_markets".to_string(),
             Subscription::Simulation => "simulation".to_string(),
             Subscription::Account => "account".to_string(),
+            Subscription::Context => "context".to_string(),
+            Subscription::Strategy(id) => format!("strategy:{}", id),
+            Subscription::AccountCredential(id) => format!("account:{}", id),
+        }
+    }
+
+    /// 구독에 필요한 권한.
+    ///
+    /// `None`이면 인증 없이 구독할 수 있는 공개 채널(시장 데이터, 시뮬레이션,
+    /// 컨텍스트)입니다. 주문/포지션/계정/전략 채널은 JWT 인증과 권한을 요구합니다.
+    pub fn required_permission(&self) -> Option<Permission> {
+        match self {
+            Subscription::Orders => Some(Permission::ViewOrders),
+            Subscription::Positions | Subscription::Account => Some(Permission::ViewPositions),
+            Subscription::Strategies | Subscription::Strategy(_) => {
+                Some(Permission::ViewStrategies)
+            }
+            Subscription::AccountCredential(_) => Some(Permission::ViewPositions),
+            Subscription::Market(_)
+            | Subscription::AllMarkets
+            | Subscription::Simulation
+            | Subscription::Context => None,
         }
     }
 
@@ -84,6 +125,16 @@ impl Subscription {
             (Subscription::AllMarkets, ServerMessage::Ticker(_)) => true,
             (Subscription::Simulation, ServerMessage::SimulationUpdate(_)) => true,
             (Subscription::Account, ServerMessage::ActiveAccountChanged(_)) => true,
+            (Subscription::Context, ServerMessage::ContextDelta(_)) => true,
+            (Subscription::Strategy(id), ServerMessage::StrategyUpdate(data)) => {
+                data.strategy_id == *id
+            }
+            (Subscription::Strategy(id), ServerMessage::SignalConflict(data)) => {
+                data.strategy_id == *id
+            }
+            (Subscription::AccountCredential(id), ServerMessage::ActiveAccountChanged(data)) => {
+                data.credential_id.as_deref() == Some(id.as_str())
+            }
             _ => false,
         }
     }
@@ -100,6 +151,10 @@ pub struct ClientSession {
     pub authenticated: bool,
     /// 사용자 ID (인증된 경우)
     pub user_id: Option<String>,
+    /// 인증에 사용된 JWT 클레임 (권한 검증 및 만료 재검증용)
+    pub claims: Option<Claims>,
+    /// 접근 가능한 리소스 범위 (소유 전략/계정)
+    pub scope: ResourceScope,
 }
 
 impl ClientSession {
@@ -110,6 +165,8 @@ impl ClientSession {
             subscriptions: HashSet::new(),
             authenticated: false,
             user_id: None,
+            claims: None,
+            scope: ResourceScope::default(),
         }
     }
 
@@ -124,8 +181,10 @@ impl ClientSession {
     }
 
     /// 메시지를 수신해야 하는지 확인.
+    ///
+    /// 구독 채널에 해당하더라도 소유하지 않은 전략/계정의 메시지는 받지 않습니다.
     pub fn should_receive(&self, message: &ServerMessage) -> bool {
-        self.subscriptions.iter().any(|sub| sub.matches(message))
+        self.subscriptions.iter().any(|sub| sub.matches(message)) && self.scope.allows(message)
     }
 
     /// 인증 설정.
@@ -133,6 +192,28 @@ impl ClientSession {
         self.authenticated = true;
         self.user_id = Some(user_id.into());
     }
+
+    /// JWT 클레임으로 인증 설정.
+    pub fn authenticate_with_claims(&mut self, claims: Claims) {
+        self.authenticate(claims.sub.clone());
+        self.claims = Some(claims);
+    }
+
+    /// 인증 토큰이 `now` 시점에 만료되었는지 확인.
+    ///
+    /// 클레임 없이 인증되지 않은 세션은 만료 대상이 아닙니다.
+    pub fn is_token_expired(&self, now: i64) -> bool {
+        self.claims.as_ref().is_some_and(|c| c.exp <= now)
+    }
+}
+
+/// 구독 요청 처리 결과.
+#[derive(Debug, Default)]
+pub struct SubscribeOutcome {
+    /// 구독된 채널 목록
+    pub subscribed: Vec<String>,
+    /// 권한 부족으로 거부된 채널과 사유
+    pub denied: Vec<(String, SubscriptionDenied)>,
 }
 
 /// 구독 관리자.
@@ -194,6 +275,43 @@ impl SubscriptionManager {
         subscribed
     }
 
+    /// 권한을 검증한 뒤 채널 구독 추가.
+    ///
+    /// 세션의 JWT 클레임으로 각 채널의 [`Subscription::required_permission`]을
+    /// 확인하고, 리소스 채널은 세션 범위([`ClientSession::scope`])의 소유 여부도
+    /// 확인합니다. 권한이 없는 채널은 구독하지 않고 `denied`로 반환합니다.
+    pub async fn subscribe_authorized(
+        &self,
+        session_id: &str,
+        channels: &[String],
+        now: i64,
+    ) -> SubscribeOutcome {
+        let mut sessions = self.sessions.write().await;
+        let mut outcome = SubscribeOutcome::default();
+
+        if let Some(session) = sessions.get_mut(session_id) {
+            for channel in channels {
+                let Some(subscription) = Subscription::from_channel(channel) else {
+                    continue;
+                };
+                match authorize_subscription(
+                    session.claims.as_ref(),
+                    &session.scope,
+                    &subscription,
+                    now,
+                ) {
+                    Ok(()) => {
+                        session.subscribe(subscription);
+                        outcome.subscribed.push(channel.clone());
+                    }
+                    Err(denied) => outcome.denied.push((channel.clone(), denied)),
+                }
+            }
+        }
+
+        outcome
+    }
+
     /// 채널 구독 해제.
     pub async fn unsubscribe(&self, session_id: &str, channels: &[String]) -> Vec<String> {
         let mut sessions = self.sessions.write().await;
@@ -219,6 +337,43 @@ impl SubscriptionManager {
         }
     }
 
+    /// JWT 클레임으로 세션 인증.
+    pub async fn authenticate_with_claims(&self, session_id: &str, claims: Claims) {
+        let mut sessions = self.sessions.write().await;
+        if let Some(session) = sessions.get_mut(session_id) {
+            session.authenticate_with_claims(claims);
+        }
+    }
+
+    /// 세션의 리소스 접근 범위 설정.
+    pub async fn set_scope(&self, session_id: &str, scope: ResourceScope) {
+        let mut sessions = self.sessions.write().await;
+        if let Some(session) = sessions.get_mut(session_id) {
+            session.scope = scope;
+        }
+    }
+
+    /// 세션의 JWT 클레임.
+    pub async fn session_claims(&self, session_id: &str) -> Option<Claims> {
+        let sessions = self.sessions.read().await;
+        sessions.get(session_id).and_then(|s| s.claims.clone())
+    }
+
+    /// 세션의 인증된 사용자 ID.
+    pub async fn session_user(&self, session_id: &str) -> Option<String> {
+        let sessions = self.sessions.read().await;
+        sessions.get(session_id).and_then(|s| s.user_id.clone())
+    }
+
+    /// 인증 토큰이 만료된 세션이면 사용자 ID 반환.
+    pub async fn expired_session_user(&self, session_id: &str, now: i64) -> Option<String> {
+        let sessions = self.sessions.read().await;
+        sessions
+            .get(session_id)
+            .filter(|s| s.is_token_expired(now))
+            .and_then(|s| s.user_id.clone())
+    }
+
     /// 메시지 브로드캐스트.
     ///
     /// 구독 중인 모든 클라이언트에게 메시지를 전송합니다.
@@ -314,6 +469,10 @@ mod tests {
             Subscription::from_channel("positions"),
             Some(Subscription::Positions)
         );
+        assert_eq!(
+            Subscription::from_channel("context"),
+            Some(Subscription::Context)
+        );
         assert_eq!(Subscription::from_channel("unknown"), None);
     }
 
@@ -368,6 +527,97 @@ mod tests {
         assert_eq!(manager.client_count().await, 0);
     }
 
+    #[tokio::test]
+    async fn test_subscribe_authorized_rejects_resource_channel_without_auth() {
+        use super::super::authorization::OwnedResources;
+        use crate::auth::Role;
+
+        let manager = SubscriptionManager::new(100);
+        let _rx = manager.register("session-1").await;
+        let channels = [
+            "market:BTC-USDT".to_string(),
+            "orders".to_string(),
+            "strategy:rsi-1".to_string(),
+        ];
+
+        let outcome = manager
+            .subscribe_authorized("session-1", &channels, 1_000)
+            .await;
+        assert_eq!(outcome.subscribed, vec!["market:BTC-USDT".to_string()]);
+        assert_eq!(
+            outcome.denied,
+            vec![
+                ("orders".to_string(), SubscriptionDenied::Unauthenticated),
+                (
+                    "strategy:rsi-1".to_string(),
+                    SubscriptionDenied::Unauthenticated
+                ),
+            ]
+        );
+
+        let claims = Claims {
+            sub: "user-1".to_string(),
+            username: "tester".to_string(),
+            role: Role::Viewer,
+            iat: 0,
+            exp: 2_000,
+            jti: None,
+        };
+        manager.authenticate_with_claims("session-1", claims).await;
+
+        // 인증했더라도 소유하지 않은 전략은 거부
+        let outcome = manager
+            .subscribe_authorized("session-1", &channels, 1_000)
+            .await;
+        assert_eq!(outcome.subscribed.len(), 2);
+        assert_eq!(
+            outcome.denied,
+            vec![("strategy:rsi-1".to_string(), SubscriptionDenied::Forbidden)]
+        );
+
+        let owned = OwnedResources {
+            strategies: ["rsi-1".to_string()].into_iter().collect(),
+            credentials: HashSet::new(),
+        };
+        manager
+            .set_scope("session-1", ResourceScope::Owned(owned))
+            .await;
+        let outcome = manager
+            .subscribe_authorized("session-1", &channels, 1_000)
+            .await;
+        assert_eq!(outcome.subscribed.len(), 3);
+        assert!(outcome.denied.is_empty());
+
+        // 만료 시점 이후에는 재검증에서 만료 세션으로 판단
+        assert_eq!(manager.expired_session_user("session-1", 1_999).await, None);
+        assert_eq!(
+            manager.expired_session_user("session-1", 2_000).await,
+            Some("user-1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_strategy_channel_matches_only_its_strategy() {
+        use super::super::messages::StrategyUpdateData;
+
+        let sub = Subscription::from_channel("strategy:rsi-1").unwrap();
+        assert_eq!(sub.to_channel(), "strategy:rsi-1");
+        assert_eq!(Subscription::from_channel("strategy:"), None);
+
+        let update = |id: &str| {
+            ServerMessage::StrategyUpdate(StrategyUpdateData {
+                strategy_id: id.to_string(),
+                name: "RSI".to_string(),
+                running: true,
+                event: "started".to_string(),
+                data: None,
+                timestamp: 0,
+            })
+        };
+        assert!(sub.matches(&update("rsi-1")));
+        assert!(!sub.matches(&update("grid-1")));
+    }
+
     #[tokio::test]
     async fn test_broadcast() {
         use rust_decimal_macros::dec;
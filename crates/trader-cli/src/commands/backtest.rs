@@ -14,6 +14,15 @@
 //! # 특정 기간만 백테스트
 //! trader backtest -c config/backtest/haa.toml -s SPY -m US -f 2024-01-01 -t 2024-12-31
 //!
+//! # 다중 심볼 포트폴리오 백테스트 (공통 기간으로 정렬, 종목별 기여 수익률 출력)
+//! trader backtest -c config/backtest/rotation.toml --symbols "SPY,QQQ,IWM" -m US
+//!
+//! # 결과를 CSV로 내보내기 (result_metrics.csv, result_trades.csv, result_equity.csv)
+//! trader backtest -c config/backtest/rsi.toml -s 005930 -m KR -o out/result.csv --format csv
+//!
+//! # 몬테카를로 부트스트랩 (1000회, 블록 길이 5, 낙폭 40% 이상을 파산으로 간주)
+//! trader backtest -c config/backtest/rsi.toml -s 005930 -m KR --monte-carlo 1000 --mc-ruin-dd 40
+//!
 //! # 사용 가능한 전략 목록
 //! trader backtest --list-strategies
 //! ```
@@ -21,14 +30,16 @@
 use std::{collections::HashMap, path::Path, str::FromStr, sync::Arc};
 
 use anyhow::{anyhow, Result};
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::prelude::*;
 use serde::Deserialize;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
-use trader_analytics::backtest::{BacktestConfig, BacktestEngine, BacktestReport};
+use trader_analytics::backtest::{
+    monte_carlo_with, BacktestConfig, BacktestEngine, BacktestReport, MonteCarloConfig,
+};
 use trader_core::{Kline, StrategyContext, Timeframe};
-use trader_data::{Database, DatabaseConfig, OhlcvCache};
+use trader_data::{Database, DatabaseConfig, MissingCandlePolicy, OhlcvCache};
 use trader_strategy::{
     strategies::{
         AssetAllocationStrategy, CompoundMomentumStrategy, DayTradingStrategy, DcaStrategy,
@@ -37,7 +48,11 @@ use trader_strategy::{
     Strategy, StrategyRegistry,
 };
 
-use crate::commands::{chart_gen::RegressionChartGenerator, download::Market};
+use crate::commands::{
+    backtest_export::{export_report, ExportFormat},
+    chart_gen::RegressionChartGenerator,
+    download::Market,
+};
 
 /// 백테스트 CLI 설정
 #[derive(Debug, Clone)]
@@ -46,8 +61,12 @@ pub struct BacktestCliConfig {
     pub config_path: String,
     /// 시장 (KR/US)
     pub market: Market,
-    /// 종목 코드
+    /// 종목 코드 (포트폴리오 모드에서는 첫 번째 종목)
     pub symbol: String,
+    /// 포트폴리오 종목 목록 (2개 이상이면 다중 심볼 백테스트)
+    pub symbols: Vec<String>,
+    /// 벤치마크 종목 (설정 시 Buy & Hold 대비 성과 계산)
+    pub benchmark: Option<String>,
     /// 시작일 (옵션)
     pub start_date: Option<NaiveDate>,
     /// 종료일 (옵션)
@@ -58,14 +77,24 @@ pub struct BacktestCliConfig {
     pub commission_rate: Decimal,
     /// 슬리피지율
     pub slippage_rate: Decimal,
+    /// 연간 무위험 수익률 (샤프/소르티노 계산용, None이면 엔진 기본값 5%)
+    pub risk_free_rate: Option<f64>,
     /// 데이터베이스 URL
     pub db_url: Option<String>,
     /// 결과 저장 경로 (옵션)
     pub output_path: Option<String>,
+    /// 결과 내보내기 형식 (None이면 확장자 기준: .json은 전체 리포트, 그 외 텍스트 요약)
+    pub output_format: Option<ExportFormat>,
     /// 차트 생성 여부
     pub generate_chart: bool,
     /// Signal 분석 리포트 상세 출력
     pub verbose_signals: bool,
+    /// 몬테카를로 시나리오 수 (0이면 실행 안 함)
+    pub monte_carlo_runs: usize,
+    /// 몬테카를로 난수 시드
+    pub monte_carlo_seed: u64,
+    /// 몬테카를로 설정 (리샘플링 방식, 파산 기준 낙폭)
+    pub monte_carlo: MonteCarloConfig,
 }
 
 impl Default for BacktestCliConfig {
@@ -74,15 +103,22 @@ impl Default for BacktestCliConfig {
             config_path: String::new(),
             market: Market::KR,
             symbol: String::new(),
+            symbols: Vec::new(),
+            benchmark: None,
             start_date: None,
             end_date: None,
             initial_capital: Decimal::from(10_000_000), // 1천만원
             commission_rate: Decimal::from_str("0.00015").unwrap(), // 0.015% (한국 증권사 평균)
             slippage_rate: Decimal::from_str("0.0005").unwrap(), // 0.05%
+            risk_free_rate: None,
             db_url: None,
             output_path: None,
+            output_format: None,
             generate_chart: true,  // 기본: 차트 생성
             verbose_signals: true, // 기본: 상세 신호 분석 출력
+            monte_carlo_runs: 0,
+            monte_carlo_seed: 42,
+            monte_carlo: MonteCarloConfig::default(),
         }
     }
 }
@@ -148,8 +184,155 @@ impl StrategyType {
     }
 }
 
+/// 백테스트 입력 데이터 (전략 설정 + 시간 정렬된 캔들)
+pub(crate) struct BacktestInputs {
+    /// 전략 설정 파일
+    pub strategy_config: StrategyConfigFile,
+    /// 전략 타입
+    pub strategy_type: StrategyType,
+    /// DB에서 실제 사용된 주 심볼
+    pub used_symbol: String,
+    /// 주 심볼 캔들
+    pub klines: Vec<Kline>,
+    /// 추가 심볼 캔들 (포트폴리오/멀티 자산 전략)
+    pub multi_asset_klines: HashMap<String, Vec<Kline>>,
+    /// 벤치마크 (DB에서 실제 사용된 심볼, 일봉 캔들)
+    pub benchmark: Option<(String, Vec<Kline>)>,
+}
+
 /// 백테스트 실행
 pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
+    // 1~6. 전략 설정 로드, 캔들 로드 및 공통 기간 정렬
+    let inputs = prepare_backtest_inputs(&config).await?;
+    let is_portfolio = config.symbols.len() > 1;
+
+    // 7. 백테스트 엔진 설정
+    let backtest_config = build_backtest_config(
+        &config,
+        &inputs.strategy_type,
+        &inputs.strategy_config.parameters,
+    );
+
+    // 8. 전략별 백테스트 실행
+    let mut report = execute_backtest(
+        inputs.strategy_type,
+        backtest_config,
+        &inputs.klines,
+        &inputs.multi_asset_klines,
+        &inputs.strategy_config.parameters,
+        config.initial_capital,
+    )
+    .await?;
+
+    // 엔진이 벤치마크 캔들을 찾지 못한 경우(컨텍스트 미등록) 별도 로드한 데이터로 비교
+    if report.benchmark.is_none() {
+        if let Some((benchmark_symbol, benchmark_klines)) = &inputs.benchmark {
+            report.compare_with_benchmark(benchmark_symbol, benchmark_klines);
+        }
+    }
+
+    // 8. 결과 출력
+    println!("\n{}", report.summary());
+    if is_portfolio {
+        println!("\n{}", format_symbol_contributions(&report));
+    }
+
+    // 9. Signal 분석 리포트 (Claude 검증용 상세 텍스트)
+    if config.verbose_signals {
+        println!("\n{}", generate_signal_analysis(&report));
+    }
+
+    // 몬테카를로 부트스트랩 (옵션)
+    let monte_carlo = if config.monte_carlo_runs > 0 {
+        match monte_carlo_with(
+            &report,
+            config.monte_carlo_runs,
+            config.monte_carlo_seed,
+            &config.monte_carlo,
+        ) {
+            Ok(mc) => {
+                println!("\n{}", mc.summary());
+                Some(mc)
+            }
+            Err(e) => {
+                println!("\n⚠️ 몬테카를로 분석 생략: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // 10. 차트 생성 (사용자 확인용 이미지)
+    if config.generate_chart {
+        // regression_charts 디렉토리 생성
+        let charts_dir = Path::new("regression_charts");
+        if !charts_dir.exists() {
+            std::fs::create_dir_all(charts_dir).ok();
+        }
+
+        let chart_filename = config
+            .output_path
+            .as_ref()
+            .map(|p| {
+                let filename = Path::new(p)
+                    .file_name()
+                    .and_then(|f| f.to_str())
+                    .unwrap_or("backtest");
+                filename
+                    .replace(".json", "_chart.png")
+                    .replace(".txt", "_chart.png")
+            })
+            .unwrap_or_else(|| {
+                format!(
+                    "backtest_{}_{}_chart.png",
+                    config.symbol,
+                    chrono::Utc::now().format("%Y%m%d_%H%M%S")
+                )
+            });
+
+        let chart_path = charts_dir.join(&chart_filename);
+
+        let generator = RegressionChartGenerator::new();
+        match generator.generate_combined_chart(&report, &inputs.strategy_config.name, &chart_path)
+        {
+            Ok(()) => {
+                println!("\n📊 차트 저장: {}", chart_path.display());
+            }
+            Err(e) => {
+                println!("\n⚠️ 차트 생성 실패: {}", e);
+            }
+        }
+
+        if let Some(mc) = &monte_carlo {
+            let mc_path = charts_dir.join(chart_filename.replace("_chart.png", "_monte_carlo.png"));
+            match generator.generate_monte_carlo_chart(mc, &inputs.strategy_config.name, &mc_path) {
+                Ok(()) => println!("📊 몬테카를로 차트 저장: {}", mc_path.display()),
+                Err(e) => println!("⚠️ 몬테카를로 차트 생성 실패: {}", e),
+            }
+        }
+    }
+
+    // 11. 결과 저장 (옵션)
+    if let Some(output_path) = &config.output_path {
+        match config.output_format {
+            Some(format) => {
+                for path in export_report(&report, output_path, format)? {
+                    info!("Report exported to: {}", path.display());
+                }
+            }
+            None => {
+                save_report(&report, output_path)?;
+                info!("Report saved to: {}", output_path);
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// 백테스트 입력 준비: 전략 설정 로드, DB 연결, 캔들 로드 및 공통 기간 정렬.
+pub(crate) async fn prepare_backtest_inputs(config: &BacktestCliConfig) -> Result<BacktestInputs> {
     info!(
         "Running backtest for {} {} with config: {}",
         match config.market {
@@ -200,45 +383,16 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
     // 4. OhlcvCache 생성 및 심볼 이름 준비
     let ohlcv_cache = OhlcvCache::new(db.pool().clone());
 
-    // DB에 저장된 심볼 형식에 맞춰 시도 (Yahoo 형식 → 원본 순서)
-    // DB에는 "005930" 또는 "005930.KS" 형식으로 저장될 수 있음
-    let symbol_candidates = match config.market {
-        Market::KR => vec![
-            config.symbol.clone(),           // 먼저 원본 (005930)
-            format!("{}.KS", config.symbol), // 코스피 형식
-            format!("{}.KQ", config.symbol), // 코스닥 형식
-        ],
-        Market::US => vec![config.symbol.clone()],
-    };
-
-    let mut klines = Vec::new();
-    let mut used_symbol = String::new();
-
-    for symbol in &symbol_candidates {
-        info!("Trying symbol: {}", symbol);
-        let loaded = load_klines_from_db(
-            &ohlcv_cache,
-            symbol,
-            config.start_date,
-            config.end_date,
-            default_tf,
-            secondary_tfs,
-        )
-        .await?;
-        if !loaded.is_empty() {
-            klines = loaded;
-            used_symbol = symbol.clone();
-            break;
-        }
-    }
-
-    if klines.is_empty() {
-        return Err(anyhow!(
-            "No historical data found for {} (tried: {:?}). Run import-db first.",
-            config.symbol,
-            symbol_candidates
-        ));
-    }
+    let (used_symbol, mut klines) = load_symbol_klines(
+        &ohlcv_cache,
+        config.market,
+        &config.symbol,
+        config.start_date,
+        config.end_date,
+        default_tf,
+        secondary_tfs,
+    )
+    .await?;
 
     info!(
         "Loaded {} klines for {} (symbol: {})",
@@ -247,18 +401,51 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
         used_symbol
     );
 
-    // 5. 멀티 자산 전략: 추가 심볼 데이터 로드
+    // 5-1. 포트폴리오 모드: 나머지 종목 로드
+    let is_portfolio = config.symbols.len() > 1;
     let mut multi_asset_klines: HashMap<String, Vec<Kline>> = HashMap::new();
+    if is_portfolio {
+        multi_asset_klines.insert(used_symbol.clone(), klines.clone());
+        for symbol in config.symbols.iter().skip(1) {
+            match load_symbol_klines(
+                &ohlcv_cache,
+                config.market,
+                symbol,
+                config.start_date,
+                config.end_date,
+                default_tf,
+                secondary_tfs,
+            )
+            .await
+            {
+                Ok((used, loaded)) => {
+                    info!("  {} 심볼: {} 캔들 로드", used, loaded.len());
+                    multi_asset_klines.insert(used, loaded);
+                }
+                Err(e) => warn!("  {} 심볼 건너뜀: {}", symbol, e),
+            }
+        }
+        info!(
+            "포트폴리오 데이터 로드 완료: {}/{} 심볼",
+            multi_asset_klines.len(),
+            config.symbols.len()
+        );
+    }
+
+    // 5-2. 멀티 자산 전략: 유니버스 심볼 데이터 로드
     if is_multi_asset_strategy(&strategy_type) {
         let universe = extract_universe(&strategy_type, &strategy_config.parameters);
         info!("멀티 자산 전략 감지 - 유니버스: {:?}", universe);
 
         for symbol in &universe {
-            // 이미 로드된 주 심볼은 건너뜀
+            // 이미 로드된 주 심볼/포트폴리오 심볼은 건너뜀
             if symbol == &used_symbol || symbol == &config.symbol {
                 multi_asset_klines.insert(symbol.clone(), klines.clone());
                 continue;
             }
+            if multi_asset_klines.contains_key(symbol) {
+                continue;
+            }
 
             let loaded = load_klines_from_db(
                 &ohlcv_cache,
@@ -284,12 +471,79 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
         );
     }
 
-    // 7. 백테스트 엔진 설정
+    // 6. 여러 심볼의 데이터 기간이 다르면 공통 기간(교집합)으로 정렬
+    if multi_asset_klines.len() > 1 {
+        match common_time_range(multi_asset_klines.values()) {
+            Some((start, end)) => {
+                println!(
+                    "  📅 공통 시간 범위: {} ~ {}",
+                    start.format("%Y-%m-%d"),
+                    end.format("%Y-%m-%d")
+                );
+                retain_time_range(&mut multi_asset_klines, start, end);
+                klines.retain(|k| k.open_time >= start && k.close_time <= end);
+            }
+            None => println!("  ⚠️ 공통 시간 범위를 찾을 수 없음, 요청 범위 사용"),
+        }
+        if klines.is_empty() {
+            return Err(anyhow!(
+                "No overlapping data for {} within the common period",
+                used_symbol
+            ));
+        }
+    }
+
+    // 7. 벤치마크 캔들 로드 (일봉, 실패 시 경고 후 비교 생략)
+    let benchmark = match &config.benchmark {
+        Some(benchmark) => {
+            match load_symbol_klines(
+                &ohlcv_cache,
+                config.market,
+                benchmark,
+                config.start_date,
+                config.end_date,
+                "1d",
+                &[],
+            )
+            .await
+            {
+                Ok((used, loaded)) => {
+                    info!("벤치마크 {}: {} 캔들 로드", used, loaded.len());
+                    Some((used, loaded))
+                }
+                Err(e) => {
+                    warn!("벤치마크 {} 데이터 로드 실패, 비교 생략: {}", benchmark, e);
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+
+    Ok(BacktestInputs {
+        strategy_config,
+        strategy_type,
+        used_symbol,
+        klines,
+        multi_asset_klines,
+        benchmark,
+    })
+}
+
+/// 백테스트 엔진 설정 생성.
+///
+/// 전략 파라미터의 `exit_config`(손절/익절), `max_position_size_pct`와
+/// 전략별 max_positions를 반영합니다.
+pub(crate) fn build_backtest_config(
+    config: &BacktestCliConfig,
+    strategy_type: &StrategyType,
+    params: &serde_json::Value,
+) -> BacktestConfig {
     // 전략별 max_positions 추출
-    let max_positions = extract_max_positions(&strategy_type, &strategy_config.parameters);
+    let max_positions = extract_max_positions(strategy_type, params);
 
     // exit_config에서 리스크 관리 파라미터 추출
-    let exit_config = strategy_config.parameters.get("exit_config");
+    let exit_config = params.get("exit_config");
 
     let stop_loss_enabled = exit_config
         .and_then(|c| c.get("stop_loss_enabled"))
@@ -314,14 +568,13 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
         .unwrap_or(Decimal::new(10, 2)); // 기본 10%
 
     // max_position_size_pct 추출 (기본 20%)
-    let max_position_size_pct = strategy_config
-        .parameters
+    let max_position_size_pct = params
         .get("max_position_size_pct")
         .and_then(|v| v.as_f64())
         .map(|v| Decimal::from_f64_retain(v / 100.0).unwrap_or(Decimal::new(2, 1)))
         .unwrap_or(Decimal::new(2, 1)); // 20%
 
-    let backtest_config = BacktestConfig::new(config.initial_capital)
+    let mut backtest_config = BacktestConfig::new(config.initial_capital)
         .with_commission_rate(config.commission_rate)
         .with_slippage_rate(config.slippage_rate)
         .with_max_positions(max_positions)
@@ -330,83 +583,45 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
         .with_stop_loss(stop_loss_enabled, stop_loss_pct)
         .with_take_profit(take_profit_enabled, take_profit_pct);
 
-    // 8. 전략별 백테스트 실행
-    let report = if is_multi_asset_strategy(&strategy_type) {
+    if let Some(rate) = config.risk_free_rate {
+        backtest_config = backtest_config.with_risk_free_rate(rate);
+    }
+
+    match &config.benchmark {
+        Some(benchmark) => backtest_config.with_benchmark_symbol(benchmark.clone()),
+        None => backtest_config,
+    }
+}
+
+/// 전략 타입에 따라 백테스트 실행 (멀티 자산 전략 / 단일·포트폴리오 전략 분기).
+pub(crate) async fn execute_backtest(
+    strategy_type: StrategyType,
+    backtest_config: BacktestConfig,
+    klines: &[Kline],
+    multi_asset_klines: &HashMap<String, Vec<Kline>>,
+    params: &serde_json::Value,
+    initial_capital: Decimal,
+) -> Result<BacktestReport> {
+    if is_multi_asset_strategy(&strategy_type) {
         run_multi_asset_backtest(
             strategy_type,
             backtest_config,
-            &klines,
-            &multi_asset_klines,
-            &strategy_config.parameters,
-            config.initial_capital,
+            klines,
+            multi_asset_klines,
+            params,
+            initial_capital,
         )
-        .await?
+        .await
     } else {
         run_strategy_backtest(
             strategy_type,
             backtest_config,
-            &klines,
-            &strategy_config.parameters,
+            klines,
+            multi_asset_klines,
+            params,
         )
-        .await?
-    };
-
-    // 8. 결과 출력
-    println!("\n{}", report.summary());
-
-    // 9. Signal 분석 리포트 (Claude 검증용 상세 텍스트)
-    if config.verbose_signals {
-        println!("\n{}", generate_signal_analysis(&report));
-    }
-
-    // 10. 차트 생성 (사용자 확인용 이미지)
-    if config.generate_chart {
-        // regression_charts 디렉토리 생성
-        let charts_dir = Path::new("regression_charts");
-        if !charts_dir.exists() {
-            std::fs::create_dir_all(charts_dir).ok();
-        }
-
-        let chart_filename = config
-            .output_path
-            .as_ref()
-            .map(|p| {
-                let filename = Path::new(p)
-                    .file_name()
-                    .and_then(|f| f.to_str())
-                    .unwrap_or("backtest");
-                filename
-                    .replace(".json", "_chart.png")
-                    .replace(".txt", "_chart.png")
-            })
-            .unwrap_or_else(|| {
-                format!(
-                    "backtest_{}_{}_chart.png",
-                    config.symbol,
-                    chrono::Utc::now().format("%Y%m%d_%H%M%S")
-                )
-            });
-
-        let chart_path = charts_dir.join(&chart_filename);
-
-        let generator = RegressionChartGenerator::new();
-        match generator.generate_combined_chart(&report, &strategy_config.name, &chart_path) {
-            Ok(()) => {
-                println!("\n📊 차트 저장: {}", chart_path.display());
-            }
-            Err(e) => {
-                println!("\n⚠️ 차트 생성 실패: {}", e);
-            }
-        }
+        .await
     }
-
-    // 11. 결과 저장 (옵션)
-    if let Some(output_path) = &config.output_path {
-        save_report(&report, output_path)?;
-        info!("Report saved to: {}", output_path);
-    }
-
-    Ok(report)
 }
 
 /// 전략별 max_positions 추출
@@ -563,10 +778,14 @@ fn inject_variant(params: &serde_json::Value, variant: &str) -> serde_json::Valu
 }
 
 /// 전략별 백테스트 실행 (제네릭 문제 해결을 위한 매크로 대신 개별 함수)
+///
+/// `extra_klines`가 있으면(포트폴리오 모드) StrategyContext에 등록하여
+/// 엔진이 각 시점마다 모든 심볼의 시장 데이터를 전략에 전달합니다.
 async fn run_strategy_backtest(
     strategy_type: StrategyType,
     backtest_config: BacktestConfig,
     klines: &[Kline],
+    extra_klines: &HashMap<String, Vec<Kline>>,
     params: &serde_json::Value,
 ) -> Result<BacktestReport> {
     // StrategyContext 기반 전략은 run() 사용
@@ -581,6 +800,12 @@ async fn run_strategy_backtest(
         });
 
     let context = Arc::new(RwLock::new(StrategyContext::default()));
+    {
+        let mut ctx = context.write().await;
+        for (symbol, symbol_klines) in extra_klines {
+            ctx.update_klines(symbol, Timeframe::D1, symbol_klines.clone());
+        }
+    }
 
     // DCA 계열 전략에 variant 필드 주입 (설정 파일에 없으면 strategy_type 기반으로 설정)
     let params = match strategy_type {
@@ -876,7 +1101,7 @@ async fn load_klines_from_db(
         };
 
         match ohlcv_cache
-            .get_cached_klines_range(symbol, tf, start, end)
+            .get_cached_klines_range(symbol, tf, start, end, MissingCandlePolicy::None)
             .await
         {
             Ok(klines) if !klines.is_empty() => {
@@ -910,6 +1135,122 @@ async fn load_klines_from_db(
     Ok(Vec::new())
 }
 
+/// 종목 코드 후보(KR: 원본 → .KS → .KQ)를 순서대로 시도하여 캔들 데이터 로드.
+///
+/// DB에 저장된 심볼 형식에 맞춰 시도합니다. 반환값은 (실제 사용된 심볼, 캔들)입니다.
+async fn load_symbol_klines(
+    ohlcv_cache: &OhlcvCache,
+    market: Market,
+    symbol: &str,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+    default_timeframe: &str,
+    secondary_timeframes: &[&str],
+) -> Result<(String, Vec<Kline>)> {
+    let symbol_candidates = match market {
+        Market::KR => vec![
+            symbol.to_string(),       // 먼저 원본 (005930)
+            format!("{}.KS", symbol), // 코스피 형식
+            format!("{}.KQ", symbol), // 코스닥 형식
+        ],
+        Market::US => vec![symbol.to_string()],
+    };
+
+    for candidate in &symbol_candidates {
+        info!("Trying symbol: {}", candidate);
+        let loaded = load_klines_from_db(
+            ohlcv_cache,
+            candidate,
+            start_date,
+            end_date,
+            default_timeframe,
+            secondary_timeframes,
+        )
+        .await?;
+        if !loaded.is_empty() {
+            return Ok((candidate.clone(), loaded));
+        }
+    }
+
+    Err(anyhow!(
+        "No historical data found for {} (tried: {:?}). Run import-db first.",
+        symbol,
+        symbol_candidates
+    ))
+}
+
+/// 여러 심볼 캔들의 공통 시간 범위(교집합) 계산.
+///
+/// 가장 늦게 시작한 심볼의 시작 시각 ~ 가장 일찍 끝난 심볼의 종료 시각을 반환합니다.
+/// 데이터가 없거나 교집합이 비어 있으면 None을 반환합니다.
+pub(crate) fn common_time_range<'a>(
+    klines_by_symbol: impl IntoIterator<Item = &'a Vec<Kline>>,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut common_start: Option<DateTime<Utc>> = None;
+    let mut common_end: Option<DateTime<Utc>> = None;
+
+    for symbol_klines in klines_by_symbol {
+        let (Some(first), Some(last)) = (symbol_klines.first(), symbol_klines.last()) else {
+            continue;
+        };
+        common_start = Some(common_start.map_or(first.open_time, |cs| cs.max(first.open_time)));
+        common_end = Some(common_end.map_or(last.close_time, |ce| ce.min(last.close_time)));
+    }
+
+    match (common_start, common_end) {
+        (Some(cs), Some(ce)) if cs < ce => Some((cs, ce)),
+        _ => None,
+    }
+}
+
+/// 모든 심볼의 캔들을 주어진 시간 범위로 필터링.
+pub(crate) fn retain_time_range(
+    klines_by_symbol: &mut HashMap<String, Vec<Kline>>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) {
+    for klines in klines_by_symbol.values_mut() {
+        klines.retain(|k| k.open_time >= start && k.close_time <= end);
+    }
+}
+
+/// 포트폴리오 종목별 기여 수익률 표 생성.
+///
+/// 기여 수익률은 종목별 순손익 / 초기 자본 기준이므로 합계가 포트폴리오 실현 수익률과 일치합니다.
+fn format_symbol_contributions(report: &BacktestReport) -> String {
+    let mut rows: Vec<_> = report.performance_by_symbol.iter().collect();
+    rows.sort_by_key(|(_, performance)| std::cmp::Reverse(performance.total_return_pct));
+
+    let mut output = String::new();
+    output.push_str("종목별 기여 수익률\n");
+    output.push_str("═══════════════════════════════════════════════════════════\n");
+    output.push_str(&format!(
+        "{:<12} {:>8} {:>10} {:>16} {:>12}\n",
+        "종목", "거래", "승률(%)", "순손익", "기여(%)"
+    ));
+    output.push_str("───────────────────────────────────────────────────────────\n");
+
+    let mut total_contribution = Decimal::ZERO;
+    for (symbol, metrics) in rows {
+        total_contribution += metrics.total_return_pct;
+        output.push_str(&format!(
+            "{:<12} {:>8} {:>10} {:>16} {:>+12}\n",
+            symbol,
+            metrics.total_trades,
+            metrics.win_rate_pct.round_dp(1),
+            metrics.net_profit.round_dp(0),
+            metrics.total_return_pct.round_dp(2)
+        ));
+    }
+    output.push_str("───────────────────────────────────────────────────────────\n");
+    output.push_str(&format!(
+        "{:<12} {:>48}\n",
+        "합계",
+        format!("{:+}", total_contribution.round_dp(2))
+    ));
+    output
+}
+
 /// 백테스트 리포트를 파일로 저장
 fn save_report(report: &BacktestReport, path: &str) -> Result<()> {
     let path = Path::new(path);
@@ -1289,6 +1630,50 @@ mod tests {
         assert_eq!(symbol.quote, "USD");
     }
 
+    fn kline_at(day: u32) -> Kline {
+        let open_time = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 1, day, 0, 0, 0).unwrap();
+        Kline {
+            ticker: "TEST".to_string(),
+            timeframe: Timeframe::D1,
+            open_time,
+            open: Decimal::ONE,
+            high: Decimal::ONE,
+            low: Decimal::ONE,
+            close: Decimal::ONE,
+            volume: Decimal::ONE,
+            close_time: open_time + chrono::Duration::hours(23),
+            quote_volume: None,
+            num_trades: None,
+            synthetic: false,
+        }
+    }
+
+    #[test]
+    fn test_common_time_range_intersects_symbols() {
+        let mut all_klines: HashMap<String, Vec<Kline>> = HashMap::new();
+        all_klines.insert("SPY".to_string(), (1..=20).map(kline_at).collect());
+        all_klines.insert("QQQ".to_string(), (5..=25).map(kline_at).collect());
+        all_klines.insert("IWM".to_string(), (3..=15).map(kline_at).collect());
+
+        let (start, end) = common_time_range(all_klines.values()).unwrap();
+        assert_eq!(start, kline_at(5).open_time);
+        assert_eq!(end, kline_at(15).close_time);
+
+        retain_time_range(&mut all_klines, start, end);
+        for klines in all_klines.values() {
+            assert_eq!(klines.len(), 11);
+        }
+    }
+
+    #[test]
+    fn test_common_time_range_disjoint() {
+        let all_klines = [
+            (1..=5).map(kline_at).collect::<Vec<_>>(),
+            (10..=15).map(kline_at).collect::<Vec<_>>(),
+        ];
+        assert!(common_time_range(all_klines.iter()).is_none());
+    }
+
     #[test]
     fn test_strategy_type_parsing() {
         assert!(matches!(
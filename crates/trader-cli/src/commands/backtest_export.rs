@@ -0,0 +1,309 @@
+//! 백테스트 결과 내보내기 (JSON/CSV).
+//!
+//! `BacktestReport`를 성과 지표(`metrics`), 거래 내역(`trades`), 자산 곡선(`equity_curve`)으로
+//! 나누어 외부 분석 도구(pandas 등)에서 바로 읽을 수 있는 형식으로 저장합니다.
+//!
+//! - 모든 금액/비율(`Decimal`)은 정밀도 손실을 막기 위해 문자열로 직렬화합니다.
+//! - 모든 시각은 UTC ISO8601(RFC 3339) 문자열입니다. 예: `2024-01-02T00:00:00Z`
+//!
+//! # 출력 형식
+//!
+//! ## JSON (`--format json`)
+//!
+//! ```json
+//! {
+//!   "metrics": { "total_return_pct": "12.34", "start_time": "2024-01-02T00:00:00Z", ... },
+//!   "trades": [ { "symbol": "SPY", "side": "BUY", "entry_price": "470.5", ... } ],
+//!   "equity_curve": [ { "timestamp": "2024-01-02T00:00:00Z", "equity": "10000000", ... } ]
+//! }
+//! ```
+//!
+//! ## CSV (`--format csv`)
+//!
+//! 출력 경로 `out/result.csv`에 대해 다음 3개 파일을 생성합니다.
+//!
+//! | 파일 | 헤더 |
+//! |------|------|
+//! | `out/result_metrics.csv` | [`MetricsRecord`] 필드 (1행) |
+//! | `out/result_trades.csv` | `symbol,side,entry_time,exit_time,entry_price,exit_price,quantity,fees,pnl,return_pct,entry_reason,exit_reason` |
+//! | `out/result_equity.csv` | `timestamp,equity,drawdown_pct` |
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use trader_analytics::{
+    backtest::BacktestReport,
+    performance::{EquityPoint, RoundTrip},
+};
+
+/// 내보내기 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// 단일 JSON 파일 (metrics, trades, equity_curve)
+    Json,
+    /// 여러 CSV 파일 (지표/거래/자산 곡선)
+    Csv,
+}
+
+impl ExportFormat {
+    /// 문자열에서 파싱 (json, csv)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// 성과 지표 레코드 (JSON `metrics` 객체 / `*_metrics.csv` 1행)
+#[derive(Debug, Serialize)]
+pub struct MetricsRecord {
+    pub start_time: String,
+    pub end_time: String,
+    pub data_points: usize,
+    pub initial_capital: String,
+    pub total_return_pct: String,
+    pub annualized_return_pct: String,
+    pub net_profit: String,
+    pub sharpe_ratio: String,
+    pub sortino_ratio: String,
+    pub calmar_ratio: String,
+    pub ulcer_index: String,
+    pub max_drawdown_pct: String,
+    pub win_rate_pct: String,
+    pub profit_factor: String,
+    pub total_trades: usize,
+    pub winning_trades: usize,
+    pub losing_trades: usize,
+    pub avg_win: String,
+    pub avg_loss: String,
+    pub largest_win: String,
+    pub largest_loss: String,
+    pub avg_holding_hours: String,
+    pub expectancy: String,
+    pub total_orders: usize,
+    pub total_commission: String,
+    pub total_slippage: String,
+}
+
+/// 거래 내역 레코드 (라운드트립 1건)
+#[derive(Debug, Serialize)]
+pub struct TradeRecord {
+    pub symbol: String,
+    pub side: String,
+    pub entry_time: String,
+    pub exit_time: String,
+    pub entry_price: String,
+    pub exit_price: String,
+    pub quantity: String,
+    pub fees: String,
+    pub pnl: String,
+    pub return_pct: String,
+    pub entry_reason: String,
+    pub exit_reason: String,
+}
+
+/// 자산 곡선 레코드
+#[derive(Debug, Serialize)]
+pub struct EquityRecord {
+    pub timestamp: String,
+    pub equity: String,
+    pub drawdown_pct: String,
+}
+
+/// JSON 내보내기 문서
+#[derive(Debug, Serialize)]
+pub struct BacktestExport {
+    pub metrics: MetricsRecord,
+    pub trades: Vec<TradeRecord>,
+    pub equity_curve: Vec<EquityRecord>,
+}
+
+impl BacktestExport {
+    /// 백테스트 리포트에서 내보내기 문서 생성
+    pub fn from_report(report: &BacktestReport) -> Self {
+        Self {
+            metrics: metrics_record(report),
+            trades: report.trades.iter().map(trade_record).collect(),
+            equity_curve: report.equity_curve.iter().map(equity_record).collect(),
+        }
+    }
+}
+
+/// ISO8601(RFC 3339, UTC `Z` 표기) 문자열 변환
+pub(crate) fn iso8601(time: &DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Decimal을 정규화된 문자열로 변환 (불필요한 소수점 0 제거)
+pub(crate) fn decimal_str(value: Decimal) -> String {
+    value.normalize().to_string()
+}
+
+fn metrics_record(report: &BacktestReport) -> MetricsRecord {
+    let m = &report.metrics;
+    MetricsRecord {
+        start_time: iso8601(&report.start_time),
+        end_time: iso8601(&report.end_time),
+        data_points: report.data_points,
+        initial_capital: decimal_str(report.config.initial_capital),
+        total_return_pct: decimal_str(m.total_return_pct),
+        annualized_return_pct: decimal_str(m.annualized_return_pct),
+        net_profit: decimal_str(m.net_profit),
+        sharpe_ratio: decimal_str(m.sharpe_ratio),
+        sortino_ratio: decimal_str(m.sortino_ratio),
+        calmar_ratio: decimal_str(m.calmar_ratio),
+        ulcer_index: decimal_str(m.ulcer_index),
+        max_drawdown_pct: decimal_str(m.max_drawdown_pct),
+        win_rate_pct: decimal_str(m.win_rate_pct),
+        profit_factor: decimal_str(m.profit_factor),
+        total_trades: m.total_trades,
+        winning_trades: m.winning_trades,
+        losing_trades: m.losing_trades,
+        avg_win: decimal_str(m.avg_win),
+        avg_loss: decimal_str(m.avg_loss),
+        largest_win: decimal_str(m.largest_win),
+        largest_loss: decimal_str(m.largest_loss),
+        avg_holding_hours: decimal_str(m.avg_holding_hours),
+        expectancy: decimal_str(m.expectancy),
+        total_orders: report.total_orders,
+        total_commission: decimal_str(report.total_commission),
+        total_slippage: decimal_str(report.total_slippage),
+    }
+}
+
+fn trade_record(trade: &RoundTrip) -> TradeRecord {
+    TradeRecord {
+        symbol: trade.symbol.clone(),
+        side: trade.side.to_string(),
+        entry_time: iso8601(&trade.entry_time),
+        exit_time: iso8601(&trade.exit_time),
+        entry_price: decimal_str(trade.entry_price),
+        exit_price: decimal_str(trade.exit_price),
+        quantity: decimal_str(trade.quantity),
+        fees: decimal_str(trade.fees),
+        pnl: decimal_str(trade.pnl),
+        return_pct: decimal_str(trade.return_pct),
+        entry_reason: trade.entry_reason.clone().unwrap_or_default(),
+        exit_reason: trade.exit_reason.clone().unwrap_or_default(),
+    }
+}
+
+fn equity_record(point: &EquityPoint) -> EquityRecord {
+    EquityRecord {
+        timestamp: iso8601(&point.timestamp),
+        equity: decimal_str(point.equity),
+        drawdown_pct: decimal_str(point.drawdown_pct),
+    }
+}
+
+/// CSV 파일 경로 생성 (`dir/stem_{suffix}.csv`)
+fn csv_path(base: &Path, suffix: &str) -> PathBuf {
+    let stem = base
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("backtest");
+    base.with_file_name(format!("{}_{}.csv", stem, suffix))
+}
+
+fn write_csv<T: Serialize>(path: &Path, records: &[T]) -> Result<()> {
+    let mut wtr = csv::Writer::from_path(path)
+        .with_context(|| format!("Failed to create CSV writer: {}", path.display()))?;
+    for record in records {
+        wtr.serialize(record)
+            .context("Failed to write CSV record")?;
+    }
+    wtr.flush().context("Failed to flush CSV writer")?;
+    Ok(())
+}
+
+/// 백테스트 리포트를 지정 형식으로 내보내기.
+///
+/// 생성된 파일 경로 목록을 반환합니다.
+pub fn export_report(
+    report: &BacktestReport,
+    path: &str,
+    format: ExportFormat,
+) -> Result<Vec<PathBuf>> {
+    let path = Path::new(path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let export = BacktestExport::from_report(report);
+
+    match format {
+        ExportFormat::Json => {
+            let content = serde_json::to_string_pretty(&export)?;
+            std::fs::write(path, content)?;
+            Ok(vec![path.to_path_buf()])
+        }
+        ExportFormat::Csv => {
+            let metrics_path = csv_path(path, "metrics");
+            let trades_path = csv_path(path, "trades");
+            let equity_path = csv_path(path, "equity");
+
+            write_csv(&metrics_path, std::slice::from_ref(&export.metrics))?;
+            write_csv(&trades_path, &export.trades)?;
+            write_csv(&equity_path, &export.equity_curve)?;
+
+            Ok(vec![metrics_path, trades_path, equity_path])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+
+    use super::*;
+
+    #[test]
+    fn test_export_format_parse() {
+        assert_eq!(ExportFormat::parse("JSON"), Some(ExportFormat::Json));
+        assert_eq!(ExportFormat::parse("csv"), Some(ExportFormat::Csv));
+        assert_eq!(ExportFormat::parse("xlsx"), None);
+    }
+
+    #[test]
+    fn test_trade_record_uses_strings_and_iso8601() {
+        let entry = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let exit = Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap();
+        let trade = RoundTrip::new(
+            "SPY",
+            Side::Buy,
+            dec!(470.50),
+            dec!(480.25),
+            dec!(10),
+            dec!(1.5),
+            entry,
+            exit,
+        );
+
+        let json = serde_json::to_value(trade_record(&trade)).unwrap();
+        assert_eq!(json["side"], "BUY");
+        assert_eq!(json["entry_time"], "2024-01-02T00:00:00Z");
+        assert_eq!(json["entry_price"], "470.5");
+        assert_eq!(json["pnl"], "96");
+        assert!(json["return_pct"].is_string());
+    }
+
+    #[test]
+    fn test_csv_path_uses_output_stem() {
+        let base = Path::new("results/spy_rsi.csv");
+        assert_eq!(
+            csv_path(base, "trades"),
+            PathBuf::from("results/spy_rsi_trades.csv")
+        );
+        assert_eq!(
+            csv_path(Path::new("spy_rsi"), "equity"),
+            PathBuf::from("spy_rsi_equity.csv")
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! 백테스트 실행 이력 조회/비교/정리.
+//!
+//! API(`/api/v1/backtest/results/history`, `/diff`, `/prune`)와 같은 저장소를 사용합니다.
+
+use anyhow::{Context, Result};
+use sqlx::PgPool;
+use tracing::info;
+use trader_analytics::backtest::{
+    build_performance_trend, BacktestRunDiff, RetentionPolicy, TrendPoint,
+};
+use trader_api::repository::BacktestResultsRepository;
+use trader_data::{Database, DatabaseConfig};
+use uuid::Uuid;
+
+/// 이력 명령 동작.
+#[derive(Debug, Clone)]
+pub enum HistoryAction {
+    /// 전략별 실행 이력 및 성과 추이
+    List { strategy_id: String, limit: i64 },
+    /// 두 실행 비교
+    Diff { base: Uuid, target: Uuid },
+    /// 보존 정책에 따른 정리
+    Prune {
+        strategy_id: Option<String>,
+        policy: RetentionPolicy,
+    },
+}
+
+/// 이력 명령 설정.
+#[derive(Debug, Clone)]
+pub struct BacktestHistoryConfig {
+    /// 실행할 동작
+    pub action: HistoryAction,
+    /// JSON 출력 여부
+    pub json: bool,
+    /// 데이터베이스 URL
+    pub db_url: Option<String>,
+}
+
+/// 이력 명령 실행.
+pub async fn run_backtest_history(config: BacktestHistoryConfig) -> Result<()> {
+    let pool = connect(config.db_url.clone()).await?;
+
+    let result = match &config.action {
+        HistoryAction::List { strategy_id, limit } => {
+            let runs = BacktestResultsRepository::list_runs(&pool, strategy_id, *limit)
+                .await
+                .context("실행 이력 조회 실패")?;
+            let trend = build_performance_trend(&runs);
+            info!("{} 실행 이력 {}건", strategy_id, trend.len());
+
+            if config.json {
+                println!("{}", serde_json::to_string_pretty(&trend)?);
+            } else {
+                println!("{}", format_trend(strategy_id, &trend));
+            }
+            Ok(())
+        }
+        HistoryAction::Diff { base, target } => {
+            let (base_run, target_run) = tokio::try_join!(
+                BacktestResultsRepository::get_run(&pool, *base),
+                BacktestResultsRepository::get_run(&pool, *target),
+            )
+            .context("실행 조회 실패")?;
+
+            let base_run =
+                base_run.with_context(|| format!("결과를 찾을 수 없습니다: {}", base))?;
+            let target_run =
+                target_run.with_context(|| format!("결과를 찾을 수 없습니다: {}", target))?;
+            let diff = BacktestRunDiff::between(&base_run, &target_run);
+
+            if config.json {
+                println!("{}", serde_json::to_string_pretty(&diff)?);
+            } else {
+                println!("{}", format_diff(&diff));
+            }
+            Ok(())
+        }
+        HistoryAction::Prune {
+            strategy_id,
+            policy,
+        } => {
+            let pruned = BacktestResultsRepository::prune(&pool, strategy_id.as_deref(), *policy)
+                .await
+                .context("백테스트 결과 정리 실패")?;
+            println!("🧹 정리된 백테스트 결과: {}건", pruned);
+            Ok(())
+        }
+    };
+
+    pool.close().await;
+    result
+}
+
+async fn connect(db_url: Option<String>) -> Result<PgPool> {
+    let db_url = db_url
+        .or_else(|| std::env::var("DATABASE_URL").ok())
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "DATABASE_URL not found. Set DATABASE_URL environment variable or use --db-url flag"
+            )
+        })?;
+
+    let db = Database::connect(&DatabaseConfig::for_cli(db_url))
+        .await
+        .context("데이터베이스 연결 실패")?;
+    Ok(db.pool().clone())
+}
+
+/// 성과 추이 테이블 출력.
+fn format_trend(strategy_id: &str, trend: &[TrendPoint]) -> String {
+    let mut output = format!("\n📈 {} 백테스트 실행 이력 (오래된 순)\n", strategy_id);
+    output.push_str(&format!(
+        "{:<38} {:<20} {:<10} {:>10} {:>10} {:>8} {:>10}\n",
+        "ID", "실행 시각", "설정", "수익률%", "변화%p", "샤프", "MDD%"
+    ));
+    output.push_str(&"-".repeat(112));
+    output.push('\n');
+
+    for point in trend {
+        let hash = point
+            .config_hash
+            .as_deref()
+            .map(|h| &h[..h.len().min(8)])
+            .unwrap_or("-");
+        let marker = if point.config_changed { "*" } else { " " };
+        output.push_str(&format!(
+            "{:<38} {:<20} {}{:<9} {:>10} {:>10} {:>8} {:>10}\n",
+            point.run_id,
+            point.created_at.format("%Y-%m-%d %H:%M"),
+            marker,
+            hash,
+            point.total_return_pct.round_dp(2),
+            point
+                .return_change_pct
+                .map(|c| c.round_dp(2).to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            point.sharpe_ratio.round_dp(2),
+            point.max_drawdown_pct.round_dp(2),
+        ));
+    }
+
+    output.push_str(&format!(
+        "\nTotal: {} runs (* = 직전 실행 대비 설정 변경)",
+        trend.len()
+    ));
+    output
+}
+
+/// 실행 비교 결과 출력.
+fn format_diff(diff: &BacktestRunDiff) -> String {
+    let mut output = format!("\n🔍 {} → {}\n", diff.base_id, diff.target_id);
+
+    if diff.same_config {
+        output.push_str("설정: 동일\n");
+    } else if diff.config_changes.is_empty() {
+        output.push_str("설정: 파라미터 동일 (기간/자본/비용 차이)\n");
+    } else {
+        output.push_str("설정 변경:\n");
+        for change in &diff.config_changes {
+            let show = |v: &Option<serde_json::Value>| {
+                v.as_ref()
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|| "(없음)".to_string())
+            };
+            output.push_str(&format!(
+                "  {}: {} → {}\n",
+                change.path,
+                show(&change.base),
+                show(&change.target)
+            ));
+        }
+    }
+
+    output.push_str(&format!(
+        "\n{:<24} {:>14} {:>14} {:>14}\n",
+        "지표", "기준", "비교", "변화"
+    ));
+    output.push_str(&"-".repeat(70));
+    output.push('\n');
+    for delta in &diff.metric_deltas {
+        output.push_str(&format!(
+            "{:<24} {:>14} {:>14} {:>14}\n",
+            delta.metric,
+            delta.base.round_dp(4),
+            delta.target.round_dp(4),
+            delta.delta.round_dp(4),
+        ));
+    }
+
+    output
+}
@@ -4,10 +4,18 @@
 //!
 //! # 생성되는 차트 (3패널 레이아웃)
 //!
-//! 1. **캔들스틱 차트 + Volume**: 실제 가격 움직임과 거래량, 신호 마커 표시
-//! 2. **자산 곡선 (Equity Curve)**: 시간에 따른 포트폴리오 가치 변화
+//! 1. **캔들스틱 차트 + Volume**: 실제 가격 움직임과 거래량, 거래 마커 표시
+//!    (진입 ▲ / 청산 ▼, 수익 거래는 초록·손실 거래는 빨강)
+//! 2. **자산 곡선 (Equity Curve)**: 시간에 따른 포트폴리오 가치 변화 (낙폭 구간 음영,
+//!    벤치마크 설정 시 Buy & Hold 곡선 겹침)
 //! 3. **낙폭 차트 (Drawdown Chart)**: 고점 대비 하락률
 //!
+//! 몬테카를로 분석 시에는 별도 차트로 시나리오 자산 경로와 5~95 백분위 밴드,
+//! 중앙값 경로를 거래 순번 축에 그립니다.
+//!
+//! 거래 수가 [`ChartConfig::max_trade_markers`]를 넘으면 등간격으로 샘플링하고,
+//! 마커가 많을수록 투명도를 높여 가독성을 유지합니다.
+//!
 //! # 기술적 참고
 //!
 //! plotters의 RangedDateTime은 내부적으로 나노초 계산 시 overflow가 발생할 수 있어,
@@ -19,7 +27,10 @@ use anyhow::Result;
 use chrono::{DateTime, TimeZone, Utc};
 use plotters::prelude::*;
 use rust_decimal::Decimal;
-use trader_analytics::{backtest::BacktestReport, performance::EquityPoint};
+use trader_analytics::{
+    backtest::{BacktestReport, MonteCarloReport},
+    performance::{EquityPoint, RoundTrip},
+};
 use trader_core::{Kline, Side, SignalMarker, SignalType};
 
 /// 차트 생성 설정
@@ -33,12 +44,20 @@ pub struct ChartConfig {
     pub background_color: RGBColor,
     /// 자산 곡선 색상
     pub equity_color: RGBColor,
+    /// 벤치마크 곡선 색상
+    pub benchmark_color: RGBColor,
     /// 낙폭 색상
     pub drawdown_color: RGBColor,
     /// 상승 캔들 색상
     pub candle_up_color: RGBColor,
     /// 하락 캔들 색상
     pub candle_down_color: RGBColor,
+    /// 수익 거래 마커 색상
+    pub win_color: RGBColor,
+    /// 손실 거래 마커 색상
+    pub loss_color: RGBColor,
+    /// 가격 차트에 표시할 최대 거래 수 (초과 시 샘플링)
+    pub max_trade_markers: usize,
     /// Volume 색상
     #[allow(dead_code)]
     pub volume_color: RGBColor,
@@ -54,9 +73,13 @@ impl Default for ChartConfig {
             height: 1000,
             background_color: WHITE,
             equity_color: RGBColor(0, 100, 180),
+            benchmark_color: RGBColor(120, 120, 120),
             drawdown_color: RGBColor(200, 50, 50),
             candle_up_color: RGBColor(0, 150, 0),
             candle_down_color: RGBColor(200, 0, 0),
+            win_color: RGBColor(0, 160, 60),
+            loss_color: RGBColor(210, 40, 40),
+            max_trade_markers: 200,
             volume_color: RGBColor(100, 100, 200),
             show_grid: true,
         }
@@ -109,6 +132,13 @@ impl RegressionChartGenerator {
         // 헤더 그리기 (메트릭스 정보)
         self.draw_header(&header_area, report, strategy_name)?;
 
+        // 벤치마크 Buy & Hold 곡선 (설정된 경우 자산 곡선에 겹쳐 표시)
+        let benchmark_curve = report
+            .benchmark
+            .as_ref()
+            .map(|b| b.equity_curve.as_slice())
+            .unwrap_or(&[]);
+
         // 캔들 데이터가 충분하면 3패널, 부족하면 2패널 (최소 2개 필요)
         if report.klines.len() >= 2 {
             // 3패널: 캔들(45%) + Equity(30%) + Drawdown(25%)
@@ -121,13 +151,15 @@ impl RegressionChartGenerator {
 
             let (time_range, equity_range, drawdown_range) =
                 self.calculate_ranges(&report.equity_curve);
+            let equity_range = include_benchmark_range(equity_range, benchmark_curve);
             let (candle_time_range, price_range, volume_range) =
                 self.calculate_candle_ranges(&report.klines);
 
-            // 상단: 캔들스틱 + Volume + 신호 마커
+            // 상단: 캔들스틱 + Volume + 거래(없으면 신호) 마커
             self.draw_candlestick_chart(
                 &upper,
                 &report.klines,
+                &report.trades,
                 &report.signal_markers,
                 &candle_time_range,
                 &price_range,
@@ -138,6 +170,7 @@ impl RegressionChartGenerator {
             self.draw_equity_curve(
                 &middle,
                 &report.equity_curve,
+                benchmark_curve,
                 &[],
                 "Equity Curve",
                 &time_range,
@@ -153,11 +186,13 @@ impl RegressionChartGenerator {
 
             let (time_range, equity_range, drawdown_range) =
                 self.calculate_ranges(&report.equity_curve);
+            let equity_range = include_benchmark_range(equity_range, benchmark_curve);
 
             // 상단: 자산 곡선 (신호 마커 포함)
             self.draw_equity_curve(
                 &upper,
                 &report.equity_curve,
+                benchmark_curve,
                 &report.signal_markers,
                 "Equity Curve",
                 &time_range,
@@ -243,11 +278,14 @@ impl RegressionChartGenerator {
         Ok(())
     }
 
-    /// 캔들스틱 차트 + Volume + 신호 마커 그리기 (f64 타임스탬프 사용)
+    /// 캔들스틱 차트 + Volume + 거래/신호 마커 그리기 (f64 타임스탬프 사용)
+    ///
+    /// 완료된 거래가 있으면 손익이 구분되는 거래 마커를, 없으면 신호 마커를 표시합니다.
     fn draw_candlestick_chart<DB: DrawingBackend>(
         &self,
         area: &DrawingArea<DB, plotters::coord::Shift>,
         klines: &[Kline],
+        trades: &[RoundTrip],
         signal_markers: &[SignalMarker],
         time_range: &std::ops::Range<f64>,
         price_range: &std::ops::Range<f64>,
@@ -315,8 +353,12 @@ impl RegressionChartGenerator {
             )))?;
         }
 
-        // 신호 마커 그리기 (캔들 차트 위에)
-        self.add_signal_markers_to_candle(&mut candle_chart, signal_markers, klines)?;
+        // 거래 마커 (없으면 신호 마커) 그리기 (캔들 차트 위에)
+        if trades.is_empty() {
+            self.add_signal_markers_to_candle(&mut candle_chart, signal_markers, klines)?;
+        } else {
+            self.add_trade_markers_to_candle(&mut candle_chart, trades, time_range, price_range)?;
+        }
 
         // 볼륨 차트 (f64 좌표계 사용)
         let mut volume_chart = ChartBuilder::on(&volume_area)
@@ -357,6 +399,60 @@ impl RegressionChartGenerator {
         Ok(())
     }
 
+    /// 캔들 차트에 거래 마커 추가 (진입 ▲ / 청산 ▼, 손익별 색상)
+    fn add_trade_markers_to_candle<DB: DrawingBackend>(
+        &self,
+        chart: &mut ChartContext<
+            DB,
+            Cartesian2d<
+                plotters::coord::types::RangedCoordf64,
+                plotters::coord::types::RangedCoordf64,
+            >,
+        >,
+        trades: &[RoundTrip],
+        time_range: &std::ops::Range<f64>,
+        price_range: &std::ops::Range<f64>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let markers = build_trade_markers(
+            trades,
+            self.config.max_trade_markers,
+            time_range,
+            price_range,
+        );
+        if markers.is_empty() {
+            return Ok(());
+        }
+
+        let alpha = trade_marker_alpha(markers.len() / 2);
+        let size = if markers.len() > 200 { 5 } else { 8 };
+
+        for marker in markers {
+            let color = if marker.is_winner {
+                self.config.win_color.mix(alpha)
+            } else {
+                self.config.loss_color.mix(alpha)
+            };
+            // 진입: 가격 아래 ▲, 청산: 가격 위 ▼
+            let (offset, tri_size) = if marker.is_entry {
+                (size, size)
+            } else {
+                (-size, -size)
+            };
+
+            chart.draw_series(PointSeries::of_element(
+                vec![(marker.timestamp, marker.price)],
+                size,
+                &color,
+                &move |coord, _size, style| {
+                    EmptyElement::at(coord)
+                        + TriangleMarker::new((0, offset), tri_size, style.filled())
+                },
+            ))?;
+        }
+
+        Ok(())
+    }
+
     /// 캔들 차트에 신호 마커 추가 (f64 타임스탬프 좌표계)
     fn add_signal_markers_to_candle<DB: DrawingBackend>(
         &self,
@@ -499,6 +595,7 @@ impl RegressionChartGenerator {
         self.draw_equity_curve(
             &root,
             &report.equity_curve,
+            &[],
             &report.signal_markers,
             strategy_name,
             &time_range,
@@ -509,6 +606,108 @@ impl RegressionChartGenerator {
         Ok(())
     }
 
+    /// 몬테카를로 시나리오 차트 생성
+    ///
+    /// 시나리오 자산 경로(반투명)와 5~95 백분위 밴드, 중앙값 경로를 거래 순번 축에 표시합니다.
+    pub fn generate_monte_carlo_chart(
+        &self,
+        mc: &MonteCarloReport,
+        strategy_name: &str,
+        output_path: &Path,
+    ) -> Result<()> {
+        if mc.sample_paths.is_empty() {
+            return Err(anyhow::anyhow!("몬테카를로 시나리오 경로가 비어있습니다"));
+        }
+
+        let root = BitMapBackend::new(output_path, (self.config.width, self.config.height))
+            .into_drawing_area();
+        root.fill(&self.config.background_color)?;
+
+        let bands = percentile_bands(&mc.sample_paths);
+        let steps = bands.len().max(2) - 1;
+        let (min_equity, max_equity) = mc
+            .sample_paths
+            .iter()
+            .flatten()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| {
+                (lo.min(v), hi.max(v))
+            });
+        let margin = ((max_equity - min_equity) * 0.05).max(1.0);
+        let equity_range = (min_equity - margin).max(0.0)..(max_equity + margin);
+
+        let caption = format!(
+            "{} - Monte Carlo ({} runs, ruin {:.1}%, median return {:.2}%)",
+            strategy_name, mc.n_runs, mc.ruin_probability_pct, mc.final_return_pct.median
+        );
+        let mut chart = ChartBuilder::on(&root)
+            .caption(caption, ("sans-serif", 18).into_font())
+            .margin(10)
+            .x_label_area_size(40)
+            .y_label_area_size(80)
+            .build_cartesian_2d(0.0..steps as f64, equity_range.clone())?;
+
+        chart
+            .configure_mesh()
+            .x_labels(10)
+            .y_labels(8)
+            .x_desc("Trade #")
+            .y_label_formatter(&|v| format_currency(*v))
+            .draw()?;
+
+        // 시나리오 경로 (반투명)
+        let path_color = self.config.benchmark_color.mix(0.15);
+        for path in &mc.sample_paths {
+            chart.draw_series(LineSeries::new(
+                path.iter().enumerate().map(|(i, &v)| (i as f64, v)),
+                path_color,
+            ))?;
+        }
+
+        // 5~95 백분위 밴드
+        let band_color = self.config.equity_color.mix(0.2);
+        let polygon: Vec<(f64, f64)> = bands
+            .iter()
+            .enumerate()
+            .map(|(i, &(p5, _, _))| (i as f64, p5))
+            .chain(
+                bands
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .map(|(i, &(_, _, p95))| (i as f64, p95)),
+            )
+            .collect();
+        chart
+            .draw_series(std::iter::once(Polygon::new(polygon, band_color.filled())))?
+            .label("P5 ~ P95")
+            .legend(move |(x, y)| {
+                Rectangle::new([(x, y - 5), (x + 20, y + 5)], band_color.filled())
+            });
+
+        // 중앙값 경로
+        let equity_color = self.config.equity_color;
+        chart
+            .draw_series(LineSeries::new(
+                bands
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &(_, median, _))| (i as f64, median)),
+                equity_color.stroke_width(2),
+            ))?
+            .label("Median")
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], equity_color));
+
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperLeft)
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK.mix(0.3))
+            .draw()?;
+
+        root.present()?;
+        Ok(())
+    }
+
     /// 캔들 데이터 범위 계산 (f64 타임스탬프 사용 - overflow 방지)
     fn calculate_candle_ranges(
         &self,
@@ -628,6 +827,7 @@ impl RegressionChartGenerator {
         &self,
         area: &DrawingArea<DB, plotters::coord::Shift>,
         equity_curve: &[EquityPoint],
+        benchmark_curve: &[EquityPoint],
         signal_markers: &[SignalMarker],
         caption: &str,
         time_range: &std::ops::Range<f64>,
@@ -654,7 +854,22 @@ impl RegressionChartGenerator {
             .map(|p| (p.timestamp.timestamp() as f64, decimal_to_f64(p.equity)))
             .collect();
 
-        chart.draw_series(LineSeries::new(data.clone(), &self.config.equity_color))?;
+        // 낙폭 구간 음영 (직전 고점과 자산 곡선 사이)
+        let shade_color = self.config.drawdown_color.mix(0.15);
+        for segment in drawdown_segments(equity_curve) {
+            let polygon: Vec<(f64, f64)> = segment
+                .iter()
+                .map(|&(ts, _, peak)| (ts, peak))
+                .chain(segment.iter().rev().map(|&(ts, equity, _)| (ts, equity)))
+                .collect();
+            chart.draw_series(std::iter::once(Polygon::new(polygon, shade_color.filled())))?;
+        }
+
+        let equity_color = self.config.equity_color;
+        chart
+            .draw_series(LineSeries::new(data.clone(), &equity_color))?
+            .label("Strategy")
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], equity_color));
 
         // 영역 채우기 (반투명)
         let fill_color = self.config.equity_color.mix(0.2);
@@ -664,6 +879,29 @@ impl RegressionChartGenerator {
             fill_color,
         ))?;
 
+        // 벤치마크 Buy & Hold 곡선 (범례 포함)
+        if !benchmark_curve.is_empty() {
+            let benchmark_data: Vec<(f64, f64)> = benchmark_curve
+                .iter()
+                .map(|p| (p.timestamp.timestamp() as f64, decimal_to_f64(p.equity)))
+                .collect();
+            let benchmark_color = self.config.benchmark_color;
+
+            chart
+                .draw_series(LineSeries::new(
+                    benchmark_data,
+                    benchmark_color.stroke_width(2),
+                ))?
+                .label("Benchmark (Buy & Hold)")
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], benchmark_color));
+            chart
+                .configure_series_labels()
+                .position(SeriesLabelPosition::UpperLeft)
+                .background_style(WHITE.mix(0.8))
+                .border_style(BLACK.mix(0.3))
+                .draw()?;
+        }
+
         // 주요 지점 마커 (시작/종료/MDD)
         self.add_equity_markers(&mut chart, equity_curve)?;
 
@@ -948,6 +1186,143 @@ impl Default for RegressionChartGenerator {
     }
 }
 
+/// 가격 차트에 표시할 거래 마커
+#[derive(Debug, Clone, PartialEq)]
+struct TradeMarker {
+    /// 체결 시각 (f64 타임스탬프)
+    timestamp: f64,
+    /// 체결 가격
+    price: f64,
+    /// 진입 여부 (false = 청산)
+    is_entry: bool,
+    /// 수익 거래 여부
+    is_winner: bool,
+}
+
+/// 거래 목록에서 진입/청산 마커 생성.
+///
+/// 거래 수가 `max_trades`를 넘으면 등간격으로 샘플링하며, 차트 범위를 벗어나거나
+/// 유한하지 않은 좌표는 plotters 오버플로우를 피하기 위해 제외합니다.
+fn build_trade_markers(
+    trades: &[RoundTrip],
+    max_trades: usize,
+    time_range: &std::ops::Range<f64>,
+    price_range: &std::ops::Range<f64>,
+) -> Vec<TradeMarker> {
+    let sampled: Vec<&RoundTrip> = if max_trades > 0 && trades.len() > max_trades {
+        let stride = trades.len() as f64 / max_trades as f64;
+        (0..max_trades)
+            .map(|i| &trades[((i as f64 * stride) as usize).min(trades.len() - 1)])
+            .collect()
+    } else {
+        trades.iter().collect()
+    };
+
+    let in_range = |ts: f64, price: f64| {
+        ts.is_finite()
+            && price.is_finite()
+            && time_range.start <= ts
+            && ts <= time_range.end
+            && price_range.start <= price
+            && price <= price_range.end
+    };
+
+    sampled
+        .into_iter()
+        .flat_map(|trade| {
+            let is_winner = trade.is_winner();
+            [
+                TradeMarker {
+                    timestamp: trade.entry_time.timestamp() as f64,
+                    price: decimal_to_f64(trade.entry_price),
+                    is_entry: true,
+                    is_winner,
+                },
+                TradeMarker {
+                    timestamp: trade.exit_time.timestamp() as f64,
+                    price: decimal_to_f64(trade.exit_price),
+                    is_entry: false,
+                    is_winner,
+                },
+            ]
+        })
+        .filter(|m| in_range(m.timestamp, m.price))
+        .collect()
+}
+
+/// 표시 거래 수에 따른 마커 불투명도 (50건까지 불투명, 이후 점차 투명, 최소 0.3).
+fn trade_marker_alpha(trade_count: usize) -> f64 {
+    if trade_count <= 50 {
+        1.0
+    } else {
+        (50.0 / trade_count as f64).sqrt().max(0.3)
+    }
+}
+
+/// 낙폭 구간 추출 (구간별 (타임스탬프, 자산, 직전 고점) 목록).
+///
+/// 각 구간은 직전 고점 지점에서 시작해 회복 지점(또는 곡선 끝)에서 끝납니다.
+fn drawdown_segments(equity_curve: &[EquityPoint]) -> Vec<Vec<(f64, f64, f64)>> {
+    let mut segments = Vec::new();
+    let mut current: Vec<(f64, f64, f64)> = Vec::new();
+    let mut peak = f64::NEG_INFINITY;
+    let mut peak_point: Option<(f64, f64, f64)> = None;
+
+    for point in equity_curve {
+        let ts = point.timestamp.timestamp() as f64;
+        let equity = decimal_to_f64(point.equity);
+
+        if equity >= peak {
+            if !current.is_empty() {
+                current.push((ts, equity, peak));
+                segments.push(std::mem::take(&mut current));
+            }
+            peak = equity;
+            peak_point = Some((ts, equity, equity));
+        } else {
+            if current.is_empty() {
+                current.extend(peak_point);
+            }
+            current.push((ts, equity, peak));
+        }
+    }
+    if !current.is_empty() {
+        segments.push(current);
+    }
+    segments
+}
+
+/// 시나리오 경로의 거래 순번별 (P5, 중앙값, P95).
+///
+/// 파산으로 일찍 끝난 경로는 마지막 자산(0)을 유지한 것으로 간주합니다.
+fn percentile_bands(paths: &[Vec<f64>]) -> Vec<(f64, f64, f64)> {
+    let len = paths.iter().map(Vec::len).max().unwrap_or(0);
+    (0..len)
+        .map(|step| {
+            let mut values: Vec<f64> = paths
+                .iter()
+                .filter_map(|p| p.get(step).or_else(|| p.last()).copied())
+                .collect();
+            values.sort_by(f64::total_cmp);
+            let at = |q: f64| values[((values.len() - 1) as f64 * q).round() as usize];
+            (at(0.05), at(0.5), at(0.95))
+        })
+        .collect()
+}
+
+/// 자산 범위에 벤치마크 곡선 값을 포함하도록 확장
+fn include_benchmark_range(
+    equity_range: std::ops::Range<f64>,
+    benchmark_curve: &[EquityPoint],
+) -> std::ops::Range<f64> {
+    benchmark_curve
+        .iter()
+        .map(|p| decimal_to_f64(p.equity))
+        .fold(equity_range, |range, v| {
+            range.start.min(v)..range.end.max(v)
+        })
+}
+
 /// Decimal을 f64로 변환
 fn decimal_to_f64(d: Decimal) -> f64 {
     d.to_string().parse().unwrap_or(0.0)
@@ -1087,6 +1462,97 @@ mod tests {
         assert!(equity_range.start < equity_range.end);
     }
 
+    #[test]
+    fn test_include_benchmark_range() {
+        let mut benchmark = create_test_equity_curve();
+        benchmark[10].equity = Decimal::from(5_000_000);
+
+        let range = include_benchmark_range(9_000_000.0..12_000_000.0, &benchmark);
+        assert_eq!(range, 5_000_000.0..12_000_000.0);
+
+        // 벤치마크가 없으면 범위 유지
+        let range = include_benchmark_range(9_000_000.0..12_000_000.0, &[]);
+        assert_eq!(range, 9_000_000.0..12_000_000.0);
+    }
+
+    fn round_trip(entry_day: i64, exit_day: i64, exit_price: i64) -> RoundTrip {
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        RoundTrip::new(
+            "BTC/USDT",
+            Side::Buy,
+            Decimal::from(100),
+            Decimal::from(exit_price),
+            Decimal::ONE,
+            Decimal::ZERO,
+            base + chrono::Duration::days(entry_day),
+            base + chrono::Duration::days(exit_day),
+        )
+    }
+
+    #[test]
+    fn test_build_trade_markers() {
+        let base = Utc
+            .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+            .unwrap()
+            .timestamp() as f64;
+        let time_range = base..(base + 30.0 * 86400.0);
+        let trades = vec![
+            round_trip(1, 3, 110),  // 수익
+            round_trip(5, 8, 90),   // 손실
+            round_trip(20, 40, 95), // 청산이 차트 범위 밖
+        ];
+
+        let markers = build_trade_markers(&trades, 100, &time_range, &(50.0..150.0));
+
+        assert_eq!(markers.len(), 5);
+        assert!(markers[0].is_entry && markers[0].is_winner);
+        assert!(!markers[1].is_entry && markers[1].price == 110.0);
+        assert!(!markers[2].is_winner && !markers[3].is_winner);
+        assert!(markers[4].is_entry);
+    }
+
+    #[test]
+    fn test_build_trade_markers_sampling() {
+        let base = Utc
+            .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+            .unwrap()
+            .timestamp() as f64;
+        let trades: Vec<RoundTrip> = (0..1000).map(|_| round_trip(1, 2, 105)).collect();
+
+        let markers =
+            build_trade_markers(&trades, 200, &(base..(base + 86400.0 * 3.0)), &(0.0..200.0));
+        assert_eq!(markers.len(), 400);
+
+        assert_eq!(trade_marker_alpha(10), 1.0);
+        assert!(trade_marker_alpha(200) < 1.0);
+        assert_eq!(trade_marker_alpha(100_000), 0.3);
+    }
+
+    #[test]
+    fn test_drawdown_segments() {
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let curve: Vec<EquityPoint> = [100, 110, 100, 105, 120, 115]
+            .iter()
+            .enumerate()
+            .map(|(i, &equity)| EquityPoint {
+                timestamp: base + chrono::Duration::days(i as i64),
+                equity: Decimal::from(equity),
+                drawdown_pct: Decimal::ZERO,
+            })
+            .collect();
+
+        let segments = drawdown_segments(&curve);
+
+        assert_eq!(segments.len(), 2);
+        // 110 고점 → 100, 105 → 120 회복
+        let equities: Vec<f64> = segments[0].iter().map(|p| p.1).collect();
+        assert_eq!(equities, vec![110.0, 100.0, 105.0, 120.0]);
+        assert!(segments[0][1..3].iter().all(|p| p.2 == 110.0));
+        // 곡선 끝까지 미회복
+        assert_eq!(segments[1].len(), 2);
+        assert_eq!(segments[1][1], (segments[1][1].0, 115.0, 120.0));
+    }
+
     #[test]
     fn test_format_currency() {
         assert_eq!(format_currency(1_500_000_000.0), "1.5B");
@@ -1094,4 +1560,22 @@ mod tests {
         assert_eq!(format_currency(50_000.0), "50K");
         assert_eq!(format_currency(500.0), "500");
     }
+
+    #[test]
+    fn test_percentile_bands() {
+        let paths = vec![
+            vec![100.0, 110.0, 120.0],
+            vec![100.0, 90.0, 80.0],
+            vec![100.0, 0.0], // 파산으로 조기 종료
+        ];
+
+        let bands = percentile_bands(&paths);
+
+        assert_eq!(bands.len(), 3);
+        assert_eq!(bands[0], (100.0, 100.0, 100.0));
+        assert_eq!(bands[1], (0.0, 90.0, 110.0));
+        // 조기 종료 경로는 마지막 값(0) 유지
+        assert_eq!(bands[2], (0.0, 80.0, 120.0));
+        assert!(percentile_bands(&[]).is_empty());
+    }
 }
@@ -5,20 +5,30 @@
 //! - KR: Yahoo Finance → KIS API (fallback)
 //!
 //! KIS API는 사용량 제한이 있으므로 외부 데이터 소스를 우선적으로 사용합니다.
+//!
+//! # Rate limit
+//!
+//! 모든 요청은 [`DownloadClient`]의 소스별 토큰 버킷([`DataSource::rate_limit`])을
+//! 거치며, 429/5xx 응답과 네트워크 오류는 [`RetryPolicy`]에 따라 지수 백오프로
+//! 재시도합니다. 여러 심볼을 연속 다운로드할 때는 하나의 [`DownloadClient`]를
+//! 공유해야 제한이 전체 요청에 적용됩니다.
 
 use std::{
+    collections::HashMap,
     fs::File,
     io::{BufWriter, Write},
     path::Path,
     str::FromStr,
+    time::Duration,
 };
 
 use anyhow::{Context, Result};
 use chrono::{NaiveDate, TimeZone, Utc};
 use indicatif::{ProgressBar, ProgressStyle};
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use rust_decimal::Decimal;
 use serde::Deserialize;
+use tokio::{sync::Mutex, time::Instant};
 use tracing::{debug, info, warn};
 
 /// 지원되는 시장 유형
@@ -89,6 +99,237 @@ impl Interval {
     }
 }
 
+/// 과거 데이터 소스
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataSource {
+    /// Yahoo Finance (1차)
+    Yahoo,
+    /// KIS API (fallback)
+    Kis,
+}
+
+impl DataSource {
+    /// 모든 데이터 소스
+    pub const ALL: [DataSource; 2] = [DataSource::Yahoo, DataSource::Kis];
+
+    /// 소스별 요청 제한 (버스트 크기, 토큰 보충 간격)
+    pub fn rate_limit(self) -> (usize, Duration) {
+        match self {
+            // 비공식 API: 초당 2회, 짧은 버스트만 허용
+            Self::Yahoo => (5, Duration::from_millis(500)),
+            // 모의투자 기준 초당 2회 제한에 여유를 둠
+            Self::Kis => (1, Duration::from_millis(600)),
+        }
+    }
+}
+
+/// 요청 간격 기반 토큰 버킷.
+///
+/// `interval`마다 토큰 1개가 보충되며, 최대 `capacity`개까지 모아 둘 수 있습니다.
+#[derive(Debug)]
+pub struct TokenBucket {
+    state: Mutex<(f64, Instant)>,
+    capacity: f64,
+    interval: Duration,
+}
+
+impl TokenBucket {
+    /// 새 토큰 버킷 생성 (가득 찬 상태로 시작)
+    pub fn new(capacity: usize, interval: Duration) -> Self {
+        let capacity = capacity.max(1) as f64;
+        Self {
+            state: Mutex::new((capacity, Instant::now())),
+            capacity,
+            interval,
+        }
+    }
+
+    /// 토큰 1개를 획득할 때까지 대기
+    pub async fn acquire(&self) {
+        if self.interval.is_zero() {
+            return;
+        }
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                let (tokens, last_refill) = &mut *state;
+                let now = Instant::now();
+                let elapsed = now.saturating_duration_since(*last_refill);
+                *tokens = (*tokens + elapsed.as_secs_f64() / self.interval.as_secs_f64())
+                    .min(self.capacity);
+                *last_refill = now;
+
+                if *tokens >= 1.0 {
+                    *tokens -= 1.0;
+                    return;
+                }
+                self.interval.mul_f64(1.0 - *tokens)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// 429/5xx 재시도 정책
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// 최대 재시도 횟수 (첫 시도 제외)
+    pub max_retries: u32,
+    /// 첫 재시도 대기 시간
+    pub base_delay: Duration,
+    /// 최대 대기 시간
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 4,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `attempt`번째 재시도 전 대기 시간 (0부터 시작, 지수 증가)
+    ///
+    /// 서버가 `Retry-After`를 보낸 경우 그 값을 우선합니다.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        let backoff = self.base_delay.saturating_mul(2u32.saturating_pow(attempt));
+        retry_after.unwrap_or(backoff).min(self.max_delay)
+    }
+}
+
+/// 재시도 대상 HTTP 상태 (429, 5xx)
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// rate limit과 재시도를 적용하는 다운로드 클라이언트.
+///
+/// HTTP 연결과 소스별 토큰 버킷을 공유하므로 대량 다운로드 시 하나만 생성해
+/// 재사용합니다.
+pub struct DownloadClient {
+    http: Client,
+    limiters: HashMap<DataSource, TokenBucket>,
+    retry: RetryPolicy,
+}
+
+impl DownloadClient {
+    /// 기본 rate limit/재시도 정책으로 생성
+    pub fn new() -> Result<Self> {
+        Self::with_retry_policy(RetryPolicy::default())
+    }
+
+    /// 재시도 정책 지정
+    pub fn with_retry_policy(retry: RetryPolicy) -> Result<Self> {
+        let http = Client::builder()
+            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
+            .build()?;
+        let limiters = DataSource::ALL
+            .iter()
+            .map(|source| {
+                let (capacity, interval) = source.rate_limit();
+                (*source, TokenBucket::new(capacity, interval))
+            })
+            .collect();
+        Ok(Self {
+            http,
+            limiters,
+            retry,
+        })
+    }
+
+    /// 과거 데이터 다운로드 (데이터 소스 자동 선택)
+    pub async fn download(&self, config: &DownloadConfig) -> Result<usize> {
+        info!(
+            "Downloading {} {} data for {} from {} to {}",
+            config.market_name(),
+            config.interval_name(),
+            config.symbol,
+            config.start_date,
+            config.end_date
+        );
+
+        // 1차: Yahoo Finance 시도
+        match download_from_yahoo(self, config).await {
+            Ok(data) if !data.is_empty() => {
+                info!(
+                    "Successfully fetched {} candles from Yahoo Finance",
+                    data.len()
+                );
+                return save_to_csv(config, &data);
+            }
+            Ok(_) => {
+                warn!("Yahoo Finance returned empty data, trying fallback...");
+            }
+            Err(e) => {
+                warn!("Yahoo Finance failed: {}, trying fallback...", e);
+            }
+        }
+
+        // 2차: KIS API fallback (현재는 미구현 - 추후 연동)
+        warn!("KIS API fallback not yet implemented for historical data download");
+        anyhow::bail!(
+            "Failed to download data for {} from all sources. \
+            Please check if the symbol is correct and the date range is valid.",
+            config.symbol
+        )
+    }
+
+    /// rate limit을 지키며 GET 요청, 429/5xx/네트워크 오류는 백오프 재시도
+    async fn get_text(&self, source: DataSource, url: &str) -> Result<String> {
+        let mut attempt = 0;
+        loop {
+            if let Some(limiter) = self.limiters.get(&source) {
+                limiter.acquire().await;
+            }
+
+            let (error, retry_after) = match self.http.get(url).send().await {
+                Ok(response) if response.status().is_success() => {
+                    return Ok(response.text().await?);
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    let retry_after = response
+                        .headers()
+                        .get(reqwest::header::RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.trim().parse::<u64>().ok())
+                        .map(Duration::from_secs);
+                    let body = response.text().await.unwrap_or_default();
+                    let error = anyhow::anyhow!("{:?} API error: {} - {}", source, status, body);
+                    if !is_retryable_status(status) {
+                        return Err(error);
+                    }
+                    (error, retry_after)
+                }
+                Err(e) => (
+                    anyhow::Error::new(e)
+                        .context(format!("Failed to send request to {:?}", source)),
+                    None,
+                ),
+            };
+
+            if attempt >= self.retry.max_retries {
+                return Err(error.context(format!("{}회 재시도 후 실패", attempt)));
+            }
+            let delay = self.retry.delay(attempt, retry_after);
+            warn!(
+                "{:?} request failed ({}), retrying in {:?} ({}/{})",
+                source,
+                error,
+                delay,
+                attempt + 1,
+                self.retry.max_retries
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
 /// 다운로드 설정
 pub struct DownloadConfig {
     pub market: Market,
@@ -159,43 +400,42 @@ struct YahooAdjClose {
 }
 
 /// 과거 데이터 다운로드 (데이터 소스 자동 선택)
+///
+/// 단일 심볼용입니다. 여러 심볼은 [`DownloadClient`]를 공유하세요.
 pub async fn download_data(config: DownloadConfig) -> Result<usize> {
-    info!(
-        "Downloading {} {} data for {} from {} to {}",
-        config.market_name(),
-        config.interval_name(),
-        config.symbol,
-        config.start_date,
-        config.end_date
-    );
-
-    // 1차: Yahoo Finance 시도
-    match download_from_yahoo(&config).await {
-        Ok(data) if !data.is_empty() => {
-            info!(
-                "Successfully fetched {} candles from Yahoo Finance",
-                data.len()
-            );
-            return save_to_csv(&config, &data);
-        }
-        Ok(_) => {
-            warn!("Yahoo Finance returned empty data, trying fallback...");
-        }
-        Err(e) => {
-            warn!("Yahoo Finance failed: {}, trying fallback...", e);
-        }
-    }
-
-    // 2차: KIS API fallback (현재는 미구현 - 추후 연동)
-    warn!("KIS API fallback not yet implemented for historical data download");
-    anyhow::bail!(
-        "Failed to download data for {} from all sources. \
-        Please check if the symbol is correct and the date range is valid.",
-        config.symbol
-    )
+    DownloadClient::new()?.download(&config).await
 }
 
 impl DownloadConfig {
+    /// 기본 출력 경로 (`{base_dir}/{kr|us}/{SYMBOL}_{interval}_{start}_to_{end}.csv`)
+    pub fn default_output_path(
+        base_dir: &str,
+        market: Market,
+        symbol: &str,
+        interval: Interval,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> String {
+        let market_str = match market {
+            Market::KR => "kr",
+            Market::US => "us",
+        };
+        let interval_str = match interval {
+            Interval::D1 => "daily",
+            Interval::W1 => "weekly",
+            Interval::M1 => "monthly",
+        };
+        format!(
+            "{}/{}/{}_{}_{}_to_{}.csv",
+            base_dir.trim_end_matches('/'),
+            market_str,
+            symbol.to_uppercase(),
+            interval_str,
+            start_date.format("%Y%m%d"),
+            end_date.format("%Y%m%d")
+        )
+    }
+
     fn market_name(&self) -> &'static str {
         match self.market {
             Market::KR => "Korean",
@@ -225,11 +465,10 @@ impl DownloadConfig {
 
 /// Yahoo Finance에서 데이터 다운로드
 #[allow(clippy::needless_range_loop)]
-async fn download_from_yahoo(config: &DownloadConfig) -> Result<Vec<OhlcvData>> {
-    let client = Client::builder()
-        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
-        .build()?;
-
+async fn download_from_yahoo(
+    client: &DownloadClient,
+    config: &DownloadConfig,
+) -> Result<Vec<OhlcvData>> {
     let yahoo_symbol = config.yahoo_symbol();
 
     // 날짜를 UNIX 타임스탬프로 변환
@@ -260,19 +499,7 @@ async fn download_from_yahoo(config: &DownloadConfig) -> Result<Vec<OhlcvData>>
     );
     pb.set_message(format!("Fetching {} from Yahoo Finance...", yahoo_symbol));
 
-    let response = client
-        .get(&url)
-        .send()
-        .await
-        .with_context(|| "Failed to send request to Yahoo Finance")?;
-
-    if !response.status().is_success() {
-        let status = response.status();
-        let body = response.text().await.unwrap_or_default();
-        anyhow::bail!("Yahoo Finance API error: {} - {}", status, body);
-    }
-
-    let body = response.text().await?;
+    let body = client.get_text(DataSource::Yahoo, &url).await?;
     debug!("Yahoo Finance response length: {} bytes", body.len());
 
     let chart_response: YahooChartResponse =
@@ -506,6 +733,55 @@ mod tests {
         assert_eq!(us_config.yahoo_symbol(), "SPY");
     }
 
+    #[test]
+    fn test_retry_policy_delay() {
+        let policy = RetryPolicy {
+            max_retries: 4,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(10),
+        };
+        assert_eq!(policy.delay(0, None), Duration::from_secs(1));
+        assert_eq!(policy.delay(2, None), Duration::from_secs(4));
+        assert_eq!(policy.delay(5, None), Duration::from_secs(10));
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_secs(7))),
+            Duration::from_secs(7)
+        );
+    }
+
+    #[test]
+    fn test_retryable_status() {
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_token_bucket_limits_rate() {
+        let bucket = TokenBucket::new(2, Duration::from_secs(1));
+        let started = Instant::now();
+
+        // 버스트 2개는 즉시, 이후는 1초당 1개
+        for _ in 0..4 {
+            bucket.acquire().await;
+        }
+        assert!(started.elapsed() >= Duration::from_secs(2));
+        assert!(started.elapsed() < Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_default_output_path() {
+        let path = DownloadConfig::default_output_path(
+            "data/",
+            Market::US,
+            "spy",
+            Interval::D1,
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+        );
+        assert_eq!(path, "data/us/SPY_daily_20240101_to_20241231.csv");
+    }
+
     #[test]
     fn test_date_parsing() {
         let date = parse_date("2024-01-15").unwrap();
@@ -0,0 +1,434 @@
+//! 심볼 목록 파일 기반 대량 다운로드 (체크포인트/재개).
+//!
+//! `--symbols-file`의 심볼을 하나의 [`DownloadClient`]로 순차 다운로드하여
+//! 소스별 rate limit을 전체 요청에 적용합니다. 심볼마다 결과를 체크포인트 JSON에
+//! 기록하므로, 중단되더라도 `--resume`으로 완료된 심볼을 건너뛰고 이어서 받을 수
+//! 있습니다.
+//!
+//! # 심볼 목록 파일
+//!
+//! ```text
+//! # 주석과 빈 줄은 무시
+//! 005930
+//! 035720.KQ      # .KQ 접미사는 코스닥, .KS는 코스피
+//! SPY,S&P 500    # 쉼표 뒤 열은 무시
+//! ```
+//!
+//! # 출력 파일
+//!
+//! | 파일 | 내용 |
+//! |------|------|
+//! | `{symbols_file}.checkpoint.json` | 완료/실패 심볼과 다운로드 조건 |
+//! | `{symbols_file}.failed.txt` | 실패 심볼 목록 (`--symbols-file`로 재시도 가능) |
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, warn};
+
+use crate::commands::download::{DownloadClient, DownloadConfig, Interval, Market};
+
+/// 진행률 출력 기본 주기 (심볼 수)
+pub const DEFAULT_PROGRESS_EVERY: usize = 10;
+
+/// 대량 다운로드 설정
+pub struct BatchDownloadConfig {
+    pub market: Market,
+    pub symbols_file: PathBuf,
+    pub interval: Interval,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// CSV 출력 기준 디렉토리
+    pub output_dir: String,
+    /// 체크포인트 파일 경로
+    pub checkpoint_path: PathBuf,
+    /// 체크포인트에서 이어서 받기
+    pub resume: bool,
+    /// 진행률 출력 주기 (심볼 수)
+    pub progress_every: usize,
+}
+
+impl BatchDownloadConfig {
+    /// 기본 체크포인트 경로 (`{symbols_file}.checkpoint.json`)
+    pub fn default_checkpoint_path(symbols_file: &Path) -> PathBuf {
+        append_extension(symbols_file, "checkpoint.json")
+    }
+
+    /// 실패 심볼 목록 경로 (`{symbols_file}.failed.txt`)
+    pub fn failed_list_path(&self) -> PathBuf {
+        append_extension(&self.symbols_file, "failed.txt")
+    }
+}
+
+fn append_extension(path: &Path, ext: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(ext);
+    PathBuf::from(name)
+}
+
+/// 심볼 목록 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolEntry {
+    pub symbol: String,
+    /// 코스닥 종목 여부 (`.KQ` 접미사)
+    pub is_kosdaq: bool,
+}
+
+impl SymbolEntry {
+    /// 목록 파일 형식으로 변환 (코스닥은 `.KQ` 접미사)
+    fn to_line(&self) -> String {
+        if self.is_kosdaq {
+            format!("{}.KQ", self.symbol)
+        } else {
+            self.symbol.clone()
+        }
+    }
+}
+
+/// 심볼 목록 파싱 (주석/빈 줄 제외, 중복 제거, 순서 유지)
+pub fn parse_symbols(content: &str) -> Vec<SymbolEntry> {
+    let mut seen = HashSet::new();
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap_or("");
+            let raw = line.split(',').next().unwrap_or("").trim().to_uppercase();
+            if raw.is_empty() {
+                return None;
+            }
+            let (symbol, is_kosdaq) = if let Some(code) = raw.strip_suffix(".KQ") {
+                (code.to_string(), true)
+            } else if let Some(code) = raw.strip_suffix(".KS") {
+                (code.to_string(), false)
+            } else {
+                (raw, false)
+            };
+            seen.insert(symbol.clone())
+                .then_some(SymbolEntry { symbol, is_kosdaq })
+        })
+        .collect()
+}
+
+/// 대량 다운로드 체크포인트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCheckpoint {
+    pub market: String,
+    pub interval: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// 다운로드 완료 심볼
+    pub completed: BTreeSet<String>,
+    /// 실패 심볼 → 마지막 에러
+    pub failed: BTreeMap<String, String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BatchCheckpoint {
+    /// 새 체크포인트 생성
+    pub fn new(config: &BatchDownloadConfig) -> Self {
+        Self {
+            market: format!("{:?}", config.market),
+            interval: format!("{:?}", config.interval),
+            start_date: config.start_date,
+            end_date: config.end_date,
+            completed: BTreeSet::new(),
+            failed: BTreeMap::new(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// 파일에서 로드 (없으면 None)
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read checkpoint: {}", path.display()))?;
+        let checkpoint = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid checkpoint: {}", path.display()))?;
+        Ok(Some(checkpoint))
+    }
+
+    /// 임시 파일에 쓴 뒤 교체하여 저장 (중단 시 손상 방지)
+    pub fn save(&mut self, path: &Path) -> Result<()> {
+        self.updated_at = Utc::now();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp = append_extension(path, "tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&tmp, path)
+            .with_context(|| format!("Failed to write checkpoint: {}", path.display()))
+    }
+
+    /// 같은 조건(시장/간격/기간)의 체크포인트인지 확인
+    pub fn is_compatible(&self, config: &BatchDownloadConfig) -> bool {
+        let fresh = Self::new(config);
+        self.market == fresh.market
+            && self.interval == fresh.interval
+            && self.start_date == fresh.start_date
+            && self.end_date == fresh.end_date
+    }
+
+    /// 완료되지 않은 심볼 (이전 실패 심볼 포함)
+    pub fn remaining<'a>(&self, symbols: &'a [SymbolEntry]) -> Vec<&'a SymbolEntry> {
+        symbols
+            .iter()
+            .filter(|entry| !self.completed.contains(&entry.symbol))
+            .collect()
+    }
+}
+
+/// 대량 다운로드 결과
+#[derive(Debug)]
+pub struct BatchSummary {
+    /// 목록 전체 심볼 수
+    pub total: usize,
+    /// 이전 실행에서 완료되어 건너뛴 심볼 수
+    pub skipped: usize,
+    /// 이번 실행에서 성공한 심볼 수
+    pub succeeded: usize,
+    /// 실패 심볼
+    pub failed: Vec<String>,
+    /// 실패 심볼 목록 파일 (실패가 있을 때)
+    pub failed_list_path: Option<PathBuf>,
+}
+
+/// 심볼 목록 파일 대량 다운로드
+pub async fn run_batch_download(config: BatchDownloadConfig) -> Result<BatchSummary> {
+    let content = std::fs::read_to_string(&config.symbols_file).with_context(|| {
+        format!(
+            "Failed to read symbols file: {}",
+            config.symbols_file.display()
+        )
+    })?;
+    let symbols = parse_symbols(&content);
+    if symbols.is_empty() {
+        bail!(
+            "심볼 목록이 비어 있습니다: {}",
+            config.symbols_file.display()
+        );
+    }
+
+    let mut checkpoint = match BatchCheckpoint::load(&config.checkpoint_path)? {
+        Some(existing) if config.resume => {
+            if !existing.is_compatible(&config) {
+                bail!(
+                    "체크포인트 조건이 다릅니다 ({} {} {}~{}). --resume 없이 다시 시작하세요: {}",
+                    existing.market,
+                    existing.interval,
+                    existing.start_date,
+                    existing.end_date,
+                    config.checkpoint_path.display()
+                );
+            }
+            println!(
+                "♻️  체크포인트에서 재개: 완료 {} / 이전 실패 {}",
+                existing.completed.len(),
+                existing.failed.len()
+            );
+            existing
+        }
+        Some(_) => {
+            warn!(
+                "Existing checkpoint ignored (use --resume to continue): {}",
+                config.checkpoint_path.display()
+            );
+            BatchCheckpoint::new(&config)
+        }
+        None => {
+            if config.resume {
+                warn!(
+                    "No checkpoint found, starting from scratch: {}",
+                    config.checkpoint_path.display()
+                );
+            }
+            BatchCheckpoint::new(&config)
+        }
+    };
+
+    let total = symbols.len();
+    let pending: Vec<SymbolEntry> = checkpoint
+        .remaining(&symbols)
+        .into_iter()
+        .cloned()
+        .collect();
+    let skipped = total - pending.len();
+    let progress_every = config.progress_every.max(1);
+    let client = DownloadClient::new()?;
+    let mut succeeded = 0;
+
+    println!(
+        "\n📥 대량 다운로드: 전체 {} / 대상 {} / 건너뜀 {}",
+        total,
+        pending.len(),
+        skipped
+    );
+
+    for (index, entry) in pending.iter().enumerate() {
+        let download = DownloadConfig {
+            market: config.market,
+            symbol: entry.symbol.clone(),
+            interval: config.interval,
+            start_date: config.start_date,
+            end_date: config.end_date,
+            output_path: DownloadConfig::default_output_path(
+                &config.output_dir,
+                config.market,
+                &entry.symbol,
+                config.interval,
+                config.start_date,
+                config.end_date,
+            ),
+            is_kosdaq: entry.is_kosdaq,
+        };
+
+        match client.download(&download).await {
+            Ok(count) => {
+                info!("{}: {} candles", entry.symbol, count);
+                checkpoint.failed.remove(&entry.symbol);
+                checkpoint.completed.insert(entry.symbol.clone());
+                succeeded += 1;
+            }
+            Err(e) => {
+                error!("{}: download failed: {:#}", entry.symbol, e);
+                checkpoint
+                    .failed
+                    .insert(entry.symbol.clone(), format!("{:#}", e));
+            }
+        }
+        checkpoint.save(&config.checkpoint_path)?;
+
+        let processed = index + 1;
+        if processed % progress_every == 0 || processed == pending.len() {
+            let done = checkpoint.completed.len();
+            println!(
+                "📊 진행률: {}/{} ({:.1}%) | 성공 {} | 실패 {} | 남은 심볼 {}",
+                done,
+                total,
+                done as f64 / total as f64 * 100.0,
+                succeeded,
+                checkpoint.failed.len(),
+                pending.len() - processed
+            );
+        }
+    }
+
+    // 실패 목록 기록 (다음 실행의 --symbols-file로 재시도 가능)
+    let failed: Vec<&SymbolEntry> = symbols
+        .iter()
+        .filter(|entry| checkpoint.failed.contains_key(&entry.symbol))
+        .collect();
+    let failed_path = config.failed_list_path();
+    let failed_list_path = if failed.is_empty() {
+        if failed_path.exists() {
+            std::fs::remove_file(&failed_path)?;
+        }
+        None
+    } else {
+        let lines: Vec<String> = failed.iter().map(|entry| entry.to_line()).collect();
+        std::fs::write(&failed_path, lines.join("\n") + "\n")
+            .with_context(|| format!("Failed to write {}", failed_path.display()))?;
+        Some(failed_path)
+    };
+
+    Ok(BatchSummary {
+        total,
+        skipped,
+        succeeded,
+        failed: failed.iter().map(|entry| entry.symbol.clone()).collect(),
+        failed_list_path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> BatchDownloadConfig {
+        BatchDownloadConfig {
+            market: Market::KR,
+            symbols_file: PathBuf::from("symbols.txt"),
+            interval: Interval::D1,
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            output_dir: "data".to_string(),
+            checkpoint_path: PathBuf::from("symbols.txt.checkpoint.json"),
+            resume: true,
+            progress_every: DEFAULT_PROGRESS_EVERY,
+        }
+    }
+
+    #[test]
+    fn test_parse_symbols() {
+        let content = "# 코스피\n005930\n035720.kq  # 카카오\n\nSPY,S&P 500\n005930.KS\n";
+        let symbols = parse_symbols(content);
+
+        assert_eq!(symbols.len(), 3);
+        assert_eq!(symbols[0].symbol, "005930");
+        assert!(!symbols[0].is_kosdaq);
+        assert_eq!(symbols[1].symbol, "035720");
+        assert!(symbols[1].is_kosdaq);
+        assert_eq!(symbols[1].to_line(), "035720.KQ");
+        assert_eq!(symbols[2].symbol, "SPY");
+    }
+
+    #[test]
+    fn test_checkpoint_remaining_and_compatibility() {
+        let config = config();
+        let symbols = parse_symbols("A\nB\nC\n");
+        let mut checkpoint = BatchCheckpoint::new(&config);
+        checkpoint.completed.insert("A".to_string());
+        checkpoint.failed.insert("B".to_string(), "429".to_string());
+
+        let remaining: Vec<&str> = checkpoint
+            .remaining(&symbols)
+            .iter()
+            .map(|e| e.symbol.as_str())
+            .collect();
+        assert_eq!(remaining, vec!["B", "C"]);
+        assert!(checkpoint.is_compatible(&config));
+
+        let other = BatchDownloadConfig {
+            interval: Interval::W1,
+            ..config
+        };
+        assert!(!checkpoint.is_compatible(&other));
+    }
+
+    #[test]
+    fn test_checkpoint_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("trader_dl_{}", uuid::Uuid::new_v4()));
+        let path = dir.join("symbols.txt.checkpoint.json");
+        let mut checkpoint = BatchCheckpoint::new(&config());
+        checkpoint.completed.insert("005930".to_string());
+        checkpoint.save(&path).unwrap();
+
+        let loaded = BatchCheckpoint::load(&path).unwrap().unwrap();
+        assert!(loaded.completed.contains("005930"));
+        assert!(BatchCheckpoint::load(&dir.join("missing.json"))
+            .unwrap()
+            .is_none());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_default_paths() {
+        let config = config();
+        assert_eq!(
+            BatchDownloadConfig::default_checkpoint_path(&config.symbols_file),
+            PathBuf::from("symbols.txt.checkpoint.json")
+        );
+        assert_eq!(
+            config.failed_list_path(),
+            PathBuf::from("symbols.txt.failed.txt")
+        );
+    }
+}
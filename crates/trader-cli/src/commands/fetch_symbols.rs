@@ -1,11 +1,40 @@
 //! 온라인 소스에서 종목 정보 자동 수집 및 DB 동기화.
-
-use std::{fs, path::Path};
+//!
+//! 수집한 종목을 기존 DB 상태와 비교하여 변경분만 반영합니다.
+//!
+//! - **신규**: DB에 없는 종목 → 삽입
+//! - **변경**: 종목명/거래소/섹터/Yahoo 심볼이 달라진 종목 → 갱신
+//!   (소스가 값을 제공하지 않는 필드는 기존 값을 유지)
+//! - **폐지 추정**: 전체 목록을 제공하는 소스(KRX, Binance)에서 사라진 활성 종목 →
+//!   삭제하지 않고 `deactivated_reason = 'delisted_suspect'`로 비활성화
+//!
+//! `--dry-run`은 DB를 읽어 diff만 출력하고, `--record-history`는 변경 내역을
+//! `symbol_change_history` 테이블에 기록합니다.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
 
 use anyhow::{Context, Result};
+use serde_json::json;
 use sqlx::PgPool;
 use tracing::{error, info, warn};
+use trader_api::repository::{NewSymbolInfo, SymbolInfo, SymbolInfoRepository};
 use trader_data::{Database, DatabaseConfig};
+use uuid::Uuid;
+
+/// 폐지 추정 비활성화 사유 (`trader-collector delisted reactivate`로 재활성화 가능).
+const DELISTED_SUSPECT: &str = "delisted_suspect";
+
+/// 한 번에 비활성화할 수 있는 활성 종목 비율 상한.
+///
+/// 소스 응답이 일부만 오는 장애 상황에서 대량 비활성화를 막기 위한 안전장치입니다.
+const MAX_DELISTED_RATIO: f64 = 0.1;
+
+/// diff 출력 시 분류별 최대 표시 수.
+const MAX_DIFF_LINES: usize = 20;
 
 /// 종목 수집 설정.
 #[derive(Debug)]
@@ -20,15 +49,218 @@ pub struct FetchSymbolsConfig {
     pub db_url: Option<String>,
     /// 드라이런 모드
     pub dry_run: bool,
+    /// 변경 이력 기록
+    pub record_history: bool,
+}
+
+/// 시장별 동기화 요약.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SyncSummary {
+    /// 소스에서 조회한 종목 수
+    pub fetched: usize,
+    /// 신규 종목 수
+    pub added: usize,
+    /// 변경된 종목 수
+    pub changed: usize,
+    /// 폐지 추정(비활성화) 종목 수
+    pub delisted: usize,
+    /// 변경 없는 종목 수
+    pub unchanged: usize,
+}
+
+impl SyncSummary {
+    fn merge(&mut self, other: &SyncSummary) {
+        self.fetched += other.fetched;
+        self.added += other.added;
+        self.changed += other.changed;
+        self.delisted += other.delisted;
+        self.unchanged += other.unchanged;
+    }
 }
 
 /// 시장별 종목 수집 결과.
 #[derive(Debug, Default)]
 pub struct FetchResult {
-    pub kr_count: usize,
-    pub us_count: usize,
-    pub crypto_count: usize,
-    pub total: usize,
+    pub kr: SyncSummary,
+    pub us: SyncSummary,
+    pub crypto: SyncSummary,
+    pub total: SyncSummary,
+}
+
+/// 필드 변경 내역.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// 변경된 종목 (병합된 최신 값 포함).
+#[derive(Debug, Clone)]
+pub struct SymbolChange {
+    pub id: Uuid,
+    pub symbol: NewSymbolInfo,
+    pub fields: Vec<FieldChange>,
+}
+
+/// 폐지 추정 종목.
+#[derive(Debug, Clone)]
+pub struct DelistedSymbol {
+    pub id: Uuid,
+    pub ticker: String,
+    pub name: String,
+}
+
+/// 기존 DB 상태와 소스 목록의 차이.
+#[derive(Debug, Default)]
+pub struct SymbolDiff {
+    pub added: Vec<NewSymbolInfo>,
+    pub changed: Vec<SymbolChange>,
+    pub delisted: Vec<DelistedSymbol>,
+    pub unchanged: usize,
+    /// 소스에는 있지만 DB에서 비활성 상태인 종목 (자동 재활성화하지 않음)
+    pub inactive_listed: Vec<String>,
+}
+
+impl SymbolDiff {
+    /// 신규 + 변경 종목 (upsert 대상).
+    pub fn upserts(&self) -> Vec<NewSymbolInfo> {
+        self.added
+            .iter()
+            .cloned()
+            .chain(self.changed.iter().map(|c| c.symbol.clone()))
+            .collect()
+    }
+}
+
+/// 시장별 비교 규칙.
+struct MarketSource {
+    /// 시장 코드
+    market: &'static str,
+    /// 소스 이름 (이력 기록용)
+    source: &'static str,
+    /// 소스가 전체 목록을 제공하는지 (false면 폐지 추정 생략)
+    full_listing: bool,
+    /// 비교 대상 기존 종목의 거래소 (None이면 시장 전체)
+    exchange_scope: Option<&'static str>,
+}
+
+const KR_SOURCE: MarketSource = MarketSource {
+    market: "KR",
+    source: "KRX",
+    full_listing: true,
+    exchange_scope: None,
+};
+
+// 상위 종목만 수집하므로 목록에 없다고 폐지로 볼 수 없음
+const US_SOURCE: MarketSource = MarketSource {
+    market: "US",
+    source: "YAHOO",
+    full_listing: false,
+    exchange_scope: None,
+};
+
+const CRYPTO_SOURCE: MarketSource = MarketSource {
+    market: "CRYPTO",
+    source: "BINANCE",
+    full_listing: true,
+    exchange_scope: Some("BINANCE"),
+};
+
+/// 기존 종목과 소스 목록 비교.
+///
+/// 소스가 제공하지 않은(`None`/빈 문자열) 필드는 기존 값을 유지한 것으로 간주합니다.
+/// `detect_delisted`가 true이면 소스에 없는 활성 종목을 폐지 추정으로 분류합니다.
+pub fn compute_symbol_diff(
+    existing: &[SymbolInfo],
+    fetched: Vec<NewSymbolInfo>,
+    detect_delisted: bool,
+) -> SymbolDiff {
+    let by_ticker: HashMap<&str, &SymbolInfo> =
+        existing.iter().map(|s| (s.ticker.as_str(), s)).collect();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut diff = SymbolDiff::default();
+
+    for symbol in fetched {
+        if !seen.insert(symbol.ticker.clone()) {
+            continue;
+        }
+
+        let Some(current) = by_ticker.get(symbol.ticker.as_str()) else {
+            diff.added.push(symbol);
+            continue;
+        };
+
+        if current.is_active == Some(false) {
+            diff.inactive_listed.push(symbol.ticker.clone());
+        }
+
+        let merged = NewSymbolInfo {
+            name: if symbol.name.trim().is_empty() {
+                current.name.clone()
+            } else {
+                symbol.name
+            },
+            name_en: symbol.name_en.or_else(|| current.name_en.clone()),
+            exchange: symbol.exchange.or_else(|| current.exchange.clone()),
+            sector: symbol.sector.or_else(|| current.sector.clone()),
+            yahoo_symbol: symbol.yahoo_symbol.or_else(|| current.yahoo_symbol.clone()),
+            ..symbol
+        };
+
+        let fields: Vec<FieldChange> = [
+            ("name", Some(&current.name), Some(&merged.name)),
+            ("name_en", current.name_en.as_ref(), merged.name_en.as_ref()),
+            (
+                "exchange",
+                current.exchange.as_ref(),
+                merged.exchange.as_ref(),
+            ),
+            ("sector", current.sector.as_ref(), merged.sector.as_ref()),
+            (
+                "yahoo_symbol",
+                current.yahoo_symbol.as_ref(),
+                merged.yahoo_symbol.as_ref(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, old, new)| old != new)
+        .map(|(field, old, new)| FieldChange {
+            field,
+            old: old.cloned(),
+            new: new.cloned(),
+        })
+        .collect();
+
+        if fields.is_empty() {
+            diff.unchanged += 1;
+        } else {
+            diff.changed.push(SymbolChange {
+                id: current.id,
+                symbol: merged,
+                fields,
+            });
+        }
+    }
+
+    if detect_delisted {
+        diff.delisted = existing
+            .iter()
+            .filter(|s| s.is_active != Some(false) && !seen.contains(&s.ticker))
+            .map(|s| DelistedSymbol {
+                id: s.id,
+                ticker: s.ticker.clone(),
+                name: s.name.clone(),
+            })
+            .collect();
+    }
+
+    diff
+}
+
+/// 폐지 추정 수가 안전 상한을 넘는지 여부.
+fn exceeds_delist_guard(delisted: usize, active: usize) -> bool {
+    delisted > 0 && delisted as f64 > active as f64 * MAX_DELISTED_RATIO
 }
 
 /// 온라인 소스에서 종목 정보 수집 및 동기화.
@@ -36,31 +268,34 @@ pub async fn fetch_symbols(config: FetchSymbolsConfig) -> Result<FetchResult> {
     println!("\n🔍 종목 정보 자동 수집 시작...");
     println!("대상 시장: {}", config.market);
     if config.dry_run {
-        println!("⚠️  드라이런 모드: DB에 저장하지 않습니다");
+        println!("⚠️  드라이런 모드: 변경 내역만 출력하고 DB에 저장하지 않습니다");
     }
     println!();
 
     let mut result = FetchResult::default();
 
-    // DB 연결 (드라이런이 아닐 때만)
-    let pool = if !config.dry_run {
-        let db_url = config
-            .db_url
-            .clone()
-            .or_else(|| std::env::var("DATABASE_URL").ok())
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "DATABASE_URL not found. Set DATABASE_URL environment variable or use --db-url flag"
-                )
-            })?;
-
-        let db_config = DatabaseConfig::for_cli(db_url);
-        let db = Database::connect(&db_config)
-            .await
-            .context("데이터베이스 연결 실패")?;
-        Some(db.pool().clone())
-    } else {
-        None
+    // DB 연결 (드라이런은 비교용 조회만 수행하며, URL이 없으면 비교 생략)
+    let db_url = config
+        .db_url
+        .clone()
+        .or_else(|| std::env::var("DATABASE_URL").ok());
+    let pool = match db_url {
+        Some(db_url) => {
+            let db_config = DatabaseConfig::for_cli(db_url);
+            let db = Database::connect(&db_config)
+                .await
+                .context("데이터베이스 연결 실패")?;
+            Some(db.pool().clone())
+        }
+        None if config.dry_run => {
+            warn!("DATABASE_URL이 없어 기존 상태와 비교하지 않습니다 (모두 신규로 표시)");
+            None
+        }
+        None => {
+            return Err(anyhow::anyhow!(
+                "DATABASE_URL not found. Set DATABASE_URL environment variable or use --db-url flag"
+            ))
+        }
     };
 
     // CSV 디렉토리 생성
@@ -73,11 +308,15 @@ pub async fn fetch_symbols(config: FetchSymbolsConfig) -> Result<FetchResult> {
 
     // 한국 시장
     if market_upper == "KR" || market_upper == "ALL" {
-        match fetch_kr_symbols(pool.as_ref(), &config).await {
-            Ok(count) => {
-                result.kr_count = count;
-                result.total += count;
-                info!("✅ 한국 시장: {}개 종목 수집", count);
+        let synced = match fetch_kr_symbols(&config).await {
+            Ok(symbols) => sync_market(pool.as_ref(), &KR_SOURCE, symbols, &config).await,
+            Err(e) => Err(e),
+        };
+        match synced {
+            Ok(summary) => {
+                result.kr = summary;
+                result.total.merge(&summary);
+                info!("✅ 한국 시장: {}개 종목 수집", summary.fetched);
             }
             Err(e) => {
                 error!("✗ 한국 시장 수집 실패: {}", e);
@@ -88,11 +327,15 @@ pub async fn fetch_symbols(config: FetchSymbolsConfig) -> Result<FetchResult> {
 
     // 미국 시장
     if market_upper == "US" || market_upper == "ALL" {
-        match fetch_us_symbols(pool.as_ref(), &config).await {
-            Ok(count) => {
-                result.us_count = count;
-                result.total += count;
-                info!("✅ 미국 시장: {}개 종목 수집", count);
+        let synced = match fetch_us_symbols(&config).await {
+            Ok(symbols) => sync_market(pool.as_ref(), &US_SOURCE, symbols, &config).await,
+            Err(e) => Err(e),
+        };
+        match synced {
+            Ok(summary) => {
+                result.us = summary;
+                result.total.merge(&summary);
+                info!("✅ 미국 시장: {}개 종목 수집", summary.fetched);
             }
             Err(e) => {
                 error!("✗ 미국 시장 수집 실패: {}", e);
@@ -103,11 +346,15 @@ pub async fn fetch_symbols(config: FetchSymbolsConfig) -> Result<FetchResult> {
 
     // 암호화폐 시장
     if market_upper == "CRYPTO" || market_upper == "ALL" {
-        match fetch_crypto_symbols(pool.as_ref(), &config).await {
-            Ok(count) => {
-                result.crypto_count = count;
-                result.total += count;
-                info!("✅ 암호화폐 시장: {}개 종목 수집", count);
+        let synced = match fetch_crypto_symbols(&config).await {
+            Ok(symbols) => sync_market(pool.as_ref(), &CRYPTO_SOURCE, symbols, &config).await,
+            Err(e) => Err(e),
+        };
+        match synced {
+            Ok(summary) => {
+                result.crypto = summary;
+                result.total.merge(&summary);
+                info!("✅ 암호화폐 시장: {}개 종목 수집", summary.fetched);
             }
             Err(e) => {
                 error!("✗ 암호화폐 시장 수집 실패: {}", e);
@@ -122,18 +369,248 @@ pub async fn fetch_symbols(config: FetchSymbolsConfig) -> Result<FetchResult> {
     }
 
     println!("\n{}", "=".repeat(60));
-    println!("✅ 종목 수집 완료!");
-    println!("   한국: {}개", result.kr_count);
-    println!("   미국: {}개", result.us_count);
-    println!("   암호화폐: {}개", result.crypto_count);
-    println!("   총: {}개", result.total);
+    if config.dry_run {
+        println!("✅ 종목 비교 완료 (드라이런)");
+    } else {
+        println!("✅ 종목 동기화 완료!");
+    }
+    for (label, summary) in [
+        ("한국", &result.kr),
+        ("미국", &result.us),
+        ("암호화폐", &result.crypto),
+        ("총", &result.total),
+    ] {
+        println!(
+            "   {}: 수집 {}개 → 신규 {}, 변경 {}, 폐지 {}",
+            label, summary.fetched, summary.added, summary.changed, summary.delisted
+        );
+    }
     println!("{}\n", "=".repeat(60));
 
     Ok(result)
 }
 
+/// 수집한 시장 종목을 기존 상태와 비교하여 변경분만 반영.
+async fn sync_market(
+    pool: Option<&PgPool>,
+    source: &MarketSource,
+    fetched: Vec<NewSymbolInfo>,
+    config: &FetchSymbolsConfig,
+) -> Result<SyncSummary> {
+    let fetched_count = fetched.len();
+
+    let existing: Vec<SymbolInfo> = match pool {
+        Some(pool) => SymbolInfoRepository::get_by_market(pool, source.market)
+            .await
+            .with_context(|| format!("Failed to load existing {} symbols", source.market))?
+            .into_iter()
+            .filter(|s| match source.exchange_scope {
+                Some(exchange) => s.exchange.as_deref() == Some(exchange),
+                None => true,
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    let active = existing
+        .iter()
+        .filter(|s| s.is_active != Some(false))
+        .count();
+
+    // 빈 응답으로 전체가 폐지 처리되는 것을 막기 위해 수집 결과가 있을 때만 감지
+    let detect_delisted = source.full_listing && fetched_count > 0;
+    let mut diff = compute_symbol_diff(&existing, fetched, detect_delisted);
+
+    print_diff(&diff);
+
+    if exceeds_delist_guard(diff.delisted.len(), active) {
+        warn!(
+            "{} 폐지 추정 종목이 {}개로 활성 종목({})의 {:.0}%를 초과하여 비활성화를 건너뜁니다",
+            source.market,
+            diff.delisted.len(),
+            active,
+            MAX_DELISTED_RATIO * 100.0
+        );
+        diff.delisted.clear();
+    }
+
+    let summary = SyncSummary {
+        fetched: fetched_count,
+        added: diff.added.len(),
+        changed: diff.changed.len(),
+        delisted: diff.delisted.len(),
+        unchanged: diff.unchanged,
+    };
+
+    let Some(pool) = pool.filter(|_| !config.dry_run) else {
+        return Ok(summary);
+    };
+
+    // 변경분만 upsert
+    let upserts = diff.upserts();
+    if !upserts.is_empty() {
+        let upserted = SymbolInfoRepository::upsert_batch(pool, &upserts)
+            .await
+            .with_context(|| format!("Failed to upsert {} symbols", source.market))?;
+        println!("   DB 저장: {}개 종목 반영", upserted);
+    }
+
+    // 폐지 추정 종목 비활성화 (삭제하지 않음)
+    if !diff.delisted.is_empty() {
+        let deactivated = deactivate_delisted(pool, &diff.delisted, source.source)
+            .await
+            .with_context(|| format!("Failed to deactivate {} symbols", source.market))?;
+        println!("   비활성화: {}개 종목 (폐지 추정)", deactivated);
+    }
+
+    if config.record_history {
+        let recorded = record_history(pool, source, &diff)
+            .await
+            .context("Failed to record symbol change history")?;
+        println!("   변경 이력: {}건 기록", recorded);
+    }
+
+    Ok(summary)
+}
+
+/// diff 출력.
+fn print_diff(diff: &SymbolDiff) {
+    println!(
+        "   비교 결과: 신규 {}, 변경 {}, 폐지 추정 {}, 유지 {}",
+        diff.added.len(),
+        diff.changed.len(),
+        diff.delisted.len(),
+        diff.unchanged
+    );
+
+    for symbol in diff.added.iter().take(MAX_DIFF_LINES) {
+        println!("     + {} {}", symbol.ticker, symbol.name);
+    }
+    for change in diff.changed.iter().take(MAX_DIFF_LINES) {
+        let fields: Vec<String> = change
+            .fields
+            .iter()
+            .map(|f| {
+                format!(
+                    "{}: {} → {}",
+                    f.field,
+                    f.old.as_deref().unwrap_or("-"),
+                    f.new.as_deref().unwrap_or("-")
+                )
+            })
+            .collect();
+        println!("     ~ {} ({})", change.symbol.ticker, fields.join(", "));
+    }
+    for symbol in diff.delisted.iter().take(MAX_DIFF_LINES) {
+        println!("     - {} {}", symbol.ticker, symbol.name);
+    }
+
+    let total = diff.added.len() + diff.changed.len() + diff.delisted.len();
+    let shown = diff.added.len().min(MAX_DIFF_LINES)
+        + diff.changed.len().min(MAX_DIFF_LINES)
+        + diff.delisted.len().min(MAX_DIFF_LINES);
+    if total > shown {
+        println!("     ... 외 {}건", total - shown);
+    }
+
+    if !diff.inactive_listed.is_empty() {
+        println!(
+            "   ℹ️  소스에 있지만 비활성 상태인 종목 {}개 (trader-collector delisted reactivate로 재활성화)",
+            diff.inactive_listed.len()
+        );
+    }
+}
+
+/// 폐지 추정 종목 비활성화.
+async fn deactivate_delisted(
+    pool: &PgPool,
+    symbols: &[DelistedSymbol],
+    source: &str,
+) -> Result<u64, sqlx::Error> {
+    let ids: Vec<Uuid> = symbols.iter().map(|s| s.id).collect();
+    let result = sqlx::query(
+        r#"
+        UPDATE symbol_info
+        SET is_active = FALSE,
+            deactivated_reason = $2,
+            deactivated_at = NOW(),
+            last_fetch_error = $3,
+            updated_at = NOW()
+        WHERE id = ANY($1) AND is_active IS DISTINCT FROM FALSE
+        "#,
+    )
+    .bind(&ids)
+    .bind(DELISTED_SUSPECT)
+    .bind(format!("{}에서 조회되지 않음 (상장폐지 추정)", source))
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// 변경 이력 기록.
+async fn record_history(
+    pool: &PgPool,
+    source: &MarketSource,
+    diff: &SymbolDiff,
+) -> Result<usize, sqlx::Error> {
+    let mut tickers: Vec<String> = Vec::new();
+    let mut ids: Vec<Option<Uuid>> = Vec::new();
+    let mut change_types: Vec<&str> = Vec::new();
+    let mut changes: Vec<serde_json::Value> = Vec::new();
+
+    for symbol in &diff.added {
+        tickers.push(symbol.ticker.clone());
+        ids.push(None);
+        change_types.push("added");
+        changes.push(json!({ "name": { "old": null, "new": symbol.name } }));
+    }
+    for change in &diff.changed {
+        tickers.push(change.symbol.ticker.clone());
+        ids.push(Some(change.id));
+        change_types.push("changed");
+        let fields: serde_json::Map<String, serde_json::Value> = change
+            .fields
+            .iter()
+            .map(|f| (f.field.to_string(), json!({ "old": f.old, "new": f.new })))
+            .collect();
+        changes.push(serde_json::Value::Object(fields));
+    }
+    for symbol in &diff.delisted {
+        tickers.push(symbol.ticker.clone());
+        ids.push(Some(symbol.id));
+        change_types.push("delisted");
+        changes.push(json!({ "is_active": { "old": true, "new": false } }));
+    }
+
+    if tickers.is_empty() {
+        return Ok(0);
+    }
+
+    // 신규 종목은 upsert 이후 ID를 조회하여 연결
+    sqlx::query(
+        r#"
+        INSERT INTO symbol_change_history
+            (symbol_info_id, ticker, market, change_type, changes, source)
+        SELECT COALESCE(c.id, si.id), c.ticker, $5, c.change_type, c.changes, $6
+        FROM UNNEST($1::uuid[], $2::text[], $3::text[], $4::jsonb[])
+            AS c(id, ticker, change_type, changes)
+        LEFT JOIN symbol_info si ON si.ticker = c.ticker AND si.market = $5
+        "#,
+    )
+    .bind(&ids)
+    .bind(&tickers)
+    .bind(&change_types)
+    .bind(&changes)
+    .bind(source.market)
+    .bind(source.source)
+    .execute(pool)
+    .await?;
+
+    Ok(tickers.len())
+}
+
 /// 한국 시장 종목 수집.
-async fn fetch_kr_symbols(pool: Option<&PgPool>, config: &FetchSymbolsConfig) -> Result<usize> {
+async fn fetch_kr_symbols(config: &FetchSymbolsConfig) -> Result<Vec<NewSymbolInfo>> {
     println!("📊 한국 시장 수집 중 (KRX)...");
 
     use trader_data::provider::{KrxSymbolProvider, SymbolInfoProvider};
@@ -152,35 +629,11 @@ async fn fetch_kr_symbols(pool: Option<&PgPool>, config: &FetchSymbolsConfig) ->
         println!("   CSV 저장: {}/krx_symbols.csv", config.csv_dir);
     }
 
-    // DB 저장
-    if let Some(pool) = pool {
-        let new_symbols: Vec<trader_api::repository::NewSymbolInfo> = symbols
-            .into_iter()
-            .map(|s| trader_api::repository::NewSymbolInfo {
-                ticker: s.ticker,
-                name: s.name,
-                name_en: s.name_en,
-                market: s.market,
-                exchange: s.exchange,
-                sector: s.sector,
-                yahoo_symbol: s.yahoo_symbol,
-            })
-            .collect();
-
-        let upserted =
-            trader_api::repository::SymbolInfoRepository::upsert_batch(pool, &new_symbols)
-                .await
-                .context("Failed to upsert KRX symbols")?;
-
-        println!("   DB 저장: {}개 종목 업데이트", upserted);
-        return Ok(upserted);
-    }
-
-    Ok(symbols.len())
+    Ok(symbols.into_iter().map(to_new_symbol).collect())
 }
 
 /// 미국 시장 종목 수집.
-async fn fetch_us_symbols(pool: Option<&PgPool>, config: &FetchSymbolsConfig) -> Result<usize> {
+async fn fetch_us_symbols(config: &FetchSymbolsConfig) -> Result<Vec<NewSymbolInfo>> {
     println!("📊 미국 시장 수집 중 (Yahoo Finance)...");
 
     use trader_data::provider::{SymbolInfoProvider, YahooSymbolProvider};
@@ -200,35 +653,11 @@ async fn fetch_us_symbols(pool: Option<&PgPool>, config: &FetchSymbolsConfig) ->
         println!("   CSV 저장: {}/us_symbols.csv", config.csv_dir);
     }
 
-    // DB 저장
-    if let Some(pool) = pool {
-        let new_symbols: Vec<trader_api::repository::NewSymbolInfo> = symbols
-            .into_iter()
-            .map(|s| trader_api::repository::NewSymbolInfo {
-                ticker: s.ticker,
-                name: s.name,
-                name_en: s.name_en,
-                market: s.market,
-                exchange: s.exchange,
-                sector: s.sector,
-                yahoo_symbol: s.yahoo_symbol,
-            })
-            .collect();
-
-        let upserted =
-            trader_api::repository::SymbolInfoRepository::upsert_batch(pool, &new_symbols)
-                .await
-                .context("Failed to upsert US symbols")?;
-
-        println!("   DB 저장: {}개 종목 업데이트", upserted);
-        return Ok(upserted);
-    }
-
-    Ok(symbols.len())
+    Ok(symbols.into_iter().map(to_new_symbol).collect())
 }
 
 /// 암호화폐 시장 종목 수집.
-async fn fetch_crypto_symbols(pool: Option<&PgPool>, config: &FetchSymbolsConfig) -> Result<usize> {
+async fn fetch_crypto_symbols(config: &FetchSymbolsConfig) -> Result<Vec<NewSymbolInfo>> {
     println!("📊 암호화폐 시장 수집 중 (Binance)...");
 
     // Binance API를 통해 USDT 페어 조회
@@ -291,32 +720,31 @@ async fn fetch_crypto_symbols(pool: Option<&PgPool>, config: &FetchSymbolsConfig
         println!("   CSV 저장: {}/crypto_symbols.csv", config.csv_dir);
     }
 
-    // DB 저장
-    if let Some(pool) = pool {
-        let new_symbols: Vec<trader_api::repository::NewSymbolInfo> = usdt_pairs
-            .into_iter()
-            .map(|s| trader_api::repository::NewSymbolInfo {
-                ticker: format!("{}/USDT", s.base_asset),
-                name: format!("{}/USDT", s.base_asset),
-                name_en: Some(s.base_asset.clone()),
-                market: "CRYPTO".to_string(),
-                exchange: Some("BINANCE".to_string()),
-                sector: Some("Cryptocurrency".to_string()),
-                yahoo_symbol: None, // Yahoo Finance는 암호화폐 미지원
-            })
-            .collect();
-
-        let _count = new_symbols.len();
-        let upserted =
-            trader_api::repository::SymbolInfoRepository::upsert_batch(pool, &new_symbols)
-                .await
-                .context("Failed to upsert crypto symbols")?;
+    Ok(usdt_pairs
+        .into_iter()
+        .map(|s| NewSymbolInfo {
+            ticker: format!("{}/USDT", s.base_asset),
+            name: format!("{}/USDT", s.base_asset),
+            name_en: Some(s.base_asset.clone()),
+            market: "CRYPTO".to_string(),
+            exchange: Some("BINANCE".to_string()),
+            sector: Some("Cryptocurrency".to_string()),
+            yahoo_symbol: None, // Yahoo Finance는 암호화폐 미지원
+        })
+        .collect())
+}
 
-        println!("   DB 저장: {}개 종목 업데이트", upserted);
-        return Ok(upserted);
+/// 소스 메타데이터를 저장용 구조로 변환.
+fn to_new_symbol(s: trader_data::provider::SymbolMetadata) -> NewSymbolInfo {
+    NewSymbolInfo {
+        ticker: s.ticker,
+        name: s.name,
+        name_en: s.name_en,
+        market: s.market,
+        exchange: s.exchange,
+        sector: s.sector,
+        yahoo_symbol: s.yahoo_symbol,
     }
-
-    Ok(usdt_pairs.len())
 }
 
 /// 종목 정보를 CSV 파일로 저장.
@@ -356,3 +784,110 @@ fn save_to_csv(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn existing(ticker: &str, name: &str, sector: Option<&str>, active: bool) -> SymbolInfo {
+        SymbolInfo {
+            id: Uuid::new_v4(),
+            ticker: ticker.to_string(),
+            name: name.to_string(),
+            name_en: None,
+            market: "KR".to_string(),
+            exchange: Some("KOSPI".to_string()),
+            sector: sector.map(str::to_string),
+            yahoo_symbol: Some(format!("{}.KS", ticker)),
+            is_active: Some(active),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn fetched(ticker: &str, name: &str, sector: Option<&str>) -> NewSymbolInfo {
+        NewSymbolInfo {
+            ticker: ticker.to_string(),
+            name: name.to_string(),
+            name_en: None,
+            market: "KR".to_string(),
+            exchange: Some("KOSPI".to_string()),
+            sector: sector.map(str::to_string),
+            yahoo_symbol: Some(format!("{}.KS", ticker)),
+        }
+    }
+
+    #[test]
+    fn test_diff_classifies_added_changed_delisted() {
+        let db = vec![
+            existing("005930", "삼성전자", Some("반도체"), true),
+            existing("000660", "하이닉스", Some("반도체"), true),
+            existing("035720", "카카오", None, true),
+        ];
+        let source = vec![
+            fetched("005930", "삼성전자", Some("반도체")),
+            fetched("000660", "SK하이닉스", Some("반도체")),
+            fetched("373220", "LG에너지솔루션", None),
+        ];
+
+        let diff = compute_symbol_diff(&db, source, true);
+
+        assert_eq!(diff.unchanged, 1);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].ticker, "373220");
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(
+            diff.changed[0].fields,
+            vec![FieldChange {
+                field: "name",
+                old: Some("하이닉스".to_string()),
+                new: Some("SK하이닉스".to_string()),
+            }]
+        );
+        assert_eq!(diff.delisted.len(), 1);
+        assert_eq!(diff.delisted[0].ticker, "035720");
+        assert_eq!(diff.upserts().len(), 2);
+    }
+
+    #[test]
+    fn test_diff_keeps_existing_values_when_source_omits() {
+        let db = vec![existing("005930", "삼성전자", Some("반도체"), true)];
+        let source = vec![fetched("005930", "", None)];
+
+        let diff = compute_symbol_diff(&db, source, true);
+
+        assert_eq!(diff.unchanged, 1);
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_partial_listing_skips_delisting() {
+        let db = vec![
+            existing("AAPL", "Apple", None, true),
+            existing("MSFT", "Microsoft", None, true),
+        ];
+        let diff = compute_symbol_diff(&db, vec![fetched("AAPL", "Apple", None)], false);
+        assert!(diff.delisted.is_empty());
+    }
+
+    #[test]
+    fn test_diff_inactive_symbols() {
+        let db = vec![
+            existing("005930", "삼성전자", None, false),
+            existing("000660", "SK하이닉스", None, false),
+        ];
+        let diff = compute_symbol_diff(&db, vec![fetched("005930", "삼성전자", None)], true);
+
+        // 이미 비활성인 종목은 다시 폐지 처리하지 않고, 재등장은 보고만 함
+        assert!(diff.delisted.is_empty());
+        assert_eq!(diff.inactive_listed, vec!["005930".to_string()]);
+    }
+
+    #[test]
+    fn test_delist_guard() {
+        assert!(!exceeds_delist_guard(0, 0));
+        assert!(!exceeds_delist_guard(10, 100));
+        assert!(exceeds_delist_guard(11, 100));
+        assert!(exceeds_delist_guard(1, 0));
+    }
+}
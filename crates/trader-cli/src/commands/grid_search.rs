@@ -0,0 +1,436 @@
+//! 전략 파라미터 그리드 서치.
+//!
+//! `--grid` JSON의 각 파라미터 후보 목록에 대한 데카르트 곱으로 설정 조합을 만들고,
+//! 조합마다 전략 테스트를 실행하여 수익률·샤프·최대 낙폭 기준으로 정렬된 결과를 출력합니다.
+//! 캔들 데이터는 한 번만 로드하여 모든 조합이 공유합니다.
+//!
+//! # 사용 예시
+//!
+//! ```bash
+//! # RSI 파라미터 6개 조합 탐색 (샤프 기준 상위 5개)
+//! trader strategy-test --strategy rsi --symbol 005930 \
+//!     --grid '{"rsi_period":[9,14,21],"oversold":[25,30]}' --grid-sort sharpe --grid-top 5
+//!
+//! # 병렬 8개로 실행하고 전체 결과를 CSV로 저장
+//! trader strategy-test --strategy bollinger --symbol SPY --market US \
+//!     --grid '{"period":[10,20,30],"std_dev":[1.5,2.0,2.5]}' --grid-parallel 8 --grid-csv grid.csv
+//! ```
+
+use std::{io::Write, path::Path, sync::Arc};
+
+use anyhow::{anyhow, Context, Result};
+use rust_decimal::Decimal;
+use serde_json::{Map, Value};
+use tokio::task::JoinSet;
+use tracing::warn;
+
+use crate::commands::strategy_test::{
+    load_test_data, run_strategy_test_with_data, StrategyTestConfig, TestData, TestResult,
+};
+
+/// 실행 대기 중인 (파라미터 조합, 설정) 목록
+type PendingCombinations = std::vec::IntoIter<(Map<String, Value>, StrategyTestConfig)>;
+
+/// 조합별 실행 결과 (파라미터 조합, 테스트 결과)
+type CombinationOutcome = (Map<String, Value>, Result<TestResult>);
+
+/// 그리드 서치 정렬 기준
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSortKey {
+    /// 총 수익률 (내림차순)
+    Return,
+    /// 샤프 비율 (내림차순)
+    Sharpe,
+    /// 최대 낙폭 (오름차순)
+    MaxDrawdown,
+}
+
+impl GridSortKey {
+    /// 문자열에서 파싱 (return, sharpe, mdd)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "return" => Some(Self::Return),
+            "sharpe" => Some(Self::Sharpe),
+            "mdd" | "drawdown" => Some(Self::MaxDrawdown),
+            _ => None,
+        }
+    }
+}
+
+/// 그리드 서치 옵션
+#[derive(Debug, Clone)]
+pub struct GridSearchOptions {
+    /// 파라미터 그리드 JSON (예: `{"rsi_period":[9,14,21]}`)
+    pub grid: String,
+    /// 동시 실행 수
+    pub parallel: usize,
+    /// 출력할 상위 결과 수
+    pub top: usize,
+    /// 정렬 기준
+    pub sort_by: GridSortKey,
+    /// 전체 결과 CSV 저장 경로 (옵션)
+    pub csv_path: Option<String>,
+}
+
+/// 조합별 실행 결과
+#[derive(Debug, Clone)]
+pub struct GridSearchResult {
+    /// 파라미터 조합
+    pub params: Map<String, Value>,
+    pub total_return_pct: Decimal,
+    pub sharpe_ratio: Decimal,
+    pub max_drawdown_pct: Decimal,
+    pub win_rate_pct: Decimal,
+    pub trades: usize,
+    /// 실행 실패 시 오류 메시지
+    pub error: Option<String>,
+}
+
+/// 그리드 JSON 파싱.
+///
+/// 값이 배열이 아니면 단일 후보로 취급합니다. 빈 배열은 오류입니다.
+fn parse_grid(grid: &str) -> Result<Vec<(String, Vec<Value>)>> {
+    let value: Value = serde_json::from_str(grid).context("Invalid --grid JSON")?;
+    let obj = value
+        .as_object()
+        .ok_or_else(|| anyhow!("--grid must be a JSON object"))?;
+
+    obj.iter()
+        .map(|(key, candidates)| {
+            let candidates = match candidates {
+                Value::Array(values) if values.is_empty() => {
+                    return Err(anyhow!("--grid parameter '{}' has no candidates", key));
+                }
+                Value::Array(values) => values.clone(),
+                other => vec![other.clone()],
+            };
+            Ok((key.clone(), candidates))
+        })
+        .collect()
+}
+
+/// 파라미터 후보의 데카르트 곱
+fn expand_grid(grid: &[(String, Vec<Value>)]) -> Vec<Map<String, Value>> {
+    grid.iter()
+        .fold(vec![Map::new()], |combinations, (key, candidates)| {
+            combinations
+                .iter()
+                .flat_map(|base| {
+                    candidates.iter().map(move |candidate| {
+                        let mut combination = base.clone();
+                        combination.insert(key.clone(), candidate.clone());
+                        combination
+                    })
+                })
+                .collect()
+        })
+}
+
+/// 기본 JSON 설정에 파라미터 조합을 덮어써 새 JSON 설정 문자열 생성
+fn merge_params(base: &Option<String>, params: &Map<String, Value>) -> Result<String> {
+    let mut merged = match base {
+        Some(json) => serde_json::from_str::<Value>(json).context("Invalid --config JSON")?,
+        None => Value::Object(Map::new()),
+    };
+    let obj = merged
+        .as_object_mut()
+        .ok_or_else(|| anyhow!("--config must be a JSON object"))?;
+    for (key, value) in params {
+        obj.insert(key.clone(), value.clone());
+    }
+    Ok(merged.to_string())
+}
+
+/// 정렬 기준에 따라 결과 정렬 (실패한 조합은 맨 뒤)
+fn sort_results(results: &mut [GridSearchResult], sort_by: GridSortKey) {
+    results.sort_by(|a, b| {
+        a.error
+            .is_some()
+            .cmp(&b.error.is_some())
+            .then_with(|| match sort_by {
+                GridSortKey::Return => b.total_return_pct.cmp(&a.total_return_pct),
+                GridSortKey::Sharpe => b.sharpe_ratio.cmp(&a.sharpe_ratio),
+                GridSortKey::MaxDrawdown => a.max_drawdown_pct.cmp(&b.max_drawdown_pct),
+            })
+    });
+}
+
+/// 파라미터 조합을 `key=value` 형식으로 표시
+fn format_params(params: &Map<String, Value>) -> String {
+    params
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 대기 중인 다음 조합을 실행 태스크로 추가
+fn spawn_next(
+    tasks: &mut JoinSet<CombinationOutcome>,
+    pending: &mut PendingCombinations,
+    data: &Arc<TestData>,
+) {
+    if let Some((params, combo_config)) = pending.next() {
+        let data = Arc::clone(data);
+        tasks.spawn(async move {
+            let result = run_strategy_test_with_data(combo_config, &data).await;
+            (params, result)
+        });
+    }
+}
+
+/// 그리드 서치 실행
+pub async fn run_grid_search(
+    mut config: StrategyTestConfig,
+    options: GridSearchOptions,
+) -> Result<Vec<GridSearchResult>> {
+    let grid = parse_grid(&options.grid)?;
+    let combinations = expand_grid(&grid);
+    let total = combinations.len();
+
+    println!(
+        "\n🔎 그리드 서치: {} ({} 조합, 병렬 {})",
+        config.strategy_id,
+        total,
+        options.parallel.max(1)
+    );
+
+    // 1. 캔들 데이터 1회 로드 (모든 조합이 공유)
+    let data = Arc::new(load_test_data(&mut config).await?);
+    println!("  📥 데이터 로드 완료: {}", config.symbols.join(", "));
+
+    // 2. 조합별 설정 생성
+    let mut pending = Vec::with_capacity(total);
+    for params in combinations {
+        let mut combo_config = config.clone();
+        combo_config.json_config = Some(merge_params(&config.json_config, &params)?);
+        combo_config.debug = false;
+        pending.push((params, combo_config));
+    }
+
+    // 3. 최대 parallel개씩 동시 실행
+    let mut pending = pending.into_iter();
+    let mut tasks: JoinSet<CombinationOutcome> = JoinSet::new();
+    let mut results = Vec::with_capacity(total);
+
+    for _ in 0..options.parallel.max(1) {
+        spawn_next(&mut tasks, &mut pending, &data);
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let (params, outcome) = joined.context("Grid search task panicked")?;
+        let result = match outcome {
+            Ok(test_result) => {
+                let metrics = test_result.report.as_ref().map(|r| &r.metrics);
+                GridSearchResult {
+                    params,
+                    total_return_pct: test_result.total_return_pct,
+                    sharpe_ratio: metrics.map(|m| m.sharpe_ratio).unwrap_or_default(),
+                    max_drawdown_pct: metrics.map(|m| m.max_drawdown_pct).unwrap_or_default(),
+                    win_rate_pct: test_result.win_rate_pct,
+                    trades: test_result.trades_executed,
+                    error: None,
+                }
+            }
+            Err(e) => {
+                warn!("조합 실패 ({}): {}", format_params(&params), e);
+                GridSearchResult {
+                    params,
+                    total_return_pct: Decimal::ZERO,
+                    sharpe_ratio: Decimal::ZERO,
+                    max_drawdown_pct: Decimal::ZERO,
+                    win_rate_pct: Decimal::ZERO,
+                    trades: 0,
+                    error: Some(e.to_string()),
+                }
+            }
+        };
+        results.push(result);
+
+        print!(
+            "\r  진행: {}/{} ({}%)",
+            results.len(),
+            total,
+            results.len() * 100 / total.max(1)
+        );
+        std::io::stdout().flush().ok();
+
+        spawn_next(&mut tasks, &mut pending, &data);
+    }
+    println!();
+
+    // 4. 정렬 및 출력
+    sort_results(&mut results, options.sort_by);
+    println!("\n{}", format_table(&results, options.top));
+
+    if let Some(ref csv_path) = options.csv_path {
+        save_results_csv(&results, &grid, csv_path)?;
+        println!("📁 그리드 서치 결과 저장: {}", csv_path);
+    }
+
+    Ok(results)
+}
+
+/// 상위 결과 표
+fn format_table(results: &[GridSearchResult], top: usize) -> String {
+    let failed = results.iter().filter(|r| r.error.is_some()).count();
+
+    let mut output = String::new();
+    output.push_str(&format!(
+        "그리드 서치 상위 {}개 (성공 {} / 실패 {})\n",
+        top.min(results.len() - failed),
+        results.len() - failed,
+        failed
+    ));
+    output.push_str("══════════════════════════════════════════════════════════════════════════\n");
+    output.push_str(&format!(
+        "{:>3}  {:>10}  {:>8}  {:>8}  {:>8}  {:>5}  파라미터\n",
+        "#", "수익률(%)", "Sharpe", "MDD(%)", "승률(%)", "거래"
+    ));
+    output.push_str("──────────────────────────────────────────────────────────────────────────\n");
+
+    for (rank, r) in results
+        .iter()
+        .filter(|r| r.error.is_none())
+        .take(top)
+        .enumerate()
+    {
+        output.push_str(&format!(
+            "{:>3}  {:>+10}  {:>8}  {:>8}  {:>8}  {:>5}  {}\n",
+            rank + 1,
+            r.total_return_pct.round_dp(2),
+            r.sharpe_ratio.round_dp(2),
+            r.max_drawdown_pct.round_dp(2),
+            r.win_rate_pct.round_dp(1),
+            r.trades,
+            format_params(&r.params)
+        ));
+    }
+    output
+}
+
+/// 전체 결과를 CSV로 저장 (헤더: 파라미터 이름들 + 지표 컬럼)
+fn save_results_csv(
+    results: &[GridSearchResult],
+    grid: &[(String, Vec<Value>)],
+    path: &str,
+) -> Result<()> {
+    let path = Path::new(path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut wtr = csv::Writer::from_path(path).context("Failed to create CSV writer")?;
+
+    let mut header: Vec<&str> = grid.iter().map(|(key, _)| key.as_str()).collect();
+    header.extend([
+        "total_return_pct",
+        "sharpe_ratio",
+        "max_drawdown_pct",
+        "win_rate_pct",
+        "trades",
+        "error",
+    ]);
+    wtr.write_record(&header)
+        .context("Failed to write CSV header")?;
+
+    for r in results {
+        let mut record: Vec<String> = grid
+            .iter()
+            .map(|(key, _)| match r.params.get(key) {
+                Some(Value::String(s)) => s.clone(),
+                Some(value) => value.to_string(),
+                None => String::new(),
+            })
+            .collect();
+        record.extend([
+            r.total_return_pct.to_string(),
+            r.sharpe_ratio.to_string(),
+            r.max_drawdown_pct.to_string(),
+            r.win_rate_pct.to_string(),
+            r.trades.to_string(),
+            r.error.clone().unwrap_or_default(),
+        ]);
+        wtr.write_record(&record)
+            .context("Failed to write CSV record")?;
+    }
+
+    wtr.flush().context("Failed to flush CSV writer")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::*;
+
+    fn result(return_pct: Decimal, sharpe: Decimal, mdd: Decimal) -> GridSearchResult {
+        GridSearchResult {
+            params: Map::new(),
+            total_return_pct: return_pct,
+            sharpe_ratio: sharpe,
+            max_drawdown_pct: mdd,
+            win_rate_pct: Decimal::ZERO,
+            trades: 0,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_expand_grid_cartesian_product() {
+        let grid = parse_grid(r#"{"rsi_period":[9,14,21],"oversold":[25,30]}"#).unwrap();
+        let combinations = expand_grid(&grid);
+
+        assert_eq!(combinations.len(), 6);
+        assert!(combinations
+            .iter()
+            .any(|c| c["rsi_period"] == json!(21) && c["oversold"] == json!(25)));
+    }
+
+    #[test]
+    fn test_parse_grid_scalar_and_empty() {
+        let grid = parse_grid(r#"{"period":14}"#).unwrap();
+        assert_eq!(grid[0].1, vec![json!(14)]);
+
+        assert!(parse_grid(r#"{"period":[]}"#).is_err());
+        assert!(parse_grid("[1,2]").is_err());
+    }
+
+    #[test]
+    fn test_merge_params_overrides_base() {
+        let mut params = Map::new();
+        params.insert("rsi_period".to_string(), json!(21));
+
+        let merged = merge_params(
+            &Some(r#"{"rsi_period":14,"amount":"1000"}"#.to_string()),
+            &params,
+        )
+        .unwrap();
+        let merged: Value = serde_json::from_str(&merged).unwrap();
+        assert_eq!(merged["rsi_period"], json!(21));
+        assert_eq!(merged["amount"], json!("1000"));
+    }
+
+    #[test]
+    fn test_sort_results_by_key() {
+        let mut failed = result(dec!(99), dec!(9), dec!(0));
+        failed.error = Some("error".to_string());
+        let mut results = vec![
+            failed,
+            result(dec!(10), dec!(0.5), dec!(20)),
+            result(dec!(5), dec!(1.5), dec!(5)),
+        ];
+
+        sort_results(&mut results, GridSortKey::Return);
+        assert_eq!(results[0].total_return_pct, dec!(10));
+        assert!(results[2].error.is_some());
+
+        sort_results(&mut results, GridSortKey::Sharpe);
+        assert_eq!(results[0].sharpe_ratio, dec!(1.5));
+
+        sort_results(&mut results, GridSortKey::MaxDrawdown);
+        assert_eq!(results[0].max_drawdown_pct, dec!(5));
+    }
+}
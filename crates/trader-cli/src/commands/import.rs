@@ -238,6 +238,7 @@ async fn download_from_yahoo(config: &ImportDbConfig) -> Result<Vec<Kline>> {
             close_time,
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         });
     }
 
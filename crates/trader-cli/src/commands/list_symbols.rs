@@ -1,9 +1,16 @@
 //! 종목 목록 조회 기능.
+//!
+//! `symbol_info`와 `symbol_fundamental`을 조인하여 시장/섹터/시가총액 필터와
+//! 거래량/시가총액/이름 정렬을 적용합니다. 모든 사용자 입력은 바인드 파라미터로
+//! 전달되며, 필터 조건은 컬럼에 함수를 씌우지 않아 기존 인덱스
+//! (`idx_symbol_info_market_sector`, `idx_symbol_fundamental_market_cap` 등)를 사용합니다.
 
 use std::{fs::File, io::Write};
 
 use anyhow::{Context, Result};
+use rust_decimal::Decimal;
 use serde::Serialize;
+use sqlx::{Postgres, QueryBuilder};
 use tracing::info;
 use trader_data::{Database, DatabaseConfig};
 
@@ -14,6 +21,14 @@ pub struct ListSymbolsConfig {
     pub market: String,
     /// 활성화된 종목만
     pub active_only: bool,
+    /// 섹터 필터 (정확히 일치)
+    pub sector: Option<String>,
+    /// 최소 시가총액
+    pub min_market_cap: Option<Decimal>,
+    /// 정렬 기준 (None이면 시장, 티커 순)
+    pub sort_by: Option<SortBy>,
+    /// 정렬 방향 (None이면 정렬 기준의 기본 방향)
+    pub order: Option<SortOrder>,
     /// 출력 형식
     pub format: OutputFormat,
     /// 출력 파일 경로
@@ -48,6 +63,72 @@ impl OutputFormat {
     }
 }
 
+/// 정렬 기준.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+    /// 10일 평균 거래량
+    Volume,
+    /// 시가총액
+    MarketCap,
+    /// 종목명
+    Name,
+}
+
+impl SortBy {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "volume" => Ok(Self::Volume),
+            "market_cap" | "marketcap" => Ok(Self::MarketCap),
+            "name" => Ok(Self::Name),
+            _ => Err(anyhow::anyhow!(
+                "Invalid sort key: {}. Use: volume, market_cap, name",
+                s
+            )),
+        }
+    }
+
+    /// 정렬 컬럼.
+    fn column(self) -> &'static str {
+        match self {
+            Self::Volume => "sf.avg_volume_10d",
+            Self::MarketCap => "sf.market_cap",
+            Self::Name => "si.name",
+        }
+    }
+
+    /// `--order` 미지정 시 방향 (수치는 큰 값부터, 이름은 가나다순).
+    fn default_order(self) -> SortOrder {
+        match self {
+            Self::Volume | Self::MarketCap => SortOrder::Desc,
+            Self::Name => SortOrder::Asc,
+        }
+    }
+}
+
+/// 정렬 방향.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "asc" => Ok(Self::Asc),
+            "desc" => Ok(Self::Desc),
+            _ => Err(anyhow::anyhow!("Invalid order: {}. Use: asc, desc", s)),
+        }
+    }
+
+    fn as_sql(self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+}
+
 /// 종목 정보.
 #[derive(Debug, Clone, Serialize, sqlx::FromRow)]
 pub struct SymbolInfo {
@@ -56,62 +137,141 @@ pub struct SymbolInfo {
     pub market: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exchange: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub sector: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub yahoo_symbol: Option<String>,
     pub is_active: bool,
+    /// 시가총액 (펀더멘털 미수집 시 None)
+    pub market_cap: Option<Decimal>,
+    /// 10일 평균 거래량 (펀더멘털 미수집 시 None)
+    pub volume: Option<i64>,
 }
 
-/// 종목 목록 조회.
-pub async fn list_symbols(config: ListSymbolsConfig) -> Result<usize> {
-    // DB URL 가져오기
-    let db_url = config
-        .db_url
-        .or_else(|| std::env::var("DATABASE_URL").ok())
-        .ok_or_else(|| {
-            anyhow::anyhow!(
-                "DATABASE_URL not found. Set DATABASE_URL environment variable or use --db-url flag"
-            )
-        })?;
-
-    let db_config = DatabaseConfig::for_cli(db_url);
-    let db = Database::connect(&db_config)
-        .await
-        .context("데이터베이스 연결 실패")?;
-    let pool = db.pool().clone();
+/// 조회 쿼리 생성.
+///
+/// 최소 시가총액 필터가 있으면 펀더멘털이 없는 종목은 어차피 제외되므로
+/// 내부 조인으로 바꿔 시가총액 인덱스부터 탐색할 수 있게 합니다.
+fn build_query(config: &ListSymbolsConfig) -> QueryBuilder<'_, Postgres> {
+    let join = if config.min_market_cap.is_some() {
+        "JOIN"
+    } else {
+        "LEFT JOIN"
+    };
 
-    // 쿼리 생성
-    let mut query = String::from("SELECT ticker, name, market, exchange, sector, yahoo_symbol, is_active FROM symbol_info WHERE 1=1");
+    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(
+        "SELECT si.ticker, si.name, si.market, si.exchange, si.sector, si.yahoo_symbol, \
+         si.is_active, sf.market_cap, sf.avg_volume_10d AS volume FROM symbol_info si ",
+    );
+    builder.push(join);
+    builder.push(" symbol_fundamental sf ON sf.symbol_info_id = si.id WHERE 1=1");
 
     // 시장 필터
-    if config.market.to_uppercase() != "ALL" {
-        query.push_str(&format!(" AND market = '{}'", config.market.to_uppercase()));
+    if !config.market.eq_ignore_ascii_case("ALL") {
+        builder.push(" AND si.market = ");
+        builder.push_bind(config.market.to_uppercase());
     }
 
-    // 활성화 필터
+    // 활성화 필터 (부분 인덱스 조건과 일치하도록 리터럴 사용)
     if config.active_only {
-        query.push_str(" AND is_active = true");
+        builder.push(" AND si.is_active = true");
+    }
+
+    // 섹터 필터
+    if let Some(ref sector) = config.sector {
+        builder.push(" AND si.sector = ");
+        builder.push_bind(sector.as_str());
+    }
+
+    // 최소 시가총액
+    if let Some(min_market_cap) = config.min_market_cap {
+        builder.push(" AND sf.market_cap >= ");
+        builder.push_bind(min_market_cap);
     }
 
     // 검색 키워드
     if let Some(ref search) = config.search {
-        query.push_str(&format!(
-            " AND (ticker ILIKE '%{}%' OR name ILIKE '%{}%')",
-            search, search
-        ));
+        let pattern = format!("%{}%", search);
+        builder.push(" AND (si.ticker ILIKE ");
+        builder.push_bind(pattern.clone());
+        builder.push(" OR si.name ILIKE ");
+        builder.push_bind(pattern);
+        builder.push(")");
     }
 
     // 정렬
-    query.push_str(" ORDER BY market, ticker");
+    match config.sort_by {
+        Some(sort_by) => {
+            let order = config.order.unwrap_or_else(|| sort_by.default_order());
+            builder.push(format!(
+                " ORDER BY {} {} NULLS LAST, si.ticker",
+                sort_by.column(),
+                order.as_sql()
+            ));
+        }
+        None => {
+            builder.push(" ORDER BY si.market, si.ticker");
+        }
+    }
 
     // 제한
     if config.limit > 0 {
-        query.push_str(&format!(" LIMIT {}", config.limit));
+        builder.push(" LIMIT ");
+        builder.push_bind(config.limit as i64);
+    }
+
+    builder
+}
+
+/// 적용된 필터 설명 (결과가 없을 때 안내용).
+fn describe_filters(config: &ListSymbolsConfig) -> String {
+    let mut filters = Vec::new();
+
+    if !config.market.eq_ignore_ascii_case("ALL") {
+        filters.push(format!("market={}", config.market.to_uppercase()));
     }
+    if config.active_only {
+        filters.push("active_only".to_string());
+    }
+    if let Some(ref sector) = config.sector {
+        filters.push(format!("sector={}", sector));
+    }
+    if let Some(min_market_cap) = config.min_market_cap {
+        filters.push(format!("min_market_cap={}", min_market_cap));
+    }
+    if let Some(ref search) = config.search {
+        filters.push(format!("search={}", search));
+    }
+
+    if filters.is_empty() {
+        "없음".to_string()
+    } else {
+        filters.join(", ")
+    }
+}
+
+/// 종목 목록 조회.
+pub async fn list_symbols(config: ListSymbolsConfig) -> Result<usize> {
+    // DB URL 가져오기
+    let db_url = config
+        .db_url
+        .clone()
+        .or_else(|| std::env::var("DATABASE_URL").ok())
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "DATABASE_URL not found. Set DATABASE_URL environment variable or use --db-url flag"
+            )
+        })?;
+
+    let db_config = DatabaseConfig::for_cli(db_url);
+    let db = Database::connect(&db_config)
+        .await
+        .context("데이터베이스 연결 실패")?;
+    let pool = db.pool().clone();
 
     info!("Querying symbols...");
-    let symbols: Vec<SymbolInfo> = sqlx::query_as(&query)
+    let mut query = build_query(&config);
+    let symbols: Vec<SymbolInfo> = query
+        .build_query_as()
         .fetch_all(&pool)
         .await
         .context("Failed to query symbols")?;
@@ -120,6 +280,14 @@ pub async fn list_symbols(config: ListSymbolsConfig) -> Result<usize> {
 
     info!("Found {} symbols", symbols.len());
 
+    if symbols.is_empty() {
+        println!(
+            "조건에 맞는 종목이 없습니다 (적용된 필터: {})",
+            describe_filters(&config)
+        );
+        return Ok(0);
+    }
+
     // 출력
     output_symbols(&symbols, config.format, config.output.as_deref())?;
 
@@ -158,21 +326,37 @@ fn format_table(symbols: &[SymbolInfo]) -> String {
 
     // 헤더
     output.push_str(&format!(
-        "{:<12} {:<50} {:<8} {:<12} {:<30} {:<15} {:<8}\n",
-        "TICKER", "NAME", "MARKET", "EXCHANGE", "SECTOR", "YAHOO_SYMBOL", "ACTIVE"
+        "{:<12} {:<50} {:<8} {:<12} {:<30} {:>20} {:>14} {:<15} {:<8}\n",
+        "TICKER",
+        "NAME",
+        "MARKET",
+        "EXCHANGE",
+        "SECTOR",
+        "MARKET_CAP",
+        "VOLUME",
+        "YAHOO_SYMBOL",
+        "ACTIVE"
     ));
-    output.push_str(&"-".repeat(145));
+    output.push_str(&"-".repeat(181));
     output.push('\n');
 
     // 데이터
     for symbol in symbols {
         output.push_str(&format!(
-            "{:<12} {:<50} {:<8} {:<12} {:<30} {:<15} {:<8}\n",
+            "{:<12} {:<50} {:<8} {:<12} {:<30} {:>20} {:>14} {:<15} {:<8}\n",
             symbol.ticker,
             truncate(&symbol.name, 50),
             symbol.market,
             symbol.exchange.as_deref().unwrap_or("-"),
             truncate(symbol.sector.as_deref().unwrap_or("-"), 30),
+            symbol
+                .market_cap
+                .map(|v| v.round().to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            symbol
+                .volume
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "-".to_string()),
             symbol.yahoo_symbol.as_deref().unwrap_or("-"),
             if symbol.is_active { "✓" } else { "✗" }
         ));
@@ -206,17 +390,20 @@ fn format_csv(symbols: &[SymbolInfo]) -> String {
     let mut output = String::new();
 
     // 헤더
-    output.push_str("ticker,name,market,exchange,sector,yahoo_symbol,is_active\n");
+    output
+        .push_str("ticker,name,market,exchange,sector,market_cap,volume,yahoo_symbol,is_active\n");
 
     // 데이터
     for symbol in symbols {
         output.push_str(&format!(
-            "{},{},{},{},{},{},{}\n",
+            "{},{},{},{},{},{},{},{},{}\n",
             symbol.ticker,
             escape_csv(&symbol.name),
             symbol.market,
             symbol.exchange.as_deref().unwrap_or(""),
             escape_csv(symbol.sector.as_deref().unwrap_or("")),
+            symbol.market_cap.map(|v| v.to_string()).unwrap_or_default(),
+            symbol.volume.map(|v| v.to_string()).unwrap_or_default(),
             symbol.yahoo_symbol.as_deref().unwrap_or(""),
             symbol.is_active
         ));
@@ -251,3 +438,122 @@ fn escape_csv(s: &str) -> String {
         s.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ListSymbolsConfig {
+        ListSymbolsConfig {
+            market: "ALL".to_string(),
+            active_only: true,
+            sector: None,
+            min_market_cap: None,
+            sort_by: None,
+            order: None,
+            format: OutputFormat::Table,
+            output: None,
+            search: None,
+            limit: 0,
+            db_url: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_sort_options() {
+        assert_eq!(SortBy::parse("volume").unwrap(), SortBy::Volume);
+        assert_eq!(SortBy::parse("MARKET_CAP").unwrap(), SortBy::MarketCap);
+        assert_eq!(SortBy::parse("name").unwrap(), SortBy::Name);
+        assert!(SortBy::parse("price").is_err());
+        assert_eq!(SortOrder::parse("Desc").unwrap(), SortOrder::Desc);
+        assert!(SortOrder::parse("up").is_err());
+    }
+
+    #[test]
+    fn test_build_query_default() {
+        let config = config();
+        let query = build_query(&config);
+        let sql = query.sql();
+        assert!(sql.contains("LEFT JOIN symbol_fundamental sf"));
+        assert!(sql.contains("si.is_active = true"));
+        assert!(!sql.contains("si.market ="));
+        assert!(sql.ends_with("ORDER BY si.market, si.ticker"));
+    }
+
+    #[test]
+    fn test_build_query_filters_are_bound() {
+        let mut config = config();
+        config.market = "kr".to_string();
+        config.sector = Some("반도체".to_string());
+        config.min_market_cap = Some(Decimal::new(1_000_000_000, 0));
+        config.search = Some("'; DROP TABLE symbol_info; --".to_string());
+        config.sort_by = Some(SortBy::MarketCap);
+        config.limit = 20;
+
+        let query = build_query(&config);
+        let sql = query.sql();
+        assert!(sql.contains(" JOIN symbol_fundamental sf") && !sql.contains("LEFT JOIN"));
+        assert!(sql.contains("si.market = $1"));
+        assert!(sql.contains("si.sector = $2"));
+        assert!(sql.contains("sf.market_cap >= $3"));
+        assert!(sql.contains("si.ticker ILIKE $4 OR si.name ILIKE $5"));
+        assert!(sql.contains("ORDER BY sf.market_cap DESC NULLS LAST, si.ticker"));
+        assert!(sql.ends_with("LIMIT $6"));
+        assert!(!sql.contains("DROP TABLE"));
+    }
+
+    #[test]
+    fn test_build_query_sort_order() {
+        let mut config = config();
+        config.sort_by = Some(SortBy::Name);
+        assert!(build_query(&config)
+            .sql()
+            .contains("ORDER BY si.name ASC NULLS LAST"));
+
+        config.sort_by = Some(SortBy::Volume);
+        config.order = Some(SortOrder::Asc);
+        assert!(build_query(&config)
+            .sql()
+            .contains("ORDER BY sf.avg_volume_10d ASC NULLS LAST"));
+    }
+
+    #[test]
+    fn test_describe_filters() {
+        let mut config = config();
+        config.active_only = false;
+        assert_eq!(describe_filters(&config), "없음");
+
+        config.market = "us".to_string();
+        config.sector = Some("Technology".to_string());
+        config.min_market_cap = Some(Decimal::new(5, 0));
+        assert_eq!(
+            describe_filters(&config),
+            "market=US, sector=Technology, min_market_cap=5"
+        );
+    }
+
+    #[test]
+    fn test_format_csv_includes_filter_columns() {
+        let symbols = vec![SymbolInfo {
+            ticker: "005930".to_string(),
+            name: "삼성전자".to_string(),
+            market: "KR".to_string(),
+            exchange: Some("KRX".to_string()),
+            sector: Some("반도체".to_string()),
+            yahoo_symbol: None,
+            is_active: true,
+            market_cap: Some(Decimal::new(400_000_000_000_000, 0)),
+            volume: None,
+        }];
+        let csv = format_csv(&symbols);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "ticker,name,market,exchange,sector,market_cap,volume,yahoo_symbol,is_active"
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "005930,삼성전자,KR,KRX,반도체,400000000000000,,,true"
+        );
+    }
+}
@@ -6,6 +6,7 @@
 //! # 현재 마이그레이션 검증
 //! trader migrate verify
 //! trader migrate verify --verbose
+//! trader migrate verify --fail-on warn
 //!
 //! # 통합 계획 생성 (dry-run)
 //! trader migrate consolidate --dry-run
@@ -13,6 +14,13 @@
 //! # 통합 실행
 //! trader migrate consolidate --output migrations_v2
 //!
+//! # 롤백 SQL 생성 (dry-run은 화면 출력만)
+//! trader migrate rollback --dry-run
+//! trader migrate rollback --output migrations_rollback
+//!
+//! # 실제 DB 스키마와 마이그레이션 간 드리프트 검출 (읽기 전용 연결)
+//! trader migrate drift --db-url "postgres://..."
+//!
 //! # 의존성 그래프 시각화
 //! trader migrate graph --format mermaid > graph.md
 //!
@@ -22,9 +30,13 @@
 
 use std::path::PathBuf;
 
+use sqlx::{
+    postgres::{PgConnectOptions, PgPoolOptions},
+    PgPool,
+};
 use trader_core::migration::{
-    generate_safety_checklist, DependencyGraph, MigrationAnalyzer, MigrationConsolidator,
-    MigrationValidator,
+    generate_safety_checklist, ColumnSchema, DependencyGraph, MigrationAnalyzer,
+    MigrationConsolidator, MigrationValidator, SchemaSnapshot, Severity,
 };
 
 /// 마이그레이션 설정
@@ -42,6 +54,8 @@ pub struct MigrateConfig {
     pub graph_format: GraphFormat,
     /// 데이터베이스 URL (apply 시)
     pub db_url: Option<String>,
+    /// verify 실패 기준 심각도 (이 심각도 이상 이슈가 있으면 실패)
+    pub fail_on: Severity,
 }
 
 impl Default for MigrateConfig {
@@ -53,6 +67,7 @@ impl Default for MigrateConfig {
             dry_run: false,
             graph_format: GraphFormat::Mermaid,
             db_url: None,
+            fail_on: Severity::Error,
         }
     }
 }
@@ -135,7 +150,7 @@ pub fn run_verify(config: &MigrateConfig) -> Result<bool, String> {
         }
     }
 
-    Ok(report.is_valid())
+    Ok(report.count_at_least(config.fail_on) == 0)
 }
 
 /// 마이그레이션 통합 실행
@@ -187,6 +202,76 @@ pub fn run_consolidate(config: &MigrateConfig) -> Result<(), String> {
     Ok(())
 }
 
+/// 마이그레이션별 롤백 SQL 생성
+///
+/// 파일마다 `<name>.down.sql`을 생성합니다. 자동 역변환이 불가능한 문장은
+/// 경고 주석과 TODO 스텁으로 남으므로 적용 전 반드시 검토해야 합니다.
+pub fn run_rollback(config: &MigrateConfig) -> Result<(), String> {
+    println!("\n⏪ 롤백 SQL 생성 시작...\n");
+
+    let analyzer = MigrationAnalyzer::new();
+    let files = analyzer.scan_directory(&config.migrations_dir)?;
+
+    if files.is_empty() {
+        return Err("마이그레이션 파일을 찾을 수 없습니다".to_string());
+    }
+
+    let output_dir = config
+        .output_dir
+        .as_ref()
+        .cloned()
+        .unwrap_or_else(|| PathBuf::from("migrations_rollback"));
+
+    if !config.dry_run {
+        std::fs::create_dir_all(&output_dir)
+            .map_err(|e| format!("출력 디렉토리 생성 실패: {}", e))?;
+    }
+
+    let mut incomplete = 0;
+    for file in &files {
+        let script = analyzer.generate_rollback(file);
+        if !script.is_complete() {
+            incomplete += 1;
+        }
+
+        if config.dry_run {
+            println!("{}", script.to_sql());
+            continue;
+        }
+
+        let path = output_dir.join(format!("{}.down.sql", file.name));
+        std::fs::write(&path, script.to_sql())
+            .map_err(|e| format!("롤백 파일 쓰기 실패 {:?}: {}", path, e))?;
+
+        if config.verbose || !script.is_complete() {
+            println!(
+                "   {} {} ({} 단계, 수동 {} 개)",
+                if script.is_complete() {
+                    "✅"
+                } else {
+                    "⚠️"
+                },
+                file.name,
+                script.steps.len(),
+                script.manual_step_count()
+            );
+        }
+    }
+
+    println!("\n✅ 롤백 SQL 생성 완료: {} 개 파일", files.len());
+    if !config.dry_run {
+        println!("   출력 디렉토리: {:?}", output_dir);
+    }
+    if incomplete > 0 {
+        println!(
+            "   ⚠️ {} 개 파일에 수동 작성이 필요한 단계가 있습니다 (TODO 주석 참고)",
+            incomplete
+        );
+    }
+
+    Ok(())
+}
+
 /// 의존성 그래프 출력
 pub fn run_graph(config: &MigrateConfig) -> Result<String, String> {
     let analyzer = MigrationAnalyzer::new();
@@ -357,6 +442,7 @@ pub async fn run_apply(config: &MigrateConfig) -> Result<(), String> {
     let verify_config = MigrateConfig {
         migrations_dir: config.migrations_dir.clone(),
         verbose: false,
+        fail_on: config.fail_on,
         ..Default::default()
     };
 
@@ -413,6 +499,135 @@ pub async fn run_apply(config: &MigrateConfig) -> Result<(), String> {
     }
 }
 
+/// 실제 DB 스키마와 마이그레이션 간 드리프트 검출
+///
+/// 마이그레이션을 순서대로 적용했을 때의 기대 스키마와 `information_schema`에서 읽은
+/// 현재 스키마를 비교합니다. 연결은 `default_transaction_read_only`로 열어
+/// 실수로 스키마를 변경할 수 없도록 합니다.
+///
+/// # Returns
+/// 드리프트가 없으면 true
+pub async fn run_drift(config: &MigrateConfig) -> Result<bool, String> {
+    println!("\n🔎 스키마 드리프트 검사 시작...\n");
+
+    let analyzer = MigrationAnalyzer::new();
+    let files = analyzer.scan_directory(&config.migrations_dir)?;
+    if files.is_empty() {
+        return Err("마이그레이션 파일을 찾을 수 없습니다".to_string());
+    }
+    let expected = analyzer.expected_schema(&files);
+
+    let db_url = config
+        .db_url
+        .clone()
+        .or_else(|| std::env::var("DATABASE_URL").ok())
+        .ok_or("DATABASE_URL이 설정되지 않았습니다. --db-url 옵션 사용")?;
+
+    let pool = connect_read_only(&db_url).await?;
+    let actual = load_actual_schema(&pool).await;
+    pool.close().await;
+    let actual = actual?;
+
+    println!(
+        "📁 기대 스키마: {} 테이블, {} 인덱스 ({} 개 파일)",
+        expected.tables.len(),
+        expected.indexes.len(),
+        files.len()
+    );
+    println!(
+        "🗄️ 실제 스키마: {} 테이블, {} 인덱스\n",
+        actual.tables.len(),
+        actual.indexes.len()
+    );
+
+    let report = expected.diff(&actual);
+    println!("{}", report);
+
+    if let (false, Some(output_dir)) = (report.is_clean(), &config.output_dir) {
+        std::fs::create_dir_all(output_dir)
+            .map_err(|e| format!("출력 디렉토리 생성 실패: {}", e))?;
+        let path = output_dir.join("fix_schema_drift.sql");
+        std::fs::write(&path, report.suggested_migration())
+            .map_err(|e| format!("해결 스텁 쓰기 실패 {:?}: {}", path, e))?;
+        println!(
+            "📝 해결용 마이그레이션 스텁: {:?} (적용 전 검토 필요)",
+            path
+        );
+    }
+
+    Ok(report.is_clean())
+}
+
+/// 읽기 전용 DB 연결
+async fn connect_read_only(db_url: &str) -> Result<PgPool, String> {
+    let options: PgConnectOptions = db_url
+        .parse()
+        .map_err(|e| format!("DATABASE_URL 파싱 실패: {}", e))?;
+    let options = options.options([("default_transaction_read_only", "on")]);
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .map_err(|e| format!("데이터베이스 연결 실패: {}", e))?;
+
+    // 읽기 전용 설정이 적용되지 않으면 (예: 풀러가 옵션을 무시) 중단
+    let read_only: String = sqlx::query_scalar("SHOW transaction_read_only")
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| format!("읽기 전용 확인 실패: {}", e))?;
+    if read_only != "on" {
+        pool.close().await;
+        return Err("읽기 전용 연결을 보장할 수 없어 중단합니다".to_string());
+    }
+
+    Ok(pool)
+}
+
+/// `information_schema`와 `pg_index`에서 현재 스키마 조회
+///
+/// PK/UNIQUE 제약조건이 자동 생성한 인덱스는 제외합니다.
+async fn load_actual_schema(pool: &PgPool) -> Result<SchemaSnapshot, String> {
+    let columns: Vec<(String, String, String)> = sqlx::query_as(
+        r#"
+        SELECT c.table_name::text, c.column_name::text, c.data_type::text
+        FROM information_schema.columns c
+        JOIN information_schema.tables t
+          ON t.table_schema = c.table_schema AND t.table_name = c.table_name
+        WHERE c.table_schema = 'public' AND t.table_type = 'BASE TABLE'
+        ORDER BY c.table_name, c.ordinal_position
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("컬럼 조회 실패: {}", e))?;
+
+    let indexes: Vec<(String, String, String)> = sqlx::query_as(
+        r#"
+        SELECT i.relname::text, t.relname::text, pg_get_indexdef(x.indexrelid)
+        FROM pg_index x
+        JOIN pg_class i ON i.oid = x.indexrelid
+        JOIN pg_class t ON t.oid = x.indrelid
+        JOIN pg_namespace n ON n.oid = t.relnamespace
+        WHERE n.nspname = 'public'
+          AND NOT EXISTS (SELECT 1 FROM pg_constraint c WHERE c.conindid = x.indexrelid)
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("인덱스 조회 실패: {}", e))?;
+
+    let mut schema = SchemaSnapshot::new();
+    for (table, column, data_type) in columns {
+        schema.add_column(&table, ColumnSchema::new(&column, &data_type));
+    }
+    for (index, table, definition) in indexes {
+        schema.add_index(&index, &table, Some(definition));
+    }
+
+    Ok(schema)
+}
+
 /// 마이그레이션 상태 확인
 pub async fn run_status(config: &MigrateConfig) -> Result<(), String> {
     let db_url = config
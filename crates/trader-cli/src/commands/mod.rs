@@ -1,14 +1,20 @@
 //! CLI 명령어 구현 모듈.
 
 pub mod backtest;
+pub mod backtest_export;
+pub mod backtest_history;
 pub mod chart_gen;
 pub mod download;
+pub mod download_batch;
 pub mod fetch_symbols;
+pub mod grid_search;
 pub mod health;
 pub mod import;
 pub mod list_symbols;
 pub mod migrate;
 pub mod strategy_test;
+pub mod train;
+pub mod walkforward;
 // sync_csv는 trader-collector로 이동됨
 
 // 각 서브모듈 직접 사용 권장 (ambiguous re-export 방지)
@@ -24,7 +24,7 @@ use tracing::{debug, info, warn};
 
 use trader_core::{Kline, MarketType, StrategyContext, Timeframe, SignalType};
 use trader_data::cache::CachedHistoricalDataProvider;
-use trader_data::storage::ohlcv::OhlcvCache;
+use trader_data::storage::ohlcv::{MissingCandlePolicy, OhlcvCache};
 use trader_data::{Database, DatabaseConfig};
 use trader_strategy::StrategyRegistry;
 
@@ -170,7 +170,13 @@ pub async fn run_sim_test(config: SimTestConfig) -> Result<SimTestResult> {
         .unwrap_or(now);
 
     let klines = ohlcv_cache
-        .get_cached_klines_range(&config.symbol, Timeframe::D1, start, end)
+        .get_cached_klines_range(
+            &config.symbol,
+            Timeframe::D1,
+            start,
+            end,
+            MissingCandlePolicy::None,
+        )
         .await
         .map_err(|e| anyhow!("캔들 데이터 로드 실패: {}", e))?;
 
@@ -7,7 +7,7 @@
 //! 1. **UI 동일 흐름**: JSON config → StrategyContext 주입 → 전략 초기화 → 백테스트
 //! 2. **상세 진단**: 신호 발생 여부, 거래 내역, 조건 평가 결과
 //! 3. **거래 분석**: 진입/청산 시점, 가격, PnL 상세
-//! 4. **문제 원인 분석**: 신호 미발생 시 원인 추적
+//! 4. **문제 원인 분석**: 신호 미발생 및 성과 부진 원인을 규칙 기반으로 진단
 //! 5. **다중 심볼 지원**: 로테이션/자산배분 전략 테스트
 //!
 //! # 사용 예시
@@ -24,6 +24,12 @@
 //!
 //! # 상세 디버그 모드
 //! trader strategy-test --strategy rsi --symbol 005930 --debug
+//!
+//! # 스크리닝 기반 전략 + 시점별 펀더멘털 스냅샷
+//! trader strategy-test --strategy small_cap_quant_v2 --symbols "005930,000660" --fundamentals fundamentals.json
+//!
+//! # 파라미터 그리드 서치 (데이터 1회 로드, 조합 병렬 실행)
+//! trader strategy-test --strategy rsi --symbol 005930 --grid '{"rsi_period":[9,14,21],"oversold":[25,30]}'
 //! ```
 
 use std::sync::Arc;
@@ -37,17 +43,25 @@ use tracing::{debug, warn};
 use trader_analytics::{
     backtest::{
         BacktestConfig, BacktestEngine, BacktestReport, BacktestScreeningProvider,
-        ScreeningCalculatorConfig,
+        FundamentalSnapshotStore, ScreeningCalculatorConfig,
     },
     AnalyticsProviderImpl,
 };
 use trader_core::{AnalyticsProvider, Kline, MarketType, StrategyContext, Timeframe};
 use trader_data::{
-    cache::CachedHistoricalDataProvider, storage::ohlcv::OhlcvCache, Database, DatabaseConfig,
+    cache::CachedHistoricalDataProvider,
+    storage::ohlcv::{MissingCandlePolicy, OhlcvCache},
+    Database, DatabaseConfig,
+};
+use trader_strategy::{
+    strategies::common::{DiagnosisInput, StrategyDiagnoser},
+    StrategyRegistry,
 };
-use trader_strategy::StrategyRegistry;
 
-use crate::commands::download::Market;
+use crate::commands::{
+    backtest::{common_time_range, retain_time_range},
+    download::Market,
+};
 
 /// 전략 테스트 CLI 설정
 #[derive(Debug, Clone)]
@@ -70,6 +84,8 @@ pub struct StrategyTestConfig {
     pub debug: bool,
     /// 데이터베이스 URL
     pub db_url: Option<String>,
+    /// 시점별 펀더멘털 스냅샷 JSON 경로 (스크리닝 기반 전략용)
+    pub fundamentals_path: Option<String>,
 }
 
 impl Default for StrategyTestConfig {
@@ -84,6 +100,7 @@ impl Default for StrategyTestConfig {
             initial_capital: Decimal::from(10_000_000),
             debug: false,
             db_url: None,
+            fundamentals_path: None,
         }
     }
 }
@@ -137,6 +154,7 @@ fn create_screening_provider_for_strategy(strategy_id: &str) -> Option<BacktestS
     // 주의: 백테스트에서는 Fundamental 데이터(목표가, 손절가, 추천가)가 없어서
     // GlobalScore 계산 시 기술적 지표(33%)만 반영됩니다.
     // 따라서 min_score를 낮게 설정해야 스크리닝 결과가 나옵니다.
+    // PER/PBR/ROE는 --fundamentals 스냅샷으로 시점별 값을 반영할 수 있습니다.
     let config = match strategy_id {
         "small_cap_quant_v2" => ScreeningCalculatorConfig::monthly(
             SCREENING_PRESET_NAME,
@@ -312,13 +330,17 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
     // 모든 심볼의 klines 로드 및 공통 시간 범위 계산
     let mut all_klines: std::collections::HashMap<String, Vec<Kline>> =
         std::collections::HashMap::new();
-    let mut common_start: Option<chrono::DateTime<Utc>> = None;
-    let mut common_end: Option<chrono::DateTime<Utc>> = None;
 
     println!("  📥 {} 심볼 로드 중...", config.symbols.len());
     for symbol in &config.symbols {
         match ohlcv_cache
-            .get_cached_klines_range(symbol, Timeframe::D1, requested_start, requested_end)
+            .get_cached_klines_range(
+                symbol,
+                Timeframe::D1,
+                requested_start,
+                requested_end,
+                MissingCandlePolicy::None,
+            )
             .await
         {
             Ok(symbol_klines) if !symbol_klines.is_empty() => {
@@ -332,10 +354,6 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
                     sym_end.format("%Y-%m-%d")
                 );
 
-                // 공통 시간 범위 계산 (교집합)
-                common_start = Some(common_start.map_or(sym_start, |cs| cs.max(sym_start)));
-                common_end = Some(common_end.map_or(sym_end, |ce| ce.min(sym_end)));
-
                 all_klines.insert(symbol.clone(), symbol_klines);
             }
             Ok(_) => {
@@ -347,9 +365,9 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
         }
     }
 
-    // 공통 시간 범위로 klines 필터링
-    let (start, end) = match (common_start, common_end) {
-        (Some(cs), Some(ce)) if cs < ce => {
+    // 공통 시간 범위(교집합)로 klines 필터링
+    let (start, end) = match common_time_range(all_klines.values()) {
+        Some((cs, ce)) => {
             println!(
                 "  📅 공통 시간 범위: {} ~ {}",
                 cs.format("%Y-%m-%d"),
@@ -357,16 +375,12 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
             );
             (cs, ce)
         }
-        _ => {
+        None => {
             println!("  ⚠️ 공통 시간 범위를 찾을 수 없음, 요청 범위 사용");
             (requested_start, requested_end)
         }
     };
-
-    // 공통 시간 범위로 필터링
-    for (_, klines) in all_klines.iter_mut() {
-        klines.retain(|k| k.open_time >= start && k.close_time <= end);
-    }
+    retain_time_range(&mut all_klines, start, end);
 
     // 첫 번째 심볼의 klines를 메인으로 사용 (백테스트 엔진용)
     let primary_symbol = &config.symbols[0];
@@ -420,6 +434,7 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
             ctx_read.screening_results.len()
         );
     }
+    print_global_score_breakdowns(pool.clone(), &config).await;
 
     // 백테스트용: route_states를 Armed로 설정 (진입 가능 상태)
     // 실제 RouteState는 백테스트 엔진에서 각 캔들 시점마다 계산하여 업데이트됨
@@ -485,7 +500,13 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
             // 주 심볼의 추가 타임프레임 데이터 로드
             let primary = &config.symbols[0];
             if let Ok(tf_klines) = ohlcv_cache
-                .get_cached_klines_range(primary, *tf, requested_start, requested_end)
+                .get_cached_klines_range(
+                    primary,
+                    *tf,
+                    requested_start,
+                    requested_end,
+                    MissingCandlePolicy::None,
+                )
                 .await
             {
                 if !tf_klines.is_empty() {
@@ -517,10 +538,31 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
     let ticker = config.symbols[0].clone();
 
     // 스크리닝 기반 전략용 Provider 생성 (해당하는 경우만)
-    let screening_provider = create_screening_provider_for_strategy(&config.strategy_id);
+    let mut screening_provider = create_screening_provider_for_strategy(&config.strategy_id);
     if screening_provider.is_some() {
         println!("  📊 스크리닝 기반 전략 감지: 동적 유니버스 스크리닝 활성화");
     }
+    if let Some(path) = &config.fundamentals_path {
+        match screening_provider.take() {
+            Some(provider) => {
+                let store = FundamentalSnapshotStore::load_json(path).map_err(|e| {
+                    diagnostics.push(format!("❌ 펀더멘털 스냅샷 로드 실패: {}", e));
+                    anyhow!("펀더멘털 스냅샷 로드 실패: {}", e)
+                })?;
+                println!(
+                    "  📑 시점별 펀더멘털 스냅샷 로드: {}개 종목",
+                    store.ticker_count()
+                );
+                screening_provider = Some(provider.with_fundamentals(store));
+            }
+            None => {
+                diagnostics.push(
+                    "⚠️ 스크리닝 기반 전략이 아니므로 펀더멘털 스냅샷을 사용하지 않습니다"
+                        .to_string(),
+                );
+            }
+        }
+    }
 
     let report = engine
         .run(
@@ -552,11 +594,18 @@ pub async fn run_strategy_test(config: StrategyTestConfig) -> Result<TestResult>
     if trades_executed == 0 {
         println!("\n⚠️  거래가 발생하지 않았습니다!");
         diagnostics.push("⚠️ 거래 미발생".to_string());
-        analyze_no_trades(&klines, &strategy_config, &mut diagnostics);
     } else {
         println!("\n✅ 거래 발생: {} 건", trades_executed);
     }
 
+    // 규칙 기반 성과 진단 (영향도 순)
+    let diagnosis = StrategyDiagnoser::default()
+        .diagnose(&build_diagnosis_input(&report, &klines), &strategy_config);
+    if !diagnosis.is_healthy() {
+        diagnostics.push("\n🔍 성과 진단 (영향도 순):".to_string());
+        diagnostics.extend(diagnosis.lines());
+    }
+
     println!("\n📈 성과 지표:");
     println!("  총 수익률: {:.2}%", report.metrics.total_return_pct);
     println!(
@@ -1031,76 +1080,70 @@ async fn create_strategy_context(
     Ok(Arc::new(RwLock::new(ctx)))
 }
 
-/// 거래 미발생 원인 분석
-fn analyze_no_trades(klines: &[Kline], config: &serde_json::Value, diagnostics: &mut Vec<String>) {
-    diagnostics.push("\n🔍 거래 미발생 원인 분석:".to_string());
-
-    // 1. 데이터 부족 확인
-    if klines.len() < 50 {
-        diagnostics.push(format!(
-            "  - 데이터 부족: {}개 캔들 (최소 50개 권장)",
-            klines.len()
-        ));
-    }
+/// 테스트 대상 종목의 GlobalScore 구성요소 분해 출력
+///
+/// 백테스트에는 Fundamental 데이터(목표가, 손절가, 추천가)가 없으므로
+/// 결측 구성요소와 함께 가중치 재정규화 점수를 나란히 보여줍니다.
+async fn print_global_score_breakdowns(pool: sqlx::PgPool, config: &StrategyTestConfig) {
+    let data_provider = Arc::new(CachedHistoricalDataProvider::new(pool));
+    let analytics_provider = AnalyticsProviderImpl::new(data_provider);
 
-    // 2. 설정 값 확인
-    if let Some(obj) = config.as_object() {
-        // RSI 설정
-        if let Some(overbought) = obj.get("overbought").and_then(|v| v.as_f64()) {
-            if overbought < 60.0 {
-                diagnostics.push(format!("  - RSI 과매수 임계값이 낮음: {}", overbought));
-            }
-        }
-        if let Some(oversold) = obj.get("oversold").and_then(|v| v.as_f64()) {
-            if oversold > 40.0 {
-                diagnostics.push(format!("  - RSI 과매도 임계값이 높음: {}", oversold));
-            }
+    let tickers: Vec<&str> = config.symbols.iter().map(|s| s.as_str()).collect();
+    let breakdowns = match analytics_provider
+        .fetch_global_score_breakdowns(&tickers)
+        .await
+    {
+        Ok(breakdowns) => breakdowns,
+        Err(e) => {
+            warn!("GlobalScore 분해 계산 실패 (계속 진행): {}", e);
+            return;
         }
+    };
 
-        // min_score 확인
-        if let Some(min_score) = obj.get("min_score").and_then(|v| v.as_f64()) {
-            if min_score > 80.0 {
-                diagnostics.push(format!(
-                    "  - GlobalScore 필터가 너무 엄격: min_score={}",
-                    min_score
-                ));
-            }
+    for ticker in &tickers {
+        let Some(breakdown) = breakdowns.get(*ticker) else {
+            continue;
+        };
+        println!("  🧮 GlobalScore 분해:");
+        for line in breakdown.to_string().lines() {
+            println!("    {}", line);
         }
 
-        // enable_route_filter 확인
-        if obj
-            .get("enable_route_filter")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false)
-        {
-            diagnostics.push(
-                "  - RouteState 필터 활성화됨 (백테스트에서는 RouteState가 없을 수 있음)"
-                    .to_string(),
+        let missing = breakdown.missing_components();
+        if !missing.is_empty() {
+            println!(
+                "    ⚠️  결측 구성요소: {} → 재정규화 점수 {:.1}",
+                missing.join(", "),
+                breakdown.reweighted().overall_score
             );
         }
     }
+}
 
-    // 3. 가격 움직임 분석
-    if klines.len() > 1 {
-        let first_close = klines.first().map(|k| k.close).unwrap_or(Decimal::ONE);
-        let last_close = klines.last().map(|k| k.close).unwrap_or(Decimal::ONE);
-
-        if first_close > Decimal::ZERO {
-            let change_pct = ((last_close - first_close) / first_close * Decimal::from(100))
-                .to_string()
-                .parse::<f64>()
-                .unwrap_or(0.0);
-
-            if change_pct.abs() < 5.0 {
-                diagnostics.push(format!("  - 기간 내 가격 변동이 적음: {:.1}%", change_pct));
-            }
+/// 백테스트 리포트에서 진단 입력 추출
+fn build_diagnosis_input(report: &BacktestReport, klines: &[Kline]) -> DiagnosisInput {
+    let price_change_pct = match (klines.first(), klines.last()) {
+        (Some(first), Some(last)) if klines.len() > 1 && first.close > Decimal::ZERO => {
+            Some((last.close - first.close) / first.close * Decimal::from(100))
         }
-    }
+        _ => None,
+    };
 
-    diagnostics.push("\n💡 권장 조치:".to_string());
-    diagnostics.push("  1. 전략 파라미터 완화 (RSI 임계값 조정 등)".to_string());
-    diagnostics.push("  2. GlobalScore/RouteState 필터 비활성화".to_string());
-    diagnostics.push("  3. 더 긴 기간 또는 더 변동성 있는 종목으로 테스트".to_string());
+    DiagnosisInput {
+        data_points: klines.len(),
+        price_change_pct,
+        signals_generated: report.signal_markers.len(),
+        signals_executed: report.signal_markers.iter().filter(|m| m.executed).count(),
+        total_trades: report.metrics.total_trades,
+        win_rate_pct: report.metrics.win_rate_pct,
+        avg_win: report.metrics.avg_win,
+        avg_loss: report.metrics.avg_loss,
+        total_return_pct: report.metrics.total_return_pct,
+        max_drawdown_pct: report.metrics.max_drawdown_pct,
+        net_profit: report.metrics.net_profit,
+        total_commission: report.total_commission,
+        total_slippage: report.total_slippage,
+    }
 }
 
 /// 사용 가능한 전략 목록 출력
@@ -1510,13 +1553,24 @@ async fn run_single_fixture_test(
     fixture: &StrategyFixture,
     db_url: Option<String>,
 ) -> Result<TestResult> {
+    let config = fixture_test_config(fixture, db_url)?;
+
+    // 조용한 모드로 테스트 실행 (로깅 최소화)
+    run_strategy_test_quiet(config).await
+}
+
+/// Fixture로부터 테스트 설정 생성
+fn fixture_test_config(
+    fixture: &StrategyFixture,
+    db_url: Option<String>,
+) -> Result<StrategyTestConfig> {
     let market = match fixture.market.to_uppercase().as_str() {
         "KR" => Market::KR,
         "US" => Market::US,
         _ => return Err(anyhow!("알 수 없는 시장: {}", fixture.market)),
     };
 
-    let config = StrategyTestConfig {
+    Ok(StrategyTestConfig {
         strategy_id: fixture.strategy_id.clone(),
         symbols: fixture.symbols.clone(),
         market,
@@ -1526,14 +1580,35 @@ async fn run_single_fixture_test(
         initial_capital: Decimal::from(10_000_000),
         debug: false,
         db_url,
-    };
-
-    // 조용한 모드로 테스트 실행 (로깅 최소화)
-    run_strategy_test_quiet(config).await
+        fundamentals_path: None,
+    })
 }
 
 /// 조용한 모드 테스트 실행 (회귀 테스트용)
-async fn run_strategy_test_quiet(config: StrategyTestConfig) -> Result<TestResult> {
+async fn run_strategy_test_quiet(mut config: StrategyTestConfig) -> Result<TestResult> {
+    let data = load_test_data(&mut config).await?;
+    run_strategy_test_with_data(config, &data).await
+}
+
+/// 전략 테스트 공유 데이터.
+///
+/// 그리드 서치처럼 같은 심볼로 여러 번 실행할 때 캔들을 한 번만 로드하여 재사용합니다.
+pub(crate) struct TestData {
+    pool: sqlx::PgPool,
+    ohlcv_cache: OhlcvCache,
+    /// 심볼별 일봉 (공통 시간 범위로 정렬됨)
+    all_klines: std::collections::HashMap<String, Vec<Kline>>,
+    requested_start: chrono::DateTime<Utc>,
+    requested_end: chrono::DateTime<Utc>,
+    /// 주 심볼의 추가 타임프레임 캔들 (멀티 타임프레임 전략, 최초 요청 시 로드)
+    timeframe_klines: tokio::sync::Mutex<std::collections::HashMap<Timeframe, Vec<Kline>>>,
+}
+
+/// 전략 테스트 데이터 로드.
+///
+/// 전략 설정에서 필요한 심볼을 추출해 `config.symbols`를 갱신하고,
+/// 모든 심볼의 일봉을 공통 시간 범위로 정렬하여 반환합니다.
+pub(crate) async fn load_test_data(config: &mut StrategyTestConfig) -> Result<TestData> {
     // 전략 존재 여부 확인
     let available_strategies = StrategyRegistry::list_ids();
     if !available_strategies.contains(&config.strategy_id.as_str()) {
@@ -1553,7 +1628,7 @@ async fn run_strategy_test_quiet(config: StrategyTestConfig) -> Result<TestResul
     };
 
     let db = Database::connect(&db_config).await?;
-    let pool = db.pool();
+    let pool = db.pool().clone();
 
     // 캔들 데이터 로드 - ohlcv 테이블 사용
     let ohlcv_cache = OhlcvCache::new(pool.clone());
@@ -1564,8 +1639,7 @@ async fn run_strategy_test_quiet(config: StrategyTestConfig) -> Result<TestResul
 
     // 전략 설정 미리 준비하여 필요한 모든 심볼 추출
     // (자산 배분 전략의 경우 내부 기본 자산 + JSON config의 추가 심볼)
-    let mut config = config; // make mutable
-    let preliminary_config = prepare_strategy_config(&config)?;
+    let preliminary_config = prepare_strategy_config(config)?;
     let all_required_symbols =
         extract_required_symbols(&preliminary_config, &config.symbols[0], &config.strategy_id);
 
@@ -1584,22 +1658,19 @@ async fn run_strategy_test_quiet(config: StrategyTestConfig) -> Result<TestResul
     // 모든 심볼의 klines 로드 및 공통 시간 범위 계산
     let mut all_klines: std::collections::HashMap<String, Vec<Kline>> =
         std::collections::HashMap::new();
-    let mut common_start: Option<chrono::DateTime<Utc>> = None;
-    let mut common_end: Option<chrono::DateTime<Utc>> = None;
 
     for symbol in &config.symbols {
         match ohlcv_cache
-            .get_cached_klines_range(symbol, Timeframe::D1, requested_start, requested_end)
+            .get_cached_klines_range(
+                symbol,
+                Timeframe::D1,
+                requested_start,
+                requested_end,
+                MissingCandlePolicy::None,
+            )
             .await
         {
             Ok(symbol_klines) if !symbol_klines.is_empty() => {
-                let sym_start = symbol_klines.first().unwrap().open_time;
-                let sym_end = symbol_klines.last().unwrap().close_time;
-
-                // 공통 시간 범위 계산 (교집합)
-                common_start = Some(common_start.map_or(sym_start, |cs| cs.max(sym_start)));
-                common_end = Some(common_end.map_or(sym_end, |ce| ce.min(sym_end)));
-
                 all_klines.insert(symbol.clone(), symbol_klines);
             }
             _ => {
@@ -1608,32 +1679,52 @@ async fn run_strategy_test_quiet(config: StrategyTestConfig) -> Result<TestResul
         }
     }
 
-    // 공통 시간 범위로 klines 필터링
-    let (start, end) = match (common_start, common_end) {
-        (Some(cs), Some(ce)) if cs < ce => (cs, ce),
-        _ => (requested_start, requested_end),
-    };
+    // 공통 시간 범위(교집합)로 klines 필터링
+    let (start, end) =
+        common_time_range(all_klines.values()).unwrap_or((requested_start, requested_end));
+    retain_time_range(&mut all_klines, start, end);
 
-    for (_, klines) in all_klines.iter_mut() {
-        klines.retain(|k| k.open_time >= start && k.close_time <= end);
+    if !all_klines.contains_key(&config.symbols[0]) {
+        return Err(anyhow!("캔들 데이터가 없습니다: {}", config.symbols[0]));
     }
 
+    Ok(TestData {
+        pool,
+        ohlcv_cache,
+        all_klines,
+        requested_start,
+        requested_end,
+        timeframe_klines: tokio::sync::Mutex::new(std::collections::HashMap::new()),
+    })
+}
+
+/// 로드된 공유 데이터로 전략 테스트 실행.
+///
+/// `config.symbols`는 [`load_test_data`]로 갱신된 목록이어야 합니다.
+pub(crate) async fn run_strategy_test_with_data(
+    config: StrategyTestConfig,
+    data: &TestData,
+) -> Result<TestResult> {
     // 첫 번째 심볼의 klines를 메인으로 사용 (백테스트 엔진용)
     let primary_symbol = &config.symbols[0];
-    let klines = all_klines.get(primary_symbol).cloned().unwrap_or_default();
+    let klines = data
+        .all_klines
+        .get(primary_symbol)
+        .cloned()
+        .unwrap_or_default();
 
     if klines.is_empty() {
         return Err(anyhow!("캔들 데이터가 없습니다: {}", primary_symbol));
     }
 
     // StrategyContext 생성
-    let context = create_strategy_context(pool.clone(), &config).await?;
+    let context = create_strategy_context(data.pool.clone(), &config).await?;
 
     // 모든 심볼의 klines를 StrategyContext에 저장
     // (멀티 자산 전략이 context.get_klines()로 접근 가능)
     {
         let mut ctx_write = context.write().await;
-        for (symbol, symbol_klines) in &all_klines {
+        for (symbol, symbol_klines) in &data.all_klines {
             ctx_write.update_klines(symbol, Timeframe::D1, symbol_klines.clone());
         }
     }
@@ -1662,16 +1753,32 @@ async fn run_strategy_test_quiet(config: StrategyTestConfig) -> Result<TestResul
                 continue;
             }
 
-            // 주 심볼의 추가 타임프레임 데이터 로드
+            // 주 심볼의 추가 타임프레임 데이터 로드 (최초 1회만 DB 조회)
             let primary = &config.symbols[0];
-            if let Ok(tf_klines) = ohlcv_cache
-                .get_cached_klines_range(primary, *tf, requested_start, requested_end)
-                .await
-            {
-                if !tf_klines.is_empty() {
-                    let mut ctx_write = context.write().await;
-                    ctx_write.update_klines(primary, *tf, tf_klines);
+            let tf_klines = {
+                let mut cache = data.timeframe_klines.lock().await;
+                match cache.get(tf) {
+                    Some(cached) => cached.clone(),
+                    None => {
+                        let loaded = data
+                            .ohlcv_cache
+                            .get_cached_klines_range(
+                                primary,
+                                *tf,
+                                data.requested_start,
+                                data.requested_end,
+                                MissingCandlePolicy::None,
+                            )
+                            .await
+                            .unwrap_or_default();
+                        cache.insert(*tf, loaded.clone());
+                        loaded
+                    }
                 }
+            };
+            if !tf_klines.is_empty() {
+                let mut ctx_write = context.write().await;
+                ctx_write.update_klines(primary, *tf, tf_klines);
             }
         }
     }
@@ -1924,7 +2031,8 @@ pub async fn run_init_only_regression_tests(
         let mut failed = 0;
 
         for strategy_fixture in &fixture.strategies {
-            let test_passed = test_strategy_init_only(strategy_fixture);
+            let outcome = test_strategy_init_only(strategy_fixture);
+            let test_passed = outcome.is_ok();
 
             if test_passed {
                 passed += 1;
@@ -1944,11 +2052,7 @@ pub async fn run_init_only_regression_tests(
                 strategy_id: strategy_fixture.strategy_id.clone(),
                 strategy_name: strategy_fixture.name.clone(),
                 passed: test_passed,
-                error_message: if test_passed {
-                    None
-                } else {
-                    Some("초기화 실패".to_string())
-                },
+                error_message: outcome.err(),
                 test_result: None,
             });
         }
@@ -1968,26 +2072,43 @@ pub async fn run_init_only_regression_tests(
 }
 
 /// 전략 초기화만 테스트 (DB 연결 없이)
-fn test_strategy_init_only(fixture: &StrategyFixture) -> bool {
-    // 전략 존재 여부 확인
-    let available_strategies = StrategyRegistry::list_ids();
-    if !available_strategies.contains(&fixture.strategy_id.as_str()) {
-        // 존재하지 않는 전략인데 expected.initialization이 failure면 통과
-        return fixture.expected.initialization == "failure";
+///
+/// 전략 생성과 설정 검증(`StrategyRegistry::validate_config`)을 수행하고
+/// 기대 결과와 다르면 실패 사유를 반환합니다.
+fn test_strategy_init_only(fixture: &StrategyFixture) -> std::result::Result<(), String> {
+    let expect_failure = fixture.expected.initialization == "failure";
+    let outcome = init_only_outcome(fixture);
+
+    match (outcome, expect_failure) {
+        (Ok(()), false) | (Err(_), true) => Ok(()),
+        (Ok(()), true) => Err("초기화 실패가 기대되었으나 성공".to_string()),
+        (Err(e), false) => Err(e),
     }
+}
 
+/// 전략 생성 + 설정 검증 결과
+fn init_only_outcome(fixture: &StrategyFixture) -> std::result::Result<(), String> {
     // 전략 생성
-    let strategy = match StrategyRegistry::create_instance(&fixture.strategy_id) {
-        Ok(s) => s,
-        Err(_) => return fixture.expected.initialization == "failure",
-    };
+    let strategy = StrategyRegistry::create_instance(&fixture.strategy_id)?;
 
     // 전략 이름/버전 확인
     let _ = strategy.name();
     let _ = strategy.version();
 
-    // 기대 결과와 비교
-    fixture.expected.initialization == "success"
+    // 실제 실행과 동일하게 ticker/amount 등을 주입한 설정으로 검증
+    let config = fixture_test_config(fixture, None).map_err(|e| e.to_string())?;
+    let strategy_config = prepare_strategy_config(&config).map_err(|e| e.to_string())?;
+    let report = StrategyRegistry::validate_config(&fixture.strategy_id, &strategy_config)?;
+
+    for issue in &report.issues {
+        println!("     {}", issue);
+    }
+
+    if report.has_errors() {
+        return Err(format!("설정 검증 실패: {}", report.error_summary()));
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -0,0 +1,206 @@
+//! ML 모델 훈련 후 검증 및 배포.
+//!
+//! `trader train`은 Python 학습 스크립트(`tools/ml/train_model.py`)로 ONNX 모델을
+//! 생성한 뒤, 이 모듈로 모델을 Rust 추론 런타임에서 로드해 검증하고
+//! `--deploy` 지정 시 `crates/trader-analytics/models/`로 복사합니다.
+//!
+//! # 배포 규칙
+//!
+//! - 검증(로드, `[1, feature_count]` 더미 추론, 특성 순서)을 통과한 모델만 배포
+//! - 모델과 함께 `{name}_metadata.json`, `{name}_scaler.joblib`도 복사
+//! - 같은 이름의 기존 파일은 `backup/{타임스탬프}/`로 옮긴 뒤 덮어씀
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+
+/// 기본 배포 디렉토리.
+pub const DEFAULT_DEPLOY_DIR: &str = "crates/trader-analytics/models";
+
+/// 배포 디렉토리 내 백업 하위 디렉토리 이름.
+const BACKUP_DIR: &str = "backup";
+
+/// 자동 모델 이름 생성.
+///
+/// Python 학습 스크립트의 기본 이름 규칙(`{model}_{최대 3개 심볼}_{period}`)과 같습니다.
+pub fn default_model_name(model: &str, symbols: &[String], period: &str) -> String {
+    let symbol_str = symbols
+        .iter()
+        .take(3)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join("_");
+    format!("{}_{}_{}", model, symbol_str, period)
+}
+
+/// 모델과 함께 배포할 파일 경로 (ONNX, 메타데이터, 스케일러).
+///
+/// 존재하지 않는 부속 파일은 제외됩니다.
+pub fn model_artifacts(onnx_path: &Path) -> Vec<PathBuf> {
+    let stem = onnx_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let companions = [
+        format!("{}_metadata.json", stem),
+        format!("{}_scaler.joblib", stem),
+    ];
+
+    std::iter::once(onnx_path.to_path_buf())
+        .chain(
+            companions
+                .iter()
+                .map(|name| onnx_path.with_file_name(name))
+                .filter(|path| path.exists()),
+        )
+        .collect()
+}
+
+/// 배포 결과.
+#[derive(Debug, Default)]
+pub struct DeployResult {
+    /// 배포된 파일 경로
+    pub deployed: Vec<PathBuf>,
+    /// 백업된 기존 파일 경로
+    pub backups: Vec<PathBuf>,
+}
+
+/// 모델 파일을 배포 디렉토리로 복사.
+///
+/// 같은 이름의 기존 파일은 `{deploy_dir}/backup/{타임스탬프}/`로 이동합니다.
+pub fn deploy_model(onnx_path: &Path, deploy_dir: &Path) -> Result<DeployResult> {
+    if !onnx_path.exists() {
+        bail!("모델 파일이 없습니다: {}", onnx_path.display());
+    }
+
+    std::fs::create_dir_all(deploy_dir)
+        .with_context(|| format!("배포 디렉토리 생성 실패: {}", deploy_dir.display()))?;
+
+    let backup_dir = deploy_dir
+        .join(BACKUP_DIR)
+        .join(Utc::now().format("%Y%m%d_%H%M%S").to_string());
+    let mut result = DeployResult::default();
+
+    for source in model_artifacts(onnx_path) {
+        let file_name = source
+            .file_name()
+            .with_context(|| format!("잘못된 파일 경로: {}", source.display()))?;
+        let target = deploy_dir.join(file_name);
+
+        if target.exists() {
+            std::fs::create_dir_all(&backup_dir)
+                .with_context(|| format!("백업 디렉토리 생성 실패: {}", backup_dir.display()))?;
+            let backup = backup_dir.join(file_name);
+            std::fs::rename(&target, &backup)
+                .with_context(|| format!("기존 모델 백업 실패: {}", target.display()))?;
+            result.backups.push(backup);
+        }
+
+        std::fs::copy(&source, &target)
+            .with_context(|| format!("복사 실패: {} → {}", source.display(), target.display()))?;
+        result.deployed.push(target);
+    }
+
+    Ok(result)
+}
+
+/// 학습된 모델을 추론 런타임으로 검증하고 결과를 출력.
+///
+/// 모든 검증을 통과하면 `true`를 반환합니다.
+#[cfg(feature = "ml")]
+pub fn validate_model(onnx_path: &Path) -> Result<bool> {
+    use trader_analytics::ml::{validate_onnx_model, FeatureConfig};
+
+    let report = validate_onnx_model(onnx_path, &FeatureConfig::default());
+
+    println!("\n🔍 모델 검증: {}", onnx_path.display());
+    println!("  기대 입력 shape: {:?}", report.input_shape());
+    if let Some(metadata) = &report.metadata {
+        println!(
+            "  메타데이터: {} ({}, 특성 {}개)",
+            metadata.model_name, metadata.model_type, metadata.n_features
+        );
+    }
+    if let Some(prediction) = &report.sample_prediction {
+        println!(
+            "  더미 추론: {:?} (확률 {:?})",
+            prediction.direction, prediction.probabilities
+        );
+    }
+
+    if report.is_valid() {
+        println!("  ✅ 검증 통과");
+    } else {
+        println!("  ❌ 검증 실패:");
+        for mismatch in &report.mismatches {
+            println!("    - {}", mismatch);
+        }
+    }
+
+    Ok(report.is_valid())
+}
+
+/// ML 기능 없이 빌드된 경우 검증 불가.
+#[cfg(not(feature = "ml"))]
+pub fn validate_model(_onnx_path: &Path) -> Result<bool> {
+    bail!("ONNX 검증에는 ML 기능이 필요합니다 (cargo build --features ml)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("trader_train_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_default_model_name() {
+        let symbols: Vec<String> = ["SPY", "QQQ", "IWM", "DIA"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            default_model_name("xgboost", &symbols, "5y"),
+            "xgboost_SPY_QQQ_IWM_5y"
+        );
+        assert_eq!(
+            default_model_name("lightgbm", &symbols[..1], "1y"),
+            "lightgbm_SPY_1y"
+        );
+    }
+
+    #[test]
+    fn test_deploy_model_backs_up_existing() {
+        let root = temp_dir();
+        let output = root.join("out");
+        let deploy = root.join("deploy");
+        std::fs::create_dir_all(&output).unwrap();
+        std::fs::create_dir_all(&deploy).unwrap();
+
+        let onnx = output.join("m.onnx");
+        std::fs::write(&onnx, b"new").unwrap();
+        std::fs::write(output.join("m_metadata.json"), b"{}").unwrap();
+        std::fs::write(deploy.join("m.onnx"), b"old").unwrap();
+
+        let result = deploy_model(&onnx, &deploy).unwrap();
+
+        assert_eq!(result.deployed.len(), 2);
+        assert_eq!(std::fs::read(deploy.join("m.onnx")).unwrap(), b"new");
+        assert!(deploy.join("m_metadata.json").exists());
+        assert_eq!(result.backups.len(), 1);
+        assert_eq!(std::fs::read(&result.backups[0]).unwrap(), b"old");
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_deploy_model_missing_source() {
+        let root = temp_dir();
+        assert!(deploy_model(&root.join("missing.onnx"), &root.join("deploy")).is_err());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
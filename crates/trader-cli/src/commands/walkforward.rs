@@ -0,0 +1,537 @@
+//! 워크포워드(walk-forward) 분석 명령어.
+//!
+//! 전체 기간을 학습(in-sample) → 검증(out-of-sample) 롤링 윈도우로 나누고
+//! 각 구간마다 `BacktestEngine`을 실행하여 파라미터 과적합 여부를 점검합니다.
+//! 검증 구간 성과를 이어 붙인 복합 자산 곡선과 구간별 지표 표를 출력합니다.
+//!
+//! # 사용 예시
+//!
+//! ```bash
+//! # 학습 12개월 → 검증 3개월, 3개월씩 이동 (롤링)
+//! trader walkforward -c config/backtest/rsi.toml -s SPY -m US --train-months 12 --test-months 3
+//!
+//! # 앵커드: 학습 시작일 고정, 학습 구간이 점점 늘어남
+//! trader walkforward -c config/backtest/haa.toml -s SPY -m US --train-months 24 --test-months 6 --anchored
+//!
+//! # 복합 자산 곡선 CSV 저장
+//! trader walkforward -c config/backtest/rsi.toml -s 005930 -m KR -o out/wf_equity.csv
+//! ```
+
+use std::{collections::HashMap, path::Path};
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Months, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use tracing::{info, warn};
+use trader_analytics::{backtest::BacktestReport, performance::EquityPoint};
+use trader_core::Kline;
+
+use crate::commands::{
+    backtest::{
+        build_backtest_config, execute_backtest, prepare_backtest_inputs, BacktestCliConfig,
+        BacktestInputs,
+    },
+    backtest_export::{decimal_str, iso8601},
+};
+
+/// 워크포워드 CLI 설정
+#[derive(Debug, Clone)]
+pub struct WalkForwardCliConfig {
+    /// 백테스트 공통 설정 (전략, 심볼, 기간, 자본금 등)
+    pub backtest: BacktestCliConfig,
+    /// 학습 구간 길이 (개월)
+    pub train_months: u32,
+    /// 검증 구간 길이 (개월)
+    pub test_months: u32,
+    /// 윈도우 이동 스텝 (개월, None이면 검증 구간 길이)
+    pub step_months: Option<u32>,
+    /// 앵커드 모드 (학습 시작일 고정)
+    pub anchored: bool,
+    /// 복합 자산 곡선 CSV 저장 경로 (옵션)
+    pub output_path: Option<String>,
+}
+
+/// 워크포워드 윈도우 (각 구간은 [start, end) 반개구간)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalkForwardWindow {
+    /// 윈도우 번호 (1부터)
+    pub index: usize,
+    pub train_start: DateTime<Utc>,
+    pub train_end: DateTime<Utc>,
+    pub test_start: DateTime<Utc>,
+    pub test_end: DateTime<Utc>,
+}
+
+/// 윈도우별 실행 결과
+#[derive(Debug, Clone)]
+pub struct WindowResult {
+    pub window: WalkForwardWindow,
+    /// 학습 구간 수익률 (%)
+    pub in_sample_return_pct: Decimal,
+    /// 학습 구간 연율화 수익률 (%)
+    pub in_sample_annualized_pct: Decimal,
+    /// 검증 구간 수익률 (%)
+    pub out_of_sample_return_pct: Decimal,
+    /// 검증 구간 연율화 수익률 (%)
+    pub out_of_sample_annualized_pct: Decimal,
+    /// 검증 구간 샤프 비율
+    pub out_of_sample_sharpe: Decimal,
+    /// 검증 구간 최대 낙폭 (%)
+    pub out_of_sample_max_drawdown_pct: Decimal,
+    /// 검증 구간 거래 수
+    pub out_of_sample_trades: usize,
+}
+
+/// 복합(검증 구간 연결) 자산 곡선 포인트
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositePoint {
+    pub timestamp: DateTime<Utc>,
+    pub equity: Decimal,
+    /// 해당 포인트가 속한 윈도우 번호
+    pub window: usize,
+}
+
+/// 워크포워드 분석 결과
+#[derive(Debug, Clone)]
+pub struct WalkForwardSummary {
+    pub windows: Vec<WindowResult>,
+    pub composite_curve: Vec<CompositePoint>,
+    /// 복합 자산 곡선 총 수익률 (%)
+    pub composite_return_pct: Decimal,
+    /// 복합 자산 곡선 최대 낙폭 (%)
+    pub composite_max_drawdown_pct: Decimal,
+    /// 워크포워드 효율 (평균 OOS 연율화 수익률 / 평균 IS 연율화 수익률)
+    pub efficiency: Option<Decimal>,
+}
+
+/// 복합 자산 곡선 CSV 레코드
+#[derive(Debug, Serialize)]
+struct CompositeRecord {
+    timestamp: String,
+    equity: String,
+    window: usize,
+}
+
+/// 데이터 기간을 학습/검증 윈도우로 분할.
+///
+/// 학습 구간이 데이터 끝에 닿으면 중단하며, 마지막 검증 구간은 데이터 끝에서 잘립니다.
+/// 앵커드 모드에서는 모든 윈도우의 학습 시작일이 `data_start`로 고정됩니다.
+pub fn generate_windows(
+    data_start: DateTime<Utc>,
+    data_end: DateTime<Utc>,
+    train_months: u32,
+    test_months: u32,
+    step_months: Option<u32>,
+    anchored: bool,
+) -> Vec<WalkForwardWindow> {
+    let step = step_months.unwrap_or(test_months).max(1);
+    let mut windows = Vec::new();
+    let mut offset = 0u32;
+
+    while let Some(train_end) = data_start.checked_add_months(Months::new(offset + train_months)) {
+        if train_end >= data_end {
+            break;
+        }
+
+        let train_start = if anchored {
+            data_start
+        } else {
+            data_start
+                .checked_add_months(Months::new(offset))
+                .unwrap_or(data_start)
+        };
+        let test_end = train_end
+            .checked_add_months(Months::new(test_months))
+            .map_or(data_end, |end| end.min(data_end));
+
+        windows.push(WalkForwardWindow {
+            index: windows.len() + 1,
+            train_start,
+            train_end,
+            test_start: train_end,
+            test_end,
+        });
+
+        if test_end >= data_end {
+            break;
+        }
+        offset += step;
+    }
+
+    windows
+}
+
+/// 캔들을 [start, end) 구간으로 자르기
+fn slice_klines(klines: &[Kline], start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<Kline> {
+    klines
+        .iter()
+        .filter(|k| k.open_time >= start && k.open_time < end)
+        .cloned()
+        .collect()
+}
+
+fn slice_multi_klines(
+    multi_asset_klines: &HashMap<String, Vec<Kline>>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> HashMap<String, Vec<Kline>> {
+    multi_asset_klines
+        .iter()
+        .map(|(symbol, klines)| (symbol.clone(), slice_klines(klines, start, end)))
+        .filter(|(_, klines)| !klines.is_empty())
+        .collect()
+}
+
+/// 검증 구간 자산 곡선을 이어 붙여 복합 자산 곡선 생성.
+///
+/// 각 윈도우는 동일한 초기 자본으로 실행되므로, 직전 윈도우 종료 자산 / 초기 자본 비율로
+/// 스케일링하여 연속된 곡선을 만듭니다.
+pub fn chain_equity_curves(
+    initial_capital: Decimal,
+    segments: &[(usize, &[EquityPoint])],
+) -> Vec<CompositePoint> {
+    let mut curve = Vec::new();
+    if initial_capital <= Decimal::ZERO {
+        return curve;
+    }
+
+    let mut capital = initial_capital;
+    for (window, points) in segments {
+        let scale = capital / initial_capital;
+        for point in points.iter() {
+            curve.push(CompositePoint {
+                timestamp: point.timestamp,
+                equity: point.equity * scale,
+                window: *window,
+            });
+        }
+        if let Some(last) = points.last() {
+            capital = last.equity * scale;
+        }
+    }
+    curve
+}
+
+/// 자산 곡선 최대 낙폭 (%)
+fn max_drawdown_pct(curve: &[CompositePoint]) -> Decimal {
+    let mut peak = Decimal::ZERO;
+    let mut max_dd = Decimal::ZERO;
+    for point in curve {
+        peak = peak.max(point.equity);
+        if peak > Decimal::ZERO {
+            max_dd = max_dd.max((peak - point.equity) / peak * Decimal::ONE_HUNDRED);
+        }
+    }
+    max_dd
+}
+
+/// 평균값 (빈 목록이면 None)
+fn mean(values: impl Iterator<Item = Decimal>) -> Option<Decimal> {
+    let (sum, count) = values.fold((Decimal::ZERO, 0u32), |(s, c), v| (s + v, c + 1));
+    (count > 0).then(|| sum / Decimal::from(count))
+}
+
+/// [start, end) 구간 캔들로 백테스트 실행
+async fn run_period(
+    inputs: &BacktestInputs,
+    config: &BacktestCliConfig,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<BacktestReport> {
+    let klines = slice_klines(&inputs.klines, start, end);
+    if klines.is_empty() {
+        return Err(anyhow!("no klines in period"));
+    }
+    let multi_asset_klines = slice_multi_klines(&inputs.multi_asset_klines, start, end);
+    let params = &inputs.strategy_config.parameters;
+
+    execute_backtest(
+        inputs.strategy_type,
+        build_backtest_config(config, &inputs.strategy_type, params),
+        &klines,
+        &multi_asset_klines,
+        params,
+        config.initial_capital,
+    )
+    .await
+}
+
+/// 워크포워드 분석 실행
+pub async fn run_walkforward(config: WalkForwardCliConfig) -> Result<WalkForwardSummary> {
+    if config.train_months == 0 || config.test_months == 0 {
+        return Err(anyhow!("train/test months must be greater than 0"));
+    }
+
+    // 1. 전략 설정 및 전체 기간 캔들 로드 (공통 기간 정렬 포함)
+    let inputs = prepare_backtest_inputs(&config.backtest).await?;
+    let data_start = inputs
+        .klines
+        .first()
+        .map(|k| k.open_time)
+        .ok_or_else(|| anyhow!("No klines loaded for {}", inputs.used_symbol))?;
+    let data_end = inputs
+        .klines
+        .last()
+        .map(|k| k.close_time)
+        .unwrap_or(data_start);
+
+    // 2. 윈도우 분할
+    let windows = generate_windows(
+        data_start,
+        data_end,
+        config.train_months,
+        config.test_months,
+        config.step_months,
+        config.anchored,
+    );
+    if windows.is_empty() {
+        return Err(anyhow!(
+            "Data period {} ~ {} is too short for {} train months",
+            data_start.format("%Y-%m-%d"),
+            data_end.format("%Y-%m-%d"),
+            config.train_months
+        ));
+    }
+    info!(
+        "Walk-forward: {} windows (train {}M / test {}M, {})",
+        windows.len(),
+        config.train_months,
+        config.test_months,
+        if config.anchored {
+            "anchored"
+        } else {
+            "rolling"
+        }
+    );
+
+    // 3. 윈도우별 학습/검증 백테스트
+    let mut results = Vec::new();
+    let mut oos_reports: Vec<(usize, BacktestReport)> = Vec::new();
+
+    for window in windows {
+        let in_sample = match run_period(
+            &inputs,
+            &config.backtest,
+            window.train_start,
+            window.train_end,
+        )
+        .await
+        {
+            Ok(report) => report,
+            Err(e) => {
+                warn!("Window {} 학습 구간 건너뜀: {}", window.index, e);
+                continue;
+            }
+        };
+        let out_of_sample = match run_period(
+            &inputs,
+            &config.backtest,
+            window.test_start,
+            window.test_end,
+        )
+        .await
+        {
+            Ok(report) => report,
+            Err(e) => {
+                warn!("Window {} 검증 구간 건너뜀: {}", window.index, e);
+                continue;
+            }
+        };
+
+        let index = window.index;
+        results.push(WindowResult {
+            window,
+            in_sample_return_pct: in_sample.metrics.total_return_pct,
+            in_sample_annualized_pct: in_sample.metrics.annualized_return_pct,
+            out_of_sample_return_pct: out_of_sample.metrics.total_return_pct,
+            out_of_sample_annualized_pct: out_of_sample.metrics.annualized_return_pct,
+            out_of_sample_sharpe: out_of_sample.metrics.sharpe_ratio,
+            out_of_sample_max_drawdown_pct: out_of_sample.metrics.max_drawdown_pct,
+            out_of_sample_trades: out_of_sample.metrics.total_trades,
+        });
+        oos_reports.push((index, out_of_sample));
+    }
+
+    if results.is_empty() {
+        return Err(anyhow!("All walk-forward windows failed"));
+    }
+
+    // 4. 검증 구간 자산 곡선 연결
+    let segments: Vec<(usize, &[EquityPoint])> = oos_reports
+        .iter()
+        .map(|(index, report)| (*index, report.equity_curve.as_slice()))
+        .collect();
+    let composite_curve = chain_equity_curves(config.backtest.initial_capital, &segments);
+
+    let initial_capital = config.backtest.initial_capital;
+    let composite_return_pct = composite_curve
+        .last()
+        .map(|p| (p.equity - initial_capital) / initial_capital * Decimal::ONE_HUNDRED)
+        .unwrap_or(Decimal::ZERO);
+    let composite_max_drawdown_pct = max_drawdown_pct(&composite_curve);
+
+    let avg_is = mean(results.iter().map(|r| r.in_sample_annualized_pct));
+    let avg_oos = mean(results.iter().map(|r| r.out_of_sample_annualized_pct));
+    let efficiency = match (avg_is, avg_oos) {
+        (Some(is), Some(oos)) if is > Decimal::ZERO => Some(oos / is),
+        _ => None,
+    };
+
+    let summary = WalkForwardSummary {
+        windows: results,
+        composite_curve,
+        composite_return_pct,
+        composite_max_drawdown_pct,
+        efficiency,
+    };
+
+    // 5. 결과 출력 및 저장
+    println!("\n{}", format_summary(&summary));
+
+    if let Some(output_path) = &config.output_path {
+        save_composite_curve(&summary.composite_curve, output_path)?;
+        info!("Composite equity curve saved to: {}", output_path);
+    }
+
+    Ok(summary)
+}
+
+/// 구간별 지표 표 및 복합 성과 요약
+fn format_summary(summary: &WalkForwardSummary) -> String {
+    let mut output = String::new();
+    output.push_str("워크포워드 분석 결과\n");
+    output.push_str(
+        "═══════════════════════════════════════════════════════════════════════════════════════\n",
+    );
+    output.push_str(&format!(
+        "{:>3}  {:<23}  {:<23}  {:>9}  {:>9}  {:>7}  {:>8}  {:>5}\n",
+        "#", "학습 구간", "검증 구간", "IS(%)", "OOS(%)", "Sharpe", "MDD(%)", "거래"
+    ));
+    output.push_str(
+        "───────────────────────────────────────────────────────────────────────────────────────\n",
+    );
+
+    for r in &summary.windows {
+        let w = &r.window;
+        output.push_str(&format!(
+            "{:>3}  {} ~ {}  {} ~ {}  {:>+9}  {:>+9}  {:>7}  {:>8}  {:>5}\n",
+            w.index,
+            w.train_start.format("%Y-%m-%d"),
+            w.train_end.format("%Y-%m-%d"),
+            w.test_start.format("%Y-%m-%d"),
+            w.test_end.format("%Y-%m-%d"),
+            r.in_sample_return_pct.round_dp(2),
+            r.out_of_sample_return_pct.round_dp(2),
+            r.out_of_sample_sharpe.round_dp(2),
+            r.out_of_sample_max_drawdown_pct.round_dp(2),
+            r.out_of_sample_trades
+        ));
+    }
+
+    output.push_str(
+        "───────────────────────────────────────────────────────────────────────────────────────\n",
+    );
+    output.push_str(&format!(
+        "복합 OOS 수익률: {:+}%\n",
+        summary.composite_return_pct.round_dp(2)
+    ));
+    output.push_str(&format!(
+        "복합 OOS 최대 낙폭: {}%\n",
+        summary.composite_max_drawdown_pct.round_dp(2)
+    ));
+    match summary.efficiency {
+        Some(efficiency) => output.push_str(&format!(
+            "워크포워드 효율 (OOS/IS 연율화): {}\n",
+            efficiency.round_dp(2)
+        )),
+        None => output.push_str("워크포워드 효율: N/A (학습 구간 평균 수익률 ≤ 0)\n"),
+    }
+    output
+}
+
+/// 복합 자산 곡선을 CSV로 저장 (헤더: timestamp,equity,window)
+fn save_composite_curve(curve: &[CompositePoint], path: &str) -> Result<()> {
+    let path = Path::new(path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut wtr = csv::Writer::from_path(path).context("Failed to create CSV writer")?;
+    for point in curve {
+        wtr.serialize(CompositeRecord {
+            timestamp: iso8601(&point.timestamp),
+            equity: decimal_str(point.equity),
+            window: point.window,
+        })
+        .context("Failed to write CSV record")?;
+    }
+    wtr.flush().context("Failed to flush CSV writer")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap()
+    }
+
+    fn point(day: u32, equity: Decimal) -> EquityPoint {
+        EquityPoint {
+            timestamp: ymd(2024, 1, day),
+            equity,
+            drawdown_pct: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_generate_windows_rolling() {
+        let windows = generate_windows(ymd(2020, 1, 1), ymd(2022, 12, 31), 12, 6, None, false);
+
+        assert_eq!(windows.len(), 4);
+        assert_eq!(windows[0].train_start, ymd(2020, 1, 1));
+        assert_eq!(windows[0].test_start, ymd(2021, 1, 1));
+        assert_eq!(windows[0].test_end, ymd(2021, 7, 1));
+        assert_eq!(windows[1].train_start, ymd(2020, 7, 1));
+        // 마지막 검증 구간은 데이터 끝에서 잘림
+        assert_eq!(windows[3].test_start, ymd(2022, 7, 1));
+        assert_eq!(windows[3].test_end, ymd(2022, 12, 31));
+    }
+
+    #[test]
+    fn test_generate_windows_anchored_with_step() {
+        let windows = generate_windows(ymd(2020, 1, 1), ymd(2022, 1, 1), 12, 3, Some(6), true);
+
+        assert_eq!(windows.len(), 2);
+        assert!(windows.iter().all(|w| w.train_start == ymd(2020, 1, 1)));
+        assert_eq!(windows[1].train_end, ymd(2021, 7, 1));
+        assert_eq!(windows[1].test_end, ymd(2021, 10, 1));
+    }
+
+    #[test]
+    fn test_generate_windows_too_short() {
+        assert!(generate_windows(ymd(2020, 1, 1), ymd(2020, 6, 1), 12, 3, None, false).is_empty());
+    }
+
+    #[test]
+    fn test_chain_equity_curves_compounds_windows() {
+        let first = [point(1, dec!(1000)), point(2, dec!(1100))];
+        let second = [point(3, dec!(1000)), point(4, dec!(900))];
+        let curve = chain_equity_curves(dec!(1000), &[(1, &first), (2, &second)]);
+
+        assert_eq!(curve.len(), 4);
+        assert_eq!(curve[1].equity, dec!(1100));
+        // 두 번째 윈도우는 1100 기준으로 스케일링: 900 × 1.1
+        assert_eq!(curve[2].equity, dec!(1100));
+        assert_eq!(curve[3].equity, dec!(990));
+        assert_eq!(curve[3].window, 2);
+
+        // 고점 1100 → 990: 10% 낙폭
+        assert_eq!(max_drawdown_pct(&curve), dec!(10));
+    }
+}
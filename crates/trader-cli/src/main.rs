@@ -48,8 +48,20 @@ enum Commands {
         market: String,
 
         /// 종목 코드/심볼 (예: 005930, SPY)
-        #[arg(short, long)]
-        symbol: String,
+        #[arg(short, long, required_unless_present = "symbols_file")]
+        symbol: Option<String>,
+
+        /// 심볼 목록 파일 (한 줄에 하나, 코스닥은 .KQ 접미사)
+        #[arg(long, conflicts_with = "symbol")]
+        symbols_file: Option<String>,
+
+        /// 체크포인트에서 이어서 다운로드 (--symbols-file 전용)
+        #[arg(long, default_value = "false", requires = "symbols_file")]
+        resume: bool,
+
+        /// 체크포인트 파일 경로 (기본: {symbols_file}.checkpoint.json)
+        #[arg(long, requires = "symbols_file")]
+        checkpoint: Option<String>,
 
         /// 타임프레임 간격 (1d: 일봉, 1w: 주봉, 1m: 월봉)
         #[arg(short, long, default_value = "1d")]
@@ -63,7 +75,7 @@ enum Commands {
         #[arg(short, long)]
         to: String,
 
-        /// 출력 파일 경로 (자동 생성됨)
+        /// 출력 파일 경로 (자동 생성됨, --symbols-file 사용 시 출력 디렉토리)
         #[arg(short, long)]
         output: Option<String>,
 
@@ -143,6 +155,22 @@ enum Commands {
         #[arg(long, default_value = "true")]
         active_only: bool,
 
+        /// 섹터 필터 (정확히 일치, 예: 반도체, Technology)
+        #[arg(long)]
+        sector: Option<String>,
+
+        /// 최소 시가총액 (펀더멘털이 없는 종목은 제외)
+        #[arg(long)]
+        min_market_cap: Option<rust_decimal::Decimal>,
+
+        /// 정렬 기준 (volume, market_cap, name; 기본: 시장, 티커 순)
+        #[arg(long)]
+        sort_by: Option<String>,
+
+        /// 정렬 방향 (asc, desc; 기본: volume/market_cap은 desc, name은 asc)
+        #[arg(long, requires = "sort_by")]
+        order: Option<String>,
+
         /// 출력 형식 (table, csv, json)
         #[arg(short, long, default_value = "table")]
         format: String,
@@ -182,9 +210,13 @@ enum Commands {
         #[arg(long)]
         db_url: Option<String>,
 
-        /// 드라이런 모드 (DB에 저장하지 않음)
+        /// 드라이런 모드 (기존 DB와 비교한 변경 내역만 출력, 저장하지 않음)
         #[arg(long, default_value = "false")]
         dry_run: bool,
+
+        /// 추가/변경/폐지 추정 내역을 symbol_change_history 테이블에 기록
+        #[arg(long, default_value = "false")]
+        record_history: bool,
     },
 
     /// 백테스트 실행
@@ -199,7 +231,11 @@ enum Commands {
 
         /// 종목 코드/심볼 (예: 005930, SPY)
         #[arg(short, long)]
-        symbol: String,
+        symbol: Option<String>,
+
+        /// 포트폴리오 종목 목록 (콤마 구분, 예: "SPY,QQQ,IWM")
+        #[arg(long, conflicts_with = "symbol")]
+        symbols: Option<String>,
 
         /// 시작 날짜 (YYYY-MM-DD)
         #[arg(short = 'f', long)]
@@ -217,11 +253,133 @@ enum Commands {
         #[arg(short, long)]
         output: Option<String>,
 
+        /// 결과 내보내기 형식 (json: 단일 파일, csv: 지표/거래/자산 곡선 파일 분리)
+        #[arg(long, requires = "output")]
+        format: Option<String>,
+
+        /// 벤치마크 종목 (Buy & Hold 대비 알파/베타/정보 비율 계산, 예: SPY, 069500)
+        #[arg(long)]
+        benchmark: Option<String>,
+
+        /// 연간 무위험 수익률 (샤프/소르티노 계산용, 예: 0.035 = 3.5%, 기본: 0.05)
+        #[arg(long)]
+        risk_free_rate: Option<f64>,
+
+        /// 몬테카를로 부트스트랩 시나리오 수 (거래별 수익률 리샘플링, 예: 1000)
+        #[arg(long)]
+        monte_carlo: Option<usize>,
+
+        /// 몬테카를로 난수 시드 (재현용)
+        #[arg(long, default_value = "42", requires = "monte_carlo")]
+        mc_seed: u64,
+
+        /// 몬테카를로 리샘플링 방식 (shuffle: 거래 순서 섞기, block: 블록 부트스트랩)
+        #[arg(long, default_value = "block", requires = "monte_carlo")]
+        mc_method: String,
+
+        /// 블록 부트스트랩 블록 길이 (거래 수)
+        #[arg(long, default_value = "5", requires = "monte_carlo")]
+        mc_block_size: usize,
+
+        /// 파산으로 간주할 최대 낙폭 (%)
+        #[arg(long, default_value = "50", requires = "monte_carlo")]
+        mc_ruin_dd: String,
+
         /// 사용 가능한 전략 목록 보기
         #[arg(long)]
         list_strategies: bool,
     },
 
+    /// 저장된 백테스트 실행 이력 조회/비교/정리
+    BacktestHistory {
+        /// 동작 (list: 이력 및 성과 추이, diff: 두 실행 비교, prune: 오래된 결과 정리)
+        #[arg(value_name = "ACTION", default_value = "list")]
+        action: String,
+
+        /// 전략 ID (list 필수, prune 시 생략하면 전체)
+        #[arg(short = 'i', long)]
+        strategy: Option<String>,
+
+        /// 기준 실행 ID (diff)
+        #[arg(long)]
+        base: Option<String>,
+
+        /// 비교 실행 ID (diff)
+        #[arg(long)]
+        target: Option<String>,
+
+        /// 최근 N개 실행 (list)
+        #[arg(long, default_value = "50")]
+        limit: i64,
+
+        /// 전략별 최대 보관 건수 (prune)
+        #[arg(long, default_value = "50")]
+        max_runs: usize,
+
+        /// 최대 보관 기간 (일, prune, 0 = 무제한)
+        #[arg(long, default_value = "180")]
+        max_age_days: u32,
+
+        /// JSON 형식으로 출력
+        #[arg(long)]
+        json: bool,
+
+        /// 데이터베이스 URL
+        #[arg(long)]
+        db_url: Option<String>,
+    },
+
+    /// 워크포워드 분석 (학습 → 검증 롤링 윈도우 백테스트)
+    Walkforward {
+        /// 전략 설정 파일 (TOML 또는 JSON)
+        #[arg(short, long)]
+        config: String,
+
+        /// 시장 유형 (KR: 한국, US: 미국)
+        #[arg(short, long)]
+        market: String,
+
+        /// 종목 코드/심볼 (예: 005930, SPY)
+        #[arg(short, long)]
+        symbol: Option<String>,
+
+        /// 포트폴리오 종목 목록 (콤마 구분, 예: "SPY,QQQ,IWM")
+        #[arg(long, conflicts_with = "symbol")]
+        symbols: Option<String>,
+
+        /// 시작 날짜 (YYYY-MM-DD)
+        #[arg(short = 'f', long)]
+        from: Option<String>,
+
+        /// 종료 날짜 (YYYY-MM-DD)
+        #[arg(short, long)]
+        to: Option<String>,
+
+        /// 초기 자본금 (기본: 10,000,000원)
+        #[arg(long, default_value = "10000000")]
+        capital: String,
+
+        /// 학습 구간 길이 (개월)
+        #[arg(long, default_value = "12")]
+        train_months: u32,
+
+        /// 검증 구간 길이 (개월)
+        #[arg(long, default_value = "3")]
+        test_months: u32,
+
+        /// 윈도우 이동 스텝 (개월, 기본: 검증 구간 길이)
+        #[arg(long)]
+        step_months: Option<u32>,
+
+        /// 앵커드 모드 (학습 시작일 고정, 학습 구간 누적 확장)
+        #[arg(long)]
+        anchored: bool,
+
+        /// 복합 자산 곡선 CSV 저장 경로
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
     /// 전략 통합 테스트 (UI와 동일한 환경에서 전략 검증)
     StrategyTest {
         /// 전략 ID (예: rsi, grid, bollinger)
@@ -268,6 +426,10 @@ enum Commands {
         #[arg(long)]
         db_url: Option<String>,
 
+        /// 시점별 펀더멘털 스냅샷 JSON 경로 (스크리닝 기반 전략 백테스트용)
+        #[arg(long)]
+        fundamentals: Option<String>,
+
         /// 회귀 테스트 Fixture 파일 경로 (단일 파일)
         #[arg(long)]
         fixture: Option<String>,
@@ -291,6 +453,26 @@ enum Commands {
         /// 차트 출력 디렉토리 (기본: ./regression_charts)
         #[arg(long, default_value = "regression_charts")]
         charts_dir: String,
+
+        /// 파라미터 그리드 서치 (JSON, 예: '{"rsi_period":[9,14,21],"oversold":[25,30]}')
+        #[arg(long)]
+        grid: Option<String>,
+
+        /// 그리드 서치 동시 실행 수
+        #[arg(long, default_value = "4", requires = "grid")]
+        grid_parallel: usize,
+
+        /// 그리드 서치 상위 결과 출력 수
+        #[arg(long, default_value = "10", requires = "grid")]
+        grid_top: usize,
+
+        /// 그리드 서치 정렬 기준 (return, sharpe, mdd)
+        #[arg(long, default_value = "return", requires = "grid")]
+        grid_sort: String,
+
+        /// 그리드 서치 전체 결과 CSV 저장 경로
+        #[arg(long, requires = "grid")]
+        grid_csv: Option<String>,
     },
 
     /// 시스템 상태 확인
@@ -336,11 +518,19 @@ enum Commands {
         /// 출력 디렉토리
         #[arg(short, long, default_value = "models")]
         output_dir: String,
+
+        /// 검증 통과 시 추론 모델 디렉토리로 자동 배포 (기존 모델은 백업)
+        #[arg(long, default_value = "false")]
+        deploy: bool,
+
+        /// 배포 디렉토리
+        #[arg(long, default_value = commands::train::DEFAULT_DEPLOY_DIR)]
+        deploy_dir: String,
     },
 
     /// 마이그레이션 관리 (검증, 통합, 적용)
     Migrate {
-        /// 서브커맨드 (verify, consolidate, graph, apply, status)
+        /// 서브커맨드 (verify, consolidate, rollback, drift, graph, apply, status)
         #[arg(value_name = "SUBCOMMAND")]
         action: String,
 
@@ -348,7 +538,7 @@ enum Commands {
         #[arg(short, long, default_value = "migrations")]
         dir: String,
 
-        /// 출력 디렉토리 (consolidate 시)
+        /// 출력 디렉토리 (consolidate, rollback, drift 시)
         #[arg(short, long)]
         output: Option<String>,
 
@@ -367,6 +557,10 @@ enum Commands {
         /// 데이터베이스 URL
         #[arg(long)]
         db_url: Option<String>,
+
+        /// verify 실패 기준 위험도 (info, warn, critical)
+        #[arg(long, default_value = "critical")]
+        fail_on: String,
     },
 }
 
@@ -384,6 +578,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Download {
             market,
             symbol,
+            symbols_file,
+            resume,
+            checkpoint,
             interval,
             from,
             to,
@@ -407,24 +604,52 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 return Err("Start date must be before end date".into());
             }
 
+            // 심볼 목록 파일: 대량 다운로드 (체크포인트/재개)
+            if let Some(symbols_file) = symbols_file {
+                use commands::download_batch::{
+                    run_batch_download, BatchDownloadConfig, DEFAULT_PROGRESS_EVERY,
+                };
+
+                let symbols_file = std::path::PathBuf::from(symbols_file);
+                let checkpoint_path = checkpoint
+                    .map(Into::into)
+                    .unwrap_or_else(|| BatchDownloadConfig::default_checkpoint_path(&symbols_file));
+                let summary = run_batch_download(BatchDownloadConfig {
+                    market,
+                    symbols_file,
+                    interval,
+                    start_date,
+                    end_date,
+                    output_dir: output.unwrap_or_else(|| "data".to_string()),
+                    checkpoint_path: checkpoint_path.clone(),
+                    resume,
+                    progress_every: DEFAULT_PROGRESS_EVERY,
+                })
+                .await?;
+
+                println!(
+                    "\n대량 다운로드 완료: 성공 {} / 실패 {} / 건너뜀 {} (전체 {})",
+                    summary.succeeded,
+                    summary.failed.len(),
+                    summary.skipped,
+                    summary.total
+                );
+                println!("체크포인트: {}", checkpoint_path.display());
+                if let Some(path) = summary.failed_list_path {
+                    println!(
+                        "실패 심볼 목록: {} (--symbols-file로 재시도)",
+                        path.display()
+                    );
+                }
+                return Ok(());
+            }
+
+            let symbol = symbol.ok_or("--symbol 또는 --symbols-file이 필요합니다")?;
+
             // 출력 경로 자동 생성
             let output_path = output.unwrap_or_else(|| {
-                let market_str = match market {
-                    Market::KR => "kr",
-                    Market::US => "us",
-                };
-                let interval_str = match interval {
-                    Interval::D1 => "daily",
-                    Interval::W1 => "weekly",
-                    Interval::M1 => "monthly",
-                };
-                format!(
-                    "data/{}/{}_{}_{}_to_{}.csv",
-                    market_str,
-                    symbol.to_uppercase(),
-                    interval_str,
-                    start_date.format("%Y%m%d"),
-                    end_date.format("%Y%m%d")
+                DownloadConfig::default_output_path(
+                    "data", market, &symbol, interval, start_date, end_date,
                 )
             });
 
@@ -570,19 +795,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::ListSymbols {
             market,
             active_only,
+            sector,
+            min_market_cap,
+            sort_by,
+            order,
             format,
             output,
             search,
             limit,
             db_url,
         } => {
-            use commands::list_symbols::{list_symbols, ListSymbolsConfig, OutputFormat};
+            use commands::list_symbols::{
+                list_symbols, ListSymbolsConfig, OutputFormat, SortBy, SortOrder,
+            };
 
             let output_format = OutputFormat::parse(&format)?;
+            let sort_by = sort_by.as_deref().map(SortBy::parse).transpose()?;
+            let order = order.as_deref().map(SortOrder::parse).transpose()?;
 
             let config = ListSymbolsConfig {
                 market: market.clone(),
                 active_only,
+                sector: sector.clone(),
+                min_market_cap,
+                sort_by,
+                order,
                 format: output_format,
                 output: output.clone(),
                 search: search.clone(),
@@ -607,6 +844,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             csv_dir,
             db_url,
             dry_run,
+            record_history,
         } => {
             use commands::fetch_symbols::{fetch_symbols, FetchSymbolsConfig};
 
@@ -616,13 +854,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 csv_dir: csv_dir.clone(),
                 db_url: db_url.clone(),
                 dry_run,
+                record_history,
             };
 
             match fetch_symbols(config).await {
                 Ok(result) => {
                     info!(
-                        "✅ Fetched symbols: KR={}, US={}, CRYPTO={}, Total={}",
-                        result.kr_count, result.us_count, result.crypto_count, result.total
+                        "✅ Fetched symbols: KR={}, US={}, CRYPTO={}, Total={} (added={}, changed={}, delisted={})",
+                        result.kr.fetched,
+                        result.us.fetched,
+                        result.crypto.fetched,
+                        result.total.fetched,
+                        result.total.added,
+                        result.total.changed,
+                        result.total.delisted
                     );
                 }
                 Err(e) => {
@@ -636,10 +881,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             config,
             market,
             symbol,
+            symbols,
             from,
             to,
             capital,
             output,
+            format,
+            benchmark,
+            risk_free_rate,
+            monte_carlo,
+            mc_seed,
+            mc_method,
+            mc_block_size,
+            mc_ruin_dd,
             list_strategies,
         } => {
             // 전략 목록 출력
@@ -648,6 +902,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 return Ok(());
             }
 
+            // 심볼 처리: --symbols가 있으면 포트폴리오, 없으면 --symbol 사용
+            let symbol_list: Vec<String> = if let Some(ref s) = symbols {
+                s.split(',')
+                    .map(|x| x.trim().to_uppercase())
+                    .filter(|x| !x.is_empty())
+                    .collect()
+            } else if let Some(ref s) = symbol {
+                vec![s.to_uppercase()]
+            } else {
+                return Err(
+                    "종목 코드가 필요합니다. --symbol <CODE> 또는 --symbols <CODES> 지정".into(),
+                );
+            };
+            if symbol_list.is_empty() {
+                return Err("--symbols에 유효한 종목 코드가 없습니다".into());
+            }
+
             let market = Market::parse(&market)
                 .ok_or_else(|| format!("Invalid market: {}. Supported: KR, US", market))?;
 
@@ -658,14 +929,46 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .parse::<rust_decimal::Decimal>()
                 .map_err(|_| format!("Invalid capital: {}", capital))?;
 
+            let output_format = format
+                .as_deref()
+                .map(|f| {
+                    commands::backtest_export::ExportFormat::parse(f)
+                        .ok_or_else(|| format!("Invalid format: {}. Supported: json, csv", f))
+                })
+                .transpose()?;
+
+            let mc_method = match mc_method.to_lowercase().as_str() {
+                "shuffle" => trader_analytics::backtest::ResampleMethod::Shuffle,
+                "block" => trader_analytics::backtest::ResampleMethod::Block {
+                    block_size: mc_block_size,
+                },
+                other => {
+                    return Err(
+                        format!("Invalid mc-method: {}. Supported: shuffle, block", other).into(),
+                    )
+                }
+            };
+            let mc_ruin_dd = mc_ruin_dd
+                .parse::<rust_decimal::Decimal>()
+                .map_err(|_| format!("Invalid mc-ruin-dd: {}", mc_ruin_dd))?;
+
             let backtest_config = commands::backtest::BacktestCliConfig {
                 config_path: config.clone(),
                 market,
-                symbol: symbol.clone(),
+                symbol: symbol_list[0].clone(),
+                symbols: symbol_list.clone(),
+                benchmark: benchmark.as_ref().map(|b| b.to_uppercase()),
+                risk_free_rate,
                 start_date,
                 end_date,
                 initial_capital,
                 output_path: output.clone(),
+                output_format,
+                monte_carlo_runs: monte_carlo.unwrap_or(0),
+                monte_carlo_seed: mc_seed,
+                monte_carlo: trader_analytics::backtest::MonteCarloConfig::default()
+                    .with_method(mc_method)
+                    .with_ruin_drawdown_pct(mc_ruin_dd),
                 ..Default::default()
             };
 
@@ -676,7 +979,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Market::US => "US",
             };
             println!("시장: {}", market_str);
-            println!("종목: {}", symbol.to_uppercase());
+            println!("종목: {}", symbol_list.join(", "));
+            if let Some(b) = &benchmark {
+                println!("벤치마크: {}", b.to_uppercase());
+            }
             if let (Some(s), Some(e)) = (&start_date, &end_date) {
                 println!("기간: {} ~ {}", s, e);
             }
@@ -696,6 +1002,147 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
+        Commands::Walkforward {
+            config,
+            market,
+            symbol,
+            symbols,
+            from,
+            to,
+            capital,
+            train_months,
+            test_months,
+            step_months,
+            anchored,
+            output,
+        } => {
+            use commands::walkforward::{run_walkforward, WalkForwardCliConfig};
+
+            let market = Market::parse(&market)
+                .ok_or_else(|| format!("Invalid market: {}. Supported: KR, US", market))?;
+
+            let symbol_list: Vec<String> = if let Some(ref s) = symbols {
+                s.split(',')
+                    .map(|x| x.trim().to_uppercase())
+                    .filter(|x| !x.is_empty())
+                    .collect()
+            } else if let Some(ref s) = symbol {
+                vec![s.to_uppercase()]
+            } else {
+                return Err(
+                    "종목 코드가 필요합니다. --symbol <CODE> 또는 --symbols <CODES> 지정".into(),
+                );
+            };
+            if symbol_list.is_empty() {
+                return Err("--symbols에 유효한 종목 코드가 없습니다".into());
+            }
+
+            let start_date = from.as_ref().map(|d| parse_date(d)).transpose()?;
+            let end_date = to.as_ref().map(|d| parse_date(d)).transpose()?;
+
+            let initial_capital = capital
+                .parse::<rust_decimal::Decimal>()
+                .map_err(|_| format!("Invalid capital: {}", capital))?;
+
+            let walkforward_config = WalkForwardCliConfig {
+                backtest: commands::backtest::BacktestCliConfig {
+                    config_path: config.clone(),
+                    market,
+                    symbol: symbol_list[0].clone(),
+                    symbols: symbol_list.clone(),
+                    start_date,
+                    end_date,
+                    initial_capital,
+                    generate_chart: false,
+                    verbose_signals: false,
+                    ..Default::default()
+                },
+                train_months,
+                test_months,
+                step_months,
+                anchored,
+                output_path: output.clone(),
+            };
+
+            println!("\n🔁 워크포워드 분석 실행 중...");
+            println!("전략 설정: {}", config);
+            println!("종목: {}", symbol_list.join(", "));
+            println!(
+                "윈도우: 학습 {}개월 → 검증 {}개월, 스텝 {}개월 ({})",
+                train_months,
+                test_months,
+                step_months.unwrap_or(test_months),
+                if anchored { "앵커드" } else { "롤링" }
+            );
+
+            match run_walkforward(walkforward_config).await {
+                Ok(summary) => {
+                    info!(
+                        "✅ Walk-forward completed: {} windows",
+                        summary.windows.len()
+                    );
+                    if let Some(out) = output {
+                        println!("\n📁 복합 자산 곡선 저장됨: {}", out);
+                    }
+                }
+                Err(e) => {
+                    error!("Walk-forward failed: {}", e);
+                    return Err(e.into());
+                }
+            }
+        }
+
+        Commands::BacktestHistory {
+            action,
+            strategy,
+            base,
+            target,
+            limit,
+            max_runs,
+            max_age_days,
+            json,
+            db_url,
+        } => {
+            use commands::backtest_history::{
+                run_backtest_history, BacktestHistoryConfig, HistoryAction,
+            };
+
+            let parse_id = |label: &str, id: Option<String>| {
+                let id = id.ok_or_else(|| format!("diff requires --{}", label))?;
+                uuid::Uuid::parse_str(&id).map_err(|_| format!("Invalid {} id: {}", label, id))
+            };
+
+            let action = match action.as_str() {
+                "list" => HistoryAction::List {
+                    strategy_id: strategy.ok_or("list requires --strategy")?,
+                    limit: limit.clamp(1, 500),
+                },
+                "diff" => HistoryAction::Diff {
+                    base: parse_id("base", base)?,
+                    target: parse_id("target", target)?,
+                },
+                "prune" => HistoryAction::Prune {
+                    strategy_id: strategy,
+                    policy: trader_analytics::backtest::RetentionPolicy {
+                        max_runs_per_strategy: max_runs,
+                        max_age_days: (max_age_days > 0).then_some(max_age_days),
+                    },
+                },
+                other => {
+                    return Err(
+                        format!("Unknown action: {}. Supported: list, diff, prune", other).into(),
+                    );
+                }
+            };
+
+            run_backtest_history(BacktestHistoryConfig {
+                action,
+                json,
+                db_url,
+            })
+            .await?;
+        }
+
         Commands::Health => {
             info!("Checking system health...");
             println!("\n시스템 상태 확인 중...");
@@ -718,12 +1165,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             debug,
             list_strategies,
             db_url,
+            fundamentals,
             fixture,
             regression,
             fixtures_dir,
             init_only,
             charts,
             charts_dir,
+            grid,
+            grid_parallel,
+            grid_top,
+            grid_sort,
+            grid_csv,
         } => {
             use std::path::Path;
 
@@ -858,8 +1311,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 initial_capital,
                 debug,
                 db_url: db_url.clone(),
+                fundamentals_path: fundamentals,
             };
 
+            // 파라미터 그리드 서치 모드
+            if let Some(grid) = grid {
+                use commands::grid_search::{run_grid_search, GridSearchOptions, GridSortKey};
+
+                let sort_by = GridSortKey::parse(&grid_sort).ok_or_else(|| {
+                    format!(
+                        "Invalid grid sort: {}. Supported: return, sharpe, mdd",
+                        grid_sort
+                    )
+                })?;
+
+                run_grid_search(
+                    test_config,
+                    GridSearchOptions {
+                        grid,
+                        parallel: grid_parallel,
+                        top: grid_top,
+                        sort_by,
+                        csv_path: grid_csv,
+                    },
+                )
+                .await?;
+                return Ok(());
+            }
+
             match run_strategy_test(test_config).await {
                 Ok(result) => {
                     if result.success {
@@ -927,7 +1406,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             horizon,
             name,
             output_dir,
+            deploy,
+            deploy_dir,
         } => {
+            use commands::train::{default_model_name, deploy_model, validate_model};
+
             info!("Starting ML model training...");
             println!("\n🤖 ML 모델 훈련 시작...");
 
@@ -948,24 +1431,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             ];
 
             // 심볼 처리
-            if let Some(s) = symbol {
+            let symbol_list: Vec<String> = if let Some(s) = symbol {
                 args.push("--symbol".to_string());
                 args.push(s.clone());
                 println!("심볼: {}", s);
+                vec![s]
             } else if let Some(syms) = symbols {
                 args.push("--symbols".to_string());
                 args.push(syms.clone());
                 println!("심볼: {}", syms);
+                syms.split(',').map(|s| s.trim().to_string()).collect()
             } else {
                 args.push("--symbol".to_string());
                 args.push("SPY".to_string());
                 println!("심볼: SPY (기본값)");
-            }
+                vec!["SPY".to_string()]
+            };
 
-            if let Some(n) = name {
-                args.push("--name".to_string());
-                args.push(n);
-            }
+            // 검증/배포 대상 파일을 찾기 위해 모델 이름을 항상 명시
+            let model_name =
+                name.unwrap_or_else(|| default_model_name(&model, &symbol_list, &period));
+            args.push("--name".to_string());
+            args.push(model_name.clone());
 
             println!("모델: {}", model);
             println!("기간: {}", period);
@@ -985,12 +1472,39 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     if status.success() {
                         info!("✅ ML model training completed successfully");
                         println!("\n✅ 모델 훈련 완료!");
-                        println!("ONNX 모델이 {} 디렉토리에 저장되었습니다.", output_dir);
-                        println!("\nRust에서 사용하려면:");
-                        println!(
-                            "  cp {}/[모델이름].onnx crates/trader-analytics/models/",
-                            output_dir
-                        );
+
+                        let onnx_path =
+                            std::path::Path::new(&output_dir).join(format!("{}.onnx", model_name));
+                        println!("ONNX 모델: {}", onnx_path.display());
+
+                        let is_valid = match validate_model(&onnx_path) {
+                            Ok(valid) => valid,
+                            Err(e) if !deploy => {
+                                warn!("ONNX model validation skipped: {}", e);
+                                println!("\n⚠️  모델 검증 생략: {}", e);
+                                return Ok(());
+                            }
+                            Err(e) => return Err(e.into()),
+                        };
+
+                        if !is_valid {
+                            error!("ONNX model validation failed: {}", onnx_path.display());
+                            return Err("모델 검증 실패: 배포하지 않습니다".into());
+                        }
+
+                        if deploy {
+                            let result =
+                                deploy_model(&onnx_path, std::path::Path::new(&deploy_dir))?;
+                            for backup in &result.backups {
+                                println!("  📦 백업: {}", backup.display());
+                            }
+                            for deployed in &result.deployed {
+                                println!("  🚀 배포: {}", deployed.display());
+                            }
+                            info!("Model deployed to {}", deploy_dir);
+                        } else {
+                            println!("\n배포하려면 --deploy 옵션을 사용하세요 ({})", deploy_dir);
+                        }
                     } else {
                         error!("ML training failed with exit code: {:?}", status.code());
                         return Err("ML training failed".into());
@@ -1015,12 +1529,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             dry_run,
             format,
             db_url,
+            fail_on,
         } => {
             use commands::migrate::{GraphFormat, MigrateConfig};
+            use trader_core::migration::Severity;
 
             let graph_format = GraphFormat::parse(&format).ok_or_else(|| {
                 format!("Invalid format: {}. Supported: mermaid, dot, text", format)
             })?;
+            let fail_on = Severity::parse(&fail_on).ok_or_else(|| {
+                format!(
+                    "Invalid fail-on level: {}. Supported: info, warn, critical",
+                    fail_on
+                )
+            })?;
 
             let config = MigrateConfig {
                 migrations_dir: dir.into(),
@@ -1029,6 +1551,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 dry_run,
                 graph_format,
                 db_url,
+                fail_on,
             };
 
             match action.as_str() {
@@ -1041,6 +1564,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "consolidate" => {
                     commands::migrate::run_consolidate(&config)?;
                 }
+                "rollback" => {
+                    commands::migrate::run_rollback(&config)?;
+                }
+                "drift" => {
+                    let is_clean = commands::migrate::run_drift(&config).await?;
+                    if !is_clean {
+                        return Err("스키마 드리프트 발견".into());
+                    }
+                }
                 "graph" => {
                     let output = commands::migrate::run_graph(&config)?;
                     println!("{}", output);
@@ -1056,6 +1588,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!("\n사용 가능한 액션:");
                     println!("  verify      - 마이그레이션 검증");
                     println!("  consolidate - 마이그레이션 통합");
+                    println!("  rollback    - 롤백 SQL 생성");
+                    println!("  drift       - 실제 DB 스키마와 드리프트 검출");
                     println!("  graph       - 의존성 그래프 출력");
                     println!("  apply       - 마이그레이션 적용");
                     println!("  status      - 마이그레이션 상태");
@@ -25,6 +25,8 @@ pub struct CollectorConfig {
     pub signal_performance: SignalPerformanceConfig,
     /// 관심종목 우선 처리 여부
     pub prioritize_watchlist: bool,
+    /// 수집 우선순위 스코어링 설정
+    pub collection_priority: CollectionPriorityConfig,
 }
 
 /// 데이터 프로바이더 설정
@@ -88,6 +90,15 @@ pub struct OhlcvCollectConfig {
     /// 포함되지 않은 경우 수집을 건너뜁니다.
     /// 0이면 제한 없음 (기존 동작). 기본값: 90일.
     pub max_gap_days_non_priority: i64,
+    /// 수집 후 중간 갭(휴장일이 아닌 날의 누락 캔들) 탐지 및 백필 여부
+    pub gap_backfill_enabled: bool,
+    /// 갭 하나당 한 번에 재요청할 최대 거래일 수 (소스 제한).
+    /// 초과하는 갭은 부분 백필 후 체크포인트에 기록합니다.
+    pub gap_backfill_max_trading_days: u32,
+    /// 상장폐지 의심 판정 기준 연속 누락 거래일 수.
+    /// 소스가 "데이터 없음"을 이 기간 이상 연속 반환하면 심볼을 비활성화합니다.
+    /// 네트워크 오류는 집계하지 않습니다. 0이면 자동 비활성화 안 함.
+    pub delisting_no_data_trading_days: u32,
 }
 
 /// Fundamental 및 지표 수집 설정
@@ -103,6 +114,38 @@ pub struct FundamentalCollectConfig {
     pub include_ohlcv: bool,
 }
 
+/// 종목별 수집 우선순위 스코어링 설정
+///
+/// 거래량·관심종목·전략 사용 여부·신선도를 가중합하여 스코어를 계산하고,
+/// 스코어 등급(Hot/Warm/Cold)에 따라 수집 주기를 다르게 적용합니다.
+#[derive(Debug, Clone)]
+pub struct CollectionPriorityConfig {
+    /// 우선순위 스케줄링 활성화 여부
+    /// 기본값: false (기존 관심종목 우선 정렬 유지)
+    pub enabled: bool,
+    /// 거래량 가중치
+    pub weight_volume: f64,
+    /// 관심종목(watchlist) 가중치
+    pub weight_watchlist: f64,
+    /// 전략 사용 종목 가중치
+    pub weight_strategy: f64,
+    /// 신선도(마지막 수집 후 경과 시간) 가중치
+    pub weight_freshness: f64,
+    /// Hot 등급 최소 스코어 (0.0 ~ 1.0)
+    pub hot_threshold: f64,
+    /// Warm 등급 최소 스코어 (0.0 ~ 1.0), 미만은 Cold
+    pub warm_threshold: f64,
+    /// Hot 등급 수집 주기 (시간)
+    pub hot_interval_hours: i64,
+    /// Warm 등급 수집 주기 (시간)
+    pub warm_interval_hours: i64,
+    /// Cold 등급 수집 주기 (시간)
+    pub cold_interval_hours: i64,
+    /// 기아 방지 임계값 (시간)
+    /// 마지막 수집 후 이 시간을 넘긴 종목은 스코어와 무관하게 최우선 수집합니다.
+    pub max_starvation_hours: i64,
+}
+
 /// 데몬 모드 설정
 #[derive(Debug, Clone)]
 pub struct DaemonConfig {
@@ -177,6 +220,12 @@ impl CollectorConfig {
                     .ok()
                     .and_then(|v| v.parse().ok()),
                 max_gap_days_non_priority: env_var_parse("OHLCV_MAX_GAP_DAYS_NON_PRIORITY", 90),
+                gap_backfill_enabled: env_var_bool("OHLCV_GAP_BACKFILL_ENABLED", true),
+                gap_backfill_max_trading_days: env_var_parse(
+                    "OHLCV_GAP_BACKFILL_MAX_TRADING_DAYS",
+                    120,
+                ),
+                delisting_no_data_trading_days: env_var_parse("OHLCV_DELISTING_NO_DATA_DAYS", 5),
             },
             fundamental_collect: FundamentalCollectConfig {
                 batch_size: env_var_parse("FUNDAMENTAL_BATCH_SIZE", 100),
@@ -203,6 +252,7 @@ impl CollectorConfig {
                 max_days: env_var_parse("SIGNAL_PERFORMANCE_MAX_DAYS", 20),
             },
             prioritize_watchlist: env_var_bool("PRIORITIZE_WATCHLIST", true),
+            collection_priority: CollectionPriorityConfig::from_env(),
         })
     }
 }
@@ -221,6 +271,53 @@ impl FundamentalCollectConfig {
     }
 }
 
+impl Default for CollectionPriorityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            weight_volume: 0.3,
+            weight_watchlist: 0.25,
+            weight_strategy: 0.25,
+            weight_freshness: 0.2,
+            hot_threshold: 0.6,
+            warm_threshold: 0.3,
+            hot_interval_hours: 6,
+            warm_interval_hours: 24,
+            cold_interval_hours: 72,
+            max_starvation_hours: 168,
+        }
+    }
+}
+
+impl CollectionPriorityConfig {
+    /// 환경변수에서 우선순위 설정 로드 (미설정 항목은 기본값 사용)
+    fn from_env() -> Self {
+        let d = Self::default();
+        Self {
+            enabled: env_var_bool("PRIORITY_SCHEDULING_ENABLED", d.enabled),
+            weight_volume: env_var_parse("PRIORITY_WEIGHT_VOLUME", d.weight_volume),
+            weight_watchlist: env_var_parse("PRIORITY_WEIGHT_WATCHLIST", d.weight_watchlist),
+            weight_strategy: env_var_parse("PRIORITY_WEIGHT_STRATEGY", d.weight_strategy),
+            weight_freshness: env_var_parse("PRIORITY_WEIGHT_FRESHNESS", d.weight_freshness),
+            hot_threshold: env_var_parse("PRIORITY_HOT_THRESHOLD", d.hot_threshold),
+            warm_threshold: env_var_parse("PRIORITY_WARM_THRESHOLD", d.warm_threshold),
+            hot_interval_hours: env_var_parse("PRIORITY_HOT_INTERVAL_HOURS", d.hot_interval_hours),
+            warm_interval_hours: env_var_parse(
+                "PRIORITY_WARM_INTERVAL_HOURS",
+                d.warm_interval_hours,
+            ),
+            cold_interval_hours: env_var_parse(
+                "PRIORITY_COLD_INTERVAL_HOURS",
+                d.cold_interval_hours,
+            ),
+            max_starvation_hours: env_var_parse(
+                "PRIORITY_MAX_STARVATION_HOURS",
+                d.max_starvation_hours,
+            ),
+        }
+    }
+}
+
 impl DaemonConfig {
     /// 워크플로우 실행 주기를 Duration으로 반환
     pub fn interval(&self) -> Duration {
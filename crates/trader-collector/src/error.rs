@@ -57,5 +57,11 @@ impl From<Box<dyn std::error::Error + Send + Sync>> for CollectorError {
     }
 }
 
+impl From<trader_core::IdempotencyError> for CollectorError {
+    fn from(err: trader_core::IdempotencyError) -> Self {
+        Self::Other(Box::new(err))
+    }
+}
+
 /// Result 타입 별칭
 pub type Result<T> = std::result::Result<T, CollectorError>;
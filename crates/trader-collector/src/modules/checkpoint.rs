@@ -7,6 +7,7 @@
 //! - **체크포인트 저장**: 100개 처리마다 진행 상태 저장
 //! - **중단점 재개**: 중단된 지점부터 이어서 처리
 //! - **Stale 필터링**: N시간 이내 처리된 데이터 스킵
+//! - **진행률/ETA**: 예상 처리량과 시작 시각 기준으로 진행률 및 예상 완료 시각 계산
 //!
 //! # 사용 예
 //!
@@ -18,6 +19,9 @@
 //!     None
 //! };
 //!
+//! // 처리 대상 조회 후 (예상 처리량 기록)
+//! begin_checkpoint(pool, "my_workflow", total, options.resume && options.keep_started_at).await?;
+//!
 //! // 처리 중 (100개마다)
 //! save_checkpoint(pool, "my_workflow", &ticker, processed, "running").await?;
 //!
@@ -25,14 +29,17 @@
 //! save_checkpoint(pool, "my_workflow", "", total, "completed").await?;
 //! ```
 
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 
 type CheckpointRow = (
     String,
     Option<String>,
-    Option<chrono::DateTime<chrono::Utc>>,
+    Option<DateTime<Utc>>,
     i32,
     String,
+    Option<i32>,
+    Option<DateTime<Utc>>,
 );
 
 use crate::Result;
@@ -62,8 +69,71 @@ impl CheckpointStatus {
     }
 }
 
+/// 워크플로우 시작 기록 (예상 처리량, 시작 시각).
+///
+/// 처리 대상 조회 직후 호출합니다. `keep_started_at`이 true이고 이전 실행이
+/// interrupted 상태이면 기존 `started_at`과 처리량을 유지하고, 이번 실행의
+/// 처리량을 그 위에 누적합니다. 그렇지 않으면 진행 상태를 새로 시작합니다.
+///
+/// # Arguments
+/// * `pool` - DB 연결 풀
+/// * `workflow` - 워크플로우 이름
+/// * `total_expected` - 이번 실행에서 처리할 항목 수
+/// * `keep_started_at` - 재개 시 이전 시작 시각 유지 여부
+pub async fn begin_checkpoint(
+    pool: &PgPool,
+    workflow: &str,
+    total_expected: i32,
+    keep_started_at: bool,
+) -> Result<()> {
+    // 재개 가능한 이전 실행이 있을 때만 시작 시각 유지
+    let keep = keep_started_at
+        && sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT status = 'interrupted' AND started_at IS NOT NULL
+            FROM sync_checkpoint
+            WHERE workflow_name = $1
+            "#,
+        )
+        .bind(workflow)
+        .fetch_optional(pool)
+        .await?
+        .unwrap_or(false);
+
+    sqlx::query(
+        r#"
+        INSERT INTO sync_checkpoint (
+            workflow_name, last_ticker, last_processed_at, total_processed,
+            total_expected, started_at, processed_offset, status, updated_at
+        )
+        VALUES ($1, '', NOW(), 0, $2, NOW(), 0, 'running', NOW())
+        ON CONFLICT (workflow_name)
+        DO UPDATE SET
+            last_ticker = CASE WHEN $3 THEN sync_checkpoint.last_ticker ELSE '' END,
+            last_processed_at = NOW(),
+            total_processed = CASE WHEN $3 THEN sync_checkpoint.total_processed ELSE 0 END,
+            total_expected = CASE
+                WHEN $3 THEN sync_checkpoint.total_processed + EXCLUDED.total_expected
+                ELSE EXCLUDED.total_expected
+            END,
+            started_at = CASE WHEN $3 THEN sync_checkpoint.started_at ELSE NOW() END,
+            processed_offset = CASE WHEN $3 THEN sync_checkpoint.total_processed ELSE 0 END,
+            status = 'running',
+            updated_at = NOW()
+        "#,
+    )
+    .bind(workflow)
+    .bind(total_expected)
+    .bind(keep)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
 /// 체크포인트 저장.
 ///
+/// `total_processed`는 이번 실행 기준 처리 수이며, 재개 시 유지된 이전 처리량이 합산됩니다.
+///
 /// # Arguments
 /// * `pool` - DB 연결 풀
 /// * `workflow` - 워크플로우 이름 (e.g., "naver_fundamental", "ohlcv_collect")
@@ -85,7 +155,11 @@ pub async fn save_checkpoint(
         DO UPDATE SET
             last_ticker = EXCLUDED.last_ticker,
             last_processed_at = NOW(),
-            total_processed = EXCLUDED.total_processed,
+            total_processed = sync_checkpoint.processed_offset + EXCLUDED.total_processed,
+            processed_offset = CASE
+                WHEN EXCLUDED.status = 'completed' THEN 0
+                ELSE sync_checkpoint.processed_offset
+            END,
             status = EXCLUDED.status,
             updated_at = NOW()
         "#,
@@ -151,10 +225,14 @@ pub async fn clear_checkpoint(pool: &PgPool, workflow: &str) -> Result<()> {
 }
 
 /// 모든 워크플로우의 체크포인트 상태 조회.
+///
+/// 예상 처리량이 기록된 워크플로우는 진행률(%)을, 실행 중인 워크플로우는
+/// 시작 이후 평균 처리 속도 기반의 예상 완료 시각을 함께 반환합니다.
 pub async fn list_checkpoints(pool: &PgPool) -> Result<Vec<CheckpointInfo>> {
     let rows: Vec<CheckpointRow> = sqlx::query_as(
         r#"
-            SELECT workflow_name, last_ticker, last_processed_at, total_processed, status
+            SELECT workflow_name, last_ticker, last_processed_at, total_processed, status,
+                   total_expected, started_at
             FROM sync_checkpoint
             ORDER BY workflow_name
             "#,
@@ -165,25 +243,116 @@ pub async fn list_checkpoints(pool: &PgPool) -> Result<Vec<CheckpointInfo>> {
     Ok(rows
         .into_iter()
         .map(
-            |(workflow_name, last_ticker, last_processed_at, total_processed, status)| {
+            |(
+                workflow_name,
+                last_ticker,
+                last_processed_at,
+                total_processed,
+                status,
+                total_expected,
+                started_at,
+            )| {
+                let progress_pct = progress_percent(total_processed, total_expected);
+                let eta = if status == CheckpointStatus::Running.as_str() {
+                    estimate_eta(
+                        total_processed,
+                        total_expected,
+                        started_at,
+                        last_processed_at,
+                    )
+                } else {
+                    None
+                };
                 CheckpointInfo {
                     workflow_name,
                     last_ticker,
                     last_processed_at,
                     total_processed,
                     status,
+                    total_expected,
+                    started_at,
+                    progress_pct,
+                    eta,
                 }
             },
         )
         .collect())
 }
 
+/// 진행률(%) 계산. 예상 처리량이 없으면 None.
+fn progress_percent(total_processed: i32, total_expected: Option<i32>) -> Option<f64> {
+    let expected = total_expected.filter(|&e| e > 0)?;
+    Some((total_processed as f64 / expected as f64 * 100.0).clamp(0.0, 100.0))
+}
+
+/// 시작 이후 평균 처리 속도로 예상 완료 시각 계산.
+///
+/// 처리 속도는 `started_at`부터 `last_processed_at`까지의 처리량으로 구합니다.
+fn estimate_eta(
+    total_processed: i32,
+    total_expected: Option<i32>,
+    started_at: Option<DateTime<Utc>>,
+    last_processed_at: Option<DateTime<Utc>>,
+) -> Option<DateTime<Utc>> {
+    let expected = total_expected?;
+    let (started_at, last_processed_at) = (started_at?, last_processed_at?);
+    if total_processed <= 0 {
+        return None;
+    }
+
+    let remaining = (expected - total_processed).max(0) as i64;
+    let elapsed_ms = (last_processed_at - started_at).num_milliseconds();
+    if elapsed_ms <= 0 {
+        return None;
+    }
+
+    let remaining_ms = elapsed_ms * remaining / total_processed as i64;
+    Some(last_processed_at + chrono::Duration::milliseconds(remaining_ms))
+}
+
 /// 체크포인트 정보
 #[derive(Debug)]
 pub struct CheckpointInfo {
     pub workflow_name: String,
     pub last_ticker: Option<String>,
-    pub last_processed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_processed_at: Option<DateTime<Utc>>,
     pub total_processed: i32,
     pub status: String,
+    /// 예상 총 처리 수
+    pub total_expected: Option<i32>,
+    /// 워크플로우 시작 시각
+    pub started_at: Option<DateTime<Utc>>,
+    /// 진행률 (0~100)
+    pub progress_pct: Option<f64>,
+    /// 예상 완료 시각 (실행 중일 때만)
+    pub eta: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_progress_percent() {
+        assert_eq!(progress_percent(50, Some(200)), Some(25.0));
+        assert_eq!(progress_percent(250, Some(200)), Some(100.0));
+        assert_eq!(progress_percent(10, Some(0)), None);
+        assert_eq!(progress_percent(10, None), None);
+    }
+
+    #[test]
+    fn test_estimate_eta_uses_average_rate() {
+        let started = Utc.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap();
+        let last = started + chrono::Duration::minutes(10);
+
+        // 10분에 100개 → 남은 300개는 30분
+        let eta = estimate_eta(100, Some(400), Some(started), Some(last));
+        assert_eq!(eta, Some(last + chrono::Duration::minutes(30)));
+
+        // 처리 전이거나 시작 시각이 없으면 추정 불가
+        assert_eq!(estimate_eta(0, Some(400), Some(started), Some(last)), None);
+        assert_eq!(estimate_eta(100, Some(400), None, Some(last)), None);
+        assert_eq!(estimate_eta(100, None, Some(started), Some(last)), None);
+    }
 }
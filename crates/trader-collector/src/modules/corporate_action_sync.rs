@@ -0,0 +1,568 @@
+//! 기업 행동(corporate action) 동기화 모듈.
+//!
+//! Yahoo Finance에서 주식 분할 비율과 배당락일 정보를 수집해 `corporate_action`
+//! 테이블에 저장하고, 일봉 OHLCV의 `adjusted_close`를 계산합니다.
+//!
+//! # 조정 방식
+//!
+//! 최신 캔들부터 과거 방향으로 누적 팩터를 곱해 나갑니다 (역방향 누적 팩터).
+//! - **분할**: 분할 기준일 이전 캔들에 `1 / 분할 비율`
+//! - **배당**: 배당락일 이전 캔들에 `1 - 배당금 / 직전 종가`
+//!
+//! 원본 `close`는 변경하지 않고 항상 원본 기준으로 다시 계산하므로 결과가
+//! 누적되지 않습니다. 반영된 이벤트는 `applied_at`으로 표시해 변경이 없는
+//! 심볼은 재계산을 건너뜁니다. 소스 가격이 이미 분할 반영된 경우(Yahoo 일봉)
+//! 분할 기준일 전후 가격 단절이 없으므로 분할 팩터를 중복 적용하지 않습니다.
+//! 조정된 캔들이 있으면 Redis kline cache 버전을 올려 이전 캔들이 조회되지 않게 합니다.
+
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use tracing::{debug, info, warn};
+use trader_data::{
+    cache::RedisCache,
+    provider::{YahooFundamentalError, YahooFundamentalFetcher},
+};
+
+use super::checkpoint::{self, CheckpointStatus};
+use crate::{error::CollectorError, stats::CollectionStats, Result};
+
+/// 체크포인트 워크플로우 이름
+const WORKFLOW: &str = "corporate_action_sync";
+
+/// 기업 행동 조회 결과 타입 (종류, 기준일, 값, 반영 시각)
+type CorporateActionRow = (String, NaiveDate, Decimal, Option<DateTime<Utc>>);
+
+/// 기업 행동 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorporateActionKind {
+    /// 주식 분할 (병합 포함, 비율 < 1)
+    Split,
+    /// 현금 배당
+    Dividend,
+}
+
+impl CorporateActionKind {
+    /// DB 저장용 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Split => "split",
+            Self::Dividend => "dividend",
+        }
+    }
+
+    /// DB 문자열에서 변환
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "split" => Some(Self::Split),
+            "dividend" => Some(Self::Dividend),
+            _ => None,
+        }
+    }
+}
+
+/// 기업 행동 이벤트
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorporateAction {
+    /// 분할 기준일 또는 배당락일
+    pub ex_date: NaiveDate,
+    /// 종류
+    pub kind: CorporateActionKind,
+    /// 분할 비율(분할 후/분할 전 주식 수) 또는 주당 배당금(분할 반영 기준)
+    pub value: Decimal,
+}
+
+/// 기업 행동 동기화 옵션
+#[derive(Debug, Clone)]
+pub struct CorporateActionSyncOptions {
+    /// 특정 심볼만 처리 (쉼표 구분)
+    pub symbols: Option<String>,
+    /// 특정 시장만 처리 (예: "US")
+    pub market_filter: Option<String>,
+    /// 수집 기간 (년)
+    pub lookback_years: u32,
+    /// API 요청 간 딜레이 (밀리초)
+    pub request_delay_ms: u64,
+    /// 중단점부터 재개
+    pub resume: bool,
+    /// 재개 시 이전 시작 시각 유지 (진행률/ETA를 최초 실행 기준으로 누적)
+    pub keep_started_at: bool,
+}
+
+impl Default for CorporateActionSyncOptions {
+    fn default() -> Self {
+        Self {
+            symbols: None,
+            market_filter: None,
+            lookback_years: 3,
+            request_delay_ms: 500,
+            resume: false,
+            keep_started_at: false,
+        }
+    }
+}
+
+/// 기업 행동 수집 및 수정 종가 계산.
+///
+/// # 동작
+/// 1. 대상 심볼 조회 (활성 STOCK/ETF, yahoo_symbol 보유)
+/// 2. Yahoo Finance에서 분할·배당 이벤트 수집 → `corporate_action` UPSERT
+/// 3. `apply_corporate_actions()`로 `ohlcv.adjusted_close` 갱신
+/// 4. 갱신된 심볼은 Redis kline cache 버전 증가 (`redis_cache`가 있을 때)
+pub async fn sync_corporate_actions(
+    pool: &PgPool,
+    redis_cache: Option<&RedisCache>,
+    options: CorporateActionSyncOptions,
+) -> Result<CollectionStats> {
+    let start = Instant::now();
+    let mut stats = CollectionStats::new();
+
+    let resume_ticker = if options.resume {
+        checkpoint::load_checkpoint(pool, WORKFLOW).await?
+    } else {
+        None
+    };
+
+    let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
+        r#"
+        SELECT ticker, yahoo_symbol
+        FROM symbol_info
+        WHERE is_active = true
+          AND symbol_type IN ('STOCK', 'ETF')
+          AND yahoo_symbol IS NOT NULL
+          AND yahoo_symbol != ''
+        "#,
+    );
+    if let Some(ref s) = options.symbols {
+        let tickers: Vec<String> = s.split(',').map(|t| t.trim().to_string()).collect();
+        qb.push(" AND ticker = ANY(");
+        qb.push_bind(tickers);
+        qb.push(")");
+    }
+    if let Some(ref m) = options.market_filter {
+        qb.push(" AND market = ");
+        qb.push_bind(m.clone());
+    }
+    if let Some(ref t) = resume_ticker {
+        qb.push(" AND ticker > ");
+        qb.push_bind(t.clone());
+    }
+    qb.push(" ORDER BY ticker");
+
+    let symbols: Vec<(String, String)> = qb.build_query_as().fetch_all(pool).await?;
+    if symbols.is_empty() {
+        info!("기업 행동 수집 대상 심볼이 없습니다");
+        stats.elapsed = start.elapsed();
+        return Ok(stats);
+    }
+
+    info!(count = symbols.len(), resume = ?resume_ticker, "기업 행동 수집 시작");
+    checkpoint::begin_checkpoint(
+        pool,
+        WORKFLOW,
+        symbols.len() as i32,
+        options.resume && options.keep_started_at,
+    )
+    .await?;
+
+    let request_delay = Duration::from_millis(options.request_delay_ms);
+    let fetcher = YahooFundamentalFetcher::with_delay(request_delay)
+        .map_err(|e| CollectorError::DataSource(format!("Yahoo 크롤러 초기화 실패: {}", e)))?;
+
+    let end_date = Utc::now().date_naive();
+    let start_date = end_date - chrono::Duration::days(options.lookback_years as i64 * 365);
+    let total = symbols.len();
+
+    for (idx, (ticker, yahoo_symbol)) in symbols.iter().enumerate() {
+        stats.total += 1;
+
+        match fetcher
+            .fetch_corporate_actions(yahoo_symbol, start_date, end_date)
+            .await
+        {
+            Ok(fetched) => {
+                let actions: Vec<CorporateAction> =
+                    fetched
+                        .splits
+                        .into_iter()
+                        .map(|(ex_date, value)| CorporateAction {
+                            ex_date,
+                            kind: CorporateActionKind::Split,
+                            value,
+                        })
+                        .chain(fetched.dividends.into_iter().map(|(ex_date, value)| {
+                            CorporateAction {
+                                ex_date,
+                                kind: CorporateActionKind::Dividend,
+                                value,
+                            }
+                        }))
+                        .collect();
+
+                if let Err(e) = upsert_corporate_actions(pool, ticker, &actions).await {
+                    warn!(ticker = %ticker, error = %e, "기업 행동 저장 실패");
+                    stats.errors += 1;
+                } else {
+                    match apply_corporate_actions(pool, ticker).await {
+                        Ok(updated) => {
+                            stats.success += 1;
+                            stats.total_klines += updated;
+                            if let (Some(cache), true) = (redis_cache, updated > 0) {
+                                if let Err(e) = cache.bump_klines_version(ticker).await {
+                                    warn!(ticker = %ticker, error = %e, "kline cache 버전 증가 실패");
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            warn!(ticker = %ticker, error = %e, "수정 종가 계산 실패");
+                            stats.errors += 1;
+                        }
+                    }
+                }
+            }
+            Err(YahooFundamentalError::RateLimited) => {
+                warn!(ticker = %ticker, "Yahoo Rate limit 초과 - 5초 대기");
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                stats.errors += 1;
+            }
+            Err(e) => {
+                debug!(ticker = %ticker, error = %e, "기업 행동 수집 실패");
+                stats.errors += 1;
+            }
+        }
+
+        if (idx + 1) % 100 == 0 {
+            checkpoint::save_checkpoint(
+                pool,
+                WORKFLOW,
+                ticker,
+                (idx + 1) as i32,
+                CheckpointStatus::Running,
+            )
+            .await?;
+        }
+
+        if idx + 1 < total {
+            tokio::time::sleep(request_delay).await;
+        }
+    }
+
+    checkpoint::save_checkpoint(
+        pool,
+        WORKFLOW,
+        "",
+        total as i32,
+        CheckpointStatus::Completed,
+    )
+    .await?;
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
+
+/// 기업 행동 UPSERT.
+///
+/// 값이 바뀐 이벤트(소스 정정)는 `applied_at`을 초기화하여 재계산 대상이 됩니다.
+async fn upsert_corporate_actions(
+    pool: &PgPool,
+    symbol: &str,
+    actions: &[CorporateAction],
+) -> Result<()> {
+    if actions.is_empty() {
+        return Ok(());
+    }
+
+    let mut qb: QueryBuilder<Postgres> =
+        QueryBuilder::new("INSERT INTO corporate_action (symbol, action_type, ex_date, value) ");
+    qb.push_values(actions, |mut b, action| {
+        b.push_bind(symbol)
+            .push_bind(action.kind.as_str())
+            .push_bind(action.ex_date)
+            .push_bind(action.value);
+    });
+    qb.push(
+        " ON CONFLICT (symbol, action_type, ex_date) DO UPDATE SET \
+         value = EXCLUDED.value, \
+         applied_at = CASE WHEN corporate_action.value = EXCLUDED.value \
+                           THEN corporate_action.applied_at ELSE NULL END, \
+         updated_at = NOW()",
+    );
+    qb.build().execute(pool).await?;
+    Ok(())
+}
+
+/// 심볼의 일봉 `adjusted_close`를 기업 행동 기준으로 재계산.
+///
+/// 미반영 이벤트도 없고 `adjusted_close`가 비어 있는 캔들도 없으면 이미 조정된
+/// 상태로 보고 건너뜁니다. 재계산은 항상 원본 `close` 기준이므로 중복 조정되지 않습니다.
+///
+/// # Returns
+/// 갱신된 캔들 수 (건너뛴 경우 0)
+pub async fn apply_corporate_actions(pool: &PgPool, symbol: &str) -> Result<usize> {
+    let rows: Vec<CorporateActionRow> = sqlx::query_as(
+        r#"
+        SELECT action_type, ex_date, value, applied_at
+        FROM corporate_action
+        WHERE symbol = $1
+        "#,
+    )
+    .bind(symbol)
+    .fetch_all(pool)
+    .await?;
+
+    let has_pending = rows
+        .iter()
+        .any(|(_, _, _, applied_at)| applied_at.is_none());
+    if !has_pending {
+        let (unadjusted,): (bool,) = sqlx::query_as(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM ohlcv
+                WHERE symbol = $1 AND timeframe = '1d' AND adjusted_close IS NULL
+            )
+            "#,
+        )
+        .bind(symbol)
+        .fetch_one(pool)
+        .await?;
+        if !unadjusted {
+            debug!(symbol = symbol, "이미 조정된 심볼 - 스킵");
+            return Ok(0);
+        }
+    }
+
+    let actions: Vec<CorporateAction> = rows
+        .into_iter()
+        .filter_map(|(kind, ex_date, value, _)| {
+            Some(CorporateAction {
+                ex_date,
+                kind: CorporateActionKind::parse(&kind)?,
+                value,
+            })
+        })
+        .collect();
+
+    let candles: Vec<(DateTime<Utc>, Decimal)> = sqlx::query_as(
+        r#"
+        SELECT open_time, close
+        FROM ohlcv
+        WHERE symbol = $1 AND timeframe = '1d'
+        ORDER BY open_time
+        "#,
+    )
+    .bind(symbol)
+    .fetch_all(pool)
+    .await?;
+
+    if candles.is_empty() {
+        return Ok(0);
+    }
+
+    let bars: Vec<(NaiveDate, Decimal)> = candles
+        .iter()
+        .map(|(open_time, close)| (open_time.date_naive(), *close))
+        .collect();
+    let factors = compute_adjustment_factors(&bars, &actions);
+    let open_times: Vec<DateTime<Utc>> = candles.iter().map(|(t, _)| *t).collect();
+
+    let mut tx = pool.begin().await?;
+    let result = sqlx::query(
+        r#"
+        UPDATE ohlcv o
+        SET adj_factor = u.factor,
+            adjusted_close = o.close * u.factor
+        FROM UNNEST($2::timestamptz[], $3::numeric[]) AS u(open_time, factor)
+        WHERE o.symbol = $1 AND o.timeframe = '1d' AND o.open_time = u.open_time
+        "#,
+    )
+    .bind(symbol)
+    .bind(&open_times)
+    .bind(&factors)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "UPDATE corporate_action SET applied_at = NOW() WHERE symbol = $1 AND applied_at IS NULL",
+    )
+    .bind(symbol)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    debug!(
+        symbol = symbol,
+        actions = actions.len(),
+        candles = result.rows_affected(),
+        "수정 종가 갱신 완료"
+    );
+    Ok(result.rows_affected() as usize)
+}
+
+/// 일봉 (날짜, 종가) 시퀀스의 역방향 누적 조정 팩터 계산.
+///
+/// `bars`는 날짜 오름차순이어야 하며, 반환값은 `bars`와 같은 순서의 팩터입니다.
+/// 수정 종가는 `close × factor`입니다. 같은 날짜의 분할과 배당은 분할을 먼저
+/// 반영합니다 (배당금은 분할 반영 기준).
+pub fn compute_adjustment_factors(
+    bars: &[(NaiveDate, Decimal)],
+    actions: &[CorporateAction],
+) -> Vec<Decimal> {
+    let mut ordered: Vec<&CorporateAction> = actions.iter().collect();
+    ordered.sort_by(|a, b| {
+        b.ex_date.cmp(&a.ex_date).then_with(|| {
+            (a.kind == CorporateActionKind::Dividend)
+                .cmp(&(b.kind == CorporateActionKind::Dividend))
+        })
+    });
+
+    let mut factors = vec![Decimal::ONE; bars.len()];
+    let mut split_factor = Decimal::ONE;
+    let mut dividend_factor = Decimal::ONE;
+    let mut next = 0;
+
+    for i in (0..bars.len()).rev() {
+        let (date, close) = bars[i];
+
+        // bars[i]가 기준일 직전 캔들인 이벤트 반영
+        while let Some(action) = ordered.get(next).filter(|a| a.ex_date > date) {
+            match action.kind {
+                CorporateActionKind::Split => {
+                    let ex_close = bars.get(i + 1).map(|(_, c)| *c);
+                    if action.value > Decimal::ZERO
+                        && split_visible_in_prices(close, ex_close, action.value)
+                    {
+                        split_factor /= action.value;
+                    }
+                }
+                CorporateActionKind::Dividend => {
+                    let prev_close = close * split_factor;
+                    if prev_close > action.value && action.value > Decimal::ZERO {
+                        dividend_factor *= Decimal::ONE - action.value / prev_close;
+                    }
+                }
+            }
+            next += 1;
+        }
+
+        factors[i] = split_factor * dividend_factor;
+    }
+
+    factors
+}
+
+/// 분할 기준일 전후 가격에 분할 단절이 있는지 판단.
+///
+/// 직전 종가 / 기준일 종가 비율이 분할 비율에 더 가까우면 원본(미조정) 가격,
+/// 1에 더 가까우면 소스에서 이미 분할이 반영된 가격으로 봅니다.
+/// 기준일 이후 캔들이 없으면 원본 가격으로 간주합니다.
+fn split_visible_in_prices(prev_close: Decimal, ex_close: Option<Decimal>, ratio: Decimal) -> bool {
+    match ex_close {
+        Some(ex_close) if ex_close > Decimal::ZERO => {
+            let jump = prev_close / ex_close;
+            (jump - ratio).abs() < (jump - Decimal::ONE).abs()
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, d).unwrap()
+    }
+
+    /// 3/5 배당락(분할 반영 기준 0.5), 3/7 2:1 분할
+    fn actions() -> Vec<CorporateAction> {
+        vec![
+            CorporateAction {
+                ex_date: date(7),
+                kind: CorporateActionKind::Split,
+                value: dec!(2),
+            },
+            CorporateAction {
+                ex_date: date(5),
+                kind: CorporateActionKind::Dividend,
+                value: dec!(0.5),
+            },
+        ]
+    }
+
+    /// Yahoo chart API adjclose 기준값 (CRSP 방식: 배당 팩터 = 1 - 배당금 / 직전 종가)
+    fn yahoo_adjclose() -> Vec<Decimal> {
+        vec![
+            dec!(49.509804),
+            dec!(50.5),
+            dec!(50.5),
+            dec!(52),
+            dec!(52.5),
+            dec!(53),
+        ]
+    }
+
+    fn assert_close_to_yahoo(bars: &[(NaiveDate, Decimal)]) {
+        let factors = compute_adjustment_factors(bars, &actions());
+        for (((d, close), factor), expected) in bars.iter().zip(factors).zip(yahoo_adjclose()) {
+            let adjusted = *close * factor;
+            assert!(
+                (adjusted - expected).abs() < dec!(0.0001),
+                "{d}: {adjusted} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_yahoo_adjclose_from_raw_prices() {
+        // KRX 등 원본 가격 (분할 전 가격이 2배)
+        let bars = vec![
+            (date(3), dec!(100)),
+            (date(4), dec!(102)),
+            (date(5), dec!(101)),
+            (date(6), dec!(104)),
+            (date(7), dec!(52.5)),
+            (date(10), dec!(53)),
+        ];
+        assert_close_to_yahoo(&bars);
+    }
+
+    #[test]
+    fn test_matches_yahoo_adjclose_from_split_adjusted_prices() {
+        // Yahoo 일봉 close는 이미 분할 반영 → 분할 팩터를 중복 적용하지 않아야 함
+        let bars = vec![
+            (date(3), dec!(50)),
+            (date(4), dec!(51)),
+            (date(5), dec!(50.5)),
+            (date(6), dec!(52)),
+            (date(7), dec!(52.5)),
+            (date(10), dec!(53)),
+        ];
+        assert_close_to_yahoo(&bars);
+    }
+
+    #[test]
+    fn test_no_actions_keeps_prices() {
+        let bars = vec![(date(3), dec!(100)), (date(4), dec!(101))];
+        assert_eq!(
+            compute_adjustment_factors(&bars, &[]),
+            vec![Decimal::ONE, Decimal::ONE]
+        );
+    }
+
+    #[test]
+    fn test_reverse_split_scales_up_history() {
+        // 1:10 병합 (비율 0.1)
+        let bars = vec![(date(3), dec!(1)), (date(4), dec!(10.2))];
+        let split = CorporateAction {
+            ex_date: date(4),
+            kind: CorporateActionKind::Split,
+            value: dec!(0.1),
+        };
+        let factors = compute_adjustment_factors(&bars, &[split]);
+        assert_eq!(factors, vec![dec!(10), Decimal::ONE]);
+    }
+}
@@ -0,0 +1,281 @@
+//! 상장폐지 의심 심볼 감지 및 재활성화.
+//!
+//! OHLCV 수집 결과를 세 가지로 구분해 기록합니다.
+//!
+//! - **데이터 수신**: 누락 추적 초기화
+//! - **데이터 없음** (빈 응답, "No data found" 등): 첫 누락 거래일을 기록하고,
+//!   누락이 설정된 거래일 수 이상 이어지면 `is_active = false`로 비활성화
+//! - **일시적 오류** (네트워크, 타임아웃 등): 오류 메시지만 기록, 누락으로 집계하지 않음
+//!
+//! 비활성화된 심볼은 [`reactivate_symbols`]로 수동 재활성화할 수 있습니다.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use trader_core::TradingCalendar;
+use uuid::Uuid;
+
+use crate::Result;
+
+/// 자동 비활성화 사유
+pub const DELISTED_SUSPECT: &str = "delisted_suspect";
+
+/// 소스가 "데이터 없음"을 의미하는 오류 메시지 패턴
+const NO_DATA_PATTERNS: &[&str] = &["may be delisted", "no data found", "empty data set"];
+
+type DeactivatedRow = (
+    String,
+    String,
+    Option<String>,
+    Option<NaiveDate>,
+    Option<DateTime<Utc>>,
+    Option<String>,
+);
+
+/// 자동 비활성화된 심볼 정보
+#[derive(Debug)]
+pub struct DeactivatedSymbol {
+    pub ticker: String,
+    pub market: String,
+    pub name: Option<String>,
+    /// 첫 누락 거래일
+    pub no_data_since: Option<NaiveDate>,
+    /// 비활성화 시각
+    pub deactivated_at: Option<DateTime<Utc>>,
+    /// 마지막 수집 오류
+    pub last_fetch_error: Option<String>,
+}
+
+/// 오류 메시지가 "데이터 없음" 응답인지 판정.
+///
+/// 네트워크·타임아웃·rate limit 등 일시적 오류는 false를 반환합니다.
+pub fn is_no_data_error(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    NO_DATA_PATTERNS.iter().any(|p| lower.contains(p))
+}
+
+/// `since`부터 `as_of`까지 연속 누락된 거래일 수.
+fn missing_trading_days(
+    calendar: &TradingCalendar,
+    market: &str,
+    since: NaiveDate,
+    as_of: NaiveDate,
+) -> usize {
+    if since > as_of {
+        return 0;
+    }
+    calendar.count_trading_days(market, since, as_of)
+}
+
+/// 데이터 수신 기록 (누락 추적 초기화).
+pub async fn record_data_received(pool: &PgPool, symbol_info_id: Uuid) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE symbol_info
+        SET no_data_since = NULL,
+            fetch_fail_count = 0,
+            last_fetch_error = NULL,
+            last_fetch_attempt = NOW(),
+            updated_at = NOW()
+        WHERE id = $1 AND (no_data_since IS NOT NULL OR fetch_fail_count > 0)
+        "#,
+    )
+    .bind(symbol_info_id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 일시적 오류 기록 (누락으로 집계하지 않음).
+pub async fn record_transient_error(
+    pool: &PgPool,
+    symbol_info_id: Uuid,
+    error: &str,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE symbol_info
+        SET last_fetch_error = $2,
+            last_fetch_attempt = NOW(),
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(symbol_info_id)
+    .bind(error)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// "데이터 없음" 응답 기록 및 상장폐지 판정.
+///
+/// 첫 누락 거래일은 기존 기록이 있으면 유지하고, 없으면 `missing_from`으로 설정합니다.
+/// 첫 누락일부터 `as_of`까지 거래일 수가 `threshold` 이상이면 비활성화하고 true를 반환합니다.
+///
+/// # Arguments
+/// * `missing_from` - 데이터가 있어야 할 첫 거래일 (마지막 캔들 다음 거래일)
+/// * `as_of` - 판정 기준일 (수집 종료일)
+/// * `threshold` - 비활성화 기준 연속 누락 거래일 수 (0이면 비활성화 안 함)
+pub async fn record_no_data(
+    pool: &PgPool,
+    symbol_info_id: Uuid,
+    market: &str,
+    missing_from: NaiveDate,
+    as_of: NaiveDate,
+    threshold: u32,
+    error: Option<&str>,
+) -> Result<bool> {
+    let (no_data_since,): (NaiveDate,) = sqlx::query_as(
+        r#"
+        UPDATE symbol_info
+        SET no_data_since = COALESCE(no_data_since, $2),
+            fetch_fail_count = COALESCE(fetch_fail_count, 0) + 1,
+            last_fetch_error = COALESCE($3, 'no data'),
+            last_fetch_attempt = NOW(),
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING no_data_since
+        "#,
+    )
+    .bind(symbol_info_id)
+    .bind(missing_from)
+    .bind(error)
+    .fetch_one(pool)
+    .await?;
+
+    let missing = missing_trading_days(TradingCalendar::global(), market, no_data_since, as_of);
+    if threshold == 0 || missing < threshold as usize {
+        return Ok(false);
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE symbol_info
+        SET is_active = false,
+            deactivated_reason = $2,
+            deactivated_at = NOW(),
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(symbol_info_id)
+    .bind(DELISTED_SUSPECT)
+    .execute(pool)
+    .await?;
+
+    tracing::warn!(
+        symbol_info_id = %symbol_info_id,
+        no_data_since = %no_data_since,
+        missing_trading_days = missing,
+        "상장폐지 의심 - 심볼 비활성화"
+    );
+    Ok(true)
+}
+
+/// 자동 비활성화된 심볼 목록 조회.
+pub async fn list_deactivated_symbols(
+    pool: &PgPool,
+    market: Option<&str>,
+) -> Result<Vec<DeactivatedSymbol>> {
+    let rows: Vec<DeactivatedRow> = sqlx::query_as(
+        r#"
+        SELECT ticker, market, name, no_data_since, deactivated_at, last_fetch_error
+        FROM symbol_info
+        WHERE is_active = false
+          AND deactivated_reason IS NOT NULL
+          AND ($1::TEXT IS NULL OR market = $1)
+        ORDER BY deactivated_at DESC NULLS LAST, ticker
+        "#,
+    )
+    .bind(market)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(ticker, market, name, no_data_since, deactivated_at, last_fetch_error)| {
+                DeactivatedSymbol {
+                    ticker,
+                    market,
+                    name,
+                    no_data_since,
+                    deactivated_at,
+                    last_fetch_error,
+                }
+            },
+        )
+        .collect())
+}
+
+/// 비활성화된 심볼 수동 재활성화.
+///
+/// 누락 추적과 실패 카운트를 함께 초기화합니다. 재활성화된 심볼 수를 반환합니다.
+pub async fn reactivate_symbols(
+    pool: &PgPool,
+    tickers: &[String],
+    market: Option<&str>,
+) -> Result<u64> {
+    let result = sqlx::query(
+        r#"
+        UPDATE symbol_info
+        SET is_active = true,
+            no_data_since = NULL,
+            deactivated_reason = NULL,
+            deactivated_at = NULL,
+            fetch_fail_count = 0,
+            last_fetch_error = NULL,
+            updated_at = NOW()
+        WHERE ticker = ANY($1)
+          AND is_active = false
+          AND ($2::TEXT IS NULL OR market = $2)
+        "#,
+    )
+    .bind(tickers)
+    .bind(market)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_is_no_data_error_distinguishes_transient() {
+        assert!(is_no_data_error(
+            "AAPL: No data found, symbol may be delisted"
+        ));
+        assert!(is_no_data_error("Yahoo returned empty data set"));
+        assert!(!is_no_data_error(
+            "error sending request: connection timed out"
+        ));
+        assert!(!is_no_data_error("HTTP 429 Too Many Requests"));
+    }
+
+    #[test]
+    fn test_missing_trading_days_skips_weekends() {
+        let calendar = TradingCalendar::new();
+
+        // 2026-03-05(목) ~ 03-11(수): 주말 제외 5거래일
+        assert_eq!(
+            missing_trading_days(&calendar, "US", date(2026, 3, 5), date(2026, 3, 11)),
+            5
+        );
+        // 금요일 누락 + 주말 → 1거래일
+        assert_eq!(
+            missing_trading_days(&calendar, "US", date(2026, 3, 6), date(2026, 3, 8)),
+            1
+        );
+        // 기준일 이후 시작이면 누락 없음
+        assert_eq!(
+            missing_trading_days(&calendar, "US", date(2026, 3, 12), date(2026, 3, 11)),
+            0
+        );
+    }
+}
@@ -13,20 +13,23 @@
 //! - 섹터, 시장 구분 (KOSPI/KOSDAQ/ETF)
 //! - 외국인 소진율
 
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{collections::HashMap, time::Duration};
 
 use chrono::Utc;
+use futures::{stream, StreamExt};
 use sqlx::{PgPool, Postgres, QueryBuilder};
-use tokio::sync::Semaphore;
 use tracing::{debug, info, warn};
 use trader_core::CredentialEncryptor;
 use trader_data::provider::{
     krx_api::{KrxApiClient, KrxDailyTrade},
-    naver::{NaverFinanceFetcher, NaverFundamentalData},
+    naver::{NaverError, NaverFinanceFetcher, NaverFundamentalData},
 };
 use uuid::Uuid;
 
-use super::checkpoint::{self, CheckpointStatus};
+use super::{
+    checkpoint::{self, CheckpointStatus},
+    rate_limiter::{AdaptiveLimiter, CompletionWatermark, TokenBucket},
+};
 use crate::{config::FundamentalCollectConfig, error::CollectorError, Result};
 
 /// Fundamental 동기화 통계.
@@ -292,7 +295,7 @@ pub async fn get_sector_statistics(pool: &PgPool) -> Result<HashMap<String, usiz
 /// 네이버 Fundamental 동기화 옵션
 #[derive(Debug, Default)]
 pub struct NaverSyncOptions {
-    /// 요청 간 딜레이 (ms)
+    /// 요청 간 딜레이 (ms). 동시 크롤링 전체에 걸친 토큰 보충 주기로 사용
     pub request_delay_ms: u64,
     /// 배치 크기 (None이면 전체)
     pub batch_size: Option<i64>,
@@ -304,6 +307,8 @@ pub struct NaverSyncOptions {
     pub force: bool,
     /// 동시 크롤링 수 (기본 3)
     pub concurrent_limit: Option<usize>,
+    /// 재개 시 이전 시작 시각 유지 (진행률/ETA를 최초 실행 기준으로 누적)
+    pub keep_started_at: bool,
 }
 
 pub async fn sync_naver_fundamentals(
@@ -319,6 +324,7 @@ pub async fn sync_naver_fundamentals(
         stale_hours: None,
         force: false, // 기본: 기존 값 보존
         concurrent_limit: None,
+        keep_started_at: false,
     };
     sync_naver_fundamentals_with_options(pool, options).await
 }
@@ -327,6 +333,7 @@ pub async fn sync_naver_fundamentals(
 ///
 /// - `resume`: true면 이전 중단점부터 재개
 /// - `stale_hours`: 지정 시 해당 시간 이내 업데이트된 심볼 스킵
+/// - `concurrent_limit`: 최대 동시 크롤링 수. 429 응답 시 자동으로 줄였다가 복구
 pub async fn sync_naver_fundamentals_with_options(
     pool: &PgPool,
     options: NaverSyncOptions,
@@ -408,17 +415,23 @@ pub async fn sync_naver_fundamentals_with_options(
     }
 
     // 시작 상태 저장
-    checkpoint::save_checkpoint(pool, "naver_fundamental", "", 0, CheckpointStatus::Running)
-        .await?;
+    checkpoint::begin_checkpoint(
+        pool,
+        "naver_fundamental",
+        total as i32,
+        options.resume && options.keep_started_at,
+    )
+    .await?;
 
     // 네이버 금융 크롤러 초기화
-    let fetcher = NaverFinanceFetcher::with_delay(Duration::from_millis(options.request_delay_ms));
-    let concurrent_limit = options.concurrent_limit.unwrap_or(3);
+    let request_interval = Duration::from_millis(options.request_delay_ms);
+    let fetcher = NaverFinanceFetcher::with_delay(request_interval);
+    let concurrent_limit = options.concurrent_limit.unwrap_or(3).max(1);
     let force = options.force;
-    let request_delay_ms = options.request_delay_ms;
 
-    // Semaphore 기반 동시성 제한
-    let semaphore = Arc::new(Semaphore::new(concurrent_limit));
+    // 동시성 제한 (429 시 자동 축소) + 토큰 버킷 (전체 요청 속도 제한)
+    let limiter = AdaptiveLimiter::new(concurrent_limit);
+    let bucket = TokenBucket::new(concurrent_limit, request_interval);
 
     info!(
         concurrent_limit = concurrent_limit,
@@ -426,81 +439,51 @@ pub async fn sync_naver_fundamentals_with_options(
         "네이버 동시 크롤링 시작"
     );
 
-    for (idx, (symbol_info_id, ticker)) in symbols.iter().enumerate() {
-        stats.processed += 1;
+    let mut results = stream::iter(symbols.clone().into_iter().enumerate())
+        .map(|(idx, (symbol_info_id, ticker))| {
+            let (fetcher, limiter, bucket) = (&fetcher, &limiter, &bucket);
+            async move {
+                let outcome = crawl_naver_symbol(
+                    pool,
+                    fetcher,
+                    limiter,
+                    bucket,
+                    symbol_info_id,
+                    &ticker,
+                    force,
+                )
+                .await;
+                (idx, outcome)
+            }
+        })
+        .buffer_unordered(concurrent_limit);
 
-        // Semaphore로 동시 실행 수 제한
-        let _permit = semaphore
-            .clone()
-            .acquire_owned()
-            .await
-            .expect("세마포어 획득 실패");
+    // 완료 순서가 뒤섞이므로, 앞에서부터 연속으로 끝난 지점만 체크포인트로 기록
+    let mut watermark = CompletionWatermark::new();
+
+    while let Some((idx, outcome)) = results.next().await {
+        stats.processed += 1;
+        stats.record_naver(&outcome);
+        watermark.complete(idx);
 
-        if (idx + 1) % 100 == 0 || idx + 1 == total {
+        if stats.processed % 100 == 0 || stats.processed == total {
             info!(
-                progress = format!("{}/{}", idx + 1, total),
+                progress = format!("{}/{}", stats.processed, total),
+                concurrency = limiter.current(),
                 "네이버 Fundamental 수집 진행 중"
             );
             // 체크포인트 저장 (100개마다)
-            checkpoint::save_checkpoint(
-                pool,
-                "naver_fundamental",
-                ticker,
-                stats.processed as i32,
-                CheckpointStatus::Running,
-            )
-            .await?;
-        }
-
-        // 네이버 금융에서 데이터 수집
-        match fetcher.fetch_fundamental(ticker).await {
-            Ok(data) => {
-                // DB에 저장 (force 옵션에 따라 기존 값 보존 또는 덮어쓰기)
-                if let Err(e) = upsert_naver_fundamental(pool, *symbol_info_id, &data, force).await
-                {
-                    debug!(ticker = ticker, error = %e, "네이버 데이터 저장 실패");
-                    stats.failed += 1;
-                } else {
-                    // 업데이트된 항목 카운트
-                    if data.per.is_some() || data.pbr.is_some() {
-                        stats.valuation_updated += 1;
-                    }
-                    if data.market_cap.is_some() {
-                        stats.market_cap_updated += 1;
-                    }
-                    if data.sector.is_some() {
-                        stats.sector_updated += 1;
-                    }
-                    if data.week_52_high.is_some() || data.week_52_low.is_some() {
-                        stats.week_52_updated += 1;
-                    }
-                }
-
-                // 시장 타입 업데이트 (KOSPI/KOSDAQ/ETF)
-                if let Err(e) =
-                    update_market_type(pool, *symbol_info_id, &data.market_type.to_string()).await
-                {
-                    debug!(ticker = ticker, error = %e, "시장 타입 업데이트 실패");
-                } else {
-                    stats.market_type_updated += 1;
-                }
-            }
-            Err(e) => {
-                // Rate limit 에러는 경고, 나머지는 debug
-                if matches!(e, trader_data::provider::naver::NaverError::RateLimited) {
-                    warn!(ticker = ticker, "네이버 Rate limit 초과 - 잠시 대기");
-                    tokio::time::sleep(Duration::from_secs(5)).await;
-                } else {
-                    debug!(ticker = ticker, error = %e, "네이버 데이터 수집 실패");
-                }
-                stats.failed += 1;
+            if let Some(done) = watermark.last_contiguous() {
+                checkpoint::save_checkpoint(
+                    pool,
+                    "naver_fundamental",
+                    &symbols[done].1,
+                    stats.processed as i32,
+                    CheckpointStatus::Running,
+                )
+                .await?;
             }
         }
-
-        // 요청 간 딜레이 (마지막 항목이 아닐 때만)
-        if idx + 1 < total {
-            tokio::time::sleep(Duration::from_millis(request_delay_ms)).await;
-        }
     }
 
     // 완료 상태 저장
@@ -527,6 +510,96 @@ pub async fn sync_naver_fundamentals_with_options(
     Ok(stats)
 }
 
+/// 네이버 단일 종목 크롤링 결과.
+#[derive(Debug, Default)]
+struct NaverCrawlOutcome {
+    failed: bool,
+    valuation: bool,
+    market_cap: bool,
+    sector: bool,
+    week_52: bool,
+    market_type: bool,
+}
+
+impl FundamentalSyncStats {
+    /// 네이버 단일 종목 결과를 통계에 반영
+    fn record_naver(&mut self, outcome: &NaverCrawlOutcome) {
+        if outcome.failed {
+            self.failed += 1;
+            return;
+        }
+        self.valuation_updated += outcome.valuation as usize;
+        self.market_cap_updated += outcome.market_cap as usize;
+        self.sector_updated += outcome.sector as usize;
+        self.week_52_updated += outcome.week_52 as usize;
+        self.market_type_updated += outcome.market_type as usize;
+    }
+}
+
+/// 네이버 금융에서 단일 종목을 수집하여 저장.
+///
+/// 실패는 결과에만 기록하고 전파하지 않으므로 다른 종목 수집은 계속됩니다.
+/// 429 응답 시 백오프 후 동시성을 1 낮춥니다.
+async fn crawl_naver_symbol(
+    pool: &PgPool,
+    fetcher: &NaverFinanceFetcher,
+    limiter: &AdaptiveLimiter,
+    bucket: &TokenBucket,
+    symbol_info_id: Uuid,
+    ticker: &str,
+    force: bool,
+) -> NaverCrawlOutcome {
+    let mut outcome = NaverCrawlOutcome::default();
+
+    let Some(permit) = limiter.acquire().await else {
+        outcome.failed = true;
+        return outcome;
+    };
+    bucket.acquire().await;
+
+    let data = match fetcher.fetch_fundamental(ticker).await {
+        Ok(data) => {
+            limiter.record_success(permit);
+            data
+        }
+        Err(NaverError::RateLimited) => {
+            warn!(ticker = ticker, "네이버 Rate limit 초과 - 잠시 대기");
+            // 슬롯을 쥔 채로 대기하여 백오프 동안 동시 요청 수를 줄임
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            limiter.record_rate_limited(permit);
+            outcome.failed = true;
+            return outcome;
+        }
+        Err(e) => {
+            debug!(ticker = ticker, error = %e, "네이버 데이터 수집 실패");
+            limiter.record_failure(permit);
+            outcome.failed = true;
+            return outcome;
+        }
+    };
+
+    // DB에 저장 (force 옵션에 따라 기존 값 보존 또는 덮어쓰기)
+    if let Err(e) = upsert_naver_fundamental(pool, symbol_info_id, &data, force).await {
+        debug!(ticker = ticker, error = %e, "네이버 데이터 저장 실패");
+        outcome.failed = true;
+        return outcome;
+    }
+
+    // 업데이트된 항목 기록
+    outcome.valuation = data.per.is_some() || data.pbr.is_some();
+    outcome.market_cap = data.market_cap.is_some();
+    outcome.sector = data.sector.is_some();
+    outcome.week_52 = data.week_52_high.is_some() || data.week_52_low.is_some();
+
+    // 시장 타입 업데이트 (KOSPI/KOSDAQ/ETF)
+    match update_market_type(pool, symbol_info_id, &data.market_type.to_string()).await {
+        Ok(()) => outcome.market_type = true,
+        Err(e) => debug!(ticker = ticker, error = %e, "시장 타입 업데이트 실패"),
+    }
+
+    outcome
+}
+
 /// 네이버 금융 데이터를 symbol_fundamental 테이블에 저장 (Upsert).
 ///
 /// # Arguments
@@ -826,6 +899,8 @@ pub struct YahooSyncOptions {
     pub market_filter: Option<String>,
     /// 기존 값 강제 덮어쓰기 (기본: false - 기존 값 보존)
     pub force: bool,
+    /// 재개 시 이전 시작 시각 유지 (진행률/ETA를 최초 실행 기준으로 누적)
+    pub keep_started_at: bool,
 }
 
 /// Yahoo Finance를 통한 글로벌 시장 fundamental 데이터 동기화.
@@ -928,8 +1003,13 @@ pub async fn sync_yahoo_fundamentals(
     }
 
     // 시작 상태 저장
-    checkpoint::save_checkpoint(pool, "yahoo_fundamental", "", 0, CheckpointStatus::Running)
-        .await?;
+    checkpoint::begin_checkpoint(
+        pool,
+        "yahoo_fundamental",
+        total as i32,
+        options.resume && options.keep_started_at,
+    )
+    .await?;
 
     // Yahoo Finance 크롤러 초기화
     let fetcher = YahooFundamentalFetcher::with_delay(Duration::from_millis(
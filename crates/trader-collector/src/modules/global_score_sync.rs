@@ -40,6 +40,8 @@ pub struct GlobalScoreSyncOptions {
     pub stale_hours: Option<u32>,
     /// 배치 크기 오버라이드 (None이면 config 기본값 사용, 0이면 제한 없음)
     pub batch_size: Option<i64>,
+    /// 재개 시 이전 시작 시각 유지 (진행률/ETA를 최초 실행 기준으로 누적)
+    pub keep_started_at: bool,
 }
 
 /// Global Score 동기화 실행.
@@ -173,8 +175,13 @@ pub async fn sync_global_scores_with_options(
     stats.total = total;
 
     // 시작 상태 저장
-    checkpoint::save_checkpoint(pool, "global_score_sync", "", 0, CheckpointStatus::Running)
-        .await?;
+    checkpoint::begin_checkpoint(
+        pool,
+        "global_score_sync",
+        total as i32,
+        options.resume && options.keep_started_at,
+    )
+    .await?;
 
     // 공유 리소스를 Arc로 래핑 (동시 접근용)
     let scorer = Arc::new(scorer);
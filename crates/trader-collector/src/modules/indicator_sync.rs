@@ -28,6 +28,8 @@ pub struct IndicatorSyncOptions {
     pub stale_hours: Option<u32>,
     /// 배치 크기 오버라이드 (None이면 config 기본값 사용, 0이면 제한 없음)
     pub batch_size: Option<i64>,
+    /// 재개 시 이전 시작 시각 유지 (진행률/ETA를 최초 실행 기준으로 누적)
+    pub keep_started_at: bool,
 }
 
 /// 분석 지표 동기화 실행.
@@ -159,7 +161,13 @@ pub async fn sync_indicators_with_options(
     stats.total = target_symbols.len();
 
     // 시작 상태 저장
-    checkpoint::save_checkpoint(pool, "indicator_sync", "", 0, CheckpointStatus::Running).await?;
+    checkpoint::begin_checkpoint(
+        pool,
+        "indicator_sync",
+        stats.total as i32,
+        options.resume && options.keep_started_at,
+    )
+    .await?;
 
     for (idx, (symbol_info_id, ticker, market, yahoo_symbol)) in target_symbols.iter().enumerate() {
         // 체크포인트 저장 (100개마다)
@@ -432,6 +440,7 @@ async fn get_candles(
                 close_time: close_time.unwrap_or(open_time),
                 quote_volume: None,
                 num_trades: None,
+                synthetic: false,
             },
         )
         .collect();
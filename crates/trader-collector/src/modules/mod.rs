@@ -1,12 +1,16 @@
 //! 데이터 수집 모듈.
 
 pub mod checkpoint;
+pub mod corporate_action_sync;
+pub mod delisting;
 pub mod fundamental_sync;
 pub mod global_score_sync;
 pub mod indicator_sync;
 pub mod macro_data_sync;
 pub mod market_breadth_sync;
 pub mod ohlcv_collect;
+pub mod priority;
+pub mod rate_limiter;
 pub mod scheduler;
 pub mod screening_refresh;
 pub mod signal_performance_sync;
@@ -15,8 +19,14 @@ pub mod utils;
 pub mod watchlist_helper;
 
 pub use checkpoint::{
-    clear_checkpoint, list_checkpoints, mark_interrupted, CheckpointInfo, CheckpointStatus,
+    begin_checkpoint, clear_checkpoint, list_checkpoints, mark_interrupted, CheckpointInfo,
+    CheckpointStatus,
 };
+pub use corporate_action_sync::{
+    apply_corporate_actions, compute_adjustment_factors, sync_corporate_actions, CorporateAction,
+    CorporateActionKind, CorporateActionSyncOptions,
+};
+pub use delisting::{list_deactivated_symbols, reactivate_symbols, DeactivatedSymbol};
 pub use fundamental_sync::{
     fetch_and_save_naver_fundamental, sync_krx_fundamentals, sync_naver_fundamentals,
     sync_naver_fundamentals_with_options, sync_yahoo_fundamentals, FundamentalSyncStats,
@@ -29,6 +39,9 @@ pub use indicator_sync::{sync_indicators, sync_indicators_with_options, Indicato
 pub use macro_data_sync::{sync_macro_data, sync_macro_data_arc, MacroSyncResult};
 pub use market_breadth_sync::{sync_market_breadth, MarketBreadthSyncResult};
 pub use ohlcv_collect::collect_ohlcv;
+pub use priority::{
+    PriorityReport, PriorityScorer, PriorityTier, ScoredSymbol, SymbolPriorityInput,
+};
 pub use scheduler::{MarketHours, MarketStatus, Scheduler};
 pub use screening_refresh::{
     get_screening_view_stats, refresh_screening_view, refresh_sector_rs_view, ScreeningViewStats,
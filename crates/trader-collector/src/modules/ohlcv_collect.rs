@@ -21,7 +21,7 @@ use rust_decimal::Decimal;
 use sqlx::PgPool;
 use tokio::sync::Semaphore;
 use trader_analytics::{indicators::IndicatorEngine, MarketRegimeCalculator, RouteStateCalculator};
-use trader_core::{CredentialEncryptor, Kline, Timeframe};
+use trader_core::{CredentialEncryptor, Kline, Timeframe, TradingCalendar};
 use trader_data::{
     cache::historical::CachedHistoricalDataProvider, provider::krx_api::KrxApiClient,
 };
@@ -29,6 +29,8 @@ use uuid::Uuid;
 
 use super::{
     checkpoint::{self, CheckpointStatus},
+    delisting,
+    priority::{self, PriorityReport, PriorityScorer},
     utils::{calculate_ttm_squeeze, to_screaming_snake_case},
     watchlist_helper,
 };
@@ -44,6 +46,12 @@ type OhlcvMetadataRow = (
     Option<chrono::DateTime<Utc>>,
 );
 
+/// 갭 후보 조회 결과 타입 (심볼, 직전 캔들 시각, 다음 캔들 시각)
+type OhlcvGapCandidateRow = (String, chrono::DateTime<Utc>, chrono::DateTime<Utc>);
+
+/// 갭 백필 체크포인트 워크플로우 이름
+const GAP_BACKFILL_WORKFLOW: &str = "ohlcv_gap_backfill";
+
 /// 날짜 범위 계산 결과 타입 (앞쪽 구간, 뒤쪽 구간)
 type DateRangeGaps = (
     Option<(NaiveDate, NaiveDate)>,
@@ -313,8 +321,14 @@ pub async fn collect_ohlcv(
         }
     };
 
-    // 관심종목 우선 처리: watchlist 심볼을 앞으로 이동
-    let target_symbols = if config.prioritize_watchlist && symbols.is_none() {
+    // 우선순위 스코어링: 주기가 도래한 종목만 스코어 순으로 수집
+    let mut priority_report: Option<PriorityReport> = None;
+    let target_symbols = if config.collection_priority.enabled && symbols.is_none() {
+        let (ordered, report) = apply_priority_schedule(pool, config, target_symbols).await;
+        priority_report = report;
+        ordered
+    } else if config.prioritize_watchlist && symbols.is_none() {
+        // 관심종목 우선 처리: watchlist 심볼을 앞으로 이동
         match watchlist_helper::fetch_all_priority_tickers(pool).await {
             Ok(wl_tickers) if !wl_tickers.is_empty() => {
                 let wl_set = watchlist_helper::to_hashset(&wl_tickers);
@@ -524,6 +538,18 @@ pub async fn collect_ohlcv(
         target_symbols.len()
     );
 
+    // 갭 탐지 대상 (일봉 수집 시에만): fallback 필터링 전 전체 심볼 보존
+    let gap_scan_targets: Vec<(String, String)> = if config.ohlcv_collect.gap_backfill_enabled
+        && timeframes.iter().any(|tf| tf == "1d" || tf == "d1")
+    {
+        target_symbols
+            .iter()
+            .map(|(_, ticker, market)| (ticker.clone(), market.clone()))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     // 심볼별 수집 (KRX API로 이미 수집된 종목은 fallback 대상에서 제외)
     // 소유권 이전으로 Send 제약 충족 (buffer_unordered용)
     let fallback_symbols: Vec<(Uuid, String, String)> = target_symbols
@@ -567,11 +593,16 @@ pub async fn collect_ohlcv(
     let mut gap_skipped = 0usize;
     let symbols_needing_collection: Vec<_> = fallback_symbols
         .into_iter()
-        .filter(|(_, ticker, _)| {
+        .filter(|(_, ticker, market)| {
             let (existing_start, existing_end) =
                 existing_ranges.get(ticker).copied().unwrap_or((None, None));
-            let (past, future) =
-                calculate_missing_ranges(start_date, end_date, existing_start, existing_end);
+            let (past, future) = calculate_missing_ranges(
+                market,
+                start_date,
+                end_date,
+                existing_start,
+                existing_end,
+            );
 
             // 수집할 것이 없으면 스킵
             if past.is_none() && future.is_none() {
@@ -627,8 +658,13 @@ pub async fn collect_ohlcv(
     let mut progress = ProgressTracker::new(&symbols_needing_collection);
 
     // 체크포인트: 수집 시작
-    let _ =
-        checkpoint::save_checkpoint(pool, "ohlcv_collect", "", 0, CheckpointStatus::Running).await;
+    let _ = checkpoint::begin_checkpoint(
+        pool,
+        "ohlcv_collect",
+        symbols_needing_collection.len() as i32,
+        false,
+    )
+    .await;
 
     for (idx, (symbol_info_id, ticker, market)) in symbols_needing_collection.iter().enumerate() {
         stats.total += 1;
@@ -647,7 +683,7 @@ pub async fn collect_ohlcv(
 
         // 누락 구간 계산
         let (past_range, future_range) =
-            calculate_missing_ranges(start_date, end_date, existing_start, existing_end);
+            calculate_missing_ranges(market, start_date, end_date, existing_start, existing_end);
 
         // 누락 구간이 없으면 스킵 (사전 필터링 후에도 안전 장치)
         if past_range.is_none() && future_range.is_none() {
@@ -671,6 +707,20 @@ pub async fn collect_ohlcv(
             }
         }
 
+        // 최신 데이터가 있어야 하는 요청인지 (상장 이전 과거 구간의 빈 응답은 누락이 아님)
+        let expects_recent = past_range.is_none() || existing_end.is_none();
+        // 데이터가 있어야 할 첫 거래일 (기존 데이터가 없으면 오늘부터 추적)
+        let missing_from = existing_end
+            .map(|e| TradingCalendar::global().next_trading_day(market, e))
+            .unwrap_or(end_date);
+        let no_data_target = NoDataTarget {
+            symbol_info_id: *symbol_info_id,
+            ticker,
+            market,
+            missing_from,
+            as_of: end_date,
+        };
+
         // 수집할 구간 결정 (과거 방향 우선)
         let (fetch_start, fetch_end) = if let Some((ps, pe)) = past_range {
             tracing::info!(
@@ -738,6 +788,10 @@ pub async fn collect_ohlcv(
                 Ok(klines) if !klines.is_empty() => {
                     stats.total_klines += klines.len();
 
+                    if timeframe == Timeframe::D1 {
+                        let _ = delisting::record_data_received(pool, *symbol_info_id).await;
+                    }
+
                     if timeframe == Timeframe::D1 && klines.len() >= 40 {
                         stats.success += 1;
                         update_indicators_for_symbol(
@@ -762,27 +816,37 @@ pub async fn collect_ohlcv(
                 Ok(_) => {
                     if timeframe == Timeframe::D1 {
                         stats.empty += 1;
+                        if expects_recent
+                            && record_no_data(pool, config, &no_data_target, None).await
+                        {
+                            stats.deactivated += 1;
+                            break;
+                        }
                     }
                 }
                 Err(e) => {
                     let error_str = e.to_string();
-                    if timeframe == Timeframe::D1
-                        && (error_str.contains("may be delisted")
-                            || error_str.contains("No data found")
-                            || error_str.contains("empty data set"))
-                    {
+                    if timeframe == Timeframe::D1 {
                         stats.errors += 1;
-                        tracing::warn!(ticker = ticker, "상장폐지 감지 - 자동 비활성화");
-                        let _ = sqlx::query(
-                            "UPDATE symbol_info SET is_active = false, updated_at = NOW() WHERE id = $1"
-                        )
-                        .bind(symbol_info_id)
-                        .execute(pool)
-                        .await;
-                        break;
+                    }
+                    if timeframe == Timeframe::D1 && delisting::is_no_data_error(&error_str) {
+                        // "데이터 없음" 응답: 연속 누락 거래일이 임계값을 넘을 때만 비활성화
+                        tracing::debug!(ticker = ticker, error = %error_str, "데이터 없음 응답");
+                        if expects_recent
+                            && record_no_data(pool, config, &no_data_target, Some(&error_str)).await
+                        {
+                            stats.deactivated += 1;
+                            break;
+                        }
                     } else {
+                        // 네트워크 등 일시적 오류: 누락으로 집계하지 않음
                         if timeframe == Timeframe::D1 {
-                            stats.errors += 1;
+                            let _ = delisting::record_transient_error(
+                                pool,
+                                *symbol_info_id,
+                                &error_str,
+                            )
+                            .await;
                         }
                         tracing::error!(ticker = ticker, timeframe = tf_str, error = %e, "조회 실패");
                     }
@@ -825,10 +889,121 @@ pub async fn collect_ohlcv(
     )
     .await;
 
+    // 중간 갭 탐지 및 백필
+    if !gap_scan_targets.is_empty() {
+        detect_and_backfill_gaps(
+            pool,
+            config,
+            &gap_scan_targets,
+            start_date,
+            &krx_client,
+            &yahoo_provider,
+            &mut stats,
+        )
+        .await;
+    }
+
     stats.elapsed = start.elapsed();
+
+    // 우선순위 적용 시 처리량 영향 측정용 요약 출력
+    if let Some(report) = priority_report {
+        report.log_summary("ohlcv_collect");
+        tracing::info!(
+            due = report.due,
+            deferred = report.deferred,
+            symbols_per_minute = format!("{:.1}", stats.symbols_per_minute()),
+            "우선순위 스케줄링 처리량"
+        );
+    }
+
     Ok(stats)
 }
 
+/// 우선순위 스코어링을 적용하여 수집 대상과 순서를 결정합니다.
+///
+/// 입력 조회에 실패하면 원래 순서를 그대로 반환합니다.
+async fn apply_priority_schedule(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    target_symbols: Vec<(Uuid, String, String)>,
+) -> (Vec<(Uuid, String, String)>, Option<PriorityReport>) {
+    let watchlist = watchlist_helper::fetch_watchlist_tickers(pool)
+        .await
+        .map(|t| watchlist_helper::to_hashset(&t))
+        .unwrap_or_default();
+    let strategy = watchlist_helper::fetch_strategy_watched_tickers(pool)
+        .await
+        .map(|t| watchlist_helper::to_hashset(&t))
+        .unwrap_or_default();
+
+    let inputs =
+        match priority::load_priority_inputs(pool, &target_symbols, &watchlist, &strategy).await {
+            Ok(inputs) => inputs,
+            Err(e) => {
+                tracing::warn!(error = %e, "우선순위 입력 조회 실패 - 기본 순서로 수집");
+                return (target_symbols, None);
+            }
+        };
+
+    let scorer = PriorityScorer::new(config.collection_priority.clone());
+    let schedule = scorer.schedule(inputs, Utc::now());
+    let ordered = schedule
+        .due
+        .into_iter()
+        .map(|s| (s.input.symbol_info_id, s.input.ticker, s.input.market))
+        .collect();
+
+    (ordered, Some(schedule.report))
+}
+
+/// "데이터 없음" 응답을 기록할 심볼과 누락 구간.
+struct NoDataTarget<'a> {
+    symbol_info_id: Uuid,
+    ticker: &'a str,
+    market: &'a str,
+    /// 데이터가 있어야 할 첫 거래일
+    missing_from: NaiveDate,
+    /// 수집 기준일
+    as_of: NaiveDate,
+}
+
+/// "데이터 없음" 응답을 기록하고 상장폐지 의심 시 비활성화합니다.
+///
+/// 비활성화되었으면 true를 반환합니다. DB 오류는 로그만 남기고 false를 반환합니다.
+async fn record_no_data(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    target: &NoDataTarget<'_>,
+    error: Option<&str>,
+) -> bool {
+    match delisting::record_no_data(
+        pool,
+        target.symbol_info_id,
+        target.market,
+        target.missing_from,
+        target.as_of,
+        config.ohlcv_collect.delisting_no_data_trading_days,
+        error,
+    )
+    .await
+    {
+        Ok(deactivated) => {
+            if deactivated {
+                tracing::warn!(
+                    ticker = target.ticker,
+                    market = target.market,
+                    "상장폐지 의심 - 수집 대상에서 제외"
+                );
+            }
+            deactivated
+        }
+        Err(e) => {
+            tracing::warn!(ticker = target.ticker, error = %e, "데이터 누락 기록 실패");
+            false
+        }
+    }
+}
+
 /// 개별 심볼의 지표 계산 및 DB 업데이트 (RouteState, MarketRegime, TTM Squeeze)
 ///
 /// GlobalScore는 별도 워크플로우(global_score_sync)에서 계산합니다.
@@ -1013,6 +1188,7 @@ async fn fetch_kr_klines(
                         close_time: k.date.and_hms_opt(23, 59, 59).unwrap().and_utc(),
                         quote_volume: k.trading_value,
                         num_trades: None,
+                        synthetic: false,
                     })
                     .collect();
 
@@ -1125,17 +1301,25 @@ async fn get_all_existing_ranges(
 /// 증분 수집 구간 계산
 ///
 /// 요청 범위와 기존 데이터 범위를 비교하여 수집해야 할 구간을 반환합니다.
+/// 경계는 시장 거래일 캘린더 기준으로 계산하며, 거래일이 하나도 없는 구간
+/// (주말, 연휴만 남은 경우)은 수집 대상에서 제외합니다.
 ///
 /// # 반환
-/// - `past_range`: 과거 방향 누락 구간 (요청 시작일 ~ 기존 데이터 시작일 - 1일)
-/// - `future_range`: 최신 방향 누락 구간 (기존 데이터 종료일 + 1일 ~ 요청 종료일)
-/// - `gaps`: 중간 갭 (현재 미구현)
+/// - `past_range`: 과거 방향 누락 구간 (요청 시작일 ~ 기존 시작일 직전 거래일)
+/// - `future_range`: 최신 방향 누락 구간 (기존 종료일 다음 거래일 ~ 요청 종료일)
+///
+/// 기존 범위 내부의 중간 갭은 수집 후 `detect_and_backfill_gaps()`에서 처리합니다.
 fn calculate_missing_ranges(
+    market: &str,
     requested_start: NaiveDate,
     requested_end: NaiveDate,
     existing_start: Option<NaiveDate>,
     existing_end: Option<NaiveDate>,
 ) -> DateRangeGaps {
+    let calendar = TradingCalendar::global();
+    let has_trading_day =
+        |start: NaiveDate, end: NaiveDate| calendar.count_trading_days(market, start, end) > 0;
+
     match (existing_start, existing_end) {
         (None, None) => {
             // 데이터 없음 - 전체 구간 수집 필요
@@ -1147,12 +1331,18 @@ fn calculate_missing_ranges(
 
             // 1. 과거 방향 누락 (요청 시작일 < 기존 시작일)
             if requested_start < ex_start {
-                past_range = Some((requested_start, ex_start - chrono::Duration::days(1)));
+                let past_end = calendar.prev_trading_day(market, ex_start);
+                if has_trading_day(requested_start, past_end) {
+                    past_range = Some((requested_start, past_end));
+                }
             }
 
             // 2. 최신 방향 누락 (요청 종료일 > 기존 종료일)
             if requested_end > ex_end {
-                future_range = Some((ex_end + chrono::Duration::days(1), requested_end));
+                let future_start = calendar.next_trading_day(market, ex_end);
+                if has_trading_day(future_start, requested_end) {
+                    future_range = Some((future_start, requested_end));
+                }
             }
 
             (past_range, future_range)
@@ -1161,6 +1351,194 @@ fn calculate_missing_ranges(
     }
 }
 
+// ============================================================================
+// 갭 탐지 및 백필 헬퍼 함수
+// ============================================================================
+
+/// 수집된 일봉 시퀀스의 중간 갭을 탐지하고 누락 구간만 재요청합니다.
+///
+/// 연속된 두 캔들 사이에 거래일이 하나라도 있으면 갭으로 판단하며,
+/// 주말·휴장일만 끼어 있는 구간은 정상 휴장으로 간주합니다.
+/// 갭이 `gap_backfill_max_trading_days`를 초과하면 앞쪽부터 부분 백필하고
+/// 체크포인트에 해당 티커를 기록합니다 (남은 구간은 다음 실행에서 다시 탐지).
+async fn detect_and_backfill_gaps(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    targets: &[(String, String)],
+    start_date: NaiveDate,
+    krx_client: &Option<KrxApiClient>,
+    yahoo_provider: &CachedHistoricalDataProvider,
+    stats: &mut CollectionStats,
+) {
+    let tickers: Vec<String> = targets.iter().map(|(t, _)| t.clone()).collect();
+    let market_by_ticker: HashMap<&str, &str> = targets
+        .iter()
+        .map(|(t, m)| (t.as_str(), m.as_str()))
+        .collect();
+
+    // 1거래일 초과 간격만 후보로 조회 (금→월 주말 간격은 SQL 단계에서 제외)
+    let candidates: Vec<OhlcvGapCandidateRow> = match sqlx::query_as(
+        r#"
+        SELECT symbol, prev_time, open_time
+        FROM (
+            SELECT symbol, open_time,
+                   LAG(open_time) OVER (PARTITION BY symbol ORDER BY open_time) AS prev_time
+            FROM ohlcv
+            WHERE symbol = ANY($1) AND timeframe = '1d' AND open_time >= $2
+        ) seq
+        WHERE prev_time IS NOT NULL
+          AND open_time - prev_time > INTERVAL '1 day'
+          AND NOT (EXTRACT(ISODOW FROM prev_time) = 5 AND open_time - prev_time = INTERVAL '3 days')
+        ORDER BY symbol, prev_time
+        "#,
+    )
+    .bind(&tickers)
+    .bind(start_date)
+    .fetch_all(pool)
+    .await
+    {
+        Ok(rows) => rows,
+        Err(e) => {
+            tracing::warn!(error = %e, "갭 후보 조회 실패 - 갭 백필 건너뜀");
+            return;
+        }
+    };
+
+    let calendar = TradingCalendar::global();
+    let max_trading_days = config.ohlcv_collect.gap_backfill_max_trading_days;
+    let request_delay = config.ohlcv_collect.request_delay();
+    let mut detected = 0usize;
+    let mut partial_tickers: Vec<String> = Vec::new();
+
+    let _ = checkpoint::save_checkpoint(
+        pool,
+        GAP_BACKFILL_WORKFLOW,
+        "",
+        0,
+        CheckpointStatus::Running,
+    )
+    .await;
+
+    for (ticker, prev_time, next_time) in candidates {
+        let Some(market) = market_by_ticker.get(ticker.as_str()).copied() else {
+            continue;
+        };
+        let Some(gap) = missing_trading_range(
+            calendar,
+            market,
+            prev_time.date_naive(),
+            next_time.date_naive(),
+        ) else {
+            continue;
+        };
+        detected += 1;
+
+        let ((fetch_start, fetch_end), partial) =
+            clamp_backfill_range(calendar, market, gap, max_trading_days);
+        if partial {
+            tracing::warn!(
+                ticker = %ticker,
+                gap = format!("{} ~ {}", gap.0, gap.1),
+                backfill = format!("{} ~ {}", fetch_start, fetch_end),
+                max_trading_days,
+                "갭이 소스 제한 초과 - 부분 백필"
+            );
+            partial_tickers.push(ticker.clone());
+        }
+
+        let klines_result = if market == "KR" {
+            fetch_kr_klines(krx_client, yahoo_provider, &ticker, fetch_start, fetch_end).await
+        } else {
+            yahoo_provider
+                .get_klines_range(&ticker, Timeframe::D1, fetch_start, fetch_end)
+                .await
+                .map_err(|e| e.to_string())
+        };
+
+        match klines_result {
+            Ok(klines) if !klines.is_empty() => {
+                stats.gaps_filled += 1;
+                stats.total_klines += klines.len();
+                tracing::info!(
+                    ticker = %ticker,
+                    range = format!("{} ~ {}", fetch_start, fetch_end),
+                    klines = klines.len(),
+                    "갭 백필 완료"
+                );
+            }
+            Ok(_) => {
+                tracing::debug!(
+                    ticker = %ticker,
+                    range = format!("{} ~ {}", fetch_start, fetch_end),
+                    "갭 구간 데이터 없음 (소스 측 누락)"
+                );
+            }
+            Err(e) => {
+                tracing::warn!(ticker = %ticker, error = %e, "갭 백필 조회 실패");
+            }
+        }
+
+        tokio::time::sleep(request_delay).await;
+    }
+
+    // 부분 백필이 있으면 재개 대상으로 기록, 아니면 완료 처리
+    let (last_ticker, status) = match partial_tickers.last() {
+        Some(ticker) => (ticker.as_str(), CheckpointStatus::Interrupted),
+        None => ("", CheckpointStatus::Completed),
+    };
+    let _ = checkpoint::save_checkpoint(
+        pool,
+        GAP_BACKFILL_WORKFLOW,
+        last_ticker,
+        detected as i32,
+        status,
+    )
+    .await;
+
+    tracing::info!(
+        detected,
+        filled = stats.gaps_filled,
+        partial = partial_tickers.len(),
+        "갭 탐지 및 백필 완료"
+    );
+}
+
+/// 연속된 두 캔들 날짜 사이의 누락 거래일 구간을 계산합니다.
+///
+/// 사이에 거래일이 없으면(주말·휴장일만 있는 정상 휴장) `None`을 반환합니다.
+fn missing_trading_range(
+    calendar: &TradingCalendar,
+    market: &str,
+    prev: NaiveDate,
+    next: NaiveDate,
+) -> Option<(NaiveDate, NaiveDate)> {
+    let first_missing = calendar.next_trading_day(market, prev);
+    if first_missing >= next {
+        return None;
+    }
+    Some((first_missing, calendar.prev_trading_day(market, next)))
+}
+
+/// 백필 구간을 소스 제한(최대 거래일 수)에 맞춰 자릅니다.
+///
+/// 두 번째 반환값은 구간이 잘렸는지(부분 백필) 여부입니다.
+/// `max_trading_days`가 0이면 제한 없이 전체 구간을 반환합니다.
+fn clamp_backfill_range(
+    calendar: &TradingCalendar,
+    market: &str,
+    range: (NaiveDate, NaiveDate),
+    max_trading_days: u32,
+) -> ((NaiveDate, NaiveDate), bool) {
+    let (start, end) = range;
+    if max_trading_days == 0
+        || calendar.count_trading_days(market, start, end) <= max_trading_days as usize
+    {
+        return (range, false);
+    }
+    let clamped_end = calendar.add_trading_days(market, start, max_trading_days - 1);
+    ((start, clamped_end), true)
+}
+
 // ============================================================================
 // KRX API 일괄 수집 헬퍼 함수
 // ============================================================================
@@ -1270,3 +1648,59 @@ async fn update_market_info_batch(
 
     Ok(total_affected)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_missing_trading_range_ignores_weekend_and_holiday() {
+        let mut calendar = TradingCalendar::new();
+        calendar.add_holiday("KR", date(2025, 1, 1));
+
+        // 금 → 월: 주말만 끼어 있음
+        assert_eq!(
+            missing_trading_range(&calendar, "KR", date(2025, 1, 3), date(2025, 1, 6)),
+            None
+        );
+        // 화 → 목: 수요일 휴장
+        assert_eq!(
+            missing_trading_range(&calendar, "KR", date(2024, 12, 31), date(2025, 1, 2)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_missing_trading_range_detects_real_gap() {
+        let calendar = TradingCalendar::new();
+
+        // 월 → 다음 주 화: 화~금, 월 누락 (주말 제외)
+        assert_eq!(
+            missing_trading_range(&calendar, "US", date(2025, 1, 6), date(2025, 1, 14)),
+            Some((date(2025, 1, 7), date(2025, 1, 13)))
+        );
+    }
+
+    #[test]
+    fn test_clamp_backfill_range_partial() {
+        let calendar = TradingCalendar::new();
+        let range = (date(2025, 1, 6), date(2025, 1, 17)); // 10거래일
+
+        assert_eq!(
+            clamp_backfill_range(&calendar, "US", range, 0),
+            (range, false)
+        );
+        assert_eq!(
+            clamp_backfill_range(&calendar, "US", range, 10),
+            (range, false)
+        );
+        assert_eq!(
+            clamp_backfill_range(&calendar, "US", range, 3),
+            ((date(2025, 1, 6), date(2025, 1, 8)), true)
+        );
+    }
+}
@@ -0,0 +1,421 @@
+//! 종목별 수집 우선순위 스코어링 모듈.
+//!
+//! Rate limit이 제한된 상황에서 모든 종목을 같은 빈도로 수집하지 않도록,
+//! 다음 요소를 가중합한 우선순위 스코어(0.0 ~ 1.0)를 계산합니다:
+//! - **거래량**: 10일 평균 거래량 (로그 스케일 정규화)
+//! - **관심종목**: `watchlist_item` 등록 여부
+//! - **전략 사용**: `strategy_watched_tickers` 등록 여부
+//! - **신선도**: 마지막 수집 후 경과 시간
+//!
+//! 스코어 등급(Hot/Warm/Cold)마다 수집 주기가 다르며, 주기가 도래한 종목만
+//! 스코어 내림차순으로 수집합니다. 마지막 수집 후 `max_starvation_hours`를 넘긴
+//! 종목은 스코어와 무관하게 최우선 수집하여 기아(starvation)를 방지합니다.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{config::CollectionPriorityConfig, error::CollectorError, Result};
+
+/// 우선순위 등급
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriorityTier {
+    /// 인기 종목 (짧은 주기)
+    Hot,
+    /// 일반 종목
+    Warm,
+    /// 한산한 종목 (긴 주기)
+    Cold,
+}
+
+impl PriorityTier {
+    /// 스코어로부터 등급 결정
+    pub fn from_score(score: f64, config: &CollectionPriorityConfig) -> Self {
+        if score >= config.hot_threshold {
+            Self::Hot
+        } else if score >= config.warm_threshold {
+            Self::Warm
+        } else {
+            Self::Cold
+        }
+    }
+
+    /// 등급별 수집 주기
+    pub fn interval(&self, config: &CollectionPriorityConfig) -> chrono::Duration {
+        let hours = match self {
+            Self::Hot => config.hot_interval_hours,
+            Self::Warm => config.warm_interval_hours,
+            Self::Cold => config.cold_interval_hours,
+        };
+        chrono::Duration::hours(hours)
+    }
+}
+
+/// 스코어 계산 입력 (종목별)
+#[derive(Debug, Clone)]
+pub struct SymbolPriorityInput {
+    /// symbol_info ID
+    pub symbol_info_id: Uuid,
+    /// 종목 코드
+    pub ticker: String,
+    /// 시장 코드
+    pub market: String,
+    /// 10일 평균 거래량
+    pub avg_volume: Option<i64>,
+    /// 관심종목 등록 여부
+    pub in_watchlist: bool,
+    /// 전략 사용 여부
+    pub in_strategy: bool,
+    /// 마지막 수집 시각 (None이면 수집 이력 없음)
+    pub last_collected_at: Option<DateTime<Utc>>,
+}
+
+/// 스코어 계산 결과
+#[derive(Debug, Clone)]
+pub struct ScoredSymbol {
+    /// 원본 입력
+    pub input: SymbolPriorityInput,
+    /// 우선순위 스코어 (0.0 ~ 1.0)
+    pub score: f64,
+    /// 등급
+    pub tier: PriorityTier,
+    /// 이번 회차 수집 대상 여부
+    pub due: bool,
+    /// 기아 방지 임계값 초과 여부
+    pub starved: bool,
+}
+
+/// 우선순위 스케줄링 요약 (처리량 영향 측정용)
+#[derive(Debug, Clone, Default)]
+pub struct PriorityReport {
+    /// Hot 등급 종목 수
+    pub hot: usize,
+    /// Warm 등급 종목 수
+    pub warm: usize,
+    /// Cold 등급 종목 수
+    pub cold: usize,
+    /// 이번 회차 수집 대상 수
+    pub due: usize,
+    /// 주기 미도래로 연기된 종목 수
+    pub deferred: usize,
+    /// 기아 방지로 승격된 종목 수
+    pub starved: usize,
+    /// 수집 대상 평균 스코어
+    pub avg_due_score: f64,
+}
+
+impl PriorityReport {
+    /// 전체 평가 종목 수
+    pub fn total(&self) -> usize {
+        self.due + self.deferred
+    }
+
+    /// 수집 대상 비율 (%)
+    ///
+    /// 우선순위 적용 전(전체 수집) 대비 요청량이 얼마나 줄었는지 나타냅니다.
+    pub fn due_ratio(&self) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            0.0
+        } else {
+            (self.due as f64 / total as f64) * 100.0
+        }
+    }
+
+    /// 요약 로그 출력
+    pub fn log_summary(&self, operation: &str) {
+        tracing::info!(
+            operation = operation,
+            hot = self.hot,
+            warm = self.warm,
+            cold = self.cold,
+            due = self.due,
+            deferred = self.deferred,
+            starved = self.starved,
+            due_ratio = format!("{:.1}%", self.due_ratio()),
+            avg_due_score = format!("{:.3}", self.avg_due_score),
+            "우선순위 스케줄링 적용"
+        );
+    }
+}
+
+/// 우선순위 스케줄링 결과
+#[derive(Debug, Clone, Default)]
+pub struct PrioritySchedule {
+    /// 수집 대상 (기아 종목 → 스코어 내림차순)
+    pub due: Vec<ScoredSymbol>,
+    /// 연기된 종목
+    pub deferred: Vec<ScoredSymbol>,
+    /// 요약
+    pub report: PriorityReport,
+}
+
+/// 수집 우선순위 스코어러
+pub struct PriorityScorer {
+    config: CollectionPriorityConfig,
+}
+
+impl PriorityScorer {
+    /// 새 스코어러 생성
+    pub fn new(config: CollectionPriorityConfig) -> Self {
+        Self { config }
+    }
+
+    /// 가중치 합 (0 이하이면 1.0으로 간주하여 0으로 나누기 방지)
+    fn weight_sum(&self) -> f64 {
+        let sum = self.config.weight_volume
+            + self.config.weight_watchlist
+            + self.config.weight_strategy
+            + self.config.weight_freshness;
+        if sum > 0.0 {
+            sum
+        } else {
+            1.0
+        }
+    }
+
+    /// 거래량 구성요소 (0.0 ~ 1.0), 배치 내 최대 거래량 대비 로그 스케일
+    fn volume_component(avg_volume: Option<i64>, max_volume: i64) -> f64 {
+        match avg_volume {
+            Some(v) if v > 0 && max_volume > 0 => {
+                ((v as f64).ln_1p() / (max_volume as f64).ln_1p()).clamp(0.0, 1.0)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// 신선도 구성요소 (0.0 ~ 1.0), 오래될수록 1.0에 가까움
+    fn freshness_component(&self, last: Option<DateTime<Utc>>, now: DateTime<Utc>) -> f64 {
+        let Some(last) = last else {
+            return 1.0;
+        };
+        let horizon = self.config.cold_interval_hours.max(1) as f64;
+        let elapsed_hours = (now - last).num_minutes().max(0) as f64 / 60.0;
+        (elapsed_hours / horizon).clamp(0.0, 1.0)
+    }
+
+    /// 단일 종목 스코어 계산
+    pub fn score(&self, input: &SymbolPriorityInput, max_volume: i64, now: DateTime<Utc>) -> f64 {
+        let c = &self.config;
+        let flag = |b: bool| if b { 1.0 } else { 0.0 };
+
+        let weighted = c.weight_volume * Self::volume_component(input.avg_volume, max_volume)
+            + c.weight_watchlist * flag(input.in_watchlist)
+            + c.weight_strategy * flag(input.in_strategy)
+            + c.weight_freshness * self.freshness_component(input.last_collected_at, now);
+
+        (weighted / self.weight_sum()).clamp(0.0, 1.0)
+    }
+
+    /// 종목 목록을 평가하여 이번 회차 수집 대상을 결정합니다.
+    pub fn schedule(
+        &self,
+        inputs: Vec<SymbolPriorityInput>,
+        now: DateTime<Utc>,
+    ) -> PrioritySchedule {
+        let max_volume = inputs
+            .iter()
+            .filter_map(|i| i.avg_volume)
+            .max()
+            .unwrap_or(0);
+        let starvation = chrono::Duration::hours(self.config.max_starvation_hours);
+
+        let mut schedule = PrioritySchedule::default();
+
+        for input in inputs {
+            let score = self.score(&input, max_volume, now);
+            let tier = PriorityTier::from_score(score, &self.config);
+            let (due, starved) = match input.last_collected_at {
+                None => (true, false),
+                Some(last) => {
+                    let elapsed = now - last;
+                    let starved = self.config.max_starvation_hours > 0 && elapsed >= starvation;
+                    (starved || elapsed >= tier.interval(&self.config), starved)
+                }
+            };
+
+            match tier {
+                PriorityTier::Hot => schedule.report.hot += 1,
+                PriorityTier::Warm => schedule.report.warm += 1,
+                PriorityTier::Cold => schedule.report.cold += 1,
+            }
+
+            let scored = ScoredSymbol {
+                input,
+                score,
+                tier,
+                due,
+                starved,
+            };
+            if due {
+                if starved {
+                    schedule.report.starved += 1;
+                }
+                schedule.due.push(scored);
+            } else {
+                schedule.deferred.push(scored);
+            }
+        }
+
+        // 기아 종목 우선, 이후 스코어 내림차순 (동점 시 오래된 종목 우선)
+        schedule.due.sort_by(|a, b| {
+            b.starved
+                .cmp(&a.starved)
+                .then_with(|| b.score.total_cmp(&a.score))
+                .then_with(|| a.input.last_collected_at.cmp(&b.input.last_collected_at))
+        });
+
+        schedule.report.due = schedule.due.len();
+        schedule.report.deferred = schedule.deferred.len();
+        schedule.report.avg_due_score = if schedule.due.is_empty() {
+            0.0
+        } else {
+            schedule.due.iter().map(|s| s.score).sum::<f64>() / schedule.due.len() as f64
+        };
+
+        schedule
+    }
+}
+
+/// 스코어 계산 입력 조회 결과 타입 (ticker, 평균 거래량, 마지막 수집 시각)
+type PriorityInputRow = (String, Option<i64>, Option<DateTime<Utc>>);
+
+/// 대상 종목의 스코어 계산 입력을 DB에서 일괄 조회합니다.
+///
+/// - 거래량: `symbol_fundamental.avg_volume_10d`
+/// - 마지막 수집 시각: `ohlcv_metadata.last_updated_at` (일봉 기준)
+/// - 관심종목/전략: `watchlist_helper` 조회 결과
+pub async fn load_priority_inputs(
+    pool: &PgPool,
+    symbols: &[(Uuid, String, String)],
+    watchlist: &HashSet<String>,
+    strategy: &HashSet<String>,
+) -> Result<Vec<SymbolPriorityInput>> {
+    if symbols.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let tickers: Vec<&str> = symbols.iter().map(|(_, t, _)| t.as_str()).collect();
+    let rows: Vec<PriorityInputRow> = sqlx::query_as(
+        r#"
+        SELECT si.ticker, sf.avg_volume_10d, om.last_updated_at
+        FROM symbol_info si
+        LEFT JOIN symbol_fundamental sf ON sf.symbol_info_id = si.id
+        LEFT JOIN ohlcv_metadata om ON om.symbol = si.ticker AND om.timeframe = '1d'
+        WHERE si.ticker = ANY($1)
+        "#,
+    )
+    .bind(&tickers)
+    .fetch_all(pool)
+    .await
+    .map_err(CollectorError::Database)?;
+
+    let meta: std::collections::HashMap<String, (Option<i64>, Option<DateTime<Utc>>)> = rows
+        .into_iter()
+        .map(|(ticker, volume, last)| (ticker, (volume, last)))
+        .collect();
+
+    Ok(symbols
+        .iter()
+        .map(|(id, ticker, market)| {
+            let (avg_volume, last_collected_at) = meta.get(ticker).copied().unwrap_or_default();
+            SymbolPriorityInput {
+                symbol_info_id: *id,
+                ticker: ticker.clone(),
+                market: market.clone(),
+                avg_volume,
+                in_watchlist: watchlist.contains(ticker),
+                in_strategy: strategy.contains(ticker),
+                last_collected_at,
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(
+        ticker: &str,
+        volume: Option<i64>,
+        last_hours_ago: Option<i64>,
+    ) -> SymbolPriorityInput {
+        let now = Utc::now();
+        SymbolPriorityInput {
+            symbol_info_id: Uuid::nil(),
+            ticker: ticker.to_string(),
+            market: "KR".to_string(),
+            avg_volume: volume,
+            in_watchlist: false,
+            in_strategy: false,
+            last_collected_at: last_hours_ago.map(|h| now - chrono::Duration::hours(h)),
+        }
+    }
+
+    #[test]
+    fn test_watchlist_and_volume_raise_score() {
+        let scorer = PriorityScorer::new(CollectionPriorityConfig::default());
+        let now = Utc::now();
+
+        let quiet = input("QUIET", Some(1_000), Some(1));
+        let mut popular = input("HOT", Some(10_000_000), Some(1));
+        popular.in_watchlist = true;
+        popular.in_strategy = true;
+
+        let quiet_score = scorer.score(&quiet, 10_000_000, now);
+        let popular_score = scorer.score(&popular, 10_000_000, now);
+
+        assert!(popular_score > quiet_score);
+        assert_eq!(
+            PriorityTier::from_score(popular_score, &CollectionPriorityConfig::default()),
+            PriorityTier::Hot
+        );
+    }
+
+    #[test]
+    fn test_cold_symbol_deferred_until_interval() {
+        let config = CollectionPriorityConfig::default();
+        let scorer = PriorityScorer::new(config.clone());
+
+        // Cold 등급이며 최근(1시간 전) 수집된 종목은 연기
+        let schedule = scorer.schedule(vec![input("COLD", None, Some(1))], Utc::now());
+        assert_eq!(schedule.report.deferred, 1);
+        assert!(schedule.due.is_empty());
+
+        // Cold 주기를 넘기면 수집 대상
+        let hours = config.cold_interval_hours + 1;
+        let schedule = scorer.schedule(vec![input("COLD", None, Some(hours))], Utc::now());
+        assert_eq!(schedule.report.due, 1);
+    }
+
+    #[test]
+    fn test_starved_symbol_goes_first() {
+        let config = CollectionPriorityConfig {
+            weight_freshness: 0.0,
+            cold_interval_hours: 10_000,
+            max_starvation_hours: 48,
+            ..Default::default()
+        };
+        let scorer = PriorityScorer::new(config);
+
+        let mut hot = input("HOT", Some(1_000_000), None);
+        hot.in_watchlist = true;
+        let starved = input("OLD", None, Some(100));
+
+        let schedule = scorer.schedule(vec![hot, starved], Utc::now());
+        assert_eq!(schedule.report.starved, 1);
+        assert_eq!(schedule.due[0].input.ticker, "OLD");
+        assert_eq!(schedule.due[1].input.ticker, "HOT");
+    }
+
+    #[test]
+    fn test_never_collected_is_due() {
+        let scorer = PriorityScorer::new(CollectionPriorityConfig::default());
+        let schedule = scorer.schedule(vec![input("NEW", None, None)], Utc::now());
+        assert_eq!(schedule.report.due, 1);
+        assert!((schedule.report.due_ratio() - 100.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,296 @@
+//! 외부 데이터 소스 요청 속도 제어.
+//!
+//! 동시 크롤링 시 소스별 rate limit을 넘지 않도록 다음 장치를 제공합니다.
+//!
+//! - [`TokenBucket`]: 요청 간격(`request_delay_ms`)을 토큰 보충 주기로 사용하는 토큰 버킷.
+//!   동시 작업 수와 무관하게 전체 요청 속도를 제한합니다.
+//! - [`AdaptiveLimiter`]: Semaphore 기반 동시성 제한. 429 응답 시 동시성을 1씩 낮추고,
+//!   연속 성공이 누적되면 설정된 최대치까지 다시 올립니다.
+//! - [`CompletionWatermark`]: 병렬 처리 중 "이 지점까지는 모두 처리됨"을 추적해
+//!   체크포인트에 안전한 재개 지점을 기록합니다.
+
+use std::{
+    collections::BTreeSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+use tracing::{info, warn};
+
+/// 동시성 복구에 필요한 연속 성공 횟수
+const RECOVERY_STREAK: usize = 20;
+
+/// 토큰 버킷 상태
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl BucketState {
+    /// 경과 시간만큼 토큰을 보충한 뒤 1개 소비를 시도합니다.
+    ///
+    /// 토큰이 부족하면 다음 토큰까지 남은 시간을 반환합니다.
+    fn try_take(&mut self, now: Instant, capacity: f64, interval: Duration) -> Option<Duration> {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() / interval.as_secs_f64()).min(capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Some(interval.mul_f64(1.0 - self.tokens))
+        }
+    }
+}
+
+/// 요청 간격 기반 토큰 버킷.
+///
+/// `interval`마다 토큰 1개가 보충되며, 최대 `capacity`개까지 모아 둘 수 있습니다.
+/// `interval`이 0이면 제한하지 않습니다.
+#[derive(Debug)]
+pub struct TokenBucket {
+    state: Mutex<BucketState>,
+    capacity: f64,
+    interval: Duration,
+}
+
+impl TokenBucket {
+    /// 새 토큰 버킷 생성 (가득 찬 상태로 시작)
+    pub fn new(capacity: usize, interval: Duration) -> Self {
+        let capacity = capacity.max(1) as f64;
+        Self {
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+            capacity,
+            interval,
+        }
+    }
+
+    /// 토큰 1개를 획득할 때까지 대기
+    pub async fn acquire(&self) {
+        if self.interval.is_zero() {
+            return;
+        }
+        loop {
+            let wait =
+                self.state
+                    .lock()
+                    .await
+                    .try_take(Instant::now(), self.capacity, self.interval);
+            match wait {
+                None => return,
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+}
+
+/// 429 응답에 반응하는 적응형 동시성 제한기.
+#[derive(Debug)]
+pub struct AdaptiveLimiter {
+    semaphore: Arc<Semaphore>,
+    max: usize,
+    current: AtomicUsize,
+    success_streak: AtomicUsize,
+}
+
+impl AdaptiveLimiter {
+    /// 최대 동시성 `max`로 생성 (최소 1)
+    pub fn new(max: usize) -> Self {
+        let max = max.max(1);
+        Self {
+            semaphore: Arc::new(Semaphore::new(max)),
+            max,
+            current: AtomicUsize::new(max),
+            success_streak: AtomicUsize::new(0),
+        }
+    }
+
+    /// 현재 허용 동시성
+    pub fn current(&self) -> usize {
+        self.current.load(Ordering::SeqCst)
+    }
+
+    /// 작업 슬롯 획득
+    pub async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        self.semaphore.clone().acquire_owned().await.ok()
+    }
+
+    /// 성공 기록. 연속 성공이 누적되면 동시성을 1 올립니다.
+    pub fn record_success(&self, permit: OwnedSemaphorePermit) {
+        drop(permit);
+        let streak = self.success_streak.fetch_add(1, Ordering::SeqCst) + 1;
+        if streak < RECOVERY_STREAK {
+            return;
+        }
+        self.success_streak.store(0, Ordering::SeqCst);
+        let raised = self
+            .current
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |c| {
+                (c < self.max).then_some(c + 1)
+            });
+        if let Ok(previous) = raised {
+            self.semaphore.add_permits(1);
+            info!(concurrency = previous + 1, "동시성 복구");
+        }
+    }
+
+    /// 실패 기록 (rate limit 이외). 동시성은 유지합니다.
+    pub fn record_failure(&self, permit: OwnedSemaphorePermit) {
+        drop(permit);
+        self.success_streak.store(0, Ordering::SeqCst);
+    }
+
+    /// rate limit(429) 기록. 사용 중인 슬롯을 반납하지 않고 소멸시켜 동시성을 1 낮춥니다.
+    pub fn record_rate_limited(&self, permit: OwnedSemaphorePermit) {
+        self.success_streak.store(0, Ordering::SeqCst);
+        let lowered = self
+            .current
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |c| {
+                (c > 1).then_some(c - 1)
+            });
+        match lowered {
+            Ok(previous) => {
+                permit.forget();
+                warn!(concurrency = previous - 1, "Rate limit 감지 - 동시성 축소");
+            }
+            Err(_) => drop(permit),
+        }
+    }
+}
+
+/// 병렬 처리 완료 지점 추적기.
+///
+/// 정렬된 작업 목록에서 앞쪽부터 연속으로 완료된 마지막 인덱스를 반환합니다.
+/// 이 지점을 체크포인트로 기록하면 재개 시 미처리 항목을 건너뛰지 않습니다.
+#[derive(Debug, Default)]
+pub struct CompletionWatermark {
+    next: usize,
+    pending: BTreeSet<usize>,
+}
+
+impl CompletionWatermark {
+    /// 새 추적기 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 인덱스 완료 기록
+    pub fn complete(&mut self, index: usize) {
+        if index < self.next {
+            return;
+        }
+        self.pending.insert(index);
+        while self.pending.remove(&self.next) {
+            self.next += 1;
+        }
+    }
+
+    /// 앞쪽부터 연속으로 완료된 마지막 인덱스
+    pub fn last_contiguous(&self) -> Option<usize> {
+        self.next.checked_sub(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_refills_by_interval() {
+        let start = Instant::now();
+        let interval = Duration::from_millis(100);
+        let mut state = BucketState {
+            tokens: 2.0,
+            last_refill: start,
+        };
+
+        // 버스트: 용량만큼 즉시 소비
+        assert_eq!(state.try_take(start, 2.0, interval), None);
+        assert_eq!(state.try_take(start, 2.0, interval), None);
+
+        // 고갈 후 다음 토큰까지 대기 시간 반환
+        let wait = state.try_take(start, 2.0, interval).unwrap();
+        assert_eq!(wait, interval);
+
+        // 절반 경과 → 절반 남음
+        let wait = state
+            .try_take(start + Duration::from_millis(50), 2.0, interval)
+            .unwrap();
+        assert_eq!(wait.as_millis(), 50);
+
+        // 한 주기 경과 → 1개 획득
+        assert_eq!(
+            state.try_take(start + Duration::from_millis(100), 2.0, interval),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bucket_caps_at_capacity() {
+        let start = Instant::now();
+        let interval = Duration::from_millis(100);
+        let mut state = BucketState {
+            tokens: 0.0,
+            last_refill: start,
+        };
+
+        // 오래 쉬어도 용량 이상 쌓이지 않음
+        let later = start + Duration::from_secs(10);
+        assert_eq!(state.try_take(later, 3.0, interval), None);
+        assert_eq!(state.tokens, 2.0);
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_limiter_lowers_and_recovers() {
+        let limiter = AdaptiveLimiter::new(3);
+        assert_eq!(limiter.current(), 3);
+
+        let permit = limiter.acquire().await.unwrap();
+        limiter.record_rate_limited(permit);
+        assert_eq!(limiter.current(), 2);
+        assert_eq!(limiter.semaphore.available_permits(), 2);
+
+        // 최소 1 유지
+        for _ in 0..3 {
+            let permit = limiter.acquire().await.unwrap();
+            limiter.record_rate_limited(permit);
+        }
+        assert_eq!(limiter.current(), 1);
+        assert_eq!(limiter.semaphore.available_permits(), 1);
+
+        // 연속 성공으로 복구 (최대치 초과 없음)
+        for _ in 0..(RECOVERY_STREAK * 5) {
+            let permit = limiter.acquire().await.unwrap();
+            limiter.record_success(permit);
+        }
+        assert_eq!(limiter.current(), 3);
+        assert_eq!(limiter.semaphore.available_permits(), 3);
+    }
+
+    #[test]
+    fn test_watermark_tracks_contiguous_completion() {
+        let mut watermark = CompletionWatermark::new();
+        assert_eq!(watermark.last_contiguous(), None);
+
+        watermark.complete(1);
+        watermark.complete(2);
+        assert_eq!(watermark.last_contiguous(), None);
+
+        watermark.complete(0);
+        assert_eq!(watermark.last_contiguous(), Some(2));
+
+        watermark.complete(4);
+        assert_eq!(watermark.last_contiguous(), Some(2));
+        watermark.complete(3);
+        assert_eq!(watermark.last_contiguous(), Some(4));
+    }
+}
@@ -1,12 +1,15 @@
 //! 시장 운영 시간 기반 스케줄러.
 //!
 //! 각 시장의 운영 시간을 고려하여 워크플로우 실행 시점을 결정합니다.
+//!
+//! 개장/폐장 시각은 현지 시간으로 관리하므로 미국 서머타임(DST) 전환 시
+//! UTC 기준 시각이 자동으로 달라집니다. 반일장(조기 폐장일)은 캘린더의
+//! 조기 마감 시각을 적용합니다.
 
-use std::collections::HashSet;
-
-use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Timelike, Utc, Weekday};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
 use chrono_tz::Tz;
 use tracing::{debug, info};
+use trader_core::TradingCalendar;
 
 use crate::config::SchedulingConfig;
 
@@ -70,8 +73,8 @@ pub enum MarketStatus {
 pub struct Scheduler {
     /// 시장별 운영 시간
     markets: Vec<MarketHours>,
-    /// 공휴일 목록 (시장코드:날짜)
-    holidays: HashSet<String>,
+    /// 거래일 캘린더 (trader-core 내장 휴장일 + 추가 휴장일)
+    calendar: TradingCalendar,
     /// 설정
     config: SchedulingConfig,
     /// 마지막 일일 워크플로우 실행 날짜 (시장코드별)
@@ -80,76 +83,20 @@ pub struct Scheduler {
 
 impl Scheduler {
     /// 새 스케줄러 생성
+    ///
+    /// 전역 캘린더(내장 휴장일 + 규칙 기반 US/JP 휴장일)를 복사해 사용합니다.
     pub fn new(config: &SchedulingConfig) -> Self {
-        let markets = vec![MarketHours::krx(), MarketHours::us(), MarketHours::jp()];
-
         Self {
-            markets,
-            holidays: HashSet::new(),
+            markets: vec![MarketHours::krx(), MarketHours::us(), MarketHours::jp()],
+            calendar: TradingCalendar::global().clone(),
             config: config.clone(),
             last_daily_run: std::collections::HashMap::new(),
         }
     }
 
-    /// 공휴일 추가 (형식: "KR:2024-01-01")
+    /// 공휴일 추가 (내장 캘린더에 없는 임시 휴장일 등)
     pub fn add_holiday(&mut self, market: &str, date: NaiveDate) {
-        let key = format!("{}:{}", market, date);
-        self.holidays.insert(key);
-    }
-
-    /// 2025년 한국 공휴일 로드
-    pub fn load_kr_holidays_2025(&mut self) {
-        let holidays = [
-            "2025-01-01", // 신정
-            "2025-01-28", // 설날 연휴
-            "2025-01-29", // 설날
-            "2025-01-30", // 설날 연휴
-            "2025-03-01", // 삼일절
-            "2025-05-05", // 어린이날
-            "2025-05-06", // 부처님오신날
-            "2025-06-06", // 현충일
-            "2025-08-15", // 광복절
-            "2025-10-03", // 개천절
-            "2025-10-05", // 추석 연휴
-            "2025-10-06", // 추석
-            "2025-10-07", // 추석 연휴
-            "2025-10-09", // 한글날
-            "2025-12-25", // 크리스마스
-        ];
-
-        for date_str in holidays {
-            if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-                self.add_holiday("KR", date);
-            }
-        }
-    }
-
-    /// 2026년 한국 공휴일 로드
-    pub fn load_kr_holidays_2026(&mut self) {
-        let holidays = [
-            "2026-01-01", // 신정
-            "2026-02-16", // 설날 연휴
-            "2026-02-17", // 설날
-            "2026-02-18", // 설날 연휴
-            "2026-03-01", // 삼일절 (일요일)
-            "2026-03-02", // 대체공휴일
-            "2026-05-05", // 어린이날
-            "2026-05-24", // 부처님오신날
-            "2026-06-06", // 현충일
-            "2026-08-15", // 광복절
-            "2026-09-24", // 추석 연휴
-            "2026-09-25", // 추석
-            "2026-09-26", // 추석 연휴
-            "2026-10-03", // 개천절
-            "2026-10-09", // 한글날
-            "2026-12-25", // 크리스마스
-        ];
-
-        for date_str in holidays {
-            if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-                self.add_holiday("KR", date);
-            }
-        }
+        self.calendar.add_holiday(market, date);
     }
 
     /// 특정 시장의 운영 시간 조회
@@ -164,8 +111,25 @@ impl Scheduler {
 
     /// 공휴일 여부 확인
     pub fn is_holiday(&self, market: &str, date: NaiveDate) -> bool {
-        let key = format!("{}:{}", market, date);
-        self.holidays.contains(&key)
+        self.calendar.is_holiday(market, date)
+    }
+
+    /// 해당 날짜의 장 마감 시각 (현지 시간, 반일장이면 조기 마감 시각)
+    pub fn close_time_on(&self, market_hours: &MarketHours, date: NaiveDate) -> NaiveTime {
+        self.calendar
+            .early_close(&market_hours.market, date)
+            .map_or(market_hours.close_time, |early| {
+                early.min(market_hours.close_time)
+            })
+    }
+
+    /// 장 마감 후 워크플로우 실행까지 대기 시간 (분)
+    fn delay_after_close_minutes(&self, market: &str) -> u32 {
+        if market == "KR" {
+            self.config.krx_delay_after_close_minutes
+        } else {
+            60 // 기타 시장 기본값
+        }
     }
 
     /// 시장 상태 조회
@@ -190,9 +154,9 @@ impl Scheduler {
             return MarketStatus::Holiday;
         }
 
-        // 장 운영 시간 체크
-        if local_naive_time >= market_hours.open_time && local_naive_time < market_hours.close_time
-        {
+        // 장 운영 시간 체크 (반일장은 조기 마감 시각 적용)
+        let close_time = self.close_time_on(market_hours, local_date);
+        if local_naive_time >= market_hours.open_time && local_naive_time < close_time {
             MarketStatus::Open
         } else {
             MarketStatus::Closed
@@ -224,15 +188,10 @@ impl Scheduler {
             return false;
         }
 
-        // 장 마감 후 대기 시간 계산
-        let delay_minutes = if market == "KR" {
-            self.config.krx_delay_after_close_minutes
-        } else {
-            60 // 기타 시장 기본값
-        };
-
-        let earliest_run_time =
-            market_hours.close_time + chrono::Duration::minutes(delay_minutes as i64);
+        // 장 마감 후 대기 시간 계산 (반일장은 조기 마감 기준)
+        let delay_minutes = self.delay_after_close_minutes(market);
+        let earliest_run_time = self.close_time_on(market_hours, local_date)
+            + chrono::Duration::minutes(delay_minutes as i64);
 
         // 마감 후 대기 시간이 지났는지 확인
         if local_naive_time < earliest_run_time {
@@ -259,34 +218,30 @@ impl Scheduler {
     }
 
     /// 다음 실행 시간까지 대기해야 하는 시간 (초)
+    ///
+    /// 목표 시각을 현지 날짜·시간으로 구성한 뒤 UTC로 변환하여 차이를 계산하므로,
+    /// DST 전환일을 넘어가는 경우에도 실제 경과 초를 반환합니다.
     pub fn seconds_until_next_run(&self, market: &str, now: DateTime<Utc>) -> Option<i64> {
         let market_hours = self.get_market_hours(market)?;
-
-        let local_time = now.with_timezone(&market_hours.timezone);
-        let local_naive_time = local_time.time();
-
-        let delay_minutes = if market == "KR" {
-            self.config.krx_delay_after_close_minutes
-        } else {
-            60
-        };
-
-        let target_time = market_hours.close_time + chrono::Duration::minutes(delay_minutes as i64);
-
-        if local_naive_time < target_time {
-            // 오늘 실행 예정
-            let diff = target_time.signed_duration_since(local_naive_time);
-            Some(diff.num_seconds())
-        } else {
-            // 내일 실행
-            // 대략적 계산 (24시간 - 현재 시간 + 마감 후 시간)
-            let seconds_to_midnight = NaiveTime::from_hms_opt(23, 59, 59)
-                .unwrap()
-                .signed_duration_since(local_naive_time)
+        let delay = chrono::Duration::minutes(self.delay_after_close_minutes(market) as i64);
+        let local_date = now.with_timezone(&market_hours.timezone).date_naive();
+
+        // 오늘 목표 시각이 지났으면 내일 목표 시각
+        for date in [local_date, local_date.succ_opt()?] {
+            let target_local = date.and_time(self.close_time_on(market_hours, date)) + delay;
+            let target = market_hours
+                .timezone
+                .from_local_datetime(&target_local)
+                .earliest()?;
+            let seconds = target
+                .with_timezone(&Utc)
+                .signed_duration_since(now)
                 .num_seconds();
-            let seconds_from_midnight = target_time.num_seconds_from_midnight() as i64;
-            Some(seconds_to_midnight + seconds_from_midnight)
+            if seconds > 0 {
+                return Some(seconds);
+            }
         }
+        None
     }
 
     /// 스케줄러 상태 요약
@@ -311,8 +266,23 @@ impl Scheduler {
 
 #[cfg(test)]
 mod tests {
+    use chrono::Timelike;
+
     use super::*;
 
+    fn test_config() -> SchedulingConfig {
+        SchedulingConfig {
+            enabled: true,
+            krx_delay_after_close_minutes: 60,
+            skip_weekends: true,
+            skip_holidays: true,
+        }
+    }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
     #[test]
     fn test_weekend_check() {
         let saturday = NaiveDate::from_ymd_opt(2025, 1, 4).unwrap(); // 토요일
@@ -331,13 +301,16 @@ mod tests {
             skip_holidays: true,
         };
         let mut scheduler = Scheduler::new(&config);
-        scheduler.load_kr_holidays_2025();
 
         let new_year = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
         let regular_day = NaiveDate::from_ymd_opt(2025, 1, 2).unwrap();
 
         assert!(scheduler.is_holiday("KR", new_year));
         assert!(!scheduler.is_holiday("KR", regular_day));
+
+        // 임시 휴장일 추가
+        scheduler.add_holiday("KR", regular_day);
+        assert!(scheduler.is_holiday("KR", regular_day));
     }
 
     #[test]
@@ -348,4 +321,89 @@ mod tests {
         assert_eq!(krx.close_time.hour(), 15);
         assert_eq!(krx.close_time.minute(), 30);
     }
+
+    #[test]
+    fn test_us_market_status_follows_dst() {
+        let scheduler = Scheduler::new(&test_config());
+
+        // 겨울(EST, UTC-5): 09:30 개장 = 14:30 UTC
+        assert_eq!(
+            scheduler.get_market_status("US", utc(2025, 1, 8, 14, 15)),
+            MarketStatus::Closed
+        );
+        assert_eq!(
+            scheduler.get_market_status("US", utc(2025, 1, 8, 14, 45)),
+            MarketStatus::Open
+        );
+
+        // 여름(EDT, UTC-4): 09:30 개장 = 13:30 UTC
+        assert_eq!(
+            scheduler.get_market_status("US", utc(2025, 7, 8, 13, 45)),
+            MarketStatus::Open
+        );
+        assert_eq!(
+            scheduler.get_market_status("US", utc(2025, 7, 8, 20, 15)),
+            MarketStatus::Closed
+        );
+    }
+
+    #[test]
+    fn test_us_half_day_closes_early() {
+        let scheduler = Scheduler::new(&test_config());
+
+        // 2025-11-28 (추수감사절 다음날): 13:00 EST 조기 폐장 = 18:00 UTC
+        assert_eq!(
+            scheduler.get_market_status("US", utc(2025, 11, 28, 17, 30)),
+            MarketStatus::Open
+        );
+        assert_eq!(
+            scheduler.get_market_status("US", utc(2025, 11, 28, 18, 30)),
+            MarketStatus::Closed
+        );
+    }
+
+    #[test]
+    fn test_global_calendar_provides_rule_based_holidays() {
+        let scheduler = Scheduler::new(&test_config());
+
+        // 내장 데이터가 없는 JP 휴장일도 전역 캘린더에서 채워짐
+        let this_year = Utc::now().year();
+        assert!(scheduler.calendar.has_holiday_data("JP", this_year));
+        assert!(scheduler.calendar.has_holiday_data("US", this_year + 1));
+    }
+
+    #[test]
+    fn test_seconds_until_next_run_across_dst_start() {
+        let scheduler = Scheduler::new(&test_config());
+
+        // 2025-03-08 18:00 EST (토) → 2025-03-09 17:00 EDT (DST 시작일)
+        // 현지 시각 차이는 23시간이지만 실제 경과는 22시간
+        assert_eq!(
+            scheduler.seconds_until_next_run("US", utc(2025, 3, 8, 23, 0)),
+            Some(22 * 3600)
+        );
+    }
+
+    #[test]
+    fn test_seconds_until_next_run_across_dst_end() {
+        let scheduler = Scheduler::new(&test_config());
+
+        // 2025-11-01 18:00 EDT (토) → 2025-11-02 17:00 EST (DST 종료일)
+        // 현지 시각 차이는 23시간이지만 실제 경과는 24시간
+        assert_eq!(
+            scheduler.seconds_until_next_run("US", utc(2025, 11, 1, 22, 0)),
+            Some(24 * 3600)
+        );
+    }
+
+    #[test]
+    fn test_seconds_until_next_run_same_day() {
+        let scheduler = Scheduler::new(&test_config());
+
+        // KR 15:30 마감 + 60분 = 16:30 KST = 07:30 UTC
+        assert_eq!(
+            scheduler.seconds_until_next_run("KR", utc(2025, 1, 8, 6, 0)),
+            Some(90 * 60)
+        );
+    }
 }
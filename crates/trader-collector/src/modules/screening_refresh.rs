@@ -52,6 +52,8 @@ pub async fn refresh_screening_view(pool: &PgPool) -> Result<CollectionStats> {
                 skipped: 0,
                 empty: 0,
                 total_klines: 0,
+                gaps_filled: 0,
+                deactivated: 0,
                 elapsed,
             })
         }
@@ -66,6 +68,8 @@ pub async fn refresh_screening_view(pool: &PgPool) -> Result<CollectionStats> {
                     skipped: 1,
                     empty: 0,
                     total_klines: 0,
+                    gaps_filled: 0,
+                    deactivated: 0,
                     elapsed,
                 })
             } else {
@@ -113,6 +117,8 @@ pub async fn refresh_sector_rs_view(pool: &PgPool) -> Result<CollectionStats> {
                 skipped: 0,
                 empty: 0,
                 total_klines: 0,
+                gaps_filled: 0,
+                deactivated: 0,
                 elapsed,
             })
         }
@@ -126,6 +132,8 @@ pub async fn refresh_sector_rs_view(pool: &PgPool) -> Result<CollectionStats> {
                     skipped: 1,
                     empty: 0,
                     total_klines: 0,
+                    gaps_filled: 0,
+                    deactivated: 0,
                     elapsed,
                 })
             } else {
@@ -1,6 +1,6 @@
 //! 신호 성과 동기화 모듈.
 //!
-//! signal_marker 테이블의 신호에 대해 N일 후 수익률을 계산하여
+//! signal_marker 테이블의 신호에 대해 N거래일 후 수익률을 계산하여
 //! signal_performance 테이블에 저장합니다.
 
 use std::time::Instant;
@@ -10,6 +10,7 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use sqlx::PgPool;
 use tracing::{debug, info, warn};
+use trader_core::add_trading_days;
 use uuid::Uuid;
 
 use crate::{
@@ -68,6 +69,8 @@ struct PendingSignal {
     id: Uuid,
     symbol_id: Uuid,
     ticker: String,
+    /// 시장 코드 (KR, US 등) - 거래일 계산용
+    market: String,
     timestamp: DateTime<Utc>,
     signal_type: String,
     side: Option<String>,
@@ -148,6 +151,7 @@ async fn get_pending_signals(
             Uuid,
             Uuid,
             String,
+            String,
             DateTime<Utc>,
             String,
             Option<String>,
@@ -161,6 +165,7 @@ async fn get_pending_signals(
             sm.id,
             sm.symbol_id,
             si.ticker,
+            si.market,
             sm.timestamp,
             sm.signal_type,
             sm.side,
@@ -190,6 +195,7 @@ async fn get_pending_signals(
                 id,
                 symbol_id,
                 ticker,
+                market,
                 timestamp,
                 signal_type,
                 side,
@@ -201,6 +207,7 @@ async fn get_pending_signals(
                     id,
                     symbol_id,
                     ticker,
+                    market,
                     timestamp,
                     signal_type,
                     side,
@@ -215,8 +222,9 @@ async fn get_pending_signals(
 
 /// 단일 신호에 대해 성과 계산 및 저장.
 ///
-/// 단일 쿼리로 1/3/5/10/20일 후 가격 + MFE/MAE를 한 번에 조회하여
+/// 단일 쿼리로 1/3/5/10/20거래일 후 가격 + MFE/MAE를 한 번에 조회하여
 /// DB 왕복을 최소화합니다 (기존 7회 → 1회).
+/// 목표일은 trader-core 거래일 캘린더 기준으로 계산합니다.
 async fn calculate_and_save_performance(
     pool: &PgPool,
     signal: &PendingSignal,
@@ -225,25 +233,27 @@ async fn calculate_and_save_performance(
     let signal_price = signal.price;
     let side = signal.side.as_deref().unwrap_or("Buy");
 
+    // N거래일 후 목표일 (시장 휴장일/주말 제외)
     let signal_date = signal.timestamp.date_naive();
-    let target_1d = (signal.timestamp + Duration::days(1)).date_naive();
-    let target_3d = (signal.timestamp + Duration::days(3)).date_naive();
-    let target_5d = (signal.timestamp + Duration::days(5)).date_naive();
-    let target_10d = (signal.timestamp + Duration::days(10)).date_naive();
-    let target_20d = (signal.timestamp + Duration::days(20)).date_naive();
-    let mfe_end = (signal.timestamp + Duration::days(max_days as i64)).date_naive();
+    let target_date = |n: u32| add_trading_days(&signal.market, signal_date, n);
+    let target_1d = target_date(1);
+    let target_3d = target_date(3);
+    let target_5d = target_date(5);
+    let target_10d = target_date(10);
+    let target_20d = target_date(20);
+    let mfe_end = target_date(max_days);
 
     // 단일 쿼리로 N일 후 가격 + MFE/MAE 한번에 조회
     let row: Option<SignalPerformanceRow> = sqlx::query_as(
         r#"
         SELECT
-            -- N일 후 종가 (각 목표일 이후 첫 거래일 기준)
+            -- N거래일 후 종가 (목표일에 데이터가 없으면 이후 첫 캔들 기준)
             (SELECT close FROM ohlcv WHERE symbol = $1 AND timeframe = '1d' AND open_time::date >= $3 ORDER BY open_time LIMIT 1) as price_1d,
             (SELECT close FROM ohlcv WHERE symbol = $1 AND timeframe = '1d' AND open_time::date >= $4 ORDER BY open_time LIMIT 1) as price_3d,
             (SELECT close FROM ohlcv WHERE symbol = $1 AND timeframe = '1d' AND open_time::date >= $5 ORDER BY open_time LIMIT 1) as price_5d,
             (SELECT close FROM ohlcv WHERE symbol = $1 AND timeframe = '1d' AND open_time::date >= $6 ORDER BY open_time LIMIT 1) as price_10d,
             (SELECT close FROM ohlcv WHERE symbol = $1 AND timeframe = '1d' AND open_time::date >= $7 ORDER BY open_time LIMIT 1) as price_20d,
-            -- MFE/MAE용 고가/저가 (신호일 다음날 ~ max_days일 이내)
+            -- MFE/MAE용 고가/저가 (신호일 다음날 ~ max_days거래일 이내)
             (SELECT MAX(high) FROM ohlcv WHERE symbol = $1 AND timeframe = '1d' AND open_time::date > $2 AND open_time::date <= $8) as max_high,
             (SELECT MIN(low) FROM ohlcv WHERE symbol = $1 AND timeframe = '1d' AND open_time::date > $2 AND open_time::date <= $8) as min_low
         "#,
@@ -19,6 +19,12 @@ pub struct CollectionStats {
     pub empty: usize,
     /// 저장된 총 캔들 수
     pub total_klines: usize,
+    /// 백필로 채운 갭(누락 구간) 수
+    #[serde(default)]
+    pub gaps_filled: usize,
+    /// 상장폐지 의심으로 비활성화된 심볼 수
+    #[serde(default)]
+    pub deactivated: usize,
     /// 소요 시간
     #[serde(skip)]
     pub elapsed: Duration,
@@ -43,6 +49,16 @@ impl CollectionStats {
         }
     }
 
+    /// 분당 처리 심볼 수 (처리량)
+    pub fn symbols_per_minute(&self) -> f64 {
+        let minutes = self.elapsed.as_secs_f64() / 60.0;
+        if minutes <= 0.0 {
+            0.0
+        } else {
+            self.total as f64 / minutes
+        }
+    }
+
     /// 통계 요약 로그 출력
     pub fn log_summary(&self, operation: &str) {
         tracing::info!(
@@ -53,6 +69,8 @@ impl CollectionStats {
             skipped = self.skipped,
             empty = self.empty,
             total_klines = self.total_klines,
+            gaps_filled = self.gaps_filled,
+            deactivated = self.deactivated,
             success_rate = format!("{:.1}%", self.success_rate()),
             elapsed = format!("{:.1}s", self.elapsed.as_secs_f64()),
             "수집 완료"
@@ -74,6 +74,169 @@ pub struct GlobalScoreResult {
     pub timestamp: DateTime<Utc>,
 }
 
+/// GlobalScore 구성요소 분류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreComponentGroup {
+    /// 기술적 지표 (변동성, 이격도)
+    Technical,
+    /// 펀더멘털 기반 가격 (목표가, 손절가, 추천 진입가)
+    Fundamental,
+    /// 모멘텀 (RSI, MACD, ERS)
+    Momentum,
+    /// 유동성 (거래대금 퍼센타일)
+    Liquidity,
+}
+
+/// 결측 구성요소 정규화 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreNormalization {
+    /// 결측 구성요소를 0점으로 처리 (가중치 유지, 최대 도달 점수가 낮아짐)
+    #[default]
+    ZeroFill,
+    /// 결측 구성요소를 제외하고 나머지 가중치 합이 1이 되도록 재조정
+    Reweighted,
+}
+
+/// GlobalScore 구성요소 하나의 점수와 기여도.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreComponent {
+    /// 구성요소 이름 (예: "risk_reward", "momentum")
+    pub name: String,
+    /// 분류
+    pub group: ScoreComponentGroup,
+    /// 구성요소 점수 (0 ~ 100)
+    pub score: Decimal,
+    /// 가중치 (정규화 방식 반영)
+    pub weight: Decimal,
+    /// 종합 점수 기여도 (`score * weight`)
+    pub contribution: Decimal,
+    /// 계산에 없었던 입력 (예: "target_price"), 비어 있으면 완전한 점수
+    pub missing_inputs: Vec<String>,
+    /// 입력이 없어 구성요소 전체가 기본값으로 채워졌는지 여부
+    pub missing: bool,
+}
+
+/// GlobalScore 구성요소 분해 결과.
+///
+/// 종합 점수가 어떤 하위 점수와 가중치로 만들어졌는지 설명합니다.
+/// `overall_score = Σ contribution - penalties` (0 미만은 0)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalScoreBreakdown {
+    /// 종목 티커
+    pub ticker: Option<String>,
+    /// 종합 점수 (0.0 ~ 100.0)
+    pub overall_score: Decimal,
+    /// 구성요소별 점수
+    pub components: Vec<ScoreComponent>,
+    /// 페널티 항목 (이름 → 차감 점수, 양수)
+    pub penalties: HashMap<String, Decimal>,
+    /// 결측 구성요소 정규화 방식
+    pub normalization: ScoreNormalization,
+    /// 계산 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+impl GlobalScoreBreakdown {
+    /// 페널티 합계.
+    pub fn total_penalty(&self) -> Decimal {
+        self.penalties.values().copied().sum()
+    }
+
+    /// 입력이 없어 기본값으로 채워진 구성요소 이름.
+    pub fn missing_components(&self) -> Vec<&str> {
+        self.components
+            .iter()
+            .filter(|c| c.missing)
+            .map(|c| c.name.as_str())
+            .collect()
+    }
+
+    /// 실제 데이터로 계산된 구성요소의 가중치 합 (ZeroFill 기준 최대 도달 비율).
+    pub fn available_weight(&self) -> Decimal {
+        self.components
+            .iter()
+            .filter(|c| !c.missing)
+            .map(|c| c.weight)
+            .sum()
+    }
+
+    /// 분류별 기여도 합계.
+    pub fn group_contributions(&self) -> HashMap<ScoreComponentGroup, Decimal> {
+        let mut groups = HashMap::new();
+        for component in &self.components {
+            *groups.entry(component.group).or_insert(Decimal::ZERO) += component.contribution;
+        }
+        groups
+    }
+
+    /// 결측 구성요소를 제외하고 가중치를 재조정한 분해 결과.
+    ///
+    /// 펀더멘털이 없는 백테스트처럼 일부 구성요소가 비어 있을 때,
+    /// 사용 가능한 구성요소만으로 0~100 범위를 다시 채운 점수를 비교용으로 제공합니다.
+    pub fn reweighted(&self) -> Self {
+        let available = self.available_weight();
+        if self.normalization == ScoreNormalization::Reweighted || available.is_zero() {
+            return self.clone();
+        }
+
+        let components: Vec<ScoreComponent> = self
+            .components
+            .iter()
+            .map(|c| {
+                let weight = if c.missing {
+                    Decimal::ZERO
+                } else {
+                    c.weight / available
+                };
+                ScoreComponent {
+                    weight,
+                    contribution: c.score * weight,
+                    ..c.clone()
+                }
+            })
+            .collect();
+
+        let sum: Decimal = components.iter().map(|c| c.contribution).sum();
+        Self {
+            overall_score: (sum - self.total_penalty()).max(Decimal::ZERO),
+            components,
+            normalization: ScoreNormalization::Reweighted,
+            ..self.clone()
+        }
+    }
+}
+
+impl fmt::Display for GlobalScoreBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} 종합 {:.1}점 (정규화: {:?})",
+            self.ticker.as_deref().unwrap_or("-"),
+            self.overall_score,
+            self.normalization
+        )?;
+        for c in &self.components {
+            write!(
+                f,
+                "  {:<18} {:>5.1} × {:.2} = {:>5.1}",
+                c.name, c.score, c.weight, c.contribution
+            )?;
+            if !c.missing_inputs.is_empty() {
+                write!(
+                    f,
+                    "  [{}: {}]",
+                    if c.missing { "결측" } else { "부분 결측" },
+                    c.missing_inputs.join(", ")
+                )?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "  {:<18} -{:.1}", "penalties", self.total_penalty())
+    }
+}
+
 /// 스크리닝 결과.
 ///
 /// 특정 프리셋을 통과한 종목의 스크리닝 결과를 나타냅니다.
@@ -139,7 +302,7 @@ impl ScreeningPreset {
 /// 구조적 피처.
 ///
 /// "살아있는 횡보"와 "죽은 횡보"를 구분하여 돌파 가능성을 예측합니다.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StructuralFeatures {
     /// 종목 티커
     pub ticker: String,
@@ -245,6 +408,25 @@ pub trait AnalyticsProvider: Send + Sync {
         market_type: MarketType,
     ) -> Result<Vec<GlobalScoreResult>, AnalyticsError>;
 
+    /// Global Score 구성요소 분해 조회 (종목별).
+    ///
+    /// 하위 점수, 가중치, 결측 구성요소를 포함한 점수 근거를 조회합니다.
+    ///
+    /// # Arguments
+    /// * `tickers` - 조회할 종목 티커 목록
+    ///
+    /// # Returns
+    /// ticker -> GlobalScoreBreakdown 매핑
+    async fn fetch_global_score_breakdowns(
+        &self,
+        tickers: &[&str],
+    ) -> Result<HashMap<String, GlobalScoreBreakdown>, AnalyticsError> {
+        let _ = tickers;
+        Err(AnalyticsError::Unsupported(
+            "GlobalScore breakdown".to_string(),
+        ))
+    }
+
     /// RouteState 조회 (종목별).
     ///
     /// 특정 종목들의 경로 상태를 조회합니다.
@@ -316,6 +498,26 @@ pub trait AnalyticsProvider: Send + Sync {
     /// # Returns
     /// 현재 MarketBreadth
     async fn fetch_market_breadth(&self) -> Result<MarketBreadth, AnalyticsError>;
+
+    /// 특정 시각 이후 데이터가 갱신된 종목 조회.
+    ///
+    /// 증분 동기화에 사용됩니다. 갱신 시각을 알 수 없는 구현체는
+    /// 기본적으로 전달받은 종목을 모두 반환합니다 (전체 재조회).
+    ///
+    /// # Arguments
+    /// * `tickers` - 확인할 종목 티커 목록
+    /// * `since` - 마지막 동기화 시각
+    ///
+    /// # Returns
+    /// `since` 이후 갱신된 종목 티커 목록
+    async fn fetch_updated_tickers(
+        &self,
+        tickers: &[&str],
+        since: DateTime<Utc>,
+    ) -> Result<Vec<String>, AnalyticsError> {
+        let _ = since;
+        Ok(tickers.iter().map(|ticker| ticker.to_string()).collect())
+    }
 }
 
 // ================================================================================================
@@ -194,10 +194,16 @@ pub struct StrategyPositionInfo {
     pub unrealized_pnl_pct: Decimal,
     /// 청산가 (레버리지 거래 시)
     pub liquidation_price: Option<Decimal>,
+    /// 포지션 증거금 (레버리지 거래 시)
+    #[serde(default)]
+    pub margin: Option<Decimal>,
     /// 포지션 생성 시각
     pub created_at: DateTime<Utc>,
     /// 마지막 업데이트 시각
     pub updated_at: DateTime<Utc>,
+    /// 현재가를 알 수 없어 진입가로 평가했는지 여부
+    #[serde(default)]
+    pub stale: bool,
 }
 
 impl StrategyPositionInfo {
@@ -213,8 +219,10 @@ impl StrategyPositionInfo {
             unrealized_pnl: Decimal::ZERO,
             unrealized_pnl_pct: Decimal::ZERO,
             liquidation_price: None,
+            margin: None,
             created_at: now,
             updated_at: now,
+            stale: false,
         }
     }
 
@@ -222,6 +230,7 @@ impl StrategyPositionInfo {
     pub fn update_price(&mut self, current_price: Decimal) {
         self.current_price = current_price;
         self.updated_at = Utc::now();
+        self.stale = false;
 
         // 미실현 손익 계산
         let price_diff = match self.side {
@@ -236,6 +245,15 @@ impl StrategyPositionInfo {
                 (self.unrealized_pnl / (self.avg_entry_price * self.quantity)) * Decimal::from(100);
         }
     }
+
+    /// 현재가를 알 수 없는 포지션으로 표시 (진입가 기준 평가, 미실현 손익 0).
+    pub fn mark_stale(mut self) -> Self {
+        self.current_price = self.avg_entry_price;
+        self.unrealized_pnl = Decimal::ZERO;
+        self.unrealized_pnl_pct = Decimal::ZERO;
+        self.stale = true;
+        self
+    }
 }
 
 // =============================================================================
@@ -810,6 +828,102 @@ impl StrategyContext {
         self.last_analytics_sync = Utc::now();
     }
 
+    // =============================================================================
+    // 증분 업데이트 메서드 (변경분 추적)
+    // =============================================================================
+
+    /// Global Score 결과를 교체하고 값이 바뀐 종목을 반환합니다.
+    ///
+    /// 점수/추천/신뢰도가 달라졌거나 새로 추가·제거된 종목이 변경분에 포함됩니다.
+    pub fn merge_global_scores(&mut self, scores: Vec<GlobalScoreResult>) -> HashSet<String> {
+        let mut next = HashMap::new();
+        for score in scores {
+            if let Some(ticker) = score.ticker.clone() {
+                next.insert(ticker, score);
+            }
+        }
+
+        let mut changed: HashSet<String> = self
+            .global_scores
+            .keys()
+            .filter(|ticker| !next.contains_key(*ticker))
+            .cloned()
+            .collect();
+        for (ticker, score) in &next {
+            let same = self.global_scores.get(ticker).is_some_and(|old| {
+                old.overall_score == score.overall_score
+                    && old.recommendation == score.recommendation
+                    && old.confidence == score.confidence
+            });
+            if !same {
+                changed.insert(ticker.clone());
+            }
+        }
+
+        self.global_scores = next;
+        self.last_analytics_sync = Utc::now();
+        changed
+    }
+
+    /// 종목별 분석 결과(RouteState, 피처, MarketRegime)를 병합합니다.
+    ///
+    /// 전달된 종목만 갱신하고 나머지 종목은 유지합니다.
+    /// 값이 실제로 바뀐 종목을 반환합니다 (피처는 계산 시각을 제외하고 비교).
+    pub fn merge_ticker_analytics(
+        &mut self,
+        route_states: HashMap<String, RouteState>,
+        features: HashMap<String, StructuralFeatures>,
+        regimes: HashMap<String, MarketRegime>,
+    ) -> HashSet<String> {
+        let mut changed = HashSet::new();
+
+        for (ticker, state) in route_states {
+            if self.route_states.get(&ticker) != Some(&state) {
+                changed.insert(ticker.clone());
+            }
+            self.route_states.insert(ticker, state);
+        }
+
+        for (ticker, feature) in features {
+            let same = self.structural_features.get(&ticker).is_some_and(|old| {
+                StructuralFeatures {
+                    timestamp: feature.timestamp,
+                    ..old.clone()
+                } == feature
+            });
+            if !same {
+                changed.insert(ticker.clone());
+            }
+            self.structural_features.insert(ticker, feature);
+        }
+
+        for (ticker, regime) in regimes {
+            if self.market_regime.get(&ticker) != Some(&regime) {
+                changed.insert(ticker.clone());
+            }
+            self.market_regime.insert(ticker, regime);
+        }
+
+        self.last_analytics_sync = Utc::now();
+        changed
+    }
+
+    /// 분석 대상에서 빠진 종목의 종목별 분석 결과를 제거합니다.
+    ///
+    /// 실제로 데이터가 있던 종목을 반환합니다.
+    pub fn remove_ticker_analytics(&mut self, tickers: &[String]) -> Vec<String> {
+        let mut removed = Vec::new();
+        for ticker in tickers {
+            let route = self.route_states.remove(ticker).is_some();
+            let features = self.structural_features.remove(ticker).is_some();
+            let regime = self.market_regime.remove(ticker).is_some();
+            if route || features || regime {
+                removed.push(ticker.clone());
+            }
+        }
+        removed
+    }
+
     // =============================================================================
     // 분석 결과 조회 헬퍼
     // =============================================================================
@@ -1098,4 +1212,69 @@ mod tests {
         assert_eq!(valid.len(), 2); // MSFT Entry, AAPL Exit
         assert_eq!(conflicts.len(), 2); // AAPL Entry, GOOG Exit
     }
+
+    #[test]
+    fn test_merge_ticker_analytics_reports_changes() {
+        let mut ctx = StrategyContext::new();
+        let features = StructuralFeatures {
+            ticker: "005930".to_string(),
+            rsi: dec!(55),
+            ..Default::default()
+        };
+
+        let changed = ctx.merge_ticker_analytics(
+            HashMap::from([("005930".to_string(), RouteState::Wait)]),
+            HashMap::from([("005930".to_string(), features.clone())]),
+            HashMap::from([("000660".to_string(), MarketRegime::Correction)]),
+        );
+        assert_eq!(
+            changed,
+            HashSet::from(["005930".to_string(), "000660".to_string()])
+        );
+
+        // 계산 시각만 다른 피처와 동일한 상태는 변경분이 아님
+        let recalculated = StructuralFeatures {
+            timestamp: features.timestamp + chrono::Duration::minutes(1),
+            ..features
+        };
+        let changed = ctx.merge_ticker_analytics(
+            HashMap::from([("005930".to_string(), RouteState::Wait)]),
+            HashMap::from([("005930".to_string(), recalculated)]),
+            HashMap::from([("000660".to_string(), MarketRegime::StrongUptrend)]),
+        );
+        assert_eq!(changed, HashSet::from(["000660".to_string()]));
+
+        // 전달되지 않은 종목은 유지
+        assert_eq!(ctx.get_route_state("005930"), Some(&RouteState::Wait));
+
+        let removed = ctx.remove_ticker_analytics(&["005930".to_string(), "035720".to_string()]);
+        assert_eq!(removed, vec!["005930".to_string()]);
+        assert!(ctx.get_features("005930").is_none());
+    }
+
+    #[test]
+    fn test_merge_global_scores_reports_changes() {
+        let score = |ticker: &str, overall: Decimal| GlobalScoreResult {
+            ticker: Some(ticker.to_string()),
+            market_type: None,
+            overall_score: overall,
+            component_scores: HashMap::new(),
+            recommendation: "HOLD".to_string(),
+            confidence: dec!(0.5),
+            timestamp: Utc::now(),
+        };
+        let mut ctx = StrategyContext::new();
+
+        let changed =
+            ctx.merge_global_scores(vec![score("AAPL", dec!(70)), score("MSFT", dec!(60))]);
+        assert_eq!(changed.len(), 2);
+
+        let changed =
+            ctx.merge_global_scores(vec![score("AAPL", dec!(70)), score("NVDA", dec!(80))]);
+        assert_eq!(
+            changed,
+            HashSet::from(["MSFT".to_string(), "NVDA".to_string()])
+        );
+        assert!(ctx.get_global_score("MSFT").is_none());
+    }
 }
@@ -7,12 +7,21 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use super::{PendingOrder, StrategyAccountInfo, StrategyPositionInfo, Trade};
+use super::{
+    OrderSession, OrderStatus, PendingOrder, StrategyAccountInfo, StrategyPositionInfo,
+    TimeInForce, Trade,
+};
 
 // =============================================================================
 // 요청/응답 타입
 // =============================================================================
 
+/// 체결 내역 페이지 기본 크기
+pub const DEFAULT_EXECUTION_HISTORY_LIMIT: u32 = 100;
+
+/// 체결 내역 페이지 최대 크기
+pub const MAX_EXECUTION_HISTORY_LIMIT: u32 = 500;
+
 /// 체결 내역 조회 요청.
 #[derive(Debug, Clone)]
 pub struct ExecutionHistoryRequest {
@@ -22,8 +31,10 @@ pub struct ExecutionHistoryRequest {
     pub end_date: String,
     /// 매수/매도 구분 (거래소별로 다름, 예: "00"=전체, "01"=매도, "02"=매수)
     pub side: Option<String>,
-    /// 페이지네이션 커서 (거래소별로 다름)
+    /// 페이지네이션 커서 (이전 응답의 `next_cursor`, 호출자에게는 불투명한 문자열)
     pub cursor: Option<String>,
+    /// 페이지 크기 (None이면 [`DEFAULT_EXECUTION_HISTORY_LIMIT`])
+    pub limit: Option<u32>,
 }
 
 impl ExecutionHistoryRequest {
@@ -34,6 +45,7 @@ impl ExecutionHistoryRequest {
             end_date: end_date.into(),
             side: None,
             cursor: None,
+            limit: None,
         }
     }
 
@@ -48,6 +60,73 @@ impl ExecutionHistoryRequest {
         self.cursor = Some(cursor.into());
         self
     }
+
+    /// 페이지 크기 설정.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// 실제 적용할 페이지 크기 (1 ~ [`MAX_EXECUTION_HISTORY_LIMIT`]로 제한).
+    pub fn effective_limit(&self) -> u32 {
+        self.limit
+            .unwrap_or(DEFAULT_EXECUTION_HISTORY_LIMIT)
+            .clamp(1, MAX_EXECUTION_HISTORY_LIMIT)
+    }
+}
+
+/// 체결 내역 복합 커서 (`executed_at` + 체결 ID).
+///
+/// 체결 시각만으로는 같은 타임스탬프의 체결이 여러 건일 때 페이지 경계에서
+/// 누락/중복이 생기므로, 체결 ID를 보조 키로 사용합니다.
+/// 문자열 형식은 `{RFC 3339 체결 시각}|{체결 ID}`입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionCursor {
+    /// 페이지 마지막 체결 시각
+    pub executed_at: DateTime<Utc>,
+    /// 페이지 마지막 체결 ID
+    pub id: String,
+}
+
+impl ExecutionCursor {
+    /// 새 커서 생성.
+    pub fn new(executed_at: DateTime<Utc>, id: impl Into<String>) -> Self {
+        Self {
+            executed_at,
+            id: id.into(),
+        }
+    }
+
+    /// 체결 내역에서 커서 생성 (거래소 체결 ID 사용).
+    pub fn from_trade(trade: &Trade) -> Self {
+        Self::new(trade.executed_at, trade.exchange_trade_id.clone())
+    }
+
+    /// 커서 문자열로 인코딩.
+    pub fn encode(&self) -> String {
+        format!(
+            "{}|{}",
+            self.executed_at
+                .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+            self.id
+        )
+    }
+
+    /// 커서 문자열 파싱.
+    ///
+    /// # Errors
+    ///
+    /// 형식이 잘못되면 `ProviderError::Parse`를 반환합니다.
+    pub fn parse(cursor: &str) -> Result<Self, ProviderError> {
+        let (executed_at, id) = cursor
+            .split_once('|')
+            .filter(|(_, id)| !id.is_empty())
+            .ok_or_else(|| ProviderError::Parse(format!("잘못된 체결 내역 커서: {}", cursor)))?;
+        let executed_at = DateTime::parse_from_rfc3339(executed_at)
+            .map_err(|e| ProviderError::Parse(format!("잘못된 커서 시각: {}", e)))?
+            .with_timezone(&Utc);
+        Ok(Self::new(executed_at, id))
+    }
 }
 
 /// 체결 내역 조회 응답.
@@ -59,6 +138,23 @@ pub struct ExecutionHistoryResponse {
     pub next_cursor: Option<String>,
 }
 
+/// 심볼별 주문 수량 규칙 (거래소 심볼 메타데이터).
+///
+/// 거래소가 제공하지 않는 항목은 `None`입니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SymbolOrderRule {
+    /// 종목 심볼
+    pub ticker: String,
+    /// 최소 주문 수량
+    pub min_quantity: Option<Decimal>,
+    /// 최대 주문 수량
+    pub max_quantity: Option<Decimal>,
+    /// 주문 수량 단위 (lot size / step size)
+    pub quantity_step: Option<Decimal>,
+    /// 최소 주문 금액 (수량 × 가격)
+    pub min_notional: Option<Decimal>,
+}
+
 // =============================================================================
 // 에러 타입
 // =============================================================================
@@ -70,6 +166,10 @@ pub enum ProviderError {
     #[error("네트워크 에러: {0}")]
     Network(String),
 
+    /// 요청 타임아웃 (요청이 거래소에 도달했는지 알 수 없음)
+    #[error("요청 타임아웃: {0}")]
+    Timeout(String),
+
     /// 인증 실패
     #[error("인증 실패: {0}")]
     Authentication(String),
@@ -86,11 +186,31 @@ pub enum ProviderError {
     #[error("지원하지 않는 기능: {0}")]
     Unsupported(String),
 
+    /// 주문 사전 검증 실패 (거래소 전송 전 로컬 거부)
+    #[error("잘못된 주문: {0}")]
+    InvalidOrder(String),
+
     /// 기타 에러
     #[error("기타 에러: {0}")]
     Other(String),
 }
 
+impl ProviderError {
+    /// 메트릭 라벨용 에러 분류 이름.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ProviderError::Network(_) => "network",
+            ProviderError::Timeout(_) => "timeout",
+            ProviderError::Authentication(_) => "authentication",
+            ProviderError::Api(_) => "api",
+            ProviderError::Parse(_) => "parse",
+            ProviderError::Unsupported(_) => "unsupported",
+            ProviderError::InvalidOrder(_) => "invalid_order",
+            ProviderError::Other(_) => "other",
+        }
+    }
+}
+
 // =============================================================================
 // ExchangeProvider Trait
 // =============================================================================
@@ -175,7 +295,18 @@ pub trait ExchangeProvider: Send + Sync {
     /// 체결 내역 조회.
     ///
     /// 지정된 기간 동안의 체결 내역을 조회합니다.
-    /// 거래소마다 페이지네이션 방식이 다를 수 있으므로 커서를 지원합니다.
+    ///
+    /// # 커서 규약
+    ///
+    /// 모든 구현은 다음 규약을 따라야 합니다.
+    ///
+    /// - 체결은 최신순(`executed_at` 내림차순, 같은 시각이면 체결 ID 내림차순)으로 반환합니다.
+    /// - 한 페이지는 최대 [`ExecutionHistoryRequest::effective_limit`]건입니다.
+    /// - `next_cursor`는 다음 페이지가 실제로 존재할 때만 `Some`이고, 마지막 페이지면 `None`입니다.
+    /// - 다음 요청에 `next_cursor`를 그대로 넘기면 이전 페이지의 마지막 체결 바로 다음부터
+    ///   반환하며, 같은 타임스탬프의 체결이 페이지 경계에서 누락/중복되지 않아야 합니다.
+    /// - 커서는 호출자에게 불투명한 문자열입니다. 자체 정렬 키를 쓰는 구현은
+    ///   [`ExecutionCursor`]를 사용하고, 거래소 고유 연속 조회 키가 있으면 그 값을 담을 수 있습니다.
     ///
     /// # Arguments
     ///
@@ -204,6 +335,44 @@ pub trait ExchangeProvider: Send + Sync {
             "이 거래소는 체결 내역 조회를 지원하지 않습니다".to_string(),
         ))
     }
+
+    /// 단일 주문 상태 조회.
+    ///
+    /// 폴링 기반 주문 상태 스트림이 미체결 목록에서 사라진 주문의
+    /// 최종 상태(체결/취소)를 확인할 때 사용합니다.
+    ///
+    /// # Errors
+    ///
+    /// - `ProviderError::Unsupported`: 조회 미지원 거래소 (기본 구현)
+    async fn fetch_order_status(
+        &self,
+        order_id: &str,
+        _ticker: &str,
+    ) -> Result<OrderStatus, ProviderError> {
+        Err(ProviderError::Unsupported(format!(
+            "{}: 주문 상태 조회 미지원 ({})",
+            self.exchange_name(),
+            order_id
+        )))
+    }
+
+    /// 심볼별 주문 수량 규칙 조회.
+    ///
+    /// 최소/최대 수량, 수량 단위, 최소 주문 금액 등 거래소 심볼 메타데이터를 조회합니다.
+    /// 규칙을 찾을 수 없는 심볼은 결과에서 제외합니다.
+    ///
+    /// # Errors
+    ///
+    /// - `ProviderError::Unsupported`: 조회 미지원 거래소 (기본 구현)
+    async fn fetch_symbol_order_rules(
+        &self,
+        _tickers: &[String],
+    ) -> Result<Vec<SymbolOrderRule>, ProviderError> {
+        Err(ProviderError::Unsupported(format!(
+            "{}: 심볼 주문 규칙 조회 미지원",
+            self.exchange_name()
+        )))
+    }
 }
 
 // =============================================================================
@@ -391,6 +560,7 @@ pub trait OrderExecutionProvider: Send + Sync {
     ///
     /// - `ProviderError::Api`: 거래소 API 에러 (자금 부족, 수량 초과 등)
     /// - `ProviderError::Network`: 네트워크 연결 실패
+    /// - `ProviderError::Timeout`: 응답 타임아웃 (주문 접수 여부 불확실)
     /// - `ProviderError::Authentication`: 인증 실패
     async fn place_order(
         &self,
@@ -434,6 +604,55 @@ pub trait OrderExecutionProvider: Send + Sync {
         price: Option<Decimal>,
     ) -> Result<super::OrderResponse, ProviderError>;
 
+    /// client_order_id로 주문 조회.
+    ///
+    /// 주문 전송이 타임아웃되어 접수 여부가 불확실할 때, 재전송 전에
+    /// 이미 접수된 주문이 있는지 확인하여 중복 주문을 막는 데 사용합니다.
+    ///
+    /// # Returns
+    ///
+    /// 접수된 주문이 있으면 `Some(응답)`, 없으면 `None`.
+    ///
+    /// # Errors
+    ///
+    /// - `ProviderError::Unsupported`: 조회 미지원 거래소 (기본 구현)
+    async fn find_order_by_client_id(
+        &self,
+        client_order_id: &str,
+        _ticker: &str,
+    ) -> Result<Option<super::OrderResponse>, ProviderError> {
+        Err(ProviderError::Unsupported(format!(
+            "{}: client_order_id 주문 조회 미지원 ({})",
+            self.exchange_name(),
+            client_order_id
+        )))
+    }
+
+    /// client_order_id 주문 조회 지원 여부.
+    ///
+    /// `find_order_by_client_id`를 구현한 provider만 재정의해야 합니다.
+    /// 미지원 거래소는 타임아웃된 주문의 접수 여부를 확인할 수 없으므로
+    /// 상위 레이어가 재전송하지 않습니다.
+    fn supports_client_order_lookup(&self) -> bool {
+        false
+    }
+
+    /// 주문 유효 기간(TIF) 지원 여부.
+    ///
+    /// 기본 구현은 GTC만 지원합니다. IOC/FOK/GTD를 거래소에 전달하는
+    /// provider만 재정의해야 합니다.
+    fn supports_time_in_force(&self, time_in_force: &TimeInForce) -> bool {
+        matches!(time_in_force, TimeInForce::GTC)
+    }
+
+    /// 주문 세션(시간외/예약) 지원 여부.
+    ///
+    /// 기본 구현은 정규장만 지원합니다. 시간외·예약 주문을 처리하는
+    /// provider만 재정의해야 합니다.
+    fn supports_order_session(&self, session: &OrderSession) -> bool {
+        session.is_regular()
+    }
+
     /// 거래소 이름.
     fn exchange_name(&self) -> &str;
 }
@@ -538,4 +757,31 @@ mod tests {
             ProviderError::Authentication(_)
         ));
     }
+
+    #[test]
+    fn test_execution_cursor_roundtrip() {
+        let executed_at = DateTime::parse_from_rfc3339("2024-03-01T09:00:00.123456Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let cursor = ExecutionCursor::new(executed_at, "trade-42");
+
+        let encoded = cursor.encode();
+        assert_eq!(encoded, "2024-03-01T09:00:00.123456Z|trade-42");
+        assert_eq!(ExecutionCursor::parse(&encoded).unwrap(), cursor);
+
+        assert!(ExecutionCursor::parse("2024-03-01T09:00:00Z").is_err());
+        assert!(ExecutionCursor::parse("not-a-date|trade-42").is_err());
+        assert!(ExecutionCursor::parse("2024-03-01T09:00:00Z|").is_err());
+    }
+
+    #[test]
+    fn test_execution_history_request_limit() {
+        let request = ExecutionHistoryRequest::new("20240101", "20240131");
+        assert_eq!(request.effective_limit(), DEFAULT_EXECUTION_HISTORY_LIMIT);
+        assert_eq!(request.clone().with_limit(0).effective_limit(), 1);
+        assert_eq!(
+            request.with_limit(10_000).effective_limit(),
+            MAX_EXECUTION_HISTORY_LIMIT
+        );
+    }
 }
@@ -43,6 +43,9 @@ pub struct Kline {
     /// 체결 건수
     #[serde(skip_serializing_if = "Option::is_none")]
     pub num_trades: Option<u32>,
+    /// 결측 구간을 채운 합성 캔들 여부 (실제 거래 없음)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub synthetic: bool,
 }
 
 impl Kline {
@@ -71,6 +74,7 @@ impl Kline {
             close_time,
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         }
     }
 
@@ -13,13 +13,17 @@ mod market_breadth;
 mod market_data;
 mod market_regime;
 mod order;
+mod order_update;
 mod position;
 mod route_state;
 mod schema;
 mod signal;
+mod signal_conflict;
+mod signal_metadata;
 mod statistics;
 mod tick_size;
 mod trade;
+mod trading_calendar;
 mod trigger;
 mod watchlist;
 
@@ -35,12 +39,16 @@ pub use market_breadth::*;
 pub use market_data::*;
 pub use market_regime::*;
 pub use order::*;
+pub use order_update::*;
 pub use position::*;
 pub use route_state::*;
 pub use schema::*;
 pub use signal::*;
+pub use signal_conflict::*;
+pub use signal_metadata::*;
 pub use statistics::*;
 pub use tick_size::*;
 pub use trade::*;
+pub use trading_calendar::*;
 pub use trigger::*;
 pub use watchlist::*;
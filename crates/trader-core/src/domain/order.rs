@@ -5,6 +5,7 @@
 //! - `OrderType` - 주문 유형 (시장가, 지정가 등)
 //! - `OrderStatusType` - 주문 상태
 //! - `TimeInForce` - 주문 유효 기간
+//! - `OrderSession` - 주문 세션 (정규장/시간외/예약)
 //! - `OrderRequest` - 주문 요청
 //! - `Order` - 주문 엔티티
 
@@ -219,18 +220,94 @@ pub struct OrderStatus {
 }
 
 /// 주문 유효 기간.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 #[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
 pub enum TimeInForce {
     /// 취소될 때까지 유효 (Good Till Cancelled)
+    #[default]
     GTC,
     /// 즉시 체결 또는 취소 (Immediate Or Cancel)
     IOC,
     /// 전량 체결 또는 취소 (Fill Or Kill)
     FOK,
-    /// 지정일까지 유효 (Good Till Date)
-    GTD,
+    /// 지정 시각까지 유효 (Good Till Date), 만료 시각 포함
+    GTD(DateTime<Utc>),
+}
+
+impl TimeInForce {
+    /// 만료 시각을 제외한 유효 기간 코드 (`GTC`, `IOC`, `FOK`, `GTD`).
+    pub fn code(&self) -> &'static str {
+        match self {
+            TimeInForce::GTC => "GTC",
+            TimeInForce::IOC => "IOC",
+            TimeInForce::FOK => "FOK",
+            TimeInForce::GTD(_) => "GTD",
+        }
+    }
+
+    /// 즉시 체결되지 않으면 취소되는 주문(IOC/FOK)인지 확인합니다.
+    pub fn is_immediate(&self) -> bool {
+        matches!(self, TimeInForce::IOC | TimeInForce::FOK)
+    }
+
+    /// GTD 주문의 만료 시각.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            TimeInForce::GTD(expires_at) => Some(*expires_at),
+            _ => None,
+        }
+    }
+
+    /// 주어진 시각에 만료되었는지 확인합니다 (GTD 전용).
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| now >= expires_at)
+    }
+}
+
+/// 주문 세션 구분.
+///
+/// 국내 주식의 장전 시간외·시간외 단일가·예약 주문을 구분합니다.
+/// 정규장 외 세션은 이를 지원하는 거래소만 처리할 수 있습니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub enum OrderSession {
+    /// 정규장
+    #[default]
+    Regular,
+    /// 장전 시간외 (전일 종가로 체결)
+    PreMarket,
+    /// 시간외 단일가 (당일 종가 기준 가격 제한 범위 내)
+    AfterHoursSingle,
+    /// 예약 주문 (지정 시각 이후 정규장에서 전송)
+    Reserved(DateTime<Utc>),
+}
+
+impl OrderSession {
+    /// 예약 시각을 제외한 세션 코드 (`regular`, `pre_market`, `after_hours_single`, `reserved`).
+    pub fn code(&self) -> &'static str {
+        match self {
+            OrderSession::Regular => "regular",
+            OrderSession::PreMarket => "pre_market",
+            OrderSession::AfterHoursSingle => "after_hours_single",
+            OrderSession::Reserved(_) => "reserved",
+        }
+    }
+
+    /// 정규장 주문인지 확인합니다.
+    pub fn is_regular(&self) -> bool {
+        matches!(self, OrderSession::Regular)
+    }
+
+    /// 예약 주문의 전송 가능 시각.
+    pub fn reserved_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            OrderSession::Reserved(at) => Some(*at),
+            _ => None,
+        }
+    }
 }
 
 /// 새 주문 생성을 위한 주문 요청.
@@ -258,6 +335,12 @@ pub struct OrderRequest {
     /// 이 주문을 생성한 전략
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strategy_id: Option<String>,
+    /// 포지션 감소 전용 주문 여부 (선물 전용, 현물 거래소는 무시)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reduce_only: bool,
+    /// 주문 세션 (기본값: 정규장)
+    #[serde(default, skip_serializing_if = "OrderSession::is_regular")]
+    pub session: OrderSession,
 }
 
 impl OrderRequest {
@@ -273,6 +356,8 @@ impl OrderRequest {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         }
     }
 
@@ -288,6 +373,8 @@ impl OrderRequest {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         }
     }
 
@@ -303,6 +390,8 @@ impl OrderRequest {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         }
     }
 
@@ -318,6 +407,8 @@ impl OrderRequest {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         }
     }
 
@@ -332,6 +423,24 @@ impl OrderRequest {
         self.client_order_id = Some(client_id.into());
         self
     }
+
+    /// 주문 유효 기간을 설정합니다.
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = time_in_force;
+        self
+    }
+
+    /// 포지션 감소 전용 주문으로 설정합니다.
+    pub fn with_reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = reduce_only;
+        self
+    }
+
+    /// 주문 세션을 설정합니다.
+    pub fn with_session(mut self, session: OrderSession) -> Self {
+        self.session = session;
+        self
+    }
 }
 
 /// 제출된 주문을 나타내는 주문 엔티티.
@@ -516,4 +625,48 @@ mod tests {
         assert_eq!(Side::Buy.to_position_side(), "Long");
         assert_eq!(Side::Sell.to_position_side(), "Short");
     }
+
+    #[test]
+    fn test_time_in_force_gtd_expiry_and_serde() {
+        let expires_at = Utc::now();
+        let tif = TimeInForce::GTD(expires_at);
+
+        assert_eq!(tif.code(), "GTD");
+        assert!(!tif.is_immediate());
+        assert!(!tif.is_expired_at(expires_at - chrono::Duration::seconds(1)));
+        assert!(tif.is_expired_at(expires_at));
+        assert!(TimeInForce::IOC.is_immediate());
+        assert!(!TimeInForce::GTC.is_expired_at(expires_at));
+
+        let json = serde_json::to_value(tif).unwrap();
+        assert!(json.get("GTD").is_some());
+        assert_eq!(serde_json::to_value(TimeInForce::FOK).unwrap(), "FOK");
+        assert_eq!(serde_json::from_value::<TimeInForce>(json).unwrap(), tif);
+    }
+
+    #[test]
+    fn test_order_session_serde_and_default() {
+        let request = OrderRequest::limit_buy("005930".to_string(), dec!(10), dec!(70000));
+        assert!(request.session.is_regular());
+
+        // 정규장은 직렬화에서 생략되고, 없으면 정규장으로 역직렬화
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("session").is_none());
+        let parsed: OrderRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.session, OrderSession::Regular);
+
+        let at = Utc::now();
+        let reserved = request.with_session(OrderSession::Reserved(at));
+        assert_eq!(reserved.session.code(), "reserved");
+        assert_eq!(reserved.session.reserved_at(), Some(at));
+
+        let json = serde_json::to_value(&reserved).unwrap();
+        assert!(json["session"].get("reserved").is_some());
+        let parsed: OrderRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.session, OrderSession::Reserved(at));
+        assert_eq!(
+            serde_json::to_value(OrderSession::AfterHoursSingle).unwrap(),
+            "after_hours_single"
+        );
+    }
 }
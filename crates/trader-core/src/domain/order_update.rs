@@ -0,0 +1,117 @@
+//! 주문 상태 변경 이벤트.
+//!
+//! 거래소마다 다른 방식(WebSocket 체결통보, REST 폴링)으로 전달되는 주문 상태 변경을
+//! 하나의 정규화된 [`OrderUpdate`] 이벤트로 표현합니다.
+//! 상위 레이어(LiveExecutor, OrderManager)는 [`OrderUpdateProvider`]가 제공하는
+//! 단일 스트림만 구독하면 됩니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use super::{OrderStatus, OrderStatusType};
+
+/// 정규화된 주문 상태 변경 이벤트.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderUpdate {
+    /// 거래소 이름
+    pub exchange: String,
+    /// 거래소 주문 ID
+    pub order_id: String,
+    /// 클라이언트 주문 ID (거래소가 제공하는 경우)
+    pub client_order_id: Option<String>,
+    /// 종목 티커 (거래소가 제공하는 경우)
+    pub ticker: Option<String>,
+    /// 현재 주문 상태
+    pub status: OrderStatusType,
+    /// 누적 체결 수량
+    pub filled_quantity: Decimal,
+    /// 평균 체결가 (체결이 있는 경우)
+    pub average_price: Option<Decimal>,
+    /// 상태 변경 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+impl OrderUpdate {
+    /// 거래소 주문 상태 응답으로부터 이벤트를 생성합니다.
+    pub fn from_status(exchange: impl Into<String>, status: &OrderStatus) -> Self {
+        Self {
+            exchange: exchange.into(),
+            order_id: status.order_id.clone(),
+            client_order_id: status.client_order_id.clone(),
+            ticker: status.ticker.clone(),
+            status: status.status,
+            filled_quantity: status.filled_quantity,
+            average_price: status.average_price,
+            timestamp: status.updated_at,
+        }
+    }
+
+    /// 주문 상태 응답 형태로 변환합니다 (OrderManager 업데이트용).
+    pub fn to_order_status(&self) -> OrderStatus {
+        OrderStatus {
+            order_id: self.order_id.clone(),
+            client_order_id: self.client_order_id.clone(),
+            ticker: self.ticker.clone(),
+            side: None,
+            quantity: None,
+            price: None,
+            status: self.status,
+            filled_quantity: self.filled_quantity,
+            average_price: self.average_price,
+            updated_at: self.timestamp,
+        }
+    }
+
+    /// 최종 상태(체결 완료/취소/거부/만료) 여부.
+    pub fn is_final(&self) -> bool {
+        self.status.is_final()
+    }
+}
+
+/// 주문 상태 변경 스트림 제공자.
+///
+/// WebSocket 체결통보를 지원하는 거래소는 push 이벤트를, 지원하지 않는 거래소는
+/// 폴링으로 생성한 이벤트를 같은 형식으로 내보냅니다.
+pub trait OrderUpdateProvider: Send + Sync {
+    /// 주문 상태 변경 스트림을 구독합니다.
+    ///
+    /// 구독 이후 발생한 이벤트만 수신하며, 여러 구독자가 같은 이벤트를 받습니다.
+    fn subscribe_order_updates(&self) -> broadcast::Receiver<OrderUpdate>;
+
+    /// 이벤트를 내보내는 거래소 이름.
+    fn exchange_name(&self) -> &str;
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_order_status_round_trip() {
+        let status = OrderStatus {
+            order_id: "12345".to_string(),
+            client_order_id: Some("sig_1".to_string()),
+            ticker: Some("BTC/USDT".to_string()),
+            side: None,
+            quantity: None,
+            price: None,
+            status: OrderStatusType::PartiallyFilled,
+            filled_quantity: dec!(0.5),
+            average_price: Some(dec!(50000)),
+            updated_at: Utc::now(),
+        };
+
+        let update = OrderUpdate::from_status("binance", &status);
+        assert_eq!(update.exchange, "binance");
+        assert!(!update.is_final());
+
+        let converted = update.to_order_status();
+        assert_eq!(converted.order_id, status.order_id);
+        assert_eq!(converted.filled_quantity, dec!(0.5));
+        assert_eq!(converted.average_price, Some(dec!(50000)));
+    }
+}
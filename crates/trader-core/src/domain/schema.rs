@@ -324,6 +324,98 @@ impl StrategyUISchema {
     }
 }
 
+/// 설정 값의 JSON 타입 (설정 검증용).
+///
+/// UI 스키마의 [`FieldType`]과 달리 serde 역직렬화 기준의 타입입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigValueKind {
+    /// 정수 (i32, usize 등)
+    Integer,
+    /// 실수 (f32, f64)
+    Number,
+    /// Decimal (숫자 또는 숫자 문자열)
+    Decimal,
+    /// 불리언
+    Boolean,
+    /// 문자열
+    String,
+    /// 배열 (Vec 등)
+    Array,
+    /// 객체 (HashMap 등)
+    Object,
+    /// 사용자 정의 타입 또는 커스텀 역직렬화 (타입 검사 생략)
+    Any,
+}
+
+impl ConfigValueKind {
+    /// 값이 이 타입으로 역직렬화 가능한지 확인합니다.
+    pub fn accepts(&self, value: &serde_json::Value) -> bool {
+        use serde_json::Value;
+
+        match self {
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::Number => value.is_number(),
+            Self::Decimal => match value {
+                Value::Number(_) => true,
+                Value::String(s) => s.trim().parse::<rust_decimal::Decimal>().is_ok(),
+                _ => false,
+            },
+            Self::Boolean => value.is_boolean(),
+            Self::String => value.is_string(),
+            Self::Array => value.is_array(),
+            Self::Object => value.is_object(),
+            Self::Any => true,
+        }
+    }
+
+    /// JSON 값의 타입 이름 (에러 메시지용).
+    pub fn json_type_name(value: &serde_json::Value) -> &'static str {
+        use serde_json::Value;
+
+        match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(n) if n.is_f64() => "number",
+            Value::Number(_) => "integer",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+}
+
+/// 설정 필드 명세.
+///
+/// `#[derive(StrategyConfig)]` 매크로가 설정 구조체의 serde 속성으로부터 생성하며,
+/// 알 수 없는 필드·타입 불일치·필수값 누락 검증에 사용됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigFieldSpec {
+    /// JSON 필드 이름 (serde rename 반영)
+    pub name: String,
+
+    /// 허용되는 별칭 (serde alias)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+
+    /// 값 타입
+    pub kind: ConfigValueKind,
+
+    /// 필수 여부 (serde default가 없고 Option이 아닌 필드)
+    pub required: bool,
+
+    /// null 허용 여부 (Option 필드)
+    #[serde(default)]
+    pub nullable: bool,
+}
+
+impl ConfigFieldSpec {
+    /// 필드 이름 또는 별칭으로 매칭
+    pub fn matches(&self, key: &str) -> bool {
+        self.name == key || self.aliases.iter().any(|alias| alias == key)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -388,4 +480,22 @@ mod tests {
         assert_eq!(fragment.id, deserialized.id);
         assert_eq!(fragment.category, deserialized.category);
     }
+
+    #[test]
+    fn test_config_value_kind_accepts() {
+        assert!(ConfigValueKind::Integer.accepts(&json!(14)));
+        assert!(!ConfigValueKind::Integer.accepts(&json!(14.5)));
+        assert!(!ConfigValueKind::Integer.accepts(&json!("14")));
+
+        assert!(ConfigValueKind::Decimal.accepts(&json!("0.6")));
+        assert!(ConfigValueKind::Decimal.accepts(&json!(0.6)));
+        assert!(!ConfigValueKind::Decimal.accepts(&json!("abc")));
+
+        assert!(ConfigValueKind::Boolean.accepts(&json!(true)));
+        assert!(!ConfigValueKind::Boolean.accepts(&json!("true")));
+        assert!(ConfigValueKind::Any.accepts(&json!({"a": 1})));
+
+        assert_eq!(ConfigValueKind::json_type_name(&json!(1.5)), "number");
+        assert_eq!(ConfigValueKind::json_type_name(&json!(1)), "integer");
+    }
 }
@@ -12,7 +12,20 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::{RouteState, Side};
+use crate::domain::{
+    MissingQuantityPolicy, RouteState, Side, SignalMetadata, SignalMetadataError, TimeInForce,
+    EXPIRE_AT_KEY, QUANTITY_KEY, REASON_KEY, TIME_IN_FORCE_KEY,
+};
+
+/// 신호 메타데이터 키: 이 신호로 보유 가능한 최대 포지션 금액.
+///
+/// 전략 분산 한도(종목당 최대 비중)에 의해 설정되며, 실행기는 주문 금액을 이 값 이하로 축소합니다.
+pub const MAX_POSITION_VALUE_KEY: &str = "max_position_value";
+
+/// 신호 메타데이터 키: 심볼의 최근 변동성 (가격 대비 비율, 예: ATR / 종가).
+///
+/// 변동성 타깃 포지션 사이징에 사용되며, 없으면 실행기는 고정 비율 사이징으로 폴백합니다.
+pub const VOLATILITY_KEY: &str = "volatility";
 
 /// 수행할 액션의 종류를 나타내는 신호 유형.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -173,6 +186,31 @@ impl Signal {
         self
     }
 
+    /// 중복 수신 판정용 신호 ID를 반환합니다.
+    ///
+    /// `id`(UUID)는 발행 시마다 새로 생성되므로, 같은 신호가 재전송되면 달라집니다.
+    /// 이 ID는 전략 + 심볼 + 타임스탬프(+ 방향, 유형, 포지션 키)의 FNV-1a 64비트 해시로,
+    /// 프로세스/버전과 무관하게 같은 신호는 같은 값을 가집니다 (16자리 hex).
+    pub fn dedup_id(&self) -> String {
+        let material = format!(
+            "{}|{}|{}|{:?}|{}|{}",
+            self.strategy_id,
+            self.ticker,
+            self.timestamp
+                .timestamp_nanos_opt()
+                .unwrap_or_else(|| self.timestamp.timestamp_micros()),
+            self.side,
+            self.signal_type,
+            self.position_key()
+        );
+        let hash = material
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+            });
+        format!("{:016x}", hash)
+    }
+
     /// Executor에서 포지션을 식별하는 키를 반환합니다.
     ///
     /// position_id가 있으면 해당 ID 사용, 없으면 ticker 사용 (기존 동작)
@@ -182,6 +220,92 @@ impl Signal {
             .unwrap_or_else(|| self.ticker.clone())
     }
 
+    /// 최대 포지션 금액을 설정합니다 ([`MAX_POSITION_VALUE_KEY`] 메타데이터).
+    pub fn with_max_position_value(mut self, value: Decimal) -> Self {
+        self.metadata.insert(
+            MAX_POSITION_VALUE_KEY.to_string(),
+            serde_json::Value::String(value.to_string()),
+        );
+        self
+    }
+
+    /// 최대 포지션 금액을 반환합니다 (문자열/숫자 메타데이터 모두 허용).
+    pub fn max_position_value(&self) -> Option<Decimal> {
+        match self.metadata.get(MAX_POSITION_VALUE_KEY)? {
+            serde_json::Value::String(s) => s.parse().ok(),
+            serde_json::Value::Number(n) => n.to_string().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// 최근 변동성을 설정합니다 ([`VOLATILITY_KEY`] 메타데이터).
+    pub fn with_volatility(mut self, volatility: f64) -> Self {
+        self.metadata
+            .insert(VOLATILITY_KEY.to_string(), serde_json::json!(volatility));
+        self
+    }
+
+    /// 최근 변동성을 반환합니다 (문자열/숫자 메타데이터 모두 허용).
+    ///
+    /// 유한한 양수가 아니면 `None`을 반환합니다.
+    pub fn volatility(&self) -> Option<f64> {
+        let value = match self.metadata.get(VOLATILITY_KEY)? {
+            serde_json::Value::String(s) => s.parse().ok()?,
+            serde_json::Value::Number(n) => n.as_f64()?,
+            _ => return None,
+        };
+        (value.is_finite() && value > 0.0).then_some(value)
+    }
+
+    /// 주문 수량을 설정합니다 ([`QUANTITY_KEY`] 메타데이터).
+    pub fn with_quantity(mut self, quantity: Decimal) -> Self {
+        self.metadata.insert(
+            QUANTITY_KEY.to_string(),
+            serde_json::Value::String(quantity.to_string()),
+        );
+        self
+    }
+
+    /// 주문 유효 기간을 설정합니다 ([`TIME_IN_FORCE_KEY`], GTD는 [`EXPIRE_AT_KEY`] 포함).
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.metadata.insert(
+            TIME_IN_FORCE_KEY.to_string(),
+            serde_json::json!(time_in_force.code()),
+        );
+        match time_in_force.expires_at() {
+            Some(expire_at) => {
+                self.metadata.insert(
+                    EXPIRE_AT_KEY.to_string(),
+                    serde_json::json!(expire_at.to_rfc3339()),
+                );
+            }
+            None => {
+                self.metadata.remove(EXPIRE_AT_KEY);
+            }
+        }
+        self
+    }
+
+    /// 신호 발생 사유를 설정합니다 ([`REASON_KEY`] 메타데이터).
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.metadata
+            .insert(REASON_KEY.to_string(), serde_json::json!(reason.into()));
+        self
+    }
+
+    /// 메타데이터의 타입 안전한 뷰를 반환합니다.
+    pub fn typed_metadata(&self) -> SignalMetadata<'_> {
+        SignalMetadata::new(&self.metadata)
+    }
+
+    /// 주문 수량을 반환합니다. 수량이 없으면 `policy`에 따라 1을 사용하거나 거부합니다.
+    pub fn order_quantity(
+        &self,
+        policy: MissingQuantityPolicy,
+    ) -> Result<Decimal, SignalMetadataError> {
+        self.typed_metadata().required_quantity(policy)
+    }
+
     /// 그룹 ID를 설정합니다 (관련 포지션 묶기).
     ///
     /// 그룹 단위 청산이나 손익 추적에 사용됩니다.
@@ -343,7 +467,7 @@ impl SignalMarker {
     /// 3. signal_type 기본값
     fn build_reason(signal: &Signal) -> String {
         // 1. 명시적 reason이 있으면 사용
-        if let Some(reason) = signal.metadata.get("reason").and_then(|v| v.as_str()) {
+        if let Some(reason) = signal.metadata.get(REASON_KEY).and_then(|v| v.as_str()) {
             return Self::format_reason(reason, signal);
         }
 
@@ -594,6 +718,32 @@ mod tests {
         assert_eq!(signal.strength, 1.0);
     }
 
+    #[test]
+    fn test_signal_max_position_value() {
+        use rust_decimal_macros::dec;
+
+        let signal = Signal::entry("s", "AAPL".to_string(), Side::Buy);
+        assert_eq!(signal.max_position_value(), None);
+
+        let signal = signal.with_max_position_value(dec!(1500.5));
+        assert_eq!(signal.max_position_value(), Some(dec!(1500.5)));
+
+        let signal = signal.with_metadata(MAX_POSITION_VALUE_KEY, serde_json::json!(200));
+        assert_eq!(signal.max_position_value(), Some(dec!(200)));
+    }
+
+    #[test]
+    fn test_signal_volatility() {
+        let signal = Signal::entry("s", "AAPL".to_string(), Side::Buy);
+        assert_eq!(signal.volatility(), None);
+
+        let signal = signal.with_volatility(0.02);
+        assert_eq!(signal.volatility(), Some(0.02));
+
+        let signal = signal.with_metadata(VOLATILITY_KEY, serde_json::json!("0.0"));
+        assert_eq!(signal.volatility(), None);
+    }
+
     #[test]
     fn test_signal_marker_creation() {
         use rust_decimal_macros::dec;
@@ -617,4 +767,30 @@ mod tests {
         assert_eq!(marker.reason, "RSI 과매도 (25)");
         assert_eq!(marker.indicators.rsi, Some(25.0));
     }
+
+    #[test]
+    fn test_dedup_id_stable_across_resend() {
+        let signal = Signal::entry("rsi_1", "005930".to_string(), Side::Buy);
+        let mut resent = signal.clone();
+        resent.id = Uuid::new_v4();
+        resent.strength = 0.3;
+
+        assert_eq!(signal.dedup_id(), resent.dedup_id());
+        assert_eq!(signal.dedup_id().len(), 16);
+
+        // 전략, 심볼, 타임스탬프, 방향이 다르면 다른 신호
+        let mut other = signal.clone();
+        other.timestamp += chrono::Duration::milliseconds(1);
+        assert_ne!(signal.dedup_id(), other.dedup_id());
+        let exit = Signal {
+            signal_type: SignalType::Exit,
+            ..signal.clone()
+        };
+        assert_ne!(signal.dedup_id(), exit.dedup_id());
+        let other_ticker = Signal {
+            ticker: "000660".to_string(),
+            ..signal.clone()
+        };
+        assert_ne!(signal.dedup_id(), other_ticker.dedup_id());
+    }
 }
@@ -0,0 +1,421 @@
+//! 전략 간 시그널 충돌 해소.
+//!
+//! 같은 틱에서 여러 전략이 같은 티커에 상반된 방향(매수/매도)의 신호를 낼 때
+//! 설정된 정책에 따라 채택할 신호를 결정합니다.
+//!
+//! - `FirstWins`: 먼저 들어온 신호의 방향을 채택
+//! - `HighestConfidence`: 강도(strength)가 가장 높은 신호의 방향을 채택
+//! - `Netting`: 방향별 강도를 상계해 순방향 신호 하나만 채택
+//! - `Reject`: 충돌한 신호를 모두 기각
+//!
+//! 주문 수량은 강도에 비례(잔고 × 최대 비중 × 강도)하므로,
+//! Netting은 강도를 상계하는 방식으로 수량을 상계합니다.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{Side, Signal, SignalType};
+
+/// Netting으로 합성된 신호의 원본 신호 ID 목록 메타데이터 키.
+pub const NETTED_FROM_KEY: &str = "netted_from";
+
+/// 시그널 충돌 해소 정책.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictResolutionPolicy {
+    /// 먼저 들어온 신호의 방향 채택
+    #[default]
+    FirstWins,
+    /// 강도가 가장 높은 신호의 방향 채택 (동률이면 먼저 들어온 신호)
+    HighestConfidence,
+    /// 방향별 강도를 상계해 순방향 신호만 채택
+    Netting,
+    /// 충돌한 신호 모두 기각
+    Reject,
+}
+
+impl std::fmt::Display for ConflictResolutionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FirstWins => write!(f, "first_wins"),
+            Self::HighestConfidence => write!(f, "highest_confidence"),
+            Self::Netting => write!(f, "netting"),
+            Self::Reject => write!(f, "reject"),
+        }
+    }
+}
+
+/// 충돌 신호별 처리 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictDecision {
+    /// 그대로 채택
+    Accepted,
+    /// 기각
+    Rejected,
+    /// 상계되어 순방향 신호로 합성됨
+    Netted,
+}
+
+/// 충돌에 참여한 신호의 처리 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictSignalOutcome {
+    /// 신호 ID
+    pub signal_id: Uuid,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 신호 방향
+    pub side: Side,
+    /// 신호 유형
+    pub signal_type: SignalType,
+    /// 신호 강도
+    pub strength: f64,
+    /// 처리 결과
+    pub decision: ConflictDecision,
+}
+
+/// 티커 하나에 대한 충돌 해소 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignalConflictResolution {
+    /// 티커
+    pub ticker: String,
+    /// 적용된 정책
+    pub policy: ConflictResolutionPolicy,
+    /// 신호별 처리 결과 (입력 순서)
+    pub outcomes: Vec<ConflictSignalOutcome>,
+    /// 최종 반영 방향 (None이면 반영된 신호 없음)
+    pub net_side: Option<Side>,
+    /// 최종 반영 강도 (Netting은 상계 후 강도)
+    pub net_strength: f64,
+    /// 해소 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+impl SignalConflictResolution {
+    /// 채택(또는 합성)된 신호 결과
+    pub fn accepted(&self) -> impl Iterator<Item = &ConflictSignalOutcome> {
+        self.outcomes
+            .iter()
+            .filter(|o| o.decision != ConflictDecision::Rejected)
+    }
+
+    /// 기각된 신호 결과
+    pub fn rejected(&self) -> impl Iterator<Item = &ConflictSignalOutcome> {
+        self.outcomes
+            .iter()
+            .filter(|o| o.decision == ConflictDecision::Rejected)
+    }
+
+    /// 충돌에 참여한 전략 ID 목록 (중복 제거, 입력 순서)
+    pub fn strategy_ids(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.outcomes
+            .iter()
+            .filter(|o| seen.insert(o.strategy_id.as_str()))
+            .map(|o| o.strategy_id.clone())
+            .collect()
+    }
+
+    /// 사람이 읽을 수 있는 요약
+    pub fn summary(&self) -> String {
+        let describe = |o: &ConflictSignalOutcome| format!("{}:{:?}", o.strategy_id, o.side);
+        let accepted: Vec<_> = self.accepted().map(describe).collect();
+        let rejected: Vec<_> = self.rejected().map(describe).collect();
+        format!(
+            "{} 신호 충돌 ({}) - 채택 [{}], 기각 [{}]",
+            self.ticker,
+            self.policy,
+            accepted.join(", "),
+            rejected.join(", ")
+        )
+    }
+}
+
+/// 충돌 해소 후 신호 목록.
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedSignals {
+    /// 실행할 신호 (입력 순서 유지)
+    pub signals: Vec<Signal>,
+    /// 충돌 해소 결과 (충돌이 있던 티커만)
+    pub conflicts: Vec<SignalConflictResolution>,
+}
+
+/// 같은 틱에서 모인 신호들의 전략 간 충돌 해소.
+///
+/// 같은 티커에 두 개 이상의 전략이 매수와 매도를 함께 낸 경우만 충돌로 봅니다.
+/// Alert 신호는 실행되지 않으므로 충돌 판정에서 제외하고 그대로 통과시킵니다.
+pub fn resolve_signal_conflicts(
+    signals: Vec<Signal>,
+    policy: ConflictResolutionPolicy,
+) -> ResolvedSignals {
+    // 티커별 실행 신호 인덱스 (입력 순서)
+    let mut groups: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (idx, signal) in signals.iter().enumerate() {
+        if signal.signal_type != SignalType::Alert {
+            groups.entry(signal.ticker.as_str()).or_default().push(idx);
+        }
+    }
+
+    let mut conflict_groups: Vec<Vec<usize>> = groups
+        .into_values()
+        .filter(|indices| is_conflict(&signals, indices))
+        .collect();
+    conflict_groups.sort_by_key(|indices| indices[0]);
+
+    if conflict_groups.is_empty() {
+        return ResolvedSignals {
+            signals,
+            conflicts: Vec::new(),
+        };
+    }
+
+    let mut dropped: HashSet<usize> = HashSet::new();
+    let mut replaced: HashMap<usize, Signal> = HashMap::new();
+    let mut conflicts = Vec::with_capacity(conflict_groups.len());
+
+    for indices in &conflict_groups {
+        let (resolution, netted) = resolve_group(&signals, indices, policy);
+        for (&idx, outcome) in indices.iter().zip(&resolution.outcomes) {
+            if outcome.decision != ConflictDecision::Accepted {
+                dropped.insert(idx);
+            }
+        }
+        if let Some((idx, signal)) = netted {
+            dropped.remove(&idx);
+            replaced.insert(idx, signal);
+        }
+        conflicts.push(resolution);
+    }
+
+    let signals = signals
+        .into_iter()
+        .enumerate()
+        .filter(|(idx, _)| !dropped.contains(idx))
+        .map(|(idx, signal)| replaced.remove(&idx).unwrap_or(signal))
+        .collect();
+
+    ResolvedSignals { signals, conflicts }
+}
+
+/// 두 개 이상의 전략이 상반된 방향을 냈는지 여부.
+fn is_conflict(signals: &[Signal], indices: &[usize]) -> bool {
+    let has_buy = indices.iter().any(|&i| signals[i].side == Side::Buy);
+    let has_sell = indices.iter().any(|&i| signals[i].side == Side::Sell);
+    let strategies: HashSet<&str> = indices
+        .iter()
+        .map(|&i| signals[i].strategy_id.as_str())
+        .collect();
+    has_buy && has_sell && strategies.len() > 1
+}
+
+/// 충돌 그룹 하나를 정책에 따라 해소.
+///
+/// Netting은 합성된 신호와 그 신호가 대체할 인덱스를 함께 반환합니다.
+fn resolve_group(
+    signals: &[Signal],
+    indices: &[usize],
+    policy: ConflictResolutionPolicy,
+) -> (SignalConflictResolution, Option<(usize, Signal)>) {
+    let first = &signals[indices[0]];
+
+    let (decisions, net_side, net_strength, netted) = match policy {
+        ConflictResolutionPolicy::FirstWins | ConflictResolutionPolicy::HighestConfidence => {
+            let winner = if policy == ConflictResolutionPolicy::FirstWins {
+                indices[0]
+            } else {
+                // max_by는 동률 시 마지막 값을 반환하므로 역순으로 탐색
+                *indices
+                    .iter()
+                    .rev()
+                    .max_by(|&&a, &&b| signals[a].strength.total_cmp(&signals[b].strength))
+                    .unwrap_or(&indices[0])
+            };
+            let side = signals[winner].side;
+            let decisions = indices
+                .iter()
+                .map(|&i| {
+                    if signals[i].side == side {
+                        ConflictDecision::Accepted
+                    } else {
+                        ConflictDecision::Rejected
+                    }
+                })
+                .collect::<Vec<_>>();
+            let strength: f64 = indices
+                .iter()
+                .filter(|&&i| signals[i].side == side)
+                .map(|&i| signals[i].strength)
+                .sum();
+            (decisions, Some(side), strength, None)
+        }
+        ConflictResolutionPolicy::Netting => {
+            let net: f64 = indices
+                .iter()
+                .map(|&i| match signals[i].side {
+                    Side::Buy => signals[i].strength,
+                    Side::Sell => -signals[i].strength,
+                })
+                .sum();
+
+            if net.abs() <= f64::EPSILON {
+                let decisions = vec![ConflictDecision::Netted; indices.len()];
+                (decisions, None, 0.0, None)
+            } else {
+                let side = if net > 0.0 { Side::Buy } else { Side::Sell };
+                // 순방향에서 강도가 가장 큰 신호를 기준으로 합성
+                let base = *indices
+                    .iter()
+                    .rev()
+                    .filter(|&&i| signals[i].side == side)
+                    .max_by(|&&a, &&b| signals[a].strength.total_cmp(&signals[b].strength))
+                    .unwrap_or(&indices[0]);
+                let source_ids: Vec<String> =
+                    indices.iter().map(|&i| signals[i].id.to_string()).collect();
+                let strength = net.abs().min(1.0);
+                let mut signal = signals[base].clone().with_strength(strength);
+                signal
+                    .metadata
+                    .insert(NETTED_FROM_KEY.to_string(), serde_json::json!(source_ids));
+
+                let decisions = vec![ConflictDecision::Netted; indices.len()];
+                (decisions, Some(side), strength, Some((base, signal)))
+            }
+        }
+        ConflictResolutionPolicy::Reject => (
+            vec![ConflictDecision::Rejected; indices.len()],
+            None,
+            0.0,
+            None,
+        ),
+    };
+
+    let outcomes = indices
+        .iter()
+        .zip(decisions)
+        .map(|(&i, decision)| ConflictSignalOutcome {
+            signal_id: signals[i].id,
+            strategy_id: signals[i].strategy_id.clone(),
+            side: signals[i].side,
+            signal_type: signals[i].signal_type,
+            strength: signals[i].strength,
+            decision,
+        })
+        .collect();
+
+    let resolution = SignalConflictResolution {
+        ticker: first.ticker.clone(),
+        policy,
+        outcomes,
+        net_side,
+        net_strength,
+        timestamp: Utc::now(),
+    };
+
+    (resolution, netted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(strategy: &str, ticker: &str, side: Side, strength: f64) -> Signal {
+        Signal::entry(strategy, ticker.to_string(), side).with_strength(strength)
+    }
+
+    fn conflicting() -> Vec<Signal> {
+        vec![
+            signal("a", "005930", Side::Buy, 0.4),
+            signal("b", "005930", Side::Sell, 0.9),
+            signal("c", "000660", Side::Buy, 0.5),
+        ]
+    }
+
+    #[test]
+    fn test_no_conflict_passes_through() {
+        let signals = vec![
+            signal("a", "005930", Side::Buy, 0.5),
+            signal("b", "005930", Side::Buy, 0.7),
+            // 같은 전략의 양방향 신호(그리드 등)는 충돌이 아님
+            signal("c", "000660", Side::Buy, 0.5),
+            signal("c", "000660", Side::Sell, 0.5),
+        ];
+
+        let resolved = resolve_signal_conflicts(signals, ConflictResolutionPolicy::Reject);
+
+        assert_eq!(resolved.signals.len(), 4);
+        assert!(resolved.conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_first_wins() {
+        let resolved = resolve_signal_conflicts(conflicting(), ConflictResolutionPolicy::FirstWins);
+
+        assert_eq!(resolved.signals.len(), 2);
+        assert_eq!(resolved.signals[0].strategy_id, "a");
+        assert_eq!(resolved.signals[1].ticker, "000660");
+
+        let conflict = &resolved.conflicts[0];
+        assert_eq!(conflict.net_side, Some(Side::Buy));
+        assert_eq!(conflict.rejected().next().unwrap().strategy_id, "b");
+    }
+
+    #[test]
+    fn test_highest_confidence() {
+        let resolved =
+            resolve_signal_conflicts(conflicting(), ConflictResolutionPolicy::HighestConfidence);
+
+        assert_eq!(resolved.signals.len(), 2);
+        assert_eq!(resolved.signals[0].strategy_id, "b");
+        assert_eq!(resolved.conflicts[0].net_side, Some(Side::Sell));
+    }
+
+    #[test]
+    fn test_netting_offsets_strength() {
+        let resolved = resolve_signal_conflicts(conflicting(), ConflictResolutionPolicy::Netting);
+
+        assert_eq!(resolved.signals.len(), 2);
+        let netted = &resolved.signals[0];
+        assert_eq!(netted.side, Side::Sell);
+        assert!((netted.strength - 0.5).abs() < 1e-9);
+        assert_eq!(
+            netted
+                .metadata
+                .get(NETTED_FROM_KEY)
+                .unwrap()
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(resolved.conflicts[0]
+            .outcomes
+            .iter()
+            .all(|o| o.decision == ConflictDecision::Netted));
+    }
+
+    #[test]
+    fn test_netting_fully_offset_drops_all() {
+        let signals = vec![
+            signal("a", "005930", Side::Buy, 0.5),
+            signal("b", "005930", Side::Sell, 0.5),
+        ];
+
+        let resolved = resolve_signal_conflicts(signals, ConflictResolutionPolicy::Netting);
+
+        assert!(resolved.signals.is_empty());
+        assert_eq!(resolved.conflicts[0].net_side, None);
+    }
+
+    #[test]
+    fn test_reject_drops_conflicting_ticker_only() {
+        let resolved = resolve_signal_conflicts(conflicting(), ConflictResolutionPolicy::Reject);
+
+        assert_eq!(resolved.signals.len(), 1);
+        assert_eq!(resolved.signals[0].ticker, "000660");
+        assert_eq!(resolved.conflicts[0].rejected().count(), 2);
+        assert_eq!(resolved.conflicts[0].strategy_ids(), vec!["a", "b"]);
+    }
+}
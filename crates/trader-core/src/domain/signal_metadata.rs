@@ -0,0 +1,323 @@
+//! 신호 메타데이터의 타입 안전한 해석.
+//!
+//! `Signal::metadata`는 전략 간 호환을 위해 자유 형식 JSON 맵으로 유지하고,
+//! 주문에 쓰이는 표준 키(수량, 가격, 주문 유효 기간, 사유)는 [`SignalMetadata`]로
+//! 검증하며 읽습니다. 숫자 값은 전략들이 기록하는 문자열(`"10"`)과 JSON 숫자(`10`)를
+//! 모두 허용하지만, 그 밖의 타입이나 파싱할 수 없는 값은 기본값으로 넘기지 않고
+//! [`SignalMetadataError`]로 반환합니다.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::domain::TimeInForce;
+
+/// 신호 메타데이터 키: 주문 수량.
+pub const QUANTITY_KEY: &str = "quantity";
+
+/// 신호 메타데이터 키: 주문 가격 (`suggested_price` 없이 가격을 넘기는 전략용).
+pub const PRICE_KEY: &str = "price";
+
+/// 신호 메타데이터 키: 주문 유효 기간 (`GTC`, `IOC`, `FOK`, `GTD`).
+pub const TIME_IN_FORCE_KEY: &str = "time_in_force";
+
+/// 신호 메타데이터 키: GTD 만료 시각 (RFC 3339).
+pub const EXPIRE_AT_KEY: &str = "expire_at";
+
+/// 신호 메타데이터 키: 신호 발생 사유.
+pub const REASON_KEY: &str = "reason";
+
+/// 신호 메타데이터 해석 에러.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SignalMetadataError {
+    /// 필수 키 누락
+    #[error("신호 메타데이터 '{0}' 누락")]
+    Missing(&'static str),
+
+    /// 값의 JSON 타입이 기대와 다름
+    #[error("신호 메타데이터 '{key}' 타입 오류 ({expected} 필요): {value}")]
+    InvalidType {
+        key: String,
+        expected: &'static str,
+        value: String,
+    },
+
+    /// 파싱할 수 없거나 허용 범위를 벗어난 값
+    #[error("신호 메타데이터 '{key}' 값 오류: {reason}")]
+    InvalidValue { key: String, reason: String },
+}
+
+/// 신호 메타데이터에 주문 수량이 없을 때의 처리 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingQuantityPolicy {
+    /// 1단위로 간주 (기존 동작)
+    #[default]
+    DefaultOne,
+    /// 신호 거부
+    Reject,
+}
+
+/// 신호 메타데이터의 타입 안전한 읽기 전용 뷰.
+///
+/// 키가 없거나 `null`이면 `Ok(None)`, 값이 잘못되었으면 `Err`를 반환합니다.
+#[derive(Debug, Clone, Copy)]
+pub struct SignalMetadata<'a> {
+    metadata: &'a HashMap<String, Value>,
+}
+
+impl<'a> SignalMetadata<'a> {
+    /// 메타데이터 맵에 대한 뷰 생성.
+    pub fn new(metadata: &'a HashMap<String, Value>) -> Self {
+        Self { metadata }
+    }
+
+    /// 소수 값 조회 (숫자 문자열과 JSON 숫자 모두 허용).
+    pub fn decimal(&self, key: &str) -> Result<Option<Decimal>, SignalMetadataError> {
+        let text = match self.metadata.get(key) {
+            None | Some(Value::Null) => return Ok(None),
+            Some(Value::String(s)) => s.trim().to_string(),
+            Some(Value::Number(n)) => n.to_string(),
+            Some(other) => return Err(invalid_type(key, "숫자 또는 숫자 문자열", other)),
+        };
+
+        Decimal::from_str(&text)
+            .or_else(|_| Decimal::from_scientific(&text))
+            .map(Some)
+            .map_err(|e| SignalMetadataError::InvalidValue {
+                key: key.to_string(),
+                reason: format!("'{}' 파싱 실패: {}", text, e),
+            })
+    }
+
+    /// 문자열 값 조회.
+    pub fn string(&self, key: &str) -> Result<Option<&'a str>, SignalMetadataError> {
+        match self.metadata.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(s)) => Ok(Some(s.as_str())),
+            Some(other) => Err(invalid_type(key, "문자열", other)),
+        }
+    }
+
+    /// 주문 수량 ([`QUANTITY_KEY`]). 음수는 거부합니다.
+    pub fn quantity(&self) -> Result<Option<Decimal>, SignalMetadataError> {
+        let quantity = self.decimal(QUANTITY_KEY)?;
+        if let Some(q) = quantity.filter(|q| *q < Decimal::ZERO) {
+            return Err(SignalMetadataError::InvalidValue {
+                key: QUANTITY_KEY.to_string(),
+                reason: format!("음수 수량 {}", q),
+            });
+        }
+        Ok(quantity)
+    }
+
+    /// 주문 수량. 없으면 `policy`에 따라 1을 사용하거나 거부합니다.
+    pub fn required_quantity(
+        &self,
+        policy: MissingQuantityPolicy,
+    ) -> Result<Decimal, SignalMetadataError> {
+        match (self.quantity()?, policy) {
+            (Some(quantity), _) => Ok(quantity),
+            (None, MissingQuantityPolicy::DefaultOne) => Ok(Decimal::ONE),
+            (None, MissingQuantityPolicy::Reject) => {
+                Err(SignalMetadataError::Missing(QUANTITY_KEY))
+            }
+        }
+    }
+
+    /// 주문 가격 ([`PRICE_KEY`]). 0 이하는 거부합니다.
+    pub fn price(&self) -> Result<Option<Decimal>, SignalMetadataError> {
+        let price = self.decimal(PRICE_KEY)?;
+        if let Some(p) = price.filter(|p| *p <= Decimal::ZERO) {
+            return Err(SignalMetadataError::InvalidValue {
+                key: PRICE_KEY.to_string(),
+                reason: format!("가격은 0보다 커야 합니다: {}", p),
+            });
+        }
+        Ok(price)
+    }
+
+    /// 신호 발생 사유 ([`REASON_KEY`]).
+    pub fn reason(&self) -> Result<Option<&'a str>, SignalMetadataError> {
+        self.string(REASON_KEY)
+    }
+
+    /// 주문 유효 기간 ([`TIME_IN_FORCE_KEY`], 대소문자 무시).
+    ///
+    /// `GTD`는 [`EXPIRE_AT_KEY`](RFC 3339)가 함께 있어야 하며, `now` 이전 시각이면 거부합니다.
+    pub fn time_in_force(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Option<TimeInForce>, SignalMetadataError> {
+        let Some(code) = self.string(TIME_IN_FORCE_KEY)? else {
+            return Ok(None);
+        };
+
+        let time_in_force = match code.to_ascii_uppercase().as_str() {
+            "GTC" => TimeInForce::GTC,
+            "IOC" => TimeInForce::IOC,
+            "FOK" => TimeInForce::FOK,
+            "GTD" => {
+                let raw = self
+                    .string(EXPIRE_AT_KEY)?
+                    .ok_or(SignalMetadataError::Missing(EXPIRE_AT_KEY))?;
+                let expire_at = DateTime::parse_from_rfc3339(raw)
+                    .map_err(|e| SignalMetadataError::InvalidValue {
+                        key: EXPIRE_AT_KEY.to_string(),
+                        reason: format!("'{}' 파싱 실패: {}", raw, e),
+                    })?
+                    .with_timezone(&Utc);
+                if expire_at <= now {
+                    return Err(SignalMetadataError::InvalidValue {
+                        key: EXPIRE_AT_KEY.to_string(),
+                        reason: format!("이미 지난 만료 시각 {}", expire_at),
+                    });
+                }
+                TimeInForce::GTD(expire_at)
+            }
+            other => {
+                return Err(SignalMetadataError::InvalidValue {
+                    key: TIME_IN_FORCE_KEY.to_string(),
+                    reason: format!("알 수 없는 주문 유효 기간 {}", other),
+                })
+            }
+        };
+
+        Ok(Some(time_in_force))
+    }
+}
+
+fn invalid_type(key: &str, expected: &'static str, value: &Value) -> SignalMetadataError {
+    SignalMetadataError::InvalidType {
+        key: key.to_string(),
+        expected,
+        value: value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Side, Signal};
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn signal() -> Signal {
+        Signal::entry("test", "005930".to_string(), Side::Buy)
+    }
+
+    #[test]
+    fn test_quantity_accepts_string_and_number() {
+        let as_string = signal().with_metadata(QUANTITY_KEY, json!("12.5"));
+        let as_number = signal().with_metadata(QUANTITY_KEY, json!(12.5));
+        let as_integer = signal().with_metadata(QUANTITY_KEY, json!(3));
+
+        assert_eq!(as_string.typed_metadata().quantity(), Ok(Some(dec!(12.5))));
+        assert_eq!(as_number.typed_metadata().quantity(), Ok(Some(dec!(12.5))));
+        assert_eq!(as_integer.typed_metadata().quantity(), Ok(Some(dec!(3))));
+        assert_eq!(signal().typed_metadata().quantity(), Ok(None));
+    }
+
+    #[test]
+    fn test_invalid_values_are_errors() {
+        let wrong_type = signal().with_metadata(QUANTITY_KEY, json!(true));
+        assert!(matches!(
+            wrong_type.typed_metadata().quantity(),
+            Err(SignalMetadataError::InvalidType { .. })
+        ));
+
+        let unparsable = signal().with_metadata(QUANTITY_KEY, json!("10주"));
+        assert!(matches!(
+            unparsable.typed_metadata().quantity(),
+            Err(SignalMetadataError::InvalidValue { .. })
+        ));
+
+        let negative = signal().with_metadata(QUANTITY_KEY, json!("-1"));
+        assert!(negative.typed_metadata().quantity().is_err());
+
+        let zero_price = signal().with_metadata(PRICE_KEY, json!(0));
+        assert!(zero_price.typed_metadata().price().is_err());
+
+        let reason = signal().with_metadata(REASON_KEY, json!(42));
+        assert!(reason.typed_metadata().reason().is_err());
+    }
+
+    #[test]
+    fn test_missing_quantity_policy() {
+        let metadata = signal();
+        assert_eq!(
+            metadata
+                .typed_metadata()
+                .required_quantity(MissingQuantityPolicy::DefaultOne),
+            Ok(Decimal::ONE)
+        );
+        assert_eq!(
+            metadata
+                .typed_metadata()
+                .required_quantity(MissingQuantityPolicy::Reject),
+            Err(SignalMetadataError::Missing(QUANTITY_KEY))
+        );
+
+        let explicit = signal().with_quantity(dec!(7));
+        assert_eq!(
+            explicit
+                .typed_metadata()
+                .required_quantity(MissingQuantityPolicy::Reject),
+            Ok(dec!(7))
+        );
+    }
+
+    #[test]
+    fn test_time_in_force() {
+        let now = Utc::now();
+        let expire_at = now + Duration::hours(1);
+
+        let gtd = signal().with_time_in_force(TimeInForce::GTD(expire_at));
+        assert_eq!(
+            gtd.typed_metadata().time_in_force(now),
+            Ok(Some(TimeInForce::GTD(expire_at)))
+        );
+
+        let lowercase = signal().with_metadata(TIME_IN_FORCE_KEY, json!("ioc"));
+        assert_eq!(
+            lowercase.typed_metadata().time_in_force(now),
+            Ok(Some(TimeInForce::IOC))
+        );
+
+        let missing_expiry = signal().with_metadata(TIME_IN_FORCE_KEY, json!("GTD"));
+        assert_eq!(
+            missing_expiry.typed_metadata().time_in_force(now),
+            Err(SignalMetadataError::Missing(EXPIRE_AT_KEY))
+        );
+
+        assert!(gtd
+            .typed_metadata()
+            .time_in_force(expire_at + Duration::seconds(1))
+            .is_err());
+    }
+
+    #[test]
+    fn test_typed_fields_survive_serde_roundtrip() {
+        let original = signal()
+            .with_quantity(dec!(0.001))
+            .with_time_in_force(TimeInForce::FOK)
+            .with_reason("RSI 과매도");
+
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: Signal = serde_json::from_str(&json).unwrap();
+        let metadata = restored.typed_metadata();
+
+        assert_eq!(metadata.quantity(), Ok(Some(dec!(0.001))));
+        assert_eq!(
+            metadata.time_in_force(Utc::now()),
+            Ok(Some(TimeInForce::FOK))
+        );
+        assert_eq!(metadata.reason(), Ok(Some("RSI 과매도")));
+    }
+}
@@ -6,6 +6,8 @@
 use std::{collections::HashMap, sync::Arc};
 
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
 
 /// 호가 단위 라운딩 방법
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -197,6 +199,187 @@ impl TickSizeProvider for BinanceTickSize {
     }
 }
 
+/// 가격 구간별 호가 단위.
+///
+/// `min_price` 이상인 가격에 `tick`이 적용됩니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TickSizeTier {
+    /// 구간 하한 (이상)
+    pub min_price: Decimal,
+    /// 호가 단위
+    pub tick: Decimal,
+}
+
+/// KRX 주식 호가 단위 (2023년 1월 개편, 코스피/코스닥 공통).
+///
+/// | 가격 구간 | 호가 단위 |
+/// |-----------|-----------|
+/// | 2,000원 미만 | 1원 |
+/// | 2,000원 ~ 5,000원 미만 | 5원 |
+/// | 5,000원 ~ 20,000원 미만 | 10원 |
+/// | 20,000원 ~ 50,000원 미만 | 50원 |
+/// | 50,000원 ~ 200,000원 미만 | 100원 |
+/// | 200,000원 ~ 500,000원 미만 | 500원 |
+/// | 500,000원 이상 | 1,000원 |
+pub const KRX_STOCK_TICK_TIERS: &[TickSizeTier] = &[
+    TickSizeTier {
+        min_price: dec!(0),
+        tick: dec!(1),
+    },
+    TickSizeTier {
+        min_price: dec!(2_000),
+        tick: dec!(5),
+    },
+    TickSizeTier {
+        min_price: dec!(5_000),
+        tick: dec!(10),
+    },
+    TickSizeTier {
+        min_price: dec!(20_000),
+        tick: dec!(50),
+    },
+    TickSizeTier {
+        min_price: dec!(50_000),
+        tick: dec!(100),
+    },
+    TickSizeTier {
+        min_price: dec!(200_000),
+        tick: dec!(500),
+    },
+    TickSizeTier {
+        min_price: dec!(500_000),
+        tick: dec!(1_000),
+    },
+];
+
+/// KRX ETF/ETN 호가 단위.
+///
+/// - 2,000원 미만: 1원
+/// - 2,000원 이상: 5원
+pub const KRX_ETF_TICK_TIERS: &[TickSizeTier] = &[
+    TickSizeTier {
+        min_price: dec!(0),
+        tick: dec!(1),
+    },
+    TickSizeTier {
+        min_price: dec!(2_000),
+        tick: dec!(5),
+    },
+];
+
+/// 미국 주식 호가 단위 (Reg NMS Rule 612).
+///
+/// - $1.00 미만: $0.0001
+/// - $1.00 이상: $0.01
+pub const US_EQUITY_TICK_TIERS: &[TickSizeTier] = &[
+    TickSizeTier {
+        min_price: dec!(0),
+        tick: dec!(0.0001),
+    },
+    TickSizeTier {
+        min_price: dec!(1),
+        tick: dec!(0.01),
+    },
+];
+
+/// 가격 구간 테이블 기반 호가 단위 제공자.
+///
+/// 구간이 없으면 호가 단위 제약이 없는 것으로 간주합니다 (tick = 0).
+/// 양수 가격은 라운딩 후에도 최소 1 tick 이상으로 유지됩니다
+/// (예: 0.4원 → 1원, 0원으로 내려가지 않음).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TickSizeTable {
+    /// 하한 오름차순 구간 목록
+    tiers: Vec<TickSizeTier>,
+}
+
+impl TickSizeTable {
+    /// 구간 목록으로 생성합니다 (하한 오름차순으로 정렬됨).
+    pub fn new(mut tiers: Vec<TickSizeTier>) -> Self {
+        tiers.sort_by_key(|tier| tier.min_price);
+        Self { tiers }
+    }
+
+    /// 제약 없는 테이블 (암호화폐 등).
+    pub fn unrestricted() -> Self {
+        Self::default()
+    }
+
+    /// 모든 가격에 고정 호가 단위를 적용합니다.
+    pub fn fixed(tick: Decimal) -> Self {
+        Self::new(vec![TickSizeTier {
+            min_price: Decimal::ZERO,
+            tick,
+        }])
+    }
+
+    /// KRX 주식 테이블.
+    pub fn krx_stock() -> Self {
+        Self::new(KRX_STOCK_TICK_TIERS.to_vec())
+    }
+
+    /// KRX ETF/ETN 테이블.
+    pub fn krx_etf() -> Self {
+        Self::new(KRX_ETF_TICK_TIERS.to_vec())
+    }
+
+    /// 미국 주식 테이블.
+    pub fn us_equity() -> Self {
+        Self::new(US_EQUITY_TICK_TIERS.to_vec())
+    }
+
+    /// 구간 목록.
+    pub fn tiers(&self) -> &[TickSizeTier] {
+        &self.tiers
+    }
+
+    /// 제약이 없는 테이블인지 여부.
+    pub fn is_unrestricted(&self) -> bool {
+        self.tiers.is_empty()
+    }
+}
+
+impl TickSizeProvider for TickSizeTable {
+    fn tick_size(&self, price: Decimal) -> Decimal {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| price >= tier.min_price)
+            .or_else(|| self.tiers.first())
+            .map(|tier| tier.tick)
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    fn round_to_tick(&self, price: Decimal, method: RoundMethod) -> Decimal {
+        let tick = self.tick_size(price);
+        if tick.is_zero() {
+            return price;
+        }
+
+        let rounded_ticks = match method {
+            RoundMethod::Round => (price / tick).round(),
+            RoundMethod::Floor => (price / tick).floor(),
+            RoundMethod::Ceil => (price / tick).ceil(),
+        };
+        let rounded = rounded_ticks * tick;
+
+        // 라운딩 결과가 상위 구간으로 넘어가면 해당 구간의 호가 단위로 다시 맞춤
+        // (예: KRX 1,999.6원 → 2,000원은 5원 단위에도 유효)
+        let rounded = if self.is_valid_price(rounded) {
+            rounded
+        } else {
+            let upper_tick = self.tick_size(rounded);
+            (rounded / upper_tick).ceil() * upper_tick
+        };
+
+        if price > Decimal::ZERO && rounded < tick {
+            tick
+        } else {
+            rounded
+        }
+    }
+}
+
 // 거래소별 팩토리 함수는 trader-exchange 크레이트에서 제공합니다.
 // trader-core는 거래소 중립적인 trait과 구현체만 제공합니다.
 
@@ -307,6 +490,74 @@ mod tests {
         assert_eq!(provider.get_tick_size_for_symbol("UNKNOWN"), dec!(0.01));
     }
 
+    #[test]
+    fn test_tick_size_table_krx_stock() {
+        let table = TickSizeTable::krx_stock();
+
+        let cases = [
+            (dec!(1_999), dec!(1)),
+            (dec!(2_000), dec!(5)),
+            (dec!(4_995), dec!(5)),
+            (dec!(5_000), dec!(10)),
+            (dec!(19_990), dec!(10)),
+            (dec!(20_000), dec!(50)),
+            (dec!(50_000), dec!(100)),
+            (dec!(199_900), dec!(100)),
+            (dec!(200_000), dec!(500)),
+            (dec!(500_000), dec!(1_000)),
+        ];
+        for (price, tick) in cases {
+            assert_eq!(table.tick_size(price), tick, "price {}", price);
+        }
+
+        assert_eq!(
+            table.round_to_tick(dec!(35_432), RoundMethod::Round),
+            dec!(35_450)
+        );
+        assert_eq!(
+            table.round_to_tick(dec!(3_502), RoundMethod::Round),
+            dec!(3_500)
+        );
+        // 구간 경계: 1,999.6원 → 2,000원
+        assert_eq!(
+            table.round_to_tick(dec!(1_999.6), RoundMethod::Round),
+            dec!(2_000)
+        );
+        // 1원 미만 가격은 최소 호가(1원)로
+        assert_eq!(table.round_to_tick(dec!(0.4), RoundMethod::Round), dec!(1));
+    }
+
+    #[test]
+    fn test_tick_size_table_etf_and_us() {
+        let etf = TickSizeTable::krx_etf();
+        assert_eq!(etf.tick_size(dec!(1_500)), dec!(1));
+        assert_eq!(
+            etf.round_to_tick(dec!(35_432), RoundMethod::Round),
+            dec!(35_430)
+        );
+        assert_eq!(
+            etf.round_to_tick(dec!(35_433), RoundMethod::Round),
+            dec!(35_435)
+        );
+
+        let us = TickSizeTable::us_equity();
+        assert_eq!(
+            us.round_to_tick(dec!(0.53217), RoundMethod::Round),
+            dec!(0.5322)
+        );
+        assert_eq!(
+            us.round_to_tick(dec!(123.456), RoundMethod::Round),
+            dec!(123.46)
+        );
+
+        let none = TickSizeTable::unrestricted();
+        assert!(none.is_unrestricted());
+        assert_eq!(
+            none.round_to_tick(dec!(0.123456), RoundMethod::Round),
+            dec!(0.123456)
+        );
+    }
+
     #[test]
     fn test_round_method() {
         let provider = KrxTickSize::new();
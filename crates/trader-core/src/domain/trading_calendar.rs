@@ -0,0 +1,745 @@
+//! 시장별 거래일 캘린더.
+//!
+//! 주말, 휴장일, 반일장을 고려한 거래일 계산을 제공합니다.
+//! 스케줄러, 데이터 수집, 신호 성과 계산, 백테스트가 같은 기준을 쓰도록
+//! 전역 캘린더([`TradingCalendar::global`])와 편의 함수를 함께 제공합니다.
+//!
+//! # 폴백
+//!
+//! 휴장일 데이터가 없는 연도(미래 연도 등)는 주말만 휴장으로 간주합니다.
+//! 암호화폐 시장은 연중무휴로 모든 날이 거래일입니다.
+//!
+//! # 사용 예시
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use trader_core::next_trading_day;
+//!
+//! // 2025-12-31(연말 휴장), 2026-01-01(신정) → 2026-01-02
+//! let date = NaiveDate::from_ymd_opt(2025, 12, 30).unwrap();
+//! assert_eq!(
+//!     next_trading_day("KR", date),
+//!     NaiveDate::from_ymd_opt(2026, 1, 2).unwrap()
+//! );
+//! ```
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::OnceLock,
+};
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// 한국(KRX) 휴장일 (연, 월, 일).
+///
+/// 공휴일, 대체공휴일, 선거일, 근로자의 날, 연말 휴장일(12/31)을 포함합니다.
+const KR_HOLIDAYS: &[(i32, u32, u32)] = &[
+    // 2024
+    (2024, 1, 1),   // 신정
+    (2024, 2, 9),   // 설날 연휴
+    (2024, 2, 12),  // 설날 대체공휴일
+    (2024, 3, 1),   // 삼일절
+    (2024, 4, 10),  // 국회의원 선거
+    (2024, 5, 1),   // 근로자의 날
+    (2024, 5, 6),   // 어린이날 대체공휴일
+    (2024, 5, 15),  // 부처님오신날
+    (2024, 6, 6),   // 현충일
+    (2024, 8, 15),  // 광복절
+    (2024, 9, 16),  // 추석 연휴
+    (2024, 9, 17),  // 추석
+    (2024, 9, 18),  // 추석 연휴
+    (2024, 10, 1),  // 국군의 날 (임시공휴일)
+    (2024, 10, 3),  // 개천절
+    (2024, 10, 9),  // 한글날
+    (2024, 12, 25), // 크리스마스
+    (2024, 12, 31), // 연말 휴장일
+    // 2025
+    (2025, 1, 1),   // 신정
+    (2025, 1, 27),  // 임시공휴일
+    (2025, 1, 28),  // 설날 연휴
+    (2025, 1, 29),  // 설날
+    (2025, 1, 30),  // 설날 연휴
+    (2025, 3, 3),   // 삼일절 대체공휴일
+    (2025, 5, 1),   // 근로자의 날
+    (2025, 5, 5),   // 어린이날, 부처님오신날
+    (2025, 5, 6),   // 대체공휴일
+    (2025, 6, 3),   // 대통령 선거
+    (2025, 6, 6),   // 현충일
+    (2025, 8, 15),  // 광복절
+    (2025, 10, 3),  // 개천절
+    (2025, 10, 6),  // 추석
+    (2025, 10, 7),  // 추석 연휴
+    (2025, 10, 8),  // 추석 대체공휴일
+    (2025, 10, 9),  // 한글날
+    (2025, 12, 25), // 크리스마스
+    (2025, 12, 31), // 연말 휴장일
+    // 2026
+    (2026, 1, 1),   // 신정
+    (2026, 2, 16),  // 설날 연휴
+    (2026, 2, 17),  // 설날
+    (2026, 2, 18),  // 설날 연휴
+    (2026, 3, 2),   // 삼일절 대체공휴일
+    (2026, 5, 1),   // 근로자의 날
+    (2026, 5, 5),   // 어린이날
+    (2026, 5, 25),  // 부처님오신날 대체공휴일
+    (2026, 6, 3),   // 지방선거
+    (2026, 8, 17),  // 광복절 대체공휴일
+    (2026, 9, 24),  // 추석 연휴
+    (2026, 9, 25),  // 추석
+    (2026, 10, 5),  // 개천절 대체공휴일
+    (2026, 10, 9),  // 한글날
+    (2026, 12, 25), // 크리스마스
+    (2026, 12, 31), // 연말 휴장일
+];
+
+/// 한국 휴장일 데이터가 포함된 연도 범위.
+const KR_COVERED_YEARS: (i32, i32) = (2024, 2026);
+
+/// 미국(NYSE/NASDAQ) 휴장일 (연, 월, 일).
+const US_HOLIDAYS: &[(i32, u32, u32)] = &[
+    // 2024
+    (2024, 1, 1),   // New Year's Day
+    (2024, 1, 15),  // Martin Luther King Jr. Day
+    (2024, 2, 19),  // Presidents' Day
+    (2024, 3, 29),  // Good Friday
+    (2024, 5, 27),  // Memorial Day
+    (2024, 6, 19),  // Juneteenth
+    (2024, 7, 4),   // Independence Day
+    (2024, 9, 2),   // Labor Day
+    (2024, 11, 28), // Thanksgiving Day
+    (2024, 12, 25), // Christmas Day
+    // 2025
+    (2025, 1, 1),   // New Year's Day
+    (2025, 1, 9),   // National Day of Mourning
+    (2025, 1, 20),  // Martin Luther King Jr. Day
+    (2025, 2, 17),  // Presidents' Day
+    (2025, 4, 18),  // Good Friday
+    (2025, 5, 26),  // Memorial Day
+    (2025, 6, 19),  // Juneteenth
+    (2025, 7, 4),   // Independence Day
+    (2025, 9, 1),   // Labor Day
+    (2025, 11, 27), // Thanksgiving Day
+    (2025, 12, 25), // Christmas Day
+    // 2026
+    (2026, 1, 1),   // New Year's Day
+    (2026, 1, 19),  // Martin Luther King Jr. Day
+    (2026, 2, 16),  // Presidents' Day
+    (2026, 4, 3),   // Good Friday
+    (2026, 5, 25),  // Memorial Day
+    (2026, 6, 19),  // Juneteenth
+    (2026, 7, 3),   // Independence Day (대체)
+    (2026, 9, 7),   // Labor Day
+    (2026, 11, 26), // Thanksgiving Day
+    (2026, 12, 25), // Christmas Day
+];
+
+/// 미국 반일장 (연, 월, 일). 현지 시간 13:00 조기 마감.
+const US_HALF_DAYS: &[(i32, u32, u32)] = &[
+    (2024, 7, 3),
+    (2024, 11, 29),
+    (2024, 12, 24),
+    (2025, 7, 3),
+    (2025, 11, 28),
+    (2025, 12, 24),
+    (2026, 11, 27),
+    (2026, 12, 24),
+];
+
+/// 미국 휴장일 데이터가 포함된 연도 범위.
+const US_COVERED_YEARS: (i32, i32) = (2024, 2026);
+
+/// 거래일 탐색 상한 (잘못된 데이터로 인한 무한 루프 방지).
+const MAX_SEARCH_DAYS: i64 = 366;
+
+/// 날짜의 거래일 구분.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradingDayKind {
+    /// 정규 거래일
+    Regular,
+    /// 반일장 (조기 마감)
+    HalfDay,
+    /// 주말
+    Weekend,
+    /// 휴장일
+    Holiday,
+}
+
+impl TradingDayKind {
+    /// 거래가 이루어지는 날인지 여부 (정규 거래일 또는 반일장).
+    pub fn is_trading(&self) -> bool {
+        matches!(self, TradingDayKind::Regular | TradingDayKind::HalfDay)
+    }
+}
+
+/// 시장 코드를 캘린더 키로 정규화합니다.
+///
+/// 거래소/지수 이름(KRX, KOSPI, NYSE 등)을 국가 단위 시장 코드로 변환합니다.
+pub fn calendar_market_code(market: &str) -> String {
+    let upper = market.trim().to_uppercase();
+    match upper.as_str() {
+        "KR" | "KRX" | "KOSPI" | "KOSDAQ" | "KR_STOCK" => "KR".to_string(),
+        "US" | "NYSE" | "NASDAQ" | "AMEX" | "US_STOCK" => "US".to_string(),
+        "JP" | "JPX" | "TSE" | "JP_STOCK" => "JP".to_string(),
+        "CRYPTO" | "BINANCE" | "UPBIT" | "BITHUMB" | "BYBIT" => "CRYPTO".to_string(),
+        _ => upper,
+    }
+}
+
+/// 시장별 거래일 캘린더.
+#[derive(Debug, Clone, Default)]
+pub struct TradingCalendar {
+    /// 시장별 휴장일
+    holidays: HashMap<String, HashSet<NaiveDate>>,
+    /// 시장별 반일장 (날짜 → 현지 마감 시각)
+    half_days: HashMap<String, HashMap<NaiveDate, NaiveTime>>,
+    /// 시장별 휴장일 데이터가 있는 연도
+    covered_years: HashMap<String, BTreeSet<i32>>,
+}
+
+impl TradingCalendar {
+    /// 빈 캘린더 생성 (모든 시장이 주말만 휴장).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 내장 휴장일 데이터(KR, US)를 포함한 캘린더 생성.
+    pub fn with_builtin_holidays() -> Self {
+        let mut calendar = Self::new();
+
+        for &(y, m, d) in KR_HOLIDAYS {
+            if let Some(date) = NaiveDate::from_ymd_opt(y, m, d) {
+                calendar.add_holiday("KR", date);
+            }
+        }
+        for year in KR_COVERED_YEARS.0..=KR_COVERED_YEARS.1 {
+            calendar.mark_year_covered("KR", year);
+        }
+
+        for &(y, m, d) in US_HOLIDAYS {
+            if let Some(date) = NaiveDate::from_ymd_opt(y, m, d) {
+                calendar.add_holiday("US", date);
+            }
+        }
+        let us_early_close = NaiveTime::from_hms_opt(13, 0, 0).unwrap_or(NaiveTime::MIN);
+        for &(y, m, d) in US_HALF_DAYS {
+            if let Some(date) = NaiveDate::from_ymd_opt(y, m, d) {
+                calendar.add_half_day("US", date, us_early_close);
+            }
+        }
+        for year in US_COVERED_YEARS.0..=US_COVERED_YEARS.1 {
+            calendar.mark_year_covered("US", year);
+        }
+
+        calendar
+    }
+
+    /// 내장 데이터로 초기화된 전역 캘린더.
+    ///
+    /// 내장 데이터가 없는 작년~내년의 US/JP 휴장일은 규칙 기반으로 채웁니다.
+    pub fn global() -> &'static TradingCalendar {
+        static GLOBAL: OnceLock<TradingCalendar> = OnceLock::new();
+        GLOBAL.get_or_init(|| {
+            let mut calendar = Self::with_builtin_holidays();
+            let this_year = Utc::now().year();
+            calendar.fill_rule_based_holidays(this_year - 1..=this_year + 1);
+            calendar
+        })
+    }
+
+    /// 내장 데이터가 없는 연도의 US/JP 휴장일을 규칙 기반으로 채웁니다.
+    pub fn fill_rule_based_holidays(&mut self, years: impl IntoIterator<Item = i32>) {
+        for year in years {
+            if !self.has_holiday_data("US", year) {
+                self.load_us_holidays(year);
+            }
+            if !self.has_holiday_data("JP", year) {
+                self.load_jp_holidays(year);
+            }
+        }
+    }
+
+    /// 미국(NYSE/NASDAQ) 휴장일 및 반일장 로드 (규칙 기반).
+    ///
+    /// 토요일 휴일은 전날(금), 일요일 휴일은 다음날(월)로 대체합니다.
+    /// 단, 신정이 토요일이면 전년도 12/31은 정상 거래합니다.
+    /// 반일장: 독립기념일 전날(7/3, 월~목), 추수감사절 다음날, 크리스마스 이브(월~목).
+    pub fn load_us_holidays(&mut self, year: i32) {
+        let mut holidays = Vec::new();
+
+        if let Some(new_year) = NaiveDate::from_ymd_opt(year, 1, 1) {
+            if new_year.weekday() != Weekday::Sat {
+                holidays.push(us_observed(new_year));
+            }
+        }
+        holidays.extend(NaiveDate::from_weekday_of_month_opt(
+            year,
+            1,
+            Weekday::Mon,
+            3,
+        )); // MLK Day
+        holidays.extend(NaiveDate::from_weekday_of_month_opt(
+            year,
+            2,
+            Weekday::Mon,
+            3,
+        )); // Presidents' Day
+        holidays.extend(
+            easter_sunday(year).and_then(|d| d.checked_sub_signed(chrono::Duration::days(2))),
+        ); // Good Friday
+        holidays.extend(last_weekday_of_month(year, 5, Weekday::Mon)); // Memorial Day
+        if year >= 2022 {
+            holidays.extend(NaiveDate::from_ymd_opt(year, 6, 19).map(us_observed));
+            // Juneteenth
+        }
+        holidays.extend(NaiveDate::from_ymd_opt(year, 7, 4).map(us_observed)); // Independence Day
+        holidays.extend(NaiveDate::from_weekday_of_month_opt(
+            year,
+            9,
+            Weekday::Mon,
+            1,
+        )); // Labor Day
+        let thanksgiving = NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Thu, 4);
+        holidays.extend(thanksgiving); // Thanksgiving Day
+        holidays.extend(NaiveDate::from_ymd_opt(year, 12, 25).map(us_observed)); // Christmas
+
+        for date in holidays {
+            self.add_holiday("US", date);
+        }
+
+        // 독립기념일 전날·크리스마스 이브는 월~목일 때만 반일장 (금요일이면 대체 휴장일)
+        let early_close = NaiveTime::from_hms_opt(13, 0, 0).unwrap_or(NaiveTime::MIN);
+        let eves = [(7, 3), (12, 24)]
+            .into_iter()
+            .filter_map(|(month, day)| NaiveDate::from_ymd_opt(year, month, day))
+            .filter(|d| d.weekday().number_from_monday() <= 4);
+        let black_friday = thanksgiving.and_then(|d| d.succ_opt());
+        for date in eves.chain(black_friday) {
+            self.add_half_day("US", date, early_close);
+        }
+        self.mark_year_covered("US", year);
+    }
+
+    /// 일본(JPX) 휴장일 로드 (규칙 기반).
+    ///
+    /// 국민의 축일, 대체휴일(일요일 축일 → 다음 평일), 국민의 휴일(축일 사이 평일)과
+    /// 거래소 연말연시 휴장일(1/2, 1/3, 12/31)을 포함합니다.
+    pub fn load_jp_holidays(&mut self, year: i32) {
+        let mut national: std::collections::BTreeSet<NaiveDate> = [
+            NaiveDate::from_ymd_opt(year, 1, 1), // 元日
+            NaiveDate::from_weekday_of_month_opt(year, 1, Weekday::Mon, 2), // 成人の日
+            NaiveDate::from_ymd_opt(year, 2, 11), // 建国記念の日
+            NaiveDate::from_ymd_opt(year, 2, 23), // 天皇誕生日
+            jp_equinox(year, 20.8431).and_then(|d| NaiveDate::from_ymd_opt(year, 3, d)), // 春分の日
+            NaiveDate::from_ymd_opt(year, 4, 29), // 昭和の日
+            NaiveDate::from_ymd_opt(year, 5, 3), // 憲法記念日
+            NaiveDate::from_ymd_opt(year, 5, 4), // みどりの日
+            NaiveDate::from_ymd_opt(year, 5, 5), // こどもの日
+            NaiveDate::from_weekday_of_month_opt(year, 7, Weekday::Mon, 3), // 海の日
+            NaiveDate::from_ymd_opt(year, 8, 11), // 山の日
+            NaiveDate::from_weekday_of_month_opt(year, 9, Weekday::Mon, 3), // 敬老の日
+            jp_equinox(year, 23.2488).and_then(|d| NaiveDate::from_ymd_opt(year, 9, d)), // 秋分の日
+            NaiveDate::from_weekday_of_month_opt(year, 10, Weekday::Mon, 2), // スポーツの日
+            NaiveDate::from_ymd_opt(year, 11, 3), // 文化の日
+            NaiveDate::from_ymd_opt(year, 11, 23), // 勤労感謝の日
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        // 대체휴일: 일요일 축일 → 축일이 아닌 다음 날
+        let substitutes: Vec<NaiveDate> = national
+            .iter()
+            .filter(|d| d.weekday() == Weekday::Sun)
+            .filter_map(|d| {
+                let mut next = d.succ_opt()?;
+                while national.contains(&next) {
+                    next = next.succ_opt()?;
+                }
+                Some(next)
+            })
+            .collect();
+
+        // 국민의 휴일: 앞뒤가 모두 축일인 평일
+        let sandwiched: Vec<NaiveDate> = national
+            .iter()
+            .filter_map(|d| {
+                let between = d.succ_opt()?;
+                let after = between.succ_opt()?;
+                (national.contains(&after)
+                    && !national.contains(&between)
+                    && between.weekday() != Weekday::Sun)
+                    .then_some(between)
+            })
+            .collect();
+
+        national.extend(substitutes);
+        national.extend(sandwiched);
+        national.extend(
+            [
+                NaiveDate::from_ymd_opt(year, 1, 2),
+                NaiveDate::from_ymd_opt(year, 1, 3),
+                NaiveDate::from_ymd_opt(year, 12, 31),
+            ]
+            .into_iter()
+            .flatten(),
+        );
+
+        for date in national {
+            self.add_holiday("JP", date);
+        }
+        self.mark_year_covered("JP", year);
+    }
+
+    /// 휴장일 추가.
+    pub fn add_holiday(&mut self, market: &str, date: NaiveDate) {
+        let key = calendar_market_code(market);
+        self.covered_years
+            .entry(key.clone())
+            .or_default()
+            .insert(date.year());
+        self.holidays.entry(key).or_default().insert(date);
+    }
+
+    /// 반일장 추가 (현지 조기 마감 시각 지정).
+    pub fn add_half_day(&mut self, market: &str, date: NaiveDate, close_time: NaiveTime) {
+        self.half_days
+            .entry(calendar_market_code(market))
+            .or_default()
+            .insert(date, close_time);
+    }
+
+    /// 해당 연도의 휴장일 데이터가 완비되었음을 표시.
+    ///
+    /// 휴장일이 없는 연도라도 데이터가 있다는 사실을 구분하기 위해 사용합니다.
+    pub fn mark_year_covered(&mut self, market: &str, year: i32) {
+        self.covered_years
+            .entry(calendar_market_code(market))
+            .or_default()
+            .insert(year);
+    }
+
+    /// 해당 시장/연도의 휴장일 데이터 보유 여부.
+    ///
+    /// false면 주말만 휴장으로 간주하는 폴백이 적용됩니다.
+    pub fn has_holiday_data(&self, market: &str, year: i32) -> bool {
+        self.covered_years
+            .get(&calendar_market_code(market))
+            .is_some_and(|years| years.contains(&year))
+    }
+
+    /// 날짜의 거래일 구분 조회.
+    pub fn day_kind(&self, market: &str, date: NaiveDate) -> TradingDayKind {
+        let key = calendar_market_code(market);
+        if key == "CRYPTO" {
+            return TradingDayKind::Regular;
+        }
+        if matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            return TradingDayKind::Weekend;
+        }
+        if self
+            .holidays
+            .get(&key)
+            .is_some_and(|dates| dates.contains(&date))
+        {
+            return TradingDayKind::Holiday;
+        }
+        if self
+            .half_days
+            .get(&key)
+            .is_some_and(|dates| dates.contains_key(&date))
+        {
+            return TradingDayKind::HalfDay;
+        }
+        TradingDayKind::Regular
+    }
+
+    /// 거래일 여부 (반일장 포함).
+    pub fn is_trading_day(&self, market: &str, date: NaiveDate) -> bool {
+        self.day_kind(market, date).is_trading()
+    }
+
+    /// 휴장일 여부 (주말 제외).
+    pub fn is_holiday(&self, market: &str, date: NaiveDate) -> bool {
+        self.day_kind(market, date) == TradingDayKind::Holiday
+    }
+
+    /// 반일장의 조기 마감 시각 (현지 시간). 반일장이 아니면 None.
+    pub fn early_close(&self, market: &str, date: NaiveDate) -> Option<NaiveTime> {
+        self.half_days
+            .get(&calendar_market_code(market))
+            .and_then(|dates| dates.get(&date))
+            .copied()
+    }
+
+    /// `date` 이후(미포함) 첫 거래일.
+    pub fn next_trading_day(&self, market: &str, date: NaiveDate) -> NaiveDate {
+        self.step_trading_day(market, date, 1)
+    }
+
+    /// `date` 이전(미포함) 마지막 거래일.
+    pub fn prev_trading_day(&self, market: &str, date: NaiveDate) -> NaiveDate {
+        self.step_trading_day(market, date, -1)
+    }
+
+    /// `date`로부터 N거래일 후의 날짜 (`n == 0`이면 `date` 그대로).
+    pub fn add_trading_days(&self, market: &str, date: NaiveDate, n: u32) -> NaiveDate {
+        (0..n).fold(date, |d, _| self.next_trading_day(market, d))
+    }
+
+    /// `start` ~ `end` (양 끝 포함) 사이의 거래일 목록.
+    pub fn trading_days_between(
+        &self,
+        market: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Vec<NaiveDate> {
+        start
+            .iter_days()
+            .take_while(|d| *d <= end)
+            .filter(|d| self.is_trading_day(market, *d))
+            .collect()
+    }
+
+    /// `start` ~ `end` (양 끝 포함) 사이의 거래일 수.
+    pub fn count_trading_days(&self, market: &str, start: NaiveDate, end: NaiveDate) -> usize {
+        start
+            .iter_days()
+            .take_while(|d| *d <= end)
+            .filter(|d| self.is_trading_day(market, *d))
+            .count()
+    }
+
+    /// 한 방향으로 다음 거래일 탐색.
+    fn step_trading_day(&self, market: &str, date: NaiveDate, direction: i64) -> NaiveDate {
+        let mut current = date;
+        for _ in 0..MAX_SEARCH_DAYS {
+            match current.checked_add_signed(chrono::Duration::days(direction)) {
+                Some(next) => current = next,
+                None => return current,
+            }
+            if self.is_trading_day(market, current) {
+                return current;
+            }
+        }
+        current
+    }
+}
+
+/// 미국 휴일 관측일 (토요일 → 금요일, 일요일 → 월요일).
+fn us_observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date.pred_opt().unwrap_or(date),
+        Weekday::Sun => date.succ_opt().unwrap_or(date),
+        _ => date,
+    }
+}
+
+/// 해당 월의 마지막 특정 요일.
+fn last_weekday_of_month(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    let mut date = NaiveDate::from_ymd_opt(next_year, next_month, 1)?.pred_opt()?;
+    while date.weekday() != weekday {
+        date = date.pred_opt()?;
+    }
+    Some(date)
+}
+
+/// 부활절 일요일 (그레고리력, Anonymous Gregorian algorithm).
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// 일본 춘분/추분일 (1980~2099년 근사식). `base`는 춘분 20.8431, 추분 23.2488.
+fn jp_equinox(year: i32, base: f64) -> Option<u32> {
+    if !(1980..=2099).contains(&year) {
+        return None;
+    }
+    let offset = year - 1980;
+    let day = (base + 0.242194 * offset as f64).floor() as i32 - offset / 4;
+    u32::try_from(day).ok()
+}
+
+/// 전역 캘린더 기준 거래일 여부.
+pub fn is_trading_day(market: &str, date: NaiveDate) -> bool {
+    TradingCalendar::global().is_trading_day(market, date)
+}
+
+/// 전역 캘린더 기준 `date` 이후(미포함) 첫 거래일.
+pub fn next_trading_day(market: &str, date: NaiveDate) -> NaiveDate {
+    TradingCalendar::global().next_trading_day(market, date)
+}
+
+/// 전역 캘린더 기준 `date` 이전(미포함) 마지막 거래일.
+pub fn prev_trading_day(market: &str, date: NaiveDate) -> NaiveDate {
+    TradingCalendar::global().prev_trading_day(market, date)
+}
+
+/// 전역 캘린더 기준 N거래일 후의 날짜.
+pub fn add_trading_days(market: &str, date: NaiveDate, n: u32) -> NaiveDate {
+    TradingCalendar::global().add_trading_days(market, date, n)
+}
+
+/// 전역 캘린더 기준 `start` ~ `end` (양 끝 포함) 거래일 목록.
+pub fn trading_days_between(market: &str, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+    TradingCalendar::global().trading_days_between(market, start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_kr_year_end_boundary() {
+        let calendar = TradingCalendar::with_builtin_holidays();
+
+        // 12/31 연말 휴장 + 1/1 신정
+        assert!(calendar.is_holiday("KR", date(2025, 12, 31)));
+        assert_eq!(
+            calendar.next_trading_day("KR", date(2025, 12, 30)),
+            date(2026, 1, 2)
+        );
+        assert_eq!(
+            calendar.prev_trading_day("KR", date(2026, 1, 2)),
+            date(2025, 12, 30)
+        );
+    }
+
+    #[test]
+    fn test_kr_lunar_new_year_holidays() {
+        let calendar = TradingCalendar::with_builtin_holidays();
+
+        // 2026 설 연휴: 2/16(월) ~ 2/18(수), 직전 금요일 2/13
+        assert_eq!(
+            calendar.next_trading_day("KR", date(2026, 2, 13)),
+            date(2026, 2, 19)
+        );
+        // 2/9(월) ~ 2/20(금): 10 평일 - 3 휴장 = 7 거래일
+        assert_eq!(
+            calendar.count_trading_days("KR", date(2026, 2, 9), date(2026, 2, 20)),
+            7
+        );
+        // 5거래일 후: 2/13 → 2/19, 2/20, 2/23, 2/24, 2/25
+        assert_eq!(
+            calendar.add_trading_days("KR", date(2026, 2, 13), 5),
+            date(2026, 2, 25)
+        );
+    }
+
+    #[test]
+    fn test_weekend_only_fallback_for_uncovered_year() {
+        let calendar = TradingCalendar::with_builtin_holidays();
+
+        assert!(!calendar.has_holiday_data("KR", 2030));
+        // 2030-01-01은 화요일: 데이터가 없으므로 거래일로 간주
+        assert!(calendar.is_trading_day("KR", date(2030, 1, 1)));
+        // 2030-01-04(금) → 다음 거래일은 월요일
+        assert_eq!(
+            calendar.next_trading_day("KR", date(2030, 1, 4)),
+            date(2030, 1, 7)
+        );
+    }
+
+    #[test]
+    fn test_load_us_holidays_matches_builtin() {
+        let mut calendar = TradingCalendar::new();
+        calendar.load_us_holidays(2026);
+
+        let builtin = TradingCalendar::with_builtin_holidays();
+        for date in NaiveDate::from_ymd_opt(2026, 1, 1)
+            .unwrap()
+            .iter_days()
+            .take_while(|d| d.year() == 2026)
+        {
+            assert_eq!(
+                calendar.day_kind("US", date),
+                builtin.day_kind("US", date),
+                "{date}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_jp_holidays_substitutes() {
+        let mut calendar = TradingCalendar::new();
+        calendar.load_jp_holidays(2025);
+
+        let holidays = [
+            (1, 2),   // 연초 휴장
+            (1, 13),  // 成人の日
+            (2, 24),  // 天皇誕生日 대체휴일
+            (3, 20),  // 春分の日
+            (5, 6),   // 憲法記念日·みどりの日 대체휴일
+            (9, 23),  // 秋分の日
+            (11, 24), // 勤労感謝の日 대체휴일
+            (12, 31), // 연말 휴장
+        ];
+        for (m, d) in holidays {
+            let date = NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+            assert!(calendar.is_holiday("JP", date), "{date}");
+        }
+        assert!(!calendar.is_holiday("JP", NaiveDate::from_ymd_opt(2025, 5, 7).unwrap()));
+        assert!(calendar.has_holiday_data("JPX", 2025));
+    }
+
+    #[test]
+    fn test_global_fills_rule_based_years() {
+        let this_year = Utc::now().year();
+        let calendar = TradingCalendar::global();
+        for year in this_year - 1..=this_year + 1 {
+            assert!(calendar.has_holiday_data("US", year), "US {year}");
+            assert!(calendar.has_holiday_data("JP", year), "JP {year}");
+        }
+    }
+
+    #[test]
+    fn test_us_half_day_and_crypto() {
+        let calendar = TradingCalendar::with_builtin_holidays();
+
+        assert_eq!(
+            calendar.day_kind("NYSE", date(2025, 11, 28)),
+            TradingDayKind::HalfDay
+        );
+        assert!(calendar.is_trading_day("US", date(2025, 11, 28)));
+        assert_eq!(
+            calendar.early_close("US", date(2025, 11, 28)),
+            NaiveTime::from_hms_opt(13, 0, 0)
+        );
+        assert_eq!(
+            calendar.next_trading_day("US", date(2025, 11, 26)),
+            date(2025, 11, 28)
+        );
+
+        // 암호화폐: 주말/연휴 구분 없음
+        assert!(calendar.is_trading_day("CRYPTO", date(2025, 12, 25)));
+        assert_eq!(
+            calendar
+                .trading_days_between("crypto", date(2025, 12, 27), date(2025, 12, 28))
+                .len(),
+            2
+        );
+    }
+}
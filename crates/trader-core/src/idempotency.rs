@@ -0,0 +1,631 @@
+//! 멱등 작업 토큰 및 중복 실행 방지 유틸리티.
+//!
+//! 작업 단위에 멱등 키를 부여하여 이미 처리된 작업을 건너뛰고,
+//! 동시에 실행될 때는 하나만 진행하도록 보장합니다.
+//!
+//! # 구조
+//!
+//! ```text
+//! IdempotencyKey            // 스코프 + 작업 식별자 (order / webhook / collect / signal)
+//! IdempotencyStore (trait)  // 처리 상태 저장소 (TTL 포함)
+//! ├── InMemoryIdempotencyStore   // 단일 프로세스용 (trader-core)
+//! ├── RedisIdempotencyStore      // 분산 환경용 (trader-data)
+//! └── PgIdempotencyStore         // 분산 환경용 (trader-data)
+//! IdempotencyGuard          // 락 획득 → 실행 → 완료 기록 (결과 캐시 옵션)
+//! ```
+//!
+//! # 상태 전이
+//!
+//! ```text
+//! (없음) ──try_acquire──▶ InProgress ──complete──▶ Completed (result_ttl 동안 유지)
+//!                             │
+//!                             └──release (실패 시)──▶ (없음)  // 재시도 가능
+//! ```
+//!
+//! 실행 중 프로세스가 죽으면 `lock_ttl` 경과 후 락이 만료되어 다른 실행자가 재획득합니다.
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! let guard = IdempotencyGuard::in_memory().with_cached_result(true);
+//! let key = IdempotencyKey::webhook("slack", &notification.id);
+//!
+//! match guard.run(&key, || async { send(&notification).await }).await? {
+//!     IdempotentOutcome::Executed(resp) => { /* 이번 호출에서 처리 */ }
+//!     IdempotentOutcome::Completed(cached) => { /* 이미 처리됨 (캐시된 결과) */ }
+//!     IdempotentOutcome::InProgress => { /* 다른 실행자가 처리 중 */ }
+//! }
+//! ```
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+// ==================== 키 ====================
+
+/// 주문 스코프 (거래소 client_order_id).
+pub const ORDER_SCOPE: &str = "order";
+/// 웹훅/알림 전송 스코프.
+pub const WEBHOOK_SCOPE: &str = "webhook";
+/// 데이터 수집 스코프.
+pub const COLLECT_SCOPE: &str = "collect";
+/// 전략 신호 처리 스코프.
+pub const SIGNAL_SCOPE: &str = "signal";
+
+/// client_order_id 최대 길이 (Binance `newClientOrderId`, Bybit `orderLinkId` 기준).
+pub const MAX_CLIENT_ORDER_ID_LEN: usize = 36;
+
+/// 멱등 작업 키.
+///
+/// 같은 스코프 안에서 같은 `id`를 가진 작업은 한 번만 실행됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IdempotencyKey {
+    scope: String,
+    id: String,
+}
+
+impl IdempotencyKey {
+    /// 임의 스코프의 키 생성.
+    pub fn new(scope: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            scope: scope.into(),
+            id: id.into(),
+        }
+    }
+
+    /// 주문 키 생성 (`{prefix}_{id}`).
+    ///
+    /// [`client_order_id`](Self::client_order_id)를 거래소에 전달하면
+    /// 재시도 시에도 거래소가 같은 주문으로 식별합니다.
+    /// [`MAX_CLIENT_ORDER_ID_LEN`]을 넘으면 `id` 대신 `{prefix}_{id}`의 SHA-256 해시 앞부분을 사용합니다.
+    pub fn order(prefix: &str, id: impl fmt::Display) -> Self {
+        Self::new(ORDER_SCOPE, compact_order_id(prefix, &id.to_string()))
+    }
+
+    /// 웹훅/알림 전송 키 생성 (`{channel}:{event_id}`).
+    pub fn webhook(channel: &str, event_id: impl fmt::Display) -> Self {
+        Self::new(WEBHOOK_SCOPE, format!("{}:{}", channel, event_id))
+    }
+
+    /// 데이터 수집 키 생성 (`{job}:{window}`).
+    ///
+    /// `window`는 수집 대상 구간(예: 날짜, 주기 시작 시각)입니다.
+    pub fn collection(job: &str, window: impl fmt::Display) -> Self {
+        Self::new(COLLECT_SCOPE, format!("{}:{}", job, window))
+    }
+
+    /// 신호 처리 키 생성 (`Signal::dedup_id`).
+    ///
+    /// 같은 신호가 중복 수신되어도 한 번만 주문으로 이어지도록 사용합니다.
+    pub fn signal(dedup_id: impl fmt::Display) -> Self {
+        Self::new(SIGNAL_SCOPE, dedup_id.to_string())
+    }
+
+    /// 스코프.
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// 스코프 내 작업 식별자.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// 거래소에 전달할 client_order_id.
+    pub fn client_order_id(&self) -> String {
+        self.id.clone()
+    }
+
+    /// 저장소 키 (`{scope}:{id}`).
+    pub fn storage_key(&self) -> String {
+        format!("{}:{}", self.scope, self.id)
+    }
+}
+
+/// 길이 제한에 맞춘 주문 식별자 (같은 입력이면 항상 같은 값).
+fn compact_order_id(prefix: &str, id: &str) -> String {
+    let full = format!("{}_{}", prefix, id);
+    if full.len() <= MAX_CLIENT_ORDER_ID_LEN {
+        return full;
+    }
+
+    // 해시가 충분히 남도록 prefix는 절반까지만 유지
+    let prefix: String = prefix
+        .chars()
+        .filter(char::is_ascii)
+        .take(MAX_CLIENT_ORDER_ID_LEN / 2)
+        .collect();
+    let digest = hex::encode(Sha256::digest(full.as_bytes()));
+    let hash_len = MAX_CLIENT_ORDER_ID_LEN - prefix.len() - 1;
+    format!("{}_{}", prefix, &digest[..hash_len])
+}
+
+impl fmt::Display for IdempotencyKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.scope, self.id)
+    }
+}
+
+// ==================== 저장소 ====================
+
+/// 멱등 처리 에러.
+#[derive(Debug, Error)]
+pub enum IdempotencyError {
+    /// 저장소 접근 실패
+    #[error("멱등 저장소 에러: {0}")]
+    Store(String),
+
+    /// 결과 직렬화/역직렬화 실패
+    #[error("멱등 결과 직렬화 에러: {0}")]
+    Serialization(String),
+}
+
+/// 락 획득 시도 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdempotencyStatus {
+    /// 락 획득 - 호출자가 작업을 실행해야 함
+    Acquired,
+    /// 다른 실행자가 처리 중
+    InProgress,
+    /// 이미 완료됨 (결과 캐시 사용 시 결과 포함)
+    Completed(Option<Value>),
+}
+
+/// 멱등 처리 상태 저장소.
+///
+/// 구현체는 `try_acquire`를 원자적으로 수행해야 합니다
+/// (동시에 호출되어도 하나만 `Acquired`를 받아야 함).
+#[async_trait]
+pub trait IdempotencyStore: Send + Sync {
+    /// 락 획득 시도.
+    ///
+    /// 키가 없거나 만료되었으면 `lock_ttl` 동안 유효한 진행 중 상태로 기록하고
+    /// `Acquired`를 반환합니다.
+    async fn try_acquire(
+        &self,
+        key: &IdempotencyKey,
+        lock_ttl: Duration,
+    ) -> Result<IdempotencyStatus, IdempotencyError>;
+
+    /// 완료 기록 (결과는 `ttl` 동안 유지).
+    async fn complete(
+        &self,
+        key: &IdempotencyKey,
+        result: Option<Value>,
+        ttl: Duration,
+    ) -> Result<(), IdempotencyError>;
+
+    /// 진행 중 락 해제 (작업 실패 시 재시도를 허용하기 위해 사용).
+    ///
+    /// 이미 완료된 키는 삭제하지 않습니다.
+    async fn release(&self, key: &IdempotencyKey) -> Result<(), IdempotencyError>;
+}
+
+/// 메모리 저장 항목.
+#[derive(Debug, Clone)]
+struct MemoryEntry {
+    /// 완료 결과 (`None`이면 진행 중)
+    completed: Option<Option<Value>>,
+    expires_at: Instant,
+}
+
+/// 단일 프로세스용 메모리 저장소.
+///
+/// 만료된 항목은 접근 시 정리됩니다.
+#[derive(Debug, Default)]
+pub struct InMemoryIdempotencyStore {
+    entries: Mutex<HashMap<String, MemoryEntry>>,
+}
+
+impl InMemoryIdempotencyStore {
+    /// 빈 저장소 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 만료되지 않은 항목 수.
+    pub async fn len(&self) -> usize {
+        let now = Instant::now();
+        self.entries
+            .lock()
+            .await
+            .values()
+            .filter(|e| e.expires_at > now)
+            .count()
+    }
+
+    /// 만료되지 않은 항목이 없는지 여부.
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    async fn try_acquire(
+        &self,
+        key: &IdempotencyKey,
+        lock_ttl: Duration,
+    ) -> Result<IdempotencyStatus, IdempotencyError> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().await;
+        entries.retain(|_, e| e.expires_at > now);
+
+        let storage_key = key.storage_key();
+        if let Some(entry) = entries.get(&storage_key) {
+            return Ok(match &entry.completed {
+                Some(result) => IdempotencyStatus::Completed(result.clone()),
+                None => IdempotencyStatus::InProgress,
+            });
+        }
+
+        entries.insert(
+            storage_key,
+            MemoryEntry {
+                completed: None,
+                expires_at: now + lock_ttl,
+            },
+        );
+        Ok(IdempotencyStatus::Acquired)
+    }
+
+    async fn complete(
+        &self,
+        key: &IdempotencyKey,
+        result: Option<Value>,
+        ttl: Duration,
+    ) -> Result<(), IdempotencyError> {
+        self.entries.lock().await.insert(
+            key.storage_key(),
+            MemoryEntry {
+                completed: Some(result),
+                expires_at: Instant::now() + ttl,
+            },
+        );
+        Ok(())
+    }
+
+    async fn release(&self, key: &IdempotencyKey) -> Result<(), IdempotencyError> {
+        let mut entries = self.entries.lock().await;
+        let storage_key = key.storage_key();
+        if entries
+            .get(&storage_key)
+            .is_some_and(|e| e.completed.is_none())
+        {
+            entries.remove(&storage_key);
+        }
+        Ok(())
+    }
+}
+
+// ==================== 실행기 ====================
+
+/// 멱등 실행 설정.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdempotencyConfig {
+    /// 진행 중 락 유지 시간 (실행자가 죽었을 때 재획득까지의 대기 시간)
+    pub lock_ttl: Duration,
+    /// 완료 기록 유지 시간 (이 기간 동안 같은 키는 건너뜀)
+    pub result_ttl: Duration,
+    /// 작업 결과 캐시 여부 (중복 호출 시 같은 결과 반환)
+    pub cache_result: bool,
+}
+
+impl Default for IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            lock_ttl: Duration::from_secs(5 * 60),
+            result_ttl: Duration::from_secs(24 * 60 * 60),
+            cache_result: false,
+        }
+    }
+}
+
+/// 멱등 실행 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdempotentOutcome<T> {
+    /// 이번 호출에서 실행됨
+    Executed(T),
+    /// 이미 완료된 작업 (결과 캐시 사용 시 이전 결과)
+    Completed(Option<T>),
+    /// 다른 실행자가 처리 중
+    InProgress,
+}
+
+impl<T> IdempotentOutcome<T> {
+    /// 이번 호출에서 실행되었는지 여부.
+    pub fn is_executed(&self) -> bool {
+        matches!(self, Self::Executed(_))
+    }
+
+    /// 실행 결과 또는 캐시된 이전 결과.
+    pub fn into_value(self) -> Option<T> {
+        match self {
+            Self::Executed(value) => Some(value),
+            Self::Completed(value) => value,
+            Self::InProgress => None,
+        }
+    }
+}
+
+/// 락과 결합된 멱등 실행기.
+///
+/// 주문 재시도, 웹훅 재전송, 수집 재실행이 같은 규칙으로 중복을 방지하도록
+/// 저장소를 공유하여 사용합니다.
+#[derive(Clone)]
+pub struct IdempotencyGuard {
+    store: Arc<dyn IdempotencyStore>,
+    config: IdempotencyConfig,
+}
+
+impl IdempotencyGuard {
+    /// 저장소를 지정하여 생성.
+    pub fn new(store: Arc<dyn IdempotencyStore>) -> Self {
+        Self {
+            store,
+            config: IdempotencyConfig::default(),
+        }
+    }
+
+    /// 메모리 저장소로 생성 (단일 프로세스용).
+    pub fn in_memory() -> Self {
+        Self::new(Arc::new(InMemoryIdempotencyStore::new()))
+    }
+
+    /// 설정 지정 (빌더 패턴).
+    pub fn with_config(mut self, config: IdempotencyConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// 결과 캐시 여부 설정 (빌더 패턴).
+    pub fn with_cached_result(mut self, cache_result: bool) -> Self {
+        self.config.cache_result = cache_result;
+        self
+    }
+
+    /// 완료 기록 유지 시간 설정 (빌더 패턴).
+    pub fn with_result_ttl(mut self, ttl: Duration) -> Self {
+        self.config.result_ttl = ttl;
+        self
+    }
+
+    /// 설정 참조.
+    pub fn config(&self) -> &IdempotencyConfig {
+        &self.config
+    }
+
+    /// 저장소 참조.
+    pub fn store(&self) -> &Arc<dyn IdempotencyStore> {
+        &self.store
+    }
+
+    /// 멱등 키로 작업을 실행합니다.
+    ///
+    /// - 처음 호출: 락 획득 후 실행, 성공 시 완료 기록 (`Executed`)
+    /// - 이미 완료: 실행하지 않음 (`Completed`, 결과 캐시 시 이전 결과 포함)
+    /// - 처리 중: 실행하지 않음 (`InProgress`)
+    ///
+    /// 작업이 실패하면 락을 해제하여 같은 키로 재시도할 수 있게 합니다.
+    pub async fn run<T, E, F, Fut>(
+        &self,
+        key: &IdempotencyKey,
+        operation: F,
+    ) -> Result<IdempotentOutcome<T>, E>
+    where
+        T: Serialize + DeserializeOwned,
+        E: From<IdempotencyError>,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        match self.store.try_acquire(key, self.config.lock_ttl).await? {
+            IdempotencyStatus::Acquired => {}
+            IdempotencyStatus::InProgress => {
+                tracing::debug!(key = %key, "멱등 작업 처리 중 - 건너뜀");
+                return Ok(IdempotentOutcome::InProgress);
+            }
+            IdempotencyStatus::Completed(result) => {
+                tracing::debug!(key = %key, "멱등 작업 이미 완료 - 건너뜀");
+                let cached = match result.filter(|_| self.config.cache_result) {
+                    Some(value) => Some(
+                        serde_json::from_value(value)
+                            .map_err(|e| IdempotencyError::Serialization(e.to_string()))?,
+                    ),
+                    None => None,
+                };
+                return Ok(IdempotentOutcome::Completed(cached));
+            }
+        }
+
+        match operation().await {
+            Ok(value) => {
+                let result = if self.config.cache_result {
+                    Some(
+                        serde_json::to_value(&value)
+                            .map_err(|e| IdempotencyError::Serialization(e.to_string()))?,
+                    )
+                } else {
+                    None
+                };
+                self.store
+                    .complete(key, result, self.config.result_ttl)
+                    .await?;
+                Ok(IdempotentOutcome::Executed(value))
+            }
+            Err(e) => {
+                if let Err(release_err) = self.store.release(key).await {
+                    tracing::warn!(key = %key, error = %release_err, "멱등 락 해제 실패");
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for IdempotencyGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IdempotencyGuard")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[derive(Debug, Error)]
+    enum TestError {
+        #[error("failed")]
+        Failed,
+        #[error(transparent)]
+        Idempotency(#[from] IdempotencyError),
+    }
+
+    #[test]
+    fn test_key_formats() {
+        let key = IdempotencyKey::order("sig", "abc");
+        assert_eq!(key.scope(), ORDER_SCOPE);
+        assert_eq!(key.client_order_id(), "sig_abc");
+        assert_eq!(key.storage_key(), "order:sig_abc");
+
+        // 길이 제한을 넘는 id는 결정적 해시로 축약
+        let id = "6f1c2a9e-8b3d-4c7a-9e21-5d8f0b3a7c64";
+        let long = IdempotencyKey::order("sig_exit", id).client_order_id();
+        assert_eq!(long.len(), MAX_CLIENT_ORDER_ID_LEN);
+        assert!(long.starts_with("sig_exit_"));
+        assert_eq!(
+            long,
+            IdempotencyKey::order("sig_exit", id).client_order_id()
+        );
+        assert_ne!(long, IdempotencyKey::order("sig_add", id).client_order_id());
+        let long_prefix = IdempotencyKey::order(&"x".repeat(40), id).client_order_id();
+        assert_eq!(long_prefix.len(), MAX_CLIENT_ORDER_ID_LEN);
+
+        assert_eq!(
+            IdempotencyKey::webhook("slack", "n1").to_string(),
+            "webhook:slack:n1"
+        );
+        assert_eq!(
+            IdempotencyKey::collection("ohlcv", "2026-01-02").id(),
+            "ohlcv:2026-01-02"
+        );
+        assert_eq!(
+            IdempotencyKey::signal("0123abcd").storage_key(),
+            "signal:0123abcd"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_guard_skips_completed_and_replays_cached_result() {
+        let guard = IdempotencyGuard::in_memory().with_cached_result(true);
+        let key = IdempotencyKey::webhook("slack", "n1");
+        let calls = AtomicUsize::new(0);
+
+        for _ in 0..3 {
+            let outcome = guard
+                .run(&key, || async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Ok::<_, TestError>(42u32)
+                })
+                .await
+                .unwrap();
+            assert_eq!(outcome.into_value(), Some(42));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // 결과 캐시를 끄면 완료 여부만 반환
+        let plain = IdempotencyGuard::new(guard.store().clone());
+        let outcome = plain
+            .run(&key, || async { Ok::<_, TestError>(0u32) })
+            .await
+            .unwrap();
+        assert_eq!(outcome, IdempotentOutcome::Completed(None));
+    }
+
+    #[tokio::test]
+    async fn test_guard_releases_lock_on_failure() {
+        let guard = IdempotencyGuard::in_memory();
+        let key = IdempotencyKey::order("sig", "retry");
+
+        let result = guard
+            .run(&key, || async { Err::<u32, _>(TestError::Failed) })
+            .await;
+        assert!(matches!(result, Err(TestError::Failed)));
+
+        let outcome = guard
+            .run(&key, || async { Ok::<_, TestError>(7u32) })
+            .await
+            .unwrap();
+        assert_eq!(outcome, IdempotentOutcome::Executed(7));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_runs_execute_once() {
+        let guard = IdempotencyGuard::in_memory();
+        let key = IdempotencyKey::collection("ohlcv", "w1");
+        let (started_tx, started_rx) = tokio::sync::oneshot::channel();
+        let (finish_tx, finish_rx) = tokio::sync::oneshot::channel::<()>();
+
+        let first = {
+            let guard = guard.clone();
+            let key = key.clone();
+            tokio::spawn(async move {
+                guard
+                    .run(&key, || async move {
+                        started_tx.send(()).unwrap();
+                        finish_rx.await.unwrap();
+                        Ok::<_, TestError>(1u32)
+                    })
+                    .await
+                    .unwrap()
+            })
+        };
+
+        started_rx.await.unwrap();
+        let second = guard
+            .run(&key, || async { Ok::<_, TestError>(2u32) })
+            .await
+            .unwrap();
+        assert_eq!(second, IdempotentOutcome::InProgress);
+
+        finish_tx.send(()).unwrap();
+        assert!(first.await.unwrap().is_executed());
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_expires_entries() {
+        let store = InMemoryIdempotencyStore::new();
+        let key = IdempotencyKey::new("test", "ttl");
+
+        store.complete(&key, None, Duration::ZERO).await.unwrap();
+        assert!(store.is_empty().await);
+        assert_eq!(
+            store
+                .try_acquire(&key, Duration::from_secs(60))
+                .await
+                .unwrap(),
+            IdempotencyStatus::Acquired
+        );
+        assert_eq!(
+            store
+                .try_acquire(&key, Duration::from_secs(60))
+                .await
+                .unwrap(),
+            IdempotencyStatus::InProgress
+        );
+    }
+}
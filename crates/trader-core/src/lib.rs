@@ -11,14 +11,18 @@
 //! - 설정 관리
 //! - 로깅 인프라
 //! - 자격증명 암호화
+//! - 멱등 작업 토큰 및 중복 실행 방지
+//! - Prometheus 메트릭 계측 헬퍼
 
 pub mod cache;
 pub mod config;
 pub mod crypto;
 pub mod domain;
 pub mod error;
+pub mod idempotency;
 pub mod logging;
 pub mod migration;
+pub mod telemetry;
 pub mod types;
 
 pub use cache::{ExchangeCache, ExchangeCacheConfig, TtlCache};
@@ -26,5 +30,9 @@ pub use config::*;
 pub use crypto::{CredentialEncryptor, CryptoError, ExchangeCredentials};
 pub use domain::*;
 pub use error::*;
+pub use idempotency::{
+    IdempotencyConfig, IdempotencyError, IdempotencyGuard, IdempotencyKey, IdempotencyStatus,
+    IdempotencyStore, IdempotentOutcome, InMemoryIdempotencyStore, MAX_CLIENT_ORDER_ID_LEN,
+};
 pub use logging::*;
 pub use types::*;
@@ -4,6 +4,8 @@
 //! - **pretty**: 개발용 사람이 읽기 쉬운 형식
 //! - **json**: 운영환경/로그 집계용 JSON 형식
 //! - **compact**: 로그 크기를 줄이기 위한 간결한 형식
+//!
+//! 신호 → 주문 → 거래소 요청 흐름을 묶는 correlation ID도 제공합니다.
 
 use tracing_subscriber::{
     fmt::{self, format::FmtSpan},
@@ -11,6 +13,7 @@ use tracing_subscriber::{
     util::SubscriberInitExt,
     EnvFilter,
 };
+use uuid::Uuid;
 
 /// 로그 출력 형식.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -190,6 +193,29 @@ pub fn init_logging_from_env() -> Result<(), Box<dyn std::error::Error>> {
     init_logging(LogConfig::from_env())
 }
 
+tokio::task_local! {
+    static CORRELATION_ID: String;
+}
+
+/// 주어진 correlation ID 범위 안에서 future를 실행합니다.
+///
+/// 범위 내에서 발생한 모든 거래소 요청 로그에 같은 ID가 기록됩니다.
+pub async fn with_correlation_id<F>(correlation_id: impl Into<String>, fut: F) -> F::Output
+where
+    F: std::future::Future,
+{
+    CORRELATION_ID.scope(correlation_id.into(), fut).await
+}
+
+/// 현재 범위의 correlation ID를 반환합니다.
+///
+/// 범위 밖에서 호출되면 새 ID를 생성합니다 (단일 요청 추적용).
+pub fn current_correlation_id() -> String {
+    CORRELATION_ID
+        .try_with(|id| id.clone())
+        .unwrap_or_else(|_| Uuid::new_v4().to_string())
+}
+
 /// 공통 트레이딩 컨텍스트 필드가 포함된 span을 생성하는 매크로.
 #[macro_export]
 macro_rules! trading_span {
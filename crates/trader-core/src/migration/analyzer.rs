@@ -380,7 +380,7 @@ impl MigrationAnalyzer {
         }
 
         // INSERT INTO
-        if sql_upper.starts_with("INSERT") {
+        if sql_upper.trim_start().starts_with("INSERT") {
             let name = self.extract_insert_table_name(sql_original)?;
             return Some((StatementType::Insert, name));
         }
@@ -447,19 +447,19 @@ impl MigrationAnalyzer {
         let sql_upper = sql.to_uppercase();
 
         // CREATE UNIQUE INDEX IF NOT EXISTS idx_name ON ...
-        // CREATE INDEX IF NOT EXISTS idx_name ON ...
+        // CREATE INDEX CONCURRENTLY IF NOT EXISTS idx_name ON ...
         // CREATE INDEX idx_name ON ...
-        let patterns = [
-            "CREATE UNIQUE INDEX IF NOT EXISTS",
-            "CREATE INDEX IF NOT EXISTS",
-            "CREATE UNIQUE INDEX",
-            "CREATE INDEX",
-        ];
+        let patterns = ["CREATE UNIQUE INDEX", "CREATE INDEX"];
 
         for pattern in patterns {
             if sql_upper.contains(pattern) {
                 let pos = sql_upper.find(pattern)?;
-                let after = &sql[pos + pattern.len()..];
+                let mut after = &sql[pos + pattern.len()..];
+                for keyword in ["CONCURRENTLY", "IF NOT EXISTS"] {
+                    if after.trim_start().to_uppercase().starts_with(keyword) {
+                        after = &after.trim_start()[keyword.len()..];
+                    }
+                }
                 let name = after
                     .split_whitespace()
                     .next()?
@@ -593,7 +593,7 @@ impl MigrationAnalyzer {
     fn extract_insert_table_name(&self, sql: &str) -> Option<String> {
         let sql_upper = sql.to_uppercase();
         let pos = sql_upper.find("INSERT INTO")?;
-        let after = &sql[pos + "INSERT INTO".len()..];
+        let after = sql[pos + "INSERT INTO".len()..].trim_start();
         let name = after
             .split(|c: char| c == '(' || c.is_whitespace())
             .next()?
@@ -621,7 +621,7 @@ impl MigrationAnalyzer {
     }
 
     /// 객체명 정리 (스키마 prefix 제거, 소문자 변환)
-    fn clean_object_name(&self, name: &str) -> String {
+    pub(super) fn clean_object_name(&self, name: &str) -> String {
         // public.table_name → table_name
         let name = if name.contains('.') {
             name.split('.').next_back().unwrap_or(name)
@@ -756,6 +756,78 @@ impl MigrationAnalyzer {
     }
 }
 
+/// ALTER TABLE 문에서 테이블명 뒤의 동작 목록 추출 (최상위 쉼표 기준 분리)
+pub(super) fn alter_table_actions(sql: &str) -> Option<Vec<String>> {
+    let sql = strip_line_comments(sql);
+    let sql = sql.as_str();
+    let sql_upper = sql.to_uppercase();
+    let pos = sql_upper.find("ALTER TABLE")?;
+    let mut rest = sql[pos + "ALTER TABLE".len()..].trim_start();
+
+    for keyword in ["IF EXISTS", "ONLY"] {
+        if rest.to_uppercase().starts_with(keyword) {
+            rest = rest[keyword.len()..].trim_start();
+        }
+    }
+
+    // 테이블명 건너뛰기
+    let name_end = rest.find(char::is_whitespace)?;
+    let body = rest[name_end..].trim().trim_end_matches(';').trim();
+    if body.is_empty() {
+        return None;
+    }
+
+    Some(split_top_level(body))
+}
+
+/// 문장 중간의 `--` 라인 주석 제거 (문자열 리터럴 내부는 유지)
+pub(super) fn strip_line_comments(sql: &str) -> String {
+    let mut output = String::with_capacity(sql.len());
+    for line in sql.lines() {
+        let mut in_quote = false;
+        let mut end = line.len();
+        let mut chars = line.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\'' => in_quote = !in_quote,
+                '-' if !in_quote && chars.peek().is_some_and(|(_, n)| *n == '-') => {
+                    end = i;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        output.push_str(line[..end].trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+/// 괄호와 문자열 리터럴 밖의 쉼표 기준으로 분리 (빈 항목 제외)
+pub(super) fn split_top_level(body: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut in_quote = false;
+    let mut current = String::new();
+    for c in body.chars() {
+        match c {
+            '\'' => in_quote = !in_quote,
+            '(' if !in_quote => depth += 1,
+            ')' if !in_quote => depth = depth.saturating_sub(1),
+            ',' if depth == 0 && !in_quote => {
+                items.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    items.push(current.trim().to_string());
+
+    items.into_iter().filter(|item| !item.is_empty()).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -12,14 +12,24 @@
 //! let files = analyzer.scan_directory("migrations")?;
 //! let validator = MigrationValidator::new(&files);
 //! let report = validator.validate()?;
+//!
+//! // 파일별 롤백 SQL 생성
+//! let rollback = analyzer.generate_rollback(&files[0]);
+//! println!("{}", rollback.to_sql());
 //! ```
 
 pub mod analyzer;
 pub mod consolidator;
 pub mod models;
+pub mod rollback;
+pub mod schema;
 pub mod validator;
 
 pub use analyzer::MigrationAnalyzer;
 pub use consolidator::MigrationConsolidator;
 pub use models::*;
+pub use schema::{
+    normalize_type, ColumnSchema, DriftReport, IndexSchema, SchemaDrift, SchemaSnapshot,
+    TableSchema,
+};
 pub use validator::{generate_safety_checklist, MigrationValidator};
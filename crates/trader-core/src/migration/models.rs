@@ -272,16 +272,30 @@ impl DependencyGraph {
 }
 
 /// 검증 결과 심각도
+///
+/// 위험도 레벨 `info` / `warn` / `critical`에 각각 대응합니다.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Severity {
     /// 정보 (권장사항)
     Info,
     /// 경고 (수정 권장)
     Warning,
-    /// 에러 (수정 필수)
+    /// 에러 (수정 필수, critical)
     Error,
 }
 
+impl Severity {
+    /// 위험도 레벨 문자열에서 파싱 (info, warn, critical)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "info" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warning),
+            "critical" | "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for Severity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -418,6 +432,14 @@ impl ValidationReport {
         self.error_count() == 0
     }
 
+    /// 지정한 심각도 이상인 이슈 수 (CI 실패 기준)
+    pub fn count_at_least(&self, severity: Severity) -> usize {
+        self.issues
+            .iter()
+            .filter(|i| i.severity >= severity)
+            .count()
+    }
+
     /// 심각도별 정렬된 이슈 목록
     pub fn sorted_issues(&self) -> Vec<&ValidationIssue> {
         let mut sorted: Vec<_> = self.issues.iter().collect();
@@ -575,6 +597,94 @@ impl std::fmt::Display for ConsolidationPlan {
     }
 }
 
+/// 롤백 단계
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RollbackStep {
+    /// 자동 생성된 역변환 SQL
+    Sql {
+        /// 실행할 SQL
+        sql: String,
+        /// 역변환 대상 원본 문장의 라인 번호
+        source_line: usize,
+    },
+    /// 자동 역변환 불가 (수동 작성 필요)
+    Manual {
+        /// 역변환이 불가능한 이유
+        reason: String,
+        /// 원본 SQL
+        source_sql: String,
+        /// 원본 문장의 라인 번호
+        source_line: usize,
+    },
+}
+
+/// 마이그레이션 파일 하나에 대한 롤백 스크립트
+///
+/// 단계는 원본 문장의 역순으로 정렬되어 있습니다.
+#[derive(Debug, Clone, Default)]
+pub struct RollbackScript {
+    /// 원본 마이그레이션 파일명
+    pub file_name: String,
+    /// 롤백 단계
+    pub steps: Vec<RollbackStep>,
+}
+
+impl RollbackScript {
+    /// 수동 작성이 필요한 단계 수
+    pub fn manual_step_count(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|s| matches!(s, RollbackStep::Manual { .. }))
+            .count()
+    }
+
+    /// 모든 단계가 자동 생성되었는지 확인
+    pub fn is_complete(&self) -> bool {
+        self.manual_step_count() == 0
+    }
+
+    /// 실행 가능한 SQL 파일 내용 생성
+    ///
+    /// 수동 단계는 경고 주석과 원본 SQL, TODO 스텁으로 출력됩니다.
+    pub fn to_sql(&self) -> String {
+        let mut output = String::new();
+        output.push_str(&format!("-- Rollback: {}\n", self.file_name));
+        output.push_str("-- 자동 생성됨 (원본 문장의 역순으로 실행)\n");
+        if !self.is_complete() {
+            output.push_str(&format!(
+                "-- WARNING: 수동 작성이 필요한 단계 {} 개 (TODO 참고)\n",
+                self.manual_step_count()
+            ));
+        }
+
+        for step in &self.steps {
+            output.push('\n');
+            match step {
+                RollbackStep::Sql { sql, .. } => {
+                    output.push_str(sql);
+                    output.push('\n');
+                }
+                RollbackStep::Manual {
+                    reason,
+                    source_sql,
+                    source_line,
+                } => {
+                    output.push_str(&format!(
+                        "-- WARNING: 자동 역변환 불가 (원본 {}행): {}\n",
+                        source_line, reason
+                    ));
+                    for line in source_sql.lines() {
+                        output.push_str(&format!("--   {}\n", line));
+                    }
+                    output.push_str("-- TODO: 수동 롤백 SQL 작성\n");
+                }
+            }
+        }
+
+        output
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
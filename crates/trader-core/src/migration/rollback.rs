@@ -0,0 +1,328 @@
+//! 마이그레이션 롤백 SQL 생성.
+//!
+//! [`MigrationAnalyzer`]가 파싱한 문장을 역순으로 순회하며 대응하는 역변환 SQL을 생성합니다.
+//!
+//! | 원본 | 롤백 |
+//! |------|------|
+//! | CREATE TABLE/VIEW/MATERIALIZED VIEW/INDEX/TYPE/FUNCTION | DROP ... IF EXISTS |
+//! | CREATE TRIGGER ... ON table | DROP TRIGGER IF EXISTS ... ON table |
+//! | ALTER TABLE ... ADD COLUMN | ALTER TABLE ... DROP COLUMN IF EXISTS |
+//! | ALTER TABLE ... ADD CONSTRAINT | ALTER TABLE ... DROP CONSTRAINT IF EXISTS |
+//!
+//! DROP, 데이터 변경(INSERT/UPDATE/DELETE), DO 블록처럼 원래 상태를 알 수 없는 문장은
+//! [`RollbackStep::Manual`]로 남겨 경고 주석과 TODO 스텁으로 출력합니다.
+//! 단, 같은 파일에서 생성한 테이블에 대한 INSERT/hypertable 변환은 테이블 삭제로
+//! 함께 되돌려지므로 별도 단계를 만들지 않습니다.
+
+use std::collections::HashSet;
+
+use super::{
+    analyzer::{alter_table_actions, MigrationAnalyzer},
+    models::*,
+};
+
+/// 역변환 없이 건너뛰는 기타 문장 (객체 삭제 시 함께 사라지거나 상태가 없는 문장)
+const SKIPPED_STATEMENTS: &[&str] = &["COMMENT", "SET", "BEGIN", "COMMIT", "ANALYZE"];
+
+impl MigrationAnalyzer {
+    /// 마이그레이션 파일의 롤백 스크립트 생성
+    pub fn generate_rollback(&self, file: &MigrationFile) -> RollbackScript {
+        let created_tables: HashSet<&str> = file
+            .statements
+            .iter()
+            .filter(|s| s.statement_type == StatementType::CreateTable)
+            .map(|s| s.object_name.as_str())
+            .collect();
+
+        let mut steps = Vec::new();
+        let mut emitted = HashSet::new();
+
+        for stmt in file.statements.iter().rev() {
+            let drop_sql = match &stmt.statement_type {
+                StatementType::CreateTable => {
+                    Some(format!("DROP TABLE IF EXISTS {};", stmt.object_name))
+                }
+                StatementType::CreateView => {
+                    Some(format!("DROP VIEW IF EXISTS {};", stmt.object_name))
+                }
+                StatementType::CreateMaterializedView => Some(format!(
+                    "DROP MATERIALIZED VIEW IF EXISTS {};",
+                    stmt.object_name
+                )),
+                StatementType::CreateIndex => {
+                    Some(format!("DROP INDEX IF EXISTS {};", stmt.object_name))
+                }
+                StatementType::CreateType => {
+                    Some(format!("DROP TYPE IF EXISTS {};", stmt.object_name))
+                }
+                StatementType::CreateFunction => {
+                    Some(format!("DROP FUNCTION IF EXISTS {};", stmt.object_name))
+                }
+                StatementType::CreateTrigger => match self.trigger_table(stmt) {
+                    Some(table) => Some(format!(
+                        "DROP TRIGGER IF EXISTS {} ON {};",
+                        stmt.object_name, table
+                    )),
+                    None => {
+                        steps.push(manual(stmt, "트리거 대상 테이블을 찾을 수 없음"));
+                        continue;
+                    }
+                },
+                StatementType::AlterTable => {
+                    self.rollback_alter_table(stmt, &mut steps);
+                    continue;
+                }
+                StatementType::Insert | StatementType::SelectInto
+                    if created_tables.contains(stmt.object_name.as_str()) =>
+                {
+                    // 테이블 삭제로 함께 되돌려짐
+                    continue;
+                }
+                StatementType::Insert => {
+                    steps.push(manual(stmt, "데이터 변경은 자동으로 되돌릴 수 없음"));
+                    continue;
+                }
+                StatementType::SelectInto => {
+                    steps.push(manual(stmt, "hypertable 변환은 자동으로 되돌릴 수 없음"));
+                    continue;
+                }
+                StatementType::CreateExtension => {
+                    steps.push(manual(
+                        stmt,
+                        "확장은 다른 마이그레이션에서도 사용할 수 있어 자동 삭제하지 않음",
+                    ));
+                    continue;
+                }
+                stmt_type if stmt_type.is_drop() => {
+                    steps.push(manual(
+                        stmt,
+                        "삭제된 객체는 이전 마이그레이션의 정의로 다시 생성해야 함",
+                    ));
+                    continue;
+                }
+                StatementType::Other(keyword) if SKIPPED_STATEMENTS.contains(&keyword.as_str()) => {
+                    continue;
+                }
+                StatementType::Other(keyword) => {
+                    let reason = match keyword.as_str() {
+                        "INSERT" | "UPDATE" | "DELETE" | "TRUNCATE" => {
+                            "데이터 변경은 자동으로 되돌릴 수 없음".to_string()
+                        }
+                        _ => format!("{} 문장은 자동 역변환을 지원하지 않음", keyword),
+                    };
+                    steps.push(manual(stmt, &reason));
+                    continue;
+                }
+                // is_drop()에서 모두 처리됨
+                _ => continue,
+            };
+
+            if let Some(sql) = drop_sql {
+                // CREATE OR REPLACE 반복 정의 등 같은 객체는 한 번만 삭제
+                if emitted.insert(sql.clone()) {
+                    steps.push(RollbackStep::Sql {
+                        sql,
+                        source_line: stmt.line_number,
+                    });
+                }
+            }
+        }
+
+        RollbackScript {
+            file_name: file.name.clone(),
+            steps,
+        }
+    }
+
+    /// CREATE TRIGGER ... ON table 에서 테이블명 추출
+    fn trigger_table(&self, stmt: &SqlStatement) -> Option<String> {
+        let sql_upper = stmt.raw_sql.to_uppercase();
+        let pos = sql_upper.find(" ON ")?;
+        let name = stmt.raw_sql[pos + 4..].split_whitespace().next()?;
+        let name = self.clean_object_name(name);
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// ALTER TABLE 역변환
+    ///
+    /// ADD COLUMN/ADD CONSTRAINT는 DROP으로 되돌리고, 나머지 동작은 수동 단계로 남깁니다.
+    fn rollback_alter_table(&self, stmt: &SqlStatement, steps: &mut Vec<RollbackStep>) {
+        let Some(actions) = alter_table_actions(&stmt.raw_sql) else {
+            steps.push(manual(stmt, "ALTER TABLE 구문을 해석할 수 없음"));
+            return;
+        };
+
+        let mut reversed = Vec::new();
+        let mut unsupported = Vec::new();
+        for action in &actions {
+            match self.reverse_alter_action(action) {
+                Some(sql) => reversed.push(sql),
+                None => unsupported.push(action.as_str()),
+            }
+        }
+
+        if !reversed.is_empty() {
+            reversed.reverse();
+            steps.push(RollbackStep::Sql {
+                sql: format!("ALTER TABLE {} {};", stmt.object_name, reversed.join(", ")),
+                source_line: stmt.line_number,
+            });
+        }
+
+        if !unsupported.is_empty() {
+            steps.push(RollbackStep::Manual {
+                reason: "ALTER TABLE 동작은 자동으로 되돌릴 수 없음 (ADD COLUMN/CONSTRAINT만 지원)"
+                    .to_string(),
+                source_sql: format!(
+                    "ALTER TABLE {} {};",
+                    stmt.object_name,
+                    unsupported.join(", ")
+                ),
+                source_line: stmt.line_number,
+            });
+        }
+    }
+
+    /// ALTER TABLE 동작 하나의 역변환 (ADD COLUMN → DROP COLUMN, ADD CONSTRAINT → DROP CONSTRAINT)
+    fn reverse_alter_action(&self, action: &str) -> Option<String> {
+        let mut words = action.split_whitespace();
+        if !words.next()?.eq_ignore_ascii_case("ADD") {
+            return None;
+        }
+
+        let mut next = words.next()?;
+        let kind = if next.eq_ignore_ascii_case("CONSTRAINT") {
+            next = words.next()?;
+            "CONSTRAINT"
+        } else {
+            if next.eq_ignore_ascii_case("COLUMN") {
+                next = words.next()?;
+            } else if ["PRIMARY", "UNIQUE", "FOREIGN", "CHECK", "EXCLUDE"]
+                .iter()
+                .any(|k| next.eq_ignore_ascii_case(k))
+            {
+                // 이름 없는 제약조건은 자동 생성 이름을 알 수 없음
+                return None;
+            }
+            "COLUMN"
+        };
+
+        // ADD COLUMN IF NOT EXISTS name
+        if next.eq_ignore_ascii_case("IF") {
+            let (not, exists) = (words.next()?, words.next()?);
+            if !not.eq_ignore_ascii_case("NOT") || !exists.eq_ignore_ascii_case("EXISTS") {
+                return None;
+            }
+            next = words.next()?;
+        }
+
+        let name = self.clean_object_name(next);
+        (!name.is_empty()).then(|| format!("DROP {} IF EXISTS {}", kind, name))
+    }
+}
+
+/// 수동 롤백 단계 생성
+fn manual(stmt: &SqlStatement, reason: &str) -> RollbackStep {
+    RollbackStep::Manual {
+        reason: reason.to_string(),
+        source_sql: stmt.raw_sql.clone(),
+        source_line: stmt.line_number,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migration(content: &str) -> MigrationFile {
+        let analyzer = MigrationAnalyzer::new();
+        let mut file = MigrationFile::new("05_sample.sql".into(), 5, content.to_string());
+        file.statements = analyzer.parse_statements(content);
+        file
+    }
+
+    #[test]
+    fn test_rollback_is_symmetric_with_original() {
+        let analyzer = MigrationAnalyzer::new();
+        let file = migration(
+            "CREATE TABLE IF NOT EXISTS orders (
+                id UUID PRIMARY KEY,
+                user_id UUID REFERENCES users(id)
+            );
+            CREATE INDEX IF NOT EXISTS idx_orders_user ON orders (user_id);
+            CREATE OR REPLACE VIEW v_orders AS SELECT * FROM orders;
+            CREATE TRIGGER trg_orders_updated BEFORE UPDATE ON orders
+                FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+            ALTER TABLE users ADD COLUMN IF NOT EXISTS tier TEXT,
+                ADD CONSTRAINT chk_tier CHECK (tier IN ('a', 'b'));",
+        );
+
+        let script = analyzer.generate_rollback(&file);
+        assert!(script.is_complete());
+
+        // 롤백 SQL을 다시 파싱해 원본과 대칭인지 확인
+        let rollback = analyzer.parse_statements(&script.to_sql());
+        let kinds: Vec<(StatementType, &str)> = rollback
+            .iter()
+            .map(|s| (s.statement_type.clone(), s.object_name.as_str()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (StatementType::AlterTable, "users"),
+                (StatementType::DropTrigger, "trg_orders_updated"),
+                (StatementType::DropView, "v_orders"),
+                (StatementType::DropIndex, "idx_orders_user"),
+                (StatementType::DropTable, "orders"),
+            ]
+        );
+        assert!(rollback.iter().all(|s| s.if_exists));
+        assert!(rollback[0]
+            .raw_sql
+            .contains("DROP CONSTRAINT IF EXISTS chk_tier, DROP COLUMN IF EXISTS tier"));
+        assert!(rollback[1].raw_sql.contains("ON orders"));
+
+        // 원본의 모든 CREATE 객체가 정확히 한 번씩 삭제됨
+        let created: HashSet<&str> = file
+            .statements
+            .iter()
+            .filter(|s| s.statement_type.is_create())
+            .map(|s| s.object_name.as_str())
+            .collect();
+        let dropped: HashSet<&str> = rollback
+            .iter()
+            .filter(|s| s.statement_type.is_drop())
+            .map(|s| s.object_name.as_str())
+            .collect();
+        assert_eq!(created, dropped);
+    }
+
+    #[test]
+    fn test_rollback_emits_todo_for_data_changes() {
+        let analyzer = MigrationAnalyzer::new();
+        let file = migration(
+            "CREATE TABLE settings (key TEXT PRIMARY KEY, value TEXT);
+INSERT INTO settings (key, value) VALUES ('a', '1');
+INSERT INTO strategies (id) VALUES ('grid');
+UPDATE strategies SET enabled = false;
+ALTER TABLE strategies ALTER COLUMN name TYPE VARCHAR(200);
+DROP TABLE IF EXISTS legacy_orders;",
+        );
+
+        let script = analyzer.generate_rollback(&file);
+        // 같은 파일에서 생성한 테이블의 INSERT는 테이블 삭제로 되돌려짐
+        assert_eq!(script.manual_step_count(), 4);
+        assert!(!script.is_complete());
+
+        let sql = script.to_sql();
+        assert!(sql.contains("-- WARNING: 수동 작성이 필요한 단계 4 개"));
+        assert!(sql.contains("--   UPDATE strategies SET enabled = false;"));
+        assert_eq!(sql.matches("-- TODO: 수동 롤백 SQL 작성").count(), 4);
+
+        // 수동 단계는 주석으로만 출력되어 실행되지 않음
+        let rollback = analyzer.parse_statements(&sql);
+        assert_eq!(rollback.len(), 1);
+        assert_eq!(rollback[0].statement_type, StatementType::DropTable);
+        assert_eq!(rollback[0].object_name, "settings");
+    }
+}
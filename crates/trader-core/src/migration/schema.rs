@@ -0,0 +1,871 @@
+//! 스키마 드리프트 검출.
+//!
+//! 마이그레이션 파일을 순서대로 적용했을 때 기대되는 스키마([`SchemaSnapshot`])를 만들고,
+//! 실제 DB에서 읽은 스키마와 비교하여 누락 컬럼, 타입 불일치, 추가 인덱스 등을 보고합니다.
+//!
+//! # 기대 스키마 계산 범위
+//!
+//! - CREATE TABLE (컬럼 정의), DROP TABLE
+//! - ALTER TABLE: ADD/DROP COLUMN, ALTER COLUMN TYPE, RENAME COLUMN, RENAME TO
+//! - CREATE/DROP INDEX, `create_hypertable` 기본 인덱스
+//!
+//! `DO $$ ... $$` 블록 안의 조건부 DDL은 해석하지 않습니다.
+//! 제약조건(PK/UNIQUE)이 자동 생성하는 인덱스는 비교 대상이 아니므로,
+//! 실제 스키마를 읽을 때 제외해야 합니다.
+
+use std::collections::BTreeMap;
+
+use super::{
+    analyzer::{alter_table_actions, split_top_level, strip_line_comments, MigrationAnalyzer},
+    models::*,
+};
+
+/// 컬럼 정의에서 타입 다음에 오는 키워드
+const COLUMN_CONSTRAINT_KEYWORDS: &[&str] = &[
+    "NOT",
+    "NULL",
+    "DEFAULT",
+    "PRIMARY",
+    "REFERENCES",
+    "UNIQUE",
+    "CHECK",
+    "CONSTRAINT",
+    "GENERATED",
+    "COLLATE",
+    "USING",
+];
+
+/// 테이블 제약조건 정의 시작 키워드 (컬럼이 아님)
+const TABLE_CONSTRAINT_KEYWORDS: &[&str] = &[
+    "CONSTRAINT",
+    "PRIMARY",
+    "UNIQUE",
+    "FOREIGN",
+    "CHECK",
+    "EXCLUDE",
+    "LIKE",
+];
+
+/// 컬럼 스키마
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSchema {
+    /// 컬럼명
+    pub name: String,
+    /// 정규화된 타입 (`information_schema.columns.data_type` 형식)
+    pub data_type: String,
+    /// 선언된 타입 원문 (해결 SQL 제안용)
+    pub declared_type: String,
+}
+
+impl ColumnSchema {
+    /// 새 컬럼 생성 (선언 타입을 정규화)
+    pub fn new(name: &str, declared_type: &str) -> Self {
+        Self {
+            name: name.to_lowercase(),
+            data_type: normalize_type(declared_type),
+            declared_type: declared_type.trim().to_string(),
+        }
+    }
+}
+
+/// 테이블 스키마
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableSchema {
+    /// 컬럼명 → 컬럼
+    pub columns: BTreeMap<String, ColumnSchema>,
+}
+
+/// 인덱스 스키마
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexSchema {
+    /// 대상 테이블
+    pub table: String,
+    /// 인덱스 정의 SQL (있는 경우)
+    pub definition: Option<String>,
+}
+
+/// 스키마 스냅샷 (기대 스키마 또는 실제 DB 스키마)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaSnapshot {
+    /// 테이블명 → 테이블
+    pub tables: BTreeMap<String, TableSchema>,
+    /// 인덱스명 → 인덱스
+    pub indexes: BTreeMap<String, IndexSchema>,
+}
+
+impl SchemaSnapshot {
+    /// 빈 스냅샷 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 컬럼 추가 (테이블이 없으면 생성)
+    pub fn add_column(&mut self, table: &str, column: ColumnSchema) {
+        self.tables
+            .entry(table.to_lowercase())
+            .or_default()
+            .columns
+            .insert(column.name.clone(), column);
+    }
+
+    /// 인덱스 추가
+    pub fn add_index(&mut self, name: &str, table: &str, definition: Option<String>) {
+        self.indexes.insert(
+            name.to_lowercase(),
+            IndexSchema {
+                table: table.to_lowercase(),
+                definition,
+            },
+        );
+    }
+
+    /// 테이블 삭제 (테이블의 인덱스 포함)
+    fn drop_table(&mut self, table: &str) {
+        self.tables.remove(table);
+        self.indexes.retain(|_, index| index.table != table);
+    }
+
+    /// 기대 스키마(`self`)와 실제 스키마 비교
+    pub fn diff(&self, actual: &SchemaSnapshot) -> DriftReport {
+        let mut items = Vec::new();
+
+        for (name, expected) in &self.tables {
+            let Some(actual_table) = actual.tables.get(name) else {
+                items.push(SchemaDrift::MissingTable {
+                    table: name.clone(),
+                    columns: expected.columns.values().cloned().collect(),
+                });
+                continue;
+            };
+
+            // 컬럼 정의를 해석하지 못한 테이블(CREATE TABLE ... AS 등)은 존재 여부만 비교
+            if expected.columns.is_empty() {
+                continue;
+            }
+
+            for (column_name, column) in &expected.columns {
+                match actual_table.columns.get(column_name) {
+                    None => items.push(SchemaDrift::MissingColumn {
+                        table: name.clone(),
+                        column: column.clone(),
+                    }),
+                    Some(actual_column) if actual_column.data_type != column.data_type => items
+                        .push(SchemaDrift::TypeMismatch {
+                            table: name.clone(),
+                            expected: column.clone(),
+                            actual_type: actual_column.data_type.clone(),
+                        }),
+                    Some(_) => {}
+                }
+            }
+
+            for (column_name, column) in &actual_table.columns {
+                if !expected.columns.contains_key(column_name) {
+                    items.push(SchemaDrift::ExtraColumn {
+                        table: name.clone(),
+                        column: column_name.clone(),
+                        actual_type: column.data_type.clone(),
+                    });
+                }
+            }
+        }
+
+        for name in actual.tables.keys() {
+            // `_sqlx_migrations` 등 내부 관리 테이블은 제외
+            if !self.tables.contains_key(name) && !name.starts_with('_') {
+                items.push(SchemaDrift::ExtraTable {
+                    table: name.clone(),
+                });
+            }
+        }
+
+        // 양쪽에 모두 존재하는 테이블의 인덱스만 비교 (테이블 누락/추가와 중복 보고 방지)
+        let in_both =
+            |table: &str| self.tables.contains_key(table) && actual.tables.contains_key(table);
+
+        for (name, index) in &self.indexes {
+            if in_both(&index.table) && !actual.indexes.contains_key(name) {
+                items.push(SchemaDrift::MissingIndex {
+                    index: name.clone(),
+                    table: index.table.clone(),
+                    definition: index.definition.clone(),
+                });
+            }
+        }
+
+        for (name, index) in &actual.indexes {
+            if in_both(&index.table) && !self.indexes.contains_key(name) {
+                items.push(SchemaDrift::ExtraIndex {
+                    index: name.clone(),
+                    table: index.table.clone(),
+                    definition: index.definition.clone(),
+                });
+            }
+        }
+
+        DriftReport { items }
+    }
+}
+
+/// 스키마 차이 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaDrift {
+    /// 마이그레이션에는 있지만 DB에 없는 테이블
+    MissingTable {
+        table: String,
+        columns: Vec<ColumnSchema>,
+    },
+    /// DB에만 있는 테이블
+    ExtraTable { table: String },
+    /// DB에 없는 컬럼
+    MissingColumn { table: String, column: ColumnSchema },
+    /// DB에만 있는 컬럼
+    ExtraColumn {
+        table: String,
+        column: String,
+        actual_type: String,
+    },
+    /// 컬럼 타입 불일치
+    TypeMismatch {
+        table: String,
+        expected: ColumnSchema,
+        actual_type: String,
+    },
+    /// DB에 없는 인덱스
+    MissingIndex {
+        index: String,
+        table: String,
+        definition: Option<String>,
+    },
+    /// DB에만 있는 인덱스
+    ExtraIndex {
+        index: String,
+        table: String,
+        definition: Option<String>,
+    },
+}
+
+impl SchemaDrift {
+    /// 드리프트 코드 (ValidationIssue 코드 체계와 동일한 형식)
+    pub fn code(&self) -> &'static str {
+        match self {
+            SchemaDrift::MissingTable { .. } => "DRIFT001",
+            SchemaDrift::ExtraTable { .. } => "DRIFT002",
+            SchemaDrift::MissingColumn { .. } => "DRIFT003",
+            SchemaDrift::ExtraColumn { .. } => "DRIFT004",
+            SchemaDrift::TypeMismatch { .. } => "DRIFT005",
+            SchemaDrift::MissingIndex { .. } => "DRIFT006",
+            SchemaDrift::ExtraIndex { .. } => "DRIFT007",
+        }
+    }
+
+    /// 차이 설명
+    pub fn description(&self) -> String {
+        match self {
+            SchemaDrift::MissingTable { table, .. } => format!("테이블 누락: {}", table),
+            SchemaDrift::ExtraTable { table } => {
+                format!("마이그레이션에 없는 테이블: {}", table)
+            }
+            SchemaDrift::MissingColumn { table, column } => format!(
+                "컬럼 누락: {}.{} ({})",
+                table, column.name, column.declared_type
+            ),
+            SchemaDrift::ExtraColumn {
+                table,
+                column,
+                actual_type,
+            } => format!(
+                "마이그레이션에 없는 컬럼: {}.{} ({})",
+                table, column, actual_type
+            ),
+            SchemaDrift::TypeMismatch {
+                table,
+                expected,
+                actual_type,
+            } => format!(
+                "타입 불일치: {}.{} (기대 {}, 실제 {})",
+                table, expected.name, expected.data_type, actual_type
+            ),
+            SchemaDrift::MissingIndex { index, table, .. } => {
+                format!("인덱스 누락: {} ON {}", index, table)
+            }
+            SchemaDrift::ExtraIndex { index, table, .. } => {
+                format!("마이그레이션에 없는 인덱스: {} ON {}", index, table)
+            }
+        }
+    }
+
+    /// 해결용 마이그레이션 스텁
+    ///
+    /// DB에만 있는 객체는 마이그레이션에 추가할지, DB에서 제거할지 판단이 필요하므로
+    /// 제거 SQL을 주석으로 함께 제안합니다.
+    pub fn suggested_migration(&self) -> String {
+        match self {
+            SchemaDrift::MissingTable { table, columns } => {
+                if columns.is_empty() {
+                    return format!("-- TODO: {} 테이블 정의를 마이그레이션에서 확인", table);
+                }
+                let defs: Vec<String> = columns
+                    .iter()
+                    .map(|c| format!("    {} {}", c.name, c.declared_type))
+                    .collect();
+                format!(
+                    "CREATE TABLE IF NOT EXISTS {} (\n{}\n);",
+                    table,
+                    defs.join(",\n")
+                )
+            }
+            SchemaDrift::ExtraTable { table } => format!(
+                "-- 수동 생성된 테이블이면 마이그레이션에 CREATE TABLE 추가, 불필요하면:\n-- DROP TABLE IF EXISTS {};",
+                table
+            ),
+            SchemaDrift::MissingColumn { table, column } => format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS {} {};",
+                table, column.name, column.declared_type
+            ),
+            SchemaDrift::ExtraColumn {
+                table,
+                column,
+                actual_type,
+            } => format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS {} {};\n-- 또는 불필요하면: ALTER TABLE {} DROP COLUMN IF EXISTS {};",
+                table, column, actual_type, table, column
+            ),
+            SchemaDrift::TypeMismatch {
+                table, expected, ..
+            } => format!(
+                "ALTER TABLE {} ALTER COLUMN {} TYPE {} USING {}::{};",
+                table, expected.name, expected.declared_type, expected.name, expected.declared_type
+            ),
+            SchemaDrift::MissingIndex {
+                index,
+                table,
+                definition,
+            } => definition
+                .clone()
+                .unwrap_or_else(|| format!("-- TODO: CREATE INDEX {} ON {} (...);", index, table)),
+            SchemaDrift::ExtraIndex {
+                index, definition, ..
+            } => {
+                let keep = definition
+                    .as_ref()
+                    .map(|d| format!("{};", d.trim_end_matches(';')))
+                    .unwrap_or_else(|| format!("-- 마이그레이션에 {} 정의 추가", index));
+                format!(
+                    "{}\n-- 또는 불필요하면: DROP INDEX IF EXISTS {};",
+                    keep, index
+                )
+            }
+        }
+    }
+}
+
+/// 스키마 드리프트 보고서
+#[derive(Debug, Clone, Default)]
+pub struct DriftReport {
+    /// 차이 항목
+    pub items: Vec<SchemaDrift>,
+}
+
+impl DriftReport {
+    /// 차이가 없는지 확인
+    pub fn is_clean(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// 모든 해결 스텁을 하나의 마이그레이션 SQL로 합침
+    pub fn suggested_migration(&self) -> String {
+        self.items
+            .iter()
+            .map(|item| format!("-- {}\n{}", item.description(), item.suggested_migration()))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+impl std::fmt::Display for DriftReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "═══════════════════════════════════════════════════════════════"
+        )?;
+        writeln!(f, "                    스키마 드리프트 보고서")?;
+        writeln!(
+            f,
+            "═══════════════════════════════════════════════════════════════"
+        )?;
+        writeln!(f)?;
+
+        if self.is_clean() {
+            writeln!(f, "✅ 마이그레이션과 실제 스키마가 일치합니다.")?;
+        } else {
+            writeln!(f, "🟡 차이: {} 개", self.items.len())?;
+            for (i, item) in self.items.iter().enumerate() {
+                writeln!(f)?;
+                writeln!(f, "{}. [{}] {}", i + 1, item.code(), item.description())?;
+                for line in item.suggested_migration().lines() {
+                    writeln!(f, "   {}", line)?;
+                }
+            }
+        }
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "═══════════════════════════════════════════════════════════════"
+        )?;
+
+        Ok(())
+    }
+}
+
+impl MigrationAnalyzer {
+    /// 마이그레이션 파일을 순서대로 적용했을 때 기대되는 스키마 계산
+    ///
+    /// `files`는 [`scan_directory`](Self::scan_directory)처럼 순서 번호로 정렬되어 있어야 합니다.
+    pub fn expected_schema(&self, files: &[MigrationFile]) -> SchemaSnapshot {
+        let mut schema = SchemaSnapshot::new();
+
+        for stmt in files.iter().flat_map(|f| &f.statements) {
+            // DO 블록 안의 DDL은 조건부이므로 기대 스키마에 반영하지 않음
+            if is_do_block(&stmt.raw_sql) {
+                continue;
+            }
+            let sql = strip_line_comments(&stmt.raw_sql);
+            match stmt.statement_type {
+                StatementType::CreateTable => {
+                    // IF NOT EXISTS로 재정의해도 기존 테이블이 유지됨
+                    if schema.tables.contains_key(&stmt.object_name) {
+                        continue;
+                    }
+                    schema
+                        .tables
+                        .insert(stmt.object_name.clone(), TableSchema::default());
+                    for column in parse_column_definitions(&sql) {
+                        schema.add_column(&stmt.object_name, column);
+                    }
+                }
+                StatementType::DropTable => {
+                    for table in self.drop_targets(&sql, "TABLE") {
+                        schema.drop_table(&table);
+                    }
+                }
+                StatementType::AlterTable => {
+                    self.apply_alter_table(&mut schema, &stmt.object_name, &sql);
+                }
+                StatementType::CreateIndex => {
+                    if schema.indexes.contains_key(&stmt.object_name) {
+                        continue;
+                    }
+                    if let Some(table) = self.index_table(&sql) {
+                        let definition = sql.trim().trim_end_matches(';').to_string();
+                        schema.add_index(
+                            &stmt.object_name,
+                            &table,
+                            Some(format!("{};", definition)),
+                        );
+                    }
+                }
+                StatementType::DropIndex => {
+                    for index in self.drop_targets(&sql, "INDEX") {
+                        schema.indexes.remove(&index);
+                    }
+                }
+                StatementType::SelectInto => {
+                    self.apply_hypertable(&mut schema, &stmt.object_name, &sql);
+                }
+                _ => {}
+            }
+        }
+
+        schema
+    }
+
+    /// ALTER TABLE 동작 적용
+    fn apply_alter_table(&self, schema: &mut SchemaSnapshot, table: &str, sql: &str) {
+        let Some(actions) = alter_table_actions(sql) else {
+            return;
+        };
+
+        for action in actions {
+            let words: Vec<&str> = action.split_whitespace().collect();
+            let upper: Vec<String> = words.iter().map(|w| w.to_uppercase()).collect();
+            let upper: Vec<&str> = upper.iter().map(String::as_str).collect();
+
+            match upper.as_slice() {
+                // RENAME TO new_name
+                ["RENAME", "TO", ..] => {
+                    if let Some(definition) = schema.tables.remove(table) {
+                        let new_name = self.clean_object_name(words[2]);
+                        for index in schema.indexes.values_mut() {
+                            if index.table == table {
+                                index.table = new_name.clone();
+                            }
+                        }
+                        schema.tables.insert(new_name, definition);
+                    }
+                    return;
+                }
+                // RENAME [COLUMN] a TO b
+                ["RENAME", "COLUMN", _, "TO", ..] | ["RENAME", _, "TO", ..] => {
+                    let offset = usize::from(upper[1] == "COLUMN");
+                    let old = self.clean_object_name(words[1 + offset]);
+                    let new = self.clean_object_name(words[3 + offset]);
+                    if let Some(columns) = schema.tables.get_mut(table).map(|t| &mut t.columns) {
+                        if let Some(mut column) = columns.remove(&old) {
+                            column.name = new.clone();
+                            columns.insert(new, column);
+                        }
+                    }
+                }
+                // DROP [COLUMN] [IF EXISTS] name
+                ["DROP", rest @ ..] if !matches!(rest.first(), Some(&"CONSTRAINT")) => {
+                    let mut i = 1;
+                    if upper.get(i) == Some(&"COLUMN") {
+                        i += 1;
+                    }
+                    if upper.get(i) == Some(&"IF") {
+                        i += 2;
+                    }
+                    if let (Some(name), Some(t)) = (words.get(i), schema.tables.get_mut(table)) {
+                        t.columns.remove(&self.clean_object_name(name));
+                    }
+                }
+                // ALTER [COLUMN] name [SET DATA] TYPE type
+                ["ALTER", ..] => {
+                    let Some(type_pos) = upper.iter().position(|w| *w == "TYPE") else {
+                        continue;
+                    };
+                    let name_pos = if upper.get(1) == Some(&"COLUMN") {
+                        2
+                    } else {
+                        1
+                    };
+                    let declared = column_type(&words[type_pos + 1..]);
+                    if let (Some(name), Some(t)) =
+                        (words.get(name_pos), schema.tables.get_mut(table))
+                    {
+                        let name = self.clean_object_name(name);
+                        if t.columns.contains_key(&name) && !declared.is_empty() {
+                            t.columns
+                                .insert(name.clone(), ColumnSchema::new(&name, &declared));
+                        }
+                    }
+                }
+                // ADD [COLUMN] [IF NOT EXISTS] name type
+                ["ADD", second, ..] if !TABLE_CONSTRAINT_KEYWORDS.contains(second) => {
+                    let mut i = 1;
+                    if upper.get(i) == Some(&"COLUMN") {
+                        i += 1;
+                    }
+                    if upper.get(i) == Some(&"IF") {
+                        i += 3;
+                    }
+                    if let Some(column) = words.get(i..).and_then(parse_column) {
+                        // ADD COLUMN IF NOT EXISTS는 기존 컬럼을 유지
+                        let exists = schema
+                            .tables
+                            .get(table)
+                            .is_some_and(|t| t.columns.contains_key(&column.name));
+                        if !exists {
+                            schema.add_column(table, column);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// create_hypertable 기본 시간 인덱스 (`{table}_{time_column}_idx`)
+    fn apply_hypertable(&self, schema: &mut SchemaSnapshot, table: &str, sql: &str) {
+        if sql
+            .to_lowercase()
+            .contains("create_default_indexes => false")
+        {
+            return;
+        }
+        let Some(start) = sql.find('(') else {
+            return;
+        };
+        let args = split_top_level(sql[start + 1..].trim_end_matches(';').trim_end_matches(')'));
+        if let Some(time_column) = args.get(1) {
+            let time_column = self.clean_object_name(time_column.trim());
+            let index = format!("{}_{}_idx", table, time_column);
+            if !schema.indexes.contains_key(&index) {
+                schema.add_index(&index, table, None);
+            }
+        }
+    }
+
+    /// CREATE INDEX ... ON [ONLY] table 에서 테이블명 추출
+    pub(super) fn index_table(&self, sql: &str) -> Option<String> {
+        let sql_upper = sql.to_uppercase();
+        let pos = sql_upper.find(" ON ")?;
+        let mut words = sql[pos + 4..].split(|c: char| c == '(' || c.is_whitespace());
+        let mut name = words.find(|w| !w.is_empty())?;
+        if name.eq_ignore_ascii_case("ONLY") {
+            name = words.find(|w| !w.is_empty())?;
+        }
+        Some(self.clean_object_name(name))
+    }
+
+    /// DROP TABLE/INDEX [IF EXISTS] a, b [CASCADE] 에서 대상 목록 추출
+    fn drop_targets(&self, sql: &str, keyword: &str) -> Vec<String> {
+        let sql_upper = sql.to_uppercase();
+        let Some(pos) = sql_upper.find(&format!("DROP {}", keyword)) else {
+            return Vec::new();
+        };
+        let mut rest = sql[pos + 5 + keyword.len()..].trim_start();
+        for skip in ["CONCURRENTLY", "IF EXISTS"] {
+            if rest.to_uppercase().starts_with(skip) {
+                rest = rest[skip.len()..].trim_start();
+            }
+        }
+
+        let rest = rest.trim_end().trim_end_matches(';');
+        let rest_upper = rest.to_uppercase();
+        let end = [" CASCADE", " RESTRICT"]
+            .iter()
+            .filter_map(|k| rest_upper.find(k))
+            .min()
+            .unwrap_or(rest.len());
+
+        rest[..end]
+            .split(',')
+            .map(|name| self.clean_object_name(name.trim()))
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+}
+
+/// `DO $$ ... $$` 익명 블록인지 확인
+fn is_do_block(sql: &str) -> bool {
+    sql.split_whitespace()
+        .next()
+        .is_some_and(|w| w.eq_ignore_ascii_case("DO"))
+}
+
+/// CREATE TABLE 본문에서 컬럼 정의 파싱
+fn parse_column_definitions(sql: &str) -> Vec<ColumnSchema> {
+    let Some(start) = sql.find('(') else {
+        return Vec::new();
+    };
+    // CREATE TABLE ... AS SELECT 는 컬럼 정의가 없음
+    if sql[..start]
+        .split_whitespace()
+        .any(|w| w.eq_ignore_ascii_case("AS"))
+    {
+        return Vec::new();
+    }
+
+    // 여는 괄호와 짝이 맞는 닫는 괄호까지가 본문
+    let mut depth = 0usize;
+    let mut end = None;
+    for (i, c) in sql[start..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    end = Some(start + i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let Some(end) = end else {
+        return Vec::new();
+    };
+
+    split_top_level(&sql[start + 1..end])
+        .iter()
+        .filter_map(|def| {
+            let words: Vec<&str> = def.split_whitespace().collect();
+            // `UNIQUE(a, b)`처럼 괄호가 붙어 있어도 키워드로 인식
+            let first = words.first()?.split('(').next()?.to_uppercase();
+            if TABLE_CONSTRAINT_KEYWORDS.contains(&first.as_str()) {
+                return None;
+            }
+            parse_column(&words)
+        })
+        .collect()
+}
+
+/// `name type [제약조건...]` 형태의 컬럼 정의 파싱
+fn parse_column(words: &[&str]) -> Option<ColumnSchema> {
+    let name = words.first()?.trim_matches('"');
+    let declared = column_type(&words[1..]);
+    if name.is_empty() || declared.is_empty() {
+        return None;
+    }
+    Some(ColumnSchema::new(name, &declared))
+}
+
+/// 제약조건 키워드 전까지의 타입 토큰 결합 (예: `DOUBLE PRECISION`, `DECIMAL(30, 15)`)
+fn column_type(words: &[&str]) -> String {
+    words
+        .iter()
+        .take_while(|w| {
+            let upper = w.trim_end_matches(';').to_uppercase();
+            !COLUMN_CONSTRAINT_KEYWORDS.contains(&upper.as_str())
+        })
+        .map(|w| w.trim_end_matches(';'))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 선언 타입을 `information_schema.columns.data_type` 형식으로 정규화
+///
+/// 길이/정밀도는 무시하고 기본 타입만 비교합니다. 알 수 없는 타입은
+/// 사용자 정의 타입(ENUM 등)으로 간주합니다.
+pub fn normalize_type(declared: &str) -> String {
+    let lower = declared.trim().to_lowercase();
+    if lower.ends_with("[]") || lower.starts_with("array") || lower == "array" {
+        return "ARRAY".to_string();
+    }
+
+    // 괄호(길이/정밀도) 제거 후 공백 정리
+    let mut base = String::with_capacity(lower.len());
+    let mut depth = 0usize;
+    for c in lower.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => base.push(c),
+            _ => {}
+        }
+    }
+    let base = base.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let normalized = match base.as_str() {
+        "int" | "integer" | "int4" | "serial" | "serial4" => "integer",
+        "bigint" | "int8" | "bigserial" | "serial8" => "bigint",
+        "smallint" | "int2" | "smallserial" | "serial2" => "smallint",
+        "varchar" | "character varying" => "character varying",
+        "char" | "character" | "bpchar" => "character",
+        "decimal" | "numeric" => "numeric",
+        "bool" | "boolean" => "boolean",
+        "float" | "float8" | "double precision" => "double precision",
+        "float4" | "real" => "real",
+        "timestamptz" | "timestamp with time zone" => "timestamp with time zone",
+        "timestamp" | "timestamp without time zone" => "timestamp without time zone",
+        "timetz" | "time with time zone" => "time with time zone",
+        "time" | "time without time zone" => "time without time zone",
+        "text" | "jsonb" | "json" | "uuid" | "date" | "bytea" | "interval" | "inet" | "cidr"
+        | "macaddr" | "money" | "tsvector" | "xml" => base.as_str(),
+        _ => "USER-DEFINED",
+    };
+    normalized.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migration(order: u32, content: &str) -> MigrationFile {
+        let analyzer = MigrationAnalyzer::new();
+        let mut file = MigrationFile::new(
+            format!("{:02}_m.sql", order).into(),
+            order,
+            content.to_string(),
+        );
+        file.statements = analyzer.parse_statements(content);
+        file
+    }
+
+    fn expected() -> SchemaSnapshot {
+        let analyzer = MigrationAnalyzer::new();
+        analyzer.expected_schema(&[
+            migration(
+                1,
+                "CREATE TABLE orders (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    price DECIMAL(30, 15) NOT NULL,     -- 가격 (원화, 달러)
+    side order_side NOT NULL,
+    tags TEXT[],
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    legacy_flag BOOLEAN,
+    CONSTRAINT chk_price CHECK (price > 0)
+);
+CREATE INDEX idx_orders_created ON orders (created_at DESC);
+CREATE TABLE temp_import (id INT);",
+            ),
+            migration(
+                2,
+                "ALTER TABLE orders ADD COLUMN IF NOT EXISTS note VARCHAR(200),
+    DROP COLUMN IF EXISTS legacy_flag;
+ALTER TABLE orders RENAME COLUMN note TO memo;
+ALTER TABLE orders ALTER COLUMN price TYPE DOUBLE PRECISION;
+DROP TABLE IF EXISTS temp_import CASCADE;",
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_expected_schema_applies_migrations_in_order() {
+        let schema = expected();
+
+        assert_eq!(schema.tables.keys().collect::<Vec<_>>(), vec!["orders"]);
+        let columns = &schema.tables["orders"].columns;
+        assert_eq!(
+            columns.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec!["created_at", "id", "memo", "price", "side", "tags"]
+        );
+        assert_eq!(columns["price"].data_type, "double precision");
+        assert_eq!(columns["memo"].data_type, "character varying");
+        assert_eq!(columns["side"].data_type, "USER-DEFINED");
+        assert_eq!(columns["tags"].data_type, "ARRAY");
+        assert_eq!(columns["created_at"].data_type, "timestamp with time zone");
+        assert_eq!(schema.indexes["idx_orders_created"].table, "orders");
+    }
+
+    #[test]
+    fn test_diff_reports_drift_with_suggestions() {
+        let expected = expected();
+
+        let mut actual = SchemaSnapshot::new();
+        for (name, data_type) in [
+            ("id", "uuid"),
+            ("price", "numeric"),
+            ("side", "USER-DEFINED"),
+            ("tags", "ARRAY"),
+            ("created_at", "timestamp with time zone"),
+            ("hotfix", "text"),
+        ] {
+            actual.add_column("orders", ColumnSchema::new(name, data_type));
+        }
+        actual.add_index(
+            "idx_orders_manual",
+            "orders",
+            Some("CREATE INDEX idx_orders_manual ON public.orders USING btree (price)".to_string()),
+        );
+
+        let report = expected.diff(&actual);
+        let codes: Vec<&str> = report.items.iter().map(SchemaDrift::code).collect();
+        assert_eq!(
+            codes,
+            vec!["DRIFT003", "DRIFT005", "DRIFT004", "DRIFT006", "DRIFT007"]
+        );
+
+        assert_eq!(
+            report.items[0].suggested_migration(),
+            "ALTER TABLE orders ADD COLUMN IF NOT EXISTS memo VARCHAR(200);"
+        );
+        assert_eq!(
+            report.items[1].suggested_migration(),
+            "ALTER TABLE orders ALTER COLUMN price TYPE DOUBLE PRECISION USING price::DOUBLE PRECISION;"
+        );
+        assert_eq!(
+            report.items[3].suggested_migration(),
+            "CREATE INDEX idx_orders_created ON orders (created_at DESC);"
+        );
+        assert!(report.items[4]
+            .suggested_migration()
+            .contains("DROP INDEX IF EXISTS idx_orders_manual;"));
+
+        // 기대 스키마와 같은 실제 스키마는 드리프트 없음
+        assert!(expected.diff(&expected).is_clean());
+    }
+}
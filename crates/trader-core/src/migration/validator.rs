@@ -1,10 +1,35 @@
 //! 마이그레이션 검증기.
 //!
 //! 중복 정의, DROP CASCADE, 순환 의존성 등의 문제를 검출합니다.
+//! 대형 테이블(hypertable 또는 시계열/로그성 이름)에서 잠금을 유발하는
+//! 구문은 `LOCK` 코드로 보고하며, 테이블 재작성이 필요한 변경은 Error(critical)로 분류합니다.
 
 use std::collections::{HashMap, HashSet};
 
-use super::{analyzer::MigrationAnalyzer, models::*};
+use super::{
+    analyzer::{alter_table_actions, MigrationAnalyzer},
+    models::*,
+};
+
+/// 대형 테이블로 추정하는 이름 패턴 (시계열/로그성 테이블)
+const LARGE_TABLE_PATTERNS: &[&str] = &[
+    "klines",
+    "ohlcv",
+    "candle",
+    "ticks",
+    "snapshot",
+    "execution",
+    "history",
+    "_log",
+];
+
+/// 매 행마다 값이 달라 테이블 재작성을 유발하는 기본값 함수
+const VOLATILE_DEFAULTS: &[&str] = &[
+    "CLOCK_TIMESTAMP()",
+    "RANDOM()",
+    "GEN_RANDOM_UUID()",
+    "UUID_GENERATE_V4()",
+];
 
 /// 마이그레이션 검증기
 pub struct MigrationValidator<'a> {
@@ -39,6 +64,7 @@ impl<'a> MigrationValidator<'a> {
         self.check_view_dependencies(&mut report);
         self.check_missing_if_not_exists(&mut report);
         self.check_data_safety(&mut report);
+        self.check_lock_risk(&mut report);
 
         report
     }
@@ -388,6 +414,64 @@ impl<'a> MigrationValidator<'a> {
         }
     }
 
+    /// 대형 테이블 잠금 위험 검사
+    ///
+    /// 같은 파일에서 생성한 테이블은 비어 있으므로 제외합니다.
+    fn check_lock_risk(&self, report: &mut ValidationReport) {
+        let hypertables: HashSet<String> = self
+            .files
+            .iter()
+            .flat_map(|f| &f.statements)
+            .filter(|s| s.statement_type == StatementType::SelectInto)
+            .map(|s| s.object_name.to_lowercase())
+            .collect();
+
+        for file in self.files {
+            let created_here: HashSet<String> = file
+                .statements
+                .iter()
+                .filter(|s| s.statement_type == StatementType::CreateTable)
+                .map(|s| s.object_name.to_lowercase())
+                .collect();
+
+            for stmt in &file.statements {
+                let table = match stmt.statement_type {
+                    StatementType::CreateIndex => self.analyzer.index_table(&stmt.raw_sql),
+                    StatementType::AlterTable => Some(stmt.object_name.clone()),
+                    _ => None,
+                };
+                let Some(table) = table.map(|t| t.to_lowercase()) else {
+                    continue;
+                };
+                if created_here.contains(&table) {
+                    continue;
+                }
+
+                let is_hypertable = hypertables.contains(&table);
+                if !is_hypertable && !is_large_table_name(&table) {
+                    continue;
+                }
+
+                let issues = if stmt.statement_type == StatementType::CreateIndex {
+                    index_lock_issue(stmt, &table, is_hypertable)
+                        .into_iter()
+                        .collect()
+                } else {
+                    alter_lock_issues(stmt, &table)
+                };
+
+                for issue in issues {
+                    report.add_issue(
+                        issue
+                            .with_file(&file.name)
+                            .with_line(stmt.line_number)
+                            .with_object(&table),
+                    );
+                }
+            }
+        }
+    }
+
     /// 시스템 객체 여부 확인
     fn is_system_object(&self, name: &str) -> bool {
         let system_names = [
@@ -415,6 +499,113 @@ impl<'a> MigrationValidator<'a> {
     }
 }
 
+/// 이름 패턴으로 대형 테이블 여부 추정
+fn is_large_table_name(table: &str) -> bool {
+    LARGE_TABLE_PATTERNS.iter().any(|p| table.contains(p))
+}
+
+/// 비동시 인덱스 생성 검사 (LOCK001)
+fn index_lock_issue(
+    stmt: &SqlStatement,
+    table: &str,
+    is_hypertable: bool,
+) -> Option<ValidationIssue> {
+    let sql_upper = stmt.raw_sql.to_uppercase();
+    if sql_upper.contains("CONCURRENTLY") || sql_upper.contains("TRANSACTION_PER_CHUNK") {
+        return None;
+    }
+
+    // TimescaleDB hypertable은 CONCURRENTLY를 지원하지 않음
+    let suggestion = if is_hypertable {
+        "hypertable은 CONCURRENTLY 미지원. WITH (timescaledb.transaction_per_chunk)로 청크별 생성 권장."
+    } else {
+        "CREATE INDEX CONCURRENTLY 사용 권장 (트랜잭션 블록 밖에서 실행)."
+    };
+
+    Some(
+        ValidationIssue::new(
+            Severity::Warning,
+            "LOCK001",
+            &format!(
+                "대형 테이블 '{}'에 비동시 인덱스 생성 - 생성 중 쓰기 차단",
+                table
+            ),
+        )
+        .with_suggestion(suggestion),
+    )
+}
+
+/// ALTER TABLE 잠금 유발 동작 검사 (LOCK002 ~ LOCK005)
+fn alter_lock_issues(stmt: &SqlStatement, table: &str) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    for action in alter_table_actions(&stmt.raw_sql).unwrap_or_default() {
+        let action_upper = action.to_uppercase();
+        let action_upper = action_upper
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if action_upper.starts_with("ALTER") && action_upper.contains("SET NOT NULL") {
+            issues.push(
+                ValidationIssue::new(
+                    Severity::Warning,
+                    "LOCK002",
+                    &format!("대형 테이블 '{}' SET NOT NULL - 전체 스캔 동안 잠금", table),
+                )
+                .with_suggestion(
+                    "CHECK (col IS NOT NULL) NOT VALID 추가 → VALIDATE CONSTRAINT → SET NOT NULL 순서로 분리 권장.",
+                ),
+            );
+        } else if action_upper.starts_with("ADD")
+            && !action_upper.starts_with("ADD CONSTRAINT")
+            && VOLATILE_DEFAULTS.iter().any(|f| action_upper.contains(f))
+        {
+            issues.push(
+                ValidationIssue::new(
+                    Severity::Error,
+                    "LOCK003",
+                    &format!(
+                        "대형 테이블 '{}'에 휘발성 기본값 컬럼 추가 - 테이블 재작성",
+                        table
+                    ),
+                )
+                .with_suggestion(
+                    "기본값 없이 nullable 컬럼 추가 → 배치 백필 → DEFAULT/NOT NULL 설정 순서로 분리 권장.",
+                ),
+            );
+        } else if action_upper.starts_with("ALTER") && action_upper.contains(" TYPE ") {
+            issues.push(
+                ValidationIssue::new(
+                    Severity::Error,
+                    "LOCK004",
+                    &format!("대형 테이블 '{}' 컬럼 타입 변경 - 테이블 재작성", table),
+                )
+                .with_suggestion(
+                    "새 컬럼 추가 → 배치 백필 → 애플리케이션 전환 → 기존 컬럼 삭제 순서 권장.",
+                ),
+            );
+        } else if action_upper.starts_with("ADD")
+            && (action_upper.contains("FOREIGN KEY") || action_upper.contains("CHECK"))
+            && !action_upper.contains("NOT VALID")
+        {
+            issues.push(
+                ValidationIssue::new(
+                    Severity::Warning,
+                    "LOCK005",
+                    &format!(
+                        "대형 테이블 '{}' 제약조건 추가 - 전체 검증 동안 잠금",
+                        table
+                    ),
+                )
+                .with_suggestion("NOT VALID로 추가 후 VALIDATE CONSTRAINT 별도 실행 권장."),
+            );
+        }
+    }
+
+    issues
+}
+
 /// 안전한 마이그레이션 체크리스트 생성
 pub fn generate_safety_checklist(report: &ValidationReport) -> String {
     let mut checklist = String::new();
@@ -456,7 +647,33 @@ pub fn generate_safety_checklist(report: &ValidationReport) -> String {
         checklist.push('\n');
     }
 
-    checklist.push_str("□ 4. 실행 후 확인\n");
+    // 대형 테이블 잠금 위험 시
+    let lock_issues: Vec<_> = report
+        .issues
+        .iter()
+        .filter(|i| i.code.starts_with("LOCK"))
+        .collect();
+
+    if !lock_issues.is_empty() {
+        checklist.push_str(&format!("□ 4. 잠금 위험 ({} 건)\n", lock_issues.len()));
+        for issue in lock_issues {
+            let location = match (&issue.file, issue.line) {
+                (Some(file), Some(line)) => format!(" ({}:{})", file, line),
+                _ => String::new(),
+            };
+            checklist.push_str(&format!(
+                "   □ [{}] {}{}\n",
+                issue.severity, issue.message, location
+            ));
+            if let Some(ref suggestion) = issue.suggestion {
+                checklist.push_str(&format!("      → {}\n", suggestion));
+            }
+        }
+        checklist.push_str("   □ 트래픽이 적은 시간대에 실행, lock_timeout 설정\n");
+        checklist.push('\n');
+    }
+
+    checklist.push_str("□ 5. 실행 후 확인\n");
     checklist.push_str("   □ 모든 테이블 접근 가능 확인\n");
     checklist.push_str("   □ 주요 쿼리 정상 동작 확인\n");
     checklist.push_str("   □ 애플리케이션 정상 작동 확인\n");
@@ -560,6 +777,96 @@ mod tests {
         assert!(!report.issues.iter().any(|i| i.code == "CASC001"));
     }
 
+    fn stmt(statement_type: StatementType, object: &str, sql: &str) -> SqlStatement {
+        SqlStatement::new(statement_type, object.to_string(), sql.to_string(), 1)
+    }
+
+    #[test]
+    fn test_lock_risk_on_large_tables() {
+        let files = vec![
+            create_test_file(
+                "01",
+                1,
+                vec![
+                    stmt(StatementType::CreateTable, "ohlcv", "CREATE TABLE ohlcv (time TIMESTAMPTZ)"),
+                    stmt(
+                        StatementType::SelectInto,
+                        "ohlcv",
+                        "SELECT create_hypertable('ohlcv', 'time')",
+                    ),
+                    // 같은 파일에서 생성한 빈 테이블은 제외
+                    stmt(
+                        StatementType::CreateIndex,
+                        "idx_ohlcv_time",
+                        "CREATE INDEX idx_ohlcv_time ON ohlcv (time)",
+                    ),
+                ],
+            ),
+            create_test_file(
+                "02",
+                2,
+                vec![
+                    stmt(
+                        StatementType::CreateIndex,
+                        "idx_ohlcv_symbol",
+                        "CREATE INDEX idx_ohlcv_symbol ON ohlcv (symbol)",
+                    ),
+                    stmt(
+                        StatementType::CreateIndex,
+                        "idx_logs_user",
+                        "CREATE INDEX CONCURRENTLY idx_logs_user ON access_log (user_id)",
+                    ),
+                    stmt(
+                        StatementType::AlterTable,
+                        "ohlcv",
+                        "ALTER TABLE ohlcv ALTER COLUMN symbol SET NOT NULL, ALTER COLUMN close TYPE NUMERIC",
+                    ),
+                    // 소형 테이블은 제외
+                    stmt(
+                        StatementType::AlterTable,
+                        "users",
+                        "ALTER TABLE users ALTER COLUMN email SET NOT NULL",
+                    ),
+                ],
+            ),
+        ];
+
+        let report = MigrationValidator::new(&files).validate();
+        let lock: Vec<_> = report
+            .issues
+            .iter()
+            .filter(|i| i.code.starts_with("LOCK"))
+            .map(|i| (i.code.as_str(), i.severity, i.file.as_deref()))
+            .collect();
+
+        assert_eq!(
+            lock,
+            vec![
+                ("LOCK001", Severity::Warning, Some("02")),
+                ("LOCK002", Severity::Warning, Some("02")),
+                ("LOCK004", Severity::Error, Some("02")),
+            ]
+        );
+        assert!(report.issues.iter().any(|i| i.code == "LOCK001"
+            && i.suggestion
+                .as_deref()
+                .unwrap()
+                .contains("transaction_per_chunk")));
+        assert_eq!(report.count_at_least(Severity::Error), 1);
+
+        let checklist = generate_safety_checklist(&report);
+        assert!(checklist.contains("잠금 위험 (3 건)"));
+        assert!(checklist.contains("배치 백필"));
+    }
+
+    #[test]
+    fn test_severity_parse() {
+        assert_eq!(Severity::parse("critical"), Some(Severity::Error));
+        assert_eq!(Severity::parse("warn"), Some(Severity::Warning));
+        assert_eq!(Severity::parse("INFO"), Some(Severity::Info));
+        assert_eq!(Severity::parse("fatal"), None);
+    }
+
     #[test]
     fn test_safety_checklist() {
         let mut report = ValidationReport::new();
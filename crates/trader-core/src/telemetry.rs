@@ -0,0 +1,138 @@
+//! 트레이딩 Prometheus 메트릭 이름·라벨 정의.
+//!
+//! 주문 생명주기, 실현손익, 포지션 수, 거래소 API 호출 메트릭의 이름과 라벨 값만 정의하며,
+//! 이 crate는 메트릭 라이브러리에 의존하지 않습니다. 기록은 trader-execution(주문·포지션)과
+//! trader-exchange(거래소 API)가, 레코더 설치와 `/metrics` 노출은 trader-api가 담당합니다.
+//!
+//! # 라벨 규칙
+//!
+//! 카디널리티 폭발을 막기 위해 심볼(ticker)은 라벨로 사용하지 않습니다.
+//! 라벨은 다음으로 제한됩니다:
+//!
+//! - `strategy`: 전략 ID (전략 없는 수동 주문은 [`MANUAL_STRATEGY`])
+//! - `exchange`: 거래소/provider 이름
+//! - `event`: 주문 이벤트 (`created`, `submitted`, `filled`, `cancelled`, `rejected`, `expired`)
+//! - `operation`: provider 메서드 이름 (`fetch_account`, `place_order` 등)
+//! - `outcome`: `success` / `error` / `unsupported` (provider가 기능을 지원하지 않음)
+//! - `kind`: [`ProviderError::kind`] 값
+//!
+//! # 메트릭 목록
+//!
+//! | 이름 | 타입 | 단위 | 라벨 |
+//! |------|------|------|------|
+//! | `trading_orders_total` | counter | 건 | strategy, exchange, event |
+//! | `trading_order_fills_total` | counter | 건 | strategy, exchange |
+//! | `trading_order_fill_latency_seconds` | histogram | 초 | strategy, exchange |
+//! | `trading_realized_pnl` | gauge | 계좌 통화 | strategy, exchange |
+//! | `trading_positions_open` | gauge | 개 | strategy, exchange |
+//! | `exchange_api_requests_total` | counter | 건 | exchange, operation, outcome |
+//! | `exchange_api_errors_total` | counter | 건 | exchange, operation, kind |
+//! | `exchange_api_request_duration_seconds` | histogram | 초 | exchange, operation |
+//!
+//! 거부율은 `rate(trading_orders_total{event="rejected"}) / rate(trading_orders_total{event="created"})`,
+//! API 에러율은 `rate(exchange_api_requests_total{outcome="error"}) / rate(exchange_api_requests_total)`로
+//! 계산합니다.
+
+use crate::ProviderError;
+
+/// 주문 이벤트 카운터 (건).
+pub const ORDERS_TOTAL: &str = "trading_orders_total";
+/// 체결 카운터 (부분 체결 포함, 건).
+pub const ORDER_FILLS_TOTAL: &str = "trading_order_fills_total";
+/// 주문 생성부터 전량 체결까지 걸린 시간 (초).
+pub const ORDER_FILL_LATENCY_SECONDS: &str = "trading_order_fill_latency_seconds";
+/// 누적 실현손익 (계좌 통화).
+pub const REALIZED_PNL: &str = "trading_realized_pnl";
+/// 열린 포지션 수 (개).
+pub const POSITIONS_OPEN: &str = "trading_positions_open";
+/// 거래소 API 호출 카운터 (건).
+pub const EXCHANGE_API_REQUESTS_TOTAL: &str = "exchange_api_requests_total";
+/// 거래소 API 에러 카운터 (건).
+pub const EXCHANGE_API_ERRORS_TOTAL: &str = "exchange_api_errors_total";
+/// 거래소 API 호출 소요 시간 (초).
+pub const EXCHANGE_API_REQUEST_DURATION_SECONDS: &str = "exchange_api_request_duration_seconds";
+
+/// 체결 지연 히스토그램 버킷 (초).
+pub const ORDER_FILL_LATENCY_BUCKETS: &[f64] = &[
+    0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0, 900.0, 3600.0,
+];
+/// 거래소 API 호출 시간 히스토그램 버킷 (초).
+pub const EXCHANGE_API_DURATION_BUCKETS: &[f64] =
+    &[0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// 전략 ID가 없는 주문/포지션의 `strategy` 라벨.
+pub const MANUAL_STRATEGY: &str = "manual";
+
+/// 전략 라벨 값.
+pub fn strategy_label(strategy_id: Option<&str>) -> String {
+    match strategy_id {
+        Some(id) if !id.is_empty() => id.to_string(),
+        _ => MANUAL_STRATEGY.to_string(),
+    }
+}
+
+/// 거래소 API 호출 결과 (`outcome` 라벨).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiCallOutcome {
+    /// 성공
+    Success,
+    /// 실패 (`kind` 라벨은 [`ProviderError::kind`])
+    Error(&'static str),
+    /// provider가 기능을 지원하지 않음 (장애가 아니므로 에러율에서 제외)
+    Unsupported,
+}
+
+impl ApiCallOutcome {
+    /// 호출 결과의 에러로부터 분류.
+    pub fn from_error(error: Option<&ProviderError>) -> Self {
+        match error {
+            None => Self::Success,
+            Some(ProviderError::Unsupported(_)) => Self::Unsupported,
+            Some(error) => Self::Error(error.kind()),
+        }
+    }
+
+    /// `outcome` 라벨 값.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Error(_) => "error",
+            Self::Unsupported => "unsupported",
+        }
+    }
+
+    /// 에러인 경우 `kind` 라벨 값.
+    pub fn error_kind(&self) -> Option<&'static str> {
+        match self {
+            Self::Error(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strategy_label() {
+        assert_eq!(strategy_label(Some("rsi_1")), "rsi_1");
+        assert_eq!(strategy_label(Some("")), MANUAL_STRATEGY);
+        assert_eq!(strategy_label(None), MANUAL_STRATEGY);
+    }
+
+    #[test]
+    fn test_api_call_outcome() {
+        assert_eq!(ApiCallOutcome::from_error(None).as_str(), "success");
+
+        let unsupported = ProviderError::Unsupported("fetch_pending_orders".to_string());
+        let outcome = ApiCallOutcome::from_error(Some(&unsupported));
+        assert_eq!(outcome.as_str(), "unsupported");
+        assert_eq!(outcome.error_kind(), None);
+
+        let network = ProviderError::Network("reset".to_string());
+        let outcome = ApiCallOutcome::from_error(Some(&network));
+        assert_eq!(outcome.as_str(), "error");
+        assert_eq!(outcome.error_kind(), Some(network.kind()));
+    }
+}
@@ -2,8 +2,10 @@
 
 mod decimal;
 mod symbol;
+mod symbol_normalizer;
 mod timeframe;
 
 pub use decimal::*;
 pub use symbol::*;
+pub use symbol_normalizer::*;
 pub use timeframe::*;
@@ -9,6 +9,8 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
+use super::KrMarket;
+
 /// 시장 유형 분류 (정규화).
 ///
 /// UsStock, KrStock 등은 향후 Stock + Country 조합으로 대체됩니다.
@@ -289,7 +291,7 @@ pub struct YahooSymbolConverter;
 impl YahooSymbolConverter {
     /// 한국 거래소 판별 (KOSPI/KOSDAQ).
     ///
-    /// 종목 코드 첫 글자로 판별:
+    /// [`KrMarket::from_code`]의 예외 테이블을 먼저 확인하고, 없으면 종목 코드 첫 글자로 판별:
     /// - `0`: KOSPI
     /// - `1~4`: KOSDAQ
     /// - 기타: KOSDAQ (기본값)
@@ -297,16 +299,8 @@ impl YahooSymbolConverter {
     /// # Returns
     /// (거래소명, Yahoo 접미사)
     pub fn determine_kr_exchange(ticker: &str) -> (&'static str, &'static str) {
-        if ticker.is_empty() {
-            return ("KOSPI", ".KS");
-        }
-
-        let first_char = ticker.chars().next().unwrap();
-        match first_char {
-            '0' => ("KOSPI", ".KS"),
-            '1'..='4' => ("KOSDAQ", ".KQ"),
-            _ => ("KOSDAQ", ".KQ"),
-        }
+        let market = KrMarket::from_code(ticker);
+        (market.name(), market.yahoo_suffix())
     }
 
     /// Canonical ticker를 Yahoo Finance 심볼로 변환.
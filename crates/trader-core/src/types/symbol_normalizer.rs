@@ -0,0 +1,527 @@
+//! 거래소 중립 심볼 정규화.
+//!
+//! 내부 표준 심볼과 거래소별 심볼 포맷 사이의 양방향 변환을 한곳에서 처리합니다.
+//!
+//! | 자산 | 내부 표준 | Yahoo | KIS | Binance | Upbit |
+//! |------|-----------|-------|-----|---------|-------|
+//! | 국내 주식 | `005930` | `005930.KS` / `086520.KQ` | `005930` | - | - |
+//! | 미국 주식 | `AAPL`, `BRK.B` | `AAPL`, `BRK-B` | `AAPL`, `BRK/B` | - | - |
+//! | 암호화폐 | `BTC/USDT`, `BTC/KRW` | `BTC-USD` | - | `BTCUSDT` | `KRW-BTC` |
+//!
+//! 내부 표준 → 거래소 → 내부 표준 변환은 항상 원본을 복원하며, 역변환 결과가
+//! 원본과 다른 조합은 변환 단계에서 거부합니다.
+//! Yahoo의 코스피/코스닥 접미사는 내부 심볼에 남지 않으므로, 역방향 변환은
+//! 예외 테이블과 [`SymbolNormalizer::with_kr_market`]으로 등록한 시장 정보를 따릅니다.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use thiserror::Error;
+
+/// 심볼 변환 대상 거래소/데이터 소스.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolVenue {
+    /// Yahoo Finance
+    Yahoo,
+    /// 한국투자증권
+    Kis,
+    /// Binance
+    Binance,
+    /// Upbit
+    Upbit,
+}
+
+impl fmt::Display for SymbolVenue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SymbolVenue::Yahoo => write!(f, "Yahoo"),
+            SymbolVenue::Kis => write!(f, "KIS"),
+            SymbolVenue::Binance => write!(f, "Binance"),
+            SymbolVenue::Upbit => write!(f, "Upbit"),
+        }
+    }
+}
+
+/// 국내 주식 상장 시장.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KrMarket {
+    /// 유가증권시장 (ETF 포함)
+    Kospi,
+    /// 코스닥
+    Kosdaq,
+}
+
+impl KrMarket {
+    /// 종목 코드로 상장 시장 판별.
+    ///
+    /// 예외 테이블을 먼저 확인하고, 없으면 첫 글자로 판별합니다
+    /// (`0` 또는 빈 문자열: 코스피, 그 외: 코스닥).
+    pub fn from_code(code: &str) -> Self {
+        if let Some((_, market)) = KR_MARKET_EXCEPTIONS.iter().find(|(c, _)| *c == code) {
+            return *market;
+        }
+        match code.chars().next() {
+            None | Some('0') => KrMarket::Kospi,
+            Some(_) => KrMarket::Kosdaq,
+        }
+    }
+
+    /// 거래소명 (KOSPI/KOSDAQ).
+    pub fn name(&self) -> &'static str {
+        match self {
+            KrMarket::Kospi => "KOSPI",
+            KrMarket::Kosdaq => "KOSDAQ",
+        }
+    }
+
+    /// Yahoo Finance 접미사.
+    pub fn yahoo_suffix(&self) -> &'static str {
+        match self {
+            KrMarket::Kospi => ".KS",
+            KrMarket::Kosdaq => ".KQ",
+        }
+    }
+}
+
+/// 첫 글자 규칙과 실제 상장 시장이 다른 종목.
+const KR_MARKET_EXCEPTIONS: &[(&str, KrMarket)] = &[
+    // 1~4로 시작하는 코스피 종목
+    ("105560", KrMarket::Kospi), // KB금융
+    ("138040", KrMarket::Kospi), // 메리츠금융지주
+    ("207940", KrMarket::Kospi), // 삼성바이오로직스
+    ("229200", KrMarket::Kospi), // KODEX 코스닥150 (ETF)
+    ("259960", KrMarket::Kospi), // 크래프톤
+    ("302440", KrMarket::Kospi), // SK바이오사이언스
+    ("316140", KrMarket::Kospi), // 우리금융지주
+    ("323410", KrMarket::Kospi), // 카카오뱅크
+    ("352820", KrMarket::Kospi), // 하이브
+    ("373220", KrMarket::Kospi), // LG에너지솔루션
+    ("377300", KrMarket::Kospi), // 카카오페이
+    // 0으로 시작하는 코스닥 종목
+    ("028300", KrMarket::Kosdaq), // HLB
+    ("035900", KrMarket::Kosdaq), // JYP Ent.
+    ("039030", KrMarket::Kosdaq), // 이오테크닉스
+    ("041510", KrMarket::Kosdaq), // 에스엠
+    ("058470", KrMarket::Kosdaq), // 리노공업
+    ("068760", KrMarket::Kosdaq), // 셀트리온제약
+    ("086520", KrMarket::Kosdaq), // 에코프로
+];
+
+/// 미국 클래스주 구분자 (BRK.B, BF.A 등).
+const US_SHARE_CLASSES: &[&str] = &["A", "B", "C"];
+
+/// Binance 호가 자산 (접미사 매칭 우선순위 순).
+const BINANCE_QUOTES: &[&str] = &[
+    "FDUSD", "USDT", "USDC", "BUSD", "TUSD", "BTC", "ETH", "BNB", "EUR",
+];
+
+/// Upbit 마켓 (원화/BTC/USDT 마켓).
+const UPBIT_QUOTES: &[&str] = &["KRW", "BTC", "USDT"];
+
+/// Yahoo Finance 암호화폐 호가 통화.
+const YAHOO_CRYPTO_QUOTES: &[&str] = &["USD", "EUR", "KRW", "JPY", "GBP"];
+
+/// 심볼 변환 에러.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SymbolError {
+    /// 형식이 잘못된 심볼
+    #[error("잘못된 심볼 형식: {0}")]
+    Invalid(String),
+
+    /// 해당 거래소에서 표현할 수 없는 심볼
+    #[error("{venue}에서 지원하지 않는 심볼: {symbol}")]
+    Unsupported {
+        /// 대상 거래소
+        venue: SymbolVenue,
+        /// 변환하려던 심볼
+        symbol: String,
+    },
+}
+
+/// 내부 표준 심볼 분류.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Canonical {
+    /// 국내 주식 (6자리 종목 코드)
+    KrStock(String),
+    /// 미국 주식 (클래스주 포함)
+    UsStock { root: String, class: Option<String> },
+    /// 암호화폐 페어
+    Crypto { base: String, quote: String },
+}
+
+impl Canonical {
+    /// 내부 표준 심볼 파싱.
+    fn parse(symbol: &str) -> Result<Self, SymbolError> {
+        let upper = symbol.trim().to_uppercase();
+        let invalid = || SymbolError::Invalid(symbol.to_string());
+
+        if let Some((base, quote)) = upper.split_once('/') {
+            if !is_asset_code(base) || !is_asset_code(quote) {
+                return Err(invalid());
+            }
+            return Ok(Canonical::Crypto {
+                base: base.to_string(),
+                quote: quote.to_string(),
+            });
+        }
+
+        if is_kr_code(&upper) {
+            return Ok(Canonical::KrStock(upper));
+        }
+
+        let (root, class) = match upper.split_once('.') {
+            Some((root, class)) if US_SHARE_CLASSES.contains(&class) => {
+                (root, Some(class.to_string()))
+            }
+            Some(_) => return Err(invalid()),
+            None => (upper.as_str(), None),
+        };
+        if !is_us_root(root) {
+            return Err(invalid());
+        }
+        Ok(Canonical::UsStock {
+            root: root.to_string(),
+            class,
+        })
+    }
+
+    /// 내부 표준 문자열.
+    fn internal_string(&self) -> String {
+        match self {
+            Canonical::KrStock(code) => code.clone(),
+            Canonical::UsStock { root, class: None } => root.clone(),
+            Canonical::UsStock {
+                root,
+                class: Some(class),
+            } => format!("{}.{}", root, class),
+            Canonical::Crypto { base, quote } => format!("{}/{}", base, quote),
+        }
+    }
+}
+
+/// 국내 종목 코드 여부 (숫자로 시작하는 6자리 영숫자, 예: 005930, 03473K).
+fn is_kr_code(s: &str) -> bool {
+    s.len() == 6
+        && s.starts_with(|c: char| c.is_ascii_digit())
+        && s.chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// 미국 종목 티커 본체 여부 (영문 대문자 1~5자).
+fn is_us_root(s: &str) -> bool {
+    (1..=5).contains(&s.len()) && s.chars().all(|c| c.is_ascii_uppercase())
+}
+
+/// 암호화폐 자산 코드 여부.
+fn is_asset_code(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// 거래소 중립 심볼 정규화기.
+///
+/// 기본 예외 테이블 외에 DB 등에서 확인한 국내 종목의 상장 시장을
+/// [`with_kr_market`](Self::with_kr_market)으로 등록할 수 있습니다.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolNormalizer {
+    /// 종목 코드별 상장 시장 (예외 테이블보다 우선)
+    kr_markets: HashMap<String, KrMarket>,
+}
+
+impl SymbolNormalizer {
+    /// 기본 예외 테이블만 사용하는 정규화기 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 국내 종목의 상장 시장을 등록합니다.
+    pub fn with_kr_market(mut self, code: impl Into<String>, market: KrMarket) -> Self {
+        self.kr_markets.insert(code.into().to_uppercase(), market);
+        self
+    }
+
+    /// 국내 종목의 상장 시장.
+    pub fn kr_market(&self, code: &str) -> KrMarket {
+        self.kr_markets
+            .get(code)
+            .copied()
+            .unwrap_or_else(|| KrMarket::from_code(code))
+    }
+
+    /// 내부 표준 심볼을 거래소 포맷으로 변환합니다.
+    pub fn to_venue(&self, symbol: &str, venue: SymbolVenue) -> Result<String, SymbolError> {
+        let canonical = Canonical::parse(symbol)?;
+        let unsupported = || SymbolError::Unsupported {
+            venue,
+            symbol: symbol.to_string(),
+        };
+
+        let converted = match (venue, &canonical) {
+            (SymbolVenue::Yahoo, Canonical::KrStock(code)) => {
+                format!("{}{}", code, self.kr_market(code).yahoo_suffix())
+            }
+            (SymbolVenue::Kis, Canonical::KrStock(code)) => code.clone(),
+            (SymbolVenue::Yahoo | SymbolVenue::Kis, Canonical::UsStock { root, class: None }) => {
+                root.clone()
+            }
+            (
+                SymbolVenue::Yahoo,
+                Canonical::UsStock {
+                    root,
+                    class: Some(class),
+                },
+            ) => format!("{}-{}", root, class),
+            (
+                SymbolVenue::Kis,
+                Canonical::UsStock {
+                    root,
+                    class: Some(class),
+                },
+            ) => format!("{}/{}", root, class),
+            (SymbolVenue::Yahoo, Canonical::Crypto { base, quote })
+                if YAHOO_CRYPTO_QUOTES.contains(&quote.as_str()) =>
+            {
+                format!("{}-{}", base, quote)
+            }
+            (SymbolVenue::Binance, Canonical::Crypto { base, quote })
+                if BINANCE_QUOTES.contains(&quote.as_str()) =>
+            {
+                format!("{}{}", base, quote)
+            }
+            (SymbolVenue::Upbit, Canonical::Crypto { base, quote })
+                if UPBIT_QUOTES.contains(&quote.as_str()) =>
+            {
+                format!("{}-{}", quote, base)
+            }
+            _ => return Err(unsupported()),
+        };
+
+        // 역변환으로 원본을 복원할 수 없는 조합 거부
+        match self.parse_venue(&converted, venue) {
+            Ok(restored) if restored == canonical => Ok(converted),
+            _ => Err(unsupported()),
+        }
+    }
+
+    /// 거래소 포맷 심볼을 내부 표준 심볼로 변환합니다.
+    pub fn to_internal(&self, symbol: &str, venue: SymbolVenue) -> Result<String, SymbolError> {
+        self.parse_venue(symbol, venue)
+            .map(|canonical| canonical.internal_string())
+    }
+
+    /// 거래소 포맷 심볼 파싱.
+    fn parse_venue(&self, symbol: &str, venue: SymbolVenue) -> Result<Canonical, SymbolError> {
+        let upper = symbol.trim().to_uppercase();
+        let invalid = || SymbolError::Invalid(symbol.to_string());
+        let unsupported = || SymbolError::Unsupported {
+            venue,
+            symbol: symbol.to_string(),
+        };
+
+        let internal = match venue {
+            SymbolVenue::Yahoo => {
+                if let Some(code) = upper
+                    .strip_suffix(".KS")
+                    .or_else(|| upper.strip_suffix(".KQ"))
+                {
+                    if !is_kr_code(code) {
+                        return Err(invalid());
+                    }
+                    code.to_string()
+                } else if let Some((left, right)) = upper.rsplit_once('-') {
+                    if YAHOO_CRYPTO_QUOTES.contains(&right) {
+                        format!("{}/{}", left, right)
+                    } else if US_SHARE_CLASSES.contains(&right) {
+                        format!("{}.{}", left, right)
+                    } else {
+                        return Err(invalid());
+                    }
+                } else if upper.contains('.') {
+                    // 국내/미국 외 시장(.T, .L 등)은 변환 대상 아님
+                    return Err(unsupported());
+                } else {
+                    upper
+                }
+            }
+            SymbolVenue::Kis => match upper.split_once('/') {
+                Some((root, class)) if US_SHARE_CLASSES.contains(&class) => {
+                    format!("{}.{}", root, class)
+                }
+                Some(_) => return Err(invalid()),
+                None => upper,
+            },
+            SymbolVenue::Binance => BINANCE_QUOTES
+                .iter()
+                .find_map(|quote| {
+                    upper
+                        .strip_suffix(quote)
+                        .filter(|base| is_asset_code(base))
+                        .map(|base| format!("{}/{}", base, quote))
+                })
+                .ok_or_else(unsupported)?,
+            SymbolVenue::Upbit => match upper.split_once('-') {
+                Some((quote, base)) if UPBIT_QUOTES.contains(&quote) => {
+                    format!("{}/{}", base, quote)
+                }
+                _ => return Err(unsupported()),
+            },
+        };
+
+        let canonical = Canonical::parse(&internal)?;
+        // 주식 거래소에서 암호화폐로, 암호화폐 거래소에서 주식으로 해석되는 입력 거부
+        let is_crypto = matches!(canonical, Canonical::Crypto { .. });
+        let crypto_venue = matches!(venue, SymbolVenue::Binance | SymbolVenue::Upbit);
+        if (venue == SymbolVenue::Kis && is_crypto) || (crypto_venue && !is_crypto) {
+            return Err(unsupported());
+        }
+        Ok(canonical)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (내부 표준, 거래소, 거래소 포맷)
+    const CASES: &[(&str, SymbolVenue, &str)] = &[
+        ("005930", SymbolVenue::Yahoo, "005930.KS"),
+        ("124560", SymbolVenue::Yahoo, "124560.KQ"),
+        ("086520", SymbolVenue::Yahoo, "086520.KQ"),
+        ("373220", SymbolVenue::Yahoo, "373220.KS"),
+        ("03473K", SymbolVenue::Yahoo, "03473K.KS"),
+        ("AAPL", SymbolVenue::Yahoo, "AAPL"),
+        ("BRK.B", SymbolVenue::Yahoo, "BRK-B"),
+        ("BTC/USD", SymbolVenue::Yahoo, "BTC-USD"),
+        ("005930", SymbolVenue::Kis, "005930"),
+        ("AAPL", SymbolVenue::Kis, "AAPL"),
+        ("BRK.B", SymbolVenue::Kis, "BRK/B"),
+        ("BF.A", SymbolVenue::Kis, "BF/A"),
+        ("BTC/USDT", SymbolVenue::Binance, "BTCUSDT"),
+        ("ETH/BTC", SymbolVenue::Binance, "ETHBTC"),
+        ("SOL/FDUSD", SymbolVenue::Binance, "SOLFDUSD"),
+        ("BTC/KRW", SymbolVenue::Upbit, "KRW-BTC"),
+        ("ETH/BTC", SymbolVenue::Upbit, "BTC-ETH"),
+    ];
+
+    #[test]
+    fn test_conversion_table() {
+        let normalizer = SymbolNormalizer::new();
+        for (internal, venue, external) in CASES {
+            assert_eq!(
+                normalizer.to_venue(internal, *venue).as_deref(),
+                Ok(*external),
+                "{} → {}",
+                internal,
+                venue
+            );
+            assert_eq!(
+                normalizer.to_internal(external, *venue).as_deref(),
+                Ok(*internal),
+                "{} ← {}",
+                internal,
+                venue
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip_restores_original() {
+        let normalizer = SymbolNormalizer::new();
+        let internals = [
+            "005930", "000660", "124560", "086520", "105560", "229200", "03473K", "AAPL", "SPY",
+            "BRK.A", "BRK.B", "BF.B", "BTC/USDT", "BTC/KRW", "ETH/BTC", "XRP/USDC", "DOGE/USD",
+        ];
+        let venues = [
+            SymbolVenue::Yahoo,
+            SymbolVenue::Kis,
+            SymbolVenue::Binance,
+            SymbolVenue::Upbit,
+        ];
+
+        for internal in internals {
+            for venue in venues {
+                if let Ok(external) = normalizer.to_venue(internal, venue) {
+                    assert_eq!(
+                        normalizer.to_internal(&external, venue).as_deref(),
+                        Ok(internal),
+                        "{} via {} ({})",
+                        internal,
+                        venue,
+                        external
+                    );
+                    // 거래소 포맷 → 내부 → 거래소 포맷도 동일
+                    let internal = normalizer.to_internal(&external, venue).unwrap();
+                    assert_eq!(
+                        normalizer.to_venue(&internal, venue).as_deref(),
+                        Ok(external.as_str())
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kr_market_table_and_override() {
+        let normalizer = SymbolNormalizer::new();
+        assert_eq!(normalizer.kr_market("005930"), KrMarket::Kospi);
+        assert_eq!(normalizer.kr_market("247540"), KrMarket::Kosdaq);
+        // 첫 글자 규칙 예외
+        assert_eq!(normalizer.kr_market("105560"), KrMarket::Kospi);
+        assert_eq!(normalizer.kr_market("086520"), KrMarket::Kosdaq);
+
+        // 등록한 시장 정보가 예외 테이블보다 우선
+        let normalizer = SymbolNormalizer::new().with_kr_market("161390", KrMarket::Kospi);
+        assert_eq!(
+            normalizer.to_venue("161390", SymbolVenue::Yahoo).unwrap(),
+            "161390.KS"
+        );
+        let normalizer = SymbolNormalizer::new().with_kr_market("060310", KrMarket::Kosdaq);
+        assert_eq!(
+            normalizer.to_venue("060310", SymbolVenue::Yahoo).unwrap(),
+            "060310.KQ"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_and_invalid() {
+        let normalizer = SymbolNormalizer::new();
+
+        // 주식은 암호화폐 거래소에서 지원하지 않음
+        assert!(matches!(
+            normalizer.to_venue("005930", SymbolVenue::Binance),
+            Err(SymbolError::Unsupported { .. })
+        ));
+        assert!(matches!(
+            normalizer.to_venue("BTC/USDT", SymbolVenue::Kis),
+            Err(SymbolError::Unsupported { .. })
+        ));
+        // Upbit에 없는 마켓
+        assert!(matches!(
+            normalizer.to_venue("BTC/USDC", SymbolVenue::Upbit),
+            Err(SymbolError::Unsupported { .. })
+        ));
+        assert!(matches!(
+            normalizer.to_venue("ABC/XYZ", SymbolVenue::Binance),
+            Err(SymbolError::Unsupported { .. })
+        ));
+        // 해외 거래소 접미사
+        assert!(matches!(
+            normalizer.to_internal("7203.T", SymbolVenue::Yahoo),
+            Err(SymbolError::Unsupported { .. })
+        ));
+
+        assert!(matches!(
+            normalizer.to_venue("BRK.X", SymbolVenue::Yahoo),
+            Err(SymbolError::Invalid(_))
+        ));
+        assert!(matches!(
+            normalizer.to_venue("BTC/", SymbolVenue::Binance),
+            Err(SymbolError::Invalid(_))
+        ));
+        assert!(matches!(
+            normalizer.to_internal("KRW-BTC", SymbolVenue::Binance),
+            Err(SymbolError::Unsupported { .. })
+        ));
+    }
+}
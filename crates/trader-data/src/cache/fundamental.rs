@@ -569,6 +569,7 @@ impl FundamentalFetcher {
                     close_time,
                     quote_volume: None,
                     num_trades: None,
+                    synthetic: false,
                 })
             })
             .collect();
@@ -8,6 +8,7 @@
 //! - **시장 시간 체크**: 마감 후 불필요한 API 호출 방지
 //! - **갭 감지**: 누락된 캔들 자동 감지
 //! - **증분 업데이트**: 새 데이터만 가져와 캐시
+//! - **부분 범위 채우기**: 날짜 범위 조회 시 Redis에 없는 구간만 PostgreSQL에서 조회
 //!
 //! # 동작 흐름
 //!
@@ -48,15 +49,19 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use sqlx::postgres::PgPool;
 use time::OffsetDateTime;
 use tracing::{debug, info, instrument, warn};
-use trader_core::{Kline, Timeframe};
+use trader_core::{Kline, SymbolNormalizer, SymbolVenue, Timeframe};
 
 use crate::{
     error::{DataError, Result},
     provider::SymbolResolver,
-    storage::ohlcv::{timeframe_to_string, OhlcvCache},
+    storage::{
+        ohlcv::{timeframe_to_string, MissingCandlePolicy, OhlcvCache},
+        redis::kline_ttl_secs,
+    },
 };
 
 // =============================================================================
@@ -374,7 +379,12 @@ impl CachedHistoricalDataProvider {
 
     /// 날짜 범위로 캔들 데이터 조회 (읽기 전용).
     ///
-    /// PostgreSQL ohlcv 테이블에서만 데이터를 조회합니다.
+    /// 요청 범위는 반개구간 `[start_date 00:00, end_date + 1일 00:00)`이며,
+    /// `open_time`이 이 구간에 속하는 캔들만 반환합니다.
+    ///
+    /// Redis가 설정된 경우 캐시된 구간과 누락 구간을 계산해 누락된 부분만
+    /// PostgreSQL에서 조회한 뒤 병합합니다. 가까운 누락 구간들은 하나로 합쳐
+    /// 조회 횟수를 줄이며, 채운 구간은 Redis에 기록되어 다음 요청은 완전 히트가 됩니다.
     ///
     /// **중요**: 데이터가 없거나 부족해도 외부 API를 호출하지 않습니다.
     /// 데이터 수집/갱신은 Collector에서만 수행합니다.
@@ -382,11 +392,11 @@ impl CachedHistoricalDataProvider {
     /// # 인자
     /// - `symbol`: canonical 심볼 (예: "005930", "AAPL", "BTC/USDT")
     /// - `timeframe`: 타임프레임
-    /// - `start_date`: 시작 날짜
-    /// - `end_date`: 종료 날짜
+    /// - `start_date`: 시작 날짜 (포함)
+    /// - `end_date`: 종료 날짜 (포함)
     ///
     /// # 반환
-    /// 캐시에 있는 캔들 데이터 (없으면 빈 Vec)
+    /// 캐시에 있는 캔들 데이터 (시간순, 없으면 빈 Vec)
     #[instrument(skip(self))]
     pub async fn get_klines_range(
         &self,
@@ -407,17 +417,29 @@ impl CachedHistoricalDataProvider {
             "날짜 범위 데이터 조회 요청 (읽기 전용)"
         );
 
-        // PostgreSQL 캐시에서 조회 (외부 API 호출 없음)
-        let start_dt = Utc.from_utc_datetime(&start_date.and_hms_opt(0, 0, 0).unwrap());
-        let end_dt = Utc.from_utc_datetime(&end_date.and_hms_opt(23, 59, 59).unwrap());
+        let requested = TimeRange::from_dates(start_date, end_date);
 
-        let cached_klines = self
-            .cache
-            .get_cached_klines_range(&ticker, timeframe, start_dt, end_dt)
-            .await?;
+        let klines = match &self.redis_cache {
+            Some(redis) => {
+                self.get_klines_range_cached(redis, &ticker, timeframe, requested)
+                    .await?
+            }
+            // Redis 없음 - PostgreSQL에서 전체 범위 조회 (외부 API 호출 없음)
+            None => {
+                self.cache
+                    .get_cached_klines_range(
+                        &ticker,
+                        timeframe,
+                        requested.start,
+                        requested.end,
+                        MissingCandlePolicy::None,
+                    )
+                    .await?
+            }
+        };
 
         // canonical 심볼로 변환하여 반환
-        let klines: Vec<Kline> = cached_klines
+        let klines: Vec<Kline> = klines
             .into_iter()
             .map(|k| Kline {
                 ticker: symbol.to_string(),
@@ -429,13 +451,105 @@ impl CachedHistoricalDataProvider {
             canonical = %symbol,
             ticker = %ticker,
             returned = klines.len(),
-            source = "postgresql",
             "날짜 범위 데이터 반환 (읽기 전용)"
         );
 
         Ok(klines)
     }
 
+    /// Redis 범위 캐시를 사용한 날짜 범위 조회 (누락 구간만 PostgreSQL 조회).
+    async fn get_klines_range_cached(
+        &self,
+        redis: &Arc<crate::storage::redis::RedisCache>,
+        ticker: &str,
+        timeframe: Timeframe,
+        requested: TimeRange,
+    ) -> Result<Vec<Kline>> {
+        // "local"을 exchange로 사용 (단일 시장 환경), 키에 kline 버전 태그 포함
+        let key = match redis.klines_range_key("local", ticker, &timeframe).await {
+            Ok(key) => key,
+            Err(e) => {
+                warn!(ticker = %ticker, error = %e, "Redis 버전 조회 실패, PostgreSQL fallback");
+                return self
+                    .cache
+                    .get_cached_klines_range(
+                        ticker,
+                        timeframe,
+                        requested.start,
+                        requested.end,
+                        MissingCandlePolicy::None,
+                    )
+                    .await;
+            }
+        };
+
+        let mut entry: RangeCacheEntry = match redis.get(&key).await {
+            Ok(entry) => entry.unwrap_or_default(),
+            Err(e) => {
+                warn!(ticker = %ticker, error = %e, "Redis 범위 캐시 조회 실패, PostgreSQL fallback");
+                RangeCacheEntry::default()
+            }
+        };
+
+        let candle = timeframe_to_duration(timeframe);
+        let gaps = coalesce_ranges(
+            missing_ranges(&entry.ranges, requested),
+            candle * RANGE_GAP_MERGE_CANDLES,
+        );
+
+        if gaps.is_empty() {
+            debug!(ticker = %ticker, source = "redis", "날짜 범위 캐시 히트");
+            return Ok(entry.klines_in(requested));
+        }
+
+        debug!(
+            ticker = %ticker,
+            gaps = gaps.len(),
+            cached_ranges = entry.ranges.len(),
+            "날짜 범위 부분 캐시 미스, 누락 구간만 PostgreSQL 조회"
+        );
+
+        // 아직 진행 중인 마지막 캔들 구간은 Collector가 채울 수 있으므로 캐시 구간으로 기록하지 않음
+        let settled = Utc::now() - candle;
+        for gap in gaps {
+            let fetched = self
+                .cache
+                .get_cached_klines_range(
+                    ticker,
+                    timeframe,
+                    gap.start,
+                    gap.end,
+                    MissingCandlePolicy::None,
+                )
+                .await?;
+            entry.merge_klines(fetched);
+
+            if gap.start < settled {
+                entry.ranges.push(TimeRange {
+                    start: gap.start,
+                    end: gap.end.min(settled),
+                });
+            }
+        }
+        entry.ranges = merge_ranges(std::mem::take(&mut entry.ranges));
+
+        let result = entry.klines_in(requested);
+
+        // 채운 구간을 Redis에 기록 (백그라운드)
+        let redis = redis.clone();
+        let ticker = ticker.to_string();
+        tokio::spawn(async move {
+            if let Err(e) = redis
+                .set_with_ttl(&key, &entry, kline_ttl_secs(timeframe))
+                .await
+            {
+                warn!(ticker = %ticker, error = %e, "Redis 범위 캐시 저장 실패");
+            }
+        });
+
+        Ok(result)
+    }
+
     /// 캐시 통계 조회.
     pub async fn get_cache_stats(&self) -> Result<Vec<CacheStats>> {
         use crate::storage::ohlcv::OhlcvMetadataRecord;
@@ -623,6 +737,129 @@ pub struct CacheStats {
     pub last_updated: Option<DateTime<Utc>>,
 }
 
+// =============================================================================
+// 날짜 범위 캐시
+// =============================================================================
+
+/// 이 캔들 수 이하로 떨어진 누락 구간은 하나의 조회로 합침.
+const RANGE_GAP_MERGE_CANDLES: i32 = 10;
+
+/// 반개구간 `[start, end)` 시간 범위.
+///
+/// `open_time`이 `start` 이상, `end` 미만인 캔들을 포함합니다.
+/// 인접한 두 구간 `[a, b)`, `[b, c)`는 경계 캔들을 중복 없이 나눠 가집니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeRange {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl TimeRange {
+    /// 날짜 범위(양끝 포함)를 `[start 00:00, end + 1일 00:00)` 구간으로 변환.
+    pub fn from_dates(start_date: NaiveDate, end_date: NaiveDate) -> Self {
+        let start = Utc.from_utc_datetime(&start_date.and_hms_opt(0, 0, 0).unwrap());
+        let end =
+            Utc.from_utc_datetime(&end_date.and_hms_opt(0, 0, 0).unwrap()) + Duration::days(1);
+        Self { start, end }
+    }
+
+    /// 시각이 구간에 포함되는지 확인.
+    pub fn contains(&self, time: DateTime<Utc>) -> bool {
+        self.start <= time && time < self.end
+    }
+
+    fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+}
+
+/// Redis에 저장되는 날짜 범위 캐시 (캐시된 구간 + 해당 캔들).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct RangeCacheEntry {
+    /// 캐시된 구간 (정렬, 겹침 없음)
+    ranges: Vec<TimeRange>,
+    /// 캐시된 캔들 (open_time 순)
+    klines: Vec<Kline>,
+}
+
+impl RangeCacheEntry {
+    /// 새 캔들을 병합 (같은 open_time은 새 값으로 대체).
+    fn merge_klines(&mut self, fetched: Vec<Kline>) {
+        let mut by_time: std::collections::BTreeMap<DateTime<Utc>, Kline> =
+            self.klines.drain(..).map(|k| (k.open_time, k)).collect();
+        by_time.extend(fetched.into_iter().map(|k| (k.open_time, k)));
+        self.klines = by_time.into_values().collect();
+    }
+
+    /// 구간에 속하는 캔들.
+    fn klines_in(&self, range: TimeRange) -> Vec<Kline> {
+        self.klines
+            .iter()
+            .filter(|k| range.contains(k.open_time))
+            .cloned()
+            .collect()
+    }
+}
+
+/// 구간 목록을 정렬하고 겹치거나 맞닿은 구간을 합침.
+fn merge_ranges(mut ranges: Vec<TimeRange>) -> Vec<TimeRange> {
+    ranges.retain(|r| !r.is_empty());
+    ranges.sort_by_key(|r| r.start);
+
+    let mut merged: Vec<TimeRange> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// 요청 구간 중 캐시된 구간에 포함되지 않는 부분.
+fn missing_ranges(cached: &[TimeRange], requested: TimeRange) -> Vec<TimeRange> {
+    let mut missing = Vec::new();
+    let mut cursor = requested.start;
+
+    for range in merge_ranges(cached.to_vec()) {
+        if range.end <= cursor {
+            continue;
+        }
+        if range.start >= requested.end {
+            break;
+        }
+        if range.start > cursor {
+            missing.push(TimeRange {
+                start: cursor,
+                end: range.start,
+            });
+        }
+        cursor = range.end;
+    }
+
+    if cursor < requested.end {
+        missing.push(TimeRange {
+            start: cursor,
+            end: requested.end,
+        });
+    }
+    missing
+}
+
+/// 사이 간격이 `max_gap` 이하인 누락 구간을 하나로 합침 (조회 횟수 감소).
+///
+/// 합쳐진 구간에는 이미 캐시된 캔들이 일부 포함되며, 병합 시 같은 값으로 대체됩니다.
+fn coalesce_ranges(ranges: Vec<TimeRange>, max_gap: Duration) -> Vec<TimeRange> {
+    let mut coalesced: Vec<TimeRange> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match coalesced.last_mut() {
+            Some(last) if range.start - last.end <= max_gap => last.end = range.end,
+            _ => coalesced.push(range),
+        }
+    }
+    coalesced
+}
+
 // =============================================================================
 // 헬퍼 함수
 // =============================================================================
@@ -686,7 +923,8 @@ impl YahooProviderWrapper {
     /// ticker를 Yahoo Finance API 호출용 심볼로 변환.
     ///
     /// `SymbolResolver`를 통해 DB에서 정확한 yahoo_symbol을 조회합니다.
-    /// DB에 없으면 fallback으로 6자리 숫자는 `.KS` 추가.
+    /// DB에 없으면 fallback으로 [`SymbolNormalizer`]의 Yahoo 변환 사용
+    /// (국내 주식 `.KS`/`.KQ`, 미국 클래스주 `BRK-B`).
     async fn resolve_yahoo_symbol(&self, ticker: &str) -> String {
         // DB에서 yahoo_symbol 조회 시도
         if let Ok(Some(info)) = self.symbol_resolver.get_symbol_info(ticker).await {
@@ -694,12 +932,9 @@ impl YahooProviderWrapper {
                 return yahoo_symbol;
             }
         }
-        // Fallback: 6자리 숫자 한국 주식인 경우 .KS 추가
-        if ticker.len() == 6 && ticker.chars().all(|c| c.is_ascii_digit()) {
-            format!("{}.KS", ticker)
-        } else {
-            ticker.to_string()
-        }
+        SymbolNormalizer::new()
+            .to_venue(ticker, SymbolVenue::Yahoo)
+            .unwrap_or_else(|_| ticker.to_string())
     }
 
     pub async fn get_klines_internal(
@@ -777,6 +1012,7 @@ impl YahooProviderWrapper {
                     close_time,
                     quote_volume: None,
                     num_trades: None,
+                    synthetic: false,
                 }
             })
             .collect();
@@ -875,6 +1111,7 @@ impl YahooProviderWrapper {
                     close_time,
                     quote_volume: None,
                     num_trades: None,
+                    synthetic: false,
                 }
             })
             .collect();
@@ -985,6 +1222,101 @@ fn calculate_range_string(timeframe: Timeframe, limit: usize) -> &'static str {
 
 #[cfg(test)]
 mod tests {
-    // 현재 테스트할 standalone 함수 없음
-    // 리팩토링 이후 필요 시 추가
+    use super::*;
+
+    fn day(d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, d, 0, 0, 0).unwrap()
+    }
+
+    fn range(start: u32, end: u32) -> TimeRange {
+        TimeRange {
+            start: day(start),
+            end: day(end),
+        }
+    }
+
+    fn kline(d: u32, close: i64) -> Kline {
+        Kline {
+            ticker: "AAPL".to_string(),
+            timeframe: Timeframe::D1,
+            open_time: day(d),
+            open: Decimal::from(close),
+            high: Decimal::from(close),
+            low: Decimal::from(close),
+            close: Decimal::from(close),
+            volume: Decimal::ZERO,
+            close_time: day(d) + Duration::days(1),
+            quote_volume: None,
+            num_trades: None,
+            synthetic: false,
+        }
+    }
+
+    #[test]
+    fn test_time_range_from_dates_is_half_open() {
+        let r = TimeRange::from_dates(
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 4).unwrap(),
+        );
+        assert_eq!(r, range(2, 5));
+        assert!(r.contains(day(2)));
+        assert!(r.contains(day(4)));
+        assert!(!r.contains(day(5)));
+    }
+
+    #[test]
+    fn test_missing_ranges() {
+        // 캐시 없음 → 전체 누락
+        assert_eq!(missing_ranges(&[], range(1, 10)), vec![range(1, 10)]);
+
+        // 앞/중간/뒤 누락, 요청 밖 캐시 구간은 무시
+        let cached = [range(3, 5), range(7, 8), range(12, 15)];
+        assert_eq!(
+            missing_ranges(&cached, range(1, 10)),
+            vec![range(1, 3), range(5, 7), range(8, 10)]
+        );
+
+        // 맞닿은 구간은 누락 없음
+        assert!(missing_ranges(&[range(1, 4), range(4, 10)], range(2, 9)).is_empty());
+    }
+
+    #[test]
+    fn test_coalesce_ranges_merges_small_gaps() {
+        let gaps = vec![range(1, 3), range(4, 5), range(20, 22)];
+        assert_eq!(
+            coalesce_ranges(gaps.clone(), Duration::days(2)),
+            vec![range(1, 5), range(20, 22)]
+        );
+        assert_eq!(coalesce_ranges(gaps.clone(), Duration::zero()), gaps);
+    }
+
+    #[test]
+    fn test_partial_fill_has_no_duplicates_at_boundaries() {
+        let mut entry = RangeCacheEntry {
+            ranges: vec![range(1, 4)],
+            klines: vec![kline(1, 1), kline(2, 2), kline(3, 3)],
+        };
+
+        // [1, 7) 요청 → 누락 구간 [4, 7)만 조회
+        let requested = range(1, 7);
+        let gaps = missing_ranges(&entry.ranges, requested);
+        assert_eq!(gaps, vec![range(4, 7)]);
+
+        // 소스는 반개구간으로 조회하므로 경계 캔들(day 4)은 한 번만 포함
+        entry.merge_klines(vec![kline(4, 4), kline(5, 5), kline(6, 6)]);
+        entry.ranges = merge_ranges([entry.ranges.clone(), gaps].concat());
+
+        let result = entry.klines_in(requested);
+        let days: Vec<_> = result.iter().map(|k| k.open_time).collect();
+        assert_eq!(days, (1..7).map(day).collect::<Vec<_>>());
+
+        // 다음 요청은 완전 히트
+        assert_eq!(entry.ranges, vec![range(1, 7)]);
+        assert!(missing_ranges(&entry.ranges, range(2, 6)).is_empty());
+
+        // 재조회한 캔들은 새 값으로 대체
+        entry.merge_klines(vec![kline(3, 30)]);
+        assert_eq!(entry.klines.len(), 6);
+        assert_eq!(entry.klines[2].close, Decimal::from(30));
+    }
 }
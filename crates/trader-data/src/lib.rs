@@ -4,7 +4,9 @@
 //! - 실시간 및 과거 데이터를 위한 데이터 관리자
 //! - TimescaleDB 저장소
 //! - Redis 캐싱
+//! - 멱등 작업 저장소 (Redis / PostgreSQL)
 //! - OHLCV 캔들 데이터 캐싱 (증분 업데이트 지원)
+//! - 일봉 → 주봉/월봉 리샘플링
 //! - 데이터 가져오기 유틸리티
 
 pub mod cache;
@@ -12,6 +14,7 @@ pub mod error;
 pub mod manager;
 pub mod market_breadth;
 pub mod provider;
+pub mod resample;
 pub mod storage;
 
 // Fundamental 데이터 수집 재내보내기
@@ -27,12 +30,19 @@ pub use provider::{
     BinanceSymbolProvider, CompositeSymbolProvider, KrxSymbolProvider, SymbolInfoProvider,
     SymbolMetadata, SymbolResolver, YahooSymbolProvider,
 };
+// OHLCV 리샘플링 재내보내기
+pub use resample::{resample_klines, resample_klines_with, ResampleOptions, ResampledKline};
 // KRX 데이터 소스 재내보내기
 pub use storage::krx::KrxDataSource;
+// 멱등 작업 저장소 재내보내기
+pub use storage::idempotency::{PgIdempotencyStore, RedisIdempotencyStore};
 // 저장소 타입 재내보내기
 pub use storage::redis::{CacheStats, MetricsCache, RedisCache, RedisConfig};
 pub use storage::{
-    ohlcv::{OhlcvCache, OhlcvMetadataRecord, OhlcvRecord},
+    ohlcv::{
+        apply_missing_candle_policy, MissingCandlePolicy, OhlcvCache, OhlcvMetadataRecord,
+        OhlcvRecord, DEFAULT_MAX_FILL_CANDLES,
+    },
     timescale::{
         Database, DatabaseConfig, OrderRecord, OrderRepository, PositionRecord, PositionRepository,
         SymbolRecord, SymbolRepository, TradeRecord, TradeRepository, TradeTickRecord,
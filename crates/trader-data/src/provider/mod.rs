@@ -16,6 +16,7 @@
 //! - `YahooFundamentalFetcher`: Yahoo Finance 펀더멘털 크롤러
 //! - 글로벌 주식 펀더멘털 데이터 수집 (미국, 일본, 홍콩 등)
 //! - PER, PBR, PSR, ROE, ROA, 배당수익률, 재무비율 등
+//! - 주식 분할·배당 이벤트 (기업 행동)
 //!
 //! ## 심볼 정보 Provider
 //! - `KrxSymbolProvider`: 한국거래소(KRX) 종목 정보
@@ -34,4 +35,6 @@ pub use symbol_info::{
     BinanceSymbolProvider, CompositeSymbolProvider, KrxSymbolProvider, SymbolInfoProvider,
     SymbolMetadata, SymbolResolver, YahooSymbolProvider,
 };
-pub use yahoo_fundamental::{YahooFundamentalData, YahooFundamentalError, YahooFundamentalFetcher};
+pub use yahoo_fundamental::{
+    YahooCorporateActions, YahooFundamentalData, YahooFundamentalError, YahooFundamentalFetcher,
+};
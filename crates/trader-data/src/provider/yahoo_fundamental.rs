@@ -11,6 +11,7 @@
 //! - **성장성**: 매출성장률, 이익성장률
 //! - **배당**: 배당수익률
 //! - **기타**: 섹터, 산업, 베타, 부채비율
+//! - **기업 행동**: 주식 분할 비율, 배당락일·주당 배당금
 //!
 //! ## 사용 예시
 //!
@@ -22,6 +23,7 @@
 
 use std::time::Duration;
 
+use chrono::{DateTime, NaiveDate};
 use rust_decimal::Decimal;
 use thiserror::Error;
 use tokio::sync::Mutex;
@@ -126,6 +128,15 @@ pub struct YahooFundamentalData {
     pub float_shares: Option<i64>,
 }
 
+/// Yahoo Finance 기업 행동 (분할·배당)
+#[derive(Debug, Clone, Default)]
+pub struct YahooCorporateActions {
+    /// 주식 분할 (분할 기준일, 분할 비율 = 분할 후 주식 수 / 분할 전 주식 수)
+    pub splits: Vec<(NaiveDate, Decimal)>,
+    /// 배당 (배당락일, 주당 배당금 - 분할 반영 기준)
+    pub dividends: Vec<(NaiveDate, Decimal)>,
+}
+
 /// Yahoo Finance 펀더멘털 크롤러
 ///
 /// yahoo_finance_api crate를 사용하여 Crumb 인증을 자동으로 처리합니다.
@@ -289,6 +300,60 @@ impl YahooFundamentalFetcher {
         Ok(data)
     }
 
+    /// 기업 행동(분할·배당) 수집
+    ///
+    /// chart API의 `events=div|split` 응답을 사용합니다.
+    ///
+    /// # Arguments
+    /// * `ticker` - Yahoo Finance 형식 종목 코드 (예: "AAPL", "005930.KS")
+    /// * `start` / `end` - 조회 기간
+    pub async fn fetch_corporate_actions(
+        &self,
+        ticker: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<YahooCorporateActions, YahooFundamentalError> {
+        let to_offset = |date: NaiveDate| {
+            let timestamp = date
+                .and_hms_opt(0, 0, 0)
+                .unwrap_or_default()
+                .and_utc()
+                .timestamp();
+            time::OffsetDateTime::from_unix_timestamp(timestamp)
+                .map_err(|e| YahooFundamentalError::ApiError(format!("날짜 변환 실패: {}", e)))
+        };
+        let (period_start, period_end) = (to_offset(start)?, to_offset(end)?);
+
+        debug!(ticker = ticker, start = %start, end = %end, "Yahoo Finance 기업 행동 요청");
+
+        let response = {
+            let provider = self.provider.lock().await;
+            provider
+                .get_quote_history_interval(ticker, period_start, period_end, "1d")
+                .await
+        }?;
+
+        let to_date =
+            |timestamp: i64| DateTime::from_timestamp(timestamp, 0).map(|dt| dt.date_naive());
+
+        let splits = response
+            .splits()?
+            .into_iter()
+            .filter(|s| s.denominator > 0.0)
+            .filter_map(|s| {
+                let ratio = Decimal::from_f64_retain(s.numerator / s.denominator)?;
+                Some((to_date(s.date)?, ratio))
+            })
+            .collect();
+        let dividends = response
+            .dividends()?
+            .into_iter()
+            .filter_map(|d| Some((to_date(d.date)?, Decimal::from_f64_retain(d.amount)?)))
+            .collect();
+
+        Ok(YahooCorporateActions { splits, dividends })
+    }
+
     /// 통화 추측 (티커 접미사 기반)
     fn guess_currency(&self, ticker: &str) -> String {
         if ticker.ends_with(".KS") || ticker.ends_with(".KQ") {
@@ -0,0 +1,451 @@
+//! OHLCV 타임프레임 리샘플링.
+//!
+//! 일봉을 주봉/월봉으로 집계하여 상위 타임프레임을 별도로 다운로드하지 않도록 합니다.
+//!
+//! # 집계 규칙
+//!
+//! - 시가: 구간 첫 거래일 시가
+//! - 고가/저가: 구간 최고가/최저가
+//! - 종가: 구간 마지막 거래일 종가
+//! - 거래량: 합계 (거래대금·체결 건수는 모든 일봉에 값이 있을 때만 합계)
+//! - 구간의 모든 일봉이 합성 캔들이면 결과도 합성 캔들
+//!
+//! # 구간 경계
+//!
+//! 구간은 시장 현지 시간 기준 거래일로 나눕니다 (주봉: 월~일 ISO 주, 월봉: 달력 월).
+//! 데이터 소스마다 일봉 시작 시각이 다르므로 (현지 자정, UTC 자정, 장 시작 시각 등)
+//! 캔들의 중간 시각을 현지 시간으로 변환한 날짜를 거래일로 사용합니다.
+//! 휴장일은 일봉이 없으므로 자연히 제외됩니다.
+//!
+//! 마지막 구간이 아직 끝나지 않았으면 [`ResampledKline::is_complete`]가 `false`입니다.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+use chrono_tz::Tz;
+use rust_decimal::Decimal;
+use trader_core::{Country, Kline, Timeframe};
+
+use crate::error::{DataError, Result};
+
+/// 리샘플링 결과 캔들.
+#[derive(Debug, Clone)]
+pub struct ResampledKline {
+    /// 집계된 캔들
+    pub kline: Kline,
+    /// 구간에 포함된 거래일 수
+    pub trading_days: usize,
+    /// 구간이 끝났는지 여부 (마지막 구간이 진행 중이면 `false`)
+    pub is_complete: bool,
+}
+
+/// 리샘플링 옵션.
+#[derive(Debug, Clone)]
+pub struct ResampleOptions {
+    /// 거래일 판정 기준 시장
+    pub country: Country,
+    /// 완료 여부 판정 기준 시각
+    pub as_of: DateTime<Utc>,
+}
+
+impl ResampleOptions {
+    /// 티커로 시장을 추정한 옵션 생성 (기준 시각: 현재).
+    pub fn for_ticker(ticker: &str) -> Self {
+        Self {
+            country: market_country(ticker),
+            as_of: Utc::now(),
+        }
+    }
+
+    /// 시장 지정 (빌더 패턴).
+    pub fn with_country(mut self, country: Country) -> Self {
+        self.country = country;
+        self
+    }
+
+    /// 완료 여부 판정 기준 시각 지정 (빌더 패턴).
+    pub fn with_as_of(mut self, as_of: DateTime<Utc>) -> Self {
+        self.as_of = as_of;
+        self
+    }
+}
+
+/// 일봉을 주봉/월봉으로 리샘플링.
+///
+/// 시장은 첫 캔들의 티커로 추정하고, 현재 시각 기준으로 마지막 구간의 완료 여부를 판정합니다.
+pub fn resample_klines(
+    daily: &[Kline],
+    target_timeframe: Timeframe,
+) -> Result<Vec<ResampledKline>> {
+    let Some(first) = daily.first() else {
+        return Ok(Vec::new());
+    };
+    let options = ResampleOptions::for_ticker(&first.ticker);
+    resample_klines_with(daily, target_timeframe, &options)
+}
+
+/// 옵션을 지정하여 일봉을 주봉/월봉으로 리샘플링.
+pub fn resample_klines_with(
+    daily: &[Kline],
+    target_timeframe: Timeframe,
+    options: &ResampleOptions,
+) -> Result<Vec<ResampledKline>> {
+    if !matches!(target_timeframe, Timeframe::W1 | Timeframe::MN1) {
+        return Err(DataError::InvalidData(format!(
+            "일봉은 주봉/월봉으로만 리샘플링할 수 있습니다: {:?}",
+            target_timeframe
+        )));
+    }
+    if let Some(kline) = daily.iter().find(|k| k.timeframe != Timeframe::D1) {
+        return Err(DataError::InvalidData(format!(
+            "일봉이 아닌 캔들이 포함되어 있습니다: {} {:?}",
+            kline.ticker, kline.timeframe
+        )));
+    }
+
+    let tz = market_timezone(options.country);
+    let mut sorted: Vec<&Kline> = daily.iter().collect();
+    sorted.sort_by_key(|k| k.open_time);
+
+    let mut buckets: Vec<(NaiveDate, Vec<&Kline>)> = Vec::new();
+    for kline in sorted {
+        let period_start = period_start(trading_date(kline, tz), target_timeframe);
+        match buckets.last_mut() {
+            Some((start, klines)) if *start == period_start => klines.push(kline),
+            _ => buckets.push((period_start, vec![kline])),
+        }
+    }
+
+    let bucket_count = buckets.len();
+    Ok(buckets
+        .into_iter()
+        .enumerate()
+        .map(|(index, (start, klines))| {
+            let is_complete = index + 1 < bucket_count
+                || is_period_complete(start, &klines, target_timeframe, options, tz);
+            ResampledKline {
+                kline: aggregate(&klines, target_timeframe),
+                trading_days: klines.len(),
+                is_complete,
+            }
+        })
+        .collect())
+}
+
+/// 티커로 거래 시장 추정.
+pub fn market_country(ticker: &str) -> Country {
+    let upper = ticker.to_uppercase();
+    if upper.ends_with(".KS")
+        || upper.ends_with(".KQ")
+        || (upper.len() == 6 && upper.chars().all(|c| c.is_ascii_digit()))
+    {
+        Country::KR
+    } else if upper.ends_with(".T") {
+        Country::JP
+    } else if upper.ends_with(".HK") {
+        Country::HK
+    } else if upper.ends_with(".SS") || upper.ends_with(".SZ") {
+        Country::CN
+    } else if upper.ends_with(".L") {
+        Country::GB
+    } else if upper.contains('/') || upper.starts_with("KRW-") || upper.ends_with("USDT") {
+        Country::Global
+    } else {
+        Country::US
+    }
+}
+
+/// 시장 현지 시간대.
+pub(crate) fn market_timezone(country: Country) -> Tz {
+    match country {
+        Country::KR => chrono_tz::Asia::Seoul,
+        Country::US => chrono_tz::America::New_York,
+        Country::JP => chrono_tz::Asia::Tokyo,
+        Country::CN => chrono_tz::Asia::Shanghai,
+        Country::HK => chrono_tz::Asia::Hong_Kong,
+        Country::SG => chrono_tz::Asia::Singapore,
+        Country::GB => chrono_tz::Europe::London,
+        Country::AU => chrono_tz::Australia::Sydney,
+        Country::CA => chrono_tz::America::Toronto,
+        Country::Global => chrono_tz::UTC,
+    }
+}
+
+/// 캔들의 현지 거래일 (캔들 중간 시각 기준).
+pub(crate) fn trading_date(kline: &Kline, tz: Tz) -> NaiveDate {
+    let midpoint = if kline.close_time > kline.open_time {
+        kline.open_time + (kline.close_time - kline.open_time) / 2
+    } else {
+        kline.open_time
+    };
+    midpoint.with_timezone(&tz).date_naive()
+}
+
+/// 거래일이 속한 구간의 시작일.
+fn period_start(date: NaiveDate, timeframe: Timeframe) -> NaiveDate {
+    match timeframe {
+        Timeframe::MN1 => date.with_day(1).unwrap_or(date),
+        _ => date - Duration::days(date.weekday().num_days_from_monday() as i64),
+    }
+}
+
+/// 구간의 마지막 날짜.
+fn period_end(start: NaiveDate, timeframe: Timeframe) -> NaiveDate {
+    match timeframe {
+        Timeframe::MN1 => {
+            let (year, month) = if start.month() == 12 {
+                (start.year() + 1, 1)
+            } else {
+                (start.year(), start.month() + 1)
+            };
+            NaiveDate::from_ymd_opt(year, month, 1)
+                .map(|next| next - Duration::days(1))
+                .unwrap_or(start)
+        }
+        _ => start + Duration::days(6),
+    }
+}
+
+/// 구간의 마지막 거래 가능일 (주말 휴장 시장은 마지막 평일).
+fn last_session_date(end: NaiveDate, country: Country) -> NaiveDate {
+    if country == Country::Global {
+        return end;
+    }
+    match end.weekday() {
+        Weekday::Sat => end - Duration::days(1),
+        Weekday::Sun => end - Duration::days(2),
+        _ => end,
+    }
+}
+
+/// 마지막 구간의 완료 여부.
+///
+/// 마지막 일봉이 마감되었고, 기준 시각이 구간을 지났거나 구간의 마지막 거래 가능일까지
+/// 일봉이 있으면 완료로 봅니다.
+fn is_period_complete(
+    start: NaiveDate,
+    klines: &[&Kline],
+    timeframe: Timeframe,
+    options: &ResampleOptions,
+    tz: Tz,
+) -> bool {
+    let Some(last) = klines.last() else {
+        return false;
+    };
+    if last.close_time > options.as_of {
+        return false;
+    }
+
+    let end = period_end(start, timeframe);
+    let today = options.as_of.with_timezone(&tz).date_naive();
+    today > end || trading_date(last, tz) >= last_session_date(end, options.country)
+}
+
+/// 구간 일봉 집계.
+fn aggregate(klines: &[&Kline], timeframe: Timeframe) -> Kline {
+    let first = klines[0];
+    let last = klines[klines.len() - 1];
+
+    let mut kline = Kline::new(
+        first.ticker.clone(),
+        timeframe,
+        first.open_time,
+        first.open,
+        klines.iter().map(|k| k.high).max().unwrap_or(first.high),
+        klines.iter().map(|k| k.low).min().unwrap_or(first.low),
+        last.close,
+        klines.iter().map(|k| k.volume).sum(),
+        last.close_time,
+    );
+    kline.quote_volume = klines
+        .iter()
+        .map(|k| k.quote_volume)
+        .sum::<Option<Decimal>>();
+    kline.num_trades = klines.iter().map(|k| k.num_trades).sum::<Option<u32>>();
+    kline.synthetic = klines.iter().all(|k| k.synthetic);
+    kline
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn d(value: i64) -> Decimal {
+        Decimal::from(value)
+    }
+
+    /// KST 자정 시작 일봉 (Yahoo 한국 주식 형식).
+    fn kr_daily(date: (i32, u32, u32), ohlcv: [Decimal; 5]) -> Kline {
+        let open_time = chrono_tz::Asia::Seoul
+            .with_ymd_and_hms(date.0, date.1, date.2, 0, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc);
+        Kline::new(
+            "005930.KS".to_string(),
+            Timeframe::D1,
+            open_time,
+            ohlcv[0],
+            ohlcv[1],
+            ohlcv[2],
+            ohlcv[3],
+            ohlcv[4],
+            open_time + Duration::days(1) - Duration::seconds(1),
+        )
+    }
+
+    /// 2024-09-09 ~ 2024-09-20 일봉 (9/16~18 추석 휴장).
+    fn kr_fixture() -> Vec<Kline> {
+        vec![
+            kr_daily(
+                (2024, 9, 9),
+                [d(64100), d(64200), d(63100), d(63500), d(19_834_422)],
+            ),
+            kr_daily(
+                (2024, 9, 10),
+                [d(63500), d(64600), d(63200), d(64000), d(17_512_047)],
+            ),
+            kr_daily(
+                (2024, 9, 11),
+                [d(64300), d(64500), d(63800), d(64100), d(20_211_153)],
+            ),
+            kr_daily(
+                (2024, 9, 12),
+                [d(64600), d(66600), d(64500), d(66000), d(28_017_405)],
+            ),
+            kr_daily(
+                (2024, 9, 13),
+                [d(66200), d(66500), d(64800), d(64900), d(22_455_818)],
+            ),
+            kr_daily(
+                (2024, 9, 19),
+                [d(63100), d(63300), d(62000), d(62300), d(40_303_617)],
+            ),
+            kr_daily(
+                (2024, 9, 20),
+                [d(62800), d(63800), d(62500), d(63000), d(26_416_718)],
+            ),
+        ]
+    }
+
+    fn options_at(country: Country, as_of: DateTime<Utc>) -> ResampleOptions {
+        ResampleOptions::for_ticker("005930.KS")
+            .with_country(country)
+            .with_as_of(as_of)
+    }
+
+    fn within_tolerance(actual: Decimal, expected: Decimal) -> bool {
+        // 소스 반올림 차이 허용 (0.01%)
+        (actual - expected).abs() <= expected.abs() * Decimal::new(1, 4)
+    }
+
+    #[test]
+    fn test_weekly_matches_source_weekly() {
+        // 소스(Yahoo) 공식 주봉: 주 시작 월요일 KST 자정, 휴장 주는 목요일 시가부터
+        let official = [
+            (d(64100), d(66600), d(63100), d(64900), d(108_030_845)),
+            (d(63100), d(63800), d(62000), d(63000), d(66_720_335)),
+        ];
+
+        let as_of = Utc.with_ymd_and_hms(2024, 9, 23, 0, 0, 0).unwrap();
+        let weekly = resample_klines_with(
+            &kr_fixture(),
+            Timeframe::W1,
+            &options_at(Country::KR, as_of),
+        )
+        .unwrap();
+
+        assert_eq!(weekly.len(), official.len());
+        for (bar, (open, high, low, close, volume)) in weekly.iter().zip(official) {
+            assert_eq!(bar.kline.timeframe, Timeframe::W1);
+            assert!(within_tolerance(bar.kline.open, open));
+            assert!(within_tolerance(bar.kline.high, high));
+            assert!(within_tolerance(bar.kline.low, low));
+            assert!(within_tolerance(bar.kline.close, close));
+            assert_eq!(bar.kline.volume, volume);
+            assert!(bar.is_complete);
+        }
+        assert_eq!(weekly[0].trading_days, 5);
+        assert_eq!(weekly[1].trading_days, 2);
+        // 휴장으로 주 시작이 목요일
+        assert_eq!(weekly[1].kline.open_time, kr_fixture()[5].open_time);
+    }
+
+    #[test]
+    fn test_incomplete_last_period_is_flagged() {
+        let mut daily = kr_fixture();
+        daily.truncate(6); // 9/19(목)까지
+
+        // 9/20(금) 장중 기준
+        let as_of = Utc.with_ymd_and_hms(2024, 9, 20, 2, 0, 0).unwrap();
+        let weekly =
+            resample_klines_with(&daily, Timeframe::W1, &options_at(Country::KR, as_of)).unwrap();
+        assert!(weekly[0].is_complete);
+        assert!(!weekly[1].is_complete);
+
+        // 주가 끝난 뒤에는 금요일 일봉이 없어도 완료
+        let later = Utc.with_ymd_and_hms(2024, 9, 23, 0, 0, 0).unwrap();
+        let weekly =
+            resample_klines_with(&daily, Timeframe::W1, &options_at(Country::KR, later)).unwrap();
+        assert!(weekly[1].is_complete);
+    }
+
+    #[test]
+    fn test_monthly_and_us_trading_date() {
+        // 미국 일봉: 장 시작(09:30 ET) 기준 시각 → UTC 날짜가 아닌 현지 거래일로 집계
+        let ny = chrono_tz::America::New_York;
+        let daily: Vec<Kline> = [(8, 30), (9, 3), (9, 30), (10, 1)]
+            .iter()
+            .enumerate()
+            .map(|(i, (month, day))| {
+                let open_time = ny
+                    .with_ymd_and_hms(2024, *month, *day, 9, 30, 0)
+                    .unwrap()
+                    .with_timezone(&Utc);
+                let price = d(100 + i as i64);
+                Kline::new(
+                    "AAPL".to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    price,
+                    price + d(2),
+                    price - d(1),
+                    price + d(1),
+                    d(1000),
+                    open_time + Duration::minutes(390),
+                )
+            })
+            .collect();
+
+        let as_of = Utc.with_ymd_and_hms(2024, 10, 1, 16, 0, 0).unwrap();
+        let monthly =
+            resample_klines_with(&daily, Timeframe::MN1, &options_at(Country::US, as_of)).unwrap();
+
+        assert_eq!(monthly.len(), 3);
+        assert_eq!(monthly[1].trading_days, 2);
+        assert_eq!(monthly[1].kline.open, d(101));
+        assert_eq!(monthly[1].kline.close, d(103));
+        assert_eq!(monthly[1].kline.high, d(104));
+        assert_eq!(monthly[1].kline.volume, d(2000));
+        // 10월은 진행 중
+        assert!(monthly[1].is_complete);
+        assert!(!monthly[2].is_complete);
+    }
+
+    #[test]
+    fn test_rejects_non_daily_input() {
+        assert!(resample_klines(&kr_fixture(), Timeframe::H1).is_err());
+
+        let mut daily = kr_fixture();
+        daily[0].timeframe = Timeframe::H1;
+        assert!(resample_klines(&daily, Timeframe::W1).is_err());
+        assert!(resample_klines(&[], Timeframe::W1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_market_country() {
+        assert_eq!(market_country("005930"), Country::KR);
+        assert_eq!(market_country("035720.KQ"), Country::KR);
+        assert_eq!(market_country("BTC/USDT"), Country::Global);
+        assert_eq!(market_country("AAPL"), Country::US);
+    }
+}
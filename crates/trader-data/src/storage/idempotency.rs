@@ -0,0 +1,268 @@
+//! 멱등 작업 저장소 구현.
+//!
+//! trader-core의 [`IdempotencyStore`]를 Redis와 PostgreSQL로 구현하여
+//! 여러 프로세스(API 서버, 수집기, 실행기)가 같은 중복 방지 기록을 공유하게 합니다.
+//!
+//! - [`RedisIdempotencyStore`]: `SET NX PX`로 락 획득, TTL은 Redis 만료로 처리
+//! - [`PgIdempotencyStore`]: `idempotency_keys` 테이블, 만료된 행은 획득 시 덮어씀
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::postgres::PgPool;
+use trader_core::{IdempotencyError, IdempotencyKey, IdempotencyStatus, IdempotencyStore};
+
+use super::redis::RedisCache;
+
+// =========================================================================
+// Redis
+// =========================================================================
+
+/// Redis에 저장되는 멱등 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IdempotencyRecord {
+    completed: bool,
+    #[serde(default)]
+    result: Option<Value>,
+}
+
+/// Redis 기반 멱등 저장소.
+#[derive(Clone)]
+pub struct RedisIdempotencyStore {
+    cache: RedisCache,
+}
+
+impl RedisIdempotencyStore {
+    /// 새 저장소 생성.
+    pub fn new(cache: RedisCache) -> Self {
+        Self { cache }
+    }
+
+    /// Redis 키 (`idempotency:{scope}:{id}`).
+    fn redis_key(key: &IdempotencyKey) -> String {
+        format!("idempotency:{}", key.storage_key())
+    }
+
+    fn encode(record: &IdempotencyRecord) -> Result<String, IdempotencyError> {
+        serde_json::to_string(record).map_err(|e| IdempotencyError::Serialization(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for RedisIdempotencyStore {
+    async fn try_acquire(
+        &self,
+        key: &IdempotencyKey,
+        lock_ttl: Duration,
+    ) -> Result<IdempotencyStatus, IdempotencyError> {
+        let redis_key = Self::redis_key(key);
+        let record = Self::encode(&IdempotencyRecord {
+            completed: false,
+            result: None,
+        })?;
+
+        let acquired = self
+            .cache
+            .set_nx_with_ttl_ms(&redis_key, &record, ttl_millis(lock_ttl))
+            .await
+            .map_err(|e| IdempotencyError::Store(e.to_string()))?;
+        if acquired {
+            return Ok(IdempotencyStatus::Acquired);
+        }
+
+        let existing: Option<IdempotencyRecord> = self
+            .cache
+            .get(&redis_key)
+            .await
+            .map_err(|e| IdempotencyError::Store(e.to_string()))?;
+
+        Ok(match existing {
+            Some(record) if record.completed => IdempotencyStatus::Completed(record.result),
+            Some(_) => IdempotencyStatus::InProgress,
+            // SET NX 실패 직후 만료된 경우 - 다음 호출에서 재획득
+            None => IdempotencyStatus::InProgress,
+        })
+    }
+
+    async fn complete(
+        &self,
+        key: &IdempotencyKey,
+        result: Option<Value>,
+        ttl: Duration,
+    ) -> Result<(), IdempotencyError> {
+        let record = IdempotencyRecord {
+            completed: true,
+            result,
+        };
+        self.cache
+            .set_with_ttl(&Self::redis_key(key), &record, ttl.as_secs().max(1))
+            .await
+            .map_err(|e| IdempotencyError::Store(e.to_string()))
+    }
+
+    async fn release(&self, key: &IdempotencyKey) -> Result<(), IdempotencyError> {
+        let redis_key = Self::redis_key(key);
+        let existing: Option<IdempotencyRecord> = self
+            .cache
+            .get(&redis_key)
+            .await
+            .map_err(|e| IdempotencyError::Store(e.to_string()))?;
+
+        if existing.is_some_and(|record| !record.completed) {
+            self.cache
+                .delete(&redis_key)
+                .await
+                .map_err(|e| IdempotencyError::Store(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+// =========================================================================
+// PostgreSQL
+// =========================================================================
+
+/// PostgreSQL 기반 멱등 저장소 (`idempotency_keys` 테이블).
+#[derive(Clone)]
+pub struct PgIdempotencyStore {
+    pool: PgPool,
+}
+
+impl PgIdempotencyStore {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 만료된 키를 삭제하고 삭제된 개수를 반환합니다.
+    pub async fn purge_expired(&self) -> Result<u64, IdempotencyError> {
+        let result = sqlx::query("DELETE FROM idempotency_keys WHERE expires_at <= NOW()")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| IdempotencyError::Store(e.to_string()))?;
+        Ok(result.rows_affected())
+    }
+
+    /// 스코프 내 만료되지 않은 완료 키와 남은 유지 시간을 조회합니다.
+    ///
+    /// 재시작 후 메모리 캐시를 복원할 때 사용합니다.
+    pub async fn load_completed(
+        &self,
+        scope: &str,
+    ) -> Result<Vec<(IdempotencyKey, Duration)>, IdempotencyError> {
+        let prefix = format!("{}:", scope);
+        let rows: Vec<(String, f64)> = sqlx::query_as(
+            r#"
+            SELECT key, EXTRACT(EPOCH FROM (expires_at - NOW()))::float8
+            FROM idempotency_keys
+            WHERE starts_with(key, $1) AND status = 'completed' AND expires_at > NOW()
+            "#,
+        )
+        .bind(&prefix)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| IdempotencyError::Store(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(key, remaining_secs)| {
+                let id = key.strip_prefix(&prefix)?;
+                Some((
+                    IdempotencyKey::new(scope, id),
+                    Duration::from_secs_f64(remaining_secs.max(0.0)),
+                ))
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for PgIdempotencyStore {
+    async fn try_acquire(
+        &self,
+        key: &IdempotencyKey,
+        lock_ttl: Duration,
+    ) -> Result<IdempotencyStatus, IdempotencyError> {
+        let storage_key = key.storage_key();
+
+        // 키가 없거나 만료된 경우에만 진행 중 상태로 기록 (원자적)
+        let acquired: Option<(String,)> = sqlx::query_as(
+            r#"
+            INSERT INTO idempotency_keys (key, status, result, expires_at)
+            VALUES ($1, 'in_progress', NULL, NOW() + $2 * INTERVAL '1 millisecond')
+            ON CONFLICT (key) DO UPDATE
+                SET status = 'in_progress',
+                    result = NULL,
+                    expires_at = EXCLUDED.expires_at,
+                    created_at = NOW(),
+                    updated_at = NOW()
+                WHERE idempotency_keys.expires_at <= NOW()
+            RETURNING key
+            "#,
+        )
+        .bind(&storage_key)
+        .bind(ttl_millis(lock_ttl) as f64)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| IdempotencyError::Store(e.to_string()))?;
+
+        if acquired.is_some() {
+            return Ok(IdempotencyStatus::Acquired);
+        }
+
+        let existing: Option<(String, Option<Value>)> = sqlx::query_as(
+            "SELECT status, result FROM idempotency_keys WHERE key = $1 AND expires_at > NOW()",
+        )
+        .bind(&storage_key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| IdempotencyError::Store(e.to_string()))?;
+
+        Ok(match existing {
+            Some((status, result)) if status == "completed" => IdempotencyStatus::Completed(result),
+            _ => IdempotencyStatus::InProgress,
+        })
+    }
+
+    async fn complete(
+        &self,
+        key: &IdempotencyKey,
+        result: Option<Value>,
+        ttl: Duration,
+    ) -> Result<(), IdempotencyError> {
+        sqlx::query(
+            r#"
+            INSERT INTO idempotency_keys (key, status, result, expires_at)
+            VALUES ($1, 'completed', $2, NOW() + $3 * INTERVAL '1 millisecond')
+            ON CONFLICT (key) DO UPDATE
+                SET status = 'completed',
+                    result = EXCLUDED.result,
+                    expires_at = EXCLUDED.expires_at,
+                    updated_at = NOW()
+            "#,
+        )
+        .bind(key.storage_key())
+        .bind(result)
+        .bind(ttl_millis(ttl) as f64)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| IdempotencyError::Store(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn release(&self, key: &IdempotencyKey) -> Result<(), IdempotencyError> {
+        sqlx::query("DELETE FROM idempotency_keys WHERE key = $1 AND status = 'in_progress'")
+            .bind(key.storage_key())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| IdempotencyError::Store(e.to_string()))?;
+        Ok(())
+    }
+}
+
+/// TTL을 밀리초로 변환 (최소 1ms).
+fn ttl_millis(ttl: Duration) -> u64 {
+    (ttl.as_millis() as u64).max(1)
+}
@@ -211,6 +211,7 @@ impl KrxDataSource {
                 close_time: date + chrono::Duration::days(1),
                 quote_volume: None,
                 num_trades: None,
+                synthetic: false,
             });
         }
 
@@ -1,5 +1,6 @@
 //! 데이터 저장소 구현.
 
+pub mod idempotency;
 pub mod krx;
 pub mod ohlcv;
 pub mod redis;
@@ -10,6 +10,9 @@
 //! 3. 새 데이터를 DB에 저장 (증분 업데이트)
 //! 4. 캐시된 데이터 반환
 //!
+//! 범위 조회는 [`MissingCandlePolicy`]로 결측 캔들 처리 방식을 지정할 수 있습니다.
+//! 전방 채우기로 만든 캔들은 `synthetic` 플래그로 실제 캔들과 구분됩니다.
+//!
 //! # 사용 예제
 //!
 //! ```rust,ignore
@@ -19,13 +22,48 @@
 //! let klines = cache.get_klines("AAPL", Timeframe::D1, 100).await?;
 //! ```
 
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, Months, Utc};
+use chrono_tz::Tz;
 use rust_decimal::Decimal;
 use sqlx::{postgres::PgPool, FromRow};
 use tracing::{debug, info, instrument, warn};
-use trader_core::{Kline, Timeframe};
+use trader_core::{Country, Kline, Timeframe, TradingCalendar};
+
+use crate::{
+    error::{DataError, Result},
+    resample::{market_country, market_timezone, trading_date},
+};
+
+/// 전방 채우기 기본 상한 (연속 결측 캔들 수).
+///
+/// 이보다 긴 결측은 거래정지가 아닌 상장폐지/재상장 수준으로 보고 채우지 않습니다.
+pub const DEFAULT_MAX_FILL_CANDLES: usize = 20;
+
+/// 범위 조회 시 결측 캔들 처리 정책.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingCandlePolicy {
+    /// 저장된 캔들을 그대로 반환
+    #[default]
+    None,
+    /// 직전 종가로 결측 캔들을 채움 (OHLC = 직전 종가, 거래량 0, `synthetic: true`).
+    ///
+    /// 연속 결측이 `max_candles`를 넘으면 채우지 않고 결측 이전 구간을 잘라냅니다.
+    ForwardFill {
+        /// 채울 수 있는 최대 연속 결측 캔들 수
+        max_candles: usize,
+    },
+    /// 결측 이전 구간과 진행 중인 마지막 캔들을 버리고 마지막 연속 구간만 반환
+    DropIncomplete,
+}
 
-use crate::error::{DataError, Result};
+impl MissingCandlePolicy {
+    /// 기본 상한을 사용하는 전방 채우기 정책.
+    pub fn forward_fill() -> Self {
+        Self::ForwardFill {
+            max_candles: DEFAULT_MAX_FILL_CANDLES,
+        }
+    }
+}
 
 /// OHLCV 캔들 데이터베이스 레코드.
 #[derive(Debug, Clone, FromRow)]
@@ -66,6 +104,7 @@ impl OhlcvRecord {
             close_time,
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         }
     }
 
@@ -101,6 +140,7 @@ impl OhlcvRecord {
             close_time,
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         }
     }
 }
@@ -255,6 +295,8 @@ impl OhlcvCache {
     }
 
     /// 특정 시간 범위의 캔들 조회.
+    ///
+    /// `policy`에 따라 결측 캔들을 채우거나 불완전 구간을 제외합니다.
     #[instrument(skip(self))]
     pub async fn get_cached_klines_range(
         &self,
@@ -262,6 +304,7 @@ impl OhlcvCache {
         timeframe: Timeframe,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
+        policy: MissingCandlePolicy,
     ) -> Result<Vec<Kline>> {
         let tf_str = timeframe_to_string(timeframe);
 
@@ -283,7 +326,12 @@ impl OhlcvCache {
 
         let klines: Vec<Kline> = records.into_iter().map(|r| r.to_kline()).collect();
 
-        Ok(klines)
+        Ok(apply_missing_candle_policy(
+            klines,
+            timeframe,
+            policy,
+            Utc::now(),
+        ))
     }
 
     /// 캔들 데이터를 캐시에 저장.
@@ -590,6 +638,139 @@ fn timeframe_to_duration(timeframe: Timeframe) -> Duration {
     }
 }
 
+/// 결측 캔들 처리 정책 적용.
+///
+/// 캔들은 시간순으로 정렬되어 있어야 합니다. 결측 판정은 시장 현지 시간 기준이며,
+/// 거래소 휴장일(주말·공휴일, [`TradingCalendar`] 기준)과 분봉/시간봉의 장 마감 이후(다음 거래일 사이)는
+/// 결측으로 보지 않습니다.
+pub fn apply_missing_candle_policy(
+    klines: Vec<Kline>,
+    timeframe: Timeframe,
+    policy: MissingCandlePolicy,
+    now: DateTime<Utc>,
+) -> Vec<Kline> {
+    if policy == MissingCandlePolicy::None || klines.is_empty() {
+        return klines;
+    }
+
+    let country = market_country(&klines[0].ticker);
+    let tz = market_timezone(country);
+    let mut result: Vec<Kline> = Vec::with_capacity(klines.len());
+
+    for kline in klines {
+        let missing = result
+            .last()
+            .map(|prev| missing_slots(prev, &kline, timeframe, country, tz))
+            .unwrap_or_default();
+
+        if !missing.is_empty() {
+            let fillable = matches!(
+                policy,
+                MissingCandlePolicy::ForwardFill { max_candles } if missing.len() <= max_candles
+            );
+            if fillable {
+                // 결측이 있으면 직전 캔들이 반드시 존재
+                let prev = result[result.len() - 1].clone();
+                result.extend(
+                    missing
+                        .into_iter()
+                        .map(|open_time| synthetic_kline(&prev, open_time)),
+                );
+            } else {
+                warn!(
+                    ticker = %kline.ticker,
+                    missing = missing.len(),
+                    resume_at = %kline.open_time,
+                    policy = ?policy,
+                    "결측 구간 이전 캔들 제외"
+                );
+                result.clear();
+            }
+        }
+        result.push(kline);
+    }
+
+    if policy == MissingCandlePolicy::DropIncomplete
+        && result.last().is_some_and(|k| k.close_time > now)
+    {
+        result.pop();
+    }
+    result
+}
+
+/// 두 캔들 사이에 있어야 할 캔들의 시작 시각 목록.
+fn missing_slots(
+    prev: &Kline,
+    next: &Kline,
+    timeframe: Timeframe,
+    country: Country,
+    tz: Tz,
+) -> Vec<DateTime<Utc>> {
+    let trades_weekends = country == Country::Global;
+    let mut slots = Vec::new();
+
+    if timeframe == Timeframe::D1 {
+        // 일봉은 현지 거래일 기준 (소스의 일봉 시작 시각 형식은 직전 캔들을 따름)
+        let calendar = TradingCalendar::global();
+        let market = country.to_string();
+        let prev_date = trading_date(prev, tz);
+        let next_date = trading_date(next, tz);
+        let mut date = prev_date + Duration::days(1);
+        while date < next_date {
+            if trades_weekends || calendar.is_trading_day(&market, date) {
+                slots.push(prev.open_time + (date - prev_date));
+            }
+            date += Duration::days(1);
+        }
+        return slots;
+    }
+
+    // 분봉/시간봉은 같은 거래일 안의 빈 구간만 결측으로 봄 (24시간 시장 제외)
+    if is_intraday(timeframe)
+        && !trades_weekends
+        && prev.open_time.with_timezone(&tz).date_naive()
+            != next.open_time.with_timezone(&tz).date_naive()
+    {
+        return slots;
+    }
+
+    let step = timeframe_to_duration(timeframe);
+    let mut slot = next_slot(prev.open_time, timeframe);
+    // 소스별 시작 시각 차이(예: 휴장 월요일 주봉)를 감안해 반 구간 여유를 둠
+    while slot + step / 2 <= next.open_time {
+        slots.push(slot);
+        slot = next_slot(slot, timeframe);
+    }
+    slots
+}
+
+/// 다음 캔들 시작 시각.
+fn next_slot(open_time: DateTime<Utc>, timeframe: Timeframe) -> DateTime<Utc> {
+    match timeframe {
+        Timeframe::MN1 => open_time
+            .checked_add_months(Months::new(1))
+            .unwrap_or(open_time + timeframe_to_duration(timeframe)),
+        _ => open_time + timeframe_to_duration(timeframe),
+    }
+}
+
+/// 직전 종가로 채운 합성 캔들.
+fn synthetic_kline(prev: &Kline, open_time: DateTime<Utc>) -> Kline {
+    let mut kline = Kline::new(
+        prev.ticker.clone(),
+        prev.timeframe,
+        open_time,
+        prev.close,
+        prev.close,
+        prev.close,
+        prev.close,
+        Decimal::ZERO,
+        open_time + (prev.close_time - prev.open_time),
+    );
+    kline.synthetic = true;
+    kline
+}
+
 /// 분봉/시간봉인지 확인.
 fn is_intraday(timeframe: Timeframe) -> bool {
     matches!(
@@ -614,6 +795,8 @@ fn is_intraday(timeframe: Timeframe) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use chrono::TimeZone;
+
     use super::*;
     use crate::cache::historical::guess_currency;
 
@@ -634,6 +817,199 @@ mod tests {
         assert!(!is_intraday(Timeframe::W1));
     }
 
+    fn kline(ticker: &str, timeframe: Timeframe, open_time: DateTime<Utc>, close: i64) -> Kline {
+        let close = Decimal::new(close, 0);
+        Kline::new(
+            ticker.to_string(),
+            timeframe,
+            open_time,
+            close,
+            close,
+            close,
+            close,
+            Decimal::new(1000, 0),
+            open_time + timeframe_to_duration(timeframe) - Duration::seconds(1),
+        )
+    }
+
+    fn daily(ticker: &str, day: u32, close: i64) -> Kline {
+        let open_time = Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap();
+        kline(ticker, Timeframe::D1, open_time, close)
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_forward_fill_marks_synthetic() {
+        // 2024-01-03(수) 결측
+        let klines = vec![daily("005930", 2, 70000), daily("005930", 4, 71000)];
+
+        let filled = apply_missing_candle_policy(
+            klines,
+            Timeframe::D1,
+            MissingCandlePolicy::forward_fill(),
+            now(),
+        );
+
+        assert_eq!(filled.len(), 3);
+        let synthetic = &filled[1];
+        assert!(synthetic.synthetic);
+        assert_eq!(
+            synthetic.open_time,
+            Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap()
+        );
+        assert_eq!(synthetic.open, Decimal::new(70000, 0));
+        assert_eq!(synthetic.close, Decimal::new(70000, 0));
+        assert!(synthetic.volume.is_zero());
+        assert!(!filled[0].synthetic && !filled[2].synthetic);
+    }
+
+    #[test]
+    fn test_forward_fill_skips_weekend_and_overnight() {
+        // 금요일 → 월요일은 결측 아님
+        let klines = vec![daily("005930", 5, 70000), daily("005930", 8, 71000)];
+        let filled = apply_missing_candle_policy(
+            klines,
+            Timeframe::D1,
+            MissingCandlePolicy::forward_fill(),
+            now(),
+        );
+        assert_eq!(filled.len(), 2);
+
+        // 미국 종목 UTC 자정 일봉도 현지 거래일로 판정
+        let klines = vec![daily("AAPL", 5, 180), daily("AAPL", 8, 181)];
+        let filled = apply_missing_candle_policy(
+            klines,
+            Timeframe::D1,
+            MissingCandlePolicy::forward_fill(),
+            now(),
+        );
+        assert_eq!(filled.len(), 2);
+
+        // 시간봉: 장중 빈 구간만 채우고 장 마감 ~ 다음 장 시작은 유지 (KST = UTC+9)
+        let at = |day, hour| Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap();
+        let klines = vec![
+            kline("005930", Timeframe::H1, at(2, 0), 70000),
+            kline("005930", Timeframe::H1, at(2, 3), 70100),
+            kline("005930", Timeframe::H1, at(3, 0), 70200),
+        ];
+        let filled = apply_missing_candle_policy(
+            klines,
+            Timeframe::H1,
+            MissingCandlePolicy::forward_fill(),
+            now(),
+        );
+        assert_eq!(filled.len(), 5);
+        assert_eq!(filled.iter().filter(|k| k.synthetic).count(), 2);
+        assert_eq!(filled[2].open_time, at(2, 2));
+
+        // 24시간 시장은 주말도 결측
+        let klines = vec![daily("BTC/USDT", 5, 42000), daily("BTC/USDT", 8, 43000)];
+        let filled = apply_missing_candle_policy(
+            klines,
+            Timeframe::D1,
+            MissingCandlePolicy::forward_fill(),
+            now(),
+        );
+        assert_eq!(filled.len(), 4);
+    }
+
+    #[test]
+    fn test_forward_fill_cuts_long_gap() {
+        // 01-03 ~ 01-12 거래일 8개 결측 → 상한 초과로 이전 구간 제외
+        let klines = vec![
+            daily("005930", 2, 70000),
+            daily("005930", 15, 50000),
+            daily("005930", 16, 51000),
+        ];
+
+        let filled = apply_missing_candle_policy(
+            klines,
+            Timeframe::D1,
+            MissingCandlePolicy::ForwardFill { max_candles: 5 },
+            now(),
+        );
+
+        assert_eq!(filled.len(), 2);
+        assert_eq!(filled[0].close, Decimal::new(50000, 0));
+        assert!(filled.iter().all(|k| !k.synthetic));
+    }
+
+    #[test]
+    fn test_holidays_are_not_missing() {
+        let on = |month, day| Utc.with_ymd_and_hms(2024, month, day, 0, 0, 0).unwrap();
+
+        // 2024 설날: 02-09(금) 휴장, 02-12(월) 대체공휴일 → 02-08 다음 거래일은 02-13
+        let seollal = || {
+            vec![
+                kline("005930", Timeframe::D1, on(2, 7), 73000),
+                kline("005930", Timeframe::D1, on(2, 8), 74000),
+                kline("005930", Timeframe::D1, on(2, 13), 75000),
+            ]
+        };
+        let filled = apply_missing_candle_policy(
+            seollal(),
+            Timeframe::D1,
+            MissingCandlePolicy::ForwardFill { max_candles: 1 },
+            on(3, 1),
+        );
+        assert_eq!(filled.len(), 3);
+        assert!(filled.iter().all(|k| !k.synthetic));
+
+        // 휴장 이전 캔들을 결측 구간으로 잘라내지 않음
+        let result = apply_missing_candle_policy(
+            seollal(),
+            Timeframe::D1,
+            MissingCandlePolicy::DropIncomplete,
+            on(3, 1),
+        );
+        assert_eq!(result.len(), 3);
+
+        // 미국 2024-01-15 Martin Luther King Jr. Day
+        let klines = vec![daily("AAPL", 12, 185), daily("AAPL", 16, 183)];
+        let filled = apply_missing_candle_policy(
+            klines,
+            Timeframe::D1,
+            MissingCandlePolicy::forward_fill(),
+            now(),
+        );
+        assert_eq!(filled.len(), 2);
+    }
+
+    #[test]
+    fn test_drop_incomplete() {
+        let klines = vec![
+            daily("005930", 2, 70000),
+            daily("005930", 4, 71000),
+            daily("005930", 5, 72000),
+            daily("005930", 8, 73000),
+        ];
+        // 01-08 캔들 진행 중
+        let now = Utc.with_ymd_and_hms(2024, 1, 8, 12, 0, 0).unwrap();
+
+        let result = apply_missing_candle_policy(
+            klines,
+            Timeframe::D1,
+            MissingCandlePolicy::DropIncomplete,
+            now,
+        );
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].close, Decimal::new(71000, 0));
+        assert_eq!(result[1].close, Decimal::new(72000, 0));
+    }
+
+    #[test]
+    fn test_policy_none_keeps_klines() {
+        let klines = vec![daily("005930", 2, 70000), daily("005930", 4, 71000)];
+        let result =
+            apply_missing_candle_policy(klines, Timeframe::D1, MissingCandlePolicy::None, now());
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|k| !k.synthetic));
+    }
+
     #[test]
     fn test_guess_currency() {
         assert_eq!(guess_currency("005930.KS"), "KRW");
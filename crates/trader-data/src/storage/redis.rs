@@ -2,6 +2,14 @@
 //!
 //! 자주 접근하는 시장 데이터에 대한 cache 레이어를 제공하여
 //! 데이터베이스 부하를 줄이고 응답 시간을 개선합니다.
+//!
+//! # OHLCV cache 버전
+//!
+//! kline 키에는 `v{스키마 버전}.{데이터 버전}` 태그가 붙습니다.
+//! 데이터 버전은 심볼+타임프레임별 카운터(`klines_version:{symbol}:{timeframe}`)로,
+//! 무효화나 기업 행동 조정이 일어나면 증가하여 과거 키는 자동으로 미스 처리됩니다.
+
+use std::collections::HashMap;
 
 use redis::{aio::MultiplexedConnection, AsyncCommands, Client};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -10,6 +18,33 @@ use trader_core::{Kline, OrderBook, Ticker, Timeframe};
 
 use crate::error::{DataError, Result};
 
+/// OHLCV cache 스키마 버전.
+///
+/// `Kline` 직렬화 형식이 바뀌면 올려서 기존 cache를 모두 미스 처리합니다.
+pub const KLINE_CACHE_SCHEMA_VERSION: u32 = 1;
+
+/// 무효화 시 한 번에 삭제할 키 수
+const DELETE_CHUNK_SIZE: usize = 500;
+
+/// 버전 태그 대상 전체 타임프레임 (심볼 단위 버전 증가용)
+const ALL_TIMEFRAMES: [Timeframe; 15] = [
+    Timeframe::M1,
+    Timeframe::M3,
+    Timeframe::M5,
+    Timeframe::M15,
+    Timeframe::M30,
+    Timeframe::H1,
+    Timeframe::H2,
+    Timeframe::H4,
+    Timeframe::H6,
+    Timeframe::H8,
+    Timeframe::H12,
+    Timeframe::D1,
+    Timeframe::D3,
+    Timeframe::W1,
+    Timeframe::MN1,
+];
+
 /// Redis 설정.
 #[derive(Debug, Clone, Deserialize)]
 pub struct RedisConfig {
@@ -170,23 +205,40 @@ impl RedisCache {
     }
 
     /// 패턴과 일치하는 키들을 삭제합니다.
+    ///
+    /// `KEYS` 대신 `SCAN`으로 순회하므로 Redis를 블로킹하지 않습니다.
     pub async fn delete_pattern(&self, pattern: &str) -> Result<usize> {
+        let keys = self.scan_keys(pattern).await?;
+        self.delete_keys(&keys).await
+    }
+
+    /// 패턴과 일치하는 키 목록을 `SCAN`으로 조회합니다.
+    async fn scan_keys(&self, pattern: &str) -> Result<Vec<String>> {
         let mut conn = self.connection.clone();
-        let keys: Vec<String> = conn
-            .keys(pattern)
+        let mut iter: redis::AsyncIter<String> = conn
+            .scan_match(pattern)
             .await
             .map_err(|e| DataError::CacheError(e.to_string()))?;
 
-        if keys.is_empty() {
-            return Ok(0);
+        let mut keys = Vec::new();
+        while let Some(key) = iter.next_item().await {
+            keys.push(key);
         }
+        Ok(keys)
+    }
 
-        let deleted: i64 = conn
-            .del(&keys)
-            .await
-            .map_err(|e| DataError::CacheError(e.to_string()))?;
-
-        Ok(deleted as usize)
+    /// 키 목록을 나눠서 삭제합니다 (이미 없는 키는 무시).
+    async fn delete_keys(&self, keys: &[String]) -> Result<usize> {
+        let mut conn = self.connection.clone();
+        let mut deleted = 0;
+        for chunk in keys.chunks(DELETE_CHUNK_SIZE) {
+            let count: i64 = conn
+                .del(chunk)
+                .await
+                .map_err(|e| DataError::CacheError(e.to_string()))?;
+            deleted += count as usize;
+        }
+        Ok(deleted)
     }
 
     // =========================================================================
@@ -239,9 +291,126 @@ impl RedisCache {
     // Kline Cache
     // =========================================================================
 
-    /// kline용 cache 키.
-    fn klines_key(exchange: &str, symbol: &str, timeframe: &Timeframe) -> String {
-        format!("klines:{}:{}:{}", exchange, symbol, timeframe)
+    /// kline용 cache 키 (버전 태그 포함).
+    fn klines_key(exchange: &str, symbol: &str, timeframe: &Timeframe, version: u64) -> String {
+        format!(
+            "klines:{}:{}:{}:{}",
+            exchange,
+            symbol,
+            timeframe,
+            kline_version_tag(version)
+        )
+    }
+
+    /// 날짜 범위 kline용 cache 키 (버전 태그 포함).
+    fn klines_range_key_for(
+        exchange: &str,
+        symbol: &str,
+        timeframe: &Timeframe,
+        version: u64,
+    ) -> String {
+        format!(
+            "klines_range:{}:{}:{}:{}",
+            exchange,
+            symbol,
+            timeframe,
+            kline_version_tag(version)
+        )
+    }
+
+    /// kline 데이터 버전 카운터 키 (TTL 없음).
+    fn klines_version_key(symbol: &str, timeframe: &Timeframe) -> String {
+        format!("klines_version:{}:{}", symbol, timeframe)
+    }
+
+    /// 심볼+타임프레임의 현재 kline 데이터 버전 (없으면 0).
+    pub async fn klines_version(&self, symbol: &str, timeframe: &Timeframe) -> Result<u64> {
+        let mut conn = self.connection.clone();
+        let version: Option<u64> = conn
+            .get(Self::klines_version_key(symbol, timeframe))
+            .await
+            .map_err(|e| DataError::CacheError(e.to_string()))?;
+        Ok(version.unwrap_or(0))
+    }
+
+    /// 현재 버전의 날짜 범위 kline cache 키.
+    ///
+    /// 범위 cache는 호출자가 관리하며, 무효화 시 일반 kline cache와 함께 삭제됩니다.
+    pub async fn klines_range_key(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        timeframe: &Timeframe,
+    ) -> Result<String> {
+        let version = self.klines_version(symbol, timeframe).await?;
+        Ok(Self::klines_range_key_for(
+            exchange, symbol, timeframe, version,
+        ))
+    }
+
+    /// 심볼+타임프레임의 kline cache를 무효화합니다.
+    ///
+    /// 버전을 먼저 올린 뒤 `SCAN`으로 찾은 이전 버전 키만 삭제합니다 (전체 FLUSH 없음).
+    /// 무효화 도중 이전 버전으로 저장하는 요청이 있어도 그 키는 더 이상 조회되지 않으며 TTL로 만료됩니다.
+    ///
+    /// # 반환
+    ///
+    /// 삭제된 키 수
+    #[instrument(skip(self))]
+    pub async fn invalidate_klines(&self, symbol: &str, timeframe: &Timeframe) -> Result<usize> {
+        self.invalidate_kline_versions(symbol, &[*timeframe]).await
+    }
+
+    /// 심볼의 모든 타임프레임 kline 버전을 올립니다.
+    ///
+    /// 기업 행동 조정이나 데이터 재수집으로 과거 캔들이 바뀌었을 때 호출합니다.
+    ///
+    /// # 반환
+    ///
+    /// 삭제된 키 수
+    #[instrument(skip(self))]
+    pub async fn bump_klines_version(&self, symbol: &str) -> Result<usize> {
+        self.invalidate_kline_versions(symbol, &ALL_TIMEFRAMES)
+            .await
+    }
+
+    /// 타임프레임별 버전을 올리고 이전 버전 키를 삭제합니다.
+    async fn invalidate_kline_versions(
+        &self,
+        symbol: &str,
+        timeframes: &[Timeframe],
+    ) -> Result<usize> {
+        let mut conn = self.connection.clone();
+        let mut current = HashMap::new();
+        for timeframe in timeframes {
+            let version: u64 = conn
+                .incr(Self::klines_version_key(symbol, timeframe), 1)
+                .await
+                .map_err(|e| DataError::CacheError(e.to_string()))?;
+            current.insert(timeframe.to_string(), version);
+        }
+
+        let timeframe_pattern = match timeframes {
+            [timeframe] => escape_glob(&timeframe.to_string()),
+            _ => "*".to_string(),
+        };
+        let pattern = format!("klines*:*:{}:{}:v*", escape_glob(symbol), timeframe_pattern);
+
+        let stale: Vec<String> = self
+            .scan_keys(&pattern)
+            .await?
+            .into_iter()
+            .filter(|key| is_stale_kline_key(key, symbol, &current))
+            .collect();
+        let deleted = self.delete_keys(&stale).await?;
+
+        info!(
+            symbol = symbol,
+            timeframes = timeframes.len(),
+            deleted = deleted,
+            "kline cache 무효화"
+        );
+        Ok(deleted)
     }
 
     /// kline을 cache에 저장합니다.
@@ -253,25 +422,23 @@ impl RedisCache {
         timeframe: &Timeframe,
         klines: &[Kline],
     ) -> Result<()> {
-        let key = Self::klines_key(exchange, symbol, timeframe);
+        let version = self.klines_version(symbol, timeframe).await?;
+        self.set_klines_versioned(exchange, symbol, timeframe, klines, version)
+            .await
+    }
+
+    /// 지정한 버전으로 kline을 저장합니다.
+    async fn set_klines_versioned(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        timeframe: &Timeframe,
+        klines: &[Kline],
+        version: u64,
+    ) -> Result<()> {
+        let key = Self::klines_key(exchange, symbol, timeframe, version);
         // Kline은 과거 데이터이므로 더 오래 cache할 수 있음
-        let ttl = match timeframe {
-            Timeframe::M1 => 60,       // 1분봉은 1분
-            Timeframe::M3 => 180,      // 3분봉은 3분
-            Timeframe::M5 => 300,      // 5분봉은 5분
-            Timeframe::M15 => 900,     // 15분봉은 15분
-            Timeframe::M30 => 1800,    // 30분봉은 30분
-            Timeframe::H1 => 3600,     // 1시간봉은 1시간
-            Timeframe::H2 => 7200,     // 2시간봉은 2시간
-            Timeframe::H4 => 14400,    // 4시간봉은 4시간
-            Timeframe::H6 => 21600,    // 6시간봉은 6시간
-            Timeframe::H8 => 28800,    // 8시간봉은 8시간
-            Timeframe::H12 => 43200,   // 12시간봉은 12시간
-            Timeframe::D1 => 86400,    // 일봉은 1일
-            Timeframe::D3 => 259200,   // 3일봉은 3일
-            Timeframe::W1 => 604800,   // 주봉은 1주
-            Timeframe::MN1 => 2592000, // 월봉은 30일
-        };
+        let ttl = kline_ttl_secs(*timeframe);
         self.set_with_ttl(&key, klines, ttl).await
     }
 
@@ -282,7 +449,8 @@ impl RedisCache {
         symbol: &str,
         timeframe: &Timeframe,
     ) -> Result<Option<Vec<Kline>>> {
-        let key = Self::klines_key(exchange, symbol, timeframe);
+        let version = self.klines_version(symbol, timeframe).await?;
+        let key = Self::klines_key(exchange, symbol, timeframe, version);
         self.get(&key).await
     }
 
@@ -295,7 +463,9 @@ impl RedisCache {
         kline: &Kline,
         max_count: usize,
     ) -> Result<()> {
-        let key = Self::klines_key(exchange, symbol, timeframe);
+        // 조회와 저장에 같은 버전 사용 (도중 무효화되면 이전 버전 키에 저장되어 무시됨)
+        let version = self.klines_version(symbol, timeframe).await?;
+        let key = Self::klines_key(exchange, symbol, timeframe, version);
 
         let mut klines: Vec<Kline> = self.get(&key).await?.unwrap_or_default();
 
@@ -314,7 +484,8 @@ impl RedisCache {
             klines = klines.split_off(klines.len() - max_count);
         }
 
-        self.set_klines(exchange, symbol, timeframe, &klines).await
+        self.set_klines_versioned(exchange, symbol, timeframe, &klines, version)
+            .await
     }
 
     /// 여러 타임프레임의 kline을 병렬로 조회합니다.
@@ -558,6 +729,25 @@ impl RedisCache {
         Ok(result.is_some())
     }
 
+    /// 키가 없을 때만 값을 저장합니다 (SET NX PX).
+    ///
+    /// 저장에 성공하면 `true`, 이미 키가 존재하면 `false`를 반환합니다.
+    pub async fn set_nx_with_ttl_ms(&self, key: &str, value: &str, ttl_ms: u64) -> Result<bool> {
+        let mut conn = self.connection.clone();
+
+        let result: Option<String> = redis::cmd("SET")
+            .arg(key)
+            .arg(value)
+            .arg("NX")
+            .arg("PX")
+            .arg(ttl_ms)
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| DataError::CacheError(e.to_string()))?;
+
+        Ok(result.is_some())
+    }
+
     /// 분산 잠금을 해제합니다.
     pub async fn release_lock(&self, lock_name: &str) -> Result<bool> {
         let key = format!("lock:{}", lock_name);
@@ -673,6 +863,61 @@ impl MetricsCache {
     }
 }
 
+/// kline 키 버전 태그 (`v{스키마}.{데이터 버전}`).
+fn kline_version_tag(version: u64) -> String {
+    format!("v{}.{}", KLINE_CACHE_SCHEMA_VERSION, version)
+}
+
+/// `SCAN` 패턴의 glob 특수문자 이스케이프.
+fn escape_glob(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// `klines*:{exchange}:{symbol}:{timeframe}:{tag}` 키가 현재 버전이 아닌지 확인.
+///
+/// 다른 심볼이나 무효화 대상이 아닌 타임프레임의 키는 `false`입니다.
+fn is_stale_kline_key(key: &str, symbol: &str, current: &HashMap<String, u64>) -> bool {
+    let mut parts = key.rsplitn(4, ':');
+    let (Some(tag), Some(timeframe), Some(key_symbol)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    if key_symbol != symbol {
+        return false;
+    }
+    current
+        .get(timeframe)
+        .is_some_and(|version| tag != kline_version_tag(*version))
+}
+
+/// 타임프레임별 kline cache TTL (초). 캔들 하나의 기간만큼 유지합니다.
+pub(crate) fn kline_ttl_secs(timeframe: Timeframe) -> u64 {
+    match timeframe {
+        Timeframe::M1 => 60,       // 1분봉은 1분
+        Timeframe::M3 => 180,      // 3분봉은 3분
+        Timeframe::M5 => 300,      // 5분봉은 5분
+        Timeframe::M15 => 900,     // 15분봉은 15분
+        Timeframe::M30 => 1800,    // 30분봉은 30분
+        Timeframe::H1 => 3600,     // 1시간봉은 1시간
+        Timeframe::H2 => 7200,     // 2시간봉은 2시간
+        Timeframe::H4 => 14400,    // 4시간봉은 4시간
+        Timeframe::H6 => 21600,    // 6시간봉은 6시간
+        Timeframe::H8 => 28800,    // 8시간봉은 8시간
+        Timeframe::H12 => 43200,   // 12시간봉은 12시간
+        Timeframe::D1 => 86400,    // 일봉은 1일
+        Timeframe::D3 => 259200,   // 3일봉은 3일
+        Timeframe::W1 => 604800,   // 주봉은 1주
+        Timeframe::MN1 => 2592000, // 월봉은 30일
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -684,6 +929,72 @@ mod tests {
         assert_eq!(config.pool_size, 10);
     }
 
+    #[test]
+    fn test_kline_keys_are_versioned() {
+        assert_eq!(
+            RedisCache::klines_key("local", "AAPL", &Timeframe::D1, 3),
+            format!("klines:local:AAPL:1d:v{}.3", KLINE_CACHE_SCHEMA_VERSION)
+        );
+        assert_eq!(
+            RedisCache::klines_range_key_for("local", "AAPL", &Timeframe::D1, 0),
+            format!(
+                "klines_range:local:AAPL:1d:v{}.0",
+                KLINE_CACHE_SCHEMA_VERSION
+            )
+        );
+        assert_eq!(
+            RedisCache::klines_version_key("AAPL", &Timeframe::D1),
+            "klines_version:AAPL:1d"
+        );
+    }
+
+    #[test]
+    fn test_stale_kline_key_detection() {
+        let current = HashMap::from([("1d".to_string(), 2)]);
+        let key = |symbol: &str, tf: &Timeframe, version| {
+            RedisCache::klines_key("local", symbol, tf, version)
+        };
+
+        assert!(is_stale_kline_key(
+            &key("AAPL", &Timeframe::D1, 1),
+            "AAPL",
+            &current
+        ));
+        assert!(is_stale_kline_key(
+            &RedisCache::klines_range_key_for("local", "AAPL", &Timeframe::D1, 0),
+            "AAPL",
+            &current
+        ));
+        // 현재 버전, 다른 타임프레임, 다른 심볼은 유지
+        assert!(!is_stale_kline_key(
+            &key("AAPL", &Timeframe::D1, 2),
+            "AAPL",
+            &current
+        ));
+        assert!(!is_stale_kline_key(
+            &key("AAPL", &Timeframe::H1, 0),
+            "AAPL",
+            &current
+        ));
+        assert!(!is_stale_kline_key(
+            &key("XAAPL", &Timeframe::D1, 0),
+            "AAPL",
+            &current
+        ));
+        // 심볼에 '/'가 있어도 정확히 비교
+        assert!(is_stale_kline_key(
+            &key("BTC/USDT", &Timeframe::D1, 0),
+            "BTC/USDT",
+            &current
+        ));
+    }
+
+    #[test]
+    fn test_escape_glob() {
+        assert_eq!(escape_glob("BTC/USDT"), "BTC/USDT");
+        assert_eq!(escape_glob("A*[1]?"), "A\\*\\[1\\]\\?");
+    }
+
     #[test]
     fn test_cache_keys() {
         assert_eq!(
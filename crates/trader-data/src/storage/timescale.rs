@@ -486,7 +486,7 @@ impl OrderRepository {
         .bind(format!("{:?}", order.status).to_lowercase())
         .bind(order.filled_quantity)
         .bind(order.average_fill_price)
-        .bind(format!("{:?}", order.time_in_force))
+        .bind(order.time_in_force.code())
         .bind(&order.strategy_id)
         .bind(&order.client_order_id)
         .bind(order.created_at)
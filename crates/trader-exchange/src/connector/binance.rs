@@ -1,6 +1,6 @@
 //! Binance 거래소 커넥터.
 //!
-//! Binance Spot용 REST API 및 WebSocket 연결 구현.
+//! Binance Spot 및 USDT-M 선물(Futures)용 REST API 및 WebSocket 연결 구현.
 //! 메인넷과 테스트넷 모두 지원.
 
 #![allow(dead_code)] // API 응답 필드 전체 매핑 (일부만 사용)
@@ -20,10 +20,12 @@ use sha2::Sha256;
 use tracing::{debug, error, info, warn};
 use trader_core::{
     Kline, MarketType, OrderBook, OrderBookLevel, OrderRequest, OrderStatus, OrderType, Position,
-    RoundMethod, Side, Symbol, TickSizeProvider, Ticker, Timeframe, TradeTick,
+    RoundMethod, Side, Symbol, SymbolNormalizer, SymbolVenue, TickSizeProvider, Ticker,
+    TimeInForce, Timeframe, TradeTick,
 };
 
 use crate::{
+    request_log::{send_logged, RequestLogger},
     traits::{AccountInfo, Balance, ExchangeResult},
     ExchangeError,
 };
@@ -128,6 +130,15 @@ impl BinanceConfig {
         }
     }
 
+    /// USDT-M 선물 REST API 기본 URL 반환.
+    pub fn futures_rest_base_url(&self) -> &str {
+        if self.testnet {
+            "https://testnet.binancefuture.com"
+        } else {
+            "https://fapi.binance.com"
+        }
+    }
+
     /// WebSocket 기본 URL 반환.
     pub fn ws_base_url(&self) -> &str {
         if self.testnet {
@@ -136,6 +147,148 @@ impl BinanceConfig {
             "wss://stream.binance.com:9443/ws"
         }
     }
+
+    /// 엔드포인트에 맞는 REST 기본 URL 반환 (`/fapi/`는 선물, 그 외는 현물).
+    fn base_url_for(&self, endpoint: &str) -> &str {
+        if endpoint.starts_with("/fapi/") {
+            self.futures_rest_base_url()
+        } else {
+            self.rest_base_url()
+        }
+    }
+}
+
+// ============================================================================
+// USDT-M 선물 타입
+// ============================================================================
+
+/// USDT-M 선물 포지션 모드.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionMode {
+    /// 단방향 모드 (심볼당 순포지션 1개)
+    OneWay,
+    /// 헤지 모드 (심볼당 롱/숏 포지션 동시 보유)
+    Hedge,
+}
+
+impl PositionMode {
+    /// 주문 방향과 감소 전용 여부로 주문의 포지션 방향을 결정합니다.
+    ///
+    /// 헤지 모드에서는 `reduceOnly`를 전달할 수 없으므로 청산 대상 포지션 방향으로
+    /// 표현합니다 (매도 청산 → LONG, 매수 청산 → SHORT).
+    pub fn position_side_for(&self, side: Side, reduce_only: bool) -> FuturesPositionSide {
+        match (self, side, reduce_only) {
+            (PositionMode::OneWay, _, _) => FuturesPositionSide::Both,
+            (PositionMode::Hedge, Side::Buy, false) | (PositionMode::Hedge, Side::Sell, true) => {
+                FuturesPositionSide::Long
+            }
+            (PositionMode::Hedge, Side::Sell, false) | (PositionMode::Hedge, Side::Buy, true) => {
+                FuturesPositionSide::Short
+            }
+        }
+    }
+}
+
+/// USDT-M 선물 포지션 방향.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuturesPositionSide {
+    /// 단방향 모드 포지션
+    Both,
+    /// 헤지 모드 롱 포지션
+    Long,
+    /// 헤지 모드 숏 포지션
+    Short,
+}
+
+impl FuturesPositionSide {
+    /// Binance API 문자열 반환.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FuturesPositionSide::Both => "BOTH",
+            FuturesPositionSide::Long => "LONG",
+            FuturesPositionSide::Short => "SHORT",
+        }
+    }
+
+    fn parse(s: &str) -> Self {
+        match s {
+            "LONG" => FuturesPositionSide::Long,
+            "SHORT" => FuturesPositionSide::Short,
+            _ => FuturesPositionSide::Both,
+        }
+    }
+}
+
+/// USDT-M 선물 포지션.
+#[derive(Debug, Clone)]
+pub struct FuturesPosition {
+    /// 심볼 (예: "BTC/USDT")
+    pub ticker: String,
+    /// 포지션 방향
+    pub position_side: FuturesPositionSide,
+    /// 포지션 수량 (양수: 롱, 음수: 숏)
+    pub quantity: Decimal,
+    /// 평균 진입가
+    pub entry_price: Decimal,
+    /// 마크 가격
+    pub mark_price: Decimal,
+    /// 미실현 손익
+    pub unrealized_pnl: Decimal,
+    /// 청산가 (포지션이 없으면 0)
+    pub liquidation_price: Decimal,
+    /// 레버리지 배수
+    pub leverage: u32,
+    /// 격리 마진 여부
+    pub isolated: bool,
+    /// 포지션 증거금 (격리: 격리 증거금, 교차: 명목가치 / 레버리지)
+    pub margin: Decimal,
+}
+
+impl FuturesPosition {
+    /// 보유 수량이 있는지 여부.
+    pub fn is_open(&self) -> bool {
+        !self.quantity.is_zero()
+    }
+
+    /// 포지션 방향 (롱: 매수, 숏: 매도).
+    pub fn side(&self) -> Side {
+        match self.position_side {
+            FuturesPositionSide::Long => Side::Buy,
+            FuturesPositionSide::Short => Side::Sell,
+            FuturesPositionSide::Both if self.quantity < Decimal::ZERO => Side::Sell,
+            FuturesPositionSide::Both => Side::Buy,
+        }
+    }
+}
+
+/// USDT-M 선물 펀딩비 정보.
+#[derive(Debug, Clone)]
+pub struct FundingRate {
+    /// 심볼 (예: "BTC/USDT")
+    pub ticker: String,
+    /// 마크 가격
+    pub mark_price: Decimal,
+    /// 인덱스 가격
+    pub index_price: Decimal,
+    /// 최근 펀딩비 (예: 0.0001 = 0.01%)
+    pub funding_rate: Decimal,
+    /// 다음 펀딩 시각
+    pub next_funding_time: DateTime<Utc>,
+}
+
+/// USDT-M 선물 계좌 요약.
+#[derive(Debug, Clone)]
+pub struct FuturesAccount {
+    /// 지갑 잔고
+    pub total_wallet_balance: Decimal,
+    /// 마진 잔고 (지갑 잔고 + 미실현 손익)
+    pub total_margin_balance: Decimal,
+    /// 주문 가능 금액
+    pub available_balance: Decimal,
+    /// 사용 중인 개시 증거금
+    pub total_initial_margin: Decimal,
+    /// 미실현 손익
+    pub total_unrealized_pnl: Decimal,
 }
 
 // ============================================================================
@@ -251,6 +404,55 @@ struct BinanceOrderResponse {
     side: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinancePositionRisk {
+    symbol: String,
+    position_amt: String,
+    entry_price: String,
+    mark_price: String,
+    un_realized_profit: String,
+    liquidation_price: String,
+    leverage: String,
+    margin_type: String,
+    isolated_margin: String,
+    notional: String,
+    position_side: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceFuturesAccount {
+    total_wallet_balance: String,
+    total_margin_balance: String,
+    available_balance: String,
+    total_initial_margin: String,
+    total_unrealized_profit: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinancePremiumIndex {
+    symbol: String,
+    mark_price: String,
+    index_price: String,
+    last_funding_rate: String,
+    next_funding_time: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinancePositionModeResponse {
+    dual_side_position: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceLeverageResponse {
+    leverage: u32,
+    symbol: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct BinanceError {
@@ -269,6 +471,8 @@ pub struct BinanceClient {
     connected: bool,
     /// 호가 단위 제공자 (옵션, 설정 시 주문 가격 자동 라운딩)
     tick_size_provider: Option<Arc<dyn TickSizeProvider>>,
+    /// 요청/응답 추적 로거 (옵션)
+    request_logger: Option<Arc<RequestLogger>>,
 }
 
 impl BinanceClient {
@@ -289,6 +493,7 @@ impl BinanceClient {
             client,
             connected: false,
             tick_size_provider: None,
+            request_logger: None,
         })
     }
 
@@ -305,6 +510,14 @@ impl BinanceClient {
         self
     }
 
+    /// 요청/응답 추적 로거를 설정합니다.
+    ///
+    /// 설정 시 모든 REST 요청이 마스킹된 상태로 기록됩니다.
+    pub fn with_request_logger(mut self, logger: Arc<RequestLogger>) -> Self {
+        self.request_logger = Some(logger);
+        self
+    }
+
     /// 환경 변수에서 생성.
     ///
     /// 환경 변수가 설정되지 않았거나 클라이언트 생성에 실패하면 `None`을 반환합니다.
@@ -343,7 +556,7 @@ impl BinanceClient {
         endpoint: &str,
         params: &[(&str, String)],
     ) -> ExchangeResult<T> {
-        let url = format!("{}{}", self.config.rest_base_url(), endpoint);
+        let url = format!("{}{}", self.config.base_url_for(endpoint), endpoint);
         let query = Self::build_query(params);
 
         let full_url = if query.is_empty() {
@@ -354,14 +567,8 @@ impl BinanceClient {
 
         debug!("GET {}", full_url);
 
-        let response = self
-            .client
-            .get(&full_url)
-            .send()
+        self.send_request(endpoint, self.client.get(&full_url))
             .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        self.handle_response(response).await
     }
 
     /// 서명된 API 요청 (인증 필요).
@@ -370,7 +577,7 @@ impl BinanceClient {
         endpoint: &str,
         params: &[(&str, String)],
     ) -> ExchangeResult<T> {
-        let url = format!("{}{}", self.config.rest_base_url(), endpoint);
+        let url = format!("{}{}", self.config.base_url_for(endpoint), endpoint);
 
         let mut all_params = params.to_vec();
         all_params.push(("timestamp", Self::timestamp_ms().to_string()));
@@ -382,15 +589,11 @@ impl BinanceClient {
 
         debug!("GET (signed) {}", endpoint);
 
-        let response = self
+        let builder = self
             .client
             .get(&full_url)
-            .header("X-MBX-APIKEY", &self.config.api_key)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        self.handle_response(response).await
+            .header("X-MBX-APIKEY", &self.config.api_key);
+        self.send_request(endpoint, builder).await
     }
 
     /// 서명된 POST 요청.
@@ -399,7 +602,7 @@ impl BinanceClient {
         endpoint: &str,
         params: &[(&str, String)],
     ) -> ExchangeResult<T> {
-        let url = format!("{}{}", self.config.rest_base_url(), endpoint);
+        let url = format!("{}{}", self.config.base_url_for(endpoint), endpoint);
 
         let mut all_params = params.to_vec();
         all_params.push(("timestamp", Self::timestamp_ms().to_string()));
@@ -411,17 +614,13 @@ impl BinanceClient {
 
         debug!("POST (signed) {}", endpoint);
 
-        let response = self
+        let builder = self
             .client
             .post(&url)
             .header("X-MBX-APIKEY", &self.config.api_key)
             .header("Content-Type", "application/x-www-form-urlencoded")
-            .body(body)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        self.handle_response(response).await
+            .body(body.clone());
+        self.send_request(endpoint, builder).await
     }
 
     /// 서명된 DELETE 요청.
@@ -430,7 +629,7 @@ impl BinanceClient {
         endpoint: &str,
         params: &[(&str, String)],
     ) -> ExchangeResult<T> {
-        let url = format!("{}{}", self.config.rest_base_url(), endpoint);
+        let url = format!("{}{}", self.config.base_url_for(endpoint), endpoint);
 
         let mut all_params = params.to_vec();
         all_params.push(("timestamp", Self::timestamp_ms().to_string()));
@@ -442,28 +641,39 @@ impl BinanceClient {
 
         debug!("DELETE (signed) {}", endpoint);
 
-        let response = self
+        let builder = self
             .client
             .delete(&full_url)
-            .header("X-MBX-APIKEY", &self.config.api_key)
-            .send()
+            .header("X-MBX-APIKEY", &self.config.api_key);
+        self.send_request(endpoint, builder).await
+    }
+
+    /// 요청 전송 및 응답 처리 (요청 로거가 설정된 경우 마스킹하여 기록).
+    ///
+    /// `/fapi/` 엔드포인트는 `binance_futures`로 구분해 기록합니다.
+    async fn send_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        endpoint: &str,
+        builder: reqwest::RequestBuilder,
+    ) -> ExchangeResult<T> {
+        let exchange = if endpoint.starts_with("/fapi/") {
+            "binance_futures"
+        } else {
+            "binance"
+        };
+        let (status, body) = send_logged(self.request_logger.as_deref(), exchange, builder)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
-        self.handle_response(response).await
+        self.handle_response(status, body)
     }
 
     /// API 응답 처리.
-    async fn handle_response<T: for<'de> Deserialize<'de>>(
+    fn handle_response<T: for<'de> Deserialize<'de>>(
         &self,
-        response: reqwest::Response,
+        status: reqwest::StatusCode,
+        body: String,
     ) -> ExchangeResult<T> {
-        let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
         if status.is_success() {
             serde_json::from_str(&body).map_err(|e| {
                 error!("Failed to parse response: {} - Body: {}", e, body);
@@ -494,6 +704,9 @@ impl BinanceClient {
             -2010 => ExchangeError::InsufficientBalance(msg.to_string()),
             -2011 => ExchangeError::OrderNotFound(msg.to_string()),
             -2013 => ExchangeError::OrderNotFound(msg.to_string()),
+            // USDT-M 선물: 증거금 부족 / 감소 전용 주문 거부
+            -2019 => ExchangeError::InsufficientBalance(msg.to_string()),
+            -2022 => ExchangeError::OrderRejected(msg.to_string()),
             _ => ExchangeError::ApiError {
                 code,
                 message: msg.to_string(),
@@ -503,23 +716,20 @@ impl BinanceClient {
 
     /// Binance 심볼 형식을 내부 Symbol로 변환.
     fn to_symbol(binance_symbol: &str) -> Symbol {
-        // 일반적인 호가 자산
-        let quotes = ["USDT", "BUSD", "BTC", "ETH", "BNB", "USDC"];
-
-        for quote in quotes {
-            if let Some(base) = binance_symbol.strip_suffix(quote) {
-                return Symbol::new(base, quote, MarketType::Crypto);
-            }
-        }
-
-        // 폴백: USDT로 가정
-        Symbol::new(binance_symbol, "USDT", MarketType::Crypto)
+        SymbolNormalizer::new()
+            .to_internal(binance_symbol, SymbolVenue::Binance)
+            .ok()
+            .and_then(|ticker| Symbol::from_string(&ticker, MarketType::Crypto))
+            // 폴백: USDT로 가정
+            .unwrap_or_else(|| Symbol::new(binance_symbol, "USDT", MarketType::Crypto))
     }
 
     /// 내부 Symbol을 Binance 심볼 형식으로 변환.
     fn from_symbol(ticker: &str) -> String {
-        // "BTC/USDT" -> "BTCUSDT"
-        ticker.replace("/", "")
+        // "BTC/USDT" -> "BTCUSDT" (이미 Binance 형식이면 그대로)
+        SymbolNormalizer::new()
+            .to_venue(ticker, SymbolVenue::Binance)
+            .unwrap_or_else(|_| ticker.replace('/', ""))
     }
 
     /// 문자열에서 Decimal 파싱.
@@ -766,6 +976,7 @@ impl BinanceClient {
                 close_time: DateTime::from_timestamp_millis(k.6).unwrap_or_else(Utc::now),
                 quote_volume: Some(Self::parse_decimal(&k.7)),
                 num_trades: Some(k.8 as u32),
+                synthetic: false,
             })
             .collect())
     }
@@ -774,11 +985,6 @@ impl BinanceClient {
         // TODO: SymbolResolver로 ticker → Symbol 변환 후 from_symbol 사용
         let binance_symbol = request.ticker.clone();
 
-        let side = match request.side {
-            Side::Buy => "BUY",
-            Side::Sell => "SELL",
-        };
-
         let order_type = match request.order_type {
             OrderType::Market => "MARKET",
             OrderType::Limit => "LIMIT",
@@ -789,6 +995,42 @@ impl BinanceClient {
             OrderType::TrailingStop => "TRAILING_STOP_MARKET",
         };
 
+        // Spot은 GTD 미지원 (provider에서 사전 차단)
+        let time_in_force = match request.time_in_force {
+            TimeInForce::IOC => "IOC",
+            TimeInForce::FOK => "FOK",
+            TimeInForce::GTC | TimeInForce::GTD(_) => "GTC",
+        };
+
+        let params = self.order_params(request, binance_symbol, order_type, time_in_force);
+
+        info!(
+            "Placing {} {} order for {} {} @ {:?}",
+            request.side, order_type, request.quantity, request.ticker, request.price
+        );
+
+        let resp: BinanceOrderResponse = self.signed_post("/api/v3/order", &params).await?;
+
+        info!("Order placed successfully: {}", resp.order_id);
+        Ok(resp.order_id.to_string())
+    }
+
+    /// 현물/선물 공통 주문 파라미터 생성.
+    ///
+    /// 지정가/스톱 가격에는 호가 단위 라운딩을 적용하고,
+    /// 지정가가 있는 주문에만 `timeInForce`를 추가합니다.
+    fn order_params(
+        &self,
+        request: &OrderRequest,
+        binance_symbol: String,
+        order_type: &str,
+        time_in_force: &str,
+    ) -> Vec<(&'static str, String)> {
+        let side = match request.side {
+            Side::Buy => "BUY",
+            Side::Sell => "SELL",
+        };
+
         // 호가 단위 라운딩 헬퍼 (클로저)
         let round_price = |price: Decimal, is_buy: bool| -> Decimal {
             if let Some(ref provider) = self.tick_size_provider {
@@ -828,7 +1070,7 @@ impl BinanceClient {
         if let Some(price) = request.price {
             let rounded_price = round_price(price, is_buy);
             params.push(("price", rounded_price.to_string()));
-            params.push(("timeInForce", "GTC".to_string()));
+            params.push(("timeInForce", time_in_force.to_string()));
         }
 
         // 스톱 가격이 있으면 추가 (라운딩 적용)
@@ -842,15 +1084,7 @@ impl BinanceClient {
             params.push(("newClientOrderId", client_id.clone()));
         }
 
-        info!(
-            "Placing {} {} order for {} {} @ {:?}",
-            side, order_type, request.quantity, request.ticker, request.price
-        );
-
-        let resp: BinanceOrderResponse = self.signed_post("/api/v3/order", &params).await?;
-
-        info!("Order placed successfully: {}", resp.order_id);
-        Ok(resp.order_id.to_string())
+        params
     }
 
     pub async fn cancel_order(&self, symbol: &str, order_id: &str) -> ExchangeResult<()> {
@@ -880,6 +1114,37 @@ impl BinanceClient {
         Ok(Self::parse_order_status(&resp))
     }
 
+    /// client_order_id로 주문 조회 (`origClientOrderId`).
+    ///
+    /// 주문이 없으면(-2013) `None`을 반환합니다.
+    pub async fn get_order_by_client_id(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> ExchangeResult<Option<OrderStatus>> {
+        self.find_order_by_client_id("/api/v3/order", symbol, client_order_id)
+            .await
+    }
+
+    /// 현물/선물 공통 `origClientOrderId` 조회.
+    async fn find_order_by_client_id(
+        &self,
+        path: &str,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> ExchangeResult<Option<OrderStatus>> {
+        let params = vec![
+            ("symbol", Self::from_symbol(symbol)),
+            ("origClientOrderId", client_order_id.to_string()),
+        ];
+
+        match self.signed_get::<BinanceOrderResponse>(path, &params).await {
+            Ok(resp) => Ok(Some(Self::parse_order_status(&resp))),
+            Err(ExchangeError::OrderNotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     pub async fn get_open_orders(&self, symbol: Option<&str>) -> ExchangeResult<Vec<OrderStatus>> {
         let params: Vec<(&str, String)> = if let Some(s) = symbol {
             vec![("symbol", Self::from_symbol(s))]
@@ -932,6 +1197,260 @@ impl BinanceClient {
     }
 }
 
+/// USDT-M 선물 메서드 (`/fapi/` 엔드포인트).
+impl BinanceClient {
+    /// 현재 포지션 모드 조회.
+    pub async fn futures_position_mode(&self) -> ExchangeResult<PositionMode> {
+        let resp: BinancePositionModeResponse =
+            self.signed_get("/fapi/v1/positionSide/dual", &[]).await?;
+
+        Ok(if resp.dual_side_position {
+            PositionMode::Hedge
+        } else {
+            PositionMode::OneWay
+        })
+    }
+
+    /// 포지션 모드 변경.
+    ///
+    /// 포지션 또는 미체결 주문이 있으면 거래소가 거부합니다.
+    pub async fn futures_set_position_mode(&self, mode: PositionMode) -> ExchangeResult<()> {
+        let params = vec![(
+            "dualSidePosition",
+            matches!(mode, PositionMode::Hedge).to_string(),
+        )];
+
+        let _: serde_json::Value = self
+            .signed_post("/fapi/v1/positionSide/dual", &params)
+            .await?;
+
+        info!("Binance 선물 포지션 모드 변경: {:?}", mode);
+        Ok(())
+    }
+
+    /// 심볼별 레버리지 변경. 적용된 레버리지를 반환합니다.
+    pub async fn futures_set_leverage(&self, symbol: &str, leverage: u32) -> ExchangeResult<u32> {
+        let params = vec![
+            ("symbol", Self::from_symbol(symbol)),
+            ("leverage", leverage.to_string()),
+        ];
+
+        let resp: BinanceLeverageResponse = self.signed_post("/fapi/v1/leverage", &params).await?;
+
+        info!("Binance 선물 레버리지 변경: {} x{}", symbol, resp.leverage);
+        Ok(resp.leverage)
+    }
+
+    /// 포지션 조회 (`/fapi/v2/positionRisk`).
+    ///
+    /// 수량이 0인 항목도 포함되므로 필요 시 [`FuturesPosition::is_open`]으로 거릅니다.
+    pub async fn futures_positions(
+        &self,
+        symbol: Option<&str>,
+    ) -> ExchangeResult<Vec<FuturesPosition>> {
+        let params: Vec<(&str, String)> = if let Some(s) = symbol {
+            vec![("symbol", Self::from_symbol(s))]
+        } else {
+            vec![]
+        };
+
+        let resp: Vec<BinancePositionRisk> =
+            self.signed_get("/fapi/v2/positionRisk", &params).await?;
+
+        Ok(resp.iter().map(Self::parse_position_risk).collect())
+    }
+
+    /// 펀딩비 조회 (`/fapi/v1/premiumIndex`).
+    pub async fn futures_funding_rate(&self, symbol: &str) -> ExchangeResult<FundingRate> {
+        let params = vec![("symbol", Self::from_symbol(symbol))];
+
+        let resp: BinancePremiumIndex = self.public_get("/fapi/v1/premiumIndex", &params).await?;
+
+        Ok(FundingRate {
+            ticker: Self::to_symbol(&resp.symbol).to_string(),
+            mark_price: Self::parse_decimal(&resp.mark_price),
+            index_price: Self::parse_decimal(&resp.index_price),
+            funding_rate: Self::parse_decimal(&resp.last_funding_rate),
+            next_funding_time: DateTime::from_timestamp_millis(resp.next_funding_time)
+                .unwrap_or_else(Utc::now),
+        })
+    }
+
+    /// 선물 계좌 조회 (`/fapi/v2/account`).
+    pub async fn futures_account(&self) -> ExchangeResult<FuturesAccount> {
+        let resp: BinanceFuturesAccount = self.signed_get("/fapi/v2/account", &[]).await?;
+
+        Ok(FuturesAccount {
+            total_wallet_balance: Self::parse_decimal(&resp.total_wallet_balance),
+            total_margin_balance: Self::parse_decimal(&resp.total_margin_balance),
+            available_balance: Self::parse_decimal(&resp.available_balance),
+            total_initial_margin: Self::parse_decimal(&resp.total_initial_margin),
+            total_unrealized_pnl: Self::parse_decimal(&resp.total_unrealized_profit),
+        })
+    }
+
+    /// 선물 주문 제출.
+    ///
+    /// `position_side`는 [`PositionMode::position_side_for`]로 결정합니다.
+    pub async fn futures_place_order(
+        &self,
+        request: &OrderRequest,
+        position_side: FuturesPositionSide,
+    ) -> ExchangeResult<String> {
+        let params = self.futures_order_params(request, position_side);
+
+        info!(
+            "Placing futures {:?} order for {} {} @ {:?} (reduce_only: {}, position_side: {})",
+            request.order_type,
+            request.quantity,
+            request.ticker,
+            request.price,
+            request.reduce_only,
+            position_side.as_str()
+        );
+
+        let resp: BinanceOrderResponse = self.signed_post("/fapi/v1/order", &params).await?;
+
+        info!("Futures order placed successfully: {}", resp.order_id);
+        Ok(resp.order_id.to_string())
+    }
+
+    /// 선물 주문 취소.
+    pub async fn futures_cancel_order(&self, symbol: &str, order_id: &str) -> ExchangeResult<()> {
+        let params = vec![
+            ("symbol", Self::from_symbol(symbol)),
+            ("orderId", order_id.to_string()),
+        ];
+
+        let _: BinanceOrderResponse = self.signed_delete("/fapi/v1/order", &params).await?;
+
+        info!("Futures order {} cancelled", order_id);
+        Ok(())
+    }
+
+    /// 선물 주문 상태 조회.
+    pub async fn futures_get_order(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> ExchangeResult<OrderStatus> {
+        let params = vec![
+            ("symbol", Self::from_symbol(symbol)),
+            ("orderId", order_id.to_string()),
+        ];
+
+        let resp: BinanceOrderResponse = self.signed_get("/fapi/v1/order", &params).await?;
+
+        Ok(Self::parse_order_status(&resp))
+    }
+
+    /// 선물 client_order_id로 주문 조회 (`origClientOrderId`).
+    ///
+    /// 주문이 없으면(-2013) `None`을 반환합니다.
+    pub async fn futures_get_order_by_client_id(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> ExchangeResult<Option<OrderStatus>> {
+        self.find_order_by_client_id("/fapi/v1/order", symbol, client_order_id)
+            .await
+    }
+
+    /// 선물 미체결 주문 조회.
+    pub async fn futures_open_orders(
+        &self,
+        symbol: Option<&str>,
+    ) -> ExchangeResult<Vec<OrderStatus>> {
+        let params: Vec<(&str, String)> = if let Some(s) = symbol {
+            vec![("symbol", Self::from_symbol(s))]
+        } else {
+            vec![]
+        };
+
+        let resp: Vec<BinanceOrderResponse> =
+            self.signed_get("/fapi/v1/openOrders", &params).await?;
+
+        Ok(resp.iter().map(Self::parse_order_status).collect())
+    }
+
+    /// 선물 주문 파라미터 생성.
+    ///
+    /// 단방향 모드는 `reduceOnly`로, 헤지 모드는 `positionSide`로 청산 주문을 표현합니다.
+    fn futures_order_params(
+        &self,
+        request: &OrderRequest,
+        position_side: FuturesPositionSide,
+    ) -> Vec<(&'static str, String)> {
+        let order_type = match request.order_type {
+            OrderType::Market => "MARKET",
+            OrderType::Limit => "LIMIT",
+            OrderType::StopLoss => "STOP_MARKET",
+            OrderType::StopLossLimit => "STOP",
+            OrderType::TakeProfit => "TAKE_PROFIT_MARKET",
+            OrderType::TakeProfitLimit => "TAKE_PROFIT",
+            OrderType::TrailingStop => "TRAILING_STOP_MARKET",
+        };
+
+        let time_in_force = match request.time_in_force {
+            TimeInForce::GTC => "GTC",
+            TimeInForce::IOC => "IOC",
+            TimeInForce::FOK => "FOK",
+            TimeInForce::GTD(_) => "GTD",
+        };
+
+        let mut params = self.order_params(
+            request,
+            Self::from_symbol(&request.ticker),
+            order_type,
+            time_in_force,
+        );
+
+        if let (TimeInForce::GTD(expires_at), Some(_)) = (request.time_in_force, request.price) {
+            params.push(("goodTillDate", expires_at.timestamp_millis().to_string()));
+        }
+
+        match position_side {
+            FuturesPositionSide::Both => {
+                if request.reduce_only {
+                    params.push(("reduceOnly", "true".to_string()));
+                }
+            }
+            FuturesPositionSide::Long | FuturesPositionSide::Short => {
+                params.push(("positionSide", position_side.as_str().to_string()));
+            }
+        }
+
+        params
+    }
+
+    /// `positionRisk` 응답을 FuturesPosition으로 변환.
+    fn parse_position_risk(resp: &BinancePositionRisk) -> FuturesPosition {
+        let quantity = Self::parse_decimal(&resp.position_amt);
+        let leverage = resp.leverage.parse::<u32>().unwrap_or(1).max(1);
+        let isolated = resp.margin_type.eq_ignore_ascii_case("isolated");
+
+        // 격리 마진은 거래소가 보고한 증거금, 교차 마진은 명목가치 / 레버리지로 근사
+        let margin = if isolated {
+            Self::parse_decimal(&resp.isolated_margin)
+        } else {
+            Self::parse_decimal(&resp.notional).abs() / Decimal::from(leverage)
+        };
+
+        FuturesPosition {
+            ticker: Self::to_symbol(&resp.symbol).to_string(),
+            position_side: FuturesPositionSide::parse(&resp.position_side),
+            quantity,
+            entry_price: Self::parse_decimal(&resp.entry_price),
+            mark_price: Self::parse_decimal(&resp.mark_price),
+            unrealized_pnl: Self::parse_decimal(&resp.un_realized_profit),
+            liquidation_price: Self::parse_decimal(&resp.liquidation_price),
+            leverage,
+            isolated,
+            margin,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -963,4 +1482,110 @@ mod tests {
             "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
         );
     }
+
+    fn test_client() -> BinanceClient {
+        BinanceClient::new(BinanceConfig::new("key".to_string(), "secret".to_string()))
+            .expect("테스트용 클라이언트 생성 실패")
+    }
+
+    #[test]
+    fn test_base_url_for_futures_endpoint() {
+        let config = BinanceConfig::new("key".to_string(), "secret".to_string());
+        assert_eq!(
+            config.base_url_for("/fapi/v1/order"),
+            "https://fapi.binance.com"
+        );
+        assert_eq!(
+            config.base_url_for("/api/v3/order"),
+            "https://api.binance.com"
+        );
+
+        let testnet = config.with_testnet(true);
+        assert_eq!(
+            testnet.base_url_for("/fapi/v1/order"),
+            "https://testnet.binancefuture.com"
+        );
+    }
+
+    #[test]
+    fn test_position_side_for_mode() {
+        assert_eq!(
+            PositionMode::OneWay.position_side_for(Side::Sell, true),
+            FuturesPositionSide::Both
+        );
+        assert_eq!(
+            PositionMode::Hedge.position_side_for(Side::Buy, false),
+            FuturesPositionSide::Long
+        );
+        assert_eq!(
+            PositionMode::Hedge.position_side_for(Side::Sell, false),
+            FuturesPositionSide::Short
+        );
+        // 헤지 모드 청산: 매도는 롱 청산, 매수는 숏 청산
+        assert_eq!(
+            PositionMode::Hedge.position_side_for(Side::Sell, true),
+            FuturesPositionSide::Long
+        );
+        assert_eq!(
+            PositionMode::Hedge.position_side_for(Side::Buy, true),
+            FuturesPositionSide::Short
+        );
+    }
+
+    #[test]
+    fn test_futures_order_params_reduce_only() {
+        let client = test_client();
+        let request =
+            OrderRequest::market_sell("BTC/USDT".to_string(), Decimal::ONE).with_reduce_only(true);
+
+        let one_way = client.futures_order_params(&request, FuturesPositionSide::Both);
+        assert!(one_way.contains(&("symbol", "BTCUSDT".to_string())));
+        assert!(one_way.contains(&("reduceOnly", "true".to_string())));
+        assert!(!one_way.iter().any(|(k, _)| *k == "positionSide"));
+
+        // 헤지 모드는 reduceOnly 대신 positionSide 전달
+        let hedge = client.futures_order_params(&request, FuturesPositionSide::Long);
+        assert!(hedge.contains(&("positionSide", "LONG".to_string())));
+        assert!(!hedge.iter().any(|(k, _)| *k == "reduceOnly"));
+    }
+
+    #[test]
+    fn test_futures_order_params_stop_type() {
+        let client = test_client();
+        let mut request = OrderRequest::market_sell("ETH/USDT".to_string(), Decimal::ONE);
+        request.order_type = OrderType::StopLoss;
+        request.stop_price = Some(Decimal::from(3000));
+
+        let params = client.futures_order_params(&request, FuturesPositionSide::Both);
+        assert!(params.contains(&("type", "STOP_MARKET".to_string())));
+        assert!(params.contains(&("stopPrice", "3000".to_string())));
+        assert!(!params.iter().any(|(k, _)| *k == "reduceOnly"));
+    }
+
+    #[test]
+    fn test_parse_position_risk_margin() {
+        let raw = r#"{
+            "symbol": "BTCUSDT", "positionAmt": "-0.5", "entryPrice": "60000",
+            "markPrice": "59000", "unRealizedProfit": "500", "liquidationPrice": "70000",
+            "leverage": "10", "marginType": "cross", "isolatedMargin": "0",
+            "notional": "-29500", "positionSide": "BOTH"
+        }"#;
+        let resp: BinancePositionRisk = serde_json::from_str(raw).unwrap();
+        let position = BinanceClient::parse_position_risk(&resp);
+
+        assert_eq!(position.ticker, "BTC/USDT");
+        assert!(position.is_open());
+        assert_eq!(position.side(), Side::Sell);
+        assert_eq!(position.liquidation_price, Decimal::from(70000));
+        assert_eq!(position.margin, Decimal::from(2950));
+
+        let isolated: BinancePositionRisk = serde_json::from_str(
+            &raw.replace(r#""cross""#, r#""isolated""#)
+                .replace(r#""isolatedMargin": "0""#, r#""isolatedMargin": "3100.5""#),
+        )
+        .unwrap();
+        let position = BinanceClient::parse_position_risk(&isolated);
+        assert!(position.isolated);
+        assert_eq!(position.margin, "3100.5".parse::<Decimal>().unwrap());
+    }
 }
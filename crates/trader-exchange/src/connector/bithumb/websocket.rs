@@ -1,31 +1,149 @@
-use std::{str::FromStr, sync::Arc, time::Duration};
+//! 빗썸 공개 WebSocket 클라이언트.
+//!
+//! 티커(`ticker`), 체결(`transaction`), 호가(`orderbooksnapshot` + `orderbookdepth`)를 구독합니다.
+//!
+//! # 호가 병합
+//!
+//! 빗썸 `orderbookdepth`는 변경된 가격 레벨만 전송하며, 수량은 증감분이 아니라
+//! 해당 레벨의 잔량(누적)입니다. 수량이 0이면 레벨이 제거됩니다.
+//! 구독 시 `orderbooksnapshot`으로 전체 호가를 받은 뒤 [`BithumbOrderbook`]에
+//! 델타를 병합하여 항상 완전한 호가창을 내보냅니다.
 
-use chrono::Utc;
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+    time::Duration,
+};
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Asia::Seoul;
 use futures::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
 use serde_json::json;
-use tokio::{
-    sync::{mpsc, RwLock},
-    time::interval,
-};
+use tokio::{sync::mpsc, time::interval};
 use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
-use tracing::{error, info};
-use trader_core::{ProviderError, QuoteData};
+use tracing::{debug, error, info, warn};
+use trader_core::{OrderBook, OrderBookLevel, QuoteData, Side, TradeTick};
+
+use crate::ExchangeError;
 
 const BITHUMB_WS_URL: &str = "wss://api.bithumb.com/pub/ws";
 const MAX_RECONNECT_ATTEMPTS: u32 = 5;
 const RECONNECT_DELAY: Duration = Duration::from_secs(5);
 
+/// 호가창으로 내보낼 최대 레벨 수 (빗썸 스냅샷 깊이).
+const ORDERBOOK_DEPTH: usize = 30;
+
 #[derive(Debug, Clone)]
 pub enum BithumbWsMessage {
     Ticker(QuoteData),
+    /// 체결
+    Trade(TradeTick),
+    /// 병합된 호가창
+    Orderbook(OrderBook),
+    /// 연결 상태 변경
+    ConnectionStatus(bool),
     Error(String),
 }
 
 #[derive(Debug)]
 pub enum BithumbWsCommand {
     SubscribeTicker(Vec<String>),
-    UnsubscribeTicker(Vec<String>),
+    SubscribeTrade(Vec<String>),
+    SubscribeOrderbook(Vec<String>),
+    /// 모든 채널에서 구독 해제
+    Unsubscribe(Vec<String>),
+}
+
+/// 호가 델타 한 건 (`orderbookdepth`의 list 항목).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BithumbDepthUpdate {
+    pub symbol: String,
+    pub side: Side,
+    pub price: Decimal,
+    /// 해당 가격 레벨의 잔량 (0이면 레벨 제거)
+    pub quantity: Decimal,
+}
+
+/// 스냅샷과 델타를 병합하는 심볼별 로컬 호가창.
+#[derive(Debug, Clone, Default)]
+pub struct BithumbOrderbook {
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    /// 마지막으로 반영한 메시지 시각 (마이크로초)
+    last_update_us: i64,
+    has_snapshot: bool,
+}
+
+impl BithumbOrderbook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 스냅샷 수신 여부 (스냅샷 전에는 일부 레벨만 알고 있음).
+    pub fn has_snapshot(&self) -> bool {
+        self.has_snapshot
+    }
+
+    /// 전체 호가로 교체.
+    pub fn apply_snapshot(
+        &mut self,
+        bids: &[(Decimal, Decimal)],
+        asks: &[(Decimal, Decimal)],
+        datetime_us: i64,
+    ) {
+        self.bids = bids
+            .iter()
+            .filter(|(_, qty)| !qty.is_zero())
+            .copied()
+            .collect();
+        self.asks = asks
+            .iter()
+            .filter(|(_, qty)| !qty.is_zero())
+            .copied()
+            .collect();
+        self.last_update_us = datetime_us;
+        self.has_snapshot = true;
+    }
+
+    /// 델타 병합.
+    ///
+    /// 스냅샷보다 오래된 메시지는 무시하고 `false`를 반환합니다.
+    pub fn apply_depth(&mut self, updates: &[BithumbDepthUpdate], datetime_us: i64) -> bool {
+        if datetime_us < self.last_update_us {
+            return false;
+        }
+
+        for update in updates {
+            let levels = match update.side {
+                Side::Buy => &mut self.bids,
+                Side::Sell => &mut self.asks,
+            };
+            if update.quantity.is_zero() {
+                levels.remove(&update.price);
+            } else {
+                levels.insert(update.price, update.quantity);
+            }
+        }
+        self.last_update_us = datetime_us;
+        true
+    }
+
+    /// 정렬된 호가창 생성 (매수 내림차순, 매도 오름차순).
+    pub fn to_order_book(&self, ticker: &str, depth: usize) -> OrderBook {
+        let level = |(price, quantity): (&Decimal, &Decimal)| OrderBookLevel {
+            price: *price,
+            quantity: *quantity,
+        };
+
+        OrderBook {
+            ticker: ticker.to_string(),
+            bids: self.bids.iter().rev().take(depth).map(level).collect(),
+            asks: self.asks.iter().take(depth).map(level).collect(),
+            timestamp: DateTime::from_timestamp_micros(self.last_update_us)
+                .unwrap_or_else(Utc::now),
+        }
+    }
 }
 
 pub struct BithumbWebSocket {
@@ -33,7 +151,11 @@ pub struct BithumbWebSocket {
     rx: Option<mpsc::Receiver<BithumbWsMessage>>,
     command_tx: mpsc::Sender<BithumbWsCommand>,
     command_rx: Option<mpsc::Receiver<BithumbWsCommand>>,
-    subscribed_tickers: Arc<RwLock<Vec<String>>>,
+    subscribed_tickers: Vec<String>,
+    subscribed_trades: Vec<String>,
+    subscribed_orderbooks: Vec<String>,
+    /// 수립된 연결 세션 수 (`connect_once` 결과 판별용)
+    sessions_established: u64,
 }
 
 impl Default for BithumbWebSocket {
@@ -51,7 +173,10 @@ impl BithumbWebSocket {
             rx: Some(rx),
             command_tx: cmd_tx,
             command_rx: Some(cmd_rx),
-            subscribed_tickers: Arc::new(RwLock::new(Vec::new())),
+            subscribed_tickers: Vec::new(),
+            subscribed_trades: Vec::new(),
+            subscribed_orderbooks: Vec::new(),
+            sessions_established: 0,
         }
     }
 
@@ -63,12 +188,33 @@ impl BithumbWebSocket {
         self.command_tx.clone()
     }
 
+    /// 티커 구독 추가 (연결 시 일괄 전송).
+    pub fn add_ticker_subscription(&mut self, symbol: &str) {
+        add_unique(&mut self.subscribed_tickers, symbol);
+    }
+
+    /// 체결 구독 추가 (연결 시 일괄 전송).
+    pub fn add_trade_subscription(&mut self, symbol: &str) {
+        add_unique(&mut self.subscribed_trades, symbol);
+    }
+
+    /// 호가 구독 추가 (연결 시 일괄 전송).
+    pub fn add_orderbook_subscription(&mut self, symbol: &str) {
+        add_unique(&mut self.subscribed_orderbooks, symbol);
+    }
+
+    /// 모든 구독 제거 (재연결 전 구독 재구성용).
+    pub fn clear_subscriptions(&mut self) {
+        self.subscribed_tickers.clear();
+        self.subscribed_trades.clear();
+        self.subscribed_orderbooks.clear();
+    }
+
     pub async fn connect(&mut self) {
         let mut attempts = 0;
-        let mut cmd_rx = self.command_rx.take().expect("command_rx already taken");
 
         loop {
-            match self.run_session(&mut cmd_rx).await {
+            match self.run_session().await {
                 Ok(_) => {
                     info!("Bithumb WebSocket session ended normally.");
                     break;
@@ -91,22 +237,68 @@ impl BithumbWebSocket {
         }
     }
 
-    async fn run_session(
-        &self,
-        cmd_rx: &mut mpsc::Receiver<BithumbWsCommand>,
-    ) -> Result<(), ProviderError> {
+    /// 단일 연결 세션 실행 (재연결 없음).
+    ///
+    /// 세션이 수립되었다가 끊기면 `Ok(())`, 연결 수립 자체에 실패하면 `Err`를 반환합니다.
+    /// 재연결 정책은 호출자(`BithumbMarketStream`)가 결정합니다.
+    pub async fn connect_once(&mut self) -> Result<(), ExchangeError> {
+        let sessions_before = self.sessions_established;
+        let result = self.run_session().await;
+
+        if self.sessions_established > sessions_before {
+            Ok(())
+        } else {
+            result
+        }
+    }
+
+    async fn run_session(&mut self) -> Result<(), ExchangeError> {
         let (ws_stream, _) = connect_async(BITHUMB_WS_URL)
             .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
         let (mut ws_tx, mut ws_rx) = ws_stream.split();
+        self.sessions_established += 1;
+        let _ = self.tx.send(BithumbWsMessage::ConnectionStatus(true)).await;
         info!("Connected to Bithumb WebSocket");
 
+        // command_rx를 take하여 이 연결 세션에서 사용
+        let mut cmd_rx = self.command_rx.take().unwrap_or_else(|| {
+            let (tx, rx) = mpsc::channel(10);
+            self.command_tx = tx;
+            rx
+        });
+
+        let result = self.session_loop(&mut ws_tx, &mut ws_rx, &mut cmd_rx).await;
+
+        // 연결 종료 시 command_rx를 복원하여 재연결에서 재사용
+        self.command_rx = Some(cmd_rx);
+        let _ = self
+            .tx
+            .send(BithumbWsMessage::ConnectionStatus(false))
+            .await;
+
+        result
+    }
+
+    async fn session_loop<S, R>(
+        &mut self,
+        ws_tx: &mut S,
+        ws_rx: &mut R,
+        cmd_rx: &mut mpsc::Receiver<BithumbWsCommand>,
+    ) -> Result<(), ExchangeError>
+    where
+        S: futures::Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+        R: futures::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+    {
         // 접속 안정화 대기 (서버 초기화 완료 대기)
         tokio::time::sleep(Duration::from_millis(200)).await;
 
-        self.send_subscription(&mut ws_tx).await?;
+        // 기존 구독 복원
+        self.send_subscription(ws_tx).await?;
 
+        // 호가창은 세션마다 스냅샷부터 다시 구성
+        let mut orderbooks: HashMap<String, BithumbOrderbook> = HashMap::new();
         let mut ping_interval = interval(Duration::from_secs(30));
 
         loop {
@@ -115,40 +307,43 @@ impl BithumbWebSocket {
                     match msg {
                         Ok(Message::Text(text)) => {
                             if let Ok(val) = serde_json::from_str::<serde_json::Value>(&text) {
-                                if val["type"] == "ticker" {
-                                    if let Some(quote) = self.parse_ticker(&val["content"]) {
-                                        let _ = self.tx.send(BithumbWsMessage::Ticker(quote)).await;
-                                    }
-                                }
+                                self.handle_message(&val, &mut orderbooks).await;
                             }
                         }
                         Ok(Message::Ping(_)) => {
                             let _ = ws_tx.send(Message::Pong(vec![])).await;
                         }
                         Ok(Message::Close(_)) => break,
-                        Err(e) => return Err(ProviderError::Network(e.to_string())),
+                        Err(e) => return Err(ExchangeError::NetworkError(e.to_string())),
                         _ => {}
                     }
                 }
                 Some(cmd) = cmd_rx.recv() => {
                     match cmd {
                         BithumbWsCommand::SubscribeTicker(codes) => {
-                            let mut subs = self.subscribed_tickers.write().await;
-                            for code in codes {
-                                if !subs.contains(&code) {
-                                    subs.push(code);
-                                }
+                            for code in &codes {
+                                self.add_ticker_subscription(code);
+                            }
+                        }
+                        BithumbWsCommand::SubscribeTrade(codes) => {
+                            for code in &codes {
+                                self.add_trade_subscription(code);
+                            }
+                        }
+                        BithumbWsCommand::SubscribeOrderbook(codes) => {
+                            for code in &codes {
+                                self.add_orderbook_subscription(code);
                             }
-                            drop(subs);
-                            self.send_subscription(&mut ws_tx).await?;
                         }
-                        BithumbWsCommand::UnsubscribeTicker(codes) => {
-                            let mut subs = self.subscribed_tickers.write().await;
-                            subs.retain(|c| !codes.contains(c));
-                            drop(subs);
-                            self.send_subscription(&mut ws_tx).await?;
+                        BithumbWsCommand::Unsubscribe(codes) => {
+                            self.subscribed_tickers.retain(|c| !codes.contains(c));
+                            self.subscribed_trades.retain(|c| !codes.contains(c));
+                            self.subscribed_orderbooks.retain(|c| !codes.contains(c));
+                            orderbooks.retain(|c, _| !codes.contains(c));
                         }
                     }
+                    // 빗썸은 채널별 마지막 구독 요청을 기준으로 필터링하므로 전체 목록을 재전송
+                    self.send_subscription(ws_tx).await?;
                 }
                 _ = ping_interval.tick() => {
                     let _ = ws_tx.send(Message::Ping(vec![])).await;
@@ -158,25 +353,110 @@ impl BithumbWebSocket {
         Ok(())
     }
 
-    async fn send_subscription<S>(&self, ws_tx: &mut S) -> Result<(), ProviderError>
+    async fn handle_message(
+        &self,
+        val: &serde_json::Value,
+        orderbooks: &mut HashMap<String, BithumbOrderbook>,
+    ) {
+        let content = &val["content"];
+        match val["type"].as_str() {
+            Some("ticker") => {
+                if let Some(quote) = self.parse_ticker(content) {
+                    let _ = self.tx.send(BithumbWsMessage::Ticker(quote)).await;
+                }
+            }
+            Some("transaction") => {
+                for tick in parse_transactions(content) {
+                    let _ = self.tx.send(BithumbWsMessage::Trade(tick)).await;
+                }
+            }
+            Some("orderbooksnapshot") => {
+                let Some((symbol, bids, asks, datetime_us)) = parse_orderbook_snapshot(content)
+                else {
+                    return;
+                };
+                let book = orderbooks.entry(symbol.clone()).or_default();
+                book.apply_snapshot(&bids, &asks, datetime_us);
+                let _ = self
+                    .tx
+                    .send(BithumbWsMessage::Orderbook(
+                        book.to_order_book(&symbol, ORDERBOOK_DEPTH),
+                    ))
+                    .await;
+            }
+            Some("orderbookdepth") => {
+                let (updates, datetime_us) = parse_orderbook_depth(content);
+                let mut by_symbol: HashMap<&str, Vec<BithumbDepthUpdate>> = HashMap::new();
+                for update in &updates {
+                    by_symbol
+                        .entry(update.symbol.as_str())
+                        .or_default()
+                        .push(update.clone());
+                }
+
+                for (symbol, updates) in by_symbol {
+                    let book = orderbooks.entry(symbol.to_string()).or_default();
+                    if !book.apply_depth(&updates, datetime_us) {
+                        debug!("Bithumb 지난 호가 델타 무시: {}", symbol);
+                        continue;
+                    }
+                    // 스냅샷 전에는 일부 레벨만 알고 있으므로 내보내지 않음
+                    if book.has_snapshot() {
+                        let _ = self
+                            .tx
+                            .send(BithumbWsMessage::Orderbook(
+                                book.to_order_book(symbol, ORDERBOOK_DEPTH),
+                            ))
+                            .await;
+                    }
+                }
+            }
+            _ => {
+                if val["status"].as_str().is_some_and(|s| s != "0000") {
+                    warn!("Bithumb WebSocket 응답: {}", val);
+                }
+            }
+        }
+    }
+
+    async fn send_subscription<S>(&self, ws_tx: &mut S) -> Result<(), ExchangeError>
     where
         S: futures::Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
     {
-        let codes = self.subscribed_tickers.read().await;
-        if codes.is_empty() {
-            return Ok(());
+        let mut messages = Vec::new();
+
+        if !self.subscribed_tickers.is_empty() {
+            messages.push(json!({
+                "type": "ticker",
+                "symbols": self.subscribed_tickers,
+                "tickTypes": ["24H"]
+            }));
         }
 
-        let msg = json!({
-            "type": "ticker",
-            "symbols": *codes,
-            "tickTypes": ["24H"]
-        });
+        if !self.subscribed_trades.is_empty() {
+            messages.push(json!({
+                "type": "transaction",
+                "symbols": self.subscribed_trades
+            }));
+        }
 
-        ws_tx
-            .send(Message::Text(msg.to_string()))
-            .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
+        if !self.subscribed_orderbooks.is_empty() {
+            messages.push(json!({
+                "type": "orderbooksnapshot",
+                "symbols": self.subscribed_orderbooks
+            }));
+            messages.push(json!({
+                "type": "orderbookdepth",
+                "symbols": self.subscribed_orderbooks
+            }));
+        }
+
+        for msg in messages {
+            ws_tx
+                .send(Message::Text(msg.to_string()))
+                .await
+                .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+        }
 
         Ok(())
     }
@@ -207,3 +487,227 @@ impl BithumbWebSocket {
         })
     }
 }
+
+fn add_unique(list: &mut Vec<String>, symbol: &str) {
+    if !list.iter().any(|s| s == symbol) {
+        list.push(symbol.to_string());
+    }
+}
+
+fn decimal_field(value: &serde_json::Value) -> Option<Decimal> {
+    match value {
+        serde_json::Value::String(s) => Decimal::from_str(s).ok(),
+        serde_json::Value::Number(n) => Decimal::from_str(&n.to_string()).ok(),
+        _ => None,
+    }
+}
+
+/// 빗썸 `datetime` 필드 (마이크로초 문자열) 파싱.
+fn parse_datetime_us(value: &serde_json::Value) -> Option<i64> {
+    match value {
+        serde_json::Value::String(s) => s.parse().ok(),
+        serde_json::Value::Number(n) => n.as_i64(),
+        _ => None,
+    }
+}
+
+/// `transaction` 메시지를 체결 틱으로 변환.
+///
+/// `buySellGb`: 1 = 매도 체결, 2 = 매수 체결. `contDtm`은 KST 기준입니다.
+fn parse_transactions(content: &serde_json::Value) -> Vec<TradeTick> {
+    let Some(list) = content["list"].as_array() else {
+        return Vec::new();
+    };
+
+    list.iter()
+        .filter_map(|item| {
+            let symbol = item["symbol"].as_str()?.to_string();
+            let price = decimal_field(&item["contPrice"])?;
+            let quantity = decimal_field(&item["contQty"])?;
+            let side = match item["buySellGb"].as_str()? {
+                "1" => Side::Sell,
+                "2" => Side::Buy,
+                _ => return None,
+            };
+            let cont_dtm = item["contDtm"].as_str().unwrap_or_default();
+            let timestamp = NaiveDateTime::parse_from_str(cont_dtm, "%Y-%m-%d %H:%M:%S%.f")
+                .ok()
+                .and_then(|dt| Seoul.from_local_datetime(&dt).single())
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or_else(Utc::now);
+
+            Some(TradeTick {
+                id: format!("{}-{}", symbol, cont_dtm),
+                ticker: symbol,
+                price,
+                quantity,
+                side,
+                timestamp,
+            })
+        })
+        .collect()
+}
+
+type SnapshotLevels = Vec<(Decimal, Decimal)>;
+
+/// `orderbooksnapshot` 메시지 파싱 (`[[가격, 수량], ...]` 형식).
+fn parse_orderbook_snapshot(
+    content: &serde_json::Value,
+) -> Option<(String, SnapshotLevels, SnapshotLevels, i64)> {
+    let symbol = content["symbol"].as_str()?.to_string();
+    let levels = |value: &serde_json::Value| -> SnapshotLevels {
+        value
+            .as_array()
+            .map(|rows| {
+                rows.iter()
+                    .filter_map(|row| Some((decimal_field(&row[0])?, decimal_field(&row[1])?)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let datetime_us = parse_datetime_us(&content["datetime"]).unwrap_or_default();
+
+    Some((
+        symbol,
+        levels(&content["bids"]),
+        levels(&content["asks"]),
+        datetime_us,
+    ))
+}
+
+/// `orderbookdepth` 메시지 파싱.
+fn parse_orderbook_depth(content: &serde_json::Value) -> (Vec<BithumbDepthUpdate>, i64) {
+    let updates = content["list"]
+        .as_array()
+        .map(|list| {
+            list.iter()
+                .filter_map(|item| {
+                    let side = match item["orderType"].as_str()? {
+                        "bid" => Side::Buy,
+                        "ask" => Side::Sell,
+                        _ => return None,
+                    };
+                    Some(BithumbDepthUpdate {
+                        symbol: item["symbol"].as_str()?.to_string(),
+                        side,
+                        price: decimal_field(&item["price"])?,
+                        quantity: decimal_field(&item["quantity"])?,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    (
+        updates,
+        parse_datetime_us(&content["datetime"]).unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn depth(side: Side, price: Decimal, quantity: Decimal) -> BithumbDepthUpdate {
+        BithumbDepthUpdate {
+            symbol: "BTC_KRW".to_string(),
+            side,
+            price,
+            quantity,
+        }
+    }
+
+    #[test]
+    fn test_depth_merges_into_snapshot() {
+        let mut book = BithumbOrderbook::new();
+        book.apply_snapshot(
+            &[(dec!(100), dec!(1)), (dec!(99), dec!(2))],
+            &[(dec!(101), dec!(1.5)), (dec!(102), dec!(3))],
+            1_000,
+        );
+
+        // 잔량 교체, 레벨 제거, 신규 레벨 추가
+        assert!(book.apply_depth(
+            &[
+                depth(Side::Buy, dec!(100), dec!(0.4)),
+                depth(Side::Sell, dec!(101), dec!(0)),
+                depth(Side::Buy, dec!(100.5), dec!(7)),
+            ],
+            2_000,
+        ));
+
+        let ob = book.to_order_book("BTC_KRW", 30);
+        let bids: Vec<_> = ob.bids.iter().map(|l| (l.price, l.quantity)).collect();
+        let asks: Vec<_> = ob.asks.iter().map(|l| (l.price, l.quantity)).collect();
+        assert_eq!(
+            bids,
+            vec![
+                (dec!(100.5), dec!(7)),
+                (dec!(100), dec!(0.4)),
+                (dec!(99), dec!(2))
+            ]
+        );
+        assert_eq!(asks, vec![(dec!(102), dec!(3))]);
+        assert_eq!(ob.timestamp.timestamp_micros(), 2_000);
+    }
+
+    #[test]
+    fn test_stale_depth_and_resnapshot() {
+        let mut book = BithumbOrderbook::new();
+
+        // 스냅샷 전 델타는 병합하되 스냅샷이 오면 교체됨
+        assert!(book.apply_depth(&[depth(Side::Sell, dec!(105), dec!(1))], 500));
+        assert!(!book.has_snapshot());
+
+        book.apply_snapshot(&[(dec!(100), dec!(1))], &[(dec!(101), dec!(1))], 1_000);
+        assert!(book.has_snapshot());
+
+        // 스냅샷보다 오래된 델타는 무시
+        assert!(!book.apply_depth(&[depth(Side::Buy, dec!(100), dec!(0))], 900));
+
+        let ob = book.to_order_book("BTC_KRW", 30);
+        assert_eq!(ob.best_bid(), Some(dec!(100)));
+        assert_eq!(ob.asks.len(), 1);
+        assert_eq!(ob.asks[0].price, dec!(101));
+    }
+
+    #[test]
+    fn test_parse_depth_and_transaction_messages() {
+        let depth_msg: serde_json::Value = serde_json::from_str(
+            r#"{"type":"orderbookdepth","content":{"list":[
+                {"symbol":"BTC_KRW","orderType":"ask","price":"10593000","quantity":"1.11223318","total":"3"},
+                {"symbol":"BTC_KRW","orderType":"bid","price":"10532000","quantity":"0","total":"0"}
+            ],"datetime":"1580268255864325"}}"#,
+        )
+        .unwrap();
+        let (updates, datetime_us) = parse_orderbook_depth(&depth_msg["content"]);
+        assert_eq!(datetime_us, 1_580_268_255_864_325);
+        assert_eq!(
+            updates,
+            vec![
+                depth(Side::Sell, dec!(10593000), dec!(1.11223318)),
+                depth(Side::Buy, dec!(10532000), dec!(0)),
+            ]
+        );
+
+        let tx_msg: serde_json::Value = serde_json::from_str(
+            r#"{"type":"transaction","content":{"list":[
+                {"symbol":"BTC_KRW","buySellGb":"1","contPrice":"10579000","contQty":"0.01",
+                 "contAmt":"105790.00","contDtm":"2020-01-29 12:24:18.830039","updn":"dn"}
+            ]}}"#,
+        )
+        .unwrap();
+        let ticks = parse_transactions(&tx_msg["content"]);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].side, Side::Sell);
+        assert_eq!(ticks[0].price, dec!(10579000));
+        assert_eq!(ticks[0].quantity, dec!(0.01));
+        // KST 12:24 → UTC 03:24
+        assert_eq!(
+            ticks[0].timestamp.format("%H:%M:%S").to_string(),
+            "03:24:18"
+        );
+    }
+}
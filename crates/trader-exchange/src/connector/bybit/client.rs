@@ -0,0 +1,1152 @@
+//! Bybit V5 통합 API 클라이언트.
+//!
+//! 현물(spot)과 USDT 무기한(linear)을 같은 V5 엔드포인트로 다룹니다.
+//!
+//! # 인증
+//!
+//! 서명 문자열은 `timestamp + api_key + recv_window + (GET 쿼리 | POST JSON 본문)`이며
+//! HMAC-SHA256(hex)으로 서명해 `X-BAPI-*` 헤더로 전달합니다.
+//!
+//! # 시간 동기화
+//!
+//! 요청 타임스탬프가 서버 시각과 `recv_window` 이상 어긋나면 거부(10002)되므로
+//! `/v5/market/time`으로 서버 시각과의 오프셋을 주기적으로 보정하고,
+//! 10002 응답을 받으면 즉시 재동기화 후 한 번 재시도합니다.
+
+use std::{
+    str::FromStr,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::{Client, Method};
+use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Deserialize};
+use sha2::Sha256;
+use tracing::{debug, warn};
+use trader_core::{
+    domain::{
+        ExchangeProvider, MarketDataProvider, OrderRequest, OrderStatusType, OrderType,
+        PendingOrder, Side, StrategyAccountInfo, StrategyPositionInfo, TimeInForce,
+    },
+    ProviderError, QuoteData,
+};
+
+use crate::request_log::{send_logged, RequestLogger};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 메인넷 REST URL
+const MAINNET_URL: &str = "https://api.bybit.com";
+/// 테스트넷 REST URL
+const TESTNET_URL: &str = "https://api-testnet.bybit.com";
+/// 서버 시각 재동기화 주기 (밀리초)
+const TIME_SYNC_INTERVAL_MS: i64 = 30 * 60 * 1000;
+/// 체결 내역 조회 최대 기간 (Bybit 제한: 7일)
+const MAX_EXECUTION_RANGE_DAYS: i64 = 7;
+/// 타임스탬프 오류 응답 코드
+const RET_CODE_TIMESTAMP: i64 = 10002;
+/// 현물 포지션에서 제외할 결제 통화
+const QUOTE_COINS: [&str; 3] = ["USDT", "USDC", "USD"];
+
+// ============================================================================
+// 설정
+// ============================================================================
+
+/// 거래 카테고리.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BybitCategory {
+    /// 현물
+    #[default]
+    Spot,
+    /// USDT 무기한 선물
+    Linear,
+}
+
+impl BybitCategory {
+    /// V5 API `category` 값.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BybitCategory::Spot => "spot",
+            BybitCategory::Linear => "linear",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct BybitConfig {
+    pub api_key: String,
+    pub api_secret: String,
+    /// 테스트넷 사용 여부
+    pub testnet: bool,
+    /// 거래 카테고리
+    pub category: BybitCategory,
+    /// 요청 유효 시간 (밀리초)
+    pub recv_window: u64,
+}
+
+impl std::fmt::Debug for BybitConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BybitConfig")
+            .field("api_key", &"***")
+            .field("api_secret", &"***")
+            .field("testnet", &self.testnet)
+            .field("category", &self.category)
+            .field("recv_window", &self.recv_window)
+            .finish()
+    }
+}
+
+impl BybitConfig {
+    pub fn new(api_key: String, api_secret: String) -> Self {
+        Self {
+            api_key,
+            api_secret,
+            testnet: false,
+            category: BybitCategory::Spot,
+            recv_window: 5000,
+        }
+    }
+
+    /// 테스트넷 사용 설정.
+    pub fn with_testnet(mut self, testnet: bool) -> Self {
+        self.testnet = testnet;
+        self
+    }
+
+    /// 거래 카테고리 설정.
+    pub fn with_category(mut self, category: BybitCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// REST 기본 URL.
+    pub fn base_url(&self) -> &'static str {
+        if self.testnet {
+            TESTNET_URL
+        } else {
+            MAINNET_URL
+        }
+    }
+}
+
+// ============================================================================
+// API 응답 타입
+// ============================================================================
+
+/// V5 공통 응답 래퍼.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitResponse<T> {
+    pub ret_code: i64,
+    pub ret_msg: String,
+    pub result: Option<T>,
+    #[serde(default)]
+    pub time: i64,
+}
+
+/// 목록 응답 (`result.list`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitList<T> {
+    pub list: Vec<T>,
+    #[serde(default)]
+    pub next_page_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitServerTime {
+    pub time_second: String,
+    pub time_nano: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWalletBalance {
+    pub total_equity: String,
+    pub total_available_balance: String,
+    #[serde(default)]
+    pub total_initial_margin: String,
+    #[serde(default, rename = "totalPerpUPL")]
+    pub total_perp_upl: String,
+    #[serde(default)]
+    pub coin: Vec<BybitCoinBalance>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitCoinBalance {
+    pub coin: String,
+    pub wallet_balance: String,
+    #[serde(default)]
+    pub usd_value: String,
+    #[serde(default)]
+    pub unrealised_pnl: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPosition {
+    pub symbol: String,
+    /// "Buy" / "Sell" / "" (포지션 없음)
+    pub side: String,
+    pub size: String,
+    pub avg_price: String,
+    #[serde(default)]
+    pub mark_price: String,
+    #[serde(default)]
+    pub unrealised_pnl: String,
+    #[serde(default)]
+    pub liq_price: String,
+    #[serde(default, rename = "positionIM")]
+    pub position_im: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitOrder {
+    pub order_id: String,
+    #[serde(default)]
+    pub order_link_id: String,
+    pub symbol: String,
+    pub side: String,
+    pub price: String,
+    pub qty: String,
+    #[serde(default)]
+    pub cum_exec_qty: String,
+    pub order_status: String,
+    pub created_time: String,
+}
+
+/// 주문 생성/취소/정정 응답.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitOrderAck {
+    pub order_id: String,
+    #[serde(default)]
+    pub order_link_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitExecution {
+    pub symbol: String,
+    pub order_id: String,
+    #[serde(default)]
+    pub order_link_id: String,
+    pub side: String,
+    pub exec_id: String,
+    pub exec_price: String,
+    pub exec_qty: String,
+    #[serde(default)]
+    pub exec_fee: String,
+    #[serde(default)]
+    pub fee_currency: String,
+    pub exec_time: String,
+    #[serde(default)]
+    pub is_maker: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitTicker {
+    pub symbol: String,
+    pub last_price: String,
+    #[serde(default)]
+    pub prev_price24h: String,
+    #[serde(default)]
+    pub price24h_pcnt: String,
+    #[serde(default)]
+    pub high_price24h: String,
+    #[serde(default)]
+    pub low_price24h: String,
+    #[serde(default)]
+    pub volume24h: String,
+    #[serde(default)]
+    pub turnover24h: String,
+}
+
+// ============================================================================
+// Bybit 클라이언트
+// ============================================================================
+
+pub struct BybitClient {
+    client: Client,
+    config: BybitConfig,
+    base_url: String,
+    /// 서버 시각 - 로컬 시각 (밀리초)
+    time_offset_ms: AtomicI64,
+    /// 마지막 시간 동기화 시각 (로컬 밀리초, 0이면 미동기화)
+    last_sync_ms: AtomicI64,
+    /// 요청/응답 추적 로거 (옵션)
+    request_logger: Option<Arc<RequestLogger>>,
+}
+
+impl BybitClient {
+    pub fn new(config: BybitConfig) -> Self {
+        let base_url = config.base_url().to_string();
+        Self {
+            client: Client::new(),
+            config,
+            base_url,
+            time_offset_ms: AtomicI64::new(0),
+            last_sync_ms: AtomicI64::new(0),
+            request_logger: None,
+        }
+    }
+
+    /// 기본 URL 변경 (테스트/프록시용).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// 요청/응답 추적 로거를 설정합니다.
+    ///
+    /// 설정 시 모든 REST 요청이 마스킹된 상태로 기록됩니다.
+    pub fn with_request_logger(mut self, logger: Arc<RequestLogger>) -> Self {
+        self.request_logger = Some(logger);
+        self
+    }
+
+    /// 거래 카테고리.
+    pub fn category(&self) -> BybitCategory {
+        self.config.category
+    }
+
+    /// 현재 서버 시각 오프셋 (밀리초).
+    pub fn time_offset_ms(&self) -> i64 {
+        self.time_offset_ms.load(Ordering::Relaxed)
+    }
+
+    fn local_ms() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as i64
+    }
+
+    /// 서버 시각 기준 타임스탬프 (밀리초).
+    fn timestamp_ms(&self) -> i64 {
+        Self::local_ms() + self.time_offset_ms()
+    }
+
+    /// 서버 시각과 동기화.
+    ///
+    /// 왕복 지연의 절반을 보정한 서버 시각과 로컬 시각의 차이를 저장합니다.
+    pub async fn sync_time(&self) -> Result<i64, ProviderError> {
+        let sent = Self::local_ms();
+        let server: BybitServerTime = self.public_get("/v5/market/time", &[]).await?;
+        let received = Self::local_ms();
+
+        let server_ms = server_time_ms(&server)?;
+        let offset = server_ms - (sent + received) / 2;
+
+        self.time_offset_ms.store(offset, Ordering::Relaxed);
+        self.last_sync_ms.store(received, Ordering::Relaxed);
+        debug!(offset_ms = offset, "Bybit 서버 시각 동기화");
+        Ok(offset)
+    }
+
+    /// 동기화 주기가 지났으면 재동기화 (실패 시 기존 오프셋 유지).
+    async fn ensure_time_synced(&self) {
+        let last = self.last_sync_ms.load(Ordering::Relaxed);
+        if last == 0 || Self::local_ms() - last > TIME_SYNC_INTERVAL_MS {
+            if let Err(e) = self.sync_time().await {
+                warn!("Bybit 서버 시각 동기화 실패 (기존 오프셋 사용): {}", e);
+            }
+        }
+    }
+
+    /// HMAC-SHA256 서명.
+    fn sign(&self, timestamp: i64, payload: &str) -> String {
+        let message = format!(
+            "{}{}{}{}",
+            timestamp, self.config.api_key, self.config.recv_window, payload
+        );
+        let mut mac =
+            HmacSha256::new_from_slice(self.config.api_secret.as_bytes()).expect("Invalid key");
+        mac.update(message.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn build_query(params: &[(&str, String)]) -> String {
+        params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// 공개 API GET.
+    async fn public_get<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, String)],
+    ) -> Result<T, ProviderError> {
+        let query = Self::build_query(params);
+        let url = if query.is_empty() {
+            format!("{}{}", self.base_url, endpoint)
+        } else {
+            format!("{}{}?{}", self.base_url, endpoint, query)
+        };
+
+        let (_, body) = send_logged(
+            self.request_logger.as_deref(),
+            "bybit",
+            self.client.get(&url),
+        )
+        .await
+        .map_err(|e| ProviderError::Network(e.to_string()))?;
+
+        parse_response(&body)
+    }
+
+    /// 서명된 요청 (타임스탬프 오류 시 재동기화 후 1회 재시도).
+    async fn signed_request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(&str, String)],
+        body: Option<&serde_json::Value>,
+    ) -> Result<T, ProviderError> {
+        self.ensure_time_synced().await;
+
+        match self
+            .send_signed::<T>(method.clone(), endpoint, params, body)
+            .await
+        {
+            Err(BybitRequestError::Timestamp(msg)) => {
+                warn!("Bybit 타임스탬프 오류, 재동기화 후 재시도: {}", msg);
+                self.sync_time().await?;
+                self.send_signed(method, endpoint, params, body)
+                    .await
+                    .map_err(ProviderError::from)
+            }
+            other => other.map_err(ProviderError::from),
+        }
+    }
+
+    async fn send_signed<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(&str, String)],
+        body: Option<&serde_json::Value>,
+    ) -> Result<T, BybitRequestError> {
+        let timestamp = self.timestamp_ms();
+        let query = Self::build_query(params);
+        let body_str = body.map(|b| b.to_string()).unwrap_or_default();
+
+        // GET은 쿼리 문자열, POST는 JSON 본문을 서명
+        let payload = if method == Method::GET {
+            &query
+        } else {
+            &body_str
+        };
+        let signature = self.sign(timestamp, payload);
+
+        let url = if query.is_empty() {
+            format!("{}{}", self.base_url, endpoint)
+        } else {
+            format!("{}{}?{}", self.base_url, endpoint, query)
+        };
+
+        let mut builder = self
+            .client
+            .request(method, &url)
+            .header("X-BAPI-API-KEY", &self.config.api_key)
+            .header("X-BAPI-TIMESTAMP", timestamp.to_string())
+            .header("X-BAPI-RECV-WINDOW", self.config.recv_window.to_string())
+            .header("X-BAPI-SIGN", signature);
+        if body.is_some() {
+            builder = builder
+                .header("Content-Type", "application/json")
+                .body(body_str);
+        }
+
+        let (_, text) = send_logged(self.request_logger.as_deref(), "bybit", builder)
+            .await
+            .map_err(|e| BybitRequestError::Provider(ProviderError::Network(e.to_string())))?;
+
+        parse_response_checked(&text)
+    }
+
+    fn category_param(&self) -> (&'static str, String) {
+        ("category", self.config.category.as_str().to_string())
+    }
+}
+
+/// 서명 요청 내부 에러 (타임스탬프 오류는 재시도 대상).
+#[derive(Debug)]
+enum BybitRequestError {
+    Timestamp(String),
+    Provider(ProviderError),
+}
+
+impl From<BybitRequestError> for ProviderError {
+    fn from(e: BybitRequestError) -> Self {
+        match e {
+            BybitRequestError::Timestamp(msg) => {
+                ProviderError::Authentication(format!("Bybit 타임스탬프 오류: {}", msg))
+            }
+            BybitRequestError::Provider(e) => e,
+        }
+    }
+}
+
+fn parse_response<T: DeserializeOwned>(body: &str) -> Result<T, ProviderError> {
+    parse_response_checked(body).map_err(ProviderError::from)
+}
+
+/// V5 응답 본문 파싱 및 retCode 확인.
+fn parse_response_checked<T: DeserializeOwned>(body: &str) -> Result<T, BybitRequestError> {
+    let parse_error = |e: serde_json::Error| {
+        BybitRequestError::Provider(ProviderError::Parse(format!("{}: {}", e, body)))
+    };
+
+    // 에러 응답은 result가 빈 객체이므로 retCode를 먼저 확인
+    let response: BybitResponse<serde_json::Value> =
+        serde_json::from_str(body).map_err(parse_error)?;
+
+    if response.ret_code != 0 {
+        return Err(map_ret_code(response.ret_code, &response.ret_msg));
+    }
+
+    let result = response.result.ok_or_else(|| {
+        BybitRequestError::Provider(ProviderError::Parse("Bybit 응답에 result 없음".to_string()))
+    })?;
+    serde_json::from_value(result).map_err(parse_error)
+}
+
+/// Bybit retCode를 에러로 매핑.
+fn map_ret_code(code: i64, msg: &str) -> BybitRequestError {
+    let message = format!("Bybit API Error {}: {}", code, msg);
+    let error = match code {
+        RET_CODE_TIMESTAMP => return BybitRequestError::Timestamp(msg.to_string()),
+        10003 | 10004 | 10005 | 10007 | 33004 => ProviderError::Authentication(message),
+        10006 | 10018 => ProviderError::Api(format!("요청 한도 초과 - {}", message)),
+        _ => ProviderError::Api(message),
+    };
+    BybitRequestError::Provider(error)
+}
+
+fn server_time_ms(server: &BybitServerTime) -> Result<i64, ProviderError> {
+    if let Ok(nano) = server.time_nano.parse::<i128>() {
+        return Ok((nano / 1_000_000) as i64);
+    }
+    server
+        .time_second
+        .parse::<i64>()
+        .map(|s| s * 1000)
+        .map_err(|e| ProviderError::Parse(format!("서버 시각 파싱 실패: {}", e)))
+}
+
+/// 문자열에서 Decimal 파싱 (빈 문자열은 0).
+fn parse_decimal(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap_or(Decimal::ZERO)
+}
+
+/// 내부 티커("BTC/USDT")를 Bybit 심볼("BTCUSDT")로 변환.
+pub fn to_bybit_symbol(ticker: &str) -> String {
+    ticker.replace(['/', '-'], "").to_uppercase()
+}
+
+/// Bybit 심볼("BTCUSDT")을 내부 티커("BTC/USDT")로 변환.
+pub fn from_bybit_symbol(symbol: &str) -> String {
+    for quote in ["USDT", "USDC", "BTC", "ETH"] {
+        if let Some(base) = symbol.strip_suffix(quote) {
+            if !base.is_empty() {
+                return format!("{}/{}", base, quote);
+            }
+        }
+    }
+    symbol.to_string()
+}
+
+fn parse_side(side: &str) -> Side {
+    if side.eq_ignore_ascii_case("buy") {
+        Side::Buy
+    } else {
+        Side::Sell
+    }
+}
+
+fn side_str(side: Side) -> &'static str {
+    match side {
+        Side::Buy => "Buy",
+        Side::Sell => "Sell",
+    }
+}
+
+fn ms_to_datetime(ms: &str) -> DateTime<Utc> {
+    ms.parse::<i64>()
+        .ok()
+        .and_then(|ms| Utc.timestamp_millis_opt(ms).single())
+        .unwrap_or_else(Utc::now)
+}
+
+/// YYYYMMDD 날짜 범위를 Bybit 체결 조회 범위(밀리초)로 변환.
+///
+/// Bybit은 최대 7일 범위만 허용하므로 초과 시 종료일 기준 7일로 줄입니다.
+fn execution_range_ms(start_date: &str, end_date: &str) -> Result<(i64, i64), ProviderError> {
+    let parse = |s: &str| {
+        NaiveDate::parse_from_str(s, "%Y%m%d")
+            .map_err(|e| ProviderError::Parse(format!("날짜 형식 오류 ({}): {}", s, e)))
+    };
+    let start = parse(start_date)?
+        .and_hms_opt(0, 0, 0)
+        .expect("valid time")
+        .and_utc();
+    let end = parse(end_date)?
+        .and_hms_milli_opt(23, 59, 59, 999)
+        .expect("valid time")
+        .and_utc();
+
+    let earliest = end - Duration::days(MAX_EXECUTION_RANGE_DAYS) + Duration::milliseconds(1);
+    let start = if start < earliest {
+        debug!(
+            "Bybit 체결 조회 범위 7일 초과, {} 이후로 제한",
+            earliest.format("%Y-%m-%d %H:%M:%S")
+        );
+        earliest
+    } else {
+        start
+    };
+
+    Ok((start.timestamp_millis(), end.timestamp_millis()))
+}
+
+// ============================================================================
+// 주문 실행 메서드
+// ============================================================================
+
+impl BybitClient {
+    /// 주문 요청을 `/v5/order/create` 본문으로 변환.
+    ///
+    /// 스톱/익절 주문은 `triggerPrice` 조건부 주문으로 전달합니다.
+    pub fn order_body(&self, request: &OrderRequest) -> Result<serde_json::Value, ProviderError> {
+        let (order_type, trigger_direction) = match request.order_type {
+            OrderType::Market => ("Market", None),
+            OrderType::Limit => ("Limit", None),
+            // 손절: 매도는 하락 돌파(2), 매수는 상승 돌파(1) 시 발동
+            OrderType::StopLoss | OrderType::StopLossLimit => {
+                let direction = match request.side {
+                    Side::Sell => 2,
+                    Side::Buy => 1,
+                };
+                let order_type = if request.order_type == OrderType::StopLoss {
+                    "Market"
+                } else {
+                    "Limit"
+                };
+                (order_type, Some(direction))
+            }
+            // 익절: 손절과 반대 방향
+            OrderType::TakeProfit | OrderType::TakeProfitLimit => {
+                let direction = match request.side {
+                    Side::Sell => 1,
+                    Side::Buy => 2,
+                };
+                let order_type = if request.order_type == OrderType::TakeProfit {
+                    "Market"
+                } else {
+                    "Limit"
+                };
+                (order_type, Some(direction))
+            }
+            OrderType::TrailingStop => {
+                return Err(ProviderError::Unsupported(
+                    "Bybit 트레일링 스톱은 주문 API로 지원하지 않습니다".to_string(),
+                ));
+            }
+        };
+
+        let mut body = serde_json::json!({
+            "category": self.config.category.as_str(),
+            "symbol": to_bybit_symbol(&request.ticker),
+            "side": side_str(request.side),
+            "orderType": order_type,
+            "qty": request.quantity.to_string(),
+        });
+
+        if order_type == "Limit" {
+            let price = request.price.ok_or_else(|| {
+                ProviderError::Api("지정가 주문에는 가격이 필요합니다".to_string())
+            })?;
+            body["price"] = serde_json::Value::String(price.to_string());
+            body["timeInForce"] = serde_json::Value::String(
+                match request.time_in_force {
+                    TimeInForce::IOC => "IOC",
+                    TimeInForce::FOK => "FOK",
+                    TimeInForce::GTC => "GTC",
+                    TimeInForce::GTD(_) => {
+                        return Err(ProviderError::Unsupported(
+                            "Bybit은 GTD 주문을 지원하지 않습니다".to_string(),
+                        ));
+                    }
+                }
+                .to_string(),
+            );
+        } else if self.config.category == BybitCategory::Spot {
+            // 현물 시장가 매수의 기본 수량 단위는 결제 통화이므로 기초 자산으로 고정
+            body["marketUnit"] = serde_json::Value::String("baseCoin".to_string());
+        }
+
+        if let Some(direction) = trigger_direction {
+            let trigger = request.stop_price.or(request.price).ok_or_else(|| {
+                ProviderError::Api("조건부 주문에는 발동 가격이 필요합니다".to_string())
+            })?;
+            body["triggerPrice"] = serde_json::Value::String(trigger.to_string());
+            if self.config.category == BybitCategory::Linear {
+                body["triggerDirection"] = serde_json::json!(direction);
+            } else {
+                body["orderFilter"] = serde_json::Value::String("StopOrder".to_string());
+            }
+        }
+
+        if let Some(client_id) = &request.client_order_id {
+            body["orderLinkId"] = serde_json::Value::String(client_id.clone());
+        }
+        if request.reduce_only && self.config.category == BybitCategory::Linear {
+            body["reduceOnly"] = serde_json::Value::Bool(true);
+        }
+
+        Ok(body)
+    }
+
+    /// 주문 생성 (POST /v5/order/create)
+    pub async fn place_order(
+        &self,
+        request: &OrderRequest,
+    ) -> Result<BybitOrderAck, ProviderError> {
+        let body = self.order_body(request)?;
+        self.signed_request(Method::POST, "/v5/order/create", &[], Some(&body))
+            .await
+    }
+
+    /// 주문 취소 (POST /v5/order/cancel)
+    pub async fn cancel_order(
+        &self,
+        ticker: &str,
+        order_id: &str,
+    ) -> Result<BybitOrderAck, ProviderError> {
+        let body = serde_json::json!({
+            "category": self.config.category.as_str(),
+            "symbol": to_bybit_symbol(ticker),
+            "orderId": order_id,
+        });
+        self.signed_request(Method::POST, "/v5/order/cancel", &[], Some(&body))
+            .await
+    }
+
+    /// 주문 정정 (POST /v5/order/amend)
+    pub async fn amend_order(
+        &self,
+        ticker: &str,
+        order_id: &str,
+        quantity: Option<Decimal>,
+        price: Option<Decimal>,
+    ) -> Result<BybitOrderAck, ProviderError> {
+        let mut body = serde_json::json!({
+            "category": self.config.category.as_str(),
+            "symbol": to_bybit_symbol(ticker),
+            "orderId": order_id,
+        });
+        if let Some(q) = quantity {
+            body["qty"] = serde_json::Value::String(q.to_string());
+        }
+        if let Some(p) = price {
+            body["price"] = serde_json::Value::String(p.to_string());
+        }
+        self.signed_request(Method::POST, "/v5/order/amend", &[], Some(&body))
+            .await
+    }
+
+    /// client_order_id(orderLinkId)로 주문 조회.
+    ///
+    /// 미체결 주문과 최근 주문 이력을 차례로 확인합니다.
+    pub async fn find_order_by_link_id(
+        &self,
+        ticker: &str,
+        order_link_id: &str,
+    ) -> Result<Option<BybitOrder>, ProviderError> {
+        let params = [
+            self.category_param(),
+            ("symbol", to_bybit_symbol(ticker)),
+            ("orderLinkId", order_link_id.to_string()),
+        ];
+
+        for endpoint in ["/v5/order/realtime", "/v5/order/history"] {
+            let orders: BybitList<BybitOrder> = self
+                .signed_request(Method::GET, endpoint, &params, None)
+                .await?;
+            if let Some(order) = orders.list.into_iter().next() {
+                return Ok(Some(order));
+            }
+        }
+        Ok(None)
+    }
+
+    /// 체결 내역 조회 (GET /v5/execution/list)
+    pub async fn fetch_executions(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        cursor: Option<&str>,
+    ) -> Result<BybitList<BybitExecution>, ProviderError> {
+        let (start_ms, end_ms) = execution_range_ms(start_date, end_date)?;
+        let mut params = vec![
+            self.category_param(),
+            ("startTime", start_ms.to_string()),
+            ("endTime", end_ms.to_string()),
+            ("limit", "100".to_string()),
+        ];
+        if let Some(c) = cursor.filter(|c| !c.is_empty()) {
+            params.push(("cursor", c.to_string()));
+        }
+
+        self.signed_request(Method::GET, "/v5/execution/list", &params, None)
+            .await
+    }
+
+    /// 통합 계좌 잔고 조회 (GET /v5/account/wallet-balance)
+    async fn wallet_balance(&self) -> Result<BybitWalletBalance, ProviderError> {
+        let params = [("accountType", "UNIFIED".to_string())];
+        let balances: BybitList<BybitWalletBalance> = self
+            .signed_request(Method::GET, "/v5/account/wallet-balance", &params, None)
+            .await?;
+        balances
+            .list
+            .into_iter()
+            .next()
+            .ok_or_else(|| ProviderError::Api("Bybit 통합 계좌 잔고 없음".to_string()))
+    }
+
+    fn position_info(position: &BybitPosition) -> Option<StrategyPositionInfo> {
+        let quantity = parse_decimal(&position.size);
+        if quantity <= Decimal::ZERO || position.side.is_empty() {
+            return None;
+        }
+
+        let mut info = StrategyPositionInfo::new(
+            from_bybit_symbol(&position.symbol),
+            parse_side(&position.side),
+            quantity,
+            parse_decimal(&position.avg_price),
+        );
+        let mark = parse_decimal(&position.mark_price);
+        if mark > Decimal::ZERO {
+            info.current_price = mark;
+        }
+        info.unrealized_pnl = parse_decimal(&position.unrealised_pnl);
+        let cost = info.avg_entry_price * quantity;
+        if cost > Decimal::ZERO {
+            info.unrealized_pnl_pct = info.unrealized_pnl / cost * Decimal::from(100);
+        }
+        let liq = parse_decimal(&position.liq_price);
+        info.liquidation_price = (liq > Decimal::ZERO).then_some(liq);
+        let margin = parse_decimal(&position.position_im);
+        info.margin = (margin > Decimal::ZERO).then_some(margin);
+        Some(info)
+    }
+
+    /// 현물 코인 잔고를 포지션으로 변환.
+    ///
+    /// 통합 계좌는 평균 매입가를 제공하지 않으므로 USD 평가가를 진입가로 사용합니다.
+    fn spot_position_info(coin: &BybitCoinBalance) -> Option<StrategyPositionInfo> {
+        if QUOTE_COINS.contains(&coin.coin.as_str()) {
+            return None;
+        }
+        let quantity = parse_decimal(&coin.wallet_balance);
+        if quantity <= Decimal::ZERO {
+            return None;
+        }
+        let price = parse_decimal(&coin.usd_value) / quantity;
+        Some(StrategyPositionInfo::new(
+            format!("{}/USDT", coin.coin),
+            Side::Buy,
+            quantity,
+            price,
+        ))
+    }
+
+    fn to_quote(t: BybitTicker) -> QuoteData {
+        let current_price = parse_decimal(&t.last_price);
+        let prev_close = parse_decimal(&t.prev_price24h);
+        QuoteData {
+            symbol: from_bybit_symbol(&t.symbol),
+            current_price,
+            price_change: if prev_close > Decimal::ZERO {
+                current_price - prev_close
+            } else {
+                Decimal::ZERO
+            },
+            change_percent: parse_decimal(&t.price24h_pcnt) * Decimal::from(100),
+            high: parse_decimal(&t.high_price24h),
+            low: parse_decimal(&t.low_price24h),
+            // V5 티커는 시가를 제공하지 않아 24시간 전 가격으로 대체
+            open: prev_close,
+            prev_close,
+            volume: parse_decimal(&t.volume24h),
+            trading_value: parse_decimal(&t.turnover24h),
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+// ============================================================================
+// ExchangeProvider 구현
+// ============================================================================
+
+#[async_trait]
+impl ExchangeProvider for BybitClient {
+    fn exchange_name(&self) -> &str {
+        "bybit"
+    }
+
+    async fn fetch_account(&self) -> Result<StrategyAccountInfo, ProviderError> {
+        let wallet = self.wallet_balance().await?;
+
+        Ok(StrategyAccountInfo {
+            total_balance: parse_decimal(&wallet.total_equity),
+            available_balance: parse_decimal(&wallet.total_available_balance),
+            margin_used: parse_decimal(&wallet.total_initial_margin),
+            unrealized_pnl: parse_decimal(&wallet.total_perp_upl),
+            currency: "USD".to_string(),
+        })
+    }
+
+    async fn fetch_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
+        match self.config.category {
+            BybitCategory::Spot => {
+                let wallet = self.wallet_balance().await?;
+                Ok(wallet
+                    .coin
+                    .iter()
+                    .filter_map(Self::spot_position_info)
+                    .collect())
+            }
+            BybitCategory::Linear => {
+                let params = [self.category_param(), ("settleCoin", "USDT".to_string())];
+                let positions: BybitList<BybitPosition> = self
+                    .signed_request(Method::GET, "/v5/position/list", &params, None)
+                    .await?;
+                Ok(positions
+                    .list
+                    .iter()
+                    .filter_map(Self::position_info)
+                    .collect())
+            }
+        }
+    }
+
+    async fn fetch_pending_orders(&self) -> Result<Vec<PendingOrder>, ProviderError> {
+        let mut params = vec![self.category_param()];
+        if self.config.category == BybitCategory::Linear {
+            params.push(("settleCoin", "USDT".to_string()));
+        }
+
+        let orders: BybitList<BybitOrder> = self
+            .signed_request(Method::GET, "/v5/order/realtime", &params, None)
+            .await?;
+
+        Ok(orders
+            .list
+            .into_iter()
+            .map(|order| {
+                let filled_quantity = parse_decimal(&order.cum_exec_qty);
+                PendingOrder {
+                    order_id: order.order_id,
+                    ticker: from_bybit_symbol(&order.symbol),
+                    side: parse_side(&order.side),
+                    price: parse_decimal(&order.price),
+                    quantity: parse_decimal(&order.qty),
+                    filled_quantity,
+                    status: if filled_quantity > Decimal::ZERO {
+                        OrderStatusType::PartiallyFilled
+                    } else {
+                        OrderStatusType::Pending
+                    },
+                    created_at: ms_to_datetime(&order.created_time),
+                }
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for BybitClient {
+    async fn get_quote(&self, symbol: &str) -> Result<QuoteData, ProviderError> {
+        let params = [self.category_param(), ("symbol", to_bybit_symbol(symbol))];
+        let tickers: BybitList<BybitTicker> =
+            self.public_get("/v5/market/tickers", &params).await?;
+
+        tickers
+            .list
+            .into_iter()
+            .next()
+            .map(Self::to_quote)
+            .ok_or_else(|| ProviderError::Api("Quote not found".to_string()))
+    }
+
+    async fn get_quotes(&self, symbols: &[String]) -> Vec<QuoteData> {
+        if symbols.is_empty() {
+            return Vec::new();
+        }
+
+        // 카테고리 전체 티커를 한 번에 조회한 뒤 요청 심볼만 선택
+        let wanted: std::collections::HashSet<String> =
+            symbols.iter().map(|s| to_bybit_symbol(s)).collect();
+        match self
+            .public_get::<BybitList<BybitTicker>>("/v5/market/tickers", &[self.category_param()])
+            .await
+        {
+            Ok(tickers) => tickers
+                .list
+                .into_iter()
+                .filter(|t| wanted.contains(&t.symbol))
+                .map(Self::to_quote)
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn provider_name(&self) -> &str {
+        "bybit"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn client(category: BybitCategory) -> BybitClient {
+        BybitClient::new(
+            BybitConfig::new("key".to_string(), "secret".to_string()).with_category(category),
+        )
+    }
+
+    #[test]
+    fn test_sign() {
+        let client = client(BybitCategory::Linear);
+        assert_eq!(
+            client.sign(1700000000000, "category=linear&symbol=BTCUSDT"),
+            "3906b813750309cce9879a975510651953382a28592d69104d0b599e3d201f40"
+        );
+    }
+
+    #[test]
+    fn test_symbol_conversion() {
+        assert_eq!(to_bybit_symbol("BTC/USDT"), "BTCUSDT");
+        assert_eq!(to_bybit_symbol("eth-usdt"), "ETHUSDT");
+        assert_eq!(from_bybit_symbol("ETHUSDT"), "ETH/USDT");
+        assert_eq!(from_bybit_symbol("SOLUSDC"), "SOL/USDC");
+    }
+
+    #[test]
+    fn test_order_body_market_and_limit() {
+        let spot = client(BybitCategory::Spot);
+        let market = spot
+            .order_body(&OrderRequest::market_buy(
+                "BTC/USDT".to_string(),
+                dec!(0.01),
+            ))
+            .unwrap();
+        assert_eq!(market["category"], "spot");
+        assert_eq!(market["orderType"], "Market");
+        assert_eq!(market["marketUnit"], "baseCoin");
+        assert_eq!(market["qty"], "0.01");
+
+        let linear = client(BybitCategory::Linear);
+        let limit = OrderRequest::limit_sell("BTC/USDT".to_string(), dec!(1), dec!(65000))
+            .with_reduce_only(true)
+            .with_client_id("cid-1");
+        let body = linear.order_body(&limit).unwrap();
+        assert_eq!(body["side"], "Sell");
+        assert_eq!(body["price"], "65000");
+        assert_eq!(body["timeInForce"], "GTC");
+        assert_eq!(body["reduceOnly"], true);
+        assert_eq!(body["orderLinkId"], "cid-1");
+        assert!(body.get("marketUnit").is_none());
+    }
+
+    #[test]
+    fn test_order_body_stop_loss_trigger() {
+        let linear = client(BybitCategory::Linear);
+        let mut request = OrderRequest::market_sell("BTC/USDT".to_string(), dec!(1));
+        request.order_type = OrderType::StopLoss;
+        request.stop_price = Some(dec!(60000));
+
+        let body = linear.order_body(&request).unwrap();
+        assert_eq!(body["orderType"], "Market");
+        assert_eq!(body["triggerPrice"], "60000");
+        assert_eq!(body["triggerDirection"], 2);
+    }
+
+    #[test]
+    fn test_parse_response_ret_codes() {
+        let ok: BybitServerTime = parse_response(
+            r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1700000000","timeNano":"1700000000123456789"},"time":1700000000123}"#,
+        )
+        .unwrap();
+        assert_eq!(server_time_ms(&ok).unwrap(), 1700000000123);
+
+        let timestamp = parse_response_checked::<BybitServerTime>(
+            r#"{"retCode":10002,"retMsg":"invalid request, please check your server timestamp","result":{}}"#,
+        );
+        assert!(matches!(timestamp, Err(BybitRequestError::Timestamp(_))));
+
+        let auth = parse_response::<BybitServerTime>(
+            r#"{"retCode":10003,"retMsg":"API key is invalid.","result":{}}"#,
+        );
+        assert!(matches!(auth, Err(ProviderError::Authentication(_))));
+    }
+
+    #[test]
+    fn test_execution_range_clamped_to_seven_days() {
+        let (start, end) = execution_range_ms("20240101", "20240131").unwrap();
+        assert_eq!(end - start, 7 * 24 * 60 * 60 * 1000 - 1);
+
+        let (start, end) = execution_range_ms("20240130", "20240131").unwrap();
+        assert_eq!(end - start, 2 * 24 * 60 * 60 * 1000 - 1);
+
+        assert!(execution_range_ms("2024-01-01", "20240131").is_err());
+    }
+
+    #[test]
+    fn test_position_info_from_linear() {
+        let position = BybitPosition {
+            symbol: "BTCUSDT".to_string(),
+            side: "Sell".to_string(),
+            size: "0.5".to_string(),
+            avg_price: "60000".to_string(),
+            mark_price: "59000".to_string(),
+            unrealised_pnl: "500".to_string(),
+            liq_price: "".to_string(),
+            position_im: "3000".to_string(),
+        };
+        let info = BybitClient::position_info(&position).unwrap();
+        assert_eq!(info.ticker, "BTC/USDT");
+        assert_eq!(info.side, Side::Sell);
+        assert_eq!(info.current_price, dec!(59000));
+        assert_eq!(info.liquidation_price, None);
+        assert_eq!(info.margin, Some(dec!(3000)));
+
+        let flat = BybitPosition {
+            side: String::new(),
+            size: "0".to_string(),
+            ..position
+        };
+        assert!(BybitClient::position_info(&flat).is_none());
+    }
+}
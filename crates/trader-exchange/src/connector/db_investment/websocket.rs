@@ -88,6 +88,8 @@ pub struct DbInvestmentWebSocket {
     subscribed_trades: Vec<String>,
     /// 구독 중인 호가 종목
     subscribed_orderbooks: Vec<String>,
+    /// 수립된 연결 세션 수 (`connect_once` 결과 판별용)
+    sessions_established: u64,
 }
 
 impl DbInvestmentWebSocket {
@@ -108,6 +110,7 @@ impl DbInvestmentWebSocket {
             command_rx: Some(cmd_rx),
             subscribed_trades: Vec::new(),
             subscribed_orderbooks: Vec::new(),
+            sessions_established: 0,
         }
     }
 
@@ -163,6 +166,21 @@ impl DbInvestmentWebSocket {
         Ok(())
     }
 
+    /// 단일 연결 세션 실행 (재연결 없음).
+    ///
+    /// 세션이 수립되었다가 끊기면 `Ok(())`, 연결 수립 자체에 실패하면 `Err`를 반환합니다.
+    /// 재연결 정책은 호출자(`DbInvestmentMarketStream`)가 결정합니다.
+    pub async fn connect_once(&mut self) -> Result<(), ExchangeError> {
+        let sessions_before = self.sessions_established;
+        let result = self.run_session().await;
+
+        if self.sessions_established > sessions_before {
+            Ok(())
+        } else {
+            result
+        }
+    }
+
     /// 내부 연결 로직.
     ///
     /// command channel을 통해 연결 중 동적 구독/해제 명령을 수신합니다.
@@ -184,6 +202,7 @@ impl DbInvestmentWebSocket {
         let (mut write, mut read) = ws_stream.split();
 
         // 연결 성공 알림
+        self.sessions_established += 1;
         let _ = self.tx.send(DbWsMessage::ConnectionStatus(true)).await;
         info!("DB증권 WebSocket 연결 성공");
 
@@ -523,8 +542,8 @@ impl DbInvestmentWebSocket {
         Ok(())
     }
 
-    /// 체결가 구독 추가 (내부용).
-    fn add_trade_subscription(&mut self, symbol: &str) {
+    /// 체결가 구독 추가 (연결 시 복원 대상).
+    pub fn add_trade_subscription(&mut self, symbol: &str) {
         if !self.subscribed_trades.contains(&symbol.to_string()) {
             self.subscribed_trades.push(symbol.to_string());
         }
@@ -43,7 +43,7 @@ use super::{
     },
     config::{KisAccountType, KisEnvironment},
 };
-use crate::{retry::RetryConfig, ExchangeError};
+use crate::{request_log::RequestLogger, retry::RetryConfig, ExchangeError};
 
 /// KIS 통합 클라이언트.
 ///
@@ -103,6 +103,13 @@ impl KisClient {
         self
     }
 
+    /// 요청/응답 추적 로거 설정 (KR/US 클라이언트 공통).
+    pub fn with_request_logger(mut self, logger: Arc<RequestLogger>) -> Self {
+        self.kr_client = self.kr_client.with_request_logger(Arc::clone(&logger));
+        self.us_client = self.us_client.with_request_logger(logger);
+        self
+    }
+
     /// OAuth 참조 반환.
     pub fn oauth(&self) -> &Arc<KisOAuth> {
         self.kr_client.oauth()
@@ -16,7 +16,7 @@
 
 use std::sync::Arc;
 
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder, StatusCode};
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use tracing::{debug, error, info, warn};
@@ -29,7 +29,11 @@ use super::{
     config::{KisAccountType, KisEnvironment},
     tr_id,
 };
-use crate::{retry::RetryConfig, ExchangeError};
+use crate::{
+    request_log::{send_logged, RequestLogger},
+    retry::RetryConfig,
+    ExchangeError,
+};
 
 /// KIS API Rate Limit 에러 메시지 코드.
 /// KIS는 HTTP 500과 함께 이 코드를 반환합니다.
@@ -58,6 +62,8 @@ pub struct KisKrClient {
     retry_config: RetryConfig,
     /// 호가 단위 제공자 (옵션, 설정 시 주문 가격 자동 라운딩)
     tick_size_provider: Option<Arc<dyn TickSizeProvider>>,
+    /// 요청/응답 추적 로거 (옵션)
+    request_logger: Option<Arc<RequestLogger>>,
 }
 
 impl KisKrClient {
@@ -108,6 +114,7 @@ impl KisKrClient {
             client,
             retry_config,
             tick_size_provider: None,
+            request_logger: None,
         })
     }
 
@@ -124,6 +131,19 @@ impl KisKrClient {
         self
     }
 
+    /// 요청/응답 추적 로거를 설정합니다.
+    ///
+    /// 설정 시 모든 REST 요청이 마스킹된 상태로 기록됩니다.
+    pub fn with_request_logger(mut self, logger: Arc<RequestLogger>) -> Self {
+        self.request_logger = Some(logger);
+        self
+    }
+
+    /// 요청 전송 (요청 로거가 설정된 경우 마스킹하여 기록).
+    async fn send(&self, request: RequestBuilder) -> Result<(StatusCode, String), reqwest::Error> {
+        send_logged(self.request_logger.as_deref(), "kis", request).await
+    }
+
     /// 내부 OAuth 참조 반환 (토큰 캐싱용).
     pub fn oauth(&self) -> &Arc<KisOAuth> {
         &self.oauth
@@ -161,22 +181,11 @@ impl KisKrClient {
             // 매 시도마다 새 토큰 빌드 (토큰 갱신 지원)
             let headers = self.oauth.build_headers(tr_id, None).await?;
 
-            let result = self
-                .client
-                .get(url)
-                .headers(headers)
-                .query(query)
-                .send()
-                .await;
+            let request = self.client.get(url).headers(headers).query(query);
+            let result = self.send(request).await;
 
             match result {
-                Ok(response) => {
-                    let status = response.status();
-                    let body = response
-                        .text()
-                        .await
-                        .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
+                Ok((status, body)) => {
                     if status.is_success() {
                         return parse_response(&body);
                     }
@@ -264,22 +273,11 @@ impl KisKrClient {
         loop {
             let headers = self.oauth.build_headers(tr_id, hash_body).await?;
 
-            let result = self
-                .client
-                .post(url)
-                .headers(headers)
-                .json(body)
-                .send()
-                .await;
+            let request = self.client.post(url).headers(headers).json(body);
+            let result = self.send(request).await;
 
             match result {
-                Ok(response) => {
-                    let status = response.status();
-                    let resp_body = response
-                        .text()
-                        .await
-                        .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
+                Ok((status, resp_body)) => {
                     if status.is_success() {
                         return parse_response(&resp_body);
                     }
@@ -407,21 +405,12 @@ impl KisKrClient {
 
         let headers = self.oauth.build_headers(tr_id, None).await?;
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("FID_COND_MRKT_DIV_CODE", "J"),
-                ("FID_INPUT_ISCD", stock_code),
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("FID_COND_MRKT_DIV_CODE", "J"),
+            ("FID_INPUT_ISCD", stock_code),
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -573,18 +562,9 @@ impl KisKrClient {
             order_type
         );
 
-        let response = self
-            .client
-            .post(&url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let response_body = response
-            .text()
+        let request = self.client.post(&url).headers(headers).json(&body);
+        let (status, response_body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -693,18 +673,9 @@ impl KisKrClient {
             stock_code
         );
 
-        let response = self
-            .client
-            .post(&url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let response_body = response
-            .text()
+        let request = self.client.post(&url).headers(headers).json(&body);
+        let (status, response_body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -766,30 +737,21 @@ impl KisKrClient {
 
         let headers = self.oauth.build_headers(tr_id, None).await?;
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("CANO", self.oauth.config().cano()),
-                ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
-                ("AFHR_FLPR_YN", "N"),
-                ("OFL_YN", ""),
-                ("INQR_DVSN", "01"), // 02(종목별) 사용 제한됨 → 01 사용 (KIS 공지 2026-02-10)
-                ("UNPR_DVSN", "01"),
-                ("FUND_STTL_ICLD_YN", "N"),
-                ("FNCG_AMT_AUTO_RDPT_YN", "N"),
-                ("PRCS_DVSN", "00"),
-                ("CTX_AREA_FK100", ""),
-                ("CTX_AREA_NK100", ""),
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("CANO", self.oauth.config().cano()),
+            ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
+            ("AFHR_FLPR_YN", "N"),
+            ("OFL_YN", ""),
+            ("INQR_DVSN", "01"), // 02(종목별) 사용 제한됨 → 01 사용 (KIS 공지 2026-02-10)
+            ("UNPR_DVSN", "01"),
+            ("FUND_STTL_ICLD_YN", "N"),
+            ("FNCG_AMT_AUTO_RDPT_YN", "N"),
+            ("PRCS_DVSN", "00"),
+            ("CTX_AREA_FK100", ""),
+            ("CTX_AREA_NK100", ""),
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -875,25 +837,16 @@ impl KisKrClient {
         // FID_PERIOD_DIV_CODE: D(일), W(주), M(월), Y(년)
         let adj_code = if adj_price { "0" } else { "1" }; // 0=수정주가, 1=원주가
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("FID_COND_MRKT_DIV_CODE", "J"), // J=주식
-                ("FID_INPUT_ISCD", stock_code),
-                ("FID_INPUT_DATE_1", start_date),
-                ("FID_INPUT_DATE_2", end_date),
-                ("FID_PERIOD_DIV_CODE", period),
-                ("FID_ORG_ADJ_PRC", adj_code),
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("FID_COND_MRKT_DIV_CODE", "J"), // J=주식
+            ("FID_INPUT_ISCD", stock_code),
+            ("FID_INPUT_DATE_1", start_date),
+            ("FID_INPUT_DATE_2", end_date),
+            ("FID_PERIOD_DIV_CODE", period),
+            ("FID_ORG_ADJ_PRC", adj_code),
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -943,24 +896,15 @@ impl KisKrClient {
         let now = chrono::Utc::now() + chrono::Duration::hours(9); // KST
         let time_str = now.format("%H%M%S").to_string();
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("FID_ETC_CLS_CODE", ""),
-                ("FID_COND_MRKT_DIV_CODE", "J"),
-                ("FID_INPUT_ISCD", stock_code),
-                ("FID_INPUT_HOUR_1", &time_str),
-                ("FID_PW_DATA_INCU_YN", "Y"), // 과거 데이터 포함
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("FID_ETC_CLS_CODE", ""),
+            ("FID_COND_MRKT_DIV_CODE", "J"),
+            ("FID_INPUT_ISCD", stock_code),
+            ("FID_INPUT_HOUR_1", &time_str),
+            ("FID_PW_DATA_INCU_YN", "Y"), // 과거 데이터 포함
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -1022,26 +966,17 @@ impl KisKrClient {
 
         let headers = self.oauth.build_headers(tr_id, None).await?;
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("CANO", self.oauth.config().cano()),
-                ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
-                ("PDNO", stock_code),
-                ("ORD_UNPR", &price.to_string()),
-                ("ORD_DVSN", "00"),
-                ("CMA_EVLU_AMT_ICLD_YN", "Y"),
-                ("OVRS_ICLD_YN", "N"),
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("CANO", self.oauth.config().cano()),
+            ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
+            ("PDNO", stock_code),
+            ("ORD_UNPR", &price.to_string()),
+            ("ORD_DVSN", "00"),
+            ("CMA_EVLU_AMT_ICLD_YN", "Y"),
+            ("OVRS_ICLD_YN", "N"),
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -1108,35 +1043,26 @@ impl KisKrClient {
 
         let headers = self.oauth.build_headers(tr_id, None).await?;
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("CANO", self.oauth.config().cano()),
-                ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
-                ("INQR_STRT_DT", start_date),
-                ("INQR_END_DT", end_date),
-                ("SLL_BUY_DVSN_CD", side),
-                ("INQR_DVSN", "00"), // 00=역순
-                ("PDNO", ""),        // 전 종목
-                ("CCLD_DVSN", "00"), // 00=전체, 01=체결, 02=미체결
-                ("ORD_GNO_BRNO", ""),
-                ("ODNO", ""),
-                ("INQR_DVSN_3", "00"), // 00=전체, 01=현금, 02=신용
-                ("INQR_DVSN_1", ""),
-                ("INQR_DVSN_2", ""), // 조회 구분 2
-                ("CTX_AREA_FK100", ctx_area_fk100),
-                ("CTX_AREA_NK100", ctx_area_nk100),
-                ("EXCG_ID_DVSN_CD", "KRX"), // 거래소 구분 코드
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("CANO", self.oauth.config().cano()),
+            ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
+            ("INQR_STRT_DT", start_date),
+            ("INQR_END_DT", end_date),
+            ("SLL_BUY_DVSN_CD", side),
+            ("INQR_DVSN", "00"), // 00=역순
+            ("PDNO", ""),        // 전 종목
+            ("CCLD_DVSN", "00"), // 00=전체, 01=체결, 02=미체결
+            ("ORD_GNO_BRNO", ""),
+            ("ODNO", ""),
+            ("INQR_DVSN_3", "00"), // 00=전체, 01=현금, 02=신용
+            ("INQR_DVSN_1", ""),
+            ("INQR_DVSN_2", ""), // 조회 구분 2
+            ("CTX_AREA_FK100", ctx_area_fk100),
+            ("CTX_AREA_NK100", ctx_area_nk100),
+            ("EXCG_ID_DVSN_CD", "KRX"), // 거래소 구분 코드
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -1246,35 +1172,26 @@ impl KisKrClient {
 
         let headers = self.oauth.build_headers(tr_id, None).await?;
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("CANO", self.oauth.config().cano()),
-                ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
-                ("INQR_STRT_DT", today.as_str()),
-                ("INQR_END_DT", today.as_str()),
-                ("SLL_BUY_DVSN_CD", "00"), // 00=전체 (매수+매도)
-                ("INQR_DVSN", "00"),       // 00=역순
-                ("PDNO", ""),              // 전 종목
-                ("CCLD_DVSN", "02"),       // 02=미체결 ← 핵심
-                ("ORD_GNO_BRNO", ""),
-                ("ODNO", ""),
-                ("INQR_DVSN_3", "00"), // 00=전체
-                ("INQR_DVSN_1", ""),
-                ("INQR_DVSN_2", ""),
-                ("CTX_AREA_FK100", ""),
-                ("CTX_AREA_NK100", ""),
-                ("EXCG_ID_DVSN_CD", "KRX"),
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("CANO", self.oauth.config().cano()),
+            ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
+            ("INQR_STRT_DT", today.as_str()),
+            ("INQR_END_DT", today.as_str()),
+            ("SLL_BUY_DVSN_CD", "00"), // 00=전체 (매수+매도)
+            ("INQR_DVSN", "00"),       // 00=역순
+            ("PDNO", ""),              // 전 종목
+            ("CCLD_DVSN", "02"),       // 02=미체결 ← 핵심
+            ("ORD_GNO_BRNO", ""),
+            ("ODNO", ""),
+            ("INQR_DVSN_3", "00"), // 00=전체
+            ("INQR_DVSN_1", ""),
+            ("INQR_DVSN_2", ""),
+            ("CTX_AREA_FK100", ""),
+            ("CTX_AREA_NK100", ""),
+            ("EXCG_ID_DVSN_CD", "KRX"),
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
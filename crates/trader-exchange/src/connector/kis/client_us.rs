@@ -20,14 +20,17 @@
 
 use std::sync::Arc;
 
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder, StatusCode};
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use tracing::{debug, error, info, warn};
 use trader_core::{RoundMethod, TickSizeProvider};
 
 use super::{auth::KisOAuth, config::KisEnvironment, exchange_code, tr_id};
-use crate::ExchangeError;
+use crate::{
+    request_log::{send_logged, RequestLogger},
+    ExchangeError,
+};
 
 /// 미국 시장 세션 유형.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -50,6 +53,8 @@ pub struct KisUsClient {
     client: Client,
     /// 호가 단위 제공자 (옵션, 설정 시 주문 가격 자동 라운딩)
     tick_size_provider: Option<Arc<dyn TickSizeProvider>>,
+    /// 요청/응답 추적 로거 (옵션)
+    request_logger: Option<Arc<RequestLogger>>,
 }
 
 impl KisUsClient {
@@ -80,6 +85,7 @@ impl KisUsClient {
             oauth,
             client,
             tick_size_provider: None,
+            request_logger: None,
         })
     }
 
@@ -96,6 +102,19 @@ impl KisUsClient {
         self
     }
 
+    /// 요청/응답 추적 로거를 설정합니다.
+    ///
+    /// 설정 시 모든 REST 요청이 마스킹된 상태로 기록됩니다.
+    pub fn with_request_logger(mut self, logger: Arc<RequestLogger>) -> Self {
+        self.request_logger = Some(logger);
+        self
+    }
+
+    /// 요청 전송 (요청 로거가 설정된 경우 마스킹하여 기록).
+    async fn send(&self, request: RequestBuilder) -> Result<(StatusCode, String), reqwest::Error> {
+        send_logged(self.request_logger.as_deref(), "kis", request).await
+    }
+
     /// 내부 OAuth 참조 반환 (토큰 캐싱용).
     pub fn oauth(&self) -> &Arc<KisOAuth> {
         &self.oauth
@@ -164,18 +183,13 @@ impl KisUsClient {
         let excd = exchange_code.unwrap_or_else(|| Self::get_exchange_code(symbol));
         let headers = self.oauth.build_headers(tr_id, None).await?;
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[("AUTH", ""), ("EXCD", excd), ("SYMB", symbol)])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("AUTH", ""),
+            ("EXCD", excd),
+            ("SYMB", symbol),
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -228,25 +242,16 @@ impl KisUsClient {
         let excd = exchange_code.unwrap_or_else(|| Self::get_exchange_code(symbol));
         let headers = self.oauth.build_headers(tr_id, None).await?;
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("AUTH", ""),
-                ("EXCD", excd),
-                ("SYMB", symbol),
-                ("GUBN", period), // D: daily, W: weekly, M: monthly
-                ("BYMD", end_date),
-                ("MODP", "1"), // 수정주가 반영
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("AUTH", ""),
+            ("EXCD", excd),
+            ("SYMB", symbol),
+            ("GUBN", period), // D: daily, W: weekly, M: monthly
+            ("BYMD", end_date),
+            ("MODP", "1"), // 수정주가 반영
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -390,18 +395,9 @@ impl KisUsClient {
             order_type
         );
 
-        let response = self
-            .client
-            .post(&url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let response_body = response
-            .text()
+        let request = self.client.post(&url).headers(headers).json(&body);
+        let (status, response_body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -510,18 +506,9 @@ impl KisUsClient {
             symbol
         );
 
-        let response = self
-            .client
-            .post(&url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let response_body = response
-            .text()
+        let request = self.client.post(&url).headers(headers).json(&body);
+        let (status, response_body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -569,25 +556,16 @@ impl KisUsClient {
 
         let headers = self.oauth.build_headers(tr_id, None).await?;
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("CANO", self.oauth.config().cano()),
-                ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
-                ("OVRS_EXCG_CD", "NASD"), // Default to NASDAQ
-                ("TR_CRCY_CD", currency),
-                ("CTX_AREA_FK200", ""),
-                ("CTX_AREA_NK200", ""),
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("CANO", self.oauth.config().cano()),
+            ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
+            ("OVRS_EXCG_CD", "NASD"), // Default to NASDAQ
+            ("TR_CRCY_CD", currency),
+            ("CTX_AREA_FK200", ""),
+            ("CTX_AREA_NK200", ""),
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -656,31 +634,22 @@ impl KisUsClient {
 
         let headers = self.oauth.build_headers(tr_id, None).await?;
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("CANO", self.oauth.config().cano()),
-                ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
-                ("INQR_STRT_DT", today.as_str()), // 조회 시작일
-                ("INQR_END_DT", today.as_str()),  // 조회 종료일
-                ("INQR_DVSN_CD", "00"),           // 조회구분: 00=전체
-                ("OVRS_EXCG_CD", ""),             // 거래소코드 (공백=전체)
-                ("PDNO", ""),                     // 종목코드 (공백=전체)
-                ("CCLD_DVSN", "02"),              // 체결구분: 02=미체결
-                ("SORT_SQN", "DS"),               // 정렬순서: DS=내림차순
-                ("ORD_DT", ""),                   // 주문일자 (공백=당일)
-                ("CTX_AREA_FK200", ""),           // 연속조회키
-                ("CTX_AREA_NK200", ""),           // 연속조회키
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("CANO", self.oauth.config().cano()),
+            ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
+            ("INQR_STRT_DT", today.as_str()), // 조회 시작일
+            ("INQR_END_DT", today.as_str()),  // 조회 종료일
+            ("INQR_DVSN_CD", "00"),           // 조회구분: 00=전체
+            ("OVRS_EXCG_CD", ""),             // 거래소코드 (공백=전체)
+            ("PDNO", ""),                     // 종목코드 (공백=전체)
+            ("CCLD_DVSN", "02"),              // 체결구분: 02=미체결
+            ("SORT_SQN", "DS"),               // 정렬순서: DS=내림차순
+            ("ORD_DT", ""),                   // 주문일자 (공백=당일)
+            ("CTX_AREA_FK200", ""),           // 연속조회키
+            ("CTX_AREA_NK200", ""),           // 연속조회키
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -720,21 +689,12 @@ impl KisUsClient {
 
         let headers = self.oauth.build_headers(tr_id, None).await?;
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .query(&[
-                ("CANO", self.oauth.config().cano()),
-                ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
-            ])
-            .send()
-            .await
-            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
-
-        let status = response.status();
-        let body = response
-            .text()
+        let request = self.client.get(&url).headers(headers).query(&[
+            ("CANO", self.oauth.config().cano()),
+            ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
+        ]);
+        let (status, body) = self
+            .send(request)
             .await
             .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
 
@@ -154,6 +154,8 @@ pub struct KisKrWebSocket {
     command_tx: mpsc::Sender<WsCommand>,
     /// 동적 구독/해제 명령 수신용 (connect 루프 내부에서 사용)
     command_rx: Option<mpsc::Receiver<WsCommand>>,
+    /// 수립된 연결 세션 수 (`connect_once` 결과 판별용)
+    sessions_established: u64,
 }
 
 impl KisKrWebSocket {
@@ -170,6 +172,7 @@ impl KisKrWebSocket {
             is_connected: Arc::new(tokio::sync::RwLock::new(false)),
             command_tx: cmd_tx,
             command_rx: Some(cmd_rx),
+            sessions_established: 0,
         }
     }
 
@@ -235,6 +238,25 @@ impl KisKrWebSocket {
         Ok(())
     }
 
+    /// 단일 연결 세션 실행 (재연결 없음).
+    ///
+    /// 세션이 수립되었다가 끊기면 `Ok(())`, 연결 수립 자체에 실패하면 `Err`를 반환합니다.
+    /// 재연결 정책은 호출자(`KisKrMarketStream`)가 결정합니다.
+    pub async fn connect_once(&mut self) -> Result<(), ExchangeError> {
+        let sessions_before = self.sessions_established;
+        let result = self.connect_internal().await;
+
+        *self.is_connected.write().await = false;
+        // WebSocket 키 초기화 (다음 연결 시 재발급)
+        self.oauth.clear_websocket_key().await;
+
+        if self.sessions_established > sessions_before {
+            Ok(())
+        } else {
+            result
+        }
+    }
+
     /// 내부 연결 로직.
     ///
     /// command channel을 통해 연결 중 동적 구독/해제 명령을 수신합니다.
@@ -257,6 +279,7 @@ impl KisKrWebSocket {
             let mut connected = self.is_connected.write().await;
             *connected = true;
         }
+        self.sessions_established += 1;
 
         if let Some(tx) = &self.tx {
             let _ = tx.send(KrRealtimeMessage::ConnectionStatus(true)).await;
@@ -578,6 +601,12 @@ impl KisKrWebSocket {
         }
     }
 
+    /// 모든 구독 제거 (재연결 전 구독 목록 재구성용).
+    pub fn clear_subscriptions(&mut self) {
+        self.subscribed_trades.clear();
+        self.subscribed_orderbooks.clear();
+    }
+
     /// 체결가 구독 제거.
     pub fn remove_trade_subscription(&mut self, symbol: &str) {
         self.subscribed_trades.retain(|s| s != symbol);
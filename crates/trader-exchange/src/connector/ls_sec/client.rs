@@ -11,6 +11,7 @@ use rust_decimal::Decimal;
 use serde::Deserialize;
 use serde_json::{json, Value};
 use tokio::sync::Mutex;
+use tracing::warn;
 use trader_core::{
     domain::{
         ExchangeProvider, MarketDataProvider, OrderResponse, OrderStatusType, PendingOrder, Side,
@@ -19,6 +20,8 @@ use trader_core::{
     ProviderError, QuoteData,
 };
 
+use crate::retry::RetryConfig;
+
 // ============================================================================
 // 설정
 // ============================================================================
@@ -28,6 +31,8 @@ pub struct LsSecConfig {
     pub app_key: String,
     pub app_secret: String,
     pub base_url: String,
+    /// 모의투자 계좌 여부 (LS증권은 모의투자용 앱키로 구분하며 국내 주식만 지원)
+    pub is_virtual: bool,
 }
 
 impl std::fmt::Debug for LsSecConfig {
@@ -36,6 +41,7 @@ impl std::fmt::Debug for LsSecConfig {
             .field("app_key", &"***")
             .field("app_secret", &"***")
             .field("base_url", &self.base_url)
+            .field("is_virtual", &self.is_virtual)
             .finish()
     }
 }
@@ -46,10 +52,42 @@ impl LsSecConfig {
             app_key,
             app_secret,
             base_url: base_url.unwrap_or_else(|| "https://openapi.ls-sec.co.kr:8080".to_string()),
+            is_virtual: false,
         }
     }
 }
 
+// ============================================================================
+// 응답 에러 분류
+// ============================================================================
+
+/// 토큰 만료/무효 응답 코드.
+const LS_TOKEN_EXPIRED_CODE: &str = "IGW00121";
+
+/// 초당 전송 건수 초과 응답 코드.
+const LS_RATE_LIMIT_CODE: &str = "IGW00201";
+
+/// 실패 응답 분류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LsFailure {
+    /// 토큰 만료 → 재발급 후 재시도
+    TokenExpired,
+    /// 전송 한도 초과 → 대기 후 재시도
+    RateLimited,
+    /// 재시도하지 않음
+    Other,
+}
+
+fn classify_failure(status: u16, body: &str) -> LsFailure {
+    if status == 401 || body.contains(LS_TOKEN_EXPIRED_CODE) {
+        LsFailure::TokenExpired
+    } else if status == 429 || body.contains(LS_RATE_LIMIT_CODE) {
+        LsFailure::RateLimited
+    } else {
+        LsFailure::Other
+    }
+}
+
 // ============================================================================
 // 토큰 관리
 // ============================================================================
@@ -67,38 +105,101 @@ pub struct LsSecClient {
     client: Client,
     config: LsSecConfig,
     token_manager: Arc<Mutex<TokenManager>>,
+    /// 전송 한도 초과 시 재시도 설정
+    retry_config: RetryConfig,
 }
 
+/// CSPAQ12300 (현물계좌 잔고내역) 응답.
 #[derive(Deserialize, Debug)]
-struct BalanceResponse {
-    #[serde(rename = "CSPAQ12200OutBlock2")]
-    out_block: BalanceOutBlock,
+struct KrBalanceResponse {
+    /// 계좌 요약
+    #[serde(rename = "CSPAQ12300OutBlock2")]
+    summary: KrBalanceSummary,
+    /// 종목별 잔고
+    #[serde(rename = "CSPAQ12300OutBlock3", default)]
+    holdings: Vec<KrHolding>,
 }
 
 #[derive(Deserialize, Debug)]
-struct BalanceOutBlock {
-    #[serde(rename = "BalEvalAmt")]
-    _bal_eval_amt: Value,
-    #[serde(rename = "MnyOrdAbleAmt")]
-    mny_ord_able_amt: Value,
-    #[serde(rename = "DpsastTotamt")]
+struct KrBalanceSummary {
+    /// 예수금
+    #[serde(rename = "Dps", default)]
+    dps: Value,
+    /// 잔고평가금액
+    #[serde(rename = "BalEvalAmt", default)]
+    bal_eval_amt: Value,
+    /// 예탁자산총액
+    #[serde(rename = "DpsastTotamt", default)]
     dpsast_totamt: Value,
 }
 
 #[derive(Deserialize, Debug)]
-struct KrPositionsResponse {
-    #[serde(rename = "CSPAQ12300OutBlock2")]
-    out_block2: Vec<KrPosition>,
-}
-
-#[derive(Deserialize, Debug)]
-struct KrPosition {
+struct KrHolding {
     #[serde(rename = "IsuNo")]
     isu_no: String,
-    #[serde(rename = "BalQty")]
-    bal_qty: String,
-    #[serde(rename = "PchsAvgPrc")]
-    pchs_avg_prc: String,
+    #[serde(rename = "BalQty", default)]
+    bal_qty: Value,
+    /// 평균단가
+    #[serde(rename = "AvrUprc", default)]
+    avr_uprc: Value,
+    /// 현재가
+    #[serde(rename = "NowPrc", default)]
+    now_prc: Value,
+    /// 평가손익
+    #[serde(rename = "EvalPnl", default)]
+    eval_pnl: Value,
+}
+
+impl KrBalanceResponse {
+    /// 보유 종목을 포지션으로 변환 (수량 0 제외).
+    fn positions(&self) -> Vec<StrategyPositionInfo> {
+        self.holdings
+            .iter()
+            .filter_map(|holding| {
+                let quantity = parse_decimal(&holding.bal_qty);
+                if quantity <= Decimal::ZERO {
+                    return None;
+                }
+
+                let mut position = StrategyPositionInfo::new(
+                    normalize_kr_ticker(&holding.isu_no),
+                    Side::Buy,
+                    quantity,
+                    parse_decimal(&holding.avr_uprc),
+                );
+                let current_price = parse_decimal(&holding.now_prc);
+                if current_price > Decimal::ZERO {
+                    position.update_price(current_price);
+                    Some(position)
+                } else {
+                    Some(position.mark_stale())
+                }
+            })
+            .collect()
+    }
+
+    /// 계좌 요약 변환 (KIS와 동일하게 총평가 = 예수금 + 잔고평가).
+    fn account_info(&self) -> StrategyAccountInfo {
+        let cash = parse_decimal(&self.summary.dps);
+        let total_balance = match parse_decimal(&self.summary.dpsast_totamt) {
+            total if total > Decimal::ZERO => total,
+            _ => cash + parse_decimal(&self.summary.bal_eval_amt),
+        };
+        let unrealized_pnl = self
+            .holdings
+            .iter()
+            .filter(|h| parse_decimal(&h.bal_qty) > Decimal::ZERO)
+            .map(|h| parse_decimal(&h.eval_pnl))
+            .sum();
+
+        StrategyAccountInfo {
+            total_balance,
+            available_balance: cash,
+            margin_used: Decimal::ZERO,
+            unrealized_pnl,
+            currency: "KRW".to_string(),
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -228,9 +329,36 @@ impl LsSecClient {
                 access_token: None,
                 expires_at: Instant::now(),
             })),
+            retry_config: RetryConfig::default(),
         }
     }
 
+    /// 재시도 설정 (빌더 패턴).
+    pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
+        self.retry_config = config;
+        self
+    }
+
+    /// 모의투자 계좌 여부.
+    pub fn is_virtual(&self) -> bool {
+        self.config.is_virtual
+    }
+
+    /// 캐시된 토큰 폐기 (다음 요청에서 재발급).
+    async fn invalidate_token(&self) {
+        let mut tm = self.token_manager.lock().await;
+        tm.access_token = None;
+    }
+
+    /// `attempt`번째(1부터) 재시도 전 대기 시간.
+    fn retry_delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.retry_config
+            .base_delay
+            .saturating_mul(factor)
+            .min(self.retry_config.max_delay)
+    }
+
     async fn get_token(&self) -> Result<String, ProviderError> {
         let mut tm = self.token_manager.lock().await;
 
@@ -290,52 +418,91 @@ impl LsSecClient {
         }
     }
 
+    /// TR 요청 실행.
+    ///
+    /// 토큰 만료 응답이면 토큰을 재발급해 한 번 재시도하고, 전송 한도 초과 응답이면
+    /// `retry_config`에 따라 대기 후 재시도합니다. 네트워크 오류는 주문 중복을 피하기 위해
+    /// 재시도하지 않습니다.
     async fn request<T: for<'de> Deserialize<'de>>(
         &self,
         path: &str,
         tr_cd: &str,
         body: Option<Value>,
     ) -> Result<T, ProviderError> {
-        let token = self.get_token().await?;
         let url = format!("{}/{}", self.config.base_url, path);
+        let mut attempt = 0;
+        let mut token_refreshed = false;
 
-        let mut builder = self.client.post(&url);
-        builder = builder.header("Authorization", format!("Bearer {}", token));
-        builder = builder.header("Content-Type", "application/json; charset=UTF-8");
-        builder = builder.header("tr_cd", tr_cd);
-        builder = builder.header("tr_cont", "N");
-        builder = builder.header("tr_cont_key", "");
+        loop {
+            let token = self.get_token().await?;
 
-        if let Some(b) = body {
-            builder = builder.json(&b);
-        }
+            let mut builder = self.client.post(&url);
+            builder = builder.header("Authorization", format!("Bearer {}", token));
+            builder = builder.header("Content-Type", "application/json; charset=UTF-8");
+            builder = builder.header("tr_cd", tr_cd);
+            builder = builder.header("tr_cont", "N");
+            builder = builder.header("tr_cont_key", "");
 
-        let response = builder
-            .send()
-            .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
+            if let Some(b) = &body {
+                builder = builder.json(b);
+            }
 
-        if !response.status().is_success() {
-            let error_text = response
+            let response = builder
+                .send()
+                .await
+                .map_err(|e| ProviderError::Network(e.to_string()))?;
+            let status = response.status();
+            let text = response
                 .text()
                 .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(ProviderError::Api(format!(
-                "LS Securities API Error: {}",
-                error_text
-            )));
-        }
+                .map_err(|e| ProviderError::Network(e.to_string()))?;
+
+            if status.is_success() {
+                return serde_json::from_str::<T>(&text).map_err(|e| {
+                    ProviderError::Parse(format!(
+                        "Failed to parse LS response: {}. Body: {}",
+                        e, text
+                    ))
+                });
+            }
 
-        let text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
-        serde_json::from_str::<T>(&text).map_err(|e| {
-            ProviderError::Parse(format!(
-                "Failed to parse LS response: {}. Body: {}",
-                e, text
-            ))
-        })
+            match classify_failure(status.as_u16(), &text) {
+                LsFailure::TokenExpired if !token_refreshed => {
+                    warn!(tr_cd = tr_cd, "LS증권 토큰 만료, 재발급 후 재시도");
+                    self.invalidate_token().await;
+                    token_refreshed = true;
+                }
+                LsFailure::TokenExpired => {
+                    return Err(ProviderError::Authentication(format!(
+                        "LS Securities token rejected: {}",
+                        text
+                    )));
+                }
+                LsFailure::RateLimited if attempt < self.retry_config.max_retries => {
+                    attempt += 1;
+                    let delay = self.retry_delay(attempt);
+                    warn!(
+                        tr_cd = tr_cd,
+                        attempt = attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        "LS증권 전송 한도 초과, 재시도 대기"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                LsFailure::RateLimited => {
+                    return Err(ProviderError::Api(format!(
+                        "LS Securities rate limit exceeded: {}",
+                        text
+                    )));
+                }
+                LsFailure::Other => {
+                    return Err(ProviderError::Api(format!(
+                        "LS Securities API Error: {}",
+                        text
+                    )));
+                }
+            }
+        }
     }
 
     #[allow(dead_code)]
@@ -343,32 +510,17 @@ impl LsSecClient {
         symbol.chars().any(|c| c.is_alphabetic())
     }
 
-    async fn fetch_kr_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
-        // CSPAQ12300: KR Stock Balance/Positions
+    /// 국내 주식 잔고 조회 (CSPAQ12300: 계좌 요약 + 종목별 잔고).
+    async fn fetch_kr_balance(&self) -> Result<KrBalanceResponse, ProviderError> {
         let body = json!({
             "CSPAQ12300InBlock1": {
-                "BalCreTp": "0"
+                "BalCreTp": "0",       // 0=전체
+                "CmsnAppTpCode": "0",  // 0=평가 시 수수료 미적용
+                "D2balBaseQryTp": "0", // 0=전부 조회
+                "UprcTpCode": "0"      // 0=평균단가
             }
         });
-        let res: KrPositionsResponse = self
-            .request("stock/accno", "CSPAQ12300", Some(body))
-            .await?;
-
-        let mut positions = Vec::new();
-        for stock in res.out_block2 {
-            let qty = Decimal::from_str(&stock.bal_qty).unwrap_or_default();
-            if qty.is_zero() {
-                continue;
-            }
-
-            positions.push(StrategyPositionInfo::new(
-                stock.isu_no,
-                Side::Buy,
-                qty,
-                Decimal::from_str(&stock.pchs_avg_prc).unwrap_or_default(),
-            ));
-        }
-        Ok(positions)
+        self.request("stock/accno", "CSPAQ12300", Some(body)).await
     }
 
     async fn fetch_us_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
@@ -634,6 +786,27 @@ impl LsSecClient {
     }
 }
 
+/// 숫자/문자열 혼용 필드를 Decimal로 변환 (실패 시 0).
+fn parse_decimal(v: &Value) -> Decimal {
+    if let Some(s) = v.as_str() {
+        Decimal::from_str(s.trim()).unwrap_or_default()
+    } else if let Some(n) = v.as_i64() {
+        Decimal::from(n)
+    } else if let Some(n) = v.as_f64() {
+        Decimal::from_f64_retain(n).unwrap_or_default()
+    } else {
+        Decimal::ZERO
+    }
+}
+
+/// 국내 종목코드 정규화 (A 접두사 제거).
+fn normalize_kr_ticker(code: &str) -> String {
+    match code.strip_prefix('A') {
+        Some(rest) if rest.len() == 6 => rest.to_string(),
+        _ => code.to_string(),
+    }
+}
+
 // ============================================================================
 // ExchangeProvider 구현
 // ============================================================================
@@ -644,58 +817,22 @@ impl ExchangeProvider for LsSecClient {
         "ls_securities"
     }
 
+    /// 국내 주식 계좌 요약.
+    ///
+    /// 해외 주식 잔고는 USD 기준이므로 KRW 계좌 합계에 포함하지 않습니다.
     async fn fetch_account(&self) -> Result<StrategyAccountInfo, ProviderError> {
-        let mut total_balance = Decimal::ZERO;
-        let mut available_balance = Decimal::ZERO;
-
-        fn parse_value(v: &Value) -> Decimal {
-            if let Some(s) = v.as_str() {
-                Decimal::from_str(s).unwrap_or_default()
-            } else if let Some(n) = v.as_f64() {
-                Decimal::from_f64_retain(n).unwrap_or_default()
-            } else if let Some(n) = v.as_i64() {
-                Decimal::from(n)
-            } else {
-                Decimal::ZERO
-            }
-        }
-
-        // KR Balance (CSPAQ12200)
-        let kr_body = json!({ "CSPAQ12200InBlock1": { "BalCreTp": "0" } });
-        if let Ok(res) = self
-            .request::<BalanceResponse>("stock/accno", "CSPAQ12200", Some(kr_body))
-            .await
-        {
-            total_balance += parse_value(&res.out_block.dpsast_totamt);
-            available_balance += parse_value(&res.out_block.mny_ord_able_amt);
-        }
-
-        // US Balance (COSOQ02701)
-        let _us_body = json!({ "COSOQ02701InBlock1": { "D2_KRW_Base_Tp": "0" } });
-        // (Assuming US balance response structure is similar or check it)
-        // For simplicity, we just implement KR for now if US structure is too different without exact specs.
-        // But let's try to add a placeholder or implementation if we have it in Python.
-
-        Ok(StrategyAccountInfo {
-            total_balance,
-            available_balance,
-            margin_used: Decimal::ZERO,
-            unrealized_pnl: Decimal::ZERO,
-            currency: "KRW".to_string(),
-        })
+        Ok(self.fetch_kr_balance().await?.account_info())
     }
 
     async fn fetch_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
-        let mut all_positions = Vec::new();
+        let mut all_positions = self.fetch_kr_balance().await?.positions();
 
-        match self.fetch_kr_positions().await {
-            Ok(pos) => all_positions.extend(pos),
-            Err(e) => eprintln!("Failed to fetch LS KR positions: {:?}", e),
-        }
-
-        match self.fetch_us_positions().await {
-            Ok(pos) => all_positions.extend(pos),
-            Err(e) => eprintln!("Failed to fetch LS US positions: {:?}", e),
+        // 모의투자는 국내 주식만 지원
+        if !self.config.is_virtual {
+            match self.fetch_us_positions().await {
+                Ok(pos) => all_positions.extend(pos),
+                Err(e) => warn!("LS증권 해외 포지션 조회 실패: {}", e),
+            }
         }
 
         Ok(all_positions)
@@ -817,3 +954,85 @@ impl MarketDataProvider for LsSecClient {
         "ls_securities"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_kr_balance_mapping() {
+        let res: KrBalanceResponse = serde_json::from_value(json!({
+            "CSPAQ12300OutBlock2": {
+                "Dps": "1000000",
+                "BalEvalAmt": "1520000",
+                "DpsastTotamt": "2520000"
+            },
+            "CSPAQ12300OutBlock3": [
+                {
+                    "IsuNo": "A005930",
+                    "BalQty": "20",
+                    "AvrUprc": "70000",
+                    "NowPrc": "76000",
+                    "EvalPnl": "120000"
+                },
+                {
+                    "IsuNo": "A000660",
+                    "BalQty": "0",
+                    "AvrUprc": "150000",
+                    "NowPrc": "160000",
+                    "EvalPnl": "0"
+                }
+            ]
+        }))
+        .unwrap();
+
+        let account = res.account_info();
+        assert_eq!(account.total_balance, dec!(2520000));
+        assert_eq!(account.available_balance, dec!(1000000));
+        assert_eq!(account.unrealized_pnl, dec!(120000));
+
+        let positions = res.positions();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].ticker, "005930");
+        assert_eq!(positions[0].current_price, dec!(76000));
+        assert_eq!(positions[0].unrealized_pnl, dec!(120000));
+        assert!(!positions[0].stale);
+    }
+
+    #[test]
+    fn test_kr_balance_fallbacks() {
+        // 예탁자산총액이 없으면 예수금 + 잔고평가, 현재가가 없으면 진입가 기준
+        let res: KrBalanceResponse = serde_json::from_value(json!({
+            "CSPAQ12300OutBlock2": { "Dps": 500000, "BalEvalAmt": 300000 },
+            "CSPAQ12300OutBlock3": [
+                { "IsuNo": "A035420", "BalQty": "2", "AvrUprc": "150000", "NowPrc": "0" }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(res.account_info().total_balance, dec!(800000));
+        let positions = res.positions();
+        assert!(positions[0].stale);
+        assert_eq!(positions[0].current_price, dec!(150000));
+    }
+
+    #[test]
+    fn test_classify_failure() {
+        assert_eq!(classify_failure(401, ""), LsFailure::TokenExpired);
+        assert_eq!(
+            classify_failure(
+                500,
+                r#"{"rsp_cd":"IGW00121","rsp_msg":"유효하지 않은 토큰"}"#
+            ),
+            LsFailure::TokenExpired
+        );
+        assert_eq!(classify_failure(429, ""), LsFailure::RateLimited);
+        assert_eq!(
+            classify_failure(500, r#"{"rsp_cd":"IGW00201"}"#),
+            LsFailure::RateLimited
+        );
+        assert_eq!(classify_failure(500, "internal"), LsFailure::Other);
+    }
+}
@@ -1,5 +1,6 @@
 pub mod binance;
 pub mod bithumb;
+pub mod bybit;
 pub mod db_investment;
 pub mod kis;
 pub mod ls_sec;
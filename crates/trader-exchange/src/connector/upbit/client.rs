@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{str::FromStr, sync::Arc};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -15,6 +15,8 @@ use trader_core::{
 };
 use uuid::Uuid;
 
+use crate::request_log::{send_logged, RequestLogger};
+
 // ============================================================================
 // 설정
 // ============================================================================
@@ -153,6 +155,8 @@ pub struct UpbitClient {
     client: Client,
     config: UpbitConfig,
     base_url: String,
+    /// 요청/응답 추적 로거 (옵션)
+    request_logger: Option<Arc<RequestLogger>>,
 }
 
 impl UpbitClient {
@@ -161,9 +165,18 @@ impl UpbitClient {
             client: Client::new(),
             config,
             base_url: "https://api.upbit.com/v1".to_string(),
+            request_logger: None,
         }
     }
 
+    /// 요청/응답 추적 로거를 설정합니다.
+    ///
+    /// 설정 시 모든 REST 요청이 마스킹된 상태로 기록됩니다.
+    pub fn with_request_logger(mut self, logger: Arc<RequestLogger>) -> Self {
+        self.request_logger = Some(logger);
+        self
+    }
+
     fn generate_token(&self, query_hash: Option<String>) -> Result<String, ProviderError> {
         let nonce = Uuid::new_v4().to_string();
         let payload = UpbitPayload {
@@ -222,26 +235,15 @@ impl UpbitClient {
         let token = self.generate_token(query_hash)?;
         builder = builder.header("Authorization", token);
 
-        let response = builder
-            .send()
+        let (status, body) = send_logged(self.request_logger.as_deref(), "upbit", builder)
             .await
             .map_err(|e| ProviderError::Network(e.to_string()))?;
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(ProviderError::Api(format!(
-                "Upbit API Error: {}",
-                error_text
-            )));
+        if !status.is_success() {
+            return Err(ProviderError::Api(format!("Upbit API Error: {}", body)));
         }
 
-        response
-            .json::<T>()
-            .await
-            .map_err(|e| ProviderError::Parse(e.to_string()))
+        serde_json::from_str::<T>(&body).map_err(|e| ProviderError::Parse(e.to_string()))
     }
 }
 
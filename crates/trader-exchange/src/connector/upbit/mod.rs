@@ -1,5 +1,7 @@
 mod client;
+pub mod order_rules;
 pub mod websocket;
 
 pub use client::*;
+pub use order_rules::*;
 pub use websocket::*;
@@ -0,0 +1,364 @@
+//! 업비트 마켓별 주문 규칙.
+//!
+//! 주문 전송 전에 최소 주문 금액, 호가 단위, 수량 소수 자릿수를 로컬에서 검증하여
+//! 거래소 거부로 인한 라운드 트립을 피합니다.
+//!
+//! | 마켓 | 최소 주문 금액 | 수수료 | 주문 금액 단위 |
+//! |------|----------------|--------|----------------|
+//! | KRW  | 5,000 KRW      | 0.05%  | 1 KRW          |
+//! | BTC  | 0.00005 BTC    | 0.25%  | 0.00000001 BTC |
+//! | USDT | 0.5 USDT       | 0.25%  | 0.001 USDT     |
+//!
+//! 수량은 모든 마켓에서 소수점 8자리까지 허용됩니다.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+use trader_core::domain::ProviderError;
+
+/// 수량 최대 소수 자릿수.
+pub const UPBIT_VOLUME_SCALE: u32 = 8;
+
+/// 가격 구간별 호가 단위.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TickBand {
+    /// 구간 하한 (이상)
+    pub min_price: Decimal,
+    /// 호가 단위
+    pub tick: Decimal,
+}
+
+/// 마켓(기준 통화)별 주문 규칙.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UpbitMarketRule {
+    /// 기준 통화 (예: `KRW`)
+    pub quote: &'static str,
+    /// 최소 주문 금액 (기준 통화)
+    pub min_order_amount: Decimal,
+    /// 거래 수수료율
+    pub fee_rate: Decimal,
+    /// 시장가 매수 주문 금액 소수 자릿수
+    pub amount_scale: u32,
+    /// 호가 단위 테이블 (하한 내림차순)
+    pub tick_bands: &'static [TickBand],
+}
+
+const KRW_TICK_BANDS: &[TickBand] = &[
+    TickBand {
+        min_price: dec!(2000000),
+        tick: dec!(1000),
+    },
+    TickBand {
+        min_price: dec!(1000000),
+        tick: dec!(500),
+    },
+    TickBand {
+        min_price: dec!(500000),
+        tick: dec!(100),
+    },
+    TickBand {
+        min_price: dec!(100000),
+        tick: dec!(50),
+    },
+    TickBand {
+        min_price: dec!(10000),
+        tick: dec!(10),
+    },
+    TickBand {
+        min_price: dec!(1000),
+        tick: dec!(1),
+    },
+    TickBand {
+        min_price: dec!(100),
+        tick: dec!(0.1),
+    },
+    TickBand {
+        min_price: dec!(10),
+        tick: dec!(0.01),
+    },
+    TickBand {
+        min_price: dec!(1),
+        tick: dec!(0.001),
+    },
+    TickBand {
+        min_price: dec!(0.1),
+        tick: dec!(0.0001),
+    },
+    TickBand {
+        min_price: dec!(0.01),
+        tick: dec!(0.00001),
+    },
+    TickBand {
+        min_price: dec!(0.001),
+        tick: dec!(0.000001),
+    },
+    TickBand {
+        min_price: dec!(0.0001),
+        tick: dec!(0.0000001),
+    },
+    TickBand {
+        min_price: dec!(0),
+        tick: dec!(0.00000001),
+    },
+];
+
+const BTC_TICK_BANDS: &[TickBand] = &[TickBand {
+    min_price: dec!(0),
+    tick: dec!(0.00000001),
+}];
+
+const USDT_TICK_BANDS: &[TickBand] = &[
+    TickBand {
+        min_price: dec!(10),
+        tick: dec!(0.01),
+    },
+    TickBand {
+        min_price: dec!(1),
+        tick: dec!(0.001),
+    },
+    TickBand {
+        min_price: dec!(0.1),
+        tick: dec!(0.0001),
+    },
+    TickBand {
+        min_price: dec!(0.01),
+        tick: dec!(0.00001),
+    },
+    TickBand {
+        min_price: dec!(0.001),
+        tick: dec!(0.000001),
+    },
+    TickBand {
+        min_price: dec!(0.0001),
+        tick: dec!(0.0000001),
+    },
+    TickBand {
+        min_price: dec!(0),
+        tick: dec!(0.00000001),
+    },
+];
+
+/// 지원 마켓 규칙 테이블.
+pub const UPBIT_MARKET_RULES: &[UpbitMarketRule] = &[
+    UpbitMarketRule {
+        quote: "KRW",
+        min_order_amount: dec!(5000),
+        fee_rate: dec!(0.0005),
+        amount_scale: 0,
+        tick_bands: KRW_TICK_BANDS,
+    },
+    UpbitMarketRule {
+        quote: "BTC",
+        min_order_amount: dec!(0.00005),
+        fee_rate: dec!(0.0025),
+        amount_scale: 8,
+        tick_bands: BTC_TICK_BANDS,
+    },
+    UpbitMarketRule {
+        quote: "USDT",
+        min_order_amount: dec!(0.5),
+        fee_rate: dec!(0.0025),
+        amount_scale: 3,
+        tick_bands: USDT_TICK_BANDS,
+    },
+];
+
+impl UpbitMarketRule {
+    /// 마켓 코드(`KRW-BTC`)의 기준 통화로 규칙 조회.
+    pub fn for_market(market: &str) -> Option<&'static UpbitMarketRule> {
+        let quote = market.split('-').next()?;
+        UPBIT_MARKET_RULES
+            .iter()
+            .find(|rule| rule.quote.eq_ignore_ascii_case(quote))
+    }
+
+    /// 가격에 해당하는 호가 단위.
+    pub fn tick_size(&self, price: Decimal) -> Decimal {
+        self.tick_bands
+            .iter()
+            .find(|band| price >= band.min_price)
+            .or_else(|| self.tick_bands.last())
+            .map(|band| band.tick)
+            .unwrap_or(Decimal::ONE)
+    }
+
+    /// 주문 금액에 대한 예상 수수료.
+    pub fn fee(&self, amount: Decimal) -> Decimal {
+        amount * self.fee_rate
+    }
+
+    /// 시장가 매수 주문 금액을 금액 단위로 내림.
+    pub fn round_amount(&self, amount: Decimal) -> Decimal {
+        amount.round_dp_with_strategy(self.amount_scale, RoundingStrategy::ToZero)
+    }
+
+    /// 지정가 주문 검증 (호가 단위, 수량 자릿수, 최소 주문 금액).
+    pub fn validate_limit(&self, price: Decimal, volume: Decimal) -> Result<(), ProviderError> {
+        if price <= Decimal::ZERO {
+            return Err(invalid(format!("주문 가격은 0보다 커야 합니다: {}", price)));
+        }
+        let tick = self.tick_size(price);
+        if !(price % tick).is_zero() {
+            return Err(invalid(format!(
+                "주문 가격 {}이(가) 호가 단위 {} {}에 맞지 않습니다",
+                price, tick, self.quote
+            )));
+        }
+        self.validate_volume(volume)?;
+        self.validate_amount(price * volume)
+    }
+
+    /// 시장가 매수 주문 검증 (주문 금액 기준).
+    pub fn validate_market_buy(&self, amount: Decimal) -> Result<(), ProviderError> {
+        if amount.normalize().scale() > self.amount_scale {
+            return Err(invalid(format!(
+                "시장가 매수 금액 {}은(는) 소수점 {}자리까지 가능합니다",
+                amount, self.amount_scale
+            )));
+        }
+        self.validate_amount(amount)
+    }
+
+    /// 시장가 매도 주문 검증 (수량 기준).
+    ///
+    /// 기준가를 알면 예상 주문 금액으로 최소 주문 금액도 검증합니다.
+    pub fn validate_market_sell(
+        &self,
+        volume: Decimal,
+        reference_price: Option<Decimal>,
+    ) -> Result<(), ProviderError> {
+        self.validate_volume(volume)?;
+        match reference_price {
+            Some(price) if price > Decimal::ZERO => self.validate_amount(price * volume),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_volume(&self, volume: Decimal) -> Result<(), ProviderError> {
+        if volume <= Decimal::ZERO {
+            return Err(invalid(format!(
+                "주문 수량은 0보다 커야 합니다: {}",
+                volume
+            )));
+        }
+        if volume.normalize().scale() > UPBIT_VOLUME_SCALE {
+            return Err(invalid(format!(
+                "주문 수량 {}은(는) 소수점 {}자리까지 가능합니다",
+                volume, UPBIT_VOLUME_SCALE
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_amount(&self, amount: Decimal) -> Result<(), ProviderError> {
+        if amount < self.min_order_amount {
+            return Err(invalid(format!(
+                "주문 금액 {} {}이(가) 최소 주문 금액 {} {} 미만입니다",
+                amount.normalize(),
+                self.quote,
+                self.min_order_amount,
+                self.quote
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn invalid(message: String) -> ProviderError {
+    ProviderError::InvalidOrder(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (마켓, 가격, 기대 호가 단위)
+    const TICK_FIXTURES: &[(&str, Decimal, Decimal)] = &[
+        ("KRW-BTC", dec!(95000000), dec!(1000)),
+        ("KRW-BTC", dec!(2000000), dec!(1000)),
+        ("KRW-ETH", dec!(1999999), dec!(500)),
+        ("KRW-SOL", dec!(250000), dec!(50)),
+        ("KRW-XRP", dec!(850), dec!(0.1)),
+        ("KRW-XRP", dec!(1000), dec!(1)),
+        ("KRW-DOGE", dec!(99.9), dec!(0.01)),
+        ("KRW-SHIB", dec!(0.0123), dec!(0.00001)),
+        ("BTC-ETH", dec!(0.035), dec!(0.00000001)),
+        ("USDT-BTC", dec!(65000), dec!(0.01)),
+        ("USDT-XRP", dec!(0.52), dec!(0.0001)),
+    ];
+
+    /// (마켓, 최소 주문 금액, 수수료율)
+    const MARKET_FIXTURES: &[(&str, Decimal, Decimal)] = &[
+        ("KRW-BTC", dec!(5000), dec!(0.0005)),
+        ("BTC-ETH", dec!(0.00005), dec!(0.0025)),
+        ("USDT-BTC", dec!(0.5), dec!(0.0025)),
+    ];
+
+    #[test]
+    fn test_tick_size_fixtures() {
+        for (market, price, expected) in TICK_FIXTURES {
+            let rule = UpbitMarketRule::for_market(market).unwrap();
+            assert_eq!(
+                rule.tick_size(*price),
+                *expected,
+                "{} @ {} 호가 단위",
+                market,
+                price
+            );
+        }
+    }
+
+    #[test]
+    fn test_market_rule_fixtures() {
+        for (market, min_amount, fee_rate) in MARKET_FIXTURES {
+            let rule = UpbitMarketRule::for_market(market).unwrap();
+            assert_eq!(rule.min_order_amount, *min_amount, "{}", market);
+            assert_eq!(rule.fee_rate, *fee_rate, "{}", market);
+        }
+        assert!(UpbitMarketRule::for_market("ETH-BTC").is_none());
+        assert_eq!(
+            UpbitMarketRule::for_market("KRW-BTC")
+                .unwrap()
+                .fee(dec!(100000)),
+            dec!(50)
+        );
+    }
+
+    #[test]
+    fn test_validate_limit() {
+        let krw = UpbitMarketRule::for_market("KRW-BTC").unwrap();
+
+        assert!(krw.validate_limit(dec!(95000000), dec!(0.0001)).is_ok());
+        // 호가 단위 위반
+        assert!(matches!(
+            krw.validate_limit(dec!(95000500), dec!(0.0001)),
+            Err(ProviderError::InvalidOrder(_))
+        ));
+        // 최소 주문 금액 미만 (4,750원)
+        assert!(matches!(
+            krw.validate_limit(dec!(95000000), dec!(0.00005)),
+            Err(ProviderError::InvalidOrder(_))
+        ));
+        // 수량 9자리
+        assert!(matches!(
+            krw.validate_limit(dec!(95000000), dec!(0.123456789)),
+            Err(ProviderError::InvalidOrder(_))
+        ));
+        // 후행 0은 자릿수로 세지 않음
+        assert!(krw.validate_limit(dec!(1000), dec!(5.000000000)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_market_orders() {
+        let krw = UpbitMarketRule::for_market("KRW-BTC").unwrap();
+
+        assert!(krw.validate_market_buy(dec!(5000)).is_ok());
+        assert!(krw.validate_market_buy(dec!(4999)).is_err());
+        assert!(krw.validate_market_buy(dec!(5000.5)).is_err());
+        assert_eq!(krw.round_amount(dec!(12345.678)), dec!(12345));
+
+        assert!(krw.validate_market_sell(dec!(0.001), None).is_ok());
+        assert!(krw
+            .validate_market_sell(dec!(0.00001), Some(dec!(95000000)))
+            .is_err());
+    }
+}
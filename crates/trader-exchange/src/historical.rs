@@ -269,6 +269,7 @@ impl UnifiedHistoricalProvider {
             close_time,
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         }
     }
 
@@ -293,6 +294,7 @@ impl UnifiedHistoricalProvider {
             close_time,
             quote_volume: Some(data.trading_value),
             num_trades: None,
+            synthetic: false,
         }
     }
 
@@ -317,6 +319,7 @@ impl UnifiedHistoricalProvider {
             close_time,
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         }
     }
 }
@@ -7,12 +7,18 @@
 //! - 시장 데이터 정규화
 //! - Rate limiting 및 에러 처리
 //! - Circuit breaker: 장애 허용을 위한 회로 차단기
+//! - 주문 상태 변경 통합 스트림 (WebSocket push + 폴링 fallback)
+//! - 요청/응답 추적 로깅 (민감 정보 마스킹, correlation ID)
+//! - 실시간 스트림 통합 시세 캐시
 
 pub mod circuit_breaker;
 pub mod connector;
 pub mod error;
 pub mod historical;
+pub mod order_update;
 pub mod provider;
+pub mod quote_cache;
+pub mod request_log;
 pub mod retry;
 pub mod simulated;
 pub mod stream;
@@ -25,7 +31,9 @@ pub use circuit_breaker::{
     ErrorCategory,
 };
 pub use connector::{
+    binance::{FundingRate, FuturesAccount, FuturesPosition, FuturesPositionSide, PositionMode},
     bithumb::{BithumbClient, BithumbConfig},
+    bybit::{BybitCategory, BybitClient, BybitConfig},
     db_investment::{DbInvestmentClient, DbInvestmentConfig},
     kis::client::KisClient,
     ls_sec::{LsSecClient, LsSecConfig},
@@ -33,11 +41,20 @@ pub use connector::{
 };
 pub use error::*;
 pub use historical::{HistoricalDataProvider, UnifiedHistoricalProvider};
+pub use order_update::{
+    spawn_user_stream_forwarder, OrderUpdateHub, PollingOrderUpdater, DEFAULT_POLL_INTERVAL,
+};
 pub use provider::{
-    BinanceExchangeProvider, BinanceProvider, BithumbExchangeProvider, BithumbProvider,
-    DbInvestmentExchangeProvider, DbInvestmentProvider, KisExchangeProvider, KisProvider,
+    BinanceExchangeProvider, BinanceFuturesProvider, BinanceProvider, BithumbExchangeProvider,
+    BithumbProvider, BybitExchangeProvider, BybitProvider, DbInvestmentExchangeProvider,
+    DbInvestmentProvider, InstrumentedProvider, KisExchangeProvider, KisProvider,
     LsSecExchangeProvider, LsSecProvider, UpbitExchangeProvider, UpbitProvider,
 };
+pub use quote_cache::{CachedMarketDataProvider, CachedQuote, QuoteCacheConfig, QuoteSource};
+pub use request_log::{
+    current_correlation_id, with_correlation_id, ExchangeRequestRecord, FileRequestLogSink,
+    PgRequestLogSink, RequestLogConfig, RequestLogLevel, RequestLogSink, RequestLogger,
+};
 pub use retry::{
     with_retry, with_retry_context, with_retry_if, RetryConfig, RetryContext, RetryStats,
 };
@@ -47,7 +64,7 @@ pub use simulated::{
 };
 pub use stream::{
     DbInvestmentMarketStream, KisKrMarketStream, KisUsMarketStream, LsSecMarketStream,
-    UnifiedMarketStream,
+    ReconnectPolicy, UnifiedMarketStream,
 };
 pub use traits::*;
 pub use yahoo::YahooFinanceProvider;
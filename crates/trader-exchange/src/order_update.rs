@@ -0,0 +1,497 @@
+//! 주문 상태 변경 통합 스트림.
+//!
+//! provider마다 다른 주문 상태 전달 방식을 하나의 [`OrderUpdate`] 스트림으로 통합합니다.
+//!
+//! - [`OrderUpdateHub`]: 이벤트 정규화(중복·누적체결 역행·최종 상태 이후 이벤트 제거) 후
+//!   구독자에게 브로드캐스트
+//! - [`PollingOrderUpdater`]: WebSocket 체결통보를 지원하지 않는 거래소용 기본 구현.
+//!   미체결 주문 목록을 주기적으로 조회하여 변화를 이벤트로 변환
+//! - [`spawn_user_stream_forwarder`]: push 방식 [`UserStream`]의 주문 업데이트를 허브로 전달
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! let updater = Arc::new(PollingOrderUpdater::new(provider, DEFAULT_POLL_INTERVAL));
+//! let mut updates = updater.subscribe_order_updates();
+//! let _handle = Arc::clone(&updater).spawn();
+//!
+//! while let Ok(update) = updates.recv().await {
+//!     order_manager.apply_order_update(&update)?;
+//! }
+//! ```
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use tokio::{sync::broadcast, task::JoinHandle, time::MissedTickBehavior};
+use tracing::{debug, warn};
+use trader_core::{
+    ExchangeProvider, OrderStatusType, OrderUpdate, OrderUpdateProvider, PendingOrder,
+    ProviderError,
+};
+
+use crate::traits::{UserEvent, UserStream};
+
+/// 기본 폴링 주기.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 브로드캐스트 채널 용량.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// 정규화를 위해 마지막 상태를 기억하는 최대 주문 수.
+const MAX_TRACKED_ORDERS: usize = 10_000;
+
+/// 주문 상태 이벤트 허브.
+///
+/// 같은 주문에 대해 push와 폴링이 겹치거나 이벤트가 재전송되어도
+/// 구독자는 실제 상태 변화만 한 번씩 받습니다.
+pub struct OrderUpdateHub {
+    /// 거래소 이름
+    exchange: String,
+    /// 이벤트 송신기
+    sender: broadcast::Sender<OrderUpdate>,
+    /// 주문별 마지막 (상태, 누적 체결수량)
+    last_seen: Mutex<HashMap<String, (OrderStatusType, Decimal)>>,
+}
+
+impl OrderUpdateHub {
+    /// 새 허브 생성.
+    pub fn new(exchange: impl Into<String>) -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            exchange: exchange.into(),
+            sender,
+            last_seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 이벤트 발행.
+    ///
+    /// 다음 이벤트는 버리고 `false`를 반환합니다.
+    /// - 직전과 상태·누적 체결수량이 같은 중복 이벤트
+    /// - 누적 체결수량이 줄어드는 역행 이벤트 (늦게 도착한 이전 상태)
+    /// - 이미 최종 상태가 된 주문의 이벤트
+    pub fn publish(&self, update: OrderUpdate) -> bool {
+        {
+            let mut last_seen = self.last_seen.lock().unwrap_or_else(|e| e.into_inner());
+
+            if let Some((status, filled)) = last_seen.get(&update.order_id) {
+                let duplicate = *status == update.status && *filled == update.filled_quantity;
+                if status.is_final() || duplicate || update.filled_quantity < *filled {
+                    return false;
+                }
+            }
+
+            last_seen.insert(
+                update.order_id.clone(),
+                (update.status, update.filled_quantity),
+            );
+            if last_seen.len() > MAX_TRACKED_ORDERS {
+                last_seen.retain(|_, (status, _)| !status.is_final());
+            }
+        }
+
+        debug!(
+            exchange = %self.exchange,
+            order_id = %update.order_id,
+            status = ?update.status,
+            filled_quantity = %update.filled_quantity,
+            "주문 상태 변경"
+        );
+        // 구독자가 없어도 정상 (이벤트 유실 아님)
+        let _ = self.sender.send(update);
+        true
+    }
+}
+
+impl OrderUpdateProvider for OrderUpdateHub {
+    fn subscribe_order_updates(&self) -> broadcast::Receiver<OrderUpdate> {
+        self.sender.subscribe()
+    }
+
+    fn exchange_name(&self) -> &str {
+        &self.exchange
+    }
+}
+
+/// 폴링 기반 주문 상태 스트림 (기본 구현).
+///
+/// 미체결 주문 목록을 주기적으로 조회하여 직전 스냅샷과 비교합니다.
+/// 목록에서 사라진 주문은 [`ExchangeProvider::fetch_order_status`]로 최종 상태를 확인하고,
+/// 조회를 지원하지 않는 거래소는 마지막 체결수량으로 추정합니다
+/// (전량 체결이면 `Filled`, 아니면 `Cancelled`).
+///
+/// 폴링 주기 안에 접수와 체결이 모두 끝난 주문은 미체결 목록에 나타나지 않으므로
+/// 감지되지 않습니다.
+pub struct PollingOrderUpdater {
+    /// 거래소 provider
+    provider: Arc<dyn ExchangeProvider>,
+    /// 이벤트 허브
+    hub: Arc<OrderUpdateHub>,
+    /// 폴링 주기
+    interval: Duration,
+    /// 직전 미체결 주문 스냅샷 (order_id → 주문)
+    snapshot: tokio::sync::Mutex<HashMap<String, PendingOrder>>,
+}
+
+impl PollingOrderUpdater {
+    /// 새 폴링 스트림 생성.
+    pub fn new(provider: Arc<dyn ExchangeProvider>, interval: Duration) -> Self {
+        let hub = Arc::new(OrderUpdateHub::new(provider.exchange_name()));
+        Self {
+            provider,
+            hub,
+            interval,
+            snapshot: tokio::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 외부 허브 사용 (빌더 패턴).
+    ///
+    /// provider가 주문 접수/정정 결과를 직접 발행하는 허브와 폴링 이벤트를 합칠 때 사용합니다.
+    pub fn with_hub(mut self, hub: Arc<OrderUpdateHub>) -> Self {
+        self.hub = hub;
+        self
+    }
+
+    /// 이벤트 허브 반환 (push 소스를 함께 연결할 때 사용).
+    pub fn hub(&self) -> Arc<OrderUpdateHub> {
+        Arc::clone(&self.hub)
+    }
+
+    /// 폴링 주기.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// 한 번 폴링하여 변경 사항을 발행하고, 발행된 이벤트 수를 반환합니다.
+    pub async fn poll_once(&self) -> Result<usize, ProviderError> {
+        let pending = self.provider.fetch_pending_orders().await?;
+        let mut snapshot = self.snapshot.lock().await;
+
+        let mut current: HashMap<String, PendingOrder> = pending
+            .into_iter()
+            .map(|order| (order.order_id.clone(), order))
+            .collect();
+
+        let mut published = 0;
+        for order in current.values() {
+            if self.hub.publish(self.update_from_pending(order)) {
+                published += 1;
+            }
+        }
+
+        let vanished: Vec<PendingOrder> = snapshot
+            .iter()
+            .filter(|(order_id, _)| !current.contains_key(*order_id))
+            .map(|(_, order)| order.clone())
+            .collect();
+
+        for order in vanished {
+            let update = self.resolve_vanished(&order).await;
+            // 아직 최종 상태가 아니면 (거래소 반영 지연) 다음 폴링에서 다시 확인
+            if !update.is_final() {
+                current.insert(order.order_id.clone(), order);
+            }
+            if self.hub.publish(update) {
+                published += 1;
+            }
+        }
+
+        *snapshot = current;
+        Ok(published)
+    }
+
+    /// 백그라운드 폴링 태스크 시작.
+    ///
+    /// 반환된 핸들을 `abort()`하면 폴링이 중지됩니다.
+    pub fn spawn(self: Arc<Self>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.poll_once().await {
+                    warn!(
+                        exchange = %self.hub.exchange_name(),
+                        error = %e,
+                        "주문 상태 폴링 실패"
+                    );
+                }
+            }
+        })
+    }
+
+    /// 미체결 주문을 이벤트로 변환.
+    fn update_from_pending(&self, order: &PendingOrder) -> OrderUpdate {
+        OrderUpdate {
+            exchange: self.hub.exchange_name().to_string(),
+            order_id: order.order_id.clone(),
+            client_order_id: None,
+            ticker: Some(order.ticker.clone()),
+            status: order.status,
+            filled_quantity: order.filled_quantity,
+            average_price: (order.filled_quantity > Decimal::ZERO && order.price > Decimal::ZERO)
+                .then_some(order.price),
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// 미체결 목록에서 사라진 주문의 최종 상태 확인.
+    async fn resolve_vanished(&self, order: &PendingOrder) -> OrderUpdate {
+        match self
+            .provider
+            .fetch_order_status(&order.order_id, &order.ticker)
+            .await
+        {
+            Ok(status) => {
+                let mut update = OrderUpdate::from_status(self.hub.exchange_name(), &status);
+                update.ticker.get_or_insert_with(|| order.ticker.clone());
+                update
+            }
+            Err(e) => {
+                debug!(
+                    exchange = %self.hub.exchange_name(),
+                    order_id = %order.order_id,
+                    error = %e,
+                    "주문 상태 조회 불가, 마지막 체결수량으로 최종 상태 추정"
+                );
+                let mut update = self.update_from_pending(order);
+                update.status = if order.filled_quantity >= order.quantity {
+                    OrderStatusType::Filled
+                } else {
+                    OrderStatusType::Cancelled
+                };
+                update
+            }
+        }
+    }
+}
+
+impl OrderUpdateProvider for PollingOrderUpdater {
+    fn subscribe_order_updates(&self) -> broadcast::Receiver<OrderUpdate> {
+        self.hub.subscribe_order_updates()
+    }
+
+    fn exchange_name(&self) -> &str {
+        self.hub.exchange_name()
+    }
+}
+
+/// push 방식 사용자 스트림의 주문 업데이트를 허브로 전달하는 태스크 시작.
+///
+/// WebSocket 체결통보 등 거래소가 직접 보내는 주문 상태를 정규화된 이벤트로 변환합니다.
+/// 스트림이 종료되면 태스크도 종료됩니다.
+pub fn spawn_user_stream_forwarder<S>(mut stream: S, hub: Arc<OrderUpdateHub>) -> JoinHandle<()>
+where
+    S: UserStream + 'static,
+{
+    tokio::spawn(async move {
+        while let Some(event) = stream.next_event().await {
+            if let UserEvent::OrderUpdate(status) = event {
+                hub.publish(OrderUpdate::from_status(hub.exchange_name(), &status));
+            }
+        }
+        debug!(exchange = %hub.exchange_name(), "사용자 스트림 종료, 주문 업데이트 전달 중지");
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use tokio::sync::mpsc;
+    use trader_core::{OrderStatus, Side, StrategyAccountInfo, StrategyPositionInfo};
+
+    use super::*;
+    use crate::simulated::SimulatedUserStream;
+
+    /// 미체결 목록과 주문 상태를 직접 지정하는 테스트용 provider
+    struct ScriptedProvider {
+        pending: Mutex<Vec<PendingOrder>>,
+        statuses: Mutex<HashMap<String, OrderStatus>>,
+    }
+
+    impl ScriptedProvider {
+        fn new() -> Self {
+            Self {
+                pending: Mutex::new(Vec::new()),
+                statuses: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn set_pending(&self, orders: Vec<PendingOrder>) {
+            *self.pending.lock().unwrap() = orders;
+        }
+    }
+
+    #[async_trait]
+    impl ExchangeProvider for ScriptedProvider {
+        async fn fetch_account(&self) -> Result<StrategyAccountInfo, ProviderError> {
+            Err(ProviderError::Unsupported("test".to_string()))
+        }
+
+        async fn fetch_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
+            Ok(Vec::new())
+        }
+
+        async fn fetch_pending_orders(&self) -> Result<Vec<PendingOrder>, ProviderError> {
+            Ok(self.pending.lock().unwrap().clone())
+        }
+
+        fn exchange_name(&self) -> &str {
+            "scripted"
+        }
+
+        async fn fetch_order_status(
+            &self,
+            order_id: &str,
+            _ticker: &str,
+        ) -> Result<OrderStatus, ProviderError> {
+            self.statuses
+                .lock()
+                .unwrap()
+                .get(order_id)
+                .cloned()
+                .ok_or_else(|| ProviderError::Unsupported(order_id.to_string()))
+        }
+    }
+
+    fn pending(order_id: &str, filled: Decimal, status: OrderStatusType) -> PendingOrder {
+        PendingOrder {
+            order_id: order_id.to_string(),
+            ticker: "005930".to_string(),
+            side: Side::Buy,
+            price: dec!(70000),
+            quantity: dec!(10),
+            filled_quantity: filled,
+            status,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn order_status(order_id: &str, status: OrderStatusType, filled: Decimal) -> OrderStatus {
+        OrderStatus {
+            order_id: order_id.to_string(),
+            client_order_id: None,
+            ticker: None,
+            side: None,
+            quantity: None,
+            price: None,
+            status,
+            filled_quantity: filled,
+            average_price: Some(dec!(69900)),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_hub_drops_duplicate_regressed_and_post_final_events() {
+        let hub = OrderUpdateHub::new("test");
+        let mut rx = hub.subscribe_order_updates();
+        let update = |status, filled| OrderUpdate {
+            exchange: "test".to_string(),
+            order_id: "1".to_string(),
+            client_order_id: None,
+            ticker: None,
+            status,
+            filled_quantity: filled,
+            average_price: None,
+            timestamp: Utc::now(),
+        };
+
+        assert!(hub.publish(update(OrderStatusType::Open, dec!(0))));
+        assert!(!hub.publish(update(OrderStatusType::Open, dec!(0))));
+        assert!(hub.publish(update(OrderStatusType::PartiallyFilled, dec!(5))));
+        assert!(!hub.publish(update(OrderStatusType::PartiallyFilled, dec!(3))));
+        assert!(hub.publish(update(OrderStatusType::Filled, dec!(10))));
+        assert!(!hub.publish(update(OrderStatusType::Cancelled, dec!(10))));
+
+        let received: Vec<OrderStatusType> =
+            std::iter::from_fn(|| rx.try_recv().ok().map(|u| u.status)).collect();
+        assert_eq!(
+            received,
+            vec![
+                OrderStatusType::Open,
+                OrderStatusType::PartiallyFilled,
+                OrderStatusType::Filled
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_polling_emits_changes_and_resolves_vanished_orders() {
+        let provider = Arc::new(ScriptedProvider::new());
+        let updater = PollingOrderUpdater::new(provider.clone(), DEFAULT_POLL_INTERVAL);
+        let mut rx = updater.subscribe_order_updates();
+
+        provider.set_pending(vec![
+            pending("A", dec!(0), OrderStatusType::Open),
+            pending("B", dec!(0), OrderStatusType::Open),
+        ]);
+        assert_eq!(updater.poll_once().await.unwrap(), 2);
+        assert_eq!(updater.poll_once().await.unwrap(), 0);
+
+        provider.set_pending(vec![
+            pending("A", dec!(4), OrderStatusType::PartiallyFilled),
+            pending("B", dec!(0), OrderStatusType::Open),
+        ]);
+        assert_eq!(updater.poll_once().await.unwrap(), 1);
+
+        // A: 상태 조회 가능 → 거래소 응답 사용, B: 조회 불가 → 미체결로 사라졌으므로 취소 추정
+        provider.statuses.lock().unwrap().insert(
+            "A".to_string(),
+            order_status("A", OrderStatusType::Filled, dec!(10)),
+        );
+        provider.set_pending(Vec::new());
+        assert_eq!(updater.poll_once().await.unwrap(), 2);
+
+        let updates: Vec<OrderUpdate> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+        assert_eq!(updates.len(), 5);
+
+        let final_a = updates
+            .iter()
+            .find(|u| u.order_id == "A" && u.is_final())
+            .unwrap();
+        assert_eq!(final_a.status, OrderStatusType::Filled);
+        assert_eq!(final_a.filled_quantity, dec!(10));
+        assert_eq!(final_a.average_price, Some(dec!(69900)));
+        assert_eq!(final_a.ticker.as_deref(), Some("005930"));
+
+        let final_b = updates
+            .iter()
+            .find(|u| u.order_id == "B" && u.is_final())
+            .unwrap();
+        assert_eq!(final_b.status, OrderStatusType::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_user_stream_forwarder_publishes_push_updates() {
+        let (tx, rx) = mpsc::channel(8);
+        let mut stream = SimulatedUserStream::new(rx);
+        stream.start().await.unwrap();
+
+        let hub = Arc::new(OrderUpdateHub::new("simulated"));
+        let mut updates = hub.subscribe_order_updates();
+        let handle = spawn_user_stream_forwarder(stream, Arc::clone(&hub));
+
+        tx.send(UserEvent::OrderUpdate(order_status(
+            "X",
+            OrderStatusType::Filled,
+            dec!(1),
+        )))
+        .await
+        .unwrap();
+        drop(tx);
+
+        let update = updates.recv().await.unwrap();
+        assert_eq!(update.exchange, "simulated");
+        assert_eq!(update.status, OrderStatusType::Filled);
+        handle.await.unwrap();
+    }
+}
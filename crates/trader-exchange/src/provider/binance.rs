@@ -10,13 +10,16 @@
 //! │   ├── fetch_account() - USDT 기준 계좌
 //! │   ├── fetch_positions() - 보유 자산 → 포지션 변환
 //! │   ├── fetch_pending_orders() - 미체결 주문
-//! │   └── fetch_execution_history() - 체결 내역
+//! │   ├── fetch_execution_history() - 체결 내역
+//! │   └── fetch_order_status() - 단일 주문 상태
 //! ├── MarketDataProvider 구현
 //! │   └── get_quote(symbol) - 24hr 시세
 //! ├── OrderExecutionProvider 구현
 //! │   ├── place_order() - 주문 제출
 //! │   ├── cancel_order() - 주문 취소
-//! │   └── modify_order() - Unsupported (Spot 미지원)
+//! │   ├── modify_order() - Unsupported (Spot 미지원)
+//! │   ├── find_order_by_client_id() - origClientOrderId 조회
+//! │   └── supports_time_in_force() - GTC/IOC/FOK (GTD 미지원)
 //! └── 내부
 //!     ├── client: Arc<BinanceClient>
 //!     └── cache: Arc<ExchangeCache>
@@ -32,8 +35,8 @@ use trader_core::{
     cache::ExchangeCache,
     domain::{
         ExchangeProvider, ExecutionHistoryRequest, ExecutionHistoryResponse, MarketDataProvider,
-        OrderExecutionProvider, OrderResponse, PendingOrder, ProviderError, QuoteData, Side,
-        StrategyAccountInfo, StrategyPositionInfo, Trade,
+        OrderExecutionProvider, OrderResponse, OrderStatus, PendingOrder, ProviderError, QuoteData,
+        Side, StrategyAccountInfo, StrategyPositionInfo, TimeInForce, Trade,
     },
 };
 use uuid::Uuid;
@@ -43,12 +46,13 @@ use crate::{connector::binance::BinanceClient, ExchangeError};
 // ==================== 에러 변환 ====================
 
 /// ExchangeError → ProviderError 변환.
-fn to_provider_error(e: ExchangeError) -> ProviderError {
+pub(super) fn to_provider_error(e: ExchangeError) -> ProviderError {
     match e {
         ExchangeError::Unauthorized(msg) => ProviderError::Authentication(msg),
         ExchangeError::NetworkError(msg) | ExchangeError::Disconnected(msg) => {
             ProviderError::Network(msg)
         }
+        ExchangeError::Timeout(msg) => ProviderError::Timeout(msg),
         ExchangeError::RateLimited => ProviderError::Api("Rate limit exceeded".to_string()),
         ExchangeError::ParseError(msg) => ProviderError::Parse(msg),
         ExchangeError::NotSupported(msg) => ProviderError::Unsupported(msg),
@@ -353,6 +357,17 @@ impl ExchangeProvider for BinanceExchangeProvider {
         })
     }
 
+    async fn fetch_order_status(
+        &self,
+        order_id: &str,
+        ticker: &str,
+    ) -> Result<OrderStatus, ProviderError> {
+        self.client
+            .get_order(ticker, order_id)
+            .await
+            .map_err(to_provider_error)
+    }
+
     fn exchange_name(&self) -> &str {
         "Binance"
     }
@@ -446,6 +461,32 @@ impl OrderExecutionProvider for BinanceExchangeProvider {
         ))
     }
 
+    async fn find_order_by_client_id(
+        &self,
+        client_order_id: &str,
+        ticker: &str,
+    ) -> Result<Option<OrderResponse>, ProviderError> {
+        let order = self
+            .client
+            .get_order_by_client_id(ticker, client_order_id)
+            .await
+            .map_err(to_provider_error)?;
+
+        Ok(order.map(|o| OrderResponse {
+            order_no: o.order_id,
+            order_time: o.updated_at.format("%H%M%S").to_string(),
+        }))
+    }
+
+    fn supports_client_order_lookup(&self) -> bool {
+        true
+    }
+
+    fn supports_time_in_force(&self, time_in_force: &TimeInForce) -> bool {
+        // Spot 지정가 주문은 GTC/IOC/FOK만 지원 (GTD 미지원)
+        !matches!(time_in_force, TimeInForce::GTD(_))
+    }
+
     fn exchange_name(&self) -> &str {
         "Binance"
     }
@@ -487,6 +528,9 @@ mod tests {
         let err = to_provider_error(ExchangeError::NetworkError("timeout".to_string()));
         assert!(matches!(err, ProviderError::Network(_)));
 
+        let err = to_provider_error(ExchangeError::Timeout("read timeout".to_string()));
+        assert!(matches!(err, ProviderError::Timeout(_)));
+
         let err = to_provider_error(ExchangeError::RateLimited);
         assert!(matches!(err, ProviderError::Api(_)));
 
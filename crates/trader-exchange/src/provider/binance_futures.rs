@@ -0,0 +1,431 @@
+//! Binance USDT-M 선물 ExchangeProvider + OrderExecutionProvider 구현.
+//!
+//! BinanceClient의 `/fapi/` 엔드포인트를 래핑하여 거래소 중립적인 인터페이스를 제공합니다.
+//!
+//! # 아키텍처
+//!
+//! ```text
+//! BinanceFuturesProvider
+//! ├── ExchangeProvider 구현
+//! │   ├── fetch_account() - 선물 지갑/증거금 기준 계좌
+//! │   ├── fetch_positions() - 보유 포지션 (청산가, 증거금 포함)
+//! │   ├── fetch_pending_orders() - 미체결 주문
+//! │   └── fetch_order_status() - 단일 주문 상태
+//! ├── OrderExecutionProvider 구현
+//! │   ├── place_order() - 주문 제출 (reduce_only, 포지션 모드 반영)
+//! │   ├── cancel_order() - 주문 취소
+//! │   ├── modify_order() - Unsupported
+//! │   └── supports_time_in_force() - GTC/IOC/FOK/GTD
+//! ├── 선물 전용
+//! │   ├── position_mode() / set_position_mode() - 단방향/헤지 모드
+//! │   ├── set_leverage() - 레버리지 변경 (포지션 보유 시 거부)
+//! │   └── funding_rate() - 펀딩비 조회
+//! └── 내부
+//!     ├── client: Arc<BinanceClient>
+//!     ├── cache: Arc<ExchangeCache>
+//!     └── position_mode: RwLock<Option<PositionMode>>
+//! ```
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use tokio::sync::RwLock;
+use tracing::{debug, info};
+use trader_core::{
+    cache::ExchangeCache,
+    domain::{
+        ExchangeProvider, OrderExecutionProvider, OrderResponse, OrderStatus, PendingOrder,
+        ProviderError, StrategyAccountInfo, StrategyPositionInfo, TimeInForce,
+    },
+};
+
+use super::binance::to_provider_error;
+use crate::connector::binance::{BinanceClient, FundingRate, FuturesPosition, PositionMode};
+
+/// Binance USDT-M 선물 최대 레버리지.
+pub const MAX_FUTURES_LEVERAGE: u32 = 125;
+
+// ==================== Provider ====================
+
+/// Binance USDT-M 선물 ExchangeProvider + OrderExecutionProvider 구현.
+///
+/// 포지션 모드는 최초 주문 시 거래소에서 조회해 캐시하며,
+/// `set_position_mode()` 호출 시 갱신합니다.
+pub struct BinanceFuturesProvider {
+    /// Binance REST API 클라이언트
+    client: Arc<BinanceClient>,
+    /// 거래소 공용 캐시 (계좌, 포지션, 미체결 주문)
+    cache: Arc<ExchangeCache>,
+    /// 포지션 모드 캐시
+    position_mode: RwLock<Option<PositionMode>>,
+}
+
+impl BinanceFuturesProvider {
+    /// 새 BinanceFuturesProvider 생성.
+    pub fn new(client: Arc<BinanceClient>) -> Self {
+        Self {
+            client,
+            cache: Arc::new(ExchangeCache::with_defaults()),
+            position_mode: RwLock::new(None),
+        }
+    }
+
+    /// BinanceClient에서 생성.
+    pub fn from_client(client: BinanceClient) -> Self {
+        Self::new(Arc::new(client))
+    }
+
+    /// 공용 캐시 참조 반환.
+    pub fn exchange_cache(&self) -> Arc<ExchangeCache> {
+        Arc::clone(&self.cache)
+    }
+
+    /// 모든 캐시 무효화.
+    async fn invalidate_cache(&self) {
+        self.cache.invalidate_all().await;
+    }
+
+    /// 현재 포지션 모드 조회 (캐시 우선).
+    pub async fn position_mode(&self) -> Result<PositionMode, ProviderError> {
+        if let Some(mode) = *self.position_mode.read().await {
+            return Ok(mode);
+        }
+
+        let mode = self
+            .client
+            .futures_position_mode()
+            .await
+            .map_err(to_provider_error)?;
+        *self.position_mode.write().await = Some(mode);
+        Ok(mode)
+    }
+
+    /// 포지션 모드 변경.
+    ///
+    /// 포지션 또는 미체결 주문이 있으면 거래소가 거부하며 `ProviderError::Api`로 반환됩니다.
+    pub async fn set_position_mode(&self, mode: PositionMode) -> Result<(), ProviderError> {
+        self.client
+            .futures_set_position_mode(mode)
+            .await
+            .map_err(to_provider_error)?;
+
+        *self.position_mode.write().await = Some(mode);
+        self.invalidate_cache().await;
+        Ok(())
+    }
+
+    /// 심볼별 레버리지 변경. 적용된 레버리지를 반환합니다.
+    ///
+    /// # Errors
+    ///
+    /// - `ProviderError::Api`: 레버리지가 1~125 범위를 벗어나거나 해당 심볼에 포지션이 있음
+    pub async fn set_leverage(&self, ticker: &str, leverage: u32) -> Result<u32, ProviderError> {
+        let positions = self
+            .client
+            .futures_positions(Some(ticker))
+            .await
+            .map_err(to_provider_error)?;
+        validate_leverage_change(&positions, ticker, leverage)?;
+
+        let applied = self
+            .client
+            .futures_set_leverage(ticker, leverage)
+            .await
+            .map_err(to_provider_error)?;
+
+        self.invalidate_cache().await;
+        Ok(applied)
+    }
+
+    /// 펀딩비 조회.
+    pub async fn funding_rate(&self, ticker: &str) -> Result<FundingRate, ProviderError> {
+        self.client
+            .futures_funding_rate(ticker)
+            .await
+            .map_err(to_provider_error)
+    }
+}
+
+/// 레버리지 변경 가능 여부 검증.
+///
+/// 보유 포지션이 있는 상태에서 레버리지를 바꾸면 증거금/청산가가 즉시 변하므로 거부합니다.
+fn validate_leverage_change(
+    positions: &[FuturesPosition],
+    ticker: &str,
+    leverage: u32,
+) -> Result<(), ProviderError> {
+    if !(1..=MAX_FUTURES_LEVERAGE).contains(&leverage) {
+        return Err(ProviderError::Api(format!(
+            "레버리지는 1~{}배 사이여야 합니다: {}",
+            MAX_FUTURES_LEVERAGE, leverage
+        )));
+    }
+
+    if let Some(open) = positions.iter().find(|p| p.is_open()) {
+        return Err(ProviderError::Api(format!(
+            "{} 포지션 보유 중에는 레버리지를 변경할 수 없습니다 (수량: {}, 현재 레버리지: {}배)",
+            ticker, open.quantity, open.leverage
+        )));
+    }
+
+    Ok(())
+}
+
+/// FuturesPosition → StrategyPositionInfo 변환.
+fn to_position_info(position: &FuturesPosition) -> StrategyPositionInfo {
+    let mut info = StrategyPositionInfo::new(
+        position.ticker.clone(),
+        position.side(),
+        position.quantity.abs(),
+        position.entry_price,
+    );
+    info.update_price(position.mark_price);
+    info.liquidation_price =
+        (position.liquidation_price > Decimal::ZERO).then_some(position.liquidation_price);
+    info.margin = Some(position.margin);
+    info
+}
+
+// ==================== ExchangeProvider ====================
+
+#[async_trait]
+impl ExchangeProvider for BinanceFuturesProvider {
+    async fn fetch_account(&self) -> Result<StrategyAccountInfo, ProviderError> {
+        if let Some(cached) = self.cache.get_account().await {
+            debug!("Binance 선물 계좌 정보 캐시 히트");
+            return Ok(cached);
+        }
+
+        let account = self
+            .client
+            .futures_account()
+            .await
+            .map_err(to_provider_error)?;
+
+        let result = StrategyAccountInfo {
+            total_balance: account.total_margin_balance,
+            available_balance: account.available_balance,
+            margin_used: account.total_initial_margin,
+            unrealized_pnl: account.total_unrealized_pnl,
+            currency: "USDT".to_string(),
+        };
+
+        self.cache.set_account(result.clone()).await;
+        Ok(result)
+    }
+
+    async fn fetch_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
+        if let Some(cached) = self.cache.get_positions().await {
+            debug!("Binance 선물 포지션 캐시 히트");
+            return Ok(cached);
+        }
+
+        let positions: Vec<StrategyPositionInfo> = self
+            .client
+            .futures_positions(None)
+            .await
+            .map_err(to_provider_error)?
+            .iter()
+            .filter(|p| p.is_open())
+            .map(to_position_info)
+            .collect();
+
+        self.cache.set_positions(positions.clone()).await;
+        Ok(positions)
+    }
+
+    async fn fetch_pending_orders(&self) -> Result<Vec<PendingOrder>, ProviderError> {
+        if let Some(cached) = self.cache.get_pending_orders().await {
+            debug!("Binance 선물 미체결 주문 캐시 히트");
+            return Ok(cached);
+        }
+
+        let orders = self
+            .client
+            .futures_open_orders(None)
+            .await
+            .map_err(to_provider_error)?;
+
+        let pending_orders: Vec<PendingOrder> = orders
+            .into_iter()
+            .filter_map(|order| {
+                Some(PendingOrder {
+                    ticker: order.ticker?,
+                    side: order.side?,
+                    price: order.price?,
+                    quantity: order.quantity?,
+                    order_id: order.order_id,
+                    filled_quantity: order.filled_quantity,
+                    status: order.status,
+                    created_at: order.updated_at,
+                })
+            })
+            .collect();
+
+        self.cache.set_pending_orders(pending_orders.clone()).await;
+        Ok(pending_orders)
+    }
+
+    async fn fetch_order_status(
+        &self,
+        order_id: &str,
+        ticker: &str,
+    ) -> Result<OrderStatus, ProviderError> {
+        self.client
+            .futures_get_order(ticker, order_id)
+            .await
+            .map_err(to_provider_error)
+    }
+
+    fn exchange_name(&self) -> &str {
+        "BinanceFutures"
+    }
+}
+
+// ==================== OrderExecutionProvider ====================
+
+#[async_trait]
+impl OrderExecutionProvider for BinanceFuturesProvider {
+    async fn place_order(
+        &self,
+        request: &trader_core::domain::OrderRequest,
+    ) -> Result<OrderResponse, ProviderError> {
+        let mode = self.position_mode().await?;
+        let position_side = mode.position_side_for(request.side, request.reduce_only);
+
+        info!(
+            "Binance 선물 주문 제출: {} {} {} @ {:?} (reduce_only: {}, {:?})",
+            request.side,
+            request.quantity,
+            request.ticker,
+            request.price,
+            request.reduce_only,
+            mode
+        );
+
+        let order_id = self
+            .client
+            .futures_place_order(request, position_side)
+            .await
+            .map_err(to_provider_error)?;
+
+        self.invalidate_cache().await;
+
+        Ok(OrderResponse {
+            order_no: order_id,
+            order_time: Utc::now().format("%H%M%S").to_string(),
+        })
+    }
+
+    async fn cancel_order(&self, order_id: &str, ticker: &str) -> Result<(), ProviderError> {
+        info!("Binance 선물 주문 취소: {} ({})", order_id, ticker);
+
+        self.client
+            .futures_cancel_order(ticker, order_id)
+            .await
+            .map_err(to_provider_error)?;
+
+        self.invalidate_cache().await;
+
+        Ok(())
+    }
+
+    async fn modify_order(
+        &self,
+        _order_id: &str,
+        _ticker: &str,
+        _quantity: Option<Decimal>,
+        _price: Option<Decimal>,
+    ) -> Result<OrderResponse, ProviderError> {
+        Err(ProviderError::Unsupported(
+            "Binance 선물 주문 정정은 지원하지 않습니다. cancel + place로 처리하세요.".to_string(),
+        ))
+    }
+
+    async fn find_order_by_client_id(
+        &self,
+        client_order_id: &str,
+        ticker: &str,
+    ) -> Result<Option<OrderResponse>, ProviderError> {
+        let order = self
+            .client
+            .futures_get_order_by_client_id(ticker, client_order_id)
+            .await
+            .map_err(to_provider_error)?;
+
+        Ok(order.map(|o| OrderResponse {
+            order_no: o.order_id,
+            order_time: o.updated_at.format("%H%M%S").to_string(),
+        }))
+    }
+
+    fn supports_client_order_lookup(&self) -> bool {
+        true
+    }
+
+    fn supports_time_in_force(&self, _time_in_force: &TimeInForce) -> bool {
+        // 선물 지정가 주문은 GTC/IOC/FOK/GTD 모두 지원
+        true
+    }
+
+    fn exchange_name(&self) -> &str {
+        "BinanceFutures"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connector::binance::FuturesPositionSide;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+
+    fn position(quantity: Decimal) -> FuturesPosition {
+        FuturesPosition {
+            ticker: "BTC/USDT".to_string(),
+            position_side: FuturesPositionSide::Both,
+            quantity,
+            entry_price: dec!(60000),
+            mark_price: dec!(61000),
+            unrealized_pnl: dec!(1000),
+            liquidation_price: dec!(48000),
+            leverage: 5,
+            isolated: false,
+            margin: dec!(12200),
+        }
+    }
+
+    #[test]
+    fn test_leverage_change_rejected_with_open_position() {
+        let err = validate_leverage_change(&[position(dec!(1))], "BTC/USDT", 10).unwrap_err();
+        assert!(matches!(err, ProviderError::Api(msg) if msg.contains("포지션 보유 중")));
+
+        // 수량 0 항목만 있으면 허용
+        assert!(validate_leverage_change(&[position(Decimal::ZERO)], "BTC/USDT", 10).is_ok());
+        assert!(validate_leverage_change(&[], "BTC/USDT", 10).is_ok());
+    }
+
+    #[test]
+    fn test_leverage_range() {
+        assert!(validate_leverage_change(&[], "BTC/USDT", 0).is_err());
+        assert!(validate_leverage_change(&[], "BTC/USDT", 126).is_err());
+        assert!(validate_leverage_change(&[], "BTC/USDT", 125).is_ok());
+    }
+
+    #[test]
+    fn test_to_position_info_includes_liquidation_and_margin() {
+        let info = to_position_info(&position(dec!(-2)));
+        assert_eq!(info.side, Side::Sell);
+        assert_eq!(info.quantity, dec!(2));
+        assert_eq!(info.current_price, dec!(61000));
+        assert_eq!(info.unrealized_pnl, dec!(-2000));
+        assert_eq!(info.liquidation_price, Some(dec!(48000)));
+        assert_eq!(info.margin, Some(dec!(12200)));
+
+        let mut no_liq = position(dec!(1));
+        no_liq.liquidation_price = Decimal::ZERO;
+        assert_eq!(to_position_info(&no_liq).liquidation_price, None);
+    }
+}
@@ -0,0 +1,266 @@
+//! Bybit ExchangeProvider + MarketDataProvider 구현.
+//!
+//! BybitClient(V5 통합 API)를 래핑하여 거래소 중립적인 인터페이스를 제공합니다.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use tracing::{debug, info};
+use trader_core::{
+    cache::ExchangeCache,
+    domain::{
+        ExchangeProvider, ExecutionHistoryRequest, ExecutionHistoryResponse, MarketDataProvider,
+        OrderExecutionProvider, OrderRequest, OrderResponse, PendingOrder, ProviderError,
+        QuoteData, Side, StrategyAccountInfo, StrategyPositionInfo, TimeInForce,
+    },
+};
+
+use crate::connector::bybit::{from_bybit_symbol, BybitClient};
+
+/// Bybit ExchangeProvider 구현.
+///
+/// BybitClient를 래핑하여 캐싱 레이어를 추가합니다.
+pub struct BybitExchangeProvider {
+    client: Arc<BybitClient>,
+    cache: Arc<ExchangeCache>,
+}
+
+/// 하위 호환성을 위한 타입 별칭.
+pub type BybitProvider = BybitExchangeProvider;
+
+impl BybitExchangeProvider {
+    /// 새 BybitExchangeProvider 생성.
+    pub fn new(client: Arc<BybitClient>) -> Self {
+        Self {
+            client,
+            cache: Arc::new(ExchangeCache::with_defaults()),
+        }
+    }
+
+    /// 공용 캐시 참조 반환.
+    pub fn exchange_cache(&self) -> Arc<ExchangeCache> {
+        Arc::clone(&self.cache)
+    }
+
+    /// 서버 시각 동기화 (서명 요청 전 자동 수행되며, 연결 직후 명시적으로 호출 가능).
+    pub async fn sync_time(&self) -> Result<i64, ProviderError> {
+        self.client.sync_time().await
+    }
+}
+
+// ==================== ExchangeProvider ====================
+
+#[async_trait]
+impl ExchangeProvider for BybitExchangeProvider {
+    async fn fetch_account(&self) -> Result<StrategyAccountInfo, ProviderError> {
+        if let Some(cached) = self.cache.get_account().await {
+            debug!("Bybit 계좌 정보 캐시 히트");
+            return Ok(cached);
+        }
+
+        let result = self.client.fetch_account().await?;
+        self.cache.set_account(result.clone()).await;
+        Ok(result)
+    }
+
+    async fn fetch_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
+        if let Some(cached) = self.cache.get_positions().await {
+            debug!("Bybit 포지션 캐시 히트");
+            return Ok(cached);
+        }
+
+        let result = self.client.fetch_positions().await?;
+        self.cache.set_positions(result.clone()).await;
+        Ok(result)
+    }
+
+    async fn fetch_pending_orders(&self) -> Result<Vec<PendingOrder>, ProviderError> {
+        if let Some(cached) = self.cache.get_pending_orders().await {
+            debug!("Bybit 미체결 주문 캐시 히트");
+            return Ok(cached);
+        }
+
+        let result = self.client.fetch_pending_orders().await?;
+        self.cache.set_pending_orders(result.clone()).await;
+        Ok(result)
+    }
+
+    async fn fetch_execution_history(
+        &self,
+        request: &ExecutionHistoryRequest,
+    ) -> Result<ExecutionHistoryResponse, ProviderError> {
+        use std::str::FromStr;
+
+        use chrono::{TimeZone, Utc};
+        use trader_core::domain::Trade;
+        use uuid::Uuid;
+
+        let page = self
+            .client
+            .fetch_executions(
+                &request.start_date,
+                &request.end_date,
+                request.cursor.as_deref(),
+            )
+            .await?;
+
+        let trades = page
+            .list
+            .into_iter()
+            .map(|exec| {
+                let side = if exec.side.eq_ignore_ascii_case("buy") {
+                    Side::Buy
+                } else {
+                    Side::Sell
+                };
+                let executed_at = exec
+                    .exec_time
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(|ms| Utc.timestamp_millis_opt(ms).single())
+                    .unwrap_or_else(Utc::now);
+                let fee_currency = if exec.fee_currency.is_empty() {
+                    "USDT".to_string()
+                } else {
+                    exec.fee_currency.clone()
+                };
+
+                Trade {
+                    id: Uuid::new_v4(),
+                    // Bybit orderId는 UUID 형식 (파싱 실패 시 새 UUID)
+                    order_id: Uuid::parse_str(&exec.order_id).unwrap_or_else(|_| Uuid::new_v4()),
+                    exchange: "bybit".to_string(),
+                    exchange_trade_id: exec.exec_id.clone(),
+                    ticker: from_bybit_symbol(&exec.symbol),
+                    side,
+                    quantity: Decimal::from_str(&exec.exec_qty).unwrap_or_default(),
+                    price: Decimal::from_str(&exec.exec_price).unwrap_or_default(),
+                    fee: Decimal::from_str(&exec.exec_fee).unwrap_or_default(),
+                    fee_currency,
+                    executed_at,
+                    is_maker: exec.is_maker,
+                    metadata: serde_json::json!({
+                        "order_id": exec.order_id,
+                        "order_link_id": exec.order_link_id,
+                        "category": self.client.category().as_str(),
+                    }),
+                }
+            })
+            .collect();
+
+        Ok(ExecutionHistoryResponse {
+            trades,
+            next_cursor: page.next_page_cursor.filter(|c| !c.is_empty()),
+        })
+    }
+
+    fn exchange_name(&self) -> &str {
+        "bybit"
+    }
+}
+
+// ==================== MarketDataProvider ====================
+
+#[async_trait]
+impl MarketDataProvider for BybitExchangeProvider {
+    async fn get_quote(&self, symbol: &str) -> Result<QuoteData, ProviderError> {
+        self.client.get_quote(symbol).await
+    }
+
+    async fn get_quotes(&self, symbols: &[String]) -> Vec<QuoteData> {
+        self.client.get_quotes(symbols).await
+    }
+
+    fn provider_name(&self) -> &str {
+        "bybit"
+    }
+}
+
+// ==================== OrderExecutionProvider ====================
+
+#[async_trait]
+impl OrderExecutionProvider for BybitExchangeProvider {
+    async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ProviderError> {
+        info!(
+            ticker = %request.ticker,
+            side = ?request.side,
+            order_type = %request.order_type,
+            quantity = %request.quantity,
+            price = ?request.price,
+            "Bybit 주문 생성"
+        );
+
+        let ack = self.client.place_order(request).await?;
+
+        // 캐시 무효화
+        self.cache.invalidate_all().await;
+
+        Ok(OrderResponse {
+            order_no: ack.order_id,
+            order_time: chrono::Utc::now().format("%H%M%S").to_string(),
+        })
+    }
+
+    async fn cancel_order(&self, order_id: &str, ticker: &str) -> Result<(), ProviderError> {
+        info!(order_id = order_id, ticker = ticker, "Bybit 주문 취소");
+
+        self.client.cancel_order(ticker, order_id).await?;
+
+        // 캐시 무효화
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+
+    async fn modify_order(
+        &self,
+        order_id: &str,
+        ticker: &str,
+        quantity: Option<Decimal>,
+        price: Option<Decimal>,
+    ) -> Result<OrderResponse, ProviderError> {
+        info!(order_id = order_id, ticker = ticker, "Bybit 주문 정정");
+
+        let ack = self
+            .client
+            .amend_order(ticker, order_id, quantity, price)
+            .await?;
+
+        // 캐시 무효화
+        self.cache.invalidate_all().await;
+
+        Ok(OrderResponse {
+            order_no: ack.order_id,
+            order_time: chrono::Utc::now().format("%H%M%S").to_string(),
+        })
+    }
+
+    async fn find_order_by_client_id(
+        &self,
+        client_order_id: &str,
+        ticker: &str,
+    ) -> Result<Option<OrderResponse>, ProviderError> {
+        let order = self
+            .client
+            .find_order_by_link_id(ticker, client_order_id)
+            .await?;
+
+        Ok(order.map(|o| OrderResponse {
+            order_no: o.order_id,
+            order_time: o.created_time,
+        }))
+    }
+
+    fn supports_client_order_lookup(&self) -> bool {
+        true
+    }
+
+    fn supports_time_in_force(&self, time_in_force: &TimeInForce) -> bool {
+        !matches!(time_in_force, TimeInForce::GTD(_))
+    }
+
+    fn exchange_name(&self) -> &str {
+        "bybit"
+    }
+}
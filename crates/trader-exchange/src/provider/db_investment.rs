@@ -1,8 +1,12 @@
 //! DB Investment ExchangeProvider + MarketDataProvider 구현.
 //!
 //! DbInvestmentClient를 래핑하여 거래소 중립적인 인터페이스를 제공합니다.
+//!
+//! DB증권은 WebSocket 체결통보를 제공하지 않으므로, 주문 상태 변경은 접수/정정 응답을
+//! 즉시 발행하고 체결·취소는 미체결 주문 폴링으로 감지합니다
+//! ([`DbInvestmentExchangeProvider::order_update_stream`]).
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDateTime, Utc};
@@ -12,13 +16,17 @@ use trader_core::{
     cache::ExchangeCache,
     domain::{
         ExchangeProvider, ExecutionHistoryRequest, ExecutionHistoryResponse, MarketDataProvider,
-        OrderExecutionProvider, OrderRequest, OrderResponse, OrderType, PendingOrder,
-        ProviderError, QuoteData, StrategyAccountInfo, StrategyPositionInfo, Trade,
+        OrderExecutionProvider, OrderRequest, OrderResponse, OrderStatusType, OrderType,
+        OrderUpdate, PendingOrder, ProviderError, QuoteData, StrategyAccountInfo,
+        StrategyPositionInfo, Trade,
     },
 };
 use uuid::Uuid;
 
-use crate::connector::db_investment::DbInvestmentClient;
+use crate::{
+    connector::db_investment::DbInvestmentClient,
+    order_update::{OrderUpdateHub, PollingOrderUpdater},
+};
 
 /// DB Investment ExchangeProvider 구현.
 ///
@@ -26,6 +34,7 @@ use crate::connector::db_investment::DbInvestmentClient;
 pub struct DbInvestmentExchangeProvider {
     client: Arc<DbInvestmentClient>,
     cache: Arc<ExchangeCache>,
+    order_updates: Arc<OrderUpdateHub>,
 }
 
 /// 하위 호환성을 위한 타입 별칭.
@@ -37,6 +46,7 @@ impl DbInvestmentExchangeProvider {
         Self {
             client,
             cache: Arc::new(ExchangeCache::with_defaults()),
+            order_updates: Arc::new(OrderUpdateHub::new("db_investment")),
         }
     }
 
@@ -44,6 +54,34 @@ impl DbInvestmentExchangeProvider {
     pub fn exchange_cache(&self) -> Arc<ExchangeCache> {
         Arc::clone(&self.cache)
     }
+
+    /// 주문 접수/정정 이벤트를 발행하는 허브 반환.
+    pub fn order_update_hub(&self) -> Arc<OrderUpdateHub> {
+        Arc::clone(&self.order_updates)
+    }
+
+    /// 주문 상태 스트림 생성.
+    ///
+    /// 접수/정정 이벤트와 미체결 주문 폴링 결과를 같은 허브로 발행합니다.
+    /// 반환된 updater의 `spawn()`을 호출해야 폴링이 시작됩니다.
+    pub fn order_update_stream(self: Arc<Self>, interval: Duration) -> Arc<PollingOrderUpdater> {
+        let hub = self.order_update_hub();
+        Arc::new(PollingOrderUpdater::new(self, interval).with_hub(hub))
+    }
+
+    /// 접수된 주문의 상태 이벤트 발행.
+    fn publish_accepted(&self, order_no: &str, ticker: &str, client_order_id: Option<String>) {
+        self.order_updates.publish(OrderUpdate {
+            exchange: "db_investment".to_string(),
+            order_id: order_no.to_string(),
+            client_order_id,
+            ticker: Some(ticker.to_string()),
+            status: OrderStatusType::Open,
+            filled_quantity: Decimal::ZERO,
+            average_price: None,
+            timestamp: Utc::now(),
+        });
+    }
 }
 
 // ==================== ExchangeProvider ====================
@@ -221,6 +259,11 @@ impl OrderExecutionProvider for DbInvestmentExchangeProvider {
 
         // 캐시 무효화 (주문 후 포지션/계좌 변동)
         self.cache.invalidate_all().await;
+        self.publish_accepted(
+            &response.order_no,
+            &request.ticker,
+            request.client_order_id.clone(),
+        );
 
         Ok(response)
     }
@@ -282,6 +325,8 @@ impl OrderExecutionProvider for DbInvestmentExchangeProvider {
 
         // 캐시 무효화
         self.cache.invalidate_all().await;
+        // 정정 주문은 새 주문번호로 접수됨
+        self.publish_accepted(&response.order_no, ticker, None);
 
         Ok(response)
     }
@@ -0,0 +1,316 @@
+//! 거래소 API 호출 계측 Provider.
+//!
+//! [`InstrumentedProvider`]는 기존 provider를 감싸서 모든 호출의 소요 시간과
+//! 성공/실패를 [`trader_core::telemetry`]에 정의된 메트릭으로 기록합니다. 라벨은
+//! `exchange`/`operation`만 사용하며 심볼은 기록하지 않습니다.
+//!
+//! ```text
+//! InstrumentedProvider::new(provider, "upbit")
+//! ├── fetch_account()  → exchange_api_requests_total{exchange="upbit", operation="fetch_account"}
+//! ├── place_order()    → exchange_api_request_duration_seconds{...}
+//! └── 실패 시          → exchange_api_errors_total{..., kind="network"}
+//! ```
+
+use std::{future::Future, sync::Arc, time::Instant};
+
+use async_trait::async_trait;
+use metrics::{counter, histogram};
+use rust_decimal::Decimal;
+use trader_core::{
+    domain::{
+        ExchangeProvider, ExecutionHistoryRequest, ExecutionHistoryResponse, MarketDataProvider,
+        OrderExecutionProvider, OrderRequest, OrderResponse, OrderSession, OrderStatus,
+        PendingOrder, ProviderError, QuoteData, StrategyAccountInfo, StrategyPositionInfo,
+        SymbolOrderRule, TimeInForce,
+    },
+    telemetry::{
+        ApiCallOutcome, EXCHANGE_API_ERRORS_TOTAL, EXCHANGE_API_REQUESTS_TOTAL,
+        EXCHANGE_API_REQUEST_DURATION_SECONDS,
+    },
+};
+
+/// 거래소 API 호출 결과 기록.
+fn record_exchange_api_call(
+    exchange: &str,
+    operation: &'static str,
+    duration_secs: f64,
+    error: Option<&ProviderError>,
+) {
+    let outcome = ApiCallOutcome::from_error(error);
+    counter!(
+        EXCHANGE_API_REQUESTS_TOTAL,
+        "exchange" => exchange.to_string(),
+        "operation" => operation,
+        "outcome" => outcome.as_str()
+    )
+    .increment(1);
+    histogram!(
+        EXCHANGE_API_REQUEST_DURATION_SECONDS,
+        "exchange" => exchange.to_string(),
+        "operation" => operation
+    )
+    .record(duration_secs);
+
+    if let Some(kind) = outcome.error_kind() {
+        counter!(
+            EXCHANGE_API_ERRORS_TOTAL,
+            "exchange" => exchange.to_string(),
+            "operation" => operation,
+            "kind" => kind
+        )
+        .increment(1);
+    }
+}
+
+/// 호출 메트릭을 기록하는 provider 래퍼.
+///
+/// 감싼 provider가 구현하는 trait(`ExchangeProvider`, `MarketDataProvider`,
+/// `OrderExecutionProvider`)을 그대로 구현하며, 기본 구현이 있는 메서드도
+/// 내부 provider로 위임합니다.
+pub struct InstrumentedProvider<P: ?Sized> {
+    inner: Arc<P>,
+    exchange: String,
+}
+
+impl<P: ?Sized> InstrumentedProvider<P> {
+    /// 새 InstrumentedProvider 생성.
+    ///
+    /// `exchange`는 메트릭의 `exchange` 라벨 값입니다 (예: `"upbit"`, `"kis"`).
+    pub fn new(inner: Arc<P>, exchange: impl Into<String>) -> Self {
+        Self {
+            inner,
+            exchange: exchange.into(),
+        }
+    }
+
+    /// 내부 provider 참조 반환.
+    pub fn inner(&self) -> &Arc<P> {
+        &self.inner
+    }
+
+    /// 메트릭 라벨로 사용하는 거래소 이름.
+    pub fn exchange_label(&self) -> &str {
+        &self.exchange
+    }
+
+    async fn observe<T, F>(&self, operation: &'static str, call: F) -> Result<T, ProviderError>
+    where
+        F: Future<Output = Result<T, ProviderError>>,
+    {
+        let started = Instant::now();
+        let result = call.await;
+        record_exchange_api_call(
+            &self.exchange,
+            operation,
+            started.elapsed().as_secs_f64(),
+            result.as_ref().err(),
+        );
+        result
+    }
+}
+
+#[async_trait]
+impl<P> ExchangeProvider for InstrumentedProvider<P>
+where
+    P: ExchangeProvider + ?Sized,
+{
+    async fn fetch_account(&self) -> Result<StrategyAccountInfo, ProviderError> {
+        self.observe("fetch_account", self.inner.fetch_account())
+            .await
+    }
+
+    async fn fetch_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
+        self.observe("fetch_positions", self.inner.fetch_positions())
+            .await
+    }
+
+    async fn fetch_pending_orders(&self) -> Result<Vec<PendingOrder>, ProviderError> {
+        self.observe("fetch_pending_orders", self.inner.fetch_pending_orders())
+            .await
+    }
+
+    fn exchange_name(&self) -> &str {
+        self.inner.exchange_name()
+    }
+
+    async fn fetch_execution_history(
+        &self,
+        request: &ExecutionHistoryRequest,
+    ) -> Result<ExecutionHistoryResponse, ProviderError> {
+        self.observe(
+            "fetch_execution_history",
+            self.inner.fetch_execution_history(request),
+        )
+        .await
+    }
+
+    async fn fetch_order_status(
+        &self,
+        order_id: &str,
+        ticker: &str,
+    ) -> Result<OrderStatus, ProviderError> {
+        self.observe(
+            "fetch_order_status",
+            self.inner.fetch_order_status(order_id, ticker),
+        )
+        .await
+    }
+
+    async fn fetch_symbol_order_rules(
+        &self,
+        tickers: &[String],
+    ) -> Result<Vec<SymbolOrderRule>, ProviderError> {
+        self.observe(
+            "fetch_symbol_order_rules",
+            self.inner.fetch_symbol_order_rules(tickers),
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl<P> MarketDataProvider for InstrumentedProvider<P>
+where
+    P: MarketDataProvider + ?Sized,
+{
+    async fn get_quote(&self, symbol: &str) -> Result<QuoteData, ProviderError> {
+        self.observe("get_quote", self.inner.get_quote(symbol))
+            .await
+    }
+
+    fn provider_name(&self) -> &str {
+        self.inner.provider_name()
+    }
+
+    /// 일괄 조회는 실패한 심볼을 결과에서 제외할 뿐 에러를 반환하지 않으므로
+    /// 항상 성공으로 기록합니다.
+    async fn get_quotes(&self, symbols: &[String]) -> Vec<QuoteData> {
+        let started = Instant::now();
+        let quotes = self.inner.get_quotes(symbols).await;
+        record_exchange_api_call(
+            &self.exchange,
+            "get_quotes",
+            started.elapsed().as_secs_f64(),
+            None,
+        );
+        quotes
+    }
+}
+
+#[async_trait]
+impl<P> OrderExecutionProvider for InstrumentedProvider<P>
+where
+    P: OrderExecutionProvider + ?Sized,
+{
+    async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ProviderError> {
+        self.observe("place_order", self.inner.place_order(request))
+            .await
+    }
+
+    async fn cancel_order(&self, order_id: &str, ticker: &str) -> Result<(), ProviderError> {
+        self.observe("cancel_order", self.inner.cancel_order(order_id, ticker))
+            .await
+    }
+
+    async fn modify_order(
+        &self,
+        order_id: &str,
+        ticker: &str,
+        quantity: Option<Decimal>,
+        price: Option<Decimal>,
+    ) -> Result<OrderResponse, ProviderError> {
+        self.observe(
+            "modify_order",
+            self.inner.modify_order(order_id, ticker, quantity, price),
+        )
+        .await
+    }
+
+    async fn find_order_by_client_id(
+        &self,
+        client_order_id: &str,
+        ticker: &str,
+    ) -> Result<Option<OrderResponse>, ProviderError> {
+        self.observe(
+            "find_order_by_client_id",
+            self.inner.find_order_by_client_id(client_order_id, ticker),
+        )
+        .await
+    }
+
+    fn supports_client_order_lookup(&self) -> bool {
+        self.inner.supports_client_order_lookup()
+    }
+
+    fn supports_time_in_force(&self, time_in_force: &TimeInForce) -> bool {
+        self.inner.supports_time_in_force(time_in_force)
+    }
+
+    fn supports_order_session(&self, session: &OrderSession) -> bool {
+        self.inner.supports_order_session(session)
+    }
+
+    fn exchange_name(&self) -> &str {
+        OrderExecutionProvider::exchange_name(self.inner.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StubProvider {
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl ExchangeProvider for StubProvider {
+        async fn fetch_account(&self) -> Result<StrategyAccountInfo, ProviderError> {
+            if self.fail {
+                return Err(ProviderError::Network("연결 끊김".to_string()));
+            }
+            Ok(StrategyAccountInfo {
+                total_balance: Decimal::new(1000, 0),
+                available_balance: Decimal::new(1000, 0),
+                margin_used: Decimal::ZERO,
+                unrealized_pnl: Decimal::ZERO,
+                currency: "KRW".to_string(),
+            })
+        }
+
+        async fn fetch_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
+            Ok(vec![])
+        }
+
+        async fn fetch_pending_orders(&self) -> Result<Vec<PendingOrder>, ProviderError> {
+            Ok(vec![])
+        }
+
+        fn exchange_name(&self) -> &str {
+            "stub"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_forwards_results_and_errors() {
+        let ok: Arc<dyn ExchangeProvider> = Arc::new(StubProvider { fail: false });
+        let provider = InstrumentedProvider::new(ok, "stub");
+        let account = provider.fetch_account().await.unwrap();
+        assert_eq!(account.currency, "KRW");
+        assert_eq!(ExchangeProvider::exchange_name(&provider), "stub");
+
+        let failing = InstrumentedProvider::new(Arc::new(StubProvider { fail: true }), "stub");
+        let err = failing.fetch_account().await.unwrap_err();
+        assert!(matches!(err, ProviderError::Network(_)));
+    }
+
+    #[tokio::test]
+    async fn test_forwards_default_methods() {
+        let provider = InstrumentedProvider::new(Arc::new(StubProvider { fail: false }), "stub");
+        let err = provider
+            .fetch_order_status("order-1", "BTC/KRW")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ProviderError::Unsupported(_)));
+    }
+}
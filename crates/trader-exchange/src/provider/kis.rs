@@ -12,6 +12,8 @@
 //! │   ├── fetch_account() - 국내/해외 통합 계좌
 //! │   ├── fetch_positions() - 국내/해외 통합 포지션
 //! │   └── fetch_pending_orders() - 국내/해외 통합 주문
+//! ├── OrderExecutionProvider 구현
+//! │   └── place_order() - 정규장/장전 시간외/시간외 단일가/예약 주문
 //! ├── MarketDataProvider 구현
 //! │   └── get_quote(symbol) - 심볼 패턴으로 국내/해외 분기
 //! └── 내부
@@ -19,27 +21,31 @@
 //!     └── oauth: Arc<KisOAuth> (공유)
 //! ```
 
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
-use chrono::{TimeZone, Utc};
+use chrono::{DateTime, Datelike, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Asia::Seoul;
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use tokio::{sync::Mutex, task::AbortHandle};
 use tracing::{debug, info, warn};
 use trader_core::{
     cache::{ExchangeCache, TtlCache},
     domain::{
         ExchangeProvider, ExecutionHistoryRequest, ExecutionHistoryResponse, MarketDataProvider,
-        OrderExecution, OrderExecutionProvider, OrderRequest, OrderResponse, OrderType,
-        PendingOrder, ProviderError, QuoteData, Side, StrategyAccountInfo, StrategyPositionInfo,
-        Trade,
+        OrderExecution, OrderExecutionProvider, OrderRequest, OrderResponse, OrderSession,
+        OrderType, PendingOrder, ProviderError, QuoteData, Side, StrategyAccountInfo,
+        StrategyPositionInfo, Trade,
     },
-    types::{MarketType, Symbol},
+    types::{MarketType, Symbol, SymbolNormalizer, SymbolVenue},
     OrderStatusType,
 };
 use uuid::Uuid;
 
-use crate::connector::kis::{
-    client::KisClient, client_kr::KrOrderExecution, config::KisAccountType,
+use crate::{
+    connector::kis::{client::KisClient, client_kr::KrOrderExecution, config::KisAccountType},
+    ExchangeError,
 };
 
 // ==================== 캐시 설정 ====================
@@ -47,11 +53,29 @@ use crate::connector::kis::{
 /// 체결 내역 캐시 TTL (10분, ISA 전용)
 const ORDER_HISTORY_CACHE_TTL: Duration = Duration::from_secs(600);
 
+// ==================== 주문 세션 ====================
+
+/// 장전 시간외 주문 구분 코드 (ORD_DVSN)
+const KR_ORD_DVSN_PRE_MARKET: &str = "05";
+/// 시간외 단일가 주문 구분 코드 (ORD_DVSN)
+const KR_ORD_DVSN_AFTER_HOURS_SINGLE: &str = "07";
+/// 시간외 단일가 가격 제한폭 (당일 종가 대비)
+const AFTER_HOURS_SINGLE_PRICE_BAND: Decimal = dec!(0.10);
+/// 예약 주문 ID 접두사 (거래소 전송 전 로컬 식별자)
+const RESERVED_ORDER_PREFIX: &str = "RSV-";
+
 /// 심볼이 한국 주식인지 확인.
 fn is_korean_symbol(symbol: &str) -> bool {
     symbol.len() == 6 && symbol.chars().all(|c| c.is_ascii_digit())
 }
 
+/// 해외 주식 심볼을 KIS 형식으로 변환 (클래스주 BRK.B → BRK/B).
+fn kis_us_symbol(ticker: &str) -> String {
+    SymbolNormalizer::new()
+        .to_venue(ticker, SymbolVenue::Kis)
+        .unwrap_or_else(|_| ticker.to_string())
+}
+
 /// KIS 날짜/시간 파싱 (YYYYMMDD + HHMMSS → DateTime<Utc>).
 fn parse_kis_datetime(date: &str, time: &str) -> Result<chrono::DateTime<chrono::Utc>, String> {
     if time.is_empty() {
@@ -71,6 +95,213 @@ fn parse_kis_datetime(date: &str, time: &str) -> Result<chrono::DateTime<chrono:
     Ok(kst_datetime.with_timezone(&chrono::Utc))
 }
 
+/// 정규장 주문 구분 코드 (ORD_DVSN).
+fn regular_order_code(order_type: OrderType) -> Result<&'static str, ProviderError> {
+    match order_type {
+        OrderType::Market => Ok("01"),
+        OrderType::Limit => Ok("00"),
+        // KIS는 손절/익절을 별도 주문 유형으로 지원하지 않음 → 지정가로 변환
+        OrderType::StopLoss | OrderType::StopLossLimit => Ok("00"),
+        OrderType::TakeProfit | OrderType::TakeProfitLimit => Ok("00"),
+        OrderType::TrailingStop => Err(ProviderError::Unsupported(
+            "KIS는 트레일링 스톱 주문을 지원하지 않습니다".to_string(),
+        )),
+    }
+}
+
+/// Decimal 수량 → u32 변환 (소수점 절사).
+fn order_quantity(request: &OrderRequest) -> Result<u32, ProviderError> {
+    let quantity = request
+        .quantity
+        .to_string()
+        .parse::<f64>()
+        .map(|v| v.floor() as u32)
+        .map_err(|e| ProviderError::Parse(format!("수량 변환 실패: {}", e)))?;
+
+    if quantity == 0 {
+        return Err(ProviderError::Api(
+            "주문 수량은 1 이상이어야 합니다".to_string(),
+        ));
+    }
+    Ok(quantity)
+}
+
+/// 주문 전송 에러 변환 (타임아웃/연결 실패는 재시도 판단을 위해 별도 분류).
+fn map_order_error(e: ExchangeError) -> ProviderError {
+    match e {
+        ExchangeError::Timeout(msg) => ProviderError::Timeout(format!("주문 실패: {}", msg)),
+        ExchangeError::NetworkError(msg) => ProviderError::Network(format!("주문 실패: {}", msg)),
+        other => ProviderError::Api(format!("주문 실패: {}", other)),
+    }
+}
+
+/// 주문 구분 코드와 가격으로 국내/해외 주문 전송.
+async fn send_order(
+    client: &KisClient,
+    request: &OrderRequest,
+    order_type_code: &str,
+    price: Decimal,
+) -> Result<OrderResponse, ProviderError> {
+    let quantity = order_quantity(request)?;
+    let ticker = &request.ticker;
+
+    let response = if is_korean_symbol(ticker) {
+        match request.side {
+            Side::Buy => {
+                client
+                    .place_kr_buy_order(ticker, quantity, price, order_type_code)
+                    .await
+            }
+            Side::Sell => {
+                client
+                    .place_kr_sell_order(ticker, quantity, price, order_type_code)
+                    .await
+            }
+        }
+    } else {
+        let ticker = kis_us_symbol(ticker);
+        match request.side {
+            Side::Buy => {
+                client
+                    .place_us_buy_order(&ticker, quantity, price, order_type_code, None)
+                    .await
+            }
+            Side::Sell => {
+                client
+                    .place_us_sell_order(&ticker, quantity, price, order_type_code, None)
+                    .await
+            }
+        }
+    };
+
+    response.map_err(map_order_error)
+}
+
+/// 정규장 주문 전송.
+async fn submit_regular_order(
+    client: &KisClient,
+    request: &OrderRequest,
+) -> Result<OrderResponse, ProviderError> {
+    let order_type_code = regular_order_code(request.order_type)?;
+
+    // 가격 결정 (시장가인 경우 0)
+    let price = match request.order_type {
+        OrderType::Market => Decimal::ZERO,
+        _ => request
+            .price
+            .or(request.stop_price)
+            .unwrap_or(Decimal::ZERO),
+    };
+
+    send_order(client, request, order_type_code, price).await
+}
+
+/// 시간외 주문 가격 검증 기준.
+#[derive(Debug, Clone, Copy)]
+struct SessionPriceReference {
+    /// 당일 종가 (장 마감 후 현재가)
+    close: Decimal,
+    /// 전일 종가
+    prev_close: Decimal,
+    /// 상한가
+    upper_limit: Decimal,
+    /// 하한가
+    lower_limit: Decimal,
+}
+
+impl SessionPriceReference {
+    /// 시간외 단일가 허용 가격 범위 (당일 종가 ±10%, 상·하한가 이내).
+    fn after_hours_single_band(&self) -> (Decimal, Decimal) {
+        let lower = self.close * (Decimal::ONE - AFTER_HOURS_SINGLE_PRICE_BAND);
+        let upper = self.close * (Decimal::ONE + AFTER_HOURS_SINGLE_PRICE_BAND);
+        let lower = if self.lower_limit > Decimal::ZERO {
+            lower.max(self.lower_limit)
+        } else {
+            lower
+        };
+        let upper = if self.upper_limit > Decimal::ZERO {
+            upper.min(self.upper_limit)
+        } else {
+            upper
+        };
+        (lower, upper)
+    }
+}
+
+/// 시간외 주문의 주문 구분 코드와 전송 가격 결정.
+///
+/// - 장전 시간외: 전일 종가로만 체결되므로 가격을 지정했다면 전일 종가와 같아야 합니다.
+/// - 시간외 단일가: 지정가만 가능하며 당일 종가 ±10% (상·하한가 이내) 범위여야 합니다.
+fn off_hours_order(
+    request: &OrderRequest,
+    reference: &SessionPriceReference,
+) -> Result<(&'static str, Decimal), ProviderError> {
+    match request.session {
+        OrderSession::PreMarket => {
+            if let Some(price) = request.price {
+                if price != reference.prev_close {
+                    return Err(ProviderError::Api(format!(
+                        "장전 시간외 주문은 전일 종가({})로만 체결됩니다 (주문가: {})",
+                        reference.prev_close, price
+                    )));
+                }
+            }
+            Ok((KR_ORD_DVSN_PRE_MARKET, Decimal::ZERO))
+        }
+        OrderSession::AfterHoursSingle => {
+            if request.order_type == OrderType::Market {
+                return Err(ProviderError::Api(
+                    "시간외 단일가는 지정가 주문만 가능합니다".to_string(),
+                ));
+            }
+            let price = request.price.ok_or_else(|| {
+                ProviderError::Api("시간외 단일가 주문은 가격이 필요합니다".to_string())
+            })?;
+            let (lower, upper) = reference.after_hours_single_band();
+            if price < lower || price > upper {
+                return Err(ProviderError::Api(format!(
+                    "시간외 단일가 주문 가격 {}이(가) 허용 범위({} ~ {})를 벗어났습니다 (당일 종가 {} ±10%)",
+                    price, lower, upper, reference.close
+                )));
+            }
+            Ok((KR_ORD_DVSN_AFTER_HOURS_SINGLE, price))
+        }
+        OrderSession::Regular | OrderSession::Reserved(_) => Err(ProviderError::Api(format!(
+            "시간외 세션이 아닙니다: {}",
+            request.session.code()
+        ))),
+    }
+}
+
+/// 지정 시각 이후 처음으로 국내 정규장(09:00~15:30 KST) 주문이 가능한 시각.
+///
+/// 주말은 건너뛰며 공휴일은 반영하지 않습니다 (휴장일 주문은 거래소가 거부).
+fn next_kr_regular_session(at: DateTime<Utc>) -> DateTime<Utc> {
+    let open = NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default();
+    let close = NaiveTime::from_hms_opt(15, 30, 0).unwrap_or_default();
+    let is_trading_day = |weekday: Weekday| !matches!(weekday, Weekday::Sat | Weekday::Sun);
+
+    let local = at.with_timezone(&Seoul);
+    let mut date = local.date_naive();
+    if is_trading_day(date.weekday()) {
+        if local.time() >= open && local.time() < close {
+            return at;
+        }
+        if local.time() >= close {
+            date = date.succ_opt().unwrap_or(date);
+        }
+    }
+    while !is_trading_day(date.weekday()) {
+        date = date.succ_opt().unwrap_or(date);
+    }
+
+    Seoul
+        .from_local_datetime(&date.and_time(open))
+        .single()
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or(at)
+}
+
 /// 한국투자증권 통합 Provider.
 ///
 /// 국내/해외 주식을 하나의 인터페이스로 통합합니다.
@@ -84,6 +315,8 @@ pub struct KisExchangeProvider {
     order_history_cache: TtlCache<Vec<KrOrderExecution>>,
     /// 거래소 공용 캐시 (계좌, 포지션, 미체결 주문)
     cache: Arc<ExchangeCache>,
+    /// 전송 대기 중인 예약 주문 (예약 ID → 전송 태스크)
+    reservations: Arc<Mutex<HashMap<String, AbortHandle>>>,
 }
 
 /// 하위 호환성을 위한 타입 별칭.
@@ -96,6 +329,7 @@ impl KisExchangeProvider {
             client,
             order_history_cache: TtlCache::new(ORDER_HISTORY_CACHE_TTL),
             cache: Arc::new(ExchangeCache::with_defaults()),
+            reservations: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -115,6 +349,113 @@ impl KisExchangeProvider {
         self.cache.invalidate_all().await;
     }
 
+    /// 전송 대기 중인 예약 주문 ID 목록.
+    pub async fn pending_reservations(&self) -> Vec<String> {
+        self.reservations.lock().await.keys().cloned().collect()
+    }
+
+    /// 장전 시간외/시간외 단일가 주문 전송.
+    ///
+    /// 세션별 가격 제약을 검증하기 위해 먼저 현재가(당일/전일 종가, 상·하한가)를 조회합니다.
+    async fn submit_off_hours_order(
+        &self,
+        request: &OrderRequest,
+    ) -> Result<OrderResponse, ProviderError> {
+        order_quantity(request)?;
+        let quote = self
+            .client
+            .get_kr_price(&request.ticker)
+            .await
+            .map_err(|e| ProviderError::Api(format!("시간외 주문 기준가 조회 실패: {}", e)))?;
+        let reference = SessionPriceReference {
+            close: quote.current_price,
+            prev_close: quote.prev_close,
+            upper_limit: quote.upper_limit,
+            lower_limit: quote.lower_limit,
+        };
+
+        let (order_type_code, price) = off_hours_order(request, &reference)?;
+        send_order(&self.client, request, order_type_code, price).await
+    }
+
+    /// 예약 주문 등록.
+    ///
+    /// 지정 시각 이후 처음 열리는 정규장에 정규 주문으로 전송합니다.
+    /// 이미 전송 가능한 시각이면 즉시 전송하고, 아니면 예약 ID(`RSV-...`)를 반환합니다.
+    /// 예약은 프로세스 메모리에만 유지되므로 재시작 시 사라집니다.
+    async fn reserve_order(
+        &self,
+        request: &OrderRequest,
+        at: DateTime<Utc>,
+    ) -> Result<OrderResponse, ProviderError> {
+        let mut regular = request.clone();
+        regular.session = OrderSession::Regular;
+
+        // 전송 시점이 아닌 등록 시점에 주문 형식 오류를 알림
+        regular_order_code(regular.order_type)?;
+        order_quantity(&regular)?;
+
+        let now = Utc::now();
+        let send_at = next_kr_regular_session(at.max(now));
+        if send_at <= now {
+            let response = submit_regular_order(&self.client, &regular).await;
+            self.invalidate_cache().await;
+            return response;
+        }
+
+        let reservation_id = format!("{}{}", RESERVED_ORDER_PREFIX, Uuid::new_v4().simple());
+        let delay = (send_at - now).to_std().unwrap_or_default();
+        let client = Arc::clone(&self.client);
+        let cache = Arc::clone(&self.cache);
+        let reservations = Arc::clone(&self.reservations);
+        let task_id = reservation_id.clone();
+
+        // 태스크가 자신을 제거하기 전에 등록되도록 락을 잡은 상태에서 spawn
+        let mut pending = self.reservations.lock().await;
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            reservations.lock().await.remove(&task_id);
+
+            match submit_regular_order(&client, &regular).await {
+                Ok(response) => info!(
+                    "예약 주문 전송 완료: reservation={}, order_no={}, stock={}",
+                    task_id, response.order_no, regular.ticker
+                ),
+                Err(e) => warn!(
+                    "예약 주문 전송 실패: reservation={}, stock={}, error={}",
+                    task_id, regular.ticker, e
+                ),
+            }
+            cache.invalidate_all().await;
+        });
+        pending.insert(reservation_id.clone(), handle.abort_handle());
+
+        info!(
+            "예약 주문 등록: reservation={}, stock={}, send_at={}",
+            reservation_id, request.ticker, send_at
+        );
+
+        Ok(OrderResponse {
+            order_no: reservation_id,
+            order_time: send_at.with_timezone(&Seoul).format("%H%M%S").to_string(),
+        })
+    }
+
+    /// 전송 전 예약 주문 취소.
+    async fn cancel_reservation(&self, reservation_id: &str) -> Result<(), ProviderError> {
+        match self.reservations.lock().await.remove(reservation_id) {
+            Some(handle) => {
+                handle.abort();
+                info!("예약 주문 취소: reservation={}", reservation_id);
+                Ok(())
+            }
+            None => Err(ProviderError::Api(format!(
+                "예약 주문을 찾을 수 없습니다 (이미 전송되었거나 취소됨): {}",
+                reservation_id
+            ))),
+        }
+    }
+
     /// ISA 계좌 여부 확인.
     fn is_isa_account(&self) -> bool {
         self.client.is_isa_account()
@@ -127,7 +468,7 @@ impl KisExchangeProvider {
 
     /// 모든 체결 내역을 페이지네이션으로 조회 (캐시 활용).
     async fn fetch_all_order_history(&self) -> Result<Vec<KrOrderExecution>, ProviderError> {
-        use chrono::{Datelike, NaiveDate};
+        use chrono::NaiveDate;
 
         // 캐시 확인
         if let Some(cached) = self.order_history_cache.get().await {
@@ -824,7 +1165,7 @@ impl MarketDataProvider for KisExchangeProvider {
             let price = self
                 .client
                 .us()
-                .get_price(symbol, None)
+                .get_price(&kis_us_symbol(symbol), None)
                 .await
                 .map_err(|e| ProviderError::Api(e.to_string()))?;
 
@@ -858,90 +1199,40 @@ impl OrderExecutionProvider for KisExchangeProvider {
     async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ProviderError> {
         let is_korean = is_korean_symbol(&request.ticker);
 
-        // OrderType → KIS 주문 구분 코드 변환
-        let order_type_code = match request.order_type {
-            OrderType::Market => "01",
-            OrderType::Limit => "00",
-            // KIS는 손절/익절을 별도 주문 유형으로 지원하지 않음 → 지정가로 변환
-            OrderType::StopLoss | OrderType::StopLossLimit => "00",
-            OrderType::TakeProfit | OrderType::TakeProfitLimit => "00",
-            OrderType::TrailingStop => {
-                return Err(ProviderError::Unsupported(
-                    "KIS는 트레일링 스톱 주문을 지원하지 않습니다".to_string(),
-                ));
-            }
-        };
-
-        // Decimal 수량 → u32 변환 (소수점 절사)
-        let quantity = request
-            .quantity
-            .to_string()
-            .parse::<f64>()
-            .map(|v| v.floor() as u32)
-            .map_err(|e| ProviderError::Parse(format!("수량 변환 실패: {}", e)))?;
-
-        if quantity == 0 {
-            return Err(ProviderError::Api(
-                "주문 수량은 1 이상이어야 합니다".to_string(),
-            ));
+        if !is_korean && !request.session.is_regular() {
+            return Err(ProviderError::Unsupported(format!(
+                "KIS 해외 주식은 정규장 주문만 지원합니다 (세션: {})",
+                request.session.code()
+            )));
         }
 
-        // 가격 결정 (시장가인 경우 0)
-        let price = match request.order_type {
-            OrderType::Market => Decimal::ZERO,
-            _ => request
-                .price
-                .or(request.stop_price)
-                .unwrap_or(Decimal::ZERO),
-        };
-
-        let response = if is_korean {
-            match request.side {
-                Side::Buy => {
-                    self.client
-                        .place_kr_buy_order(&request.ticker, quantity, price, order_type_code)
-                        .await
-                }
-                Side::Sell => {
-                    self.client
-                        .place_kr_sell_order(&request.ticker, quantity, price, order_type_code)
-                        .await
-                }
-            }
-        } else {
-            match request.side {
-                Side::Buy => {
-                    self.client
-                        .place_us_buy_order(&request.ticker, quantity, price, order_type_code, None)
-                        .await
-                }
-                Side::Sell => {
-                    self.client
-                        .place_us_sell_order(
-                            &request.ticker,
-                            quantity,
-                            price,
-                            order_type_code,
-                            None,
-                        )
-                        .await
-                }
+        let response = match request.session {
+            OrderSession::Regular => submit_regular_order(&self.client, request).await,
+            OrderSession::PreMarket | OrderSession::AfterHoursSingle => {
+                self.submit_off_hours_order(request).await
             }
+            OrderSession::Reserved(at) => return self.reserve_order(request, at).await,
         };
 
         // 캐시 무효화 (주문 후 포지션/계좌 변동)
         self.invalidate_cache().await;
 
-        response.map_err(|e| ProviderError::Api(format!("주문 실패: {}", e)))
+        response
     }
 
     async fn cancel_order(&self, order_id: &str, ticker: &str) -> Result<(), ProviderError> {
+        if order_id.starts_with(RESERVED_ORDER_PREFIX) {
+            return self.cancel_reservation(order_id).await;
+        }
+
         let is_korean = is_korean_symbol(ticker);
 
         let result = if is_korean {
             self.client.cancel_kr_order(order_id, ticker, 0).await
         } else {
-            self.client.cancel_us_order(order_id, ticker, 0, None).await
+            self.client
+                .cancel_us_order(order_id, &kis_us_symbol(ticker), 0, None)
+                .await
         };
 
         self.invalidate_cache().await;
@@ -977,7 +1268,7 @@ impl OrderExecutionProvider for KisExchangeProvider {
                 .await
         } else {
             self.client
-                .modify_us_order(order_id, ticker, qty, order_price, None)
+                .modify_us_order(order_id, &kis_us_symbol(ticker), qty, order_price, None)
                 .await
         };
 
@@ -986,6 +1277,13 @@ impl OrderExecutionProvider for KisExchangeProvider {
         response.map_err(|e| ProviderError::Api(format!("주문 정정 실패: {}", e)))
     }
 
+    /// 국내 주식은 장전 시간외·시간외 단일가·예약 주문을 지원합니다.
+    ///
+    /// 해외 주식의 정규장 외 세션은 `place_order`에서 거부됩니다.
+    fn supports_order_session(&self, _session: &OrderSession) -> bool {
+        true
+    }
+
     fn exchange_name(&self) -> &str {
         "한국투자증권"
     }
@@ -1012,4 +1310,105 @@ mod tests {
         assert!(!is_korean_symbol("0059300")); // 7자리
         assert!(!is_korean_symbol("A05930")); // 문자 포함
     }
+
+    #[test]
+    fn test_kis_us_symbol() {
+        assert_eq!(kis_us_symbol("AAPL"), "AAPL");
+        assert_eq!(kis_us_symbol("BRK.B"), "BRK/B");
+        // 이미 KIS 형식이면 그대로
+        assert_eq!(kis_us_symbol("BRK/B"), "BRK/B");
+    }
+
+    fn reference() -> SessionPriceReference {
+        SessionPriceReference {
+            close: dec!(70000),
+            prev_close: dec!(69000),
+            upper_limit: dec!(89700),
+            lower_limit: dec!(48300),
+        }
+    }
+
+    #[test]
+    fn test_off_hours_order_pre_market_uses_prev_close() {
+        let request = OrderRequest::market_buy("005930".to_string(), dec!(10))
+            .with_session(OrderSession::PreMarket);
+        let (code, price) = off_hours_order(&request, &reference()).unwrap();
+        assert_eq!(code, KR_ORD_DVSN_PRE_MARKET);
+        assert_eq!(price, Decimal::ZERO);
+
+        // 전일 종가와 다른 가격은 거부
+        let request = OrderRequest::limit_buy("005930".to_string(), dec!(10), dec!(70000))
+            .with_session(OrderSession::PreMarket);
+        let err = off_hours_order(&request, &reference()).unwrap_err();
+        assert!(err.to_string().contains("전일 종가"));
+    }
+
+    #[test]
+    fn test_off_hours_order_after_hours_single_price_band() {
+        let within = OrderRequest::limit_sell("005930".to_string(), dec!(10), dec!(76000))
+            .with_session(OrderSession::AfterHoursSingle);
+        let (code, price) = off_hours_order(&within, &reference()).unwrap();
+        assert_eq!(code, KR_ORD_DVSN_AFTER_HOURS_SINGLE);
+        assert_eq!(price, dec!(76000));
+
+        // 당일 종가 +10% 초과
+        let above = OrderRequest::limit_buy("005930".to_string(), dec!(10), dec!(77100))
+            .with_session(OrderSession::AfterHoursSingle);
+        let err = off_hours_order(&above, &reference()).unwrap_err();
+        assert!(err.to_string().contains("허용 범위"));
+
+        // 시장가는 불가
+        let market = OrderRequest::market_buy("005930".to_string(), dec!(10))
+            .with_session(OrderSession::AfterHoursSingle);
+        assert!(off_hours_order(&market, &reference()).is_err());
+    }
+
+    #[test]
+    fn test_after_hours_band_clamped_to_price_limits() {
+        // 상한가가 종가 +10%보다 낮은 경우 상한가로 제한
+        let reference = SessionPriceReference {
+            upper_limit: dec!(75000),
+            ..reference()
+        };
+        assert_eq!(
+            reference.after_hours_single_band(),
+            (dec!(63000), dec!(75000))
+        );
+    }
+
+    #[test]
+    fn test_next_kr_regular_session() {
+        let kst = |y, m, d, h, min| {
+            Seoul
+                .with_ymd_and_hms(y, m, d, h, min, 0)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+
+        // 장중 (2024-06-03 월요일 10:00) → 즉시
+        assert_eq!(
+            next_kr_regular_session(kst(2024, 6, 3, 10, 0)),
+            kst(2024, 6, 3, 10, 0)
+        );
+        // 장전 → 당일 09:00
+        assert_eq!(
+            next_kr_regular_session(kst(2024, 6, 3, 7, 30)),
+            kst(2024, 6, 3, 9, 0)
+        );
+        // 장 마감 후 → 다음 거래일 09:00
+        assert_eq!(
+            next_kr_regular_session(kst(2024, 6, 3, 16, 0)),
+            kst(2024, 6, 4, 9, 0)
+        );
+        // 금요일 마감 후 → 월요일 09:00
+        assert_eq!(
+            next_kr_regular_session(kst(2024, 6, 7, 15, 30)),
+            kst(2024, 6, 10, 9, 0)
+        );
+        // 일요일 → 월요일 09:00
+        assert_eq!(
+            next_kr_regular_session(kst(2024, 6, 9, 12, 0)),
+            kst(2024, 6, 10, 9, 0)
+        );
+    }
 }
@@ -10,6 +10,7 @@
 //! ├── ExchangeProvider 구현 (계정정보 조회)
 //! ├── SignalProcessor 위임 (SimulatedExecutor)
 //! ├── 실시간 시세 조회 (Yahoo Finance)
+//! ├── 실패 주입 (거부/자금 부족/부분 체결 후 취소/지연 후 타임아웃)
 //! └── DB 상태 영속성 (PostgreSQL)
 //! ```
 //!
@@ -32,19 +33,21 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{mpsc, Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 use trader_core::{
     domain::{
-        ExchangeProvider, ExecutionHistoryRequest, ExecutionHistoryResponse,
-        OrderExecutionProvider, OrderRequest, OrderResponse, PendingOrder, ProviderError, Side,
-        StrategyAccountInfo, StrategyPositionInfo, Trade,
+        ExchangeProvider, ExecutionCursor, ExecutionHistoryRequest, ExecutionHistoryResponse,
+        OrderExecutionProvider, OrderRequest, OrderResponse, OrderStatus, OrderStatusType,
+        PendingOrder, ProviderError, RoundMethod, Side, StrategyAccountInfo, StrategyPositionInfo,
+        SymbolOrderRule, TickSizeProvider, TickSizeTable, Trade,
     },
-    OrderType, Ticker, Timeframe,
+    MissingQuantityPolicy, OrderType, SymbolNormalizer, SymbolVenue, Ticker, Timeframe,
 };
 use trader_execution::{ProcessorPosition, TradeResult};
 use uuid::Uuid;
 
+use super::mock_failure::{FailureInjector, InjectedFailure, MockFailureConfig};
 use crate::{
     historical::HistoricalDataProvider, simulated::EventBroadcaster, traits::MarketEvent,
     yahoo::YahooFinanceProvider,
@@ -63,6 +66,21 @@ pub struct MockConfig {
     pub market_type: String,
     /// 통화
     pub currency: String,
+    /// 주문 수량 단위 (0이면 제약 없음, 국내/미국 주식은 1주)
+    #[serde(default)]
+    pub lot_size: Decimal,
+    /// 시장 기본 호가 단위 규칙
+    #[serde(default)]
+    pub tick_size: TickSizeTable,
+    /// 종목별 호가 단위 규칙 (ETF 등 시장 기본 규칙과 다른 종목)
+    #[serde(default)]
+    pub symbol_tick_sizes: HashMap<String, TickSizeTable>,
+    /// 주문 실패 주입 설정 (에러 처리 경로 검증용)
+    #[serde(default)]
+    pub failure_injection: MockFailureConfig,
+    /// 신호 메타데이터에 수량이 없을 때의 처리 (기본: 1단위로 간주)
+    #[serde(default)]
+    pub missing_quantity: MissingQuantityPolicy,
 }
 
 impl Default for MockConfig {
@@ -73,6 +91,11 @@ impl Default for MockConfig {
             slippage_rate: dec!(0.0001),       // 0.01%
             market_type: "stock_kr".to_string(),
             currency: "KRW".to_string(),
+            lot_size: Decimal::ONE,
+            tick_size: TickSizeTable::krx_stock(),
+            symbol_tick_sizes: HashMap::new(),
+            failure_injection: MockFailureConfig::default(),
+            missing_quantity: MissingQuantityPolicy::default(),
         }
     }
 }
@@ -86,6 +109,11 @@ impl MockConfig {
             slippage_rate: dec!(0.0001),
             market_type: "stock_us".to_string(),
             currency: "USD".to_string(),
+            lot_size: Decimal::ONE,
+            tick_size: TickSizeTable::us_equity(),
+            symbol_tick_sizes: HashMap::new(),
+            failure_injection: MockFailureConfig::default(),
+            missing_quantity: MissingQuantityPolicy::default(),
         }
     }
 
@@ -97,9 +125,105 @@ impl MockConfig {
             slippage_rate: dec!(0.0005),  // 0.05%
             market_type: "crypto".to_string(),
             currency: "USDT".to_string(),
+            lot_size: Decimal::ZERO,
+            tick_size: TickSizeTable::unrestricted(),
+            symbol_tick_sizes: HashMap::new(),
+            failure_injection: MockFailureConfig::default(),
+            missing_quantity: MissingQuantityPolicy::default(),
         }
     }
 
+    /// 시장 유형별 기본 설정 ("stock_us", "crypto", 그 외는 국내 주식).
+    pub fn for_market(market_type: &str) -> Self {
+        match market_type {
+            "stock_us" => Self::stock_us(),
+            "crypto" => Self::crypto(),
+            _ => Self {
+                market_type: market_type.to_string(),
+                ..Self::default()
+            },
+        }
+    }
+
+    /// 주문 수량 단위 설정 (빌더 패턴).
+    pub fn with_lot_size(mut self, lot_size: Decimal) -> Self {
+        self.lot_size = lot_size;
+        self
+    }
+
+    /// 시장 기본 호가 단위 규칙 설정 (빌더 패턴).
+    pub fn with_tick_size(mut self, table: TickSizeTable) -> Self {
+        self.tick_size = table;
+        self
+    }
+
+    /// 종목별 호가 단위 규칙 설정 (빌더 패턴).
+    pub fn with_symbol_tick_size(
+        mut self,
+        symbol: impl Into<String>,
+        table: TickSizeTable,
+    ) -> Self {
+        self.symbol_tick_sizes.insert(symbol.into(), table);
+        self
+    }
+
+    /// 국내 ETF/ETN 종목 지정 (빌더 패턴).
+    ///
+    /// 지정된 종목에는 ETF 호가 단위(2,000원 이상 5원)가 적용됩니다.
+    pub fn with_etf_symbols<I, S>(mut self, symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for symbol in symbols {
+            self.symbol_tick_sizes
+                .insert(symbol.into(), TickSizeTable::krx_etf());
+        }
+        self
+    }
+
+    /// 종목에 적용되는 호가 단위 규칙.
+    pub fn tick_size_for(&self, symbol: &str) -> &TickSizeTable {
+        self.symbol_tick_sizes
+            .get(symbol)
+            .unwrap_or(&self.tick_size)
+    }
+
+    /// 수량을 주문 수량 단위로 내림합니다.
+    pub fn normalize_quantity(&self, quantity: Decimal) -> Decimal {
+        if self.lot_size <= Decimal::ZERO {
+            return quantity;
+        }
+        (quantity / self.lot_size).floor() * self.lot_size
+    }
+
+    /// 가격을 가장 가까운 유효 호가로 반올림합니다.
+    pub fn normalize_price(&self, symbol: &str, price: Decimal) -> Decimal {
+        self.tick_size_for(symbol)
+            .round_to_tick(price, RoundMethod::Round)
+    }
+
+    /// 주문 요청의 수량과 가격을 거래 제약에 맞게 보정합니다.
+    ///
+    /// 보정 후 수량이 0이면 최소 주문 단위 미만으로 거부합니다.
+    pub fn normalize_order(&self, request: &OrderRequest) -> Result<OrderRequest, ProviderError> {
+        let mut normalized = request.clone();
+        normalized.quantity = self.normalize_quantity(request.quantity);
+        if normalized.quantity <= Decimal::ZERO {
+            return Err(ProviderError::Other(format!(
+                "주문 수량 {}이(가) 최소 주문 단위 {} 미만입니다",
+                request.quantity, self.lot_size
+            )));
+        }
+        normalized.price = request
+            .price
+            .map(|price| self.normalize_price(&request.ticker, price));
+        normalized.stop_price = request
+            .stop_price
+            .map(|price| self.normalize_price(&request.ticker, price));
+        Ok(normalized)
+    }
+
     /// 초기 잔고 설정 (빌더 패턴).
     pub fn with_balance(mut self, balance: Decimal) -> Self {
         self.initial_balance = balance;
@@ -117,6 +241,18 @@ impl MockConfig {
         self.slippage_rate = rate;
         self
     }
+
+    /// 주문 실패 주입 설정 (빌더 패턴).
+    pub fn with_failure_injection(mut self, failure_injection: MockFailureConfig) -> Self {
+        self.failure_injection = failure_injection;
+        self
+    }
+
+    /// 수량 누락 신호 처리 방식 설정 (빌더 패턴).
+    pub fn with_missing_quantity(mut self, policy: MissingQuantityPolicy) -> Self {
+        self.missing_quantity = policy;
+        self
+    }
 }
 
 /// Mock 거래소 전략별 상태 (메모리)
@@ -233,6 +369,98 @@ impl MockState {
     fn total_position_count(&self) -> usize {
         self.strategies.values().map(|s| s.positions.len()).sum()
     }
+
+    /// 전략별 포지션 평가 (현재가 캐시 기준).
+    fn strategy_position_infos(
+        &self,
+        tickers: &HashMap<String, Ticker>,
+    ) -> Vec<(String, StrategyPositionInfo)> {
+        self.strategies
+            .iter()
+            .flat_map(|(strategy_id, s)| {
+                s.positions
+                    .values()
+                    .map(move |p| (strategy_id.clone(), value_position(p, tickers)))
+            })
+            .collect()
+    }
+
+    /// 전략별 미실현 손익 집계.
+    fn unrealized_pnl_by_strategy(
+        &self,
+        tickers: &HashMap<String, Ticker>,
+    ) -> HashMap<String, StrategyUnrealizedPnl> {
+        let mut result: HashMap<String, StrategyUnrealizedPnl> = self
+            .strategies
+            .keys()
+            .map(|id| (id.clone(), StrategyUnrealizedPnl::default()))
+            .collect();
+
+        for (strategy_id, info) in self.strategy_position_infos(tickers) {
+            let entry = result.entry(strategy_id).or_default();
+            entry.unrealized_pnl += info.unrealized_pnl;
+            entry.position_value += info.avg_entry_price * info.quantity + info.unrealized_pnl;
+            entry.stale |= info.stale;
+        }
+
+        result
+    }
+}
+
+/// 전략별 미실현 손익 집계 결과.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StrategyUnrealizedPnl {
+    /// 미실현 손익 합계
+    pub unrealized_pnl: Decimal,
+    /// 포지션 평가액 합계 (진입 원가 + 미실현 손익)
+    pub position_value: Decimal,
+    /// 현재가가 없어 진입가로 평가한 포지션 포함 여부
+    pub stale: bool,
+}
+
+/// 최신 Ticker 캐시로 포지션을 평가합니다.
+///
+/// 캐시에 현재가가 없는 심볼은 진입가로 평가하고 `stale`로 표시합니다.
+fn value_position(
+    position: &ProcessorPosition,
+    tickers: &HashMap<String, Ticker>,
+) -> StrategyPositionInfo {
+    let mut info = StrategyPositionInfo::new(
+        position.symbol.clone(),
+        position.side,
+        position.quantity,
+        position.entry_price,
+    );
+    info.created_at = position.entry_time;
+
+    match tickers
+        .get(&position.symbol)
+        .map(|t| t.last)
+        .filter(|price| *price > Decimal::ZERO)
+    {
+        Some(price) => {
+            info.update_price(price);
+            info
+        }
+        None => info.mark_stale(),
+    }
+}
+
+/// 시장 유형에 맞춰 심볼을 Yahoo Finance 형식으로 변환합니다.
+///
+/// 국내/미국 주식은 [`SymbolNormalizer`]로 변환하고(코스닥 `.KQ`, 클래스주 `BRK-B` 등),
+/// 이미 Yahoo 형식이거나 변환할 수 없는 심볼은 그대로 사용합니다.
+fn yahoo_symbol_for_market(market_type: &str, symbol: &str) -> String {
+    match market_type {
+        "stock_kr" if symbol.ends_with(".KS") || symbol.ends_with(".KQ") => symbol.to_string(),
+        "stock_kr" => SymbolNormalizer::new()
+            .to_venue(symbol, SymbolVenue::Yahoo)
+            .unwrap_or_else(|_| format!("{}.KS", symbol)),
+        "stock_us" => SymbolNormalizer::new()
+            .to_venue(symbol, SymbolVenue::Yahoo)
+            .unwrap_or_else(|_| symbol.to_string()),
+        _ => symbol.to_string(),
+    }
 }
 
 /// Mock 거래소 ExchangeProvider.
@@ -284,6 +512,12 @@ pub struct MockExchangeProvider {
     latest_order_books: Arc<RwLock<HashMap<String, trader_core::OrderBook>>>,
     /// 스트리밍 설정
     streaming_config: Arc<RwLock<Option<super::mock_streaming::MockStreamingConfig>>>,
+    /// 주문 실패 주입기
+    failure_injector: Arc<Mutex<FailureInjector>>,
+    /// client_order_id별 접수 주문 (타임아웃 후 접수 여부 확인용)
+    client_orders: Arc<RwLock<HashMap<String, OrderResponse>>>,
+    /// 실패 주입으로 상태가 바뀐 주문 (주문번호 → 상태)
+    injected_statuses: Arc<RwLock<HashMap<String, OrderStatus>>>,
 }
 
 impl MockExchangeProvider {
@@ -310,6 +544,10 @@ impl MockExchangeProvider {
             config.slippage_rate,
         );
 
+        let failure_injector = Arc::new(Mutex::new(FailureInjector::new(
+            config.failure_injection.clone(),
+        )));
+
         let provider = Self {
             credential_id,
             config,
@@ -323,6 +561,9 @@ impl MockExchangeProvider {
             latest_tickers: Arc::new(RwLock::new(HashMap::new())),
             latest_order_books: Arc::new(RwLock::new(HashMap::new())),
             streaming_config: Arc::new(RwLock::new(None)),
+            failure_injector,
+            client_orders: Arc::new(RwLock::new(HashMap::new())),
+            injected_statuses: Arc::new(RwLock::new(HashMap::new())),
         };
 
         // DB에서 상태 복원
@@ -351,6 +592,105 @@ impl MockExchangeProvider {
         self.state.read().await.strategy_ids()
     }
 
+    /// 주문 실패 주입 설정 변경 (실행 중 교체, 시드부터 다시 시작).
+    pub async fn set_failure_injection(&self, config: MockFailureConfig) {
+        info!("[Mock] 실패 주입 설정 변경: {:?}", config);
+        *self.failure_injector.lock().await = FailureInjector::new(config);
+    }
+
+    /// 실패 주입 설정을 적용하여 주문 제출.
+    ///
+    /// 부분 체결 후 취소는 체결분만 주문하고, 주문 상태를 잔량 취소로 기록합니다.
+    async fn place_order_with_injection(
+        &self,
+        request: &OrderRequest,
+    ) -> Result<OrderResponse, ProviderError> {
+        let failure = self.failure_injector.lock().await.next_failure(request);
+
+        match failure {
+            None => self.place_order_tracked(request).await,
+            Some(InjectedFailure::Error(e)) => {
+                warn!("[Mock] 주문 실패 주입: {} - {}", request.ticker, e);
+                Err(e)
+            }
+            Some(InjectedFailure::Timeout { delay, accepted }) => {
+                tokio::time::sleep(delay).await;
+                if accepted {
+                    self.place_order_tracked(request).await?;
+                }
+                warn!(
+                    "[Mock] 타임아웃 주입: {} (지연 {}ms, 접수 {})",
+                    request.ticker,
+                    delay.as_millis(),
+                    accepted
+                );
+                Err(ProviderError::Timeout(format!(
+                    "[Mock] 주문 응답 타임아웃 (주입): {}",
+                    request.ticker
+                )))
+            }
+            Some(InjectedFailure::PartialFillThenCancel { fill_ratio }) => {
+                let filled = self
+                    .config
+                    .normalize_quantity(request.quantity * fill_ratio);
+                let response = if filled > Decimal::ZERO {
+                    let partial = OrderRequest {
+                        quantity: filled,
+                        ..request.clone()
+                    };
+                    self.place_order_tracked(&partial).await?
+                } else {
+                    let response = OrderResponse {
+                        order_no: Uuid::new_v4().to_string(),
+                        order_time: Utc::now().format("%H%M%S").to_string(),
+                    };
+                    self.track_client_order(request, &response).await;
+                    response
+                };
+
+                warn!(
+                    "[Mock] 부분 체결 후 잔량 취소 주입: {} {} / {}",
+                    request.ticker, filled, request.quantity
+                );
+                self.injected_statuses.write().await.insert(
+                    response.order_no.clone(),
+                    OrderStatus {
+                        order_id: response.order_no.clone(),
+                        client_order_id: request.client_order_id.clone(),
+                        ticker: Some(request.ticker.clone()),
+                        side: Some(request.side),
+                        quantity: Some(request.quantity),
+                        price: request.price,
+                        status: OrderStatusType::Cancelled,
+                        filled_quantity: filled,
+                        average_price: None,
+                        updated_at: Utc::now(),
+                    },
+                );
+                Ok(response)
+            }
+        }
+    }
+
+    /// 주문 제출 후 client_order_id 기록.
+    async fn place_order_tracked(
+        &self,
+        request: &OrderRequest,
+    ) -> Result<OrderResponse, ProviderError> {
+        let response = self.place_order_inner(request).await?;
+        self.track_client_order(request, &response).await;
+        Ok(response)
+    }
+
+    async fn track_client_order(&self, request: &OrderRequest, response: &OrderResponse) {
+        if let Some(client_order_id) = &request.client_order_id {
+            self.client_orders
+                .write()
+                .await
+                .insert(client_order_id.clone(), response.clone());
+        }
+    }
+
     /// DB에서 전략별 상태 복원.
     pub async fn load_state(&self) -> Result<(), ProviderError> {
         // paper_trading_sessions에서 전략별 잔고 + 예약 잔고 복원
@@ -404,6 +744,7 @@ impl MockExchangeProvider {
                     fees: Decimal::ZERO,
                     position_id: None,
                     group_id: None,
+                    trailing_high: None,
                 };
 
                 if let Some(strategy_state) = state.get_strategy_mut(strategy_id) {
@@ -655,17 +996,7 @@ impl MockExchangeProvider {
 
     /// 심볼을 Yahoo Finance 형식으로 변환.
     fn to_yahoo_symbol(&self, symbol: &str) -> String {
-        match self.config.market_type.as_str() {
-            "stock_kr" => {
-                // 이미 .KS/.KQ 형식이면 그대로, 아니면 .KS 추가
-                if symbol.ends_with(".KS") || symbol.ends_with(".KQ") {
-                    symbol.to_string()
-                } else {
-                    format!("{}.KS", symbol)
-                }
-            }
-            _ => symbol.to_string(),
-        }
+        yahoo_symbol_for_market(&self.config.market_type, symbol)
     }
 
     /// 전략별 잔고 조회.
@@ -708,10 +1039,19 @@ impl MockExchangeProvider {
         self.state.read().await.total_position_count()
     }
 
+    /// 전략별 미실현 손익 (최신 Ticker 캐시 기준).
+    ///
+    /// 현재가가 없는 심볼은 진입가로 평가하며, 해당 전략은 `stale`로 표시됩니다.
+    pub async fn unrealized_pnl_by_strategy(&self) -> HashMap<String, StrategyUnrealizedPnl> {
+        let tickers = self.latest_tickers.read().await.clone();
+        self.state.read().await.unrealized_pnl_by_strategy(&tickers)
+    }
+
     /// Signal 처리 (체결) - 전략별 독립 상태.
     ///
     /// Signal의 strategy에서 전략 ID를 추출하여 해당 전략의 상태를 업데이트합니다.
-    /// 수량은 metadata에서 추출하거나 기본값 1을 사용합니다.
+    /// 수량은 metadata(문자열/숫자)에서 추출하며, 잘못된 값은 거부합니다.
+    /// 수량이 없는 진입/추가/축소 신호는 [`MockConfig::missing_quantity`] 정책을 따릅니다.
     pub async fn process_signal(
         &self,
         signal: &trader_core::Signal,
@@ -731,20 +1071,36 @@ impl MockExchangeProvider {
             ))
         })?;
 
-        // 슬리피지 적용
-        let slippage = current_price * self.config.slippage_rate;
-        let execution_price = match signal.side {
-            Side::Buy => current_price + slippage,
-            Side::Sell => current_price - slippage,
+        // 슬리피지 적용 후 유효 호가로 반올림
+        let slippage_amount = current_price * self.config.slippage_rate;
+        let slipped_price = match signal.side {
+            Side::Buy => current_price + slippage_amount,
+            Side::Sell => current_price - slippage_amount,
         };
+        let execution_price = self.config.normalize_price(&signal.ticker, slipped_price);
+        let slippage = (execution_price - current_price).abs();
 
-        // 수량은 metadata에서 추출하거나 기본값 사용
-        let quantity = signal
-            .metadata
-            .get("quantity")
-            .and_then(|v| v.as_f64())
-            .map(|v| Decimal::from_f64_retain(v).unwrap_or(Decimal::ONE))
-            .unwrap_or(Decimal::ONE);
+        // 수량은 metadata에서 추출 (주문 수량 단위로 내림)
+        let requires_quantity = matches!(
+            signal.signal_type,
+            SignalType::Entry | SignalType::AddToPosition | SignalType::ReducePosition
+        );
+        let metadata = signal.typed_metadata();
+        let quantity = if requires_quantity {
+            metadata.required_quantity(self.config.missing_quantity)
+        } else {
+            metadata.quantity().map(|q| q.unwrap_or(Decimal::ONE))
+        }
+        .map_err(|e| {
+            ProviderError::InvalidOrder(format!("[{}] {}: {}", strategy_id, e, signal.ticker))
+        })?;
+        let quantity = self.config.normalize_quantity(quantity);
+        if quantity <= Decimal::ZERO && requires_quantity {
+            return Err(ProviderError::Other(format!(
+                "[{}] 주문 수량이 최소 주문 단위 {} 미만: {}",
+                strategy_id, self.config.lot_size, signal.ticker
+            )));
+        }
 
         let trade_value = execution_price * quantity;
         let commission = trade_value * self.config.commission_rate;
@@ -770,6 +1126,7 @@ impl MockExchangeProvider {
                     fees: commission,
                     position_id: signal.position_id.clone(),
                     group_id: signal.group_id.clone(),
+                    trailing_high: None,
                 };
                 strategy_state
                     .positions
@@ -857,6 +1214,7 @@ impl MockExchangeProvider {
                         fees: commission,
                         position_id: signal.position_id.clone(),
                         group_id: signal.group_id.clone(),
+                        trailing_high: None,
                     };
                     strategy_state
                         .positions
@@ -1043,41 +1401,44 @@ impl MockExchangeProvider {
 impl ExchangeProvider for MockExchangeProvider {
     /// 계정 정보 조회 (거래소 중립적 형식).
     ///
-    /// 모든 전략의 잔고 합계를 반환합니다.
+    /// 모든 전략의 잔고 합계와 최신 Ticker 캐시 기준 미실현 손익을 반환합니다.
+    /// 현재가가 없는 포지션은 진입가로 평가합니다.
     async fn fetch_account(&self) -> Result<StrategyAccountInfo, ProviderError> {
+        let tickers = self.latest_tickers.read().await.clone();
         let state = self.state.read().await;
 
         // 모든 전략의 잔고 합계
         let total_balance = state.total_balance();
 
-        // 모든 전략의 포지션 평가액 합계
-        let position_value: Decimal = state
-            .strategies
+        // 모든 전략의 포지션 평가액 및 미실현 손익 합계
+        let (position_value, unrealized_pnl) = state
+            .unrealized_pnl_by_strategy(&tickers)
             .values()
-            .flat_map(|s| s.positions.values())
-            .map(|p| p.entry_price * p.quantity)
-            .sum();
+            .fold((Decimal::ZERO, Decimal::ZERO), |(value, pnl), s| {
+                (value + s.position_value, pnl + s.unrealized_pnl)
+            });
 
         Ok(StrategyAccountInfo {
             total_balance: total_balance + position_value,
             available_balance: total_balance,
             margin_used: Decimal::ZERO,
-            unrealized_pnl: Decimal::ZERO, // 실시간 계산 비용 절약
+            unrealized_pnl,
             currency: self.config.currency.clone(),
         })
     }
 
     /// 포지션 목록 조회 (거래소 중립적 형식).
     ///
-    /// 모든 전략의 포지션을 반환합니다.
+    /// 모든 전략의 포지션을 최신 Ticker 캐시 기준으로 평가하여 반환합니다.
+    /// 현재가가 없는 심볼은 진입가로 평가하고 `stale`로 표시합니다.
     async fn fetch_positions(&self) -> Result<Vec<StrategyPositionInfo>, ProviderError> {
+        let tickers = self.latest_tickers.read().await.clone();
         let state = self.state.read().await;
 
         let positions: Vec<StrategyPositionInfo> = state
-            .strategies
-            .values()
-            .flat_map(|s| s.positions.values())
-            .map(|p| StrategyPositionInfo::new(p.symbol.clone(), p.side, p.quantity, p.entry_price))
+            .strategy_position_infos(&tickers)
+            .into_iter()
+            .map(|(_, info)| info)
             .collect();
 
         Ok(positions)
@@ -1094,28 +1455,87 @@ impl ExchangeProvider for MockExchangeProvider {
         "Mock Exchange"
     }
 
+    /// 심볼별 주문 수량 규칙 조회.
+    ///
+    /// 모든 심볼에 설정의 주문 수량 단위(`lot_size`)를 최소 수량/수량 단위로 적용합니다.
+    /// 수량 단위 제약이 없으면(0) 빈 규칙을 반환합니다.
+    async fn fetch_symbol_order_rules(
+        &self,
+        tickers: &[String],
+    ) -> Result<Vec<SymbolOrderRule>, ProviderError> {
+        let lot_size = (self.config.lot_size > Decimal::ZERO).then_some(self.config.lot_size);
+        Ok(tickers
+            .iter()
+            .map(|ticker| SymbolOrderRule {
+                ticker: ticker.clone(),
+                min_quantity: lot_size,
+                quantity_step: lot_size,
+                ..Default::default()
+            })
+            .collect())
+    }
+
+    /// 단일 주문 상태 조회.
+    ///
+    /// 실패 주입으로 상태가 바뀐 주문(부분 체결 후 잔량 취소)만 조회할 수 있습니다.
+    async fn fetch_order_status(
+        &self,
+        order_id: &str,
+        _ticker: &str,
+    ) -> Result<OrderStatus, ProviderError> {
+        self.injected_statuses
+            .read()
+            .await
+            .get(order_id)
+            .cloned()
+            .ok_or_else(|| {
+                ProviderError::Unsupported(format!("Mock: 주문 상태 조회 미지원 ({})", order_id))
+            })
+    }
+
     /// 체결 내역 조회 (거래소 중립적 형식).
+    ///
+    /// `executed_at DESC, id DESC` 순서의 keyset 페이지네이션으로, 커서는
+    /// [`ExecutionCursor`] 형식(`체결 시각|체결 ID`)입니다.
     async fn fetch_execution_history(
         &self,
-        _request: &ExecutionHistoryRequest,
+        request: &ExecutionHistoryRequest,
     ) -> Result<ExecutionHistoryResponse, ProviderError> {
-        // DB에서 체결 내역 조회 (최근 100건)
+        let after = request
+            .cursor
+            .as_deref()
+            .map(|cursor| {
+                let cursor = ExecutionCursor::parse(cursor)?;
+                let id = Uuid::parse_str(&cursor.id)
+                    .map_err(|e| ProviderError::Parse(format!("잘못된 커서 체결 ID: {}", e)))?;
+                Ok::<_, ProviderError>((cursor.executed_at, id))
+            })
+            .transpose()?;
+        let limit = request.effective_limit() as usize;
+
+        // 다음 페이지 존재 여부 확인을 위해 limit + 1건 조회
         let rows = sqlx::query!(
             r#"
-            SELECT symbol, side, quantity, price, commission, realized_pnl, executed_at
+            SELECT id, symbol, side, quantity, price, commission, realized_pnl, executed_at
             FROM mock_executions
             WHERE credential_id = $1
-            ORDER BY executed_at DESC
-            LIMIT 100
+              AND ($2::timestamptz IS NULL OR (executed_at, id) < ($2::timestamptz, $3::uuid))
+            ORDER BY executed_at DESC, id DESC
+            LIMIT $4
             "#,
-            self.credential_id
+            self.credential_id,
+            after.map(|(executed_at, _)| executed_at),
+            after.map(|(_, id)| id),
+            (limit + 1) as i64
         )
         .fetch_all(&self.db_pool)
         .await
         .map_err(|e| ProviderError::Other(format!("DB 에러: {}", e)))?;
 
-        let trades = rows
+        let has_more = rows.len() > limit;
+        let trades: Vec<Trade> = rows
             .into_iter()
+            .take(limit)
             .map(|row| {
                 let side = match row.side.as_str() {
                     "Buy" | "buy" => Side::Buy,
@@ -1125,7 +1545,7 @@ impl ExchangeProvider for MockExchangeProvider {
                 Trade::new(
                     Uuid::new_v4(), // order_id (mock)
                     "mock",
-                    Uuid::new_v4().to_string(), // exchange_trade_id
+                    row.id.to_string(), // exchange_trade_id (커서 보조 키)
                     row.symbol,
                     side,
                     row.quantity,
@@ -1136,9 +1556,17 @@ impl ExchangeProvider for MockExchangeProvider {
             })
             .collect();
 
+        let next_cursor = if has_more {
+            trades
+                .last()
+                .map(|trade| ExecutionCursor::from_trade(trade).encode())
+        } else {
+            None
+        };
+
         Ok(ExecutionHistoryResponse {
             trades,
-            next_cursor: None,
+            next_cursor,
         })
     }
 }
@@ -1330,16 +1758,8 @@ impl MockExchangeProvider {
                         for symbol in &symbols {
                             if let Some(ref yahoo_provider) = yahoo {
                                 // Yahoo Finance에서 가격 조회
-                                let yahoo_symbol = match config.market_type.as_str() {
-                                    "stock_kr" => {
-                                        if symbol.ends_with(".KS") || symbol.ends_with(".KQ") {
-                                            symbol.clone()
-                                        } else {
-                                            format!("{}.KS", symbol)
-                                        }
-                                    }
-                                    _ => symbol.clone(),
-                                };
+                                let yahoo_symbol =
+                                    yahoo_symbol_for_market(&config.market_type, symbol);
 
                                 match yahoo_provider.get_klines(&yahoo_symbol, Timeframe::D1, 1).await {
                                     Ok(klines) => {
@@ -1459,7 +1879,9 @@ impl MockExchangeProvider {
 
         // 가격 생성기 초기화
         let mut generator: Box<dyn MockPriceGenerator> = match streaming_config.mode {
-            MockPriceMode::RandomWalk => Box::new(RandomWalkGenerator::new()),
+            MockPriceMode::RandomWalk => {
+                Box::new(RandomWalkGenerator::from_config(&streaming_config))
+            }
             MockPriceMode::HistoricalReplay => Box::new(HistoricalReplayGenerator::new(
                 streaming_config.replay_speed,
             )),
@@ -1472,6 +1894,12 @@ impl MockExchangeProvider {
             generator.initialize(symbol, initial_price).await;
         }
 
+        // 시장가 잔량 최대 대기 틱 적용
+        self.order_engine
+            .write()
+            .await
+            .set_max_wait_ticks(streaming_config.max_wait_ticks);
+
         // OrderBook 생성기 초기화
         let ob_generator = MockOrderBookGenerator::new(
             &streaming_config.market_type,
@@ -1521,11 +1949,22 @@ impl MockExchangeProvider {
                             }
 
                             // 4. 미체결 주문 매칭
-                            let fills = {
+                            let (fills, expired) = {
                                 let mut engine = order_engine.write().await;
-                                engine.on_price_tick(symbol, &ticker, &orderbook)
+                                let fills = engine.on_price_tick(symbol, &ticker, &orderbook);
+                                (fills, engine.take_expired_orders())
                             };
 
+                            // 최대 대기 틱 초과로 만료된 주문의 예약금 해제
+                            if !expired.is_empty() {
+                                let mut mock_state = state.write().await;
+                                for cancel in &expired {
+                                    if let Some(strategy_state) = mock_state.get_strategy_mut(&cancel.strategy_id) {
+                                        strategy_state.release_reservation(cancel.released_amount);
+                                    }
+                                }
+                            }
+
                             // 5. 체결 결과 처리 (잔고 업데이트)
                             if !fills.is_empty() {
                                 let mut mock_state = state.write().await;
@@ -1592,11 +2031,16 @@ impl MockExchangeProvider {
 // OrderExecutionProvider 구현 (Mock 거래소 주문 시뮬레이션)
 // =============================================================================
 
-#[async_trait]
-impl OrderExecutionProvider for MockExchangeProvider {
-    async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ProviderError> {
+impl MockExchangeProvider {
+    /// 주문 매칭 엔진으로 주문 제출 (실패 주입 없음).
+    async fn place_order_inner(
+        &self,
+        request: &OrderRequest,
+    ) -> Result<OrderResponse, ProviderError> {
         use trader_core::OrderType;
 
+        // 수량은 주문 단위로 내림, 가격은 유효 호가로 반올림
+        let request = &self.config.normalize_order(request)?;
         let strategy_id = request.strategy_id.clone().unwrap_or_default();
 
         match request.order_type {
@@ -1620,6 +2064,8 @@ impl OrderExecutionProvider for MockExchangeProvider {
                                     let cost =
                                         fill.fill_price * fill.filled_quantity + fill.commission;
                                     if strategy_state.available_balance() < cost {
+                                        // 이월된 잔량도 함께 취소
+                                        engine.cancel_order(&fill.order_id);
                                         return Err(ProviderError::Other(format!(
                                             "[{}] 자금 부족: 필요 {}, 가용 {}",
                                             strategy_id,
@@ -1635,6 +2081,17 @@ impl OrderExecutionProvider for MockExchangeProvider {
                                     strategy_state.balance += proceeds;
                                 }
                             }
+
+                            // 부분 체결: 이월된 잔량의 예약금 확보
+                            if !fill.is_fully_filled {
+                                let reserved = engine.get_reserved_amount(&fill.order_id);
+                                if reserved > Decimal::ZERO {
+                                    if let Err(e) = strategy_state.reserve(reserved) {
+                                        warn!("[Mock] 시장가 잔량 예약 실패, 잔량 취소: {}", e);
+                                        engine.cancel_order(&fill.order_id);
+                                    }
+                                }
+                            }
                         }
 
                         return Ok(OrderResponse {
@@ -1768,6 +2225,13 @@ impl OrderExecutionProvider for MockExchangeProvider {
             }
         }
     }
+}
+
+#[async_trait]
+impl OrderExecutionProvider for MockExchangeProvider {
+    async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ProviderError> {
+        self.place_order_with_injection(request).await
+    }
 
     async fn cancel_order(&self, order_id: &str, _ticker: &str) -> Result<(), ProviderError> {
         let mut engine = self.order_engine.write().await;
@@ -1811,6 +2275,23 @@ impl OrderExecutionProvider for MockExchangeProvider {
         }
     }
 
+    async fn find_order_by_client_id(
+        &self,
+        client_order_id: &str,
+        _ticker: &str,
+    ) -> Result<Option<OrderResponse>, ProviderError> {
+        Ok(self
+            .client_orders
+            .read()
+            .await
+            .get(client_order_id)
+            .cloned())
+    }
+
+    fn supports_client_order_lookup(&self) -> bool {
+        true
+    }
+
     fn exchange_name(&self) -> &str {
         "Mock"
     }
@@ -1871,4 +2352,147 @@ mod tests {
         assert_eq!(strategy_state.balance, dec!(1_000_000));
         assert_eq!(strategy_state.total_commission, dec!(0));
     }
+
+    fn test_position(
+        symbol: &str,
+        side: Side,
+        quantity: Decimal,
+        entry_price: Decimal,
+    ) -> ProcessorPosition {
+        ProcessorPosition {
+            symbol: symbol.to_string(),
+            side,
+            quantity,
+            entry_price,
+            entry_time: Utc::now(),
+            fees: Decimal::ZERO,
+            position_id: None,
+            group_id: None,
+            trailing_high: None,
+        }
+    }
+
+    fn test_ticker(symbol: &str, last: Decimal) -> Ticker {
+        Ticker {
+            ticker: symbol.to_string(),
+            bid: last,
+            ask: last,
+            last,
+            volume_24h: Decimal::ZERO,
+            high_24h: last,
+            low_24h: last,
+            change_24h: Decimal::ZERO,
+            change_24h_percent: Decimal::ZERO,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_value_position_uses_latest_ticker() {
+        let tickers = HashMap::from([("005930".to_string(), test_ticker("005930", dec!(72_000)))]);
+
+        let long = value_position(
+            &test_position("005930", Side::Buy, dec!(10), dec!(70_000)),
+            &tickers,
+        );
+        assert!(!long.stale);
+        assert_eq!(long.current_price, dec!(72_000));
+        assert_eq!(long.unrealized_pnl, dec!(20_000));
+
+        let short = value_position(
+            &test_position("005930", Side::Sell, dec!(10), dec!(70_000)),
+            &tickers,
+        );
+        assert_eq!(short.unrealized_pnl, dec!(-20_000));
+
+        // 캐시에 없는 심볼은 진입가 기준 + stale
+        let missing = value_position(
+            &test_position("000660", Side::Buy, dec!(5), dec!(150_000)),
+            &tickers,
+        );
+        assert!(missing.stale);
+        assert_eq!(missing.current_price, dec!(150_000));
+        assert_eq!(missing.unrealized_pnl, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_unrealized_pnl_by_strategy() {
+        let mut state = MockState::new();
+        state
+            .get_or_create_strategy("a", dec!(1_000_000))
+            .positions
+            .insert(
+                "005930".to_string(),
+                test_position("005930", Side::Buy, dec!(10), dec!(70_000)),
+            );
+        let b = state.get_or_create_strategy("b", dec!(1_000_000));
+        b.positions.insert(
+            "005930".to_string(),
+            test_position("005930", Side::Buy, dec!(2), dec!(75_000)),
+        );
+        b.positions.insert(
+            "000660".to_string(),
+            test_position("000660", Side::Buy, dec!(1), dec!(150_000)),
+        );
+        state.get_or_create_strategy("idle", dec!(1_000_000));
+
+        let tickers = HashMap::from([("005930".to_string(), test_ticker("005930", dec!(72_000)))]);
+        let by_strategy = state.unrealized_pnl_by_strategy(&tickers);
+
+        let a = &by_strategy["a"];
+        assert_eq!(a.unrealized_pnl, dec!(20_000));
+        assert_eq!(a.position_value, dec!(720_000));
+        assert!(!a.stale);
+
+        let b = &by_strategy["b"];
+        assert_eq!(b.unrealized_pnl, dec!(-6_000));
+        assert_eq!(b.position_value, dec!(294_000));
+        assert!(b.stale);
+
+        assert_eq!(by_strategy["idle"], StrategyUnrealizedPnl::default());
+        assert_eq!(state.strategy_position_infos(&tickers).len(), 3);
+    }
+
+    #[test]
+    fn test_mock_config_normalizes_kr_stock_orders() {
+        let config = MockConfig::default().with_etf_symbols(["069500"]);
+
+        // 단주 제약: 정수 수량으로 내림
+        assert_eq!(config.normalize_quantity(dec!(10.7)), dec!(10));
+        assert_eq!(config.normalize_quantity(dec!(0.5)), dec!(0));
+
+        // 일반 주식: 20,000원 이상 50원 단위
+        assert_eq!(config.normalize_price("005930", dec!(35_432)), dec!(35_450));
+        // ETF: 5원 단위
+        assert_eq!(config.normalize_price("069500", dec!(35_432)), dec!(35_430));
+        // 1원 미만 가격은 최소 호가로
+        assert_eq!(config.normalize_price("005930", dec!(0.3)), dec!(1));
+
+        let request = OrderRequest::limit_buy("005930".to_string(), dec!(3.9), dec!(71_234));
+        let normalized = config.normalize_order(&request).unwrap();
+        assert_eq!(normalized.quantity, dec!(3));
+        assert_eq!(normalized.price, Some(dec!(71_200)));
+
+        let too_small = OrderRequest::limit_buy("005930".to_string(), dec!(0.9), dec!(71_234));
+        assert!(config.normalize_order(&too_small).is_err());
+    }
+
+    #[test]
+    fn test_mock_config_market_constraints() {
+        let us = MockConfig::for_market("stock_us");
+        assert_eq!(us.normalize_price("AAPL", dec!(187.456)), dec!(187.46));
+        assert_eq!(us.normalize_price("PENNY", dec!(0.53217)), dec!(0.5322));
+
+        // 암호화폐는 수량/가격 제약 없음
+        let crypto = MockConfig::for_market("crypto");
+        assert_eq!(crypto.normalize_quantity(dec!(0.0123)), dec!(0.0123));
+        assert_eq!(
+            crypto.normalize_price("BTCUSDT", dec!(65_432.123)),
+            dec!(65_432.123)
+        );
+
+        let kr = MockConfig::for_market("stock_kr");
+        assert_eq!(kr.lot_size, Decimal::ONE);
+        assert_eq!(kr.tick_size, TickSizeTable::krx_stock());
+    }
 }
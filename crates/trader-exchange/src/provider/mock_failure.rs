@@ -0,0 +1,318 @@
+//! Mock 거래소 실패 주입.
+//!
+//! 전략과 실행기의 에러 처리/재시도/복구 경로를 검증하기 위해
+//! [`MockExchangeProvider`](super::MockExchangeProvider)의 주문 전송에 의도적인 실패를 주입합니다.
+//!
+//! # 판정 순서
+//!
+//! 1. 항상 거부할 심볼 → `InvalidOrder`
+//! 2. 자금 부족 강제 → 잔고 부족 에러 (재시도 불가)
+//! 3. 처음 N건 실패 → 설정된 에러 유형
+//! 4. 지연 후 타임아웃 (확률)
+//! 5. 확률적 거부 → 설정된 에러 유형
+//! 6. 부분 체결 후 잔량 취소 (확률)
+//!
+//! 시드를 지정하면 같은 주문 순서에 대해 항상 같은 실패가 재현됩니다.
+
+use std::time::Duration;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::domain::{OrderRequest, ProviderError};
+
+/// 주입할 에러 유형.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InjectedErrorKind {
+    /// 주문 거부 (재시도 불가)
+    #[default]
+    Rejected,
+    /// 네트워크 오류 (재시도 가능)
+    Network,
+    /// 거래소 5xx (재시도 가능)
+    ServerError,
+    /// 요청 한도 초과 (재시도 가능)
+    RateLimited,
+}
+
+impl InjectedErrorKind {
+    /// 에러 유형에 해당하는 ProviderError 생성.
+    ///
+    /// 메시지는 실제 거래소 에러와 같은 방식으로 분류되도록 구성합니다.
+    pub fn to_error(self, request: &OrderRequest) -> ProviderError {
+        match self {
+            Self::Rejected => {
+                ProviderError::InvalidOrder(format!("[Mock] 주문 거부 (주입): {}", request.ticker))
+            }
+            Self::Network => ProviderError::Network("[Mock] connection reset (주입)".to_string()),
+            Self::ServerError => {
+                ProviderError::Api("[Mock] API error 503: Service Unavailable (주입)".to_string())
+            }
+            Self::RateLimited => {
+                ProviderError::Api("[Mock] Rate limit exceeded (주입)".to_string())
+            }
+        }
+    }
+}
+
+/// 실패 주입 설정.
+///
+/// 기본값은 실패 주입 없음입니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MockFailureConfig {
+    /// 난수 시드 (None이면 실행마다 다름)
+    pub seed: Option<u64>,
+    /// 확률적 거부율 (0.0 ~ 1.0)
+    pub reject_rate: f64,
+    /// 확률적 거부/처음 N건 실패 시 에러 유형
+    pub error_kind: InjectedErrorKind,
+    /// 항상 거부할 심볼
+    pub reject_symbols: Vec<String>,
+    /// 매수 주문에 자금 부족 강제
+    pub force_insufficient_balance: bool,
+    /// 처음 N건의 주문 실패
+    pub fail_first_orders: u32,
+    /// 부분 체결 후 잔량 취소 확률 (0.0 ~ 1.0)
+    pub partial_fill_rate: f64,
+    /// 부분 체결 비율 (0.0 ~ 1.0, 기본 0.5)
+    pub partial_fill_ratio: Option<Decimal>,
+    /// 지연 후 타임아웃 확률 (0.0 ~ 1.0)
+    pub timeout_rate: f64,
+    /// 타임아웃 전 지연 시간 (밀리초)
+    pub timeout_delay_ms: u64,
+    /// 타임아웃된 주문이 실제로는 접수되었는지 여부 (접수 여부 확인 경로 검증용)
+    pub accept_on_timeout: bool,
+}
+
+impl MockFailureConfig {
+    /// 실패 주입이 설정되어 있는지 확인.
+    pub fn is_enabled(&self) -> bool {
+        self.reject_rate > 0.0
+            || !self.reject_symbols.is_empty()
+            || self.force_insufficient_balance
+            || self.fail_first_orders > 0
+            || self.partial_fill_rate > 0.0
+            || self.timeout_rate > 0.0
+    }
+
+    /// 시드 설정 (빌더 패턴).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// 확률적 거부 설정 (빌더 패턴).
+    pub fn with_reject_rate(mut self, rate: f64, kind: InjectedErrorKind) -> Self {
+        self.reject_rate = rate.clamp(0.0, 1.0);
+        self.error_kind = kind;
+        self
+    }
+
+    /// 항상 거부할 심볼 추가 (빌더 패턴).
+    pub fn with_reject_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.reject_symbols.push(symbol.into());
+        self
+    }
+
+    /// 자금 부족 강제 (빌더 패턴).
+    pub fn with_insufficient_balance(mut self) -> Self {
+        self.force_insufficient_balance = true;
+        self
+    }
+
+    /// 처음 N건 실패 설정 (빌더 패턴).
+    pub fn with_fail_first_orders(mut self, count: u32, kind: InjectedErrorKind) -> Self {
+        self.fail_first_orders = count;
+        self.error_kind = kind;
+        self
+    }
+
+    /// 부분 체결 후 잔량 취소 설정 (빌더 패턴).
+    pub fn with_partial_fill(mut self, rate: f64, ratio: Decimal) -> Self {
+        self.partial_fill_rate = rate.clamp(0.0, 1.0);
+        self.partial_fill_ratio = Some(ratio.clamp(Decimal::ZERO, Decimal::ONE));
+        self
+    }
+
+    /// 지연 후 타임아웃 설정 (빌더 패턴).
+    pub fn with_timeout(mut self, rate: f64, delay: Duration, accept_order: bool) -> Self {
+        self.timeout_rate = rate.clamp(0.0, 1.0);
+        self.timeout_delay_ms = delay.as_millis() as u64;
+        self.accept_on_timeout = accept_order;
+        self
+    }
+}
+
+/// 주입된 실패.
+#[derive(Debug)]
+pub enum InjectedFailure {
+    /// 즉시 에러 반환
+    Error(ProviderError),
+    /// 지연 후 타임아웃 (`accepted`면 주문은 실제로 접수됨)
+    Timeout { delay: Duration, accepted: bool },
+    /// 일부만 체결하고 잔량 취소
+    PartialFillThenCancel { fill_ratio: Decimal },
+}
+
+/// 실패 주입기.
+///
+/// 주문 전송마다 [`next_failure`](Self::next_failure)를 호출해 주입할 실패를 결정합니다.
+#[derive(Debug)]
+pub struct FailureInjector {
+    config: MockFailureConfig,
+    rng: StdRng,
+    orders_seen: u32,
+}
+
+impl FailureInjector {
+    /// 새 주입기 생성.
+    pub fn new(config: MockFailureConfig) -> Self {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Self {
+            config,
+            rng,
+            orders_seen: 0,
+        }
+    }
+
+    /// 설정 조회.
+    pub fn config(&self) -> &MockFailureConfig {
+        &self.config
+    }
+
+    /// 지금까지 판정한 주문 수.
+    pub fn orders_seen(&self) -> u32 {
+        self.orders_seen
+    }
+
+    /// 주문에 주입할 실패 결정 (없으면 None).
+    pub fn next_failure(&mut self, request: &OrderRequest) -> Option<InjectedFailure> {
+        self.orders_seen += 1;
+        let config = &self.config;
+
+        if config.reject_symbols.iter().any(|s| s == &request.ticker) {
+            return Some(InjectedFailure::Error(ProviderError::InvalidOrder(
+                format!("[Mock] 거부 대상 심볼 (주입): {}", request.ticker),
+            )));
+        }
+
+        if config.force_insufficient_balance && request.side == trader_core::Side::Buy {
+            return Some(InjectedFailure::Error(ProviderError::Api(
+                "[Mock] Insufficient balance (주입)".to_string(),
+            )));
+        }
+
+        if self.orders_seen <= config.fail_first_orders {
+            return Some(InjectedFailure::Error(config.error_kind.to_error(request)));
+        }
+
+        // 확률 판정은 설정 여부와 관계없이 항상 난수를 소비하여
+        // 설정 일부만 바꿔도 나머지 판정 순서가 유지되도록 함
+        let timeout_roll = self.rng.gen::<f64>();
+        let reject_roll = self.rng.gen::<f64>();
+        let partial_roll = self.rng.gen::<f64>();
+
+        if timeout_roll < config.timeout_rate {
+            return Some(InjectedFailure::Timeout {
+                delay: Duration::from_millis(config.timeout_delay_ms),
+                accepted: config.accept_on_timeout,
+            });
+        }
+
+        if reject_roll < config.reject_rate {
+            return Some(InjectedFailure::Error(config.error_kind.to_error(request)));
+        }
+
+        if partial_roll < config.partial_fill_rate {
+            return Some(InjectedFailure::PartialFillThenCancel {
+                fill_ratio: config.partial_fill_ratio.unwrap_or(Decimal::new(5, 1)),
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use trader_core::domain::Side;
+
+    use super::*;
+
+    fn request(ticker: &str, side: Side) -> OrderRequest {
+        match side {
+            Side::Buy => OrderRequest::market_buy(ticker.to_string(), dec!(10)),
+            Side::Sell => OrderRequest::market_sell(ticker.to_string(), dec!(10)),
+        }
+    }
+
+    #[test]
+    fn test_no_failure_by_default() {
+        let config = MockFailureConfig::default();
+        assert!(!config.is_enabled());
+
+        let mut injector = FailureInjector::new(config);
+        for _ in 0..100 {
+            assert!(injector
+                .next_failure(&request("005930", Side::Buy))
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn test_symbol_and_balance_rules() {
+        let mut injector = FailureInjector::new(
+            MockFailureConfig::default()
+                .with_reject_symbol("000660")
+                .with_insufficient_balance(),
+        );
+
+        assert!(matches!(
+            injector.next_failure(&request("000660", Side::Sell)),
+            Some(InjectedFailure::Error(ProviderError::InvalidOrder(_)))
+        ));
+        assert!(matches!(
+            injector.next_failure(&request("005930", Side::Buy)),
+            Some(InjectedFailure::Error(ProviderError::Api(_)))
+        ));
+        // 매도는 자금 부족 대상 아님
+        assert!(injector
+            .next_failure(&request("005930", Side::Sell))
+            .is_none());
+    }
+
+    #[test]
+    fn test_seeded_injection_is_reproducible() {
+        let config = MockFailureConfig::default()
+            .with_seed(7)
+            .with_reject_rate(0.3, InjectedErrorKind::ServerError)
+            .with_partial_fill(0.2, dec!(0.4));
+
+        let outcomes = |config: &MockFailureConfig| {
+            let mut injector = FailureInjector::new(config.clone());
+            (0..200)
+                .map(
+                    |_| match injector.next_failure(&request("005930", Side::Buy)) {
+                        None => 0,
+                        Some(InjectedFailure::Error(_)) => 1,
+                        Some(InjectedFailure::PartialFillThenCancel { .. }) => 2,
+                        Some(InjectedFailure::Timeout { .. }) => 3,
+                    },
+                )
+                .collect::<Vec<_>>()
+        };
+
+        let first = outcomes(&config);
+        assert_eq!(first, outcomes(&config));
+
+        let rejected = first.iter().filter(|o| **o == 1).count();
+        assert!((30..90).contains(&rejected), "거부 {}건", rejected);
+        assert!(first.contains(&2));
+    }
+}
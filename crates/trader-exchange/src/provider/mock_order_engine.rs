@@ -8,7 +8,8 @@
 //! - 시장가 주문: OrderBook ask/bid 레벨 순서대로 VWAP 체결
 //! - 지정가 주문: 즉시 체결 가능이면 체결, 아니면 큐 등록
 //! - 스톱 주문: stop_price 도달 시 시장가로 전환
-//! - 부분 체결: OrderBook 물량 부족 시 가능한 만큼만 체결
+//! - 부분 체결: OrderBook 물량 부족 시 가능한 만큼만 체결, 잔량은 다음 틱으로 이월
+//! - 최대 대기 틱: 호가가 얇아 체결되지 않는 시장가 잔량은 일정 틱 후 만료
 //! - 잔고 예약: 지정가 주문 시 필요 자금 예약 (cancel 시 해제)
 
 use std::{collections::HashMap, sync::Arc};
@@ -16,7 +17,7 @@ use std::{collections::HashMap, sync::Arc};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use trader_core::{
     OrderBook, OrderBookLevel, OrderRequest, OrderStatusType, OrderType, PendingOrder, Side,
     TickSizeProvider, Ticker,
@@ -117,10 +118,24 @@ struct MockPendingOrder {
     created_at: DateTime<Utc>,
     /// 스톱 트리거 여부
     stop_triggered: bool,
+    /// 체결 없이 대기한 틱 수 (시장가 잔량 전용)
+    idle_ticks: u64,
+}
+
+impl MockPendingOrder {
+    /// 가격 조건 없이 호가 잔량만 있으면 체결되는 주문인지 여부.
+    ///
+    /// 시장가 잔량과 트리거된 스톱 시장가 주문이 해당합니다.
+    fn is_market_like(&self) -> bool {
+        self.price.is_none() && (self.stop_price.is_none() || self.stop_triggered)
+    }
 }
 
 // ==================== MockOrderEngine ====================
 
+/// 시장가 잔량 기본 최대 대기 틱 수.
+pub const DEFAULT_MAX_WAIT_TICKS: u64 = 60;
+
 /// Mock 주문 매칭 엔진.
 ///
 /// Paper Trading에서 현실적인 주문 체결을 시뮬레이션합니다.
@@ -140,6 +155,10 @@ pub struct MockOrderEngine {
     tick_size_provider: Option<Arc<dyn TickSizeProvider>>,
     /// 주문 ID 카운터
     next_order_id: u64,
+    /// 시장가 잔량 최대 대기 틱 수 (0이면 무제한)
+    max_wait_ticks: u64,
+    /// 최대 대기 틱 초과로 만료된 주문 (`take_expired_orders()`로 수거)
+    expired_orders: Vec<MockCancelResult>,
 }
 
 impl MockOrderEngine {
@@ -153,9 +172,22 @@ impl MockOrderEngine {
             slippage_rate,
             tick_size_provider: None,
             next_order_id: 1,
+            max_wait_ticks: DEFAULT_MAX_WAIT_TICKS,
+            expired_orders: Vec::new(),
         }
     }
 
+    /// 시장가 잔량 최대 대기 틱 수 설정 (0이면 무제한).
+    pub fn with_max_wait_ticks(mut self, max_wait_ticks: u64) -> Self {
+        self.max_wait_ticks = max_wait_ticks;
+        self
+    }
+
+    /// 시장가 잔량 최대 대기 틱 수 변경 (0이면 무제한).
+    pub fn set_max_wait_ticks(&mut self, max_wait_ticks: u64) {
+        self.max_wait_ticks = max_wait_ticks;
+    }
+
     /// 호가 단위 제공자 설정.
     pub fn with_tick_size_provider(mut self, provider: Arc<dyn TickSizeProvider>) -> Self {
         self.tick_size_provider = Some(provider);
@@ -176,7 +208,9 @@ impl MockOrderEngine {
     /// 매수: ask 레벨 순서대로 소진 (오름차순)
     /// 매도: bid 레벨 순서대로 소진 (내림차순)
     ///
-    /// 물량 부족 시 부분 체결 가능.
+    /// 물량 부족 시 가능한 만큼만 체결하고, 잔량은 미체결 주문으로 등록하여
+    /// 다음 틱부터 `on_price_tick()`에서 이어서 체결합니다.
+    /// 매수 잔량은 체결가 기준 5% 버퍼를 더한 금액을 예약합니다.
     pub fn submit_market_order(
         &mut self,
         request: &OrderRequest,
@@ -207,12 +241,56 @@ impl MockOrderEngine {
         };
 
         let commission = execution_price * filled_qty * self.fee_rate;
+        let is_fully_filled = filled_qty >= request.quantity;
 
         info!(
             "[MockEngine] 시장가 체결: {} {:?} {} @ {} (VWAP)",
             request.ticker, request.side, filled_qty, execution_price
         );
 
+        if !is_fully_filled {
+            let remaining_quantity = request.quantity - filled_qty;
+            let reserved_amount = match request.side {
+                Side::Buy => {
+                    execution_price
+                        * remaining_quantity
+                        * (Decimal::ONE + self.fee_rate)
+                        * dec!(1.05)
+                } // 5% 버퍼
+                Side::Sell => Decimal::ZERO,
+            };
+
+            let pending = MockPendingOrder {
+                order_id: order_id.clone(),
+                symbol: request.ticker.clone(),
+                side: request.side,
+                order_type: OrderType::Market,
+                original_quantity: request.quantity,
+                remaining_quantity,
+                price: None,
+                stop_price: None,
+                strategy_id: strategy_id.to_string(),
+                reserved_amount,
+                created_at: Utc::now(),
+                stop_triggered: false,
+                idle_ticks: 0,
+            };
+
+            self.pending_orders
+                .entry(request.ticker.clone())
+                .or_default()
+                .push(pending);
+            self.order_strategy_map
+                .insert(order_id.clone(), strategy_id.to_string());
+            self.reserved_amounts
+                .insert(order_id.clone(), reserved_amount);
+
+            debug!(
+                "[MockEngine] 시장가 잔량 이월: {} {:?} {} (예약금: {})",
+                request.ticker, request.side, remaining_quantity, reserved_amount
+            );
+        }
+
         Some(MockOrderFill {
             order_id,
             symbol: request.ticker.clone(),
@@ -222,7 +300,7 @@ impl MockOrderEngine {
             commission,
             timestamp: Utc::now(),
             strategy_id: strategy_id.to_string(),
-            is_fully_filled: filled_qty >= request.quantity,
+            is_fully_filled,
             released_reservation: Decimal::ZERO,
         })
     }
@@ -298,6 +376,7 @@ impl MockOrderEngine {
             reserved_amount,
             created_at: Utc::now(),
             stop_triggered: false,
+            idle_ticks: 0,
         };
 
         self.pending_orders
@@ -350,6 +429,7 @@ impl MockOrderEngine {
             reserved_amount,
             created_at: Utc::now(),
             stop_triggered: false,
+            idle_ticks: 0,
         };
 
         self.pending_orders
@@ -375,6 +455,9 @@ impl MockOrderEngine {
     ///
     /// 매 틱마다 호출되어 미체결 큐의 주문을 검사하고, 체결 가능한 주문을 체결합니다.
     /// 스톱 주문은 stop_price 도달 시 시장가로 전환 후 체결 시도합니다.
+    ///
+    /// 시장가 잔량은 호가 잔량만큼만 체결되며, 체결 없이 `max_wait_ticks`를 넘기면
+    /// 만료되어 `take_expired_orders()`로 수거됩니다.
     pub fn on_price_tick(
         &mut self,
         symbol: &str,
@@ -384,12 +467,14 @@ impl MockOrderEngine {
         let mut fills = Vec::new();
         let fee_rate = self.fee_rate;
         let slippage_rate = self.slippage_rate;
+        let max_wait_ticks = self.max_wait_ticks;
 
         let orders = match self.pending_orders.get_mut(symbol) {
             Some(orders) => orders,
             None => return fills,
         };
 
+        // (인덱스, 만료 여부)
         let mut to_remove = Vec::new();
 
         for (idx, order) in orders.iter_mut().enumerate() {
@@ -417,8 +502,8 @@ impl MockOrderEngine {
             }
 
             // 2. 체결 시도
-            let should_fill = if order.stop_triggered && order.price.is_none() {
-                // 스톱 시장가: 즉시 체결
+            let should_fill = if order.is_market_like() {
+                // 시장가 잔량/스톱 시장가: 즉시 체결
                 true
             } else if let Some(limit_price) = order.price {
                 // 지정가/스톱지정가: 가격 조건 확인
@@ -427,8 +512,7 @@ impl MockOrderEngine {
                     Side::Sell => ticker.bid >= limit_price,
                 }
             } else {
-                // 스톱 트리거 후 시장가
-                order.stop_triggered
+                false
             };
 
             if !should_fill {
@@ -444,8 +528,19 @@ impl MockOrderEngine {
             let (fill_price, filled_qty) = Self::calculate_vwap(levels, order.remaining_quantity);
 
             if filled_qty.is_zero() {
+                if order.is_market_like() {
+                    order.idle_ticks += 1;
+                    if max_wait_ticks > 0 && order.idle_ticks >= max_wait_ticks {
+                        warn!(
+                            "[MockEngine] 시장가 잔량 만료: {} {:?} {} ({}틱 동안 미체결)",
+                            order.symbol, order.side, order.remaining_quantity, order.idle_ticks
+                        );
+                        to_remove.push((idx, true));
+                    }
+                }
                 continue;
             }
+            order.idle_ticks = 0;
 
             // 지정가 주문은 지정가 이하로 체결 (매수 기준)
             let execution_price = if let Some(limit_price) = order.price {
@@ -465,17 +560,19 @@ impl MockOrderEngine {
             let commission = execution_price * filled_qty * fee_rate;
             let is_fully_filled = filled_qty >= order.remaining_quantity;
 
-            // 예약금 해제 계산
+            // 예약금 해제 계산 (남은 수량 대비 체결 비율만큼)
             let released = if is_fully_filled {
                 order.reserved_amount
             } else {
-                let fill_ratio = filled_qty / order.original_quantity;
+                let fill_ratio = filled_qty / order.remaining_quantity;
                 order.reserved_amount * fill_ratio
             };
 
             order.remaining_quantity -= filled_qty;
             if !is_fully_filled {
                 order.reserved_amount -= released;
+                self.reserved_amounts
+                    .insert(order.order_id.clone(), order.reserved_amount);
             }
 
             fills.push(MockOrderFill {
@@ -492,15 +589,22 @@ impl MockOrderEngine {
             });
 
             if is_fully_filled {
-                to_remove.push(idx);
+                to_remove.push((idx, false));
             }
         }
 
-        // 체결 완료된 주문 제거 (역순)
-        for idx in to_remove.into_iter().rev() {
+        // 체결 완료/만료된 주문 제거 (역순)
+        for (idx, is_expired) in to_remove.into_iter().rev() {
             let removed = orders.remove(idx);
             self.order_strategy_map.remove(&removed.order_id);
             self.reserved_amounts.remove(&removed.order_id);
+            if is_expired {
+                self.expired_orders.push(MockCancelResult {
+                    strategy_id: removed.strategy_id,
+                    released_amount: removed.reserved_amount,
+                    order_id: removed.order_id,
+                });
+            }
         }
 
         // 빈 심볼 엔트리 정리
@@ -511,6 +615,13 @@ impl MockOrderEngine {
         fills
     }
 
+    /// 최대 대기 틱 초과로 만료된 주문 수거.
+    ///
+    /// 호출 측은 반환된 예약금을 전략 잔고에서 해제해야 합니다.
+    pub fn take_expired_orders(&mut self) -> Vec<MockCancelResult> {
+        std::mem::take(&mut self.expired_orders)
+    }
+
     // ==================== 주문 취소/정정 ====================
 
     /// 주문 취소.
@@ -654,6 +765,7 @@ impl MockOrderEngine {
         self.pending_orders.clear();
         self.order_strategy_map.clear();
         self.reserved_amounts.clear();
+        self.expired_orders.clear();
     }
 
     /// 특정 전략의 미체결 주문 초기화.
@@ -722,6 +834,7 @@ impl MockOrderEngine {
             reserved_amount,
             created_at,
             stop_triggered: false,
+            idle_ticks: 0,
         };
 
         self.pending_orders.entry(symbol).or_default().push(pending);
@@ -796,7 +909,7 @@ impl MockOrderEngine {
 #[cfg(test)]
 mod tests {
     use rust_decimal_macros::dec;
-    use trader_core::TimeInForce;
+    use trader_core::{OrderSession, TimeInForce};
 
     use super::*;
 
@@ -866,6 +979,8 @@ mod tests {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         }
     }
 
@@ -899,6 +1014,84 @@ mod tests {
         let fill = fill.unwrap();
         assert_eq!(fill.filled_quantity, dec!(600)); // 부분 체결
         assert!(!fill.is_fully_filled);
+
+        // 잔량은 미체결 주문으로 이월
+        let raw = engine.get_raw_pending_orders("test_strategy");
+        assert_eq!(raw.len(), 1);
+        assert_eq!(raw[0].order_id, fill.order_id);
+        assert_eq!(raw[0].order_type, OrderType::Market);
+        assert_eq!(raw[0].remaining_quantity, dec!(400));
+        assert!(engine.get_reserved_amount(&fill.order_id) > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_market_order_remainder_fills_on_next_ticks() {
+        let mut engine = MockOrderEngine::new(dec!(0.00015), Decimal::ZERO);
+        let orderbook = create_test_orderbook("005930", dec!(70000));
+        let ticker = create_test_ticker("005930", dec!(70000));
+        let request = create_buy_request("005930", dec!(1000), None);
+
+        let first = engine
+            .submit_market_order(&request, &orderbook, "test_strategy")
+            .unwrap();
+        let reserved = engine.get_reserved_amount(&first.order_id);
+
+        // 얇은 호가 (레벨당 100) → 잔량 400 중 300만 체결
+        let mut thin = create_test_orderbook("005930", dec!(70000));
+        for level in &mut thin.asks {
+            level.quantity = dec!(100);
+        }
+        let fills = engine.on_price_tick("005930", &ticker, &thin);
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].order_id, first.order_id);
+        assert_eq!(fills[0].filled_quantity, dec!(300));
+        assert!(!fills[0].is_fully_filled);
+        assert_eq!(
+            fills[0].released_reservation,
+            reserved * dec!(300) / dec!(400)
+        );
+
+        let pending = engine.get_pending_orders("test_strategy");
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].filled_quantity, dec!(900));
+        assert_eq!(pending[0].status, OrderStatusType::PartiallyFilled);
+
+        // 다음 틱에 나머지 100 체결 → 미체결 목록에서 제거
+        let fills = engine.on_price_tick("005930", &ticker, &thin);
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].filled_quantity, dec!(100));
+        assert!(fills[0].is_fully_filled);
+        assert!(engine.get_pending_orders("test_strategy").is_empty());
+        assert_eq!(engine.get_reserved_amount(&first.order_id), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_market_order_remainder_expires_after_max_wait_ticks() {
+        let mut engine = MockOrderEngine::new(dec!(0.00015), Decimal::ZERO).with_max_wait_ticks(3);
+        let orderbook = create_test_orderbook("005930", dec!(70000));
+        let ticker = create_test_ticker("005930", dec!(70000));
+        let request = create_buy_request("005930", dec!(1000), None);
+
+        let first = engine
+            .submit_market_order(&request, &orderbook, "test_strategy")
+            .unwrap();
+        let reserved = engine.get_reserved_amount(&first.order_id);
+
+        // 매도 호가가 비어 체결 불가
+        let mut empty = create_test_orderbook("005930", dec!(70000));
+        empty.asks.clear();
+
+        for _ in 0..2 {
+            assert!(engine.on_price_tick("005930", &ticker, &empty).is_empty());
+            assert!(engine.take_expired_orders().is_empty());
+        }
+
+        engine.on_price_tick("005930", &ticker, &empty);
+        let expired = engine.take_expired_orders();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].order_id, first.order_id);
+        assert_eq!(expired[0].released_amount, reserved);
+        assert!(engine.get_pending_orders("test_strategy").is_empty());
     }
 
     #[test]
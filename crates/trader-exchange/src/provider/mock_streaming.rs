@@ -2,7 +2,7 @@
 //!
 //! 3가지 모드로 실시간 가격 데이터를 생성합니다:
 //! - `HistoricalReplay`: DB 1분봉 캔들을 틱 단위로 보간 재생
-//! - `RandomWalk`: ATR 기반 랜덤 워크 + 평균회귀
+//! - `RandomWalk`: ATR 기반 랜덤 워크 + 평균회귀 (변동성 레짐 스케줄 선택 적용)
 //! - `YahooLegacy`: 기존 Yahoo Finance D1 폴링 (하위 호환)
 //!
 //! # 데이터 흐름
@@ -15,13 +15,15 @@ use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use rand::Rng;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 use trader_core::{Kline, OrderBook, OrderBookLevel, RoundMethod, TickSizeProvider, Ticker};
 
+use super::mock_order_engine::DEFAULT_MAX_WAIT_TICKS;
+
 // ==================== 설정 타입 ====================
 
 /// 가격 생성 모드.
@@ -52,6 +54,19 @@ pub struct MockStreamingConfig {
     pub orderbook_base_volume: Decimal,
     /// 재생 속도 (HistoricalReplay 전용, 기본 1.0)
     pub replay_speed: f64,
+    /// 변동성 레짐 스케줄 (RandomWalk 전용, 없으면 고정 변동성)
+    #[serde(default)]
+    pub regime_schedule: Option<RegimeSchedule>,
+    /// 난수 시드 (RandomWalk 전용, 같은 시드면 같은 가격 경로)
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// 시장가 잔량 최대 대기 틱 수 (체결 없이 초과 시 만료, 0이면 무제한)
+    #[serde(default = "default_max_wait_ticks")]
+    pub max_wait_ticks: u64,
+}
+
+fn default_max_wait_ticks() -> u64 {
+    DEFAULT_MAX_WAIT_TICKS
 }
 
 impl Default for MockStreamingConfig {
@@ -63,10 +78,142 @@ impl Default for MockStreamingConfig {
             spread_multiplier: Decimal::ONE,
             orderbook_base_volume: dec!(100),
             replay_speed: 1.0,
+            regime_schedule: None,
+            seed: None,
+            max_wait_ticks: DEFAULT_MAX_WAIT_TICKS,
         }
     }
 }
 
+impl MockStreamingConfig {
+    /// 변동성 레짐 스케줄 설정.
+    pub fn with_regime_schedule(mut self, schedule: RegimeSchedule) -> Self {
+        self.regime_schedule = Some(schedule);
+        self
+    }
+
+    /// 난수 시드 설정.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// 시장가 잔량 최대 대기 틱 수 설정 (0이면 무제한).
+    pub fn with_max_wait_ticks(mut self, max_wait_ticks: u64) -> Self {
+        self.max_wait_ticks = max_wait_ticks;
+        self
+    }
+}
+
+// ==================== 변동성 레짐 ====================
+
+/// 변동성 레짐.
+///
+/// 틱당 드리프트와 표준편차(가격 대비 비율)로 가격 변동을 정의하며,
+/// `ticks` 만큼 유지된 뒤 다음 레짐으로 전환됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VolatilityRegime {
+    /// 레짐 이름 (로그/디버깅용)
+    pub name: String,
+    /// 틱당 기대 수익률 (예: -0.002 = 틱당 -0.2%)
+    pub drift: f64,
+    /// 틱당 수익률 표준편차 (예: 0.001 = 0.1%)
+    pub volatility: f64,
+    /// 유지 틱 수
+    pub ticks: u64,
+}
+
+impl VolatilityRegime {
+    /// 새 레짐 생성.
+    pub fn new(name: impl Into<String>, drift: f64, volatility: f64, ticks: u64) -> Self {
+        Self {
+            name: name.into(),
+            drift,
+            volatility: volatility.max(0.0),
+            ticks: ticks.max(1),
+        }
+    }
+}
+
+/// 변동성 레짐 스케줄.
+///
+/// 레짐을 순서대로 적용하며, 마지막 레짐이 끝나면 `repeat`이면 처음부터 반복하고
+/// 아니면 마지막 레짐을 계속 유지합니다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RegimeSchedule {
+    /// 순서대로 적용할 레짐 목록
+    pub regimes: Vec<VolatilityRegime>,
+    /// 스케줄 반복 여부
+    #[serde(default)]
+    pub repeat: bool,
+}
+
+impl RegimeSchedule {
+    /// 빈 스케줄 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 레짐 추가.
+    pub fn then(mut self, regime: VolatilityRegime) -> Self {
+        self.regimes.push(regime);
+        self
+    }
+
+    /// 반복 여부 설정.
+    pub fn repeating(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// 저변동 → 고변동 → 급락 → 회복 스트레스 사이클.
+    pub fn stress_cycle() -> Self {
+        Self::new()
+            .then(VolatilityRegime::new("low_vol", 0.0, 0.0005, 300))
+            .then(VolatilityRegime::new("high_vol", 0.0, 0.004, 200))
+            .then(VolatilityRegime::new("crash", -0.003, 0.006, 60))
+            .then(VolatilityRegime::new("recovery", 0.001, 0.002, 240))
+    }
+
+    /// 이름으로 프리셋 조회.
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "stress_cycle" => Some(Self::stress_cycle()),
+            _ => None,
+        }
+    }
+
+    /// 스케줄 한 주기의 총 틱 수.
+    pub fn total_ticks(&self) -> u64 {
+        self.regimes.iter().map(|r| r.ticks).sum()
+    }
+
+    /// `tick`번째 틱(0부터)에 적용되는 레짐.
+    pub fn regime_at(&self, tick: u64) -> Option<&VolatilityRegime> {
+        let total = self.total_ticks();
+        if total == 0 {
+            return None;
+        }
+
+        let tick = if self.repeat {
+            tick % total
+        } else if tick >= total {
+            return self.regimes.last();
+        } else {
+            tick
+        };
+
+        let mut elapsed = 0;
+        for regime in &self.regimes {
+            elapsed += regime.ticks;
+            if tick < elapsed {
+                return Some(regime);
+            }
+        }
+        self.regimes.last()
+    }
+}
+
 // ==================== PriceTick ====================
 
 /// 단일 가격 틱 데이터.
@@ -102,6 +249,10 @@ pub trait MockPriceGenerator: Send + Sync {
 ///
 /// ATR(Average True Range) 기반 변동성 + 평균회귀 모델:
 /// `new_price = current + ATR * N(0,1) * √dt + mean_reversion`
+///
+/// 레짐 스케줄이 설정되면 심볼별 틱 수에 따라 현재 레짐의 드리프트/표준편차로
+/// `new_price = current * (1 + drift + σ * N(0,1))`를 적용하며, 레짐의 추세가
+/// 유지되도록 평균회귀는 적용하지 않습니다.
 pub struct RandomWalkGenerator {
     /// 심볼별 현재 가격
     current_prices: HashMap<String, Decimal>,
@@ -113,6 +264,12 @@ pub struct RandomWalkGenerator {
     mean_reversion_strength: f64,
     /// 호가 단위 제공자 (옵션)
     tick_size_provider: Option<Arc<dyn TickSizeProvider>>,
+    /// 변동성 레짐 스케줄 (옵션)
+    regime_schedule: Option<RegimeSchedule>,
+    /// 심볼별 생성 틱 수 (레짐 위치 계산용)
+    tick_counts: HashMap<String, u64>,
+    /// 난수 생성기 (시드 지정 시 재현 가능)
+    rng: StdRng,
 }
 
 impl Default for RandomWalkGenerator {
@@ -130,7 +287,40 @@ impl RandomWalkGenerator {
             atr_ratio: 0.002,
             mean_reversion_strength: 0.01,
             tick_size_provider: None,
+            regime_schedule: None,
+            tick_counts: HashMap::new(),
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// 스트리밍 설정으로 생성 (레짐 스케줄/시드 반영).
+    pub fn from_config(config: &MockStreamingConfig) -> Self {
+        let mut generator = Self::new();
+        if let Some(schedule) = &config.regime_schedule {
+            generator = generator.with_regime_schedule(schedule.clone());
+        }
+        if let Some(seed) = config.seed {
+            generator = generator.with_seed(seed);
         }
+        generator
+    }
+
+    /// 난수 시드 설정 (같은 시드 → 같은 가격 경로).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// 변동성 레짐 스케줄 설정.
+    pub fn with_regime_schedule(mut self, schedule: RegimeSchedule) -> Self {
+        self.regime_schedule = Some(schedule);
+        self
+    }
+
+    /// 심볼의 현재(다음 틱에 적용될) 레짐.
+    pub fn current_regime(&self, symbol: &str) -> Option<&VolatilityRegime> {
+        let tick = self.tick_counts.get(symbol).copied().unwrap_or(0);
+        self.regime_schedule.as_ref()?.regime_at(tick)
     }
 
     /// 호가 단위 제공자 설정.
@@ -161,23 +351,33 @@ impl MockPriceGenerator for RandomWalkGenerator {
         let current_price = *self.current_prices.get(symbol)?;
         let initial_price = *self.initial_prices.get(symbol)?;
 
-        let mut rng = rand::thread_rng();
-
         // Box-Muller 정규분포 생성
-        let u1: f64 = rng.gen_range(0.0001..1.0);
-        let u2: f64 = rng.gen_range(0.0..std::f64::consts::TAU);
+        let u1: f64 = self.rng.gen_range(0.0001..1.0);
+        let u2: f64 = self.rng.gen_range(0.0..std::f64::consts::TAU);
         let normal = (-2.0 * u1.ln()).sqrt() * u2.cos();
 
-        // ATR 기반 가격 변동
         let price_f64 = current_price.to_f64().unwrap_or(0.0);
-        let atr = price_f64 * self.atr_ratio;
-        let random_change = atr * normal;
+        let tick = self.tick_counts.entry(symbol.to_string()).or_insert(0);
+        let regime = self
+            .regime_schedule
+            .as_ref()
+            .and_then(|schedule| schedule.regime_at(*tick));
+        *tick += 1;
+
+        let new_price_f64 = if let Some(regime) = regime {
+            // 레짐 드리프트 + 표준편차 기반 변동
+            price_f64 * (1.0 + regime.drift + regime.volatility * normal)
+        } else {
+            // ATR 기반 가격 변동
+            let atr = price_f64 * self.atr_ratio;
+            let random_change = atr * normal;
 
-        // 평균 회귀 (초기 가격 방향으로 약하게 끌어당김)
-        let initial_f64 = initial_price.to_f64().unwrap_or(0.0);
-        let reversion = (initial_f64 - price_f64) * self.mean_reversion_strength;
+            // 평균 회귀 (초기 가격 방향으로 약하게 끌어당김)
+            let initial_f64 = initial_price.to_f64().unwrap_or(0.0);
+            let reversion = (initial_f64 - price_f64) * self.mean_reversion_strength;
 
-        let new_price_f64 = price_f64 + random_change + reversion;
+            price_f64 + random_change + reversion
+        };
         // 가격은 0 이하로 내려가지 않음
         let new_price_f64 = new_price_f64.max(price_f64 * 0.5);
 
@@ -198,7 +398,7 @@ impl MockPriceGenerator for RandomWalkGenerator {
         self.current_prices.insert(symbol.to_string(), new_price);
 
         // 거래량 추정 (랜덤)
-        let volume = Decimal::from(rng.gen_range(10u64..500));
+        let volume = Decimal::from(self.rng.gen_range(10u64..500));
 
         Some(PriceTick {
             symbol: symbol.to_string(),
@@ -213,6 +413,7 @@ impl MockPriceGenerator for RandomWalkGenerator {
             .insert(symbol.to_string(), initial_price);
         self.initial_prices
             .insert(symbol.to_string(), initial_price);
+        self.tick_counts.insert(symbol.to_string(), 0);
     }
 }
 
@@ -550,6 +751,88 @@ mod tests {
         assert!(unique_prices.len() > 1, "가격에 변동이 있어야 함");
     }
 
+    #[tokio::test]
+    async fn test_random_walk_same_seed_same_path() {
+        let config = MockStreamingConfig::default()
+            .with_seed(42)
+            .with_regime_schedule(RegimeSchedule::stress_cycle());
+
+        let mut paths = Vec::new();
+        for _ in 0..2 {
+            let mut gen = RandomWalkGenerator::from_config(&config);
+            gen.initialize("TEST", dec!(50000)).await;
+            let mut path = Vec::new();
+            for _ in 0..200 {
+                let tick = gen.next_tick("TEST").await.unwrap();
+                path.push((tick.price, tick.volume));
+            }
+            paths.push(path);
+        }
+        assert_eq!(paths[0], paths[1], "같은 시드는 같은 가격 경로");
+
+        let mut other = RandomWalkGenerator::new().with_seed(7);
+        other.initialize("TEST", dec!(50000)).await;
+        let mut other_path = Vec::new();
+        for _ in 0..200 {
+            other_path.push(other.next_tick("TEST").await.unwrap().price);
+        }
+        let first: Vec<Decimal> = paths[0].iter().map(|(p, _)| *p).collect();
+        assert_ne!(first, other_path, "다른 시드는 다른 경로");
+    }
+
+    #[test]
+    fn test_regime_schedule_transitions() {
+        let schedule = RegimeSchedule::new()
+            .then(VolatilityRegime::new("low", 0.0, 0.001, 3))
+            .then(VolatilityRegime::new("crash", -0.01, 0.01, 2));
+
+        let names: Vec<&str> = (0..7)
+            .map(|t| schedule.regime_at(t).unwrap().name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["low", "low", "low", "crash", "crash", "crash", "crash"],
+            "비반복 스케줄은 마지막 레짐 유지"
+        );
+
+        let repeating = schedule.clone().repeating(true);
+        assert_eq!(repeating.regime_at(5).unwrap().name, "low");
+        assert_eq!(repeating.regime_at(8).unwrap().name, "crash");
+        assert!(RegimeSchedule::new().regime_at(0).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_random_walk_regime_volatility_and_drift() {
+        let schedule = RegimeSchedule::new()
+            .then(VolatilityRegime::new("calm", 0.0, 0.0001, 100))
+            .then(VolatilityRegime::new("crash", -0.01, 0.0001, 50))
+            .then(VolatilityRegime::new("wild", 0.0, 0.02, 100));
+        let mut gen = RandomWalkGenerator::new()
+            .with_seed(1)
+            .with_regime_schedule(schedule);
+        gen.initialize("TEST", dec!(10000)).await;
+
+        let mut returns = Vec::new();
+        let mut prev = dec!(10000).to_f64().unwrap();
+        for _ in 0..250 {
+            let price = gen.next_tick("TEST").await.unwrap().price.to_f64().unwrap();
+            returns.push(price / prev - 1.0);
+            prev = price;
+        }
+        assert_eq!(gen.current_regime("TEST").unwrap().name, "wild");
+
+        let mean_abs = |r: &[f64]| r.iter().map(|x| x.abs()).sum::<f64>() / r.len() as f64;
+        let calm = &returns[..100];
+        let crash = &returns[100..150];
+        let wild = &returns[150..];
+
+        assert!(
+            mean_abs(wild) > mean_abs(calm) * 10.0,
+            "고변동 레짐 변동폭 증가"
+        );
+        assert!(crash.iter().all(|r| *r < 0.0), "급락 레짐은 하락 드리프트");
+    }
+
     #[tokio::test]
     async fn test_historical_replay_generator() {
         let mut gen = HistoricalReplayGenerator::new(1.0);
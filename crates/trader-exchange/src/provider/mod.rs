@@ -7,26 +7,43 @@
 //! 모든 거래소는 `XXXExchangeProvider` 패턴을 따릅니다:
 //! - [`KisExchangeProvider`]: KIS 국내/해외/ISA 계좌 통합 Provider
 //! - [`BinanceProvider`]: Binance 거래소 Provider
+//! - [`BinanceFuturesProvider`]: Binance USDT-M 선물 Provider
+//! - [`BybitExchangeProvider`]: Bybit V5 통합 API Provider (현물/USDT 무기한)
 //! - [`MockExchangeProvider`]: 테스트/시뮬레이션용 Mock Provider
+//! - [`ShadowExecutor`]: 실거래와 Paper Trading 동시 운용 (섀도 모드)
+//! - [`InstrumentedProvider`]: 임의 Provider의 API 호출 메트릭 계측 래퍼
 
 mod binance;
+mod binance_futures;
 mod bithumb;
+mod bybit;
 mod db_investment;
+mod instrumented;
 mod kis;
 mod ls_sec;
 mod mock;
+pub mod mock_failure;
 pub mod mock_order_engine;
 pub mod mock_streaming;
+mod shadow;
 mod upbit;
 
 pub use binance::{BinanceExchangeProvider, BinanceProvider};
+pub use binance_futures::{BinanceFuturesProvider, MAX_FUTURES_LEVERAGE};
 pub use bithumb::{BithumbExchangeProvider, BithumbProvider};
+pub use bybit::{BybitExchangeProvider, BybitProvider};
 pub use db_investment::{DbInvestmentExchangeProvider, DbInvestmentProvider};
+pub use instrumented::InstrumentedProvider;
 pub use kis::{KisExchangeProvider, KisProvider};
 pub use ls_sec::{LsSecExchangeProvider, LsSecProvider};
-pub use mock::{MockConfig, MockExchangeProvider, MockMarketStream};
-pub use mock_order_engine::{MockOrderEngine, RawPendingOrder};
+pub use mock::{MockConfig, MockExchangeProvider, MockMarketStream, StrategyUnrealizedPnl};
+pub use mock_failure::{FailureInjector, InjectedErrorKind, InjectedFailure, MockFailureConfig};
+pub use mock_order_engine::{MockOrderEngine, RawPendingOrder, DEFAULT_MAX_WAIT_TICKS};
 pub use mock_streaming::{
     MockOrderBookGenerator, MockPriceGenerator, MockPriceMode, MockStreamingConfig,
+    RandomWalkGenerator, RegimeSchedule, VolatilityRegime,
+};
+pub use shadow::{
+    FillComparison, ShadowConfig, ShadowExecution, ShadowExecutor, ShadowPathSummary, ShadowReport,
 };
 pub use upbit::{UpbitExchangeProvider, UpbitProvider};
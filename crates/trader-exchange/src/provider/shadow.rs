@@ -0,0 +1,697 @@
+//! 섀도 모드 (Paper Trading + 실거래 동시 운용).
+//!
+//! 한 전략의 신호를 실거래 경로와 페이퍼 경로에 동시에 전달합니다.
+//! 페이퍼 경로는 항상 Mock 거래소와 같은 비용 모델로 시뮬레이션하고,
+//! 실거래 경로는 설정에 따라 실제 주문을 집행하거나 건너뜁니다.
+//!
+//! # 아키텍처
+//!
+//! ```text
+//! Signal ──> ShadowExecutor
+//!            ├── live  (LiveExecutor → 실거래 OrderExecutionProvider)  [선택적 집행]
+//!            ├── paper (SimulatedExecutor, MockConfig 수수료/슬리피지)  [항상 시뮬레이션]
+//!            └── 체결가·손익 비교 → 괴리 경고 / ShadowReport
+//! ```
+//!
+//! 두 경로는 자본을 독립적으로 추적하며, 같은 신호에 대한 체결가 차이(bps)와
+//! 누적 수익률 차이(%p)가 임계값을 넘으면 경고를 남깁니다.
+
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use trader_core::{domain::OrderExecutionProvider, Side, Signal, SignalType};
+use trader_execution::{
+    LiveExecutor, ProcessorConfig, SignalProcessor, SignalProcessorError, SimulatedExecutor,
+    TradeResult,
+};
+
+use super::MockConfig;
+
+/// 최근 비교 기록 보관 개수
+const MAX_COMPARISONS: usize = 500;
+
+/// 섀도 모드 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowConfig {
+    /// 섀도(페이퍼) 경로 활성화 여부
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// 실거래 경로에서 실제 주문을 집행할지 여부
+    #[serde(default)]
+    pub execute_live: bool,
+    /// 체결가 괴리 경고 임계값 (bps)
+    #[serde(default = "default_max_slippage_bps")]
+    pub max_slippage_bps: Decimal,
+    /// 수익률 괴리 경고 임계값 (%p)
+    #[serde(default = "default_max_pnl_divergence_pct")]
+    pub max_pnl_divergence_pct: Decimal,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_slippage_bps() -> Decimal {
+    dec!(50)
+}
+
+fn default_max_pnl_divergence_pct() -> Decimal {
+    dec!(1)
+}
+
+impl Default for ShadowConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            execute_live: false,
+            max_slippage_bps: default_max_slippage_bps(),
+            max_pnl_divergence_pct: default_max_pnl_divergence_pct(),
+        }
+    }
+}
+
+/// 같은 신호에 대한 두 경로의 체결 비교.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FillComparison {
+    /// 종목
+    pub ticker: String,
+    /// 방향
+    pub side: Side,
+    /// 신호 유형
+    pub signal_type: SignalType,
+    /// 신호 처리 시각
+    pub timestamp: DateTime<Utc>,
+    /// 신호 시점 기준가
+    pub reference_price: Decimal,
+    /// 실거래 체결가
+    pub live_price: Option<Decimal>,
+    /// 페이퍼 체결가
+    pub paper_price: Option<Decimal>,
+    /// 실거래 체결 수량
+    pub live_quantity: Option<Decimal>,
+    /// 페이퍼 체결 수량
+    pub paper_quantity: Option<Decimal>,
+    /// 페이퍼 대비 실거래 체결가 괴리 (bps, 양수 = 실거래가 불리)
+    pub slippage_bps: Option<Decimal>,
+    /// 실거래 실현 손익 - 페이퍼 실현 손익 (청산 거래)
+    pub realized_pnl_diff: Option<Decimal>,
+    /// 괴리 임계값 초과 여부
+    pub exceeded: bool,
+}
+
+impl FillComparison {
+    fn new(
+        signal: &Signal,
+        reference_price: Decimal,
+        timestamp: DateTime<Utc>,
+        live: Option<&TradeResult>,
+        paper: Option<&TradeResult>,
+    ) -> Self {
+        let slippage_bps = match (live, paper) {
+            (Some(l), Some(p)) => adverse_bps(signal.side, l.price, p.price),
+            _ => None,
+        };
+        let realized_pnl_diff = match (
+            live.and_then(|t| t.realized_pnl),
+            paper.and_then(|t| t.realized_pnl),
+        ) {
+            (Some(l), Some(p)) => Some(l - p),
+            _ => None,
+        };
+
+        Self {
+            ticker: signal.ticker.clone(),
+            side: signal.side,
+            signal_type: signal.signal_type,
+            timestamp,
+            reference_price,
+            live_price: live.map(|t| t.price),
+            paper_price: paper.map(|t| t.price),
+            live_quantity: live.map(|t| t.quantity),
+            paper_quantity: paper.map(|t| t.quantity),
+            slippage_bps,
+            realized_pnl_diff,
+            exceeded: false,
+        }
+    }
+
+    /// 한쪽 경로만 체결되었는지 여부.
+    pub fn is_one_sided(&self) -> bool {
+        self.live_price.is_some() != self.paper_price.is_some()
+    }
+}
+
+/// 기준가 대비 불리한 방향의 가격 차이 (bps).
+///
+/// 매수는 비싸게, 매도는 싸게 체결될수록 양수입니다.
+fn adverse_bps(side: Side, price: Decimal, base: Decimal) -> Option<Decimal> {
+    if base <= Decimal::ZERO {
+        return None;
+    }
+    let diff = match side {
+        Side::Buy => price - base,
+        Side::Sell => base - price,
+    };
+    Some(diff / base * dec!(10000))
+}
+
+/// 섀도 실행 결과.
+#[derive(Debug, Clone)]
+pub struct ShadowExecution {
+    /// 실거래 체결 (집행하지 않았거나 체결 없음이면 None)
+    pub live: Option<TradeResult>,
+    /// 페이퍼 체결 (섀도 비활성 또는 체결 없음이면 None)
+    pub paper: Option<TradeResult>,
+    /// 두 경로 비교 (둘 중 하나라도 체결된 경우)
+    pub comparison: Option<FillComparison>,
+}
+
+/// 경로별 자본 요약.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShadowPathSummary {
+    /// 초기 자본
+    pub initial_balance: Decimal,
+    /// 현금 잔고
+    pub balance: Decimal,
+    /// 총 자산 (잔고 + 포지션 평가액)
+    pub equity: Decimal,
+    /// 수익률 (%)
+    pub return_pct: Decimal,
+    /// 실현 손익
+    pub realized_pnl: Decimal,
+    /// 총 수수료
+    pub commission: Decimal,
+    /// 체결 수
+    pub fills: usize,
+    /// 처리 실패 수
+    pub errors: usize,
+}
+
+impl ShadowPathSummary {
+    fn from_processor(
+        processor: &dyn SignalProcessor,
+        initial_balance: Decimal,
+        prices: &HashMap<String, Decimal>,
+        errors: usize,
+    ) -> Self {
+        let equity = processor.total_equity(prices);
+        let return_pct = if initial_balance > Decimal::ZERO {
+            (equity - initial_balance) / initial_balance * dec!(100)
+        } else {
+            Decimal::ZERO
+        };
+
+        Self {
+            initial_balance,
+            balance: processor.balance(),
+            equity,
+            return_pct,
+            realized_pnl: processor.realized_pnl(),
+            commission: processor.total_commission(),
+            fills: processor.trades().len(),
+            errors,
+        }
+    }
+}
+
+/// 섀도 모드 비교 리포트.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowReport {
+    /// 섀도 경로 활성화 여부
+    pub enabled: bool,
+    /// 실거래 집행 여부
+    pub execute_live: bool,
+    /// 처리한 신호 수
+    pub signals: usize,
+    /// 양쪽 모두 체결되어 비교된 건수
+    pub matched_fills: usize,
+    /// 한쪽만 체결된 건수
+    pub one_sided_fills: usize,
+    /// 평균 체결가 괴리 (bps)
+    pub avg_slippage_bps: Decimal,
+    /// 최대 체결가 괴리 (bps)
+    pub max_slippage_bps: Decimal,
+    /// 임계값을 넘은 체결 수
+    pub slippage_warnings: usize,
+    /// 실거래 경로 요약
+    pub live: ShadowPathSummary,
+    /// 페이퍼 경로 요약
+    pub paper: ShadowPathSummary,
+    /// 수익률 괴리 (실거래 - 페이퍼, %p)
+    pub pnl_divergence_pct: Decimal,
+    /// 수익률 괴리 임계값 초과 여부
+    pub pnl_divergence_exceeded: bool,
+    /// 최근 비교 기록
+    pub recent: Vec<FillComparison>,
+}
+
+/// 섀도 모드 실행기.
+///
+/// 실거래 경로와 페이퍼 경로를 각각 [`SignalProcessor`]로 보유하고,
+/// 같은 신호를 양쪽에 전달해 체결 품질을 비교합니다.
+pub struct ShadowExecutor {
+    /// 설정
+    config: ShadowConfig,
+    /// 실거래 경로
+    live: Box<dyn SignalProcessor>,
+    /// 페이퍼 경로
+    paper: Box<dyn SignalProcessor>,
+    /// 실거래 초기 자본
+    live_initial: Decimal,
+    /// 페이퍼 초기 자본
+    paper_initial: Decimal,
+    /// 심볼별 최신 가격
+    last_prices: HashMap<String, Decimal>,
+    /// 최근 비교 기록
+    comparisons: Vec<FillComparison>,
+    /// 처리한 신호 수
+    signals: usize,
+    /// 실거래 처리 실패 수
+    live_errors: usize,
+    /// 페이퍼 처리 실패 수
+    paper_errors: usize,
+}
+
+impl ShadowExecutor {
+    /// 실거래/페이퍼 실행기로 생성.
+    pub fn new(
+        config: ShadowConfig,
+        live: Box<dyn SignalProcessor>,
+        paper: Box<dyn SignalProcessor>,
+    ) -> Self {
+        let live_initial = live.balance();
+        let paper_initial = paper.balance();
+        Self {
+            config,
+            live,
+            paper,
+            live_initial,
+            paper_initial,
+            last_prices: HashMap::new(),
+            comparisons: Vec::new(),
+            signals: 0,
+            live_errors: 0,
+            paper_errors: 0,
+        }
+    }
+
+    /// 실거래 Provider와 Mock 거래소 설정으로 생성.
+    ///
+    /// 실거래 경로는 `LiveExecutor`, 페이퍼 경로는 Mock 거래소의
+    /// 수수료/슬리피지를 적용한 `SimulatedExecutor`를 사용합니다.
+    pub fn from_providers(
+        config: ShadowConfig,
+        processor_config: ProcessorConfig,
+        live_provider: Arc<dyn OrderExecutionProvider>,
+        live_balance: Decimal,
+        mock_config: &MockConfig,
+    ) -> Self {
+        let paper_config = ProcessorConfig {
+            commission_rate: mock_config.commission_rate,
+            slippage_rate: mock_config.slippage_rate,
+            ..processor_config.clone()
+        };
+        let live = LiveExecutor::new(processor_config, live_balance, live_provider);
+        let paper = SimulatedExecutor::new(paper_config, mock_config.initial_balance);
+        Self::new(config, Box::new(live), Box::new(paper))
+    }
+
+    /// 설정 조회.
+    pub fn config(&self) -> &ShadowConfig {
+        &self.config
+    }
+
+    /// 섀도(페이퍼) 경로 on/off.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.config.enabled = enabled;
+    }
+
+    /// 실거래 집행 on/off.
+    pub fn set_execute_live(&mut self, execute_live: bool) {
+        self.config.execute_live = execute_live;
+    }
+
+    /// 실거래 경로.
+    pub fn live(&self) -> &dyn SignalProcessor {
+        self.live.as_ref()
+    }
+
+    /// 페이퍼 경로.
+    pub fn paper(&self) -> &dyn SignalProcessor {
+        self.paper.as_ref()
+    }
+
+    /// 최근 비교 기록.
+    pub fn comparisons(&self) -> &[FillComparison] {
+        &self.comparisons
+    }
+
+    /// 신호를 두 경로에 전달.
+    ///
+    /// 실거래 경로가 실패하면 페이퍼 결과를 기록한 뒤 에러를 반환합니다.
+    /// 페이퍼 경로 실패는 실거래를 막지 않고 경고만 남깁니다.
+    pub async fn process_signal(
+        &mut self,
+        signal: &Signal,
+        current_price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Result<ShadowExecution, SignalProcessorError> {
+        self.signals += 1;
+        self.last_prices
+            .insert(signal.ticker.clone(), current_price);
+
+        let live_result = if self.config.execute_live {
+            self.live
+                .process_signal(signal, current_price, timestamp)
+                .await
+        } else {
+            Ok(None)
+        };
+
+        let paper = if self.config.enabled {
+            match self
+                .paper
+                .process_signal(signal, current_price, timestamp)
+                .await
+            {
+                Ok(trade) => trade,
+                Err(e) => {
+                    self.paper_errors += 1;
+                    warn!(
+                        strategy_id = %signal.strategy_id,
+                        ticker = %signal.ticker,
+                        error = %e,
+                        "섀도 페이퍼 체결 실패"
+                    );
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let live = match live_result {
+            Ok(trade) => trade,
+            Err(e) => {
+                self.live_errors += 1;
+                if let Some(trade) = paper.as_ref() {
+                    let comparison =
+                        FillComparison::new(signal, current_price, timestamp, None, Some(trade));
+                    self.record(comparison);
+                }
+                return Err(e);
+            }
+        };
+
+        let comparison = if live.is_some() || paper.is_some() {
+            let mut comparison = FillComparison::new(
+                signal,
+                current_price,
+                timestamp,
+                live.as_ref(),
+                paper.as_ref(),
+            );
+            comparison.exceeded = comparison
+                .slippage_bps
+                .is_some_and(|bps| bps.abs() > self.config.max_slippage_bps);
+            if comparison.exceeded {
+                warn!(
+                    strategy_id = %signal.strategy_id,
+                    ticker = %signal.ticker,
+                    live_price = ?comparison.live_price,
+                    paper_price = ?comparison.paper_price,
+                    slippage_bps = ?comparison.slippage_bps,
+                    "섀도 체결가 괴리 임계값 초과"
+                );
+            }
+            self.record(comparison.clone());
+            Some(comparison)
+        } else {
+            None
+        };
+
+        self.check_pnl_divergence(&signal.strategy_id);
+
+        Ok(ShadowExecution {
+            live,
+            paper,
+            comparison,
+        })
+    }
+
+    /// 현재가를 두 경로에 전달 (트레일링/스톱 처리).
+    pub async fn on_price_update(
+        &mut self,
+        symbol: &str,
+        current_price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Vec<TradeResult>, SignalProcessorError> {
+        self.last_prices.insert(symbol.to_string(), current_price);
+
+        if self.config.enabled {
+            if let Err(e) = self
+                .paper
+                .on_price_update(symbol, current_price, timestamp)
+                .await
+            {
+                self.paper_errors += 1;
+                warn!(symbol, error = %e, "섀도 페이퍼 가격 갱신 실패");
+            }
+        }
+
+        if self.config.execute_live {
+            self.live
+                .on_price_update(symbol, current_price, timestamp)
+                .await
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    /// 비교 리포트 생성.
+    pub fn report(&self) -> ShadowReport {
+        let live = ShadowPathSummary::from_processor(
+            self.live.as_ref(),
+            self.live_initial,
+            &self.last_prices,
+            self.live_errors,
+        );
+        let paper = ShadowPathSummary::from_processor(
+            self.paper.as_ref(),
+            self.paper_initial,
+            &self.last_prices,
+            self.paper_errors,
+        );
+
+        let slippages: Vec<Decimal> = self
+            .comparisons
+            .iter()
+            .filter_map(|c| c.slippage_bps)
+            .collect();
+        let avg_slippage_bps = if slippages.is_empty() {
+            Decimal::ZERO
+        } else {
+            slippages.iter().sum::<Decimal>() / Decimal::from(slippages.len())
+        };
+        let max_slippage_bps = slippages
+            .iter()
+            .map(|s| s.abs())
+            .max()
+            .unwrap_or(Decimal::ZERO);
+
+        let pnl_divergence_pct = live.return_pct - paper.return_pct;
+
+        ShadowReport {
+            enabled: self.config.enabled,
+            execute_live: self.config.execute_live,
+            signals: self.signals,
+            matched_fills: slippages.len(),
+            one_sided_fills: self.comparisons.iter().filter(|c| c.is_one_sided()).count(),
+            avg_slippage_bps: avg_slippage_bps.round_dp(2),
+            max_slippage_bps: max_slippage_bps.round_dp(2),
+            slippage_warnings: self.comparisons.iter().filter(|c| c.exceeded).count(),
+            pnl_divergence_exceeded: self.pnl_divergence_exceeded(pnl_divergence_pct),
+            pnl_divergence_pct: pnl_divergence_pct.round_dp(4),
+            live,
+            paper,
+            recent: self.comparisons.clone(),
+        }
+    }
+
+    fn record(&mut self, comparison: FillComparison) {
+        if self.comparisons.len() >= MAX_COMPARISONS {
+            self.comparisons.remove(0);
+        }
+        self.comparisons.push(comparison);
+    }
+
+    /// 두 경로 모두 운용 중일 때만 수익률 괴리를 평가.
+    fn pnl_divergence_exceeded(&self, divergence: Decimal) -> bool {
+        self.config.enabled
+            && self.config.execute_live
+            && divergence.abs() > self.config.max_pnl_divergence_pct
+    }
+
+    fn check_pnl_divergence(&self, strategy_id: &str) {
+        let live_equity = self.live.total_equity(&self.last_prices);
+        let paper_equity = self.paper.total_equity(&self.last_prices);
+        let pct = |equity: Decimal, initial: Decimal| {
+            if initial > Decimal::ZERO {
+                (equity - initial) / initial * dec!(100)
+            } else {
+                Decimal::ZERO
+            }
+        };
+        let divergence =
+            pct(live_equity, self.live_initial) - pct(paper_equity, self.paper_initial);
+
+        if self.pnl_divergence_exceeded(divergence) {
+            warn!(
+                strategy_id,
+                divergence_pct = %divergence.round_dp(4),
+                "섀도 수익률 괴리 임계값 초과"
+            );
+        } else {
+            debug!(strategy_id, divergence_pct = %divergence.round_dp(4), "섀도 수익률 괴리");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(side: Side, signal_type: SignalType) -> Signal {
+        Signal::new("shadow_test", "AAPL".to_string(), side, signal_type)
+    }
+
+    fn executor(config: ShadowConfig, live_slippage: Decimal) -> ShadowExecutor {
+        let base = ProcessorConfig {
+            commission_rate: Decimal::ZERO,
+            slippage_rate: Decimal::ZERO,
+            ..Default::default()
+        };
+        let live = SimulatedExecutor::new(
+            ProcessorConfig {
+                slippage_rate: live_slippage,
+                ..base.clone()
+            },
+            dec!(100000),
+        );
+        let paper = SimulatedExecutor::new(base, dec!(100000));
+        ShadowExecutor::new(config, Box::new(live), Box::new(paper))
+    }
+
+    fn live_config() -> ShadowConfig {
+        ShadowConfig {
+            execute_live: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_adverse_bps_sign() {
+        assert_eq!(
+            adverse_bps(Side::Buy, dec!(101), dec!(100)),
+            Some(dec!(100))
+        );
+        assert_eq!(
+            adverse_bps(Side::Sell, dec!(101), dec!(100)),
+            Some(dec!(-100))
+        );
+        assert_eq!(adverse_bps(Side::Buy, dec!(1), Decimal::ZERO), None);
+    }
+
+    #[tokio::test]
+    async fn test_shadow_compares_live_and_paper_fills() {
+        // 실거래 경로에만 1% 슬리피지 → 100bps 괴리
+        let mut shadow = executor(live_config(), dec!(0.01));
+
+        let result = shadow
+            .process_signal(&signal(Side::Buy, SignalType::Entry), dec!(100), Utc::now())
+            .await
+            .unwrap();
+
+        let comparison = result.comparison.unwrap();
+        assert_eq!(comparison.live_price, Some(dec!(101)));
+        assert_eq!(comparison.paper_price, Some(dec!(100)));
+        assert_eq!(comparison.slippage_bps, Some(dec!(100)));
+        assert!(comparison.exceeded);
+
+        // 자본은 독립 추적 (같은 금액으로 실거래는 더 적은 수량 매수)
+        assert!(comparison.live_quantity.unwrap() < comparison.paper_quantity.unwrap());
+
+        let report = shadow.report();
+        assert_eq!(report.signals, 1);
+        assert_eq!(report.matched_fills, 1);
+        assert_eq!(report.slippage_warnings, 1);
+        assert!(report.live.return_pct < report.paper.return_pct);
+    }
+
+    #[tokio::test]
+    async fn test_shadow_without_live_execution_only_simulates() {
+        let mut shadow = executor(ShadowConfig::default(), dec!(0.01));
+
+        let result = shadow
+            .process_signal(&signal(Side::Buy, SignalType::Entry), dec!(100), Utc::now())
+            .await
+            .unwrap();
+
+        assert!(result.live.is_none());
+        assert!(result.paper.is_some());
+        assert!(result.comparison.unwrap().is_one_sided());
+        assert_eq!(shadow.live().trades().len(), 0);
+        assert_eq!(shadow.report().matched_fills, 0);
+    }
+
+    #[tokio::test]
+    async fn test_shadow_toggle_off_skips_paper() {
+        let mut shadow = executor(live_config(), Decimal::ZERO);
+        shadow.set_enabled(false);
+
+        let result = shadow
+            .process_signal(&signal(Side::Buy, SignalType::Entry), dec!(100), Utc::now())
+            .await
+            .unwrap();
+
+        assert!(result.live.is_some());
+        assert!(result.paper.is_none());
+        assert_eq!(shadow.paper().trades().len(), 0);
+
+        let report = shadow.report();
+        assert!(!report.enabled);
+        assert!(!report.pnl_divergence_exceeded);
+    }
+
+    #[tokio::test]
+    async fn test_shadow_pnl_divergence() {
+        let mut shadow = executor(
+            ShadowConfig {
+                max_pnl_divergence_pct: dec!(0.001),
+                ..live_config()
+            },
+            dec!(0.01),
+        );
+
+        shadow
+            .process_signal(&signal(Side::Buy, SignalType::Entry), dec!(100), Utc::now())
+            .await
+            .unwrap();
+        let result = shadow
+            .process_signal(&signal(Side::Sell, SignalType::Exit), dec!(110), Utc::now())
+            .await
+            .unwrap();
+
+        let comparison = result.comparison.unwrap();
+        assert!(comparison.realized_pnl_diff.unwrap() < Decimal::ZERO);
+
+        let report = shadow.report();
+        assert_eq!(report.matched_fills, 2);
+        assert!(report.pnl_divergence_pct < Decimal::ZERO);
+        assert!(report.pnl_divergence_exceeded);
+    }
+}
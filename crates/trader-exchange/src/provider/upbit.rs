@@ -14,9 +14,10 @@ use trader_core::{
         OrderExecutionProvider, OrderRequest, OrderResponse, OrderType, PendingOrder,
         ProviderError, QuoteData, Side, StrategyAccountInfo, StrategyPositionInfo,
     },
+    SymbolNormalizer, SymbolVenue,
 };
 
-use crate::connector::upbit::UpbitClient;
+use crate::connector::upbit::{UpbitClient, UpbitMarketRule};
 
 /// Upbit ExchangeProvider 구현.
 ///
@@ -44,6 +45,99 @@ impl UpbitExchangeProvider {
     }
 }
 
+// ==================== 주문 변환 ====================
+
+/// 업비트 주문 API 파라미터.
+#[derive(Debug, Clone, PartialEq)]
+struct UpbitOrderParams {
+    /// `bid` (매수) / `ask` (매도)
+    side: &'static str,
+    /// `limit` / `price` (시장가 매수) / `market` (시장가 매도)
+    ord_type: &'static str,
+    /// 주문 수량
+    volume: Option<String>,
+    /// 지정가 또는 시장가 매수 총액
+    price: Option<String>,
+}
+
+/// 내부 표준 심볼을 업비트 마켓 코드로 변환 (BTC/KRW → KRW-BTC).
+///
+/// 이미 업비트 형식(`KRW-BTC`)이면 그대로 사용합니다.
+fn upbit_market(ticker: &str) -> String {
+    SymbolNormalizer::new()
+        .to_venue(ticker, SymbolVenue::Upbit)
+        .unwrap_or_else(|_| ticker.to_string())
+}
+
+/// `OrderRequest`를 업비트 주문 파라미터로 변환하고 마켓 규칙으로 사전 검증.
+///
+/// - 지정가: 호가 단위, 수량 소수 자릿수, 최소 주문 금액
+/// - 시장가 매수: `quantity × reference_price`를 주문 금액(`price`)으로 전송
+/// - 시장가 매도: 수량(`volume`) 기준, 기준가가 있으면 예상 금액도 검증
+///
+/// 스톱/익절 주문은 업비트 미지원이므로 지정가로 대체합니다.
+fn build_order_params(
+    request: &OrderRequest,
+    reference_price: Option<Decimal>,
+) -> Result<UpbitOrderParams, ProviderError> {
+    let rule = UpbitMarketRule::for_market(&upbit_market(&request.ticker)).ok_or_else(|| {
+        ProviderError::InvalidOrder(format!("지원하지 않는 업비트 마켓: {}", request.ticker))
+    })?;
+
+    let side = match request.side {
+        Side::Buy => "bid",
+        Side::Sell => "ask",
+    };
+
+    match request.order_type {
+        OrderType::Market => match request.side {
+            Side::Buy => {
+                let price = reference_price.ok_or_else(|| {
+                    ProviderError::InvalidOrder(
+                        "시장가 매수 금액을 계산할 기준가가 없습니다".to_string(),
+                    )
+                })?;
+                let amount = rule.round_amount(request.quantity * price);
+                rule.validate_market_buy(amount)?;
+                Ok(UpbitOrderParams {
+                    side,
+                    ord_type: "price",
+                    volume: None,
+                    price: Some(amount.normalize().to_string()),
+                })
+            }
+            Side::Sell => {
+                rule.validate_market_sell(request.quantity, reference_price)?;
+                Ok(UpbitOrderParams {
+                    side,
+                    ord_type: "market",
+                    volume: Some(request.quantity.normalize().to_string()),
+                    price: None,
+                })
+            }
+        },
+        OrderType::Limit
+        | OrderType::StopLoss
+        | OrderType::StopLossLimit
+        | OrderType::TakeProfit
+        | OrderType::TakeProfitLimit => {
+            let price = request.price.or(request.stop_price).ok_or_else(|| {
+                ProviderError::InvalidOrder("지정가 주문에 가격이 없습니다".to_string())
+            })?;
+            rule.validate_limit(price, request.quantity)?;
+            Ok(UpbitOrderParams {
+                side,
+                ord_type: "limit",
+                volume: Some(request.quantity.normalize().to_string()),
+                price: Some(price.normalize().to_string()),
+            })
+        }
+        OrderType::TrailingStop => Err(ProviderError::Unsupported(
+            "Upbit은 트레일링 스톱 주문을 지원하지 않습니다".to_string(),
+        )),
+    }
+}
+
 // ==================== ExchangeProvider ====================
 
 #[async_trait]
@@ -170,11 +264,21 @@ impl ExchangeProvider for UpbitExchangeProvider {
 #[async_trait]
 impl MarketDataProvider for UpbitExchangeProvider {
     async fn get_quote(&self, symbol: &str) -> Result<QuoteData, ProviderError> {
-        self.client.get_quote(symbol).await
+        let mut quote = self.client.get_quote(&upbit_market(symbol)).await?;
+        quote.symbol = symbol.to_string();
+        Ok(quote)
     }
 
     async fn get_quotes(&self, symbols: &[String]) -> Vec<QuoteData> {
-        self.client.get_quotes(symbols).await
+        let markets: Vec<String> = symbols.iter().map(|s| upbit_market(s)).collect();
+        let mut quotes = self.client.get_quotes(&markets).await;
+        // 요청한 심볼 형식으로 복원
+        for quote in &mut quotes {
+            if let Some(i) = markets.iter().position(|m| *m == quote.symbol) {
+                quote.symbol = symbols[i].clone();
+            }
+        }
+        quotes
     }
 
     fn provider_name(&self) -> &str {
@@ -187,49 +291,22 @@ impl MarketDataProvider for UpbitExchangeProvider {
 #[async_trait]
 impl OrderExecutionProvider for UpbitExchangeProvider {
     async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ProviderError> {
-        // Side 변환: Buy → bid, Sell → ask
-        let side = match request.side {
-            Side::Buy => "bid",
-            Side::Sell => "ask",
-        };
+        let market = upbit_market(&request.ticker);
 
-        // OrderType 변환
-        let (ord_type, needs_volume, needs_price) = match request.order_type {
-            OrderType::Limit => ("limit", true, true),
-            OrderType::Market => {
-                match request.side {
-                    // 시장가 매수: price(총액) 필수, volume 불필요
-                    Side::Buy => ("price", false, true),
-                    // 시장가 매도: volume 필수, price 불필요
-                    Side::Sell => ("market", true, false),
-                }
-            }
-            OrderType::StopLoss
-            | OrderType::StopLossLimit
-            | OrderType::TakeProfit
-            | OrderType::TakeProfitLimit => {
-                // Upbit 미지원 → 지정가로 대체
-                ("limit", true, true)
-            }
-            OrderType::TrailingStop => {
-                return Err(ProviderError::Unsupported(
-                    "Upbit은 트레일링 스톱 주문을 지원하지 않습니다".to_string(),
-                ));
+        // 시장가 매수는 금액 기준이므로 기준가가 없으면 현재가로 주문 금액 산출
+        let reference_price = match (request.order_type, request.side, request.price) {
+            (OrderType::Market, Side::Buy, None) => {
+                Some(self.client.get_quote(&market).await?.current_price)
             }
+            _ => request.price,
         };
 
-        // 수량/가격 문자열 변환
-        let volume_str = if needs_volume {
-            Some(request.quantity.to_string())
-        } else {
-            None
-        };
-
-        let price_str = if needs_price {
-            request.price.map(|p| p.to_string())
-        } else {
-            None
-        };
+        let UpbitOrderParams {
+            side,
+            ord_type,
+            volume: volume_str,
+            price: price_str,
+        } = build_order_params(request, reference_price)?;
 
         info!(
             ticker = %request.ticker,
@@ -243,7 +320,7 @@ impl OrderExecutionProvider for UpbitExchangeProvider {
         let result = self
             .client
             .place_order(
-                &request.ticker,
+                &market,
                 side,
                 ord_type,
                 volume_str.as_deref(),
@@ -321,3 +398,81 @@ impl OrderExecutionProvider for UpbitExchangeProvider {
         "upbit"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_build_limit_order_params() {
+        let request = OrderRequest::limit_buy("KRW-BTC".to_string(), dec!(0.001), dec!(95000000));
+        let params = build_order_params(&request, request.price).unwrap();
+        assert_eq!(params.ord_type, "limit");
+        assert_eq!(params.side, "bid");
+        assert_eq!(params.volume.as_deref(), Some("0.001"));
+        assert_eq!(params.price.as_deref(), Some("95000000"));
+
+        // 호가 단위(1,000원) 위반은 로컬에서 거부
+        let request = OrderRequest::limit_buy("KRW-BTC".to_string(), dec!(0.001), dec!(95000100));
+        assert!(matches!(
+            build_order_params(&request, request.price),
+            Err(ProviderError::InvalidOrder(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_market_buy_uses_amount() {
+        let request = OrderRequest::market_buy("KRW-XRP".to_string(), dec!(10.5));
+        let params = build_order_params(&request, Some(dec!(850.3))).unwrap();
+        assert_eq!(params.ord_type, "price");
+        assert_eq!(params.volume, None);
+        // 10.5 × 850.3 = 8928.15 → 원 단위 내림
+        assert_eq!(params.price.as_deref(), Some("8928"));
+
+        // 최소 주문 금액 미만
+        let request = OrderRequest::market_buy("KRW-XRP".to_string(), dec!(5));
+        assert!(matches!(
+            build_order_params(&request, Some(dec!(850))),
+            Err(ProviderError::InvalidOrder(_))
+        ));
+        // 기준가 없음
+        assert!(build_order_params(&request, None).is_err());
+    }
+
+    #[test]
+    fn test_build_market_sell_uses_volume() {
+        let request = OrderRequest::market_sell("KRW-BTC".to_string(), dec!(0.00012345));
+        let params = build_order_params(&request, None).unwrap();
+        assert_eq!(params.ord_type, "market");
+        assert_eq!(params.side, "ask");
+        assert_eq!(params.volume.as_deref(), Some("0.00012345"));
+        assert_eq!(params.price, None);
+
+        let request = OrderRequest::market_sell("KRW-BTC".to_string(), dec!(0.000123456));
+        assert!(matches!(
+            build_order_params(&request, None),
+            Err(ProviderError::InvalidOrder(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_accepts_internal_symbol() {
+        assert_eq!(upbit_market("BTC/KRW"), "KRW-BTC");
+        assert_eq!(upbit_market("KRW-BTC"), "KRW-BTC");
+
+        let request = OrderRequest::limit_buy("BTC/KRW".to_string(), dec!(0.001), dec!(95000000));
+        let params = build_order_params(&request, request.price).unwrap();
+        assert_eq!(params.price.as_deref(), Some("95000000"));
+    }
+
+    #[test]
+    fn test_build_rejects_unknown_market() {
+        let request = OrderRequest::market_sell("ETH-XRP".to_string(), dec!(1));
+        assert!(matches!(
+            build_order_params(&request, None),
+            Err(ProviderError::InvalidOrder(_))
+        ));
+    }
+}
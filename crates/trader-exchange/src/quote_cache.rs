@@ -0,0 +1,340 @@
+//! 실시간 스트림과 통합된 시세 캐시.
+//!
+//! 여러 컴포넌트가 각자 `get_quote`로 REST 폴링하면 중복 호출과 rate limit 문제가
+//! 생깁니다. [`CachedMarketDataProvider`]는 기존 [`MarketDataProvider`]를 감싸서
+//! 활성 스트림의 최신 Ticker를 우선 반환하고, 스트림 값이 없거나 스테일일 때만
+//! REST로 조회합니다.
+//!
+//! # 조회 우선순위
+//!
+//! ```text
+//! get_quote(symbol)
+//! ├── 1. 스트림 값 (연결 중 + stale_after 이내)
+//! ├── 2. REST 값 (rest_ttl 이내, 중복 폴링 방지)
+//! └── 3. REST 강제 갱신 → 캐시 저장
+//! ```
+//!
+//! 스트림 이벤트는 [`CachedMarketDataProvider::apply_event`]로 전달하거나
+//! [`CachedMarketDataProvider::spawn_stream_listener`]로 스트림을 직접 연결합니다.
+//! 캐시 항목에는 수신 시각이 기록되며, [`CachedQuote::is_stale`]로 스테일 여부를
+//! 확인할 수 있습니다.
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use tokio::{sync::RwLock, task::JoinHandle};
+use tracing::{debug, warn};
+use trader_core::{
+    domain::{MarketDataProvider, ProviderError, QuoteData},
+    Ticker,
+};
+
+use crate::traits::{MarketEvent, MarketStream};
+
+/// 시세 캐시 설정.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuoteCacheConfig {
+    /// 이 시간이 지난 항목은 스테일로 간주하고 REST로 강제 갱신
+    pub stale_after: Duration,
+    /// REST 조회 결과 재사용 시간 (같은 심볼 중복 폴링 방지)
+    pub rest_ttl: Duration,
+}
+
+impl Default for QuoteCacheConfig {
+    fn default() -> Self {
+        Self {
+            stale_after: Duration::from_secs(10),
+            rest_ttl: Duration::from_secs(1),
+        }
+    }
+}
+
+/// 시세 출처.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteSource {
+    /// 실시간 스트림 Ticker
+    Stream,
+    /// REST 조회
+    Rest,
+}
+
+/// 캐시된 시세와 수신 정보.
+#[derive(Debug, Clone)]
+pub struct CachedQuote {
+    /// 시세 데이터
+    pub quote: QuoteData,
+    /// 출처
+    pub source: QuoteSource,
+    /// 수신 시각
+    pub received_at: Instant,
+    /// `stale_after`를 넘겼는지 여부 (조회 시점 기준)
+    pub is_stale: bool,
+}
+
+/// 캐시 항목.
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    quote: QuoteData,
+    source: QuoteSource,
+    received_at: Instant,
+}
+
+/// 캐시 내부 상태.
+#[derive(Debug, Default)]
+struct QuoteCacheState {
+    entries: HashMap<String, CacheEntry>,
+    stream_connected: bool,
+}
+
+/// 스트림 통합 시세 캐시를 갖춘 MarketDataProvider.
+pub struct CachedMarketDataProvider {
+    inner: Arc<dyn MarketDataProvider>,
+    config: QuoteCacheConfig,
+    state: RwLock<QuoteCacheState>,
+}
+
+impl CachedMarketDataProvider {
+    /// 기본 설정으로 생성.
+    pub fn new(inner: Arc<dyn MarketDataProvider>) -> Self {
+        Self::with_config(inner, QuoteCacheConfig::default())
+    }
+
+    /// 지정된 설정으로 생성.
+    pub fn with_config(inner: Arc<dyn MarketDataProvider>, config: QuoteCacheConfig) -> Self {
+        Self {
+            inner,
+            config,
+            state: RwLock::new(QuoteCacheState::default()),
+        }
+    }
+
+    /// 캐시 설정.
+    pub fn config(&self) -> QuoteCacheConfig {
+        self.config
+    }
+
+    /// 감싸고 있는 REST provider.
+    pub fn inner(&self) -> &Arc<dyn MarketDataProvider> {
+        &self.inner
+    }
+
+    /// 스트림 연결 여부.
+    pub async fn is_stream_connected(&self) -> bool {
+        self.state.read().await.stream_connected
+    }
+
+    /// 스트림 이벤트 반영.
+    ///
+    /// Ticker는 캐시에 저장하고, 연결 상태 이벤트는 스트림 값 사용 여부에 반영합니다.
+    /// 연결이 끊긴 동안에는 스트림 값을 반환하지 않고 REST로 조회합니다.
+    pub async fn apply_event(&self, event: &MarketEvent) {
+        match event {
+            MarketEvent::Ticker(ticker) => self.update_from_ticker(ticker).await,
+            MarketEvent::Connected | MarketEvent::ConnectionStatus { connected: true } => {
+                self.state.write().await.stream_connected = true;
+            }
+            MarketEvent::Disconnected | MarketEvent::ConnectionStatus { connected: false } => {
+                self.state.write().await.stream_connected = false;
+            }
+            _ => {}
+        }
+    }
+
+    /// 스트림 Ticker로 캐시 갱신.
+    pub async fn update_from_ticker(&self, ticker: &Ticker) {
+        let mut state = self.state.write().await;
+        let previous = state.entries.get(&ticker.ticker).map(|entry| &entry.quote);
+        let quote = quote_from_ticker(ticker, previous);
+
+        state.stream_connected = true;
+        state.entries.insert(
+            ticker.ticker.clone(),
+            CacheEntry {
+                quote,
+                source: QuoteSource::Stream,
+                received_at: Instant::now(),
+            },
+        );
+    }
+
+    /// 스트림 이벤트를 수신하여 캐시에 반영하는 태스크 시작.
+    ///
+    /// 스트림이 종료되면 연결 끊김으로 처리합니다.
+    pub fn spawn_stream_listener<S>(self: Arc<Self>, mut stream: S) -> JoinHandle<()>
+    where
+        S: MarketStream + 'static,
+    {
+        tokio::spawn(async move {
+            while let Some(event) = stream.next_event().await {
+                self.apply_event(&event).await;
+            }
+            warn!("시세 캐시 스트림 종료, REST 조회로 전환");
+            self.state.write().await.stream_connected = false;
+        })
+    }
+
+    /// 캐시된 시세 조회 (REST 호출 없음).
+    pub async fn cached_quote(&self, symbol: &str) -> Option<CachedQuote> {
+        let state = self.state.read().await;
+        state.entries.get(symbol).map(|entry| CachedQuote {
+            quote: entry.quote.clone(),
+            source: entry.source,
+            received_at: entry.received_at,
+            is_stale: entry.received_at.elapsed() >= self.config.stale_after,
+        })
+    }
+
+    /// 심볼 캐시 무효화.
+    pub async fn invalidate(&self, symbol: &str) {
+        self.state.write().await.entries.remove(symbol);
+    }
+
+    /// 전체 캐시 무효화.
+    pub async fn clear(&self) {
+        self.state.write().await.entries.clear();
+    }
+
+    /// REST 호출 없이 사용할 수 있는 캐시 값.
+    async fn fresh_quote(&self, symbol: &str) -> Option<QuoteData> {
+        let state = self.state.read().await;
+        let entry = state.entries.get(symbol)?;
+        let age = entry.received_at.elapsed();
+
+        let usable = match entry.source {
+            QuoteSource::Stream => state.stream_connected && age < self.config.stale_after,
+            QuoteSource::Rest => age < self.config.rest_ttl.min(self.config.stale_after),
+        };
+        usable.then(|| entry.quote.clone())
+    }
+
+    /// REST 조회 결과 저장.
+    ///
+    /// REST 응답을 기다리는 동안 더 최신 스트림 값이 들어왔으면 덮어쓰지 않습니다.
+    async fn store_rest(&self, quote: &QuoteData, requested_at: Instant) {
+        let mut state = self.state.write().await;
+        let stream_connected = state.stream_connected;
+        if let Some(entry) = state.entries.get(&quote.symbol) {
+            if entry.source == QuoteSource::Stream
+                && stream_connected
+                && entry.received_at > requested_at
+            {
+                return;
+            }
+        }
+        state.entries.insert(
+            quote.symbol.clone(),
+            CacheEntry {
+                quote: quote.clone(),
+                source: QuoteSource::Rest,
+                received_at: Instant::now(),
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for CachedMarketDataProvider {
+    async fn get_quote(&self, symbol: &str) -> Result<QuoteData, ProviderError> {
+        if let Some(quote) = self.fresh_quote(symbol).await {
+            return Ok(quote);
+        }
+
+        debug!(symbol = symbol, "시세 캐시 미스, REST 조회");
+        let requested_at = Instant::now();
+        let quote = self.inner.get_quote(symbol).await?;
+        self.store_rest(&quote, requested_at).await;
+        Ok(quote)
+    }
+
+    /// 캐시에 없는 심볼만 모아 내부 provider의 일괄 조회를 한 번 호출합니다.
+    async fn get_quotes(&self, symbols: &[String]) -> Vec<QuoteData> {
+        let mut hits = HashMap::new();
+        let mut misses = Vec::new();
+        for symbol in symbols {
+            match self.fresh_quote(symbol).await {
+                Some(quote) => {
+                    hits.insert(symbol.clone(), quote);
+                }
+                None => misses.push(symbol.clone()),
+            }
+        }
+
+        if !misses.is_empty() {
+            let requested_at = Instant::now();
+            for quote in self.inner.get_quotes(&misses).await {
+                self.store_rest(&quote, requested_at).await;
+                hits.insert(quote.symbol.clone(), quote);
+            }
+        }
+
+        symbols
+            .iter()
+            .filter_map(|symbol| hits.remove(symbol))
+            .collect()
+    }
+
+    fn provider_name(&self) -> &str {
+        self.inner.provider_name()
+    }
+}
+
+/// 스트림 Ticker를 QuoteData로 변환.
+///
+/// Ticker에 없는 시가/거래대금은 이전 캐시 값을 유지합니다.
+fn quote_from_ticker(ticker: &Ticker, previous: Option<&QuoteData>) -> QuoteData {
+    let prev_close = ticker.last - ticker.change_24h;
+    QuoteData {
+        symbol: ticker.ticker.clone(),
+        current_price: ticker.last,
+        price_change: ticker.change_24h,
+        change_percent: ticker.change_24h_percent,
+        high: ticker.high_24h,
+        low: ticker.low_24h,
+        open: previous.map(|q| q.open).unwrap_or(prev_close),
+        prev_close,
+        volume: ticker.volume_24h,
+        trading_value: previous
+            .map(|q| q.trading_value)
+            .unwrap_or(ticker.volume_24h * ticker.last),
+        timestamp: ticker.timestamp,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_quote_from_ticker() {
+        let ticker = Ticker {
+            ticker: "KRW-BTC".to_string(),
+            bid: dec!(99),
+            ask: dec!(101),
+            last: dec!(100),
+            volume_24h: dec!(10),
+            high_24h: dec!(110),
+            low_24h: dec!(90),
+            change_24h: dec!(5),
+            change_24h_percent: dec!(5.26),
+            timestamp: Utc::now(),
+        };
+
+        let quote = quote_from_ticker(&ticker, None);
+        assert_eq!(quote.current_price, dec!(100));
+        assert_eq!(quote.prev_close, dec!(95));
+        assert_eq!(quote.open, dec!(95));
+        assert_eq!(quote.trading_value, dec!(1000));
+
+        let mut previous = quote.clone();
+        previous.open = dec!(93);
+        let quote = quote_from_ticker(&ticker, Some(&previous));
+        assert_eq!(quote.open, dec!(93));
+    }
+}
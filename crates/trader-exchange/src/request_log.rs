@@ -0,0 +1,730 @@
+//! 거래소 요청/응답 추적 로깅.
+//!
+//! 실거래 주문 디버깅을 위해 거래소와 주고받은 원시 요청/응답을 구조화해 기록합니다.
+//!
+//! - **마스킹**: API 키, 시크릿, 서명, 토큰 등 민감 정보는 기록 전에 항상 마스킹됩니다.
+//! - **Correlation ID**: [`with_correlation_id`]로 감싼 범위의 모든 요청에 같은 ID가 부여되어
+//!   신호 → 주문 → 체결 흐름을 추적할 수 있습니다.
+//! - **상세도**: [`RequestLogLevel`]로 조절합니다. 프로덕션 기본값은 오류 시에만 상세 로깅합니다.
+//! - **싱크**: tracing 로그 외에 파일([`FileRequestLogSink`]) 또는 DB([`PgRequestLogSink`])로
+//!   선택적으로 전송할 수 있습니다.
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! use trader_exchange::request_log::{with_correlation_id, RequestLogConfig, RequestLogger};
+//!
+//! let logger = Arc::new(RequestLogger::new(RequestLogConfig::from_env()));
+//! // Binance, KIS, Bybit, Upbit 클라이언트 모두 같은 빌더로 설정
+//! let client = BinanceClient::new(config)?.with_request_logger(logger);
+//!
+//! // 신호 ID를 correlation ID로 사용
+//! with_correlation_id(signal.id.to_string(), async {
+//!     client.place_order(&request).await
+//! }).await?;
+//! ```
+
+use std::{path::PathBuf, sync::Arc, time::Instant};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::PgPool;
+use tokio::{io::AsyncWriteExt, sync::Mutex};
+use tracing::{debug, info, trace, warn};
+
+pub use trader_core::logging::{current_correlation_id, with_correlation_id};
+
+/// 마스킹된 값 표기.
+pub const MASKED: &str = "****";
+
+/// 민감 정보로 간주하는 키 패턴 (소문자, `-`/`_` 제거 후 부분 일치).
+const SENSITIVE_KEY_PATTERNS: &[&str] = &[
+    "apikey",
+    "appkey",
+    "accesskey",
+    "secret",
+    "signature",
+    "authorization",
+    "token",
+    "password",
+    "passphrase",
+];
+
+/// 요청/응답 로깅 상세도.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestLogLevel {
+    /// 기록하지 않음
+    Off,
+    /// 오류 응답만 상세 기록 (프로덕션 기본값)
+    #[default]
+    ErrorsOnly,
+    /// 모든 요청의 요약(메서드, 엔드포인트, 상태, 지연) + 오류 상세
+    Summary,
+    /// 모든 요청/응답 본문 기록 (마스킹 적용)
+    Full,
+}
+
+impl RequestLogLevel {
+    /// 문자열에서 파싱 (대소문자 무시, 알 수 없으면 `None`).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "off" | "none" => Some(Self::Off),
+            "errors" | "errors_only" | "error" => Some(Self::ErrorsOnly),
+            "summary" => Some(Self::Summary),
+            "full" | "debug" => Some(Self::Full),
+            _ => None,
+        }
+    }
+}
+
+/// 요청 로깅 설정.
+#[derive(Debug, Clone)]
+pub struct RequestLogConfig {
+    /// 로깅 상세도
+    pub level: RequestLogLevel,
+    /// 기록할 본문 최대 길이 (초과분은 잘라냄)
+    pub max_body_len: usize,
+}
+
+impl Default for RequestLogConfig {
+    fn default() -> Self {
+        Self {
+            level: RequestLogLevel::ErrorsOnly,
+            max_body_len: 4096,
+        }
+    }
+}
+
+impl RequestLogConfig {
+    /// 환경 변수에서 설정 로드.
+    ///
+    /// - `EXCHANGE_REQUEST_LOG`: off | errors_only | summary | full (기본: errors_only)
+    /// - `EXCHANGE_REQUEST_LOG_MAX_BODY`: 본문 최대 길이 (기본: 4096)
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            level: std::env::var("EXCHANGE_REQUEST_LOG")
+                .ok()
+                .and_then(|v| RequestLogLevel::parse(&v))
+                .unwrap_or(default.level),
+            max_body_len: std::env::var("EXCHANGE_REQUEST_LOG_MAX_BODY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.max_body_len),
+        }
+    }
+}
+
+/// 거래소 요청/응답 기록 (마스킹 완료 상태).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeRequestRecord {
+    /// Correlation ID
+    pub correlation_id: String,
+    /// 거래소 이름
+    pub exchange: String,
+    /// HTTP 메서드
+    pub method: String,
+    /// 엔드포인트 경로 (쿼리 제외)
+    pub endpoint: String,
+    /// 요청 파라미터/본문 (마스킹됨)
+    pub request: Option<String>,
+    /// HTTP 상태 코드 (네트워크 오류 시 없음)
+    pub status: Option<u16>,
+    /// 응답 본문 (마스킹됨)
+    pub response: Option<String>,
+    /// 오류 메시지
+    pub error: Option<String>,
+    /// 지연 시간 (밀리초)
+    pub latency_ms: u64,
+    /// 요청 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ExchangeRequestRecord {
+    /// 오류 응답 여부.
+    pub fn is_error(&self) -> bool {
+        self.error.is_some() || !matches!(self.status, Some(s) if s < 400)
+    }
+}
+
+/// 요청 로그 싱크 (파일, DB 등).
+#[async_trait]
+pub trait RequestLogSink: Send + Sync {
+    /// 기록을 저장합니다.
+    async fn write(&self, record: &ExchangeRequestRecord) -> Result<(), String>;
+}
+
+/// JSON Lines 파일 싱크.
+pub struct FileRequestLogSink {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileRequestLogSink {
+    /// 새 파일 싱크 생성 (파일은 첫 기록 시 append 모드로 열림).
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+}
+
+#[async_trait]
+impl RequestLogSink for FileRequestLogSink {
+    async fn write(&self, record: &ExchangeRequestRecord) -> Result<(), String> {
+        let mut line = serde_json::to_string(record).map_err(|e| e.to_string())?;
+        line.push('\n');
+
+        let _guard = self.lock.lock().await;
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| e.to_string())?;
+        file.write_all(line.as_bytes())
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// PostgreSQL 싱크 (`exchange_request_log` 테이블).
+pub struct PgRequestLogSink {
+    pool: PgPool,
+}
+
+impl PgRequestLogSink {
+    /// 새 DB 싱크 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RequestLogSink for PgRequestLogSink {
+    async fn write(&self, record: &ExchangeRequestRecord) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT INTO exchange_request_log
+                (correlation_id, exchange, method, endpoint, request, status,
+                 response, error, latency_ms, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(&record.correlation_id)
+        .bind(&record.exchange)
+        .bind(&record.method)
+        .bind(&record.endpoint)
+        .bind(&record.request)
+        .bind(record.status.map(i32::from))
+        .bind(&record.response)
+        .bind(&record.error)
+        .bind(record.latency_ms as i64)
+        .bind(record.timestamp)
+        .execute(&self.pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    }
+}
+
+/// 진행 중인 요청 정보 ([`RequestLogger::start`]로 생성).
+#[derive(Debug)]
+pub struct PendingRequest {
+    correlation_id: String,
+    method: String,
+    endpoint: String,
+    request: Option<String>,
+    started: Instant,
+    timestamp: DateTime<Utc>,
+}
+
+impl PendingRequest {
+    /// 이 요청의 correlation ID.
+    pub fn correlation_id(&self) -> &str {
+        &self.correlation_id
+    }
+}
+
+/// 거래소 요청/응답 로거.
+pub struct RequestLogger {
+    config: RequestLogConfig,
+    sinks: Vec<Arc<dyn RequestLogSink>>,
+}
+
+impl RequestLogger {
+    /// 새 로거 생성.
+    pub fn new(config: RequestLogConfig) -> Self {
+        Self {
+            config,
+            sinks: Vec::new(),
+        }
+    }
+
+    /// 싱크를 추가합니다.
+    pub fn with_sink(mut self, sink: Arc<dyn RequestLogSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// 로깅 상세도.
+    pub fn level(&self) -> RequestLogLevel {
+        self.config.level
+    }
+
+    /// 요청 시작을 기록합니다.
+    ///
+    /// `request`는 쿼리 문자열 또는 JSON 본문이며 여기서 마스킹됩니다.
+    pub fn start(&self, method: &str, endpoint: &str, request: Option<&str>) -> PendingRequest {
+        let correlation_id = current_correlation_id();
+        let request = request
+            .filter(|r| !r.is_empty())
+            .map(|r| self.truncate(mask_payload(r)));
+
+        if self.config.level == RequestLogLevel::Full {
+            trace!(
+                correlation_id = %correlation_id,
+                method = method,
+                endpoint = endpoint,
+                request = request.as_deref().unwrap_or(""),
+                "거래소 요청"
+            );
+        }
+
+        PendingRequest {
+            correlation_id,
+            method: method.to_string(),
+            endpoint: strip_query(endpoint).to_string(),
+            request,
+            started: Instant::now(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// 응답(또는 네트워크 오류)을 기록합니다.
+    pub async fn finish(
+        &self,
+        exchange: &str,
+        pending: PendingRequest,
+        status: Option<u16>,
+        response: Option<&str>,
+        error: Option<&str>,
+    ) {
+        if self.config.level == RequestLogLevel::Off {
+            return;
+        }
+
+        let record = ExchangeRequestRecord {
+            correlation_id: pending.correlation_id,
+            exchange: exchange.to_string(),
+            method: pending.method,
+            endpoint: pending.endpoint,
+            request: pending.request,
+            status,
+            response: response.map(|r| self.truncate(mask_payload(r))),
+            error: error.map(|e| self.truncate(mask_payload(e))),
+            latency_ms: pending.started.elapsed().as_millis() as u64,
+            timestamp: pending.timestamp,
+        };
+
+        let is_error = record.is_error();
+        if !is_error && self.config.level == RequestLogLevel::ErrorsOnly {
+            return;
+        }
+
+        self.emit(&record, is_error);
+
+        for sink in &self.sinks {
+            if let Err(e) = sink.write(&record).await {
+                warn!(correlation_id = %record.correlation_id, error = %e, "요청 로그 싱크 기록 실패");
+            }
+        }
+    }
+
+    fn emit(&self, record: &ExchangeRequestRecord, is_error: bool) {
+        if is_error {
+            warn!(
+                correlation_id = %record.correlation_id,
+                exchange = %record.exchange,
+                method = %record.method,
+                endpoint = %record.endpoint,
+                status = ?record.status,
+                latency_ms = record.latency_ms,
+                request = record.request.as_deref().unwrap_or(""),
+                response = record.response.as_deref().unwrap_or(""),
+                error = record.error.as_deref().unwrap_or(""),
+                "거래소 요청 실패"
+            );
+        } else if self.config.level == RequestLogLevel::Full {
+            debug!(
+                correlation_id = %record.correlation_id,
+                exchange = %record.exchange,
+                method = %record.method,
+                endpoint = %record.endpoint,
+                status = ?record.status,
+                latency_ms = record.latency_ms,
+                request = record.request.as_deref().unwrap_or(""),
+                response = record.response.as_deref().unwrap_or(""),
+                "거래소 응답"
+            );
+        } else {
+            info!(
+                correlation_id = %record.correlation_id,
+                exchange = %record.exchange,
+                method = %record.method,
+                endpoint = %record.endpoint,
+                status = ?record.status,
+                latency_ms = record.latency_ms,
+                "거래소 응답"
+            );
+        }
+    }
+
+    fn truncate(&self, mut s: String) -> String {
+        if s.len() > self.config.max_body_len {
+            let mut cut = self.config.max_body_len;
+            while !s.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            s.truncate(cut);
+            s.push_str("...(truncated)");
+        }
+        s
+    }
+}
+
+/// 키가 민감 정보인지 판별합니다.
+pub fn is_sensitive_key(key: &str) -> bool {
+    let normalized: String = key
+        .chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect();
+    SENSITIVE_KEY_PATTERNS
+        .iter()
+        .any(|pattern| normalized.contains(pattern))
+}
+
+/// JSON 값의 민감 필드를 재귀적으로 마스킹합니다.
+pub fn mask_json(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                if is_sensitive_key(key) && !v.is_null() {
+                    *v = Value::String(MASKED.to_string());
+                } else {
+                    mask_json(v);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(mask_json),
+        _ => {}
+    }
+}
+
+/// `key=value&...` 형식 문자열의 민감 파라미터를 마스킹합니다.
+pub fn mask_query(query: &str) -> String {
+    query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if is_sensitive_key(key) => format!("{}={}", key, MASKED),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// 헤더 목록의 민감 값을 마스킹합니다.
+pub fn mask_headers(headers: &[(&str, &str)]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(k, v)| {
+            let value = if is_sensitive_key(k) {
+                MASKED.to_string()
+            } else {
+                v.to_string()
+            };
+            (k.to_string(), value)
+        })
+        .collect()
+}
+
+/// 요청을 전송하고 응답 상태와 본문을 반환합니다.
+///
+/// 로거가 설정되어 있으면 메서드, 경로, 요청(쿼리 문자열 또는 본문), 응답을
+/// 마스킹하여 기록합니다. 모든 커넥터의 공통 요청 경로에서 사용합니다.
+pub(crate) async fn send_logged(
+    logger: Option<&RequestLogger>,
+    exchange: &str,
+    builder: reqwest::RequestBuilder,
+) -> Result<(reqwest::StatusCode, String), reqwest::Error> {
+    let Some(logger) = logger else {
+        let response = builder.send().await?;
+        let status = response.status();
+        return Ok((status, response.text().await?));
+    };
+
+    let (client, request) = builder.build_split();
+    let request = request?;
+    let payload = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+        .or_else(|| request.url().query().map(str::to_string));
+    let pending = logger.start(
+        request.method().as_str(),
+        request.url().path(),
+        payload.as_deref(),
+    );
+
+    let result = async {
+        let response = client.execute(request).await?;
+        let status = response.status();
+        Ok::<_, reqwest::Error>((status, response.text().await?))
+    }
+    .await;
+
+    match &result {
+        Ok((status, body)) => {
+            logger
+                .finish(exchange, pending, Some(status.as_u16()), Some(body), None)
+                .await
+        }
+        Err(e) => {
+            logger
+                .finish(exchange, pending, None, None, Some(&e.to_string()))
+                .await
+        }
+    }
+    result
+}
+
+/// 요청/응답 본문을 마스킹합니다 (JSON이면 필드 단위, 아니면 쿼리 문자열로 간주).
+pub fn mask_payload(payload: &str) -> String {
+    match serde_json::from_str::<Value>(payload) {
+        Ok(mut value) if value.is_object() || value.is_array() => {
+            mask_json(&mut value);
+            value.to_string()
+        }
+        _ => {
+            let (path, query) = match payload.split_once('?') {
+                Some((path, query)) => (Some(path), query),
+                None => (None, payload),
+            };
+            if !query.contains('=') {
+                return payload.to_string();
+            }
+            match path {
+                Some(path) => format!("{}?{}", path, mask_query(query)),
+                None => mask_query(query),
+            }
+        }
+    }
+}
+
+fn strip_query(endpoint: &str) -> &str {
+    endpoint.split_once('?').map_or(endpoint, |(path, _)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const SECRET: &str = "sk_live_abcdef123456";
+
+    #[test]
+    fn test_sensitive_key_detection() {
+        for key in [
+            "apiKey",
+            "api_key",
+            "X-MBX-APIKEY",
+            "appkey",
+            "appsecret",
+            "secret_key",
+            "signature",
+            "Authorization",
+            "access_token",
+            "access_key",
+            "password",
+        ] {
+            assert!(is_sensitive_key(key), "{} 는 민감 키여야 함", key);
+        }
+        for key in ["symbol", "side", "quantity", "price", "orderId"] {
+            assert!(!is_sensitive_key(key), "{} 는 민감 키가 아님", key);
+        }
+    }
+
+    #[test]
+    fn test_mask_query_removes_signature() {
+        let query = format!("symbol=BTCUSDT&timestamp=1&signature={}", SECRET);
+        let masked = mask_query(&query);
+        assert!(!masked.contains(SECRET));
+        assert!(masked.contains("symbol=BTCUSDT"));
+        assert!(masked.contains("signature=****"));
+    }
+
+    #[test]
+    fn test_mask_json_nested() {
+        let mut value = json!({
+            "appkey": SECRET,
+            "body": { "access_token": SECRET, "ord_qty": "10" },
+            "items": [{ "secretKey": SECRET }]
+        });
+        mask_json(&mut value);
+        let text = value.to_string();
+        assert!(!text.contains(SECRET));
+        assert!(text.contains("\"ord_qty\":\"10\""));
+    }
+
+    #[test]
+    fn test_mask_headers() {
+        let masked = mask_headers(&[("X-MBX-APIKEY", SECRET), ("Content-Type", "json")]);
+        assert_eq!(masked[0].1, MASKED);
+        assert_eq!(masked[1].1, "json");
+    }
+
+    #[test]
+    fn test_mask_payload_url_with_query() {
+        let masked = mask_payload(&format!("/api/v3/order?symbol=ETH&signature={}", SECRET));
+        assert!(!masked.contains(SECRET));
+        assert!(masked.starts_with("/api/v3/order?"));
+    }
+
+    #[test]
+    fn test_level_parse() {
+        assert_eq!(RequestLogLevel::parse("FULL"), Some(RequestLogLevel::Full));
+        assert_eq!(
+            RequestLogLevel::parse("errors_only"),
+            Some(RequestLogLevel::ErrorsOnly)
+        );
+        assert_eq!(RequestLogLevel::parse("verbose"), None);
+    }
+
+    /// 테스트용 메모리 싱크.
+    #[derive(Default)]
+    struct MemorySink {
+        records: Mutex<Vec<ExchangeRequestRecord>>,
+    }
+
+    #[async_trait]
+    impl RequestLogSink for MemorySink {
+        async fn write(&self, record: &ExchangeRequestRecord) -> Result<(), String> {
+            self.records.lock().await.push(record.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_logger_masks_and_propagates_correlation_id() {
+        let sink = Arc::new(MemorySink::default());
+        let logger = RequestLogger::new(RequestLogConfig {
+            level: RequestLogLevel::Full,
+            max_body_len: 4096,
+        })
+        .with_sink(sink.clone());
+
+        with_correlation_id("signal-1", async {
+            let pending = logger.start(
+                "POST",
+                "/api/v3/order",
+                Some(&format!("symbol=BTCUSDT&signature={}", SECRET)),
+            );
+            logger
+                .finish(
+                    "binance",
+                    pending,
+                    Some(200),
+                    Some(&format!("{{\"orderId\":1,\"token\":\"{}\"}}", SECRET)),
+                    None,
+                )
+                .await;
+        })
+        .await;
+
+        let records = sink.records.lock().await;
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.correlation_id, "signal-1");
+        let serialized = serde_json::to_string(record).unwrap();
+        assert!(!serialized.contains(SECRET));
+    }
+
+    #[tokio::test]
+    async fn test_errors_only_skips_success() {
+        let sink = Arc::new(MemorySink::default());
+        let logger = RequestLogger::new(RequestLogConfig::default()).with_sink(sink.clone());
+
+        let ok = logger.start("GET", "/ticker", None);
+        logger
+            .finish("upbit", ok, Some(200), Some("{}"), None)
+            .await;
+        let failed = logger.start("GET", "/ticker", None);
+        logger
+            .finish(
+                "upbit",
+                failed,
+                Some(400),
+                Some("{\"error\":\"bad\"}"),
+                None,
+            )
+            .await;
+
+        let records = sink.records.lock().await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].status, Some(400));
+    }
+
+    #[tokio::test]
+    async fn test_send_logged_records_masked_request() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/v3/order")
+            .match_query(mockito::Matcher::Any)
+            .with_status(400)
+            .with_body("{\"code\":-2013,\"msg\":\"Order does not exist.\"}")
+            .create_async()
+            .await;
+
+        let sink = Arc::new(MemorySink::default());
+        let logger = RequestLogger::new(RequestLogConfig::default()).with_sink(sink.clone());
+        let url = format!(
+            "{}/api/v3/order?symbol=BTCUSDT&signature={}",
+            server.url(),
+            SECRET
+        );
+
+        let (status, body) = send_logged(
+            Some(&logger),
+            "binance",
+            reqwest::Client::new()
+                .get(&url)
+                .header("X-MBX-APIKEY", SECRET),
+        )
+        .await
+        .unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(status.as_u16(), 400);
+        assert!(body.contains("-2013"));
+        let records = sink.records.lock().await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].method, "GET");
+        assert_eq!(records[0].endpoint, "/api/v3/order");
+        let serialized = serde_json::to_string(&records[0]).unwrap();
+        assert!(!serialized.contains(SECRET));
+        assert!(serialized.contains("symbol=BTCUSDT"));
+    }
+
+    #[test]
+    fn test_correlation_id_generated_outside_scope() {
+        let a = current_correlation_id();
+        let b = current_correlation_id();
+        assert_ne!(a, b);
+    }
+}
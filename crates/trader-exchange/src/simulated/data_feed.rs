@@ -158,6 +158,7 @@ impl DataFeed {
                 } else {
                     None
                 },
+                synthetic: false,
             };
 
             klines.push(kline);
@@ -390,6 +391,7 @@ pub fn generate_sample_klines(
             volume,
             quote_volume: Some(volume * close),
             num_trades: Some(rng.gen_range(10..500)),
+            synthetic: false,
         });
 
         current_price = close;
@@ -842,7 +842,7 @@ impl SimulatedExchange {
 
 #[cfg(test)]
 mod tests {
-    use trader_core::TimeInForce;
+    use trader_core::{OrderSession, TimeInForce};
 
     use super::*;
     use crate::simulated::data_feed::generate_sample_klines;
@@ -932,6 +932,8 @@ mod tests {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
         let order_id = exchange.place_order(&request).await.unwrap();
@@ -978,6 +980,8 @@ mod tests {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
         let order_id = exchange.place_order(&request).await.unwrap();
@@ -1022,6 +1026,8 @@ mod tests {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
         let order_id = exchange.place_order(&request).await.unwrap();
@@ -478,7 +478,7 @@ impl MatchingEngine {
 
 #[cfg(test)]
 mod tests {
-    use trader_core::{TimeInForce, Timeframe};
+    use trader_core::{OrderSession, TimeInForce, Timeframe};
 
     use super::*;
 
@@ -500,6 +500,7 @@ mod tests {
             volume: dec!(100),
             quote_volume: Some(Decimal::from_f64_retain(close * 100.0).unwrap()),
             num_trades: Some(50),
+            synthetic: false,
         }
     }
 
@@ -519,6 +520,8 @@ mod tests {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
         let timestamp = Utc::now();
@@ -548,6 +551,8 @@ mod tests {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
         let result = engine.submit_order(&request, current_price, Utc::now());
@@ -573,6 +578,8 @@ mod tests {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
         let result = engine.submit_order(&request, current_price, Utc::now());
@@ -598,6 +605,8 @@ mod tests {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
         engine.submit_order(&request, current_price, Utc::now());
@@ -628,6 +637,8 @@ mod tests {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
         engine.submit_order(&request, current_price, Utc::now());
@@ -657,6 +668,8 @@ mod tests {
             time_in_force: TimeInForce::GTC,
             client_order_id: None,
             strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
         let result = engine.submit_order(&request, dec!(50000), Utc::now());
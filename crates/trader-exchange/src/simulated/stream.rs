@@ -50,7 +50,10 @@ impl SimulatedMarketStream {
             }
             MarketEvent::OrderBook(ob) => self.order_book_subscriptions.contains(&ob.ticker),
             MarketEvent::Trade(trade) => self.trade_subscriptions.contains(&trade.ticker),
-            MarketEvent::Connected | MarketEvent::Disconnected | MarketEvent::Error(_) => true,
+            MarketEvent::Connected
+            | MarketEvent::Disconnected
+            | MarketEvent::ConnectionStatus { .. }
+            | MarketEvent::Error(_) => true,
         }
     }
 }
@@ -3,6 +3,13 @@
 //! 다양한 거래소의 WebSocket 연결을 `MarketStream` trait으로 래핑하여
 //! 통합된 인터페이스를 제공합니다.
 //!
+//! # 자동 재연결
+//!
+//! KIS 국내/해외, DB증권, 빗썸 스트림은 연결이 끊기면 [`ReconnectPolicy`]에 따라
+//! 지수 백오프로 재연결하고, 보유 중인 구독을 재전송합니다.
+//! 재연결 과정은 `MarketEvent::ConnectionStatus`로 전달되며,
+//! `connection_health()`로 재연결 시도 횟수와 마지막 연결 시각을 조회할 수 있습니다.
+//!
 //! # 동적 구독 지원
 //!
 //! `start()` 호출 전후 모두 `subscribe_*` / `unsubscribe` 가능합니다.
@@ -36,13 +43,21 @@
 //! }
 //! ```
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
 
 use async_trait::async_trait;
 use chrono::Utc;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
-use tokio::sync::{mpsc, RwLock};
+use tokio::{
+    sync::{mpsc, RwLock},
+    task::JoinHandle,
+};
 use tracing::{debug, error, info, warn};
 use trader_core::{OrderBook, OrderBookLevel, Side, Symbol, Ticker, Timeframe, TradeTick};
 
@@ -58,10 +73,136 @@ use crate::{
         ls_sec::websocket::{LsSecWebSocket, LsWsCommand, LsWsMessage},
         upbit::websocket::{UpbitWebSocket, UpbitWsCommand, UpbitWsMessage},
     },
-    traits::{ExchangeResult, MarketEvent, MarketStream},
+    traits::{ConnectionHealth, ExchangeResult, MarketEvent, MarketStream},
     ExchangeError,
 };
 
+// ============================================================================
+// 공통 재연결 로직
+// ============================================================================
+
+/// WebSocket 재연결 정책 (지수 백오프).
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// 첫 재연결 대기 시간
+    pub initial_delay: Duration,
+    /// 최대 대기 시간
+    pub max_delay: Duration,
+    /// 연속 재연결 최대 시도 횟수 (None이면 무제한)
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// `attempt`번째(1부터) 재연결 전 대기 시간.
+    ///
+    /// 시도마다 두 배씩 늘어나며 `max_delay`를 넘지 않습니다.
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+
+    /// 최대 시도 횟수 초과 여부.
+    fn exhausted(&self, attempt: u32) -> bool {
+        self.max_attempts.is_some_and(|max| attempt > max)
+    }
+}
+
+/// 재연결 태스크와 MarketStream이 공유하는 연결 상태.
+#[derive(Debug, Clone, Default)]
+struct ConnectionMonitor {
+    health: Arc<Mutex<ConnectionHealth>>,
+}
+
+impl ConnectionMonitor {
+    fn lock(&self) -> MutexGuard<'_, ConnectionHealth> {
+        self.health.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn snapshot(&self) -> ConnectionHealth {
+        self.lock().clone()
+    }
+
+    /// 거래소 연결 상태 메시지를 반영하고 전달할 이벤트를 반환합니다.
+    fn on_status(&self, connected: bool) -> MarketEvent {
+        let mut health = self.lock();
+        if connected {
+            health.connected = true;
+            health.reconnect_attempts = 0;
+            health.last_connected_at = Some(Utc::now());
+        } else if health.connected {
+            health.connected = false;
+            health.last_disconnected_at = Some(Utc::now());
+        }
+        MarketEvent::ConnectionStatus { connected }
+    }
+
+    /// 재연결 시도 기록.
+    fn record_attempt(&self, attempt: u32) {
+        let mut health = self.lock();
+        health.reconnect_attempts = attempt;
+        health.total_reconnect_attempts += 1;
+    }
+}
+
+/// 연결 세션이 끝날 때마다 지수 백오프로 재연결하는 태스크를 시작합니다.
+///
+/// `session`은 구독을 복원한 뒤 한 번의 연결 세션을 실행하며,
+/// 세션이 수립되었다가 끊긴 경우 `Ok`, 연결 수립에 실패한 경우 `Err`를 반환해야 합니다.
+fn spawn_reconnect_loop<F, Fut>(
+    name: &'static str,
+    policy: ReconnectPolicy,
+    monitor: ConnectionMonitor,
+    mut session: F,
+) -> JoinHandle<()>
+where
+    F: FnMut() -> Fut + Send + 'static,
+    Fut: Future<Output = ExchangeResult<()>> + Send,
+{
+    tokio::spawn(async move {
+        let mut attempt: u32 = 0;
+
+        loop {
+            match session().await {
+                Ok(()) => {
+                    warn!("{} WebSocket 세션 종료, 재연결 시도", name);
+                    attempt = 0;
+                }
+                Err(e) => error!("{} WebSocket 연결 실패: {}", name, e),
+            }
+
+            attempt += 1;
+            if policy.exhausted(attempt) {
+                error!(
+                    "{} WebSocket 재연결 포기 (최대 {}회 초과)",
+                    name,
+                    attempt - 1
+                );
+                break;
+            }
+
+            monitor.record_attempt(attempt);
+            let delay = policy.delay_for(attempt);
+            info!(
+                "{} WebSocket 재연결 대기: {:?} (시도 {})",
+                name, delay, attempt
+            );
+            tokio::time::sleep(delay).await;
+        }
+    })
+}
+
 // ============================================================================
 // KIS 국내 MarketStream
 // ============================================================================
@@ -75,13 +216,18 @@ use crate::{
 /// `start()` 전후 모두 구독/해제 가능합니다.
 /// - 연결 전: WebSocket 큐에 추가 (연결 시 일괄 전송)
 /// - 연결 후: command channel을 통해 실시간 전송
+///
+/// 연결이 끊기면 `subscribed_symbols` 기준으로 구독을 복원하며 재연결합니다.
 pub struct KisKrMarketStream {
     ws: Arc<RwLock<KisKrWebSocket>>,
     rx: Option<mpsc::Receiver<KrRealtimeMessage>>,
     /// 동적 구독을 위한 command sender (연결 후 사용)
     cmd_tx: mpsc::Sender<WsCommand>,
-    subscribed_symbols: HashMap<String, SubscriptionType>,
+    subscribed_symbols: Arc<RwLock<HashMap<String, SubscriptionType>>>,
     started: bool,
+    reconnect_policy: ReconnectPolicy,
+    connection: ConnectionMonitor,
+    reconnect_task: Option<JoinHandle<()>>,
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -102,11 +248,20 @@ impl KisKrMarketStream {
             ws: Arc::new(RwLock::new(ws)),
             rx,
             cmd_tx,
-            subscribed_symbols: HashMap::new(),
+            subscribed_symbols: Arc::new(RwLock::new(HashMap::new())),
             started: false,
+            reconnect_policy: ReconnectPolicy::default(),
+            connection: ConnectionMonitor::default(),
+            reconnect_task: None,
         }
     }
 
+    /// 재연결 정책 설정.
+    pub fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
     /// 종목코드에서 Symbol 생성 (국내).
     #[allow(dead_code)]
     fn code_to_symbol(code: &str) -> Symbol {
@@ -186,6 +341,14 @@ impl KisKrMarketStream {
     }
 }
 
+impl Drop for KisKrMarketStream {
+    fn drop(&mut self) {
+        if let Some(task) = self.reconnect_task.take() {
+            task.abort();
+        }
+    }
+}
+
 #[async_trait]
 impl MarketStream for KisKrMarketStream {
     async fn start(&mut self) -> ExchangeResult<()> {
@@ -194,14 +357,32 @@ impl MarketStream for KisKrMarketStream {
         }
 
         let ws = self.ws.clone();
+        let subscribed = self.subscribed_symbols.clone();
         self.started = true;
 
-        tokio::spawn(async move {
-            let mut ws_guard = ws.write().await;
-            if let Err(e) = ws_guard.connect().await {
-                error!("KIS KR WebSocket 연결 실패: {}", e);
-            }
-        });
+        self.reconnect_task = Some(spawn_reconnect_loop(
+            "KIS KR",
+            self.reconnect_policy.clone(),
+            self.connection.clone(),
+            move || {
+                let ws = ws.clone();
+                let subscribed = subscribed.clone();
+                async move {
+                    let mut ws_guard = ws.write().await;
+                    // 연결 중 동적으로 변경된 구독까지 반영하여 복원
+                    ws_guard.clear_subscriptions();
+                    for (code, sub_type) in subscribed.read().await.iter() {
+                        if *sub_type != SubscriptionType::Orderbook {
+                            ws_guard.add_trade_subscription(code);
+                        }
+                        if *sub_type != SubscriptionType::Trade {
+                            ws_guard.add_orderbook_subscription(code);
+                        }
+                    }
+                    ws_guard.connect_once().await
+                }
+            },
+        ));
 
         info!("KIS KR MarketStream 시작됨");
         Ok(())
@@ -211,6 +392,10 @@ impl MarketStream for KisKrMarketStream {
         self.started
     }
 
+    fn connection_health(&self) -> ConnectionHealth {
+        self.connection.snapshot()
+    }
+
     async fn subscribe_ticker(&mut self, symbol: &str) -> ExchangeResult<()> {
         let code = symbol.to_string();
 
@@ -232,6 +417,8 @@ impl MarketStream for KisKrMarketStream {
         }
 
         self.subscribed_symbols
+            .write()
+            .await
             .entry(code)
             .and_modify(|t| {
                 if *t == SubscriptionType::Orderbook {
@@ -278,6 +465,8 @@ impl MarketStream for KisKrMarketStream {
         }
 
         self.subscribed_symbols
+            .write()
+            .await
             .entry(code)
             .and_modify(|t| {
                 if *t == SubscriptionType::Trade {
@@ -297,9 +486,11 @@ impl MarketStream for KisKrMarketStream {
     async fn unsubscribe(&mut self, symbol: &str) -> ExchangeResult<()> {
         let code = symbol.to_string();
 
+        let removed = self.subscribed_symbols.write().await.remove(&code);
+
         if self.started {
             // 연결 후: command channel을 통해 실시간 구독 해제
-            if let Some(sub_type) = self.subscribed_symbols.remove(&code) {
+            if let Some(sub_type) = removed {
                 match sub_type {
                     SubscriptionType::Trade | SubscriptionType::Both => {
                         self.cmd_tx
@@ -330,7 +521,7 @@ impl MarketStream for KisKrMarketStream {
         } else {
             // 연결 전: 내부 큐에서 제거
             let mut ws = self.ws.write().await;
-            if let Some(sub_type) = self.subscribed_symbols.remove(&code) {
+            if let Some(sub_type) = removed {
                 match sub_type {
                     SubscriptionType::Trade | SubscriptionType::Both => {
                         ws.remove_trade_subscription(&code);
@@ -363,11 +554,10 @@ impl MarketStream for KisKrMarketStream {
             Some(KrRealtimeMessage::ConnectionStatus(connected)) => {
                 if connected {
                     info!("KIS KR WebSocket 연결됨");
-                    Some(MarketEvent::Connected)
                 } else {
                     warn!("KIS KR WebSocket 연결 끊김");
-                    Some(MarketEvent::Disconnected)
                 }
+                Some(self.connection.on_status(connected))
             }
             Some(KrRealtimeMessage::Error(msg)) => {
                 error!("KIS KR WebSocket 에러: {}", msg);
@@ -396,13 +586,18 @@ struct UsSubscriptionInfo {
 /// `start()` 전후 모두 구독/해제 가능합니다.
 /// - 연결 전: WebSocket 큐에 추가 (연결 시 일괄 전송)
 /// - 연결 후: command channel을 통해 실시간 전송
+///
+/// 연결이 끊기면 `subscribed_symbols` 기준으로 구독을 복원하며 재연결합니다.
 pub struct KisUsMarketStream {
     ws: Arc<RwLock<KisUsWebSocket>>,
     rx: Option<mpsc::Receiver<UsRealtimeMessage>>,
     /// 동적 구독을 위한 command sender (연결 후 사용)
     cmd_tx: mpsc::Sender<UsWsCommand>,
-    subscribed_symbols: HashMap<String, UsSubscriptionInfo>,
+    subscribed_symbols: Arc<RwLock<HashMap<String, UsSubscriptionInfo>>>,
     started: bool,
+    reconnect_policy: ReconnectPolicy,
+    connection: ConnectionMonitor,
+    reconnect_task: Option<JoinHandle<()>>,
 }
 
 impl KisUsMarketStream {
@@ -416,11 +611,20 @@ impl KisUsMarketStream {
             ws: Arc::new(RwLock::new(ws)),
             rx,
             cmd_tx,
-            subscribed_symbols: HashMap::new(),
+            subscribed_symbols: Arc::new(RwLock::new(HashMap::new())),
             started: false,
+            reconnect_policy: ReconnectPolicy::default(),
+            connection: ConnectionMonitor::default(),
+            reconnect_task: None,
         }
     }
 
+    /// 재연결 정책 설정.
+    pub fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
     /// 티커에서 Symbol 생성 (해외).
     #[allow(dead_code)]
     fn ticker_to_symbol(ticker: &str) -> Symbol {
@@ -465,6 +669,14 @@ impl KisUsMarketStream {
     }
 }
 
+impl Drop for KisUsMarketStream {
+    fn drop(&mut self) {
+        if let Some(task) = self.reconnect_task.take() {
+            task.abort();
+        }
+    }
+}
+
 #[async_trait]
 impl MarketStream for KisUsMarketStream {
     async fn start(&mut self) -> ExchangeResult<()> {
@@ -473,14 +685,32 @@ impl MarketStream for KisUsMarketStream {
         }
 
         let ws = self.ws.clone();
+        let subscribed = self.subscribed_symbols.clone();
         self.started = true;
 
-        tokio::spawn(async move {
-            let mut ws_guard = ws.write().await;
-            if let Err(e) = ws_guard.connect().await {
-                error!("KIS US WebSocket 연결 실패: {}", e);
-            }
-        });
+        self.reconnect_task = Some(spawn_reconnect_loop(
+            "KIS US",
+            self.reconnect_policy.clone(),
+            self.connection.clone(),
+            move || {
+                let ws = ws.clone();
+                let subscribed = subscribed.clone();
+                async move {
+                    let mut ws_guard = ws.write().await;
+                    // 연결 중 동적으로 변경된 구독까지 반영하여 복원
+                    ws_guard.clear_subscriptions();
+                    for (ticker, info) in subscribed.read().await.iter() {
+                        if info.sub_type != SubscriptionType::Orderbook {
+                            ws_guard.add_trade_subscription(ticker, &info.exchange_code);
+                        }
+                        if info.sub_type != SubscriptionType::Trade {
+                            ws_guard.add_orderbook_subscription(ticker, &info.exchange_code);
+                        }
+                    }
+                    ws_guard.connect_once().await
+                }
+            },
+        ));
 
         info!("KIS US MarketStream 시작됨");
         Ok(())
@@ -490,6 +720,10 @@ impl MarketStream for KisUsMarketStream {
         self.started
     }
 
+    fn connection_health(&self) -> ConnectionHealth {
+        self.connection.snapshot()
+    }
+
     async fn subscribe_ticker(&mut self, symbol: &str) -> ExchangeResult<()> {
         let ticker = symbol.to_string();
         let exchange_code = KisUsClient::get_exchange_code(symbol).to_string();
@@ -516,6 +750,8 @@ impl MarketStream for KisUsMarketStream {
         }
 
         self.subscribed_symbols
+            .write()
+            .await
             .entry(ticker)
             .and_modify(|info| {
                 if info.sub_type == SubscriptionType::Orderbook {
@@ -566,6 +802,8 @@ impl MarketStream for KisUsMarketStream {
         }
 
         self.subscribed_symbols
+            .write()
+            .await
             .entry(ticker)
             .and_modify(|info| {
                 if info.sub_type == SubscriptionType::Trade {
@@ -587,9 +825,11 @@ impl MarketStream for KisUsMarketStream {
     async fn unsubscribe(&mut self, symbol: &str) -> ExchangeResult<()> {
         let ticker = symbol.to_string();
 
+        let removed = self.subscribed_symbols.write().await.remove(&ticker);
+
         if self.started {
             // 연결 후: command channel을 통해 실시간 구독 해제
-            if let Some(info) = self.subscribed_symbols.remove(&ticker) {
+            if let Some(info) = removed {
                 let exchange_code = &info.exchange_code;
                 let tr_key = format!("D{}{}", exchange_code, ticker);
 
@@ -631,7 +871,7 @@ impl MarketStream for KisUsMarketStream {
         } else {
             // 연결 전: 내부 큐에서 제거
             let mut ws = self.ws.write().await;
-            if let Some(info) = self.subscribed_symbols.remove(&ticker) {
+            if let Some(info) = removed {
                 match info.sub_type {
                     SubscriptionType::Trade | SubscriptionType::Both => {
                         ws.remove_trade_subscription(&ticker, &info.exchange_code);
@@ -664,11 +904,10 @@ impl MarketStream for KisUsMarketStream {
             Some(UsRealtimeMessage::ConnectionStatus(connected)) => {
                 if connected {
                     info!("KIS US WebSocket 연결됨");
-                    Some(MarketEvent::Connected)
                 } else {
                     warn!("KIS US WebSocket 연결 끊김");
-                    Some(MarketEvent::Disconnected)
                 }
+                Some(self.connection.on_status(connected))
             }
             Some(UsRealtimeMessage::Error(msg)) => {
                 error!("KIS US WebSocket 에러: {}", msg);
@@ -853,13 +1092,29 @@ impl MarketStream for UpbitMarketStream {
 // Bithumb MarketStream
 // ============================================================================
 
+/// 빗썸 구독 채널.
+#[derive(Debug, Clone, Copy, Default)]
+struct BithumbChannels {
+    ticker: bool,
+    trade: bool,
+    orderbook: bool,
+}
+
 /// Bithumb WebSocket을 MarketStream trait으로 래핑하는 어댑터.
+///
+/// 티커/체결/호가를 채널별로 구독하며, `start()` 전후 모두 구독/해제 가능합니다.
+/// 호가는 스냅샷에 델타를 병합한 전체 호가창으로 전달됩니다.
+///
+/// 연결이 끊기면 `subscribed_symbols` 기준으로 구독을 복원하며 재연결합니다.
 pub struct BithumbMarketStream {
     ws: Arc<RwLock<BithumbWebSocket>>,
     rx: Option<mpsc::Receiver<BithumbWsMessage>>,
     cmd_tx: mpsc::Sender<BithumbWsCommand>,
-    subscribed_symbols: Vec<String>,
+    subscribed_symbols: Arc<RwLock<HashMap<String, BithumbChannels>>>,
     started: bool,
+    reconnect_policy: ReconnectPolicy,
+    connection: ConnectionMonitor,
+    reconnect_task: Option<JoinHandle<()>>,
 }
 
 impl Default for BithumbMarketStream {
@@ -877,11 +1132,20 @@ impl BithumbMarketStream {
             ws: Arc::new(RwLock::new(ws)),
             rx,
             cmd_tx,
-            subscribed_symbols: Vec::new(),
+            subscribed_symbols: Arc::new(RwLock::new(HashMap::new())),
             started: false,
+            reconnect_policy: ReconnectPolicy::default(),
+            connection: ConnectionMonitor::default(),
+            reconnect_task: None,
         }
     }
 
+    /// 재연결 정책 설정.
+    pub fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
     fn quote_to_ticker(quote: &trader_core::QuoteData) -> Ticker {
         Ticker {
             ticker: quote.symbol.clone(),
@@ -896,6 +1160,45 @@ impl BithumbMarketStream {
             timestamp: quote.timestamp,
         }
     }
+
+    /// 채널 구독 기록 후 연결 중이면 즉시 전송.
+    async fn subscribe_channel(
+        &mut self,
+        symbol: &str,
+        mark: fn(&mut BithumbChannels),
+        command: fn(Vec<String>) -> BithumbWsCommand,
+        label: &str,
+    ) -> ExchangeResult<()> {
+        let code = symbol.to_string();
+        mark(
+            self.subscribed_symbols
+                .write()
+                .await
+                .entry(code.clone())
+                .or_default(),
+        );
+
+        if self.started {
+            self.cmd_tx
+                .send(command(vec![code.clone()]))
+                .await
+                .map_err(|e| {
+                    ExchangeError::NetworkError(format!("Bithumb {} 구독 전송 실패: {}", label, e))
+                })?;
+            info!("Bithumb {} 동적 구독: {}", label, code);
+        } else {
+            info!("Bithumb {} 구독 설정: {}", label, code);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for BithumbMarketStream {
+    fn drop(&mut self) {
+        if let Some(task) = self.reconnect_task.take() {
+            task.abort();
+        }
+    }
 }
 
 #[async_trait]
@@ -904,12 +1207,38 @@ impl MarketStream for BithumbMarketStream {
         if self.started {
             return Ok(());
         }
+
         let ws = self.ws.clone();
+        let subscribed = self.subscribed_symbols.clone();
         self.started = true;
-        tokio::spawn(async move {
-            let mut ws_guard = ws.write().await;
-            ws_guard.connect().await;
-        });
+
+        self.reconnect_task = Some(spawn_reconnect_loop(
+            "Bithumb",
+            self.reconnect_policy.clone(),
+            self.connection.clone(),
+            move || {
+                let ws = ws.clone();
+                let subscribed = subscribed.clone();
+                async move {
+                    let mut ws_guard = ws.write().await;
+                    // 연결 중 동적으로 변경된 구독까지 반영하여 복원
+                    ws_guard.clear_subscriptions();
+                    for (code, channels) in subscribed.read().await.iter() {
+                        if channels.ticker {
+                            ws_guard.add_ticker_subscription(code);
+                        }
+                        if channels.trade {
+                            ws_guard.add_trade_subscription(code);
+                        }
+                        if channels.orderbook {
+                            ws_guard.add_orderbook_subscription(code);
+                        }
+                    }
+                    ws_guard.connect_once().await
+                }
+            },
+        ));
+
         info!("Bithumb MarketStream 시작됨");
         Ok(())
     }
@@ -918,21 +1247,18 @@ impl MarketStream for BithumbMarketStream {
         self.started
     }
 
+    fn connection_health(&self) -> ConnectionHealth {
+        self.connection.snapshot()
+    }
+
     async fn subscribe_ticker(&mut self, symbol: &str) -> ExchangeResult<()> {
-        let code = symbol.to_string();
-        if !self.subscribed_symbols.contains(&code) {
-            self.subscribed_symbols.push(code.clone());
-        }
-        if self.started {
-            self.cmd_tx
-                .send(BithumbWsCommand::SubscribeTicker(vec![code.clone()]))
-                .await
-                .map_err(|e| {
-                    ExchangeError::NetworkError(format!("Bithumb 구독 전송 실패: {}", e))
-                })?;
-            info!("Bithumb 티커 동적 구독: {}", code);
-        }
-        Ok(())
+        self.subscribe_channel(
+            symbol,
+            |c| c.ticker = true,
+            BithumbWsCommand::SubscribeTicker,
+            "티커",
+        )
+        .await
     }
 
     async fn subscribe_kline(
@@ -945,22 +1271,32 @@ impl MarketStream for BithumbMarketStream {
         ))
     }
 
-    async fn subscribe_order_book(&mut self, _symbol: &str) -> ExchangeResult<()> {
-        // TODO: Bithumb orderbook 구독 구현
-        warn!("Bithumb 호가 구독은 아직 지원되지 않습니다");
-        Ok(())
+    async fn subscribe_order_book(&mut self, symbol: &str) -> ExchangeResult<()> {
+        self.subscribe_channel(
+            symbol,
+            |c| c.orderbook = true,
+            BithumbWsCommand::SubscribeOrderbook,
+            "호가",
+        )
+        .await
     }
 
     async fn subscribe_trades(&mut self, symbol: &str) -> ExchangeResult<()> {
-        self.subscribe_ticker(symbol).await
+        self.subscribe_channel(
+            symbol,
+            |c| c.trade = true,
+            BithumbWsCommand::SubscribeTrade,
+            "체결",
+        )
+        .await
     }
 
     async fn unsubscribe(&mut self, symbol: &str) -> ExchangeResult<()> {
         let code = symbol.to_string();
-        self.subscribed_symbols.retain(|s| s != &code);
-        if self.started {
+        let removed = self.subscribed_symbols.write().await.remove(&code);
+        if self.started && removed.is_some() {
             self.cmd_tx
-                .send(BithumbWsCommand::UnsubscribeTicker(vec![code.clone()]))
+                .send(BithumbWsCommand::Unsubscribe(vec![code.clone()]))
                 .await
                 .map_err(|e| {
                     ExchangeError::NetworkError(format!("Bithumb 구독 해제 실패: {}", e))
@@ -977,6 +1313,25 @@ impl MarketStream for BithumbMarketStream {
                 debug!("Bithumb Ticker: {} @ {}", quote.symbol, quote.current_price);
                 Some(MarketEvent::Ticker(Self::quote_to_ticker(&quote)))
             }
+            Some(BithumbWsMessage::Trade(tick)) => {
+                debug!(
+                    "Bithumb Trade: {} @ {} ({:?})",
+                    tick.ticker, tick.price, tick.side
+                );
+                Some(MarketEvent::Trade(tick))
+            }
+            Some(BithumbWsMessage::Orderbook(ob)) => {
+                debug!("Bithumb Orderbook: {}", ob.ticker);
+                Some(MarketEvent::OrderBook(ob))
+            }
+            Some(BithumbWsMessage::ConnectionStatus(connected)) => {
+                if connected {
+                    info!("Bithumb WebSocket 연결됨");
+                } else {
+                    warn!("Bithumb WebSocket 연결 끊김");
+                }
+                Some(self.connection.on_status(connected))
+            }
             Some(BithumbWsMessage::Error(msg)) => {
                 error!("Bithumb WebSocket 에러: {}", msg);
                 Some(MarketEvent::Error(msg))
@@ -1157,12 +1512,17 @@ impl MarketStream for LsSecMarketStream {
 // ============================================================================
 
 /// DB증권 주식용 MarketStream 구현.
+///
+/// 연결이 끊기면 `subscribed_symbols`의 체결가 구독을 복원하며 재연결합니다.
 pub struct DbInvestmentMarketStream {
     ws: Arc<RwLock<DbInvestmentWebSocket>>,
     rx: Option<mpsc::Receiver<DbWsMessage>>,
     cmd_tx: mpsc::Sender<DbWsCommand>,
-    subscribed_symbols: Vec<String>,
+    subscribed_symbols: Arc<RwLock<Vec<String>>>,
     started: bool,
+    reconnect_policy: ReconnectPolicy,
+    connection: ConnectionMonitor,
+    reconnect_task: Option<JoinHandle<()>>,
 }
 
 impl DbInvestmentMarketStream {
@@ -1176,11 +1536,20 @@ impl DbInvestmentMarketStream {
             ws: Arc::new(RwLock::new(ws)),
             rx,
             cmd_tx,
-            subscribed_symbols: Vec::new(),
+            subscribed_symbols: Arc::new(RwLock::new(Vec::new())),
             started: false,
+            reconnect_policy: ReconnectPolicy::default(),
+            connection: ConnectionMonitor::default(),
+            reconnect_task: None,
         }
     }
 
+    /// 재연결 정책 설정.
+    pub fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
     /// QuoteData를 Ticker로 변환.
     fn quote_to_ticker(quote: &trader_core::QuoteData) -> Ticker {
         Ticker {
@@ -1198,6 +1567,14 @@ impl DbInvestmentMarketStream {
     }
 }
 
+impl Drop for DbInvestmentMarketStream {
+    fn drop(&mut self) {
+        if let Some(task) = self.reconnect_task.take() {
+            task.abort();
+        }
+    }
+}
+
 #[async_trait]
 impl MarketStream for DbInvestmentMarketStream {
     async fn start(&mut self) -> ExchangeResult<()> {
@@ -1206,14 +1583,25 @@ impl MarketStream for DbInvestmentMarketStream {
         }
 
         let ws = self.ws.clone();
+        let subscribed = self.subscribed_symbols.clone();
         self.started = true;
 
-        tokio::spawn(async move {
-            let mut ws_guard = ws.write().await;
-            if let Err(e) = ws_guard.connect().await {
-                error!("DB증권 WebSocket 연결 실패: {}", e);
-            }
-        });
+        self.reconnect_task = Some(spawn_reconnect_loop(
+            "DB증권",
+            self.reconnect_policy.clone(),
+            self.connection.clone(),
+            move || {
+                let ws = ws.clone();
+                let subscribed = subscribed.clone();
+                async move {
+                    let mut ws_guard = ws.write().await;
+                    for code in subscribed.read().await.iter() {
+                        ws_guard.add_trade_subscription(code);
+                    }
+                    ws_guard.connect_once().await
+                }
+            },
+        ));
 
         info!("DB증권 MarketStream 시작됨");
         Ok(())
@@ -1223,11 +1611,18 @@ impl MarketStream for DbInvestmentMarketStream {
         self.started
     }
 
+    fn connection_health(&self) -> ConnectionHealth {
+        self.connection.snapshot()
+    }
+
     async fn subscribe_ticker(&mut self, symbol: &str) -> ExchangeResult<()> {
         let code = symbol.to_string();
 
-        if !self.subscribed_symbols.contains(&code) {
-            self.subscribed_symbols.push(code.clone());
+        {
+            let mut subscribed = self.subscribed_symbols.write().await;
+            if !subscribed.contains(&code) {
+                subscribed.push(code.clone());
+            }
         }
 
         if self.started {
@@ -1285,7 +1680,7 @@ impl MarketStream for DbInvestmentMarketStream {
 
     async fn unsubscribe(&mut self, symbol: &str) -> ExchangeResult<()> {
         let code = symbol.to_string();
-        self.subscribed_symbols.retain(|s| s != &code);
+        self.subscribed_symbols.write().await.retain(|s| s != &code);
 
         if self.started {
             // V60 체결가 구독 해제
@@ -1320,11 +1715,10 @@ impl MarketStream for DbInvestmentMarketStream {
             Some(DbWsMessage::ConnectionStatus(connected)) => {
                 if connected {
                     info!("DB증권 WebSocket 연결됨");
-                    Some(MarketEvent::Connected)
                 } else {
                     warn!("DB증권 WebSocket 연결 해제됨");
-                    Some(MarketEvent::Disconnected)
                 }
+                Some(self.connection.on_status(connected))
             }
             Some(DbWsMessage::Error(msg)) => {
                 error!("DB증권 WebSocket 에러: {}", msg);
@@ -1867,4 +2261,79 @@ mod tests {
         assert!(!UnifiedMarketStream::is_korean_symbol("AAPL"));
         assert!(!UnifiedMarketStream::is_korean_symbol("AAPL/USD"));
     }
+
+    #[test]
+    fn test_reconnect_backoff_is_exponential_and_capped() {
+        let policy = ReconnectPolicy {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(10),
+            max_attempts: Some(3),
+        };
+
+        assert_eq!(policy.delay_for(1), Duration::from_secs(1));
+        assert_eq!(policy.delay_for(2), Duration::from_secs(2));
+        assert_eq!(policy.delay_for(4), Duration::from_secs(8));
+        assert_eq!(policy.delay_for(5), Duration::from_secs(10));
+        assert_eq!(policy.delay_for(100), Duration::from_secs(10));
+        assert!(!policy.exhausted(3));
+        assert!(policy.exhausted(4));
+        assert!(!ReconnectPolicy::default().exhausted(u32::MAX));
+    }
+
+    #[test]
+    fn test_connection_monitor_tracks_health() {
+        let monitor = ConnectionMonitor::default();
+        assert_eq!(monitor.snapshot(), ConnectionHealth::default());
+
+        let event = monitor.on_status(true);
+        assert!(matches!(
+            event,
+            MarketEvent::ConnectionStatus { connected: true }
+        ));
+        let connected_at = monitor.snapshot().last_connected_at;
+        assert!(connected_at.is_some());
+
+        monitor.on_status(false);
+        monitor.record_attempt(1);
+        monitor.record_attempt(2);
+        let health = monitor.snapshot();
+        assert!(!health.connected);
+        assert_eq!(health.reconnect_attempts, 2);
+        assert_eq!(health.total_reconnect_attempts, 2);
+        assert!(health.last_disconnected_at.is_some());
+
+        // 재연결 성공 시 연속 시도 횟수만 초기화
+        monitor.on_status(true);
+        let health = monitor.snapshot();
+        assert!(health.connected);
+        assert_eq!(health.reconnect_attempts, 0);
+        assert_eq!(health.total_reconnect_attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_loop_retries_until_exhausted() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let calls = Arc::new(AtomicU32::new(0));
+        let monitor = ConnectionMonitor::default();
+        let policy = ReconnectPolicy {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(2),
+            max_attempts: Some(2),
+        };
+
+        let counter = calls.clone();
+        let task = spawn_reconnect_loop("test", policy, monitor.clone(), move || {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Err(ExchangeError::NetworkError("연결 실패".to_string()))
+            }
+        });
+        task.await.unwrap();
+
+        // 최초 연결 + 재연결 2회
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(monitor.snapshot().total_reconnect_attempts, 2);
+    }
 }
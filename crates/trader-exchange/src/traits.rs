@@ -1,6 +1,7 @@
 //! 거래소 trait 정의.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use trader_core::{Kline, OrderBook, OrderStatus, Position, Ticker, Timeframe, TradeTick};
 
 use crate::ExchangeError;
@@ -58,10 +59,29 @@ pub enum MarketEvent {
     Connected,
     /// 연결 해제
     Disconnected,
+    /// 재연결 감시 중인 스트림의 연결 상태 변경.
+    ///
+    /// `connected: false` 이후 `true`가 오기 전까지 수신한 시세는 스테일할 수 있습니다.
+    ConnectionStatus { connected: bool },
     /// 에러 발생
     Error(String),
 }
 
+/// 스트림 연결 상태 요약.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionHealth {
+    /// 현재 연결 여부
+    pub connected: bool,
+    /// 마지막 연결 성공 이후 연속 재연결 시도 횟수
+    pub reconnect_attempts: u32,
+    /// 누적 재연결 시도 횟수
+    pub total_reconnect_attempts: u64,
+    /// 마지막 연결 성공 시각
+    pub last_connected_at: Option<DateTime<Utc>>,
+    /// 마지막 연결 끊김 시각
+    pub last_disconnected_at: Option<DateTime<Utc>>,
+}
+
 /// 사용자 데이터 스트림 이벤트.
 #[derive(Debug, Clone)]
 pub enum UserEvent {
@@ -89,6 +109,16 @@ pub trait MarketStream: Send + Sync {
         true
     }
 
+    /// 연결 상태 및 재연결 이력 조회.
+    ///
+    /// 재연결을 감시하지 않는 스트림은 시작 여부만 반영합니다.
+    fn connection_health(&self) -> ConnectionHealth {
+        ConnectionHealth {
+            connected: self.is_started(),
+            ..ConnectionHealth::default()
+        }
+    }
+
     /// 시세 업데이트 구독.
     async fn subscribe_ticker(&mut self, symbol: &str) -> ExchangeResult<()>;
 
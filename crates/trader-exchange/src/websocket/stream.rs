@@ -17,7 +17,8 @@ use tokio_tungstenite::{
 };
 use tracing::{debug, error, info};
 use trader_core::{
-    Kline, MarketType, OrderBook, OrderBookLevel, Side, Symbol, Ticker, Timeframe, TradeTick,
+    Kline, MarketType, OrderBook, OrderBookLevel, Side, Symbol, SymbolNormalizer, SymbolVenue,
+    Ticker, Timeframe, TradeTick,
 };
 
 use crate::{
@@ -269,26 +270,21 @@ impl BinanceMarketStream {
 
     /// Binance WebSocket용 심볼 형식으로 변환합니다. (BTC/USDT -> btcusdt)
     fn format_symbol(ticker: &str) -> String {
-        ticker.replace("/", "").to_lowercase()
+        SymbolNormalizer::new()
+            .to_venue(ticker, SymbolVenue::Binance)
+            .unwrap_or_else(|_| ticker.replace('/', ""))
+            .to_lowercase()
     }
 
     /// Binance 형식에서 심볼을 파싱합니다.
     fn parse_symbol(binance_symbol: &str) -> Symbol {
-        let quotes = ["usdt", "busd", "btc", "eth", "bnb", "usdc"];
-        let lower = binance_symbol.to_lowercase();
-
-        for quote in quotes {
-            if lower.ends_with(quote) {
-                let base = &binance_symbol[..binance_symbol.len() - quote.len()];
-                return Symbol::new(
-                    base.to_uppercase(),
-                    quote.to_uppercase(),
-                    MarketType::Crypto,
-                );
-            }
-        }
-
-        Symbol::new(binance_symbol.to_uppercase(), "USDT", MarketType::Crypto)
+        SymbolNormalizer::new()
+            .to_internal(binance_symbol, SymbolVenue::Binance)
+            .ok()
+            .and_then(|ticker| Symbol::from_string(&ticker, MarketType::Crypto))
+            .unwrap_or_else(|| {
+                Symbol::new(binance_symbol.to_uppercase(), "USDT", MarketType::Crypto)
+            })
     }
 
     /// 문자열에서 소수점 숫자를 파싱합니다.
@@ -338,6 +334,7 @@ impl BinanceMarketStream {
                         .unwrap_or_else(Utc::now),
                     quote_volume: Some(Self::parse_decimal(&k.quote_volume)),
                     num_trades: Some(k.num_trades),
+                    synthetic: false,
                 }));
             }
         }
@@ -229,6 +229,7 @@ impl YahooFinanceProvider {
             close_time,
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         }
     }
 
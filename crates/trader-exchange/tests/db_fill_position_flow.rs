@@ -0,0 +1,197 @@
+//! DB증권 주문 상태 스트림 → 체결 → 포지션 → PnL 통합 테스트.
+//!
+//! DB증권 provider가 사용하는 주문 상태 허브에 이벤트를 발행하고,
+//! [`OrderFillAdapter`]가 OrderManager/PositionTracker에 반영한 결과를 검증합니다.
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use tokio::sync::RwLock;
+use trader_core::{OrderRequest, OrderStatusType, OrderUpdate, OrderUpdateProvider};
+use trader_exchange::OrderUpdateHub;
+use trader_execution::{OrderFillAdapter, OrderManager, PositionTracker};
+use uuid::Uuid;
+
+struct Harness {
+    hub: Arc<OrderUpdateHub>,
+    adapter: Arc<OrderFillAdapter>,
+    order_manager: Arc<RwLock<OrderManager>>,
+    position_tracker: Arc<RwLock<PositionTracker>>,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let hub = Arc::new(OrderUpdateHub::new("db_investment"));
+        let order_manager = Arc::new(RwLock::new(OrderManager::new()));
+        let position_tracker = Arc::new(RwLock::new(PositionTracker::new("db_investment")));
+        let adapter = Arc::new(OrderFillAdapter::new(
+            Arc::clone(&order_manager),
+            Arc::clone(&position_tracker),
+        ));
+        Arc::clone(&adapter).spawn(hub.subscribe_order_updates());
+
+        Self {
+            hub,
+            adapter,
+            order_manager,
+            position_tracker,
+        }
+    }
+
+    async fn create_order(&self, request: OrderRequest) -> Uuid {
+        self.order_manager
+            .write()
+            .await
+            .create_order(request, "db_investment")
+            .unwrap()
+            .id
+    }
+
+    fn publish(
+        &self,
+        order_no: &str,
+        status: OrderStatusType,
+        filled: Decimal,
+        avg: Option<Decimal>,
+    ) {
+        self.hub.publish(OrderUpdate {
+            exchange: "db_investment".to_string(),
+            order_id: order_no.to_string(),
+            client_order_id: None,
+            ticker: Some("005930".to_string()),
+            status,
+            filled_quantity: filled,
+            average_price: avg,
+            timestamp: Utc::now(),
+        });
+    }
+
+    async fn order_state(&self, order_id: Uuid) -> (OrderStatusType, Decimal) {
+        let order_manager = self.order_manager.read().await;
+        let order = order_manager.get_order(order_id).unwrap();
+        (order.status, order.filled_quantity)
+    }
+
+    /// 백그라운드 태스크가 이벤트를 반영할 때까지 대기.
+    async fn wait_for_order(&self, order_id: Uuid, expected: (OrderStatusType, Decimal)) {
+        for _ in 0..200 {
+            if self.order_state(order_id).await == expected {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!(
+            "주문 상태 대기 시간 초과: 기대 {:?}, 실제 {:?}",
+            expected,
+            self.order_state(order_id).await
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_fill_to_position_to_pnl() {
+    let harness = Harness::new();
+
+    // 매수 10주 지정가 주문
+    let buy_id = harness
+        .create_order(OrderRequest::limit_buy(
+            "005930".to_string(),
+            dec!(10),
+            dec!(70000),
+        ))
+        .await;
+
+    // 접수 응답보다 체결 이벤트가 먼저 도착 → 버퍼링
+    harness.publish(
+        "DB1",
+        OrderStatusType::PartiallyFilled,
+        dec!(4),
+        Some(dec!(70000)),
+    );
+    for _ in 0..200 {
+        if harness.adapter.buffered_count().await == 1 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(harness.adapter.buffered_count().await, 1);
+    assert!(harness
+        .position_tracker
+        .read()
+        .await
+        .get_position_for_symbol("005930")
+        .is_none());
+
+    // 접수 등록 시 보관된 체결 재처리
+    let replayed = harness
+        .adapter
+        .register_submission(buy_id, "DB1")
+        .await
+        .unwrap();
+    assert_eq!(replayed.len(), 1);
+    assert_eq!(
+        harness.order_state(buy_id).await,
+        (OrderStatusType::PartiallyFilled, dec!(4))
+    );
+
+    // 늦게 도착한 접수 이벤트(체결 0)는 역행이므로 무시
+    harness.publish("DB1", OrderStatusType::Open, Decimal::ZERO, None);
+
+    // 추가 부분 체결: 누적 6주, 평균 70200 → 이번 2주는 70600
+    harness.publish(
+        "DB1",
+        OrderStatusType::PartiallyFilled,
+        dec!(6),
+        Some(dec!(70200)),
+    );
+    harness
+        .wait_for_order(buy_id, (OrderStatusType::PartiallyFilled, dec!(6)))
+        .await;
+
+    // 잔량 취소: 체결분은 포지션에 유지
+    harness.publish(
+        "DB1",
+        OrderStatusType::Cancelled,
+        dec!(6),
+        Some(dec!(70200)),
+    );
+    harness
+        .wait_for_order(buy_id, (OrderStatusType::Cancelled, dec!(6)))
+        .await;
+
+    {
+        let mut tracker = harness.position_tracker.write().await;
+        let position = tracker.get_position_for_symbol("005930").unwrap();
+        assert_eq!(position.quantity, dec!(6));
+        assert_eq!(position.entry_price, dec!(70200));
+
+        tracker.update_price("005930", dec!(71000)).unwrap();
+        assert_eq!(tracker.total_unrealized_pnl(), dec!(4800));
+    }
+
+    // 전량 매도 체결 → 실현 손익
+    let sell_id = harness
+        .create_order(OrderRequest::market_sell("005930".to_string(), dec!(6)))
+        .await;
+    harness
+        .adapter
+        .register_submission(sell_id, "DB2")
+        .await
+        .unwrap();
+    harness.publish("DB2", OrderStatusType::Filled, dec!(6), Some(dec!(72000)));
+    harness
+        .wait_for_order(sell_id, (OrderStatusType::Filled, dec!(6)))
+        .await;
+
+    let tracker = harness.position_tracker.read().await;
+    if let Some(position) = tracker.get_position_for_symbol("005930") {
+        assert!(position.quantity.is_zero());
+    }
+    assert_eq!(tracker.total_realized_pnl(), dec!(10800));
+
+    let order_manager = harness.order_manager.read().await;
+    assert_eq!(order_manager.get_order_fills(buy_id).len(), 2);
+    assert_eq!(order_manager.get_order_fills(sell_id).len(), 1);
+}
@@ -0,0 +1,159 @@
+//! Mock 거래소 실패 주입 통합 테스트.
+//!
+//! 주입된 실패가 LiveExecutor의 재시도/복구 로직을 실제로 작동시키는지 검증합니다.
+//! DB 없이 동작하도록 지연 연결 풀을 사용합니다 (상태 복원 실패는 무시됨).
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use rust_decimal_macros::dec;
+use sqlx::postgres::PgPoolOptions;
+use trader_core::{
+    domain::{ExchangeProvider, OrderExecutionProvider, OrderStatusType},
+    OrderRequest, Side, Signal, SignalType,
+};
+use trader_exchange::provider::{
+    InjectedErrorKind, MockConfig, MockExchangeProvider, MockFailureConfig,
+};
+use trader_execution::{
+    ConversionConfig, LiveExecutor, ProcessorConfig, RetryConfig, SignalProcessor,
+    SignalProcessorError,
+};
+use uuid::Uuid;
+
+async fn mock_provider(failure: MockFailureConfig) -> Arc<MockExchangeProvider> {
+    let pool = PgPoolOptions::new()
+        .acquire_timeout(Duration::from_millis(50))
+        .connect_lazy("postgres://localhost:1/unused")
+        .expect("lazy pool");
+    let config = MockConfig::default().with_failure_injection(failure);
+    Arc::new(
+        MockExchangeProvider::new(Uuid::new_v4(), config, pool)
+            .await
+            .expect("mock provider"),
+    )
+}
+
+fn retry_executor(provider: Arc<MockExchangeProvider>) -> LiveExecutor {
+    let conversion_config = ConversionConfig {
+        min_strength: 0.0,
+        auto_stop_loss: false,
+        auto_take_profit: false,
+        ..ConversionConfig::default()
+    };
+    LiveExecutor::with_conversion_config(
+        ProcessorConfig::default(),
+        dec!(10_000_000),
+        provider,
+        conversion_config,
+    )
+    .with_retry(RetryConfig {
+        max_attempts: 3,
+        base_delay_ms: 100,
+        max_delay_ms: 1_000,
+        jitter: false,
+    })
+}
+
+fn entry_signal(ticker: &str) -> Signal {
+    Signal::new(
+        "test_strategy",
+        ticker.to_string(),
+        Side::Buy,
+        SignalType::Entry,
+    )
+    .with_strength(0.5)
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_transient_failures_are_retried() {
+    let provider = mock_provider(
+        MockFailureConfig::default().with_fail_first_orders(2, InjectedErrorKind::ServerError),
+    )
+    .await;
+    let mut executor = retry_executor(provider);
+
+    let result = executor
+        .process_signal(&entry_signal("005930"), dec!(50000), Utc::now())
+        .await;
+
+    // 2회 실패 후 3번째 시도에서 체결
+    assert!(result.unwrap().is_some());
+    assert_eq!(executor.positions().len(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rejections_are_not_retried() {
+    let provider = mock_provider(
+        MockFailureConfig::default()
+            .with_reject_symbol("000660")
+            .with_insufficient_balance(),
+    )
+    .await;
+    let mut executor = retry_executor(provider);
+
+    for ticker in ["000660", "005930"] {
+        let result = executor
+            .process_signal(&entry_signal(ticker), dec!(50000), Utc::now())
+            .await;
+        assert!(matches!(
+            result,
+            Err(SignalProcessorError::ExchangeError(_))
+        ));
+    }
+    assert!(executor.positions().is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_timeout_recovers_accepted_order() {
+    // 첫 주문은 지연 후 타임아웃되지만 실제로는 접수됨 → 재전송 없이 접수 주문 사용
+    let provider =
+        mock_provider(MockFailureConfig::default().with_timeout(1.0, Duration::from_secs(5), true))
+            .await;
+    let mut executor = retry_executor(provider.clone());
+
+    let result = executor
+        .process_signal(&entry_signal("005930"), dec!(50000), Utc::now())
+        .await;
+
+    assert!(result.unwrap().is_some());
+    assert_eq!(executor.positions().len(), 1);
+}
+
+#[tokio::test]
+async fn test_partial_fill_then_cancel() {
+    let provider =
+        mock_provider(MockFailureConfig::default().with_partial_fill(1.0, dec!(0.3))).await;
+
+    let request = OrderRequest::market_buy("005930".to_string(), dec!(10));
+    let response = provider.place_order(&request).await.unwrap();
+
+    let status = provider
+        .fetch_order_status(&response.order_no, "005930")
+        .await
+        .unwrap();
+    assert_eq!(status.status, OrderStatusType::Cancelled);
+    assert_eq!(status.filled_quantity, dec!(3));
+    assert_eq!(status.quantity, Some(dec!(10)));
+}
+
+#[tokio::test]
+async fn test_seeded_rejections_are_reproducible() {
+    let failure = MockFailureConfig::default()
+        .with_seed(42)
+        .with_reject_rate(0.5, InjectedErrorKind::Rejected);
+
+    let mut runs = Vec::new();
+    for _ in 0..2 {
+        let provider = mock_provider(failure.clone()).await;
+        let mut outcomes = Vec::new();
+        for _ in 0..20 {
+            let request = OrderRequest::market_buy("005930".to_string(), dec!(1));
+            outcomes.push(provider.place_order(&request).await.is_ok());
+        }
+        runs.push(outcomes);
+    }
+
+    assert_eq!(runs[0], runs[1]);
+    assert!(runs[0].contains(&true) && runs[0].contains(&false));
+}
@@ -0,0 +1,241 @@
+//! Integration tests for the stream-backed quote cache.
+//!
+//! 스트림 경로(SimulatedMarketStream)와 REST 경로(고정 시세 provider)가
+//! 같은 심볼에 대해 일관된 가격을 반환하는지 검증합니다.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use tokio::sync::mpsc;
+use trader_core::{
+    domain::{MarketDataProvider, ProviderError, QuoteData},
+    Ticker,
+};
+use trader_exchange::{
+    quote_cache::{CachedMarketDataProvider, QuoteCacheConfig, QuoteSource},
+    simulated::SimulatedMarketStream,
+    traits::{MarketEvent, MarketStream},
+};
+
+/// 고정 시세를 반환하고 호출 횟수를 세는 REST provider.
+#[derive(Default)]
+struct FixedQuoteProvider {
+    prices: Mutex<HashMap<String, Decimal>>,
+    calls: AtomicUsize,
+}
+
+impl FixedQuoteProvider {
+    fn set_price(&self, symbol: &str, price: Decimal) {
+        self.prices
+            .lock()
+            .unwrap()
+            .insert(symbol.to_string(), price);
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for FixedQuoteProvider {
+    async fn get_quote(&self, symbol: &str) -> Result<QuoteData, ProviderError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let price = self
+            .prices
+            .lock()
+            .unwrap()
+            .get(symbol)
+            .copied()
+            .ok_or_else(|| ProviderError::Api(format!("시세 없음: {}", symbol)))?;
+
+        Ok(QuoteData {
+            symbol: symbol.to_string(),
+            current_price: price,
+            price_change: Decimal::ZERO,
+            change_percent: Decimal::ZERO,
+            high: price,
+            low: price,
+            open: price,
+            prev_close: price,
+            volume: Decimal::ZERO,
+            trading_value: Decimal::ZERO,
+            timestamp: Utc::now(),
+        })
+    }
+
+    fn provider_name(&self) -> &str {
+        "fixed"
+    }
+}
+
+fn ticker(symbol: &str, last: Decimal) -> Ticker {
+    Ticker {
+        ticker: symbol.to_string(),
+        bid: last,
+        ask: last,
+        last,
+        volume_24h: dec!(1),
+        high_24h: last,
+        low_24h: last,
+        change_24h: Decimal::ZERO,
+        change_24h_percent: Decimal::ZERO,
+        timestamp: Utc::now(),
+    }
+}
+
+/// 스트림 이벤트가 캐시에 반영될 때까지 대기.
+async fn wait_for_stream_price(cache: &CachedMarketDataProvider, symbol: &str, price: Decimal) {
+    for _ in 0..100 {
+        if let Some(cached) = cache.cached_quote(symbol).await {
+            if cached.source == QuoteSource::Stream && cached.quote.current_price == price {
+                return;
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("스트림 시세가 캐시에 반영되지 않음: {} @ {}", symbol, price);
+}
+
+fn setup(
+    config: QuoteCacheConfig,
+) -> (
+    Arc<FixedQuoteProvider>,
+    Arc<CachedMarketDataProvider>,
+    SimulatedMarketStream,
+    mpsc::Sender<MarketEvent>,
+) {
+    let rest = Arc::new(FixedQuoteProvider::default());
+    let cache = Arc::new(CachedMarketDataProvider::with_config(rest.clone(), config));
+    let (tx, rx) = mpsc::channel(16);
+    (rest, cache, SimulatedMarketStream::new(rx), tx)
+}
+
+#[tokio::test]
+async fn test_stream_and_rest_return_consistent_price() {
+    let (rest, cache, mut stream, tx) = setup(QuoteCacheConfig::default());
+    rest.set_price("KRW-BTC", dec!(95000000));
+
+    // 스트림 없음: REST 경로
+    let from_rest = cache.get_quote("KRW-BTC").await.unwrap();
+    assert_eq!(rest.calls(), 1);
+
+    // 같은 가격의 Ticker가 스트림으로 들어오면 스트림 경로 사용
+    stream.subscribe_ticker("KRW-BTC").await.unwrap();
+    let _listener = cache.clone().spawn_stream_listener(stream);
+    tx.send(MarketEvent::Ticker(ticker("KRW-BTC", dec!(95000000))))
+        .await
+        .unwrap();
+    wait_for_stream_price(&cache, "KRW-BTC", dec!(95000000)).await;
+
+    let from_stream = cache.get_quote("KRW-BTC").await.unwrap();
+    assert_eq!(from_stream.symbol, from_rest.symbol);
+    assert_eq!(from_stream.current_price, from_rest.current_price);
+    assert_eq!(
+        rest.calls(),
+        1,
+        "스트림 값이 있으면 REST를 호출하지 않아야 함"
+    );
+
+    // 스트림 가격 변경은 즉시 반영
+    tx.send(MarketEvent::Ticker(ticker("KRW-BTC", dec!(95100000))))
+        .await
+        .unwrap();
+    wait_for_stream_price(&cache, "KRW-BTC", dec!(95100000)).await;
+    assert_eq!(
+        cache.get_quote("KRW-BTC").await.unwrap().current_price,
+        dec!(95100000)
+    );
+    assert_eq!(rest.calls(), 1);
+}
+
+#[tokio::test]
+async fn test_rest_polling_is_deduplicated() {
+    let (rest, cache, _stream, _tx) = setup(QuoteCacheConfig {
+        stale_after: Duration::from_secs(10),
+        rest_ttl: Duration::from_secs(5),
+    });
+    rest.set_price("005930", dec!(71000));
+
+    for _ in 0..5 {
+        assert_eq!(
+            cache.get_quote("005930").await.unwrap().current_price,
+            dec!(71000)
+        );
+    }
+    assert_eq!(rest.calls(), 1);
+
+    let quotes = cache
+        .get_quotes(&["005930".to_string(), "000660".to_string()])
+        .await;
+    // 000660은 REST에도 없으므로 제외
+    assert_eq!(quotes.len(), 1);
+    assert_eq!(rest.calls(), 2);
+}
+
+#[tokio::test]
+async fn test_stale_stream_quote_forces_rest_refresh() {
+    let (rest, cache, mut stream, tx) = setup(QuoteCacheConfig {
+        stale_after: Duration::from_millis(50),
+        rest_ttl: Duration::from_millis(50),
+    });
+    rest.set_price("KRW-ETH", dec!(4500000));
+
+    stream.subscribe_ticker("KRW-ETH").await.unwrap();
+    let _listener = cache.clone().spawn_stream_listener(stream);
+    tx.send(MarketEvent::Ticker(ticker("KRW-ETH", dec!(4490000))))
+        .await
+        .unwrap();
+    wait_for_stream_price(&cache, "KRW-ETH", dec!(4490000)).await;
+    assert_eq!(
+        cache.get_quote("KRW-ETH").await.unwrap().current_price,
+        dec!(4490000)
+    );
+    assert_eq!(rest.calls(), 0);
+
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    let cached = cache.cached_quote("KRW-ETH").await.unwrap();
+    assert!(cached.is_stale);
+
+    // 스테일 값은 REST로 강제 갱신
+    let refreshed = cache.get_quote("KRW-ETH").await.unwrap();
+    assert_eq!(refreshed.current_price, dec!(4500000));
+    assert_eq!(rest.calls(), 1);
+    let cached = cache.cached_quote("KRW-ETH").await.unwrap();
+    assert_eq!(cached.source, QuoteSource::Rest);
+    assert!(!cached.is_stale);
+}
+
+#[tokio::test]
+async fn test_disconnected_stream_falls_back_to_rest() {
+    let (rest, cache, _stream, _tx) = setup(QuoteCacheConfig::default());
+    rest.set_price("AAPL", dec!(190.5));
+
+    cache
+        .apply_event(&MarketEvent::Ticker(ticker("AAPL", dec!(190.4))))
+        .await;
+    assert!(cache.is_stream_connected().await);
+    assert_eq!(
+        cache.get_quote("AAPL").await.unwrap().current_price,
+        dec!(190.4)
+    );
+
+    cache
+        .apply_event(&MarketEvent::ConnectionStatus { connected: false })
+        .await;
+    assert_eq!(
+        cache.get_quote("AAPL").await.unwrap().current_price,
+        dec!(190.5)
+    );
+    assert_eq!(rest.calls(), 1);
+}
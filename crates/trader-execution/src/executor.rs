@@ -1,33 +1,43 @@
 //! 주문 executor 구현.
 //!
 //! 제공 기능:
-//! - Signal을 OrderRequest로 변환
+//! - Signal을 OrderRequest로 변환 (시그널 메타데이터의 주문 유효 기간 반영)
+//! - 심볼별 주문 수량 규칙 적용 (최소/최대 수량, 수량 단위, 최소 주문 금액)
 //! - 주문 라우팅 및 실행
 //! - OrderManager를 통한 주문 생명주기 관리
 //! - PositionTracker를 통한 포지션 추적
 //! - 브라켓 주문 (손절/익절) 자동 관리
 //! - OCO(One-Cancels-Other) 주문 관리
+//! - 주문 유효 기간(TIF) 적용: IOC/FOK 미체결 취소, GTD 만료
+//! - 대량 주문 분할 집행 (TWAP/VWAP)
 //! - 실행 추적 및 보고
 
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
-use rust_decimal::Decimal;
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 use tracing::{debug, info, warn};
 use trader_core::{
-    Order, OrderRequest, OrderStatus, OrderStatusType, OrderType, Position, Side, Signal,
-    SignalType, TimeInForce,
+    ExchangeProvider, IdempotencyKey, Order, OrderRequest, OrderSession, OrderStatus,
+    OrderStatusType, OrderType, Position, ProviderError, Side, Signal, SignalType, SymbolOrderRule,
+    TimeInForce,
 };
-use trader_risk::RiskManager;
+use trader_risk::{CircuitBreakerHook, RiskManager};
 use uuid::Uuid;
 
 use crate::{
-    order_manager::{OrderFill, OrderManager},
+    order_manager::{OrderFill, OrderManager, TimeInForceAction},
     position_tracker::PositionTracker,
+    retry::{contains_http_5xx, RetryClass},
+    slicing::{SliceStatus, SlicedOrder, SlicingEvent},
 };
 
+/// 분할 집행 이벤트 채널 용량.
+const SLICING_EVENT_CAPACITY: usize = 256;
+
 /// 실행 오류 유형.
 #[derive(Debug, Error)]
 pub enum ExecutionError {
@@ -51,6 +61,91 @@ pub enum ExecutionError {
 
     #[error("Bracket order error: {0}")]
     BracketOrderError(String),
+
+    #[error("Network error: {0}")]
+    Network(String),
+
+    #[error("Request timeout: {0}")]
+    Timeout(String),
+
+    #[error("Exchange server error: {0}")]
+    ServerError(String),
+
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    #[error("Invalid symbol: {0}")]
+    InvalidSymbol(String),
+
+    #[error("Order size rejected: {0}")]
+    OrderSizeRejected(String),
+
+    #[error("Time-in-force {time_in_force} is not supported by {exchange}")]
+    UnsupportedTif {
+        exchange: String,
+        time_in_force: String,
+    },
+}
+
+impl ExecutionError {
+    /// 재시도 분류 반환.
+    pub fn retry_class(&self) -> RetryClass {
+        match self {
+            ExecutionError::Network(_)
+            | ExecutionError::ServerError(_)
+            | ExecutionError::RateLimited(_) => RetryClass::Retryable,
+            ExecutionError::Timeout(_) => RetryClass::Ambiguous,
+            _ => RetryClass::Fatal,
+        }
+    }
+
+    /// 재시도 가능한 에러인지 확인 (타임아웃 포함).
+    pub fn is_retryable(&self) -> bool {
+        self.retry_class() != RetryClass::Fatal
+    }
+}
+
+impl From<ProviderError> for ExecutionError {
+    /// 거래소 에러를 재시도 판단이 가능한 실행 에러로 분류.
+    ///
+    /// 거래소별로 에러 코드 체계가 달라 API 에러는 메시지 기반으로 분류합니다.
+    fn from(err: ProviderError) -> Self {
+        match err {
+            ProviderError::Timeout(msg) => ExecutionError::Timeout(msg),
+            ProviderError::Network(msg) => {
+                let lower = msg.to_lowercase();
+                if lower.contains("timeout") || lower.contains("timed out") {
+                    ExecutionError::Timeout(msg)
+                } else {
+                    ExecutionError::Network(msg)
+                }
+            }
+            ProviderError::Api(msg) => {
+                let lower = msg.to_lowercase();
+                if lower.contains("insufficient") || msg.contains("잔고 부족") {
+                    ExecutionError::InsufficientBalance
+                } else if lower.contains("invalid symbol")
+                    || lower.contains("symbol not found")
+                    || lower.contains("unknown symbol")
+                {
+                    ExecutionError::InvalidSymbol(msg)
+                } else if lower.contains("request timeout") || lower.contains("timed out") {
+                    ExecutionError::Timeout(msg)
+                } else if lower.contains("rate limit") || lower.contains("too many requests") {
+                    ExecutionError::RateLimited(msg)
+                } else if contains_http_5xx(&msg)
+                    || lower.contains("internal server error")
+                    || lower.contains("service unavailable")
+                    || lower.contains("bad gateway")
+                {
+                    ExecutionError::ServerError(msg)
+                } else {
+                    ExecutionError::ExchangeError(msg)
+                }
+            }
+            other => ExecutionError::ExchangeError(other.to_string()),
+        }
+    }
 }
 
 // ==================== 브라켓 주문 관리 ====================
@@ -336,6 +431,15 @@ pub struct ConversionConfig {
     pub auto_stop_loss: bool,
     /// 익절 주문 자동 생성
     pub auto_take_profit: bool,
+    /// 기본 주문 유효 기간 (시그널 메타데이터로 덮어쓸 수 있음)
+    #[serde(default)]
+    pub default_time_in_force: TimeInForce,
+    /// 심볼별 주문 수량 규칙
+    #[serde(default)]
+    pub size_rules: HashMap<String, OrderSizeRule>,
+    /// 심볼별 규칙이 없을 때 적용할 기본 규칙
+    #[serde(default)]
+    pub default_size_rule: Option<OrderSizeRule>,
 }
 
 impl Default for ConversionConfig {
@@ -347,10 +451,255 @@ impl Default for ConversionConfig {
             slippage_tolerance_pct: 0.1,
             auto_stop_loss: true,
             auto_take_profit: true,
+            default_time_in_force: TimeInForce::GTC,
+            size_rules: HashMap::new(),
+            default_size_rule: None,
+        }
+    }
+}
+
+impl ConversionConfig {
+    /// 심볼별 주문 수량 규칙 설정.
+    pub fn with_size_rule(mut self, ticker: impl Into<String>, rule: OrderSizeRule) -> Self {
+        self.size_rules.insert(ticker.into(), rule);
+        self
+    }
+
+    /// 기본 주문 수량 규칙 설정.
+    pub fn with_default_size_rule(mut self, rule: OrderSizeRule) -> Self {
+        self.default_size_rule = Some(rule);
+        self
+    }
+
+    /// 심볼에 적용할 주문 수량 규칙 조회.
+    pub fn size_rule(&self, ticker: &str) -> Option<&OrderSizeRule> {
+        self.size_rules
+            .get(ticker)
+            .or(self.default_size_rule.as_ref())
+    }
+
+    /// 거래소 심볼 메타데이터에서 주문 수량 규칙 로드.
+    ///
+    /// 이미 설정된 심볼은 수량 제약만 갱신하고 라운딩 방식은 유지합니다.
+    /// 새 심볼은 기본 규칙의 라운딩 방식(없으면 내림)을 사용합니다.
+    /// 로드한 규칙 개수를 반환합니다.
+    pub async fn load_size_rules(
+        &mut self,
+        provider: &dyn ExchangeProvider,
+        tickers: &[String],
+    ) -> Result<usize, ProviderError> {
+        let rules = provider.fetch_symbol_order_rules(tickers).await?;
+        let default_rounding = self
+            .default_size_rule
+            .as_ref()
+            .map(|rule| rule.rounding)
+            .unwrap_or_default();
+
+        for symbol_rule in &rules {
+            let rounding = self
+                .size_rules
+                .get(&symbol_rule.ticker)
+                .map_or(default_rounding, |rule| rule.rounding);
+            self.size_rules.insert(
+                symbol_rule.ticker.clone(),
+                OrderSizeRule::from(symbol_rule).with_rounding(rounding),
+            );
         }
+
+        info!(
+            exchange = provider.exchange_name(),
+            loaded = rules.len(),
+            "Loaded order size rules"
+        );
+        Ok(rules.len())
     }
 }
 
+/// 수량 단위에 맞지 않는 주문 수량의 처리 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuantityRounding {
+    /// 가장 가까운 단위로 반올림
+    Round,
+    /// 단위로 내림 (초과 주문 방지)
+    #[default]
+    Floor,
+    /// 단위에 맞지 않으면 주문 거부
+    Reject,
+}
+
+/// 주문 수량 규칙.
+///
+/// 적용 순서: 수량 단위 라운딩 → 최대 수량 제한 → 최소 수량 → 최소 주문 금액.
+/// 최대 수량을 넘으면 `Reject`는 거부하고, 그 외에는 최대 수량(단위 내림)으로 줄입니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderSizeRule {
+    /// 최소 주문 수량
+    #[serde(default)]
+    pub min_quantity: Option<Decimal>,
+    /// 최대 주문 수량
+    #[serde(default)]
+    pub max_quantity: Option<Decimal>,
+    /// 주문 수량 단위
+    #[serde(default)]
+    pub quantity_step: Option<Decimal>,
+    /// 최소 주문 금액 (수량 × 가격)
+    #[serde(default)]
+    pub min_notional: Option<Decimal>,
+    /// 라운딩 방식
+    #[serde(default)]
+    pub rounding: QuantityRounding,
+}
+
+impl OrderSizeRule {
+    /// 최소 주문 수량 설정.
+    pub fn with_min_quantity(mut self, min_quantity: Decimal) -> Self {
+        self.min_quantity = Some(min_quantity);
+        self
+    }
+
+    /// 최대 주문 수량 설정.
+    pub fn with_max_quantity(mut self, max_quantity: Decimal) -> Self {
+        self.max_quantity = Some(max_quantity);
+        self
+    }
+
+    /// 주문 수량 단위 설정.
+    pub fn with_quantity_step(mut self, quantity_step: Decimal) -> Self {
+        self.quantity_step = Some(quantity_step);
+        self
+    }
+
+    /// 최소 주문 금액 설정.
+    pub fn with_min_notional(mut self, min_notional: Decimal) -> Self {
+        self.min_notional = Some(min_notional);
+        self
+    }
+
+    /// 라운딩 방식 설정.
+    pub fn with_rounding(mut self, rounding: QuantityRounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// 주문 수량에 규칙 적용.
+    ///
+    /// # 인자
+    /// * `quantity` - 요청 수량
+    /// * `price` - 최소 주문 금액 판단에 사용할 가격
+    ///
+    /// # Errors
+    ///
+    /// 주문을 생성할 수 없으면 사유와 함께 `ExecutionError::OrderSizeRejected`를 반환합니다.
+    pub fn apply(&self, quantity: Decimal, price: Decimal) -> Result<Decimal, ExecutionError> {
+        let step = self.quantity_step.filter(|step| *step > Decimal::ZERO);
+
+        let mut adjusted = match step {
+            Some(step) => self.round_to_step(quantity, step)?,
+            None => quantity,
+        };
+
+        if adjusted <= Decimal::ZERO {
+            return Err(ExecutionError::OrderSizeRejected(format!(
+                "quantity {} rounds to zero with step {}",
+                quantity,
+                step.unwrap_or_default()
+            )));
+        }
+
+        if let Some(max) = self.max_quantity.filter(|max| adjusted > *max) {
+            if self.rounding == QuantityRounding::Reject {
+                return Err(ExecutionError::OrderSizeRejected(format!(
+                    "quantity {} exceeds maximum {}",
+                    adjusted, max
+                )));
+            }
+            adjusted = match step {
+                Some(step) => (max / step).floor() * step,
+                None => max,
+            };
+        }
+
+        if let Some(min) = self.min_quantity.filter(|min| adjusted < *min) {
+            return Err(ExecutionError::OrderSizeRejected(format!(
+                "quantity {} below minimum {}",
+                adjusted, min
+            )));
+        }
+
+        if let Some(min_notional) = self.min_notional {
+            let notional = adjusted * price;
+            if price > Decimal::ZERO && notional < min_notional {
+                return Err(ExecutionError::OrderSizeRejected(format!(
+                    "notional {} below minimum {}",
+                    notional, min_notional
+                )));
+            }
+        }
+
+        Ok(adjusted)
+    }
+
+    /// 수량 단위에 맞춰 라운딩.
+    fn round_to_step(&self, quantity: Decimal, step: Decimal) -> Result<Decimal, ExecutionError> {
+        let units = quantity / step;
+        match self.rounding {
+            QuantityRounding::Round => {
+                Ok(units.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero) * step)
+            }
+            QuantityRounding::Floor => Ok(units.floor() * step),
+            QuantityRounding::Reject if units.fract().is_zero() => Ok(quantity),
+            QuantityRounding::Reject => Err(ExecutionError::OrderSizeRejected(format!(
+                "quantity {} is not a multiple of step {}",
+                quantity, step
+            ))),
+        }
+    }
+}
+
+impl From<&SymbolOrderRule> for OrderSizeRule {
+    fn from(rule: &SymbolOrderRule) -> Self {
+        Self {
+            min_quantity: rule.min_quantity,
+            max_quantity: rule.max_quantity,
+            quantity_step: rule.quantity_step,
+            min_notional: rule.min_notional,
+            rounding: QuantityRounding::default(),
+        }
+    }
+}
+
+/// 시그널 메타데이터의 주문 유효 기간 키 (`GTC`, `IOC`, `FOK`, `GTD`).
+pub const TIME_IN_FORCE_METADATA_KEY: &str = trader_core::TIME_IN_FORCE_KEY;
+
+/// 시그널 메타데이터의 GTD 만료 시각 키 (RFC 3339).
+pub const EXPIRE_AT_METADATA_KEY: &str = trader_core::EXPIRE_AT_KEY;
+
+/// 시그널 메타데이터에서 추출한 주문 옵션.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalOrderMetadata {
+    /// 주문 유효 기간
+    pub time_in_force: TimeInForce,
+}
+
+/// 시그널 메타데이터를 주문 옵션으로 변환.
+///
+/// `time_in_force` 키가 없으면 설정의 기본값을 사용합니다.
+/// `GTD`는 `expire_at`(RFC 3339) 키가 함께 있어야 하며, 이미 지난 시각이면 거부합니다.
+pub fn convert_signal_order_metadata(
+    signal: &Signal,
+    config: &ConversionConfig,
+    now: DateTime<Utc>,
+) -> Result<SignalOrderMetadata, ExecutionError> {
+    let time_in_force = signal
+        .typed_metadata()
+        .time_in_force(now)
+        .map_err(|e| ExecutionError::InvalidSignal(e.to_string()))?
+        .unwrap_or(config.default_time_in_force);
+
+    Ok(SignalOrderMetadata { time_in_force })
+}
+
 /// Signal에 대한 실행 결과.
 #[derive(Debug, Clone)]
 pub struct ExecutionResult {
@@ -475,6 +824,8 @@ impl SignalConverter {
             )));
         }
 
+        let metadata = convert_signal_order_metadata(signal, &self.config, Utc::now())?;
+
         // 수량 결정 (Signal에는 수량이 없음 - 전달받거나 기본값 사용)
         let qty = quantity.unwrap_or(self.config.default_quantity);
 
@@ -511,6 +862,23 @@ impl SignalConverter {
             }
         };
 
+        // 심볼별 주문 수량 규칙 적용 (지정가 주문은 지정가 기준 최소 금액 판단)
+        let qty = match self.config.size_rule(&signal.ticker) {
+            Some(rule) => rule
+                .apply(qty, price.unwrap_or(current_price))
+                .map_err(|e| {
+                    warn!(
+                        ticker = %signal.ticker,
+                        signal_id = %signal.id,
+                        quantity = %qty,
+                        reason = %e,
+                        "Order not created: size rule rejected quantity"
+                    );
+                    e
+                })?,
+            None => qty,
+        };
+
         // 주문 요청 구성
         let order = OrderRequest {
             ticker: signal.ticker.clone(),
@@ -519,9 +887,11 @@ impl SignalConverter {
             quantity: qty,
             price,
             stop_price,
-            time_in_force: TimeInForce::GTC,
-            client_order_id: Some(format!("sig_{}", signal.id)),
+            time_in_force: metadata.time_in_force,
+            client_order_id: Some(IdempotencyKey::order("sig", signal.id).client_order_id()),
             strategy_id: Some(signal.strategy_id.clone()),
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
         Ok(order)
@@ -572,6 +942,7 @@ impl SignalConverter {
 /// 이 executor는 거래소에 독립적으로 설계되었습니다.
 /// 실제 주문 제출은 호출자가 적절한 거래소 커넥터를 통해 수행해야 합니다.
 /// `handle_fill_with_brackets()`는 제출할 브라켓 주문을 반환합니다.
+/// 분할 집행은 `release_due_slices()`가 제출할 자식 주문을 반환합니다.
 pub struct OrderExecutor {
     /// Signal 변환기
     converter: SignalConverter,
@@ -587,6 +958,12 @@ pub struct OrderExecutor {
     config: ConversionConfig,
     /// 거래소 식별자
     exchange: String,
+    /// 거래소가 지원하는 주문 유효 기간 (None이면 제한 없음)
+    supported_time_in_force: Option<Vec<TimeInForce>>,
+    /// 분할 집행 중인 부모 주문 (부모 주문 ID -> 상태)
+    sliced_orders: Arc<RwLock<HashMap<Uuid, SlicedOrder>>>,
+    /// 분할 집행 진행 이벤트
+    slicing_events: broadcast::Sender<SlicingEvent>,
 }
 
 impl OrderExecutor {
@@ -606,6 +983,39 @@ impl OrderExecutor {
             bracket_manager: Arc::new(RwLock::new(BracketOrderManager::new())),
             config,
             exchange,
+            supported_time_in_force: None,
+            sliced_orders: Arc::new(RwLock::new(HashMap::new())),
+            slicing_events: broadcast::channel(SLICING_EVENT_CAPACITY).0,
+        }
+    }
+
+    /// 거래소가 지원하는 주문 유효 기간 설정.
+    ///
+    /// GTD는 만료 시각과 무관하게 코드로 비교합니다.
+    /// 지원하지 않는 유효 기간의 신호는 `ExecutionError::UnsupportedTif`로 실패합니다.
+    pub fn with_supported_time_in_force(mut self, supported: Vec<TimeInForce>) -> Self {
+        self.supported_time_in_force = Some(supported);
+        self
+    }
+
+    /// 주문 유효 기간 지원 여부 확인.
+    pub fn ensure_time_in_force_supported(
+        &self,
+        time_in_force: &TimeInForce,
+    ) -> Result<(), ExecutionError> {
+        let supported = match &self.supported_time_in_force {
+            Some(list) => list
+                .iter()
+                .any(|candidate| candidate.code() == time_in_force.code()),
+            None => true,
+        };
+        if supported {
+            Ok(())
+        } else {
+            Err(ExecutionError::UnsupportedTif {
+                exchange: self.exchange.clone(),
+                time_in_force: time_in_force.code().to_string(),
+            })
         }
     }
 
@@ -653,6 +1063,10 @@ impl OrderExecutor {
             Err(e) => return ExecutionResult::failure(signal.id, e.to_string()),
         };
 
+        if let Err(e) = self.ensure_time_in_force_supported(&order_request.time_in_force) {
+            return ExecutionResult::failure(signal.id, e.to_string());
+        }
+
         // PositionTracker에서 현재 포지션 조회
         let positions: Vec<Position> = {
             let tracker = self.position_tracker.read().await;
@@ -820,6 +1234,9 @@ impl OrderExecutor {
             }
         }
 
+        // 분할 집행 자식 주문이면 부모 진행률 갱신
+        self.record_slice_fill(order_id, &fill).await;
+
         Ok(())
     }
 
@@ -919,6 +1336,213 @@ impl OrderExecutor {
             .map_err(|e| ExecutionError::ExecutionFailed(e.to_string()))
     }
 
+    /// TWAP 분할 집행 시작.
+    ///
+    /// `duration`을 `slices`개 구간으로 나눠 같은 수량씩 집행합니다.
+    /// 심볼의 주문 수량 단위가 있으면 슬라이스 수량을 단위에 맞춥니다.
+    /// 반환된 부모 주문 ID로 진행 상황을 조회하며, 자식 주문은
+    /// `release_due_slices()`를 주기적으로 호출해 받아 제출해야 합니다.
+    pub async fn execute_twap(
+        &self,
+        order: OrderRequest,
+        duration: Duration,
+        slices: usize,
+    ) -> Result<Uuid, ExecutionError> {
+        let step = self.quantity_step(&order.ticker);
+        let sliced = SlicedOrder::twap(order, duration, slices, step, Utc::now())?;
+        Ok(self.start_slicing(sliced).await)
+    }
+
+    /// VWAP 분할 집행 시작.
+    ///
+    /// `volume_profile`은 집행 기간을 균등 분할한 구간별 예상 거래량으로,
+    /// 일반적으로 과거 같은 시간대의 평균 거래량을 사용합니다.
+    pub async fn execute_vwap(
+        &self,
+        order: OrderRequest,
+        duration: Duration,
+        volume_profile: &[Decimal],
+    ) -> Result<Uuid, ExecutionError> {
+        let step = self.quantity_step(&order.ticker);
+        let sliced = SlicedOrder::vwap(order, duration, volume_profile, step, Utc::now())?;
+        Ok(self.start_slicing(sliced).await)
+    }
+
+    /// 심볼의 주문 수량 단위.
+    fn quantity_step(&self, ticker: &str) -> Option<Decimal> {
+        self.config
+            .size_rule(ticker)
+            .and_then(|rule| rule.quantity_step)
+    }
+
+    async fn start_slicing(&self, sliced: SlicedOrder) -> Uuid {
+        let parent_id = sliced.parent_id;
+        info!(
+            parent_id = %parent_id,
+            algorithm = ?sliced.algorithm,
+            ticker = %sliced.request.ticker,
+            quantity = %sliced.request.quantity,
+            slices = sliced.slices.len(),
+            "분할 집행 시작"
+        );
+        self.sliced_orders.write().await.insert(parent_id, sliced);
+        parent_id
+    }
+
+    /// 예정 시각이 된 슬라이스의 자식 주문 생성.
+    ///
+    /// 자식 주문을 OrderManager에 등록하고 (내부 주문 ID, 주문 요청) 목록을 반환합니다.
+    /// 반환된 주문은 호출자가 거래소에 제출한 후 `submit_order()`로 알려야 합니다.
+    /// 등록에 실패한 슬라이스는 `Pending`으로 남아 다음 호출에서 다시 집행되고,
+    /// 완료되거나 취소된 분할 집행은 정리됩니다.
+    pub async fn release_due_slices(&self, now: DateTime<Utc>) -> Vec<(Uuid, OrderRequest)> {
+        let mut released = Vec::new();
+        let mut sliced_orders = self.sliced_orders.write().await;
+        let mut order_manager = self.order_manager.write().await;
+
+        sliced_orders.retain(|_, sliced| sliced.is_running());
+
+        for sliced in sliced_orders.values_mut() {
+            for pos in sliced.due_slices(now) {
+                let slice = &mut sliced.slices[pos];
+                let request = OrderRequest {
+                    quantity: slice.quantity,
+                    client_order_id: Some(
+                        IdempotencyKey::order(&format!("slc{}", slice.index), sliced.parent_id)
+                            .client_order_id(),
+                    ),
+                    ..sliced.request.clone()
+                };
+
+                let order = Order::from_request(request.clone(), &self.exchange);
+                let order_id = order.id;
+                if let Err(e) = order_manager.add_order(order) {
+                    warn!(
+                        parent_id = %sliced.parent_id,
+                        slice_index = slice.index,
+                        error = %e,
+                        "자식 주문 등록 실패, 다음 호출에서 재시도"
+                    );
+                    continue;
+                }
+
+                slice.order_id = Some(order_id);
+                slice.status = SliceStatus::Released;
+                let _ = self.slicing_events.send(SlicingEvent::SliceReleased {
+                    parent_id: sliced.parent_id,
+                    slice_index: slice.index,
+                    order_id,
+                    quantity: slice.quantity,
+                });
+                released.push((order_id, request));
+            }
+        }
+
+        released
+    }
+
+    /// 자식 주문 체결을 부모 주문에 반영하고 진행률 이벤트 방출.
+    async fn record_slice_fill(&self, order_id: Uuid, fill: &OrderFill) {
+        let mut sliced_orders = self.sliced_orders.write().await;
+        let Some(sliced) = sliced_orders
+            .values_mut()
+            .find(|s| s.slice_for_order(order_id).is_some())
+        else {
+            return;
+        };
+
+        sliced.record_fill(order_id, fill.quantity, fill.price);
+        let event = sliced.progress_event();
+        if matches!(event, SlicingEvent::Completed { .. }) {
+            info!(
+                parent_id = %sliced.parent_id,
+                average_price = ?sliced.average_price(),
+                "분할 집행 완료"
+            );
+        }
+        let _ = self.slicing_events.send(event);
+    }
+
+    /// 분할 집행 취소.
+    ///
+    /// 이미 체결된 수량은 유지하고 남은 슬라이스만 취소합니다.
+    /// 거래소에서 취소해야 하는 미체결 자식 주문 ID를 반환합니다.
+    pub async fn cancel_sliced_order(
+        &self,
+        parent_id: Uuid,
+        reason: Option<String>,
+    ) -> Result<Vec<Uuid>, ExecutionError> {
+        let mut sliced_orders = self.sliced_orders.write().await;
+        let sliced = sliced_orders.get_mut(&parent_id).ok_or_else(|| {
+            ExecutionError::ExecutionFailed(format!("Sliced order {} not found", parent_id))
+        })?;
+        if !sliced.is_running() {
+            return Ok(Vec::new());
+        }
+
+        let remaining = sliced
+            .slices
+            .iter()
+            .filter(|s| matches!(s.status, SliceStatus::Pending | SliceStatus::Released))
+            .count();
+        let open_children = sliced.cancel_remaining();
+
+        {
+            let mut order_manager = self.order_manager.write().await;
+            for child_id in &open_children {
+                if let Err(e) = order_manager.cancel_order(*child_id, reason.clone()) {
+                    debug!(order_id = %child_id, error = %e, "자식 주문 취소 건너뜀");
+                }
+            }
+        }
+
+        info!(
+            parent_id = %parent_id,
+            filled_quantity = %sliced.filled_quantity,
+            cancelled_slices = remaining,
+            "분할 집행 취소"
+        );
+        let _ = self.slicing_events.send(SlicingEvent::Cancelled {
+            parent_id,
+            filled_quantity: sliced.filled_quantity,
+            cancelled_slices: remaining,
+        });
+
+        Ok(open_children)
+    }
+
+    /// 분할 집행 상태 조회.
+    ///
+    /// 완료/취소된 분할 집행은 다음 `release_due_slices()` 호출 전까지만 조회됩니다.
+    pub async fn get_sliced_order(&self, parent_id: Uuid) -> Option<SlicedOrder> {
+        self.sliced_orders.read().await.get(&parent_id).cloned()
+    }
+
+    /// 분할 집행 진행 이벤트 구독.
+    pub fn subscribe_slicing_events(&self) -> broadcast::Receiver<SlicingEvent> {
+        self.slicing_events.subscribe()
+    }
+
+    /// 활성 주문에 유효 기간(TIF) 규칙 적용.
+    ///
+    /// IOC/FOK 미체결 주문은 취소, 만료 시각이 지난 GTD 주문은 만료 처리하고
+    /// 해당 주문의 브라켓을 제거합니다. 반환된 주문은 호출자가 거래소에서 취소해야 합니다.
+    pub async fn enforce_time_in_force(&self) -> Vec<TimeInForceAction> {
+        let actions = {
+            let mut order_manager = self.order_manager.write().await;
+            order_manager.enforce_time_in_force(Utc::now())
+        };
+
+        if !actions.is_empty() {
+            let mut bracket_manager = self.bracket_manager.write().await;
+            for action in &actions {
+                bracket_manager.remove_bracket(action.order_id);
+            }
+        }
+
+        actions
+    }
+
     /// 모든 포지션의 시장 가격 업데이트.
     ///
     /// # 인자
@@ -982,6 +1606,11 @@ impl OrderExecutor {
         &self.position_tracker
     }
 
+    /// 리스크 관리자 참조 조회.
+    pub fn risk_manager(&self) -> &Arc<RwLock<RiskManager>> {
+        &self.risk_manager
+    }
+
     /// 여러 신호 처리.
     pub async fn process_signals(
         &self,
@@ -1017,6 +1646,12 @@ impl OrderExecutor {
         rm.record_pnl(symbol, amount);
     }
 
+    /// 일일 손실 서킷브레이커 발동 훅 설정.
+    pub async fn set_circuit_breaker_hook(&self, hook: Arc<dyn CircuitBreakerHook>) {
+        let mut rm = self.risk_manager.write().await;
+        rm.set_circuit_breaker_hook(hook);
+    }
+
     /// 거래 허용 여부 확인.
     pub async fn can_trade(&self) -> bool {
         let mut rm = self.risk_manager.write().await;
@@ -1048,6 +1683,44 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_provider_error_retry_classification() {
+        let retryable = [
+            ProviderError::Network("connection refused".to_string()),
+            ProviderError::Api("API error 503: Service Unavailable".to_string()),
+            ProviderError::Api("Rate limit exceeded".to_string()),
+        ];
+        for err in retryable {
+            assert_eq!(
+                ExecutionError::from(err).retry_class(),
+                RetryClass::Retryable
+            );
+        }
+
+        let ambiguous = [
+            ProviderError::Timeout("read timeout".to_string()),
+            ProviderError::Network("operation timed out".to_string()),
+            ProviderError::Api("주문 실패: Request timeout: deadline".to_string()),
+        ];
+        for err in ambiguous {
+            assert_eq!(
+                ExecutionError::from(err).retry_class(),
+                RetryClass::Ambiguous
+            );
+        }
+
+        let fatal = [
+            ProviderError::Api("Insufficient balance: USDT".to_string()),
+            ProviderError::Api("Invalid symbol.".to_string()),
+            ProviderError::Api("주문 수량 1500 초과".to_string()),
+            ProviderError::Authentication("bad key".to_string()),
+        ];
+        for err in fatal {
+            let err = ExecutionError::from(err);
+            assert!(!err.is_retryable(), "{} should be fatal", err);
+        }
+    }
+
     fn create_test_signal(side: Side, signal_type: SignalType) -> Signal {
         Signal::new("test_strategy", "BTC/USDT".to_string(), side, signal_type).with_strength(0.8)
     }
@@ -1156,6 +1829,78 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_order_size_rule_rounding() {
+        let rule = OrderSizeRule::default()
+            .with_quantity_step(dec!(0.01))
+            .with_min_quantity(dec!(0.01))
+            .with_max_quantity(dec!(1));
+
+        // 내림 (기본)
+        assert_eq!(
+            rule.apply(Decimal::new(1259, 4), dec!(50000)).unwrap(),
+            Decimal::new(12, 2)
+        );
+
+        // 반올림
+        let round = rule.clone().with_rounding(QuantityRounding::Round);
+        assert_eq!(
+            round.apply(Decimal::new(1259, 4), dec!(50000)).unwrap(),
+            Decimal::new(13, 2)
+        );
+
+        // 거부: 단위에 맞지 않으면 실패, 최대 수량 초과도 실패
+        let reject = rule.clone().with_rounding(QuantityRounding::Reject);
+        assert!(matches!(
+            reject.apply(Decimal::new(1259, 4), dec!(50000)),
+            Err(ExecutionError::OrderSizeRejected(_))
+        ));
+        assert!(reject.apply(dec!(2), dec!(50000)).is_err());
+
+        // 최대 수량 초과는 최대 수량으로 제한
+        assert_eq!(rule.apply(dec!(2), dec!(50000)).unwrap(), dec!(1));
+
+        // 내림 결과가 0이면 주문 생성 안 함
+        assert!(matches!(
+            rule.apply(Decimal::new(5, 3), dec!(50000)),
+            Err(ExecutionError::OrderSizeRejected(_))
+        ));
+    }
+
+    #[test]
+    fn test_signal_converter_applies_size_rule() {
+        let config = ConversionConfig::default().with_size_rule(
+            "BTC/USDT",
+            OrderSizeRule::default()
+                .with_quantity_step(Decimal::new(1, 3))
+                .with_min_notional(dec!(10)),
+        );
+        let converter = SignalConverter::new(config);
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        let order = converter
+            .convert(&signal, dec!(50000), Some(Decimal::new(12345, 5)))
+            .unwrap();
+        assert_eq!(order.quantity, Decimal::new(123, 3));
+
+        // 최소 주문 금액 미달 (0.0001 × 50000 = 5)
+        let result = converter.convert(&signal, dec!(50000), Some(Decimal::new(1, 4)));
+        assert!(matches!(result, Err(ExecutionError::OrderSizeRejected(_))));
+
+        // 규칙이 없는 심볼은 그대로 변환
+        let other = Signal::new(
+            "test_strategy",
+            "ETH/USDT".to_string(),
+            Side::Buy,
+            SignalType::Entry,
+        )
+        .with_strength(0.8);
+        let order = converter
+            .convert(&other, dec!(3000), Some(Decimal::new(12345, 5)))
+            .unwrap();
+        assert_eq!(order.quantity, Decimal::new(12345, 5));
+    }
+
     #[test]
     fn test_execution_result_builder() {
         let signal_id = Uuid::new_v4();
@@ -1331,6 +2076,165 @@ mod tests {
         assert_eq!(active_orders.len(), 3);
     }
 
+    #[test]
+    fn test_convert_signal_order_metadata_time_in_force() {
+        let config = ConversionConfig {
+            default_time_in_force: TimeInForce::IOC,
+            ..Default::default()
+        };
+        let now = Utc::now();
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        // 메타데이터 없으면 설정 기본값
+        let metadata = convert_signal_order_metadata(&signal, &config, now).unwrap();
+        assert_eq!(metadata.time_in_force, TimeInForce::IOC);
+
+        let fok = signal
+            .clone()
+            .with_metadata(TIME_IN_FORCE_METADATA_KEY, serde_json::json!("fok"));
+        let metadata = convert_signal_order_metadata(&fok, &config, now).unwrap();
+        assert_eq!(metadata.time_in_force, TimeInForce::FOK);
+
+        let expire_at = now + chrono::Duration::hours(1);
+        let gtd = signal
+            .clone()
+            .with_metadata(TIME_IN_FORCE_METADATA_KEY, serde_json::json!("GTD"))
+            .with_metadata(
+                EXPIRE_AT_METADATA_KEY,
+                serde_json::json!(expire_at.to_rfc3339()),
+            );
+        let metadata = convert_signal_order_metadata(&gtd, &config, now).unwrap();
+        assert_eq!(metadata.time_in_force.expires_at(), Some(expire_at));
+
+        // 만료 시각 누락 / 과거 시각 / 알 수 없는 코드는 거부
+        let missing = signal
+            .clone()
+            .with_metadata(TIME_IN_FORCE_METADATA_KEY, serde_json::json!("GTD"));
+        let past = missing.clone().with_metadata(
+            EXPIRE_AT_METADATA_KEY,
+            serde_json::json!((now - chrono::Duration::minutes(1)).to_rfc3339()),
+        );
+        let unknown = signal.with_metadata(TIME_IN_FORCE_METADATA_KEY, serde_json::json!("DAY"));
+        for invalid in [missing, past, unknown] {
+            assert!(matches!(
+                convert_signal_order_metadata(&invalid, &config, now),
+                Err(ExecutionError::InvalidSignal(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_rejects_unsupported_tif() {
+        let executor = create_test_executor(dec!(0.01))
+            .with_supported_time_in_force(vec![TimeInForce::GTC, TimeInForce::IOC]);
+
+        let ioc = create_test_signal(Side::Buy, SignalType::Entry)
+            .with_metadata(TIME_IN_FORCE_METADATA_KEY, serde_json::json!("IOC"));
+        let result = executor.process_signal(&ioc, dec!(50000)).await;
+        assert!(result.success);
+        assert_eq!(result.order.unwrap().time_in_force, TimeInForce::IOC);
+
+        let fok = create_test_signal(Side::Buy, SignalType::Entry)
+            .with_metadata(TIME_IN_FORCE_METADATA_KEY, serde_json::json!("FOK"));
+        let result = executor.process_signal(&fok, dec!(50000)).await;
+        assert!(!result.success);
+        assert!(result.order_id.is_none());
+        assert!(matches!(
+            executor.ensure_time_in_force_supported(&TimeInForce::FOK),
+            Err(ExecutionError::UnsupportedTif { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_enforces_ioc_cancel() {
+        let executor = create_test_executor(dec!(0.01));
+        let signal = create_test_signal(Side::Buy, SignalType::Entry)
+            .with_metadata(TIME_IN_FORCE_METADATA_KEY, serde_json::json!("IOC"));
+        let order_id = executor
+            .process_signal(&signal, dec!(50000))
+            .await
+            .order_id
+            .unwrap();
+
+        // 제출 전에는 취소하지 않음
+        assert!(executor.enforce_time_in_force().await.is_empty());
+
+        executor
+            .submit_order(order_id, "EX_IOC".to_string())
+            .await
+            .unwrap();
+        let actions = executor.enforce_time_in_force().await;
+
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].exchange_order_id.as_deref(), Some("EX_IOC"));
+        assert_eq!(actions[0].status, OrderStatusType::Cancelled);
+        assert_eq!(executor.active_bracket_count().await, 0);
+        assert_eq!(
+            executor.get_order(order_id).await.unwrap().status,
+            OrderStatusType::Cancelled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_twap_slicing() {
+        let executor = create_test_executor(dec!(0.1));
+        let mut events = executor.subscribe_slicing_events();
+        let order = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(3));
+
+        let parent_id = executor
+            .execute_twap(order, Duration::from_secs(120), 3)
+            .await
+            .unwrap();
+
+        // 첫 슬라이스만 즉시 집행 대상
+        let released = executor.release_due_slices(Utc::now()).await;
+        assert_eq!(released.len(), 1);
+        let (child_id, child) = &released[0];
+        assert_eq!(child.quantity, dec!(1));
+        assert!(executor.get_order(*child_id).await.is_some());
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            SlicingEvent::SliceReleased { slice_index: 0, .. }
+        ));
+
+        let fill = OrderFill {
+            order_id: *child_id,
+            quantity: dec!(1),
+            price: dec!(50000),
+            commission: None,
+            commission_asset: None,
+            timestamp: Utc::now(),
+        };
+        executor.handle_fill(*child_id, fill, true).await.unwrap();
+
+        let sliced = executor.get_sliced_order(parent_id).await.unwrap();
+        assert_eq!(sliced.filled_quantity, dec!(1));
+        assert_eq!(sliced.average_price(), Some(dec!(50000)));
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            SlicingEvent::Progress { .. }
+        ));
+
+        // 두 번째 슬라이스 집행 후 취소: 체결분 유지, 미체결 자식 주문과 남은 슬라이스 취소
+        let released = executor
+            .release_due_slices(Utc::now() + chrono::Duration::seconds(40))
+            .await;
+        assert_eq!(released.len(), 1);
+
+        let to_cancel = executor.cancel_sliced_order(parent_id, None).await.unwrap();
+        assert_eq!(to_cancel, vec![released[0].0]);
+
+        let sliced = executor.get_sliced_order(parent_id).await.unwrap();
+        assert_eq!(sliced.filled_quantity, dec!(1));
+        assert!(!sliced.is_running());
+        assert!(executor
+            .release_due_slices(Utc::now() + chrono::Duration::seconds(120))
+            .await
+            .is_empty());
+        // 취소된 분할 집행은 다음 집행 주기에서 정리
+        assert!(executor.get_sliced_order(parent_id).await.is_none());
+    }
+
     #[test]
     fn test_is_entry_exit_signal() {
         assert!(SignalConverter::is_entry_signal(&SignalType::Entry));
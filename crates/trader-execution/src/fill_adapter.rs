@@ -0,0 +1,725 @@
+//! 거래소 주문 상태 스트림의 체결 반영 어댑터.
+//!
+//! [`OrderUpdateProvider`](trader_core::OrderUpdateProvider)가 내보내는 누적 체결 기반 [`OrderUpdate`]를 체결 단위
+//! [`OrderFill`]로 변환하여 [`OrderManager`]와 [`PositionTracker`]에 반영합니다.
+//!
+//! - 누적 체결수량의 증가분만 새 체결로 기록하고, 체결가는 평균 체결가 변화로 역산
+//! - 취소/거부/만료는 주문 상태로만 반영 (이미 체결된 수량은 포지션에 유지)
+//! - 정정으로 거래소 주문번호가 바뀌면 [`OrderFillAdapter::register_amendment`]로
+//!   새 번호를 같은 주문에 연결하고, 원주문번호의 최종 상태 이벤트는 무시
+//! - 주문번호가 등록되기 전에 도착한 이벤트(접수 응답보다 먼저 온 체결)는 버퍼에 보관했다가
+//!   주문번호가 등록되면 도착 순서대로 재처리
+//!
+//! DB증권처럼 WebSocket 체결통보가 없는 거래소는 폴링 스트림을 연결합니다.
+//!
+//! ```rust,ignore
+//! let updater = provider.order_update_stream(DEFAULT_POLL_INTERVAL);
+//! let _poller = Arc::clone(&updater).spawn();
+//!
+//! let adapter = Arc::new(OrderFillAdapter::new(order_manager, position_tracker));
+//! let _handle = Arc::clone(&adapter).spawn(updater.subscribe_order_updates());
+//!
+//! let response = provider.place_order(&request).await?;
+//! adapter.register_submission(order.id, &response.order_no).await?;
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use thiserror::Error;
+use tokio::{
+    sync::{broadcast, Mutex, RwLock},
+    task::JoinHandle,
+};
+use tracing::{debug, warn};
+use trader_core::{Order, OrderStatus, OrderStatusType, OrderUpdate};
+use uuid::Uuid;
+
+use crate::{
+    order_manager::{OrderFill, OrderManager, OrderManagerError},
+    position_tracker::{PositionTracker, PositionTrackerError},
+};
+
+/// 미등록 주문 이벤트의 기본 보관 기간.
+pub const DEFAULT_BUFFER_TTL: Duration = Duration::from_secs(300);
+
+/// 버퍼에 보관하는 최대 거래소 주문 수.
+const MAX_BUFFERED_ORDERS: usize = 1_000;
+
+/// 체결 어댑터 에러.
+#[derive(Debug, Error)]
+pub enum FillAdapterError {
+    #[error("Order manager error: {0}")]
+    OrderManager(#[from] OrderManagerError),
+
+    #[error("Position tracker error: {0}")]
+    PositionTracker(#[from] PositionTrackerError),
+
+    #[error("Unknown exchange order: {0}")]
+    UnknownExchangeOrder(String),
+
+    #[error("Fill price unavailable for exchange order: {0}")]
+    MissingFillPrice(String),
+}
+
+/// 이벤트 처리 결과.
+#[derive(Debug, Clone)]
+pub enum FillUpdateOutcome {
+    /// 주문에 반영됨 (새 체결이 있으면 포함)
+    Applied {
+        /// 내부 주문 ID
+        order_id: Uuid,
+        /// 이번 이벤트로 생성된 체결
+        fill: Option<OrderFill>,
+    },
+    /// 주문번호 미등록으로 버퍼에 보관됨
+    Buffered,
+    /// 중복·역행 이벤트라 무시됨
+    Ignored,
+}
+
+/// 거래소 주문번호별로 반영한 누적 체결.
+#[derive(Debug, Clone, Copy, Default)]
+struct AppliedFill {
+    quantity: Decimal,
+    notional: Decimal,
+}
+
+/// 버퍼에 보관된 이벤트.
+#[derive(Debug, Clone)]
+struct BufferedUpdate {
+    update: OrderUpdate,
+    received_at: DateTime<Utc>,
+}
+
+/// 어댑터 내부 상태.
+#[derive(Debug, Default)]
+struct AdapterState {
+    /// 거래소 주문번호 → 반영한 누적 체결
+    applied: HashMap<String, AppliedFill>,
+    /// 정정으로 대체된 원주문번호
+    superseded: HashSet<String>,
+    /// 거래소 주문번호 → 미등록 상태로 도착한 이벤트 (도착 순)
+    buffer: HashMap<String, Vec<BufferedUpdate>>,
+}
+
+/// 주문 상태 스트림 → OrderManager/PositionTracker 체결 반영 어댑터.
+pub struct OrderFillAdapter {
+    order_manager: Arc<RwLock<OrderManager>>,
+    position_tracker: Arc<RwLock<PositionTracker>>,
+    buffer_ttl: Duration,
+    state: Mutex<AdapterState>,
+}
+
+impl OrderFillAdapter {
+    /// 새 어댑터 생성.
+    pub fn new(
+        order_manager: Arc<RwLock<OrderManager>>,
+        position_tracker: Arc<RwLock<PositionTracker>>,
+    ) -> Self {
+        Self {
+            order_manager,
+            position_tracker,
+            buffer_ttl: DEFAULT_BUFFER_TTL,
+            state: Mutex::new(AdapterState::default()),
+        }
+    }
+
+    /// 미등록 주문 이벤트 보관 기간 설정 (빌더 패턴).
+    pub fn with_buffer_ttl(mut self, ttl: Duration) -> Self {
+        self.buffer_ttl = ttl;
+        self
+    }
+
+    /// 버퍼에 보관 중인 이벤트 수.
+    pub async fn buffered_count(&self) -> usize {
+        self.state.lock().await.buffer.values().map(Vec::len).sum()
+    }
+
+    /// 주문 상태 이벤트 하나를 처리합니다.
+    ///
+    /// 주문번호로 주문을 찾지 못하면 클라이언트 주문 ID로 활성 주문을 찾고,
+    /// 그래도 없으면 버퍼에 보관합니다.
+    pub async fn handle_update(
+        &self,
+        update: OrderUpdate,
+    ) -> Result<FillUpdateOutcome, FillAdapterError> {
+        let mut state = self.state.lock().await;
+        self.prune_buffer(&mut state);
+
+        let resolved = {
+            let order_manager = self.order_manager.read().await;
+            resolve_order(&order_manager, &update)
+        };
+
+        let Some((order_id, needs_bind)) = resolved else {
+            debug!(
+                exchange_order_id = %update.order_id,
+                status = ?update.status,
+                "미등록 주문 이벤트 버퍼링"
+            );
+            Self::buffer_update(&mut state, update);
+            return Ok(FillUpdateOutcome::Buffered);
+        };
+
+        if needs_bind {
+            self.order_manager
+                .write()
+                .await
+                .bind_exchange_order_id(order_id, &update.order_id)?;
+        }
+
+        // 먼저 도착해 보관된 이벤트부터 순서대로 반영
+        for buffered in state.buffer.remove(&update.order_id).unwrap_or_default() {
+            self.apply_update(&mut state, order_id, &buffered.update)
+                .await?;
+        }
+        self.apply_update(&mut state, order_id, &update).await
+    }
+
+    /// 주문 접수 결과(거래소 주문번호)를 등록하고 보관된 이벤트를 재처리합니다.
+    pub async fn register_submission(
+        &self,
+        order_id: Uuid,
+        exchange_order_id: &str,
+    ) -> Result<Vec<FillUpdateOutcome>, FillAdapterError> {
+        let mut state = self.state.lock().await;
+        {
+            let mut order_manager = self.order_manager.write().await;
+            let order = order_manager
+                .get_order(order_id)
+                .cloned()
+                .ok_or(OrderManagerError::OrderNotFound(order_id))?;
+
+            if order.status == OrderStatusType::Pending {
+                let status =
+                    order_status(&order, exchange_order_id, OrderStatusType::Open, Utc::now());
+                order_manager.update_status(order_id, &status)?;
+            } else {
+                order_manager.bind_exchange_order_id(order_id, exchange_order_id)?;
+            }
+        }
+
+        self.replay(&mut state, order_id, exchange_order_id).await
+    }
+
+    /// 정정으로 바뀐 거래소 주문번호를 원주문과 같은 주문에 연결합니다.
+    ///
+    /// 이후 원주문번호의 최종 상태(정정에 따른 취소 등)는 주문 상태에 반영하지 않고,
+    /// 원주문번호로 늦게 도착한 체결만 반영합니다.
+    pub async fn register_amendment(
+        &self,
+        original_exchange_order_id: &str,
+        new_exchange_order_id: &str,
+    ) -> Result<Vec<FillUpdateOutcome>, FillAdapterError> {
+        let mut state = self.state.lock().await;
+        let order_id = {
+            let mut order_manager = self.order_manager.write().await;
+            let order_id = order_manager
+                .get_order_by_exchange_id(original_exchange_order_id)
+                .map(|o| o.id)
+                .ok_or_else(|| {
+                    FillAdapterError::UnknownExchangeOrder(original_exchange_order_id.to_string())
+                })?;
+            order_manager.bind_exchange_order_id(order_id, new_exchange_order_id)?;
+            order_id
+        };
+        state
+            .superseded
+            .insert(original_exchange_order_id.to_string());
+
+        self.replay(&mut state, order_id, new_exchange_order_id)
+            .await
+    }
+
+    /// 주문 상태 스트림을 구독하여 처리하는 백그라운드 태스크 시작.
+    ///
+    /// 이벤트가 누적 체결 기반이므로 수신 지연으로 일부 이벤트를 놓쳐도
+    /// 다음 이벤트에서 체결 수량이 보정됩니다. 스트림이 닫히면 태스크도 종료됩니다.
+    pub fn spawn(self: Arc<Self>, mut updates: broadcast::Receiver<OrderUpdate>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match updates.recv().await {
+                    Ok(update) => {
+                        let exchange_order_id = update.order_id.clone();
+                        if let Err(e) = self.handle_update(update).await {
+                            warn!(
+                                exchange_order_id = %exchange_order_id,
+                                error = %e,
+                                "주문 상태 이벤트 반영 실패"
+                            );
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "주문 상태 이벤트 수신 지연, 다음 이벤트에서 보정");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        debug!("주문 상태 스트림 종료, 체결 반영 중지");
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// 보관된 이벤트를 도착 순서대로 재처리.
+    async fn replay(
+        &self,
+        state: &mut AdapterState,
+        order_id: Uuid,
+        exchange_order_id: &str,
+    ) -> Result<Vec<FillUpdateOutcome>, FillAdapterError> {
+        let mut outcomes = Vec::new();
+        for buffered in state.buffer.remove(exchange_order_id).unwrap_or_default() {
+            outcomes.push(self.apply_update(state, order_id, &buffered.update).await?);
+        }
+        Ok(outcomes)
+    }
+
+    /// 주문이 확인된 이벤트 반영.
+    async fn apply_update(
+        &self,
+        state: &mut AdapterState,
+        order_id: Uuid,
+        update: &OrderUpdate,
+    ) -> Result<FillUpdateOutcome, FillAdapterError> {
+        let applied = state
+            .applied
+            .get(&update.order_id)
+            .copied()
+            .unwrap_or_default();
+        if update.filled_quantity < applied.quantity {
+            debug!(
+                exchange_order_id = %update.order_id,
+                filled_quantity = %update.filled_quantity,
+                applied_quantity = %applied.quantity,
+                "누적 체결수량이 역행한 이벤트 무시"
+            );
+            return Ok(FillUpdateOutcome::Ignored);
+        }
+
+        let order = self
+            .order_manager
+            .read()
+            .await
+            .get_order(order_id)
+            .cloned()
+            .ok_or(OrderManagerError::OrderNotFound(order_id))?;
+
+        let mut fill = None;
+        let mut position_error = None;
+        let delta = update.filled_quantity - applied.quantity;
+        if delta > Decimal::ZERO {
+            let (price, notional) = fill_price(update, &applied, delta, &order)?;
+            let new_fill = OrderFill {
+                order_id,
+                quantity: delta,
+                price,
+                commission: None,
+                commission_asset: None,
+                timestamp: update.timestamp,
+            };
+
+            if order.status.is_final() {
+                // 주문 상태는 확정됐지만 실제 체결은 포지션에 반영해야 함
+                warn!(
+                    order_id = %order_id,
+                    exchange_order_id = %update.order_id,
+                    quantity = %delta,
+                    "최종 상태 주문에 추가 체결 도착"
+                );
+            } else {
+                self.order_manager
+                    .write()
+                    .await
+                    .record_partial_fill(order_id, new_fill.clone())?;
+            }
+
+            // 주문에 반영된 누적 체결은 포지션 반영 실패와 관계없이 기록해 재반영을 막음
+            state.applied.insert(
+                update.order_id.clone(),
+                AppliedFill {
+                    quantity: update.filled_quantity,
+                    notional,
+                },
+            );
+            position_error = self
+                .position_tracker
+                .write()
+                .await
+                .apply_fill(&order, &new_fill)
+                .err();
+            fill = Some(new_fill);
+        }
+
+        let status_changed = if state.superseded.contains(&update.order_id) {
+            false
+        } else {
+            let mut order_manager = self.order_manager.write().await;
+            apply_status(&mut order_manager, order_id, update)?
+        };
+
+        // 주문 상태까지 반영한 뒤 포지션 반영 실패를 호출자에게 전달
+        if let Some(e) = position_error {
+            return Err(e.into());
+        }
+
+        if fill.is_none() && !status_changed {
+            return Ok(FillUpdateOutcome::Ignored);
+        }
+        Ok(FillUpdateOutcome::Applied { order_id, fill })
+    }
+
+    /// 미등록 이벤트 보관.
+    fn buffer_update(state: &mut AdapterState, update: OrderUpdate) {
+        if !state.buffer.contains_key(&update.order_id) && state.buffer.len() >= MAX_BUFFERED_ORDERS
+        {
+            let oldest = state
+                .buffer
+                .iter()
+                .filter_map(|(id, updates)| updates.first().map(|b| (id.clone(), b.received_at)))
+                .min_by_key(|(_, received_at)| *received_at)
+                .map(|(id, _)| id);
+            if let Some(oldest) = oldest {
+                warn!(exchange_order_id = %oldest, "체결 이벤트 버퍼 초과, 가장 오래된 주문 이벤트 폐기");
+                state.buffer.remove(&oldest);
+            }
+        }
+
+        state
+            .buffer
+            .entry(update.order_id.clone())
+            .or_default()
+            .push(BufferedUpdate {
+                update,
+                received_at: Utc::now(),
+            });
+    }
+
+    /// 보관 기간이 지난 이벤트 폐기.
+    fn prune_buffer(&self, state: &mut AdapterState) {
+        let Ok(ttl) = chrono::Duration::from_std(self.buffer_ttl) else {
+            return;
+        };
+        let cutoff = Utc::now() - ttl;
+
+        state.buffer.retain(|exchange_order_id, updates| {
+            let expired = updates.last().is_some_and(|b| b.received_at < cutoff);
+            if expired {
+                warn!(
+                    exchange_order_id = %exchange_order_id,
+                    dropped = updates.len(),
+                    "주문번호가 등록되지 않은 체결 이벤트 만료"
+                );
+            }
+            !expired
+        });
+    }
+}
+
+/// 이벤트에 해당하는 내부 주문 ID 조회 (클라이언트 ID로 찾으면 주문번호 연결 필요).
+fn resolve_order(order_manager: &OrderManager, update: &OrderUpdate) -> Option<(Uuid, bool)> {
+    if let Some(order) = order_manager.get_order_by_exchange_id(&update.order_id) {
+        return Some((order.id, false));
+    }
+
+    let client_order_id = update.client_order_id.as_deref()?;
+    order_manager
+        .get_active_orders()
+        .into_iter()
+        .find(|o| o.client_order_id.as_deref() == Some(client_order_id))
+        .map(|o| (o.id, true))
+}
+
+/// 누적 평균 체결가로부터 이번 체결분의 가격과 새 누적 체결 금액 계산.
+///
+/// 평균 체결가가 없으면 주문 지정가를 사용합니다.
+fn fill_price(
+    update: &OrderUpdate,
+    applied: &AppliedFill,
+    delta: Decimal,
+    order: &Order,
+) -> Result<(Decimal, Decimal), FillAdapterError> {
+    if let Some(avg) = update.average_price.filter(|p| *p > Decimal::ZERO) {
+        let notional = avg * update.filled_quantity;
+        let price = (notional - applied.notional) / delta;
+        // 평균가 반올림으로 역산 가격이 비정상이면 평균가로 대체
+        let price = if price > Decimal::ZERO { price } else { avg };
+        return Ok((price, notional));
+    }
+
+    let price = order
+        .price
+        .or(order.average_fill_price)
+        .filter(|p| *p > Decimal::ZERO)
+        .ok_or_else(|| FillAdapterError::MissingFillPrice(update.order_id.clone()))?;
+    Ok((price, applied.notional + price * delta))
+}
+
+/// 접수/최종 상태를 주문에 반영하고 변경 여부를 반환.
+///
+/// 체결 상태(부분/전량)는 `record_fill`이 갱신하므로, 여기서는 접수와
+/// 체결 누적만으로 도달하지 못한 최종 상태(취소, 정정으로 줄어든 수량의 완료 등)만 다룹니다.
+fn apply_status(
+    order_manager: &mut OrderManager,
+    order_id: Uuid,
+    update: &OrderUpdate,
+) -> Result<bool, FillAdapterError> {
+    let Some(order) = order_manager.get_order(order_id).cloned() else {
+        return Ok(false);
+    };
+    if order.status.is_final() {
+        return Ok(false);
+    }
+
+    let target = match update.status {
+        OrderStatusType::Open if order.status == OrderStatusType::Pending => OrderStatusType::Open,
+        status if status.is_final() => status,
+        _ => return Ok(false),
+    };
+
+    let status = order_status(&order, &update.order_id, target, update.timestamp);
+    order_manager.update_status(order_id, &status)?;
+    Ok(true)
+}
+
+/// 내부 누적 체결을 유지한 채 상태만 바꾸는 주문 상태 생성.
+fn order_status(
+    order: &Order,
+    exchange_order_id: &str,
+    status: OrderStatusType,
+    updated_at: DateTime<Utc>,
+) -> OrderStatus {
+    OrderStatus {
+        order_id: exchange_order_id.to_string(),
+        client_order_id: order.client_order_id.clone(),
+        ticker: Some(order.ticker.clone()),
+        side: Some(order.side),
+        quantity: Some(order.quantity),
+        price: order.price,
+        status,
+        filled_quantity: order.filled_quantity,
+        average_price: order.average_fill_price,
+        updated_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use trader_core::OrderRequest;
+
+    use super::*;
+
+    fn setup() -> (
+        OrderFillAdapter,
+        Arc<RwLock<OrderManager>>,
+        Arc<RwLock<PositionTracker>>,
+    ) {
+        let order_manager = Arc::new(RwLock::new(OrderManager::new()));
+        let position_tracker = Arc::new(RwLock::new(PositionTracker::new("db_investment")));
+        let adapter =
+            OrderFillAdapter::new(Arc::clone(&order_manager), Arc::clone(&position_tracker));
+        (adapter, order_manager, position_tracker)
+    }
+
+    fn update(
+        order_no: &str,
+        status: OrderStatusType,
+        filled: Decimal,
+        avg: Option<Decimal>,
+    ) -> OrderUpdate {
+        OrderUpdate {
+            exchange: "db_investment".to_string(),
+            order_id: order_no.to_string(),
+            client_order_id: None,
+            ticker: Some("005930".to_string()),
+            status,
+            filled_quantity: filled,
+            average_price: avg,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cumulative_updates_become_fill_deltas() {
+        let (adapter, order_manager, _) = setup();
+        let order = order_manager
+            .write()
+            .await
+            .create_order(
+                OrderRequest::limit_buy("005930".to_string(), dec!(10), dec!(70000)),
+                "db_investment",
+            )
+            .unwrap();
+        adapter.register_submission(order.id, "1001").await.unwrap();
+
+        let first = adapter
+            .handle_update(update(
+                "1001",
+                OrderStatusType::PartiallyFilled,
+                dec!(4),
+                Some(dec!(70000)),
+            ))
+            .await
+            .unwrap();
+        let FillUpdateOutcome::Applied {
+            fill: Some(fill), ..
+        } = first
+        else {
+            panic!("체결 반영 기대: {:?}", first);
+        };
+        assert_eq!((fill.quantity, fill.price), (dec!(4), dec!(70000)));
+
+        // 누적 10주, 평균 69700 → 이번 6주는 69500
+        let second = adapter
+            .handle_update(update(
+                "1001",
+                OrderStatusType::Filled,
+                dec!(10),
+                Some(dec!(69700)),
+            ))
+            .await
+            .unwrap();
+        let FillUpdateOutcome::Applied {
+            fill: Some(fill), ..
+        } = second
+        else {
+            panic!("체결 반영 기대: {:?}", second);
+        };
+        assert_eq!((fill.quantity, fill.price), (dec!(6), dec!(69500)));
+
+        // 재전송된 이전 이벤트는 무시
+        let stale = adapter
+            .handle_update(update(
+                "1001",
+                OrderStatusType::PartiallyFilled,
+                dec!(4),
+                Some(dec!(70000)),
+            ))
+            .await
+            .unwrap();
+        assert!(matches!(stale, FillUpdateOutcome::Ignored));
+
+        let stored = order_manager
+            .read()
+            .await
+            .get_order(order.id)
+            .cloned()
+            .unwrap();
+        assert_eq!(stored.status, OrderStatusType::Filled);
+        assert_eq!(stored.filled_quantity, dec!(10));
+        assert_eq!(stored.average_fill_price, Some(dec!(69700)));
+    }
+
+    #[tokio::test]
+    async fn test_fill_price_falls_back_to_limit_price() {
+        let (adapter, order_manager, position_tracker) = setup();
+        let order = order_manager
+            .write()
+            .await
+            .create_order(
+                OrderRequest::limit_buy("005930".to_string(), dec!(5), dec!(71000)),
+                "db_investment",
+            )
+            .unwrap();
+        adapter.register_submission(order.id, "2001").await.unwrap();
+
+        adapter
+            .handle_update(update("2001", OrderStatusType::Filled, dec!(5), None))
+            .await
+            .unwrap();
+
+        let tracker = position_tracker.read().await;
+        let position = tracker.get_position_for_symbol("005930").unwrap();
+        assert_eq!(position.quantity, dec!(5));
+        assert_eq!(position.entry_price, dec!(71000));
+    }
+
+    #[tokio::test]
+    async fn test_amendment_links_new_order_number() {
+        let (adapter, order_manager, _) = setup();
+        let order = order_manager
+            .write()
+            .await
+            .create_order(
+                OrderRequest::limit_buy("005930".to_string(), dec!(10), dec!(70000)),
+                "db_investment",
+            )
+            .unwrap();
+        adapter.register_submission(order.id, "3001").await.unwrap();
+        adapter
+            .handle_update(update(
+                "3001",
+                OrderStatusType::PartiallyFilled,
+                dec!(2),
+                Some(dec!(70000)),
+            ))
+            .await
+            .unwrap();
+
+        // 정정 주문번호의 체결이 정정 등록보다 먼저 도착
+        let early = adapter
+            .handle_update(update(
+                "3002",
+                OrderStatusType::Filled,
+                dec!(8),
+                Some(dec!(69000)),
+            ))
+            .await
+            .unwrap();
+        assert!(matches!(early, FillUpdateOutcome::Buffered));
+
+        adapter.register_amendment("3001", "3002").await.unwrap();
+        // 정정으로 인한 원주문 취소 확인은 주문 상태에 반영하지 않음
+        adapter
+            .handle_update(update(
+                "3001",
+                OrderStatusType::Cancelled,
+                dec!(2),
+                Some(dec!(70000)),
+            ))
+            .await
+            .unwrap();
+
+        let stored = order_manager
+            .read()
+            .await
+            .get_order(order.id)
+            .cloned()
+            .unwrap();
+        assert_eq!(stored.status, OrderStatusType::Filled);
+        assert_eq!(stored.filled_quantity, dec!(10));
+        assert_eq!(stored.exchange_order_id.as_deref(), Some("3002"));
+        assert_eq!(adapter.buffered_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_expired_buffer_is_dropped() {
+        let (adapter, _, _) = setup();
+        let adapter = adapter.with_buffer_ttl(Duration::ZERO);
+
+        adapter
+            .handle_update(update(
+                "9999",
+                OrderStatusType::Filled,
+                dec!(1),
+                Some(dec!(1000)),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(adapter.buffered_count().await, 1);
+
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        adapter
+            .handle_update(update("9998", OrderStatusType::Open, dec!(0), None))
+            .await
+            .unwrap();
+        assert_eq!(adapter.buffered_count().await, 1);
+    }
+}
@@ -0,0 +1,273 @@
+//! 거래소 지연(레이턴시) 시뮬레이션.
+//!
+//! 주문 접수부터 체결까지 걸리는 시간을 분포에서 샘플링합니다.
+//! [`SimulatedExecutor::with_latency_model`](crate::SimulatedExecutor::with_latency_model)로
+//! 설정하면 주문은 지연 시간 뒤에 체결되며, 그 사이 가격이 불리하게 움직이면
+//! 변한 가격으로 체결됩니다. 지연 중 도착한 취소 요청은 체결 시각과 비교하여
+//! 성공/실패가 결정됩니다.
+//!
+//! # 지원 모델
+//!
+//! - **Fixed**: 고정 지연 (ms)
+//! - **Normal**: 정규분포 지연 (평균/표준편차 ms, 음수는 0으로 절삭)
+//!
+//! 시드를 지정하면 같은 입력에 대해 항상 같은 지연 시퀀스를 재현합니다.
+
+use chrono::Duration;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::Side;
+
+/// 주문 지연 분포.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LatencyModel {
+    /// 고정 지연.
+    Fixed {
+        /// 지연 시간 (ms)
+        ms: u64,
+    },
+
+    /// 정규분포 지연.
+    Normal {
+        /// 평균 지연 (ms)
+        mean: f64,
+        /// 표준편차 (ms)
+        std: f64,
+    },
+}
+
+impl LatencyModel {
+    /// 모델 이름.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Fixed { .. } => "fixed",
+            Self::Normal { .. } => "normal",
+        }
+    }
+
+    /// 분포의 평균 지연 (ms).
+    pub fn mean_ms(&self) -> f64 {
+        match self {
+            Self::Fixed { ms } => *ms as f64,
+            Self::Normal { mean, .. } => mean.max(0.0),
+        }
+    }
+}
+
+/// 시드 기반 지연 샘플러.
+#[derive(Debug, Clone)]
+pub struct LatencySampler {
+    model: LatencyModel,
+    seed: Option<u64>,
+    rng: StdRng,
+}
+
+impl LatencySampler {
+    /// 샘플러 생성 (시드가 없으면 엔트로피로 초기화).
+    pub fn new(model: LatencyModel, seed: Option<u64>) -> Self {
+        Self {
+            rng: Self::make_rng(seed),
+            model,
+            seed,
+        }
+    }
+
+    fn make_rng(seed: Option<u64>) -> StdRng {
+        match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        }
+    }
+
+    /// 지연 모델.
+    pub fn model(&self) -> &LatencyModel {
+        &self.model
+    }
+
+    /// 시드.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// 다음 지연 시간 샘플링.
+    pub fn sample(&mut self) -> Duration {
+        let ms = match self.model {
+            LatencyModel::Fixed { ms } => ms as f64,
+            LatencyModel::Normal { mean, std } => {
+                // Box-Muller 변환 (u1 ∈ (0, 1])
+                let u1: f64 = 1.0 - self.rng.gen::<f64>();
+                let u2: f64 = self.rng.gen();
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+                mean + std.abs() * z
+            }
+        };
+        Duration::microseconds((ms.max(0.0) * 1000.0).round() as i64)
+    }
+
+    /// 시드 기준으로 시퀀스를 처음부터 다시 시작.
+    pub fn reset(&mut self) {
+        self.rng = Self::make_rng(self.seed);
+    }
+}
+
+/// 지연 시뮬레이션 통계.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LatencyReport {
+    /// 지연 모델 이름
+    pub model: String,
+    /// 재현용 시드
+    pub seed: Option<u64>,
+    /// 접수된 주문 수
+    pub submitted: usize,
+    /// 체결 처리된 주문 수
+    pub filled: usize,
+    /// 취소에 성공한 주문 수
+    pub cancelled: usize,
+    /// 취소 요청이 체결보다 늦게 도착해 실패한 주문 수
+    pub cancel_rejected: usize,
+    /// 아직 체결 대기 중인 주문 수
+    pub pending: usize,
+    /// 평균 체결 지연 (ms)
+    pub avg_latency_ms: f64,
+    /// 최대 체결 지연 (ms)
+    pub max_latency_ms: f64,
+    /// 지연 동안 불리한 가격 변동으로 인한 총 슬리피지 금액
+    pub total_latency_slippage: Decimal,
+    /// 체결 건당 평균 지연 슬리피지 금액
+    pub avg_latency_slippage: Decimal,
+    /// 체결 금액 대비 지연 슬리피지 (bps)
+    pub latency_slippage_bps: Decimal,
+}
+
+/// 지연 통계 누적기.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LatencyStats {
+    pub(crate) submitted: usize,
+    pub(crate) filled: usize,
+    pub(crate) cancelled: usize,
+    pub(crate) cancel_rejected: usize,
+    total_latency_ms: f64,
+    max_latency_ms: f64,
+    total_slippage: Decimal,
+    total_fill_value: Decimal,
+}
+
+impl LatencyStats {
+    /// 체결 기록 (거래가 발생하지 않은 체결은 value/slippage 0).
+    pub(crate) fn record_fill(&mut self, latency: Duration, slippage: Decimal, value: Decimal) {
+        let ms = duration_ms(latency);
+        self.filled += 1;
+        self.total_latency_ms += ms;
+        self.max_latency_ms = self.max_latency_ms.max(ms);
+        self.total_slippage += slippage;
+        self.total_fill_value += value;
+    }
+
+    pub(crate) fn report(&self, sampler: &LatencySampler, pending: usize) -> LatencyReport {
+        let filled = Decimal::from(self.filled);
+        LatencyReport {
+            model: sampler.model().name().to_string(),
+            seed: sampler.seed(),
+            submitted: self.submitted,
+            filled: self.filled,
+            cancelled: self.cancelled,
+            cancel_rejected: self.cancel_rejected,
+            pending,
+            avg_latency_ms: if self.filled > 0 {
+                self.total_latency_ms / self.filled as f64
+            } else {
+                0.0
+            },
+            max_latency_ms: self.max_latency_ms,
+            total_latency_slippage: self.total_slippage,
+            avg_latency_slippage: if filled.is_zero() {
+                Decimal::ZERO
+            } else {
+                self.total_slippage / filled
+            },
+            latency_slippage_bps: if self.total_fill_value.is_zero() {
+                Decimal::ZERO
+            } else {
+                self.total_slippage / self.total_fill_value * Decimal::from(10_000)
+            },
+        }
+    }
+}
+
+/// Duration을 ms(소수) 단위로 변환.
+pub(crate) fn duration_ms(duration: Duration) -> f64 {
+    duration
+        .num_microseconds()
+        .map(|us| us as f64 / 1000.0)
+        .unwrap_or_else(|| duration.num_milliseconds() as f64)
+}
+
+/// 지연 동안의 가격 변동을 불리한 방향으로만 반영한 체결 기준가.
+///
+/// 매수는 접수가와 도착가 중 높은 가격, 매도는 낮은 가격을 사용합니다.
+pub(crate) fn adverse_price(side: Side, submit_price: Decimal, arrival_price: Decimal) -> Decimal {
+    match side {
+        Side::Buy => submit_price.max(arrival_price),
+        Side::Sell => submit_price.min(arrival_price),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_latency() {
+        let mut sampler = LatencySampler::new(LatencyModel::Fixed { ms: 150 }, None);
+        assert_eq!(sampler.sample(), Duration::milliseconds(150));
+        assert_eq!(sampler.model().mean_ms(), 150.0);
+    }
+
+    #[test]
+    fn test_normal_latency_is_reproducible_with_seed() {
+        let model = LatencyModel::Normal {
+            mean: 200.0,
+            std: 50.0,
+        };
+        let mut a = LatencySampler::new(model.clone(), Some(42));
+        let mut b = LatencySampler::new(model, Some(42));
+
+        let first: Vec<Duration> = (0..50).map(|_| a.sample()).collect();
+        let second: Vec<Duration> = (0..50).map(|_| b.sample()).collect();
+        assert_eq!(first, second);
+        assert!(first.iter().all(|d| *d >= Duration::zero()));
+
+        let mean = first.iter().map(|d| duration_ms(*d)).sum::<f64>() / first.len() as f64;
+        assert!((mean - 200.0).abs() < 30.0, "평균 지연 {}", mean);
+
+        a.reset();
+        assert_eq!(a.sample(), first[0]);
+    }
+
+    #[test]
+    fn test_adverse_price() {
+        assert_eq!(adverse_price(Side::Buy, dec!(100), dec!(101)), dec!(101));
+        assert_eq!(adverse_price(Side::Buy, dec!(100), dec!(99)), dec!(100));
+        assert_eq!(adverse_price(Side::Sell, dec!(100), dec!(99)), dec!(99));
+        assert_eq!(adverse_price(Side::Sell, dec!(100), dec!(101)), dec!(100));
+    }
+
+    #[test]
+    fn test_latency_model_serde() {
+        let model: LatencyModel =
+            serde_json::from_str(r#"{"type":"normal","mean":120.0,"std":30.0}"#).unwrap();
+        assert_eq!(
+            model,
+            LatencyModel::Normal {
+                mean: 120.0,
+                std: 30.0
+            }
+        );
+        let json = serde_json::to_string(&LatencyModel::Fixed { ms: 50 }).unwrap();
+        assert_eq!(json, r#"{"type":"fixed","ms":50}"#);
+    }
+}
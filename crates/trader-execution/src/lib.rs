@@ -2,8 +2,10 @@
 //!
 //! 이 crate는 다음을 제공합니다:
 //! - 시그널을 주문으로 변환하는 주문 실행기
-//! - 주문 상태 관리 및 추적
+//! - 주문 상태 관리 및 추적 (저장소 영속화 및 재시작 복구)
 //! - PnL 계산을 포함한 포지션 추적
+//! - 거래소 주문 상태 스트림의 체결을 주문/포지션에 반영
+//! - 대량 주문 분할 집행 (TWAP/VWAP)
 //! - 오류 복구 및 재시도 로직
 //!
 //! # 예제
@@ -19,24 +21,51 @@
 //! ```
 
 pub mod executor;
+pub mod fill_adapter;
+pub mod latency;
 pub mod live_executor;
 pub mod order_manager;
+pub mod order_store;
 pub mod position_tracker;
+pub mod retry;
 pub mod signal_processor;
 pub mod simulated_executor;
+pub mod sizing;
+pub mod slicing;
+pub mod slippage;
+mod telemetry;
 
 // 주요 타입 재내보내기
 pub use executor::{
-    ConversionConfig, ExecutionError, ExecutionResult, OrderExecutor, SignalConverter,
+    convert_signal_order_metadata, ConversionConfig, ExecutionError, ExecutionResult,
+    OrderExecutor, OrderSizeRule, QuantityRounding, SignalConverter, SignalOrderMetadata,
+    EXPIRE_AT_METADATA_KEY, TIME_IN_FORCE_METADATA_KEY,
 };
+pub use fill_adapter::{FillAdapterError, FillUpdateOutcome, OrderFillAdapter, DEFAULT_BUFFER_TTL};
+pub use latency::{LatencyModel, LatencyReport, LatencySampler};
 // Signal 처리 추상화
-pub use live_executor::LiveExecutor;
-pub use order_manager::{OrderEvent, OrderFill, OrderManager, OrderManagerError, OrderStats};
-pub use position_tracker::{PositionEvent, PositionTracker, PositionTrackerError};
+pub use live_executor::{ExecutionMode, LiveExecutor, PositionHandoff};
+pub use order_manager::{
+    ExecutionSyncReport, FillProgress, OcoCancelAction, OcoGroup, OrderEvent, OrderFill,
+    OrderManager, OrderManagerError, OrderStats, TimeInForceAction,
+};
+pub use order_store::{InMemoryOrderStore, OrderStore, OrderStoreError, OrderStoreWriter};
+pub use position_tracker::{
+    ClosedLot, CostBasisMethod, HoldingSnapshot, PositionDiscrepancy, PositionEvent, PositionFx,
+    PositionLot, PositionTracker, PositionTrackerError, ReconciliationConfig, ReconciliationMode,
+    ReconciliationReport,
+};
+pub use retry::{RetryClass, RetryConfig};
 pub use signal_processor::{
     apply_slippage, build_add_trade, build_entry_trade, build_exit_trade, calculate_position_size,
-    calculate_realized_pnl, convert_signal_metadata, determine_close_quantity,
-    update_position_average, validate_funds, ProcessorConfig, ProcessorPosition, SignalProcessor,
-    SignalProcessorError, TradeResult,
+    calculate_realized_pnl, calculate_signal_position_size, collect_trailing_stop_exits,
+    convert_signal_metadata, determine_close_quantity, update_position_average, validate_funds,
+    ProcessorConfig, ProcessorPosition, SignalBatchResult, SignalProcessor, SignalProcessorError,
+    TradeResult,
+};
+pub use simulated_executor::{CancelOutcome, PendingSimOrder, SimulatedExecutor};
+pub use sizing::{PositionSizingMethod, SizingInputs, TradeStats};
+pub use slicing::{
+    ChildSlice, SliceStatus, SlicedOrder, SlicedOrderState, SlicingAlgorithm, SlicingEvent,
 };
-pub use simulated_executor::SimulatedExecutor;
+pub use slippage::{SlippageModel, SlippageResult, SlippageTier};
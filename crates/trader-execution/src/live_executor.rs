@@ -9,27 +9,87 @@
 //! - **포지션 추적**: 내부 HashMap으로 포지션 상태를 관리 (거래소 상태와 동기화)
 //! - **position_id/group_id 지원**: 스프레드/그리드 전략의 분할 매매 구조 완전 지원
 //! - **브라켓 주문**: SL/TP 주문을 자동으로 생성하여 거래소에 제출
+//! - **주문 재시도**: 일시 장애는 지수 백오프로 재시도하고, 타임아웃은 client_order_id로
+//!   접수 여부를 확인하여 중복 주문을 방지
+//! - **체결 보정**: 진입 시 추정 체결가로 포지션을 잡고, 통합 주문 상태 스트림
+//!   (`OrderUpdate`)의 실제 체결가·취소 이벤트로 포지션과 잔고를 보정
+//! - **드라이런(Paper) 모드**: [`ExecutionMode::Paper`]에서는 거래소 대신 내장
+//!   `SimulatedExecutor`로 라우팅하고 동일한 로그를 남김. 실거래 전환은 확인 문구가
+//!   일치해야 하며, 전환 시 기존 모드의 포지션은 [`PositionHandoff`]에 따라 유지/청산
 
 use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
+use tokio::sync::broadcast;
 use tracing::{debug, info, warn};
 use trader_core::{
-    OrderExecutionProvider, OrderRequest, OrderType, Side, Signal, SignalType, TimeInForce,
+    with_correlation_id, ConflictResolutionPolicy, IdempotencyKey, OrderExecutionProvider,
+    OrderRequest, OrderResponse, OrderSession, OrderStatusType, OrderType, OrderUpdate, Side,
+    Signal, SignalType, TimeInForce,
 };
 
 use crate::{
-    executor::{BracketOrderManager, ConversionConfig},
+    executor::{
+        convert_signal_order_metadata, BracketOrderManager, ConversionConfig, ExecutionError,
+    },
+    retry::{RetryClass, RetryConfig},
     signal_processor::{
         apply_slippage, build_add_trade, build_entry_trade, build_exit_trade,
-        calculate_position_size, calculate_realized_pnl, determine_close_quantity,
-        update_position_average, validate_funds, ProcessorConfig, ProcessorPosition,
-        SignalProcessor, SignalProcessorError, TradeResult,
+        calculate_realized_pnl, calculate_signal_position_size, collect_trailing_stop_exits,
+        determine_close_quantity, update_position_average, validate_funds, ProcessorConfig,
+        ProcessorPosition, SignalProcessor, SignalProcessorError, TradeResult,
     },
+    simulated_executor::SimulatedExecutor,
 };
 
+/// 주문 실행 모드.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExecutionMode {
+    /// 실거래: 거래소에 실제 주문 제출
+    #[default]
+    Live,
+    /// 드라이런: 내장 시뮬레이터로 체결 (거래소 주문 없음)
+    Paper,
+}
+
+impl ExecutionMode {
+    /// 로그 표시용 이름
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Live => "LIVE",
+            Self::Paper => "PAPER",
+        }
+    }
+}
+
+/// 모드 전환 시 기존 모드의 오픈 포지션 처리 정책.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PositionHandoff {
+    /// 유지: 포지션은 기존 모드의 장부에 그대로 남고, 해당 모드로 복귀하면 다시 관리됨.
+    /// 실거래 포지션은 거래소에 계속 보유됩니다.
+    #[default]
+    Keep,
+    /// 청산: 전환 전에 기존 모드의 모든 포지션을 시장가로 청산.
+    /// 실거래 청산이 하나라도 실패하면 전환하지 않습니다.
+    Liquidate,
+}
+
+/// 체결 확정 전인 진입 주문.
+///
+/// 진입 시점에는 추정 체결가로 포지션을 잡으므로, 거래소의 최종 상태를 받아
+/// 실제 체결가와 미체결 수량을 반영하기 위해 보관합니다.
+#[derive(Debug, Clone)]
+struct UnconfirmedEntry {
+    /// 포지션 키
+    position_key: String,
+    /// 포지션 반영에 사용한 추정 체결가
+    estimated_price: Decimal,
+    /// 주문 수량
+    quantity: Decimal,
+}
+
 /// 실거래 실행기.
 ///
 /// 실제 거래소에 주문을 제출하며, `SignalProcessor` trait을 구현합니다.
@@ -53,6 +113,13 @@ use crate::{
 /// );
 ///
 /// let result = executor.process_signal(&signal, dec!(50000), Utc::now()).await?;
+///
+/// // 드라이런으로 시작하고, 검증 후 명시적 확인과 함께 실거래로 전환
+/// let mut executor = executor.with_mode(ExecutionMode::Paper);
+/// let phrase = executor.live_confirmation_phrase();
+/// executor
+///     .switch_mode(ExecutionMode::Live, PositionHandoff::Liquidate, Some(&phrase), &prices, Utc::now())
+///     .await?;
 /// ```
 pub struct LiveExecutor {
     // === 공통 필드 (SimulatedExecutor와 동일) ===
@@ -80,6 +147,14 @@ pub struct LiveExecutor {
     bracket_manager: BracketOrderManager,
     /// Signal 변환 설정
     conversion_config: ConversionConfig,
+    /// 주문 전송 재시도 설정
+    retry_config: RetryConfig,
+    /// 체결 확정 전인 진입 주문 (거래소 주문번호 → 주문)
+    unconfirmed_entries: HashMap<String, UnconfirmedEntry>,
+    /// 실행 모드
+    mode: ExecutionMode,
+    /// 드라이런 모드 실행기 (Paper 모드의 잔고/포지션/거래 기록)
+    paper: SimulatedExecutor,
 }
 
 impl LiveExecutor {
@@ -90,6 +165,7 @@ impl LiveExecutor {
         order_provider: Arc<dyn OrderExecutionProvider>,
     ) -> Self {
         Self {
+            paper: SimulatedExecutor::new(config.clone(), initial_balance),
             config,
             balance: initial_balance,
             initial_balance,
@@ -101,6 +177,9 @@ impl LiveExecutor {
             order_provider,
             bracket_manager: BracketOrderManager::new(),
             conversion_config: ConversionConfig::default(),
+            retry_config: RetryConfig::disabled(),
+            unconfirmed_entries: HashMap::new(),
+            mode: ExecutionMode::Live,
         }
     }
 
@@ -112,6 +191,7 @@ impl LiveExecutor {
         conversion_config: ConversionConfig,
     ) -> Self {
         Self {
+            paper: SimulatedExecutor::new(config.clone(), initial_balance),
             config,
             balance: initial_balance,
             initial_balance,
@@ -123,14 +203,62 @@ impl LiveExecutor {
             order_provider,
             bracket_manager: BracketOrderManager::new(),
             conversion_config,
+            retry_config: RetryConfig::disabled(),
+            unconfirmed_entries: HashMap::new(),
+            mode: ExecutionMode::Live,
         }
     }
 
+    /// 주문 전송 재시도 설정.
+    ///
+    /// 기본값은 재시도 없음입니다. 네트워크 오류/거래소 5xx에 대해서만
+    /// 지수 백오프로 재시도합니다.
+    pub fn with_retry(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    /// 생성 시 실행 모드 설정.
+    ///
+    /// 기본값은 [`ExecutionMode::Live`]입니다. 생성 이후 실거래로 전환할 때는
+    /// 확인 가드가 있는 [`switch_mode`](Self::switch_mode)를 사용합니다.
+    pub fn with_mode(mut self, mode: ExecutionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// 현재 실행 모드.
+    pub fn mode(&self) -> ExecutionMode {
+        self.mode
+    }
+
+    /// 드라이런(Paper) 모드 여부.
+    pub fn is_paper(&self) -> bool {
+        self.mode == ExecutionMode::Paper
+    }
+
+    /// 드라이런 실행기 조회 (Paper 모드의 잔고/포지션/거래 기록).
+    pub fn paper_executor(&self) -> &SimulatedExecutor {
+        &self.paper
+    }
+
+    /// 실거래 전환 확인 문구 (`LIVE <거래소 이름>`).
+    ///
+    /// 거래소 이름을 포함하므로 다른 계좌용 확인 문구로는 전환되지 않습니다.
+    pub fn live_confirmation_phrase(&self) -> String {
+        format!("LIVE {}", self.order_provider.exchange_name())
+    }
+
     /// 설정 조회.
     pub fn config(&self) -> &ProcessorConfig {
         &self.config
     }
 
+    /// 재시도 설정 조회.
+    pub fn retry_config(&self) -> &RetryConfig {
+        &self.retry_config
+    }
+
     /// 총 슬리피지.
     pub fn total_slippage(&self) -> Decimal {
         self.total_slippage
@@ -146,9 +274,124 @@ impl LiveExecutor {
         self.order_provider.exchange_name()
     }
 
+    /// 체결 확정 대기 중인 진입 주문 수.
+    pub fn unconfirmed_entry_count(&self) -> usize {
+        self.unconfirmed_entries.len()
+    }
+
+    /// 통합 주문 상태 스트림의 이벤트를 반영.
+    ///
+    /// 이 실행기가 제출한 진입 주문의 최종 상태만 처리합니다.
+    /// - 체결: 추정 체결가와 실제 평균 체결가의 차이만큼 진입가와 잔고를 보정
+    /// - 취소/거부/만료: 미체결 수량만큼 포지션을 줄이고(전량 미체결이면 제거) 잔고를 환급
+    ///
+    /// 포지션 상태가 바뀌었으면 `true`를 반환합니다.
+    pub fn apply_order_update(&mut self, update: &OrderUpdate) -> bool {
+        if !update.is_final() {
+            return false;
+        }
+        let Some(entry) = self.unconfirmed_entries.remove(&update.order_id) else {
+            return false;
+        };
+
+        let filled_quantity = if update.status == OrderStatusType::Filled {
+            entry.quantity
+        } else {
+            update.filled_quantity.min(entry.quantity)
+        };
+        let unfilled_quantity = entry.quantity - filled_quantity;
+
+        let Some(position) = self.positions.get_mut(&entry.position_key) else {
+            debug!(
+                order_id = %update.order_id,
+                position_key = %entry.position_key,
+                "보정 대상 포지션이 이미 청산됨"
+            );
+            return false;
+        };
+
+        let commission_rate = self.config.commission_rate;
+        let mut balance_delta = Decimal::ZERO;
+        let mut commission_delta = Decimal::ZERO;
+
+        // 체결분: 추정가 → 실제 평균 체결가
+        if let Some(average_price) = update.average_price.filter(|p| *p > Decimal::ZERO) {
+            if filled_quantity > Decimal::ZERO && average_price != entry.estimated_price {
+                let cost_diff = (average_price - entry.estimated_price) * filled_quantity;
+                if position.quantity > Decimal::ZERO {
+                    position.entry_price += cost_diff / position.quantity;
+                }
+                commission_delta += cost_diff * commission_rate;
+                balance_delta -= cost_diff;
+            }
+        }
+
+        // 미체결분: 포지션 축소 및 환급
+        if unfilled_quantity > Decimal::ZERO {
+            let refund = entry.estimated_price * unfilled_quantity;
+            let remaining = position.quantity - unfilled_quantity;
+            if remaining > Decimal::ZERO {
+                // 남은 수량의 평균 진입가 유지를 위해 미체결분을 추정가로 제거
+                position.entry_price =
+                    (position.entry_price * position.quantity - refund) / remaining;
+            }
+            position.quantity = remaining;
+            commission_delta -= refund * commission_rate;
+            balance_delta += refund;
+        }
+
+        position.fees += commission_delta;
+        let emptied = position.quantity <= Decimal::ZERO;
+        if emptied {
+            self.positions.remove(&entry.position_key);
+        }
+
+        self.balance += balance_delta - commission_delta;
+        self.total_commission += commission_delta;
+
+        info!(
+            exchange = self.order_provider.exchange_name(),
+            order_id = %update.order_id,
+            position_key = %entry.position_key,
+            status = ?update.status,
+            filled_quantity = %filled_quantity,
+            unfilled_quantity = %unfilled_quantity,
+            average_price = ?update.average_price,
+            position_removed = emptied,
+            "진입 주문 최종 상태 반영"
+        );
+        true
+    }
+
+    /// 구독 중인 주문 상태 스트림에 쌓인 이벤트를 모두 반영.
+    ///
+    /// 대기하지 않고 현재 수신된 이벤트만 처리하며, 반영된 이벤트 수를 반환합니다.
+    /// 수신 지연으로 이벤트가 유실되면 경고를 남기고 이후 이벤트를 계속 처리합니다.
+    pub fn drain_order_updates(&mut self, rx: &mut broadcast::Receiver<OrderUpdate>) -> usize {
+        let mut applied = 0;
+        loop {
+            match rx.try_recv() {
+                Ok(update) => {
+                    if self.apply_order_update(&update) {
+                        applied += 1;
+                    }
+                }
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                    warn!(
+                        exchange = self.order_provider.exchange_name(),
+                        skipped, "주문 상태 스트림 수신 지연으로 이벤트 유실"
+                    );
+                }
+                Err(_) => break,
+            }
+        }
+        applied
+    }
+
     /// 모든 포지션 강제 청산.
     ///
     /// 실거래에서 모든 보유 포지션에 대해 시장가 청산 주문을 제출합니다.
+    /// Paper 모드에서는 드라이런 포지션을 시뮬레이션으로 청산합니다.
     ///
     /// # Arguments
     /// * `prices` - 각 심볼의 현재 가격 맵 (슬리피지 계산용)
@@ -161,6 +404,14 @@ impl LiveExecutor {
         prices: &HashMap<String, Decimal>,
         timestamp: DateTime<Utc>,
     ) -> Vec<TradeResult> {
+        if self.is_paper() {
+            let results = self.paper.close_all_positions(prices, timestamp);
+            for trade in &results {
+                self.log_paper_trade(trade);
+            }
+            return results;
+        }
+
         let mut results = Vec::new();
 
         // 포지션 키 목록 복사 (빌림 충돌 방지)
@@ -193,11 +444,13 @@ impl LiveExecutor {
                 price: None,
                 stop_price: None,
                 time_in_force: TimeInForce::GTC,
-                client_order_id: Some(format!("close_all_{}", key)),
+                client_order_id: Some(IdempotencyKey::order("close_all", &key).client_order_id()),
                 strategy_id: None,
+                reduce_only: true,
+                session: OrderSession::Regular,
             };
 
-            let execution_price = match self.order_provider.place_order(&order_request).await {
+            let execution_price = match self.submit_order(&order_request).await {
                 Ok(_response) => {
                     // 거래소 체결가를 사용해야 하지만, 현재 OrderResponse에는 체결가가 없음
                     // 현재가에 슬리피지를 적용하여 추정
@@ -268,6 +521,230 @@ impl LiveExecutor {
         results
     }
 
+    /// 실행 모드 전환.
+    ///
+    /// 실거래로 전환할 때는 `confirmation`이 [`live_confirmation_phrase`](Self::live_confirmation_phrase)와
+    /// 정확히 일치해야 합니다. 기존 모드의 오픈 포지션은 `handoff` 정책에 따라 유지하거나
+    /// 전환 전에 청산합니다.
+    ///
+    /// # Returns
+    /// 전환 과정에서 청산된 거래 결과 목록 (같은 모드로의 전환은 아무 것도 하지 않음)
+    pub async fn switch_mode(
+        &mut self,
+        target: ExecutionMode,
+        handoff: PositionHandoff,
+        confirmation: Option<&str>,
+        prices: &HashMap<String, Decimal>,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Vec<TradeResult>, SignalProcessorError> {
+        if target == self.mode {
+            return Ok(Vec::new());
+        }
+
+        if target == ExecutionMode::Live {
+            let expected = self.live_confirmation_phrase();
+            if confirmation.map(str::trim) != Some(expected.as_str()) {
+                warn!(
+                    exchange = self.order_provider.exchange_name(),
+                    "실거래 모드 전환 거부: 확인 문구 불일치"
+                );
+                return Err(SignalProcessorError::LiveConfirmationRequired { expected });
+            }
+        }
+
+        let closed = match (handoff, self.mode) {
+            (PositionHandoff::Keep, ExecutionMode::Live) => {
+                if !self.positions.is_empty() {
+                    warn!(
+                        exchange = self.order_provider.exchange_name(),
+                        positions = self.positions.len(),
+                        "실거래 포지션을 유지한 채 드라이런 전환: 거래소 포지션은 관리되지 않음"
+                    );
+                }
+                Vec::new()
+            }
+            (PositionHandoff::Keep, ExecutionMode::Paper) => Vec::new(),
+            (PositionHandoff::Liquidate, _) => {
+                let closed = self.close_all_positions(prices, timestamp).await;
+                if !self.positions().is_empty() {
+                    return Err(SignalProcessorError::OrderFailed(format!(
+                        "포지션 {}개 청산 실패로 모드 전환 중단",
+                        self.positions().len()
+                    )));
+                }
+                closed
+            }
+        };
+
+        info!(
+            "[{}] 실행 모드 전환: {} → {} (포지션 {:?}, 청산 {}건)",
+            self.order_provider.exchange_name(),
+            self.mode.as_str(),
+            target.as_str(),
+            handoff,
+            closed.len()
+        );
+        self.mode = target;
+        Ok(closed)
+    }
+
+    /// 드라이런 체결 로그 (실거래와 같은 형식, 모드 태그 추가).
+    fn log_paper_trade(&self, trade: &TradeResult) {
+        let exchange = self.order_provider.exchange_name();
+        match trade.signal_type {
+            SignalType::Entry => info!(
+                "[{}/PAPER] 진입: {} {:?} {} @ {} (잔고: {})",
+                exchange,
+                trade.symbol,
+                trade.side,
+                trade.quantity,
+                trade.price,
+                self.paper.balance()
+            ),
+            SignalType::AddToPosition => info!(
+                "[{}/PAPER] 추가 매수: {} {} @ {} (총 수량 업데이트)",
+                exchange, trade.symbol, trade.quantity, trade.price
+            ),
+            _ => info!(
+                "[{}/PAPER] 청산: {} {:?} {} @ {} (PnL: {:?})",
+                exchange, trade.symbol, trade.side, trade.quantity, trade.price, trade.realized_pnl
+            ),
+        }
+    }
+
+    /// 재시도 정책을 적용하여 거래소에 주문 제출.
+    ///
+    /// - 재시도 가능 에러(네트워크, 5xx, 요청 한도): 지수 백오프 후 재전송
+    /// - 타임아웃: 주문이 이미 접수됐을 수 있으므로 client_order_id로 조회하여
+    ///   접수된 주문이 있으면 그 응답을 사용하고, 없을 때만 같은 client_order_id로 재전송
+    ///   (조회 미지원 거래소는 재전송 없이 즉시 반환)
+    /// - 재시도 불가 에러(잔고 부족, 잘못된 심볼 등): 즉시 반환
+    /// - 거래소가 지원하지 않는 주문 유효 기간: 전송 없이 `ExecutionError::UnsupportedTif`
+    async fn submit_order(&self, request: &OrderRequest) -> Result<OrderResponse, ExecutionError> {
+        if !self
+            .order_provider
+            .supports_time_in_force(&request.time_in_force)
+        {
+            return Err(ExecutionError::UnsupportedTif {
+                exchange: self.order_provider.exchange_name().to_string(),
+                time_in_force: request.time_in_force.code().to_string(),
+            });
+        }
+
+        // 모든 시도가 같은 client_order_id를 사용해야 거래소/조회 양쪽에서 중복을 식별할 수 있음
+        let mut request = request.clone();
+        let client_order_id = request
+            .client_order_id
+            .get_or_insert_with(|| format!("ord_{}", uuid::Uuid::new_v4().simple()))
+            .clone();
+
+        let max_attempts = self.retry_config.max_attempts.max(1);
+        let mut attempt = 1;
+        let mut pending_timeout = false;
+
+        loop {
+            if pending_timeout {
+                if let Some(response) = self.find_accepted_order(&request, &client_order_id).await?
+                {
+                    return Ok(response);
+                }
+            }
+
+            let err = match self.order_provider.place_order(&request).await {
+                Ok(response) => return Ok(response),
+                Err(e) => ExecutionError::from(e),
+            };
+
+            let retry_class = err.retry_class();
+            if retry_class == RetryClass::Fatal {
+                return Err(err);
+            }
+
+            // 접수 여부를 확인할 수 없는 거래소는 타임아웃 후 재전송하면 중복 주문 위험
+            if retry_class == RetryClass::Ambiguous
+                && !self.order_provider.supports_client_order_lookup()
+            {
+                warn!(
+                    exchange = self.order_provider.exchange_name(),
+                    client_order_id = %client_order_id,
+                    error = %err,
+                    "client_order_id 조회 미지원 거래소, 타임아웃된 주문을 재전송하지 않음"
+                );
+                return Err(err);
+            }
+
+            if attempt >= max_attempts {
+                // 마지막 시도가 타임아웃이면 접수 여부를 한 번 더 확인
+                if retry_class == RetryClass::Ambiguous && max_attempts > 1 {
+                    if let Some(response) =
+                        self.find_accepted_order(&request, &client_order_id).await?
+                    {
+                        return Ok(response);
+                    }
+                }
+                warn!(
+                    exchange = self.order_provider.exchange_name(),
+                    client_order_id = %client_order_id,
+                    attempts = attempt,
+                    error = %err,
+                    "주문 재시도 한도 초과"
+                );
+                return Err(err);
+            }
+
+            let delay = self.retry_config.delay_for_retry(attempt);
+            warn!(
+                exchange = self.order_provider.exchange_name(),
+                client_order_id = %client_order_id,
+                attempt,
+                max_attempts,
+                delay_ms = delay.as_millis() as u64,
+                error = %err,
+                "주문 전송 실패, 재시도 예정"
+            );
+            tokio::time::sleep(delay).await;
+
+            pending_timeout = retry_class == RetryClass::Ambiguous;
+            attempt += 1;
+        }
+    }
+
+    /// 타임아웃된 주문이 실제로 접수됐는지 client_order_id로 확인.
+    ///
+    /// 조회가 불가능하면 중복 주문 위험이 있으므로 재전송하지 않고 에러를 반환합니다.
+    async fn find_accepted_order(
+        &self,
+        request: &OrderRequest,
+        client_order_id: &str,
+    ) -> Result<Option<OrderResponse>, ExecutionError> {
+        match self
+            .order_provider
+            .find_order_by_client_id(client_order_id, &request.ticker)
+            .await
+        {
+            Ok(Some(response)) => {
+                info!(
+                    exchange = self.order_provider.exchange_name(),
+                    client_order_id = %client_order_id,
+                    order_no = %response.order_no,
+                    "타임아웃된 주문이 이미 접수되어 있음, 재전송 생략"
+                );
+                Ok(Some(response))
+            }
+            Ok(None) => {
+                debug!(
+                    client_order_id = %client_order_id,
+                    "타임아웃된 주문 미접수 확인"
+                );
+                Ok(None)
+            }
+            Err(e) => Err(ExecutionError::Timeout(format!(
+                "주문 접수 여부 확인 불가 (client_order_id={}): {}",
+                client_order_id, e
+            ))),
+        }
+    }
+
     /// 포지션 열기 (내부 메서드).
     ///
     /// Signal을 OrderRequest로 변환하여 거래소에 제출하고,
@@ -300,16 +777,20 @@ impl LiveExecutor {
 
         // 포지션 크기 계산 (공통 유틸리티)
         let price = signal.suggested_price.unwrap_or(current_price);
-        let (position_amount, quantity) = calculate_position_size(
-            self.balance,
-            self.config.max_position_size_pct,
-            signal.strength,
-            price,
-        );
+        let (position_amount, quantity) =
+            calculate_signal_position_size(self.balance, &self.config, signal, price, &self.trades);
+
+        // 사이징 결과가 0이면 진입하지 않음 (예: Kelly 기대값 음수)
+        if quantity <= Decimal::ZERO {
+            return Ok(None);
+        }
 
         // 자금 검증 (공통 유틸리티)
         let _ = validate_funds(position_amount, self.config.commission_rate, self.balance)?;
 
+        let metadata = convert_signal_order_metadata(signal, &self.conversion_config, timestamp)
+            .map_err(|e| SignalProcessorError::OrderFailed(e.to_string()))?;
+
         // Signal → OrderRequest 변환 후 거래소에 제출
         let order_request = OrderRequest {
             ticker: signal.ticker.clone(),
@@ -326,14 +807,15 @@ impl LiveExecutor {
                 Some(price)
             },
             stop_price: None,
-            time_in_force: TimeInForce::GTC,
-            client_order_id: Some(format!("sig_{}", signal.id)),
+            time_in_force: metadata.time_in_force,
+            client_order_id: Some(IdempotencyKey::order("sig", signal.id).client_order_id()),
             strategy_id: Some(signal.strategy_id.clone()),
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
-        let _order_response = self
-            .order_provider
-            .place_order(&order_request)
+        let order_response = self
+            .submit_order(&order_request)
             .await
             .map_err(|e| SignalProcessorError::ExchangeError(e.to_string()))?;
 
@@ -351,6 +833,16 @@ impl LiveExecutor {
         self.total_slippage += slippage_amount;
         self.total_orders += 1;
 
+        // 실제 체결가/미체결 반영은 주문 상태 스트림에서 보정
+        self.unconfirmed_entries.insert(
+            order_response.order_no,
+            UnconfirmedEntry {
+                position_key: key.clone(),
+                estimated_price: execution_price,
+                quantity,
+            },
+        );
+
         // 포지션 생성
         self.positions.insert(
             key.clone(),
@@ -363,6 +855,7 @@ impl LiveExecutor {
                 fees: commission,
                 position_id: signal.position_id.clone(),
                 group_id: signal.group_id.clone(),
+                trailing_high: None,
             },
         );
 
@@ -407,17 +900,21 @@ impl LiveExecutor {
         let price = signal.suggested_price.unwrap_or(current_price);
 
         // 포지션 크기 계산 (공통 유틸리티)
-        let (position_amount, add_quantity) = calculate_position_size(
-            self.balance,
-            self.config.max_position_size_pct,
-            signal.strength,
-            price,
-        );
+        let (position_amount, add_quantity) =
+            calculate_signal_position_size(self.balance, &self.config, signal, price, &self.trades);
+
+        // 사이징 결과가 0이면 진입하지 않음 (예: Kelly 기대값 음수)
+        if add_quantity <= Decimal::ZERO {
+            return Ok(None);
+        }
 
         // 자금 검증 (공통 유틸리티)
         let commission =
             validate_funds(position_amount, self.config.commission_rate, self.balance)?;
 
+        let metadata = convert_signal_order_metadata(signal, &self.conversion_config, timestamp)
+            .map_err(|e| SignalProcessorError::OrderFailed(e.to_string()))?;
+
         // 거래소에 주문 제출
         let order_request = OrderRequest {
             ticker: signal.ticker.clone(),
@@ -434,13 +931,14 @@ impl LiveExecutor {
                 Some(price)
             },
             stop_price: None,
-            time_in_force: TimeInForce::GTC,
-            client_order_id: Some(format!("sig_add_{}", signal.id)),
+            time_in_force: metadata.time_in_force,
+            client_order_id: Some(IdempotencyKey::order("sig_add", signal.id).client_order_id()),
             strategy_id: Some(signal.strategy_id.clone()),
+            reduce_only: false,
+            session: OrderSession::Regular,
         };
 
-        self.order_provider
-            .place_order(&order_request)
+        self.submit_order(&order_request)
             .await
             .map_err(|e| SignalProcessorError::ExchangeError(e.to_string()))?;
 
@@ -502,12 +1000,13 @@ impl LiveExecutor {
             price: None,
             stop_price: None,
             time_in_force: TimeInForce::GTC,
-            client_order_id: Some(format!("sig_exit_{}", signal.id)),
+            client_order_id: Some(IdempotencyKey::order("sig_exit", signal.id).client_order_id()),
             strategy_id: Some(signal.strategy_id.clone()),
+            reduce_only: true,
+            session: OrderSession::Regular,
         };
 
-        self.order_provider
-            .place_order(&order_request)
+        self.submit_order(&order_request)
             .await
             .map_err(|e| SignalProcessorError::ExchangeError(e.to_string()))?;
 
@@ -593,12 +1092,14 @@ impl LiveExecutor {
                 price: None,
                 stop_price: Some(sl_price),
                 time_in_force: TimeInForce::GTC,
-                client_order_id: Some(format!("sl_{}", signal.id)),
+                client_order_id: Some(IdempotencyKey::order("sl", signal.id).client_order_id()),
                 strategy_id: Some(signal.strategy_id.clone()),
+                reduce_only: true,
+                session: OrderSession::Regular,
             };
 
             // 거래소에 SL 주문 제출
-            match self.order_provider.place_order(&sl_order).await {
+            match self.submit_order(&sl_order).await {
                 Ok(response) => {
                     debug!("SL 주문 제출 완료: {} @ {}", response.order_no, sl_price);
                     Some(sl_order)
@@ -628,12 +1129,14 @@ impl LiveExecutor {
                 price: Some(tp_price),
                 stop_price: None,
                 time_in_force: TimeInForce::GTC,
-                client_order_id: Some(format!("tp_{}", signal.id)),
+                client_order_id: Some(IdempotencyKey::order("tp", signal.id).client_order_id()),
                 strategy_id: Some(signal.strategy_id.clone()),
+                reduce_only: true,
+                session: OrderSession::Regular,
             };
 
             // 거래소에 TP 주문 제출
-            match self.order_provider.place_order(&tp_order).await {
+            match self.submit_order(&tp_order).await {
                 Ok(response) => {
                     debug!("TP 주문 제출 완료: {} @ {}", response.order_no, tp_price);
                     Some(tp_order)
@@ -653,11 +1156,9 @@ impl LiveExecutor {
                 .register_bracket(uuid::Uuid::new_v4(), stop_loss, take_profit);
         }
     }
-}
 
-#[async_trait]
-impl SignalProcessor for LiveExecutor {
-    async fn process_signal(
+    /// 신호 처리 (실거래/드라이런 라우팅).
+    async fn dispatch_signal(
         &mut self,
         signal: &Signal,
         current_price: Decimal,
@@ -672,6 +1173,17 @@ impl SignalProcessor for LiveExecutor {
             return Ok(None);
         }
 
+        if self.is_paper() {
+            let trade = self
+                .paper
+                .process_signal(signal, current_price, timestamp)
+                .await?;
+            if let Some(trade) = &trade {
+                self.log_paper_trade(trade);
+            }
+            return Ok(trade);
+        }
+
         match signal.signal_type {
             SignalType::Entry | SignalType::AddToPosition => {
                 // 숏 포지션 확인
@@ -705,21 +1217,100 @@ impl SignalProcessor for LiveExecutor {
             }
         }
     }
+}
+
+#[async_trait]
+impl SignalProcessor for LiveExecutor {
+    /// 신호 ID를 correlation ID로 사용하여 이 신호로 발생한 거래소 요청 로그를 묶습니다.
+    async fn process_signal(
+        &mut self,
+        signal: &Signal,
+        current_price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Option<TradeResult>, SignalProcessorError> {
+        with_correlation_id(
+            signal.id.to_string(),
+            self.dispatch_signal(signal, current_price, timestamp),
+        )
+        .await
+    }
+
+    async fn on_price_update(
+        &mut self,
+        symbol: &str,
+        current_price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Vec<TradeResult>, SignalProcessorError> {
+        if self.is_paper() {
+            let trades = self
+                .paper
+                .on_price_update(symbol, current_price, timestamp)
+                .await?;
+            for trade in &trades {
+                self.log_paper_trade(trade);
+            }
+            return Ok(trades);
+        }
+
+        let Some(trailing_stop_pct) = self.config.trailing_stop_pct else {
+            return Ok(Vec::new());
+        };
+
+        let exits = collect_trailing_stop_exits(
+            &mut self.positions,
+            symbol,
+            current_price,
+            trailing_stop_pct,
+        );
+
+        let mut results = Vec::new();
+        for signal in exits {
+            info!(
+                "[{}] 트레일링 스톱 발동: {} @ {}",
+                self.order_provider.exchange_name(),
+                symbol,
+                current_price
+            );
+            if let Some(trade) = self
+                .close_position_internal(&signal, current_price, timestamp)
+                .await?
+            {
+                results.push(trade);
+            }
+        }
+        Ok(results)
+    }
 
     fn balance(&self) -> Decimal {
-        self.balance
+        match self.mode {
+            ExecutionMode::Live => self.balance,
+            ExecutionMode::Paper => self.paper.balance(),
+        }
     }
 
     fn positions(&self) -> &HashMap<String, ProcessorPosition> {
-        &self.positions
+        match self.mode {
+            ExecutionMode::Live => &self.positions,
+            ExecutionMode::Paper => self.paper.positions(),
+        }
     }
 
     fn trades(&self) -> &[TradeResult] {
-        &self.trades
+        match self.mode {
+            ExecutionMode::Live => &self.trades,
+            ExecutionMode::Paper => self.paper.trades(),
+        }
     }
 
     fn total_commission(&self) -> Decimal {
-        self.total_commission
+        match self.mode {
+            ExecutionMode::Live => self.total_commission,
+            ExecutionMode::Paper => self.paper.total_commission(),
+        }
+    }
+
+    fn conflict_policy(&self) -> Option<ConflictResolutionPolicy> {
+        self.config.conflict_policy
     }
 
     fn reset(&mut self, initial_balance: Decimal) {
@@ -731,13 +1322,15 @@ impl SignalProcessor for LiveExecutor {
         self.total_slippage = Decimal::ZERO;
         self.total_orders = 0;
         self.bracket_manager = BracketOrderManager::new();
+        self.unconfirmed_entries.clear();
+        self.paper.reset(initial_balance);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use rust_decimal_macros::dec;
-    use trader_core::{OrderResponse, ProviderError};
+    use trader_core::{OrderResponse, ProviderError, MAX_CLIENT_ORDER_ID_LEN};
 
     use super::*;
 
@@ -783,6 +1376,152 @@ mod tests {
         }
     }
 
+    /// 정해진 순서대로 실패를 돌려주는 재시도 테스트용 주문 제공자.
+    struct FlakyOrderProvider {
+        /// 순서대로 반환할 실패 (소진 후에는 성공)
+        failures: std::sync::Mutex<std::collections::VecDeque<ProviderError>>,
+        /// 타임아웃이어도 주문은 접수된 것으로 처리할지 여부
+        accept_on_timeout: bool,
+        /// client_order_id 조회 지원 여부
+        supports_lookup: bool,
+        /// 접수된 client_order_id 목록
+        accepted: std::sync::Mutex<Vec<String>>,
+        /// place_order 호출 시점의 correlation ID
+        correlation_ids: std::sync::Mutex<Vec<String>>,
+        /// place_order 호출 횟수
+        place_calls: std::sync::atomic::AtomicUsize,
+        /// find_order_by_client_id 호출 횟수
+        lookup_calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl FlakyOrderProvider {
+        fn new(
+            failures: Vec<ProviderError>,
+            accept_on_timeout: bool,
+            supports_lookup: bool,
+        ) -> Self {
+            Self {
+                failures: std::sync::Mutex::new(failures.into()),
+                accept_on_timeout,
+                supports_lookup,
+                accepted: std::sync::Mutex::new(Vec::new()),
+                correlation_ids: std::sync::Mutex::new(Vec::new()),
+                place_calls: std::sync::atomic::AtomicUsize::new(0),
+                lookup_calls: std::sync::atomic::AtomicUsize::new(0),
+            }
+        }
+
+        fn place_calls(&self) -> usize {
+            self.place_calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+
+        fn lookup_calls(&self) -> usize {
+            self.lookup_calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+
+        fn accepted(&self) -> Vec<String> {
+            self.accepted.lock().unwrap().clone()
+        }
+
+        fn correlation_ids(&self) -> Vec<String> {
+            self.correlation_ids.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl OrderExecutionProvider for FlakyOrderProvider {
+        async fn place_order(
+            &self,
+            request: &OrderRequest,
+        ) -> Result<OrderResponse, ProviderError> {
+            self.place_calls
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.correlation_ids
+                .lock()
+                .unwrap()
+                .push(trader_core::current_correlation_id());
+            let client_order_id = request.client_order_id.clone().unwrap_or_default();
+
+            if let Some(err) = self.failures.lock().unwrap().pop_front() {
+                if matches!(err, ProviderError::Timeout(_)) && self.accept_on_timeout {
+                    self.accepted.lock().unwrap().push(client_order_id);
+                }
+                return Err(err);
+            }
+
+            self.accepted.lock().unwrap().push(client_order_id);
+            Ok(OrderResponse {
+                order_no: "FLAKY_001".to_string(),
+                order_time: "090000".to_string(),
+            })
+        }
+
+        async fn cancel_order(&self, _order_id: &str, _ticker: &str) -> Result<(), ProviderError> {
+            Ok(())
+        }
+
+        async fn modify_order(
+            &self,
+            _order_id: &str,
+            _ticker: &str,
+            _quantity: Option<Decimal>,
+            _price: Option<Decimal>,
+        ) -> Result<OrderResponse, ProviderError> {
+            Err(ProviderError::Unsupported("modify".to_string()))
+        }
+
+        async fn find_order_by_client_id(
+            &self,
+            client_order_id: &str,
+            _ticker: &str,
+        ) -> Result<Option<OrderResponse>, ProviderError> {
+            if !self.supports_lookup {
+                return Err(ProviderError::Unsupported("lookup".to_string()));
+            }
+            self.lookup_calls
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let found = self
+                .accepted
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|id| id == client_order_id);
+            Ok(found.then(|| OrderResponse {
+                order_no: "FLAKY_LOOKUP".to_string(),
+                order_time: "090000".to_string(),
+            }))
+        }
+
+        fn supports_client_order_lookup(&self) -> bool {
+            self.supports_lookup
+        }
+
+        fn exchange_name(&self) -> &str {
+            "FlakyExchange"
+        }
+    }
+
+    fn create_retry_executor(provider: Arc<FlakyOrderProvider>) -> LiveExecutor {
+        let conversion_config = ConversionConfig {
+            min_strength: 0.0,
+            auto_stop_loss: false,
+            auto_take_profit: false,
+            ..ConversionConfig::default()
+        };
+        LiveExecutor::with_conversion_config(
+            ProcessorConfig::default(),
+            dec!(10_000_000),
+            provider,
+            conversion_config,
+        )
+        .with_retry(RetryConfig {
+            max_attempts: 3,
+            base_delay_ms: 100,
+            max_delay_ms: 1_000,
+            jitter: false,
+        })
+    }
+
     fn create_test_signal(ticker: &str, side: Side, signal_type: SignalType) -> Signal {
         Signal::new("test_strategy", ticker.to_string(), side, signal_type)
     }
@@ -1019,4 +1758,342 @@ mod tests {
         let avg_price = executor.positions().get("005930").unwrap().entry_price;
         assert!(avg_price < initial_price); // 낮은 가격에 추가 매수 → 평균 단가 하락
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_recovers_from_transient_errors() {
+        let provider = Arc::new(FlakyOrderProvider::new(
+            vec![
+                ProviderError::Api("API error 503: Service Unavailable".to_string()),
+                ProviderError::Network("connection reset".to_string()),
+            ],
+            false,
+            false,
+        ));
+        let mut executor = create_retry_executor(provider.clone());
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let result = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await;
+
+        assert!(result.unwrap().is_some());
+        assert_eq!(provider.place_calls(), 3);
+        // 모든 시도가 같은 client_order_id 사용 (거래소 길이 제한 이내)
+        let client_order_id = IdempotencyKey::order("sig", signal.id).client_order_id();
+        assert!(client_order_id.len() <= MAX_CLIENT_ORDER_ID_LEN);
+        assert_eq!(provider.accepted(), vec![client_order_id]);
+        // 재시도를 포함한 모든 거래소 요청이 신호 ID로 묶임
+        assert_eq!(provider.correlation_ids(), vec![signal.id.to_string(); 3]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_fatal_error_not_retried() {
+        let provider = Arc::new(FlakyOrderProvider::new(
+            vec![ProviderError::Api("Insufficient balance".to_string())],
+            false,
+            false,
+        ));
+        let mut executor = create_retry_executor(provider.clone());
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let result = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(SignalProcessorError::ExchangeError(_))
+        ));
+        assert_eq!(provider.place_calls(), 1);
+        assert!(executor.positions().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_accepted_order_not_resubmitted() {
+        let provider = Arc::new(FlakyOrderProvider::new(
+            vec![ProviderError::Timeout("read timeout".to_string())],
+            true,
+            true,
+        ));
+        let mut executor = create_retry_executor(provider.clone());
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let result = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await;
+
+        assert!(result.unwrap().is_some());
+        // 타임아웃 후 조회로 접수 확인 → 재전송 없음
+        assert_eq!(provider.place_calls(), 1);
+        assert_eq!(provider.accepted().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_without_lookup_aborts() {
+        let provider = Arc::new(FlakyOrderProvider::new(
+            vec![ProviderError::Timeout("read timeout".to_string())],
+            true,
+            false,
+        ));
+        let mut executor = create_retry_executor(provider.clone());
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let result = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await;
+
+        // 접수 여부를 확인할 수 없으면 중복 방지를 위해 재전송하지 않음
+        assert!(result.is_err());
+        assert_eq!(provider.place_calls(), 1);
+        assert_eq!(provider.lookup_calls(), 0);
+        assert!(executor.positions().is_empty());
+    }
+
+    fn order_update(
+        status: OrderStatusType,
+        filled_quantity: Decimal,
+        average_price: Option<Decimal>,
+    ) -> OrderUpdate {
+        OrderUpdate {
+            exchange: "MockExchange".to_string(),
+            order_id: "MOCK_001".to_string(),
+            client_order_id: None,
+            ticker: Some("005930".to_string()),
+            status,
+            filled_quantity,
+            average_price,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_order_update_corrects_fill_price() {
+        let mut executor = create_mock_executor(false);
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let trade = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+        let balance_before = executor.balance();
+        assert_eq!(executor.unconfirmed_entry_count(), 1);
+
+        // 진행 중 상태는 무시
+        assert!(!executor.apply_order_update(&order_update(
+            OrderStatusType::PartiallyFilled,
+            trade.quantity / dec!(2),
+            Some(dec!(50100)),
+        )));
+
+        let actual_price = trade.price + dec!(100);
+        assert!(executor.apply_order_update(&order_update(
+            OrderStatusType::Filled,
+            trade.quantity,
+            Some(actual_price),
+        )));
+
+        let position = executor.get_position("005930").unwrap();
+        assert_eq!(position.entry_price, actual_price);
+        let cost_diff = dec!(100) * trade.quantity;
+        let expected_balance =
+            balance_before - cost_diff - cost_diff * executor.config().commission_rate;
+        assert_eq!(executor.balance(), expected_balance);
+        assert_eq!(executor.unconfirmed_entry_count(), 0);
+
+        // 같은 주문의 중복 이벤트는 다시 반영하지 않음
+        assert!(!executor.apply_order_update(&order_update(
+            OrderStatusType::Filled,
+            trade.quantity,
+            Some(actual_price),
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_order_update_cancel_refunds_unfilled() {
+        let mut executor = create_mock_executor(false);
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let trade = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let (tx, mut rx) = broadcast::channel(8);
+        tx.send(order_update(
+            OrderStatusType::Cancelled,
+            Decimal::ZERO,
+            None,
+        ))
+        .unwrap();
+        assert_eq!(executor.drain_order_updates(&mut rx), 1);
+
+        assert!(executor.positions().is_empty());
+        assert_eq!(executor.balance(), dec!(10_000_000));
+        assert_eq!(executor.total_commission(), Decimal::ZERO);
+        assert!(trade.quantity > Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_order_update_partial_cancel_shrinks_position() {
+        let mut executor = create_mock_executor(false);
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let trade = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+        let filled = (trade.quantity / dec!(2)).floor();
+
+        assert!(executor.apply_order_update(&order_update(
+            OrderStatusType::Cancelled,
+            filled,
+            Some(trade.price),
+        )));
+
+        let position = executor.get_position("005930").unwrap();
+        assert_eq!(position.quantity, filled);
+        assert_eq!(position.entry_price, trade.price);
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_time_in_force_fails_before_submit() {
+        let provider = Arc::new(FlakyOrderProvider::new(Vec::new(), false, false));
+        let mut executor = create_retry_executor(provider.clone());
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry)
+            .with_strength(0.5)
+            .with_metadata(
+                crate::executor::TIME_IN_FORCE_METADATA_KEY,
+                serde_json::json!("IOC"),
+            );
+        let result = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await;
+
+        match result {
+            Err(SignalProcessorError::ExchangeError(msg)) => assert!(msg.contains("IOC")),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(provider.place_calls(), 0);
+        assert!(executor.positions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_paper_mode_routes_to_simulator() {
+        // 거래소 주문이 항상 실패하는 제공자 → Paper 모드는 거래소를 호출하지 않아야 함
+        let mut executor = create_mock_executor(true).with_mode(ExecutionMode::Paper);
+        assert!(executor.is_paper());
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let trade = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(trade.signal_type, SignalType::Entry);
+        assert_eq!(executor.positions().len(), 1);
+        assert_eq!(executor.trades().len(), 1);
+        assert!(executor.balance() < dec!(10_000_000));
+        // 실거래 장부는 변하지 않음
+        assert_eq!(executor.total_orders(), 0);
+        assert_eq!(executor.paper_executor().positions().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_switch_to_live_requires_confirmation() {
+        let mut executor = create_mock_executor(false).with_mode(ExecutionMode::Paper);
+        let prices = HashMap::new();
+
+        for confirmation in [None, Some("LIVE"), Some("yes")] {
+            let result = executor
+                .switch_mode(
+                    ExecutionMode::Live,
+                    PositionHandoff::Keep,
+                    confirmation,
+                    &prices,
+                    Utc::now(),
+                )
+                .await;
+            assert!(matches!(
+                result,
+                Err(SignalProcessorError::LiveConfirmationRequired { .. })
+            ));
+            assert_eq!(executor.mode(), ExecutionMode::Paper);
+        }
+
+        let phrase = executor.live_confirmation_phrase();
+        executor
+            .switch_mode(
+                ExecutionMode::Live,
+                PositionHandoff::Keep,
+                Some(&phrase),
+                &prices,
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(executor.mode(), ExecutionMode::Live);
+
+        // Paper 전환에는 확인이 필요 없음
+        executor
+            .switch_mode(
+                ExecutionMode::Paper,
+                PositionHandoff::Keep,
+                None,
+                &prices,
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+        assert!(executor.is_paper());
+    }
+
+    #[tokio::test]
+    async fn test_switch_mode_position_handoff() {
+        let mut executor = create_mock_executor(false);
+        let phrase = executor.live_confirmation_phrase();
+        let mut prices = HashMap::new();
+        prices.insert("005930".to_string(), dec!(51000));
+
+        // 실거래 포지션을 유지한 채 Paper 전환 → Paper 장부는 비어 있음
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap();
+        let closed = executor
+            .switch_mode(
+                ExecutionMode::Paper,
+                PositionHandoff::Keep,
+                None,
+                &prices,
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+        assert!(closed.is_empty());
+        assert!(executor.positions().is_empty());
+
+        // Paper 포지션을 청산하고 실거래 복귀 → 유지했던 실거래 포지션이 다시 보임
+        executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap();
+        let closed = executor
+            .switch_mode(
+                ExecutionMode::Live,
+                PositionHandoff::Liquidate,
+                Some(&phrase),
+                &prices,
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].signal_type, SignalType::Exit);
+        assert!(executor.paper_executor().positions().is_empty());
+        assert_eq!(executor.positions().len(), 1);
+        assert_eq!(executor.total_orders(), 1);
+    }
 }
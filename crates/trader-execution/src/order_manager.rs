@@ -3,18 +3,30 @@
 //! 제공 기능:
 //! - 주문 생명주기 추적
 //! - 주문 장부 유지 관리
-//! - 주문 이벤트 처리
+//! - 주문 이벤트 처리 (통합 주문 상태 스트림 연동)
+//! - OCO(One-Cancels-Other) 청산 주문 그룹
+//! - 주문 유효 기간(TIF) 적용: IOC/FOK 미체결 취소, GTD 만료
+//! - 주문 상태 영속화 및 재시작 복구 (체결 내역 대조, 중복 체결 방지)
 //! - 조회 기능
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use trader_core::{Order, OrderRequest, OrderStatus, OrderStatusType, Side};
+use tracing::{info, warn};
+use trader_core::{
+    ExchangeProvider, ExecutionHistoryRequest, Order, OrderExecutionProvider, OrderRequest,
+    OrderStatus, OrderStatusType, OrderUpdate, Side, TimeInForce, Trade,
+};
 use uuid::Uuid;
 
+use crate::executor::ExecutionError;
+use crate::order_store::{OrderStore, OrderStoreWriter};
+use crate::telemetry;
+
 /// 주문 관리자 에러 타입.
 #[derive(Debug, Error)]
 pub enum OrderManagerError {
@@ -29,6 +41,57 @@ pub enum OrderManagerError {
 
     #[error("Order is in final state: {0}")]
     OrderFinalized(Uuid),
+
+    #[error("Fill quantity {fill} exceeds remaining {remaining} for order {order_id}")]
+    FillExceedsRemaining {
+        order_id: Uuid,
+        fill: Decimal,
+        remaining: Decimal,
+    },
+
+    #[error("Invalid OCO group: {0}")]
+    InvalidOcoGroup(String),
+
+    #[error("Order store not configured")]
+    StoreNotConfigured,
+
+    #[error("Order store error: {0}")]
+    Store(String),
+
+    #[error("Execution sync failed: {0}")]
+    ExecutionSync(String),
+}
+
+/// 주문 유효 기간(TIF) 규칙으로 종료된 주문.
+///
+/// 내부 상태는 이미 종료 처리되었으므로, 호출자는 거래소에 남은 주문을 취소해야 한다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeInForceAction {
+    /// 내부 주문 ID
+    pub order_id: Uuid,
+    /// 거래소 주문 ID (제출된 경우)
+    pub exchange_order_id: Option<String>,
+    /// 종목 티커
+    pub ticker: String,
+    /// 적용된 유효 기간
+    pub time_in_force: TimeInForce,
+    /// 종료 상태 (IOC/FOK: `Cancelled`, GTD: `Expired`)
+    pub status: OrderStatusType,
+}
+
+/// OCO 트리거로 내부에서 취소 처리된 청산 주문.
+///
+/// 거래소에는 아직 주문이 남아 있으므로 거래소 취소 요청이 필요하다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OcoCancelAction {
+    /// OCO 그룹 ID
+    pub oco_group_id: Uuid,
+    /// 내부 주문 ID
+    pub order_id: Uuid,
+    /// 거래소 주문 ID
+    pub exchange_order_id: String,
+    /// 종목 티커
+    pub ticker: String,
 }
 
 /// 변경 사항 추적을 위한 주문 이벤트 타입.
@@ -75,6 +138,52 @@ pub enum OrderEvent {
         order_id: Uuid,
         timestamp: DateTime<Utc>,
     },
+    /// 부분 체결 누적 (`record_partial_fill`)
+    PartiallyFilled {
+        order_id: Uuid,
+        /// 누적 체결 수량
+        filled: Decimal,
+        /// 잔여 수량
+        remaining: Decimal,
+        /// 가중평균 체결가 (VWAP)
+        avg_price: Decimal,
+        timestamp: DateTime<Utc>,
+    },
+    /// 부분 체결 누적 결과 전량 체결 (`record_partial_fill`)
+    FullyFilled {
+        order_id: Uuid,
+        /// 누적 체결 수량
+        filled: Decimal,
+        /// 가중평균 체결가 (VWAP)
+        avg_price: Decimal,
+        /// 체결 횟수
+        fill_count: u32,
+        timestamp: DateTime<Utc>,
+    },
+    /// OCO 그룹의 한쪽 청산 주문이 체결되어 반대쪽이 취소됨
+    OcoTriggered {
+        oco_group_id: Uuid,
+        /// 체결된 청산 주문
+        filled_id: Uuid,
+        /// 자동 취소된 청산 주문
+        cancelled_id: Uuid,
+        timestamp: DateTime<Utc>,
+    },
+    /// OCO 트리거로 취소 처리된 청산 주문에 늦게 도착한 체결
+    ///
+    /// 거래소에서는 두 청산 주문이 모두 체결된 상태이므로 포지션 확인이 필요하다.
+    OcoLateFill {
+        oco_group_id: Uuid,
+        /// 늦게 체결된 (취소 처리된) 청산 주문
+        order_id: Uuid,
+        /// 먼저 체결되어 OCO를 트리거한 청산 주문
+        filled_id: Uuid,
+        /// 체결 수량
+        quantity: Decimal,
+        /// 체결가
+        price: Decimal,
+        timestamp: DateTime<Utc>,
+    },
 }
 
 impl OrderEvent {
@@ -88,6 +197,10 @@ impl OrderEvent {
             OrderEvent::Cancelled { order_id, .. } => *order_id,
             OrderEvent::Rejected { order_id, .. } => *order_id,
             OrderEvent::Expired { order_id, .. } => *order_id,
+            OrderEvent::PartiallyFilled { order_id, .. } => *order_id,
+            OrderEvent::FullyFilled { order_id, .. } => *order_id,
+            OrderEvent::OcoTriggered { filled_id, .. } => *filled_id,
+            OrderEvent::OcoLateFill { order_id, .. } => *order_id,
         }
     }
 
@@ -101,6 +214,10 @@ impl OrderEvent {
             OrderEvent::Cancelled { timestamp, .. } => *timestamp,
             OrderEvent::Rejected { timestamp, .. } => *timestamp,
             OrderEvent::Expired { timestamp, .. } => *timestamp,
+            OrderEvent::PartiallyFilled { timestamp, .. } => *timestamp,
+            OrderEvent::FullyFilled { timestamp, .. } => *timestamp,
+            OrderEvent::OcoTriggered { timestamp, .. } => *timestamp,
+            OrderEvent::OcoLateFill { timestamp, .. } => *timestamp,
         }
     }
 }
@@ -122,6 +239,73 @@ pub struct OrderFill {
     pub timestamp: DateTime<Utc>,
 }
 
+/// 주문별 부분 체결 누적 상태.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FillProgress {
+    /// 누적 체결 수량
+    pub filled_quantity: Decimal,
+    /// 잔여 수량 (취소/거부/만료 시 0으로 정리)
+    pub remaining_quantity: Decimal,
+    /// 누적 체결 금액 (가격 × 수량 합계)
+    pub filled_notional: Decimal,
+    /// 체결 횟수
+    pub fill_count: u32,
+}
+
+impl FillProgress {
+    /// 가중평균 체결가 (VWAP). 체결이 없으면 `None`.
+    pub fn avg_price(&self) -> Option<Decimal> {
+        if self.filled_quantity.is_zero() {
+            None
+        } else {
+            Some(self.filled_notional / self.filled_quantity)
+        }
+    }
+}
+
+/// OCO(One-Cancels-Other) 주문 그룹.
+///
+/// 진입 주문과 함께 손절/익절 청산 주문을 한 쌍으로 묶습니다.
+/// 청산 주문 중 한쪽이 먼저 체결되면 다른 쪽은 자동 취소됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcoGroup {
+    /// OCO 그룹 ID
+    pub oco_group_id: Uuid,
+    /// 진입 주문 ID
+    pub entry_id: Uuid,
+    /// 손절 주문 ID
+    pub stop_loss_id: Uuid,
+    /// 익절 주문 ID
+    pub take_profit_id: Uuid,
+    /// 먼저 체결된 청산 주문 ID (트리거 전에는 `None`)
+    pub filled_id: Option<Uuid>,
+    /// 생성 시간
+    pub created_at: DateTime<Utc>,
+}
+
+impl OcoGroup {
+    /// 청산 주문의 반대쪽 주문 ID. 진입 주문이거나 그룹 외 주문이면 `None`.
+    pub fn sibling_of(&self, order_id: Uuid) -> Option<Uuid> {
+        if order_id == self.stop_loss_id {
+            Some(self.take_profit_id)
+        } else if order_id == self.take_profit_id {
+            Some(self.stop_loss_id)
+        } else {
+            None
+        }
+    }
+
+    /// 그룹에 속한 모든 주문 ID.
+    pub fn order_ids(&self) -> [Uuid; 3] {
+        [self.entry_id, self.stop_loss_id, self.take_profit_id]
+    }
+
+    /// 청산 주문 중 한쪽이 체결되어 그룹이 종료되었는지 여부.
+    pub fn is_triggered(&self) -> bool {
+        self.filled_id.is_some()
+    }
+}
+
 /// 모든 주문을 추적하는 주문 관리자.
 #[derive(Debug)]
 pub struct OrderManager {
@@ -139,8 +323,22 @@ pub struct OrderManager {
     events: Vec<OrderEvent>,
     /// 체결 이력
     fills: Vec<OrderFill>,
+    /// 주문별 부분 체결 누적 상태
+    fill_progress: HashMap<Uuid, FillProgress>,
+    /// OCO 그룹 (그룹 ID → 그룹)
+    oco_groups: HashMap<Uuid, OcoGroup>,
+    /// 주문 ID → 소속 OCO 그룹 ID
+    oco_membership: HashMap<Uuid, Uuid>,
     /// 최대 이력 크기
     max_history_size: usize,
+    /// 주문 상태 저장소 writer (영속화 미사용 시 `None`)
+    store: Option<OrderStoreWriter>,
+    /// 이미 반영한 거래소 체결 ID
+    processed_executions: HashSet<String>,
+    /// 체결 ID 기록 순서 (`max_history_size`를 넘으면 오래된 ID부터 제거)
+    processed_execution_order: VecDeque<String>,
+    /// 거래소 취소 대기 중인 OCO 청산 주문
+    pending_oco_cancels: Vec<OcoCancelAction>,
 }
 
 impl Default for OrderManager {
@@ -160,7 +358,14 @@ impl OrderManager {
             exchange_id_map: HashMap::new(),
             events: Vec::new(),
             fills: Vec::new(),
+            fill_progress: HashMap::new(),
+            oco_groups: HashMap::new(),
+            oco_membership: HashMap::new(),
             max_history_size: 10000,
+            store: None,
+            processed_executions: HashSet::new(),
+            processed_execution_order: VecDeque::new(),
+            pending_oco_cancels: Vec::new(),
         }
     }
 
@@ -172,6 +377,34 @@ impl OrderManager {
         }
     }
 
+    /// 주문 상태 저장소를 연결하여 생성한다.
+    ///
+    /// Tokio 런타임 안에서 호출해야 한다.
+    pub fn with_store(mut self, store: Arc<dyn OrderStore>) -> Self {
+        self.set_store(store);
+        self
+    }
+
+    /// 주문 상태 저장소를 연결한다.
+    ///
+    /// 이후 주문 생성·상태 변경·체결이 저장소에 기록된다.
+    /// Tokio 런타임 안에서 호출해야 한다.
+    pub fn set_store(&mut self, store: Arc<dyn OrderStore>) {
+        self.store = Some(OrderStoreWriter::spawn(store));
+    }
+
+    /// 저장소 연결 여부.
+    pub fn has_store(&self) -> bool {
+        self.store.is_some()
+    }
+
+    /// 대기 중인 저장 요청이 모두 기록될 때까지 대기한다.
+    pub async fn flush_store(&self) {
+        if let Some(writer) = &self.store {
+            writer.flush().await;
+        }
+    }
+
     // ==================== 주문 생성 ====================
 
     /// 요청으로부터 새 주문을 생성하고 추적한다.
@@ -226,6 +459,60 @@ impl OrderManager {
         Ok(())
     }
 
+    /// 진입 주문과 손절/익절 청산 주문을 OCO 그룹으로 생성한다.
+    ///
+    /// 세 주문 모두 추적에 추가되며, 청산 주문 중 한쪽이 체결되면
+    /// 다른 쪽은 자동 취소되고 `OrderEvent::OcoTriggered`가 방출된다.
+    /// 자동 취소된 주문의 거래소 취소는 [`Self::cancel_oco_legs_on_exchange`]로 보내며,
+    /// 그 전에 도착한 체결은 `OrderEvent::OcoLateFill`로 기록된다.
+    /// 진입 주문이 취소/거부/만료되면 두 청산 주문도 함께 취소된다.
+    pub fn submit_oco(
+        &mut self,
+        entry: OrderRequest,
+        stop_loss: OrderRequest,
+        take_profit: OrderRequest,
+        exchange: &str,
+    ) -> Result<OcoGroup, OrderManagerError> {
+        for (label, exit) in [("stop_loss", &stop_loss), ("take_profit", &take_profit)] {
+            if exit.ticker != entry.ticker {
+                return Err(OrderManagerError::InvalidOcoGroup(format!(
+                    "{} ticker {} differs from entry ticker {}",
+                    label, exit.ticker, entry.ticker
+                )));
+            }
+            if exit.side == entry.side {
+                return Err(OrderManagerError::InvalidOcoGroup(format!(
+                    "{} must be on the opposite side of entry",
+                    label
+                )));
+            }
+        }
+
+        let entry_order = Order::from_request(entry, exchange);
+        let stop_loss_order = Order::from_request(stop_loss, exchange);
+        let take_profit_order = Order::from_request(take_profit, exchange);
+
+        let group = OcoGroup {
+            oco_group_id: Uuid::new_v4(),
+            entry_id: entry_order.id,
+            stop_loss_id: stop_loss_order.id,
+            take_profit_id: take_profit_order.id,
+            filled_id: None,
+            created_at: Utc::now(),
+        };
+
+        self.add_order(entry_order)?;
+        self.add_order(stop_loss_order)?;
+        self.add_order(take_profit_order)?;
+
+        for order_id in group.order_ids() {
+            self.oco_membership.insert(order_id, group.oco_group_id);
+        }
+        self.oco_groups.insert(group.oco_group_id, group.clone());
+
+        Ok(group)
+    }
+
     // ==================== 주문 업데이트 ====================
 
     /// 거래소로부터 주문 상태를 업데이트한다.
@@ -234,6 +521,14 @@ impl OrderManager {
         order_id: Uuid,
         status: &OrderStatus,
     ) -> Result<(), OrderManagerError> {
+        if let Some((oco_group_id, filled_id)) = self.oco_cancelled_leg(order_id) {
+            if let Some(fill) = self.late_fill_from_status(order_id, status) {
+                return self
+                    .record_oco_late_fill(oco_group_id, filled_id, fill)
+                    .map(|_| ());
+            }
+        }
+
         // 먼저 주문이 존재하는지 확인하고 이전 상태를 가져옴
         let (old_status, needs_exchange_id_update) = {
             let order = self
@@ -290,6 +585,7 @@ impl OrderManager {
                     fill_price: status.average_price.unwrap_or(Decimal::ZERO),
                     timestamp: now,
                 });
+                self.trigger_oco(order_id, now);
             }
             OrderStatusType::Filled => {
                 self.record_event(OrderEvent::Filled {
@@ -298,6 +594,7 @@ impl OrderManager {
                     timestamp: now,
                 });
                 self.active_orders.remove(&order_id);
+                self.trigger_oco(order_id, now);
             }
             OrderStatusType::Cancelled => {
                 self.record_event(OrderEvent::Cancelled {
@@ -306,6 +603,8 @@ impl OrderManager {
                     timestamp: now,
                 });
                 self.active_orders.remove(&order_id);
+                self.clear_remaining(order_id);
+                self.cancel_oco_exits_for_entry(order_id);
             }
             OrderStatusType::Rejected => {
                 self.record_event(OrderEvent::Rejected {
@@ -314,6 +613,8 @@ impl OrderManager {
                     timestamp: now,
                 });
                 self.active_orders.remove(&order_id);
+                self.clear_remaining(order_id);
+                self.cancel_oco_exits_for_entry(order_id);
             }
             OrderStatusType::Expired => {
                 self.record_event(OrderEvent::Expired {
@@ -321,6 +622,8 @@ impl OrderManager {
                     timestamp: now,
                 });
                 self.active_orders.remove(&order_id);
+                self.clear_remaining(order_id);
+                self.cancel_oco_exits_for_entry(order_id);
             }
             OrderStatusType::Pending => {}
         }
@@ -335,79 +638,170 @@ impl OrderManager {
         Ok(())
     }
 
-    /// 주문에 대한 체결을 기록한다.
-    pub fn record_fill(&mut self, fill: OrderFill) -> Result<(), OrderManagerError> {
-        // 주문 존재 여부 확인
-        if !self.orders.contains_key(&fill.order_id) {
-            return Err(OrderManagerError::OrderNotFound(fill.order_id));
+    /// 통합 주문 상태 스트림의 이벤트를 적용한다.
+    ///
+    /// 거래소 주문 ID로 주문을 찾고, 없으면 클라이언트 주문 ID로 활성 주문을 찾는다.
+    /// 추적 중이지 않은 주문이거나 상태·체결수량이 그대로인 이벤트는 무시하고 `None`을 반환한다.
+    pub fn apply_order_update(
+        &mut self,
+        update: &OrderUpdate,
+    ) -> Result<Option<Uuid>, OrderManagerError> {
+        let order_id = self
+            .exchange_id_map
+            .get(&update.order_id)
+            .copied()
+            .or_else(|| {
+                let client_order_id = update.client_order_id.as_deref()?;
+                self.active_orders
+                    .values()
+                    .find(|o| o.client_order_id.as_deref() == Some(client_order_id))
+                    .map(|o| o.id)
+            });
+
+        let Some(order_id) = order_id else {
+            return Ok(None);
+        };
+
+        let unchanged = self.orders.get(&order_id).is_some_and(|o| {
+            o.status == update.status && o.filled_quantity == update.filled_quantity
+        });
+        if unchanged {
+            return Ok(None);
         }
 
-        // 업데이트 후 이벤트에 필요한 데이터 수집
-        let (new_filled, avg_price, is_fully_filled, was_partially_filled, order_quantity);
+        self.update_status(order_id, &update.to_order_status())?;
+        Ok(Some(order_id))
+    }
 
-        // 주문 업데이트 (위에서 contains_key 확인 후 가져오므로 논리적으로 안전)
-        {
+    /// 주문에 거래소 주문 ID를 연결한다.
+    ///
+    /// 정정으로 거래소 주문번호가 바뀐 경우에도 사용하며, 이전 번호의 매핑은 유지하여
+    /// 늦게 도착한 원주문 체결도 같은 주문으로 찾을 수 있게 한다.
+    pub fn bind_exchange_order_id(
+        &mut self,
+        order_id: Uuid,
+        exchange_order_id: &str,
+    ) -> Result<(), OrderManagerError> {
+        let updated = {
             let order = self
                 .orders
-                .get_mut(&fill.order_id)
-                .ok_or(OrderManagerError::OrderNotFound(fill.order_id))?;
+                .get_mut(&order_id)
+                .ok_or(OrderManagerError::OrderNotFound(order_id))?;
+            order.exchange_order_id = Some(exchange_order_id.to_string());
+            order.updated_at = Utc::now();
+            order.clone()
+        };
 
-            // 주문 체결 수량 및 평균 가격 업데이트
-            let old_filled = order.filled_quantity;
-            new_filled = old_filled + fill.quantity;
+        if let Some(active_order) = self.active_orders.get_mut(&order_id) {
+            *active_order = updated;
+        }
+        self.exchange_id_map
+            .insert(exchange_order_id.to_string(), order_id);
+        if let Some((oco_group_id, _)) = self.oco_cancelled_leg(order_id) {
+            self.queue_oco_cancel(oco_group_id, order_id);
+        }
+        Ok(())
+    }
 
-            // 새로운 평균 가격 계산
-            if let Some(old_avg) = order.average_fill_price {
-                let total_value = old_avg * old_filled + fill.price * fill.quantity;
-                order.average_fill_price = Some(total_value / new_filled);
-            } else {
-                order.average_fill_price = Some(fill.price);
-            }
+    /// 주문에 대한 체결을 기록한다.
+    ///
+    /// [`Self::record_partial_fill`]과 같은 체결 진행 추적 경로를 사용하므로
+    /// 누적 수량·가중평균 체결가 계산과 잔여 수량 초과 검증이 동일하게 적용된다.
+    pub fn record_fill(&mut self, fill: OrderFill) -> Result<(), OrderManagerError> {
+        self.record_partial_fill(fill.order_id, fill).map(|_| ())
+    }
 
-            avg_price = order.average_fill_price.unwrap_or(Decimal::ZERO);
-            order.filled_quantity = new_filled;
-            order.updated_at = fill.timestamp;
-            order_quantity = order.quantity;
-            was_partially_filled = order.status == OrderStatusType::PartiallyFilled;
-
-            // 완전 체결 여부 확인
-            is_fully_filled = new_filled >= order_quantity;
-            if is_fully_filled {
-                order.status = OrderStatusType::Filled;
-            } else if new_filled > Decimal::ZERO && !was_partially_filled {
-                order.status = OrderStatusType::PartiallyFilled;
-            }
+    /// 부분 체결을 누적 기록한다.
+    ///
+    /// 누적 체결 수량과 가중평균 체결가(VWAP)를 갱신하고,
+    /// 잔여 수량이 0이 되면 `FullyFilled`, 그 전까지는 `PartiallyFilled` 이벤트를 방출한다.
+    pub fn record_partial_fill(
+        &mut self,
+        order_id: Uuid,
+        mut fill: OrderFill,
+    ) -> Result<OrderEvent, OrderManagerError> {
+        fill.order_id = order_id;
+        if let Some((oco_group_id, filled_id)) = self.oco_cancelled_leg(order_id) {
+            return self.record_oco_late_fill(oco_group_id, filled_id, fill);
         }
 
-        // 이벤트 기록 (주문 빌림이 해제되어 안전)
-        if is_fully_filled {
-            self.active_orders.remove(&fill.order_id);
-            self.record_event(OrderEvent::Filled {
-                order_id: fill.order_id,
-                avg_price,
-                timestamp: fill.timestamp,
+        let order = self
+            .orders
+            .get_mut(&order_id)
+            .ok_or(OrderManagerError::OrderNotFound(order_id))?;
+
+        if order.status.is_final() {
+            return Err(OrderManagerError::OrderFinalized(order_id));
+        }
+
+        let progress = self
+            .fill_progress
+            .entry(order_id)
+            .or_insert_with(|| FillProgress {
+                filled_quantity: order.filled_quantity,
+                remaining_quantity: order.remaining_quantity(),
+                filled_notional: order
+                    .average_fill_price
+                    .map(|p| p * order.filled_quantity)
+                    .unwrap_or(Decimal::ZERO),
+                fill_count: 0,
             });
-        } else if new_filled > Decimal::ZERO && !was_partially_filled {
-            self.record_event(OrderEvent::PartialFill {
-                order_id: fill.order_id,
-                filled_qty: new_filled,
-                fill_price: fill.price,
-                timestamp: fill.timestamp,
+
+        if fill.quantity > progress.remaining_quantity {
+            return Err(OrderManagerError::FillExceedsRemaining {
+                order_id,
+                fill: fill.quantity,
+                remaining: progress.remaining_quantity,
             });
         }
 
-        // 활성 주문 업데이트
-        if let Some(order) = self.orders.get(&fill.order_id) {
-            if let Some(active_order) = self.active_orders.get_mut(&fill.order_id) {
+        progress.filled_quantity += fill.quantity;
+        progress.remaining_quantity -= fill.quantity;
+        progress.filled_notional += fill.price * fill.quantity;
+        progress.fill_count += 1;
+
+        let avg_price = progress.avg_price().unwrap_or(fill.price);
+        let is_fully_filled = progress.remaining_quantity.is_zero();
+        let progress = progress.clone();
+
+        order.filled_quantity = progress.filled_quantity;
+        order.average_fill_price = Some(avg_price);
+        order.updated_at = fill.timestamp;
+        order.status = if is_fully_filled {
+            OrderStatusType::Filled
+        } else {
+            OrderStatusType::PartiallyFilled
+        };
+
+        let event = if is_fully_filled {
+            self.active_orders.remove(&order_id);
+            OrderEvent::FullyFilled {
+                order_id,
+                filled: progress.filled_quantity,
+                avg_price,
+                fill_count: progress.fill_count,
+                timestamp: fill.timestamp,
+            }
+        } else {
+            if let Some(active_order) = self.active_orders.get_mut(&order_id) {
                 *active_order = order.clone();
             }
-        }
+            OrderEvent::PartiallyFilled {
+                order_id,
+                filled: progress.filled_quantity,
+                remaining: progress.remaining_quantity,
+                avg_price,
+                timestamp: fill.timestamp,
+            }
+        };
 
-        // 체결 저장
+        self.record_event(event.clone());
+        self.trigger_oco(order_id, fill.timestamp);
+        self.persist_fill(&fill);
         self.fills.push(fill);
         self.trim_history();
 
-        Ok(())
+        Ok(event)
     }
 
     /// 주문을 취소한다.
@@ -429,12 +823,14 @@ impl OrderManager {
         order.updated_at = Utc::now();
 
         self.active_orders.remove(&order_id);
+        self.clear_remaining(order_id);
 
         self.record_event(OrderEvent::Cancelled {
             order_id,
             reason,
             timestamp: Utc::now(),
         });
+        self.cancel_oco_exits_for_entry(order_id);
 
         Ok(())
     }
@@ -458,6 +854,7 @@ impl OrderManager {
         order.updated_at = Utc::now();
 
         self.active_orders.remove(&order_id);
+        self.clear_remaining(order_id);
 
         let reason_str = reason.into();
         self.record_event(OrderEvent::Rejected {
@@ -465,10 +862,358 @@ impl OrderManager {
             reason: reason_str,
             timestamp: Utc::now(),
         });
+        self.cancel_oco_exits_for_entry(order_id);
+
+        Ok(())
+    }
+
+    /// 주문을 만료 처리한다.
+    pub fn expire_order(
+        &mut self,
+        order_id: Uuid,
+        timestamp: DateTime<Utc>,
+    ) -> Result<(), OrderManagerError> {
+        let order = self
+            .orders
+            .get_mut(&order_id)
+            .ok_or(OrderManagerError::OrderNotFound(order_id))?;
+
+        if order.status.is_final() {
+            return Err(OrderManagerError::OrderFinalized(order_id));
+        }
+
+        order.status = OrderStatusType::Expired;
+        order.updated_at = timestamp;
+
+        self.active_orders.remove(&order_id);
+        self.clear_remaining(order_id);
+
+        self.record_event(OrderEvent::Expired {
+            order_id,
+            timestamp,
+        });
+        self.cancel_oco_exits_for_entry(order_id);
 
         Ok(())
     }
 
+    /// 활성 주문에 유효 기간(TIF) 규칙을 적용한다.
+    ///
+    /// - IOC/FOK: 거래소에 제출된 뒤에도 체결이 끝나지 않은 주문을 취소
+    ///   (IOC는 미체결 잔량, FOK는 전량 체결 실패로 간주)
+    /// - GTD: 만료 시각이 지난 주문을 만료 처리하고 `OrderEvent::Expired` 기록
+    ///
+    /// 아직 제출 전(`Pending`)인 IOC/FOK 주문은 건드리지 않는다.
+    /// 거래소 제출 응답과 즉시 체결 결과를 반영한 뒤 호출해야 한다.
+    pub fn enforce_time_in_force(&mut self, now: DateTime<Utc>) -> Vec<TimeInForceAction> {
+        let mut targets: Vec<(Uuid, OrderStatusType)> = self
+            .active_orders
+            .values()
+            .filter_map(|order| {
+                if order.time_in_force.is_immediate() && order.status != OrderStatusType::Pending {
+                    Some((order.id, OrderStatusType::Cancelled))
+                } else if order.time_in_force.is_expired_at(now) {
+                    Some((order.id, OrderStatusType::Expired))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        targets.sort_by_key(|(order_id, _)| *order_id);
+
+        let mut actions = Vec::with_capacity(targets.len());
+        for (order_id, status) in targets {
+            let Some(order) = self.orders.get(&order_id).cloned() else {
+                continue;
+            };
+
+            let result = if status == OrderStatusType::Expired {
+                self.expire_order(order_id, now)
+            } else {
+                let reason = match order.time_in_force {
+                    TimeInForce::FOK => "FOK: 전량 즉시 체결 실패",
+                    _ => "IOC: 즉시 체결되지 않은 잔량 취소",
+                };
+                self.cancel_order(order_id, Some(reason.to_string()))
+            };
+
+            // 같은 호출 안에서 OCO 연쇄 취소로 이미 종료된 주문은 건너뜀
+            if result.is_err() {
+                continue;
+            }
+
+            info!(
+                order_id = %order_id,
+                ticker = %order.ticker,
+                time_in_force = order.time_in_force.code(),
+                filled_quantity = %order.filled_quantity,
+                status = ?status,
+                "주문 유효 기간 적용"
+            );
+            actions.push(TimeInForceAction {
+                order_id,
+                exchange_order_id: order.exchange_order_id,
+                ticker: order.ticker,
+                time_in_force: order.time_in_force,
+                status,
+            });
+        }
+
+        actions
+    }
+
+    // ==================== 영속화 / 복구 ====================
+
+    /// 저장소에서 미완료 주문을 불러와 추적을 재개한다.
+    ///
+    /// 이미 추적 중인 주문은 건너뛰며, 복구된 주문의 처리된 체결 ID도 함께 불러온다.
+    /// 복구한 주문 수를 반환한다.
+    pub async fn restore_open_orders(&mut self) -> Result<usize, OrderManagerError> {
+        let store: Arc<dyn OrderStore> = self
+            .store
+            .as_ref()
+            .map(|writer| Arc::clone(writer.store()))
+            .ok_or(OrderManagerError::StoreNotConfigured)?;
+
+        let orders = store
+            .load_open_orders()
+            .await
+            .map_err(|e| OrderManagerError::Store(e.to_string()))?;
+
+        let mut restored_ids = Vec::new();
+        for order in orders {
+            if !order.status.is_active() || self.orders.contains_key(&order.id) {
+                continue;
+            }
+            restored_ids.push(order.id);
+            self.index_order(order);
+        }
+
+        if !restored_ids.is_empty() {
+            let processed = store
+                .load_processed_execution_ids(&restored_ids)
+                .await
+                .map_err(|e| OrderManagerError::Store(e.to_string()))?;
+            for execution_id in processed {
+                self.remember_execution(execution_id);
+            }
+        }
+
+        info!(
+            restored = restored_ids.len(),
+            processed_executions = self.processed_executions.len(),
+            "미완료 주문 복구"
+        );
+        Ok(restored_ids.len())
+    }
+
+    /// 거래소 체결 ID와 함께 체결을 기록한다.
+    ///
+    /// 이미 처리된 체결 ID면 무시하고 `false`를 반환한다.
+    /// 처리한 체결 ID는 저장소에도 기록되어 재시작 후에도 중복 반영되지 않는다.
+    pub fn record_execution_fill(
+        &mut self,
+        execution_id: &str,
+        fill: OrderFill,
+    ) -> Result<bool, OrderManagerError> {
+        if self.processed_executions.contains(execution_id) {
+            return Ok(false);
+        }
+
+        let order_id = fill.order_id;
+        self.record_fill(fill)?;
+        self.remember_execution(execution_id.to_string());
+        if let Some(writer) = &self.store {
+            writer.mark_execution(execution_id, order_id);
+        }
+        Ok(true)
+    }
+
+    /// 처리한 체결 ID 기록.
+    ///
+    /// 메모리에는 최근 `max_history_size`개만 유지한다. 오래된 체결 ID는 저장소에 남아 있어
+    /// 재시작 시 미완료 주문 기준으로 다시 불러오며, 완료된 주문의 늦은 체결은
+    /// 체결 진행 추적에서 거부되므로 중복 반영되지 않는다.
+    fn remember_execution(&mut self, execution_id: String) {
+        if !self.processed_executions.insert(execution_id.clone()) {
+            return;
+        }
+        self.processed_execution_order.push_back(execution_id);
+        while self.processed_execution_order.len() > self.max_history_size {
+            if let Some(oldest) = self.processed_execution_order.pop_front() {
+                self.processed_executions.remove(&oldest);
+            }
+        }
+    }
+
+    /// 체결 ID가 이미 처리되었는지 여부.
+    pub fn is_execution_processed(&self, execution_id: &str) -> bool {
+        self.processed_executions.contains(execution_id)
+    }
+
+    /// 거래소 체결 내역을 활성 주문에 반영한다.
+    ///
+    /// 체결 시각 순으로 적용하며, 처리된 체결 ID와 활성 주문에 매칭되지 않는 체결은 건너뛴다.
+    /// 주문 단위로 집계된 체결(누적 수량)은 이미 반영된 수량과의 차이만 체결로 기록한다.
+    pub fn apply_executions(&mut self, trades: &[Trade]) -> ExecutionSyncReport {
+        let mut sorted: Vec<&Trade> = trades.iter().collect();
+        sorted.sort_by(|a, b| {
+            a.executed_at
+                .cmp(&b.executed_at)
+                .then_with(|| a.exchange_trade_id.cmp(&b.exchange_trade_id))
+        });
+
+        let mut report = ExecutionSyncReport::default();
+        for trade in sorted {
+            let Some((order_id, cumulative)) = self.match_execution(trade) else {
+                report.unmatched += 1;
+                continue;
+            };
+            let Some(order) = self.orders.get(&order_id) else {
+                report.unmatched += 1;
+                continue;
+            };
+
+            let (execution_id, quantity, price) = if cumulative {
+                // 누적 체결: 새로 늘어난 수량과 그 구간의 평균가를 역산
+                let delta = trade.quantity - order.filled_quantity;
+                let filled_notional = order
+                    .average_fill_price
+                    .map(|p| p * order.filled_quantity)
+                    .unwrap_or(Decimal::ZERO);
+                let price = if delta > Decimal::ZERO {
+                    (trade.price * trade.quantity - filled_notional) / delta
+                } else {
+                    trade.price
+                };
+                (
+                    format!("{}@{}", trade.exchange_trade_id, trade.quantity.normalize()),
+                    delta,
+                    price,
+                )
+            } else {
+                (trade.exchange_trade_id.clone(), trade.quantity, trade.price)
+            };
+            let quantity = quantity.min(order.remaining_quantity());
+
+            if quantity <= Decimal::ZERO || self.is_execution_processed(&execution_id) {
+                report.duplicates += 1;
+                continue;
+            }
+
+            let fill = OrderFill {
+                order_id,
+                quantity,
+                price,
+                commission: (!trade.fee.is_zero()).then_some(trade.fee),
+                commission_asset: (!trade.fee.is_zero()).then(|| trade.fee_currency.clone()),
+                timestamp: trade.executed_at,
+            };
+
+            match self.record_execution_fill(&execution_id, fill) {
+                Ok(true) => report.applied += 1,
+                Ok(false) => report.duplicates += 1,
+                Err(e) => {
+                    warn!(
+                        order_id = %order_id,
+                        execution_id = %execution_id,
+                        error = %e,
+                        "체결 내역 반영 실패"
+                    );
+                    report.failed += 1;
+                }
+            }
+        }
+
+        report
+    }
+
+    /// 거래소 체결 내역을 조회하여 활성 주문 상태를 동기화한다.
+    ///
+    /// 재시작 동안 발생한 체결을 반영하기 위해 `restore_open_orders` 직후 호출한다.
+    /// 커서가 끝날 때까지 모든 페이지를 조회한 뒤 [`Self::apply_executions`]로 반영한다.
+    pub async fn sync_executions(
+        &mut self,
+        provider: &dyn ExchangeProvider,
+        mut request: ExecutionHistoryRequest,
+    ) -> Result<ExecutionSyncReport, OrderManagerError> {
+        let mut trades = Vec::new();
+        loop {
+            let response = provider
+                .fetch_execution_history(&request)
+                .await
+                .map_err(|e| OrderManagerError::ExecutionSync(e.to_string()))?;
+            trades.extend(response.trades);
+            match response.next_cursor {
+                Some(cursor) => request.cursor = Some(cursor),
+                None => break,
+            }
+        }
+
+        let report = self.apply_executions(&trades);
+        info!(
+            exchange = provider.exchange_name(),
+            fetched = trades.len(),
+            applied = report.applied,
+            duplicates = report.duplicates,
+            unmatched = report.unmatched,
+            failed = report.failed,
+            "체결 내역 동기화"
+        );
+        Ok(report)
+    }
+
+    /// 거래소 취소가 필요한 OCO 청산 주문을 꺼낸다.
+    ///
+    /// OCO 트리거 시 반대쪽 청산 주문은 내부에서만 취소 처리되므로,
+    /// 직접 취소 요청을 보내는 호출자가 사용한다.
+    pub fn take_oco_cancel_actions(&mut self) -> Vec<OcoCancelAction> {
+        std::mem::take(&mut self.pending_oco_cancels)
+    }
+
+    /// OCO 트리거로 취소 처리한 청산 주문을 거래소에서도 취소한다.
+    ///
+    /// 네트워크 오류·타임아웃처럼 재시도 가능한 실패는 대기열에 다시 넣어 다음 호출에서 재시도하고,
+    /// 이미 체결된 주문 등 재시도해도 실패하는 요청은 버린다. 취소에 성공한 주문 수를 반환한다.
+    pub async fn cancel_oco_legs_on_exchange(
+        &mut self,
+        provider: &dyn OrderExecutionProvider,
+    ) -> usize {
+        let mut cancelled = 0;
+        for action in self.take_oco_cancel_actions() {
+            match provider
+                .cancel_order(&action.exchange_order_id, &action.ticker)
+                .await
+            {
+                Ok(()) => {
+                    info!(
+                        oco_group_id = %action.oco_group_id,
+                        order_id = %action.order_id,
+                        exchange_order_id = %action.exchange_order_id,
+                        "OCO 청산 주문 거래소 취소"
+                    );
+                    cancelled += 1;
+                }
+                Err(e) => {
+                    let error = e.to_string();
+                    let retryable = ExecutionError::from(e).is_retryable();
+                    warn!(
+                        oco_group_id = %action.oco_group_id,
+                        order_id = %action.order_id,
+                        exchange_order_id = %action.exchange_order_id,
+                        error = %error,
+                        retryable,
+                        "OCO 청산 주문 거래소 취소 실패"
+                    );
+                    if retryable {
+                        self.pending_oco_cancels.push(action);
+                    }
+                }
+            }
+        }
+        cancelled
+    }
+
     // ==================== 조회 ====================
 
     /// ID로 주문을 가져온다.
@@ -555,6 +1300,37 @@ impl OrderManager {
             .collect()
     }
 
+    /// 주문의 부분 체결 누적 상태를 가져온다.
+    pub fn get_fill_progress(&self, order_id: Uuid) -> Option<&FillProgress> {
+        self.fill_progress.get(&order_id)
+    }
+
+    /// 주문의 잔여 수량을 가져온다 (최종 상태 주문은 0).
+    pub fn remaining_quantity(&self, order_id: Uuid) -> Option<Decimal> {
+        if let Some(progress) = self.fill_progress.get(&order_id) {
+            return Some(progress.remaining_quantity);
+        }
+        self.orders.get(&order_id).map(|order| {
+            if order.status.is_final() {
+                Decimal::ZERO
+            } else {
+                order.remaining_quantity()
+            }
+        })
+    }
+
+    /// OCO 그룹을 가져온다.
+    pub fn get_oco_group(&self, oco_group_id: Uuid) -> Option<&OcoGroup> {
+        self.oco_groups.get(&oco_group_id)
+    }
+
+    /// 주문이 속한 OCO 그룹을 가져온다.
+    pub fn get_oco_group_for_order(&self, order_id: Uuid) -> Option<&OcoGroup> {
+        self.oco_membership
+            .get(&order_id)
+            .and_then(|group_id| self.oco_groups.get(group_id))
+    }
+
     // ==================== 통계 ====================
 
     /// 심볼에 대한 통계를 가져온다.
@@ -606,6 +1382,18 @@ impl OrderManager {
             .filter_map(|o| o.average_fill_price.map(|p| p * o.filled_quantity))
             .sum();
 
+        let fill_counts: Vec<u32> = orders
+            .iter()
+            .filter_map(|o| self.fill_progress.get(&o.id))
+            .map(|p| p.fill_count)
+            .filter(|count| *count > 0)
+            .collect();
+        let avg_fills_per_order = if fill_counts.is_empty() {
+            0.0
+        } else {
+            fill_counts.iter().map(|c| *c as f64).sum::<f64>() / fill_counts.len() as f64
+        };
+
         OrderStats {
             total_orders: total,
             filled_orders: filled,
@@ -621,39 +1409,373 @@ impl OrderManager {
             } else {
                 0.0
             },
+            avg_fills_per_order,
         }
     }
 
     // ==================== 내부 ====================
 
-    fn record_event(&mut self, event: OrderEvent) {
-        self.events.push(event);
-        self.trim_history();
+    /// 최종 상태로 전환된 주문의 잔여 수량을 0으로 정리한다.
+    fn clear_remaining(&mut self, order_id: Uuid) {
+        if let Some(progress) = self.fill_progress.get_mut(&order_id) {
+            progress.remaining_quantity = Decimal::ZERO;
+        }
     }
 
-    fn trim_history(&mut self) {
-        if self.events.len() > self.max_history_size {
-            let drain_count = self.events.len() - self.max_history_size;
-            self.events.drain(0..drain_count);
+    /// OCO 그룹이 반대쪽 체결로 트리거되어 취소 처리된 청산 주문이면
+    /// `(OCO 그룹 ID, 먼저 체결된 주문 ID)`를 반환한다.
+    fn oco_cancelled_leg(&self, order_id: Uuid) -> Option<(Uuid, Uuid)> {
+        let group = self.get_oco_group_for_order(order_id)?;
+        if group.entry_id == order_id {
+            return None;
         }
-        if self.fills.len() > self.max_history_size {
-            let drain_count = self.fills.len() - self.max_history_size;
-            self.fills.drain(0..drain_count);
+        group
+            .filled_id
+            .filter(|filled_id| *filled_id != order_id)
+            .map(|filled_id| (group.oco_group_id, filled_id))
+    }
+
+    /// 거래소 누적 체결 상태에서 새로 늘어난 체결분을 계산한다.
+    fn late_fill_from_status(&self, order_id: Uuid, status: &OrderStatus) -> Option<OrderFill> {
+        let order = self.orders.get(&order_id)?;
+        let quantity = status.filled_quantity - order.filled_quantity;
+        if quantity <= Decimal::ZERO {
+            return None;
         }
+        let filled_notional = order
+            .average_fill_price
+            .map(|p| p * order.filled_quantity)
+            .unwrap_or(Decimal::ZERO);
+        let price = status
+            .average_price
+            .map(|avg| (avg * status.filled_quantity - filled_notional) / quantity)
+            .unwrap_or(Decimal::ZERO);
+        Some(OrderFill {
+            order_id,
+            quantity,
+            price,
+            commission: None,
+            commission_asset: None,
+            timestamp: Utc::now(),
+        })
     }
 
-    /// 특정 기간보다 오래된 완료된 주문을 정리한다 (메모리 관리용).
-    pub fn cleanup_old_orders(&mut self, older_than: DateTime<Utc>) {
-        // 제거할 주문 찾기
-        let orders_to_remove: Vec<Uuid> = self
+    /// OCO 트리거로 취소 처리된 청산 주문의 늦은 체결을 기록한다.
+    ///
+    /// 두 청산 주문이 거의 동시에 체결되면 거래소에서는 양쪽 모두 체결된 것이므로,
+    /// 체결을 버리지 않고 누적 수량·가중평균 체결가에 반영한 뒤 `OcoLateFill` 이벤트로 알린다.
+    /// 주문 수량을 모두 채우면 `Filled`, 그 전까지는 `Cancelled` 상태를 유지한다.
+    fn record_oco_late_fill(
+        &mut self,
+        oco_group_id: Uuid,
+        filled_id: Uuid,
+        fill: OrderFill,
+    ) -> Result<OrderEvent, OrderManagerError> {
+        let order_id = fill.order_id;
+        let order = self
             .orders
-            .iter()
-            .filter(|(_, o)| o.status.is_final() && o.updated_at < older_than)
-            .map(|(id, _)| *id)
-            .collect();
+            .get_mut(&order_id)
+            .ok_or(OrderManagerError::OrderNotFound(order_id))?;
+
+        let remaining = order.remaining_quantity();
+        if fill.quantity > remaining {
+            return Err(OrderManagerError::FillExceedsRemaining {
+                order_id,
+                fill: fill.quantity,
+                remaining,
+            });
+        }
+
+        let progress = self
+            .fill_progress
+            .entry(order_id)
+            .or_insert_with(|| FillProgress {
+                filled_quantity: order.filled_quantity,
+                remaining_quantity: Decimal::ZERO,
+                filled_notional: order
+                    .average_fill_price
+                    .map(|p| p * order.filled_quantity)
+                    .unwrap_or(Decimal::ZERO),
+                fill_count: 0,
+            });
+        progress.filled_quantity += fill.quantity;
+        progress.filled_notional += fill.price * fill.quantity;
+        progress.fill_count += 1;
+
+        order.filled_quantity = progress.filled_quantity;
+        order.average_fill_price = Some(progress.avg_price().unwrap_or(fill.price));
+        order.updated_at = fill.timestamp;
+        if order.remaining_quantity().is_zero() {
+            order.status = OrderStatusType::Filled;
+        }
+
+        warn!(
+            oco_group_id = %oco_group_id,
+            order_id = %order_id,
+            filled_id = %filled_id,
+            quantity = %fill.quantity,
+            price = %fill.price,
+            "OCO 양쪽 청산 주문 체결: 취소 처리된 주문의 늦은 체결 기록, 포지션 확인 필요"
+        );
+
+        let event = OrderEvent::OcoLateFill {
+            oco_group_id,
+            order_id,
+            filled_id,
+            quantity: fill.quantity,
+            price: fill.price,
+            timestamp: fill.timestamp,
+        };
+        self.record_event(event.clone());
+        self.persist_fill(&fill);
+        self.fills.push(fill);
+        self.trim_history();
+
+        Ok(event)
+    }
+
+    /// OCO 트리거로 취소 처리한 청산 주문의 거래소 취소를 대기열에 넣는다.
+    ///
+    /// 아직 거래소 주문 ID가 없으면 거래소에 주문이 없으므로 넣지 않는다.
+    /// 이후 [`Self::bind_exchange_order_id`]로 연결되면 그때 대기열에 넣는다.
+    fn queue_oco_cancel(&mut self, oco_group_id: Uuid, order_id: Uuid) {
+        let Some(order) = self.orders.get(&order_id) else {
+            return;
+        };
+        if order.remaining_quantity().is_zero() {
+            return;
+        }
+        let Some(exchange_order_id) = order.exchange_order_id.clone() else {
+            return;
+        };
+        if self
+            .pending_oco_cancels
+            .iter()
+            .any(|a| a.order_id == order_id && a.exchange_order_id == exchange_order_id)
+        {
+            return;
+        }
+        self.pending_oco_cancels.push(OcoCancelAction {
+            oco_group_id,
+            order_id,
+            exchange_order_id,
+            ticker: order.ticker.clone(),
+        });
+    }
+
+    /// 청산 주문 체결 시 OCO 그룹을 트리거하여 반대쪽 주문을 취소한다.
+    fn trigger_oco(&mut self, filled_id: Uuid, timestamp: DateTime<Utc>) {
+        let Some(group_id) = self.oco_membership.get(&filled_id).copied() else {
+            return;
+        };
+        let Some(group) = self.oco_groups.get_mut(&group_id) else {
+            return;
+        };
+        if group.is_triggered() {
+            return;
+        }
+        let Some(cancelled_id) = group.sibling_of(filled_id) else {
+            return;
+        };
+        group.filled_id = Some(filled_id);
+
+        if let Some(order) = self.orders.get_mut(&cancelled_id) {
+            if !order.status.is_final() {
+                order.status = OrderStatusType::Cancelled;
+                order.updated_at = timestamp;
+                self.active_orders.remove(&cancelled_id);
+                self.clear_remaining(cancelled_id);
+                self.record_event(OrderEvent::Cancelled {
+                    order_id: cancelled_id,
+                    reason: Some("OCO: opposite leg filled".to_string()),
+                    timestamp,
+                });
+                self.queue_oco_cancel(group_id, cancelled_id);
+            }
+        }
+
+        info!(
+            oco_group_id = %group_id,
+            filled_id = %filled_id,
+            cancelled_id = %cancelled_id,
+            "OCO 트리거: 반대쪽 청산 주문 취소"
+        );
+        self.record_event(OrderEvent::OcoTriggered {
+            oco_group_id: group_id,
+            filled_id,
+            cancelled_id,
+            timestamp,
+        });
+    }
+
+    /// OCO 진입 주문이 체결 없이 종료되면 두 청산 주문도 취소한다.
+    fn cancel_oco_exits_for_entry(&mut self, entry_id: Uuid) {
+        let Some(group) = self.get_oco_group_for_order(entry_id) else {
+            return;
+        };
+        if group.entry_id != entry_id {
+            return;
+        }
+        let exit_ids = [group.stop_loss_id, group.take_profit_id];
+        let has_entry_fill = self
+            .orders
+            .get(&entry_id)
+            .is_some_and(|o| o.filled_quantity > Decimal::ZERO);
+        if has_entry_fill {
+            return;
+        }
+
+        for exit_id in exit_ids {
+            let is_open = self
+                .orders
+                .get(&exit_id)
+                .is_some_and(|o| !o.status.is_final());
+            if is_open {
+                if let Err(e) =
+                    self.cancel_order(exit_id, Some("OCO: entry not filled".to_string()))
+                {
+                    warn!(order_id = %exit_id, error = %e, "OCO 청산 주문 취소 실패");
+                }
+            }
+        }
+    }
+
+    fn record_event(&mut self, event: OrderEvent) {
+        self.persist_order(event.order_id());
+        self.record_metrics(&event);
+        self.events.push(event);
+        self.trim_history();
+    }
+
+    /// 주문 이벤트를 전략·거래소 라벨의 Prometheus 메트릭으로 기록한다.
+    fn record_metrics(&self, event: &OrderEvent) {
+        let Some(order) = self.orders.get(&event.order_id()) else {
+            return;
+        };
+        let strategy = order.strategy_id.as_deref();
+        let exchange = order.exchange.as_str();
+
+        let name = match event {
+            OrderEvent::Created { .. } => "created",
+            OrderEvent::Submitted { .. } => "submitted",
+            OrderEvent::Cancelled { .. } => "cancelled",
+            OrderEvent::Rejected { .. } => "rejected",
+            OrderEvent::Expired { .. } => "expired",
+            OrderEvent::PartialFill { .. } | OrderEvent::PartiallyFilled { .. } => {
+                telemetry::record_order_fill(strategy, exchange);
+                return;
+            }
+            OrderEvent::Filled { timestamp, .. } | OrderEvent::FullyFilled { timestamp, .. } => {
+                telemetry::record_order_fill(strategy, exchange);
+                let latency = (*timestamp - order.created_at).num_milliseconds() as f64 / 1000.0;
+                telemetry::record_fill_latency(strategy, exchange, latency);
+                "filled"
+            }
+            OrderEvent::OcoLateFill { .. } => {
+                telemetry::record_order_fill(strategy, exchange);
+                return;
+            }
+            OrderEvent::OcoTriggered { .. } => return,
+        };
+        telemetry::record_order_event(strategy, exchange, name);
+    }
+
+    /// 주문 스냅샷을 저장소에 기록한다.
+    fn persist_order(&self, order_id: Uuid) {
+        if let (Some(writer), Some(order)) = (&self.store, self.orders.get(&order_id)) {
+            writer.save_order(order);
+        }
+    }
+
+    /// 체결과 갱신된 주문 스냅샷을 저장소에 기록한다.
+    fn persist_fill(&self, fill: &OrderFill) {
+        if let Some(writer) = &self.store {
+            writer.save_fill(fill);
+        }
+        self.persist_order(fill.order_id);
+    }
+
+    /// 추적 인덱스에 주문을 등록한다 (이벤트 기록 없음).
+    fn index_order(&mut self, order: Order) {
+        let order_id = order.id;
+        if let Some(exchange_id) = &order.exchange_order_id {
+            self.exchange_id_map.insert(exchange_id.clone(), order_id);
+        }
+        self.orders_by_symbol
+            .entry(order.ticker.clone())
+            .or_default()
+            .push(order_id);
+        if let Some(strategy_id) = &order.strategy_id {
+            self.orders_by_strategy
+                .entry(strategy_id.clone())
+                .or_default()
+                .push(order_id);
+        }
+        if order.status.is_active() {
+            self.active_orders.insert(order_id, order.clone());
+        }
+        self.orders.insert(order_id, order);
+    }
+
+    /// 체결 내역에 대응하는 활성 주문과 누적 체결 여부를 찾는다.
+    ///
+    /// 메타데이터의 주문번호(`order_no`/`order_id`/`binance_order_id`)를 먼저 확인하고,
+    /// 없으면 체결 ID를 거래소 주문 ID로 간주한다 (주문 단위로 집계하는 거래소).
+    /// 체결 ID로 매칭된 경우 수량은 누적 체결 수량이다.
+    fn match_execution(&self, trade: &Trade) -> Option<(Uuid, bool)> {
+        let metadata_order_no = ["order_no", "order_id", "binance_order_id"]
+            .iter()
+            .filter_map(|key| trade.metadata.get(*key))
+            .find_map(|value| match value {
+                serde_json::Value::String(s) => Some(s.clone()),
+                serde_json::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            });
+
+        let matched = metadata_order_no
+            .and_then(|order_no| self.exchange_id_map.get(&order_no).copied())
+            .map(|order_id| (order_id, false))
+            .or_else(|| {
+                self.exchange_id_map
+                    .get(&trade.exchange_trade_id)
+                    .map(|order_id| (*order_id, true))
+            })
+            .or_else(|| {
+                self.orders
+                    .contains_key(&trade.order_id)
+                    .then_some((trade.order_id, false))
+            });
+
+        matched.filter(|(order_id, _)| {
+            self.active_orders.contains_key(order_id) || self.oco_cancelled_leg(*order_id).is_some()
+        })
+    }
+
+    fn trim_history(&mut self) {
+        if self.events.len() > self.max_history_size {
+            let drain_count = self.events.len() - self.max_history_size;
+            self.events.drain(0..drain_count);
+        }
+        if self.fills.len() > self.max_history_size {
+            let drain_count = self.fills.len() - self.max_history_size;
+            self.fills.drain(0..drain_count);
+        }
+    }
+
+    /// 특정 기간보다 오래된 완료된 주문을 정리한다 (메모리 관리용).
+    pub fn cleanup_old_orders(&mut self, older_than: DateTime<Utc>) {
+        // 제거할 주문 찾기
+        let orders_to_remove: Vec<Uuid> = self
+            .orders
+            .iter()
+            .filter(|(_, o)| o.status.is_final() && o.updated_at < older_than)
+            .map(|(id, _)| *id)
+            .collect();
+
+        for order_id in orders_to_remove {
+            if let Some(order) = self.orders.remove(&order_id) {
+                self.fill_progress.remove(&order_id);
 
-        for order_id in orders_to_remove {
-            if let Some(order) = self.orders.remove(&order_id) {
                 // 심볼 인덱스에서 제거
                 if let Some(ids) = self.orders_by_symbol.get_mut(&order.ticker.to_string()) {
                     ids.retain(|id| *id != order_id);
@@ -672,9 +1794,31 @@ impl OrderManager {
                 }
             }
         }
+
+        // 소속 주문이 모두 정리된 OCO 그룹 제거
+        let orders = &self.orders;
+        self.oco_groups
+            .retain(|_, group| group.order_ids().iter().any(|id| orders.contains_key(id)));
+        let oco_groups = &self.oco_groups;
+        self.oco_membership.retain(|order_id, group_id| {
+            orders.contains_key(order_id) && oco_groups.contains_key(group_id)
+        });
     }
 }
 
+/// 체결 내역 동기화 결과.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionSyncReport {
+    /// 새로 반영한 체결 수
+    pub applied: usize,
+    /// 이미 처리되어 건너뛴 체결 수
+    pub duplicates: usize,
+    /// 추적 중인 활성 주문과 매칭되지 않은 체결 수
+    pub unmatched: usize,
+    /// 반영 중 오류가 발생한 체결 수
+    pub failed: usize,
+}
+
 /// 주문 통계.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderStats {
@@ -688,14 +1832,17 @@ pub struct OrderStats {
     pub total_volume: Decimal,
     pub total_notional: Decimal,
     pub fill_rate: f64,
+    /// 주문당 평균 부분 체결 횟수 (`record_partial_fill`로 체결된 주문 기준)
+    pub avg_fills_per_order: f64,
 }
 
 #[cfg(test)]
 mod tests {
     use rust_decimal::prelude::FromPrimitive;
-    use trader_core::OrderRequest;
+    use trader_core::{OrderRequest, ProviderError};
 
     use super::*;
+    use crate::order_store::InMemoryOrderStore;
 
     /// Decimal 생성을 위한 헬퍼 매크로
     macro_rules! dec {
@@ -809,6 +1956,136 @@ mod tests {
         assert_eq!(final_order.average_fill_price, Some(dec!(50000)));
     }
 
+    #[test]
+    fn test_record_fill_rejects_overfill() {
+        let mut manager = OrderManager::new();
+        let order = create_test_order(Side::Buy);
+        let order_id = order.id;
+        manager.add_order(order).unwrap();
+
+        manager
+            .record_fill(partial_fill(order_id, dec!(0.06), dec!(100)))
+            .unwrap();
+
+        // 잔여 수량(0.04)을 넘는 체결은 거부되고 상태는 그대로 유지
+        assert!(matches!(
+            manager.record_fill(partial_fill(order_id, dec!(0.06), dec!(100))),
+            Err(OrderManagerError::FillExceedsRemaining { .. })
+        ));
+        let order = manager.get_order(order_id).unwrap();
+        assert_eq!(order.filled_quantity, dec!(0.06));
+        assert_eq!(order.status, OrderStatusType::PartiallyFilled);
+    }
+
+    fn partial_fill(order_id: Uuid, quantity: Decimal, price: Decimal) -> OrderFill {
+        OrderFill {
+            order_id,
+            quantity,
+            price,
+            commission: None,
+            commission_asset: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_record_partial_fill_vwap_and_events() {
+        let mut manager = OrderManager::new();
+        let order = create_test_order(Side::Buy);
+        let order_id = order.id;
+        manager.add_order(order).unwrap();
+
+        let event = manager
+            .record_partial_fill(order_id, partial_fill(order_id, dec!(0.04), dec!(100)))
+            .unwrap();
+        match event {
+            OrderEvent::PartiallyFilled {
+                filled,
+                remaining,
+                avg_price,
+                ..
+            } => {
+                assert_eq!(filled, dec!(0.04));
+                assert_eq!(remaining, dec!(0.06));
+                assert_eq!(avg_price, dec!(100));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        manager
+            .record_partial_fill(order_id, partial_fill(order_id, dec!(0.04), dec!(110)))
+            .unwrap();
+
+        let event = manager
+            .record_partial_fill(order_id, partial_fill(order_id, dec!(0.02), dec!(120)))
+            .unwrap();
+        match event {
+            OrderEvent::FullyFilled {
+                filled,
+                avg_price,
+                fill_count,
+                ..
+            } => {
+                assert_eq!(filled, dec!(0.1));
+                // (100*0.04 + 110*0.04 + 120*0.02) / 0.1 = 108
+                assert_eq!(avg_price, dec!(108));
+                assert_eq!(fill_count, 3);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let order = manager.get_order(order_id).unwrap();
+        assert_eq!(order.status, OrderStatusType::Filled);
+        assert_eq!(manager.active_order_count(), 0);
+        assert_eq!(manager.get_overall_stats().avg_fills_per_order, 3.0);
+
+        // 최종 상태 주문에 추가 체결은 거부
+        let result =
+            manager.record_partial_fill(order_id, partial_fill(order_id, dec!(0.01), dec!(100)));
+        assert!(matches!(result, Err(OrderManagerError::OrderFinalized(_))));
+    }
+
+    #[test]
+    fn test_record_partial_fill_rejects_overfill() {
+        let mut manager = OrderManager::new();
+        let order = create_test_order(Side::Sell);
+        let order_id = order.id;
+        manager.add_order(order).unwrap();
+
+        let result =
+            manager.record_partial_fill(order_id, partial_fill(order_id, dec!(0.2), dec!(100)));
+        assert!(matches!(
+            result,
+            Err(OrderManagerError::FillExceedsRemaining { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cancel_after_partial_fill_clears_remaining() {
+        let mut manager = OrderManager::new();
+        let order = create_test_order(Side::Buy);
+        let order_id = order.id;
+        manager.add_order(order).unwrap();
+
+        manager
+            .record_partial_fill(order_id, partial_fill(order_id, dec!(0.03), dec!(100)))
+            .unwrap();
+        assert_eq!(manager.remaining_quantity(order_id), Some(dec!(0.07)));
+
+        manager
+            .cancel_order(order_id, Some("user".to_string()))
+            .unwrap();
+
+        assert_eq!(manager.remaining_quantity(order_id), Some(Decimal::ZERO));
+        let progress = manager.get_fill_progress(order_id).unwrap();
+        assert_eq!(progress.filled_quantity, dec!(0.03));
+        assert_eq!(progress.avg_price(), Some(dec!(100)));
+        assert_eq!(
+            manager.get_order(order_id).unwrap().status,
+            OrderStatusType::Cancelled
+        );
+    }
+
     #[test]
     fn test_cancel_order() {
         let mut manager = OrderManager::new();
@@ -1038,4 +2315,647 @@ mod tests {
 
         assert_eq!(manager.total_orders(), 1);
     }
+
+    fn submit_test_oco(manager: &mut OrderManager) -> OcoGroup {
+        let entry = OrderRequest::limit_buy("BTC/USDT".to_string(), dec!(0.1), dec!(50000));
+        let mut stop_loss = OrderRequest::market_sell("BTC/USDT".to_string(), dec!(0.1));
+        stop_loss.order_type = trader_core::OrderType::StopLoss;
+        stop_loss.stop_price = Some(dec!(48000));
+        let take_profit = OrderRequest::limit_sell("BTC/USDT".to_string(), dec!(0.1), dec!(55000));
+
+        manager
+            .submit_oco(entry, stop_loss, take_profit, "binance")
+            .unwrap()
+    }
+
+    fn oco_triggered_events(manager: &OrderManager) -> Vec<&OrderEvent> {
+        manager
+            .get_events()
+            .iter()
+            .filter(|e| matches!(e, OrderEvent::OcoTriggered { .. }))
+            .collect()
+    }
+
+    #[test]
+    fn test_oco_fill_cancels_opposite_leg() {
+        let mut manager = OrderManager::new();
+        let group = submit_test_oco(&mut manager);
+        assert_eq!(manager.total_orders(), 3);
+        assert_eq!(
+            manager
+                .get_oco_group_for_order(group.stop_loss_id)
+                .map(|g| g.oco_group_id),
+            Some(group.oco_group_id)
+        );
+
+        // 진입 체결은 OCO를 트리거하지 않음
+        manager
+            .record_fill(partial_fill(group.entry_id, dec!(0.1), dec!(50000)))
+            .unwrap();
+        assert!(oco_triggered_events(&manager).is_empty());
+
+        // 익절 체결 → 손절 자동 취소
+        manager
+            .record_fill(partial_fill(group.take_profit_id, dec!(0.1), dec!(55000)))
+            .unwrap();
+
+        let stop_loss = manager.get_order(group.stop_loss_id).unwrap();
+        assert_eq!(stop_loss.status, OrderStatusType::Cancelled);
+        assert_eq!(manager.active_order_count(), 0);
+
+        let events = oco_triggered_events(&manager);
+        assert_eq!(events.len(), 1);
+        match events[0] {
+            OrderEvent::OcoTriggered {
+                oco_group_id,
+                filled_id,
+                cancelled_id,
+                ..
+            } => {
+                assert_eq!(*oco_group_id, group.oco_group_id);
+                assert_eq!(*filled_id, group.take_profit_id);
+                assert_eq!(*cancelled_id, group.stop_loss_id);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert!(manager
+            .get_oco_group(group.oco_group_id)
+            .unwrap()
+            .is_triggered());
+    }
+
+    fn oco_late_fill_events(manager: &OrderManager) -> Vec<&OrderEvent> {
+        manager
+            .get_events()
+            .iter()
+            .filter(|e| matches!(e, OrderEvent::OcoLateFill { .. }))
+            .collect()
+    }
+
+    #[test]
+    fn test_oco_race_late_fill_is_recorded() {
+        let mut manager = OrderManager::new();
+        let group = submit_test_oco(&mut manager);
+        manager
+            .record_fill(partial_fill(group.entry_id, dec!(0.1), dec!(50000)))
+            .unwrap();
+
+        // 손절 체결과 익절 체결 통지가 연달아 도착
+        manager
+            .record_fill(partial_fill(group.stop_loss_id, dec!(0.1), dec!(48000)))
+            .unwrap();
+        let late_partial = manager
+            .record_partial_fill(
+                group.take_profit_id,
+                partial_fill(group.take_profit_id, dec!(0.04), dec!(55000)),
+            )
+            .unwrap();
+        match late_partial {
+            OrderEvent::OcoLateFill {
+                oco_group_id,
+                order_id,
+                filled_id,
+                quantity,
+                ..
+            } => {
+                assert_eq!(oco_group_id, group.oco_group_id);
+                assert_eq!(order_id, group.take_profit_id);
+                assert_eq!(filled_id, group.stop_loss_id);
+                assert_eq!(quantity, dec!(0.04));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        // 일부만 체결된 동안은 취소 상태 유지
+        let take_profit = manager.get_order(group.take_profit_id).unwrap();
+        assert_eq!(take_profit.status, OrderStatusType::Cancelled);
+        assert_eq!(take_profit.filled_quantity, dec!(0.04));
+
+        // 누적 상태 업데이트로 나머지 체결 도착
+        let status = OrderStatus {
+            status: OrderStatusType::Filled,
+            filled_quantity: dec!(0.1),
+            average_price: Some(dec!(55000)),
+            ..submitted_status("TP1")
+        };
+        manager
+            .update_status(group.take_profit_id, &status)
+            .unwrap();
+
+        let take_profit = manager.get_order(group.take_profit_id).unwrap();
+        assert_eq!(take_profit.status, OrderStatusType::Filled);
+        assert_eq!(take_profit.filled_quantity, dec!(0.1));
+        assert_eq!(take_profit.average_fill_price, Some(dec!(55000)));
+        assert_eq!(manager.get_order_fills(group.take_profit_id).len(), 2);
+        assert_eq!(oco_late_fill_events(&manager).len(), 2);
+
+        // OCO 트리거는 먼저 체결된 손절 한 번뿐
+        assert_eq!(oco_triggered_events(&manager).len(), 1);
+        assert_eq!(
+            manager.get_oco_group(group.oco_group_id).unwrap().filled_id,
+            Some(group.stop_loss_id)
+        );
+
+        // 주문 수량을 넘는 체결은 거부
+        let overfill =
+            manager.record_fill(partial_fill(group.take_profit_id, dec!(0.01), dec!(55000)));
+        assert!(matches!(
+            overfill,
+            Err(OrderManagerError::FillExceedsRemaining { .. })
+        ));
+    }
+
+    #[test]
+    fn test_oco_concurrent_fills_single_winner() {
+        use std::sync::{Arc, Barrier, Mutex};
+
+        let manager = Arc::new(Mutex::new(OrderManager::new()));
+        let group = submit_test_oco(&mut manager.lock().unwrap());
+        let barrier = Arc::new(Barrier::new(2));
+
+        let handles: Vec<_> = [
+            (group.stop_loss_id, dec!(48000)),
+            (group.take_profit_id, dec!(55000)),
+        ]
+        .into_iter()
+        .map(|(order_id, price)| {
+            let manager = Arc::clone(&manager);
+            let barrier = Arc::clone(&barrier);
+            std::thread::spawn(move || {
+                barrier.wait();
+                manager
+                    .lock()
+                    .unwrap()
+                    .record_fill(partial_fill(order_id, dec!(0.1), price))
+                    .is_ok()
+            })
+        })
+        .collect();
+
+        let recorded = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|ok| *ok)
+            .count();
+        assert_eq!(recorded, 2);
+
+        // 두 체결 모두 기록되지만 OCO 트리거는 하나, 나머지는 늦은 체결로 알림
+        let manager = manager.lock().unwrap();
+        assert_eq!(oco_triggered_events(&manager).len(), 1);
+        assert_eq!(oco_late_fill_events(&manager).len(), 1);
+        let winner = manager
+            .get_oco_group(group.oco_group_id)
+            .unwrap()
+            .filled_id
+            .unwrap();
+        match oco_late_fill_events(&manager)[0] {
+            OrderEvent::OcoLateFill { filled_id, .. } => assert_eq!(*filled_id, winner),
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_apply_executions_records_late_fill_on_oco_cancelled_leg() {
+        let mut manager = OrderManager::new();
+        let group = submit_test_oco(&mut manager);
+        manager
+            .bind_exchange_order_id(group.take_profit_id, "TP1")
+            .unwrap();
+        manager
+            .record_fill(partial_fill(group.stop_loss_id, dec!(0.1), dec!(48000)))
+            .unwrap();
+
+        let trade = execution("T-LATE", "TP1", dec!(0.1), dec!(55000));
+        let report = manager.apply_executions(&[trade]);
+        assert_eq!(report.applied, 1);
+        assert_eq!(report.unmatched, 0);
+        assert_eq!(oco_late_fill_events(&manager).len(), 1);
+        assert_eq!(
+            manager.get_order(group.take_profit_id).unwrap().status,
+            OrderStatusType::Filled
+        );
+    }
+
+    /// 취소 요청을 기록하고 정해진 실패를 돌려주는 테스트용 주문 제공자.
+    struct CancelRecordingProvider {
+        failure: std::sync::Mutex<Option<ProviderError>>,
+        cancelled: std::sync::Mutex<Vec<(String, String)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl OrderExecutionProvider for CancelRecordingProvider {
+        async fn place_order(
+            &self,
+            _request: &OrderRequest,
+        ) -> Result<trader_core::OrderResponse, ProviderError> {
+            Err(ProviderError::Unsupported("place_order".to_string()))
+        }
+
+        async fn cancel_order(&self, order_id: &str, ticker: &str) -> Result<(), ProviderError> {
+            if let Some(e) = self.failure.lock().unwrap().take() {
+                return Err(e);
+            }
+            self.cancelled
+                .lock()
+                .unwrap()
+                .push((order_id.to_string(), ticker.to_string()));
+            Ok(())
+        }
+
+        async fn modify_order(
+            &self,
+            _order_id: &str,
+            _ticker: &str,
+            _quantity: Option<Decimal>,
+            _price: Option<Decimal>,
+        ) -> Result<trader_core::OrderResponse, ProviderError> {
+            Err(ProviderError::Unsupported("modify_order".to_string()))
+        }
+
+        fn exchange_name(&self) -> &str {
+            "MockExchange"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oco_trigger_cancels_sibling_on_exchange() {
+        let mut manager = OrderManager::new();
+        let group = submit_test_oco(&mut manager);
+        manager
+            .bind_exchange_order_id(group.stop_loss_id, "SL1")
+            .unwrap();
+        manager
+            .record_fill(partial_fill(group.take_profit_id, dec!(0.1), dec!(55000)))
+            .unwrap();
+
+        let provider = CancelRecordingProvider {
+            failure: std::sync::Mutex::new(Some(ProviderError::Network(
+                "connection reset".to_string(),
+            ))),
+            cancelled: std::sync::Mutex::new(Vec::new()),
+        };
+
+        // 재시도 가능한 실패는 대기열에 남아 다음 호출에서 재시도
+        assert_eq!(manager.cancel_oco_legs_on_exchange(&provider).await, 0);
+        assert_eq!(manager.cancel_oco_legs_on_exchange(&provider).await, 1);
+        assert_eq!(
+            *provider.cancelled.lock().unwrap(),
+            vec![("SL1".to_string(), "BTC/USDT".to_string())]
+        );
+        assert!(manager.take_oco_cancel_actions().is_empty());
+    }
+
+    #[test]
+    fn test_oco_cancel_queued_when_exchange_id_bound_late() {
+        let mut manager = OrderManager::new();
+        let group = submit_test_oco(&mut manager);
+        manager
+            .record_fill(partial_fill(group.take_profit_id, dec!(0.1), dec!(55000)))
+            .unwrap();
+
+        // 제출 전에 취소 처리된 주문은 거래소 취소 대상이 아님
+        assert!(manager.take_oco_cancel_actions().is_empty());
+
+        // 제출 응답이 늦게 도착하면 그때 거래소 취소 대상이 됨
+        manager
+            .bind_exchange_order_id(group.stop_loss_id, "SL1")
+            .unwrap();
+        let actions = manager.take_oco_cancel_actions();
+        assert_eq!(
+            actions,
+            vec![OcoCancelAction {
+                oco_group_id: group.oco_group_id,
+                order_id: group.stop_loss_id,
+                exchange_order_id: "SL1".to_string(),
+                ticker: "BTC/USDT".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_oco_entry_cancel_cancels_exits() {
+        let mut manager = OrderManager::new();
+        let group = submit_test_oco(&mut manager);
+
+        manager
+            .cancel_order(group.entry_id, Some("user".to_string()))
+            .unwrap();
+
+        for order_id in group.order_ids() {
+            assert_eq!(
+                manager.get_order(order_id).unwrap().status,
+                OrderStatusType::Cancelled
+            );
+        }
+        assert!(oco_triggered_events(&manager).is_empty());
+    }
+
+    #[test]
+    fn test_submit_oco_rejects_same_side_exit() {
+        let mut manager = OrderManager::new();
+        let entry = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.1));
+        let stop_loss = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.1));
+        let take_profit = OrderRequest::limit_sell("BTC/USDT".to_string(), dec!(0.1), dec!(55000));
+
+        let result = manager.submit_oco(entry, stop_loss, take_profit, "binance");
+        assert!(matches!(result, Err(OrderManagerError::InvalidOcoGroup(_))));
+        assert_eq!(manager.total_orders(), 0);
+    }
+
+    #[test]
+    fn test_apply_order_update_matches_client_then_exchange_id() {
+        let mut manager = OrderManager::new();
+        let request =
+            OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.1)).with_client_id("sig_1");
+        let order = manager.create_order(request, "binance").unwrap();
+
+        let update = |status, filled, avg| OrderUpdate {
+            exchange: "binance".to_string(),
+            order_id: "BINANCE123".to_string(),
+            client_order_id: Some("sig_1".to_string()),
+            ticker: Some("BTC/USDT".to_string()),
+            status,
+            filled_quantity: filled,
+            average_price: avg,
+            timestamp: Utc::now(),
+        };
+
+        let open = update(OrderStatusType::Open, Decimal::ZERO, None);
+        assert_eq!(manager.apply_order_update(&open).unwrap(), Some(order.id));
+        assert_eq!(manager.apply_order_update(&open).unwrap(), None);
+
+        let mut filled = update(OrderStatusType::Filled, dec!(0.1), Some(dec!(50000)));
+        filled.client_order_id = None;
+        assert_eq!(manager.apply_order_update(&filled).unwrap(), Some(order.id));
+
+        let stored = manager.get_order(order.id).unwrap();
+        assert_eq!(stored.status, OrderStatusType::Filled);
+        assert_eq!(stored.average_fill_price, Some(dec!(50000)));
+        assert_eq!(manager.active_order_count(), 0);
+
+        let mut unknown = update(OrderStatusType::Open, Decimal::ZERO, None);
+        unknown.order_id = "OTHER".to_string();
+        unknown.client_order_id = None;
+        assert_eq!(manager.apply_order_update(&unknown).unwrap(), None);
+    }
+
+    #[test]
+    fn test_bind_exchange_order_id_keeps_previous_mapping() {
+        let mut manager = OrderManager::new();
+        let order = create_test_order(Side::Buy);
+        let order_id = order.id;
+        manager.add_order(order).unwrap();
+
+        manager.bind_exchange_order_id(order_id, "1001").unwrap();
+        manager.bind_exchange_order_id(order_id, "1002").unwrap();
+
+        let stored = manager.get_order(order_id).unwrap();
+        assert_eq!(stored.exchange_order_id.as_deref(), Some("1002"));
+        assert_eq!(
+            manager.get_order_by_exchange_id("1001").unwrap().id,
+            order_id
+        );
+        assert_eq!(
+            manager.get_order_by_exchange_id("1002").unwrap().id,
+            order_id
+        );
+        assert!(matches!(
+            manager.bind_exchange_order_id(Uuid::new_v4(), "1003"),
+            Err(OrderManagerError::OrderNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_enforce_time_in_force_expires_gtd() {
+        let mut manager = OrderManager::new();
+        let now = Utc::now();
+        let expire_at = now + chrono::Duration::minutes(5);
+
+        let gtd = manager
+            .create_order(
+                OrderRequest::limit_buy("BTC/USDT".to_string(), dec!(0.1), dec!(50000))
+                    .with_time_in_force(TimeInForce::GTD(expire_at)),
+                "binance",
+            )
+            .unwrap();
+        let gtc = manager
+            .create_order(
+                OrderRequest::limit_buy("BTC/USDT".to_string(), dec!(0.1), dec!(49000)),
+                "binance",
+            )
+            .unwrap();
+
+        assert!(manager.enforce_time_in_force(now).is_empty());
+
+        let actions = manager.enforce_time_in_force(expire_at);
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].order_id, gtd.id);
+        assert_eq!(actions[0].status, OrderStatusType::Expired);
+        assert_eq!(
+            manager.get_order(gtd.id).unwrap().status,
+            OrderStatusType::Expired
+        );
+        assert!(manager
+            .get_order_events(gtd.id)
+            .iter()
+            .any(|e| matches!(e, OrderEvent::Expired { .. })));
+        assert_eq!(
+            manager.get_order(gtc.id).unwrap().status,
+            OrderStatusType::Pending
+        );
+    }
+
+    fn submitted_status(exchange_order_id: &str) -> OrderStatus {
+        OrderStatus {
+            order_id: exchange_order_id.to_string(),
+            client_order_id: None,
+            ticker: None,
+            side: None,
+            quantity: None,
+            price: None,
+            status: OrderStatusType::Open,
+            filled_quantity: Decimal::ZERO,
+            average_price: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn execution(trade_id: &str, order_no: &str, quantity: Decimal, price: Decimal) -> Trade {
+        Trade::new(
+            Uuid::new_v4(),
+            "binance",
+            trade_id,
+            "BTC/USDT".to_string(),
+            Side::Buy,
+            quantity,
+            price,
+        )
+        .with_metadata(serde_json::json!({ "order_no": order_no }))
+    }
+
+    /// 저장소를 공유하는 새 관리자로 재시작을 재현한다.
+    async fn restart(store: &Arc<InMemoryOrderStore>) -> OrderManager {
+        let mut manager = OrderManager::new().with_store(store.clone());
+        manager.restore_open_orders().await.unwrap();
+        manager
+    }
+
+    #[tokio::test]
+    async fn test_restore_open_orders_after_restart() {
+        let store = Arc::new(InMemoryOrderStore::new());
+        let mut manager = OrderManager::new().with_store(store.clone());
+
+        let open = create_test_order(Side::Buy);
+        let open_id = open.id;
+        manager.add_order(open).unwrap();
+        manager
+            .update_status(open_id, &submitted_status("EX-1"))
+            .unwrap();
+
+        let done = create_test_order(Side::Sell);
+        let done_id = done.id;
+        manager.add_order(done).unwrap();
+        manager.cancel_order(done_id, None).unwrap();
+        manager.flush_store().await;
+
+        assert_eq!(
+            store.get_order(done_id).unwrap().status,
+            OrderStatusType::Cancelled
+        );
+
+        let restored = restart(&store).await;
+        assert_eq!(restored.active_order_count(), 1);
+        assert!(restored.get_order(done_id).is_none());
+        let order = restored.get_order_by_exchange_id("EX-1").unwrap();
+        assert_eq!(order.id, open_id);
+        assert_eq!(order.status, OrderStatusType::Open);
+        assert_eq!(restored.get_orders_for_strategy("test").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_restore_requires_store() {
+        let mut manager = OrderManager::new();
+        assert!(matches!(
+            manager.restore_open_orders().await,
+            Err(OrderManagerError::StoreNotConfigured)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_apply_executions_skips_processed_ids_across_restart() {
+        let store = Arc::new(InMemoryOrderStore::new());
+        let mut manager = OrderManager::new().with_store(store.clone());
+        let order = create_test_order(Side::Buy);
+        let order_id = order.id;
+        manager.add_order(order).unwrap();
+        manager
+            .update_status(order_id, &submitted_status("EX-1"))
+            .unwrap();
+
+        let first = execution("T-1", "EX-1", Decimal::new(4, 2), Decimal::new(50000, 0));
+        let report = manager.apply_executions(std::slice::from_ref(&first));
+        assert_eq!(report.applied, 1);
+        manager.flush_store().await;
+        drop(manager);
+
+        // 재시작 후 같은 체결이 다시 조회되어도 중복 반영하지 않음
+        let mut manager = restart(&store).await;
+        assert!(manager.is_execution_processed("T-1"));
+        let second = execution("T-2", "EX-1", Decimal::new(6, 2), Decimal::new(51000, 0));
+        let unknown = execution("T-3", "EX-404", Decimal::new(1, 2), Decimal::new(50000, 0));
+        let report = manager.apply_executions(&[second, first, unknown]);
+
+        assert_eq!(
+            report,
+            ExecutionSyncReport {
+                applied: 1,
+                duplicates: 1,
+                unmatched: 1,
+                failed: 0,
+            }
+        );
+        let order = manager.get_order(order_id).unwrap();
+        assert_eq!(order.status, OrderStatusType::Filled);
+        assert_eq!(order.filled_quantity, Decimal::new(10, 2));
+        assert_eq!(manager.active_order_count(), 0);
+
+        manager.flush_store().await;
+        assert_eq!(store.fill_count(), 2);
+        assert_eq!(
+            store.get_order(order_id).unwrap().status,
+            OrderStatusType::Filled
+        );
+    }
+
+    #[test]
+    fn test_processed_execution_ids_are_bounded() {
+        let mut manager = OrderManager::with_history_size(2);
+        let order = create_test_order(Side::Buy);
+        let order_id = order.id;
+        manager.add_order(order).unwrap();
+        manager
+            .update_status(order_id, &submitted_status("EX-1"))
+            .unwrap();
+
+        let trades: Vec<Trade> = (1..=3)
+            .map(|i| {
+                execution(
+                    &format!("T-{i}"),
+                    "EX-1",
+                    Decimal::new(1, 2),
+                    Decimal::new(50000, 0),
+                )
+            })
+            .collect();
+        assert_eq!(manager.apply_executions(&trades).applied, 3);
+
+        // 보관 한도를 넘으면 가장 오래된 체결 ID부터 제거
+        assert!(!manager.is_execution_processed("T-1"));
+        assert!(manager.is_execution_processed("T-2"));
+        assert!(manager.is_execution_processed("T-3"));
+    }
+
+    #[tokio::test]
+    async fn test_apply_executions_cumulative_order_record() {
+        let mut manager = OrderManager::new();
+        let order = create_test_order(Side::Buy);
+        let order_id = order.id;
+        manager.add_order(order).unwrap();
+        manager
+            .update_status(order_id, &submitted_status("0000123"))
+            .unwrap();
+
+        // 주문 단위 집계 거래소: 체결 ID = 주문번호, 수량은 누적
+        let partial = Trade::new(
+            Uuid::new_v4(),
+            "kis",
+            "0000123",
+            "BTC/USDT".to_string(),
+            Side::Buy,
+            Decimal::new(4, 2),
+            Decimal::new(100, 0),
+        );
+        let mut full = partial.clone();
+        full.quantity = Decimal::new(10, 2);
+        full.price = Decimal::new(130, 0);
+
+        assert_eq!(
+            manager
+                .apply_executions(std::slice::from_ref(&partial))
+                .applied,
+            1
+        );
+        let report = manager.apply_executions(&[partial, full]);
+        assert_eq!(report.applied, 1);
+        assert_eq!(report.duplicates, 1);
+
+        let order = manager.get_order(order_id).unwrap();
+        assert_eq!(order.filled_quantity, Decimal::new(10, 2));
+        assert_eq!(order.average_fill_price, Some(Decimal::new(130, 0)));
+        // 두 번째 구간 체결가: (130×0.10 − 100×0.04) / 0.06 = 150
+        assert_eq!(
+            manager.get_order_fills(order_id)[1].price,
+            Decimal::new(150, 0)
+        );
+    }
 }
@@ -0,0 +1,254 @@
+//! 주문 상태 영속화.
+//!
+//! [`OrderManager`](crate::OrderManager)의 주문 생성·상태 변경·체결을 저장소에 기록하고,
+//! 재시작 시 미완료 주문과 처리된 체결 ID를 다시 불러오기 위한 추상화입니다.
+//!
+//! # 구조
+//!
+//! ```text
+//! OrderStore (trait)
+//! ├── InMemoryOrderStore     // 테스트/단일 프로세스용
+//! └── PgOrderStore           // PostgreSQL (trader-api)
+//! ```
+//!
+//! `OrderManager`의 메서드는 동기 함수이므로 저장은 [`OrderStoreWriter`]가
+//! 백그라운드 태스크에서 요청 순서대로 수행합니다 (write-behind).
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use thiserror::Error;
+use tokio::sync::{mpsc, oneshot};
+use tracing::warn;
+use trader_core::Order;
+use uuid::Uuid;
+
+use crate::order_manager::OrderFill;
+
+/// 주문 저장소 에러.
+#[derive(Debug, Error)]
+pub enum OrderStoreError {
+    #[error("Order store error: {0}")]
+    Store(String),
+
+    #[error("Order serialization error: {0}")]
+    Serialization(String),
+}
+
+/// 주문 상태 저장소.
+///
+/// 같은 주문 ID로 `upsert_order`가 여러 번 호출되면 마지막 스냅샷이 유지되어야 합니다.
+#[async_trait]
+pub trait OrderStore: Send + Sync {
+    /// 주문 스냅샷 저장 (생성 및 상태 변경 시 호출).
+    async fn upsert_order(&self, order: &Order) -> Result<(), OrderStoreError>;
+
+    /// 체결 기록 저장.
+    async fn insert_fill(&self, fill: &OrderFill) -> Result<(), OrderStoreError>;
+
+    /// 처리 완료된 거래소 체결 ID 기록.
+    async fn mark_execution_processed(
+        &self,
+        execution_id: &str,
+        order_id: Uuid,
+    ) -> Result<(), OrderStoreError>;
+
+    /// 최종 상태가 아닌 주문 조회 (재시작 복구용).
+    async fn load_open_orders(&self) -> Result<Vec<Order>, OrderStoreError>;
+
+    /// 주어진 주문들에 대해 처리된 체결 ID 조회.
+    async fn load_processed_execution_ids(
+        &self,
+        order_ids: &[Uuid],
+    ) -> Result<HashSet<String>, OrderStoreError>;
+}
+
+/// 메모리 저장소 내부 상태.
+#[derive(Debug, Default)]
+struct MemoryState {
+    orders: HashMap<Uuid, Order>,
+    fills: Vec<OrderFill>,
+    executions: HashMap<String, Uuid>,
+}
+
+/// 단일 프로세스용 메모리 저장소.
+///
+/// 같은 인스턴스를 새 `OrderManager`에 다시 연결하면 재시작 복구를 재현할 수 있습니다.
+#[derive(Debug, Default)]
+pub struct InMemoryOrderStore {
+    state: Mutex<MemoryState>,
+}
+
+impl InMemoryOrderStore {
+    /// 빈 저장소 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 저장된 주문 스냅샷 조회
+    pub fn get_order(&self, order_id: Uuid) -> Option<Order> {
+        self.state.lock().ok()?.orders.get(&order_id).cloned()
+    }
+
+    /// 저장된 체결 수
+    pub fn fill_count(&self) -> usize {
+        self.state.lock().map(|s| s.fills.len()).unwrap_or(0)
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, MemoryState>, OrderStoreError> {
+        self.state
+            .lock()
+            .map_err(|e| OrderStoreError::Store(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl OrderStore for InMemoryOrderStore {
+    async fn upsert_order(&self, order: &Order) -> Result<(), OrderStoreError> {
+        self.lock()?.orders.insert(order.id, order.clone());
+        Ok(())
+    }
+
+    async fn insert_fill(&self, fill: &OrderFill) -> Result<(), OrderStoreError> {
+        self.lock()?.fills.push(fill.clone());
+        Ok(())
+    }
+
+    async fn mark_execution_processed(
+        &self,
+        execution_id: &str,
+        order_id: Uuid,
+    ) -> Result<(), OrderStoreError> {
+        self.lock()?
+            .executions
+            .insert(execution_id.to_string(), order_id);
+        Ok(())
+    }
+
+    async fn load_open_orders(&self) -> Result<Vec<Order>, OrderStoreError> {
+        let mut orders: Vec<Order> = self
+            .lock()?
+            .orders
+            .values()
+            .filter(|o| o.status.is_active())
+            .cloned()
+            .collect();
+        orders.sort_by_key(|o| o.created_at);
+        Ok(orders)
+    }
+
+    async fn load_processed_execution_ids(
+        &self,
+        order_ids: &[Uuid],
+    ) -> Result<HashSet<String>, OrderStoreError> {
+        Ok(self
+            .lock()?
+            .executions
+            .iter()
+            .filter(|(_, order_id)| order_ids.contains(order_id))
+            .map(|(execution_id, _)| execution_id.clone())
+            .collect())
+    }
+}
+
+/// 저장 요청.
+enum StoreCommand {
+    Order(Box<Order>),
+    Fill(OrderFill),
+    Execution {
+        execution_id: String,
+        order_id: Uuid,
+    },
+    Flush(oneshot::Sender<()>),
+}
+
+/// 저장 요청을 순서대로 처리하는 백그라운드 writer.
+///
+/// 저장 실패는 경고 로그만 남기고 주문 처리를 막지 않습니다.
+/// Tokio 런타임 안에서 생성해야 합니다.
+#[derive(Clone)]
+pub struct OrderStoreWriter {
+    store: Arc<dyn OrderStore>,
+    tx: mpsc::UnboundedSender<StoreCommand>,
+}
+
+impl std::fmt::Debug for OrderStoreWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OrderStoreWriter")
+            .field("closed", &self.tx.is_closed())
+            .finish()
+    }
+}
+
+impl OrderStoreWriter {
+    /// writer 태스크를 시작한다.
+    pub fn spawn(store: Arc<dyn OrderStore>) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<StoreCommand>();
+        let task_store = Arc::clone(&store);
+
+        tokio::spawn(async move {
+            while let Some(command) = rx.recv().await {
+                let result = match command {
+                    StoreCommand::Order(order) => task_store.upsert_order(&order).await,
+                    StoreCommand::Fill(fill) => task_store.insert_fill(&fill).await,
+                    StoreCommand::Execution {
+                        execution_id,
+                        order_id,
+                    } => {
+                        task_store
+                            .mark_execution_processed(&execution_id, order_id)
+                            .await
+                    }
+                    StoreCommand::Flush(done) => {
+                        let _ = done.send(());
+                        Ok(())
+                    }
+                };
+                if let Err(e) = result {
+                    warn!(error = %e, "주문 상태 저장 실패");
+                }
+            }
+        });
+
+        Self { store, tx }
+    }
+
+    /// 연결된 저장소.
+    pub fn store(&self) -> &Arc<dyn OrderStore> {
+        &self.store
+    }
+
+    /// 주문 스냅샷 저장 요청.
+    pub fn save_order(&self, order: &Order) {
+        self.send(StoreCommand::Order(Box::new(order.clone())));
+    }
+
+    /// 체결 저장 요청.
+    pub fn save_fill(&self, fill: &OrderFill) {
+        self.send(StoreCommand::Fill(fill.clone()));
+    }
+
+    /// 처리된 체결 ID 저장 요청.
+    pub fn mark_execution(&self, execution_id: &str, order_id: Uuid) {
+        self.send(StoreCommand::Execution {
+            execution_id: execution_id.to_string(),
+            order_id,
+        });
+    }
+
+    /// 앞서 보낸 저장 요청이 모두 처리될 때까지 대기한다.
+    pub async fn flush(&self) {
+        let (done_tx, done_rx) = oneshot::channel();
+        self.send(StoreCommand::Flush(done_tx));
+        let _ = done_rx.await;
+    }
+
+    fn send(&self, command: StoreCommand) {
+        if self.tx.send(command).is_err() {
+            warn!("주문 저장 writer가 종료되어 저장 요청을 버립니다");
+        }
+    }
+}
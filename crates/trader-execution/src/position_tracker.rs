@@ -4,17 +4,21 @@
 //! - 주문 체결에 따른 실시간 포지션 업데이트
 //! - 손익(PnL) 추적 및 계산
 //! - 포지션 조회 및 집계
+//! - 다중 통화 포지션의 기준통화 환산 및 환차손익 분리
+//! - 거래소 포지션과의 리컨실리에이션 (불일치 보고 및 자동 보정)
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, VecDeque};
 
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use trader_core::{Order, Position, PositionSummary, Side};
+use tracing::{info, warn};
+use trader_core::{ExchangeProvider, Order, Position, PositionSummary, Side, StrategyPositionInfo};
 use uuid::Uuid;
 
 use crate::order_manager::OrderFill;
+use crate::telemetry;
 
 /// 포지션 트래커 에러 타입.
 #[derive(Debug, Error)]
@@ -30,6 +34,179 @@ pub enum PositionTrackerError {
 
     #[error("Insufficient quantity: have {0}, need {1}")]
     InsufficientQuantity(Decimal, Decimal),
+
+    #[error("FX rate not set: {0}/{1}")]
+    MissingFxRate(String, String),
+
+    #[error("Reconciliation failed: {0}")]
+    Reconciliation(String),
+}
+
+/// 기본 기준통화.
+const DEFAULT_BASE_CURRENCY: &str = "KRW";
+
+/// 포지션별 통화 및 기준통화 환산 정보.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PositionFx {
+    /// 포지션 통화 (원통화)
+    pub currency: String,
+    /// 진입 평균 환율 (원통화 → 기준통화, 진입 금액 가중평균)
+    pub entry_fx_rate: Decimal,
+    /// 기준통화 환산 누적 실현손익 (환차손익 포함, 실현 시점 환율로 확정)
+    pub realized_pnl_base: Decimal,
+    /// 누적 실현 환차손익 (기준통화)
+    pub realized_fx_pnl: Decimal,
+}
+
+/// 실현손익 계산 시 원가 기준.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CostBasisMethod {
+    /// 평균단가 (기본값)
+    #[default]
+    AverageCost,
+    /// 선입선출 - 가장 오래된 로트부터 소진
+    Fifo,
+    /// 후입선출 - 가장 최근 로트부터 소진
+    Lifo,
+}
+
+/// 포지션을 구성하는 진입 로트.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PositionLot {
+    /// 진입 시각
+    pub entry_time: DateTime<Utc>,
+    /// 진입가
+    pub entry_price: Decimal,
+    /// 잔여 수량
+    pub quantity: Decimal,
+}
+
+/// 청산으로 소진된 로트.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClosedLot {
+    /// 진입 시각
+    pub entry_time: DateTime<Utc>,
+    /// 진입가 (평균단가 방식은 청산 시점 평균단가)
+    pub entry_price: Decimal,
+    /// 청산 수량
+    pub quantity: Decimal,
+    /// 청산가
+    pub exit_price: Decimal,
+    /// 로트 실현손익
+    pub realized_pnl: Decimal,
+}
+
+/// 리컨실리에이션 불일치 처리 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconciliationMode {
+    /// 불일치를 보고만 하고 수동 개입을 기다림 (기본값)
+    #[default]
+    ReportOnly,
+    /// 거래소를 진실의 원천으로 삼아 내부 상태를 자동 보정
+    AutoCorrect,
+}
+
+/// 리컨실리에이션 설정.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReconciliationConfig {
+    /// 불일치 처리 방식
+    #[serde(default)]
+    pub mode: ReconciliationMode,
+    /// 실행 주기 (초)
+    #[serde(default = "default_reconciliation_interval_secs")]
+    pub interval_secs: u64,
+    /// 수량 차이 허용 오차 (이하의 차이는 일치로 간주)
+    #[serde(default)]
+    pub quantity_tolerance: Decimal,
+}
+
+fn default_reconciliation_interval_secs() -> u64 {
+    300
+}
+
+impl Default for ReconciliationConfig {
+    fn default() -> Self {
+        Self {
+            mode: ReconciliationMode::default(),
+            interval_secs: default_reconciliation_interval_secs(),
+            quantity_tolerance: Decimal::ZERO,
+        }
+    }
+}
+
+/// 리컨실리에이션 비교용 보유 상태.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HoldingSnapshot {
+    /// 포지션 방향
+    pub side: Side,
+    /// 보유 수량
+    pub quantity: Decimal,
+    /// 평균 진입가
+    pub avg_entry_price: Decimal,
+}
+
+impl From<&Position> for HoldingSnapshot {
+    fn from(position: &Position) -> Self {
+        Self {
+            side: position.side,
+            quantity: position.quantity,
+            avg_entry_price: position.entry_price,
+        }
+    }
+}
+
+impl From<&StrategyPositionInfo> for HoldingSnapshot {
+    fn from(info: &StrategyPositionInfo) -> Self {
+        Self {
+            side: info.side,
+            quantity: info.quantity,
+            avg_entry_price: info.avg_entry_price,
+        }
+    }
+}
+
+/// 내부 상태와 거래소 포지션의 불일치.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PositionDiscrepancy {
+    /// 심볼
+    pub symbol: String,
+    /// 내부 포지션 ID (내부에 없으면 `None`)
+    pub position_id: Option<Uuid>,
+    /// 내부 보유 상태 (없으면 `None`)
+    pub internal: Option<HoldingSnapshot>,
+    /// 거래소 보유 상태 (없으면 `None`)
+    pub exchange: Option<HoldingSnapshot>,
+    /// 내부 상태를 거래소 기준으로 보정했는지 여부
+    pub corrected: bool,
+}
+
+/// 리컨실리에이션 실행 결과.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReconciliationReport {
+    /// 실행 시각
+    pub run_at: DateTime<Utc>,
+    /// 적용된 처리 방식
+    pub mode: ReconciliationMode,
+    /// 비교한 심볼 수 (내부 ∪ 거래소)
+    pub checked: usize,
+    /// 발견된 불일치
+    pub discrepancies: Vec<PositionDiscrepancy>,
+    /// 거래소 조회 실패 등 실행 오류 (성공 시 `None`)
+    pub error: Option<String>,
+}
+
+impl ReconciliationReport {
+    /// 불일치 없이 성공했는지 여부.
+    pub fn is_consistent(&self) -> bool {
+        self.error.is_none() && self.discrepancies.is_empty()
+    }
+
+    /// 보정된 불일치 수.
+    pub fn corrected_count(&self) -> usize {
+        self.discrepancies.iter().filter(|d| d.corrected).count()
+    }
 }
 
 /// 포지션 이벤트 타입.
@@ -39,6 +216,9 @@ pub enum PositionEvent {
     Opened {
         position_id: Uuid,
         symbol: String,
+        /// 포지션 통화
+        #[serde(default)]
+        currency: String,
         side: Side,
         quantity: Decimal,
         price: Decimal,
@@ -58,13 +238,31 @@ pub enum PositionEvent {
         quantity: Decimal,
         price: Decimal,
         realized_pnl: Decimal,
+        /// 기준통화 환산 실현손익 (환차손익 포함)
+        #[serde(default)]
+        realized_pnl_base: Decimal,
+        /// 실현 환차손익 (기준통화)
+        #[serde(default)]
+        fx_pnl: Decimal,
         remaining: Decimal,
+        /// 청산된 로트
+        #[serde(default)]
+        closed_lots: Vec<ClosedLot>,
         timestamp: DateTime<Utc>,
     },
     /// 포지션 종료
     Closed {
         position_id: Uuid,
         final_pnl: Decimal,
+        /// 기준통화 환산 최종 손익 (환차손익 포함)
+        #[serde(default)]
+        final_pnl_base: Decimal,
+        /// 누적 환차손익 (기준통화)
+        #[serde(default)]
+        fx_pnl: Decimal,
+        /// 마지막 청산에서 소진된 로트
+        #[serde(default)]
+        closed_lots: Vec<ClosedLot>,
         timestamp: DateTime<Utc>,
     },
     /// 가격 업데이트
@@ -73,6 +271,22 @@ pub enum PositionEvent {
         old_price: Decimal,
         new_price: Decimal,
         unrealized_pnl: Decimal,
+        /// 기준통화 환산 미실현손익 (환율 미설정 시 None)
+        #[serde(default)]
+        unrealized_pnl_base: Option<Decimal>,
+        timestamp: DateTime<Utc>,
+    },
+    /// 거래소 포지션과 내부 상태 불일치 (리컨실리에이션)
+    Discrepancy {
+        /// 내부 포지션 ID (내부에 없으면 `None`)
+        position_id: Option<Uuid>,
+        symbol: String,
+        /// 내부 보유 상태
+        internal: Option<HoldingSnapshot>,
+        /// 거래소 보유 상태
+        exchange: Option<HoldingSnapshot>,
+        /// 거래소 기준으로 보정했는지 여부
+        corrected: bool,
         timestamp: DateTime<Utc>,
     },
 }
@@ -86,6 +300,7 @@ impl PositionEvent {
             PositionEvent::Decreased { position_id, .. } => *position_id,
             PositionEvent::Closed { position_id, .. } => *position_id,
             PositionEvent::PriceUpdated { position_id, .. } => *position_id,
+            PositionEvent::Discrepancy { position_id, .. } => position_id.unwrap_or_default(),
         }
     }
 
@@ -97,6 +312,7 @@ impl PositionEvent {
             PositionEvent::Decreased { timestamp, .. } => *timestamp,
             PositionEvent::Closed { timestamp, .. } => *timestamp,
             PositionEvent::PriceUpdated { timestamp, .. } => *timestamp,
+            PositionEvent::Discrepancy { timestamp, .. } => *timestamp,
         }
     }
 }
@@ -116,8 +332,24 @@ pub struct PositionTracker {
     events: Vec<PositionEvent>,
     /// 거래소 이름
     exchange: String,
+    /// 실현손익 원가 기준
+    cost_basis: CostBasisMethod,
+    /// 오픈 포지션별 진입 로트 (오래된 순)
+    lots: HashMap<Uuid, VecDeque<PositionLot>>,
     /// 최대 히스토리 크기
     max_history_size: usize,
+    /// 기준통화
+    base_currency: String,
+    /// 심볼별 통화 (미지정 심볼은 기준통화)
+    symbol_currencies: HashMap<String, String>,
+    /// 환율 ((from, to) -> rate)
+    fx_rates: HashMap<(String, String), Decimal>,
+    /// 포지션별 통화/환산 정보 (종료 포지션 포함)
+    position_fx: HashMap<Uuid, PositionFx>,
+    /// 리컨실리에이션 설정
+    reconciliation: ReconciliationConfig,
+    /// 마지막 리컨실리에이션 결과
+    last_reconciliation: Option<ReconciliationReport>,
 }
 
 impl PositionTracker {
@@ -130,10 +362,109 @@ impl PositionTracker {
             closed_positions: Vec::new(),
             events: Vec::new(),
             exchange: exchange.into(),
+            cost_basis: CostBasisMethod::default(),
+            lots: HashMap::new(),
             max_history_size: 10000,
+            base_currency: DEFAULT_BASE_CURRENCY.to_string(),
+            symbol_currencies: HashMap::new(),
+            fx_rates: HashMap::new(),
+            position_fx: HashMap::new(),
+            reconciliation: ReconciliationConfig::default(),
+            last_reconciliation: None,
+        }
+    }
+
+    /// 기준통화를 지정한다 (기본값 KRW).
+    pub fn with_base_currency(mut self, currency: impl Into<String>) -> Self {
+        self.base_currency = currency.into().to_uppercase();
+        self
+    }
+
+    /// 기준통화를 가져온다.
+    pub fn base_currency(&self) -> &str {
+        &self.base_currency
+    }
+
+    // ==================== 통화/환율 ====================
+
+    /// 심볼의 거래 통화를 지정한다.
+    ///
+    /// 이후 오픈되는 포지션에 적용되며, 지정하지 않은 심볼은 기준통화로 간주한다.
+    pub fn set_symbol_currency(&mut self, symbol: impl Into<String>, currency: impl Into<String>) {
+        self.symbol_currencies
+            .insert(symbol.into(), currency.into().to_uppercase());
+    }
+
+    /// 환율을 설정한다 (1 `from` = `rate` `to`).
+    ///
+    /// 역방향 환율은 설정하지 않아도 역수로 계산된다.
+    pub fn set_fx_rate(
+        &mut self,
+        from: &str,
+        to: &str,
+        rate: Decimal,
+    ) -> Result<(), PositionTrackerError> {
+        if rate <= Decimal::ZERO {
+            return Err(PositionTrackerError::InvalidOperation(format!(
+                "FX rate must be positive: {}/{} = {}",
+                from, to, rate
+            )));
+        }
+        self.fx_rates
+            .insert((from.to_uppercase(), to.to_uppercase()), rate);
+        Ok(())
+    }
+
+    /// 두 통화 간 환율을 가져온다 (1 `from` = ? `to`).
+    pub fn fx_rate(&self, from: &str, to: &str) -> Result<Decimal, PositionTrackerError> {
+        let from = from.to_uppercase();
+        let to = to.to_uppercase();
+        if from == to {
+            return Ok(Decimal::ONE);
+        }
+        if let Some(rate) = self.fx_rates.get(&(from.clone(), to.clone())) {
+            return Ok(*rate);
+        }
+        self.fx_rates
+            .get(&(to.clone(), from.clone()))
+            .map(|rate| Decimal::ONE / *rate)
+            .ok_or(PositionTrackerError::MissingFxRate(from, to))
+    }
+
+    /// 포지션의 통화/환산 정보를 가져온다.
+    pub fn get_position_fx(&self, position_id: Uuid) -> Option<&PositionFx> {
+        self.position_fx.get(&position_id)
+    }
+
+    /// 포지션 통화의 현재 기준통화 환율 (통화 정보가 없으면 1).
+    fn position_fx_rate(&self, position_id: Uuid) -> Result<Decimal, PositionTrackerError> {
+        match self.position_fx.get(&position_id) {
+            Some(fx) => self.fx_rate(&fx.currency, &self.base_currency),
+            None => Ok(Decimal::ONE),
+        }
+    }
+
+    /// 원가 기준을 지정하여 생성한다.
+    pub fn new_with_cost_basis(exchange: impl Into<String>, method: CostBasisMethod) -> Self {
+        Self {
+            cost_basis: method,
+            ..Self::new(exchange)
         }
     }
 
+    /// 실현손익 원가 기준을 가져온다.
+    pub fn cost_basis(&self) -> CostBasisMethod {
+        self.cost_basis
+    }
+
+    /// 오픈 포지션의 잔여 로트를 가져온다 (오래된 순).
+    pub fn get_lots(&self, position_id: Uuid) -> Vec<&PositionLot> {
+        self.lots
+            .get(&position_id)
+            .map(|lots| lots.iter().collect())
+            .unwrap_or_default()
+    }
+
     /// 커스텀 히스토리 크기로 생성한다.
     pub fn with_history_size(exchange: impl Into<String>, max_history_size: usize) -> Self {
         Self {
@@ -163,6 +494,13 @@ impl PositionTracker {
             )));
         }
 
+        let currency = self
+            .symbol_currencies
+            .get(&symbol_str)
+            .cloned()
+            .unwrap_or_else(|| self.base_currency.clone());
+        let entry_fx_rate = self.fx_rate(&currency, &self.base_currency)?;
+
         let mut position = Position::new(&self.exchange, symbol, side, quantity, price);
 
         if let Some(ref strat_id) = strategy_id {
@@ -173,22 +511,41 @@ impl PositionTracker {
         let now = Utc::now();
 
         // 포지션 저장
+        self.lots.insert(
+            position_id,
+            VecDeque::from([PositionLot {
+                entry_time: position.opened_at,
+                entry_price: price,
+                quantity,
+            }]),
+        );
         self.positions.insert(position_id, position.clone());
         self.positions_by_symbol
             .insert(symbol_str.clone(), position_id);
+        self.position_fx.insert(
+            position_id,
+            PositionFx {
+                currency: currency.clone(),
+                entry_fx_rate,
+                realized_pnl_base: Decimal::ZERO,
+                realized_fx_pnl: Decimal::ZERO,
+            },
+        );
 
         // 전략별 인덱싱
-        if let Some(strat_id) = strategy_id {
+        if let Some(ref strat_id) = strategy_id {
             self.positions_by_strategy
-                .entry(strat_id)
+                .entry(strat_id.clone())
                 .or_default()
                 .push(position_id);
         }
+        self.record_open_positions(strategy_id.as_deref());
 
         // 이벤트 기록
         self.events.push(PositionEvent::Opened {
             position_id,
             symbol: symbol_str,
+            currency,
             side,
             quantity,
             price,
@@ -288,9 +645,23 @@ impl PositionTracker {
                     .cloned()
             })
             .ok_or(PositionTrackerError::PositionNotFound(pos_id))?;
+
+        let strategy = position.strategy_id.as_deref();
+        telemetry::record_realized_pnl(strategy, &self.exchange, pnl);
+        self.record_open_positions(strategy);
         Ok((position, pnl))
     }
 
+    /// 전략의 열린 포지션 수를 메트릭으로 기록한다.
+    fn record_open_positions(&self, strategy_id: Option<&str>) {
+        let count = self
+            .positions
+            .values()
+            .filter(|p| p.strategy_id.as_deref() == strategy_id)
+            .count();
+        telemetry::set_open_positions(strategy_id, &self.exchange, count);
+    }
+
     /// 포지션을 완전히 종료한다.
     pub fn close_position(
         &mut self,
@@ -318,15 +689,36 @@ impl PositionTracker {
         quantity: Decimal,
         price: Decimal,
     ) -> Result<(), PositionTrackerError> {
+        let fx_now = self.position_fx_rate(position_id)?;
         let position = self
             .positions
             .get_mut(&position_id)
             .ok_or(PositionTrackerError::PositionNotFound(position_id))?;
 
+        // 진입 환율을 진입 금액 기준으로 가중평균
+        if let Some(fx) = self.position_fx.get_mut(&position_id) {
+            let existing_cost = position.quantity * position.entry_price;
+            let added_cost = quantity * price;
+            let total_cost = existing_cost + added_cost;
+            if total_cost > Decimal::ZERO {
+                fx.entry_fx_rate =
+                    (existing_cost * fx.entry_fx_rate + added_cost * fx_now) / total_cost;
+            }
+        }
+
         position.add(quantity, price);
         let new_total = position.quantity;
         let now = Utc::now();
 
+        self.lots
+            .entry(position_id)
+            .or_default()
+            .push_back(PositionLot {
+                entry_time: now,
+                entry_price: price,
+                quantity,
+            });
+
         self.events.push(PositionEvent::Increased {
             position_id,
             quantity,
@@ -345,6 +737,7 @@ impl PositionTracker {
         quantity: Decimal,
         price: Decimal,
     ) -> Result<Decimal, PositionTrackerError> {
+        let fx_now = self.position_fx_rate(position_id)?;
         let position = self
             .positions
             .get_mut(&position_id)
@@ -357,11 +750,52 @@ impl PositionTracker {
             ));
         }
 
-        let pnl = position.reduce(quantity, price);
+        let average_entry = position.entry_price;
+        let average_pnl = position.reduce(quantity, price);
+        let closed_lots = consume_lots(
+            self.lots.entry(position_id).or_default(),
+            self.cost_basis,
+            position.side,
+            quantity,
+            price,
+            average_entry,
+            position.opened_at,
+        );
+
+        // 로트 기준 방식은 로트 단위 실현손익으로 교체하고, 잔여 로트로 평균단가를 재계산
+        let pnl = if self.cost_basis == CostBasisMethod::AverageCost {
+            average_pnl
+        } else {
+            let lot_pnl: Decimal = closed_lots.iter().map(|l| l.realized_pnl).sum();
+            position.realized_pnl += lot_pnl - average_pnl;
+            if let Some(entry_price) = self.lots.get(&position_id).and_then(weighted_entry_price) {
+                position.entry_price = entry_price;
+                position.update_price(position.current_price);
+            }
+            lot_pnl
+        };
+
+        // 기준통화 환산: 원통화 손익은 현재 환율로, 매수 원금은 진입 환율 대비 환차손익 분리
+        let closed_cost: Decimal = closed_lots.iter().map(|l| l.entry_price * l.quantity).sum();
+        let (pnl_base, fx_pnl, total_pnl_base, total_fx_pnl) =
+            match self.position_fx.get_mut(&position_id) {
+                Some(fx) => {
+                    let fx_pnl =
+                        principal_fx_pnl(position.side, closed_cost, fx.entry_fx_rate, fx_now);
+                    let pnl_base = pnl * fx_now + fx_pnl;
+                    fx.realized_pnl_base += pnl_base;
+                    fx.realized_fx_pnl += fx_pnl;
+                    (pnl_base, fx_pnl, fx.realized_pnl_base, fx.realized_fx_pnl)
+                }
+                None => (pnl, Decimal::ZERO, position.realized_pnl, Decimal::ZERO),
+            };
+
         let remaining = position.quantity;
         let now = Utc::now();
 
         if position.is_closed() {
+            self.lots.remove(&position_id);
+
             // 포지션 완전 종료
             let final_pnl = position.realized_pnl;
             let symbol_str = position.ticker.to_string();
@@ -388,6 +822,9 @@ impl PositionTracker {
             self.events.push(PositionEvent::Closed {
                 position_id,
                 final_pnl,
+                final_pnl_base: total_pnl_base,
+                fx_pnl: total_fx_pnl,
+                closed_lots,
                 timestamp: now,
             });
         } else {
@@ -396,7 +833,10 @@ impl PositionTracker {
                 quantity,
                 price,
                 realized_pnl: pnl,
+                realized_pnl_base: pnl_base,
+                fx_pnl,
                 remaining,
+                closed_lots,
                 timestamp: now,
             });
         }
@@ -425,12 +865,17 @@ impl PositionTracker {
         let old_price = position.current_price;
         position.update_price(new_price);
         let unrealized_pnl = position.unrealized_pnl;
+        let unrealized_pnl_base = self
+            .positions
+            .get(&pos_id)
+            .and_then(|p| self.unrealized_pnl_in_base(p).ok());
 
         self.events.push(PositionEvent::PriceUpdated {
             position_id: pos_id,
             old_price,
             new_price,
             unrealized_pnl,
+            unrealized_pnl_base,
             timestamp: Utc::now(),
         });
 
@@ -543,6 +988,71 @@ impl PositionTracker {
         result
     }
 
+    /// 기준통화 환산 총 평가액을 가져온다.
+    pub fn total_value_in_base(&self) -> Result<Decimal, PositionTrackerError> {
+        self.positions.values().try_fold(Decimal::ZERO, |acc, p| {
+            Ok(acc + p.notional_value() * self.position_fx_rate(p.id)?)
+        })
+    }
+
+    /// 기준통화 환산 총 미실현손익을 가져온다 (미실현 환차손익 포함).
+    pub fn total_unrealized_pnl_in_base(&self) -> Result<Decimal, PositionTrackerError> {
+        self.positions.values().try_fold(Decimal::ZERO, |acc, p| {
+            Ok(acc + self.unrealized_pnl_in_base(p)?)
+        })
+    }
+
+    /// 기준통화 환산 총 실현손익을 가져온다.
+    ///
+    /// 실현손익은 실현 시점 환율로 확정되므로 현재 환율이 필요 없다.
+    pub fn total_realized_pnl_in_base(&self) -> Decimal {
+        self.positions
+            .values()
+            .chain(self.closed_positions.iter())
+            .map(|p| {
+                self.position_fx
+                    .get(&p.id)
+                    .map(|fx| fx.realized_pnl_base)
+                    .unwrap_or(p.realized_pnl)
+            })
+            .sum()
+    }
+
+    /// 환율 변동만으로 발생한 총 환차손익을 가져온다 (실현 + 미실현, 기준통화).
+    pub fn total_fx_pnl(&self) -> Result<Decimal, PositionTrackerError> {
+        let realized: Decimal = self
+            .positions
+            .values()
+            .chain(self.closed_positions.iter())
+            .filter_map(|p| self.position_fx.get(&p.id))
+            .map(|fx| fx.realized_fx_pnl)
+            .sum();
+
+        self.positions
+            .values()
+            .try_fold(realized, |acc, p| Ok(acc + self.unrealized_fx_pnl(p)?))
+    }
+
+    /// 오픈 포지션의 미실현 환차손익 (기준통화).
+    fn unrealized_fx_pnl(&self, position: &Position) -> Result<Decimal, PositionTrackerError> {
+        let Some(fx) = self.position_fx.get(&position.id) else {
+            return Ok(Decimal::ZERO);
+        };
+        let fx_now = self.fx_rate(&fx.currency, &self.base_currency)?;
+        Ok(principal_fx_pnl(
+            position.side,
+            position.quantity * position.entry_price,
+            fx.entry_fx_rate,
+            fx_now,
+        ))
+    }
+
+    /// 오픈 포지션의 기준통화 환산 미실현손익 (환차손익 포함).
+    fn unrealized_pnl_in_base(&self, position: &Position) -> Result<Decimal, PositionTrackerError> {
+        let fx_now = self.position_fx_rate(position.id)?;
+        Ok(position.unrealized_pnl * fx_now + self.unrealized_fx_pnl(position)?)
+    }
+
     /// 포지션 이벤트들을 가져온다.
     pub fn get_events(&self) -> &[PositionEvent] {
         &self.events
@@ -556,6 +1066,231 @@ impl PositionTracker {
             .collect()
     }
 
+    // ==================== 리컨실리에이션 ====================
+
+    /// 리컨실리에이션 설정을 지정한다.
+    pub fn with_reconciliation(mut self, config: ReconciliationConfig) -> Self {
+        self.reconciliation = config;
+        self
+    }
+
+    /// 리컨실리에이션 설정을 변경한다.
+    pub fn set_reconciliation_config(&mut self, config: ReconciliationConfig) {
+        self.reconciliation = config;
+    }
+
+    /// 리컨실리에이션 설정.
+    pub fn reconciliation_config(&self) -> &ReconciliationConfig {
+        &self.reconciliation
+    }
+
+    /// 리컨실리에이션 주기.
+    pub fn reconciliation_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.reconciliation.interval_secs)
+    }
+
+    /// 마지막 리컨실리에이션 결과.
+    pub fn last_reconciliation(&self) -> Option<&ReconciliationReport> {
+        self.last_reconciliation.as_ref()
+    }
+
+    /// 주기상 리컨실리에이션을 실행할 시점인지 여부.
+    pub fn is_reconciliation_due(&self, now: DateTime<Utc>) -> bool {
+        match &self.last_reconciliation {
+            Some(report) => {
+                let elapsed = (now - report.run_at).num_seconds().max(0) as u64;
+                elapsed >= self.reconciliation.interval_secs
+            }
+            None => true,
+        }
+    }
+
+    /// 거래소 포지션을 조회해 내부 상태와 비교한다.
+    ///
+    /// 조회 실패 시 오류를 마지막 결과로 기록하고 에러를 반환합니다.
+    pub async fn reconcile(
+        &mut self,
+        provider: &dyn ExchangeProvider,
+    ) -> Result<ReconciliationReport, PositionTrackerError> {
+        match provider.fetch_positions().await {
+            Ok(positions) => Ok(self.reconcile_positions(&positions)),
+            Err(e) => {
+                let message = e.to_string();
+                self.record_reconciliation_error(message.clone());
+                Err(PositionTrackerError::Reconciliation(message))
+            }
+        }
+    }
+
+    /// 조회된 거래소 포지션과 내부 상태를 비교한다.
+    ///
+    /// 방향 또는 수량(허용 오차 초과)이 다르거나 한쪽에만 포지션이 있으면
+    /// [`PositionEvent::Discrepancy`]를 기록합니다. 평균 진입가는 수수료 반영 방식이
+    /// 거래소마다 달라 비교하지 않습니다. [`ReconciliationMode::AutoCorrect`]에서는
+    /// 거래소 상태로 내부 포지션을 보정합니다.
+    pub fn reconcile_positions(
+        &mut self,
+        exchange_positions: &[StrategyPositionInfo],
+    ) -> ReconciliationReport {
+        let now = Utc::now();
+        let mode = self.reconciliation.mode;
+        let tolerance = self.reconciliation.quantity_tolerance;
+
+        let exchange: HashMap<&str, &StrategyPositionInfo> = exchange_positions
+            .iter()
+            .filter(|p| !p.quantity.is_zero())
+            .map(|p| (p.ticker.as_str(), p))
+            .collect();
+        let symbols: BTreeSet<String> = self
+            .positions_by_symbol
+            .keys()
+            .cloned()
+            .chain(exchange.keys().map(|s| s.to_string()))
+            .collect();
+
+        let mut discrepancies = Vec::new();
+        for symbol in &symbols {
+            let position_id = self.positions_by_symbol.get(symbol).copied();
+            let internal = position_id
+                .and_then(|id| self.positions.get(&id))
+                .map(HoldingSnapshot::from);
+            let remote = exchange.get(symbol.as_str()).copied();
+
+            let matches = match (&internal, remote) {
+                (Some(i), Some(r)) => {
+                    i.side == r.side && (i.quantity - r.quantity).abs() <= tolerance
+                }
+                (None, None) => true,
+                _ => false,
+            };
+            if matches {
+                continue;
+            }
+
+            let corrected = mode == ReconciliationMode::AutoCorrect
+                && match self.correct_position(symbol, position_id, remote) {
+                    Ok(()) => true,
+                    Err(e) => {
+                        warn!(symbol = %symbol, error = %e, "포지션 보정 실패");
+                        false
+                    }
+                };
+
+            let discrepancy = PositionDiscrepancy {
+                symbol: symbol.clone(),
+                position_id,
+                internal,
+                exchange: remote.map(HoldingSnapshot::from),
+                corrected,
+            };
+            self.events.push(PositionEvent::Discrepancy {
+                position_id,
+                symbol: symbol.clone(),
+                internal: discrepancy.internal.clone(),
+                exchange: discrepancy.exchange.clone(),
+                corrected,
+                timestamp: now,
+            });
+            discrepancies.push(discrepancy);
+        }
+
+        if !discrepancies.is_empty() {
+            info!(
+                discrepancies = discrepancies.len(),
+                mode = ?mode,
+                "포지션 리컨실리에이션 불일치 발견"
+            );
+        }
+
+        let report = ReconciliationReport {
+            run_at: now,
+            mode,
+            checked: symbols.len(),
+            discrepancies,
+            error: None,
+        };
+        self.last_reconciliation = Some(report.clone());
+        self.trim_history();
+        report
+    }
+
+    /// 거래소 조회 실패를 마지막 리컨실리에이션 결과로 기록한다.
+    pub fn record_reconciliation_error(&mut self, error: impl Into<String>) {
+        self.last_reconciliation = Some(ReconciliationReport {
+            run_at: Utc::now(),
+            mode: self.reconciliation.mode,
+            checked: 0,
+            discrepancies: Vec::new(),
+            error: Some(error.into()),
+        });
+    }
+
+    /// 거래소 상태를 기준으로 심볼의 내부 포지션을 보정한다.
+    fn correct_position(
+        &mut self,
+        symbol: &str,
+        position_id: Option<Uuid>,
+        remote: Option<&StrategyPositionInfo>,
+    ) -> Result<(), PositionTrackerError> {
+        let now = Utc::now();
+        let mut strategy_id = None;
+
+        if let Some(id) = position_id {
+            let position = self
+                .positions
+                .get_mut(&id)
+                .ok_or(PositionTrackerError::PositionNotFound(id))?;
+
+            // 같은 방향이면 수량·진입가만 맞추고 로트를 단일 로트로 재구성
+            if let Some(remote) = remote.filter(|r| r.side == position.side) {
+                position.quantity = remote.quantity;
+                position.entry_price = remote.avg_entry_price;
+                position.update_price(remote.current_price);
+                self.lots.insert(
+                    id,
+                    VecDeque::from([PositionLot {
+                        entry_time: position.opened_at,
+                        entry_price: remote.avg_entry_price,
+                        quantity: remote.quantity,
+                    }]),
+                );
+                return Ok(());
+            }
+
+            // 거래소에 없거나 방향이 다르면 내부 포지션을 강제 종료
+            let mut closed = self
+                .positions
+                .remove(&id)
+                .ok_or(PositionTrackerError::PositionNotFound(id))?;
+            closed.closed_at = Some(now);
+            closed.updated_at = now;
+            strategy_id = closed.strategy_id.clone();
+            if let Some(ids) = strategy_id
+                .as_ref()
+                .and_then(|s| self.positions_by_strategy.get_mut(s))
+            {
+                ids.retain(|pid| *pid != id);
+            }
+            self.positions_by_symbol.remove(symbol);
+            self.lots.remove(&id);
+            self.closed_positions.push(closed);
+        }
+
+        if let Some(remote) = remote {
+            let position = self.open_position(
+                symbol.to_string(),
+                remote.side,
+                remote.quantity,
+                remote.avg_entry_price,
+                strategy_id,
+            )?;
+            if let Some(opened) = self.positions.get_mut(&position.id) {
+                opened.update_price(remote.current_price);
+            }
+        }
+        Ok(())
+    }
+
     // ==================== 내부 ====================
 
     fn trim_history(&mut self) {
@@ -566,14 +1301,110 @@ impl PositionTracker {
         if self.closed_positions.len() > self.max_history_size {
             let drain_count = self.closed_positions.len() - self.max_history_size;
             self.closed_positions.drain(0..drain_count);
+            self.prune_position_fx();
         }
     }
 
+    /// 더 이상 추적하지 않는 포지션의 통화 정보를 제거한다.
+    fn prune_position_fx(&mut self) {
+        let positions = &self.positions;
+        let closed = &self.closed_positions;
+        self.position_fx
+            .retain(|id, _| positions.contains_key(id) || closed.iter().any(|p| p.id == *id));
+    }
+
     /// 오래된 종료 포지션을 정리한다.
     pub fn cleanup_old_positions(&mut self, older_than: DateTime<Utc>) {
         self.closed_positions
             .retain(|p| p.closed_at.map(|t| t >= older_than).unwrap_or(true));
+        self.prune_position_fx();
+    }
+}
+
+/// 원가 기준에 따라 로트를 소진하고 청산된 로트 목록을 반환한다.
+///
+/// 평균단가 방식은 수량 추적을 위해 오래된 로트부터 소진하되,
+/// 실현손익은 청산 시점 평균단가 기준 단일 로트로 보고한다.
+fn consume_lots(
+    lots: &mut VecDeque<PositionLot>,
+    method: CostBasisMethod,
+    side: Side,
+    quantity: Decimal,
+    exit_price: Decimal,
+    average_entry: Decimal,
+    opened_at: DateTime<Utc>,
+) -> Vec<ClosedLot> {
+    let lot_pnl = |entry_price: Decimal, qty: Decimal| match side {
+        Side::Buy => (exit_price - entry_price) * qty,
+        Side::Sell => (entry_price - exit_price) * qty,
+    };
+
+    let mut closed = Vec::new();
+    let mut left = quantity;
+    while left > Decimal::ZERO {
+        let lot = match method {
+            CostBasisMethod::Lifo => lots.back_mut(),
+            CostBasisMethod::AverageCost | CostBasisMethod::Fifo => lots.front_mut(),
+        };
+        let Some(lot) = lot else {
+            break;
+        };
+
+        let take = left.min(lot.quantity);
+        lot.quantity -= take;
+        left -= take;
+        closed.push(ClosedLot {
+            entry_time: lot.entry_time,
+            entry_price: lot.entry_price,
+            quantity: take,
+            exit_price,
+            realized_pnl: lot_pnl(lot.entry_price, take),
+        });
+
+        if lot.quantity.is_zero() {
+            match method {
+                CostBasisMethod::Lifo => lots.pop_back(),
+                CostBasisMethod::AverageCost | CostBasisMethod::Fifo => lots.pop_front(),
+            };
+        }
+    }
+
+    if method == CostBasisMethod::AverageCost {
+        return vec![ClosedLot {
+            entry_time: opened_at,
+            entry_price: average_entry,
+            quantity,
+            exit_price,
+            realized_pnl: lot_pnl(average_entry, quantity),
+        }];
+    }
+    closed
+}
+
+/// 매수 원금의 환차손익 (기준통화).
+///
+/// 매수 포지션은 원금을 외화로 환전해 보유하므로 환율 변동에 노출된다.
+/// 공매도 포지션은 매도 대금과 상환 의무가 같은 통화라 원금 노출이 없고,
+/// 원통화 손익만 현재 환율로 환산된다.
+fn principal_fx_pnl(side: Side, cost: Decimal, entry_fx_rate: Decimal, fx_now: Decimal) -> Decimal {
+    match side {
+        Side::Buy => cost * (fx_now - entry_fx_rate),
+        Side::Sell => Decimal::ZERO,
+    }
+}
+
+/// 잔여 로트의 가중평균 진입가.
+fn weighted_entry_price(lots: &VecDeque<PositionLot>) -> Option<Decimal> {
+    let total: Decimal = lots.iter().map(|l| l.quantity).sum();
+    if total.is_zero() {
+        return None;
     }
+    Some(
+        lots.iter()
+            .map(|l| l.entry_price * l.quantity)
+            .sum::<Decimal>()
+            / total,
+    )
 }
 
 #[cfg(test)]
@@ -947,4 +1778,316 @@ mod tests {
         assert_eq!(position.quantity, dec!(0.1));
         assert_eq!(position.realized_pnl, dec!(500)); // 손익: (55000-50000)*0.1
     }
+
+    fn build_lots(tracker: &mut PositionTracker) {
+        let symbol = create_test_symbol();
+        tracker
+            .open_position(symbol.clone(), Side::Buy, dec!(10), dec!(100), None)
+            .unwrap();
+        tracker
+            .add_to_position(&symbol, dec!(10), dec!(120))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_fifo_consumes_oldest_lot() {
+        let mut tracker = PositionTracker::new_with_cost_basis("binance", CostBasisMethod::Fifo);
+        build_lots(&mut tracker);
+        let symbol = create_test_symbol();
+
+        let (position, pnl) = tracker
+            .reduce_position(&symbol, dec!(15), dec!(130))
+            .unwrap();
+
+        // 100 로트 10개 + 120 로트 5개: (130-100)*10 + (130-120)*5 = 350
+        assert_eq!(pnl, dec!(350));
+        assert_eq!(position.realized_pnl, dec!(350));
+        assert_eq!(position.entry_price, dec!(120));
+
+        let lots = tracker.get_lots(position.id);
+        assert_eq!(lots.len(), 1);
+        assert_eq!(lots[0].quantity, dec!(5));
+
+        match tracker.get_events().last().unwrap() {
+            PositionEvent::Decreased { closed_lots, .. } => {
+                assert_eq!(closed_lots.len(), 2);
+                assert_eq!(closed_lots[0].entry_price, dec!(100));
+                assert_eq!(closed_lots[0].quantity, dec!(10));
+                assert_eq!(closed_lots[1].realized_pnl, dec!(50));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lifo_consumes_newest_lot() {
+        let mut tracker = PositionTracker::new_with_cost_basis("binance", CostBasisMethod::Lifo);
+        build_lots(&mut tracker);
+        let symbol = create_test_symbol();
+
+        let (_, pnl) = tracker
+            .reduce_position(&symbol, dec!(15), dec!(130))
+            .unwrap();
+        // 120 로트 10개 + 100 로트 5개: (130-120)*10 + (130-100)*5 = 250
+        assert_eq!(pnl, dec!(250));
+
+        // 잔여 100 로트 전량 청산 후 누적 실현손익
+        let (position, pnl) = tracker.close_position(&symbol, dec!(90)).unwrap();
+        assert_eq!(pnl, dec!(-50));
+        assert_eq!(position.realized_pnl, dec!(200));
+        assert!(position.is_closed());
+        assert!(tracker.get_lots(position.id).is_empty());
+    }
+
+    #[test]
+    fn test_average_cost_matches_fifo_for_single_lot() {
+        let symbol = create_test_symbol();
+        let mut results = Vec::new();
+
+        for method in [CostBasisMethod::AverageCost, CostBasisMethod::Fifo] {
+            let mut tracker = PositionTracker::new_with_cost_basis("binance", method);
+            tracker
+                .open_position(symbol.clone(), Side::Sell, dec!(4), dec!(200), None)
+                .unwrap();
+            let (_, partial) = tracker
+                .reduce_position(&symbol, dec!(1), dec!(180))
+                .unwrap();
+            let (position, last) = tracker.close_position(&symbol, dec!(210)).unwrap();
+            results.push((partial, last, position.realized_pnl));
+        }
+
+        assert_eq!(results[0], results[1]);
+        assert_eq!(results[0], (dec!(20), dec!(-30), dec!(-10)));
+    }
+
+    #[test]
+    fn test_missing_fx_rate_rejects_foreign_position() {
+        let mut tracker = PositionTracker::new("kis").with_base_currency("KRW");
+        tracker.set_symbol_currency("AAPL", "usd");
+
+        let result = tracker.open_position("AAPL".to_string(), Side::Buy, dec!(1), dec!(100), None);
+        assert!(matches!(
+            result,
+            Err(PositionTrackerError::MissingFxRate(from, to)) if from == "USD" && to == "KRW"
+        ));
+        assert_eq!(tracker.open_position_count(), 0);
+
+        // 기준통화 심볼은 환율 없이 오픈 가능, 역방향 환율은 역수로 계산
+        tracker
+            .open_position("005930".to_string(), Side::Buy, dec!(1), dec!(70000), None)
+            .unwrap();
+        tracker.set_fx_rate("USD", "KRW", dec!(1250)).unwrap();
+        assert_eq!(tracker.fx_rate("KRW", "USD").unwrap(), dec!(0.0008));
+    }
+
+    #[test]
+    fn test_multi_currency_pnl_separates_fx_gain() {
+        let mut tracker = PositionTracker::new("kis").with_base_currency("KRW");
+        tracker.set_symbol_currency("AAPL", "USD");
+        tracker.set_fx_rate("USD", "KRW", dec!(1300)).unwrap();
+
+        let aapl = tracker
+            .open_position("AAPL".to_string(), Side::Buy, dec!(10), dec!(100), None)
+            .unwrap();
+        tracker
+            .open_position("005930".to_string(), Side::Buy, dec!(10), dec!(70000), None)
+            .unwrap();
+        assert_eq!(tracker.get_position_fx(aapl.id).unwrap().currency, "USD");
+
+        // 주가 10% 상승 + 원화 약세 (1300 → 1400)
+        tracker.set_fx_rate("USD", "KRW", dec!(1400)).unwrap();
+        tracker.update_price("AAPL", dec!(110)).unwrap();
+        tracker.update_price("005930", dec!(71000)).unwrap();
+
+        // 1,100 USD * 1400 + 710,000 KRW
+        assert_eq!(tracker.total_value_in_base().unwrap(), dec!(2250000));
+        // AAPL: 100 USD * 1400 + 원금 1,000 USD * 100 환차익 = 240,000 / 삼성전자: 10,000
+        assert_eq!(
+            tracker.total_unrealized_pnl_in_base().unwrap(),
+            dec!(250000)
+        );
+        assert_eq!(tracker.total_fx_pnl().unwrap(), dec!(100000));
+
+        let (_, pnl) = tracker.close_position("AAPL", dec!(110)).unwrap();
+        assert_eq!(pnl, dec!(100));
+        match tracker.get_events().last().unwrap() {
+            PositionEvent::Closed {
+                final_pnl,
+                final_pnl_base,
+                fx_pnl,
+                ..
+            } => {
+                assert_eq!(*final_pnl, dec!(100));
+                assert_eq!(*final_pnl_base, dec!(240000));
+                assert_eq!(*fx_pnl, dec!(100000));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        // 실현 후에는 환율이 바뀌어도 실현손익/실현 환차손익이 고정됨
+        tracker.set_fx_rate("USD", "KRW", dec!(1200)).unwrap();
+        assert_eq!(tracker.total_realized_pnl_in_base(), dec!(240000));
+        assert_eq!(tracker.total_fx_pnl().unwrap(), dec!(100000));
+    }
+
+    #[test]
+    fn test_reconcile_reports_without_correction_by_default() {
+        let mut tracker = PositionTracker::new("kis");
+        tracker
+            .open_position("005930".to_string(), Side::Buy, dec!(10), dec!(70000), None)
+            .unwrap();
+
+        // 수동 매도로 거래소 수량이 줄어든 상황
+        let exchange = vec![StrategyPositionInfo::new(
+            "005930".to_string(),
+            Side::Buy,
+            dec!(4),
+            dec!(70000),
+        )];
+        let report = tracker.reconcile_positions(&exchange);
+
+        assert_eq!(report.mode, ReconciliationMode::ReportOnly);
+        assert_eq!(report.checked, 1);
+        assert_eq!(report.discrepancies.len(), 1);
+        assert_eq!(report.corrected_count(), 0);
+        assert_eq!(
+            report.discrepancies[0].exchange.as_ref().unwrap().quantity,
+            dec!(4)
+        );
+        // 내부 상태는 그대로
+        assert_eq!(
+            tracker.get_position_for_symbol("005930").unwrap().quantity,
+            dec!(10)
+        );
+        assert!(matches!(
+            tracker.get_events().last().unwrap(),
+            PositionEvent::Discrepancy {
+                corrected: false,
+                ..
+            }
+        ));
+        assert_eq!(tracker.last_reconciliation(), Some(&report));
+    }
+
+    #[test]
+    fn test_reconcile_auto_correct_uses_exchange_state() {
+        let mut tracker = PositionTracker::new("kis").with_reconciliation(ReconciliationConfig {
+            mode: ReconciliationMode::AutoCorrect,
+            ..Default::default()
+        });
+        tracker
+            .open_position(
+                "005930".to_string(),
+                Side::Buy,
+                dec!(10),
+                dec!(70000),
+                Some("rsi".to_string()),
+            )
+            .unwrap();
+        tracker
+            .open_position("000660".to_string(), Side::Buy, dec!(5), dec!(120000), None)
+            .unwrap();
+
+        let exchange = vec![
+            // 수량 불일치 → 수량·진입가 보정
+            StrategyPositionInfo::new("005930".to_string(), Side::Buy, dec!(12), dec!(71000)),
+            // 내부에 없는 포지션 → 신규 생성
+            StrategyPositionInfo::new("035720".to_string(), Side::Buy, dec!(3), dec!(50000)),
+            // 수량 0은 무시
+            StrategyPositionInfo::new("051910".to_string(), Side::Buy, dec!(0), dec!(400000)),
+        ];
+        let report = tracker.reconcile_positions(&exchange);
+
+        // 000660은 거래소에 없으므로 종료
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.discrepancies.len(), 3);
+        assert_eq!(report.corrected_count(), 3);
+
+        let samsung = tracker.get_position_for_symbol("005930").unwrap();
+        assert_eq!(samsung.quantity, dec!(12));
+        assert_eq!(samsung.entry_price, dec!(71000));
+        assert_eq!(tracker.get_lots(samsung.id).len(), 1);
+        assert!(!tracker.has_position("000660"));
+        assert!(tracker.get_closed_positions()[0].closed_at.is_some());
+        assert_eq!(
+            tracker.get_position_for_symbol("035720").unwrap().quantity,
+            dec!(3)
+        );
+
+        // 보정 후 다시 실행하면 일치
+        assert!(tracker.reconcile_positions(&exchange).is_consistent());
+    }
+
+    #[test]
+    fn test_reconcile_side_mismatch_reopens_with_exchange_side() {
+        let mut tracker =
+            PositionTracker::new("binance").with_reconciliation(ReconciliationConfig {
+                mode: ReconciliationMode::AutoCorrect,
+                ..Default::default()
+            });
+        tracker
+            .open_position(
+                create_test_symbol(),
+                Side::Buy,
+                dec!(1),
+                dec!(50000),
+                Some("grid".to_string()),
+            )
+            .unwrap();
+
+        let exchange = vec![StrategyPositionInfo::new(
+            create_test_symbol(),
+            Side::Sell,
+            dec!(1),
+            dec!(51000),
+        )];
+        let report = tracker.reconcile_positions(&exchange);
+
+        assert_eq!(report.corrected_count(), 1);
+        let position = tracker.get_position_for_symbol("BTC/USDT").unwrap();
+        assert_eq!(position.side, Side::Sell);
+        assert_eq!(position.strategy_id.as_deref(), Some("grid"));
+        assert_eq!(tracker.get_positions_for_strategy("grid").len(), 1);
+        assert_eq!(tracker.get_closed_positions().len(), 1);
+    }
+
+    #[test]
+    fn test_reconcile_tolerance_and_schedule() {
+        let mut tracker = PositionTracker::new("upbit").with_reconciliation(ReconciliationConfig {
+            mode: ReconciliationMode::ReportOnly,
+            interval_secs: 60,
+            quantity_tolerance: dec!(0.0001),
+        });
+        assert!(tracker.is_reconciliation_due(Utc::now()));
+        assert_eq!(
+            tracker.reconciliation_interval(),
+            std::time::Duration::from_secs(60)
+        );
+
+        tracker
+            .open_position(
+                "KRW-BTC".to_string(),
+                Side::Buy,
+                dec!(0.5),
+                dec!(90000000),
+                None,
+            )
+            .unwrap();
+        let exchange = vec![StrategyPositionInfo::new(
+            "KRW-BTC".to_string(),
+            Side::Buy,
+            dec!(0.49995),
+            dec!(90000000),
+        )];
+        let report = tracker.reconcile_positions(&exchange);
+
+        assert!(report.is_consistent());
+        assert!(!tracker.is_reconciliation_due(report.run_at + chrono::Duration::seconds(30)));
+        assert!(tracker.is_reconciliation_due(report.run_at + chrono::Duration::seconds(60)));
+
+        tracker.record_reconciliation_error("timeout");
+        let last = tracker.last_reconciliation().unwrap();
+        assert_eq!(last.error.as_deref(), Some("timeout"));
+        assert!(!last.is_consistent());
+    }
 }
@@ -0,0 +1,138 @@
+//! 주문 전송 재시도 정책.
+//!
+//! 거래소 일시 장애(5xx)나 네트워크 오류처럼 재시도로 복구 가능한 실패에 대해
+//! 지수 백오프로 주문 전송을 재시도합니다.
+//!
+//! # 에러 분류
+//!
+//! - **재시도 가능**: 네트워크 연결 실패, 거래소 5xx, 요청 한도 초과
+//! - **모호함**: 타임아웃 - 주문이 접수됐는지 알 수 없으므로 client_order_id로
+//!   접수 여부를 확인한 뒤에만 재전송
+//! - **재시도 불가**: 잔고 부족, 잘못된 심볼, 인증 실패 등
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 주문 전송 재시도 설정.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// 최대 시도 횟수 (최초 전송 포함, 1이면 재시도 없음)
+    pub max_attempts: u32,
+    /// 첫 재시도 대기 시간 (밀리초)
+    pub base_delay_ms: u64,
+    /// 최대 대기 시간 (밀리초)
+    pub max_delay_ms: u64,
+    /// 대기 시간에 무작위 지터 적용 여부 (동시 재시도 분산)
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 200,
+            max_delay_ms: 5_000,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// 재시도 비활성화 설정 (1회만 전송).
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// `retry`번째 재시도 전 대기 시간 계산 (1부터 시작).
+    ///
+    /// `base_delay_ms * 2^(retry-1)`을 `max_delay_ms`로 제한합니다.
+    /// 지터가 켜져 있으면 계산된 값의 50%~100% 사이에서 무작위로 선택합니다.
+    pub fn delay_for_retry(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(31);
+        let delay_ms = self
+            .base_delay_ms
+            .saturating_mul(1u64 << exponent)
+            .min(self.max_delay_ms);
+
+        let delay_ms = if self.jitter && delay_ms > 1 {
+            let half = delay_ms / 2;
+            let spread = (Uuid::new_v4().as_u128() % u128::from(delay_ms - half + 1)) as u64;
+            half + spread
+        } else {
+            delay_ms
+        };
+
+        Duration::from_millis(delay_ms)
+    }
+}
+
+/// 실행 오류의 재시도 분류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryClass {
+    /// 재시도로 복구 가능 (요청이 처리되지 않았음이 확실)
+    Retryable,
+    /// 요청 처리 여부 불확실 (타임아웃) - 접수 여부 확인 후 재시도
+    Ambiguous,
+    /// 재시도해도 실패 (잔고 부족, 잘못된 심볼 등)
+    Fatal,
+}
+
+/// 메시지에 HTTP 5xx 상태 코드가 포함되어 있는지 확인.
+///
+/// 수량/가격 등 다른 숫자와 구분하기 위해 숫자 경계로 분리된 3자리 토큰만 검사합니다.
+pub(crate) fn contains_http_5xx(message: &str) -> bool {
+    message
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|token| token.len() == 3)
+        .filter_map(|token| token.parse::<u16>().ok())
+        .any(|code| (500..600).contains(&code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_delay_capped() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay_ms: 100,
+            max_delay_ms: 350,
+            jitter: false,
+        };
+
+        assert_eq!(config.delay_for_retry(1), Duration::from_millis(100));
+        assert_eq!(config.delay_for_retry(2), Duration::from_millis(200));
+        assert_eq!(config.delay_for_retry(3), Duration::from_millis(350));
+        assert_eq!(config.delay_for_retry(40), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_jitter_within_bounds() {
+        let config = RetryConfig {
+            max_attempts: 3,
+            base_delay_ms: 1_000,
+            max_delay_ms: 10_000,
+            jitter: true,
+        };
+
+        for _ in 0..50 {
+            let delay = config.delay_for_retry(2);
+            assert!(delay >= Duration::from_millis(1_000));
+            assert!(delay <= Duration::from_millis(2_000));
+        }
+    }
+
+    #[test]
+    fn test_contains_http_5xx() {
+        assert!(contains_http_5xx("API error 503: Service Unavailable"));
+        assert!(contains_http_5xx("HTTP 500"));
+        assert!(!contains_http_5xx("수량 1500 초과"));
+        assert!(!contains_http_5xx("API error 400: bad request"));
+    }
+}
@@ -31,6 +31,9 @@
 //! // 실거래 모드
 //! let mut executor = OrderExecutor::new(config, exchange);
 //! executor.process_signal(&signal, current_price, timestamp)?;
+//!
+//! // 같은 틱의 여러 전략 신호 일괄 처리 (충돌 해소 정책 적용)
+//! let batch = executor.process_signals(signals, &prices, timestamp).await;
 //! ```
 
 use std::collections::HashMap;
@@ -40,7 +43,13 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use trader_core::{Side, Signal, SignalType};
+use trader_core::{
+    resolve_signal_conflicts, ConflictResolutionPolicy, ResolvedSignals, Side, Signal,
+    SignalConflictResolution, SignalType,
+};
+use uuid::Uuid;
+
+use crate::sizing::{PositionSizingMethod, SizingInputs, TradeStats};
 
 /// Signal 처리 에러
 #[derive(Debug, Clone, Error)]
@@ -62,6 +71,8 @@ pub enum SignalProcessorError {
     ExchangeError(String),
     #[error("주문 실패: {0}")]
     OrderFailed(String),
+    #[error("실거래 모드 전환 확인 필요: '{expected}' 입력")]
+    LiveConfirmationRequired { expected: String },
 }
 
 /// 거래 결과
@@ -91,6 +102,17 @@ pub struct TradeResult {
     pub metadata: HashMap<String, String>,
 }
 
+/// Signal 일괄 처리 결과
+#[derive(Debug, Clone, Default)]
+pub struct SignalBatchResult {
+    /// 체결된 거래
+    pub trades: Vec<TradeResult>,
+    /// 충돌 해소 결과 (채택/기각 내역)
+    pub conflicts: Vec<SignalConflictResolution>,
+    /// 처리 실패한 신호 (신호 ID, 에러)
+    pub errors: Vec<(Uuid, SignalProcessorError)>,
+}
+
 /// 포지션 정보
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessorPosition {
@@ -112,6 +134,46 @@ pub struct ProcessorPosition {
     /// 그룹 ID (관련 포지션 묶음)
     /// 그룹 단위 청산, 손익 추적용
     pub group_id: Option<String>,
+    /// 트레일링 스톱 기준가 (롱: 진입 후 최고가, 숏: 진입 후 최저가)
+    /// None이면 아직 가격 추적 전 (진입가 기준)
+    #[serde(default)]
+    pub trailing_high: Option<Decimal>,
+}
+
+impl ProcessorPosition {
+    /// 트레일링 스톱 기준가 갱신.
+    ///
+    /// 롱은 최고가, 숏은 최저가를 추적합니다.
+    /// 기준가가 갱신되면 true를 반환합니다.
+    pub fn update_trailing_high(&mut self, price: Decimal) -> bool {
+        let current = self.trailing_high.unwrap_or(self.entry_price);
+        let improved = match self.side {
+            Side::Buy => price > current,
+            Side::Sell => price < current,
+        };
+        self.trailing_high = Some(if improved { price } else { current });
+        improved
+    }
+
+    /// 트레일링 스톱 가격 계산.
+    ///
+    /// 롱: 기준가 × (1 - pct), 숏: 기준가 × (1 + pct)
+    pub fn trailing_stop_price(&self, trailing_stop_pct: Decimal) -> Decimal {
+        let reference = self.trailing_high.unwrap_or(self.entry_price);
+        match self.side {
+            Side::Buy => reference * (Decimal::ONE - trailing_stop_pct),
+            Side::Sell => reference * (Decimal::ONE + trailing_stop_pct),
+        }
+    }
+
+    /// 현재가가 트레일링 스톱 가격에 도달했는지 확인.
+    pub fn is_trailing_stop_hit(&self, price: Decimal, trailing_stop_pct: Decimal) -> bool {
+        let stop_price = self.trailing_stop_price(trailing_stop_pct);
+        match self.side {
+            Side::Buy => price <= stop_price,
+            Side::Sell => price >= stop_price,
+        }
+    }
 }
 
 /// Signal 처리 설정
@@ -143,6 +205,18 @@ pub struct ProcessorConfig {
     /// 익절 비율 (기본 0.10 = 10%)
     #[serde(default = "default_take_profit_pct")]
     pub take_profit_pct: Decimal,
+    /// 트레일링 스톱 비율 (예: 0.05 = 최고가 대비 5% 하락 시 청산)
+    /// None이면 트레일링 스톱 비활성화
+    #[serde(default)]
+    pub trailing_stop_pct: Option<Decimal>,
+    /// 포지션 사이징 방식 (기본 FixedFraction)
+    /// 모든 방식의 투자 비율은 `max_position_size_pct`를 넘지 않습니다.
+    #[serde(default)]
+    pub sizing_method: PositionSizingMethod,
+    /// 전략 간 신호 충돌 해소 정책
+    /// None이면 충돌 검사 없이 들어온 순서대로 처리
+    #[serde(default)]
+    pub conflict_policy: Option<ConflictResolutionPolicy>,
 }
 
 fn default_stop_loss_pct() -> Decimal {
@@ -166,6 +240,9 @@ impl Default for ProcessorConfig {
             auto_take_profit: false,
             stop_loss_pct: Decimal::new(5, 2),    // 5%
             take_profit_pct: Decimal::new(10, 2), // 10%
+            trailing_stop_pct: None,
+            sizing_method: PositionSizingMethod::FixedFraction,
+            conflict_policy: None,
         }
     }
 }
@@ -187,6 +264,72 @@ pub trait SignalProcessor: Send + Sync {
         timestamp: DateTime<Utc>,
     ) -> Result<Option<TradeResult>, SignalProcessorError>;
 
+    /// 전략 간 신호 충돌 해소 정책 (None이면 충돌 검사 안 함)
+    fn conflict_policy(&self) -> Option<ConflictResolutionPolicy> {
+        None
+    }
+
+    /// 같은 틱에서 모인 Signal 일괄 처리
+    ///
+    /// 충돌 해소 정책이 설정되어 있으면 같은 심볼에 대한 전략 간 상반된 신호를
+    /// 정책에 따라 먼저 정리한 뒤, 남은 신호를 순서대로 처리합니다.
+    /// 가격은 `current_prices`에서 찾고, 없으면 Signal의 제안 가격을 사용합니다.
+    async fn process_signals(
+        &mut self,
+        signals: Vec<Signal>,
+        current_prices: &HashMap<String, Decimal>,
+        timestamp: DateTime<Utc>,
+    ) -> SignalBatchResult {
+        let resolved = match self.conflict_policy() {
+            Some(policy) => resolve_signal_conflicts(signals, policy),
+            None => ResolvedSignals {
+                signals,
+                conflicts: Vec::new(),
+            },
+        };
+
+        let mut result = SignalBatchResult {
+            conflicts: resolved.conflicts,
+            ..Default::default()
+        };
+
+        for signal in &resolved.signals {
+            let price = current_prices
+                .get(&signal.ticker)
+                .copied()
+                .or(signal.suggested_price);
+            let Some(price) = price else {
+                result.errors.push((
+                    signal.id,
+                    SignalProcessorError::InvalidPrice {
+                        price: Decimal::ZERO,
+                    },
+                ));
+                continue;
+            };
+
+            match self.process_signal(signal, price, timestamp).await {
+                Ok(Some(trade)) => result.trades.push(trade),
+                Ok(None) => {}
+                Err(e) => result.errors.push((signal.id, e)),
+            }
+        }
+
+        result
+    }
+
+    /// 현재가 수신 처리
+    ///
+    /// 해당 심볼 포지션의 트레일링 스톱 기준가를 갱신하고,
+    /// 스톱 가격에 도달한 포지션을 현재가로 청산합니다.
+    /// 갭으로 스톱 가격을 건너뛴 경우에도 스톱 가격이 아닌 수신된 현재가로 체결됩니다.
+    async fn on_price_update(
+        &mut self,
+        symbol: &str,
+        current_price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Vec<TradeResult>, SignalProcessorError>;
+
     /// 현재 잔고 조회
     fn balance(&self) -> Decimal;
 
@@ -303,6 +446,49 @@ pub fn apply_slippage(price: Decimal, slippage_rate: Decimal, side: Side) -> Dec
     }
 }
 
+/// 트레일링 스톱 트리거 확인.
+///
+/// 심볼이 일치하는 포지션의 기준가를 현재가로 갱신한 뒤,
+/// 스톱 가격에 도달한 포지션의 청산 Signal 목록을 반환합니다.
+/// SimulatedExecutor와 LiveExecutor에서 공통으로 사용합니다.
+pub fn collect_trailing_stop_exits(
+    positions: &mut HashMap<String, ProcessorPosition>,
+    symbol: &str,
+    current_price: Decimal,
+    trailing_stop_pct: Decimal,
+) -> Vec<Signal> {
+    let mut exits = Vec::new();
+
+    for position in positions.values_mut().filter(|p| p.symbol == symbol) {
+        position.update_trailing_high(current_price);
+        if !position.is_trailing_stop_hit(current_price, trailing_stop_pct) {
+            continue;
+        }
+
+        let exit_side = match position.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+        let stop_price = position.trailing_stop_price(trailing_stop_pct);
+        let mut signal = Signal::new(
+            "trailing_stop",
+            position.symbol.clone(),
+            exit_side,
+            SignalType::Exit,
+        )
+        .with_metadata("reason", serde_json::json!("trailing_stop"))
+        .with_metadata(
+            "trailing_stop_price",
+            serde_json::json!(stop_price.to_string()),
+        );
+        signal.position_id = position.position_id.clone();
+        signal.group_id = position.group_id.clone();
+        exits.push(signal);
+    }
+
+    exits
+}
+
 /// 포지션 크기 계산.
 ///
 /// 잔고, 최대 비율, Signal 강도를 기반으로 주문 수량을 계산합니다.
@@ -324,6 +510,43 @@ pub fn calculate_position_size(
     (position_amount, quantity)
 }
 
+/// Signal 기준 포지션 크기 계산.
+///
+/// 설정된 [`PositionSizingMethod`]로 투자 비율을 정한 뒤 [`calculate_position_size`]로
+/// 수량을 계산하고, Signal의 최대 포지션 금액
+/// ([`Signal::max_position_value`], 전략 분산 한도) 이하로 축소합니다.
+///
+/// - 변동성은 Signal 메타데이터([`Signal::volatility`])에서 읽습니다.
+/// - Kelly 통계는 `trades`(해당 실행기의 거래 기록)의 실현 손익으로 계산합니다.
+pub fn calculate_signal_position_size(
+    balance: Decimal,
+    config: &ProcessorConfig,
+    signal: &Signal,
+    price: Decimal,
+    trades: &[TradeResult],
+) -> (Decimal, Decimal) {
+    let inputs = SizingInputs {
+        volatility: signal.volatility(),
+        trade_stats: match config.sizing_method {
+            PositionSizingMethod::FractionalKelly { .. } => TradeStats::from_trades(trades),
+            _ => None,
+        },
+    };
+    let fraction = config
+        .sizing_method
+        .position_fraction(config.max_position_size_pct, &inputs);
+    let (position_amount, quantity) =
+        calculate_position_size(balance, fraction, signal.strength, price);
+
+    match signal.max_position_value() {
+        Some(cap) if cap < position_amount && price > Decimal::ZERO => {
+            let cap = cap.max(Decimal::ZERO);
+            (cap, cap / price)
+        }
+        _ => (position_amount, quantity),
+    }
+}
+
 /// 자금 검증.
 ///
 /// 주문에 필요한 금액(포지션 금액 + 수수료)이 잔고를 초과하는지 확인합니다.
@@ -401,6 +624,8 @@ pub fn update_position_average(
 /// Signal metadata를 TradeResult metadata로 변환.
 ///
 /// JSON Value의 문자열 값만 추출하여 HashMap<String, String>으로 변환합니다.
+/// 주문 옵션 키(`time_in_force`, `expire_at`)도 그대로 기록되며, 주문 변환 시의 해석은
+/// [`convert_signal_order_metadata`](crate::executor::convert_signal_order_metadata)가 담당합니다.
 pub fn convert_signal_metadata(signal: &Signal) -> HashMap<String, String> {
     signal
         .metadata
@@ -509,4 +734,64 @@ mod tests {
         assert_eq!(buy_price, dec!(10010)); // 10000 + 10
         assert_eq!(sell_price, dec!(9990)); // 10000 - 10
     }
+
+    #[test]
+    fn test_signal_position_size_capped_by_max_value() {
+        let signal = Signal::entry("s", "AAPL".to_string(), Side::Buy);
+        let config = ProcessorConfig::default();
+        let (amount, quantity) =
+            calculate_signal_position_size(dec!(10000), &config, &signal, dec!(100), &[]);
+        assert_eq!(amount, dec!(2000));
+        assert_eq!(quantity, dec!(20));
+
+        // 분산 한도 여유분(500)으로 축소
+        let capped = signal.with_max_position_value(dec!(500));
+        let (amount, quantity) =
+            calculate_signal_position_size(dec!(10000), &config, &capped, dec!(100), &[]);
+        assert_eq!(amount, dec!(500));
+        assert_eq!(quantity, dec!(5));
+    }
+
+    #[test]
+    fn test_signal_position_size_volatility_target() {
+        let config = ProcessorConfig {
+            sizing_method: PositionSizingMethod::VolatilityTarget {
+                target_volatility: 0.005,
+            },
+            ..Default::default()
+        };
+        let signal = Signal::entry("s", "AAPL".to_string(), Side::Buy).with_volatility(0.05);
+        let (amount, quantity) =
+            calculate_signal_position_size(dec!(10000), &config, &signal, dec!(100), &[]);
+        assert_eq!(amount, dec!(1000));
+        assert_eq!(quantity, dec!(10));
+
+        // 변동성 메타데이터가 없으면 고정 비율(20%)로 폴백
+        let no_volatility = Signal::entry("s", "AAPL".to_string(), Side::Buy);
+        let (amount, _) =
+            calculate_signal_position_size(dec!(10000), &config, &no_volatility, dec!(100), &[]);
+        assert_eq!(amount, dec!(2000));
+    }
+    #[test]
+    fn test_update_trailing_high_short_tracks_low() {
+        let mut position = ProcessorPosition {
+            symbol: "BTCUSDT".to_string(),
+            side: Side::Sell,
+            quantity: dec!(1),
+            entry_price: dec!(100),
+            entry_time: Utc::now(),
+            fees: Decimal::ZERO,
+            position_id: None,
+            group_id: None,
+            trailing_high: None,
+        };
+
+        assert!(position.update_trailing_high(dec!(90)));
+        assert!(!position.update_trailing_high(dec!(95)));
+        assert_eq!(position.trailing_high, Some(dec!(90)));
+        // 숏 스톱: 최저가 90 × 1.05 = 94.5
+        assert_eq!(position.trailing_stop_price(dec!(0.05)), dec!(94.5));
+        assert!(!position.is_trailing_stop_hit(dec!(94), dec!(0.05)));
+        assert!(position.is_trailing_stop_hit(dec!(96), dec!(0.05)));
+    }
 }
@@ -2,20 +2,41 @@
 //!
 //! 백테스트와 페이퍼 트레이딩에서 사용하는 가상 체결 실행기입니다.
 //! SignalProcessor trait을 구현하여 실거래와 동일한 인터페이스를 제공합니다.
+//!
+//! [`SimulatedExecutor::with_slippage_model`]로 백테스트와 같은 [`SlippageModel`]을
+//! 지정하면 체결가 계산 가정이 백테스트와 일치합니다.
+//!
+//! [`SimulatedExecutor::with_latency_model`]로 [`LatencyModel`]을 지정하면 주문이
+//! 즉시 체결되지 않고 샘플링된 지연 시간 뒤에 체결됩니다. 지연 동안 가격이
+//! 불리하게 움직이면 변한 가격으로 체결되며, [`SimulatedExecutor::cancel_order`]로
+//! 보낸 취소 요청은 도착 시각이 체결 시각보다 늦으면 실패합니다.
+//!
+//! [`SimulatedExecutor::with_margin`]으로 초기 증거금률을 지정하면 마진 계좌로
+//! 동작하여 잔고를 넘는 레버리지 진입이 가능해지며, 잔고는 차입 시 음수가 됩니다.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use trader_core::{Side, Signal, SignalType};
-
-use crate::signal_processor::{
-    apply_slippage, build_add_trade, build_entry_trade, build_exit_trade, calculate_position_size,
-    calculate_realized_pnl, determine_close_quantity, update_position_average, validate_funds,
-    ProcessorConfig, ProcessorPosition, SignalProcessor, SignalProcessorError, TradeResult,
+use tracing::warn;
+use trader_core::{unrealized_pnl, ConflictResolutionPolicy, Kline, Side, Signal, SignalType};
+use uuid::Uuid;
+
+use crate::{
+    latency::{adverse_price, LatencyModel, LatencyReport, LatencySampler, LatencyStats},
+    signal_processor::{
+        apply_slippage, build_add_trade, build_entry_trade, build_exit_trade,
+        calculate_realized_pnl, calculate_signal_position_size, collect_trailing_stop_exits,
+        determine_close_quantity, update_position_average, validate_funds, ProcessorConfig,
+        ProcessorPosition, SignalProcessor, SignalProcessorError, TradeResult,
+    },
+    slippage::SlippageModel,
 };
 
+/// 평균 거래량(ADV) 추정에 사용하는 최근 캔들 수
+const VOLUME_HISTORY_WINDOW: usize = 20;
+
 /// 브라켓 주문 시뮬레이션 정보.
 ///
 /// 시뮬레이션에서 SL/TP 가격 도달 시 자동 청산을 처리하기 위한 내부 추적용입니다.
@@ -29,6 +50,32 @@ pub struct BracketSimulation {
     pub side: Side,
 }
 
+/// 지연 체결 대기 중인 주문.
+#[derive(Debug, Clone)]
+pub struct PendingSimOrder {
+    /// 주문 ID
+    pub id: Uuid,
+    /// 원본 신호
+    pub signal: Signal,
+    /// 접수 시점 기준가 (`suggested_price` 또는 현재가)
+    pub submit_price: Decimal,
+    /// 접수 시각
+    pub submitted_at: DateTime<Utc>,
+    /// 체결 예정 시각 (접수 시각 + 지연)
+    pub fill_at: DateTime<Utc>,
+}
+
+/// 지연 중 취소 요청 결과.
+#[derive(Debug, Clone)]
+pub enum CancelOutcome {
+    /// 체결 전에 취소 요청이 도착하여 취소됨
+    Cancelled,
+    /// 취소 요청 도착 전에 이미 체결됨 (체결로 거래가 발생했으면 포함)
+    AlreadyFilled(Option<TradeResult>),
+    /// 대기 중인 주문이 없음 (이미 체결/취소됨)
+    NotFound,
+}
+
 /// 시뮬레이션 실행기
 ///
 /// 백테스트와 페이퍼 트레이딩에서 가상 체결을 수행합니다.
@@ -54,6 +101,24 @@ pub struct SimulatedExecutor {
     /// 브라켓 주문 추적 (position_key → (SL가격, TP가격))
     /// 시뮬레이션에서 SL/TP 트리거를 확인하기 위한 내부 추적용
     bracket_orders: HashMap<String, BracketSimulation>,
+    /// 슬리피지 모델 (None이면 config.slippage_rate 고정 비율 사용)
+    slippage_model: Option<SlippageModel>,
+    /// 심볼별 최신 캔들 (슬리피지 모델의 거래량/변동성 계산용)
+    latest_klines: HashMap<String, Kline>,
+    /// 심볼별 최근 캔들 거래량 (open_time, volume) - MarketImpact 모델의 ADV 추정용
+    volume_history: HashMap<String, VecDeque<(DateTime<Utc>, Decimal)>>,
+    /// 시장 데이터 부족으로 고정 비율 폴백 경고를 이미 남긴 심볼
+    slippage_fallback_warned: HashSet<String>,
+    /// 주문 지연 샘플러 (None이면 즉시 체결)
+    latency: Option<LatencySampler>,
+    /// 지연 체결 대기 주문 (접수 순)
+    pending_orders: Vec<PendingSimOrder>,
+    /// 심볼별 마지막 관측 가격 (취소 레이스에서 체결가로 사용)
+    last_prices: HashMap<String, Decimal>,
+    /// 지연 통계
+    latency_stats: LatencyStats,
+    /// 초기 증거금률 (None이면 현금 계좌)
+    initial_margin_rate: Option<Decimal>,
 }
 
 impl SimulatedExecutor {
@@ -69,9 +134,317 @@ impl SimulatedExecutor {
             total_slippage: Decimal::ZERO,
             total_orders: 0,
             bracket_orders: HashMap::new(),
+            slippage_model: None,
+            latest_klines: HashMap::new(),
+            volume_history: HashMap::new(),
+            slippage_fallback_warned: HashSet::new(),
+            latency: None,
+            pending_orders: Vec::new(),
+            last_prices: HashMap::new(),
+            latency_stats: LatencyStats::default(),
+            initial_margin_rate: None,
         }
     }
 
+    /// 슬리피지 모델 설정.
+    ///
+    /// 백테스트와 동일한 모델로 체결가를 계산합니다.
+    /// Linear/VolatilityBased 모델은 [`update_kline`](Self::update_kline)으로 전달된
+    /// 캔들이 필요하며, 캔들이 없거나 거래량이 0이면 `config.slippage_rate`
+    /// 고정 비율로 폴백하고 심볼별로 한 번 경고를 남깁니다.
+    /// MarketImpact 모델은 최근 캔들 거래량 평균을 ADV로 사용하며, 거래량이 없으면
+    /// Linear 모델로 폴백합니다.
+    pub fn with_slippage_model(mut self, model: SlippageModel) -> Self {
+        if let Some(message) = model.config_warning() {
+            warn!(model = model.name(), "{}", message);
+        }
+        self.slippage_model = Some(model);
+        self
+    }
+
+    /// 설정된 슬리피지 모델 조회
+    pub fn slippage_model(&self) -> Option<&SlippageModel> {
+        self.slippage_model.as_ref()
+    }
+
+    /// 주문 지연 모델 설정.
+    ///
+    /// 설정 후 `process_signal`은 주문을 대기열에 넣고 `None`을 반환하며,
+    /// 체결은 체결 예정 시각 이후의 [`on_price_update`](SignalProcessor::on_price_update)
+    /// 또는 [`process_pending_orders`](Self::process_pending_orders)에서 발생합니다.
+    /// `seed`를 지정하면 지연 시퀀스가 재현됩니다.
+    pub fn with_latency_model(mut self, model: LatencyModel, seed: Option<u64>) -> Self {
+        self.latency = Some(LatencySampler::new(model, seed));
+        self
+    }
+
+    /// 마진 계좌 설정.
+    ///
+    /// 진입 가능 금액이 `여유 증거금 / initial_margin_rate`로 늘어납니다
+    /// (예: 0.5 = 최대 2배 레버리지). 유지 증거금 검사와 강제청산은 호출자
+    /// (백테스트 엔진)가 담당합니다.
+    pub fn with_margin(mut self, initial_margin_rate: Decimal) -> Self {
+        self.initial_margin_rate = Some(initial_margin_rate).filter(|r| *r > Decimal::ZERO);
+        self
+    }
+
+    /// 초기 증거금률 (현금 계좌면 None)
+    pub fn initial_margin_rate(&self) -> Option<Decimal> {
+        self.initial_margin_rate
+    }
+
+    /// 포지션 평가 가격 (최신 캔들 종가 → 마지막 체결 가격 → 진입가 순).
+    fn mark_price(&self, position: &ProcessorPosition) -> Decimal {
+        self.latest_klines
+            .get(&position.symbol)
+            .map(|k| k.close)
+            .or_else(|| self.last_prices.get(&position.symbol).copied())
+            .unwrap_or(position.entry_price)
+    }
+
+    /// 마진 계좌 평가 자산 (잔고 + 포지션 평가액).
+    pub fn margin_equity(&self) -> Decimal {
+        self.balance
+            + self
+                .positions
+                .values()
+                .map(|p| {
+                    let price = self.mark_price(p);
+                    match p.side {
+                        Side::Buy => price * p.quantity,
+                        Side::Sell => {
+                            p.entry_price * p.quantity
+                                + unrealized_pnl(p.entry_price, price, p.quantity, p.side)
+                        }
+                    }
+                })
+                .sum::<Decimal>()
+    }
+
+    /// 주문 가능 금액.
+    ///
+    /// 현금 계좌는 잔고, 마진 계좌는 (평가 자산 - 사용 증거금) / 초기 증거금률입니다.
+    fn buying_power(&self) -> Decimal {
+        let Some(rate) = self.initial_margin_rate else {
+            return self.balance;
+        };
+        let used_margin: Decimal = self
+            .positions
+            .values()
+            .map(|p| self.mark_price(p) * p.quantity * rate)
+            .sum();
+        (self.margin_equity() - used_margin).max(Decimal::ZERO) / rate
+    }
+
+    /// 설정된 지연 모델 조회
+    pub fn latency_model(&self) -> Option<&LatencyModel> {
+        self.latency.as_ref().map(|sampler| sampler.model())
+    }
+
+    /// 지연 체결 대기 주문 목록
+    pub fn pending_orders(&self) -> &[PendingSimOrder] {
+        &self.pending_orders
+    }
+
+    /// 지연 시뮬레이션 통계 (지연 모델이 없으면 None)
+    pub fn latency_report(&self) -> Option<LatencyReport> {
+        self.latency.as_ref().map(|sampler| {
+            self.latency_stats
+                .report(sampler, self.pending_orders.len())
+        })
+    }
+
+    /// 주문 접수 (지연 후 체결 대기).
+    ///
+    /// 지연 모델이 없으면 지연 0으로 접수되어 다음 가격 갱신에서 체결됩니다.
+    pub fn submit_order(
+        &mut self,
+        signal: &Signal,
+        current_price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Uuid {
+        let latency = self
+            .latency
+            .as_mut()
+            .map(|sampler| sampler.sample())
+            .unwrap_or_else(chrono::Duration::zero);
+        let order = PendingSimOrder {
+            id: Uuid::new_v4(),
+            signal: signal.clone(),
+            submit_price: signal.suggested_price.unwrap_or(current_price),
+            submitted_at: timestamp,
+            fill_at: timestamp + latency,
+        };
+        let id = order.id;
+        self.last_prices
+            .insert(signal.ticker.clone(), current_price);
+        self.pending_orders.push(order);
+        self.latency_stats.submitted += 1;
+        id
+    }
+
+    /// 대기 주문 취소 요청.
+    ///
+    /// 취소 요청도 지연 모델에 따라 `requested_at` 이후에 도착합니다.
+    /// 도착 시각이 체결 예정 시각보다 늦으면 주문은 마지막 관측 가격으로 체결되고
+    /// 취소는 실패합니다.
+    pub fn cancel_order(
+        &mut self,
+        order_id: Uuid,
+        requested_at: DateTime<Utc>,
+    ) -> Result<CancelOutcome, SignalProcessorError> {
+        let Some(index) = self.pending_orders.iter().position(|o| o.id == order_id) else {
+            return Ok(CancelOutcome::NotFound);
+        };
+
+        let cancel_latency = self
+            .latency
+            .as_mut()
+            .map(|sampler| sampler.sample())
+            .unwrap_or_else(chrono::Duration::zero);
+        let arrives_at = requested_at + cancel_latency;
+        let order = self.pending_orders.remove(index);
+
+        if arrives_at < order.fill_at {
+            self.latency_stats.cancelled += 1;
+            return Ok(CancelOutcome::Cancelled);
+        }
+
+        self.latency_stats.cancel_rejected += 1;
+        let arrival_price = self
+            .last_prices
+            .get(&order.signal.ticker)
+            .copied()
+            .unwrap_or(order.submit_price);
+        let trade = self.fill_pending_order(order, arrival_price)?;
+        Ok(CancelOutcome::AlreadyFilled(trade))
+    }
+
+    /// 체결 예정 시각이 지난 대기 주문을 심볼별 마지막 관측 가격으로 체결.
+    pub fn process_pending_orders(
+        &mut self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<TradeResult>, SignalProcessorError> {
+        let (due, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_orders)
+            .into_iter()
+            .partition(|order| order.fill_at <= now);
+        self.pending_orders = waiting;
+
+        let mut results = Vec::new();
+        for order in due {
+            let arrival_price = self
+                .last_prices
+                .get(&order.signal.ticker)
+                .copied()
+                .unwrap_or(order.submit_price);
+            if let Some(trade) = self.fill_pending_order(order, arrival_price)? {
+                results.push(trade);
+            }
+        }
+        Ok(results)
+    }
+
+    /// 심볼의 대기 주문 중 체결 예정 시각이 지난 주문을 현재가로 체결.
+    fn fill_due_orders(
+        &mut self,
+        symbol: &str,
+        current_price: Decimal,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<TradeResult>, SignalProcessorError> {
+        let (due, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_orders)
+            .into_iter()
+            .partition(|order| order.signal.ticker == symbol && order.fill_at <= now);
+        self.pending_orders = waiting;
+
+        let mut results = Vec::new();
+        for order in due {
+            if let Some(trade) = self.fill_pending_order(order, current_price)? {
+                results.push(trade);
+            }
+        }
+        Ok(results)
+    }
+
+    /// 대기 주문 체결.
+    ///
+    /// 접수가 대비 불리한 가격 변동만 반영하며, 그 차이를 지연 슬리피지로 기록합니다.
+    fn fill_pending_order(
+        &mut self,
+        order: PendingSimOrder,
+        arrival_price: Decimal,
+    ) -> Result<Option<TradeResult>, SignalProcessorError> {
+        let fill_price = adverse_price(order.signal.side, order.submit_price, arrival_price);
+        let mut signal = order.signal;
+        signal.suggested_price = None;
+
+        let result = self.execute_signal(&signal, fill_price, order.fill_at);
+        let latency = order.fill_at - order.submitted_at;
+
+        let trade = match result {
+            Ok(Some(mut trade)) => {
+                let latency_slippage = (fill_price - order.submit_price).abs() * trade.quantity;
+                self.latency_stats.record_fill(
+                    latency,
+                    latency_slippage,
+                    order.submit_price * trade.quantity,
+                );
+                trade
+                    .metadata
+                    .insert("order_id".to_string(), order.id.to_string());
+                trade.metadata.insert(
+                    "latency_ms".to_string(),
+                    latency.num_milliseconds().to_string(),
+                );
+                trade
+                    .metadata
+                    .insert("latency_slippage".to_string(), latency_slippage.to_string());
+                if let Some(last) = self.trades.last_mut() {
+                    last.metadata.clone_from(&trade.metadata);
+                }
+                Some(trade)
+            }
+            Ok(None) => {
+                self.latency_stats
+                    .record_fill(latency, Decimal::ZERO, Decimal::ZERO);
+                None
+            }
+            Err(e) => {
+                warn!(order_id = %order.id, symbol = %signal.ticker, error = %e, "지연 주문 체결 실패");
+                return Err(e);
+            }
+        };
+        Ok(trade)
+    }
+
+    /// 심볼의 최신 캔들 갱신 (슬리피지 계산용).
+    ///
+    /// 같은 캔들(open_time)을 여러 번 전달해도 거래량 이력에는 한 번만 반영됩니다.
+    pub fn update_kline(&mut self, kline: Kline) {
+        let history = self.volume_history.entry(kline.ticker.clone()).or_default();
+        if history.back().map(|(t, _)| *t) != Some(kline.open_time) {
+            history.push_back((kline.open_time, kline.volume));
+            if history.len() > VOLUME_HISTORY_WINDOW {
+                history.pop_front();
+            }
+        }
+        self.latest_klines.insert(kline.ticker.clone(), kline);
+    }
+
+    /// 최근 캔들 평균 거래량 (거래량이 0인 캔들은 제외, 데이터가 없으면 None)
+    fn average_volume(&self, symbol: &str) -> Option<Decimal> {
+        let volumes: Vec<Decimal> = self
+            .volume_history
+            .get(symbol)?
+            .iter()
+            .map(|(_, v)| *v)
+            .filter(|v| *v > Decimal::ZERO)
+            .collect();
+        if volumes.is_empty() {
+            return None;
+        }
+        Some(volumes.iter().sum::<Decimal>() / Decimal::from(volumes.len()))
+    }
+
     /// 기본 설정으로 생성
     pub fn with_balance(initial_balance: Decimal) -> Self {
         Self::new(ProcessorConfig::default(), initial_balance)
@@ -92,6 +465,55 @@ impl SimulatedExecutor {
         self.total_orders
     }
 
+    /// 슬리피지를 적용한 체결가 계산.
+    ///
+    /// 모델이 없거나, 시장 데이터가 필요한 모델인데 해당 심볼의 캔들(거래량)이
+    /// 없으면 `config.slippage_rate` 고정 비율을 적용합니다.
+    fn execution_price(
+        &mut self,
+        symbol: &str,
+        price: Decimal,
+        side: Side,
+        order_value: Decimal,
+    ) -> Decimal {
+        let Some(model) = &self.slippage_model else {
+            return apply_slippage(price, self.config.slippage_rate, side);
+        };
+
+        let kline = self
+            .latest_klines
+            .get(symbol)
+            .filter(|k| k.volume > Decimal::ZERO);
+
+        if model.requires_market_data() && kline.is_none() {
+            if self.slippage_fallback_warned.insert(symbol.to_string()) {
+                warn!(
+                    symbol = %symbol,
+                    model = model.name(),
+                    fallback_rate = %self.config.slippage_rate,
+                    "거래량 데이터 없음, 고정 슬리피지로 폴백"
+                );
+            }
+            return apply_slippage(price, self.config.slippage_rate, side);
+        }
+
+        let avg_volume = self.average_volume(symbol);
+        if matches!(model, SlippageModel::MarketImpact { .. })
+            && avg_volume.is_none()
+            && self.slippage_fallback_warned.insert(symbol.to_string())
+        {
+            warn!(
+                symbol = %symbol,
+                model = model.name(),
+                "거래량 데이터 없음, Linear 슬리피지로 폴백"
+            );
+        }
+
+        model
+            .calculate_execution_price(price, side, order_value, kline, avg_volume)
+            .execution_price
+    }
+
     /// 모든 포지션 강제 청산 (시뮬레이션/백테스트 종료 시 사용)
     ///
     /// 시뮬레이션이나 백테스트가 종료될 때 남아있는 모든 포지션을
@@ -132,8 +554,12 @@ impl SimulatedExecutor {
             } else {
                 Side::Buy
             };
-            let execution_price =
-                apply_slippage(current_price, self.config.slippage_rate, exit_side);
+            let execution_price = self.execution_price(
+                &position.symbol,
+                current_price,
+                exit_side,
+                current_price * position.quantity,
+            );
 
             // 청산 금액 및 수수료 계산
             let close_value = execution_price * position.quantity;
@@ -196,7 +622,19 @@ impl SimulatedExecutor {
 
         // 실행 가격 계산 (슬리피지 적용)
         let price = signal.suggested_price.unwrap_or(current_price);
-        let execution_price = apply_slippage(price, self.config.slippage_rate, signal.side);
+        let order_value = if price > Decimal::ZERO {
+            calculate_signal_position_size(
+                self.buying_power(),
+                &self.config,
+                signal,
+                price,
+                &self.trades,
+            )
+            .0
+        } else {
+            Decimal::ZERO
+        };
+        let execution_price = self.execution_price(&signal.ticker, price, signal.side, order_value);
 
         // 유효하지 않은 가격 체크
         if execution_price <= Decimal::ZERO {
@@ -221,16 +659,23 @@ impl SimulatedExecutor {
         }
 
         // 포지션 크기 계산 (공통 유틸리티)
-        let (position_amount, quantity) = calculate_position_size(
-            self.balance,
-            self.config.max_position_size_pct,
-            signal.strength,
+        let buying_power = self.buying_power();
+        let (position_amount, quantity) = calculate_signal_position_size(
+            buying_power,
+            &self.config,
+            signal,
             execution_price,
+            &self.trades,
         );
 
+        // 사이징 결과가 0이면 진입하지 않음 (예: Kelly 기대값 음수)
+        if quantity <= Decimal::ZERO {
+            return Ok(None);
+        }
+
         // 자금 검증 (공통 유틸리티)
         let commission =
-            validate_funds(position_amount, self.config.commission_rate, self.balance)?;
+            validate_funds(position_amount, self.config.commission_rate, buying_power)?;
 
         // 잔고 차감
         let required = position_amount + commission;
@@ -252,6 +697,7 @@ impl SimulatedExecutor {
                 fees: commission,
                 position_id: signal.position_id.clone(),
                 group_id: signal.group_id.clone(),
+                trailing_high: None,
             },
         );
 
@@ -311,16 +757,23 @@ impl SimulatedExecutor {
         let key = signal.position_key();
 
         // 포지션 크기 계산 (공통 유틸리티)
-        let (position_amount, add_quantity) = calculate_position_size(
-            self.balance,
-            self.config.max_position_size_pct,
-            signal.strength,
+        let buying_power = self.buying_power();
+        let (position_amount, add_quantity) = calculate_signal_position_size(
+            buying_power,
+            &self.config,
+            signal,
             execution_price,
+            &self.trades,
         );
 
+        // 사이징 결과가 0이면 진입하지 않음 (예: Kelly 기대값 음수)
+        if add_quantity <= Decimal::ZERO {
+            return Ok(None);
+        }
+
         // 자금 검증 (공통 유틸리티)
         let commission =
-            validate_funds(position_amount, self.config.commission_rate, self.balance)?;
+            validate_funds(position_amount, self.config.commission_rate, buying_power)?;
 
         // 평균 단가 재계산 (공통 유틸리티)
         if let Some(existing) = self.positions.get_mut(&key) {
@@ -354,9 +807,13 @@ impl SimulatedExecutor {
             None => return Ok(None),
         };
 
+        // 청산 수량 결정 (공통 유틸리티)
+        let close_quantity = determine_close_quantity(signal, position.quantity);
+
         // 실행 가격 계산 (슬리피지 적용)
         let price = signal.suggested_price.unwrap_or(current_price);
-        let execution_price = apply_slippage(price, self.config.slippage_rate, signal.side);
+        let execution_price =
+            self.execution_price(&position.symbol, price, signal.side, price * close_quantity);
 
         if execution_price <= Decimal::ZERO {
             return Err(SignalProcessorError::InvalidPrice {
@@ -364,9 +821,6 @@ impl SimulatedExecutor {
             });
         }
 
-        // 청산 수량 결정 (공통 유틸리티)
-        let close_quantity = determine_close_quantity(signal, position.quantity);
-
         // 청산 금액 및 수수료 계산
         let close_value = execution_price * close_quantity;
         let commission = close_value * self.config.commission_rate;
@@ -409,6 +863,43 @@ impl SimulatedExecutor {
         Ok(Some(trade))
     }
 
+    /// 신호 유형에 따라 즉시 체결 처리.
+    fn execute_signal(
+        &mut self,
+        signal: &Signal,
+        current_price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Option<TradeResult>, SignalProcessorError> {
+        match signal.signal_type {
+            SignalType::Entry | SignalType::AddToPosition => {
+                // 숏 포지션 확인
+                if signal.side == Side::Sell && !self.config.allow_short {
+                    return Err(SignalProcessorError::ShortNotAllowed);
+                }
+                self.open_position_internal(signal, current_price, timestamp)
+            }
+            SignalType::Exit | SignalType::ReducePosition => {
+                self.close_position_internal(signal, current_price, timestamp)
+            }
+            SignalType::Scale => {
+                // 스케일 신호는 현재 포지션에 따라 처리
+                let key = signal.position_key();
+                if self.positions.contains_key(&key) {
+                    self.close_position_internal(signal, current_price, timestamp)
+                } else {
+                    if signal.side == Side::Sell && !self.config.allow_short {
+                        return Err(SignalProcessorError::ShortNotAllowed);
+                    }
+                    self.open_position_internal(signal, current_price, timestamp)
+                }
+            }
+            SignalType::Alert => {
+                // Alert는 실행하지 않음
+                Ok(None)
+            }
+        }
+    }
+
     /// 브라켓 주문 트리거 확인.
     ///
     /// 현재 가격을 기준으로 SL/TP 도달 여부를 확인하여
@@ -477,34 +968,43 @@ impl SignalProcessor for SimulatedExecutor {
             return Ok(None);
         }
 
-        match signal.signal_type {
-            SignalType::Entry | SignalType::AddToPosition => {
-                // 숏 포지션 확인
-                if signal.side == Side::Sell && !self.config.allow_short {
-                    return Err(SignalProcessorError::ShortNotAllowed);
-                }
-                self.open_position_internal(signal, current_price, timestamp)
-            }
-            SignalType::Exit | SignalType::ReducePosition => {
-                self.close_position_internal(signal, current_price, timestamp)
-            }
-            SignalType::Scale => {
-                // 스케일 신호는 현재 포지션에 따라 처리
-                let key = signal.position_key();
-                if self.positions.contains_key(&key) {
-                    self.close_position_internal(signal, current_price, timestamp)
-                } else {
-                    if signal.side == Side::Sell && !self.config.allow_short {
-                        return Err(SignalProcessorError::ShortNotAllowed);
-                    }
-                    self.open_position_internal(signal, current_price, timestamp)
-                }
-            }
-            SignalType::Alert => {
-                // Alert는 실행하지 않음
-                Ok(None)
+        // 지연 모델: 접수만 하고 체결은 지연 후 가격 갱신에서 처리
+        if self.latency.is_some() && signal.signal_type != SignalType::Alert {
+            self.submit_order(signal, current_price, timestamp);
+            return Ok(None);
+        }
+
+        self.last_prices
+            .insert(signal.ticker.clone(), current_price);
+        self.execute_signal(signal, current_price, timestamp)
+    }
+
+    async fn on_price_update(
+        &mut self,
+        symbol: &str,
+        current_price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Vec<TradeResult>, SignalProcessorError> {
+        self.last_prices.insert(symbol.to_string(), current_price);
+        let mut results = self.fill_due_orders(symbol, current_price, timestamp)?;
+
+        let Some(trailing_stop_pct) = self.config.trailing_stop_pct else {
+            return Ok(results);
+        };
+
+        let exits = collect_trailing_stop_exits(
+            &mut self.positions,
+            symbol,
+            current_price,
+            trailing_stop_pct,
+        );
+
+        for signal in exits {
+            if let Some(trade) = self.close_position_internal(&signal, current_price, timestamp)? {
+                results.push(trade);
             }
         }
+        Ok(results)
     }
 
     fn balance(&self) -> Decimal {
@@ -523,6 +1023,10 @@ impl SignalProcessor for SimulatedExecutor {
         self.total_commission
     }
 
+    fn conflict_policy(&self) -> Option<ConflictResolutionPolicy> {
+        self.config.conflict_policy
+    }
+
     fn reset(&mut self, initial_balance: Decimal) {
         self.balance = initial_balance;
         self.initial_balance = initial_balance;
@@ -532,6 +1036,15 @@ impl SignalProcessor for SimulatedExecutor {
         self.total_slippage = Decimal::ZERO;
         self.total_orders = 0;
         self.bracket_orders.clear();
+        self.latest_klines.clear();
+        self.volume_history.clear();
+        self.slippage_fallback_warned.clear();
+        self.pending_orders.clear();
+        self.last_prices.clear();
+        self.latency_stats = LatencyStats::default();
+        if let Some(sampler) = self.latency.as_mut() {
+            sampler.reset();
+        }
     }
 }
 
@@ -670,4 +1183,396 @@ mod tests {
         let equity = executor.total_equity(&prices);
         assert!(equity > dec!(10_000_000)); // 수익 발생
     }
+
+    #[tokio::test]
+    async fn test_margin_account_allows_leverage() {
+        let config = ProcessorConfig {
+            commission_rate: Decimal::ZERO,
+            slippage_rate: Decimal::ZERO,
+            max_position_size_pct: Decimal::ONE,
+            ..Default::default()
+        };
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry);
+
+        // 현금 계좌: 잔고만큼만 진입
+        let mut cash = SimulatedExecutor::new(config.clone(), dec!(1_000_000));
+        cash.process_signal(&signal, dec!(100), Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(cash.positions()["005930"].quantity, dec!(10_000));
+
+        // 마진 계좌 (초기 증거금률 50%): 2배까지 진입, 잔고는 차입으로 음수
+        let mut margin = SimulatedExecutor::new(config, dec!(1_000_000)).with_margin(dec!(0.5));
+        margin
+            .process_signal(&signal, dec!(100), Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(margin.positions()["005930"].quantity, dec!(20_000));
+        assert_eq!(margin.balance(), dec!(-1_000_000));
+        assert_eq!(margin.margin_equity(), dec!(1_000_000));
+    }
+    #[tokio::test]
+    async fn test_trailing_stop_gap_down_fills_at_actual_price() {
+        let config = ProcessorConfig {
+            slippage_rate: Decimal::ZERO,
+            commission_rate: Decimal::ZERO,
+            trailing_stop_pct: Some(dec!(0.1)),
+            ..Default::default()
+        };
+        let mut executor = SimulatedExecutor::new(config, dec!(10_000_000));
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap();
+
+        // 최고가 60000 → 스톱 가격 54000
+        let trades = executor
+            .on_price_update("005930", dec!(60000), Utc::now())
+            .await
+            .unwrap();
+        assert!(trades.is_empty());
+        let position = executor.get_position("005930").unwrap();
+        assert_eq!(position.trailing_high, Some(dec!(60000)));
+        assert_eq!(position.trailing_stop_price(dec!(0.1)), dec!(54000));
+
+        // 갭 하락: 스톱 가격(54000)을 건너뛰고 52000으로 체결
+        let trades = executor
+            .on_price_update("005930", dec!(52000), Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].side, Side::Sell);
+        assert_eq!(trades[0].price, dec!(52000));
+        assert_eq!(
+            trades[0].metadata.get("reason").map(String::as_str),
+            Some("trailing_stop")
+        );
+        assert!(trades[0].realized_pnl.unwrap() > Decimal::ZERO);
+        assert!(executor.positions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_trailing_stop_without_position_is_noop() {
+        let config = ProcessorConfig {
+            trailing_stop_pct: Some(dec!(0.05)),
+            ..Default::default()
+        };
+        let mut executor = SimulatedExecutor::new(config, dec!(10_000_000));
+
+        let trades = executor
+            .on_price_update("005930", dec!(40000), Utc::now())
+            .await
+            .unwrap();
+
+        assert!(trades.is_empty());
+        assert!(executor.trades().is_empty());
+        assert_eq!(executor.balance(), dec!(10_000_000));
+    }
+
+    fn create_test_kline(ticker: &str, close: Decimal, volume: Decimal) -> Kline {
+        Kline::new(
+            ticker.to_string(),
+            trader_core::Timeframe::D1,
+            Utc::now(),
+            close,
+            close * dec!(1.02),
+            close * dec!(0.98),
+            close,
+            volume,
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_slippage_model_uses_kline_volume() {
+        let config = ProcessorConfig {
+            commission_rate: Decimal::ZERO,
+            ..Default::default()
+        };
+        let mut executor = SimulatedExecutor::new(config, dec!(10_000_000))
+            .with_slippage_model(SlippageModel::linear(dec!(0.001), dec!(0.1)));
+        executor.update_kline(create_test_kline("005930", dec!(50000), dec!(1000)));
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let trade = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+
+        // 주문 금액 = 1천만 * 0.2(기본 최대 비중) * 0.5 = 100만, 거래대금 = 5천만
+        // 슬리피지율 = 0.001 + (100만 / 5천만) * 0.1 = 0.003
+        assert_eq!(trade.price, dec!(50150));
+    }
+
+    #[tokio::test]
+    async fn test_volatility_model_falls_back_to_fixed_without_volume() {
+        let config = ProcessorConfig {
+            slippage_rate: dec!(0.001),
+            ..Default::default()
+        };
+        let mut executor = SimulatedExecutor::new(config, dec!(10_000_000))
+            .with_slippage_model(SlippageModel::volatility_based(0.5));
+        executor.update_kline(create_test_kline("005930", dec!(50000), Decimal::ZERO));
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let trade = executor
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(trade.price, dec!(50050));
+        assert!(executor.slippage_fallback_warned.contains("005930"));
+    }
+
+    #[test]
+    fn test_average_volume_ignores_duplicate_klines() {
+        let mut executor = SimulatedExecutor::with_balance(dec!(10_000_000));
+        let kline = create_test_kline("005930", dec!(50000), dec!(1000));
+        executor.update_kline(kline.clone());
+        executor.update_kline(kline);
+
+        let mut next = create_test_kline("005930", dec!(50000), dec!(3000));
+        next.open_time += chrono::Duration::days(1);
+        executor.update_kline(next);
+
+        assert_eq!(executor.average_volume("005930"), Some(dec!(2000)));
+        assert_eq!(executor.average_volume("000660"), None);
+    }
+
+    #[tokio::test]
+    async fn test_market_impact_model_scales_with_order_size() {
+        let config = ProcessorConfig {
+            commission_rate: Decimal::ZERO,
+            ..Default::default()
+        };
+        let model = SlippageModel::market_impact(dec!(0.1));
+
+        let mut small = SimulatedExecutor::new(config.clone(), dec!(10_000_000))
+            .with_slippage_model(model.clone());
+        small.update_kline(create_test_kline("005930", dec!(50000), dec!(100_000)));
+        let mut large = SimulatedExecutor::new(config, dec!(10_000_000)).with_slippage_model(model);
+        large.update_kline(create_test_kline("005930", dec!(50000), dec!(100)));
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let small_trade = small
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+        let large_trade = large
+            .process_signal(&signal, dec!(50000), Utc::now())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(small_trade.price > dec!(50000));
+        assert!(large_trade.price > small_trade.price);
+        assert!(!large.slippage_fallback_warned.contains("005930"));
+    }
+
+    #[tokio::test]
+    async fn test_process_signals_netting_policy() {
+        let config = ProcessorConfig {
+            conflict_policy: Some(ConflictResolutionPolicy::Netting),
+            ..Default::default()
+        };
+        let mut executor = SimulatedExecutor::new(config, dec!(10_000_000));
+
+        let signals = vec![
+            Signal::entry("trend", "005930".to_string(), Side::Buy).with_strength(0.75),
+            Signal::entry("mean_revert", "005930".to_string(), Side::Sell).with_strength(0.25),
+        ];
+        let mut prices = HashMap::new();
+        prices.insert("005930".to_string(), dec!(50000));
+
+        let batch = executor.process_signals(signals, &prices, Utc::now()).await;
+
+        // 0.75 - 0.25 = 0.5 → 강도 0.5 매수 신호 하나와 같은 수량만 반영
+        let mut reference = SimulatedExecutor::new(ProcessorConfig::default(), dec!(10_000_000));
+        let expected = reference
+            .process_signal(
+                &create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5),
+                dec!(50000),
+                Utc::now(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(batch.trades.len(), 1);
+        assert_eq!(batch.trades[0].side, Side::Buy);
+        assert_eq!(batch.trades[0].quantity, expected.quantity);
+        assert_eq!(batch.conflicts.len(), 1);
+        assert!(batch.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_process_signals_reject_policy() {
+        let config = ProcessorConfig {
+            conflict_policy: Some(ConflictResolutionPolicy::Reject),
+            ..Default::default()
+        };
+        let mut executor = SimulatedExecutor::new(config, dec!(10_000_000));
+
+        let signals = vec![
+            Signal::entry("trend", "005930".to_string(), Side::Buy),
+            Signal::entry("mean_revert", "005930".to_string(), Side::Sell),
+            Signal::entry("trend", "000660".to_string(), Side::Buy).with_strength(0.5),
+        ];
+        let mut prices = HashMap::new();
+        prices.insert("005930".to_string(), dec!(50000));
+        prices.insert("000660".to_string(), dec!(100000));
+
+        let batch = executor.process_signals(signals, &prices, Utc::now()).await;
+
+        assert_eq!(batch.trades.len(), 1);
+        assert_eq!(batch.trades[0].symbol, "000660");
+        assert_eq!(batch.conflicts[0].rejected().count(), 2);
+        assert!(!executor.has_position("005930"));
+    }
+
+    fn latency_config() -> ProcessorConfig {
+        ProcessorConfig {
+            slippage_rate: Decimal::ZERO,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_latency_fills_at_adverse_price() {
+        let mut executor = SimulatedExecutor::new(latency_config(), dec!(10_000_000))
+            .with_latency_model(LatencyModel::Fixed { ms: 100 }, Some(1));
+        let t0 = Utc::now();
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let result = executor
+            .process_signal(&signal, dec!(50000), t0)
+            .await
+            .unwrap();
+        assert!(result.is_none());
+        assert_eq!(executor.pending_orders().len(), 1);
+
+        // 지연 시간 전에는 체결되지 않음
+        let fills = executor
+            .on_price_update(
+                "005930",
+                dec!(50500),
+                t0 + chrono::Duration::milliseconds(50),
+            )
+            .await
+            .unwrap();
+        assert!(fills.is_empty());
+
+        // 지연 후 상승한 가격으로 불리하게 체결
+        let fills = executor
+            .on_price_update(
+                "005930",
+                dec!(51000),
+                t0 + chrono::Duration::milliseconds(120),
+            )
+            .await
+            .unwrap();
+        assert_eq!(fills.len(), 1);
+        let trade = &fills[0];
+        assert_eq!(trade.price, dec!(51000));
+        assert_eq!(trade.timestamp, t0 + chrono::Duration::milliseconds(100));
+        assert_eq!(trade.metadata.get("latency_ms").unwrap(), "100");
+        assert!(executor.pending_orders().is_empty());
+
+        let report = executor.latency_report().unwrap();
+        assert_eq!(report.submitted, 1);
+        assert_eq!(report.filled, 1);
+        assert_eq!(report.avg_latency_ms, 100.0);
+        assert_eq!(report.total_latency_slippage, dec!(1000) * trade.quantity);
+        assert_eq!(report.seed, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_latency_ignores_favorable_move() {
+        let mut executor = SimulatedExecutor::new(latency_config(), dec!(10_000_000))
+            .with_latency_model(LatencyModel::Fixed { ms: 100 }, None);
+        let t0 = Utc::now();
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        executor
+            .process_signal(&signal, dec!(50000), t0)
+            .await
+            .unwrap();
+        let fills = executor
+            .on_price_update(
+                "005930",
+                dec!(49000),
+                t0 + chrono::Duration::milliseconds(200),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(fills[0].price, dec!(50000));
+        assert_eq!(
+            executor.latency_report().unwrap().total_latency_slippage,
+            Decimal::ZERO
+        );
+    }
+
+    /// 주문 10건을 접수와 동시에 취소 요청하고 (취소 성공, 취소 실패) 수를 반환
+    async fn run_cancel_race(seed: u64) -> (usize, usize) {
+        let mut executor = SimulatedExecutor::new(latency_config(), dec!(10_000_000))
+            .with_latency_model(
+                LatencyModel::Normal {
+                    mean: 100.0,
+                    std: 40.0,
+                },
+                Some(seed),
+            );
+        let t0 = Utc::now();
+
+        for i in 0..10 {
+            let signal = create_test_signal(&format!("SYM{}", i), Side::Buy, SignalType::Entry)
+                .with_strength(0.5);
+            let order_id = executor.submit_order(&signal, dec!(10000), t0);
+            let outcome = executor.cancel_order(order_id, t0).unwrap();
+            match outcome {
+                CancelOutcome::Cancelled => assert!(!executor.has_position(&format!("SYM{}", i))),
+                CancelOutcome::AlreadyFilled(trade) => assert!(trade.is_some()),
+                CancelOutcome::NotFound => panic!("대기 주문이 있어야 함"),
+            }
+        }
+
+        let report = executor.latency_report().unwrap();
+        assert_eq!(report.cancelled + report.cancel_rejected, 10);
+        assert_eq!(report.pending, 0);
+        (report.cancelled, report.cancel_rejected)
+    }
+
+    #[tokio::test]
+    async fn test_cancel_race_is_reproducible() {
+        let first = run_cancel_race(7).await;
+        let second = run_cancel_race(7).await;
+        assert_eq!(first, second);
+        // 지연 분포가 겹치므로 취소 성공과 실패가 모두 발생
+        assert!(first.0 > 0 && first.1 > 0, "{:?}", first);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_after_fill_returns_not_found() {
+        let mut executor = SimulatedExecutor::new(latency_config(), dec!(10_000_000))
+            .with_latency_model(LatencyModel::Fixed { ms: 10 }, None);
+        let t0 = Utc::now();
+
+        let signal = create_test_signal("005930", Side::Buy, SignalType::Entry).with_strength(0.5);
+        let order_id = executor.submit_order(&signal, dec!(50000), t0);
+        let fills = executor
+            .process_pending_orders(t0 + chrono::Duration::milliseconds(10))
+            .unwrap();
+        assert_eq!(fills.len(), 1);
+
+        assert!(matches!(
+            executor.cancel_order(order_id, t0).unwrap(),
+            CancelOutcome::NotFound
+        ));
+    }
 }
@@ -0,0 +1,230 @@
+//! 포지션 사이징 방식.
+//!
+//! [`calculate_signal_position_size`](crate::calculate_signal_position_size)가 사용하는
+//! 잔고 대비 투자 비율을 결정합니다.
+//!
+//! # 지원 방식
+//!
+//! - **FixedFraction**: `max_position_size_pct` 고정 비율 (기본값)
+//! - **VolatilityTarget**: `목표 변동성 / 심볼 변동성` 비율 (변동성이 클수록 작게)
+//! - **FractionalKelly**: 전략의 과거 승률·손익비로 계산한 Kelly 비율에 배수 적용
+//!
+//! 모든 방식의 결과는 `max_position_size_pct`를 넘지 않습니다.
+//! 필요한 데이터(변동성, 거래 기록)가 없으면 FixedFraction으로 폴백하고 경고 로그를 남깁니다.
+
+use rust_decimal::{prelude::*, Decimal};
+use serde::{Deserialize, Serialize};
+
+use crate::signal_processor::TradeResult;
+
+/// 포지션 사이징 방식.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PositionSizingMethod {
+    /// 고정 비율 (`max_position_size_pct`)
+    #[default]
+    FixedFraction,
+    /// 변동성 타깃
+    VolatilityTarget {
+        /// 목표 변동성 (심볼 변동성과 같은 단위, 예: 일간 0.01 = 1%)
+        target_volatility: f64,
+    },
+    /// 분할 Kelly
+    FractionalKelly {
+        /// Kelly 배수 (기본 0.5 = Half Kelly)
+        #[serde(default = "default_kelly_fraction")]
+        kelly_fraction: f64,
+        /// Kelly 계산에 필요한 최소 청산 거래 수 (기본 20)
+        #[serde(default = "default_min_trades")]
+        min_trades: usize,
+    },
+}
+
+fn default_kelly_fraction() -> f64 {
+    0.5
+}
+
+fn default_min_trades() -> usize {
+    20
+}
+
+/// 전략의 과거 청산 거래 통계 (Kelly 계산용).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeStats {
+    /// 손익이 0이 아닌 청산 거래 수
+    pub trades: usize,
+    /// 승률 (0.0 ~ 1.0)
+    pub win_rate: f64,
+    /// 손익비 (평균 이익 / 평균 손실)
+    pub payoff_ratio: f64,
+}
+
+impl TradeStats {
+    /// 거래 기록의 실현 손익으로 통계를 계산합니다.
+    ///
+    /// 이익 또는 손실 거래가 하나도 없으면 손익비를 정의할 수 없으므로 `None`을 반환합니다.
+    pub fn from_trades(trades: &[TradeResult]) -> Option<Self> {
+        let mut wins = 0usize;
+        let mut losses = 0usize;
+        let mut gross_profit = Decimal::ZERO;
+        let mut gross_loss = Decimal::ZERO;
+
+        for pnl in trades.iter().filter_map(|t| t.realized_pnl) {
+            if pnl > Decimal::ZERO {
+                wins += 1;
+                gross_profit += pnl;
+            } else if pnl < Decimal::ZERO {
+                losses += 1;
+                gross_loss -= pnl;
+            }
+        }
+
+        if wins == 0 || losses == 0 {
+            return None;
+        }
+
+        let avg_win = gross_profit / Decimal::from(wins);
+        let avg_loss = gross_loss / Decimal::from(losses);
+        let total = wins + losses;
+        Some(Self {
+            trades: total,
+            win_rate: wins as f64 / total as f64,
+            payoff_ratio: (avg_win / avg_loss).to_f64()?,
+        })
+    }
+
+    /// 전체 Kelly 비율 `W - (1 - W) / R` (음수면 0).
+    pub fn kelly(&self) -> f64 {
+        if self.payoff_ratio <= 0.0 {
+            return 0.0;
+        }
+        (self.win_rate - (1.0 - self.win_rate) / self.payoff_ratio).max(0.0)
+    }
+}
+
+/// 사이징에 필요한 시장/전략 데이터.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SizingInputs {
+    /// 심볼의 최근 변동성 (예: ATR / 종가)
+    pub volatility: Option<f64>,
+    /// 전략의 과거 거래 통계
+    pub trade_stats: Option<TradeStats>,
+}
+
+impl PositionSizingMethod {
+    /// 잔고 대비 투자 비율을 계산합니다.
+    ///
+    /// 결과는 `0 ~ max_position_size_pct` 범위이며, 데이터가 부족하면
+    /// `max_position_size_pct`(FixedFraction)를 반환합니다.
+    pub fn position_fraction(
+        &self,
+        max_position_size_pct: Decimal,
+        inputs: &SizingInputs,
+    ) -> Decimal {
+        let fraction = match self {
+            PositionSizingMethod::FixedFraction => return max_position_size_pct,
+            PositionSizingMethod::VolatilityTarget { target_volatility } => {
+                let Some(volatility) = inputs.volatility.filter(|v| *v > 0.0) else {
+                    tracing::warn!(
+                        "변동성 데이터 없음, 고정 비율 사이징으로 폴백 ({})",
+                        max_position_size_pct
+                    );
+                    return max_position_size_pct;
+                };
+                target_volatility / volatility
+            }
+            PositionSizingMethod::FractionalKelly {
+                kelly_fraction,
+                min_trades,
+            } => {
+                let Some(stats) = inputs.trade_stats.filter(|s| s.trades >= *min_trades) else {
+                    tracing::warn!(
+                        trades = inputs.trade_stats.map(|s| s.trades).unwrap_or(0),
+                        min_trades = *min_trades,
+                        "거래 기록 부족, 고정 비율 사이징으로 폴백 ({})",
+                        max_position_size_pct
+                    );
+                    return max_position_size_pct;
+                };
+                stats.kelly() * kelly_fraction
+            }
+        };
+
+        Decimal::from_f64(fraction)
+            .unwrap_or(Decimal::ZERO)
+            .clamp(Decimal::ZERO, max_position_size_pct)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_volatility_target_scales_inversely() {
+        let method = PositionSizingMethod::VolatilityTarget {
+            target_volatility: 0.005,
+        };
+        let inputs = SizingInputs {
+            volatility: Some(0.05),
+            ..Default::default()
+        };
+        assert_eq!(method.position_fraction(dec!(0.2), &inputs), dec!(0.1));
+
+        // 저변동성 심볼도 최대 비율을 넘지 않음
+        let calm = SizingInputs {
+            volatility: Some(0.01),
+            ..Default::default()
+        };
+        assert_eq!(method.position_fraction(dec!(0.2), &calm), dec!(0.2));
+    }
+
+    #[test]
+    fn test_volatility_target_falls_back_without_data() {
+        let method = PositionSizingMethod::VolatilityTarget {
+            target_volatility: 0.005,
+        };
+        let fraction = method.position_fraction(dec!(0.2), &SizingInputs::default());
+        assert_eq!(fraction, dec!(0.2));
+    }
+
+    #[test]
+    fn test_fractional_kelly_capped() {
+        let method = PositionSizingMethod::FractionalKelly {
+            kelly_fraction: 0.5,
+            min_trades: 20,
+        };
+        // W=0.6, R=1.5 → Kelly = 0.6 - 0.4/1.5 = 0.3333, Half Kelly = 0.1667
+        let stats = TradeStats {
+            trades: 40,
+            win_rate: 0.6,
+            payoff_ratio: 1.5,
+        };
+        let inputs = SizingInputs {
+            trade_stats: Some(stats),
+            ..Default::default()
+        };
+        let fraction = method.position_fraction(dec!(0.5), &inputs);
+        assert!((fraction.to_f64().unwrap() - 1.0 / 6.0).abs() < 1e-9);
+        assert_eq!(method.position_fraction(dec!(0.1), &inputs), dec!(0.1));
+
+        // 기대값이 음수인 전략은 진입하지 않음
+        let losing = SizingInputs {
+            trade_stats: Some(TradeStats {
+                win_rate: 0.3,
+                ..stats
+            }),
+            ..Default::default()
+        };
+        assert_eq!(method.position_fraction(dec!(0.5), &losing), Decimal::ZERO);
+
+        // 거래 수 부족 시 고정 비율
+        let few = SizingInputs {
+            trade_stats: Some(TradeStats { trades: 5, ..stats }),
+            ..Default::default()
+        };
+        assert_eq!(method.position_fraction(dec!(0.5), &few), dec!(0.5));
+    }
+}
@@ -0,0 +1,454 @@
+//! 주문 슬라이싱 (TWAP/VWAP 분할 집행).
+//!
+//! 대량 주문을 여러 자식 주문으로 나눠 시간에 걸쳐 집행합니다.
+//!
+//! - [`SlicingAlgorithm::Twap`]: 집행 기간을 균등 분할하여 같은 수량씩 집행
+//! - [`SlicingAlgorithm::Vwap`]: 구간별 예상 거래량 비중에 따라 수량 배분
+//!
+//! [`SlicedOrder`]는 부모 주문 단위로 자식 주문의 체결을 모아
+//! 평균 체결가와 완료율을 추적합니다. 실제 자식 주문 생성/제출은
+//! [`OrderExecutor`](crate::OrderExecutor)가 담당합니다.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::OrderRequest;
+use uuid::Uuid;
+
+use crate::executor::ExecutionError;
+
+/// 분할 집행 알고리즘.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlicingAlgorithm {
+    /// 시간 가중 평균 가격 (균등 분할)
+    Twap,
+    /// 거래량 가중 평균 가격 (거래량 비중 분할)
+    Vwap,
+}
+
+/// 슬라이스 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SliceStatus {
+    /// 집행 예정
+    Pending,
+    /// 자식 주문 생성됨 (거래소 제출 대상)
+    Released,
+    /// 전량 체결
+    Filled,
+    /// 취소됨
+    Cancelled,
+}
+
+/// 분할 집행 슬라이스.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChildSlice {
+    /// 슬라이스 순번 (0부터)
+    pub index: usize,
+    /// 슬라이스 수량
+    pub quantity: Decimal,
+    /// 집행 예정 시각
+    pub scheduled_at: DateTime<Utc>,
+    /// 자식 주문 ID (집행된 경우)
+    pub order_id: Option<Uuid>,
+    /// 누적 체결 수량
+    pub filled_quantity: Decimal,
+    /// 상태
+    pub status: SliceStatus,
+}
+
+/// 분할 집행 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlicedOrderState {
+    /// 집행 중
+    Running,
+    /// 전량 체결 완료
+    Completed,
+    /// 취소됨 (체결분 유지)
+    Cancelled,
+}
+
+/// 분할 집행 진행 이벤트.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SlicingEvent {
+    /// 자식 주문 생성 (호출자가 거래소에 제출해야 함)
+    SliceReleased {
+        parent_id: Uuid,
+        slice_index: usize,
+        order_id: Uuid,
+        quantity: Decimal,
+    },
+    /// 자식 주문 체결로 진행률 갱신
+    Progress {
+        parent_id: Uuid,
+        filled_quantity: Decimal,
+        average_price: Option<Decimal>,
+        completion_pct: Decimal,
+    },
+    /// 전량 체결 완료
+    Completed {
+        parent_id: Uuid,
+        filled_quantity: Decimal,
+        average_price: Option<Decimal>,
+    },
+    /// 집행 취소 (체결분 유지)
+    Cancelled {
+        parent_id: Uuid,
+        filled_quantity: Decimal,
+        cancelled_slices: usize,
+    },
+}
+
+/// 분할 집행 중인 부모 주문.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlicedOrder {
+    /// 부모 주문 ID
+    pub parent_id: Uuid,
+    /// 알고리즘
+    pub algorithm: SlicingAlgorithm,
+    /// 원 주문 요청
+    pub request: OrderRequest,
+    /// 슬라이스 목록
+    pub slices: Vec<ChildSlice>,
+    /// 누적 체결 수량
+    pub filled_quantity: Decimal,
+    /// 누적 체결 금액 (평균 체결가 계산용)
+    pub filled_notional: Decimal,
+    /// 상태
+    pub state: SlicedOrderState,
+    /// 생성 시각
+    pub created_at: DateTime<Utc>,
+}
+
+impl SlicedOrder {
+    /// TWAP 분할 집행 생성.
+    ///
+    /// `duration`을 `slices`개 구간으로 나눠 구간 시작마다 같은 수량을 집행합니다.
+    /// `quantity_step`이 있으면 슬라이스 수량을 단위로 내림하고 나머지는 마지막 슬라이스에 더합니다.
+    pub fn twap(
+        request: OrderRequest,
+        duration: Duration,
+        slices: usize,
+        quantity_step: Option<Decimal>,
+        start: DateTime<Utc>,
+    ) -> Result<Self, ExecutionError> {
+        if slices == 0 {
+            return Err(ExecutionError::InvalidSignal(
+                "TWAP requires at least one slice".to_string(),
+            ));
+        }
+        let weights = vec![Decimal::ONE; slices];
+        Self::build(
+            SlicingAlgorithm::Twap,
+            request,
+            duration,
+            &weights,
+            quantity_step,
+            start,
+        )
+    }
+
+    /// VWAP 분할 집행 생성.
+    ///
+    /// `volume_profile`은 집행 기간을 균등 분할한 구간별 예상 거래량(또는 비중)이며,
+    /// 각 슬라이스 수량은 구간 거래량 비중에 비례합니다.
+    pub fn vwap(
+        request: OrderRequest,
+        duration: Duration,
+        volume_profile: &[Decimal],
+        quantity_step: Option<Decimal>,
+        start: DateTime<Utc>,
+    ) -> Result<Self, ExecutionError> {
+        if volume_profile.iter().any(|v| *v < Decimal::ZERO)
+            || volume_profile.iter().sum::<Decimal>() <= Decimal::ZERO
+        {
+            return Err(ExecutionError::InvalidSignal(
+                "VWAP volume profile must be non-negative with a positive total".to_string(),
+            ));
+        }
+        Self::build(
+            SlicingAlgorithm::Vwap,
+            request,
+            duration,
+            volume_profile,
+            quantity_step,
+            start,
+        )
+    }
+
+    fn build(
+        algorithm: SlicingAlgorithm,
+        request: OrderRequest,
+        duration: Duration,
+        weights: &[Decimal],
+        quantity_step: Option<Decimal>,
+        start: DateTime<Utc>,
+    ) -> Result<Self, ExecutionError> {
+        if request.quantity <= Decimal::ZERO {
+            return Err(ExecutionError::InvalidSignal(
+                "Order quantity must be positive".to_string(),
+            ));
+        }
+
+        let interval = chrono::Duration::from_std(duration / weights.len() as u32)
+            .map_err(|e| ExecutionError::InvalidSignal(format!("Invalid duration: {}", e)))?;
+        let quantities = allocate(request.quantity, weights, quantity_step);
+
+        let slices = quantities
+            .into_iter()
+            .enumerate()
+            .filter(|(_, quantity)| *quantity > Decimal::ZERO)
+            .map(|(i, quantity)| ChildSlice {
+                index: i,
+                quantity,
+                scheduled_at: start + interval * i as i32,
+                order_id: None,
+                filled_quantity: Decimal::ZERO,
+                status: SliceStatus::Pending,
+            })
+            .collect();
+
+        Ok(Self {
+            parent_id: Uuid::new_v4(),
+            algorithm,
+            request,
+            slices,
+            filled_quantity: Decimal::ZERO,
+            filled_notional: Decimal::ZERO,
+            state: SlicedOrderState::Running,
+            created_at: start,
+        })
+    }
+
+    /// 총 주문 수량.
+    pub fn total_quantity(&self) -> Decimal {
+        self.request.quantity
+    }
+
+    /// 평균 체결가 (체결이 없으면 None).
+    pub fn average_price(&self) -> Option<Decimal> {
+        (self.filled_quantity > Decimal::ZERO).then(|| self.filled_notional / self.filled_quantity)
+    }
+
+    /// 완료율 (%).
+    pub fn completion_pct(&self) -> Decimal {
+        self.filled_quantity / self.total_quantity() * Decimal::ONE_HUNDRED
+    }
+
+    /// 집행 중인지 확인.
+    pub fn is_running(&self) -> bool {
+        self.state == SlicedOrderState::Running
+    }
+
+    /// 예정 시각이 지난 미집행 슬라이스 순번.
+    pub fn due_slices(&self, now: DateTime<Utc>) -> Vec<usize> {
+        if !self.is_running() {
+            return Vec::new();
+        }
+        self.slices
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.status == SliceStatus::Pending && s.scheduled_at <= now)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// 자식 주문 ID로 슬라이스 조회.
+    pub fn slice_for_order(&self, order_id: Uuid) -> Option<&ChildSlice> {
+        self.slices.iter().find(|s| s.order_id == Some(order_id))
+    }
+
+    /// 자식 주문 체결 반영.
+    ///
+    /// 해당 자식 주문이 아니면 `false`를 반환합니다.
+    pub fn record_fill(&mut self, order_id: Uuid, quantity: Decimal, price: Decimal) -> bool {
+        let Some(slice) = self
+            .slices
+            .iter_mut()
+            .find(|s| s.order_id == Some(order_id))
+        else {
+            return false;
+        };
+
+        slice.filled_quantity += quantity;
+        if slice.filled_quantity >= slice.quantity {
+            slice.status = SliceStatus::Filled;
+        }
+        self.filled_quantity += quantity;
+        self.filled_notional += quantity * price;
+
+        if self.filled_quantity >= self.total_quantity() {
+            self.state = SlicedOrderState::Completed;
+        }
+        true
+    }
+
+    /// 남은 슬라이스 취소.
+    ///
+    /// 체결분은 유지하고, 미집행 슬라이스와 미체결 자식 주문을 취소 상태로 바꿉니다.
+    /// 거래소에서 취소해야 하는 자식 주문 ID를 반환합니다.
+    pub fn cancel_remaining(&mut self) -> Vec<Uuid> {
+        let mut open_children = Vec::new();
+        for slice in &mut self.slices {
+            match slice.status {
+                SliceStatus::Pending => slice.status = SliceStatus::Cancelled,
+                SliceStatus::Released => {
+                    slice.status = SliceStatus::Cancelled;
+                    open_children.extend(slice.order_id);
+                }
+                SliceStatus::Filled | SliceStatus::Cancelled => {}
+            }
+        }
+        self.state = SlicedOrderState::Cancelled;
+        open_children
+    }
+
+    /// 진행률 이벤트 생성.
+    pub fn progress_event(&self) -> SlicingEvent {
+        match self.state {
+            SlicedOrderState::Completed => SlicingEvent::Completed {
+                parent_id: self.parent_id,
+                filled_quantity: self.filled_quantity,
+                average_price: self.average_price(),
+            },
+            _ => SlicingEvent::Progress {
+                parent_id: self.parent_id,
+                filled_quantity: self.filled_quantity,
+                average_price: self.average_price(),
+                completion_pct: self.completion_pct(),
+            },
+        }
+    }
+}
+
+/// 비중에 따라 수량 배분.
+///
+/// 단위가 있으면 각 슬라이스를 단위로 내림하고, 나머지는 마지막 양수 비중 슬라이스에 더합니다.
+fn allocate(total: Decimal, weights: &[Decimal], quantity_step: Option<Decimal>) -> Vec<Decimal> {
+    let weight_sum: Decimal = weights.iter().sum();
+    let step = quantity_step.filter(|s| *s > Decimal::ZERO);
+
+    let mut quantities: Vec<Decimal> = weights
+        .iter()
+        .map(|w| {
+            let raw = total * *w / weight_sum;
+            match step {
+                Some(step) => (raw / step).floor() * step,
+                None => raw,
+            }
+        })
+        .collect();
+
+    let allocated: Decimal = quantities.iter().sum();
+    if let Some(last) = weights.iter().rposition(|w| *w > Decimal::ZERO) {
+        quantities[last] += total - allocated;
+    }
+    quantities
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use trader_core::{OrderSession, OrderType, Side, TimeInForce};
+
+    use super::*;
+
+    fn request(quantity: Decimal) -> OrderRequest {
+        OrderRequest {
+            ticker: "005930".to_string(),
+            side: Side::Buy,
+            order_type: OrderType::Market,
+            quantity,
+            price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::GTC,
+            client_order_id: None,
+            strategy_id: None,
+            reduce_only: false,
+            session: OrderSession::Regular,
+        }
+    }
+
+    #[test]
+    fn test_twap_schedule() {
+        let start = Utc::now();
+        let order = SlicedOrder::twap(
+            request(dec!(100)),
+            Duration::from_secs(600),
+            3,
+            Some(dec!(1)),
+            start,
+        )
+        .unwrap();
+
+        let quantities: Vec<Decimal> = order.slices.iter().map(|s| s.quantity).collect();
+        assert_eq!(quantities, vec![dec!(33), dec!(33), dec!(34)]);
+        assert_eq!(
+            order.slices[2].scheduled_at,
+            start + chrono::Duration::seconds(400)
+        );
+        assert_eq!(order.due_slices(start), vec![0]);
+    }
+
+    #[test]
+    fn test_vwap_allocation() {
+        let order = SlicedOrder::vwap(
+            request(dec!(100)),
+            Duration::from_secs(300),
+            &[dec!(500), dec!(0), dec!(1500)],
+            None,
+            Utc::now(),
+        )
+        .unwrap();
+
+        // 거래량 0 구간은 건너뜀
+        let quantities: Vec<(usize, Decimal)> =
+            order.slices.iter().map(|s| (s.index, s.quantity)).collect();
+        assert_eq!(quantities, vec![(0, dec!(25)), (2, dec!(75))]);
+
+        assert!(SlicedOrder::vwap(
+            request(dec!(100)),
+            Duration::from_secs(300),
+            &[Decimal::ZERO],
+            None,
+            Utc::now(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_fill_tracking_and_cancel() {
+        let mut order = SlicedOrder::twap(
+            request(dec!(10)),
+            Duration::from_secs(60),
+            2,
+            None,
+            Utc::now(),
+        )
+        .unwrap();
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        order.slices[0].order_id = Some(first);
+        order.slices[0].status = SliceStatus::Released;
+        order.slices[1].order_id = Some(second);
+        order.slices[1].status = SliceStatus::Released;
+
+        assert!(order.record_fill(first, dec!(5), dec!(100)));
+        assert!(order.record_fill(second, dec!(2), dec!(110)));
+        assert!(!order.record_fill(Uuid::new_v4(), dec!(1), dec!(100)));
+
+        assert_eq!(order.filled_quantity, dec!(7));
+        assert_eq!(order.average_price(), Some(dec!(720) / dec!(7)));
+        assert_eq!(order.completion_pct(), dec!(70));
+
+        // 체결 완료된 첫 슬라이스는 유지, 부분 체결된 두 번째 자식 주문만 취소
+        assert_eq!(order.cancel_remaining(), vec![second]);
+        assert_eq!(order.slices[0].status, SliceStatus::Filled);
+        assert_eq!(order.state, SlicedOrderState::Cancelled);
+        assert_eq!(order.filled_quantity, dec!(7));
+    }
+}
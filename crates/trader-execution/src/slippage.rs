@@ -0,0 +1,539 @@
+//! 동적 슬리피지 모델.
+//!
+//! 다양한 시장 조건에 따라 슬리피지를 계산하는 모델을 제공합니다.
+//!
+//! # 지원 모델
+//!
+//! - **Fixed**: 고정 비율 슬리피지 (기본 0.05%)
+//! - **Linear**: 기본 슬리피지 + 거래량 기반 시장 충격
+//! - **VolatilityBased**: 변동성에 비례하는 슬리피지
+//! - **Tiered**: 거래 금액 구간별 차등 슬리피지
+//! - **MarketImpact**: 주문 수량 / 평균 거래량(ADV) 기반 제곱근 시장 충격 + 스프레드
+//!
+//! # 거래소 중립 설계
+//!
+//! 모든 모델은 거래소에 독립적으로 동작합니다.
+//! Kline 데이터만으로 슬리피지를 계산할 수 있습니다.
+//!
+//! 백테스트 엔진과 [`SimulatedExecutor`](crate::SimulatedExecutor)(페이퍼 트레이딩)가
+//! 같은 모델을 공유하여 동일한 슬리피지 가정으로 체결가를 계산합니다.
+
+use rust_decimal::{prelude::*, Decimal};
+use serde::{Deserialize, Serialize};
+use trader_core::{Kline, Side};
+
+/// 슬리피지 모델.
+///
+/// 시장 상황에 따라 적절한 슬리피지를 계산합니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SlippageModel {
+    /// 고정 비율 슬리피지.
+    ///
+    /// 가장 단순한 모델로, 항상 동일한 비율을 적용합니다.
+    Fixed {
+        /// 슬리피지 비율 (예: 0.0005 = 0.05%)
+        #[serde(default = "default_fixed_rate")]
+        rate: Decimal,
+    },
+
+    /// 선형 시장 충격 모델.
+    ///
+    /// 기본 슬리피지에 거래 금액 대비 일일 거래량의 영향을 추가합니다.
+    /// slippage = base + (order_value / daily_volume) * impact
+    Linear {
+        /// 기본 슬리피지 비율
+        #[serde(default = "default_linear_base")]
+        base: Decimal,
+        /// 시장 충격 계수 (주문 크기 대비)
+        #[serde(default = "default_linear_impact")]
+        impact: Decimal,
+    },
+
+    /// 변동성 기반 슬리피지.
+    ///
+    /// ATR(Average True Range) 또는 캔들 범위를 기반으로 슬리피지를 계산합니다.
+    /// slippage = (high - low) / close * multiplier
+    VolatilityBased {
+        /// 변동성 승수 (기본: 0.5 = 변동성의 50%)
+        #[serde(default = "default_volatility_multiplier")]
+        multiplier: f64,
+        /// 최소 슬리피지 비율
+        #[serde(default = "default_min_slippage")]
+        min_rate: Decimal,
+        /// 최대 슬리피지 비율
+        #[serde(default = "default_max_slippage")]
+        max_rate: Decimal,
+    },
+
+    /// 구간별 차등 슬리피지.
+    ///
+    /// 거래 금액 구간에 따라 다른 슬리피지 비율을 적용합니다.
+    /// 대형 주문일수록 높은 슬리피지를 적용합니다.
+    Tiered {
+        /// 구간별 슬리피지 설정 [(금액 임계값, 비율)]
+        /// 예: [(100000, 0.0003), (1000000, 0.0005), (MAX, 0.001)]
+        tiers: Vec<SlippageTier>,
+    },
+
+    /// 주문 규모 대비 시장 충격 모델.
+    ///
+    /// 슬리피지를 스프레드 성분과 충격 성분으로 나누어 계산합니다.
+    ///
+    /// ```text
+    /// 주문 비중 = 주문 수량 / ADV
+    /// 참여율    = min(주문 비중, participation_rate)
+    /// 충격      = impact_coefficient × σ × (√참여율 + 주문 비중 / 2)
+    /// 슬리피지  = spread_rate + 충격
+    /// ```
+    ///
+    /// - σ는 캔들 범위 `(high - low) / close`로 추정합니다.
+    /// - √참여율 항은 체결 속도에 따른 일시적 충격, `주문 비중 / 2` 항은 주문 규모에 따른
+    ///   영구적 충격입니다. 주문이 ADV 대비 클수록 체결가가 불리해집니다.
+    /// - ADV(평균 거래량)가 없으면 `Linear { base: spread_rate }`로 폴백합니다.
+    MarketImpact {
+        /// 최대 시장 참여율 (예: 0.1 = 캔들 거래량의 10%까지 체결)
+        #[serde(default = "default_participation_rate")]
+        participation_rate: Decimal,
+        /// 스프레드 성분 (호가 스프레드의 절반, 예: 0.0005 = 0.05%)
+        #[serde(default = "default_spread_rate")]
+        spread_rate: Decimal,
+        /// 충격 계수
+        #[serde(default = "default_impact_coefficient")]
+        impact_coefficient: Decimal,
+    },
+}
+
+/// 현실적인 시장 참여율 상한 (이를 넘으면 설정 경고)
+///
+/// 실무에서 VWAP/POV 알고리즘은 보통 거래량의 5~20% 수준으로 참여합니다.
+pub const MAX_REALISTIC_PARTICIPATION_RATE: Decimal = Decimal::from_parts(3, 0, 0, false, 1);
+
+/// 구간별 슬리피지 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlippageTier {
+    /// 금액 임계값 (이 금액 이하에 적용)
+    pub threshold: Decimal,
+    /// 해당 구간의 슬리피지 비율
+    pub rate: Decimal,
+}
+
+// 기본값 함수들
+fn default_fixed_rate() -> Decimal {
+    Decimal::new(5, 4)
+} // 0.05%
+fn default_linear_base() -> Decimal {
+    Decimal::new(3, 4)
+} // 0.03%
+fn default_linear_impact() -> Decimal {
+    Decimal::new(1, 1)
+} // 10% 충격 계수
+fn default_volatility_multiplier() -> f64 {
+    0.5
+}
+fn default_min_slippage() -> Decimal {
+    Decimal::new(1, 4)
+} // 0.01%
+fn default_max_slippage() -> Decimal {
+    Decimal::new(1, 2)
+} // 1%
+fn default_participation_rate() -> Decimal {
+    Decimal::new(1, 1)
+} // 10%
+fn default_spread_rate() -> Decimal {
+    Decimal::new(5, 4)
+} // 0.05%
+fn default_impact_coefficient() -> Decimal {
+    Decimal::ONE
+}
+
+impl Default for SlippageModel {
+    fn default() -> Self {
+        Self::Fixed {
+            rate: default_fixed_rate(),
+        }
+    }
+}
+
+impl SlippageModel {
+    /// 고정 슬리피지 모델 생성.
+    pub fn fixed(rate: Decimal) -> Self {
+        Self::Fixed { rate }
+    }
+
+    /// 선형 시장 충격 모델 생성.
+    pub fn linear(base: Decimal, impact: Decimal) -> Self {
+        Self::Linear { base, impact }
+    }
+
+    /// 변동성 기반 모델 생성.
+    pub fn volatility_based(multiplier: f64) -> Self {
+        Self::VolatilityBased {
+            multiplier,
+            min_rate: default_min_slippage(),
+            max_rate: default_max_slippage(),
+        }
+    }
+
+    /// 구간별 모델 생성.
+    pub fn tiered(tiers: Vec<(Decimal, Decimal)>) -> Self {
+        Self::Tiered {
+            tiers: tiers
+                .into_iter()
+                .map(|(threshold, rate)| SlippageTier { threshold, rate })
+                .collect(),
+        }
+    }
+
+    /// 시장 충격 모델 생성 (스프레드/충격 계수는 기본값).
+    pub fn market_impact(participation_rate: Decimal) -> Self {
+        Self::MarketImpact {
+            participation_rate,
+            spread_rate: default_spread_rate(),
+            impact_coefficient: default_impact_coefficient(),
+        }
+    }
+
+    /// 슬리피지 계산.
+    ///
+    /// # Arguments
+    /// * `price` - 기준 가격
+    /// * `side` - 주문 방향 (Buy/Sell)
+    /// * `order_value` - 주문 금액
+    /// * `kline` - 현재 캔들 데이터 (변동성 계산용)
+    /// * `avg_volume` - 최근 캔들 평균 거래량 (MarketImpact의 ADV, None이면 현재 캔들 거래량)
+    ///
+    /// # Returns
+    /// 슬리피지가 적용된 실행 가격
+    pub fn calculate_execution_price(
+        &self,
+        price: Decimal,
+        side: Side,
+        order_value: Decimal,
+        kline: Option<&Kline>,
+        avg_volume: Option<Decimal>,
+    ) -> SlippageResult {
+        let (spread_rate, impact_rate) =
+            self.calculate_components(price, order_value, kline, avg_volume);
+        let slippage_rate = spread_rate + impact_rate;
+        let slippage_amount = price * slippage_rate;
+
+        let execution_price = match side {
+            Side::Buy => price + slippage_amount,  // 매수는 높은 가격
+            Side::Sell => price - slippage_amount, // 매도는 낮은 가격
+        };
+
+        SlippageResult {
+            base_price: price,
+            execution_price,
+            slippage_rate,
+            slippage_amount,
+            spread_rate,
+            impact_rate,
+        }
+    }
+
+    /// 슬리피지 비율만 계산.
+    pub fn calculate_rate(
+        &self,
+        price: Decimal,
+        order_value: Decimal,
+        kline: Option<&Kline>,
+        avg_volume: Option<Decimal>,
+    ) -> Decimal {
+        let (spread_rate, impact_rate) =
+            self.calculate_components(price, order_value, kline, avg_volume);
+        spread_rate + impact_rate
+    }
+
+    /// 슬리피지를 (스프레드 성분, 충격 성분)으로 나누어 계산.
+    ///
+    /// 거래량과 무관한 모델(Fixed/VolatilityBased/Tiered)은 전체를 스프레드 성분으로 봅니다.
+    fn calculate_components(
+        &self,
+        price: Decimal,
+        order_value: Decimal,
+        kline: Option<&Kline>,
+        avg_volume: Option<Decimal>,
+    ) -> (Decimal, Decimal) {
+        match self {
+            SlippageModel::Fixed { rate } => (*rate, Decimal::ZERO),
+
+            SlippageModel::Linear { base, impact } => {
+                // 일일 거래량 기반 시장 충격
+                let daily_volume_value = kline.map(|k| k.volume * k.close).unwrap_or(Decimal::MAX);
+
+                if daily_volume_value > Decimal::ZERO {
+                    let volume_impact = (order_value / daily_volume_value) * *impact;
+                    (*base, volume_impact)
+                } else {
+                    (*base, Decimal::ZERO)
+                }
+            }
+
+            SlippageModel::MarketImpact {
+                participation_rate,
+                spread_rate,
+                impact_coefficient,
+            } => {
+                let adv = avg_volume
+                    .or_else(|| kline.map(|k| k.volume))
+                    .filter(|v| *v > Decimal::ZERO);
+
+                // 거래량 데이터가 없거나 참여율이 잘못되면 Linear로 폴백
+                let Some(adv) = adv.filter(|_| *participation_rate > Decimal::ZERO) else {
+                    return Self::linear(*spread_rate, default_linear_impact())
+                        .calculate_components(price, order_value, kline, None);
+                };
+                if price <= Decimal::ZERO {
+                    return (*spread_rate, Decimal::ZERO);
+                }
+
+                let order_share = order_value / price / adv;
+                let participation = order_share.min(*participation_rate);
+                let volatility = kline
+                    .filter(|k| k.close > Decimal::ZERO)
+                    .map(|k| (k.high - k.low) / k.close)
+                    .unwrap_or(Decimal::ZERO);
+
+                let impact = *impact_coefficient
+                    * volatility
+                    * (decimal_sqrt(participation) + order_share / Decimal::TWO);
+                (*spread_rate, impact)
+            }
+
+            SlippageModel::VolatilityBased {
+                multiplier,
+                min_rate,
+                max_rate,
+            } => {
+                let volatility_rate = kline
+                    .map(|k| {
+                        if k.close > Decimal::ZERO {
+                            (k.high - k.low) / k.close
+                                * Decimal::from_f64(*multiplier).unwrap_or(Decimal::new(5, 1))
+                        } else {
+                            *min_rate
+                        }
+                    })
+                    .unwrap_or(*min_rate);
+
+                // 최소/최대 범위로 클램핑
+                (volatility_rate.max(*min_rate).min(*max_rate), Decimal::ZERO)
+            }
+
+            SlippageModel::Tiered { tiers } => {
+                // 주문 금액에 해당하는 구간 찾기 (모든 구간 초과 시 마지막 구간 사용)
+                let rate = tiers
+                    .iter()
+                    .find(|tier| order_value <= tier.threshold)
+                    .or(tiers.last())
+                    .map(|t| t.rate)
+                    .unwrap_or(default_fixed_rate());
+                (rate, Decimal::ZERO)
+            }
+        }
+    }
+
+    /// 비현실적인 설정에 대한 경고 메시지.
+    ///
+    /// MarketImpact의 참여율이 0 이하이거나 [`MAX_REALISTIC_PARTICIPATION_RATE`]를
+    /// 넘으면 경고 메시지를 반환합니다. 계산은 그대로 진행되므로 호출부에서 로그로 남깁니다.
+    pub fn config_warning(&self) -> Option<String> {
+        match self {
+            SlippageModel::MarketImpact {
+                participation_rate, ..
+            } if *participation_rate <= Decimal::ZERO => Some(format!(
+                "참여율 {}는 0보다 커야 합니다. Linear 모델로 폴백합니다",
+                participation_rate
+            )),
+            SlippageModel::MarketImpact {
+                participation_rate, ..
+            } if *participation_rate > MAX_REALISTIC_PARTICIPATION_RATE => Some(format!(
+                "참여율 {}%는 비현실적으로 높습니다 (권장 {}% 이하). 충격 비용이 과소/과대 추정될 수 있습니다",
+                participation_rate * Decimal::ONE_HUNDRED,
+                MAX_REALISTIC_PARTICIPATION_RATE * Decimal::ONE_HUNDRED
+            )),
+            _ => None,
+        }
+    }
+
+    /// 캔들 데이터(거래량, 고가/저가)가 있어야 계산 가능한 모델인지 여부.
+    ///
+    /// Linear는 거래량, VolatilityBased는 캔들 범위를 사용합니다.
+    pub fn requires_market_data(&self) -> bool {
+        matches!(
+            self,
+            SlippageModel::Linear { .. } | SlippageModel::VolatilityBased { .. }
+        )
+    }
+
+    /// 모델 이름 반환.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SlippageModel::Fixed { .. } => "Fixed",
+            SlippageModel::Linear { .. } => "Linear",
+            SlippageModel::VolatilityBased { .. } => "VolatilityBased",
+            SlippageModel::Tiered { .. } => "Tiered",
+            SlippageModel::MarketImpact { .. } => "MarketImpact",
+        }
+    }
+}
+
+/// Decimal 제곱근 (f64 경유, 음수/변환 실패 시 0)
+fn decimal_sqrt(value: Decimal) -> Decimal {
+    value
+        .to_f64()
+        .filter(|v| *v > 0.0)
+        .and_then(|v| Decimal::from_f64(v.sqrt()))
+        .unwrap_or(Decimal::ZERO)
+}
+
+/// 슬리피지 계산 결과.
+#[derive(Debug, Clone)]
+pub struct SlippageResult {
+    /// 기준 가격
+    pub base_price: Decimal,
+    /// 슬리피지 적용 후 실행 가격
+    pub execution_price: Decimal,
+    /// 적용된 슬리피지 비율
+    pub slippage_rate: Decimal,
+    /// 슬리피지 금액 (단위 가격 기준)
+    pub slippage_amount: Decimal,
+    /// 스프레드 성분 비율 (주문 규모와 무관한 고정 비용)
+    pub spread_rate: Decimal,
+    /// 시장 충격 성분 비율 (주문 규모/거래량에 비례하는 비용)
+    pub impact_rate: Decimal,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_slippage() {
+        let model = SlippageModel::fixed(dec!(0.001)); // 0.1%
+        let result = model.calculate_execution_price(dec!(100), Side::Buy, dec!(10000), None, None);
+
+        assert_eq!(result.base_price, dec!(100));
+        assert_eq!(result.slippage_rate, dec!(0.001));
+        assert_eq!(result.slippage_amount, dec!(0.1)); // 100 * 0.001
+        assert_eq!(result.execution_price, dec!(100.1)); // 100 + 0.1
+    }
+
+    #[test]
+    fn test_fixed_slippage_sell() {
+        let model = SlippageModel::fixed(dec!(0.001));
+        let result =
+            model.calculate_execution_price(dec!(100), Side::Sell, dec!(10000), None, None);
+
+        assert_eq!(result.execution_price, dec!(99.9)); // 100 - 0.1
+    }
+
+    #[test]
+    fn test_tiered_slippage() {
+        let model = SlippageModel::tiered(vec![
+            (dec!(10000), dec!(0.0003)),  // < 10000: 0.03%
+            (dec!(100000), dec!(0.0005)), // < 100000: 0.05%
+            (dec!(1000000), dec!(0.001)), // < 1000000: 0.1%
+        ]);
+
+        // 소액 주문
+        let small = model.calculate_rate(dec!(100), dec!(5000), None, None);
+        assert_eq!(small, dec!(0.0003));
+
+        // 중간 주문
+        let medium = model.calculate_rate(dec!(100), dec!(50000), None, None);
+        assert_eq!(medium, dec!(0.0005));
+
+        // 대형 주문
+        let large = model.calculate_rate(dec!(100), dec!(500000), None, None);
+        assert_eq!(large, dec!(0.001));
+    }
+
+    #[test]
+    fn test_default_model() {
+        let model = SlippageModel::default();
+        assert!(matches!(model, SlippageModel::Fixed { .. }));
+
+        if let SlippageModel::Fixed { rate } = model {
+            assert_eq!(rate, dec!(0.0005)); // 0.05%
+        }
+    }
+
+    fn test_kline(volume: Decimal) -> Kline {
+        Kline::new(
+            "SPY".to_string(),
+            trader_core::Timeframe::D1,
+            chrono::Utc::now(),
+            dec!(100),
+            dec!(102),
+            dec!(98),
+            dec!(100),
+            volume,
+            chrono::Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_market_impact_components() {
+        let model = SlippageModel::market_impact(dec!(0.1));
+        let kline = test_kline(dec!(1000));
+
+        // 주문 100주 / ADV 10000주 = 1%, σ = (102 - 98) / 100 = 0.04
+        // 충격 = 0.04 × (√0.01 + 0.01 / 2) = 0.0042
+        let result = model.calculate_execution_price(
+            dec!(100),
+            Side::Buy,
+            dec!(10000),
+            Some(&kline),
+            Some(dec!(10000)),
+        );
+
+        assert_eq!(result.spread_rate, dec!(0.0005));
+        assert_eq!(result.impact_rate.round_dp(6), dec!(0.0042));
+        assert_eq!(
+            result.slippage_rate,
+            result.spread_rate + result.impact_rate
+        );
+    }
+
+    #[test]
+    fn test_market_impact_grows_with_order_size() {
+        let model = SlippageModel::market_impact(dec!(0.1));
+        let kline = test_kline(dec!(10000));
+
+        let small = model.calculate_rate(dec!(100), dec!(10000), Some(&kline), None);
+        let large = model.calculate_rate(dec!(100), dec!(500000), Some(&kline), None);
+
+        assert!(large > small);
+    }
+
+    #[test]
+    fn test_market_impact_falls_back_to_linear_without_volume() {
+        let model = SlippageModel::market_impact(dec!(0.1));
+        let kline = test_kline(Decimal::ZERO);
+
+        let result =
+            model.calculate_execution_price(dec!(100), Side::Buy, dec!(10000), Some(&kline), None);
+
+        assert_eq!(result.slippage_rate, dec!(0.0005));
+        assert_eq!(result.impact_rate, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_market_impact_config_warning() {
+        assert!(SlippageModel::market_impact(dec!(0.1))
+            .config_warning()
+            .is_none());
+        assert!(SlippageModel::market_impact(dec!(0.5))
+            .config_warning()
+            .is_some());
+        assert!(SlippageModel::market_impact(Decimal::ZERO)
+            .config_warning()
+            .is_some());
+        assert!(SlippageModel::fixed(dec!(0.001)).config_warning().is_none());
+    }
+}
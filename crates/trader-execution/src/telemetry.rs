@@ -0,0 +1,64 @@
+//! 주문·포지션 Prometheus 메트릭 기록.
+//!
+//! 메트릭 이름과 라벨 규칙은 [`trader_core::telemetry`]에 정의되어 있습니다.
+//! 레코더가 설치되지 않았으면 기록 호출은 아무 동작도 하지 않습니다.
+
+use metrics::{counter, gauge, histogram};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use trader_core::telemetry::{
+    strategy_label, ORDERS_TOTAL, ORDER_FILLS_TOTAL, ORDER_FILL_LATENCY_SECONDS, POSITIONS_OPEN,
+    REALIZED_PNL,
+};
+
+/// 주문 이벤트 기록.
+pub(crate) fn record_order_event(strategy_id: Option<&str>, exchange: &str, event: &'static str) {
+    counter!(
+        ORDERS_TOTAL,
+        "strategy" => strategy_label(strategy_id),
+        "exchange" => exchange.to_string(),
+        "event" => event
+    )
+    .increment(1);
+}
+
+/// 체결(부분 체결 포함) 기록.
+pub(crate) fn record_order_fill(strategy_id: Option<&str>, exchange: &str) {
+    counter!(
+        ORDER_FILLS_TOTAL,
+        "strategy" => strategy_label(strategy_id),
+        "exchange" => exchange.to_string()
+    )
+    .increment(1);
+}
+
+/// 전량 체결 지연 기록.
+pub(crate) fn record_fill_latency(strategy_id: Option<&str>, exchange: &str, seconds: f64) {
+    histogram!(
+        ORDER_FILL_LATENCY_SECONDS,
+        "strategy" => strategy_label(strategy_id),
+        "exchange" => exchange.to_string()
+    )
+    .record(seconds.max(0.0));
+}
+
+/// 실현손익 누적.
+///
+/// 손실도 같은 gauge에 음수로 더해지므로 값은 전략의 누적 실현손익입니다.
+pub(crate) fn record_realized_pnl(strategy_id: Option<&str>, exchange: &str, pnl: Decimal) {
+    gauge!(
+        REALIZED_PNL,
+        "strategy" => strategy_label(strategy_id),
+        "exchange" => exchange.to_string()
+    )
+    .increment(pnl.to_f64().unwrap_or(0.0));
+}
+
+/// 열린 포지션 수 설정.
+pub(crate) fn set_open_positions(strategy_id: Option<&str>, exchange: &str, count: usize) {
+    gauge!(
+        POSITIONS_OPEN,
+        "strategy" => strategy_label(strategy_id),
+        "exchange" => exchange.to_string()
+    )
+    .set(count as f64);
+}
@@ -8,6 +8,15 @@
 //! - Discord (Webhook)
 //! - Slack (Incoming Webhook)
 //! - SMS (Twilio)
+//! - 범용 Webhook (HTTP POST, HMAC 서명)
+//!
+//! # 메시지 템플릿
+//!
+//! [`NotificationTemplates`]로 이벤트별 문구 템플릿과 알림 언어(ko/en)를 설정할 수 있습니다.
+//!
+//! # 중복 억제 및 속도 제한
+//!
+//! [`ThrottleConfig`]로 동일 알림 억제 구간, 채널별 전송 한도, 요약 전송 여부를 설정할 수 있습니다.
 //!
 //! # 텔레그램 봇 명령어
 //!
@@ -24,7 +33,10 @@ pub mod email;
 pub mod slack;
 pub mod sms;
 pub mod telegram;
+pub mod template;
+pub mod throttle;
 pub mod types;
+pub mod webhook;
 
 pub use bot_handler::*;
 pub use discord::*;
@@ -32,4 +44,7 @@ pub use email::*;
 pub use slack::*;
 pub use sms::*;
 pub use telegram::*;
+pub use template::*;
+pub use throttle::*;
 pub use types::*;
+pub use webhook::*;
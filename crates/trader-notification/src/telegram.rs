@@ -2,10 +2,15 @@
 //!
 //! Telegram Bot API를 통해 트레이딩 알림 및 업데이트를 전송합니다.
 
+use std::time::Instant;
+
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use tracing::{debug, error, info, warn};
+use trader_core::{IdempotencyGuard, IdempotencyKey, IdempotentOutcome};
 
+use crate::template::NotificationTemplates;
+use crate::throttle::{NotificationThrottle, ThrottleConfig, ThrottleDecision};
 use crate::types::{
     Notification, NotificationError, NotificationEvent, NotificationPriority, NotificationResult,
     NotificationSender,
@@ -468,6 +473,12 @@ impl NotificationSender for TelegramSender {
 /// 여러 전송기를 관리하는 알림 관리자.
 pub struct NotificationManager {
     senders: Vec<Box<dyn NotificationSender>>,
+    /// 전송기별 중복 전송 방지 (알림 ID 기준)
+    idempotency: Option<IdempotencyGuard>,
+    /// 이벤트별 메시지 템플릿 (없으면 전송기 기본 포맷)
+    templates: Option<NotificationTemplates>,
+    /// 중복 억제/채널별 속도 제한
+    throttle: Option<NotificationThrottle>,
 }
 
 impl NotificationManager {
@@ -475,25 +486,121 @@ impl NotificationManager {
     pub fn new() -> Self {
         Self {
             senders: Vec::new(),
+            idempotency: None,
+            templates: None,
+            throttle: None,
         }
     }
 
+    /// 중복 전송 방지를 활성화합니다.
+    ///
+    /// 같은 알림 ID는 전송기마다 한 번만 전송되며, 실패한 전송기만 재시도됩니다.
+    pub fn with_idempotency(mut self, guard: IdempotencyGuard) -> Self {
+        self.idempotency = Some(guard);
+        self
+    }
+
+    /// 메시지 템플릿 및 알림 언어를 설정합니다.
+    pub fn with_templates(mut self, templates: NotificationTemplates) -> Self {
+        self.templates = Some(templates);
+        self
+    }
+
+    /// 중복 억제 및 채널별 속도 제한을 활성화합니다.
+    pub fn with_throttle(mut self, config: ThrottleConfig) -> Self {
+        self.throttle = Some(NotificationThrottle::new(config));
+        self
+    }
+
     /// 알림 전송기를 추가합니다.
     pub fn add_sender<S: NotificationSender + 'static>(&mut self, sender: S) {
         self.senders.push(Box::new(sender));
     }
 
     /// 활성화된 모든 전송기를 통해 알림을 전송합니다.
+    ///
+    /// 템플릿이 설정되어 있으면 렌더링한 문구로 전송하며, 렌더링 실패
+    /// (알 수 없는 변수 등)는 전송 없이 에러로 반환합니다.
+    /// 억제기가 설정되어 있으면 중복 알림은 건너뛰고 집계만 합니다.
     pub async fn notify(&self, notification: &Notification) -> NotificationResult<()> {
+        let rendered = self.apply_template(notification)?;
+        let notification = rendered.as_ref().unwrap_or(notification);
+
+        if let Some(throttle) = &self.throttle {
+            let now = Instant::now();
+            self.send_summaries(throttle, now).await;
+            if throttle.check_duplicate(notification, now) == ThrottleDecision::Duplicate {
+                debug!("Suppressing duplicate notification {}", notification.id);
+                return Ok(());
+            }
+        }
+
+        self.dispatch(notification).await
+    }
+
+    /// 억제 구간이 끝난 중복 알림 요약과 속도 제한 생략 건수를 전송합니다.
+    ///
+    /// `notify` 호출 시 자동으로 처리되지만, 알림이 뜸한 경우를 위해
+    /// 주기적으로 호출할 수 있습니다.
+    pub async fn flush_suppressed(&self) {
+        if let Some(throttle) = &self.throttle {
+            self.send_summaries(throttle, Instant::now()).await;
+        }
+    }
+
+    async fn send_summaries(&self, throttle: &NotificationThrottle, now: Instant) {
+        for summary in throttle.drain_summaries(now) {
+            if let Err(e) = self.dispatch(&summary).await {
+                warn!("Failed to send suppressed notification summary: {}", e);
+            }
+        }
+
+        if !throttle.config().summarize_suppressed {
+            return;
+        }
+        for sender in self.senders.iter().filter(|s| s.is_enabled()) {
+            let dropped = throttle.take_dropped(sender.name());
+            if dropped == 0 {
+                continue;
+            }
+            let notice = Notification::new(NotificationEvent::Custom {
+                title: "알림 속도 제한".to_string(),
+                message: format!("전송 한도 초과로 알림 {}건이 생략되었습니다.", dropped),
+            });
+            if let Err(e) = sender.send(&notice).await {
+                warn!(
+                    "Failed to send rate limit notice via {}: {}",
+                    sender.name(),
+                    e
+                );
+            }
+        }
+    }
+
+    /// 채널별 속도 제한을 적용하여 전송합니다.
+    async fn dispatch(&self, notification: &Notification) -> NotificationResult<()> {
         let mut last_error = None;
 
         for sender in &self.senders {
-            if sender.is_enabled() {
-                if let Err(e) = sender.send(notification).await {
-                    error!("Failed to send notification via {}: {}", sender.name(), e);
-                    last_error = Some(e);
+            if !sender.is_enabled() {
+                continue;
+            }
+            if let Some(throttle) = &self.throttle {
+                let decision =
+                    throttle.acquire_channel(sender.name(), notification.priority, Instant::now());
+                if decision == ThrottleDecision::RateLimited {
+                    debug!(
+                        "Rate limited notification {} via {}",
+                        notification.id,
+                        sender.name()
+                    );
+                    continue;
                 }
             }
+            if let Err(e) = self.send_once(sender.as_ref(), notification).await {
+                error!("Failed to send notification via {}: {}", sender.name(), e);
+                last_error = Some(e);
+            }
         }
 
         if let Some(e) = last_error {
@@ -506,6 +613,53 @@ impl NotificationManager {
         Ok(())
     }
 
+    /// 템플릿이 있으면 렌더링된 문구의 `Custom` 이벤트로 변환합니다.
+    ///
+    /// ID/우선순위/타임스탬프/메타데이터는 유지하므로 중복 전송 방지 키가 바뀌지 않습니다.
+    fn apply_template(
+        &self,
+        notification: &Notification,
+    ) -> NotificationResult<Option<Notification>> {
+        let Some(templates) = &self.templates else {
+            return Ok(None);
+        };
+        let Some(rendered) = templates.render(notification)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Notification {
+            event: NotificationEvent::Custom {
+                title: rendered.title,
+                message: rendered.body,
+            },
+            ..notification.clone()
+        }))
+    }
+
+    /// 중복 전송 방지가 활성화된 경우 이미 전송된 알림을 건너뜁니다.
+    async fn send_once(
+        &self,
+        sender: &dyn NotificationSender,
+        notification: &Notification,
+    ) -> NotificationResult<()> {
+        let Some(guard) = &self.idempotency else {
+            return sender.send(notification).await;
+        };
+
+        let key = IdempotencyKey::webhook(sender.name(), &notification.id);
+        match guard.run(&key, || sender.send(notification)).await? {
+            IdempotentOutcome::Executed(()) => {}
+            IdempotentOutcome::Completed(_) | IdempotentOutcome::InProgress => {
+                debug!(
+                    "Skipping duplicate notification {} via {}",
+                    notification.id,
+                    sender.name()
+                );
+            }
+        }
+        Ok(())
+    }
+
     /// 주문 체결 알림을 전송합니다.
     pub async fn notify_order_filled(
         &self,
@@ -779,4 +933,129 @@ mod tests {
         assert!(message.contains("💰")); // Profit emoji
         assert!(message.contains("+100"));
     }
+
+    struct CountingSender {
+        sent: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl NotificationSender for CountingSender {
+        async fn send(&self, _notification: &Notification) -> NotificationResult<()> {
+            self.sent.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn is_enabled(&self) -> bool {
+            true
+        }
+
+        fn name(&self) -> &str {
+            "counting"
+        }
+    }
+
+    struct RecordingSender {
+        received: std::sync::Arc<std::sync::Mutex<Vec<Notification>>>,
+    }
+
+    #[async_trait]
+    impl NotificationSender for RecordingSender {
+        async fn send(&self, notification: &Notification) -> NotificationResult<()> {
+            self.received.lock().unwrap().push(notification.clone());
+            Ok(())
+        }
+
+        fn is_enabled(&self) -> bool {
+            true
+        }
+
+        fn name(&self) -> &str {
+            "counting"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_manager_skips_duplicate_notification() {
+        let sent = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut manager =
+            NotificationManager::new().with_idempotency(IdempotencyGuard::in_memory());
+        manager.add_sender(CountingSender { sent: sent.clone() });
+
+        let notification = Notification::new(NotificationEvent::SystemError {
+            error_code: "E001".to_string(),
+            message: "test".to_string(),
+        });
+        manager.notify(&notification).await.unwrap();
+        manager.notify(&notification).await.unwrap();
+        assert_eq!(sent.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // 새 알림은 전송됨
+        let other = Notification::new(NotificationEvent::SystemError {
+            error_code: "E002".to_string(),
+            message: "test".to_string(),
+        });
+        manager.notify(&other).await.unwrap();
+        assert_eq!(sent.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_manager_applies_template() {
+        use crate::template::{NotificationLanguage, NotificationTemplate};
+
+        let mut templates = NotificationTemplates::new(NotificationLanguage::Ko);
+        templates
+            .register(
+                "system_error",
+                NotificationLanguage::Ko,
+                NotificationTemplate::new("장애 {error_code}", "{message}"),
+            )
+            .unwrap();
+        let received = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut manager = NotificationManager::new().with_templates(templates);
+        manager.add_sender(RecordingSender {
+            received: received.clone(),
+        });
+
+        let notification = Notification::new(NotificationEvent::SystemError {
+            error_code: "E001".to_string(),
+            message: "DB 연결 실패".to_string(),
+        });
+        manager.notify(&notification).await.unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received[0].id, notification.id);
+        match &received[0].event {
+            NotificationEvent::Custom { title, message } => {
+                assert_eq!(title, "장애 E001");
+                assert_eq!(message, "DB 연결 실패");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_manager_suppresses_repeated_signal() {
+        let sent = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut manager = NotificationManager::new().with_throttle(ThrottleConfig::default());
+        manager.add_sender(CountingSender { sent: sent.clone() });
+
+        let event = NotificationEvent::SystemError {
+            error_code: "E001".to_string(),
+            message: "반복".to_string(),
+        };
+        for _ in 0..5 {
+            manager
+                .notify(&Notification::new(event.clone()))
+                .await
+                .unwrap();
+        }
+        assert_eq!(sent.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // critical은 억제하지 않음
+        let critical =
+            Notification::new(event.clone()).with_priority(NotificationPriority::Critical);
+        manager.notify(&critical).await.unwrap();
+        manager.notify(&critical).await.unwrap();
+        assert_eq!(sent.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
 }
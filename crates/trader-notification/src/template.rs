@@ -0,0 +1,731 @@
+//! 알림 메시지 템플릿.
+//!
+//! 이벤트 유형별로 제목/본문 템플릿을 등록하고 `{symbol}`, `{pnl}`, `{price}` 같은
+//! 변수를 이벤트 값으로 치환합니다.
+//!
+//! # 문법
+//!
+//! - `{name}` - 이벤트 변수 치환 (이름은 영문/숫자/`_`)
+//! - `{{`, `}}` - 중괄호 문자 그대로 출력
+//!
+//! 이벤트에 없는 변수는 빈 문자열로 치환하지 않고 [`TemplateError::UnknownVariable`]로
+//! 거부합니다. 등록 시점에도 같은 검증을 하므로 오타를 배포 전에 발견할 수 있습니다.
+//!
+//! # 언어 및 폴백
+//!
+//! 선택된 언어([`NotificationLanguage`])의 템플릿이 없으면 기본 문구를 사용합니다.
+//!
+//! - 한국어: 각 전송기(텔레그램, Slack 등)의 기본 포맷
+//! - 영어: 내장 영문 템플릿 (없는 이벤트는 전송기 기본 포맷)
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! let mut templates = NotificationTemplates::new(NotificationLanguage::Ko);
+//! templates.register(
+//!     "stop_loss_triggered",
+//!     NotificationLanguage::Ko,
+//!     NotificationTemplate::new("손절: {symbol}", "{trigger_price}에 손절, 손실 {loss}"),
+//! )?;
+//! let manager = NotificationManager::new().with_templates(templates);
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Notification, NotificationEvent};
+
+/// 알림 언어.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationLanguage {
+    /// 한국어
+    #[default]
+    Ko,
+    /// 영어
+    En,
+}
+
+impl NotificationLanguage {
+    /// 문자열에서 파싱 (ko, en)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "ko" | "kr" | "korean" => Some(Self::Ko),
+            "en" | "english" => Some(Self::En),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for NotificationLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ko => write!(f, "ko"),
+            Self::En => write!(f, "en"),
+        }
+    }
+}
+
+/// 템플릿 에러.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TemplateError {
+    #[error("알 수 없는 이벤트 유형: {0}")]
+    UnknownEvent(String),
+
+    #[error("이벤트 '{event}'에 없는 템플릿 변수: {{{variable}}}")]
+    UnknownVariable { event: String, variable: String },
+
+    #[error("닫히지 않은 변수 (위치 {0})")]
+    UnclosedPlaceholder(usize),
+
+    #[error("짝이 맞지 않는 '}}' (위치 {0})")]
+    UnmatchedBrace(usize),
+
+    #[error("잘못된 변수 이름 '{name}' (위치 {position})")]
+    InvalidVariableName { name: String, position: usize },
+}
+
+/// 이벤트 유형 하나에 대한 제목/본문 템플릿.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationTemplate {
+    /// 제목 템플릿
+    pub title: String,
+    /// 본문 템플릿
+    pub body: String,
+}
+
+impl NotificationTemplate {
+    /// 새 템플릿을 생성합니다.
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+        }
+    }
+
+    /// 이벤트 유형의 변수만 사용하는지 검증합니다.
+    pub fn validate(&self, kind: &str) -> Result<(), TemplateError> {
+        let names =
+            variable_names(kind).ok_or_else(|| TemplateError::UnknownEvent(kind.to_string()))?;
+        let lookup = |name: &str| names.contains(&name).then_some("");
+        render_str(&self.title, kind, lookup)?;
+        render_str(&self.body, kind, lookup)?;
+        Ok(())
+    }
+
+    /// 이벤트 값으로 템플릿을 렌더링합니다.
+    pub fn render(&self, notification: &Notification) -> Result<RenderedMessage, TemplateError> {
+        let kind = notification.event.kind();
+        let variables = event_variables(notification);
+        let lookup = |name: &str| {
+            variables
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        Ok(RenderedMessage {
+            title: render_str(&self.title, kind, lookup)?,
+            body: render_str(&self.body, kind, lookup)?,
+        })
+    }
+}
+
+/// 렌더링된 알림 문구.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedMessage {
+    /// 제목
+    pub title: String,
+    /// 본문
+    pub body: String,
+}
+
+/// 이벤트 유형/언어별 템플릿 모음.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationTemplates {
+    language: NotificationLanguage,
+    templates: HashMap<(String, NotificationLanguage), NotificationTemplate>,
+}
+
+impl NotificationTemplates {
+    /// 선택 언어로 빈 템플릿 모음을 생성합니다.
+    pub fn new(language: NotificationLanguage) -> Self {
+        Self {
+            language,
+            templates: HashMap::new(),
+        }
+    }
+
+    /// 선택된 언어.
+    pub fn language(&self) -> NotificationLanguage {
+        self.language
+    }
+
+    /// 알림 언어를 변경합니다.
+    pub fn set_language(&mut self, language: NotificationLanguage) {
+        self.language = language;
+    }
+
+    /// 이벤트 유형별 템플릿을 등록합니다.
+    ///
+    /// 알 수 없는 이벤트 유형이나 변수를 사용하면 등록하지 않고 에러를 반환합니다.
+    pub fn register(
+        &mut self,
+        kind: &str,
+        language: NotificationLanguage,
+        template: NotificationTemplate,
+    ) -> Result<(), TemplateError> {
+        template.validate(kind)?;
+        self.templates
+            .insert((kind.to_string(), language), template);
+        Ok(())
+    }
+
+    /// 알림에 적용할 템플릿을 찾습니다.
+    ///
+    /// 등록된 템플릿 → 내장 영문 템플릿(영어 선택 시) 순서로 찾고, 없으면 `None`
+    /// (전송기 기본 포맷 사용)을 반환합니다.
+    pub fn resolve(&self, event: &NotificationEvent) -> Option<NotificationTemplate> {
+        let kind = event.kind();
+        if let Some(template) = self.templates.get(&(kind.to_string(), self.language)) {
+            return Some(template.clone());
+        }
+        match self.language {
+            NotificationLanguage::Ko => None,
+            NotificationLanguage::En => builtin_en(kind),
+        }
+    }
+
+    /// 알림을 선택 언어의 템플릿으로 렌더링합니다.
+    ///
+    /// 적용할 템플릿이 없으면 `Ok(None)`을 반환합니다.
+    pub fn render(
+        &self,
+        notification: &Notification,
+    ) -> Result<Option<RenderedMessage>, TemplateError> {
+        self.resolve(&notification.event)
+            .map(|template| template.render(notification))
+            .transpose()
+    }
+}
+
+/// 템플릿 문자열 렌더링.
+///
+/// `lookup`이 `None`을 반환하면 [`TemplateError::UnknownVariable`]입니다.
+fn render_str<'a>(
+    template: &str,
+    kind: &str,
+    lookup: impl Fn(&str) -> Option<&'a str>,
+) -> Result<String, TemplateError> {
+    let mut output = String::with_capacity(template.len());
+    let mut chars = template.char_indices().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|(_, next)| *next) == Some('{') => {
+                chars.next();
+                output.push('{');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '}')) => break,
+                        Some((_, ch)) => name.push(ch),
+                        None => return Err(TemplateError::UnclosedPlaceholder(position)),
+                    }
+                }
+                if name.is_empty()
+                    || !name
+                        .chars()
+                        .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+                {
+                    return Err(TemplateError::InvalidVariableName { name, position });
+                }
+                let value = lookup(&name).ok_or_else(|| TemplateError::UnknownVariable {
+                    event: kind.to_string(),
+                    variable: name.clone(),
+                })?;
+                output.push_str(value);
+            }
+            '}' if chars.peek().map(|(_, next)| *next) == Some('}') => {
+                chars.next();
+                output.push('}');
+            }
+            '}' => return Err(TemplateError::UnmatchedBrace(position)),
+            _ => output.push(c),
+        }
+    }
+
+    Ok(output)
+}
+
+/// 모든 이벤트에서 사용할 수 있는 공통 변수.
+const COMMON_VARIABLES: &[&str] = &["priority", "timestamp"];
+
+/// 이벤트 유형별 사용 가능한 변수 이름 (공통 변수 제외).
+fn event_variable_names(kind: &str) -> Option<&'static [&'static str]> {
+    let names: &'static [&'static str] = match kind {
+        "order_filled" => &["symbol", "side", "quantity", "price", "order_id"],
+        "position_opened" => &["symbol", "side", "quantity", "entry_price"],
+        "position_closed" => &[
+            "symbol",
+            "side",
+            "quantity",
+            "entry_price",
+            "exit_price",
+            "pnl",
+            "pnl_percent",
+        ],
+        "stop_loss_triggered" => &["symbol", "quantity", "trigger_price", "loss"],
+        "take_profit_triggered" => &["symbol", "quantity", "trigger_price", "profit"],
+        "daily_summary" => &[
+            "date",
+            "total_trades",
+            "winning_trades",
+            "total_pnl",
+            "win_rate",
+        ],
+        "risk_alert" => &["alert_type", "message", "current_value", "threshold"],
+        "strategy_started" => &["strategy_id", "strategy_name"],
+        "strategy_stopped" => &["strategy_id", "strategy_name", "reason"],
+        "system_error" => &["error_code", "message"],
+        "signal_alert" => &[
+            "signal_type",
+            "symbol",
+            "side",
+            "price",
+            "strength",
+            "reason",
+            "strategy_name",
+        ],
+        "custom" => &["title", "message"],
+        "route_state_changed" => &[
+            "symbol",
+            "symbol_name",
+            "previous_state",
+            "new_state",
+            "macro_risk",
+            "macro_summary",
+        ],
+        "macro_alert" => &[
+            "risk_level",
+            "usd_krw",
+            "usd_change_pct",
+            "nasdaq_change_pct",
+            "recommendation",
+        ],
+        "market_breadth_alert" => &[
+            "temperature",
+            "all_ratio",
+            "kospi_ratio",
+            "kosdaq_ratio",
+            "recommendation",
+        ],
+        _ => return None,
+    };
+    Some(names)
+}
+
+/// 이벤트 유형의 전체 변수 이름 (공통 변수 포함).
+fn variable_names(kind: &str) -> Option<Vec<&'static str>> {
+    event_variable_names(kind).map(|names| {
+        names
+            .iter()
+            .chain(COMMON_VARIABLES.iter())
+            .copied()
+            .collect()
+    })
+}
+
+/// 알림의 변수 값 목록.
+///
+/// 값이 없는 선택 필드(`Option::None`)는 `-`로 표시합니다.
+fn event_variables(notification: &Notification) -> Vec<(&'static str, String)> {
+    fn opt(value: &Option<String>) -> String {
+        value.clone().unwrap_or_else(|| "-".to_string())
+    }
+
+    let mut vars: Vec<(&'static str, String)> = match &notification.event {
+        NotificationEvent::OrderFilled {
+            symbol,
+            side,
+            quantity,
+            price,
+            order_id,
+        } => vec![
+            ("symbol", symbol.clone()),
+            ("side", side.clone()),
+            ("quantity", quantity.to_string()),
+            ("price", price.to_string()),
+            ("order_id", order_id.clone()),
+        ],
+        NotificationEvent::PositionOpened {
+            symbol,
+            side,
+            quantity,
+            entry_price,
+        } => vec![
+            ("symbol", symbol.clone()),
+            ("side", side.clone()),
+            ("quantity", quantity.to_string()),
+            ("entry_price", entry_price.to_string()),
+        ],
+        NotificationEvent::PositionClosed {
+            symbol,
+            side,
+            quantity,
+            entry_price,
+            exit_price,
+            pnl,
+            pnl_percent,
+        } => vec![
+            ("symbol", symbol.clone()),
+            ("side", side.clone()),
+            ("quantity", quantity.to_string()),
+            ("entry_price", entry_price.to_string()),
+            ("exit_price", exit_price.to_string()),
+            ("pnl", pnl.to_string()),
+            ("pnl_percent", pnl_percent.to_string()),
+        ],
+        NotificationEvent::StopLossTriggered {
+            symbol,
+            quantity,
+            trigger_price,
+            loss,
+        } => vec![
+            ("symbol", symbol.clone()),
+            ("quantity", quantity.to_string()),
+            ("trigger_price", trigger_price.to_string()),
+            ("loss", loss.to_string()),
+        ],
+        NotificationEvent::TakeProfitTriggered {
+            symbol,
+            quantity,
+            trigger_price,
+            profit,
+        } => vec![
+            ("symbol", symbol.clone()),
+            ("quantity", quantity.to_string()),
+            ("trigger_price", trigger_price.to_string()),
+            ("profit", profit.to_string()),
+        ],
+        NotificationEvent::DailySummary {
+            date,
+            total_trades,
+            winning_trades,
+            total_pnl,
+            win_rate,
+        } => vec![
+            ("date", date.clone()),
+            ("total_trades", total_trades.to_string()),
+            ("winning_trades", winning_trades.to_string()),
+            ("total_pnl", total_pnl.to_string()),
+            ("win_rate", win_rate.to_string()),
+        ],
+        NotificationEvent::RiskAlert {
+            alert_type,
+            message,
+            current_value,
+            threshold,
+        } => vec![
+            ("alert_type", alert_type.clone()),
+            ("message", message.clone()),
+            ("current_value", current_value.to_string()),
+            ("threshold", threshold.to_string()),
+        ],
+        NotificationEvent::StrategyStarted {
+            strategy_id,
+            strategy_name,
+        } => vec![
+            ("strategy_id", strategy_id.clone()),
+            ("strategy_name", strategy_name.clone()),
+        ],
+        NotificationEvent::StrategyStopped {
+            strategy_id,
+            strategy_name,
+            reason,
+        } => vec![
+            ("strategy_id", strategy_id.clone()),
+            ("strategy_name", strategy_name.clone()),
+            ("reason", reason.clone()),
+        ],
+        NotificationEvent::SystemError {
+            error_code,
+            message,
+        } => vec![
+            ("error_code", error_code.clone()),
+            ("message", message.clone()),
+        ],
+        NotificationEvent::SignalAlert {
+            signal_type,
+            symbol,
+            side,
+            price,
+            strength,
+            reason,
+            strategy_name,
+            ..
+        } => vec![
+            ("signal_type", signal_type.clone()),
+            ("symbol", symbol.clone()),
+            ("side", opt(side)),
+            ("price", price.to_string()),
+            ("strength", format!("{:.2}", strength)),
+            ("reason", reason.clone()),
+            ("strategy_name", strategy_name.clone()),
+        ],
+        NotificationEvent::Custom { title, message } => {
+            vec![("title", title.clone()), ("message", message.clone())]
+        }
+        NotificationEvent::RouteStateChanged {
+            symbol,
+            symbol_name,
+            previous_state,
+            new_state,
+            macro_risk,
+            macro_summary,
+        } => vec![
+            ("symbol", symbol.clone()),
+            ("symbol_name", opt(symbol_name)),
+            ("previous_state", previous_state.clone()),
+            ("new_state", new_state.clone()),
+            ("macro_risk", opt(macro_risk)),
+            ("macro_summary", opt(macro_summary)),
+        ],
+        NotificationEvent::MacroAlert {
+            risk_level,
+            usd_krw,
+            usd_change_pct,
+            nasdaq_change_pct,
+            recommendation,
+        } => vec![
+            ("risk_level", risk_level.clone()),
+            ("usd_krw", usd_krw.clone()),
+            ("usd_change_pct", usd_change_pct.clone()),
+            ("nasdaq_change_pct", nasdaq_change_pct.clone()),
+            ("recommendation", recommendation.clone()),
+        ],
+        NotificationEvent::MarketBreadthAlert {
+            temperature,
+            all_ratio,
+            kospi_ratio,
+            kosdaq_ratio,
+            recommendation,
+        } => vec![
+            ("temperature", temperature.clone()),
+            ("all_ratio", all_ratio.clone()),
+            ("kospi_ratio", kospi_ratio.clone()),
+            ("kosdaq_ratio", kosdaq_ratio.clone()),
+            ("recommendation", recommendation.clone()),
+        ],
+    };
+
+    let priority = serde_json::to_value(notification.priority)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    vars.push(("priority", priority));
+    vars.push((
+        "timestamp",
+        notification
+            .timestamp
+            .format("%Y-%m-%d %H:%M:%S UTC")
+            .to_string(),
+    ));
+    vars
+}
+
+/// 내장 영문 템플릿.
+fn builtin_en(kind: &str) -> Option<NotificationTemplate> {
+    let (title, body) = match kind {
+        "order_filled" => (
+            "Order Filled",
+            "{symbol} {side} {quantity} @ {price} (order {order_id})",
+        ),
+        "position_opened" => (
+            "Position Opened",
+            "{symbol} {side} {quantity} @ {entry_price}",
+        ),
+        "position_closed" => (
+            "Position Closed",
+            "{symbol} {side} {quantity}: {entry_price} → {exit_price}, PnL {pnl} ({pnl_percent}%)",
+        ),
+        "stop_loss_triggered" => (
+            "Stop Loss Triggered",
+            "{symbol} {quantity} @ {trigger_price}, loss -{loss}",
+        ),
+        "take_profit_triggered" => (
+            "Take Profit Triggered",
+            "{symbol} {quantity} @ {trigger_price}, profit +{profit}",
+        ),
+        "daily_summary" => (
+            "Daily Summary {date}",
+            "Trades {total_trades} (wins {winning_trades}), PnL {total_pnl}, win rate {win_rate}%",
+        ),
+        "risk_alert" => (
+            "Risk Alert: {alert_type}",
+            "{message} (current {current_value} / threshold {threshold})",
+        ),
+        "strategy_started" => ("Strategy Started", "{strategy_name} ({strategy_id})"),
+        "strategy_stopped" => (
+            "Strategy Stopped",
+            "{strategy_name} ({strategy_id}): {reason}",
+        ),
+        "system_error" => ("System Error", "[{error_code}] {message}"),
+        "signal_alert" => (
+            "{signal_type} Signal",
+            "{strategy_name}: {symbol} {side} @ {price} (strength {strength}) - {reason}",
+        ),
+        _ => return None,
+    };
+    Some(NotificationTemplate::new(title, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn stop_loss() -> Notification {
+        Notification::new(NotificationEvent::StopLossTriggered {
+            symbol: "005930".to_string(),
+            quantity: dec!(10),
+            trigger_price: dec!(70000),
+            loss: dec!(50000),
+        })
+    }
+
+    #[test]
+    fn test_render_substitutes_variables_and_escapes() {
+        let template =
+            NotificationTemplate::new("손절: {symbol}", "{{{trigger_price}}} 손실 {loss}");
+        let rendered = template.render(&stop_loss()).unwrap();
+
+        assert_eq!(rendered.title, "손절: 005930");
+        assert_eq!(rendered.body, "{70000} 손실 50000");
+    }
+
+    #[test]
+    fn test_unknown_variable_is_error() {
+        let template = NotificationTemplate::new("{symbol}", "손익 {pnl}");
+
+        assert_eq!(
+            template.render(&stop_loss()),
+            Err(TemplateError::UnknownVariable {
+                event: "stop_loss_triggered".to_string(),
+                variable: "pnl".to_string(),
+            })
+        );
+
+        // 등록 시점에 오타를 거부
+        let mut templates = NotificationTemplates::default();
+        assert!(templates
+            .register("stop_loss_triggered", NotificationLanguage::Ko, template)
+            .is_err());
+        assert!(matches!(
+            templates.register(
+                "stop_los",
+                NotificationLanguage::Ko,
+                NotificationTemplate::new("x", "y")
+            ),
+            Err(TemplateError::UnknownEvent(_))
+        ));
+    }
+
+    #[test]
+    fn test_malformed_template_is_error() {
+        let notification = stop_loss();
+        assert_eq!(
+            NotificationTemplate::new("{symbol", "").render(&notification),
+            Err(TemplateError::UnclosedPlaceholder(0))
+        );
+        assert_eq!(
+            NotificationTemplate::new("a } b", "").render(&notification),
+            Err(TemplateError::UnmatchedBrace(2))
+        );
+        assert!(matches!(
+            NotificationTemplate::new("{sym bol}", "").render(&notification),
+            Err(TemplateError::InvalidVariableName { .. })
+        ));
+    }
+
+    #[test]
+    fn test_language_selection_and_fallback() {
+        let mut templates = NotificationTemplates::new(NotificationLanguage::Ko);
+        let notification = stop_loss();
+
+        // 한국어 템플릿이 없으면 전송기 기본 포맷
+        assert_eq!(templates.render(&notification).unwrap(), None);
+
+        // 영어는 내장 템플릿으로 폴백
+        templates.set_language(NotificationLanguage::En);
+        let rendered = templates.render(&notification).unwrap().unwrap();
+        assert_eq!(rendered.title, "Stop Loss Triggered");
+
+        // 등록된 템플릿이 우선
+        templates
+            .register(
+                "stop_loss_triggered",
+                NotificationLanguage::En,
+                NotificationTemplate::new("SL {symbol}", "{timestamp}"),
+            )
+            .unwrap();
+        let rendered = templates.render(&notification).unwrap().unwrap();
+        assert_eq!(rendered.title, "SL 005930");
+    }
+
+    #[test]
+    fn test_builtin_templates_are_valid() {
+        for kind in [
+            "order_filled",
+            "position_opened",
+            "position_closed",
+            "stop_loss_triggered",
+            "take_profit_triggered",
+            "daily_summary",
+            "risk_alert",
+            "strategy_started",
+            "strategy_stopped",
+            "system_error",
+            "signal_alert",
+        ] {
+            let template = builtin_en(kind).unwrap();
+            assert_eq!(template.validate(kind), Ok(()), "{}", kind);
+        }
+    }
+
+    #[test]
+    fn test_variable_names_match_event_values() {
+        let events = [
+            stop_loss(),
+            Notification::new(NotificationEvent::PositionClosed {
+                symbol: "BTC".to_string(),
+                side: "buy".to_string(),
+                quantity: dec!(1),
+                entry_price: dec!(1),
+                exit_price: dec!(2),
+                pnl: dec!(1),
+                pnl_percent: dec!(100),
+            }),
+            Notification::new(NotificationEvent::DailySummary {
+                date: "2024-01-02".to_string(),
+                total_trades: 3,
+                winning_trades: 2,
+                total_pnl: dec!(10),
+                win_rate: dec!(66.7),
+            }),
+        ];
+
+        for notification in &events {
+            let kind = notification.event.kind();
+            let values: Vec<&str> = event_variables(notification)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect();
+            assert_eq!(values, variable_names(kind).unwrap(), "{}", kind);
+        }
+    }
+}
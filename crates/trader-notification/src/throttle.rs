@@ -0,0 +1,426 @@
+//! 알림 중복 억제 및 채널별 속도 제한.
+//!
+//! 전략이 짧은 시간에 같은 신호를 반복 방출해도 채널이 도배되지 않도록
+//! 두 단계로 알림을 걸러냅니다.
+//!
+//! 1. **중복 억제**: 내용이 같은 알림(이벤트 본문 기준, ID/타임스탬프 무시)은
+//!    `dedup_window` 동안 한 번만 전송합니다.
+//! 2. **채널별 속도 제한**: 전송기마다 `rate_window` 동안 최대 `max_per_window`건만 전송합니다.
+//!
+//! 억제된 알림은 버리지 않고 건수를 집계하며, `summarize_suppressed`가 켜져 있으면
+//! [`NotificationThrottle::drain_summaries`]가 "지난 5분간 동일 알림 12건" 형태의
+//! 요약 알림을 생성합니다.
+//!
+//! `bypass_priority` 이상의 알림(기본: `Critical`)은 억제/제한 대상에서 제외됩니다.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::types::{Notification, NotificationEvent, NotificationPriority};
+
+/// 중복 억제/속도 제한 설정.
+#[derive(Debug, Clone)]
+pub struct ThrottleConfig {
+    /// 동일 내용 알림 억제 구간 (0이면 비활성화)
+    pub dedup_window: Duration,
+    /// 채널별 속도 제한 구간
+    pub rate_window: Duration,
+    /// 구간당 채널별 최대 전송 건수 (`None`이면 제한 없음)
+    pub max_per_window: Option<usize>,
+    /// 억제된 알림을 요약 알림으로 묶어 전송할지 여부
+    pub summarize_suppressed: bool,
+    /// 이 우선순위 이상은 억제/제한하지 않음 (`None`이면 모두 대상)
+    pub bypass_priority: Option<NotificationPriority>,
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            dedup_window: Duration::from_secs(300),
+            rate_window: Duration::from_secs(60),
+            max_per_window: Some(20),
+            summarize_suppressed: true,
+            bypass_priority: Some(NotificationPriority::Critical),
+        }
+    }
+}
+
+impl ThrottleConfig {
+    /// 중복 억제 구간을 설정합니다.
+    pub fn with_dedup_window(mut self, window: Duration) -> Self {
+        self.dedup_window = window;
+        self
+    }
+
+    /// 채널별 속도 제한을 설정합니다.
+    pub fn with_rate_limit(mut self, max_per_window: usize, window: Duration) -> Self {
+        self.max_per_window = Some(max_per_window);
+        self.rate_window = window;
+        self
+    }
+
+    /// 채널별 속도 제한을 해제합니다.
+    pub fn without_rate_limit(mut self) -> Self {
+        self.max_per_window = None;
+        self
+    }
+
+    /// 억제된 알림 요약 전송 여부를 설정합니다.
+    pub fn with_summary(mut self, enabled: bool) -> Self {
+        self.summarize_suppressed = enabled;
+        self
+    }
+
+    /// 억제/제한에서 제외할 최소 우선순위를 설정합니다.
+    pub fn with_bypass_priority(mut self, priority: Option<NotificationPriority>) -> Self {
+        self.bypass_priority = priority;
+        self
+    }
+
+    /// 알림이 억제/제한 대상에서 제외되는지 확인합니다.
+    fn bypasses(&self, priority: NotificationPriority) -> bool {
+        self.bypass_priority.is_some_and(|min| priority >= min)
+    }
+}
+
+/// 억제 판정 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleDecision {
+    /// 전송
+    Send,
+    /// 구간 내 중복으로 억제
+    Duplicate,
+    /// 채널 속도 제한으로 억제
+    RateLimited,
+}
+
+/// 동일 내용 알림 추적 상태.
+#[derive(Debug)]
+struct DedupEntry {
+    /// 마지막으로 전송된 시각 (억제 구간 시작)
+    window_start: Instant,
+    /// 구간 내 억제된 건수
+    suppressed: usize,
+    /// 요약 문구에 사용할 알림 제목
+    label: String,
+    /// 원본 우선순위
+    priority: NotificationPriority,
+}
+
+/// 채널별 속도 제한 상태.
+#[derive(Debug, Default)]
+struct ChannelState {
+    /// 구간 내 전송 시각
+    sent: VecDeque<Instant>,
+    /// 속도 제한으로 생략된 건수
+    dropped: usize,
+}
+
+#[derive(Debug, Default)]
+struct ThrottleState {
+    dedup: HashMap<String, DedupEntry>,
+    channels: HashMap<String, ChannelState>,
+}
+
+/// 알림 중복 억제/속도 제한기.
+#[derive(Debug)]
+pub struct NotificationThrottle {
+    config: ThrottleConfig,
+    state: Mutex<ThrottleState>,
+}
+
+impl NotificationThrottle {
+    /// 새 억제기를 생성합니다.
+    pub fn new(config: ThrottleConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(ThrottleState::default()),
+        }
+    }
+
+    /// 설정 조회.
+    pub fn config(&self) -> &ThrottleConfig {
+        &self.config
+    }
+
+    /// 중복 여부를 판정합니다.
+    ///
+    /// 억제 구간 내 동일 내용이면 건수만 집계하고 [`ThrottleDecision::Duplicate`]를 반환합니다.
+    /// 구간이 끝난 항목은 새 구간으로 교체되므로, 요약이 필요하면 먼저
+    /// [`drain_summaries`](Self::drain_summaries)를 호출해야 합니다.
+    pub fn check_duplicate(&self, notification: &Notification, now: Instant) -> ThrottleDecision {
+        if self.config.dedup_window.is_zero() || self.config.bypasses(notification.priority) {
+            return ThrottleDecision::Send;
+        }
+
+        let key = content_key(&notification.event);
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if let Some(entry) = state.dedup.get_mut(&key) {
+            if now.duration_since(entry.window_start) < self.config.dedup_window {
+                entry.suppressed += 1;
+                return ThrottleDecision::Duplicate;
+            }
+        }
+
+        state.dedup.insert(
+            key,
+            DedupEntry {
+                window_start: now,
+                suppressed: 0,
+                label: event_label(&notification.event),
+                priority: notification.priority,
+            },
+        );
+        ThrottleDecision::Send
+    }
+
+    /// 채널 속도 제한을 확인하고, 허용되면 전송 슬롯을 차지합니다.
+    pub fn acquire_channel(
+        &self,
+        channel: &str,
+        priority: NotificationPriority,
+        now: Instant,
+    ) -> ThrottleDecision {
+        let Some(max) = self.config.max_per_window else {
+            return ThrottleDecision::Send;
+        };
+        if self.config.bypasses(priority) {
+            return ThrottleDecision::Send;
+        }
+
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let channel_state = state.channels.entry(channel.to_string()).or_default();
+        while channel_state
+            .sent
+            .front()
+            .is_some_and(|sent| now.duration_since(*sent) >= self.config.rate_window)
+        {
+            channel_state.sent.pop_front();
+        }
+
+        if channel_state.sent.len() >= max {
+            channel_state.dropped += 1;
+            return ThrottleDecision::RateLimited;
+        }
+        channel_state.sent.push_back(now);
+        ThrottleDecision::Send
+    }
+
+    /// 억제 구간이 끝난 중복 알림의 요약을 생성합니다.
+    ///
+    /// 요약 옵션이 꺼져 있으면 집계만 초기화하고 빈 목록을 반환합니다.
+    /// 주기적으로(또는 알림 전송 시) 호출해야 합니다.
+    pub fn drain_summaries(&self, now: Instant) -> Vec<Notification> {
+        let window = self.config.dedup_window;
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut summaries = Vec::new();
+
+        state.dedup.retain(|_, entry| {
+            let elapsed = now.duration_since(entry.window_start);
+            if elapsed < window {
+                return true;
+            }
+            if entry.suppressed > 0 && self.config.summarize_suppressed {
+                summaries.push(
+                    Notification::new(NotificationEvent::Custom {
+                        title: format!("반복 알림 요약: {}", entry.label),
+                        message: format!(
+                            "지난 {} 동안 동일 알림 {}건이 억제되었습니다.",
+                            format_window(window),
+                            entry.suppressed
+                        ),
+                    })
+                    .with_priority(entry.priority),
+                );
+            }
+            false
+        });
+
+        summaries
+    }
+
+    /// 속도 제한으로 생략된 채널별 건수를 반환하고 초기화합니다.
+    pub fn take_dropped(&self, channel: &str) -> usize {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        state
+            .channels
+            .get_mut(channel)
+            .map(|c| std::mem::take(&mut c.dropped))
+            .unwrap_or(0)
+    }
+}
+
+/// 알림 내용 키 (이벤트 본문 직렬화, ID/타임스탬프 제외).
+fn content_key(event: &NotificationEvent) -> String {
+    serde_json::to_string(event).unwrap_or_else(|_| format!("{:?}", event))
+}
+
+/// 요약 문구에 사용할 이벤트 설명.
+fn event_label(event: &NotificationEvent) -> String {
+    match event {
+        NotificationEvent::SignalAlert {
+            signal_type,
+            symbol,
+            ..
+        } => format!("{} {}", signal_type, symbol),
+        NotificationEvent::OrderFilled { symbol, .. }
+        | NotificationEvent::PositionOpened { symbol, .. }
+        | NotificationEvent::PositionClosed { symbol, .. }
+        | NotificationEvent::StopLossTriggered { symbol, .. }
+        | NotificationEvent::TakeProfitTriggered { symbol, .. }
+        | NotificationEvent::RouteStateChanged { symbol, .. } => {
+            format!("{} {}", event.kind(), symbol)
+        }
+        NotificationEvent::RiskAlert { alert_type, .. } => format!("risk_alert {}", alert_type),
+        NotificationEvent::SystemError { error_code, .. } => {
+            format!("system_error {}", error_code)
+        }
+        NotificationEvent::Custom { title, .. } => title.clone(),
+        _ => event.kind().to_string(),
+    }
+}
+
+/// 구간 표시 ("5분", "30초").
+fn format_window(window: Duration) -> String {
+    let secs = window.as_secs();
+    if secs >= 60 && secs % 60 == 0 {
+        format!("{}분", secs / 60)
+    } else {
+        format!("{}초", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(priority: NotificationPriority) -> Notification {
+        Notification::new(NotificationEvent::SystemError {
+            error_code: "E001".to_string(),
+            message: "반복".to_string(),
+        })
+        .with_priority(priority)
+    }
+
+    #[test]
+    fn test_duplicate_suppressed_and_summarized() {
+        let throttle = NotificationThrottle::new(
+            ThrottleConfig::default().with_dedup_window(Duration::from_secs(300)),
+        );
+        let start = Instant::now();
+
+        assert_eq!(
+            throttle.check_duplicate(&signal(NotificationPriority::Normal), start),
+            ThrottleDecision::Send
+        );
+        for i in 1..=12 {
+            assert_eq!(
+                throttle.check_duplicate(
+                    &signal(NotificationPriority::Normal),
+                    start + Duration::from_secs(i)
+                ),
+                ThrottleDecision::Duplicate
+            );
+        }
+
+        // 구간이 끝나기 전에는 요약 없음
+        assert!(throttle
+            .drain_summaries(start + Duration::from_secs(100))
+            .is_empty());
+
+        let summaries = throttle.drain_summaries(start + Duration::from_secs(300));
+        assert_eq!(summaries.len(), 1);
+        match &summaries[0].event {
+            NotificationEvent::Custom { message, .. } => {
+                assert!(message.contains("지난 5분"));
+                assert!(message.contains("12건"));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        // 요약 후 새 구간 시작
+        assert_eq!(
+            throttle.check_duplicate(
+                &signal(NotificationPriority::Normal),
+                start + Duration::from_secs(301)
+            ),
+            ThrottleDecision::Send
+        );
+    }
+
+    #[test]
+    fn test_critical_bypasses_throttle() {
+        let throttle = NotificationThrottle::new(
+            ThrottleConfig::default().with_rate_limit(1, Duration::from_secs(60)),
+        );
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert_eq!(
+                throttle.check_duplicate(&signal(NotificationPriority::Critical), now),
+                ThrottleDecision::Send
+            );
+            assert_eq!(
+                throttle.acquire_channel("telegram", NotificationPriority::Critical, now),
+                ThrottleDecision::Send
+            );
+        }
+
+        // 우선순위 규칙 해제 시 critical도 억제
+        let strict =
+            NotificationThrottle::new(ThrottleConfig::default().with_bypass_priority(None));
+        strict.check_duplicate(&signal(NotificationPriority::Critical), now);
+        assert_eq!(
+            strict.check_duplicate(&signal(NotificationPriority::Critical), now),
+            ThrottleDecision::Duplicate
+        );
+    }
+
+    #[test]
+    fn test_channel_rate_limit_window() {
+        let throttle = NotificationThrottle::new(
+            ThrottleConfig::default().with_rate_limit(2, Duration::from_secs(60)),
+        );
+        let start = Instant::now();
+        let normal = NotificationPriority::Normal;
+
+        assert_eq!(
+            throttle.acquire_channel("telegram", normal, start),
+            ThrottleDecision::Send
+        );
+        assert_eq!(
+            throttle.acquire_channel("telegram", normal, start),
+            ThrottleDecision::Send
+        );
+        assert_eq!(
+            throttle.acquire_channel("telegram", normal, start),
+            ThrottleDecision::RateLimited
+        );
+        // 다른 채널은 독립
+        assert_eq!(
+            throttle.acquire_channel("slack", normal, start),
+            ThrottleDecision::Send
+        );
+        assert_eq!(throttle.take_dropped("telegram"), 1);
+        assert_eq!(throttle.take_dropped("telegram"), 0);
+
+        assert_eq!(
+            throttle.acquire_channel("telegram", normal, start + Duration::from_secs(60)),
+            ThrottleDecision::Send
+        );
+    }
+}
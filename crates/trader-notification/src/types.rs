@@ -6,8 +6,8 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use trader_core::SignalMarker;
 
-/// 알림 우선순위 레벨.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// 알림 우선순위 레벨 (`Low` < `Critical` 순으로 비교).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[derive(Default)]
 pub enum NotificationPriority {
@@ -134,6 +134,29 @@ pub enum NotificationEvent {
     },
 }
 
+impl NotificationEvent {
+    /// 이벤트 유형 이름 (serde 태그와 동일, 예: `order_filled`).
+    pub fn kind(&self) -> &'static str {
+        match self {
+            NotificationEvent::OrderFilled { .. } => "order_filled",
+            NotificationEvent::PositionOpened { .. } => "position_opened",
+            NotificationEvent::PositionClosed { .. } => "position_closed",
+            NotificationEvent::StopLossTriggered { .. } => "stop_loss_triggered",
+            NotificationEvent::TakeProfitTriggered { .. } => "take_profit_triggered",
+            NotificationEvent::DailySummary { .. } => "daily_summary",
+            NotificationEvent::RiskAlert { .. } => "risk_alert",
+            NotificationEvent::StrategyStarted { .. } => "strategy_started",
+            NotificationEvent::StrategyStopped { .. } => "strategy_stopped",
+            NotificationEvent::SystemError { .. } => "system_error",
+            NotificationEvent::SignalAlert { .. } => "signal_alert",
+            NotificationEvent::Custom { .. } => "custom",
+            NotificationEvent::RouteStateChanged { .. } => "route_state_changed",
+            NotificationEvent::MacroAlert { .. } => "macro_alert",
+            NotificationEvent::MarketBreadthAlert { .. } => "market_breadth_alert",
+        }
+    }
+}
+
 impl From<SignalMarker> for NotificationEvent {
     /// SignalMarker를 알림 이벤트로 변환합니다.
     ///
@@ -213,6 +236,12 @@ pub enum NotificationError {
 
     #[error("직렬화 에러: {0}")]
     SerializationError(#[from] serde_json::Error),
+
+    #[error("중복 전송 방지 에러: {0}")]
+    Idempotency(#[from] trader_core::IdempotencyError),
+
+    #[error("템플릿 에러: {0}")]
+    Template(#[from] crate::template::TemplateError),
 }
 
 /// 알림 전송기 trait.
@@ -0,0 +1,695 @@
+//! 범용 Webhook 알림 서비스.
+//!
+//! 임의의 HTTP 엔드포인트로 알림을 JSON으로 POST합니다.
+//!
+//! # Payload
+//!
+//! 템플릿이 없으면 다음 형식의 기본 payload를 전송합니다.
+//!
+//! ```json
+//! {
+//!   "id": "...",
+//!   "event_type": "order_filled",
+//!   "priority": "normal",
+//!   "timestamp": "2024-01-02T00:00:00+00:00",
+//!   "title": "주문 체결",
+//!   "message": "BTC-USDT Buy 0.1 @ 50000",
+//!   "event": { "type": "order_filled", ... },
+//!   "metadata": null
+//! }
+//! ```
+//!
+//! 템플릿은 JSON 문자열이며 다음 플레이스홀더를 치환합니다.
+//! 문자열 값은 JSON 이스케이프되어 들어가므로 따옴표 안에 사용합니다.
+//!
+//! - `{{id}}`, `{{event_type}}`, `{{priority}}`, `{{timestamp}}`, `{{title}}`, `{{message}}`
+//! - `{{event}}`, `{{metadata}}` - JSON 값 그대로 삽입 (따옴표 없이 사용)
+//!
+//! # 서명
+//!
+//! HMAC 시크릿을 설정하면 `{timestamp}.{body}`에 대한 HMAC-SHA256 서명을
+//! `X-Webhook-Signature: sha256={hex}` 헤더로, 서명 시각(Unix 초)을
+//! `X-Webhook-Timestamp` 헤더로 함께 전송합니다.
+
+use std::{collections::BTreeMap, time::Duration};
+
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use tracing::{debug, error, info, warn};
+
+use crate::types::{
+    Notification, NotificationError, NotificationEvent, NotificationPriority, NotificationResult,
+    NotificationSender,
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 서명 헤더 이름
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+/// 서명 시각 헤더 이름
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+
+/// 기본 요청 타임아웃 (초)
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+/// 기본 최대 재시도 횟수
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// 기본 재시도 대기 시간 (지수 백오프 기준값)
+const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Webhook 알림 전송 설정.
+#[derive(Clone)]
+pub struct WebhookConfig {
+    /// POST 대상 URL
+    pub url: String,
+    /// 요청에 추가할 커스텀 헤더
+    pub headers: BTreeMap<String, String>,
+    /// payload 템플릿 (없으면 기본 payload)
+    pub payload_template: Option<String>,
+    /// HMAC-SHA256 서명 시크릿 (없으면 서명하지 않음)
+    pub hmac_secret: Option<String>,
+    /// 요청 타임아웃
+    pub timeout: Duration,
+    /// 최대 재시도 횟수 (첫 시도 제외)
+    pub max_retries: u32,
+    /// 재시도 대기 시간 (시도마다 2배씩 증가)
+    pub retry_backoff: Duration,
+    /// 표시 이름 (메타데이터용)
+    pub display_name: Option<String>,
+    /// 전송 활성화 여부
+    pub enabled: bool,
+}
+
+impl std::fmt::Debug for WebhookConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // URL/헤더/시크릿에는 토큰이 포함될 수 있으므로 값은 출력하지 않음
+        f.debug_struct("WebhookConfig")
+            .field("headers", &self.headers.keys().collect::<Vec<_>>())
+            .field("has_payload_template", &self.payload_template.is_some())
+            .field("has_hmac_secret", &self.hmac_secret.is_some())
+            .field("timeout", &self.timeout)
+            .field("max_retries", &self.max_retries)
+            .field("display_name", &self.display_name)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl WebhookConfig {
+    /// 새 Webhook 설정을 생성합니다.
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            headers: BTreeMap::new(),
+            payload_template: None,
+            hmac_secret: None,
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_backoff: DEFAULT_RETRY_BACKOFF,
+            display_name: None,
+            enabled: true,
+        }
+    }
+
+    /// 커스텀 헤더를 추가합니다.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// payload 템플릿을 설정합니다.
+    pub fn with_payload_template(mut self, template: String) -> Self {
+        self.payload_template = Some(template);
+        self
+    }
+
+    /// HMAC 서명 시크릿을 설정합니다.
+    pub fn with_hmac_secret(mut self, secret: String) -> Self {
+        self.hmac_secret = Some(secret);
+        self
+    }
+
+    /// 요청 타임아웃을 설정합니다.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 재시도 정책을 설정합니다.
+    pub fn with_retry(mut self, max_retries: u32, backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// 표시 이름을 설정합니다.
+    pub fn with_display_name(mut self, name: String) -> Self {
+        self.display_name = Some(name);
+        self
+    }
+
+    /// 설정을 검증합니다.
+    ///
+    /// URL 스킴, 헤더 이름/값, 템플릿 렌더링 결과가 유효한 JSON인지 확인합니다.
+    pub fn validate(&self) -> NotificationResult<()> {
+        if !(self.url.starts_with("https://") || self.url.starts_with("http://")) {
+            return Err(NotificationError::InvalidConfig(
+                "Webhook URL은 http:// 또는 https://로 시작해야 합니다".to_string(),
+            ));
+        }
+
+        for (name, value) in &self.headers {
+            reqwest::header::HeaderName::from_bytes(name.as_bytes()).map_err(|_| {
+                NotificationError::InvalidConfig(format!("잘못된 헤더 이름: {}", name))
+            })?;
+            reqwest::header::HeaderValue::from_str(value).map_err(|_| {
+                NotificationError::InvalidConfig(format!("잘못된 헤더 값: {}", name))
+            })?;
+        }
+
+        if let Some(template) = &self.payload_template {
+            let sample = Notification::new(NotificationEvent::Custom {
+                title: "test".to_string(),
+                message: "test".to_string(),
+            });
+            render_template(template, &sample)?;
+        }
+
+        Ok(())
+    }
+
+    /// 환경 변수에서 설정을 생성합니다.
+    ///
+    /// - `WEBHOOK_URL` (필수)
+    /// - `WEBHOOK_SECRET` - HMAC 서명 시크릿
+    /// - `WEBHOOK_TIMEOUT_SECS`, `WEBHOOK_MAX_RETRIES`
+    /// - `WEBHOOK_ENABLED` (기본: true)
+    pub fn from_env() -> Option<Self> {
+        let url = std::env::var("WEBHOOK_URL").ok()?;
+        let mut config = Self::new(url);
+
+        config.hmac_secret = std::env::var("WEBHOOK_SECRET").ok();
+        if let Some(secs) = std::env::var("WEBHOOK_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            config.timeout = Duration::from_secs(secs);
+        }
+        if let Some(retries) = std::env::var("WEBHOOK_MAX_RETRIES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            config.max_retries = retries;
+        }
+        config.enabled = std::env::var("WEBHOOK_ENABLED")
+            .map(|v| v.to_lowercase() == "true")
+            .unwrap_or(true);
+
+        Some(config)
+    }
+}
+
+/// 범용 Webhook 알림 전송기.
+pub struct WebhookSender {
+    config: WebhookConfig,
+    client: reqwest::Client,
+}
+
+impl WebhookSender {
+    /// 새 Webhook 전송기를 생성합니다.
+    pub fn new(config: WebhookConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .unwrap_or_default();
+        Self { config, client }
+    }
+
+    /// 환경 변수에서 전송기를 생성합니다.
+    pub fn from_env() -> Option<Self> {
+        WebhookConfig::from_env().map(Self::new)
+    }
+
+    /// 알림을 payload로 변환합니다.
+    fn build_payload(&self, notification: &Notification) -> NotificationResult<Value> {
+        match &self.config.payload_template {
+            Some(template) => render_template(template, notification),
+            None => Ok(default_payload(notification)),
+        }
+    }
+
+    /// 요청 본문에 대한 서명 헤더 값을 계산합니다.
+    fn sign(&self, timestamp: i64, body: &[u8]) -> Option<String> {
+        let secret = self.config.hmac_secret.as_ref()?;
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).ok()?;
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(body);
+        Some(format!(
+            "sha256={}",
+            hex::encode(mac.finalize().into_bytes())
+        ))
+    }
+
+    /// payload를 전송합니다. 실패 시 재시도합니다.
+    ///
+    /// 네트워크 오류(타임아웃 포함), 429, 5xx 응답만 재시도하며
+    /// 그 외 4xx 응답은 즉시 실패로 처리합니다.
+    async fn send_payload(&self, payload: &Value) -> NotificationResult<()> {
+        let body = serde_json::to_vec(payload)?;
+        let mut attempt = 0;
+
+        loop {
+            match self.send_once(&body).await {
+                Ok(()) => {
+                    info!("Webhook 알림 전송 완료");
+                    return Ok(());
+                }
+                Err((error, retryable)) => {
+                    if !retryable || attempt >= self.config.max_retries {
+                        error!("Webhook 전송 실패 ({}회 시도): {}", attempt + 1, error);
+                        return Err(error);
+                    }
+                    let delay = self.config.retry_backoff * 2u32.saturating_pow(attempt);
+                    warn!(
+                        "Webhook 전송 실패, {:?} 후 재시도 ({}/{}): {}",
+                        delay,
+                        attempt + 1,
+                        self.config.max_retries,
+                        error
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// 단일 요청을 전송합니다. 에러와 함께 재시도 가능 여부를 반환합니다.
+    async fn send_once(&self, body: &[u8]) -> Result<(), (NotificationError, bool)> {
+        debug!("Sending webhook message");
+
+        let mut request = self
+            .client
+            .post(&self.config.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        for (name, value) in &self.config.headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+
+        let timestamp = chrono::Utc::now().timestamp();
+        if let Some(signature) = self.sign(timestamp, body) {
+            request = request
+                .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+                .header(WEBHOOK_SIGNATURE_HEADER, signature);
+        }
+
+        let response = request
+            .body(body.to_vec())
+            .send()
+            .await
+            .map_err(|e| (NotificationError::NetworkError(e), true))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+
+        if status.as_u16() == 429 {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(60);
+            return Err((NotificationError::RateLimited(retry_after), true));
+        }
+
+        let body = response.text().await.unwrap_or_default();
+        Err((
+            NotificationError::SendFailed(format!("HTTP {}: {}", status, body)),
+            status.is_server_error(),
+        ))
+    }
+
+    /// 테스트 메시지를 전송합니다.
+    pub async fn send_test(&self) -> NotificationResult<()> {
+        let notification = Notification::new(NotificationEvent::Custom {
+            title: "✓ Webhook 알림 설정 완료".to_string(),
+            message: "ZeroQuant 트레이딩 봇의 Webhook 알림이 정상적으로 설정되었습니다."
+                .to_string(),
+        })
+        .with_priority(NotificationPriority::Low);
+
+        let payload = self.build_payload(&notification)?;
+        self.send_payload(&payload).await
+    }
+}
+
+#[async_trait]
+impl NotificationSender for WebhookSender {
+    async fn send(&self, notification: &Notification) -> NotificationResult<()> {
+        if !self.is_enabled() {
+            debug!("Webhook 알림이 비활성화되어 있습니다");
+            return Ok(());
+        }
+
+        let payload = self.build_payload(notification)?;
+        self.send_payload(&payload).await
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.enabled && !self.config.url.is_empty()
+    }
+
+    fn name(&self) -> &str {
+        "webhook"
+    }
+}
+
+/// 이벤트 타입 문자열 (serde 태그와 동일, 예: `order_filled`).
+fn event_type(event_json: &Value) -> String {
+    event_json["type"].as_str().unwrap_or("unknown").to_string()
+}
+
+/// 우선순위 문자열.
+fn priority_str(priority: &NotificationPriority) -> &'static str {
+    match priority {
+        NotificationPriority::Low => "low",
+        NotificationPriority::Normal => "normal",
+        NotificationPriority::High => "high",
+        NotificationPriority::Critical => "critical",
+    }
+}
+
+/// 알림 이벤트의 제목과 요약 메시지.
+fn summarize(event: &NotificationEvent) -> (String, String) {
+    match event {
+        NotificationEvent::OrderFilled {
+            symbol,
+            side,
+            quantity,
+            price,
+            ..
+        } => (
+            "주문 체결".to_string(),
+            format!("{} {} {} @ {}", symbol, side, quantity, price),
+        ),
+        NotificationEvent::PositionOpened {
+            symbol,
+            side,
+            quantity,
+            entry_price,
+        } => (
+            "포지션 진입".to_string(),
+            format!("{} {} {} @ {}", symbol, side, quantity, entry_price),
+        ),
+        NotificationEvent::PositionClosed {
+            symbol,
+            pnl,
+            pnl_percent,
+            ..
+        } => (
+            "포지션 청산".to_string(),
+            format!("{} 손익 {} ({}%)", symbol, pnl, pnl_percent),
+        ),
+        NotificationEvent::StopLossTriggered {
+            symbol,
+            trigger_price,
+            loss,
+            ..
+        } => (
+            "손절 발동".to_string(),
+            format!("{} @ {} 손실 -{}", symbol, trigger_price, loss),
+        ),
+        NotificationEvent::TakeProfitTriggered {
+            symbol,
+            trigger_price,
+            profit,
+            ..
+        } => (
+            "익절 발동".to_string(),
+            format!("{} @ {} 수익 +{}", symbol, trigger_price, profit),
+        ),
+        NotificationEvent::DailySummary {
+            date,
+            total_trades,
+            total_pnl,
+            win_rate,
+            ..
+        } => (
+            "일일 요약".to_string(),
+            format!(
+                "{} 거래 {}건, 손익 {}, 승률 {}%",
+                date, total_trades, total_pnl, win_rate
+            ),
+        ),
+        NotificationEvent::RiskAlert {
+            alert_type,
+            message,
+            ..
+        } => (format!("리스크 경고: {}", alert_type), message.clone()),
+        NotificationEvent::StrategyStarted { strategy_name, .. } => {
+            ("전략 시작".to_string(), strategy_name.clone())
+        }
+        NotificationEvent::StrategyStopped {
+            strategy_name,
+            reason,
+            ..
+        } => (
+            "전략 중지".to_string(),
+            format!("{}: {}", strategy_name, reason),
+        ),
+        NotificationEvent::SystemError {
+            error_code,
+            message,
+        } => (
+            "시스템 오류".to_string(),
+            format!("[{}] {}", error_code, message),
+        ),
+        NotificationEvent::SignalAlert {
+            signal_type,
+            symbol,
+            price,
+            reason,
+            strategy_name,
+            ..
+        } => (
+            format!("{} 신호", signal_type),
+            format!("{} {} @ {} - {}", strategy_name, symbol, price, reason),
+        ),
+        NotificationEvent::Custom { title, message } => (title.clone(), message.clone()),
+        NotificationEvent::RouteStateChanged {
+            symbol,
+            previous_state,
+            new_state,
+            ..
+        } => (
+            "RouteState 변경".to_string(),
+            format!("{}: {} → {}", symbol, previous_state, new_state),
+        ),
+        NotificationEvent::MacroAlert {
+            risk_level,
+            recommendation,
+            ..
+        } => (
+            format!("매크로 환경 경고: {}", risk_level),
+            recommendation.clone(),
+        ),
+        NotificationEvent::MarketBreadthAlert {
+            temperature,
+            recommendation,
+            ..
+        } => (
+            format!("시장 온도: {}", temperature),
+            recommendation.clone(),
+        ),
+    }
+}
+
+/// 기본 payload를 생성합니다.
+fn default_payload(notification: &Notification) -> Value {
+    let event = serde_json::to_value(&notification.event).unwrap_or(Value::Null);
+    let (title, message) = summarize(&notification.event);
+
+    json!({
+        "id": notification.id,
+        "event_type": event_type(&event),
+        "priority": priority_str(&notification.priority),
+        "timestamp": notification.timestamp.to_rfc3339(),
+        "title": title,
+        "message": message,
+        "event": event,
+        "metadata": notification.metadata,
+    })
+}
+
+/// JSON 문자열 내부에 들어갈 수 있도록 이스케이프합니다 (양쪽 따옴표 제외).
+fn escape_json_str(value: &str) -> String {
+    let quoted = Value::String(value.to_string()).to_string();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+/// payload 템플릿을 렌더링합니다.
+///
+/// 렌더링 결과가 유효한 JSON이 아니면 `InvalidConfig` 에러를 반환합니다.
+fn render_template(template: &str, notification: &Notification) -> NotificationResult<Value> {
+    let event = serde_json::to_value(&notification.event)?;
+    let (title, message) = summarize(&notification.event);
+
+    let rendered = template
+        .replace("{{id}}", &escape_json_str(&notification.id))
+        .replace("{{event_type}}", &escape_json_str(&event_type(&event)))
+        .replace("{{priority}}", priority_str(&notification.priority))
+        .replace(
+            "{{timestamp}}",
+            &escape_json_str(&notification.timestamp.to_rfc3339()),
+        )
+        .replace("{{title}}", &escape_json_str(&title))
+        .replace("{{message}}", &escape_json_str(&message))
+        .replace("{{event}}", &event.to_string())
+        .replace("{{metadata}}", &notification.metadata.to_string());
+
+    serde_json::from_str(&rendered).map_err(|e| {
+        NotificationError::InvalidConfig(format!("payload 템플릿이 유효한 JSON이 아닙니다: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn order_filled() -> Notification {
+        Notification::new(NotificationEvent::OrderFilled {
+            symbol: "BTC-USDT".to_string(),
+            side: "Buy".to_string(),
+            quantity: dec!(0.1),
+            price: dec!(50000),
+            order_id: "order-1".to_string(),
+        })
+    }
+
+    fn fast_config(url: String) -> WebhookConfig {
+        WebhookConfig::new(url).with_retry(2, Duration::from_millis(1))
+    }
+
+    #[test]
+    fn test_default_payload() {
+        let payload = default_payload(&order_filled());
+
+        assert_eq!(payload["event_type"], "order_filled");
+        assert_eq!(payload["priority"], "normal");
+        assert_eq!(payload["title"], "주문 체결");
+        assert_eq!(payload["event"]["symbol"], "BTC-USDT");
+    }
+
+    #[test]
+    fn test_render_template_escapes_strings() {
+        let notification = Notification::new(NotificationEvent::Custom {
+            title: "say \"hi\"".to_string(),
+            message: "line1\nline2".to_string(),
+        });
+        let template = r#"{"text": "{{title}}: {{message}}", "raw": {{event}}}"#;
+
+        let payload = render_template(template, &notification).unwrap();
+        assert_eq!(payload["text"], "say \"hi\": line1\nline2");
+        assert_eq!(payload["raw"]["type"], "custom");
+
+        assert!(matches!(
+            render_template("{\"text\": {{title}}}", &notification),
+            Err(NotificationError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_config() {
+        assert!(WebhookConfig::new("https://example.com/hook".to_string())
+            .with_header("Authorization", "Bearer token")
+            .validate()
+            .is_ok());
+        assert!(WebhookConfig::new("ftp://example.com".to_string())
+            .validate()
+            .is_err());
+        assert!(WebhookConfig::new("https://example.com".to_string())
+            .with_header("bad header", "x")
+            .validate()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_send_with_headers_and_signature() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .match_header("authorization", "Bearer token")
+            .match_header(
+                "x-webhook-signature",
+                mockito::Matcher::Regex("^sha256=[0-9a-f]{64}$".to_string()),
+            )
+            .match_header("x-webhook-timestamp", mockito::Matcher::Any)
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let config = fast_config(format!("{}/hook", server.url()))
+            .with_header("Authorization", "Bearer token")
+            .with_hmac_secret("secret".to_string());
+        let sender = WebhookSender::new(config);
+
+        sender.send(&order_filled()).await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_signature_matches_hmac_of_timestamp_and_body() {
+        let sender = WebhookSender::new(
+            WebhookConfig::new("https://example.com".to_string())
+                .with_hmac_secret("secret".to_string()),
+        );
+
+        let mut mac = HmacSha256::new_from_slice(b"secret").unwrap();
+        mac.update(b"1700000000.{}");
+        let expected = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+
+        assert_eq!(sender.sign(1_700_000_000, b"{}"), Some(expected));
+
+        let unsigned = WebhookSender::new(WebhookConfig::new("https://example.com".to_string()));
+        assert_eq!(unsigned.sign(1_700_000_000, b"{}"), None);
+    }
+
+    #[tokio::test]
+    async fn test_retries_server_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let sender = WebhookSender::new(fast_config(format!("{}/hook", server.url())));
+
+        let result = sender.send(&order_filled()).await;
+        assert!(matches!(result, Err(NotificationError::SendFailed(_))));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_client_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .with_status(400)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let sender = WebhookSender::new(fast_config(format!("{}/hook", server.url())));
+
+        assert!(sender.send(&order_filled()).await.is_err());
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,357 @@
+//! 일일 손실 서킷브레이커.
+//!
+//! 실현 손익과 미실현 손익을 합산한 당일 손실이 당일 시작 자본 대비
+//! `daily_loss_limit_pct`를 넘으면 그날의 모든 신규 진입을 차단합니다.
+//! 청산 주문은 차단하지 않습니다.
+//!
+//! - 시장 시간대의 자정에 카운터가 초기화됩니다 ([`TradingTimezone::local_date`]).
+//! - 미실현 손익은 진입 이후 누적값으로 보고되므로, 자정 시점의 값을 기준선으로 두고
+//!   당일 변동분만 손실에 반영합니다 (전날부터 보유한 손실을 이중 계산하지 않음).
+//! - 한 번 발동하면 손실이 회복되어도 당일 동안 차단이 유지됩니다.
+//! - 발동 시점에 [`CircuitBreakerHook`]이 한 번 호출됩니다 (예: 알림 전송).
+
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::prelude::*;
+use serde::Serialize;
+
+use crate::limits::TradingTimezone;
+
+/// 서킷브레이커 발동 정보.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DailyLossBreach {
+    /// 발동된 거래일 (시장 시간대 기준)
+    pub trading_date: NaiveDate,
+    /// 당일 시작 자본
+    pub day_start_equity: Decimal,
+    /// 당일 실현 손익
+    pub realized_pnl: Decimal,
+    /// 당일 미실현 손익 변동 (자정 기준선 대비)
+    pub unrealized_pnl: Decimal,
+    /// 시작 자본 대비 손실 비율 (%)
+    pub loss_pct: f64,
+    /// 설정된 한도 (%)
+    pub limit_pct: f64,
+    /// 발동 시각
+    pub triggered_at: DateTime<Utc>,
+}
+
+impl DailyLossBreach {
+    /// 실현+미실현 합산 손실 (양수).
+    pub fn total_loss(&self) -> Decimal {
+        -(self.realized_pnl + self.unrealized_pnl).min(Decimal::ZERO)
+    }
+}
+
+/// 서킷브레이커 에러.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CircuitBreakerError {
+    #[error(
+        "Daily loss circuit breaker tripped on {}: loss {:.2}% exceeds limit {:.2}% (realized {}, unrealized {})",
+        .0.trading_date, .0.loss_pct, .0.limit_pct, .0.realized_pnl, .0.unrealized_pnl
+    )]
+    DailyLossLimitExceeded(DailyLossBreach),
+}
+
+impl CircuitBreakerError {
+    /// 발동 정보 조회.
+    pub fn breach(&self) -> &DailyLossBreach {
+        match self {
+            CircuitBreakerError::DailyLossLimitExceeded(breach) => breach,
+        }
+    }
+}
+
+/// 서킷브레이커 발동 시 호출되는 훅.
+///
+/// 리스크 검증 경로에서 동기적으로 호출되므로, 네트워크 전송 등은
+/// 구현체가 별도 태스크로 넘겨야 합니다.
+pub trait CircuitBreakerHook: Send + Sync {
+    /// 서킷브레이커가 발동되었을 때 호출됩니다.
+    fn on_trip(&self, breach: &DailyLossBreach);
+}
+
+impl<F> CircuitBreakerHook for F
+where
+    F: Fn(&DailyLossBreach) + Send + Sync,
+{
+    fn on_trip(&self, breach: &DailyLossBreach) {
+        self(breach)
+    }
+}
+
+/// 일일 손실 서킷브레이커.
+#[derive(Clone)]
+pub struct DailyLossCircuitBreaker {
+    /// 손실 한도 (%)
+    limit_pct: f64,
+    /// 초기화 기준 시간대
+    timezone: TradingTimezone,
+    /// 현재 거래일
+    trading_date: NaiveDate,
+    /// 당일 시작 자본
+    day_start_equity: Decimal,
+    /// 당일 실현 손익
+    realized_pnl: Decimal,
+    /// 현재 미실현 손익 (진입 이후 누적)
+    unrealized_pnl: Decimal,
+    /// 거래일 시작 시점의 미실현 손익 (당일 변동 계산 기준선)
+    unrealized_baseline: Decimal,
+    /// 발동 정보 (발동 시 당일 유지)
+    tripped: Option<DailyLossBreach>,
+    /// 발동 훅
+    hook: Option<Arc<dyn CircuitBreakerHook>>,
+}
+
+impl std::fmt::Debug for DailyLossCircuitBreaker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DailyLossCircuitBreaker")
+            .field("limit_pct", &self.limit_pct)
+            .field("timezone", &self.timezone)
+            .field("trading_date", &self.trading_date)
+            .field("day_start_equity", &self.day_start_equity)
+            .field("realized_pnl", &self.realized_pnl)
+            .field("unrealized_pnl", &self.unrealized_pnl)
+            .field("unrealized_baseline", &self.unrealized_baseline)
+            .field("tripped", &self.tripped)
+            .field("hook", &self.hook.is_some())
+            .finish()
+    }
+}
+
+impl DailyLossCircuitBreaker {
+    /// 새 서킷브레이커 생성.
+    ///
+    /// # Arguments
+    /// * `limit_pct` - 당일 시작 자본 대비 손실 한도 (예: 3%는 3.0)
+    /// * `timezone` - 자정 초기화 기준 시장 시간대
+    /// * `day_start_equity` - 당일 시작 자본
+    /// * `now` - 현재 시각
+    pub fn new(
+        limit_pct: f64,
+        timezone: TradingTimezone,
+        day_start_equity: Decimal,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            limit_pct,
+            timezone,
+            trading_date: timezone.local_date(now),
+            day_start_equity,
+            realized_pnl: Decimal::ZERO,
+            unrealized_pnl: Decimal::ZERO,
+            unrealized_baseline: Decimal::ZERO,
+            tripped: None,
+            hook: None,
+        }
+    }
+
+    /// 발동 훅 설정.
+    pub fn set_hook(&mut self, hook: Arc<dyn CircuitBreakerHook>) {
+        self.hook = Some(hook);
+    }
+
+    /// 손실 한도 (%).
+    pub fn limit_pct(&self) -> f64 {
+        self.limit_pct
+    }
+
+    /// 현재 거래일.
+    pub fn trading_date(&self) -> NaiveDate {
+        self.trading_date
+    }
+
+    /// 시장 자정이 지났으면 카운터를 초기화합니다.
+    ///
+    /// 새 거래일의 시작 자본은 전날 시작 자본에 전날의 실현 손익과 미실현 손익 변동을 더한 값이며,
+    /// 현재 미실현 손익이 새 거래일의 기준선이 됩니다.
+    ///
+    /// # Returns
+    /// 초기화되었으면 true
+    pub fn roll_over(&mut self, now: DateTime<Utc>) -> bool {
+        let today = self.timezone.local_date(now);
+        if today == self.trading_date {
+            return false;
+        }
+
+        self.day_start_equity += self.realized_pnl + self.day_unrealized_pnl();
+        self.trading_date = today;
+        self.realized_pnl = Decimal::ZERO;
+        self.unrealized_baseline = self.unrealized_pnl;
+        self.tripped = None;
+        true
+    }
+
+    /// 당일 시작 자본 재설정 (잔고 동기화용).
+    pub fn set_day_start_equity(&mut self, equity: Decimal) {
+        self.day_start_equity = equity;
+    }
+
+    /// 실현 손익 기록.
+    pub fn record_realized(&mut self, amount: Decimal, now: DateTime<Utc>) {
+        self.roll_over(now);
+        self.realized_pnl += amount;
+        self.evaluate(now);
+    }
+
+    /// 열린 포지션의 미실현 손익 합계 갱신 (진입 이후 누적값).
+    pub fn update_unrealized(&mut self, total: Decimal, now: DateTime<Utc>) {
+        self.roll_over(now);
+        self.unrealized_pnl = total;
+        self.evaluate(now);
+    }
+
+    /// 당일 미실현 손익 변동 (거래일 시작 기준선 대비).
+    ///
+    /// 당일 청산된 포지션의 전날까지 손익은 실현 손익에 포함되고 미실현 합계에서 빠지므로,
+    /// 실현 손익과 합산하면 당일 변동만 남습니다.
+    pub fn day_unrealized_pnl(&self) -> Decimal {
+        self.unrealized_pnl - self.unrealized_baseline
+    }
+
+    /// 시작 자본 대비 현재 손실 비율 (%).
+    pub fn loss_pct(&self) -> f64 {
+        if self.day_start_equity <= Decimal::ZERO {
+            return 0.0;
+        }
+        let loss = -(self.realized_pnl + self.day_unrealized_pnl()).min(Decimal::ZERO);
+        (loss / self.day_start_equity * Decimal::from(100))
+            .to_f64()
+            .unwrap_or(0.0)
+    }
+
+    /// 한도 초과 여부를 평가하고, 새로 발동되면 훅을 호출합니다.
+    fn evaluate(&mut self, now: DateTime<Utc>) {
+        if self.tripped.is_some() {
+            return;
+        }
+        let loss_pct = self.loss_pct();
+        if loss_pct < self.limit_pct {
+            return;
+        }
+
+        let breach = DailyLossBreach {
+            trading_date: self.trading_date,
+            day_start_equity: self.day_start_equity,
+            realized_pnl: self.realized_pnl,
+            unrealized_pnl: self.day_unrealized_pnl(),
+            loss_pct,
+            limit_pct: self.limit_pct,
+            triggered_at: now,
+        };
+        tracing::warn!(
+            trading_date = %breach.trading_date,
+            loss_pct = breach.loss_pct,
+            limit_pct = breach.limit_pct,
+            "Daily loss circuit breaker tripped"
+        );
+        if let Some(hook) = &self.hook {
+            hook.on_trip(&breach);
+        }
+        self.tripped = Some(breach);
+    }
+
+    /// 신규 진입 허용 여부 확인.
+    pub fn check(&mut self, now: DateTime<Utc>) -> Result<(), CircuitBreakerError> {
+        self.roll_over(now);
+        match &self.tripped {
+            Some(breach) => Err(CircuitBreakerError::DailyLossLimitExceeded(breach.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// 발동 정보 조회 (당일 발동된 경우).
+    pub fn tripped(&self) -> Option<&DailyLossBreach> {
+        self.tripped.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 4, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_trips_on_realized_plus_unrealized_loss() {
+        let trips = Arc::new(AtomicUsize::new(0));
+        let counter = trips.clone();
+        let mut breaker =
+            DailyLossCircuitBreaker::new(3.0, TradingTimezone::Utc, dec!(10000), at(1));
+        breaker.set_hook(Arc::new(move |_: &DailyLossBreach| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        breaker.record_realized(dec!(-200), at(2));
+        assert!(breaker.check(at(2)).is_ok());
+
+        // 실현 -200 + 미실현 -150 = 3.5%
+        breaker.update_unrealized(dec!(-150), at(3));
+        let err = breaker.check(at(3)).unwrap_err();
+        let breach = err.breach();
+        assert_eq!(breach.total_loss(), dec!(350));
+        assert_eq!(breach.realized_pnl, dec!(-200));
+        assert!((breach.loss_pct - 3.5).abs() < 1e-9);
+
+        // 손실이 회복되어도 당일 차단 유지, 훅은 한 번만 호출
+        breaker.update_unrealized(dec!(500), at(4));
+        assert!(breaker.check(at(4)).is_err());
+        assert_eq!(trips.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_resets_at_market_midnight() {
+        // KST 자정 = UTC 15:00
+        let mut breaker =
+            DailyLossCircuitBreaker::new(2.0, TradingTimezone::Kst, dec!(10000), at(1));
+        breaker.record_realized(dec!(-300), at(2));
+        assert!(breaker.check(at(14)).is_err());
+
+        assert!(breaker.check(at(15)).is_ok());
+        assert_eq!(
+            breaker.trading_date(),
+            NaiveDate::from_ymd_opt(2024, 3, 5).unwrap()
+        );
+        // 새 거래일 시작 자본은 전날 손익 반영
+        breaker.record_realized(dec!(-194), at(16));
+        assert!(breaker.check(at(16)).is_err());
+        assert_eq!(breaker.tripped().unwrap().day_start_equity, dec!(9700));
+    }
+
+    #[test]
+    fn test_carried_unrealized_loss_not_double_counted() {
+        let mut breaker =
+            DailyLossCircuitBreaker::new(3.0, TradingTimezone::Utc, dec!(10000), at(1));
+
+        // 전날 미실현 -250 (2.5%) 보유 상태로 자정 통과
+        breaker.update_unrealized(dec!(-250), at(20));
+        assert!(breaker.check(at(20)).is_ok());
+
+        let next_day = at(23) + chrono::Duration::hours(2);
+        assert!(breaker.roll_over(next_day));
+
+        // 다음날에도 진입 이후 누적값(-260)이 보고되지만 당일 변동은 -10뿐
+        breaker.update_unrealized(dec!(-260), next_day);
+        assert!(breaker.check(next_day).is_ok());
+        assert_eq!(breaker.day_unrealized_pnl(), dec!(-10));
+        assert!((breaker.loss_pct() - 10.0 / 9750.0 * 100.0).abs() < 1e-9);
+
+        // 같은 가격에 청산: 실현 -260, 미실현 0 → 당일 손실은 여전히 -10
+        breaker.record_realized(dec!(-260), next_day);
+        breaker.update_unrealized(Decimal::ZERO, next_day);
+        assert!(breaker.check(next_day).is_ok());
+        assert!((breaker.loss_pct() - 10.0 / 9750.0 * 100.0).abs() < 1e-9);
+
+        // 당일 실제 추가 손실은 여전히 발동
+        breaker.record_realized(dec!(-300), next_day);
+        assert!(breaker.check(next_day).is_err());
+    }
+}
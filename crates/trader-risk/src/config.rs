@@ -8,6 +8,9 @@ use std::collections::HashMap;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::correlation::CorrelatedExposureConfig;
+use crate::limits::TradingTimezone;
+
 /// 전역 리스크 관리 설정.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RiskConfig {
@@ -20,6 +23,20 @@ pub struct RiskConfig {
     #[serde(default = "default_max_daily_loss_pct")]
     pub max_daily_loss_pct: f64,
 
+    /// 일일 손실 서킷브레이커 한도 (기본값: 비활성화)
+    /// 실현+미실현 손실이 당일 시작 자본 대비 이 비율을 넘으면 모든 신규 진입을 거부합니다
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_loss_limit_pct: Option<f64>,
+
+    /// 서킷브레이커 초기화 기준 시장 시간대 (기본값: UTC, 현지 자정에 초기화)
+    #[serde(default)]
+    pub circuit_breaker_timezone: TradingTimezone,
+
+    /// 상관관계 기반 합산 노출 한도 (기본값: 비활성화)
+    /// 새 진입과 상관이 높은 기존 포지션의 합산 노출을 제한합니다
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlated_exposure: Option<CorrelatedExposureConfig>,
+
     /// 계좌 잔고 대비 최대 총 노출 비율 (기본값: 50%)
     /// 모든 열린 포지션의 합이 이를 초과하지 않아야 합니다
     #[serde(default = "default_max_total_exposure_pct")]
@@ -129,6 +146,9 @@ impl Default for RiskConfig {
         Self {
             max_position_pct: default_max_position_pct(),
             max_daily_loss_pct: default_max_daily_loss_pct(),
+            daily_loss_limit_pct: None,
+            circuit_breaker_timezone: TradingTimezone::default(),
+            correlated_exposure: None,
             max_total_exposure_pct: default_max_total_exposure_pct(),
             volatility_threshold: default_volatility_threshold(),
             default_stop_loss_pct: default_stop_loss_pct(),
@@ -165,6 +185,9 @@ impl RiskConfig {
         Self {
             max_position_pct: 5.0,
             max_daily_loss_pct: 1.5,
+            daily_loss_limit_pct: None,
+            circuit_breaker_timezone: TradingTimezone::default(),
+            correlated_exposure: None,
             max_total_exposure_pct: 30.0,
             volatility_threshold: 3.0,
             default_stop_loss_pct: 1.5,
@@ -182,6 +205,9 @@ impl RiskConfig {
         Self {
             max_position_pct: 20.0,
             max_daily_loss_pct: 5.0,
+            daily_loss_limit_pct: None,
+            circuit_breaker_timezone: TradingTimezone::default(),
+            correlated_exposure: None,
             max_total_exposure_pct: 80.0,
             volatility_threshold: 8.0,
             default_stop_loss_pct: 3.0,
@@ -254,6 +280,30 @@ impl RiskConfig {
             ));
         }
 
+        if let Some(limit) = self.daily_loss_limit_pct {
+            if limit <= 0.0 || limit > 100.0 {
+                return Err(ConfigValidationError::InvalidValue(
+                    "daily_loss_limit_pct must be between 0 and 100".into(),
+                ));
+            }
+        }
+
+        if let Some(correlated) = &self.correlated_exposure {
+            if !(0.0..=1.0).contains(&correlated.correlation_threshold) {
+                return Err(ConfigValidationError::InvalidValue(
+                    "correlated_exposure.correlation_threshold must be between 0 and 1".into(),
+                ));
+            }
+            if correlated.max_correlated_exposure_pct <= 0.0
+                || correlated.max_correlated_exposure_pct > 100.0
+            {
+                return Err(ConfigValidationError::InvalidValue(
+                    "correlated_exposure.max_correlated_exposure_pct must be between 0 and 100"
+                        .into(),
+                ));
+            }
+        }
+
         if self.default_stop_loss_pct <= 0.0 || self.default_stop_loss_pct > 50.0 {
             return Err(ConfigValidationError::InvalidValue(
                 "default_stop_loss_pct must be between 0 and 50".into(),
@@ -368,6 +418,13 @@ mod tests {
             ..Default::default()
         };
         assert!(invalid.validate().is_err());
+
+        // 유효하지 않은 daily_loss_limit_pct
+        let invalid = RiskConfig {
+            daily_loss_limit_pct: Some(0.0),
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
     }
 
     #[test]
@@ -0,0 +1,212 @@
+//! 포지션 상관관계 기반 노출 한도.
+//!
+//! 여러 레버리지 ETF처럼 함께 움직이는 종목을 동시에 보유하면 종목별 한도를
+//! 지켜도 실질 노출이 과도해질 수 있습니다. 새 진입 종목과 상관계수가
+//! 임계값 이상인 기존 포지션을 하나의 그룹으로 보고 합산 노출 한도를 적용합니다.
+//!
+//! # 상관계수 출처
+//!
+//! - 최근 수익률 기반 상관계수는 `trader-analytics`에서 계산해 주기적으로 주입합니다.
+//! - 쌍의 상관계수가 없거나 테이블이 오래된 경우, 같은 섹터 종목은 보수적으로
+//!   상관 1.0으로 간주합니다. 섹터 정보도 없으면 상관이 없는 것으로 봅니다.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 상관 노출 한도 초과 시 처리 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorrelatedExposureMode {
+    /// 한도 내로 진입 수량 축소 제안
+    #[default]
+    Reduce,
+    /// 진입 완전 거부
+    Reject,
+}
+
+/// 상관 노출 한도 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrelatedExposureConfig {
+    /// 같은 그룹으로 볼 최소 상관계수 (기본값: 0.7)
+    #[serde(default = "default_correlation_threshold")]
+    pub correlation_threshold: f64,
+
+    /// 계좌 잔고 대비 상관 그룹 최대 합산 노출 비율 (기본값: 25%)
+    #[serde(default = "default_max_correlated_exposure_pct")]
+    pub max_correlated_exposure_pct: f64,
+
+    /// 한도 초과 시 처리 방식 (기본값: 수량 축소 제안)
+    #[serde(default)]
+    pub mode: CorrelatedExposureMode,
+
+    /// 상관계수 테이블 유효 기간 (초, 기본값: 1일)
+    /// 이보다 오래된 테이블은 섹터 폴백만 사용합니다
+    #[serde(default = "default_max_staleness_secs")]
+    pub max_staleness_secs: i64,
+}
+
+fn default_correlation_threshold() -> f64 {
+    0.7
+}
+
+fn default_max_correlated_exposure_pct() -> f64 {
+    25.0
+}
+
+fn default_max_staleness_secs() -> i64 {
+    86_400
+}
+
+impl Default for CorrelatedExposureConfig {
+    fn default() -> Self {
+        Self {
+            correlation_threshold: default_correlation_threshold(),
+            max_correlated_exposure_pct: default_max_correlated_exposure_pct(),
+            mode: CorrelatedExposureMode::default(),
+            max_staleness_secs: default_max_staleness_secs(),
+        }
+    }
+}
+
+/// 심볼 간 상관계수 테이블.
+#[derive(Debug, Clone, Default)]
+pub struct CorrelationTable {
+    /// 정렬된 심볼 쌍 -> 상관계수
+    pairs: HashMap<(String, String), f64>,
+    /// 심볼 -> 섹터 (폴백용)
+    sectors: HashMap<String, String>,
+    /// 상관계수 계산 시각
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl CorrelationTable {
+    /// 빈 테이블 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// N×N 상관행렬로 생성.
+    ///
+    /// `matrix[i][j]`는 `symbols[i]`와 `symbols[j]`의 상관계수입니다.
+    pub fn from_matrix(symbols: &[String], matrix: &[Vec<f64>], updated_at: DateTime<Utc>) -> Self {
+        let mut table = Self::new();
+        for (i, a) in symbols.iter().enumerate() {
+            for (j, b) in symbols.iter().enumerate().skip(i + 1) {
+                if let Some(value) = matrix.get(i).and_then(|row| row.get(j)) {
+                    table.set(a, b, *value);
+                }
+            }
+        }
+        table.updated_at = Some(updated_at);
+        table
+    }
+
+    /// 심볼 쌍의 상관계수 설정.
+    pub fn set(&mut self, a: &str, b: &str, correlation: f64) {
+        if a != b && correlation.is_finite() {
+            self.pairs
+                .insert(pair_key(a, b), correlation.clamp(-1.0, 1.0));
+        }
+    }
+
+    /// 심볼의 섹터 설정.
+    pub fn set_sector(&mut self, symbol: impl Into<String>, sector: impl Into<String>) {
+        self.sectors.insert(symbol.into(), sector.into());
+    }
+
+    /// 섹터 정보 일괄 설정.
+    pub fn with_sectors(mut self, sectors: HashMap<String, String>) -> Self {
+        self.sectors.extend(sectors);
+        self
+    }
+
+    /// 상관계수 계산 시각 설정.
+    pub fn set_updated_at(&mut self, updated_at: DateTime<Utc>) {
+        self.updated_at = Some(updated_at);
+    }
+
+    /// 상관계수 계산 시각.
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// 기존 테이블의 섹터 정보를 유지하면서 상관계수만 교체합니다.
+    pub fn replace_correlations(&mut self, other: CorrelationTable) {
+        self.pairs = other.pairs;
+        self.updated_at = other.updated_at;
+        self.sectors.extend(other.sectors);
+    }
+
+    /// 두 심볼의 유효 상관계수.
+    ///
+    /// 1. 같은 심볼 → 1.0
+    /// 2. 유효 기간 내 계산된 상관계수
+    /// 3. 같은 섹터 → 1.0 (보수적 폴백)
+    /// 4. 그 외 → `None` (상관 없음으로 간주)
+    pub fn correlation(
+        &self,
+        a: &str,
+        b: &str,
+        max_staleness: Duration,
+        now: DateTime<Utc>,
+    ) -> Option<f64> {
+        if a == b {
+            return Some(1.0);
+        }
+
+        let fresh = self
+            .updated_at
+            .is_some_and(|updated| now - updated <= max_staleness);
+        if fresh {
+            if let Some(value) = self.pairs.get(&pair_key(a, b)) {
+                return Some(*value);
+            }
+        }
+
+        match (self.sectors.get(a), self.sectors.get(b)) {
+            (Some(sa), Some(sb)) if sa == sb => Some(1.0),
+            _ => None,
+        }
+    }
+}
+
+/// 순서와 무관한 심볼 쌍 키.
+fn pair_key(a: &str, b: &str) -> (String, String) {
+    if a <= b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_correlation_lookup_and_sector_fallback() {
+        let now = Utc::now();
+        let symbols = vec!["TQQQ".to_string(), "SOXL".to_string(), "TLT".to_string()];
+        let matrix = vec![
+            vec![1.0, 0.85, -0.3],
+            vec![0.85, 1.0, -0.2],
+            vec![-0.3, -0.2, 1.0],
+        ];
+        let mut table = CorrelationTable::from_matrix(&symbols, &matrix, now);
+        table.set_sector("TQQQ", "leveraged_tech");
+        table.set_sector("TECL", "leveraged_tech");
+
+        let window = Duration::days(1);
+        assert_eq!(table.correlation("SOXL", "TQQQ", window, now), Some(0.85));
+        assert_eq!(table.correlation("TLT", "TQQQ", window, now), Some(-0.3));
+        // 데이터 부족: 같은 섹터는 1.0으로 간주
+        assert_eq!(table.correlation("TECL", "TQQQ", window, now), Some(1.0));
+        assert_eq!(table.correlation("TECL", "TLT", window, now), None);
+
+        // 오래된 테이블은 섹터 폴백만 사용
+        let later = now + Duration::days(2);
+        assert_eq!(table.correlation("SOXL", "TQQQ", window, later), None);
+    }
+}
@@ -5,6 +5,8 @@
 //! - 포지션 사이징
 //! - Stop-loss/Take-profit 관리
 //! - 일일 손실 한도
+//! - 일일 손실 서킷브레이커
+//! - 상관관계 기반 합산 노출 한도
 //! - 변동성 필터
 //!
 //! # 예제
@@ -22,7 +24,9 @@
 //! }
 //! ```
 
+pub mod circuit_breaker;
 pub mod config;
+pub mod correlation;
 pub mod limits;
 pub mod manager;
 pub mod position_sizing;
@@ -30,8 +34,12 @@ pub mod stop_loss;
 pub mod trailing_stop;
 
 // 주요 타입 재내보내기
+pub use circuit_breaker::{
+    CircuitBreakerError, CircuitBreakerHook, DailyLossBreach, DailyLossCircuitBreaker,
+};
 pub use config::{ConfigValidationError, RiskConfig, SymbolRiskConfig};
-pub use limits::{DailyLimitStatus, DailyLossTracker, PnLRecord, RiskLimits};
+pub use correlation::{CorrelatedExposureConfig, CorrelatedExposureMode, CorrelationTable};
+pub use limits::{DailyLimitStatus, DailyLossTracker, PnLRecord, RiskLimits, TradingTimezone};
 pub use manager::{RiskManager, RiskValidation};
 pub use position_sizing::{PositionSizer, SizingValidation};
 pub use stop_loss::{StopOrder, StopOrderGenerator, StopType, TrailingStopState};
@@ -82,6 +82,18 @@ impl TradingTimezone {
         }
     }
 
+    /// 시장 시간대 자정 기준 날짜를 계산합니다.
+    ///
+    /// 장 개시가 아닌 현지 자정에 날짜가 바뀝니다 (EST는 DST 무시, UTC-5 고정).
+    pub fn local_date(&self, now: DateTime<Utc>) -> chrono::NaiveDate {
+        let offset_hours = match self {
+            TradingTimezone::Utc => 0,
+            TradingTimezone::Kst => 9,
+            TradingTimezone::Est => -5,
+        };
+        (now + chrono::Duration::hours(offset_hours)).date_naive()
+    }
+
     /// 시간대 이름 반환.
     pub fn name(&self) -> &'static str {
         match self {
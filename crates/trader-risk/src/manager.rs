@@ -3,16 +3,23 @@
 //! 모든 리스크 관리 작업을 위한 통합 인터페이스 제공:
 //! - 포지션 크기 제한에 대한 주문 검증
 //! - 일일 손실 한도 추적
+//! - 일일 손실 서킷브레이커 (실현+미실현 손실 기준 신규 진입 차단)
+//! - 상관관계 기반 합산 노출 한도
 //! - Stop-loss/Take-profit 주문 생성
 //! - 변동성 필터링
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use rust_decimal::Decimal;
 use trader_core::{OrderRequest, Position, TraderResult};
 
 use crate::{
+    circuit_breaker::{
+        CircuitBreakerError, CircuitBreakerHook, DailyLossBreach, DailyLossCircuitBreaker,
+    },
     config::RiskConfig,
+    correlation::{CorrelatedExposureMode, CorrelationTable},
     limits::DailyLossTracker,
     position_sizing::PositionSizer,
     stop_loss::{StopOrder, StopOrderGenerator, TrailingStopState},
@@ -27,6 +34,8 @@ pub struct RiskValidation {
     pub messages: Vec<String>,
     /// 수정된 주문 (조정이 이루어진 경우)
     pub modified_order: Option<OrderRequest>,
+    /// 서킷브레이커 차단 사유 (일일 손실 한도 초과로 거부된 경우)
+    pub circuit_breaker: Option<DailyLossBreach>,
 }
 
 impl RiskValidation {
@@ -36,6 +45,7 @@ impl RiskValidation {
             is_valid: true,
             messages: vec![],
             modified_order: None,
+            circuit_breaker: None,
         }
     }
 
@@ -45,6 +55,7 @@ impl RiskValidation {
             is_valid: false,
             messages: vec![reason.into()],
             modified_order: None,
+            circuit_breaker: None,
         }
     }
 
@@ -54,6 +65,13 @@ impl RiskValidation {
         self
     }
 
+    /// 서킷브레이커 차단 결과 생성.
+    pub fn circuit_breaker_tripped(error: CircuitBreakerError) -> Self {
+        let mut validation = Self::invalid(error.to_string());
+        validation.circuit_breaker = Some(error.breach().clone());
+        validation
+    }
+
     /// 수정된 주문 설정.
     pub fn with_modified_order(mut self, order: OrderRequest) -> Self {
         self.modified_order = Some(order);
@@ -80,6 +98,8 @@ pub struct RiskManager {
     position_sizer: PositionSizer,
     /// 일일 손실 추적기
     daily_tracker: DailyLossTracker,
+    /// 일일 손실 서킷브레이커 (`daily_loss_limit_pct` 설정 시)
+    circuit_breaker: Option<DailyLossCircuitBreaker>,
     /// Stop 주문 생성기
     stop_generator: StopOrderGenerator,
     /// 계좌 잔고
@@ -88,6 +108,8 @@ pub struct RiskManager {
     volatility_data: HashMap<String, VolatilityData>,
     /// 활성 Trailing Stop (position_id -> state)
     trailing_stops: HashMap<String, TrailingStopState>,
+    /// 심볼 간 상관계수 (상관 노출 한도용)
+    correlations: CorrelationTable,
 }
 
 impl RiskManager {
@@ -96,15 +118,25 @@ impl RiskManager {
         let position_sizer = PositionSizer::new(config.clone());
         let daily_tracker = DailyLossTracker::from_config(&config, starting_balance);
         let stop_generator = StopOrderGenerator::new(config.clone());
+        let circuit_breaker = config.daily_loss_limit_pct.map(|limit_pct| {
+            DailyLossCircuitBreaker::new(
+                limit_pct,
+                config.circuit_breaker_timezone,
+                starting_balance,
+                chrono::Utc::now(),
+            )
+        });
 
         Self {
             config,
             position_sizer,
             daily_tracker,
+            circuit_breaker,
             stop_generator,
             balance: starting_balance,
             volatility_data: HashMap::new(),
             trailing_stops: HashMap::new(),
+            correlations: CorrelationTable::new(),
         }
     }
 
@@ -156,6 +188,15 @@ impl RiskManager {
             ));
         }
 
+        // Check 1b: Daily loss circuit breaker (신규 진입만 차단)
+        if !is_exit_order(order, positions) {
+            let unrealized: Decimal = positions.iter().map(|p| p.unrealized_pnl).sum();
+            self.update_unrealized_pnl(unrealized);
+            if let Err(e) = self.check_entry_allowed() {
+                return Ok(RiskValidation::circuit_breaker_tripped(e));
+            }
+        }
+
         // Check 2: Symbol enabled
         if !self.config.is_symbol_enabled(&symbol) {
             return Ok(RiskValidation::invalid(format!(
@@ -208,6 +249,14 @@ impl RiskManager {
             return Ok(validation);
         }
 
+        // Check 4b: Correlated exposure (신규 진입만)
+        if !is_exit_order(order, positions) {
+            let correlated = self.check_correlated_exposure(order, positions, current_price);
+            if !correlated.is_valid {
+                return Ok(correlated);
+            }
+        }
+
         // Check 5: Daily limit status warning
         let daily_status = self.daily_tracker.get_status();
         if let Some(warning) = daily_status.warning {
@@ -232,6 +281,9 @@ impl RiskManager {
 
     /// 수익 또는 손실 기록.
     pub fn record_pnl(&mut self, symbol: &str, amount: Decimal) {
+        if let Some(breaker) = &mut self.circuit_breaker {
+            breaker.record_realized(amount, chrono::Utc::now());
+        }
         if amount >= Decimal::ZERO {
             self.daily_tracker.record_profit(symbol, amount);
         } else {
@@ -259,6 +311,124 @@ impl RiskManager {
         self.daily_tracker.force_reset();
     }
 
+    // ==================== Circuit Breaker ====================
+
+    /// 서킷브레이커 발동 훅 설정 (예: 알림 전송).
+    pub fn set_circuit_breaker_hook(&mut self, hook: Arc<dyn CircuitBreakerHook>) {
+        if let Some(breaker) = &mut self.circuit_breaker {
+            breaker.set_hook(hook);
+        }
+    }
+
+    /// 열린 포지션의 미실현 손익 합계 갱신.
+    pub fn update_unrealized_pnl(&mut self, total: Decimal) {
+        if let Some(breaker) = &mut self.circuit_breaker {
+            breaker.update_unrealized(total, chrono::Utc::now());
+        }
+    }
+
+    /// 신규 진입 허용 여부 확인.
+    ///
+    /// 서킷브레이커가 발동된 상태면 발동 정보를 담은 에러를 반환합니다.
+    pub fn check_entry_allowed(&mut self) -> Result<(), CircuitBreakerError> {
+        match &mut self.circuit_breaker {
+            Some(breaker) => breaker.check(chrono::Utc::now()),
+            None => Ok(()),
+        }
+    }
+
+    /// 서킷브레이커 상태 조회.
+    pub fn circuit_breaker(&self) -> Option<&DailyLossCircuitBreaker> {
+        self.circuit_breaker.as_ref()
+    }
+
+    // ==================== Correlated Exposure ====================
+
+    /// 상관계수 테이블 교체 (섹터 정보는 유지).
+    ///
+    /// `trader-analytics`에서 최근 수익률로 계산한 상관계수를 주기적으로 주입합니다.
+    pub fn update_correlations(&mut self, table: CorrelationTable) {
+        self.correlations.replace_correlations(table);
+    }
+
+    /// 심볼의 섹터 설정 (상관계수 부족 시 폴백용).
+    pub fn set_symbol_sector(&mut self, symbol: impl Into<String>, sector: impl Into<String>) {
+        self.correlations.set_sector(symbol, sector);
+    }
+
+    /// 상관계수 테이블 조회.
+    pub fn correlations(&self) -> &CorrelationTable {
+        &self.correlations
+    }
+
+    /// 상관관계 기반 합산 노출 한도 검증.
+    ///
+    /// 새 진입 종목과 상관계수가 임계값 이상인 열린 포지션의 노출과 주문 금액을
+    /// 합산해 한도를 넘는지 확인합니다. 한도 초과 시 `Reduce` 모드면 한도 내
+    /// 수량을 `modified_order`로 제안하고, `Reject` 모드면 거부합니다.
+    pub fn check_correlated_exposure(
+        &self,
+        order: &OrderRequest,
+        positions: &[Position],
+        current_price: Decimal,
+    ) -> RiskValidation {
+        let Some(config) = &self.config.correlated_exposure else {
+            return RiskValidation::valid();
+        };
+
+        let now = chrono::Utc::now();
+        let staleness = chrono::Duration::seconds(config.max_staleness_secs);
+        let mut correlated_symbols = Vec::new();
+        let group_exposure: Decimal = positions
+            .iter()
+            .filter(|p| p.is_open())
+            .filter(|p| {
+                let correlated = self
+                    .correlations
+                    .correlation(&order.ticker, &p.ticker, staleness, now)
+                    .is_some_and(|c| c >= config.correlation_threshold);
+                if correlated && !correlated_symbols.contains(&p.ticker) {
+                    correlated_symbols.push(p.ticker.clone());
+                }
+                correlated
+            })
+            .map(|p| p.notional_value())
+            .sum();
+
+        let order_value = order.quantity * current_price;
+        let limit = self.balance
+            * Decimal::from_f64_retain(config.max_correlated_exposure_pct / 100.0)
+                .unwrap_or(Decimal::ZERO);
+        if group_exposure + order_value <= limit {
+            return RiskValidation::valid();
+        }
+
+        let reason = format!(
+            "Correlated exposure {} + order {} exceeds limit {} (correlated with: {})",
+            group_exposure.round_dp(2),
+            order_value.round_dp(2),
+            limit.round_dp(2),
+            correlated_symbols.join(", ")
+        );
+        let mut validation = RiskValidation::invalid(reason);
+
+        // 남은 한도가 최소 주문 금액 이상일 때만 축소 수량 제안
+        let remaining = limit - group_exposure;
+        if config.mode == CorrelatedExposureMode::Reduce
+            && current_price > Decimal::ZERO
+            && remaining >= self.config.min_order_size
+        {
+            let suggested_qty = (remaining / current_price).round_dp(8);
+            let mut adjusted_order = order.clone();
+            adjusted_order.quantity = suggested_qty;
+            validation = validation
+                .with_warning(format!("Suggested adjusted quantity: {}", suggested_qty))
+                .with_modified_order(adjusted_order);
+        }
+
+        validation
+    }
+
     // ==================== Stop Orders ====================
 
     /// 포지션에 대한 Stop-loss 주문 생성.
@@ -421,6 +591,14 @@ impl RiskManager {
     }
 }
 
+/// 기존 포지션을 줄이는 주문인지 확인 (청산 주문은 서킷브레이커 대상이 아님).
+fn is_exit_order(order: &OrderRequest, positions: &[Position]) -> bool {
+    order.reduce_only
+        || positions
+            .iter()
+            .any(|p| p.ticker == order.ticker && p.side != order.side && p.is_open())
+}
+
 impl Default for RiskManager {
     fn default() -> Self {
         Self::new(RiskConfig::default(), Decimal::ZERO)
@@ -652,4 +830,116 @@ mod tests {
         assert!(manager.can_trade());
         assert_eq!(manager.daily_pnl(), dec!(0));
     }
+
+    #[test]
+    fn test_circuit_breaker_blocks_new_entries_only() {
+        let config = RiskConfig {
+            max_daily_loss_pct: 50.0,
+            daily_loss_limit_pct: Some(2.0),
+            ..Default::default()
+        };
+        let mut manager = RiskManager::new(config, dec!(10000));
+        let hook_calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = hook_calls.clone();
+        manager.set_circuit_breaker_hook(Arc::new(move |_: &DailyLossBreach| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }));
+
+        let btc = Symbol::crypto("BTC", "USDT");
+        let mut position = create_test_position(&btc, Side::Buy, dec!(0.01), dec!(50000));
+        position.unrealized_pnl = dec!(-150);
+        let positions = vec![position];
+
+        // 실현 -50 + 미실현 -150 = 2%
+        manager.record_pnl("ETH/USDT", dec!(-50));
+        assert!(manager.check_entry_allowed().is_ok());
+
+        let entry = OrderRequest::market_buy("ETH/USDT".to_string(), dec!(0.01));
+        let result = manager
+            .validate_order(&entry, &positions, dec!(3000))
+            .unwrap();
+        assert!(!result.is_valid);
+        let breach = result.circuit_breaker.expect("circuit breaker breach");
+        assert_eq!(breach.total_loss(), dec!(200));
+        assert!(matches!(
+            manager.check_entry_allowed(),
+            Err(CircuitBreakerError::DailyLossLimitExceeded(_))
+        ));
+        assert_eq!(hook_calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // 청산 주문은 차단하지 않음
+        let exit = OrderRequest::market_sell(btc.to_string(), dec!(0.01));
+        let result = manager
+            .validate_order(&exit, &positions, dec!(49000))
+            .unwrap();
+        assert!(result.circuit_breaker.is_none());
+    }
+
+    #[test]
+    fn test_correlated_exposure_reduce_and_reject() {
+        use crate::correlation::CorrelatedExposureConfig;
+
+        let config = RiskConfig {
+            max_position_pct: 50.0,
+            correlated_exposure: Some(CorrelatedExposureConfig {
+                correlation_threshold: 0.7,
+                max_correlated_exposure_pct: 30.0,
+                mode: CorrelatedExposureMode::Reduce,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut manager = RiskManager::new(config.clone(), dec!(10000));
+        let mut table = CorrelationTable::new();
+        table.set("TQQQ", "SOXL", 0.9);
+        table.set("TQQQ", "TLT", -0.4);
+        table.set_updated_at(chrono::Utc::now());
+        manager.update_correlations(table);
+        // 상관계수가 없는 종목은 섹터로 폴백
+        manager.set_symbol_sector("TQQQ", "leveraged");
+        manager.set_symbol_sector("TECL", "leveraged");
+
+        // TQQQ 2000 보유
+        let positions = vec![Position::new(
+            "test_exchange",
+            "TQQQ".to_string(),
+            Side::Buy,
+            dec!(20),
+            dec!(100),
+        )];
+
+        // 음의 상관 종목은 그룹에 포함되지 않음
+        let tlt = OrderRequest::market_buy("TLT".to_string(), dec!(20));
+        assert!(
+            manager
+                .check_correlated_exposure(&tlt, &positions, dec!(100))
+                .is_valid
+        );
+
+        // SOXL 2000 추가 시 4000 > 3000 → 1000까지 축소 제안
+        let soxl = OrderRequest::market_buy("SOXL".to_string(), dec!(20));
+        let result = manager.check_correlated_exposure(&soxl, &positions, dec!(100));
+        assert!(!result.is_valid);
+        assert_eq!(result.modified_order.unwrap().quantity, dec!(10));
+
+        // 섹터 폴백으로 TECL도 같은 그룹
+        let tecl = OrderRequest::market_buy("TECL".to_string(), dec!(20));
+        assert!(
+            !manager
+                .check_correlated_exposure(&tecl, &positions, dec!(100))
+                .is_valid
+        );
+
+        // 거부 모드는 수량 제안 없음
+        let mut reject_config = config;
+        if let Some(c) = reject_config.correlated_exposure.as_mut() {
+            c.mode = CorrelatedExposureMode::Reject;
+        }
+        let mut manager = RiskManager::new(reject_config, dec!(10000));
+        manager.set_symbol_sector("TQQQ", "leveraged");
+        manager.set_symbol_sector("SOXL", "leveraged");
+        let result = manager.check_correlated_exposure(&soxl, &positions, dec!(100));
+        assert!(!result.is_valid);
+        assert!(result.modified_order.is_none());
+    }
 }
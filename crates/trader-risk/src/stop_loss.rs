@@ -8,7 +8,7 @@
 
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
-use trader_core::{OrderRequest, OrderType, Position, Side, TimeInForce};
+use trader_core::{OrderRequest, OrderSession, OrderType, Position, Side, TimeInForce};
 
 use crate::config::RiskConfig;
 
@@ -148,6 +148,8 @@ impl StopOrder {
                 time_in_force: TimeInForce::GTC,
                 client_order_id: None,
                 strategy_id: None,
+                reduce_only: self.reduce_only,
+                session: OrderSession::Regular,
             },
             None => OrderRequest {
                 ticker: self.symbol.clone(),
@@ -159,6 +161,8 @@ impl StopOrder {
                 time_in_force: TimeInForce::GTC,
                 client_order_id: None,
                 strategy_id: None,
+                reduce_only: self.reduce_only,
+                session: OrderSession::Regular,
             },
         }
     }
@@ -1,6 +1,6 @@
 //! trader-strategy를 위한 프로시저 매크로.
 //!
-//! 이 크레이트는 전략 설정 구조체에 대한 SDUI 스키마 자동 생성을 제공합니다.
+//! 이 크레이트는 전략 설정 구조체에 대한 SDUI 스키마 및 설정 검증용 필드 명세 자동 생성을 제공합니다.
 
 use proc_macro::TokenStream;
 use quote::quote;
@@ -8,7 +8,10 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields};
 
 /// StrategyConfig derive 매크로.
 ///
-/// 전략 설정 구조체에 `ui_schema()` 메서드를 자동 생성합니다.
+/// 전략 설정 구조체에 `ui_schema()`와 `config_fields()` 메서드를 자동 생성합니다.
+///
+/// `config_fields()`는 serde 속성(`default`, `rename`, `alias`, `skip`, `deserialize_with`)과
+/// 필드 타입으로부터 JSON 설정 검증용 필드 명세를 반환합니다.
 ///
 /// # Attributes
 ///
@@ -77,10 +80,40 @@ pub fn derive_strategy_config(input: TokenStream) -> TokenStream {
     let mut fragment_refs = Vec::new();
     // 커스텀 필드 수집
     let mut custom_fields = Vec::new();
+    // 설정 검증용 필드 명세 수집
+    let mut config_fields = Vec::new();
+
+    let container_serde = parse_serde_attributes(&input.attrs);
 
     for field in fields {
         let field_name = field.ident.as_ref().unwrap();
 
+        // serde 기준 필드 명세 (Fragment/스키마 제외 필드 포함 모든 필드)
+        let serde_attrs = parse_serde_attributes(&field.attrs);
+        if !serde_attrs.skip {
+            let json_name = serde_attrs
+                .rename
+                .clone()
+                .unwrap_or_else(|| field_name.to_string());
+            let aliases = &serde_attrs.aliases;
+            let (kind, nullable) = if serde_attrs.deserialize_with {
+                (quote! { trader_core::ConfigValueKind::Any }, false)
+            } else {
+                infer_value_kind(&field.ty)
+            };
+            let required = !serde_attrs.default && !container_serde.default && !nullable;
+
+            config_fields.push(quote! {
+                trader_core::ConfigFieldSpec {
+                    name: #json_name.to_string(),
+                    aliases: vec![#(#aliases.to_string()),*],
+                    kind: #kind,
+                    required: #required,
+                    nullable: #nullable,
+                }
+            });
+        }
+
         // fragment 속성 확인
         let has_fragment = field
             .attrs
@@ -238,6 +271,13 @@ pub fn derive_strategy_config(input: TokenStream) -> TokenStream {
                     defaults: None,
                 }
             }
+
+            /// 설정 검증용 필드 명세를 반환합니다 (serde 역직렬화 기준).
+            pub fn config_fields() -> Vec<trader_core::ConfigFieldSpec> {
+                vec![
+                    #(#config_fields),*
+                ]
+            }
         }
     };
 
@@ -384,6 +424,103 @@ fn parse_schema_attributes(attrs: &[syn::Attribute]) -> SchemaAttributes {
     result
 }
 
+/// serde 속성 결과.
+#[derive(Default)]
+struct SerdeAttributes {
+    /// `default` 또는 `default = "..."` 지정 여부
+    default: bool,
+    /// `skip` / `skip_deserializing` 지정 여부
+    skip: bool,
+    /// `deserialize_with` / `with` 지정 여부 (타입 검사 생략)
+    deserialize_with: bool,
+    /// `rename = "..."`
+    rename: Option<String>,
+    /// `alias = "..."` 목록
+    aliases: Vec<String>,
+}
+
+/// serde 속성을 파싱합니다.
+fn parse_serde_attributes(attrs: &[syn::Attribute]) -> SerdeAttributes {
+    let mut result = SerdeAttributes::default();
+
+    for attr in attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+
+        // 알 수 없는 키는 값/중첩 목록을 소비하고 무시
+        let _ = attr.parse_nested_meta(|meta| {
+            let key = meta
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+
+            let value = if meta.input.peek(syn::Token![=]) {
+                Some(meta.value()?.parse::<syn::LitStr>()?.value())
+            } else {
+                if meta.input.peek(syn::token::Paren) {
+                    meta.parse_nested_meta(|nested| {
+                        if nested.input.peek(syn::Token![=]) {
+                            nested.value()?.parse::<syn::LitStr>()?;
+                        }
+                        Ok(())
+                    })?;
+                }
+                None
+            };
+
+            match key.as_str() {
+                "default" => result.default = true,
+                "skip" | "skip_deserializing" => result.skip = true,
+                "deserialize_with" | "with" => result.deserialize_with = true,
+                "rename" => result.rename = value,
+                "alias" => result.aliases.extend(value),
+                _ => {}
+            }
+            Ok(())
+        });
+    }
+
+    result
+}
+
+/// 필드 타입으로부터 ConfigValueKind와 null 허용 여부를 추론합니다.
+fn infer_value_kind(ty: &syn::Type) -> (proc_macro2::TokenStream, bool) {
+    let syn::Type::Path(type_path) = ty else {
+        return (quote! { trader_core::ConfigValueKind::Any }, false);
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return (quote! { trader_core::ConfigValueKind::Any }, false);
+    };
+
+    let kind = match segment.ident.to_string().as_str() {
+        "Option" => {
+            // Option<T>: 내부 타입 + null 허용
+            if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                    return (infer_value_kind(inner).0, true);
+                }
+            }
+            return (quote! { trader_core::ConfigValueKind::Any }, true);
+        }
+        "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize" => {
+            quote! { trader_core::ConfigValueKind::Integer }
+        }
+        "f32" | "f64" => quote! { trader_core::ConfigValueKind::Number },
+        "Decimal" => quote! { trader_core::ConfigValueKind::Decimal },
+        "bool" => quote! { trader_core::ConfigValueKind::Boolean },
+        "String" => quote! { trader_core::ConfigValueKind::String },
+        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => {
+            quote! { trader_core::ConfigValueKind::Array }
+        }
+        "HashMap" | "BTreeMap" => quote! { trader_core::ConfigValueKind::Object },
+        _ => quote! { trader_core::ConfigValueKind::Any },
+    };
+
+    (kind, false)
+}
+
 /// 필드 타입으로부터 FieldType을 추론합니다.
 fn infer_field_type(ty: &syn::Type) -> proc_macro2::TokenStream {
     let type_str = quote!(#ty).to_string();
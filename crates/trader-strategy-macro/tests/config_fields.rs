@@ -0,0 +1,112 @@
+//! `#[derive(StrategyConfig)]`의 `config_fields()` 생성 테스트.
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use trader_core::{ConfigFieldSpec, ConfigValueKind};
+use trader_strategy_macro::StrategyConfig;
+
+#[derive(Debug, Clone, Deserialize, StrategyConfig)]
+#[strategy(id = "sample", name = "샘플 전략", category = "Intraday")]
+struct SampleConfig {
+    /// 필수 문자열
+    ticker: String,
+
+    #[serde(default)]
+    period: usize,
+
+    #[serde(default = "default_threshold")]
+    #[schema(label = "임계값", min = 0, max = 1)]
+    threshold: Decimal,
+
+    #[serde(rename = "use_filter", alias = "filter")]
+    filter_enabled: bool,
+
+    weights: HashMap<String, f64>,
+
+    optional_limit: Option<i64>,
+
+    #[serde(skip)]
+    #[schema(skip)]
+    cache: Vec<String>,
+
+    #[serde(default, deserialize_with = "deserialize_any")]
+    custom: Vec<String>,
+}
+
+fn default_threshold() -> Decimal {
+    Decimal::ONE
+}
+
+fn deserialize_any<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)
+}
+
+fn field<'a>(fields: &'a [ConfigFieldSpec], name: &str) -> &'a ConfigFieldSpec {
+    fields
+        .iter()
+        .find(|f| f.name == name)
+        .unwrap_or_else(|| panic!("field not found: {}", name))
+}
+
+#[test]
+fn test_config_fields_from_serde_attributes() {
+    let fields = SampleConfig::config_fields();
+
+    // skip 필드는 제외
+    assert_eq!(fields.len(), 7);
+    assert!(fields.iter().all(|f| f.name != "cache"));
+
+    let ticker = field(&fields, "ticker");
+    assert_eq!(ticker.kind, ConfigValueKind::String);
+    assert!(ticker.required);
+
+    let period = field(&fields, "period");
+    assert_eq!(period.kind, ConfigValueKind::Integer);
+    assert!(!period.required);
+
+    let threshold = field(&fields, "threshold");
+    assert_eq!(threshold.kind, ConfigValueKind::Decimal);
+    assert!(!threshold.required);
+
+    // rename/alias 반영
+    let filter = field(&fields, "use_filter");
+    assert_eq!(filter.kind, ConfigValueKind::Boolean);
+    assert!(filter.matches("filter"));
+    assert!(!filter.matches("filter_enabled"));
+
+    assert_eq!(field(&fields, "weights").kind, ConfigValueKind::Object);
+
+    let optional = field(&fields, "optional_limit");
+    assert_eq!(optional.kind, ConfigValueKind::Integer);
+    assert!(optional.nullable);
+    assert!(!optional.required);
+
+    // deserialize_with는 타입 검사 생략
+    assert_eq!(field(&fields, "custom").kind, ConfigValueKind::Any);
+}
+
+#[test]
+fn test_sample_config_deserializes_with_serde_names() {
+    let config: SampleConfig = serde_json::from_value(serde_json::json!({
+        "ticker": "005930",
+        "filter": true,
+        "weights": {"a": 0.5},
+        "optional_limit": null,
+        "custom": ["x"]
+    }))
+    .unwrap();
+
+    assert_eq!(config.ticker, "005930");
+    assert_eq!(config.period, 0);
+    assert_eq!(config.threshold, Decimal::ONE);
+    assert!(config.filter_enabled);
+    assert_eq!(config.weights["a"], 0.5);
+    assert_eq!(config.optional_limit, None);
+    assert!(config.cache.is_empty());
+    assert_eq!(config.custom, vec!["x".to_string()]);
+}
@@ -0,0 +1,364 @@
+//! 전략 설정 JSON 검증.
+//!
+//! `strategy.initialize(config)`는 알 수 없는 필드를 조용히 무시하므로,
+//! 초기화 전에 설정 필드 명세([`ConfigFieldSpec`])와 대조하여 문제를 보고합니다.
+//!
+//! # 검증 수준
+//!
+//! - **경고**: 알 수 없는 필드 (오타 가능성, 유사한 필드명 제안)
+//! - **오류**: 필수값 누락, 타입 불일치, 설정 타입 역직렬화 실패
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! use trader_strategy::StrategyRegistry;
+//!
+//! let report = StrategyRegistry::validate_config("rsi", &config)?;
+//! for issue in &report.issues {
+//!     println!("{}", issue);
+//! }
+//! if !report.is_valid() {
+//!     return Err(report.error_summary());
+//! }
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use trader_core::{ConfigFieldSpec, ConfigValueKind};
+
+/// 유사 필드명 제안 최대 편집 거리
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+/// 검증 이슈 수준
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigIssueLevel {
+    /// 초기화는 가능하지만 의도와 다를 수 있음 (알 수 없는 필드)
+    Warning,
+    /// 초기화 실패 또는 잘못된 동작 (필수 누락, 타입 불일치)
+    Error,
+}
+
+/// 검증 이슈 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigIssueKind {
+    /// 설정이 JSON 객체가 아님
+    NotAnObject,
+    /// 알 수 없는 필드
+    UnknownField,
+    /// 필수값 누락
+    MissingRequired,
+    /// 타입 불일치
+    TypeMismatch,
+    /// 설정 타입 역직렬화 실패 (enum 값 오류 등)
+    DeserializeFailed,
+}
+
+/// 검증 이슈
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigIssue {
+    /// 수준
+    pub level: ConfigIssueLevel,
+    /// 종류
+    pub kind: ConfigIssueKind,
+    /// 관련 필드 (설정 전체에 대한 이슈면 None)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// 사람이 읽을 수 있는 메시지
+    pub message: String,
+    /// 유사한 필드명 제안 (알 수 없는 필드)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self.level {
+            ConfigIssueLevel::Warning => "경고",
+            ConfigIssueLevel::Error => "오류",
+        };
+        write!(f, "[{}] {}", level, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " ('{}'을(를) 의도하셨나요?)", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+/// 설정 검증 결과
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigValidationReport {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 설정 필드 명세 존재 여부 (false면 검증 생략)
+    pub schema_available: bool,
+    /// 검증 이슈 목록
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl ConfigValidationReport {
+    /// 필드 명세가 없는 전략의 결과 (검증 생략)
+    pub fn unavailable(strategy_id: impl Into<String>) -> Self {
+        Self {
+            strategy_id: strategy_id.into(),
+            schema_available: false,
+            issues: Vec::new(),
+        }
+    }
+
+    /// 오류가 없으면 true (경고는 허용)
+    pub fn is_valid(&self) -> bool {
+        !self.has_errors()
+    }
+
+    /// 오류 존재 여부
+    pub fn has_errors(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| issue.level == ConfigIssueLevel::Error)
+    }
+
+    /// 오류 목록
+    pub fn errors(&self) -> impl Iterator<Item = &ConfigIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.level == ConfigIssueLevel::Error)
+    }
+
+    /// 경고 목록
+    pub fn warnings(&self) -> impl Iterator<Item = &ConfigIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.level == ConfigIssueLevel::Warning)
+    }
+
+    /// 오류 메시지 요약 (한 줄에 하나씩)
+    pub fn error_summary(&self) -> String {
+        self.errors()
+            .map(|issue| issue.message.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// 역직렬화 실패 오류 추가
+    pub(crate) fn push_deserialize_error(&mut self, message: String) {
+        self.issues.push(ConfigIssue {
+            level: ConfigIssueLevel::Error,
+            kind: ConfigIssueKind::DeserializeFailed,
+            field: None,
+            message: format!("설정을 해석할 수 없습니다: {}", message),
+            suggestion: None,
+        });
+    }
+}
+
+/// 필드 명세와 대조하여 설정 JSON을 검증합니다.
+pub fn validate_against_fields(
+    strategy_id: &str,
+    fields: &[ConfigFieldSpec],
+    config: &Value,
+) -> ConfigValidationReport {
+    let mut report = ConfigValidationReport {
+        strategy_id: strategy_id.to_string(),
+        schema_available: true,
+        issues: Vec::new(),
+    };
+
+    let Some(object) = config.as_object() else {
+        report.issues.push(ConfigIssue {
+            level: ConfigIssueLevel::Error,
+            kind: ConfigIssueKind::NotAnObject,
+            field: None,
+            message: format!(
+                "설정은 JSON 객체여야 합니다 (입력: {})",
+                ConfigValueKind::json_type_name(config)
+            ),
+            suggestion: None,
+        });
+        return report;
+    };
+
+    // 알 수 없는 필드 (경고)
+    for key in object.keys() {
+        if fields.iter().any(|spec| spec.matches(key)) {
+            continue;
+        }
+        report.issues.push(ConfigIssue {
+            level: ConfigIssueLevel::Warning,
+            kind: ConfigIssueKind::UnknownField,
+            field: Some(key.clone()),
+            message: format!("알 수 없는 필드 '{}'는 무시됩니다", key),
+            suggestion: suggest_field(key, fields),
+        });
+    }
+
+    for spec in fields {
+        let value = std::iter::once(&spec.name)
+            .chain(&spec.aliases)
+            .find_map(|key| object.get(key));
+
+        match value {
+            // 필수값 누락 (오류)
+            None if spec.required => report.issues.push(ConfigIssue {
+                level: ConfigIssueLevel::Error,
+                kind: ConfigIssueKind::MissingRequired,
+                field: Some(spec.name.clone()),
+                message: format!("필수 필드 '{}'가 없습니다", spec.name),
+                suggestion: None,
+            }),
+            None => {}
+            Some(Value::Null) if spec.nullable => {}
+            // 타입 불일치 (오류)
+            Some(value) if !spec.kind.accepts(value) => report.issues.push(ConfigIssue {
+                level: ConfigIssueLevel::Error,
+                kind: ConfigIssueKind::TypeMismatch,
+                field: Some(spec.name.clone()),
+                message: format!(
+                    "필드 '{}'의 타입이 올바르지 않습니다 (기대: {}, 입력: {})",
+                    spec.name,
+                    kind_name(spec.kind),
+                    ConfigValueKind::json_type_name(value)
+                ),
+                suggestion: None,
+            }),
+            Some(_) => {}
+        }
+    }
+
+    report
+}
+
+/// 기대 타입 이름 (에러 메시지용)
+fn kind_name(kind: ConfigValueKind) -> &'static str {
+    match kind {
+        ConfigValueKind::Integer => "integer",
+        ConfigValueKind::Number => "number",
+        ConfigValueKind::Decimal => "number 또는 숫자 문자열",
+        ConfigValueKind::Boolean => "boolean",
+        ConfigValueKind::String => "string",
+        ConfigValueKind::Array => "array",
+        ConfigValueKind::Object => "object",
+        ConfigValueKind::Any => "any",
+    }
+}
+
+/// 편집 거리가 가장 가까운 필드명 제안
+fn suggest_field(key: &str, fields: &[ConfigFieldSpec]) -> Option<String> {
+    fields
+        .iter()
+        .map(|spec| (edit_distance(key, &spec.name), &spec.name))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name.clone())
+}
+
+/// 레벤슈타인 편집 거리
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn spec(name: &str, kind: ConfigValueKind, required: bool) -> ConfigFieldSpec {
+        ConfigFieldSpec {
+            name: name.to_string(),
+            aliases: Vec::new(),
+            kind,
+            required,
+            nullable: false,
+        }
+    }
+
+    fn sample_fields() -> Vec<ConfigFieldSpec> {
+        vec![
+            spec("ticker", ConfigValueKind::String, true),
+            spec("rsi_period", ConfigValueKind::Integer, false),
+            spec("oversold", ConfigValueKind::Decimal, false),
+            ConfigFieldSpec {
+                aliases: vec!["limit".to_string()],
+                nullable: true,
+                ..spec("max_amount", ConfigValueKind::Decimal, false)
+            },
+        ]
+    }
+
+    #[test]
+    fn test_valid_config() {
+        let report = validate_against_fields(
+            "rsi",
+            &sample_fields(),
+            &json!({"ticker": "005930", "rsi_period": 14, "oversold": "30", "limit": null}),
+        );
+        assert!(report.is_valid());
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_field_is_warning_with_suggestion() {
+        let report = validate_against_fields(
+            "rsi",
+            &sample_fields(),
+            &json!({"ticker": "005930", "rsi_peroid": 14}),
+        );
+
+        assert!(report.is_valid());
+        let warnings: Vec<_> = report.warnings().collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, ConfigIssueKind::UnknownField);
+        assert_eq!(warnings[0].suggestion.as_deref(), Some("rsi_period"));
+    }
+
+    #[test]
+    fn test_missing_required_and_type_mismatch_are_errors() {
+        let report = validate_against_fields(
+            "rsi",
+            &sample_fields(),
+            &json!({"rsi_period": "14", "oversold": "abc"}),
+        );
+
+        assert!(!report.is_valid());
+        let kinds: Vec<_> = report.errors().map(|issue| issue.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ConfigIssueKind::MissingRequired,
+                ConfigIssueKind::TypeMismatch,
+                ConfigIssueKind::TypeMismatch
+            ]
+        );
+        assert!(report.error_summary().contains("'ticker'"));
+    }
+
+    #[test]
+    fn test_non_object_config() {
+        let report = validate_against_fields("rsi", &sample_fields(), &json!([1, 2]));
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].kind, ConfigIssueKind::NotAnObject);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("rsi_period", "rsi_period"), 0);
+        assert_eq!(edit_distance("rsi_peroid", "rsi_period"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}
@@ -6,6 +6,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use thiserror::Error;
@@ -13,10 +14,17 @@ use tokio::sync::{broadcast, mpsc, RwLock};
 use tracing::{debug, error, info, warn};
 use trader_core::{
     domain::{MarketDataType, SignalConflictError, StrategyContext},
-    Kline, MarketData, Order, Position, Signal, SignalType, Timeframe,
+    resolve_signal_conflicts, ConflictResolutionPolicy, Kline, MarketData, Order, Position, Signal,
+    SignalConflictResolution, SignalType, Timeframe,
 };
 
-use crate::Strategy;
+use crate::{
+    strategies::common::{
+        diversification::{ConcentrationLimits, DiversificationConfig, DiversificationGuard},
+        performance_target::{PerformanceTargetConfig, PerformanceTargetTracker, TargetEvaluation},
+    },
+    Strategy,
+};
 
 /// Signal 충돌 이벤트.
 ///
@@ -36,6 +44,9 @@ pub struct SignalConflictEvent {
     pub message: String,
     /// 타임스탬프
     pub timestamp: DateTime<Utc>,
+    /// 전략 간 충돌 해소 결과 (채택/기각 내역, 전략 간 충돌인 경우만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<SignalConflictResolution>,
 }
 
 impl SignalConflictEvent {
@@ -55,8 +66,36 @@ impl SignalConflictEvent {
             conflict_type: conflict_type.to_string(),
             message: error.to_string(),
             timestamp: Utc::now(),
+            resolution: None,
         }
     }
+
+    /// 전략 간 충돌 해소 결과에서 이벤트 생성.
+    ///
+    /// 충돌에 참여한 전략마다 하나씩 생성하여 전략별 구독자가 모두 알림을 받도록 합니다.
+    pub fn from_resolution(resolution: &SignalConflictResolution) -> Vec<Self> {
+        let message = resolution.summary();
+
+        resolution
+            .strategy_ids()
+            .into_iter()
+            .filter_map(|strategy_id| {
+                let outcome = resolution
+                    .outcomes
+                    .iter()
+                    .find(|o| o.strategy_id == strategy_id)?;
+                Some(Self {
+                    ticker: resolution.ticker.clone(),
+                    signal_type: outcome.signal_type,
+                    conflict_type: "cross_strategy".to_string(),
+                    message: message.clone(),
+                    timestamp: resolution.timestamp,
+                    resolution: Some(resolution.clone()),
+                    strategy_id,
+                })
+            })
+            .collect()
+    }
 }
 
 /// 전략 엔진 에러.
@@ -98,6 +137,10 @@ pub struct StrategyInstance {
     custom_name: Option<String>,
     /// 전략 컨텍스트 (다중 타임프레임 데이터 등)
     context: Arc<RwLock<StrategyContext>>,
+    /// 성과 목표 추적기 (설정에 `performance_target`이 있을 때만)
+    performance_target: Option<PerformanceTargetTracker>,
+    /// 분산 한도 강제기 (설정에 `diversification`이 있을 때만)
+    diversification: Option<DiversificationGuard>,
 }
 
 /// 전략 통계.
@@ -134,6 +177,9 @@ pub struct StrategyStatus {
     pub stats: StrategyStats,
     /// 현재 전략 상태
     pub state: Value,
+    /// 마지막 성과 목표 평가 결과
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performance_target: Option<TargetEvaluation>,
 }
 
 /// 엔진 설정.
@@ -158,6 +204,14 @@ pub struct EngineConfig {
     /// 신호 중복 제거 윈도우(밀리초)
     #[serde(default = "default_dedup_window")]
     pub dedup_window_ms: u64,
+
+    /// RiskManager 집중도 한도 (전략 분산 한도와 결합해 더 엄격한 값 적용)
+    #[serde(default)]
+    pub risk_limits: Option<ConcentrationLimits>,
+
+    /// 전략 간 상반된 신호 충돌 해소 정책 (None이면 모든 신호 통과)
+    #[serde(default)]
+    pub conflict_policy: Option<ConflictResolutionPolicy>,
 }
 
 fn default_max_strategies() -> usize {
@@ -237,6 +291,8 @@ impl Default for EngineConfig {
             broadcast_buffer_size: default_broadcast_buffer(),
             deduplicate_signals: default_true(),
             dedup_window_ms: default_dedup_window(),
+            risk_limits: None,
+            conflict_policy: None,
         }
     }
 }
@@ -353,6 +409,11 @@ impl StrategyEngine {
             shared_context.unwrap_or_else(|| Arc::new(RwLock::new(StrategyContext::default())));
         strategy.set_context(Arc::clone(&context));
 
+        let performance_target = PerformanceTargetConfig::from_strategy_config(&config)
+            .map(PerformanceTargetTracker::new);
+        let diversification =
+            DiversificationConfig::from_strategy_config(&config).map(DiversificationGuard::new);
+
         strategies.insert(
             id,
             StrategyInstance {
@@ -362,6 +423,8 @@ impl StrategyEngine {
                 stats: StrategyStats::default(),
                 custom_name,
                 context,
+                performance_target,
+                diversification,
             },
         );
 
@@ -489,6 +552,10 @@ impl StrategyEngine {
             running: instance.running,
             stats: instance.stats.clone(),
             state: instance.strategy.get_state(),
+            performance_target: instance
+                .performance_target
+                .as_ref()
+                .and_then(|t| t.last_evaluation().cloned()),
         })
     }
 
@@ -551,6 +618,10 @@ impl StrategyEngine {
                     running: instance.running,
                     stats: instance.stats.clone(),
                     state: instance.strategy.get_state(),
+                    performance_target: instance
+                        .performance_target
+                        .as_ref()
+                        .and_then(|t| t.last_evaluation().cloned()),
                 },
             );
         }
@@ -675,6 +746,35 @@ impl StrategyEngine {
                         }
                     }
 
+                    // 전략 분산 한도 적용 (보유 종목 수, 종목당 비중)
+                    let valid_signals: Vec<Signal> = valid_signals.into_iter().cloned().collect();
+                    let valid_signals = match &instance.diversification {
+                        Some(guard) => {
+                            let outcome =
+                                guard.apply(&ctx, valid_signals, self.config.risk_limits.as_ref());
+
+                            for skipped in &outcome.skipped {
+                                info!(
+                                    strategy_id = %id,
+                                    ticker = %skipped.signal.ticker,
+                                    signal_type = ?skipped.signal.signal_type,
+                                    reason = %skipped.reason,
+                                    "Signal skipped by diversification limit"
+                                );
+                            }
+                            for ticker in &outcome.replaced {
+                                info!(
+                                    strategy_id = %id,
+                                    ticker = %ticker,
+                                    "Weakest holding replaced by diversification policy"
+                                );
+                            }
+
+                            outcome.signals
+                        }
+                        None => valid_signals,
+                    };
+
                     for signal in valid_signals {
                         instance.stats.signals_generated += 1;
                         instance.stats.last_signal_time = Some(Utc::now());
@@ -687,7 +787,7 @@ impl StrategyEngine {
                             "Strategy generated signal"
                         );
 
-                        all_signals.push(signal.clone());
+                        all_signals.push(signal);
                     }
                 }
                 Err(e) => {
@@ -701,6 +801,28 @@ impl StrategyEngine {
             }
         }
 
+        // 같은 틱에서 모인 전략 간 상반된 신호 충돌 해소
+        if let Some(policy) = self.config.conflict_policy {
+            let resolved = resolve_signal_conflicts(all_signals, policy);
+
+            for resolution in &resolved.conflicts {
+                warn!(
+                    ticker = %resolution.ticker,
+                    policy = %resolution.policy,
+                    net_side = ?resolution.net_side,
+                    "Cross-strategy signal conflict resolved"
+                );
+
+                for event in SignalConflictEvent::from_resolution(resolution) {
+                    if let Err(e) = self.conflict_tx.try_send(event) {
+                        debug!(error = %e, "Failed to send conflict event (channel full or closed)");
+                    }
+                }
+            }
+
+            all_signals = resolved.signals;
+        }
+
         // 활성화된 경우 신호 중복 제거
         if self.config.deduplicate_signals {
             all_signals = self.deduplicate_signals(all_signals).await;
@@ -1022,6 +1144,32 @@ impl StrategyEngine {
         }
     }
 
+    /// 전략 성과 목표 평가.
+    ///
+    /// 기간 수익률을 전달하면 설정된 목표(절대/벤치마크 대비) 달성 여부를 평가하고
+    /// 연속 부진을 추적합니다. 벤치마크 수익률이 `None`이면 절대수익 기준으로 폴백합니다.
+    ///
+    /// # 반환
+    ///
+    /// 전략에 성과 목표가 설정되어 있으면 `Some(evaluation)`, 아니면 `None`
+    pub async fn evaluate_performance_target(
+        &self,
+        id: &str,
+        strategy_return_pct: Decimal,
+        benchmark_return_pct: Option<Decimal>,
+    ) -> Result<Option<TargetEvaluation>, EngineError> {
+        let mut strategies = self.strategies.write().await;
+
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+        Ok(instance
+            .performance_target
+            .as_mut()
+            .map(|tracker| tracker.record(id, strategy_return_pct, benchmark_return_pct)))
+    }
+
     /// 전략 설정 업데이트 (핫 리로드).
     pub async fn update_strategy_config(&self, id: &str, config: Value) -> Result<(), EngineError> {
         let mut strategies = self.strategies.write().await;
@@ -1044,6 +1192,16 @@ impl StrategyEngine {
         // 새 설정 저장 (name 필드 제외)
         instance.config = config_for_strategy.clone();
 
+        // 성과 목표가 바뀌었으면 추적기 재생성 (같으면 연속 부진 카운트 유지)
+        let new_target = PerformanceTargetConfig::from_strategy_config(&config_for_strategy);
+        if new_target.as_ref() != instance.performance_target.as_ref().map(|t| t.config()) {
+            instance.performance_target = new_target.map(PerformanceTargetTracker::new);
+        }
+
+        instance.diversification =
+            DiversificationConfig::from_strategy_config(&config_for_strategy)
+                .map(DiversificationGuard::new);
+
         // 실행 중이면 전략 재초기화
         if instance.running {
             info!(strategy_id = %id, "Hot reloading strategy configuration");
@@ -1156,6 +1314,155 @@ mod tests {
         }
     }
 
+    /// 매 데이터마다 고정 방향 신호를 내는 테스트 전략.
+    struct FixedSideStrategy {
+        name: String,
+        side: trader_core::Side,
+        strength: f64,
+    }
+
+    #[async_trait]
+    impl Strategy for FixedSideStrategy {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Fixed side strategy"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            // Scale 신호는 컨텍스트 잔고/포지션 검증을 거치지 않음
+            let signal = Signal::new(
+                &self.name,
+                data.ticker.clone(),
+                self.side,
+                SignalType::Scale,
+            )
+            .with_strength(self.strength);
+            Ok(vec![signal])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({})
+        }
+    }
+
+    async fn engine_with_opposing_strategies(policy: ConflictResolutionPolicy) -> StrategyEngine {
+        let engine = StrategyEngine::new(EngineConfig {
+            conflict_policy: Some(policy),
+            ..EngineConfig::default()
+        });
+
+        for (id, side, strength) in [
+            ("long", trader_core::Side::Buy, 0.9),
+            ("short", trader_core::Side::Sell, 0.4),
+        ] {
+            let strategy = Box::new(FixedSideStrategy {
+                name: id.to_string(),
+                side,
+                strength,
+            });
+            engine
+                .register_strategy(id, strategy, serde_json::json!({}), None, None)
+                .await
+                .unwrap();
+            engine.start_strategy(id).await.unwrap();
+        }
+
+        engine
+    }
+
+    fn test_market_data(ticker: &str) -> MarketData {
+        let now = Utc::now();
+        let price = Decimal::from(100);
+        let kline = Kline::new(
+            ticker.to_string(),
+            Timeframe::D1,
+            now,
+            price,
+            price,
+            price,
+            price,
+            Decimal::from(1000),
+            now,
+        );
+        MarketData::from_kline("test", kline)
+    }
+
+    #[tokio::test]
+    async fn test_conflict_policy_highest_confidence() {
+        let mut engine =
+            engine_with_opposing_strategies(ConflictResolutionPolicy::HighestConfidence).await;
+        let mut conflict_rx = engine.take_conflict_receiver().unwrap();
+
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].strategy_id, "long");
+
+        // 충돌에 참여한 전략마다 구조화된 이벤트 발행
+        let mut events = [
+            conflict_rx.try_recv().unwrap(),
+            conflict_rx.try_recv().unwrap(),
+        ];
+        events.sort_by(|a, b| a.strategy_id.cmp(&b.strategy_id));
+        assert_eq!(events[0].strategy_id, "long");
+        assert_eq!(events[1].strategy_id, "short");
+        assert_eq!(events[0].conflict_type, "cross_strategy");
+
+        let resolution = events[0].resolution.as_ref().unwrap();
+        assert_eq!(resolution.net_side, Some(trader_core::Side::Buy));
+        assert_eq!(resolution.rejected().next().unwrap().strategy_id, "short");
+    }
+
+    #[tokio::test]
+    async fn test_conflict_policy_reject() {
+        let engine = engine_with_opposing_strategies(ConflictResolutionPolicy::Reject).await;
+
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+
+        assert!(signals.is_empty());
+    }
+
     #[tokio::test]
     async fn test_engine_register_strategy() {
         let engine = StrategyEngine::new(EngineConfig::default());
@@ -1232,4 +1539,53 @@ mod tests {
 
         assert!(matches!(result, Err(EngineError::StrategyAlreadyExists(_))));
     }
+
+    #[tokio::test]
+    async fn test_evaluate_performance_target() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+
+        let config = serde_json::json!({
+            "performance_target": {
+                "benchmark": "SPY",
+                "goal": { "type": "benchmark_relative", "excess_return_pct": "2" }
+            }
+        });
+        engine
+            .register_strategy(
+                "test1",
+                Box::new(TestStrategy::new("test")),
+                config,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        engine
+            .register_strategy(
+                "test2",
+                Box::new(TestStrategy::new("test")),
+                serde_json::json!({}),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let eval = engine
+            .evaluate_performance_target("test1", Decimal::from(10), Some(Decimal::from(5)))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(eval.achieved);
+
+        let status = engine.get_strategy_status("test1").await.unwrap();
+        assert!(status.performance_target.is_some());
+
+        // 목표 미설정 전략은 평가하지 않음
+        let none = engine
+            .evaluate_performance_target("test2", Decimal::from(10), None)
+            .await
+            .unwrap();
+        assert!(none.is_none());
+    }
 }
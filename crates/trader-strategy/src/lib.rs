@@ -30,6 +30,7 @@
 //! }
 //! ```
 
+pub mod config_validation;
 pub mod engine;
 pub mod macros;
 pub mod plugin;
@@ -40,6 +41,9 @@ pub mod strategies;
 pub mod traits;
 
 // 주요 타입 재내보내기
+pub use config_validation::{
+    validate_against_fields, ConfigIssue, ConfigIssueKind, ConfigIssueLevel, ConfigValidationReport,
+};
 pub use engine::{
     extract_tickers_from_config, EngineConfig, EngineError, EngineStats, SignalConflictEvent,
     StrategyEngine, StrategyStats, StrategyStatus,
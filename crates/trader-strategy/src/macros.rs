@@ -81,6 +81,8 @@ macro_rules! register_strategy {
                 supported_markets: &[$(trader_core::MarketType::$market),*],
                 factory: || Box::new($factory()),
                 ui_schema_factory: None,
+                config_fields_factory: None,
+                config_check: None,
             }
         }
     };
@@ -111,6 +113,12 @@ macro_rules! register_strategy {
                 supported_markets: &[$(trader_core::MarketType::$market),*],
                 factory: || Box::new($factory()),
                 ui_schema_factory: Some(|| <$config_ty>::ui_schema()),
+                config_fields_factory: Some(|| <$config_ty>::config_fields()),
+                config_check: Some(|value| {
+                    serde_json::from_value::<$config_ty>(value.clone())
+                        .map(|_| ())
+                        .map_err(|e| e.to_string())
+                }),
             }
         }
     };
@@ -141,6 +149,8 @@ macro_rules! register_strategy {
                 supported_markets: &[$(trader_core::MarketType::$market),*],
                 factory: || Box::new($factory()),
                 ui_schema_factory: None,
+                config_fields_factory: None,
+                config_check: None,
             }
         }
     };
@@ -172,6 +182,12 @@ macro_rules! register_strategy {
                 supported_markets: &[$(trader_core::MarketType::$market),*],
                 factory: || Box::new($factory()),
                 ui_schema_factory: Some(|| <$config_ty>::ui_schema()),
+                config_fields_factory: Some(|| <$config_ty>::config_fields()),
+                config_check: Some(|value| {
+                    serde_json::from_value::<$config_ty>(value.clone())
+                        .map(|_| ())
+                        .map_err(|e| e.to_string())
+                }),
             }
         }
     };
@@ -202,6 +218,8 @@ macro_rules! register_strategy {
                 supported_markets: &[$(trader_core::MarketType::$market),*],
                 factory: || Box::new(<$ty>::new()),
                 ui_schema_factory: None,
+                config_fields_factory: None,
+                config_check: None,
             }
         }
     };
@@ -233,6 +251,12 @@ macro_rules! register_strategy {
                 supported_markets: &[$(trader_core::MarketType::$market),*],
                 factory: || Box::new(<$ty>::new()),
                 ui_schema_factory: Some(|| <$config_ty>::ui_schema()),
+                config_fields_factory: Some(|| <$config_ty>::config_fields()),
+                config_check: Some(|value| {
+                    serde_json::from_value::<$config_ty>(value.clone())
+                        .map(|_| ())
+                        .map_err(|e| e.to_string())
+                }),
             }
         }
     };
@@ -262,6 +286,8 @@ macro_rules! register_strategy {
                 supported_markets: &[$(trader_core::MarketType::$market),*],
                 factory: || Box::new(<$ty>::new()),
                 ui_schema_factory: None,
+                config_fields_factory: None,
+                config_check: None,
             }
         }
     };
@@ -292,6 +318,12 @@ macro_rules! register_strategy {
                 supported_markets: &[$(trader_core::MarketType::$market),*],
                 factory: || Box::new(<$ty>::new()),
                 ui_schema_factory: Some(|| <$config_ty>::ui_schema()),
+                config_fields_factory: Some(|| <$config_ty>::config_fields()),
+                config_check: Some(|value| {
+                    serde_json::from_value::<$config_ty>(value.clone())
+                        .map(|_| ())
+                        .map_err(|e| e.to_string())
+                }),
             }
         }
     };
@@ -4,7 +4,12 @@
 //! `inventory` crate를 사용하여 전략 메타데이터를 자동 등록합니다.
 
 use serde::{Deserialize, Serialize};
-use trader_core::{MarketType, StrategyUISchema};
+use trader_core::{ConfigFieldSpec, MarketType, StrategyUISchema};
+
+use crate::config_validation::{validate_against_fields, ConfigValidationReport};
+
+/// 설정 JSON 역직렬화 검사 함수 타입.
+pub type ConfigCheckFn = fn(&serde_json::Value) -> Result<(), String>;
 
 /// 전략 카테고리
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -63,6 +68,16 @@ pub struct StrategyMeta {
     /// `Config::ui_schema()`를 호출하여 SDUI 스키마를 반환합니다.
     /// None인 경우 기본 스키마가 사용됩니다.
     pub ui_schema_factory: Option<fn() -> StrategyUISchema>,
+
+    /// 설정 필드 명세 팩토리 함수 (설정 검증용)
+    ///
+    /// `Config::config_fields()`를 호출하여 serde 기준 필드 명세를 반환합니다.
+    pub config_fields_factory: Option<fn() -> Vec<ConfigFieldSpec>>,
+
+    /// 설정 역직렬화 검사 함수
+    ///
+    /// 설정 JSON을 Config 타입으로 역직렬화해 보고 실패 시 serde 에러 메시지를 반환합니다.
+    pub config_check: Option<ConfigCheckFn>,
 }
 
 impl std::fmt::Debug for StrategyMeta {
@@ -79,6 +94,11 @@ impl std::fmt::Debug for StrategyMeta {
             .field("supported_markets", &self.supported_markets)
             .field("factory", &"<fn>")
             .field("ui_schema_factory", &self.ui_schema_factory.map(|_| "<fn>"))
+            .field(
+                "config_fields_factory",
+                &self.config_fields_factory.map(|_| "<fn>"),
+            )
+            .field("config_check", &self.config_check.map(|_| "<fn>"))
             .finish()
     }
 }
@@ -119,6 +139,45 @@ impl StrategyRegistry {
             .ok_or_else(|| format!("Unknown strategy: {}", query))
     }
 
+    /// 전략 설정 필드 명세 조회
+    ///
+    /// 설정 타입이 등록되지 않은 전략은 `None`을 반환합니다.
+    pub fn config_fields(query: &str) -> Option<Vec<ConfigFieldSpec>> {
+        Self::find(query)
+            .and_then(|meta| meta.config_fields_factory)
+            .map(|factory| factory())
+    }
+
+    /// 전략 설정 JSON 검증
+    ///
+    /// `initialize()` 전에 알 수 없는 필드(경고), 타입 불일치·필수값 누락(오류)을 검사합니다.
+    /// 필드 검사에서 오류가 없으면 실제 설정 타입으로 역직렬화하여 enum 값 등 세부 오류도 확인합니다.
+    ///
+    /// # 에러
+    ///
+    /// 등록되지 않은 전략 ID인 경우
+    pub fn validate_config(
+        query: &str,
+        config: &serde_json::Value,
+    ) -> Result<ConfigValidationReport, String> {
+        let meta = Self::find(query).ok_or_else(|| format!("Unknown strategy: {}", query))?;
+
+        let Some(fields_factory) = meta.config_fields_factory else {
+            return Ok(ConfigValidationReport::unavailable(meta.id));
+        };
+
+        let mut report = validate_against_fields(meta.id, &fields_factory(), config);
+        if !report.has_errors() {
+            if let Some(check) = meta.config_check {
+                if let Err(message) = check(config) {
+                    report.push_deserialize_error(message);
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     /// 전략 목록 (프론트엔드용 JSON)
     pub fn to_json() -> serde_json::Value {
         use serde_json::json;
@@ -205,6 +205,16 @@ pub struct AssetAllocationConfig {
     #[schema(label = "리밸런싱 임계값 (%)", min = 1, max = 20)]
     pub rebalance_threshold: Decimal,
 
+    /// 비용 인지 리밸런싱 (자산별 편차가 임계값을 넘고 거래비용보다 개선분이 클 때만 거래)
+    #[serde(default)]
+    #[schema(label = "비용 인지 리밸런싱", field_type = "boolean", default = false)]
+    pub cost_aware_rebalance: bool,
+
+    /// 최소 거래 금액 (미만 조정은 다음 주기로 이월)
+    #[serde(default = "default_min_trade_amount")]
+    #[schema(label = "최소 거래 금액", min = 0)]
+    pub min_trade_amount: Decimal,
+
     /// 최소 Global Score
     #[schema(label = "최소 GlobalScore")]
     pub min_global_score: Option<Decimal>,
@@ -224,6 +234,10 @@ impl Default for AssetAllocationConfig {
     }
 }
 
+fn default_min_trade_amount() -> Decimal {
+    dec!(10)
+}
+
 // ================================================================================================
 // 전략별 UI Config (SDUI용)
 // ================================================================================================
@@ -288,6 +302,27 @@ pub struct HaaConfig {
     )]
     pub rebalance_threshold: Decimal,
 
+    /// 비용 인지 리밸런싱 (자산별 편차가 임계값을 넘고 거래비용보다 개선분이 클 때만 거래)
+    #[serde(default)]
+    #[schema(
+        label = "비용 인지 리밸런싱",
+        section = "timing",
+        field_type = "boolean",
+        default = false
+    )]
+    pub cost_aware_rebalance: bool,
+
+    /// 최소 거래 금액 (미만 조정은 다음 주기로 이월)
+    #[serde(default = "default_min_trade_amount")]
+    #[schema(
+        label = "최소 거래 금액",
+        section = "timing",
+        field_type = "number",
+        min = 0,
+        default = "10"
+    )]
+    pub min_trade_amount: Decimal,
+
     /// 최소 Global Score
     #[schema(
         label = "최소 GlobalScore",
@@ -324,6 +359,8 @@ impl From<HaaConfig> for AssetAllocationConfig {
         base.defensive_top_n = cfg.defensive_top_n;
         base.invest_rate = cfg.invest_rate;
         base.rebalance_threshold = cfg.rebalance_threshold;
+        base.cost_aware_rebalance = cfg.cost_aware_rebalance;
+        base.min_trade_amount = cfg.min_trade_amount;
         base.min_global_score = Some(cfg.min_global_score);
         base.canary_threshold = cfg.canary_threshold;
         base.exit_config = cfg.exit_config;
@@ -401,6 +438,27 @@ pub struct XaaConfig {
     )]
     pub rebalance_threshold: Decimal,
 
+    /// 비용 인지 리밸런싱 (자산별 편차가 임계값을 넘고 거래비용보다 개선분이 클 때만 거래)
+    #[serde(default)]
+    #[schema(
+        label = "비용 인지 리밸런싱",
+        section = "timing",
+        field_type = "boolean",
+        default = false
+    )]
+    pub cost_aware_rebalance: bool,
+
+    /// 최소 거래 금액 (미만 조정은 다음 주기로 이월)
+    #[serde(default = "default_min_trade_amount")]
+    #[schema(
+        label = "최소 거래 금액",
+        section = "timing",
+        field_type = "number",
+        min = 0,
+        default = "10"
+    )]
+    pub min_trade_amount: Decimal,
+
     /// 최소 Global Score
     #[schema(
         label = "최소 GlobalScore",
@@ -441,6 +499,8 @@ impl From<XaaConfig> for AssetAllocationConfig {
         };
         base.invest_rate = cfg.invest_rate;
         base.rebalance_threshold = cfg.rebalance_threshold;
+        base.cost_aware_rebalance = cfg.cost_aware_rebalance;
+        base.min_trade_amount = cfg.min_trade_amount;
         base.min_global_score = Some(cfg.min_global_score);
         base.canary_threshold = cfg.canary_threshold;
         base.exit_config = cfg.exit_config;
@@ -507,6 +567,27 @@ pub struct BaaConfig {
     )]
     pub rebalance_threshold: Decimal,
 
+    /// 비용 인지 리밸런싱 (자산별 편차가 임계값을 넘고 거래비용보다 개선분이 클 때만 거래)
+    #[serde(default)]
+    #[schema(
+        label = "비용 인지 리밸런싱",
+        section = "timing",
+        field_type = "boolean",
+        default = false
+    )]
+    pub cost_aware_rebalance: bool,
+
+    /// 최소 거래 금액 (미만 조정은 다음 주기로 이월)
+    #[serde(default = "default_min_trade_amount")]
+    #[schema(
+        label = "최소 거래 금액",
+        section = "timing",
+        field_type = "number",
+        min = 0,
+        default = "10"
+    )]
+    pub min_trade_amount: Decimal,
+
     /// 최소 Global Score
     #[schema(
         label = "최소 GlobalScore",
@@ -543,6 +624,8 @@ impl From<BaaConfig> for AssetAllocationConfig {
         base.defensive_top_n = cfg.defensive_top_n;
         base.invest_rate = cfg.invest_rate;
         base.rebalance_threshold = cfg.rebalance_threshold;
+        base.cost_aware_rebalance = cfg.cost_aware_rebalance;
+        base.min_trade_amount = cfg.min_trade_amount;
         base.min_global_score = Some(cfg.min_global_score);
         base.canary_threshold = cfg.canary_threshold;
         base.exit_config = cfg.exit_config;
@@ -588,6 +671,27 @@ pub struct AllWeatherConfig {
     )]
     pub rebalance_threshold: Decimal,
 
+    /// 비용 인지 리밸런싱 (자산별 편차가 임계값을 넘고 거래비용보다 개선분이 클 때만 거래)
+    #[serde(default)]
+    #[schema(
+        label = "비용 인지 리밸런싱",
+        section = "timing",
+        field_type = "boolean",
+        default = false
+    )]
+    pub cost_aware_rebalance: bool,
+
+    /// 최소 거래 금액 (미만 조정은 다음 주기로 이월)
+    #[serde(default = "default_min_trade_amount")]
+    #[schema(
+        label = "최소 거래 금액",
+        section = "timing",
+        field_type = "number",
+        min = 0,
+        default = "10"
+    )]
+    pub min_trade_amount: Decimal,
+
     /// 청산 설정 (손절/익절/트레일링 스탑).
     #[serde(default = "ExitConfig::for_rebalancing")]
     #[fragment("risk.exit_config")]
@@ -600,6 +704,8 @@ impl From<AllWeatherConfig> for AssetAllocationConfig {
         base.cash_ticker = cfg.cash_ticker;
         base.invest_rate = cfg.invest_rate;
         base.rebalance_threshold = cfg.rebalance_threshold;
+        base.cost_aware_rebalance = cfg.cost_aware_rebalance;
+        base.min_trade_amount = cfg.min_trade_amount;
         base.exit_config = cfg.exit_config;
         // AllWeather는 min_global_score와 canary 미사용
         base
@@ -644,6 +750,27 @@ pub struct DualMomentumConfig {
     )]
     pub rebalance_threshold: Decimal,
 
+    /// 비용 인지 리밸런싱 (자산별 편차가 임계값을 넘고 거래비용보다 개선분이 클 때만 거래)
+    #[serde(default)]
+    #[schema(
+        label = "비용 인지 리밸런싱",
+        section = "timing",
+        field_type = "boolean",
+        default = false
+    )]
+    pub cost_aware_rebalance: bool,
+
+    /// 최소 거래 금액 (미만 조정은 다음 주기로 이월)
+    #[serde(default = "default_min_trade_amount")]
+    #[schema(
+        label = "최소 거래 금액",
+        section = "timing",
+        field_type = "number",
+        min = 0,
+        default = "10"
+    )]
+    pub min_trade_amount: Decimal,
+
     /// 최소 Global Score
     #[schema(
         label = "최소 GlobalScore",
@@ -667,6 +794,8 @@ impl From<DualMomentumConfig> for AssetAllocationConfig {
         base.cash_ticker = cfg.cash_ticker;
         base.invest_rate = cfg.invest_rate;
         base.rebalance_threshold = cfg.rebalance_threshold;
+        base.cost_aware_rebalance = cfg.cost_aware_rebalance;
+        base.min_trade_amount = cfg.min_trade_amount;
         base.min_global_score = Some(cfg.min_global_score);
         base.exit_config = cfg.exit_config;
         // DualMomentum의 canary_threshold는 1.0 고정
@@ -703,6 +832,8 @@ impl AssetAllocationConfig {
             cash_ticker: "BIL".to_string(),
             invest_rate: dec!(1.0),
             rebalance_threshold: dec!(5.0),
+            cost_aware_rebalance: false,
+            min_trade_amount: default_min_trade_amount(),
             min_global_score: Some(dec!(55)),
             canary_threshold: dec!(0.5), // 50% 이상 양수 모멘텀
             exit_config: ExitConfig::for_rebalancing(),
@@ -742,6 +873,8 @@ impl AssetAllocationConfig {
             cash_ticker: "BIL".to_string(),
             invest_rate: dec!(1.0),
             rebalance_threshold: dec!(5.0),
+            cost_aware_rebalance: false,
+            min_trade_amount: default_min_trade_amount(),
             min_global_score: Some(dec!(55)),
             canary_threshold: dec!(0.5),
             exit_config: ExitConfig::for_rebalancing(),
@@ -782,6 +915,8 @@ impl AssetAllocationConfig {
             cash_ticker: "BIL".to_string(),
             invest_rate: dec!(1.0),
             rebalance_threshold: dec!(5.0),
+            cost_aware_rebalance: false,
+            min_trade_amount: default_min_trade_amount(),
             min_global_score: Some(dec!(55)),
             canary_threshold: dec!(0.75), // 75% 이상 양수
             exit_config: ExitConfig::for_rebalancing(),
@@ -812,6 +947,8 @@ impl AssetAllocationConfig {
             cash_ticker: "BIL".to_string(),
             invest_rate: dec!(1.0),
             rebalance_threshold: dec!(5.0),
+            cost_aware_rebalance: false,
+            min_trade_amount: default_min_trade_amount(),
             min_global_score: None,      // 정적 배분이므로 스코어 필터 없음
             canary_threshold: dec!(0.0), // 카나리아 없음
             exit_config: ExitConfig::for_rebalancing(),
@@ -842,6 +979,8 @@ impl AssetAllocationConfig {
             cash_ticker: "BIL".to_string(),
             invest_rate: dec!(1.0),
             rebalance_threshold: dec!(5.0),
+            cost_aware_rebalance: false,
+            min_trade_amount: default_min_trade_amount(),
             min_global_score: Some(dec!(50)),
             canary_threshold: dec!(1.0), // 모든 카나리아 양수여야 공격 모드
             exit_config: ExitConfig::for_rebalancing(),
@@ -879,6 +1018,10 @@ pub struct AssetAllocationStrategy {
     momentum_calculator: MomentumCalculator,
     current_mode: PortfolioMode,
     cash_balance: Decimal,
+    /// 비용 인지 필터 또는 최소 거래 금액으로 이월된 티커
+    deferred_tickers: Vec<String>,
+    /// 누적 리밸런싱 회전율 (주기별 거래대금 / 포트폴리오 가치 합계)
+    cumulative_turnover: Decimal,
 }
 
 impl AssetAllocationStrategy {
@@ -893,6 +1036,8 @@ impl AssetAllocationStrategy {
             momentum_calculator: MomentumCalculator::standard(),
             current_mode: PortfolioMode::Defensive,
             cash_balance: Decimal::ZERO,
+            deferred_tickers: Vec::new(),
+            cumulative_turnover: Decimal::ZERO,
         }
     }
 
@@ -900,6 +1045,7 @@ impl AssetAllocationStrategy {
     pub fn with_config(config: AssetAllocationConfig) -> Self {
         let mut strategy = Self::new();
         strategy.init_momentum_calculator(&config);
+        strategy.init_rebalance_calculator(&config);
         strategy.config = Some(config);
         strategy
     }
@@ -954,6 +1100,19 @@ impl AssetAllocationStrategy {
         self.momentum_calculator = MomentumCalculator::new(momentum_config);
     }
 
+    /// 설정으로 리밸런싱 계산기 초기화.
+    ///
+    /// 비용 인지 모드에서는 `rebalance_threshold`(%)를 자산별 편차 임계값으로 사용합니다.
+    fn init_rebalance_calculator(&mut self, config: &AssetAllocationConfig) {
+        let mut rebalance_config = RebalanceConfig::us_market();
+        rebalance_config.min_trade_amount = config.min_trade_amount;
+        if config.cost_aware_rebalance {
+            rebalance_config.cost_aware = true;
+            rebalance_config.rebalance_threshold = config.rebalance_threshold / dec!(100);
+        }
+        self.rebalance_calculator = RebalanceCalculator::new(rebalance_config);
+    }
+
     /// StrategyContext에서 가격 히스토리 가져오기.
     fn get_price_history(&self, ticker: &str) -> Option<Vec<Decimal>> {
         let ctx = self.context.as_ref()?;
@@ -1167,14 +1326,35 @@ impl AssetAllocationStrategy {
             .rebalance_calculator
             .calculate_orders(&all_positions, &target_allocations);
 
+        // 이월된 조정 기록 (다음 주기에 다시 평가)
+        self.deferred_tickers = result
+            .deferred_orders
+            .iter()
+            .chain(result.filtered_orders.iter())
+            .map(|o| o.ticker.clone())
+            .collect();
+        if !self.deferred_tickers.is_empty() {
+            info!(
+                "[AssetAllocation] 다음 주기로 이월: {:?}",
+                self.deferred_tickers
+            );
+        }
+
         if !result.rebalance_needed {
             debug!(
                 "리밸런싱 불필요: 최대 편차 {:.2}%",
                 result.max_weight_deviation * dec!(100)
             );
+            // 비용 인지 모드는 이번 주기를 종료하고 다음 주기로 이월
+            if config.cost_aware_rebalance {
+                self.last_rebalance_ym =
+                    Some(format!("{}_{}", current_time.year(), current_time.month()));
+            }
             return Vec::new();
         }
 
+        self.cumulative_turnover += result.turnover();
+
         // 신호 생성
         let variant_name = match config.variant {
             StrategyVariant::Haa => "asset_allocation_haa",
@@ -1305,6 +1485,7 @@ impl Strategy for AssetAllocationStrategy {
         };
 
         self.init_momentum_calculator(&config);
+        self.init_rebalance_calculator(&config);
 
         // initial_capital 또는 amount가 있으면 cash_balance로 설정
         let capital_value = config_value
@@ -1408,6 +1589,9 @@ impl Strategy for AssetAllocationStrategy {
                 .map(|(k, v)| (k.clone(), v.to_string()))
                 .collect::<HashMap<_, _>>(),
             "cash_balance": self.cash_balance.to_string(),
+            "cost_aware_rebalance": self.config.as_ref().map(|c| c.cost_aware_rebalance),
+            "deferred_tickers": self.deferred_tickers,
+            "cumulative_turnover": self.cumulative_turnover.to_string(),
         })
     }
 
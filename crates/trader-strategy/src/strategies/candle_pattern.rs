@@ -21,8 +21,15 @@
 //!
 //! ## 전략 로직
 //! 1. 캔들스틱 패턴 감지
-//! 2. 패턴 강도 평가 (Volume, Trend 확인)
-//! 3. 다중 패턴 확인 시 강화 신호
+//! 2. 패턴 신뢰도 평가 (0~100: 몸통/꼬리 비율, 추세 배경, 거래량 급증)
+//! 3. 최소 신뢰도 미만 패턴 및 거래량 미확인 패턴 제외
+//! 4. 신뢰도가 가장 높은 패턴으로 신호 생성
+//!
+//! ## 신뢰도 구성 (합계 100)
+//! - 형태 (40): 패턴별 몸통/꼬리 비율 품질
+//! - 강도 (20): 패턴 감지 강도
+//! - 추세 배경 (25): 반전 패턴은 반대 추세 이후, 지속 패턴은 같은 추세에서 가점
+//! - 거래량 (15): 평균 대비 거래량 배수 (데이터 부족 시 절반)
 
 use std::{
     collections::{HashMap, VecDeque},
@@ -85,6 +92,41 @@ pub enum CandlePatternType {
     AbandonedBaby,
 }
 
+impl CandlePatternType {
+    /// 패턴을 구성하는 캔들 수
+    pub fn candle_count(&self) -> usize {
+        match self {
+            Self::Hammer
+            | Self::InvertedHammer
+            | Self::HangingMan
+            | Self::ShootingStar
+            | Self::Doji
+            | Self::LongLeggedDoji
+            | Self::DragonflyDoji
+            | Self::GravestoneDoji
+            | Self::Marubozu
+            | Self::SpinningTop => 1,
+            Self::BullishEngulfing
+            | Self::BearishEngulfing
+            | Self::BullishHarami
+            | Self::BearishHarami
+            | Self::PiercingLine
+            | Self::DarkCloudCover
+            | Self::Tweezer => 2,
+            Self::RisingThreeMethods | Self::FallingThreeMethods => 5,
+            _ => 3,
+        }
+    }
+
+    /// 추세 지속 패턴 여부 (그 외는 반전 패턴)
+    pub fn is_continuation(&self) -> bool {
+        matches!(
+            self,
+            Self::Marubozu | Self::RisingThreeMethods | Self::FallingThreeMethods
+        )
+    }
+}
+
 /// 패턴 방향
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PatternDirection {
@@ -100,6 +142,9 @@ pub struct DetectedPattern {
     pub direction: PatternDirection,
     pub strength: Decimal,
     pub confirmation: bool,
+    /// 신뢰도 점수 (0~100)
+    #[serde(default)]
+    pub confidence: Decimal,
 }
 
 /// 캔들 데이터
@@ -153,11 +198,33 @@ pub struct CandlePatternConfig {
     )]
     pub min_pattern_strength: Decimal,
 
+    /// 최소 패턴 신뢰도 (0-100)
+    #[serde(default = "default_min_confidence")]
+    #[schema(
+        label = "최소 패턴 신뢰도",
+        min = 0,
+        max = 100,
+        default = 50,
+        section = "indicator"
+    )]
+    pub min_confidence: Decimal,
+
     /// 볼륨 확인 사용
     #[serde(default = "default_use_volume")]
     #[schema(label = "볼륨 확인 사용", default = true, section = "filter")]
     pub use_volume_confirmation: bool,
 
+    /// 거래량 급증 배수 (패턴 캔들 거래량 / 직전 평균 거래량)
+    #[serde(default = "default_volume_surge_multiplier")]
+    #[schema(
+        label = "거래량 급증 배수",
+        min = 1.0,
+        max = 5.0,
+        default = 1.2,
+        section = "filter"
+    )]
+    pub volume_surge_multiplier: Decimal,
+
     /// 트렌드 확인 사용
     #[serde(default = "default_use_trend")]
     #[schema(label = "트렌드 확인 사용", default = true, section = "filter")]
@@ -227,9 +294,15 @@ fn default_trade_amount() -> Decimal {
 fn default_min_strength() -> Decimal {
     dec!(0.6)
 }
+fn default_min_confidence() -> Decimal {
+    dec!(50)
+}
 fn default_use_volume() -> bool {
     true
 }
+fn default_volume_surge_multiplier() -> Decimal {
+    dec!(1.2)
+}
 fn default_use_trend() -> bool {
     true
 }
@@ -252,7 +325,9 @@ impl Default for CandlePatternConfig {
             ticker: "005930".to_string(),
             trade_amount: default_trade_amount(),
             min_pattern_strength: default_min_strength(),
+            min_confidence: default_min_confidence(),
             use_volume_confirmation: default_use_volume(),
+            volume_surge_multiplier: default_volume_surge_multiplier(),
             use_trend_confirmation: default_use_trend(),
             trend_period: default_trend_period(),
             stop_loss_pct: default_stop_loss(),
@@ -425,6 +500,7 @@ impl CandlePatternStrategy {
                 direction: PatternDirection::Neutral,
                 strength: dec!(1) - body_ratio,
                 confirmation: false,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -458,6 +534,7 @@ impl CandlePatternStrategy {
                 direction,
                 strength: (lower / body / dec!(2)).min(dec!(1)),
                 confirmation: false,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -479,6 +556,7 @@ impl CandlePatternStrategy {
                 direction,
                 strength: (upper / body / dec!(2)).min(dec!(1)),
                 confirmation: false,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -509,6 +587,7 @@ impl CandlePatternStrategy {
                 direction: PatternDirection::Bullish,
                 strength: (curr_body / prev_body).min(dec!(1)),
                 confirmation: true,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -524,6 +603,7 @@ impl CandlePatternStrategy {
                 direction: PatternDirection::Bearish,
                 strength: (curr_body / prev_body).min(dec!(1)),
                 confirmation: true,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -550,6 +630,7 @@ impl CandlePatternStrategy {
                 direction: PatternDirection::Bullish,
                 strength: dec!(0.7),
                 confirmation: false,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -564,6 +645,7 @@ impl CandlePatternStrategy {
                 direction: PatternDirection::Bearish,
                 strength: dec!(0.7),
                 confirmation: false,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -594,6 +676,7 @@ impl CandlePatternStrategy {
                 direction: PatternDirection::Bullish,
                 strength: dec!(0.85),
                 confirmation: true,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -608,6 +691,7 @@ impl CandlePatternStrategy {
                 direction: PatternDirection::Bearish,
                 strength: dec!(0.85),
                 confirmation: true,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -641,6 +725,7 @@ impl CandlePatternStrategy {
                     direction: PatternDirection::Bullish,
                     strength: dec!(0.9),
                     confirmation: true,
+                    confidence: Decimal::ZERO,
                 });
             }
         }
@@ -657,6 +742,7 @@ impl CandlePatternStrategy {
                 direction: PatternDirection::Bearish,
                 strength: dec!(0.9),
                 confirmation: true,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -687,6 +773,7 @@ impl CandlePatternStrategy {
                 direction,
                 strength: body / total,
                 confirmation: true,
+                confidence: Decimal::ZERO,
             });
         }
 
@@ -737,21 +824,170 @@ impl CandlePatternStrategy {
         }
     }
 
+    /// 패턴 이전 구간의 트렌드 판단
+    ///
+    /// 패턴을 구성하는 최근 `skip`개 캔들을 제외한 `trend_period` 구간의 방향입니다.
+    fn prior_trend(&self, skip: usize) -> PatternDirection {
+        let config = match &self.config {
+            Some(c) => c,
+            None => return PatternDirection::Neutral,
+        };
+
+        if self.candles.len() < skip + config.trend_period {
+            return PatternDirection::Neutral;
+        }
+
+        let mut recent = self
+            .candles
+            .iter()
+            .skip(skip)
+            .take(config.trend_period)
+            .map(|k| k.close);
+        let (Some(last), Some(first)) = (recent.next(), recent.next_back()) else {
+            return PatternDirection::Neutral;
+        };
+
+        if last > first {
+            PatternDirection::Bullish
+        } else if last < first {
+            PatternDirection::Bearish
+        } else {
+            PatternDirection::Neutral
+        }
+    }
+
+    /// 현재 캔들 거래량 / 직전 평균 거래량
+    ///
+    /// 직전 거래량이 10개 미만이거나 평균이 0이면 `None`입니다.
+    fn volume_ratio(&self) -> Option<Decimal> {
+        if self.volumes.len() < 11 {
+            return None;
+        }
+
+        let current = *self.volumes.front()?;
+        let previous = self.volumes.len() - 1;
+        let avg = self.volumes.iter().skip(1).sum::<Decimal>() / Decimal::from(previous);
+
+        if avg == Decimal::ZERO {
+            return None;
+        }
+        Some(current / avg)
+    }
+
     /// 볼륨 확인
+    ///
+    /// 거래량 데이터가 부족하면 확인을 생략합니다.
     fn is_volume_confirmed(&self) -> bool {
         let config = match &self.config {
             Some(c) => c,
             None => return true,
         };
 
-        if !config.use_volume_confirmation || self.volumes.len() < 10 {
+        if !config.use_volume_confirmation {
             return true;
         }
 
-        let current = self.volumes.front().unwrap_or(&Decimal::ZERO);
-        let avg: Decimal = self.volumes.iter().take(10).sum::<Decimal>() / dec!(10);
+        match self.volume_ratio() {
+            Some(ratio) => ratio >= config.volume_surge_multiplier,
+            None => true,
+        }
+    }
+
+    /// 패턴 형태 품질 (0~1)
+    ///
+    /// `candles`는 최신 캔들이 앞에 오는 순서입니다.
+    fn shape_quality(pattern_type: CandlePatternType, candles: &[CandleData]) -> Decimal {
+        let Some(curr) = candles.first() else {
+            return Decimal::ZERO;
+        };
+        let total = Self::total_size(curr);
+        if total == Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        let body_ratio = Self::body_size(curr) / total;
+
+        let quality = match pattern_type {
+            // 긴 하단 꼬리
+            CandlePatternType::Hammer | CandlePatternType::HangingMan => {
+                Self::lower_shadow(curr) / total
+            }
+            // 긴 상단 꼬리
+            CandlePatternType::InvertedHammer | CandlePatternType::ShootingStar => {
+                Self::upper_shadow(curr) / total
+            }
+            // 작은 몸통
+            CandlePatternType::Doji
+            | CandlePatternType::LongLeggedDoji
+            | CandlePatternType::DragonflyDoji
+            | CandlePatternType::GravestoneDoji
+            | CandlePatternType::SpinningTop => dec!(1) - body_ratio,
+            // 이전 몸통을 크게 감쌀수록 강함
+            CandlePatternType::BullishEngulfing | CandlePatternType::BearishEngulfing => {
+                match candles.get(1).map(Self::body_size) {
+                    Some(prev_body) if Self::body_size(curr) > Decimal::ZERO => {
+                        dec!(1) - prev_body / Self::body_size(curr)
+                    }
+                    _ => body_ratio,
+                }
+            }
+            // 이전 몸통 안에 작게 들어갈수록 강함
+            CandlePatternType::BullishHarami | CandlePatternType::BearishHarami => {
+                match candles.get(1).map(Self::body_size) {
+                    Some(prev_body) if prev_body > Decimal::ZERO => {
+                        dec!(1) - Self::body_size(curr) / prev_body
+                    }
+                    _ => Decimal::ZERO,
+                }
+            }
+            // 확인 캔들의 몸통이 클수록 강함
+            _ => body_ratio,
+        };
+
+        quality.clamp(Decimal::ZERO, dec!(1))
+    }
+
+    /// 패턴 신뢰도 계산 (0~100)
+    ///
+    /// * `candles` - 최신 캔들이 앞에 오는 최근 캔들
+    /// * `prior_trend` - 패턴 이전 구간의 추세
+    /// * `volume_ratio` - 패턴 캔들 거래량 / 직전 평균 (데이터 부족 시 None)
+    /// * `volume_multiplier` - 거래량 급증으로 인정하는 배수
+    fn pattern_confidence(
+        pattern: &DetectedPattern,
+        candles: &[CandleData],
+        prior_trend: PatternDirection,
+        volume_ratio: Option<Decimal>,
+        volume_multiplier: Decimal,
+    ) -> Decimal {
+        // 형태 (40)
+        let shape = Self::shape_quality(pattern.pattern_type, candles) * dec!(40);
+
+        // 강도 (20)
+        let strength = pattern.strength.clamp(Decimal::ZERO, dec!(1)) * dec!(20);
+
+        // 추세 배경 (25)
+        let trend = if pattern.direction == PatternDirection::Neutral
+            || prior_trend == PatternDirection::Neutral
+        {
+            dec!(10)
+        } else if pattern.pattern_type.is_continuation() == (prior_trend == pattern.direction) {
+            dec!(25)
+        } else {
+            Decimal::ZERO
+        };
+
+        // 거래량 (15)
+        let volume = match volume_ratio {
+            Some(ratio) if volume_multiplier > Decimal::ZERO => {
+                (ratio / volume_multiplier).min(dec!(1)) * dec!(15)
+            }
+            Some(_) => dec!(15),
+            None => dec!(7.5),
+        };
 
-        *current > avg * dec!(1.2)
+        (shape + strength + trend + volume)
+            .clamp(Decimal::ZERO, dec!(100))
+            .round_dp(1)
     }
 
     /// 모든 패턴 감지
@@ -790,10 +1026,24 @@ impl CandlePatternStrategy {
             patterns.push(p);
         }
 
-        // 강도 필터
+        let recent: Vec<CandleData> = self.candles.iter().take(3).cloned().collect();
+        let volume_ratio = self.volume_ratio();
+
+        // 강도 필터 후 신뢰도 계산
         patterns
             .into_iter()
             .filter(|p| p.strength >= config.min_pattern_strength)
+            .map(|mut p| {
+                let prior_trend = self.prior_trend(p.pattern_type.candle_count());
+                p.confidence = Self::pattern_confidence(
+                    &p,
+                    &recent,
+                    prior_trend,
+                    volume_ratio,
+                    config.volume_surge_multiplier,
+                );
+                p
+            })
             .collect()
     }
 
@@ -880,10 +1130,11 @@ impl CandlePatternStrategy {
         // 패턴 감지
         let patterns = self.detect_all_patterns(candle);
 
-        // 활성화된 패턴 필터
+        // 활성화된 패턴 + 최소 신뢰도 필터
         let patterns: Vec<_> = patterns
             .into_iter()
             .filter(|p| self.is_pattern_enabled(&p.pattern_type))
+            .filter(|p| p.confidence >= config.min_confidence)
             .collect();
 
         if patterns.is_empty() {
@@ -892,11 +1143,15 @@ impl CandlePatternStrategy {
 
         // 볼륨 확인
         if !self.is_volume_confirmed() {
+            debug!("[CandlePattern] 거래량 미확인 - 패턴 무시");
             return signals;
         }
 
-        // 가장 강한 패턴 선택
-        let best_pattern = match patterns.iter().max_by(|a, b| a.strength.cmp(&b.strength)) {
+        // 신뢰도가 가장 높은 패턴 선택 (동점이면 강도 우선)
+        let best_pattern = match patterns
+            .iter()
+            .max_by(|a, b| (a.confidence, a.strength).cmp(&(b.confidence, b.strength)))
+        {
             Some(p) => p,
             None => return signals,
         };
@@ -952,13 +1207,14 @@ impl CandlePatternStrategy {
             .with_metadata("pattern", json!(pattern_name))
             .with_metadata("direction", json!(format!("{:?}", best_pattern.direction)))
             .with_metadata("strength", json!(best_pattern.strength.to_string()))
+            .with_metadata("confidence", json!(best_pattern.confidence.to_string()))
             .with_metadata("confirmation", json!(best_pattern.confirmation));
 
         signals.push(signal);
 
         info!(
-            "[CandlePattern] 패턴 감지: {:?} (강도: {:.2})",
-            best_pattern.pattern_type, best_pattern.strength
+            "[CandlePattern] 패턴 감지: {:?} (강도: {:.2}, 신뢰도: {:.1})",
+            best_pattern.pattern_type, best_pattern.strength, best_pattern.confidence
         );
 
         signals
@@ -994,6 +1250,7 @@ impl Strategy for CandlePatternStrategy {
         info!(
             ticker = %cp_config.ticker,
             min_strength = %cp_config.min_pattern_strength,
+            min_confidence = %cp_config.min_confidence,
             "Initializing Candle Pattern strategy"
         );
 
@@ -1142,6 +1399,261 @@ mod tests {
         let strategy = CandlePatternStrategy::new();
         assert_eq!(strategy.name(), "Candle Pattern");
     }
+
+    fn candle(open: Decimal, high: Decimal, low: Decimal, close: Decimal) -> CandleData {
+        CandleData {
+            open,
+            high,
+            low,
+            close,
+            volume: dec!(100),
+        }
+    }
+
+    fn pattern(
+        pattern_type: CandlePatternType,
+        direction: PatternDirection,
+        strength: Decimal,
+    ) -> DetectedPattern {
+        DetectedPattern {
+            pattern_type,
+            direction,
+            strength,
+            confirmation: false,
+            confidence: Decimal::ZERO,
+        }
+    }
+
+    fn confidence(
+        p: &DetectedPattern,
+        candles: &[CandleData],
+        trend: PatternDirection,
+        volume_ratio: Option<Decimal>,
+    ) -> Decimal {
+        CandlePatternStrategy::pattern_confidence(p, candles, trend, volume_ratio, dec!(1.5))
+    }
+
+    #[test]
+    fn test_confidence_hammer() {
+        // 하단 꼬리 80%: 형태 32 + 강도 20 + 하락 추세 후 반전 25 + 거래량 2배 15
+        let candles = [candle(dec!(98), dec!(100), dec!(90), dec!(100))];
+        let hammer = pattern(
+            CandlePatternType::Hammer,
+            PatternDirection::Bullish,
+            dec!(1),
+        );
+
+        assert_eq!(
+            confidence(&hammer, &candles, PatternDirection::Bearish, Some(dec!(2))),
+            dec!(92)
+        );
+        // 상승 추세에서의 강세 반전은 추세 가점 없음, 거래량 데이터 없으면 절반
+        assert_eq!(
+            confidence(&hammer, &candles, PatternDirection::Bullish, None),
+            dec!(59.5)
+        );
+    }
+
+    #[test]
+    fn test_confidence_shooting_star() {
+        // 상단 꼬리 80%, 상승 추세 후 약세 반전
+        let candles = [candle(dec!(100), dec!(110), dec!(100), dec!(102))];
+        let star = pattern(
+            CandlePatternType::ShootingStar,
+            PatternDirection::Bearish,
+            dec!(1),
+        );
+
+        assert_eq!(
+            confidence(&star, &candles, PatternDirection::Bullish, Some(dec!(1.5))),
+            dec!(92)
+        );
+    }
+
+    #[test]
+    fn test_confidence_doji_is_neutral() {
+        // 몸통 5%: 형태 38 + 강도 19 + 중립 10 + 거래량 절반 7.5
+        let candles = [candle(dec!(100), dec!(110), dec!(90), dec!(101))];
+        let doji = pattern(
+            CandlePatternType::Doji,
+            PatternDirection::Neutral,
+            dec!(0.95),
+        );
+
+        assert_eq!(
+            confidence(&doji, &candles, PatternDirection::Bearish, None),
+            dec!(74.5)
+        );
+    }
+
+    #[test]
+    fn test_confidence_marubozu_continuation() {
+        // 꼬리 없는 양봉: 같은 방향 추세에서 가점, 반대 추세에서는 없음
+        let candles = [candle(dec!(100), dec!(110), dec!(100), dec!(110))];
+        let marubozu = pattern(
+            CandlePatternType::Marubozu,
+            PatternDirection::Bullish,
+            dec!(1),
+        );
+
+        assert_eq!(
+            confidence(
+                &marubozu,
+                &candles,
+                PatternDirection::Bullish,
+                Some(dec!(1.5))
+            ),
+            dec!(100)
+        );
+        assert_eq!(
+            confidence(
+                &marubozu,
+                &candles,
+                PatternDirection::Bearish,
+                Some(dec!(1.5))
+            ),
+            dec!(75)
+        );
+    }
+
+    #[test]
+    fn test_confidence_engulfing() {
+        // 이전 몸통(4)을 현재 몸통(10)이 감쌈: 형태 (1 - 0.4) * 40 = 24
+        let candles = [
+            candle(dec!(95), dec!(106), dec!(94), dec!(105)),
+            candle(dec!(100), dec!(101), dec!(95), dec!(96)),
+        ];
+        let engulfing = pattern(
+            CandlePatternType::BullishEngulfing,
+            PatternDirection::Bullish,
+            dec!(1),
+        );
+
+        assert_eq!(
+            confidence(
+                &engulfing,
+                &candles,
+                PatternDirection::Bearish,
+                Some(dec!(0.75))
+            ),
+            dec!(76.5)
+        );
+    }
+
+    #[test]
+    fn test_confidence_harami() {
+        // 이전 몸통(10) 안의 작은 몸통(2): 형태 (1 - 0.2) * 40 = 32
+        let candles = [
+            candle(dec!(92), dec!(95), dec!(91), dec!(94)),
+            candle(dec!(100), dec!(101), dec!(89), dec!(90)),
+        ];
+        let harami = pattern(
+            CandlePatternType::BullishHarami,
+            PatternDirection::Bullish,
+            dec!(0.7),
+        );
+
+        assert_eq!(
+            confidence(&harami, &candles, PatternDirection::Neutral, None),
+            dec!(63.5)
+        );
+    }
+
+    #[test]
+    fn test_confidence_morning_star_and_three_soldiers() {
+        // 확인 캔들 몸통 비율 0.5
+        let candles = [
+            candle(dec!(100), dec!(106), dec!(98), dec!(104)),
+            candle(dec!(97), dec!(98), dec!(96), dec!(97)),
+            candle(dec!(110), dec!(111), dec!(99), dec!(100)),
+        ];
+
+        let morning_star = pattern(
+            CandlePatternType::MorningStar,
+            PatternDirection::Bullish,
+            dec!(0.85),
+        );
+        assert_eq!(
+            confidence(&morning_star, &candles, PatternDirection::Bearish, None),
+            dec!(69.5)
+        );
+
+        let soldiers = pattern(
+            CandlePatternType::ThreeWhiteSoldiers,
+            PatternDirection::Bullish,
+            dec!(0.9),
+        );
+        assert_eq!(
+            confidence(&soldiers, &candles, PatternDirection::Bearish, None),
+            dec!(70.5)
+        );
+    }
+
+    #[test]
+    fn test_confidence_flat_candle_has_no_shape_score() {
+        let candles = [candle(dec!(100), dec!(100), dec!(100), dec!(100))];
+        let hammer = pattern(
+            CandlePatternType::Hammer,
+            PatternDirection::Bullish,
+            dec!(0),
+        );
+
+        assert_eq!(
+            confidence(&hammer, &candles, PatternDirection::Neutral, None),
+            dec!(17.5)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_min_confidence_and_volume_confirmation() {
+        let mut strategy = CandlePatternStrategy::new();
+        strategy
+            .initialize(json!({
+                "ticker": "005930",
+                "min_pattern_strength": "0.1",
+                "min_confidence": "50",
+                "use_trend_confirmation": false,
+                "volume_surge_multiplier": "2"
+            }))
+            .await
+            .unwrap();
+
+        // 직전 평탄한 캔들 10개 (거래량 100)
+        for _ in 0..10 {
+            strategy
+                .candles
+                .push_front(candle(dec!(100), dec!(100.5), dec!(99.5), dec!(100)));
+            strategy.volumes.push_front(dec!(100));
+        }
+
+        // 꼬리 없는 양봉 + 거래량 1.5배: 배수(2) 미달로 무시
+        let marubozu = candle(dec!(100), dec!(110), dec!(100), dec!(110));
+        strategy.candles.push_front(marubozu.clone());
+        strategy.volumes.push_front(dec!(150));
+        assert!(strategy.generate_signals(&marubozu, dec!(110)).is_empty());
+
+        // 거래량 3배면 신호 생성
+        strategy.volumes.pop_front();
+        strategy.volumes.push_front(dec!(300));
+        let signals = strategy.generate_signals(&marubozu, dec!(110));
+        assert_eq!(signals.len(), 1);
+        assert!(signals[0].metadata.contains_key("confidence"));
+
+        // 최소 신뢰도를 넘지 못하면 신호 없음
+        let mut strict = CandlePatternStrategy::new();
+        strict
+            .initialize(json!({
+                "ticker": "005930",
+                "min_pattern_strength": "0.1",
+                "min_confidence": "99",
+                "use_volume_confirmation": false
+            }))
+            .await
+            .unwrap();
+        strict.candles.push_front(marubozu.clone());
+        strict.volumes.push_front(dec!(100));
+        assert!(strict.generate_signals(&marubozu, dec!(110)).is_empty());
+    }
 }
 
 // 전략 레지스트리에 자동 등록
@@ -0,0 +1,811 @@
+//! 전략 성과 자가 진단 및 개선 제안.
+//!
+//! 백테스트 결과 지표를 규칙 기반으로 분석하여 문제 패턴을 탐지하고
+//! 근거 지표와 함께 구체적인 개선책을 제안합니다:
+//! - **거래 미발생**: 데이터 부족, 과도한 필터 설정, 낮은 변동성
+//! - **승률/손익비**: 낮은 승률, 높은 승률 대비 낮은 손익비
+//! - **비용**: 과도한 회전율, 큰 슬리피지, 수수료 부담
+//! - **리스크**: 큰 최대 낙폭
+//!
+//! 진단은 관찰 전용이며 전략 설정을 변경하지 않습니다.
+//! 탐지된 문제는 영향도(0~100) 내림차순으로 정렬됩니다.
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! let input = DiagnosisInput {
+//!     total_trades: 40,
+//!     win_rate_pct: dec!(62),
+//!     avg_win: dec!(10000),
+//!     avg_loss: dec!(25000),
+//!     ..Default::default()
+//! };
+//! let report = StrategyDiagnoser::default().diagnose(&input, &strategy_config);
+//! for line in report.lines() {
+//!     println!("{}", line);
+//! }
+//! ```
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// 진단 대상 성과 지표.
+///
+/// 백테스트 리포트 또는 저장된 결과에서 추출합니다.
+/// 알 수 없는 값은 기본값(0/None)으로 두면 해당 규칙이 건너뜁니다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DiagnosisInput {
+    /// 데이터 포인트(캔들) 수
+    pub data_points: usize,
+    /// 기간 내 가격 변동률 (%)
+    #[serde(default)]
+    pub price_change_pct: Option<Decimal>,
+    /// 생성된 신호 수
+    #[serde(default)]
+    pub signals_generated: usize,
+    /// 실제 체결된 신호 수
+    #[serde(default)]
+    pub signals_executed: usize,
+    /// 완료된 거래(라운드트립) 수
+    pub total_trades: usize,
+    /// 승률 (%)
+    pub win_rate_pct: Decimal,
+    /// 평균 수익 거래 금액
+    pub avg_win: Decimal,
+    /// 평균 손실 거래 금액 (부호 무관)
+    pub avg_loss: Decimal,
+    /// 총 수익률 (%)
+    pub total_return_pct: Decimal,
+    /// 최대 낙폭 (%, 부호 무관)
+    pub max_drawdown_pct: Decimal,
+    /// 순이익 (비용 반영 후)
+    pub net_profit: Decimal,
+    /// 총 수수료
+    pub total_commission: Decimal,
+    /// 총 슬리피지 비용
+    pub total_slippage: Decimal,
+}
+
+impl DiagnosisInput {
+    /// 손익비 (평균 수익 / 평균 손실). 손실 거래가 없으면 `None`.
+    pub fn payoff_ratio(&self) -> Option<Decimal> {
+        let avg_loss = self.avg_loss.abs();
+        if avg_loss.is_zero() {
+            None
+        } else {
+            Some(self.avg_win / avg_loss)
+        }
+    }
+
+    /// 100캔들당 거래 수.
+    pub fn trades_per_100_bars(&self) -> Option<Decimal> {
+        if self.data_points == 0 {
+            None
+        } else {
+            Some(Decimal::from(self.total_trades) * dec!(100) / Decimal::from(self.data_points))
+        }
+    }
+
+    /// 비용 차감 전 순이익.
+    pub fn pre_cost_profit(&self) -> Decimal {
+        self.net_profit + self.total_commission + self.total_slippage
+    }
+
+    /// 생성된 신호 중 체결된 비율 (%). 신호가 없으면 `None`.
+    pub fn signal_execution_rate_pct(&self) -> Option<Decimal> {
+        if self.signals_generated == 0 {
+            None
+        } else {
+            Some(
+                Decimal::from(self.signals_executed) * dec!(100)
+                    / Decimal::from(self.signals_generated),
+            )
+        }
+    }
+}
+
+/// 진단 규칙 임계값.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DiagnosisThresholds {
+    /// 최소 권장 데이터 포인트 수
+    pub min_data_points: usize,
+    /// 통계적으로 의미 있는 최소 거래 수
+    pub min_trades: usize,
+    /// 낮은 승률 기준 (%)
+    pub low_win_rate_pct: Decimal,
+    /// 높은 승률 기준 (%) - 손익비 진단에 사용
+    pub high_win_rate_pct: Decimal,
+    /// 낮은 손익비 기준
+    pub low_payoff_ratio: Decimal,
+    /// 낮은 승률을 상쇄하는 손익비 (이 이상이면 추세추종형으로 보고 승률 경고 생략)
+    pub compensating_payoff_ratio: Decimal,
+    /// 과도한 회전율 기준 (100캔들당 거래 수)
+    pub max_trades_per_100_bars: Decimal,
+    /// 비용 차감 전 이익 대비 슬리피지 비중 기준 (0~1)
+    pub max_slippage_share: Decimal,
+    /// 비용 차감 전 이익 대비 수수료 비중 기준 (0~1)
+    pub max_commission_share: Decimal,
+    /// 큰 최대 낙폭 기준 (%)
+    pub max_drawdown_pct: Decimal,
+    /// 신호 체결률이 이보다 낮으면 과도한 필터로 판단 (%)
+    pub min_signal_execution_rate_pct: Decimal,
+    /// 가격 변동이 이보다 작으면 낮은 변동성으로 판단 (%)
+    pub min_price_change_pct: Decimal,
+}
+
+impl Default for DiagnosisThresholds {
+    fn default() -> Self {
+        Self {
+            min_data_points: 50,
+            min_trades: 10,
+            low_win_rate_pct: dec!(35),
+            high_win_rate_pct: dec!(55),
+            low_payoff_ratio: dec!(1.0),
+            compensating_payoff_ratio: dec!(2.0),
+            max_trades_per_100_bars: dec!(20),
+            max_slippage_share: dec!(0.3),
+            max_commission_share: dec!(0.3),
+            max_drawdown_pct: dec!(25),
+            min_signal_execution_rate_pct: dec!(30),
+            min_price_change_pct: dec!(5),
+        }
+    }
+}
+
+/// 탐지된 문제 유형.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosisKind {
+    /// 거래 미발생
+    NoTrades,
+    /// 데이터 부족
+    InsufficientData,
+    /// 과도한 필터 (신호 차단 또는 엄격한 설정)
+    ExcessiveFilter,
+    /// 가격 변동 부족
+    LowVolatility,
+    /// 표본 거래 수 부족
+    TooFewTrades,
+    /// 낮은 승률
+    LowWinRate,
+    /// 승률 대비 낮은 손익비
+    LowPayoffRatio,
+    /// 과도한 회전율
+    HighTurnover,
+    /// 큰 슬리피지
+    HighSlippage,
+    /// 과도한 수수료 부담
+    HighCommission,
+    /// 큰 최대 낙폭
+    DeepDrawdown,
+}
+
+/// 문제 심각도 (영향도 구간).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosisSeverity {
+    /// 참고 (영향도 40 미만)
+    Info,
+    /// 경고 (영향도 40 이상)
+    Warning,
+    /// 치명 (영향도 70 이상)
+    Critical,
+}
+
+impl DiagnosisSeverity {
+    /// 영향도로부터 심각도 결정.
+    pub fn from_impact(impact: Decimal) -> Self {
+        if impact >= dec!(70) {
+            Self::Critical
+        } else if impact >= dec!(40) {
+            Self::Warning
+        } else {
+            Self::Info
+        }
+    }
+
+    /// 리포트 표기용 라벨.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Info => "참고",
+            Self::Warning => "경고",
+            Self::Critical => "치명",
+        }
+    }
+}
+
+/// 문제의 근거 지표.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DiagnosisEvidence {
+    /// 지표 이름 (예: "win_rate_pct")
+    pub metric: String,
+    /// 측정값
+    pub value: Decimal,
+    /// 판단 기준값
+    pub threshold: Decimal,
+}
+
+impl DiagnosisEvidence {
+    fn new(metric: &str, value: Decimal, threshold: Decimal) -> Self {
+        Self {
+            metric: metric.to_string(),
+            value: value.round_dp(4).normalize(),
+            threshold: threshold.normalize(),
+        }
+    }
+}
+
+/// 개별 진단 결과.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DiagnosisFinding {
+    /// 문제 유형
+    pub kind: DiagnosisKind,
+    /// 심각도
+    pub severity: DiagnosisSeverity,
+    /// 영향도 (0~100, 높을수록 우선 개선 대상)
+    pub impact: Decimal,
+    /// 문제 요약
+    pub title: String,
+    /// 근거 지표
+    pub evidence: Vec<DiagnosisEvidence>,
+    /// 개선 제안
+    pub suggestions: Vec<String>,
+}
+
+impl DiagnosisFinding {
+    fn new(kind: DiagnosisKind, impact: Decimal, title: impl Into<String>) -> Self {
+        let impact = impact.clamp(Decimal::ZERO, dec!(100)).round_dp(1);
+        Self {
+            kind,
+            severity: DiagnosisSeverity::from_impact(impact),
+            impact,
+            title: title.into(),
+            evidence: Vec::new(),
+            suggestions: Vec::new(),
+        }
+    }
+
+    fn evidence(mut self, metric: &str, value: Decimal, threshold: Decimal) -> Self {
+        self.evidence
+            .push(DiagnosisEvidence::new(metric, value, threshold));
+        self
+    }
+
+    fn suggest(mut self, suggestion: &str) -> Self {
+        self.suggestions.push(suggestion.to_string());
+        self
+    }
+}
+
+/// 진단 리포트.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DiagnosisReport {
+    /// 탐지된 문제 (영향도 내림차순)
+    pub findings: Vec<DiagnosisFinding>,
+}
+
+impl DiagnosisReport {
+    /// 탐지된 문제가 없는지 여부.
+    pub fn is_healthy(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    /// 가장 영향도가 큰 문제.
+    pub fn top_finding(&self) -> Option<&DiagnosisFinding> {
+        self.findings.first()
+    }
+
+    /// 특정 유형의 문제가 탐지되었는지 여부.
+    pub fn has(&self, kind: DiagnosisKind) -> bool {
+        self.findings.iter().any(|f| f.kind == kind)
+    }
+
+    /// 리포트 출력용 라인 (CLI 표기용).
+    pub fn lines(&self) -> Vec<String> {
+        if self.findings.is_empty() {
+            return vec!["  ✅ 탐지된 문제 없음".to_string()];
+        }
+
+        let mut lines = Vec::new();
+        for (i, finding) in self.findings.iter().enumerate() {
+            lines.push(format!(
+                "  {}. [{}] {} (영향도 {})",
+                i + 1,
+                finding.severity.label(),
+                finding.title,
+                finding.impact
+            ));
+            for evidence in &finding.evidence {
+                lines.push(format!(
+                    "     - 근거: {} = {} (기준 {})",
+                    evidence.metric, evidence.value, evidence.threshold
+                ));
+            }
+            for suggestion in &finding.suggestions {
+                lines.push(format!("     → {}", suggestion));
+            }
+        }
+        lines
+    }
+}
+
+/// 규칙 기반 전략 진단 엔진.
+#[derive(Debug, Clone, Default)]
+pub struct StrategyDiagnoser {
+    thresholds: DiagnosisThresholds,
+}
+
+impl StrategyDiagnoser {
+    /// 임계값을 지정하여 생성.
+    pub fn new(thresholds: DiagnosisThresholds) -> Self {
+        Self { thresholds }
+    }
+
+    /// 임계값 참조.
+    pub fn thresholds(&self) -> &DiagnosisThresholds {
+        &self.thresholds
+    }
+
+    /// 성과 지표와 전략 설정을 진단합니다.
+    ///
+    /// 전략 설정은 거래 미발생 시 필터 설정 점검에만 사용되며,
+    /// 알 수 없으면 `Value::Null`을 전달하면 됩니다.
+    pub fn diagnose(&self, input: &DiagnosisInput, strategy_config: &Value) -> DiagnosisReport {
+        let mut findings = Vec::new();
+
+        self.check_data(input, &mut findings);
+        if input.total_trades == 0 {
+            self.check_no_trades(input, strategy_config, &mut findings);
+        } else {
+            self.check_sample_size(input, &mut findings);
+            self.check_win_rate(input, &mut findings);
+            self.check_payoff_ratio(input, &mut findings);
+            self.check_turnover(input, &mut findings);
+            self.check_costs(input, &mut findings);
+            self.check_drawdown(input, &mut findings);
+        }
+        self.check_signal_execution(input, &mut findings);
+
+        // 영향도 내림차순 (동률이면 탐지 순서 유지)
+        findings.sort_by_key(|f| std::cmp::Reverse(f.impact));
+
+        DiagnosisReport { findings }
+    }
+
+    fn check_data(&self, input: &DiagnosisInput, findings: &mut Vec<DiagnosisFinding>) {
+        let t = &self.thresholds;
+        if input.data_points < t.min_data_points {
+            findings.push(
+                DiagnosisFinding::new(
+                    DiagnosisKind::InsufficientData,
+                    dec!(90),
+                    format!(
+                        "데이터 부족: {}개 캔들 (최소 {}개 권장)",
+                        input.data_points, t.min_data_points
+                    ),
+                )
+                .evidence(
+                    "data_points",
+                    Decimal::from(input.data_points),
+                    Decimal::from(t.min_data_points),
+                )
+                .suggest("더 긴 기간으로 백테스트하여 지표 워밍업 구간 확보"),
+            );
+        }
+    }
+
+    /// 거래 미발생 원인 분석 (설정 필터, 가격 변동).
+    fn check_no_trades(
+        &self,
+        input: &DiagnosisInput,
+        config: &Value,
+        findings: &mut Vec<DiagnosisFinding>,
+    ) {
+        let t = &self.thresholds;
+
+        findings.push(
+            DiagnosisFinding::new(DiagnosisKind::NoTrades, dec!(100), "거래가 발생하지 않음")
+                .evidence("total_trades", Decimal::ZERO, Decimal::ONE)
+                .suggest("전략 파라미터 완화 (RSI 임계값 조정 등)")
+                .suggest("더 긴 기간 또는 더 변동성 있는 종목으로 테스트"),
+        );
+
+        if let Some(obj) = config.as_object() {
+            let number = |key: &str| {
+                obj.get(key)
+                    .and_then(|v| v.as_f64())
+                    .and_then(Decimal::from_f64_retain)
+            };
+
+            if let Some(overbought) = number("overbought").filter(|v| *v < dec!(60)) {
+                findings.push(
+                    DiagnosisFinding::new(
+                        DiagnosisKind::ExcessiveFilter,
+                        dec!(45),
+                        format!("RSI 과매수 임계값이 낮음: {}", overbought),
+                    )
+                    .evidence("overbought", overbought, dec!(60))
+                    .suggest("overbought를 70 전후로 상향 검토"),
+                );
+            }
+            if let Some(oversold) = number("oversold").filter(|v| *v > dec!(40)) {
+                findings.push(
+                    DiagnosisFinding::new(
+                        DiagnosisKind::ExcessiveFilter,
+                        dec!(45),
+                        format!("RSI 과매도 임계값이 높음: {}", oversold),
+                    )
+                    .evidence("oversold", oversold, dec!(40))
+                    .suggest("oversold를 30 전후로 하향 검토"),
+                );
+            }
+            if let Some(min_score) = number("min_score").filter(|v| *v > dec!(80)) {
+                findings.push(
+                    DiagnosisFinding::new(
+                        DiagnosisKind::ExcessiveFilter,
+                        dec!(60),
+                        format!("GlobalScore 필터가 너무 엄격: min_score={}", min_score),
+                    )
+                    .evidence("min_score", min_score, dec!(80))
+                    .suggest("min_score 완화 또는 GlobalScore 필터 비활성화"),
+                );
+            }
+            if obj
+                .get("enable_route_filter")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false)
+            {
+                findings.push(
+                    DiagnosisFinding::new(
+                        DiagnosisKind::ExcessiveFilter,
+                        dec!(50),
+                        "RouteState 필터 활성화됨 (백테스트에서는 RouteState가 없을 수 있음)",
+                    )
+                    .evidence("enable_route_filter", Decimal::ONE, Decimal::ZERO)
+                    .suggest("백테스트 시 enable_route_filter 비활성화"),
+                );
+            }
+        }
+
+        if let Some(change_pct) = input.price_change_pct {
+            if change_pct.abs() < t.min_price_change_pct {
+                findings.push(
+                    DiagnosisFinding::new(
+                        DiagnosisKind::LowVolatility,
+                        dec!(40),
+                        format!("기간 내 가격 변동이 적음: {:.1}%", change_pct),
+                    )
+                    .evidence("price_change_pct", change_pct, t.min_price_change_pct)
+                    .suggest("변동성이 큰 종목 또는 추세가 있는 구간으로 테스트"),
+                );
+            }
+        }
+    }
+
+    fn check_sample_size(&self, input: &DiagnosisInput, findings: &mut Vec<DiagnosisFinding>) {
+        let t = &self.thresholds;
+        if input.total_trades < t.min_trades {
+            findings.push(
+                DiagnosisFinding::new(
+                    DiagnosisKind::TooFewTrades,
+                    dec!(35),
+                    format!(
+                        "거래 수가 적어 통계적 신뢰도가 낮음: {}건",
+                        input.total_trades
+                    ),
+                )
+                .evidence(
+                    "total_trades",
+                    Decimal::from(input.total_trades),
+                    Decimal::from(t.min_trades),
+                )
+                .suggest("테스트 기간 확장 또는 다중 종목으로 표본 확보"),
+            );
+        }
+    }
+
+    fn check_win_rate(&self, input: &DiagnosisInput, findings: &mut Vec<DiagnosisFinding>) {
+        let t = &self.thresholds;
+        if input.total_trades < t.min_trades || input.win_rate_pct >= t.low_win_rate_pct {
+            return;
+        }
+        // 손익비가 충분히 크면 추세추종형 특성으로 보고 경고하지 않음
+        let payoff = input.payoff_ratio();
+        if payoff.is_some_and(|p| p >= t.compensating_payoff_ratio) {
+            return;
+        }
+
+        let impact = dec!(40) + (t.low_win_rate_pct - input.win_rate_pct) * dec!(2);
+        let mut finding = DiagnosisFinding::new(
+            DiagnosisKind::LowWinRate,
+            impact,
+            "승률이 낮고 손익비로 보상되지 않음 → 진입 조건 강화 검토",
+        )
+        .evidence("win_rate_pct", input.win_rate_pct, t.low_win_rate_pct);
+        if let Some(payoff) = payoff {
+            finding = finding.evidence("payoff_ratio", payoff, t.compensating_payoff_ratio);
+        }
+        findings.push(
+            finding
+                .suggest("추세/거래량 확인 필터를 추가하여 진입 조건 강화")
+                .suggest("손절 폭을 넓혀 노이즈로 인한 조기 손절 감소 검토"),
+        );
+    }
+
+    fn check_payoff_ratio(&self, input: &DiagnosisInput, findings: &mut Vec<DiagnosisFinding>) {
+        let t = &self.thresholds;
+        if input.total_trades < t.min_trades || input.win_rate_pct < t.high_win_rate_pct {
+            return;
+        }
+        let Some(payoff) = input.payoff_ratio() else {
+            return;
+        };
+        if payoff >= t.low_payoff_ratio {
+            return;
+        }
+
+        let impact = dec!(40) + (t.low_payoff_ratio - payoff) * dec!(60);
+        findings.push(
+            DiagnosisFinding::new(
+                DiagnosisKind::LowPayoffRatio,
+                impact,
+                "승률은 높으나 손익비가 낮음 → 익절 목표 확대 검토",
+            )
+            .evidence("win_rate_pct", input.win_rate_pct, t.high_win_rate_pct)
+            .evidence("payoff_ratio", payoff, t.low_payoff_ratio)
+            .suggest("익절 목표 확대 또는 트레일링 스탑으로 수익 구간 연장")
+            .suggest("손절 폭 축소로 평균 손실 제한"),
+        );
+    }
+
+    fn check_turnover(&self, input: &DiagnosisInput, findings: &mut Vec<DiagnosisFinding>) {
+        let t = &self.thresholds;
+        let Some(turnover) = input.trades_per_100_bars() else {
+            return;
+        };
+        if turnover <= t.max_trades_per_100_bars {
+            return;
+        }
+
+        let impact = dec!(30) + (turnover - t.max_trades_per_100_bars) * dec!(2);
+        findings.push(
+            DiagnosisFinding::new(
+                DiagnosisKind::HighTurnover,
+                impact.min(dec!(80)),
+                format!("회전율이 과도함: 100캔들당 {:.1}건", turnover),
+            )
+            .evidence("trades_per_100_bars", turnover, t.max_trades_per_100_bars)
+            .suggest("신호 쿨다운 또는 최소 보유 기간 도입")
+            .suggest("상위 타임프레임 확인으로 잦은 재진입 억제"),
+        );
+    }
+
+    fn check_costs(&self, input: &DiagnosisInput, findings: &mut Vec<DiagnosisFinding>) {
+        let t = &self.thresholds;
+        // 비용 차감 전에도 손실이면 비용이 주원인이 아님
+        let pre_cost = input.pre_cost_profit();
+        if pre_cost <= Decimal::ZERO {
+            return;
+        }
+
+        let slippage_share = input.total_slippage / pre_cost;
+        if slippage_share >= t.max_slippage_share {
+            findings.push(
+                DiagnosisFinding::new(
+                    DiagnosisKind::HighSlippage,
+                    dec!(40) + slippage_share * dec!(50),
+                    format!(
+                        "슬리피지가 비용 차감 전 이익의 {:.0}%를 잠식",
+                        slippage_share * dec!(100)
+                    ),
+                )
+                .evidence("slippage_share", slippage_share, t.max_slippage_share)
+                .evidence("total_slippage", input.total_slippage, Decimal::ZERO)
+                .suggest("지정가 주문 사용 또는 유동성 높은 종목으로 제한")
+                .suggest("체결 시점을 변동성이 낮은 시간대로 조정"),
+            );
+        }
+
+        let commission_share = input.total_commission / pre_cost;
+        if commission_share >= t.max_commission_share {
+            findings.push(
+                DiagnosisFinding::new(
+                    DiagnosisKind::HighCommission,
+                    dec!(35) + commission_share * dec!(50),
+                    format!(
+                        "수수료가 비용 차감 전 이익의 {:.0}%를 잠식",
+                        commission_share * dec!(100)
+                    ),
+                )
+                .evidence("commission_share", commission_share, t.max_commission_share)
+                .evidence("total_commission", input.total_commission, Decimal::ZERO)
+                .suggest("거래 빈도를 줄이고 거래당 기대수익 확대")
+                .suggest("수수료가 낮은 거래소/계좌 유형 검토"),
+            );
+        }
+    }
+
+    fn check_drawdown(&self, input: &DiagnosisInput, findings: &mut Vec<DiagnosisFinding>) {
+        let t = &self.thresholds;
+        let mdd = input.max_drawdown_pct.abs();
+        if mdd <= t.max_drawdown_pct {
+            return;
+        }
+
+        let impact = dec!(30) + (mdd - t.max_drawdown_pct) * dec!(2);
+        findings.push(
+            DiagnosisFinding::new(
+                DiagnosisKind::DeepDrawdown,
+                impact.min(dec!(90)),
+                format!("최대 낙폭이 큼: {:.1}%", mdd),
+            )
+            .evidence("max_drawdown_pct", mdd, t.max_drawdown_pct)
+            .suggest("손절 설정 강화 또는 포지션 크기 축소")
+            .suggest("시장 레짐 필터로 하락장 진입 제한"),
+        );
+    }
+
+    fn check_signal_execution(&self, input: &DiagnosisInput, findings: &mut Vec<DiagnosisFinding>) {
+        let t = &self.thresholds;
+        if input.signals_generated < t.min_trades {
+            return;
+        }
+        let Some(rate) = input.signal_execution_rate_pct() else {
+            return;
+        };
+        if rate >= t.min_signal_execution_rate_pct {
+            return;
+        }
+
+        let impact = dec!(40) + (t.min_signal_execution_rate_pct - rate);
+        findings.push(
+            DiagnosisFinding::new(
+                DiagnosisKind::ExcessiveFilter,
+                impact.min(dec!(90)),
+                format!(
+                    "신호 대부분이 필터에 차단됨: {}건 중 {}건 체결",
+                    input.signals_generated, input.signals_executed
+                ),
+            )
+            .evidence(
+                "signal_execution_rate_pct",
+                rate,
+                t.min_signal_execution_rate_pct,
+            )
+            .suggest("GlobalScore/RouteState 등 필터 조건 완화")
+            .suggest("최대 보유 종목 수 또는 자금 제약 확인"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn traded(total_trades: usize) -> DiagnosisInput {
+        DiagnosisInput {
+            data_points: 500,
+            total_trades,
+            win_rate_pct: dec!(50),
+            avg_win: dec!(100),
+            avg_loss: dec!(80),
+            net_profit: dec!(1000),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_trades_reports_filters_and_volatility() {
+        let input = DiagnosisInput {
+            data_points: 30,
+            price_change_pct: Some(dec!(2)),
+            ..Default::default()
+        };
+        let config = json!({ "min_score": 90, "enable_route_filter": true, "oversold": 35 });
+
+        let report = StrategyDiagnoser::default().diagnose(&input, &config);
+
+        assert_eq!(report.top_finding().unwrap().kind, DiagnosisKind::NoTrades);
+        assert!(report.has(DiagnosisKind::InsufficientData));
+        assert!(report.has(DiagnosisKind::LowVolatility));
+        // min_score, route filter (oversold 35는 정상 범위)
+        let filters = report
+            .findings
+            .iter()
+            .filter(|f| f.kind == DiagnosisKind::ExcessiveFilter)
+            .count();
+        assert_eq!(filters, 2);
+    }
+
+    #[test]
+    fn test_high_win_rate_low_payoff() {
+        let input = DiagnosisInput {
+            win_rate_pct: dec!(65),
+            avg_win: dec!(50),
+            avg_loss: dec!(-100),
+            ..traded(40)
+        };
+
+        let report = StrategyDiagnoser::default().diagnose(&input, &Value::Null);
+        let finding = report.top_finding().unwrap();
+
+        assert_eq!(finding.kind, DiagnosisKind::LowPayoffRatio);
+        assert!(finding.title.contains("익절 목표 확대"));
+        assert_eq!(finding.impact, dec!(70));
+        assert_eq!(finding.severity, DiagnosisSeverity::Critical);
+        assert!(finding
+            .evidence
+            .iter()
+            .any(|e| e.metric == "payoff_ratio" && e.value == dec!(0.5)));
+    }
+
+    #[test]
+    fn test_low_win_rate_compensated_by_payoff_is_not_flagged() {
+        let trend_following = DiagnosisInput {
+            win_rate_pct: dec!(30),
+            avg_win: dec!(300),
+            avg_loss: dec!(100),
+            ..traded(40)
+        };
+        let report = StrategyDiagnoser::default().diagnose(&trend_following, &Value::Null);
+        assert!(report.is_healthy());
+
+        let weak = DiagnosisInput {
+            avg_win: dec!(120),
+            ..trend_following
+        };
+        let report = StrategyDiagnoser::default().diagnose(&weak, &Value::Null);
+        assert!(report.has(DiagnosisKind::LowWinRate));
+    }
+
+    #[test]
+    fn test_findings_sorted_by_impact() {
+        let input = DiagnosisInput {
+            data_points: 100,
+            max_drawdown_pct: dec!(28),
+            net_profit: dec!(100),
+            total_slippage: dec!(400),
+            total_commission: dec!(100),
+            signals_generated: 50,
+            signals_executed: 45,
+            ..traded(40)
+        };
+
+        let report = StrategyDiagnoser::default().diagnose(&input, &Value::Null);
+        let kinds: Vec<_> = report.findings.iter().map(|f| f.kind).collect();
+
+        // 슬리피지 40+0.67*50=73.3, 회전율 30+20*2=70, 낙폭 30+3*2=36
+        assert_eq!(
+            kinds,
+            vec![
+                DiagnosisKind::HighSlippage,
+                DiagnosisKind::HighTurnover,
+                DiagnosisKind::DeepDrawdown,
+            ]
+        );
+        assert!(report
+            .findings
+            .windows(2)
+            .all(|w| w[0].impact >= w[1].impact));
+        assert!(report.lines()[0].starts_with("  1. [치명]"));
+    }
+
+    #[test]
+    fn test_blocked_signals_flag_excessive_filter() {
+        let input = DiagnosisInput {
+            signals_generated: 100,
+            signals_executed: 10,
+            ..traded(10)
+        };
+
+        let report = StrategyDiagnoser::default().diagnose(&input, &Value::Null);
+        let finding = report.top_finding().unwrap();
+
+        assert_eq!(finding.kind, DiagnosisKind::ExcessiveFilter);
+        assert_eq!(finding.impact, dec!(60));
+    }
+}
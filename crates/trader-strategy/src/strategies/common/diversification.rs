@@ -0,0 +1,498 @@
+//! 전략별 최대 보유 종목 수 및 종목당 비중 강제.
+//!
+//! 전략 설정의 `diversification` 섹션으로 분산 한도를 선언합니다:
+//! - **max_holdings**: 최대 동시 보유 종목 수
+//! - **max_weight_pct**: 종목당 최대 비중 (평가 자산 대비 %)
+//! - **on_limit**: 보유 종목 수 한도 도달 시 정책 (새 진입 보류 / 가장 약한 보유 교체)
+//!
+//! 엔진이 신호 집행 직전 [`DiversificationGuard::apply`]로 진입 신호를 검사합니다.
+//! 종목당 비중 여유분은 [`MAX_POSITION_VALUE_KEY`] 메타데이터로 실행기에 전달되어
+//! 주문 수량이 축소됩니다. RiskManager의 집중도 한도([`ConcentrationLimits`])가
+//! 주어지면 두 한도 중 더 엄격한 값을 적용하여, 전략 단계에서 통과한 신호가
+//! RiskManager 검증에서 다시 거부되지 않도록 합니다.
+//!
+//! # 설정 예시
+//!
+//! ```json
+//! {
+//!   "diversification": {
+//!     "max_holdings": 5,
+//!     "max_weight_pct": "25",
+//!     "on_limit": "replace_weakest"
+//!   }
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use trader_core::{domain::StrategyContext, Side, Signal, SignalType, MAX_POSITION_VALUE_KEY};
+
+/// 설정 JSON에서 분산 한도 섹션 키.
+pub const DIVERSIFICATION_KEY: &str = "diversification";
+
+/// 보유 종목 수 한도 도달 시 정책.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HoldingLimitPolicy {
+    /// 새 진입 보류
+    #[default]
+    Defer,
+    /// 미실현 수익률이 가장 낮은 보유를 청산하고 진입
+    ReplaceWeakest,
+}
+
+/// 전략 분산 한도 설정.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DiversificationConfig {
+    /// 최대 동시 보유 종목 수 (None이면 제한 없음)
+    #[serde(default)]
+    pub max_holdings: Option<usize>,
+
+    /// 종목당 최대 비중 (평가 자산 대비 %, None이면 제한 없음)
+    #[serde(default)]
+    pub max_weight_pct: Option<Decimal>,
+
+    /// 보유 종목 수 한도 도달 시 정책
+    #[serde(default)]
+    pub on_limit: HoldingLimitPolicy,
+}
+
+/// RiskManager 집중도 한도.
+///
+/// `RiskConfig`의 `max_position_pct`, `max_concurrent_positions`와 같은 값을 전달합니다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ConcentrationLimits {
+    /// 종목당 최대 포지션 비율 (%)
+    pub max_position_pct: Decimal,
+    /// 최대 동시 포지션 수
+    pub max_concurrent_positions: usize,
+}
+
+impl ConcentrationLimits {
+    /// RiskConfig 값(f64 비율)에서 생성.
+    pub fn new(max_position_pct: f64, max_concurrent_positions: usize) -> Self {
+        Self {
+            max_position_pct: Decimal::from_f64(max_position_pct).unwrap_or(Decimal::ONE_HUNDRED),
+            max_concurrent_positions,
+        }
+    }
+}
+
+impl DiversificationConfig {
+    /// 전략 설정 JSON에서 `diversification` 섹션을 파싱합니다.
+    ///
+    /// 섹션이 없거나 형식이 잘못되면 `None`을 반환합니다.
+    pub fn from_strategy_config(config: &Value) -> Option<Self> {
+        let section = config.get(DIVERSIFICATION_KEY)?;
+        match serde_json::from_value(section.clone()) {
+            Ok(diversification) => Some(diversification),
+            Err(e) => {
+                tracing::warn!(error = %e, "diversification 설정 파싱 실패 - 무시");
+                None
+            }
+        }
+    }
+
+    /// RiskManager 한도와 결합한 유효 한도 (더 엄격한 값 적용).
+    pub fn effective(&self, risk: Option<&ConcentrationLimits>) -> Self {
+        let Some(risk) = risk else {
+            return self.clone();
+        };
+
+        Self {
+            max_holdings: Some(
+                self.max_holdings
+                    .map_or(risk.max_concurrent_positions, |max| {
+                        max.min(risk.max_concurrent_positions)
+                    }),
+            ),
+            max_weight_pct: Some(
+                self.max_weight_pct
+                    .map_or(risk.max_position_pct, |max| max.min(risk.max_position_pct)),
+            ),
+            on_limit: self.on_limit,
+        }
+    }
+}
+
+/// 분산 강제로 신호가 생략된 사유.
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum DiversificationSkip {
+    /// 보유 종목 수 한도 도달 (보류 정책 또는 교체 대상 없음)
+    #[error("보유 종목 수 한도 도달: {holdings}/{max}")]
+    HoldingLimit { holdings: usize, max: usize },
+
+    /// 종목당 비중 한도 도달 (추가 진입 여유 없음)
+    #[error("종목 비중 한도 도달: {ticker} {weight_pct}% >= {max_pct}%")]
+    WeightLimit {
+        ticker: String,
+        weight_pct: Decimal,
+        max_pct: Decimal,
+    },
+}
+
+/// 분산 강제로 생략된 신호.
+#[derive(Debug, Clone)]
+pub struct SkippedSignal {
+    /// 생략된 신호
+    pub signal: Signal,
+    /// 생략 사유
+    pub reason: DiversificationSkip,
+}
+
+/// 분산 강제 적용 결과.
+#[derive(Debug, Clone, Default)]
+pub struct DiversificationOutcome {
+    /// 집행할 신호 (교체 청산 신호 포함, 비중 여유분 메타데이터 설정)
+    pub signals: Vec<Signal>,
+    /// 생략된 신호
+    pub skipped: Vec<SkippedSignal>,
+    /// 교체로 청산되는 종목
+    pub replaced: Vec<String>,
+}
+
+/// 보유 현황 (평가 금액, 미실현 수익률, 방향).
+#[derive(Debug, Clone)]
+struct Holding {
+    value: Decimal,
+    pnl_pct: Decimal,
+    side: Side,
+}
+
+/// 전략 분산 한도 강제기.
+#[derive(Debug, Clone)]
+pub struct DiversificationGuard {
+    config: DiversificationConfig,
+}
+
+impl DiversificationGuard {
+    /// 새 강제기 생성.
+    pub fn new(config: DiversificationConfig) -> Self {
+        Self { config }
+    }
+
+    /// 설정 참조.
+    pub fn config(&self) -> &DiversificationConfig {
+        &self.config
+    }
+
+    /// 신호 목록에 분산 한도를 적용합니다.
+    ///
+    /// - 신규 종목 Entry: 보유 종목 수 한도 확인 후 정책에 따라 보류하거나 가장 약한 보유를 교체
+    /// - Entry / AddToPosition: 종목당 비중 여유분을 [`MAX_POSITION_VALUE_KEY`]로 설정,
+    ///   여유가 없으면 생략
+    /// - 그 외 신호: 그대로 통과 (Exit는 보유 슬롯을 해제)
+    ///
+    /// 같은 배치 안의 신호는 순서대로 반영되므로 한 번에 여러 종목이 진입해도 한도를 넘지 않습니다.
+    pub fn apply(
+        &self,
+        context: &StrategyContext,
+        signals: Vec<Signal>,
+        risk: Option<&ConcentrationLimits>,
+    ) -> DiversificationOutcome {
+        let limits = self.config.effective(risk);
+        let mut holdings: HashMap<String, Holding> = context
+            .positions
+            .iter()
+            .filter(|(_, p)| p.quantity > Decimal::ZERO)
+            .map(|(ticker, p)| {
+                (
+                    ticker.clone(),
+                    Holding {
+                        value: p.current_price * p.quantity,
+                        pnl_pct: p.unrealized_pnl_pct,
+                        side: p.side,
+                    },
+                )
+            })
+            .collect();
+
+        let equity = if context.account.total_balance > Decimal::ZERO {
+            context.account.total_balance
+        } else {
+            context.account.available_balance + context.total_position_value()
+        };
+
+        let mut outcome = DiversificationOutcome::default();
+        // 이번 배치에서 진입한 종목 (교체 대상에서 제외)
+        let mut entered: HashSet<String> = HashSet::new();
+
+        for signal in signals {
+            match signal.signal_type {
+                SignalType::Entry if !holdings.contains_key(&signal.ticker) => {
+                    if let Some(max) = limits.max_holdings {
+                        if holdings.len() >= max {
+                            let weakest = match limits.on_limit {
+                                HoldingLimitPolicy::Defer => None,
+                                HoldingLimitPolicy::ReplaceWeakest => holdings
+                                    .iter()
+                                    .filter(|(ticker, _)| !entered.contains(*ticker))
+                                    .min_by(|a, b| a.1.pnl_pct.cmp(&b.1.pnl_pct))
+                                    .map(|(ticker, holding)| (ticker.clone(), holding.side)),
+                            };
+
+                            let Some((weakest, side)) = weakest else {
+                                outcome.skipped.push(SkippedSignal {
+                                    reason: DiversificationSkip::HoldingLimit {
+                                        holdings: holdings.len(),
+                                        max,
+                                    },
+                                    signal,
+                                });
+                                continue;
+                            };
+
+                            holdings.remove(&weakest);
+                            outcome.signals.push(
+                                Signal::exit(
+                                    signal.strategy_id.clone(),
+                                    weakest.clone(),
+                                    side.opposite(),
+                                )
+                                .with_metadata(
+                                    "reason",
+                                    serde_json::json!("diversification_replace"),
+                                )
+                                .with_metadata("replaced_by", serde_json::json!(signal.ticker)),
+                            );
+                            outcome.replaced.push(weakest);
+                        }
+                    }
+
+                    self.push_entry(&limits, equity, &mut holdings, signal, &mut outcome);
+                    if let Some(last) = outcome.signals.last() {
+                        entered.insert(last.ticker.clone());
+                    }
+                }
+                SignalType::Entry | SignalType::AddToPosition => {
+                    self.push_entry(&limits, equity, &mut holdings, signal, &mut outcome);
+                }
+                SignalType::Exit => {
+                    if signal.position_id.is_none() {
+                        holdings.remove(&signal.ticker);
+                    }
+                    outcome.signals.push(signal);
+                }
+                _ => outcome.signals.push(signal),
+            }
+        }
+
+        outcome
+    }
+
+    /// 종목당 비중 한도를 적용해 진입 신호를 추가합니다.
+    fn push_entry(
+        &self,
+        limits: &DiversificationConfig,
+        equity: Decimal,
+        holdings: &mut HashMap<String, Holding>,
+        signal: Signal,
+        outcome: &mut DiversificationOutcome,
+    ) {
+        let (Some(max_pct), true) = (limits.max_weight_pct, equity > Decimal::ZERO) else {
+            holdings.entry(signal.ticker.clone()).or_insert(Holding {
+                value: Decimal::ZERO,
+                pnl_pct: Decimal::ZERO,
+                side: signal.side,
+            });
+            outcome.signals.push(signal);
+            return;
+        };
+
+        let current = holdings
+            .get(&signal.ticker)
+            .map_or(Decimal::ZERO, |h| h.value);
+        let max_value = equity * max_pct / Decimal::ONE_HUNDRED;
+        let headroom = max_value - current;
+
+        if headroom <= Decimal::ZERO {
+            outcome.skipped.push(SkippedSignal {
+                reason: DiversificationSkip::WeightLimit {
+                    ticker: signal.ticker.clone(),
+                    weight_pct: (current / equity * Decimal::ONE_HUNDRED).round_dp(2),
+                    max_pct,
+                },
+                signal,
+            });
+            return;
+        }
+
+        // 전략이 이미 더 작은 한도를 지정했으면 유지
+        let cap = signal
+            .max_position_value()
+            .map_or(headroom, |existing| existing.min(headroom));
+
+        // 같은 배치의 후속 신호를 위해 한도만큼 보유한 것으로 간주
+        holdings
+            .entry(signal.ticker.clone())
+            .and_modify(|h| h.value += cap)
+            .or_insert(Holding {
+                value: cap,
+                pnl_pct: Decimal::ZERO,
+                side: signal.side,
+            });
+
+        let mut signal = signal;
+        signal.metadata.insert(
+            MAX_POSITION_VALUE_KEY.to_string(),
+            Value::String(cap.to_string()),
+        );
+        outcome.signals.push(signal);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use trader_core::domain::{StrategyAccountInfo, StrategyPositionInfo};
+
+    fn context(positions: &[(&str, Decimal, Decimal, Decimal)]) -> StrategyContext {
+        let mut ctx = StrategyContext {
+            account: StrategyAccountInfo {
+                total_balance: dec!(10000),
+                available_balance: dec!(5000),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        ctx.update_positions(
+            positions
+                .iter()
+                .map(|(ticker, qty, entry, price)| {
+                    let mut p =
+                        StrategyPositionInfo::new(ticker.to_string(), Side::Buy, *qty, *entry);
+                    p.update_price(*price);
+                    p
+                })
+                .collect(),
+        );
+        ctx
+    }
+
+    fn entry(ticker: &str) -> Signal {
+        Signal::entry("s1", ticker.to_string(), Side::Buy)
+    }
+
+    #[test]
+    fn test_parse_from_strategy_config() {
+        let config = serde_json::json!({
+            "diversification": { "max_holdings": 3, "max_weight_pct": "20", "on_limit": "replace_weakest" }
+        });
+        let parsed = DiversificationConfig::from_strategy_config(&config).unwrap();
+        assert_eq!(parsed.max_holdings, Some(3));
+        assert_eq!(parsed.max_weight_pct, Some(dec!(20)));
+        assert_eq!(parsed.on_limit, HoldingLimitPolicy::ReplaceWeakest);
+
+        assert!(DiversificationConfig::from_strategy_config(&serde_json::json!({})).is_none());
+    }
+
+    #[test]
+    fn test_effective_uses_stricter_risk_limits() {
+        let config = DiversificationConfig {
+            max_holdings: Some(5),
+            max_weight_pct: Some(dec!(30)),
+            on_limit: HoldingLimitPolicy::Defer,
+        };
+        let risk = ConcentrationLimits::new(10.0, 8);
+        let effective = config.effective(Some(&risk));
+        assert_eq!(effective.max_holdings, Some(5));
+        assert_eq!(effective.max_weight_pct, Some(dec!(10)));
+
+        let unset = DiversificationConfig::default().effective(Some(&risk));
+        assert_eq!(unset.max_holdings, Some(8));
+    }
+
+    #[test]
+    fn test_defer_when_holding_limit_reached() {
+        let ctx = context(&[
+            ("AAA", dec!(10), dec!(100), dec!(100)),
+            ("BBB", dec!(10), dec!(100), dec!(100)),
+        ]);
+        let guard = DiversificationGuard::new(DiversificationConfig {
+            max_holdings: Some(2),
+            ..Default::default()
+        });
+
+        let outcome = guard.apply(&ctx, vec![entry("CCC")], None);
+        assert!(outcome.signals.is_empty());
+        assert_eq!(
+            outcome.skipped[0].reason,
+            DiversificationSkip::HoldingLimit {
+                holdings: 2,
+                max: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_replace_weakest_holding() {
+        let ctx = context(&[
+            ("AAA", dec!(10), dec!(100), dec!(120)), // +20%
+            ("BBB", dec!(10), dec!(100), dec!(90)),  // -10%
+        ]);
+        let guard = DiversificationGuard::new(DiversificationConfig {
+            max_holdings: Some(2),
+            on_limit: HoldingLimitPolicy::ReplaceWeakest,
+            ..Default::default()
+        });
+
+        let outcome = guard.apply(&ctx, vec![entry("CCC"), entry("DDD")], None);
+        assert_eq!(outcome.replaced, vec!["BBB".to_string(), "AAA".to_string()]);
+        assert_eq!(outcome.signals.len(), 4);
+        assert_eq!(outcome.signals[0].signal_type, SignalType::Exit);
+        assert_eq!(outcome.signals[0].ticker, "BBB");
+        assert_eq!(outcome.signals[0].side, Side::Sell);
+        assert_eq!(outcome.signals[1].ticker, "CCC");
+
+        // 같은 배치에서 진입한 종목은 교체 대상이 아님
+        let guard = DiversificationGuard::new(DiversificationConfig {
+            max_holdings: Some(1),
+            on_limit: HoldingLimitPolicy::ReplaceWeakest,
+            ..Default::default()
+        });
+        let outcome = guard.apply(&context(&[]), vec![entry("CCC"), entry("DDD")], None);
+        assert_eq!(outcome.signals.len(), 1);
+        assert_eq!(outcome.skipped.len(), 1);
+    }
+
+    #[test]
+    fn test_weight_limit_caps_and_skips() {
+        // AAA 보유 2000 (자산 10000의 20%)
+        let ctx = context(&[("AAA", dec!(20), dec!(100), dec!(100))]);
+        let guard = DiversificationGuard::new(DiversificationConfig {
+            max_weight_pct: Some(dec!(25)),
+            ..Default::default()
+        });
+
+        let add = Signal::new(
+            "s1",
+            "AAA".to_string(),
+            Side::Buy,
+            SignalType::AddToPosition,
+        );
+        let outcome = guard.apply(&ctx, vec![add, entry("BBB")], None);
+        assert_eq!(outcome.signals[0].max_position_value(), Some(dec!(500)));
+        assert_eq!(outcome.signals[1].max_position_value(), Some(dec!(2500)));
+
+        // 한도 초과 종목 추가 진입은 생략
+        let ctx = context(&[("AAA", dec!(30), dec!(100), dec!(100))]);
+        let add = Signal::new(
+            "s1",
+            "AAA".to_string(),
+            Side::Buy,
+            SignalType::AddToPosition,
+        );
+        let outcome = guard.apply(&ctx, vec![add], None);
+        assert!(outcome.signals.is_empty());
+        assert!(matches!(
+            outcome.skipped[0].reason,
+            DiversificationSkip::WeightLimit { .. }
+        ));
+    }
+}
@@ -13,12 +13,19 @@
 //! - **position_sync**: 거래소 중립 포지션 상태 동기화
 //! - **global_score_utils**: GlobalScore 기반 종목 선택 및 포지션 가중치 계산
 //! - **screening_integration**: 스크리닝 결과 및 RouteState 전략 연동
+//! - **performance_target**: 벤치마크 및 성과 목표 평가
+//! - **diagnosis**: 성과 자가 진단 및 개선 제안
+//! - **diversification**: 전략별 최대 보유 종목 수 및 종목당 비중 강제
+//! - **stop_loss_take_profit**: 고정 %·ATR·트레일링 손절/익절 판정
 
 pub mod defaults;
+pub mod diagnosis;
+pub mod diversification;
 pub mod exit_config;
 pub mod global_score_utils;
 pub mod indicators;
 pub mod momentum;
+pub mod performance_target;
 pub mod position_sizing;
 pub mod position_sync;
 pub mod rebalance;
@@ -26,10 +33,19 @@ pub mod risk_checks;
 pub mod screening_integration;
 pub mod serde_helpers;
 pub mod signal_filters;
+pub mod stop_loss_take_profit;
 
 pub use defaults::{
     AllocationDefaults, GridDefaults, IndicatorDefaults, MomentumDefaults, RiskDefaults,
 };
+pub use diagnosis::{
+    DiagnosisEvidence, DiagnosisFinding, DiagnosisInput, DiagnosisKind, DiagnosisReport,
+    DiagnosisSeverity, DiagnosisThresholds, StrategyDiagnoser,
+};
+pub use diversification::{
+    ConcentrationLimits, DiversificationConfig, DiversificationGuard, DiversificationOutcome,
+    DiversificationSkip, HoldingLimitPolicy, SkippedSignal, DIVERSIFICATION_KEY,
+};
 pub use exit_config::{
     DailyLossLimitConfig, ExitConfig, ProfitLockConfig, StepLevel, StopLossConfig, StopLossMode,
     TakeProfitConfig, TrailingMode, TrailingStopConfig,
@@ -46,6 +62,10 @@ pub use indicators::{
 pub use momentum::{
     MomentumCalculator, MomentumConfig, MomentumResult, MomentumScore, WeightedMomentumConfig,
 };
+pub use performance_target::{
+    EvaluationBasis, PerformanceGoal, PerformanceTargetConfig, PerformanceTargetTracker,
+    TargetEvaluation,
+};
 pub use position_sizing::{
     AtrPositionSizer, FixedRatioSizer, GlobalScorePositionSizer, KellyPositionSizer, PositionSize,
     PositionSizer,
@@ -66,3 +86,7 @@ pub use signal_filters::{
     FilteredSignal, SignalContext, SignalFilter, SignalStrength, TrendFilter, ValidationResult,
     VolumeFilter,
 };
+pub use stop_loss_take_profit::{
+    ExitDecision, ExitPriority, ExitReason, ExitRule, PriceBar, ProtectedPosition,
+    StopLossTakeProfit, TrailingRule,
+};
@@ -0,0 +1,374 @@
+//! 전략별 벤치마크 및 성과 목표 설정.
+//!
+//! 전략 설정의 `performance_target` 섹션으로 목표 유형을 선언합니다:
+//! - **Absolute**: 절대수익 목표 (예: 기간 수익률 10% 이상)
+//! - **BenchmarkRelative**: 벤치마크 초과 목표 (예: 벤치마크 대비 +3%p)
+//!
+//! 평가는 관찰 전용이며 신호를 생성하거나 수정하지 않습니다.
+//! 따라서 리밸런싱·손절(ExitConfig) 로직과 독립적으로 동작합니다.
+//!
+//! # 설정 예시
+//!
+//! ```json
+//! {
+//!   "performance_target": {
+//!     "benchmark": "SPY",
+//!     "goal": { "type": "benchmark_relative", "excess_return_pct": "3" },
+//!     "underperformance_alert_periods": 3
+//!   }
+//! }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// 설정 JSON에서 성과 목표 섹션 키.
+pub const PERFORMANCE_TARGET_KEY: &str = "performance_target";
+
+/// 성과 목표 유형.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PerformanceGoal {
+    /// 절대수익 목표
+    Absolute {
+        /// 목표 수익률 (%)
+        target_return_pct: Decimal,
+    },
+    /// 벤치마크 초과 목표
+    BenchmarkRelative {
+        /// 벤치마크 대비 목표 초과 수익률 (%p)
+        excess_return_pct: Decimal,
+    },
+}
+
+impl Default for PerformanceGoal {
+    fn default() -> Self {
+        Self::Absolute {
+            target_return_pct: Decimal::ZERO,
+        }
+    }
+}
+
+/// 전략 성과 목표 설정.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PerformanceTargetConfig {
+    /// 벤치마크 심볼 (예: "SPY", "069500")
+    #[serde(default)]
+    pub benchmark: Option<String>,
+
+    /// 성과 목표
+    #[serde(default)]
+    pub goal: PerformanceGoal,
+
+    /// 연속 부진 경고 기준 (평가 횟수)
+    ///
+    /// 목표 미달이 이 횟수만큼 연속되면 경고합니다. 0이면 경고하지 않습니다.
+    #[serde(default = "default_underperformance_alert_periods")]
+    pub underperformance_alert_periods: usize,
+}
+
+fn default_underperformance_alert_periods() -> usize {
+    3
+}
+
+/// 평가 기준.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EvaluationBasis {
+    /// 절대수익 기준
+    Absolute,
+    /// 벤치마크 대비 기준
+    Benchmark,
+    /// 벤치마크 데이터 결측으로 절대수익 기준 폴백
+    AbsoluteFallback,
+}
+
+impl EvaluationBasis {
+    /// 성과 리포트 표기용 라벨.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Absolute => "절대수익 기준",
+            Self::Benchmark => "벤치마크 대비",
+            Self::AbsoluteFallback => "절대수익 기준 (벤치마크 결측)",
+        }
+    }
+}
+
+/// 목표 달성 평가 결과.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TargetEvaluation {
+    /// 평가 기준
+    pub basis: EvaluationBasis,
+    /// 벤치마크 심볼
+    pub benchmark: Option<String>,
+    /// 전략 수익률 (%)
+    pub strategy_return_pct: Decimal,
+    /// 벤치마크 수익률 (%) - 벤치마크 기준일 때만
+    pub benchmark_return_pct: Option<Decimal>,
+    /// 비교 대상 수익률 (%) - 절대 기준이면 전략 수익률, 벤치마크 기준이면 초과 수익률
+    pub measured_pct: Decimal,
+    /// 목표치 (%)
+    pub target_pct: Decimal,
+    /// 목표 대비 차이 (%p, 양수면 초과 달성)
+    pub gap_pct: Decimal,
+    /// 목표 달성 여부
+    pub achieved: bool,
+}
+
+impl TargetEvaluation {
+    /// 한 줄 요약 (리포트 표기용).
+    pub fn summary(&self) -> String {
+        let status = if self.achieved { "달성" } else { "미달" };
+        match (self.basis, self.benchmark_return_pct) {
+            (EvaluationBasis::Benchmark, Some(bench)) => format!(
+                "[{}] 전략 {:.2}% / {} {:.2}% → 초과 {:.2}%p (목표 +{:.2}%p, {})",
+                self.basis.label(),
+                self.strategy_return_pct,
+                self.benchmark.as_deref().unwrap_or("벤치마크"),
+                bench,
+                self.measured_pct,
+                self.target_pct,
+                status
+            ),
+            _ => format!(
+                "[{}] 수익률 {:.2}% (목표 {:.2}%, {})",
+                self.basis.label(),
+                self.measured_pct,
+                self.target_pct,
+                status
+            ),
+        }
+    }
+}
+
+impl PerformanceTargetConfig {
+    /// 전략 설정 JSON에서 `performance_target` 섹션을 파싱합니다.
+    ///
+    /// 섹션이 없거나 형식이 잘못되면 `None`을 반환합니다.
+    pub fn from_strategy_config(config: &Value) -> Option<Self> {
+        let section = config.get(PERFORMANCE_TARGET_KEY)?;
+        match serde_json::from_value(section.clone()) {
+            Ok(target) => Some(target),
+            Err(e) => {
+                tracing::warn!(error = %e, "performance_target 설정 파싱 실패 - 무시");
+                None
+            }
+        }
+    }
+
+    /// 목표 유형이 벤치마크 기준인지 여부.
+    pub fn is_benchmark_relative(&self) -> bool {
+        matches!(self.goal, PerformanceGoal::BenchmarkRelative { .. })
+    }
+
+    /// 목표 달성 여부를 평가합니다.
+    ///
+    /// 벤치마크 기준 목표인데 벤치마크 수익률이 없으면(데이터 결측 또는 심볼 미지정)
+    /// 벤치마크 수익률을 0으로 간주하여 절대수익 기준으로 폴백합니다.
+    pub fn evaluate(
+        &self,
+        strategy_return_pct: Decimal,
+        benchmark_return_pct: Option<Decimal>,
+    ) -> TargetEvaluation {
+        let (basis, measured_pct, target_pct, bench) = match &self.goal {
+            PerformanceGoal::Absolute { target_return_pct } => (
+                EvaluationBasis::Absolute,
+                strategy_return_pct,
+                *target_return_pct,
+                None,
+            ),
+            PerformanceGoal::BenchmarkRelative { excess_return_pct } => {
+                match benchmark_return_pct.filter(|_| self.benchmark.is_some()) {
+                    Some(bench) => (
+                        EvaluationBasis::Benchmark,
+                        strategy_return_pct - bench,
+                        *excess_return_pct,
+                        Some(bench),
+                    ),
+                    None => (
+                        EvaluationBasis::AbsoluteFallback,
+                        strategy_return_pct,
+                        *excess_return_pct,
+                        None,
+                    ),
+                }
+            }
+        };
+
+        let gap_pct = measured_pct - target_pct;
+        TargetEvaluation {
+            basis,
+            benchmark: self.benchmark.clone(),
+            strategy_return_pct,
+            benchmark_return_pct: bench,
+            measured_pct,
+            target_pct,
+            gap_pct,
+            achieved: gap_pct >= Decimal::ZERO,
+        }
+    }
+}
+
+/// 연속 부진을 추적하는 성과 목표 평가기.
+///
+/// 주기적(일/주 단위 등)으로 [`record`](Self::record)를 호출하면
+/// 목표 미달이 연속될 때 경고를 남깁니다.
+#[derive(Debug, Clone)]
+pub struct PerformanceTargetTracker {
+    config: PerformanceTargetConfig,
+    consecutive_misses: usize,
+    last: Option<TargetEvaluation>,
+}
+
+impl PerformanceTargetTracker {
+    /// 새 추적기 생성.
+    pub fn new(config: PerformanceTargetConfig) -> Self {
+        Self {
+            config,
+            consecutive_misses: 0,
+            last: None,
+        }
+    }
+
+    /// 설정 참조.
+    pub fn config(&self) -> &PerformanceTargetConfig {
+        &self.config
+    }
+
+    /// 마지막 평가 결과.
+    pub fn last_evaluation(&self) -> Option<&TargetEvaluation> {
+        self.last.as_ref()
+    }
+
+    /// 연속 목표 미달 횟수.
+    pub fn consecutive_misses(&self) -> usize {
+        self.consecutive_misses
+    }
+
+    /// 연속 부진 경고 기준을 넘었는지 여부.
+    pub fn is_persistently_underperforming(&self) -> bool {
+        self.config.underperformance_alert_periods > 0
+            && self.consecutive_misses >= self.config.underperformance_alert_periods
+    }
+
+    /// 평가를 기록하고 결과를 반환합니다.
+    pub fn record(
+        &mut self,
+        strategy_id: &str,
+        strategy_return_pct: Decimal,
+        benchmark_return_pct: Option<Decimal>,
+    ) -> TargetEvaluation {
+        let evaluation = self
+            .config
+            .evaluate(strategy_return_pct, benchmark_return_pct);
+
+        if evaluation.achieved {
+            self.consecutive_misses = 0;
+        } else {
+            self.consecutive_misses += 1;
+        }
+
+        if evaluation.basis == EvaluationBasis::AbsoluteFallback {
+            tracing::debug!(
+                strategy_id = strategy_id,
+                benchmark = ?self.config.benchmark,
+                "벤치마크 데이터 결측 - 절대수익 기준으로 평가"
+            );
+        }
+
+        if self.is_persistently_underperforming() {
+            tracing::warn!(
+                strategy_id = strategy_id,
+                consecutive_misses = self.consecutive_misses,
+                summary = %evaluation.summary(),
+                "전략 성과 목표 연속 미달"
+            );
+        }
+
+        self.last = Some(evaluation.clone());
+        evaluation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::*;
+
+    fn relative(excess: Decimal) -> PerformanceTargetConfig {
+        PerformanceTargetConfig {
+            benchmark: Some("SPY".to_string()),
+            goal: PerformanceGoal::BenchmarkRelative {
+                excess_return_pct: excess,
+            },
+            underperformance_alert_periods: 2,
+        }
+    }
+
+    #[test]
+    fn test_parse_from_strategy_config() {
+        let config = json!({
+            "ticker": "QQQ",
+            "performance_target": {
+                "benchmark": "SPY",
+                "goal": { "type": "benchmark_relative", "excess_return_pct": "3" }
+            }
+        });
+        let target = PerformanceTargetConfig::from_strategy_config(&config).unwrap();
+        assert_eq!(target.benchmark.as_deref(), Some("SPY"));
+        assert!(target.is_benchmark_relative());
+        assert_eq!(target.underperformance_alert_periods, 3);
+
+        assert!(PerformanceTargetConfig::from_strategy_config(&json!({})).is_none());
+    }
+
+    #[test]
+    fn test_benchmark_relative_evaluation() {
+        let eval = relative(dec!(3)).evaluate(dec!(15), Some(dec!(10)));
+        assert_eq!(eval.basis, EvaluationBasis::Benchmark);
+        assert_eq!(eval.measured_pct, dec!(5));
+        assert_eq!(eval.gap_pct, dec!(2));
+        assert!(eval.achieved);
+    }
+
+    #[test]
+    fn test_missing_benchmark_falls_back_to_absolute() {
+        let eval = relative(dec!(3)).evaluate(dec!(2), None);
+        assert_eq!(eval.basis, EvaluationBasis::AbsoluteFallback);
+        assert_eq!(eval.measured_pct, dec!(2));
+        assert!(!eval.achieved);
+        assert!(eval.summary().contains("벤치마크 결측"));
+    }
+
+    #[test]
+    fn test_absolute_goal_ignores_benchmark() {
+        let config = PerformanceTargetConfig {
+            benchmark: Some("SPY".to_string()),
+            goal: PerformanceGoal::Absolute {
+                target_return_pct: dec!(10),
+            },
+            underperformance_alert_periods: 3,
+        };
+        let eval = config.evaluate(dec!(12), Some(dec!(30)));
+        assert_eq!(eval.basis, EvaluationBasis::Absolute);
+        assert!(eval.achieved);
+    }
+
+    #[test]
+    fn test_tracker_detects_persistent_underperformance() {
+        let mut tracker = PerformanceTargetTracker::new(relative(dec!(0)));
+
+        tracker.record("s1", dec!(1), Some(dec!(2)));
+        assert!(!tracker.is_persistently_underperforming());
+        tracker.record("s1", dec!(1), Some(dec!(3)));
+        assert!(tracker.is_persistently_underperforming());
+
+        // 목표 달성 시 연속 카운트 초기화
+        tracker.record("s1", dec!(5), Some(dec!(3)));
+        assert_eq!(tracker.consecutive_misses(), 0);
+        assert!(tracker.last_evaluation().unwrap().achieved);
+    }
+}
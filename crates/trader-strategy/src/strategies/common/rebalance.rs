@@ -7,6 +7,7 @@
 //! - 목표 배분 달성을 위한 주문 계산 (매수/매도)
 //! - 최소 거래 금액 필터링
 //! - 수수료 및 세금 고려
+//! - 비용 인지 리밸런싱 (편차 개선분보다 거래비용이 큰 조정은 이월)
 //!
 //! # 예제
 //!
@@ -60,6 +61,14 @@ pub struct RebalanceConfig {
 
     /// 현금 심볼 (예: "CASH", "KRW", "USD").
     pub cash_ticker: String,
+
+    /// 비용 인지 리밸런싱 활성화.
+    ///
+    /// 활성화 시 자산별 편차가 `rebalance_threshold`를 넘는 주문만 실행하며,
+    /// 예상 거래비용(수수료 + 세금 + 슬리피지)이 편차 개선분보다 크면
+    /// 해당 주문을 `deferred_orders`로 이월합니다.
+    #[serde(default)]
+    pub cost_aware: bool,
 }
 
 impl Default for RebalanceConfig {
@@ -71,6 +80,7 @@ impl Default for RebalanceConfig {
             slippage_rate: dec!(0.001),      // 0.1%
             rebalance_threshold: dec!(0.03), // 3% deviation threshold
             cash_ticker: "CASH".to_string(),
+            cost_aware: false,
         }
     }
 }
@@ -85,6 +95,7 @@ impl RebalanceConfig {
             slippage_rate: dec!(0.001),    // 0.1%
             rebalance_threshold: dec!(0.03),
             cash_ticker: "KRW".to_string(),
+            cost_aware: false,
         }
     }
 
@@ -97,6 +108,7 @@ impl RebalanceConfig {
             slippage_rate: dec!(0.001), // 0.1%
             rebalance_threshold: dec!(0.03),
             cash_ticker: "USD".to_string(),
+            cost_aware: false,
         }
     }
 }
@@ -225,6 +237,12 @@ pub struct RebalanceResult {
     /// 필터링된 주문 (최소 거래 금액 미만).
     pub filtered_orders: Vec<RebalanceOrder>,
 
+    /// 비용 인지 필터로 다음 주기로 이월된 주문.
+    ///
+    /// 자산별 편차가 임계값 이하이거나 예상 거래비용이 편차 개선분보다 큰 주문.
+    #[serde(default)]
+    pub deferred_orders: Vec<RebalanceOrder>,
+
     /// 임계값 기준 리밸런싱 필요 여부.
     pub rebalance_needed: bool,
 
@@ -258,6 +276,14 @@ impl RebalanceResult {
     pub fn net_cash_flow(&self) -> Decimal {
         self.total_sell_amount - self.total_buy_amount - self.total_fees - self.total_taxes
     }
+
+    /// 실행 주문의 회전율 (총 거래대금 / 포트폴리오 가치).
+    pub fn turnover(&self) -> Decimal {
+        if self.total_portfolio_value.is_zero() {
+            return dec!(0);
+        }
+        (self.total_buy_amount + self.total_sell_amount) / self.total_portfolio_value
+    }
 }
 
 /// 포트폴리오 리밸런싱 계산기.
@@ -277,6 +303,23 @@ impl RebalanceCalculator {
         Self::new(RebalanceConfig::default())
     }
 
+    /// 주문의 예상 거래비용 (수수료 + 세금 + 슬리피지).
+    pub fn estimated_cost(&self, order: &RebalanceOrder) -> Decimal {
+        order.estimated_fee + order.estimated_tax + order.amount * self.config.slippage_rate
+    }
+
+    /// 비용 인지 필터 통과 여부.
+    ///
+    /// 자산별 편차가 임계값을 넘어야 하며, 임계값을 초과한 편차만큼의 금액
+    /// (편차 개선분)이 예상 거래비용보다 커야 합니다.
+    fn is_cost_effective(&self, order: &RebalanceOrder, total_value: Decimal) -> bool {
+        let excess = order.weight_deviation.abs() - self.config.rebalance_threshold;
+        if excess <= dec!(0) {
+            return false;
+        }
+        excess * total_value > self.estimated_cost(order)
+    }
+
     /// 목표 비중을 합계 1.0으로 정규화.
     pub fn normalize_weights(&self, targets: &[TargetAllocation]) -> Vec<TargetAllocation> {
         let total_weight: Decimal = targets.iter().map(|t| t.weight).sum();
@@ -335,6 +378,7 @@ impl RebalanceCalculator {
         // Calculate orders for each target
         let mut orders = Vec::new();
         let mut filtered_orders = Vec::new();
+        let mut deferred_orders = Vec::new();
         let mut max_deviation = dec!(0);
 
         for target in &normalized_targets {
@@ -421,6 +465,8 @@ impl RebalanceCalculator {
             // Filter orders below minimum trade amount
             if actual_amount < self.config.min_trade_amount {
                 filtered_orders.push(order);
+            } else if self.config.cost_aware && !self.is_cost_effective(&order, total_value) {
+                deferred_orders.push(order);
             } else {
                 orders.push(order);
             }
@@ -452,7 +498,9 @@ impl RebalanceCalculator {
                     weight_deviation: -current_weight,
                 };
 
-                if position.market_value >= self.config.min_trade_amount {
+                if self.config.cost_aware && !self.is_cost_effective(&order, total_value) {
+                    deferred_orders.push(order);
+                } else if position.market_value >= self.config.min_trade_amount {
                     orders.push(order);
                     if current_weight > max_deviation {
                         max_deviation = current_weight;
@@ -489,7 +537,9 @@ impl RebalanceCalculator {
         let total_taxes: Decimal = orders.iter().map(|o| o.estimated_tax).sum();
 
         // Check if rebalancing is needed based on threshold
-        let rebalance_needed = max_deviation > self.config.rebalance_threshold;
+        // (cost-aware mode: only when at least one order survived the cost filter)
+        let rebalance_needed = max_deviation > self.config.rebalance_threshold
+            && (!self.config.cost_aware || !orders.is_empty());
 
         RebalanceResult {
             total_portfolio_value: total_value,
@@ -500,6 +550,7 @@ impl RebalanceCalculator {
             total_fees,
             total_taxes,
             filtered_orders,
+            deferred_orders,
             rebalance_needed,
             max_weight_deviation: max_deviation,
         }
@@ -617,6 +668,7 @@ mod tests {
             slippage_rate: dec!(0),
             rebalance_threshold: dec!(0.03),
             cash_ticker: "CASH".to_string(),
+            cost_aware: false,
         };
         let calculator = RebalanceCalculator::new(config);
 
@@ -721,4 +773,72 @@ mod tests {
             assert!(first_is_sell || result.sell_orders().is_empty());
         }
     }
+
+    #[test]
+    fn test_cost_aware_defers_small_deviation() {
+        let config = RebalanceConfig {
+            min_trade_amount: dec!(100),
+            rebalance_threshold: dec!(0.05),
+            cost_aware: true,
+            ..Default::default()
+        };
+        let calculator = RebalanceCalculator::new(config);
+
+        // SPY 57% / TLT 43% → 60/40: 편차 3%로 임계값 5% 이하
+        let positions = vec![
+            PortfolioPosition::new("SPY", dec!(57), dec!(100)),
+            PortfolioPosition::new("TLT", dec!(43), dec!(100)),
+        ];
+        let targets = vec![
+            TargetAllocation::new("SPY", dec!(0.6)),
+            TargetAllocation::new("TLT", dec!(0.4)),
+        ];
+
+        let result = calculator.calculate_orders(&positions, &targets);
+
+        assert!(!result.has_orders());
+        assert_eq!(result.deferred_orders.len(), 2);
+        assert!(!result.rebalance_needed);
+    }
+
+    #[test]
+    fn test_cost_aware_skips_when_cost_exceeds_improvement() {
+        let targets = vec![
+            TargetAllocation::new("SPY", dec!(0.6)),
+            TargetAllocation::new("TLT", dec!(0.4)),
+        ];
+        // SPY 53% / TLT 47% → 편차 7%, 임계값 초과분 2% (= 200)
+        let positions = vec![
+            PortfolioPosition::new("SPY", dec!(53), dec!(100)),
+            PortfolioPosition::new("TLT", dec!(47), dec!(100)),
+        ];
+
+        let cheap = RebalanceCalculator::new(RebalanceConfig {
+            min_trade_amount: dec!(100),
+            fee_rate: dec!(0.001),
+            slippage_rate: dec!(0.001),
+            rebalance_threshold: dec!(0.05),
+            cost_aware: true,
+            ..Default::default()
+        });
+        let result = cheap.calculate_orders(&positions, &targets);
+        assert_eq!(result.orders.len(), 2);
+        assert!(result.deferred_orders.is_empty());
+        assert!(result.rebalance_needed);
+        assert_eq!(result.turnover(), dec!(0.14));
+
+        // 편도 비용 30%: 700 거래에 210 비용 > 개선분 200
+        let expensive = RebalanceCalculator::new(RebalanceConfig {
+            min_trade_amount: dec!(100),
+            fee_rate: dec!(0.2),
+            slippage_rate: dec!(0.1),
+            rebalance_threshold: dec!(0.05),
+            cost_aware: true,
+            ..Default::default()
+        });
+        let result = expensive.calculate_orders(&positions, &targets);
+        assert!(!result.has_orders());
+        assert_eq!(result.deferred_orders.len(), 2);
+        assert!(!result.rebalance_needed);
+    }
 }
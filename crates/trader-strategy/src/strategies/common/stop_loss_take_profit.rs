@@ -0,0 +1,678 @@
+//! 전략 공통 손절·익절 컴포넌트.
+//!
+//! 전략마다 중복 구현되던 손절/익절/트레일링 판정을 하나의 API로 제공합니다.
+//!
+//! # 청산 규칙
+//!
+//! - **고정 %**: 진입가 대비 일정 비율
+//! - **ATR 배수**: 진입가 ± ATR × 배수
+//! - **트레일링**: 진입 후 최고가(숏은 최저가) 대비 % 또는 ATR 배수
+//!
+//! # 체결 처리
+//!
+//! - 시가가 손절가를 갭으로 건너뛴 경우 손절가가 아닌 **시가**로 체결됩니다.
+//! - 시가가 익절가를 갭으로 넘어선 경우에도 시가로 체결됩니다 (유리한 갭).
+//! - 한 봉에서 손절과 익절이 모두 닿으면 [`ExitPriority`]에 따라 결정합니다.
+//!   봉 내부 순서를 알 수 없으므로 기본값은 보수적인 손절 우선입니다.
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! let sltp = StopLossTakeProfit::new()
+//!     .with_stop_loss(ExitRule::FixedPct(dec!(2)))
+//!     .with_take_profit(ExitRule::AtrMultiple(dec!(3)))
+//!     .with_trailing(TrailingRule::pct(dec!(2), dec!(1)));
+//!
+//! let mut position = ProtectedPosition::new(Side::Buy, entry_price).with_atr(atr);
+//!
+//! // 매 봉마다
+//! if let Some(exit) = sltp.evaluate(&position, &PriceBar::from(&kline), None) {
+//!     // exit.fill_price로 청산
+//! } else {
+//!     position.observe(&PriceBar::from(&kline));
+//! }
+//! ```
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use trader_core::{Kline, Side};
+
+use super::exit_config::{ExitConfig, StopLossMode, TrailingMode};
+
+// ============================================================================
+// 규칙
+// ============================================================================
+
+/// 손절/익절 거리 규칙.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum ExitRule {
+    /// 진입가 대비 고정 비율 (%)
+    FixedPct(Decimal),
+    /// ATR 배수
+    AtrMultiple(Decimal),
+}
+
+impl ExitRule {
+    /// 기준가로부터의 거리 계산 (ATR이 필요한데 없으면 None)
+    fn distance(&self, base_price: Decimal, atr: Option<Decimal>) -> Option<Decimal> {
+        match *self {
+            Self::FixedPct(pct) => Some(base_price * pct / dec!(100)),
+            Self::AtrMultiple(multiplier) => atr
+                .filter(|atr| *atr > Decimal::ZERO)
+                .map(|atr| atr * multiplier),
+        }
+    }
+}
+
+/// 트레일링 스톱 규칙.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrailingRule {
+    /// 트레일링 시작 수익률 (%) - 최고 수익이 이 값 이상일 때 활성화
+    pub activation_pct: Decimal,
+    /// 고점(숏은 저점) 대비 트레일 거리
+    pub distance: ExitRule,
+}
+
+impl TrailingRule {
+    /// 고정 % 트레일링
+    pub fn pct(activation_pct: Decimal, trail_pct: Decimal) -> Self {
+        Self {
+            activation_pct,
+            distance: ExitRule::FixedPct(trail_pct),
+        }
+    }
+
+    /// ATR 배수 트레일링
+    pub fn atr(activation_pct: Decimal, multiplier: Decimal) -> Self {
+        Self {
+            activation_pct,
+            distance: ExitRule::AtrMultiple(multiplier),
+        }
+    }
+}
+
+/// 손절·익절 동시 충족 시 우선순위.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitPriority {
+    /// 손절 우선 (보수적, 기본값)
+    #[default]
+    StopFirst,
+    /// 익절 우선 (낙관적)
+    TakeProfitFirst,
+    /// 시가에 더 가까운 가격이 먼저 닿았다고 가정
+    NearestToOpen,
+}
+
+// ============================================================================
+// 입력
+// ============================================================================
+
+/// 판정에 사용하는 가격 봉 (OHLC).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceBar {
+    /// 시가
+    pub open: Decimal,
+    /// 고가
+    pub high: Decimal,
+    /// 저가
+    pub low: Decimal,
+    /// 종가
+    pub close: Decimal,
+}
+
+impl PriceBar {
+    /// OHLC로 생성
+    pub fn new(open: Decimal, high: Decimal, low: Decimal, close: Decimal) -> Self {
+        Self {
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+
+    /// 단일 현재가 (실시간 틱)
+    pub fn at_price(price: Decimal) -> Self {
+        Self::new(price, price, price, price)
+    }
+}
+
+impl From<&Kline> for PriceBar {
+    fn from(kline: &Kline) -> Self {
+        Self::new(kline.open, kline.high, kline.low, kline.close)
+    }
+}
+
+/// 손절·익절 대상 포지션 상태.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtectedPosition {
+    /// 포지션 방향 (Buy=롱, Sell=숏)
+    pub side: Side,
+    /// 진입가
+    pub entry_price: Decimal,
+    /// 진입 시점 ATR (ATR 규칙 기본값)
+    pub entry_atr: Option<Decimal>,
+    /// 진입 이후 최고가
+    pub highest_price: Decimal,
+    /// 진입 이후 최저가
+    pub lowest_price: Decimal,
+}
+
+impl ProtectedPosition {
+    /// 새 포지션 (최고/최저가는 진입가로 초기화)
+    pub fn new(side: Side, entry_price: Decimal) -> Self {
+        Self {
+            side,
+            entry_price,
+            entry_atr: None,
+            highest_price: entry_price,
+            lowest_price: entry_price,
+        }
+    }
+
+    /// 진입 시점 ATR 설정
+    pub fn with_atr(mut self, atr: Decimal) -> Self {
+        self.entry_atr = Some(atr);
+        self
+    }
+
+    /// 봉 반영 (최고/최저가 갱신).
+    ///
+    /// 트레일링 판정이 같은 봉의 고가를 미리 보지 않도록 [`StopLossTakeProfit::evaluate`] 이후에 호출합니다.
+    pub fn observe(&mut self, bar: &PriceBar) {
+        self.highest_price = self.highest_price.max(bar.high);
+        self.lowest_price = self.lowest_price.min(bar.low);
+    }
+
+    fn is_long(&self) -> bool {
+        self.side == Side::Buy
+    }
+
+    /// 진입 이후 최고 수익률 (%)
+    fn peak_profit_pct(&self) -> Decimal {
+        if self.entry_price <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        let favorable = if self.is_long() {
+            self.highest_price - self.entry_price
+        } else {
+            self.entry_price - self.lowest_price
+        };
+        favorable / self.entry_price * dec!(100)
+    }
+}
+
+// ============================================================================
+// 출력
+// ============================================================================
+
+/// 청산 사유.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitReason {
+    /// 손절
+    StopLoss,
+    /// 익절
+    TakeProfit,
+    /// 트레일링 스톱
+    TrailingStop,
+}
+
+impl ExitReason {
+    /// Signal metadata `exit_reason` 값
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::StopLoss => "stop_loss",
+            Self::TakeProfit => "take_profit",
+            Self::TrailingStop => "trailing_stop",
+        }
+    }
+}
+
+/// 청산 판정 결과.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExitDecision {
+    /// 청산 사유
+    pub reason: ExitReason,
+    /// 발동 가격 (손절가/익절가/트레일링 스톱가)
+    pub trigger_price: Decimal,
+    /// 체결 가격 (갭 발생 시 시가)
+    pub fill_price: Decimal,
+    /// 시가가 발동 가격을 건너뛰었는지 여부
+    pub gapped: bool,
+}
+
+impl ExitDecision {
+    /// 체결가 기준 수익률 (%)
+    pub fn pnl_pct(&self, position: &ProtectedPosition) -> Decimal {
+        if position.entry_price <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        let diff = if position.is_long() {
+            self.fill_price - position.entry_price
+        } else {
+            position.entry_price - self.fill_price
+        };
+        diff / position.entry_price * dec!(100)
+    }
+}
+
+// ============================================================================
+// 컴포넌트
+// ============================================================================
+
+/// 손절·익절·트레일링 청산 판정기.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StopLossTakeProfit {
+    /// 손절 규칙
+    #[serde(default)]
+    pub stop_loss: Option<ExitRule>,
+    /// 익절 규칙
+    #[serde(default)]
+    pub take_profit: Option<ExitRule>,
+    /// 트레일링 스톱 규칙
+    #[serde(default)]
+    pub trailing: Option<TrailingRule>,
+    /// 손절·익절 동시 충족 시 우선순위
+    #[serde(default)]
+    pub priority: ExitPriority,
+}
+
+impl StopLossTakeProfit {
+    /// 규칙 없는 판정기 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 손절 규칙 설정
+    pub fn with_stop_loss(mut self, rule: ExitRule) -> Self {
+        self.stop_loss = Some(rule);
+        self
+    }
+
+    /// 익절 규칙 설정
+    pub fn with_take_profit(mut self, rule: ExitRule) -> Self {
+        self.take_profit = Some(rule);
+        self
+    }
+
+    /// 트레일링 규칙 설정
+    pub fn with_trailing(mut self, rule: TrailingRule) -> Self {
+        self.trailing = Some(rule);
+        self
+    }
+
+    /// 동시 충족 우선순위 설정
+    pub fn with_priority(mut self, priority: ExitPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// [`ExitConfig`]에서 생성 (활성화된 섹션만 반영).
+    ///
+    /// Step/ParabolicSar 트레일링은 고정 % 트레일링으로 근사합니다.
+    pub fn from_exit_config(config: &ExitConfig) -> Self {
+        let stop_rule = match config.stop_loss.mode {
+            StopLossMode::Fixed => ExitRule::FixedPct(config.stop_loss.pct),
+            StopLossMode::AtrBased => ExitRule::AtrMultiple(config.stop_loss.atr_multiplier),
+        };
+        let stop_loss = config.stop_loss.enabled.then_some(stop_rule);
+        let take_profit = config
+            .take_profit
+            .enabled
+            .then_some(ExitRule::FixedPct(config.take_profit.pct));
+        let trailing = config.trailing_stop.enabled.then(|| {
+            let trailing = &config.trailing_stop;
+            match trailing.mode {
+                TrailingMode::AtrBased => {
+                    TrailingRule::atr(trailing.trigger_pct, trailing.atr_multiplier)
+                }
+                _ => TrailingRule::pct(trailing.trigger_pct, trailing.stop_pct),
+            }
+        });
+
+        Self {
+            stop_loss,
+            take_profit,
+            trailing,
+            priority: ExitPriority::default(),
+        }
+    }
+
+    /// 손절가 계산.
+    ///
+    /// `atr`가 None이면 포지션의 진입 시점 ATR을 사용합니다.
+    pub fn stop_price(
+        &self,
+        position: &ProtectedPosition,
+        atr: Option<Decimal>,
+    ) -> Option<Decimal> {
+        let atr = atr.or(position.entry_atr);
+        let distance = self.stop_loss?.distance(position.entry_price, atr)?;
+        Some(if position.is_long() {
+            position.entry_price - distance
+        } else {
+            position.entry_price + distance
+        })
+    }
+
+    /// 익절가 계산.
+    pub fn take_profit_price(
+        &self,
+        position: &ProtectedPosition,
+        atr: Option<Decimal>,
+    ) -> Option<Decimal> {
+        let atr = atr.or(position.entry_atr);
+        let distance = self.take_profit?.distance(position.entry_price, atr)?;
+        Some(if position.is_long() {
+            position.entry_price + distance
+        } else {
+            position.entry_price - distance
+        })
+    }
+
+    /// 트레일링 스톱가 계산 (활성화 조건 미충족 시 None).
+    pub fn trailing_stop_price(
+        &self,
+        position: &ProtectedPosition,
+        atr: Option<Decimal>,
+    ) -> Option<Decimal> {
+        let rule = self.trailing?;
+        if position.peak_profit_pct() < rule.activation_pct {
+            return None;
+        }
+
+        let atr = atr.or(position.entry_atr);
+        if position.is_long() {
+            let distance = rule.distance.distance(position.highest_price, atr)?;
+            Some(position.highest_price - distance)
+        } else {
+            let distance = rule.distance.distance(position.lowest_price, atr)?;
+            Some(position.lowest_price + distance)
+        }
+    }
+
+    /// 현재 봉 기준 청산 여부 판정.
+    ///
+    /// 트레일링은 포지션에 기록된 이전 봉까지의 최고/최저가를 기준으로 합니다.
+    /// 손절과 트레일링이 모두 설정되면 포지션에 더 가까운(더 타이트한) 가격이 적용됩니다.
+    pub fn evaluate(
+        &self,
+        position: &ProtectedPosition,
+        bar: &PriceBar,
+        atr: Option<Decimal>,
+    ) -> Option<ExitDecision> {
+        let long = position.is_long();
+
+        let stop = self.protective_stop(position, atr);
+        let take_profit = self
+            .take_profit_price(position, atr)
+            .map(|price| (ExitReason::TakeProfit, price));
+
+        // 시가 갭: 시가 자체가 첫 체결 가능 가격이므로 우선순위 규칙보다 앞섭니다.
+        if let Some((reason, price)) = stop {
+            let gapped = if long {
+                bar.open <= price
+            } else {
+                bar.open >= price
+            };
+            if gapped {
+                return Some(Self::decision(reason, price, bar.open, true));
+            }
+        }
+        if let Some((reason, price)) = take_profit {
+            let gapped = if long {
+                bar.open >= price
+            } else {
+                bar.open <= price
+            };
+            if gapped {
+                return Some(Self::decision(reason, price, bar.open, true));
+            }
+        }
+
+        let stop_hit = stop.filter(|(_, price)| {
+            if long {
+                bar.low <= *price
+            } else {
+                bar.high >= *price
+            }
+        });
+        let take_profit_hit = take_profit.filter(|(_, price)| {
+            if long {
+                bar.high >= *price
+            } else {
+                bar.low <= *price
+            }
+        });
+
+        let (reason, price) = match (stop_hit, take_profit_hit) {
+            (Some(stop), Some(take_profit)) => match self.priority {
+                ExitPriority::StopFirst => stop,
+                ExitPriority::TakeProfitFirst => take_profit,
+                ExitPriority::NearestToOpen => {
+                    if (bar.open - stop.1).abs() <= (take_profit.1 - bar.open).abs() {
+                        stop
+                    } else {
+                        take_profit
+                    }
+                }
+            },
+            (Some(hit), None) | (None, Some(hit)) => hit,
+            (None, None) => return None,
+        };
+
+        Some(Self::decision(reason, price, price, false))
+    }
+
+    /// 손절가와 트레일링 스톱가 중 더 타이트한 보호 가격
+    fn protective_stop(
+        &self,
+        position: &ProtectedPosition,
+        atr: Option<Decimal>,
+    ) -> Option<(ExitReason, Decimal)> {
+        let stop = self
+            .stop_price(position, atr)
+            .map(|price| (ExitReason::StopLoss, price));
+        let trailing = self
+            .trailing_stop_price(position, atr)
+            .map(|price| (ExitReason::TrailingStop, price));
+
+        match (stop, trailing) {
+            (Some(stop), Some(trailing)) => {
+                let trailing_tighter = if position.is_long() {
+                    trailing.1 > stop.1
+                } else {
+                    trailing.1 < stop.1
+                };
+                Some(if trailing_tighter { trailing } else { stop })
+            }
+            (stop, trailing) => stop.or(trailing),
+        }
+    }
+
+    fn decision(
+        reason: ExitReason,
+        trigger_price: Decimal,
+        fill_price: Decimal,
+        gapped: bool,
+    ) -> ExitDecision {
+        ExitDecision {
+            reason,
+            trigger_price,
+            fill_price,
+            gapped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(open: Decimal, high: Decimal, low: Decimal, close: Decimal) -> PriceBar {
+        PriceBar::new(open, high, low, close)
+    }
+
+    fn fixed_sltp() -> StopLossTakeProfit {
+        StopLossTakeProfit::new()
+            .with_stop_loss(ExitRule::FixedPct(dec!(2)))
+            .with_take_profit(ExitRule::FixedPct(dec!(4)))
+    }
+
+    #[test]
+    fn test_fixed_pct_levels() {
+        let sltp = fixed_sltp();
+        let long = ProtectedPosition::new(Side::Buy, dec!(100));
+        let short = ProtectedPosition::new(Side::Sell, dec!(100));
+
+        assert_eq!(sltp.stop_price(&long, None), Some(dec!(98)));
+        assert_eq!(sltp.take_profit_price(&long, None), Some(dec!(104)));
+        assert_eq!(sltp.stop_price(&short, None), Some(dec!(102)));
+        assert_eq!(sltp.take_profit_price(&short, None), Some(dec!(96)));
+    }
+
+    #[test]
+    fn test_intrabar_stop_fills_at_stop_price() {
+        let sltp = fixed_sltp();
+        let position = ProtectedPosition::new(Side::Buy, dec!(100));
+
+        assert!(sltp
+            .evaluate(
+                &position,
+                &bar(dec!(100), dec!(101), dec!(99), dec!(100)),
+                None
+            )
+            .is_none());
+
+        let exit = sltp
+            .evaluate(
+                &position,
+                &bar(dec!(99), dec!(100), dec!(97), dec!(98)),
+                None,
+            )
+            .unwrap();
+        assert_eq!(exit.reason, ExitReason::StopLoss);
+        assert_eq!(exit.fill_price, dec!(98));
+        assert!(!exit.gapped);
+    }
+
+    #[test]
+    fn test_gap_through_stop_fills_at_open() {
+        let sltp = fixed_sltp();
+        let position = ProtectedPosition::new(Side::Buy, dec!(100));
+
+        let exit = sltp
+            .evaluate(
+                &position,
+                &bar(dec!(95), dec!(96), dec!(94), dec!(95)),
+                None,
+            )
+            .unwrap();
+        assert_eq!(exit.reason, ExitReason::StopLoss);
+        assert_eq!(exit.trigger_price, dec!(98));
+        assert_eq!(exit.fill_price, dec!(95));
+        assert!(exit.gapped);
+        assert_eq!(exit.pnl_pct(&position), dec!(-5));
+    }
+
+    #[test]
+    fn test_gap_through_take_profit_fills_at_open() {
+        let sltp = fixed_sltp();
+        let position = ProtectedPosition::new(Side::Sell, dec!(100));
+
+        // 숏: 시가 95가 익절가 96 아래로 갭
+        let exit = sltp
+            .evaluate(
+                &position,
+                &bar(dec!(95), dec!(99), dec!(94), dec!(98)),
+                None,
+            )
+            .unwrap();
+        assert_eq!(exit.reason, ExitReason::TakeProfit);
+        assert_eq!(exit.fill_price, dec!(95));
+        assert!(exit.gapped);
+    }
+
+    #[test]
+    fn test_both_hit_priority() {
+        let position = ProtectedPosition::new(Side::Buy, dec!(100));
+        // 손절 98, 익절 104 모두 닿는 넓은 봉 (시가 103으로 익절가에 더 가까움)
+        let wide = bar(dec!(103), dec!(105), dec!(97), dec!(100));
+
+        let stop_first = fixed_sltp().evaluate(&position, &wide, None).unwrap();
+        assert_eq!(stop_first.reason, ExitReason::StopLoss);
+
+        let tp_first = fixed_sltp()
+            .with_priority(ExitPriority::TakeProfitFirst)
+            .evaluate(&position, &wide, None)
+            .unwrap();
+        assert_eq!(tp_first.reason, ExitReason::TakeProfit);
+        assert_eq!(tp_first.fill_price, dec!(104));
+
+        let nearest = fixed_sltp()
+            .with_priority(ExitPriority::NearestToOpen)
+            .evaluate(&position, &wide, None)
+            .unwrap();
+        assert_eq!(nearest.reason, ExitReason::TakeProfit);
+    }
+
+    #[test]
+    fn test_atr_rules_use_entry_atr() {
+        let sltp = StopLossTakeProfit::new()
+            .with_stop_loss(ExitRule::AtrMultiple(dec!(2)))
+            .with_take_profit(ExitRule::AtrMultiple(dec!(3)));
+        let position = ProtectedPosition::new(Side::Buy, dec!(100)).with_atr(dec!(1.5));
+
+        assert_eq!(sltp.stop_price(&position, None), Some(dec!(97)));
+        assert_eq!(sltp.take_profit_price(&position, None), Some(dec!(104.5)));
+        // 명시적 ATR이 우선
+        assert_eq!(sltp.stop_price(&position, Some(dec!(1))), Some(dec!(98)));
+        // ATR 없으면 규칙 비활성
+        let no_atr = ProtectedPosition::new(Side::Buy, dec!(100));
+        assert_eq!(sltp.stop_price(&no_atr, None), None);
+    }
+
+    #[test]
+    fn test_trailing_activation_and_tighter_stop() {
+        let sltp = fixed_sltp().with_trailing(TrailingRule::pct(dec!(2), dec!(1)));
+        let mut position = ProtectedPosition::new(Side::Buy, dec!(100));
+
+        // 최고 수익 1% → 트레일링 미활성
+        position.observe(&bar(dec!(100), dec!(101), dec!(100), dec!(101)));
+        assert_eq!(sltp.trailing_stop_price(&position, None), None);
+
+        // 최고가 103 (3%) → 트레일링 스톱 101.97, 손절 98보다 타이트
+        position.observe(&bar(dec!(101), dec!(103), dec!(101), dec!(102.5)));
+        assert_eq!(
+            sltp.trailing_stop_price(&position, None),
+            Some(dec!(101.97))
+        );
+
+        let exit = sltp
+            .evaluate(
+                &position,
+                &bar(dec!(102.5), dec!(102.8), dec!(101.5), dec!(101.8)),
+                None,
+            )
+            .unwrap();
+        assert_eq!(exit.reason, ExitReason::TrailingStop);
+        assert_eq!(exit.fill_price, dec!(101.97));
+    }
+
+    #[test]
+    fn test_from_exit_config() {
+        let sltp = StopLossTakeProfit::from_exit_config(&ExitConfig::for_leverage());
+        assert_eq!(sltp.stop_loss, Some(ExitRule::FixedPct(dec!(5.0))));
+        assert_eq!(sltp.take_profit, Some(ExitRule::FixedPct(dec!(10.0))));
+        assert_eq!(sltp.trailing, Some(TrailingRule::pct(dec!(5.0), dec!(2.0))));
+
+        let rebalancing = StopLossTakeProfit::from_exit_config(&ExitConfig::for_rebalancing());
+        assert_eq!(rebalancing.stop_loss, None);
+        assert_eq!(rebalancing.take_profit, None);
+        assert_eq!(rebalancing.trailing, None);
+    }
+}
@@ -8,6 +8,8 @@
 //!   - 시가 + (전일 레인지 × K) 돌파 시 진입
 //!   - K 계수로 신호 강도 조절
 //!   - 장 마감 전 청산 옵션
+//!   - 장중 세션 필터: 진입 허용 시간대, 강제 청산 시각, 오버나이트 금지
+//!     (분봉/시간봉에서만 동작, 일봉 이상이면 경고 후 비활성화)
 //!
 //! - **Crossover**: 이동평균 크로스오버 전략
 //!   - 단기 MA가 장기 MA 상향 돌파 시 매수
@@ -52,13 +54,13 @@
 use std::{collections::VecDeque, sync::Arc};
 
 use async_trait::async_trait;
-use chrono::{DateTime, Timelike, Utc};
+use chrono::{DateTime, Duration, NaiveDateTime, NaiveTime, Timelike, Utc};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use tokio::sync::RwLock;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use trader_core::{
     domain::{RouteState, StrategyContext},
     MarketData, MarketDataType, Order, Position, Side, Signal, Timeframe,
@@ -113,6 +115,30 @@ pub struct BreakoutConfig {
     /// 기간 종료 시 청산 (기본값: true)
     #[serde(default = "default_exit_at_period_close")]
     pub exit_at_period_close: bool,
+
+    /// 장중 세션 필터 사용 (기본값: false, 분봉/시간봉에서만 적용)
+    #[serde(default)]
+    pub intraday_session: bool,
+
+    /// 세션 시각 기준 UTC 오프셋 (시간, 기본값: 9 = KST)
+    #[serde(default = "default_session_utc_offset_hours")]
+    pub session_utc_offset_hours: i32,
+
+    /// 진입 허용 시작 시각 (HH:MM, 기본값: 09:00)
+    #[serde(default = "default_entry_start_time")]
+    pub entry_start_time: String,
+
+    /// 진입 허용 종료 시각 (HH:MM, 기본값: 10:30)
+    #[serde(default = "default_entry_end_time")]
+    pub entry_end_time: String,
+
+    /// 강제 청산 시각 (HH:MM, 기본값: 15:20 = 장 마감 10분 전)
+    #[serde(default = "default_force_exit_time")]
+    pub force_exit_time: String,
+
+    /// 오버나이트 금지 (당일 진입 포지션은 강제 청산 시각에 청산, 기본값: true)
+    #[serde(default = "default_no_overnight")]
+    pub no_overnight: bool,
 }
 
 fn default_k_factor() -> Decimal {
@@ -136,6 +162,21 @@ fn default_trade_both_directions() -> bool {
 fn default_exit_at_period_close() -> bool {
     true
 }
+fn default_session_utc_offset_hours() -> i32 {
+    9
+}
+fn default_entry_start_time() -> String {
+    "09:00".to_string()
+}
+fn default_entry_end_time() -> String {
+    "10:30".to_string()
+}
+fn default_force_exit_time() -> String {
+    "15:20".to_string()
+}
+fn default_no_overnight() -> bool {
+    true
+}
 
 impl Default for BreakoutConfig {
     fn default() -> Self {
@@ -148,7 +189,94 @@ impl Default for BreakoutConfig {
             max_range_pct: default_max_range_pct(),
             trade_both_directions: default_trade_both_directions(),
             exit_at_period_close: default_exit_at_period_close(),
+            intraday_session: false,
+            session_utc_offset_hours: default_session_utc_offset_hours(),
+            entry_start_time: default_entry_start_time(),
+            entry_end_time: default_entry_end_time(),
+            force_exit_time: default_force_exit_time(),
+            no_overnight: default_no_overnight(),
+        }
+    }
+}
+
+impl BreakoutConfig {
+    /// 장중 세션 설정 파싱.
+    ///
+    /// 세션 필터 미사용 시 `Ok(None)`, 시각 형식(HH:MM)이 잘못되면 에러를 반환합니다.
+    fn session_window(&self) -> Result<Option<SessionWindow>, String> {
+        if !self.intraday_session {
+            return Ok(None);
         }
+
+        let parse = |name: &str, value: &str| {
+            NaiveTime::parse_from_str(value, "%H:%M")
+                .map_err(|e| format!("{} 형식 오류 (HH:MM): {} ({})", name, value, e))
+        };
+        let entry_start = parse("entry_start_time", &self.entry_start_time)?;
+        let entry_end = parse("entry_end_time", &self.entry_end_time)?;
+        let force_exit = parse("force_exit_time", &self.force_exit_time)?;
+
+        if entry_start >= entry_end {
+            return Err(format!(
+                "진입 시작 시각({})은 종료 시각({})보다 빨라야 합니다",
+                self.entry_start_time, self.entry_end_time
+            ));
+        }
+
+        Ok(Some(SessionWindow {
+            entry_start,
+            entry_end,
+            force_exit,
+            utc_offset: Duration::hours(self.session_utc_offset_hours as i64),
+            no_overnight: self.no_overnight,
+        }))
+    }
+}
+
+/// 장중 세션 구간.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SessionPhase {
+    /// 세션 필터 미적용 (미사용 또는 일봉 폴백)
+    Disabled,
+    /// 진입 허용 시간대
+    EntryWindow,
+    /// 진입 불가, 보유 유지
+    Holding,
+    /// 강제 청산 시각 이후
+    ForceExit,
+}
+
+/// 파싱된 장중 세션 설정.
+#[derive(Debug, Clone, Copy)]
+struct SessionWindow {
+    entry_start: NaiveTime,
+    entry_end: NaiveTime,
+    force_exit: NaiveTime,
+    utc_offset: Duration,
+    no_overnight: bool,
+}
+
+impl SessionWindow {
+    /// 세션 기준 현지 시각.
+    fn local(&self, time: DateTime<Utc>) -> NaiveDateTime {
+        (time + self.utc_offset).naive_utc()
+    }
+
+    /// 현재 시각의 세션 구간.
+    fn phase(&self, time: DateTime<Utc>) -> SessionPhase {
+        let t = self.local(time).time();
+        if t >= self.force_exit {
+            SessionPhase::ForceExit
+        } else if t >= self.entry_start && t < self.entry_end {
+            SessionPhase::EntryWindow
+        } else {
+            SessionPhase::Holding
+        }
+    }
+
+    /// 진입 이후 현지 날짜가 바뀌었는지 여부.
+    fn is_overnight(&self, entry_time: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        self.local(entry_time).date() != self.local(now).date()
     }
 }
 
@@ -385,6 +513,53 @@ pub struct VolatilityBreakoutConfig {
     )]
     pub exit_at_period_close: bool,
 
+    /// 장중 세션 필터 사용 (분봉/시간봉 전용).
+    #[serde(default)]
+    #[schema(
+        label = "장중 세션 필터",
+        field_type = "boolean",
+        default = false,
+        section = "timing"
+    )]
+    pub intraday_session: bool,
+
+    /// 세션 시각 기준 UTC 오프셋 (시간).
+    #[serde(default = "default_session_utc_offset_hours")]
+    #[schema(
+        label = "UTC 오프셋 (시간)",
+        field_type = "integer",
+        min = -12,
+        max = 14,
+        default = 9,
+        section = "timing"
+    )]
+    pub session_utc_offset_hours: i32,
+
+    /// 진입 허용 시작 시각 (HH:MM).
+    #[serde(default = "default_entry_start_time")]
+    #[schema(label = "진입 시작 시각", default = "09:00", section = "timing")]
+    pub entry_start_time: String,
+
+    /// 진입 허용 종료 시각 (HH:MM).
+    #[serde(default = "default_entry_end_time")]
+    #[schema(label = "진입 종료 시각", default = "10:30", section = "timing")]
+    pub entry_end_time: String,
+
+    /// 강제 청산 시각 (HH:MM).
+    #[serde(default = "default_force_exit_time")]
+    #[schema(label = "강제 청산 시각", default = "15:20", section = "timing")]
+    pub force_exit_time: String,
+
+    /// 오버나이트 금지.
+    #[serde(default = "default_no_overnight")]
+    #[schema(
+        label = "오버나이트 금지",
+        field_type = "boolean",
+        default = true,
+        section = "timing"
+    )]
+    pub no_overnight: bool,
+
     /// 청산 설정.
     #[serde(default)]
     #[fragment("risk.exit_config")]
@@ -419,6 +594,12 @@ impl From<VolatilityBreakoutConfig> for DayTradingConfig {
                 lookback_period: cfg.lookback_period,
                 trade_both_directions: cfg.trade_both_directions,
                 exit_at_period_close: cfg.exit_at_period_close,
+                intraday_session: cfg.intraday_session,
+                session_utc_offset_hours: cfg.session_utc_offset_hours,
+                entry_start_time: cfg.entry_start_time,
+                entry_end_time: cfg.entry_end_time,
+                force_exit_time: cfg.force_exit_time,
+                no_overnight: cfg.no_overnight,
                 ..Default::default()
             },
             crossover_config: CrossoverConfig::default(),
@@ -768,6 +949,10 @@ pub struct DayTradingStrategy {
     lower_breakout: Option<Decimal>,
     triggered_this_period: bool,
 
+    // 장중 세션 (Breakout용)
+    session: Option<SessionWindow>,
+    session_fallback_warned: bool,
+
     // 통계
     trades_count: u32,
     wins: u32,
@@ -797,6 +982,8 @@ impl DayTradingStrategy {
             upper_breakout: None,
             lower_breakout: None,
             triggered_this_period: false,
+            session: None,
+            session_fallback_warned: false,
             trades_count: 0,
             wins: 0,
             losses_count: 0,
@@ -806,9 +993,15 @@ impl DayTradingStrategy {
     }
 
     /// 설정으로 생성.
+    ///
+    /// 세션 시각 형식이 잘못된 경우 세션 필터 없이 생성합니다.
     pub fn with_config(config: DayTradingConfig) -> Self {
         let mut strategy = Self::new();
         strategy.ticker = Some(config.ticker.clone());
+        strategy.session = config.breakout_config.session_window().unwrap_or_else(|e| {
+            warn!(error = %e, "장중 세션 설정 오류 - 세션 필터 비활성화");
+            None
+        });
         strategy.config = Some(config);
         strategy
     }
@@ -1063,6 +1256,49 @@ impl DayTradingStrategy {
         }
     }
 
+    /// 현재 캔들의 장중 세션 구간.
+    ///
+    /// 일봉 이상 타임프레임에서는 장중 타이밍이 없으므로 경고 후 비활성화합니다.
+    fn session_phase(&mut self, timeframe: Timeframe, time: DateTime<Utc>) -> SessionPhase {
+        let Some(window) = self.session else {
+            return SessionPhase::Disabled;
+        };
+
+        if timeframe.as_secs() >= Timeframe::D1.as_secs() {
+            if !self.session_fallback_warned {
+                warn!(
+                    timeframe = ?timeframe,
+                    "장중 세션 필터는 분봉/시간봉에서만 동작 - 일봉 데이터로 비활성화"
+                );
+                self.session_fallback_warned = true;
+            }
+            return SessionPhase::Disabled;
+        }
+
+        window.phase(time)
+    }
+
+    /// 세션 규칙에 따른 청산 사유 (강제 청산 시각 도달 또는 오버나이트).
+    fn session_exit_reason(
+        &self,
+        phase: SessionPhase,
+        pos: &PositionState,
+        time: DateTime<Utc>,
+    ) -> Option<&'static str> {
+        let window = self.session.as_ref()?;
+        if phase == SessionPhase::Disabled || !window.no_overnight {
+            return None;
+        }
+
+        if window.is_overnight(pos.entry_time, time) {
+            Some("overnight")
+        } else if phase == SessionPhase::ForceExit {
+            Some("session_close")
+        } else {
+            None
+        }
+    }
+
     /// StrategyContext에서 레인지와 시가 가져오기.
     ///
     /// 전일 캔들의 (high - low)를 레인지로, 오늘 캔들의 open을 시가로 반환합니다.
@@ -1116,7 +1352,11 @@ impl DayTradingStrategy {
     // ====== 신호 생성 ======
 
     /// 돌파 신호 생성.
-    fn generate_breakout_signals(&mut self, candle: &CandleData) -> Vec<Signal> {
+    fn generate_breakout_signals(
+        &mut self,
+        candle: &CandleData,
+        phase: SessionPhase,
+    ) -> Vec<Signal> {
         let Some(config) = self.config.as_ref() else {
             return Vec::new();
         };
@@ -1127,6 +1367,25 @@ impl DayTradingStrategy {
 
         // 기존 포지션 처리 (borrow 충돌 방지를 위해 복사)
         if let Some(pos) = self.position.clone() {
+            // 세션 청산 (장 마감 전 강제 청산, 오버나이트 금지)
+            if let Some(reason) = self.session_exit_reason(phase, &pos, candle.timestamp) {
+                let (exit_side, pnl) = match pos.side {
+                    Side::Buy => (Side::Sell, candle.close - pos.entry_price),
+                    Side::Sell => (Side::Buy, pos.entry_price - candle.close),
+                };
+                signals.push(
+                    Signal::exit("day_trading", ticker.clone(), exit_side)
+                        .with_strength(1.0)
+                        .with_prices(Some(candle.close), None, None)
+                        .with_metadata("variant", json!("breakout"))
+                        .with_metadata("exit_reason", json!(reason)),
+                );
+                info!(price = %candle.close, reason = reason, "세션 청산 신호");
+                self.record_trade(pnl);
+                self.position = None;
+                return signals;
+            }
+
             let exit_result = self.check_exit_conditions(candle, &pos);
             if let Some((signal, pnl)) = exit_result {
                 self.record_trade(pnl);
@@ -1142,6 +1401,11 @@ impl DayTradingStrategy {
             return signals;
         }
 
+        // 진입 허용 시간대가 아님
+        if matches!(phase, SessionPhase::Holding | SessionPhase::ForceExit) {
+            return signals;
+        }
+
         // 진입 가능 여부 확인
         if !self.can_enter() {
             return signals;
@@ -1650,6 +1914,8 @@ impl Strategy for DayTradingStrategy {
             "DayTrading 전략 초기화"
         );
 
+        self.session = dt_config.breakout_config.session_window()?;
+        self.session_fallback_warned = false;
         self.ticker = Some(dt_config.ticker.clone());
         self.config = Some(dt_config);
         self.initialized = true;
@@ -1677,15 +1943,18 @@ impl Strategy for DayTradingStrategy {
         }
 
         // 캔들 데이터 추출
-        let candle = match &data.data {
-            MarketDataType::Kline(kline) => CandleData {
-                open: kline.open,
-                high: kline.high,
-                low: kline.low,
-                close: kline.close,
-                volume: kline.volume,
-                timestamp: kline.open_time,
-            },
+        let (candle, timeframe) = match &data.data {
+            MarketDataType::Kline(kline) => (
+                CandleData {
+                    open: kline.open,
+                    high: kline.high,
+                    low: kline.low,
+                    close: kline.close,
+                    volume: kline.volume,
+                    timestamp: kline.open_time,
+                },
+                kline.timeframe,
+            ),
             _ => return Ok(vec![]),
         };
 
@@ -1705,7 +1974,10 @@ impl Strategy for DayTradingStrategy {
 
         // 변형에 따른 신호 생성
         let signals = match variant {
-            DayTradingVariant::Breakout => self.generate_breakout_signals(&candle),
+            DayTradingVariant::Breakout => {
+                let phase = self.session_phase(timeframe, candle.timestamp);
+                self.generate_breakout_signals(&candle, phase)
+            }
             DayTradingVariant::Crossover => self.generate_crossover_signals(&candle),
             DayTradingVariant::VolumeSurge => self.generate_volume_surge_signals(&candle),
         };
@@ -1766,6 +2038,8 @@ impl Strategy for DayTradingStrategy {
             "long_sma": self.prev_long_sma.map(|v| v.to_string()),
             "upper_breakout": self.upper_breakout.map(|v| v.to_string()),
             "lower_breakout": self.lower_breakout.map(|v| v.to_string()),
+            "intraday_session": self.session.is_some(),
+            "session_fallback": self.session_fallback_warned,
         })
     }
 
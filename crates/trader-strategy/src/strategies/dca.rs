@@ -6,7 +6,7 @@
 //!
 //! - `Grid`: 가격 대역 분할 자동 거래 (그리드 트레이딩)
 //! - `MagicSplit`: 단계적 분할 매수
-//! - `InfinityBot`: 피라미드 물타기 + 익절
+//! - `InfinityBot`: 피라미드 물타기 + 익절, 사이클 단위 수익 재투자
 //!
 //! # 공통 특징
 //!
@@ -41,7 +41,7 @@ use trader_core::{
 use trader_strategy_macro::StrategyConfig;
 
 use crate::{
-    strategies::common::{adjust_strength_by_score, ExitConfig},
+    strategies::common::{adjust_strength_by_score, calculate_atr, ExitConfig},
     Strategy,
 };
 
@@ -129,10 +129,22 @@ pub struct GridTradingConfig {
     pub levels: usize,
 
     /// ATR 기반 동적 간격 사용
+    /// 그리드 생성 시점의 ATR × 배수를 간격으로 사용 (데이터 부족 시 고정 간격)
     #[serde(default)]
     #[schema(label = "ATR 동적 간격", field_type = "boolean", default = false)]
     pub use_atr: bool,
 
+    /// ATR 간격 배수
+    #[serde(default = "default_atr_spacing_multiplier")]
+    #[schema(
+        label = "ATR 간격 배수",
+        field_type = "number",
+        min = 0.1,
+        max = 5,
+        default = 1
+    )]
+    pub atr_spacing_multiplier: Decimal,
+
     /// ATR 기간
     #[serde(default = "default_atr_period")]
     #[schema(
@@ -172,6 +184,31 @@ pub struct GridTradingConfig {
     )]
     pub reset_threshold_pct: Decimal,
 
+    /// 그리드 재배치(recenter) 모드
+    /// 가격이 그리드 상·하단을 벗어나면 임계값 없이 중심을 현재가로 이동하여 그리드를 재생성
+    /// (미체결 매수 레벨은 취소, 보유 레벨은 진입가 유지)
+    #[serde(default)]
+    #[schema(
+        label = "그리드 재배치 모드",
+        field_type = "boolean",
+        default = false,
+        section = "indicator"
+    )]
+    pub recenter: bool,
+
+    /// 재배치 쿨다운 (캔들 수)
+    /// 마지막 재배치 이후 이 캔들 수가 지나야 다시 재배치
+    #[serde(default = "default_recenter_cooldown")]
+    #[schema(
+        label = "재배치 쿨다운 (캔들)",
+        field_type = "integer",
+        min = 0,
+        max = 100,
+        default = 10,
+        section = "indicator"
+    )]
+    pub recenter_cooldown_candles: usize,
+
     /// 워밍업 캔들 수 (초기 관찰 기간)
     /// 이 기간 동안은 그리드를 설정만 하고 실제 거래는 하지 않음
     #[serde(default = "default_warmup_candles")]
@@ -296,6 +333,30 @@ pub struct InfinityBotConfig {
     )]
     pub take_profit_pct: Decimal,
 
+    /// 익절 시 청산 비율 (%)
+    /// 100이면 전량 청산, 미만이면 진입가가 낮은 라운드부터 해당 비율만큼 청산
+    #[serde(default = "default_exit_ratio")]
+    #[schema(
+        label = "익절 시 청산 비율 (%)",
+        min = 10,
+        max = 100,
+        default = 100,
+        section = "sizing"
+    )]
+    pub exit_ratio_pct: Decimal,
+
+    /// 수익 재투자 비율 (%)
+    /// 사이클 실현 수익 중 다음 사이클 투자금에 더할 비율 (나머지는 현금화)
+    #[serde(default = "default_reinvest")]
+    #[schema(
+        label = "수익 재투자 비율 (%)",
+        min = 0,
+        max = 100,
+        default = 100,
+        section = "sizing"
+    )]
+    pub reinvest_pct: Decimal,
+
     /// 이동평균 기간
     #[serde(default = "default_ma_period")]
     #[schema(
@@ -356,6 +417,14 @@ fn default_reset_threshold() -> Decimal {
     dec!(10) // 10% - 가격이 그리드 기준에서 10% 이상 벗어나면 재설정
 }
 
+fn default_atr_spacing_multiplier() -> Decimal {
+    dec!(1)
+}
+
+fn default_recenter_cooldown() -> usize {
+    10 // 10캔들에 한 번 이하로 재배치
+}
+
 fn default_warmup_candles() -> usize {
     5 // 5캔들 동안 시장 관찰 후 거래 시작
 }
@@ -414,6 +483,14 @@ fn default_take_profit() -> Decimal {
     dec!(3)
 }
 
+fn default_exit_ratio() -> Decimal {
+    dec!(100)
+}
+
+fn default_reinvest() -> Decimal {
+    dec!(100)
+}
+
 fn default_ma_period() -> usize {
     20
 }
@@ -441,8 +518,11 @@ pub struct DcaConfig {
     pub grid_levels: usize,
     pub use_atr: bool,
     pub atr_period: usize,
+    pub atr_spacing_multiplier: Decimal,
     pub reset_threshold_pct: Decimal, // 그리드 재설정 임계값
-    pub warmup_candles: usize,        // 워밍업 캔들 수
+    pub recenter: bool,               // 그리드 재배치 모드
+    pub recenter_cooldown_candles: usize,
+    pub warmup_candles: usize, // 워밍업 캔들 수
 
     // MagicSplit 전용
     pub split_levels: Vec<SplitLevel>,
@@ -453,6 +533,8 @@ pub struct DcaConfig {
     pub round_pct: Decimal,
     pub dip_trigger_pct: Decimal,
     pub take_profit_pct: Decimal,
+    pub exit_ratio_pct: Decimal,
+    pub reinvest_pct: Decimal,
     pub ma_period: usize,
 }
 
@@ -469,7 +551,10 @@ impl From<GridTradingConfig> for DcaConfig {
             grid_levels: cfg.levels,
             use_atr: cfg.use_atr,
             atr_period: cfg.atr_period,
+            atr_spacing_multiplier: cfg.atr_spacing_multiplier,
             reset_threshold_pct: cfg.reset_threshold_pct,
+            recenter: cfg.recenter,
+            recenter_cooldown_candles: cfg.recenter_cooldown_candles,
             warmup_candles: cfg.warmup_candles, // 워밍업 캔들 수
             split_levels: vec![],
             total_amount: Decimal::ZERO,
@@ -477,6 +562,8 @@ impl From<GridTradingConfig> for DcaConfig {
             round_pct: Decimal::ZERO,
             dip_trigger_pct: Decimal::ZERO,
             take_profit_pct: Decimal::ZERO,
+            exit_ratio_pct: Decimal::ZERO,
+            reinvest_pct: Decimal::ZERO,
             ma_period: 0,
         }
     }
@@ -496,14 +583,19 @@ impl From<MagicSplitConfig> for DcaConfig {
             grid_levels: 0,
             use_atr: false,
             atr_period: 0,
+            atr_spacing_multiplier: Decimal::ZERO,
             reset_threshold_pct: Decimal::ZERO, // MagicSplit은 그리드 재설정 미사용
-            warmup_candles: 0,                  // MagicSplit은 워밍업 미사용
+            recenter: false,
+            recenter_cooldown_candles: 0,
+            warmup_candles: 0, // MagicSplit은 워밍업 미사용
             split_levels: cfg.levels,
             total_amount: Decimal::ZERO,
             max_rounds: 0,
             round_pct: Decimal::ZERO,
             dip_trigger_pct: Decimal::ZERO,
             take_profit_pct: Decimal::ZERO,
+            exit_ratio_pct: Decimal::ZERO,
+            reinvest_pct: Decimal::ZERO,
             ma_period: 0,
         }
     }
@@ -522,14 +614,19 @@ impl From<InfinityBotConfig> for DcaConfig {
             grid_levels: 0,
             use_atr: false,
             atr_period: 0,
+            atr_spacing_multiplier: Decimal::ZERO,
             reset_threshold_pct: Decimal::ZERO, // InfinityBot은 그리드 재설정 미사용
-            warmup_candles: 0,                  // InfinityBot은 워밍업 미사용 (즉시 진입)
+            recenter: false,
+            recenter_cooldown_candles: 0,
+            warmup_candles: 0, // InfinityBot은 워밍업 미사용 (즉시 진입)
             split_levels: vec![],
             total_amount: cfg.total_amount,
             max_rounds: cfg.max_rounds,
             round_pct: cfg.round_pct,
             dip_trigger_pct: cfg.dip_trigger_pct,
             take_profit_pct: cfg.take_profit_pct,
+            exit_ratio_pct: cfg.exit_ratio_pct,
+            reinvest_pct: cfg.reinvest_pct,
             ma_period: cfg.ma_period,
         }
     }
@@ -577,7 +674,6 @@ enum SplitAction {
 struct RoundInfo {
     round: usize,
     entry_price: Decimal,
-    #[allow(dead_code)]
     quantity: Decimal,
     #[allow(dead_code)]
     timestamp: i64,
@@ -608,6 +704,91 @@ impl InfinityBotState {
         }
         Some((current_price - avg) / avg * dec!(100))
     }
+
+    /// 남은 라운드 기준으로 수량/투자금/평균 단가 재계산 (부분 청산 후)
+    fn recalculate(&mut self) {
+        self.total_quantity = self.rounds.iter().map(|r| r.quantity).sum();
+        self.invested_amount = self.rounds.iter().map(|r| r.entry_price * r.quantity).sum();
+        self.avg_price = self.calculate_avg_price();
+    }
+}
+
+/// 인피니티봇 사이클 추적
+///
+/// 익절(또는 손절)로 포지션을 정리할 때마다 한 사이클이 끝나고,
+/// 실현손익을 반영한 투자금으로 다음 사이클을 시작합니다.
+/// 실현손익은 신호 가격 기준 추정치입니다 (수수료/슬리피지 미반영).
+#[derive(Debug, Clone, Default)]
+struct InfinityCycleState {
+    /// 진행 중인 사이클 번호 (1부터 시작)
+    current_cycle: usize,
+    /// 현재 사이클 투자금 (복리 반영)
+    capital: Decimal,
+    /// 완료된 사이클 수
+    completed_cycles: usize,
+    /// 수익으로 끝난 사이클 수
+    profitable_cycles: usize,
+    /// 누적 실현손익
+    cumulative_realized_pnl: Decimal,
+    /// 누적 사이클 수익률 합 (평균 계산용, %)
+    cumulative_return_pct: Decimal,
+    /// 재투자하지 않고 현금화한 수익
+    withdrawn_profit: Decimal,
+    /// 분할매수 자금 소진 여부 (회복 대기 중)
+    funds_exhausted: bool,
+}
+
+impl InfinityCycleState {
+    fn new(initial_capital: Decimal) -> Self {
+        Self {
+            current_cycle: 1,
+            capital: initial_capital,
+            ..Self::default()
+        }
+    }
+
+    /// 사이클 종료 처리.
+    ///
+    /// 수익은 재투자 비율만큼 투자금에 더하고 나머지는 현금화하며,
+    /// 손실은 전액 투자금에서 차감합니다.
+    fn complete_cycle(
+        &mut self,
+        realized_pnl: Decimal,
+        cost_basis: Decimal,
+        reinvest_pct: Decimal,
+    ) {
+        self.completed_cycles += 1;
+        self.cumulative_realized_pnl += realized_pnl;
+        if !cost_basis.is_zero() {
+            self.cumulative_return_pct += realized_pnl / cost_basis * dec!(100);
+        }
+
+        if realized_pnl > Decimal::ZERO {
+            self.profitable_cycles += 1;
+            let reinvested = realized_pnl * reinvest_pct / dec!(100);
+            self.capital += reinvested;
+            self.withdrawn_profit += realized_pnl - reinvested;
+        } else {
+            self.capital = (self.capital + realized_pnl).max(Decimal::ZERO);
+        }
+
+        self.current_cycle += 1;
+        self.funds_exhausted = false;
+    }
+
+    fn average_cycle_pnl(&self) -> Decimal {
+        if self.completed_cycles == 0 {
+            return Decimal::ZERO;
+        }
+        self.cumulative_realized_pnl / Decimal::from(self.completed_cycles)
+    }
+
+    fn average_cycle_return_pct(&self) -> Decimal {
+        if self.completed_cycles == 0 {
+            return Decimal::ZERO;
+        }
+        self.cumulative_return_pct / Decimal::from(self.completed_cycles)
+    }
 }
 
 // ================================================================================================
@@ -625,8 +806,11 @@ pub struct DcaStrategy {
     // Grid 상태
     grid_levels: Vec<GridLevel>,
     grid_base_price: Decimal,
+    grid_spacing: Decimal, // 현재 그리드 간격 (가격 단위)
     grid_group_id: Option<String>,
     candles_processed: usize, // 워밍업용 캔들 카운터
+    grid_recenter_count: usize,
+    last_recenter_candle: Option<usize>,
 
     // MagicSplit 상태
     split_states: Vec<SplitLevelState>,
@@ -635,6 +819,7 @@ pub struct DcaStrategy {
 
     // InfinityBot 상태
     infinity_state: InfinityBotState,
+    infinity_cycle: InfinityCycleState,
     infinity_group_id: Option<String>,
     last_entry_price: Option<Decimal>,
 }
@@ -648,12 +833,16 @@ impl DcaStrategy {
             initialized: false,
             grid_levels: Vec::new(),
             grid_base_price: Decimal::ZERO,
+            grid_spacing: Decimal::ZERO,
             grid_group_id: None,
             candles_processed: 0, // 워밍업용 캔들 카운터 초기화
+            grid_recenter_count: 0,
+            last_recenter_candle: None,
             split_states: Vec::new(),
             split_entry_date: None,
             split_group_id: None,
             infinity_state: InfinityBotState::default(),
+            infinity_cycle: InfinityCycleState::default(),
             infinity_group_id: None,
             last_entry_price: None,
         }
@@ -793,7 +982,9 @@ impl DcaStrategy {
             chrono::Utc::now().timestamp_millis()
         ));
 
-        let spacing = base_price * config.grid_spacing_pct / dec!(100);
+        let fixed_spacing = base_price * config.grid_spacing_pct / dec!(100);
+        let spacing = self.volatility_spacing(config).unwrap_or(fixed_spacing);
+        self.grid_spacing = spacing;
 
         for i in 1..=config.grid_levels {
             let buy_price = base_price - spacing * Decimal::from(i as i32);
@@ -808,12 +999,71 @@ impl DcaStrategy {
 
         info!(
             base_price = %base_price,
-            spacing_pct = %config.grid_spacing_pct,
+            spacing = %spacing,
             levels = config.grid_levels,
             "그리드 초기화"
         );
     }
 
+    /// ATR 기반 그리드 간격 (use_atr 비활성 또는 데이터 부족 시 None)
+    fn volatility_spacing(&self, config: &DcaConfig) -> Option<Decimal> {
+        if !config.use_atr {
+            return None;
+        }
+
+        let klines = self.get_klines()?;
+        let highs: Vec<Decimal> = klines.iter().map(|k| k.high).collect();
+        let lows: Vec<Decimal> = klines.iter().map(|k| k.low).collect();
+        let closes: Vec<Decimal> = klines.iter().map(|k| k.close).collect();
+        let atr = calculate_atr(&highs, &lows, &closes, config.atr_period)?;
+
+        let spacing = atr * config.atr_spacing_multiplier;
+        (spacing > Decimal::ZERO).then_some(spacing)
+    }
+
+    /// 그리드 하단 (가장 낮은 매수 레벨)
+    fn grid_lower_bound(&self, levels: usize) -> Decimal {
+        self.grid_base_price - self.grid_spacing * Decimal::from(levels as i32)
+    }
+
+    /// 재배치 쿨다운 경과 여부
+    fn recenter_cooldown_elapsed(&self, cooldown: usize) -> bool {
+        self.last_recenter_candle.map_or(true, |last| {
+            self.candles_processed.saturating_sub(last) >= cooldown
+        })
+    }
+
+    /// 그리드 중심을 현재가로 이동 (recenter 모드).
+    ///
+    /// 매수 대기 레벨(미체결 주문)은 취소하고 새 중심 기준으로 재설정합니다.
+    /// 보유 중인 레벨은 같은 인덱스(position_id)와 진입가·익절가를 그대로 유지하므로
+    /// 보유 포지션의 평균단가는 변하지 않습니다.
+    ///
+    /// 반환값: 취소된 매수 대기 레벨 수
+    fn recenter_grid(&mut self, price: Decimal) -> usize {
+        let held: Vec<(usize, GridLevel)> = self
+            .grid_levels
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.state == GridLevelState::WaitingSell)
+            .map(|(i, l)| (i, l.clone()))
+            .collect();
+        let cancelled = self.grid_levels.len() - held.len();
+
+        self.initialize_grid(price);
+
+        for (i, level) in held {
+            if let Some(slot) = self.grid_levels.get_mut(i) {
+                *slot = level;
+            }
+        }
+
+        self.grid_recenter_count += 1;
+        self.last_recenter_candle = Some(self.candles_processed);
+
+        cancelled
+    }
+
     fn generate_grid_signals(&mut self, price: Decimal) -> Vec<Signal> {
         let config = match &self.config {
             Some(c) => c.clone(),
@@ -837,12 +1087,27 @@ impl DcaStrategy {
                 );
                 return vec![];
             }
+        } else if config.recenter {
+            // 그리드 재배치: 상·하단 이탈 즉시 (쿨다운 내에서는 보류)
+            let grid_upper = self.grid_base_price;
+            let grid_lower = self.grid_lower_bound(config.grid_levels);
+            let breached = price > grid_upper || price < grid_lower;
+
+            if breached && self.recenter_cooldown_elapsed(config.recenter_cooldown_candles) {
+                let cancelled = self.recenter_grid(price);
+                info!(
+                    current_price = %price,
+                    grid_upper = %grid_upper,
+                    grid_lower = %grid_lower,
+                    cancelled_levels = cancelled,
+                    recenter_count = self.grid_recenter_count,
+                    "그리드 재배치 - 미체결 레벨 취소, 보유 레벨 유지"
+                );
+            }
         } else {
             // 동적 그리드 재설정 체크
-            let spacing = self.grid_base_price * config.grid_spacing_pct / dec!(100);
             let grid_upper = self.grid_base_price;
-            let grid_lower =
-                self.grid_base_price - spacing * Decimal::from(config.grid_levels as i32);
+            let grid_lower = self.grid_lower_bound(config.grid_levels);
             let threshold = config.reset_threshold_pct / dec!(100);
 
             // 가격이 그리드 상단을 threshold% 이상 벗어났거나
@@ -900,9 +1165,8 @@ impl DcaStrategy {
                         // 해당 레벨을 WaitingSell로 변경 (진입가격은 원래 값 유지)
                         self.grid_levels[idx].state = GridLevelState::WaitingSell;
                         // 익절가는 원래 진입가 기준으로 계산
-                        let spacing = self.grid_base_price * config.grid_spacing_pct / dec!(100);
                         self.grid_levels[idx].buy_price = entry_price;
-                        self.grid_levels[idx].sell_price = entry_price + spacing;
+                        self.grid_levels[idx].sell_price = entry_price + self.grid_spacing;
                     }
                 }
 
@@ -1233,7 +1497,7 @@ impl DcaStrategy {
             // 박스장: 모멘텀 긍정이면 진입
             Some(MarketRegime::Sideways) => self.has_positive_momentum(),
             // 하락장: InfinityBot은 DCA 전략이므로 진입 허용
-            // 단, 라운드 수와 stop_loss_pct가 리스크 관리
+            // 단, 라운드 수/자금 소진 가드와 stop_loss_pct가 리스크 관리
             Some(MarketRegime::Downtrend) => true,
             // 레짐 없음: MA 기준
             None => self.is_above_ma(price),
@@ -1246,7 +1510,7 @@ impl DcaStrategy {
             None => return false,
         };
 
-        if self.infinity_state.current_round >= config.max_rounds {
+        if self.infinity_state.rounds.len() >= config.max_rounds {
             return false;
         }
 
@@ -1274,13 +1538,109 @@ impl DcaStrategy {
         }
     }
 
+    /// 라운드당 투자 금액 (현재 사이클 투자금 기준, 복리 반영)
     fn round_amount(&self) -> Decimal {
         let config = match &self.config {
             Some(c) => c,
             None => return Decimal::ZERO,
         };
 
-        config.total_amount * config.round_pct / dec!(100)
+        self.infinity_cycle.capital * config.round_pct / dec!(100)
+    }
+
+    /// 다음 라운드를 매수할 자금이 남아 있는지 확인
+    fn has_round_funds(&self) -> bool {
+        self.infinity_state.invested_amount + self.round_amount() <= self.infinity_cycle.capital
+    }
+
+    /// 라운드 청산 신호 생성 및 사이클 정산.
+    ///
+    /// `exit_ratio_pct`가 100 미만이면 진입가가 낮은(수익이 큰) 라운드부터 해당 비율만큼만
+    /// 청산하고, 남은 라운드는 다음 사이클로 이월합니다.
+    fn close_infinity_rounds(
+        &mut self,
+        config: &DcaConfig,
+        price: Decimal,
+        action: &str,
+        return_pct: Decimal,
+        exit_ratio_pct: Decimal,
+    ) -> Vec<Signal> {
+        let total_rounds = self.infinity_state.current_round;
+        let target_quantity =
+            self.infinity_state.total_quantity * exit_ratio_pct.min(dec!(100)) / dec!(100);
+
+        let mut candidates = self.infinity_state.rounds.clone();
+        candidates.sort_by_key(|r| r.entry_price);
+
+        let mut closing = Vec::new();
+        let mut remaining = Vec::new();
+        let mut closed_quantity = Decimal::ZERO;
+        for round_info in candidates {
+            if closing.is_empty() || closed_quantity < target_quantity {
+                closed_quantity += round_info.quantity;
+                closing.push(round_info);
+            } else {
+                remaining.push(round_info);
+            }
+        }
+        remaining.sort_by_key(|r| r.round);
+
+        let cycle = self.infinity_cycle.current_cycle;
+        let mut realized_pnl = Decimal::ZERO;
+        let mut cost_basis = Decimal::ZERO;
+        let mut signals = Vec::with_capacity(closing.len());
+
+        // 각 라운드별로 개별 청산 신호
+        for round_info in &closing {
+            let round_pnl = (price - round_info.entry_price) * round_info.quantity;
+            realized_pnl += round_pnl;
+            cost_basis += round_info.entry_price * round_info.quantity;
+
+            let position_id = format!("{}_infinity_R{}", config.ticker, round_info.round);
+            let mut signal =
+                Signal::new("dca", config.ticker.clone(), Side::Sell, SignalType::Exit)
+                    .with_position_id(position_id)
+                    .with_strength(1.0)
+                    .with_metadata("action", json!(action))
+                    .with_metadata("return_pct", json!(return_pct.to_string()))
+                    .with_metadata("round", json!(round_info.round))
+                    .with_metadata("rounds", json!(total_rounds))
+                    .with_metadata("cycle", json!(cycle))
+                    .with_metadata("realized_pnl", json!(round_pnl.to_string()));
+
+            if let Some(ref group_id) = self.infinity_group_id {
+                signal = signal.with_group_id(group_id.clone());
+            }
+            signals.push(signal);
+        }
+
+        self.infinity_cycle
+            .complete_cycle(realized_pnl, cost_basis, config.reinvest_pct);
+
+        info!(
+            ticker = %config.ticker,
+            cycle,
+            action,
+            closed_rounds = closing.len(),
+            carried_rounds = remaining.len(),
+            realized_pnl = %realized_pnl,
+            next_capital = %self.infinity_cycle.capital,
+            "InfinityBot 사이클 종료"
+        );
+
+        if remaining.is_empty() {
+            // 상태 초기화
+            self.infinity_state = InfinityBotState::default();
+            self.last_entry_price = None;
+            self.infinity_group_id = None;
+        } else {
+            // 남은 라운드는 다음 사이클로 이월, 물타기 기준가는 청산가로 갱신
+            self.infinity_state.rounds = remaining;
+            self.infinity_state.recalculate();
+            self.last_entry_price = Some(price);
+        }
+
+        signals
     }
 
     fn generate_infinity_signals(&mut self, price: Decimal, timestamp: i64) -> Vec<Signal> {
@@ -1311,34 +1671,17 @@ impl DcaStrategy {
                 ticker = %config.ticker,
                 return_pct = %return_pct,
                 rounds = self.infinity_state.current_round,
+                cycle = self.infinity_cycle.current_cycle,
                 "익절 조건 충족"
             );
 
-            // 모든 라운드 청산 - 각 라운드별로 개별 청산 신호
-            let total_rounds = self.infinity_state.current_round;
-            for round_info in &self.infinity_state.rounds {
-                let position_id = format!("{}_infinity_R{}", config.ticker, round_info.round);
-                let mut signal =
-                    Signal::new("dca", config.ticker.clone(), Side::Sell, SignalType::Exit)
-                        .with_position_id(position_id)
-                        .with_strength(1.0)
-                        .with_metadata("action", json!("take_profit"))
-                        .with_metadata("return_pct", json!(return_pct.to_string()))
-                        .with_metadata("round", json!(round_info.round))
-                        .with_metadata("rounds", json!(total_rounds));
-
-                if let Some(ref group_id) = self.infinity_group_id {
-                    signal = signal.with_group_id(group_id.clone());
-                }
-                signals.push(signal);
-            }
-
-            // 상태 초기화
-            self.infinity_state = InfinityBotState::default();
-            self.last_entry_price = None;
-            self.infinity_group_id = None;
-
-            return signals;
+            return self.close_infinity_rounds(
+                &config,
+                price,
+                "take_profit",
+                return_pct,
+                config.exit_ratio_pct,
+            );
         }
 
         // 1-2. 손절 조건 확인 (exit_config 활용)
@@ -1351,44 +1694,38 @@ impl DcaStrategy {
                             return_pct = %return_pct,
                             stop_loss_pct = %sl_pct,
                             rounds = self.infinity_state.current_round,
+                            cycle = self.infinity_cycle.current_cycle,
                             "InfinityBot 손절 조건 충족"
                         );
 
-                        // 모든 라운드 손절 - 각 라운드별로 개별 청산 신호
-                        let total_rounds = self.infinity_state.current_round;
-                        for round_info in &self.infinity_state.rounds {
-                            let position_id =
-                                format!("{}_infinity_R{}", config.ticker, round_info.round);
-                            let mut signal = Signal::new(
-                                "dca",
-                                config.ticker.clone(),
-                                Side::Sell,
-                                SignalType::Exit,
-                            )
-                            .with_position_id(position_id)
-                            .with_strength(1.0)
-                            .with_metadata("action", json!("stop_loss"))
-                            .with_metadata("return_pct", json!(return_pct.to_string()))
-                            .with_metadata("round", json!(round_info.round))
-                            .with_metadata("rounds", json!(total_rounds));
-
-                            if let Some(ref group_id) = self.infinity_group_id {
-                                signal = signal.with_group_id(group_id.clone());
-                            }
-                            signals.push(signal);
-                        }
-
-                        // 상태 초기화
-                        self.infinity_state = InfinityBotState::default();
-                        self.last_entry_price = None;
-                        self.infinity_group_id = None;
-
-                        return signals;
+                        // 손절은 항상 전량 청산
+                        return self.close_infinity_rounds(
+                            &config,
+                            price,
+                            "stop_loss",
+                            return_pct,
+                            dec!(100),
+                        );
                     }
                 }
             }
         }
 
+        // 1-3. 분할매수 자금 소진 가드 - 사이클이 끝날 때까지 추가 진입 중단
+        if !self.infinity_state.rounds.is_empty() && !self.has_round_funds() {
+            if !self.infinity_cycle.funds_exhausted {
+                info!(
+                    ticker = %config.ticker,
+                    cycle = self.infinity_cycle.current_cycle,
+                    invested = %self.infinity_state.invested_amount,
+                    capital = %self.infinity_cycle.capital,
+                    "분할매수 자금 소진 - 추가 진입 중단, 회복 대기"
+                );
+                self.infinity_cycle.funds_exhausted = true;
+            }
+            return signals;
+        }
+
         // 2. 진입/물타기 조건 (단일 티커 전략 - GlobalScore 필터 사용 안함)
         if self.can_add_position(price) && self.can_enter_infinity(price) {
             let round = self.infinity_state.current_round + 1;
@@ -1522,6 +1859,7 @@ impl Strategy for DcaStrategy {
             }
             DcaVariant::InfinityBot => {
                 self.infinity_state = InfinityBotState::default();
+                self.infinity_cycle = InfinityCycleState::new(dca_config.total_amount);
             }
         }
 
@@ -1630,7 +1968,9 @@ impl Strategy for DcaStrategy {
                 state["state"] = json!({
                     "grid": {
                         "base_price": self.grid_base_price.to_string(),
+                        "spacing": self.grid_spacing.to_string(),
                         "group_id": self.grid_group_id.clone(),
+                        "recenter_count": self.grid_recenter_count,
                         "levels": grid_levels_json,
                     }
                 });
@@ -1660,6 +2000,8 @@ impl Strategy for DcaStrategy {
                     "round_pct": config.round_pct.to_string(),
                     "dip_trigger_pct": config.dip_trigger_pct.to_string(),
                     "take_profit_pct": config.take_profit_pct.to_string(),
+                    "exit_ratio_pct": config.exit_ratio_pct.to_string(),
+                    "reinvest_pct": config.reinvest_pct.to_string(),
                     "ma_period": config.ma_period,
                 });
                 state["state"] = json!({
@@ -1673,6 +2015,17 @@ impl Strategy for DcaStrategy {
                         Some(p) if !p.is_zero() => json!(p.to_string()),
                         _ => Value::Null,
                     },
+                    "cycle": {
+                        "current_cycle": self.infinity_cycle.current_cycle,
+                        "completed_cycles": self.infinity_cycle.completed_cycles,
+                        "profitable_cycles": self.infinity_cycle.profitable_cycles,
+                        "capital": self.infinity_cycle.capital.to_string(),
+                        "cumulative_realized_pnl": self.infinity_cycle.cumulative_realized_pnl.to_string(),
+                        "average_cycle_pnl": self.infinity_cycle.average_cycle_pnl().to_string(),
+                        "average_cycle_return_pct": self.infinity_cycle.average_cycle_return_pct().to_string(),
+                        "withdrawn_profit": self.infinity_cycle.withdrawn_profit.to_string(),
+                        "funds_exhausted": self.infinity_cycle.funds_exhausted,
+                    },
                 });
             }
         }
@@ -1755,9 +2108,12 @@ mod tests {
             levels: 5,
             use_atr: false,
             atr_period: 14,
+            atr_spacing_multiplier: dec!(1),
             exit_config: ExitConfig::for_grid_trading(),
             max_positions: 10,
             reset_threshold_pct: dec!(10),
+            recenter: false,
+            recenter_cooldown_candles: 10,
             warmup_candles: 5,
         };
         let dca_config: DcaConfig = config.into();
@@ -1765,6 +2121,113 @@ mod tests {
         assert_eq!(dca_config.grid_levels, 5);
     }
 
+    fn grid_test_strategy(recenter: bool, use_atr: bool) -> DcaStrategy {
+        let config = GridTradingConfig {
+            ticker: "BTCUSDT".to_string(),
+            amount: dec!(100000),
+            spacing_pct: dec!(1),
+            levels: 3,
+            use_atr,
+            atr_period: 14,
+            atr_spacing_multiplier: dec!(1),
+            exit_config: ExitConfig::for_grid_trading(),
+            max_positions: 10,
+            reset_threshold_pct: dec!(10),
+            recenter,
+            recenter_cooldown_candles: 2,
+            warmup_candles: 0,
+        };
+        let mut strategy = DcaStrategy::grid();
+        strategy.config = Some(config.into());
+        strategy.initialized = true;
+        strategy
+    }
+
+    #[test]
+    fn test_grid_recenter_keeps_held_levels() {
+        let mut strategy = grid_test_strategy(true, false);
+
+        // 기준가 100, 간격 1: L0 99, L1 98, L2 97
+        assert!(strategy.generate_grid_signals(dec!(100)).is_empty());
+        let signals = strategy.generate_grid_signals(dec!(99));
+        assert_eq!(signals.len(), 1);
+        assert_eq!(strategy.grid_levels[0].state, GridLevelState::WaitingSell);
+
+        // 하단(97) 이탈 → 현재가 96.5로 재배치, 미체결 L1/L2는 취소
+        assert!(strategy.generate_grid_signals(dec!(96.5)).is_empty());
+        assert_eq!(strategy.grid_base_price, dec!(96.5));
+        assert_eq!(strategy.grid_recenter_count, 1);
+
+        // 보유 레벨은 인덱스·진입가·익절가 유지
+        let held = &strategy.grid_levels[0];
+        assert_eq!(held.state, GridLevelState::WaitingSell);
+        assert_eq!(held.buy_price, dec!(99));
+        assert_eq!(held.sell_price, dec!(100));
+
+        // 나머지 레벨은 새 중심 기준 매수 대기
+        let level = &strategy.grid_levels[1];
+        assert_eq!(level.state, GridLevelState::WaitingBuy);
+        assert_eq!(level.buy_price, dec!(96.5) - dec!(0.965) * dec!(2));
+    }
+
+    #[test]
+    fn test_grid_recenter_cooldown() {
+        let mut strategy = grid_test_strategy(true, false);
+        strategy.generate_grid_signals(dec!(100));
+        strategy.generate_grid_signals(dec!(110)); // 상단 이탈 → 재배치
+        assert_eq!(strategy.grid_recenter_count, 1);
+        assert_eq!(strategy.grid_base_price, dec!(110));
+
+        // 쿨다운(2캔들) 내 재이탈은 보류
+        strategy.generate_grid_signals(dec!(120));
+        assert_eq!(strategy.grid_recenter_count, 1);
+        assert_eq!(strategy.grid_base_price, dec!(110));
+
+        strategy.generate_grid_signals(dec!(125));
+        assert_eq!(strategy.grid_recenter_count, 2);
+        assert_eq!(strategy.grid_base_price, dec!(125));
+    }
+
+    #[test]
+    fn test_grid_without_recenter_ignores_small_breach() {
+        let mut strategy = grid_test_strategy(false, false);
+        strategy.generate_grid_signals(dec!(100));
+        // 재설정 임계값(10%) 이내 이탈은 그리드 유지
+        strategy.generate_grid_signals(dec!(105));
+        assert_eq!(strategy.grid_base_price, dec!(100));
+        assert_eq!(strategy.grid_recenter_count, 0);
+    }
+
+    #[test]
+    fn test_grid_atr_spacing() {
+        let mut strategy = grid_test_strategy(false, true);
+
+        // TR = 2로 일정한 캔들 → ATR 2
+        let klines: Vec<Kline> = (0..20)
+            .map(|i| {
+                let time = Utc::now() + chrono::Duration::days(i);
+                Kline::new(
+                    "BTCUSDT".to_string(),
+                    Timeframe::D1,
+                    time,
+                    dec!(100),
+                    dec!(101),
+                    dec!(99),
+                    dec!(100),
+                    dec!(1000),
+                    time,
+                )
+            })
+            .collect();
+        let mut ctx = StrategyContext::new();
+        ctx.update_klines("BTCUSDT", Timeframe::D1, klines);
+        strategy.context = Some(Arc::new(RwLock::new(ctx)));
+
+        strategy.generate_grid_signals(dec!(100));
+        assert_eq!(strategy.grid_spacing, dec!(2));
+        assert_eq!(strategy.grid_levels[0].buy_price, dec!(98));
+    }
+
     #[test]
     fn test_magic_split_config() {
         let config = MagicSplitConfig {
@@ -1787,6 +2250,8 @@ mod tests {
             round_pct: dec!(2),
             dip_trigger_pct: dec!(2),
             take_profit_pct: dec!(3),
+            exit_ratio_pct: dec!(100),
+            reinvest_pct: dec!(100),
             ma_period: 20,
             min_global_score: dec!(50),
             exit_config: ExitConfig::for_grid_trading(),
@@ -1807,4 +2272,116 @@ mod tests {
         let ret = state.current_return(dec!(1100));
         assert_eq!(ret, Some(dec!(10)));
     }
+
+    fn infinity_test_config(
+        round_pct: Decimal,
+        exit_ratio_pct: Decimal,
+        reinvest_pct: Decimal,
+    ) -> Value {
+        let config = InfinityBotConfig {
+            ticker: "005930".to_string(),
+            total_amount: dec!(1000000),
+            max_rounds: 50,
+            round_pct,
+            dip_trigger_pct: dec!(2),
+            take_profit_pct: dec!(3),
+            exit_ratio_pct,
+            reinvest_pct,
+            ma_period: 20,
+            min_global_score: dec!(50),
+            exit_config: ExitConfig::for_grid_trading(),
+        };
+        let mut value = serde_json::to_value(config).unwrap();
+        value["variant"] = json!("infinity_bot");
+        value
+    }
+
+    /// RouteState::Attack 컨텍스트로 진입 필터를 통과시킨 인피니티봇 생성
+    async fn create_infinity_strategy(config: Value) -> DcaStrategy {
+        let mut strategy = DcaStrategy::infinity_bot();
+        strategy.initialize(config).await.unwrap();
+
+        let mut ctx = StrategyContext::new();
+        ctx.update_route_states(std::collections::HashMap::from([(
+            "005930".to_string(),
+            RouteState::Attack,
+        )]));
+        strategy.set_context(Arc::new(RwLock::new(ctx)));
+        strategy
+    }
+
+    #[tokio::test]
+    async fn test_infinity_cycle_reinvests_profit() {
+        let mut strategy =
+            create_infinity_strategy(infinity_test_config(dec!(10), dec!(100), dec!(50))).await;
+
+        let entry = strategy.generate_infinity_signals(dec!(100), 0);
+        assert_eq!(entry.len(), 1);
+        assert_eq!(entry[0].signal_type, SignalType::Entry);
+
+        // 3% 상승 → 전량 익절, 사이클 종료
+        let exit = strategy.generate_infinity_signals(dec!(103), 1);
+        assert_eq!(exit.len(), 1);
+        assert_eq!(exit[0].signal_type, SignalType::Exit);
+
+        let cycle = &strategy.infinity_cycle;
+        assert_eq!(cycle.completed_cycles, 1);
+        assert_eq!(cycle.profitable_cycles, 1);
+        assert_eq!(cycle.current_cycle, 2);
+        assert_eq!(cycle.cumulative_realized_pnl, dec!(3000));
+        // 수익 3000 중 50% 재투자, 50% 현금화
+        assert_eq!(cycle.capital, dec!(1001500));
+        assert_eq!(cycle.withdrawn_profit, dec!(1500));
+        assert_eq!(cycle.average_cycle_return_pct(), dec!(3));
+
+        // 새 사이클은 늘어난 투자금 기준으로 라운드 금액 계산
+        assert_eq!(strategy.round_amount(), dec!(100150));
+        let reentry = strategy.generate_infinity_signals(dec!(103), 2);
+        assert_eq!(reentry.len(), 1);
+        assert_eq!(strategy.infinity_state.current_round, 1);
+    }
+
+    #[tokio::test]
+    async fn test_infinity_partial_exit_carries_rounds() {
+        let mut strategy =
+            create_infinity_strategy(infinity_test_config(dec!(10), dec!(50), dec!(100))).await;
+
+        strategy.generate_infinity_signals(dec!(100), 0);
+        strategy.generate_infinity_signals(dec!(98), 1);
+        assert_eq!(strategy.infinity_state.rounds.len(), 2);
+
+        // 평균 단가 대비 3% 이상 → 진입가가 낮은 R2만 청산
+        let exit = strategy.generate_infinity_signals(dec!(102), 2);
+        assert_eq!(exit.len(), 1);
+        assert_eq!(exit[0].position_id.as_deref(), Some("005930_infinity_R2"));
+
+        assert_eq!(strategy.infinity_cycle.completed_cycles, 1);
+        assert_eq!(strategy.infinity_state.rounds.len(), 1);
+        assert_eq!(strategy.infinity_state.rounds[0].round, 1);
+        assert_eq!(strategy.infinity_state.avg_price, Some(dec!(100)));
+        assert_eq!(strategy.last_entry_price, Some(dec!(102)));
+    }
+
+    #[tokio::test]
+    async fn test_infinity_stops_when_funds_exhausted() {
+        // 라운드당 40% → 2라운드 이후 자금 부족
+        let mut strategy =
+            create_infinity_strategy(infinity_test_config(dec!(40), dec!(100), dec!(100))).await;
+
+        assert_eq!(strategy.generate_infinity_signals(dec!(100), 0).len(), 1);
+        assert_eq!(strategy.generate_infinity_signals(dec!(97), 1).len(), 1);
+
+        let blocked = strategy.generate_infinity_signals(dec!(94), 2);
+        assert!(blocked.is_empty());
+        assert!(strategy.infinity_cycle.funds_exhausted);
+        assert_eq!(strategy.infinity_state.rounds.len(), 2);
+
+        // 회복 후 익절하면 가드 해제
+        let exit = strategy.generate_infinity_signals(dec!(102), 3);
+        assert_eq!(exit.len(), 2);
+        assert!(!strategy.infinity_cycle.funds_exhausted);
+
+        let state = strategy.get_state();
+        assert_eq!(state["state"]["cycle"]["completed_cycles"], json!(1));
+    }
 }
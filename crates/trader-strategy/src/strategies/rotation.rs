@@ -283,6 +283,21 @@ pub struct RotationConfig {
     #[schema(label = "리밸런싱 허용 오차 (%)", min = 1, max = 20)]
     pub rebalance_threshold: Decimal,
 
+    /// 신규 진입 랭킹 (이 순위 이내여야 매수, 미설정 시 top_n)
+    #[serde(default)]
+    #[schema(label = "진입 랭킹", min = 1, max = 50)]
+    pub entry_rank: Option<usize>,
+
+    /// 보유 유지 랭킹 (보유 종목은 이 순위 이내면 유지, 미설정 시 top_n)
+    #[serde(default)]
+    #[schema(label = "유지 랭킹", min = 1, max = 50)]
+    pub exit_rank: Option<usize>,
+
+    /// 최소 보유 기간 (리밸런싱 주기 수, 미충족 시 랭킹이 떨어져도 청산하지 않음)
+    #[serde(default)]
+    #[schema(label = "최소 보유 기간 (리밸런싱 주기)", min = 0, max = 24)]
+    pub min_holding_periods: usize,
+
     /// 최소 모멘텀 (이 이하면 투자 안 함)
     #[serde(default)]
     #[schema(label = "최소 모멘텀")]
@@ -403,6 +418,40 @@ pub struct SectorMomentumConfig {
     )]
     pub rebalance_threshold: Decimal,
 
+    /// 신규 진입 랭킹 (이 순위 이내여야 매수, 미설정 시 상위 N)
+    #[serde(default)]
+    #[schema(
+        label = "진입 랭킹",
+        field_type = "integer",
+        min = 1,
+        max = 50,
+        section = "filter"
+    )]
+    pub entry_rank: Option<usize>,
+
+    /// 보유 유지 랭킹 (보유 종목은 이 순위 이내면 유지, 미설정 시 상위 N)
+    #[serde(default)]
+    #[schema(
+        label = "유지 랭킹",
+        field_type = "integer",
+        min = 1,
+        max = 50,
+        section = "filter"
+    )]
+    pub exit_rank: Option<usize>,
+
+    /// 최소 보유 기간 (리밸런싱 주기 수)
+    #[serde(default)]
+    #[schema(
+        label = "최소 보유 기간 (리밸런싱 주기)",
+        field_type = "integer",
+        min = 0,
+        max = 24,
+        default = "0",
+        section = "filter"
+    )]
+    pub min_holding_periods: usize,
+
     /// 최소 모멘텀 (이 이하면 투자 안 함)
     #[serde(default = "default_min_momentum")]
     #[schema(label = "최소 모멘텀", field_type = "number", min = -100, max = 100, default = "0", section = "indicator")]
@@ -444,6 +493,9 @@ impl From<SectorMomentumConfig> for RotationConfig {
         base.top_n = cfg.top_n;
         base.total_amount = cfg.total_amount;
         base.rebalance_threshold = cfg.rebalance_threshold;
+        base.entry_rank = cfg.entry_rank;
+        base.exit_rank = cfg.exit_rank;
+        base.min_holding_periods = cfg.min_holding_periods;
         base.min_momentum = Some(cfg.min_momentum);
         base.cash_reserve_rate = cfg.cash_reserve_rate;
         base.min_global_score = cfg.min_global_score;
@@ -497,6 +549,40 @@ pub struct SectorMomentumKrConfig {
     )]
     pub rebalance_threshold: Decimal,
 
+    /// 신규 진입 랭킹 (이 순위 이내여야 매수, 미설정 시 상위 N)
+    #[serde(default)]
+    #[schema(
+        label = "진입 랭킹",
+        field_type = "integer",
+        min = 1,
+        max = 50,
+        section = "filter"
+    )]
+    pub entry_rank: Option<usize>,
+
+    /// 보유 유지 랭킹 (보유 종목은 이 순위 이내면 유지, 미설정 시 상위 N)
+    #[serde(default)]
+    #[schema(
+        label = "유지 랭킹",
+        field_type = "integer",
+        min = 1,
+        max = 50,
+        section = "filter"
+    )]
+    pub exit_rank: Option<usize>,
+
+    /// 최소 보유 기간 (리밸런싱 주기 수)
+    #[serde(default)]
+    #[schema(
+        label = "최소 보유 기간 (리밸런싱 주기)",
+        field_type = "integer",
+        min = 0,
+        max = 24,
+        default = "0",
+        section = "filter"
+    )]
+    pub min_holding_periods: usize,
+
     /// 최소 모멘텀
     #[serde(default = "default_min_momentum")]
     #[schema(label = "최소 모멘텀", field_type = "number", min = -100, max = 100, default = "0", section = "indicator")]
@@ -538,6 +624,9 @@ impl From<SectorMomentumKrConfig> for RotationConfig {
         base.top_n = cfg.top_n;
         base.total_amount = cfg.total_amount;
         base.rebalance_threshold = cfg.rebalance_threshold;
+        base.entry_rank = cfg.entry_rank;
+        base.exit_rank = cfg.exit_rank;
+        base.min_holding_periods = cfg.min_holding_periods;
         base.min_momentum = Some(cfg.min_momentum);
         base.cash_reserve_rate = cfg.cash_reserve_rate;
         base.min_global_score = cfg.min_global_score;
@@ -591,6 +680,40 @@ pub struct StockRotationConfig {
     )]
     pub rebalance_threshold: Decimal,
 
+    /// 신규 진입 랭킹 (이 순위 이내여야 매수, 미설정 시 상위 N)
+    #[serde(default)]
+    #[schema(
+        label = "진입 랭킹",
+        field_type = "integer",
+        min = 1,
+        max = 50,
+        section = "filter"
+    )]
+    pub entry_rank: Option<usize>,
+
+    /// 보유 유지 랭킹 (보유 종목은 이 순위 이내면 유지, 미설정 시 상위 N)
+    #[serde(default)]
+    #[schema(
+        label = "유지 랭킹",
+        field_type = "integer",
+        min = 1,
+        max = 50,
+        section = "filter"
+    )]
+    pub exit_rank: Option<usize>,
+
+    /// 최소 보유 기간 (리밸런싱 주기 수)
+    #[serde(default)]
+    #[schema(
+        label = "최소 보유 기간 (리밸런싱 주기)",
+        field_type = "integer",
+        min = 0,
+        max = 24,
+        default = "0",
+        section = "filter"
+    )]
+    pub min_holding_periods: usize,
+
     /// 최소 모멘텀
     #[serde(default = "default_min_momentum")]
     #[schema(label = "최소 모멘텀", field_type = "number", min = -100, max = 100, default = "0", section = "indicator")]
@@ -632,6 +755,9 @@ impl From<StockRotationConfig> for RotationConfig {
         base.top_n = cfg.top_n;
         base.total_amount = cfg.total_amount;
         base.rebalance_threshold = cfg.rebalance_threshold;
+        base.entry_rank = cfg.entry_rank;
+        base.exit_rank = cfg.exit_rank;
+        base.min_holding_periods = cfg.min_holding_periods;
         base.min_momentum = Some(cfg.min_momentum);
         base.cash_reserve_rate = cfg.cash_reserve_rate;
         base.min_global_score = cfg.min_global_score;
@@ -685,6 +811,40 @@ pub struct StockRotationKrConfig {
     )]
     pub rebalance_threshold: Decimal,
 
+    /// 신규 진입 랭킹 (이 순위 이내여야 매수, 미설정 시 상위 N)
+    #[serde(default)]
+    #[schema(
+        label = "진입 랭킹",
+        field_type = "integer",
+        min = 1,
+        max = 50,
+        section = "filter"
+    )]
+    pub entry_rank: Option<usize>,
+
+    /// 보유 유지 랭킹 (보유 종목은 이 순위 이내면 유지, 미설정 시 상위 N)
+    #[serde(default)]
+    #[schema(
+        label = "유지 랭킹",
+        field_type = "integer",
+        min = 1,
+        max = 50,
+        section = "filter"
+    )]
+    pub exit_rank: Option<usize>,
+
+    /// 최소 보유 기간 (리밸런싱 주기 수)
+    #[serde(default)]
+    #[schema(
+        label = "최소 보유 기간 (리밸런싱 주기)",
+        field_type = "integer",
+        min = 0,
+        max = 24,
+        default = "0",
+        section = "filter"
+    )]
+    pub min_holding_periods: usize,
+
     /// 최소 모멘텀
     #[serde(default = "default_min_momentum")]
     #[schema(label = "최소 모멘텀", field_type = "number", min = -100, max = 100, default = "0", section = "indicator")]
@@ -726,6 +886,9 @@ impl From<StockRotationKrConfig> for RotationConfig {
         base.top_n = cfg.top_n;
         base.total_amount = cfg.total_amount;
         base.rebalance_threshold = cfg.rebalance_threshold;
+        base.entry_rank = cfg.entry_rank;
+        base.exit_rank = cfg.exit_rank;
+        base.min_holding_periods = cfg.min_holding_periods;
         base.min_momentum = Some(cfg.min_momentum);
         base.cash_reserve_rate = cfg.cash_reserve_rate;
         base.min_global_score = cfg.min_global_score;
@@ -779,6 +942,40 @@ pub struct MarketCapTopConfig {
     )]
     pub rebalance_threshold: Decimal,
 
+    /// 신규 진입 랭킹 (이 순위 이내여야 매수, 미설정 시 상위 N)
+    #[serde(default)]
+    #[schema(
+        label = "진입 랭킹",
+        field_type = "integer",
+        min = 1,
+        max = 50,
+        section = "filter"
+    )]
+    pub entry_rank: Option<usize>,
+
+    /// 보유 유지 랭킹 (보유 종목은 이 순위 이내면 유지, 미설정 시 상위 N)
+    #[serde(default)]
+    #[schema(
+        label = "유지 랭킹",
+        field_type = "integer",
+        min = 1,
+        max = 50,
+        section = "filter"
+    )]
+    pub exit_rank: Option<usize>,
+
+    /// 최소 보유 기간 (리밸런싱 주기 수)
+    #[serde(default)]
+    #[schema(
+        label = "최소 보유 기간 (리밸런싱 주기)",
+        field_type = "integer",
+        min = 0,
+        max = 24,
+        default = "0",
+        section = "filter"
+    )]
+    pub min_holding_periods: usize,
+
     /// 모멘텀 필터 사용 여부
     #[serde(default = "default_false")]
     #[schema(
@@ -825,6 +1022,9 @@ impl From<MarketCapTopConfig> for RotationConfig {
         base.top_n = cfg.top_n;
         base.total_amount = cfg.total_amount;
         base.rebalance_threshold = cfg.rebalance_threshold;
+        base.entry_rank = cfg.entry_rank;
+        base.exit_rank = cfg.exit_rank;
+        base.min_holding_periods = cfg.min_holding_periods;
         base.use_momentum_filter = cfg.use_momentum_filter;
         base.cash_reserve_rate = cfg.cash_reserve_rate;
         base.min_global_score = cfg.min_global_score;
@@ -857,6 +1057,9 @@ impl RotationConfig {
             weighting_method: WeightingMethod::Equal,
             rebalance_frequency: RebalanceFrequency::Monthly,
             rebalance_threshold: default_rebalance_threshold(),
+            entry_rank: None,
+            exit_rank: None,
+            min_holding_periods: 0,
             min_momentum: None,
             cash_reserve_rate: Decimal::ZERO,
             use_momentum_filter: false,
@@ -891,6 +1094,9 @@ impl RotationConfig {
             weighting_method: WeightingMethod::Equal,
             rebalance_frequency: RebalanceFrequency::Monthly,
             rebalance_threshold: dec!(3),
+            entry_rank: None,
+            exit_rank: None,
+            min_holding_periods: 0,
             min_momentum: None,
             cash_reserve_rate: Decimal::ZERO,
             use_momentum_filter: false,
@@ -923,6 +1129,9 @@ impl RotationConfig {
             weighting_method: WeightingMethod::Equal,
             rebalance_frequency: RebalanceFrequency::Days(30),
             rebalance_threshold: default_rebalance_threshold(),
+            entry_rank: None,
+            exit_rank: None,
+            min_holding_periods: 0,
             min_momentum: None,
             cash_reserve_rate: Decimal::ZERO,
             use_momentum_filter: false,
@@ -1005,6 +1214,18 @@ impl RotationConfig {
         ]
     }
 
+    /// 신규 진입 허용 랭킹 (미설정 시 top_n).
+    pub fn effective_entry_rank(&self) -> usize {
+        self.entry_rank.unwrap_or(self.top_n).max(1)
+    }
+
+    /// 보유 유지 랭킹 (진입 랭킹보다 작을 수 없음).
+    pub fn effective_exit_rank(&self) -> usize {
+        self.exit_rank
+            .unwrap_or(self.top_n)
+            .max(self.effective_entry_rank())
+    }
+
     /// 유니버스의 모든 티커 반환.
     pub fn all_tickers(&self) -> Vec<String> {
         self.universe.iter().map(|a| a.ticker.clone()).collect()
@@ -1154,6 +1375,15 @@ pub struct RotationStrategy {
 
     /// 통계: 거래 횟수
     trades_count: u32,
+
+    /// 보유 시작 시점 (최소 보유 기간 판단용)
+    held_since: HashMap<String, DateTime<Utc>>,
+
+    /// 통계: 종목 교체 횟수 (순위 진입 매수 + 순위 이탈 매도)
+    rotation_count: u32,
+
+    /// 통계: 히스테리시스/최소 보유 기간으로 교체를 피한 횟수
+    whipsaw_prevented: u32,
 }
 
 impl RotationStrategy {
@@ -1173,6 +1403,9 @@ impl RotationStrategy {
             cash_balance: Decimal::ZERO,
             initialized: false,
             trades_count: 0,
+            held_since: HashMap::new(),
+            rotation_count: 0,
+            whipsaw_prevented: 0,
         }
     }
 
@@ -1192,6 +1425,9 @@ impl RotationStrategy {
             cash_balance: Decimal::ZERO,
             initialized: false,
             trades_count: 0,
+            held_since: HashMap::new(),
+            rotation_count: 0,
+            whipsaw_prevented: 0,
         }
     }
 
@@ -1395,13 +1631,13 @@ impl RotationStrategy {
     // 비중 계산
     // ========================================================================
 
-    /// 목표 비중 계산.
+    /// 목표 비중 계산 (선정된 보유 대상 전체에 배분).
     fn calculate_target_weights(&self, ranked_assets: &[RankedAsset]) -> Vec<TargetAllocation> {
         let Some(config) = self.config.as_ref() else {
             return Vec::new();
         };
 
-        let top_n = config.top_n.min(ranked_assets.len());
+        let top_n = ranked_assets.len();
         if top_n == 0 {
             return Vec::new();
         }
@@ -1548,32 +1784,96 @@ impl RotationStrategy {
     // 교체 대상 계산
     // ========================================================================
 
-    /// 교체 대상 종목 계산.
-    fn calculate_rotation(&self, ranked_assets: &[RankedAsset]) -> (Vec<String>, Vec<String>) {
+    /// 보유 시작 이후 경과한 리밸런싱 주기 수.
+    ///
+    /// 보유 시작 시점 기록이 없으면 제한 없이 청산 가능하도록 `usize::MAX`를 반환합니다.
+    fn holding_periods(&self, ticker: &str, now: DateTime<Utc>) -> usize {
+        let (Some(config), Some(since)) = (self.config.as_ref(), self.held_since.get(ticker))
+        else {
+            return usize::MAX;
+        };
+
+        match &config.rebalance_frequency {
+            RebalanceFrequency::Monthly => {
+                let months =
+                    (now.year() - since.year()) * 12 + now.month() as i32 - since.month() as i32;
+                months.max(0) as usize
+            }
+            RebalanceFrequency::Days(days) => {
+                let elapsed = (now - *since).num_days().max(0) as usize;
+                elapsed / (*days as usize).max(1)
+            }
+        }
+    }
+
+    /// 보유 대상 종목 선정 (랭킹 히스테리시스 + 최소 보유 기간).
+    ///
+    /// 1. 최소 보유 기간 미충족 보유 종목은 랭킹과 무관하게 유지
+    /// 2. 유지 랭킹(`exit_rank`) 이내 보유 종목 유지
+    /// 3. 남은 슬롯은 진입 랭킹(`entry_rank`) 이내 미보유 종목으로 순위순 채움
+    ///
+    /// 반환값은 순위 오름차순으로 정렬됩니다.
+    fn select_holdings(
+        &self,
+        ranked_assets: &[RankedAsset],
+        now: DateTime<Utc>,
+    ) -> Vec<RankedAsset> {
         let Some(config) = self.config.as_ref() else {
-            return (Vec::new(), Vec::new());
+            return Vec::new();
         };
 
-        // 상위 N개 종목
-        let new_top_n: HashSet<String> = ranked_assets
+        let entry_rank = config.effective_entry_rank();
+        let exit_rank = config.effective_exit_rank();
+
+        let mut selected: Vec<RankedAsset> = Vec::new();
+        for ticker in &self.current_holdings {
+            let locked = self.holding_periods(ticker, now) < config.min_holding_periods;
+            match ranked_assets.iter().find(|a| &a.ticker == ticker) {
+                Some(asset) if asset.rank <= exit_rank || locked => selected.push(asset.clone()),
+                // 순위 계산에서 제외됐어도 최소 보유 기간 동안은 유지
+                None if locked => selected.push(RankedAsset {
+                    ticker: ticker.clone(),
+                    score: self
+                        .asset_data
+                        .get(ticker)
+                        .map(|d| d.momentum_score)
+                        .unwrap_or(Decimal::ZERO),
+                    rank: ranked_assets.len() + 1,
+                }),
+                _ => {}
+            }
+        }
+
+        let slots = config.top_n.saturating_sub(selected.len());
+        let entries: Vec<RankedAsset> = ranked_assets
             .iter()
-            .take(config.top_n)
-            .map(|a| a.ticker.clone())
+            .filter(|a| a.rank <= entry_rank && !self.current_holdings.contains(&a.ticker))
+            .take(slots)
+            .cloned()
             .collect();
+        selected.extend(entries);
+
+        selected.sort_by_key(|a| a.rank);
+        selected
+    }
+
+    /// 교체 대상 종목 계산.
+    fn calculate_rotation(&self, selected: &[RankedAsset]) -> (Vec<String>, Vec<String>) {
+        let selected_tickers: HashSet<&str> = selected.iter().map(|a| a.ticker.as_str()).collect();
 
-        // 매도 대상: 현재 보유 중인데 상위 N에서 빠진 종목
+        // 매도 대상: 현재 보유 중인데 보유 대상에서 빠진 종목
         let to_sell: Vec<String> = self
             .current_holdings
             .iter()
-            .filter(|t| !new_top_n.contains(*t))
+            .filter(|t| !selected_tickers.contains(t.as_str()))
             .cloned()
             .collect();
 
-        // 매수 대상: 상위 N에 있는데 현재 미보유
-        let to_buy: Vec<String> = new_top_n
+        // 매수 대상: 보유 대상에 있는데 현재 미보유
+        let to_buy: Vec<String> = selected
             .iter()
-            .filter(|t| !self.current_holdings.contains(*t))
-            .cloned()
+            .filter(|a| !self.current_holdings.contains(&a.ticker))
+            .map(|a| a.ticker.clone())
             .collect();
 
         (to_sell, to_buy)
@@ -1604,8 +1904,21 @@ impl RotationStrategy {
             return Vec::new();
         }
 
-        // 교체 대상 계산
-        let (to_sell, to_buy) = self.calculate_rotation(&ranked_assets);
+        // 보유 대상 선정 및 교체 대상 계산
+        let selected = self.select_holdings(&ranked_assets, timestamp);
+        let (to_sell, to_buy) = self.calculate_rotation(&selected);
+
+        // 단순 상위 N 규칙이었다면 교체됐을 보유 종목 수
+        let retained = selected
+            .iter()
+            .filter(|a| a.rank > config.top_n && self.current_holdings.contains(&a.ticker))
+            .count();
+        if retained > 0 {
+            debug!(
+                retained = retained,
+                "[Rotation] 히스테리시스/최소 보유 기간으로 보유 유지"
+            );
+        }
 
         if !to_sell.is_empty() {
             info!(
@@ -1623,7 +1936,7 @@ impl RotationStrategy {
         }
 
         // 목표 비중 계산
-        let target_allocations = self.calculate_target_weights(&ranked_assets);
+        let target_allocations = self.calculate_target_weights(&selected);
 
         if target_allocations.is_empty() {
             return Vec::new();
@@ -1706,10 +2019,14 @@ impl RotationStrategy {
             // 보유 종목 업데이트
             for ticker in &to_sell {
                 self.current_holdings.remove(ticker);
+                self.held_since.remove(ticker);
             }
             for ticker in &to_buy {
                 self.current_holdings.insert(ticker.clone());
+                self.held_since.insert(ticker.clone(), timestamp);
             }
+            self.rotation_count += (to_sell.len() + to_buy.len()) as u32;
+            self.whipsaw_prevented += retained as u32;
 
             info!(
                 variant = ?config.variant,
@@ -1944,6 +2261,7 @@ impl Strategy for RotationStrategy {
                     if data.holdings <= Decimal::ZERO {
                         data.holdings = Decimal::ZERO;
                         self.current_holdings.remove(&ticker);
+                        self.held_since.remove(&ticker);
                     }
                 }
             }
@@ -1969,6 +2287,9 @@ impl Strategy for RotationStrategy {
         if position.quantity > Decimal::ZERO {
             self.positions.insert(ticker.clone(), position.quantity);
             self.current_holdings.insert(ticker.clone());
+            self.held_since
+                .entry(ticker.clone())
+                .or_insert(position.opened_at);
 
             if let Some(data) = self.asset_data.get_mut(&ticker) {
                 data.holdings = position.quantity;
@@ -1976,6 +2297,7 @@ impl Strategy for RotationStrategy {
         } else {
             self.positions.remove(&ticker);
             self.current_holdings.remove(&ticker);
+            self.held_since.remove(&ticker);
 
             if let Some(data) = self.asset_data.get_mut(&ticker) {
                 data.holdings = Decimal::ZERO;
@@ -1995,6 +2317,8 @@ impl Strategy for RotationStrategy {
     async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         info!(
             trades_count = self.trades_count,
+            rotation_count = self.rotation_count,
+            whipsaw_prevented = self.whipsaw_prevented,
             holdings_count = self.current_holdings.len(),
             "[Rotation] 전략 종료"
         );
@@ -2011,6 +2335,8 @@ impl Strategy for RotationStrategy {
             "current_holdings": self.current_holdings.iter().collect::<Vec<_>>(),
             "last_rebalance": self.last_rebalance,
             "trades_count": self.trades_count,
+            "rotation_count": self.rotation_count,
+            "whipsaw_prevented": self.whipsaw_prevented,
             "cash_balance": self.cash_balance.to_string(),
             "positions": self.positions.iter()
                 .map(|(k, v)| (k.clone(), v.to_string()))
@@ -2106,6 +2432,8 @@ register_strategy! {
 
 #[cfg(test)]
 mod tests {
+    use chrono::TimeZone;
+
     use super::*;
 
     #[test]
@@ -2159,4 +2487,92 @@ mod tests {
         assert_eq!(MarketType::US.quote_currency(), "USD");
         assert_eq!(MarketType::KR.quote_currency(), "KRW");
     }
+
+    fn ranked(tickers: &[&str]) -> Vec<RankedAsset> {
+        tickers
+            .iter()
+            .enumerate()
+            .map(|(i, t)| RankedAsset {
+                ticker: t.to_string(),
+                score: Decimal::from(100 - i as i64),
+                rank: i + 1,
+            })
+            .collect()
+    }
+
+    fn tickers(selected: &[RankedAsset]) -> Vec<&str> {
+        selected.iter().map(|a| a.ticker.as_str()).collect()
+    }
+
+    #[test]
+    fn test_select_holdings_default_matches_top_n() {
+        let mut config = RotationConfig::stock_rotation_default();
+        config.top_n = 2;
+        let mut strategy = RotationStrategy::with_config(config);
+        strategy.current_holdings.insert("C".to_string());
+
+        let now = Utc::now();
+        let selected = strategy.select_holdings(&ranked(&["A", "B", "C", "D"]), now);
+        assert_eq!(tickers(&selected), vec!["A", "B"]);
+
+        let (to_sell, mut to_buy) = strategy.calculate_rotation(&selected);
+        to_buy.sort();
+        assert_eq!(to_sell, vec!["C".to_string()]);
+        assert_eq!(to_buy, vec!["A".to_string(), "B".to_string()]);
+    }
+
+    #[test]
+    fn test_select_holdings_exit_rank_hysteresis() {
+        let mut config = RotationConfig::stock_rotation_default();
+        config.top_n = 2;
+        config.entry_rank = Some(2);
+        config.exit_rank = Some(4);
+        let mut strategy = RotationStrategy::with_config(config);
+        strategy.current_holdings.insert("A".to_string());
+        strategy.current_holdings.insert("D".to_string());
+
+        // D는 4위로 떨어졌지만 유지 랭킹 이내 → 보유 유지, 빈 슬롯 없음
+        let now = Utc::now();
+        let selected = strategy.select_holdings(&ranked(&["B", "A", "C", "D", "E"]), now);
+        assert_eq!(tickers(&selected), vec!["A", "D"]);
+
+        // E로 더 밀려나면 청산되고 진입 랭킹 이내 B가 편입
+        let selected = strategy.select_holdings(&ranked(&["B", "A", "C", "E", "D"]), now);
+        assert_eq!(tickers(&selected), vec!["B", "A"]);
+    }
+
+    #[test]
+    fn test_select_holdings_min_holding_period_blocks_exit() {
+        let mut config = RotationConfig::stock_rotation_default();
+        config.top_n = 1;
+        config.min_holding_periods = 2;
+        let mut strategy = RotationStrategy::with_config(config);
+
+        let entry = Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap();
+        strategy.current_holdings.insert("B".to_string());
+        strategy.held_since.insert("B".to_string(), entry);
+
+        // 1개월 보유: 랭킹이 떨어져도 유지
+        let one_month = Utc.with_ymd_and_hms(2024, 2, 5, 0, 0, 0).unwrap();
+        assert_eq!(strategy.holding_periods("B", one_month), 1);
+        let selected = strategy.select_holdings(&ranked(&["A", "C", "B"]), one_month);
+        assert_eq!(tickers(&selected), vec!["B"]);
+
+        // 2개월 보유: 청산 허용
+        let two_months = Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap();
+        let selected = strategy.select_holdings(&ranked(&["A", "C", "B"]), two_months);
+        assert_eq!(tickers(&selected), vec!["A"]);
+    }
+
+    #[test]
+    fn test_effective_ranks() {
+        let mut config = RotationConfig::stock_rotation_default();
+        assert_eq!(config.effective_entry_rank(), config.top_n);
+        assert_eq!(config.effective_exit_rank(), config.top_n);
+
+        config.entry_rank = Some(5);
+        config.exit_rank = Some(3);
+        // 유지 랭킹은 진입 랭킹보다 좁을 수 없음
+        assert_eq!(config.effective_exit_rank(), 5);
+    }
 }
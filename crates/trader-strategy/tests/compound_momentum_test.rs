@@ -48,6 +48,7 @@ fn create_kline_at_month(
             volume: dec!(10000),
             quote_volume: Some(close * dec!(10000)),
             num_trades: Some(100),
+            synthetic: false,
         }),
     }
 }
@@ -1,7 +1,7 @@
 //! DayTrading 전략 테스트
 //!
 //! 각 variant별 핵심 케이스를 모두 검증합니다:
-//! - Breakout: 변동성 돌파, 손절/익절
+//! - Breakout: 변동성 돌파, 손절/익절, 장중 세션 필터
 //! - Crossover: 골든/데드 크로스
 //! - VolumeSurge: 거래량 급증 + 연속 상승
 
@@ -54,6 +54,7 @@ fn create_kline(
             volume,
             quote_volume: Some(close * volume),
             num_trades: Some(100),
+            synthetic: false,
         }),
     }
 }
@@ -394,6 +395,184 @@ mod breakout_tests {
     }
 }
 
+// ================================================================================================
+// Breakout 장중 세션 테스트
+// ================================================================================================
+
+mod session_tests {
+    use super::*;
+
+    /// 세션 필터 설정 (UTC 기준, 진입 09:00~10:30, 강제 청산 15:20)
+    fn session_config() -> serde_json::Value {
+        json!({
+            "variant": "Breakout",
+            "ticker": "BTC/USDT",
+            "intraday_session": true,
+            "session_utc_offset_hours": 0,
+            "entry_start_time": "09:00",
+            "entry_end_time": "10:30",
+            "force_exit_time": "15:20",
+            "no_overnight": true
+        })
+    }
+
+    async fn session_strategy() -> DayTradingStrategy {
+        let mut strategy = DayTradingStrategy::new();
+        strategy.initialize(session_config()).await.unwrap();
+        strategy.set_context(setup_context_for_breakout(
+            "BTC/USDT",
+            dec!(50500),
+            dec!(49500),
+            dec!(50000),
+        ));
+        strategy
+    }
+
+    fn breakout_kline(hour: u32, day: u32) -> MarketData {
+        create_kline(
+            "BTC/USDT",
+            dec!(50200),
+            dec!(51000),
+            dec!(50200),
+            dec!(50800),
+            dec!(1000),
+            hour,
+            day,
+        )
+    }
+
+    fn exit_reason(signal: &trader_core::Signal) -> Option<String> {
+        signal
+            .metadata
+            .get("exit_reason")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_entry_allowed_inside_window() {
+        let mut strategy = session_strategy().await;
+
+        let signals = strategy
+            .on_market_data(&breakout_kline(10, 2))
+            .await
+            .unwrap();
+
+        assert_eq!(signals.len(), 1, "진입 시간대 내 돌파는 신호가 발생해야 함");
+        assert_eq!(signals[0].side, Side::Buy);
+    }
+
+    #[tokio::test]
+    async fn test_entry_blocked_outside_window() {
+        let mut strategy = session_strategy().await;
+
+        let signals = strategy
+            .on_market_data(&breakout_kline(12, 2))
+            .await
+            .unwrap();
+
+        assert!(
+            signals.is_empty(),
+            "진입 시간대 밖에서는 진입하지 않아야 함"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_force_exit_before_close() {
+        let mut strategy = session_strategy().await;
+        let entry = strategy
+            .on_market_data(&breakout_kline(10, 2))
+            .await
+            .unwrap();
+        assert_eq!(entry.len(), 1);
+
+        // 보유 구간: 청산 없음
+        let holding = strategy
+            .on_market_data(&breakout_kline(13, 2))
+            .await
+            .unwrap();
+        assert!(holding.is_empty());
+
+        // 강제 청산 시각 이후
+        let exit = strategy
+            .on_market_data(&breakout_kline(16, 2))
+            .await
+            .unwrap();
+        assert_eq!(exit.len(), 1);
+        assert_eq!(exit[0].side, Side::Sell);
+        assert_eq!(exit_reason(&exit[0]).as_deref(), Some("session_close"));
+    }
+
+    #[tokio::test]
+    async fn test_no_overnight_exits_next_day() {
+        let mut strategy = session_strategy().await;
+        strategy
+            .on_market_data(&breakout_kline(10, 2))
+            .await
+            .unwrap();
+
+        // 장 마감 캔들이 누락되어 다음 날 첫 캔들에서 청산
+        let exit = strategy
+            .on_market_data(&breakout_kline(9, 3))
+            .await
+            .unwrap();
+        assert_eq!(exit.len(), 1);
+        assert_eq!(exit_reason(&exit[0]).as_deref(), Some("overnight"));
+    }
+
+    #[tokio::test]
+    async fn test_overnight_allowed_keeps_position() {
+        let mut strategy = DayTradingStrategy::new();
+        let mut config = session_config();
+        config["no_overnight"] = json!(false);
+        strategy.initialize(config).await.unwrap();
+        strategy.set_context(setup_context_for_breakout(
+            "BTC/USDT",
+            dec!(50500),
+            dec!(49500),
+            dec!(50000),
+        ));
+
+        strategy
+            .on_market_data(&breakout_kline(10, 2))
+            .await
+            .unwrap();
+        let signals = strategy
+            .on_market_data(&breakout_kline(16, 2))
+            .await
+            .unwrap();
+
+        assert!(
+            signals.is_empty(),
+            "오버나이트 허용 시 강제 청산하지 않아야 함"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_daily_timeframe_falls_back() {
+        let mut strategy = session_strategy().await;
+
+        // 일봉 데이터: 장중 타이밍 없음 → 세션 필터 비활성화
+        let mut data = breakout_kline(12, 2);
+        if let MarketDataType::Kline(kline) = &mut data.data {
+            kline.timeframe = Timeframe::D1;
+        }
+        let signals = strategy.on_market_data(&data).await.unwrap();
+
+        assert_eq!(signals.len(), 1, "일봉에서는 시간 필터 없이 진입해야 함");
+        assert_eq!(strategy.get_state()["session_fallback"], json!(true));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_session_time_rejected() {
+        let mut strategy = DayTradingStrategy::new();
+        let mut config = session_config();
+        config["force_exit_time"] = json!("25:99");
+
+        assert!(strategy.initialize(config).await.is_err());
+    }
+}
+
 // ================================================================================================
 // Crossover 전략 테스트
 // ================================================================================================
@@ -797,6 +976,9 @@ mod config_tests {
         let config = BreakoutConfig::default();
         assert_eq!(config.k_factor, dec!(0.5));
         assert!(config.trade_both_directions);
+        assert!(!config.intraday_session);
+        assert_eq!(config.force_exit_time, "15:20");
+        assert!(config.no_overnight);
     }
 
     #[test]
@@ -40,6 +40,7 @@ fn create_kline_data(ticker_name: &str, close: Decimal) -> MarketData {
             close_time: now,
             quote_volume: None,
             num_trades: None,
+            synthetic: false,
         }),
     }
 }
@@ -89,6 +90,7 @@ fn setup_context_with_klines(ticker: &str, prices: &[Decimal]) -> Arc<RwLock<Str
                 close_time: time,
                 quote_volume: None,
                 num_trades: None,
+                synthetic: false,
             }
         })
         .collect();
@@ -42,6 +42,7 @@ fn create_kline_at(ticker: &str, close: Decimal, days_from_start: i64) -> Market
             volume: dec!(10000),
             quote_volume: Some(close * dec!(10000)),
             num_trades: Some(100),
+            synthetic: false,
         }),
     }
 }
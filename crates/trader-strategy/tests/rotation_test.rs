@@ -53,6 +53,7 @@ fn create_kline(ticker: &str, close: Decimal, timestamp: DateTime<Utc>) -> Marke
             volume: dec!(10000),
             quote_volume: Some(close * dec!(10000)),
             num_trades: Some(100),
+            synthetic: false,
         }),
     }
 }
@@ -536,6 +537,9 @@ mod config_tests {
             weighting_method: WeightingMethod::MomentumProportional,
             rebalance_frequency: RebalanceFrequency::Days(7),
             rebalance_threshold: dec!(3),
+            entry_rank: None,
+            exit_rank: None,
+            min_holding_periods: 0,
             min_momentum: Some(dec!(0.01)),
             cash_reserve_rate: dec!(0.1),
             use_momentum_filter: true,
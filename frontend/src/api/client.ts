@@ -945,12 +945,16 @@ export interface SaveBacktestResultRequest {
   success: boolean;
   /** 백테스트에 사용된 타임프레임 설정 (다중 TF 백테스트 시) */
   timeframes_used?: MultiTimeframeConfig;
+  /** 전략 파라미터 (설정 해시 및 실행 간 비교에 사용) */
+  parameters?: Record<string, unknown>;
 }
 
 /** 백테스트 결과 저장 응답 */
 export interface SaveBacktestResultResponse {
   id: string;
   message: string;
+  /** 동일 설정의 기존 결과를 갱신했는지 여부 */
+  replaced: boolean;
 }
 
 /** 백테스트 결과 목록 쿼리 파라미터 */
@@ -94,6 +94,12 @@ export function createWebSocket(options: CreateWebSocketOptions = {}) {
     ws.onopen = () => {
       log('Connected')
       setIsConnected(true)
+
+      // 주문/포지션/계정 채널은 인증이 필요하므로 구독보다 먼저 인증
+      const token = localStorage.getItem('auth_token')
+      if (token) {
+        ws?.send(JSON.stringify({ type: 'auth', token }))
+      }
       onConnect?.()
 
       // 재연결 시 기존 채널 재구독
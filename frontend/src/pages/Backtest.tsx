@@ -1175,7 +1175,11 @@ export function Backtest() {
           ...resultToSave,
           dbId: saveResponse.id,
         }
-        setResults('items', items => [storedResult, ...items])
+        // 동일 설정 재실행은 서버에서 기존 결과를 갱신하므로 목록에서도 교체
+        setResults('items', items => [
+          storedResult,
+          ...items.filter(item => item.dbId !== saveResponse.id),
+        ])
         log('백테스트 결과 저장됨:', saveResponse.id)
       } catch (saveErr) {
         logError('백테스트 결과 저장 실패:', saveErr)
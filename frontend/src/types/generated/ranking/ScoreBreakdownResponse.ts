@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * GlobalScore 구성요소 분해 응답
+ */
+export type ScoreBreakdownResponse = { 
+/**
+ * 결측 구성요소를 0점으로 채운 분해 (GlobalScore와 동일한 점수)
+ */
+breakdown: Record<string, unknown>, 
+/**
+ * 결측 구성요소를 제외하고 가중치를 재정규화한 분해
+ */
+reweighted: Record<string, unknown>, 
+/**
+ * 결측 구성요소 이름 목록
+ */
+missing_components: Array<string>, };
@@ -6,6 +6,7 @@ export type { FilterInfo } from './FilterInfo';
 export type { RankedSymbol } from './RankedSymbol';
 export type { RankingQuery } from './RankingQuery';
 export type { RankingResponse } from './RankingResponse';
+export type { ScoreBreakdownResponse } from './ScoreBreakdownResponse';
 export type { SevenFactorBatchRequest } from './SevenFactorBatchRequest';
 export type { SevenFactorBatchResponse } from './SevenFactorBatchResponse';
 export type { SevenFactorData } from './SevenFactorData';
@@ -0,0 +1,26 @@
+-- 거래소 요청/응답 추적 로그 마이그레이션
+-- trader-exchange RequestLogger의 DB 싱크(PgRequestLogSink)가 기록합니다.
+-- 요청/응답 본문은 기록 전에 민감 정보(API 키, 서명, 토큰)가 마스킹됩니다.
+
+-- 1. 요청 로그 테이블
+CREATE TABLE IF NOT EXISTS exchange_request_log (
+    id BIGSERIAL PRIMARY KEY,
+    correlation_id VARCHAR(100) NOT NULL,
+    exchange VARCHAR(30) NOT NULL,
+    method VARCHAR(10) NOT NULL,
+    endpoint VARCHAR(255) NOT NULL,
+    request TEXT,
+    status INTEGER,
+    response TEXT,
+    error TEXT,
+    latency_ms BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 2. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_exchange_request_log_correlation ON exchange_request_log(correlation_id);
+CREATE INDEX IF NOT EXISTS idx_exchange_request_log_created ON exchange_request_log(created_at DESC);
+
+-- 3. 코멘트
+COMMENT ON TABLE exchange_request_log IS '거래소 요청/응답 추적 로그 (민감 정보 마스킹됨)';
+COMMENT ON COLUMN exchange_request_log.correlation_id IS '신호 → 주문 → 체결 추적용 correlation ID';
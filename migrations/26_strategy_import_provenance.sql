@@ -0,0 +1,22 @@
+-- 전략 설정 가져오기(import) 출처 추적 마이그레이션
+-- trader-api의 POST /api/v1/strategies/import 가 기록합니다.
+-- 내보낸(export) 문서의 원본 전략 정보와 가져오기 시 발생한 경고를 보존합니다.
+
+-- 1. 가져오기 출처 테이블
+CREATE TABLE IF NOT EXISTS strategy_import_provenance (
+    strategy_id VARCHAR(100) PRIMARY KEY REFERENCES strategies(id) ON DELETE CASCADE,
+    source_strategy_id VARCHAR(100),
+    source_name VARCHAR(255),
+    source_strategy_version VARCHAR(50),
+    format_version INTEGER NOT NULL,
+    exported_at TIMESTAMPTZ,
+    warnings JSONB NOT NULL DEFAULT '[]'::jsonb,
+    imported_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 2. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_strategy_import_provenance_source ON strategy_import_provenance(source_strategy_id);
+
+-- 3. 코멘트
+COMMENT ON TABLE strategy_import_provenance IS '가져온 전략 설정의 출처 (원본 전략, 내보내기 시각, 포맷 버전)';
+COMMENT ON COLUMN strategy_import_provenance.warnings IS '가져오기 시 발생한 경고 (민감 정보 제거, 이름 변경 등)';
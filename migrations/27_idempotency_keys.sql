@@ -0,0 +1,21 @@
+-- 멱등 작업 키 마이그레이션
+-- trader-core IdempotencyGuard의 PostgreSQL 저장소(trader-data PgIdempotencyStore)가 사용합니다.
+-- 주문 client_order_id, 웹훅/알림 재전송, 데이터 수집 재실행의 중복 처리를 방지합니다.
+
+-- 1. 멱등 키 테이블
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    key VARCHAR(255) PRIMARY KEY,
+    status VARCHAR(20) NOT NULL CHECK (status IN ('in_progress', 'completed')),
+    result JSONB,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 2. 인덱스 생성 (만료 항목 정리용)
+CREATE INDEX IF NOT EXISTS idx_idempotency_keys_expires_at ON idempotency_keys(expires_at);
+
+-- 3. 코멘트
+COMMENT ON TABLE idempotency_keys IS '멱등 작업 키 (처리 중 락 및 완료 기록, TTL 기반 만료)';
+COMMENT ON COLUMN idempotency_keys.key IS '저장소 키 ({scope}:{id}, 예: order:sig_..., webhook:slack:..., collect:ohlcv:...)';
+COMMENT ON COLUMN idempotency_keys.result IS '캐시된 작업 결과 (결과 캐시 사용 시)';
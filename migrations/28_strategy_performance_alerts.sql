@@ -0,0 +1,44 @@
+-- 전략 성과 알림 마이그레이션
+-- 전략별 성과 임계치(수익률/손익/낙폭/거래 횟수) 알림 규칙과 발동 이력을 저장합니다.
+-- trader-api PerformanceAlertService가 주기적으로 평가하며, 발동된 규칙은 리셋 전까지 재발동하지 않습니다.
+
+-- 1. 성과 알림 규칙 테이블
+CREATE TABLE IF NOT EXISTS strategy_performance_alert_rule (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    strategy_id VARCHAR(100) NOT NULL,
+    rule_name VARCHAR(100) NOT NULL,
+    metric VARCHAR(30) NOT NULL CHECK (metric IN ('return_pct', 'total_pnl', 'drawdown_pct', 'trade_count')),
+    comparison VARCHAR(10) NOT NULL CHECK (comparison IN ('above', 'below')),
+    threshold DECIMAL(20, 8) NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    triggered_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT strategy_performance_alert_rule_unique UNIQUE (strategy_id, rule_name)
+);
+
+-- 2. 발동 이력 테이블
+CREATE TABLE IF NOT EXISTS strategy_performance_alert_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    rule_id UUID NOT NULL REFERENCES strategy_performance_alert_rule(id) ON DELETE CASCADE,
+    strategy_id VARCHAR(100) NOT NULL,
+    metric VARCHAR(30) NOT NULL,
+    comparison VARCHAR(10) NOT NULL,
+    threshold DECIMAL(20, 8) NOT NULL,
+    observed_value DECIMAL(20, 8) NOT NULL,
+    message TEXT NOT NULL,
+    triggered_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 3. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_perf_alert_rule_strategy ON strategy_performance_alert_rule(strategy_id);
+CREATE INDEX IF NOT EXISTS idx_perf_alert_rule_enabled ON strategy_performance_alert_rule(enabled) WHERE enabled = TRUE;
+CREATE INDEX IF NOT EXISTS idx_perf_alert_history_strategy ON strategy_performance_alert_history(strategy_id, triggered_at DESC);
+CREATE INDEX IF NOT EXISTS idx_perf_alert_history_rule ON strategy_performance_alert_history(rule_id, triggered_at DESC);
+
+-- 4. 코멘트
+COMMENT ON TABLE strategy_performance_alert_rule IS '전략 성과 알림 규칙 (목표 도달/손실 경보)';
+COMMENT ON COLUMN strategy_performance_alert_rule.metric IS '평가 지표 (return_pct: 수익률%, total_pnl: 누적 손익, drawdown_pct: 최고점 대비 낙폭%, trade_count: 거래 횟수)';
+COMMENT ON COLUMN strategy_performance_alert_rule.comparison IS '비교 방향 (above: 임계치 이상, below: 임계치 이하)';
+COMMENT ON COLUMN strategy_performance_alert_rule.triggered_at IS '마지막 발동 시각 (NULL이면 대기 상태, 리셋 시 NULL)';
+COMMENT ON TABLE strategy_performance_alert_history IS '전략 성과 알림 발동 이력';
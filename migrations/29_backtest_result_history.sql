@@ -0,0 +1,17 @@
+-- 백테스트 결과 이력 마이그레이션
+-- 설정 해시로 동일 실행을 식별해 중복 저장을 막고, 실행 간 비교를 위해 전략 파라미터를 저장합니다.
+-- trader-analytics BacktestRunKey가 해시를 계산하며, 해시 도입 이전 결과는 NULL로 남습니다.
+
+-- 1. 컬럼 추가
+ALTER TABLE backtest_results
+ADD COLUMN IF NOT EXISTS config_hash VARCHAR(64),
+ADD COLUMN IF NOT EXISTS parameters JSONB NOT NULL DEFAULT '{}';
+
+-- 2. 인덱스 생성 (동일 설정은 활성 결과 1건만 유지)
+CREATE UNIQUE INDEX IF NOT EXISTS idx_backtest_results_config_hash
+    ON backtest_results(strategy_id, config_hash)
+    WHERE deleted_at IS NULL AND config_hash IS NOT NULL;
+
+-- 3. 코멘트
+COMMENT ON COLUMN backtest_results.config_hash IS '설정 해시 (SHA-256: 전략/심볼/기간/자본/비용/파라미터, 동일 실행 식별용)';
+COMMENT ON COLUMN backtest_results.parameters IS '백테스트에 사용된 전략 파라미터 (실행 간 설정 비교용)';
@@ -0,0 +1,35 @@
+-- 기업 행동(분할·배당) 및 수정 종가 마이그레이션
+-- trader-collector corporate_action_sync가 Yahoo Finance에서 분할 비율·배당락일을 수집하고,
+-- apply_corporate_actions()가 역방향 누적 팩터로 ohlcv.adjusted_close를 계산합니다.
+-- 원본 OHLC는 변경하지 않으며, 반영 완료된 이벤트는 applied_at으로 표시해 중복 조정을 막습니다.
+
+-- 1. 기업 행동 테이블
+CREATE TABLE IF NOT EXISTS corporate_action (
+    id BIGSERIAL PRIMARY KEY,
+    symbol VARCHAR(50) NOT NULL,
+    action_type VARCHAR(20) NOT NULL CHECK (action_type IN ('split', 'dividend')),
+    ex_date DATE NOT NULL,
+    value DECIMAL(30, 15) NOT NULL,
+    source VARCHAR(20) NOT NULL DEFAULT 'yahoo',
+    applied_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (symbol, action_type, ex_date)
+);
+
+-- 2. OHLCV 수정 종가 컬럼
+ALTER TABLE ohlcv
+ADD COLUMN IF NOT EXISTS adjusted_close DECIMAL(30, 15),
+ADD COLUMN IF NOT EXISTS adj_factor DECIMAL(30, 15);
+
+-- 3. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_corporate_action_pending
+    ON corporate_action(symbol)
+    WHERE applied_at IS NULL;
+
+-- 4. 코멘트
+COMMENT ON TABLE corporate_action IS '기업 행동 (주식 분할, 배당락) - 수정 종가 계산용';
+COMMENT ON COLUMN corporate_action.value IS '분할: 분할 비율(분할 후/분할 전 주식 수), 배당: 주당 배당금(분할 반영 기준)';
+COMMENT ON COLUMN corporate_action.applied_at IS 'adjusted_close 반영 시각 (NULL이면 미반영)';
+COMMENT ON COLUMN ohlcv.adjusted_close IS '분할·배당 조정 종가 (close × adj_factor)';
+COMMENT ON COLUMN ohlcv.adj_factor IS '역방향 누적 조정 팩터 (최신 캔들 = 1)';
@@ -0,0 +1,15 @@
+-- 체크포인트 진행률/ETA 마이그레이션
+-- 워크플로우 시작 시 begin_checkpoint()가 예상 처리량과 시작 시각을 기록하고,
+-- list_checkpoints()가 처리 속도 기반으로 진행률(%)과 예상 완료 시각을 계산합니다.
+-- 재개 시 started_at을 유지하면 이전 실행의 처리량은 processed_offset으로 누적됩니다.
+
+-- 1. 진행률 컬럼
+ALTER TABLE sync_checkpoint
+ADD COLUMN IF NOT EXISTS total_expected INTEGER,
+ADD COLUMN IF NOT EXISTS started_at TIMESTAMPTZ,
+ADD COLUMN IF NOT EXISTS processed_offset INTEGER NOT NULL DEFAULT 0;
+
+-- 2. 코멘트
+COMMENT ON COLUMN sync_checkpoint.total_expected IS '예상 총 처리 항목 수 (NULL이면 진행률 미표시)';
+COMMENT ON COLUMN sync_checkpoint.started_at IS '워크플로우 시작 시각 (ETA 계산 기준)';
+COMMENT ON COLUMN sync_checkpoint.processed_offset IS '재개 시 유지된 이전 실행의 처리 수 (total_processed에 합산)';
@@ -0,0 +1,21 @@
+-- 상장폐지 감지 마이그레이션
+-- collect_ohlcv가 소스의 "데이터 없음" 응답을 거래일 단위로 추적하고,
+-- 설정된 거래일 수(OHLCV_DELISTING_NO_DATA_DAYS) 이상 연속 누락되면 심볼을 비활성화합니다.
+-- 네트워크 오류는 누락으로 집계하지 않으며, 데이터가 다시 수신되면 추적을 초기화합니다.
+-- 비활성화된 심볼은 `trader-collector delisted reactivate`로 수동 재활성화합니다.
+
+-- 1. 누락 추적 / 비활성화 사유 컬럼
+ALTER TABLE symbol_info
+ADD COLUMN IF NOT EXISTS no_data_since DATE,
+ADD COLUMN IF NOT EXISTS deactivated_reason VARCHAR(50),
+ADD COLUMN IF NOT EXISTS deactivated_at TIMESTAMPTZ;
+
+-- 2. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_symbol_info_deactivated
+    ON symbol_info(deactivated_at DESC)
+    WHERE is_active = false AND deactivated_reason IS NOT NULL;
+
+-- 3. 코멘트
+COMMENT ON COLUMN symbol_info.no_data_since IS '소스가 데이터 없음을 반환하기 시작한 첫 누락 거래일 (데이터 수신 시 NULL)';
+COMMENT ON COLUMN symbol_info.deactivated_reason IS '자동 비활성화 사유 (delisted_suspect 등, 수동 재활성화 시 NULL)';
+COMMENT ON COLUMN symbol_info.deactivated_at IS '자동 비활성화 시각';
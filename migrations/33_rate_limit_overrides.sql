@@ -0,0 +1,26 @@
+-- 클라이언트별 Rate Limit 개별 한도
+-- API 서버는 기동 시 이 테이블을 로드하여 사용자 ID / API 키 단위로 독립 버킷 한도를 적용합니다.
+-- API 키는 원문 대신 SHA-256 해시(hex)로 저장하며, 서버는 요청의 X-API-Key 값을 같은 방식으로 해시하여 조회합니다.
+-- 설정되지 않은 클라이언트는 전역 한도(rate_limit.requests_per_minute)를 따릅니다.
+-- 같은 대상이 RATE_LIMIT_OVERRIDES 환경변수에도 있으면 이 테이블 값이 우선합니다.
+
+-- 1. 개별 한도 테이블
+CREATE TABLE IF NOT EXISTS rate_limit_overrides (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    subject_type VARCHAR(20) NOT NULL CHECK (subject_type IN ('user', 'api_key')),
+    subject_id VARCHAR(255) NOT NULL,
+    requests_per_minute INT NOT NULL CHECK (requests_per_minute > 0),
+    burst_size INT NOT NULL DEFAULT 0 CHECK (burst_size >= 0),
+    description TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT unique_rate_limit_subject UNIQUE (subject_type, subject_id),
+    CONSTRAINT rate_limit_api_key_hashed
+        CHECK (subject_type <> 'api_key' OR subject_id ~ '^[0-9a-f]{64}$')
+);
+
+-- 2. 코멘트
+COMMENT ON TABLE rate_limit_overrides IS '클라이언트(사용자/API 키)별 Rate Limit 개별 한도';
+COMMENT ON COLUMN rate_limit_overrides.subject_type IS '대상 유형 (user: JWT sub, api_key: X-API-Key 헤더 값의 SHA-256 해시)';
+COMMENT ON COLUMN rate_limit_overrides.subject_id IS '대상 식별자 (api_key는 encode(sha256(키), ''hex'') 값)';
+COMMENT ON COLUMN rate_limit_overrides.burst_size IS '순간적으로 허용되는 추가 요청 수';
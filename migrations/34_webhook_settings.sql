@@ -0,0 +1,49 @@
+-- 범용 Webhook 알림 설정
+-- 임의의 HTTP 엔드포인트로 알림 JSON을 POST합니다.
+-- URL, 커스텀 헤더(JSON), HMAC 시크릿은 AES-256-GCM으로 암호화하여 저장합니다.
+
+-- ================================================================================================
+-- Webhook 설정 테이블
+-- ================================================================================================
+CREATE TABLE IF NOT EXISTS webhook_settings (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    -- 암호화된 URL
+    encrypted_url BYTEA NOT NULL,
+    encryption_nonce_url BYTEA NOT NULL,
+    -- 암호화된 커스텀 헤더 (JSON 객체)
+    encrypted_headers BYTEA,
+    encryption_nonce_headers BYTEA,
+    -- 암호화된 HMAC 서명 시크릿
+    encrypted_hmac_secret BYTEA,
+    encryption_nonce_hmac BYTEA,
+    -- 헤더 이름 목록 (조회용, 값은 암호화 컬럼에만 저장)
+    header_names TEXT[] NOT NULL DEFAULT '{}',
+    -- 전송 옵션
+    payload_template TEXT,
+    timeout_secs INT NOT NULL DEFAULT 10 CHECK (timeout_secs BETWEEN 1 AND 60),
+    max_retries INT NOT NULL DEFAULT 3 CHECK (max_retries BETWEEN 0 AND 10),
+    -- 표시 정보
+    display_name VARCHAR(100),
+    -- 상태
+    is_enabled BOOLEAN NOT NULL DEFAULT true,
+    notification_settings JSONB,
+    -- 메타데이터
+    last_message_at TIMESTAMPTZ,
+    last_verified_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 단일 설정만 허용
+CREATE UNIQUE INDEX IF NOT EXISTS idx_webhook_single_setting ON webhook_settings((1));
+
+COMMENT ON TABLE webhook_settings IS '범용 Webhook 알림 설정';
+COMMENT ON COLUMN webhook_settings.encrypted_url IS 'AES-256-GCM으로 암호화된 Webhook URL';
+COMMENT ON COLUMN webhook_settings.encrypted_headers IS 'AES-256-GCM으로 암호화된 커스텀 헤더 JSON';
+COMMENT ON COLUMN webhook_settings.payload_template IS 'payload 템플릿 ({{title}}, {{message}}, {{event}} 등 치환)';
+
+DROP TRIGGER IF EXISTS update_webhook_settings_updated_at ON webhook_settings;
+CREATE TRIGGER update_webhook_settings_updated_at
+    BEFORE UPDATE ON webhook_settings
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
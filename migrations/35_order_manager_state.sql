@@ -0,0 +1,48 @@
+-- OrderManager 주문 상태 영속화
+-- trader-execution OrderStore의 PostgreSQL 구현(trader-api PgOrderStore)이 사용합니다.
+-- API 서버 재시작 시 미완료 주문을 불러와 추적을 재개하고, 재시작 동안 발생한
+-- 체결을 거래소 체결 내역과 대조할 때 이미 반영한 체결 ID를 건너뜁니다.
+
+-- 1. 주문 스냅샷 테이블 (trader-core Order 직렬화)
+CREATE TABLE IF NOT EXISTS order_manager_orders (
+    order_id UUID PRIMARY KEY,
+    exchange VARCHAR(50) NOT NULL,
+    exchange_order_id VARCHAR(100),
+    ticker VARCHAR(50) NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    snapshot JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 2. 체결 기록 테이블
+CREATE TABLE IF NOT EXISTS order_manager_fills (
+    id BIGSERIAL PRIMARY KEY,
+    order_id UUID NOT NULL REFERENCES order_manager_orders(order_id) ON DELETE CASCADE,
+    quantity DECIMAL(30, 15) NOT NULL,
+    price DECIMAL(30, 15) NOT NULL,
+    commission DECIMAL(30, 15),
+    commission_asset VARCHAR(20),
+    filled_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 3. 처리된 거래소 체결 ID (중복 반영 방지)
+CREATE TABLE IF NOT EXISTS order_processed_executions (
+    execution_id VARCHAR(150) PRIMARY KEY,
+    order_id UUID NOT NULL REFERENCES order_manager_orders(order_id) ON DELETE CASCADE,
+    processed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 4. 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_order_manager_orders_open
+    ON order_manager_orders(created_at)
+    WHERE status IN ('pending', 'open', 'partially_filled');
+CREATE INDEX IF NOT EXISTS idx_order_manager_fills_order ON order_manager_fills(order_id);
+CREATE INDEX IF NOT EXISTS idx_order_processed_executions_order
+    ON order_processed_executions(order_id);
+
+-- 5. 코멘트
+COMMENT ON TABLE order_manager_orders IS 'OrderManager 주문 스냅샷 (재시작 복구용)';
+COMMENT ON COLUMN order_manager_orders.status IS 'OrderStatusType 직렬화 값 (pending, open, partially_filled, filled, ...)';
+COMMENT ON TABLE order_processed_executions IS '주문에 반영 완료된 거래소 체결 ID (누적 집계 체결은 {주문번호}@{누적수량})';
@@ -0,0 +1,14 @@
+-- 종목 목록 정렬 인덱스
+-- `trader list-symbols --sort-by market_cap|volume`은 결측값을 뒤로 보내는
+-- `ORDER BY ... DESC NULLS LAST`로 정렬합니다. 기존 market_cap 인덱스(DESC = NULLS FIRST)는
+-- 이 정렬 순서와 맞지 않으므로 NULLS LAST로 재생성하고, 거래량 정렬 인덱스를 추가합니다.
+-- 시장/섹터 필터는 기존 idx_symbol_info_market_sector, idx_symbol_info_sector를 사용합니다.
+
+-- 1. 시가총액 정렬 인덱스 재생성
+DROP INDEX IF EXISTS idx_symbol_fundamental_market_cap;
+CREATE INDEX IF NOT EXISTS idx_symbol_fundamental_market_cap
+    ON symbol_fundamental(market_cap DESC NULLS LAST);
+
+-- 2. 거래량(10일 평균) 정렬 인덱스
+CREATE INDEX IF NOT EXISTS idx_symbol_fundamental_avg_volume
+    ON symbol_fundamental(avg_volume_10d DESC NULLS LAST);
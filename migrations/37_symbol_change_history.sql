@@ -0,0 +1,28 @@
+-- 종목 변경 이력 마이그레이션
+-- `trader fetch-symbols --record-history`가 기존 DB 상태와 온라인 소스를 비교해
+-- 신규 상장(added), 정보 변경(changed), 폐지 추정(delisted)을 이 테이블에 기록합니다.
+-- 폐지 추정 종목은 삭제하지 않고 is_active = false, deactivated_reason = 'delisted_suspect'로
+-- 비활성화하므로 `trader-collector delisted reactivate`로 재활성화할 수 있습니다.
+
+-- 1. 변경 이력 테이블
+CREATE TABLE IF NOT EXISTS symbol_change_history (
+    id BIGSERIAL PRIMARY KEY,
+    symbol_info_id UUID REFERENCES symbol_info(id) ON DELETE SET NULL,
+    ticker VARCHAR(20) NOT NULL,
+    market VARCHAR(20) NOT NULL,
+    change_type VARCHAR(20) NOT NULL CHECK (change_type IN ('added', 'changed', 'delisted')),
+    changes JSONB NOT NULL DEFAULT '{}'::jsonb,
+    source VARCHAR(50) NOT NULL,
+    detected_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 2. 인덱스
+CREATE INDEX IF NOT EXISTS idx_symbol_change_history_ticker
+    ON symbol_change_history(market, ticker, detected_at DESC);
+CREATE INDEX IF NOT EXISTS idx_symbol_change_history_detected
+    ON symbol_change_history(detected_at DESC);
+
+-- 3. 코멘트
+COMMENT ON TABLE symbol_change_history IS 'fetch-symbols 증분 동기화가 감지한 종목 추가/변경/폐지 추정 이력';
+COMMENT ON COLUMN symbol_change_history.changes IS '변경된 필드별 이전/이후 값 ({"name": {"old": ..., "new": ...}})';
+COMMENT ON COLUMN symbol_change_history.source IS '비교 대상 소스 (KRX, YAHOO, BINANCE)';
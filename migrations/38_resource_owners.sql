@@ -0,0 +1,20 @@
+-- 리소스 소유자 마이그레이션
+-- 전략과 거래소 계정에 소유 사용자를 기록해 WebSocket 리소스 채널
+-- (`strategy:{id}`, `account:{credential_id}`) 구독을 소유자에게만 허용합니다.
+-- owner_id가 NULL인 기존 리소스는 소유자 미지정으로 간주하며 관리자만 접근할 수 있습니다.
+
+-- 1. 소유자 컬럼
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS owner_id UUID REFERENCES users(id) ON DELETE SET NULL;
+ALTER TABLE exchange_credentials
+    ADD COLUMN IF NOT EXISTS owner_id UUID REFERENCES users(id) ON DELETE SET NULL;
+
+-- 2. 인덱스
+CREATE INDEX IF NOT EXISTS idx_strategies_owner
+    ON strategies(owner_id) WHERE owner_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_exchange_credentials_owner
+    ON exchange_credentials(owner_id) WHERE owner_id IS NOT NULL;
+
+-- 3. 코멘트
+COMMENT ON COLUMN strategies.owner_id IS '전략을 생성한 사용자 (NULL이면 관리자 전용)';
+COMMENT ON COLUMN exchange_credentials.owner_id IS '계정을 등록한 사용자 (NULL이면 관리자 전용)';
@@ -263,7 +263,7 @@ class ModelTrainer:
 
         if model_type in ["random_forest", "gradient_boosting"]:
             # sklearn 모델 변환
-            initial_type = [("float_input", FloatTensorType([None, n_features]))]
+            initial_type = [("input", FloatTensorType([None, n_features]))]
             onnx_model = convert_sklearn(
                 model,
                 initial_types=initial_type,
@@ -273,7 +273,7 @@ class ModelTrainer:
         elif model_type == "xgboost":
             if not HAS_ONNXMLTOOLS:
                 raise ImportError("onnxmltools not installed. Install with: pip install onnxmltools")
-            initial_type = [("float_input", OnnxFloatTensorType([None, n_features]))]
+            initial_type = [("input", OnnxFloatTensorType([None, n_features]))]
             onnx_model = convert_xgboost(
                 model,
                 initial_types=initial_type,
@@ -283,7 +283,7 @@ class ModelTrainer:
         elif model_type == "lightgbm":
             if not HAS_ONNXMLTOOLS:
                 raise ImportError("onnxmltools not installed. Install with: pip install onnxmltools")
-            initial_type = [("float_input", OnnxFloatTensorType([None, n_features]))]
+            initial_type = [("input", OnnxFloatTensorType([None, n_features]))]
             onnx_model = convert_lightgbm(
                 model,
                 initial_types=initial_type,